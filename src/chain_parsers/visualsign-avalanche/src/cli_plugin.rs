@@ -0,0 +1,42 @@
+use clap::Args as ClapArgs;
+use generated::parser::ChainMetadata;
+use visualsign::registry::{Chain, TransactionConverterRegistry};
+
+/// CLI arguments specific to Avalanche.
+///
+/// No Avalanche-specific args are needed yet. The global `--network` flag is accepted but
+/// not used: the network ID is part of every transaction.
+#[derive(ClapArgs, Debug, Default, Clone)]
+pub struct AvalancheArgs {}
+
+/// [`parser_cli_core::ChainPlugin`] implementation for Avalanche.
+pub struct AvalanchePlugin {
+    // Empty today; kept so an Avalanche flag can be added without changing the struct shape.
+    #[allow(dead_code)]
+    args: AvalancheArgs,
+}
+
+impl AvalanchePlugin {
+    /// Creates a new `AvalanchePlugin` with the given CLI args.
+    #[must_use]
+    pub fn new(args: AvalancheArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl parser_cli_core::ChainPlugin for AvalanchePlugin {
+    fn chain(&self) -> Chain {
+        Chain::Avalanche
+    }
+
+    fn register(&self, registry: &mut TransactionConverterRegistry) {
+        registry.register::<crate::AvalancheTransactionWrapper, _>(
+            Chain::Avalanche,
+            crate::AvalancheVisualSignConverter,
+        );
+    }
+
+    fn create_metadata(&self, _network: Option<String>) -> Result<Option<ChainMetadata>, String> {
+        Ok(None)
+    }
+}
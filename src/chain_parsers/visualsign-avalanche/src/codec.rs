@@ -0,0 +1,497 @@
+// Decoding for P-chain (platformvm) and X-chain (avm) transactions. Both use the
+// Avalanche linear codec: big-endian integers, u32-prefixed arrays, and a u32 type ID in
+// front of every interface value. The two VMs register different type IDs, so the chain
+// a transaction belongs to decides how its type IDs are read.
+
+use crate::AvalancheParserError;
+
+pub type Id = [u8; 32];
+pub type ShortId = [u8; 20];
+
+const CODEC_VERSION: u16 = 0;
+const SIGNATURE_LEN: usize = 65;
+const BLS_PUBLIC_KEY_LEN: usize = 48;
+const BLS_SIGNATURE_LEN: usize = 96;
+
+// Type IDs shared by both VMs (secp256k1fx registers in the same order on each).
+const SECP_TRANSFER_INPUT: u32 = 5;
+const SECP_TRANSFER_OUTPUT: u32 = 7;
+const SECP_CREDENTIAL: u32 = 9;
+
+// avm type IDs.
+const AVM_BASE_TX: u32 = 0;
+const AVM_IMPORT_TX: u32 = 3;
+const AVM_EXPORT_TX: u32 = 4;
+
+// platformvm type IDs.
+const PVM_OUTPUT_OWNERS: u32 = 11;
+const PVM_IMPORT_TX: u32 = 17;
+const PVM_EXPORT_TX: u32 = 18;
+const PVM_STAKEABLE_LOCK_IN: u32 = 21;
+const PVM_STAKEABLE_LOCK_OUT: u32 = 22;
+const PVM_ADD_PERMISSIONLESS_VALIDATOR_TX: u32 = 25;
+const PVM_ADD_PERMISSIONLESS_DELEGATOR_TX: u32 = 26;
+const PVM_EMPTY_SIGNER: u32 = 27;
+const PVM_PROOF_OF_POSSESSION: u32 = 28;
+const PVM_BASE_TX: u32 = 34;
+
+/// The chain a transaction was built for, from its blockchain ID. The P-chain's ID is
+/// all zeros on every network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vm {
+    Platform,
+    Avm,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvalancheTransaction {
+    pub network_id: u32,
+    pub blockchain_id: Id,
+    pub vm: Vm,
+    pub outputs: Vec<TransferableOutput>,
+    pub inputs: Vec<TransferableInput>,
+    pub memo: Vec<u8>,
+    pub kind: TxKind,
+    /// Number of credentials when the transaction is already signed.
+    pub credentials: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxKind {
+    Base,
+    Import {
+        source_chain: Id,
+        inputs: Vec<TransferableInput>,
+    },
+    Export {
+        destination_chain: Id,
+        outputs: Vec<TransferableOutput>,
+    },
+    AddPermissionlessValidator {
+        validator: Validator,
+        subnet: Id,
+        bls_public_key: Option<Vec<u8>>,
+        stake: Vec<TransferableOutput>,
+        validator_rewards_owner: OutputOwners,
+        delegator_rewards_owner: OutputOwners,
+        /// Delegation fee in millionths: 20_000 is 2%.
+        delegation_shares: u32,
+    },
+    AddPermissionlessDelegator {
+        validator: Validator,
+        subnet: Id,
+        stake: Vec<TransferableOutput>,
+        rewards_owner: OutputOwners,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Validator {
+    pub node_id: ShortId,
+    pub start: u64,
+    pub end: u64,
+    pub weight: u64,
+}
+
+/// Who can spend an output, or receive rewards: `threshold` of `addresses`, after
+/// `locktime`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputOwners {
+    pub locktime: u64,
+    pub threshold: u32,
+    pub addresses: Vec<ShortId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferableOutput {
+    pub asset_id: Id,
+    pub amount: u64,
+    pub owners: OutputOwners,
+    /// Set for P-chain outputs that can only be staked until this time.
+    pub stakeable_locktime: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferableInput {
+    pub tx_id: Id,
+    pub output_index: u32,
+    pub asset_id: Id,
+    pub amount: u64,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], AvalancheParserError> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(AvalancheParserError::UnexpectedEnd)?;
+        let slice = self.bytes.get(self.pos..end).unwrap_or_default();
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], AvalancheParserError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u16(&mut self) -> Result<u16, AvalancheParserError> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    fn u32(&mut self) -> Result<u32, AvalancheParserError> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> Result<u64, AvalancheParserError> {
+        Ok(u64::from_be_bytes(self.array()?))
+    }
+
+    fn len(&mut self) -> Result<usize, AvalancheParserError> {
+        let len = self.u32()? as usize;
+        // Every element takes at least one byte, which bounds allocations by the input.
+        if len > self.bytes.len() - self.pos {
+            return Err(AvalancheParserError::UnexpectedEnd);
+        }
+        Ok(len)
+    }
+
+    fn bytes(&mut self) -> Result<Vec<u8>, AvalancheParserError> {
+        let len = self.len()?;
+        Ok(self.take(len)?.to_vec())
+    }
+
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> Result<T, AvalancheParserError>,
+    ) -> Result<Vec<T>, AvalancheParserError> {
+        let len = self.len()?;
+        (0..len).map(|_| item(self)).collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+}
+
+fn unsupported(what: &str, type_id: u32) -> AvalancheParserError {
+    AvalancheParserError::UnsupportedType(format!("{what} type {type_id}"))
+}
+
+impl AvalancheTransaction {
+    /// Decodes an unsigned transaction, or a signed one followed by its credentials.
+    pub fn decode(bytes: &[u8]) -> Result<Self, AvalancheParserError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let version = reader.u16()?;
+        if version != CODEC_VERSION {
+            return Err(AvalancheParserError::UnsupportedType(format!(
+                "codec version {version}"
+            )));
+        }
+
+        let type_id = reader.u32()?;
+        let network_id = reader.u32()?;
+        let blockchain_id: Id = reader.array()?;
+        let vm = if blockchain_id == [0; 32] {
+            Vm::Platform
+        } else {
+            Vm::Avm
+        };
+        let outputs = reader.list(|r| read_output(r, vm))?;
+        let inputs = reader.list(|r| read_input(r, vm))?;
+        let memo = reader.bytes()?;
+
+        let kind = match (vm, type_id) {
+            (Vm::Avm, AVM_BASE_TX) | (Vm::Platform, PVM_BASE_TX) => TxKind::Base,
+            (Vm::Avm, AVM_IMPORT_TX) | (Vm::Platform, PVM_IMPORT_TX) => TxKind::Import {
+                source_chain: reader.array()?,
+                inputs: reader.list(|r| read_input(r, vm))?,
+            },
+            (Vm::Avm, AVM_EXPORT_TX) | (Vm::Platform, PVM_EXPORT_TX) => TxKind::Export {
+                destination_chain: reader.array()?,
+                outputs: reader.list(|r| read_output(r, vm))?,
+            },
+            (Vm::Platform, PVM_ADD_PERMISSIONLESS_VALIDATOR_TX) => {
+                let validator = read_validator(&mut reader)?;
+                let subnet = reader.array()?;
+                let bls_public_key = match reader.u32()? {
+                    PVM_EMPTY_SIGNER => None,
+                    PVM_PROOF_OF_POSSESSION => {
+                        let key = reader.take(BLS_PUBLIC_KEY_LEN)?.to_vec();
+                        reader.take(BLS_SIGNATURE_LEN)?;
+                        Some(key)
+                    }
+                    other => return Err(unsupported("signer", other)),
+                };
+                TxKind::AddPermissionlessValidator {
+                    validator,
+                    subnet,
+                    bls_public_key,
+                    stake: reader.list(|r| read_output(r, vm))?,
+                    validator_rewards_owner: read_rewards_owner(&mut reader)?,
+                    delegator_rewards_owner: read_rewards_owner(&mut reader)?,
+                    delegation_shares: reader.u32()?,
+                }
+            }
+            (Vm::Platform, PVM_ADD_PERMISSIONLESS_DELEGATOR_TX) => {
+                TxKind::AddPermissionlessDelegator {
+                    validator: read_validator(&mut reader)?,
+                    subnet: reader.array()?,
+                    stake: reader.list(|r| read_output(r, vm))?,
+                    rewards_owner: read_rewards_owner(&mut reader)?,
+                }
+            }
+            (_, other) => return Err(unsupported("transaction", other)),
+        };
+
+        let credentials = if reader.is_empty() {
+            None
+        } else {
+            Some(reader.list(read_credential)?.len())
+        };
+        if !reader.is_empty() {
+            return Err(AvalancheParserError::TrailingBytes);
+        }
+
+        Ok(Self {
+            network_id,
+            blockchain_id,
+            vm,
+            outputs,
+            inputs,
+            memo,
+            kind,
+            credentials,
+        })
+    }
+}
+
+fn read_owners(reader: &mut Reader<'_>) -> Result<OutputOwners, AvalancheParserError> {
+    Ok(OutputOwners {
+        locktime: reader.u64()?,
+        threshold: reader.u32()?,
+        addresses: reader.list(|r| r.array())?,
+    })
+}
+
+fn read_output(
+    reader: &mut Reader<'_>,
+    vm: Vm,
+) -> Result<TransferableOutput, AvalancheParserError> {
+    let asset_id = reader.array()?;
+    let mut type_id = reader.u32()?;
+    let mut stakeable_locktime = None;
+    if vm == Vm::Platform && type_id == PVM_STAKEABLE_LOCK_OUT {
+        stakeable_locktime = Some(reader.u64()?);
+        type_id = reader.u32()?;
+    }
+    if type_id != SECP_TRANSFER_OUTPUT {
+        return Err(unsupported("output", type_id));
+    }
+    let amount = reader.u64()?;
+    Ok(TransferableOutput {
+        asset_id,
+        amount,
+        owners: read_owners(reader)?,
+        stakeable_locktime,
+    })
+}
+
+fn read_input(reader: &mut Reader<'_>, vm: Vm) -> Result<TransferableInput, AvalancheParserError> {
+    let tx_id = reader.array()?;
+    let output_index = reader.u32()?;
+    let asset_id = reader.array()?;
+    let mut type_id = reader.u32()?;
+    if vm == Vm::Platform && type_id == PVM_STAKEABLE_LOCK_IN {
+        reader.u64()?;
+        type_id = reader.u32()?;
+    }
+    if type_id != SECP_TRANSFER_INPUT {
+        return Err(unsupported("input", type_id));
+    }
+    let amount = reader.u64()?;
+    reader.list(|r| r.u32())?;
+    Ok(TransferableInput {
+        tx_id,
+        output_index,
+        asset_id,
+        amount,
+    })
+}
+
+fn read_validator(reader: &mut Reader<'_>) -> Result<Validator, AvalancheParserError> {
+    Ok(Validator {
+        node_id: reader.array()?,
+        start: reader.u64()?,
+        end: reader.u64()?,
+        weight: reader.u64()?,
+    })
+}
+
+fn read_rewards_owner(reader: &mut Reader<'_>) -> Result<OutputOwners, AvalancheParserError> {
+    match reader.u32()? {
+        PVM_OUTPUT_OWNERS => read_owners(reader),
+        other => Err(unsupported("rewards owner", other)),
+    }
+}
+
+fn read_credential(reader: &mut Reader<'_>) -> Result<(), AvalancheParserError> {
+    match reader.u32()? {
+        SECP_CREDENTIAL => {
+            reader.list(|r| r.take(SIGNATURE_LEN).map(|_| ()))?;
+            Ok(())
+        }
+        other => Err(unsupported("credential", other)),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod builder {
+    //! Encodes transactions in the linear codec, for constructing test inputs.
+
+    use super::*;
+
+    #[derive(Default)]
+    pub struct Writer(pub Vec<u8>);
+
+    impl Writer {
+        pub fn u16(mut self, v: u16) -> Self {
+            self.0.extend_from_slice(&v.to_be_bytes());
+            self
+        }
+
+        pub fn u32(mut self, v: u32) -> Self {
+            self.0.extend_from_slice(&v.to_be_bytes());
+            self
+        }
+
+        pub fn u64(mut self, v: u64) -> Self {
+            self.0.extend_from_slice(&v.to_be_bytes());
+            self
+        }
+
+        pub fn raw(mut self, bytes: &[u8]) -> Self {
+            self.0.extend_from_slice(bytes);
+            self
+        }
+
+        /// A secp256k1 transfer output owned by a single address.
+        pub fn output(self, asset_id: Id, amount: u64, owner: ShortId) -> Self {
+            self.raw(&asset_id)
+                .u32(SECP_TRANSFER_OUTPUT)
+                .u64(amount)
+                .owners(owner)
+        }
+
+        pub fn owners(self, owner: ShortId) -> Self {
+            self.u64(0).u32(1).u32(1).raw(&owner)
+        }
+
+        pub fn input(self, asset_id: Id, amount: u64) -> Self {
+            self.raw(&[0xaa; 32])
+                .u32(0)
+                .raw(&asset_id)
+                .u32(SECP_TRANSFER_INPUT)
+                .u64(amount)
+                .u32(1)
+                .u32(0)
+        }
+
+        /// Codec version, type ID and the `BaseTx` fields with one input and one output.
+        pub fn base(
+            type_id: u32,
+            network_id: u32,
+            blockchain_id: Id,
+            asset_id: Id,
+            input: u64,
+            output: Option<(u64, ShortId)>,
+        ) -> Self {
+            let writer = Self::default()
+                .u16(CODEC_VERSION)
+                .u32(type_id)
+                .u32(network_id)
+                .raw(&blockchain_id);
+            let writer = match output {
+                Some((amount, owner)) => writer.u32(1).output(asset_id, amount, owner),
+                None => writer.u32(0),
+            };
+            writer.u32(1).input(asset_id, input).u32(0)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::builder::Writer;
+    use super::*;
+
+    #[test]
+    fn decodes_signed_base_tx_and_rejects_trailing_bytes() {
+        let unsigned = Writer::base(PVM_BASE_TX, 1, [0; 32], [1; 32], 10, Some((9, [2; 20])));
+        let signed = Writer(unsigned.0.clone())
+            .u32(1)
+            .u32(SECP_CREDENTIAL)
+            .u32(1)
+            .raw(&[0; SIGNATURE_LEN]);
+
+        let tx = AvalancheTransaction::decode(&unsigned.0).unwrap();
+        assert_eq!(tx.vm, Vm::Platform);
+        assert_eq!(tx.kind, TxKind::Base);
+        assert_eq!(tx.outputs[0].amount, 9);
+        assert_eq!(tx.outputs[0].owners.addresses, vec![[2; 20]]);
+        assert_eq!(tx.inputs[0].amount, 10);
+        assert_eq!(tx.credentials, None);
+
+        let tx = AvalancheTransaction::decode(&signed.0).unwrap();
+        assert_eq!(tx.credentials, Some(1));
+
+        let mut trailing = signed.0;
+        trailing.push(0);
+        assert_eq!(
+            AvalancheTransaction::decode(&trailing),
+            Err(AvalancheParserError::TrailingBytes)
+        );
+    }
+
+    #[test]
+    fn type_ids_depend_on_the_chain() {
+        // 0 is BaseTx on the X-chain but a block type on the P-chain.
+        let x = Writer::base(AVM_BASE_TX, 1, [7; 32], [1; 32], 1, None);
+        assert_eq!(AvalancheTransaction::decode(&x.0).unwrap().vm, Vm::Avm);
+        let p = Writer::base(AVM_BASE_TX, 1, [0; 32], [1; 32], 1, None);
+        assert_eq!(
+            AvalancheTransaction::decode(&p.0),
+            Err(AvalancheParserError::UnsupportedType(
+                "transaction type 0".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_input_and_oversized_lengths() {
+        let tx = Writer::base(PVM_BASE_TX, 1, [0; 32], [1; 32], 10, None);
+        let truncated = &tx.0[..tx.0.len() - 1];
+        assert_eq!(
+            AvalancheTransaction::decode(truncated),
+            Err(AvalancheParserError::UnexpectedEnd)
+        );
+        let huge = Writer::default()
+            .u16(0)
+            .u32(PVM_BASE_TX)
+            .u32(1)
+            .raw(&[0; 32])
+            .u32(u32::MAX);
+        assert_eq!(
+            AvalancheTransaction::decode(&huge.0),
+            Err(AvalancheParserError::UnexpectedEnd)
+        );
+    }
+}
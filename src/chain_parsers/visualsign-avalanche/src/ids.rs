@@ -0,0 +1,159 @@
+// Avalanche's string forms for IDs: CB58 (base58 with a 4-byte SHA-256 checksum) for
+// chain, asset and transaction IDs, `NodeID-` plus CB58 for validators, and
+// `<chain>-<hrp>1…` bech32 for addresses.
+
+use bech32::{Bech32, Hrp};
+use sha2::{Digest, Sha256};
+
+use crate::codec::{Id, ShortId};
+
+pub const MAINNET_ID: u32 = 1;
+pub const FUJI_ID: u32 = 5;
+pub const LOCAL_ID: u32 = 12345;
+
+struct KnownChains {
+    x_chain: &'static str,
+    c_chain: &'static str,
+    avax_asset: &'static str,
+}
+
+const MAINNET: KnownChains = KnownChains {
+    x_chain: "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM",
+    c_chain: "2q9e4r6Mu3U68nU1fYjgbR6JvwrRx36CohpAX5UQxse55x1Q5",
+    avax_asset: "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z",
+};
+
+const FUJI: KnownChains = KnownChains {
+    x_chain: "2JVSBoinj9C2J33VntvzYtVJNZdN2NKiwwKjcumHUWEb5DbBrm",
+    c_chain: "yH8D7ThNJkxmtkuv2jgBa4P1Rn3Qpr4pPr7QYNfcdoS6k6HWp",
+    avax_asset: "U8iRqJoiJm8xZHAacmvYyZVwqQx6uDNtQeP3CQ6fcgQk3JqnK",
+};
+
+fn checksum(bytes: &[u8]) -> [u8; 4] {
+    let digest = Sha256::digest(bytes);
+    let mut out = [0u8; 4];
+    out.copy_from_slice(&digest[28..]);
+    out
+}
+
+pub fn cb58(bytes: &[u8]) -> String {
+    let mut data = bytes.to_vec();
+    data.extend_from_slice(&checksum(bytes));
+    bs58::encode(data).into_string()
+}
+
+#[cfg(test)]
+pub fn decode_cb58(s: &str) -> Option<Vec<u8>> {
+    let data = bs58::decode(s).into_vec().ok()?;
+    let (body, sum) = data.split_at(data.len().checked_sub(4)?);
+    (checksum(body) == sum).then(|| body.to_vec())
+}
+
+pub fn node_id(id: &ShortId) -> String {
+    format!("NodeID-{}", cb58(id))
+}
+
+pub fn network_name(network_id: u32) -> String {
+    match network_id {
+        MAINNET_ID => "Avalanche Mainnet".to_string(),
+        FUJI_ID => "Fuji Testnet".to_string(),
+        LOCAL_ID => "Local Network".to_string(),
+        other => format!("Network {other}"),
+    }
+}
+
+fn hrp(network_id: u32) -> &'static str {
+    match network_id {
+        MAINNET_ID => "avax",
+        FUJI_ID => "fuji",
+        LOCAL_ID => "local",
+        _ => "custom",
+    }
+}
+
+fn known_chains(network_id: u32) -> Option<&'static KnownChains> {
+    match network_id {
+        MAINNET_ID => Some(&MAINNET),
+        FUJI_ID => Some(&FUJI),
+        _ => None,
+    }
+}
+
+/// The `P`/`X`/`C` alias of a blockchain ID, when it is one of the primary network's.
+pub fn chain_alias(network_id: u32, chain: &Id) -> Option<&'static str> {
+    if *chain == [0; 32] {
+        return Some("P");
+    }
+    let known = known_chains(network_id)?;
+    let id = cb58(chain);
+    if id == known.x_chain {
+        Some("X")
+    } else if id == known.c_chain {
+        Some("C")
+    } else {
+        None
+    }
+}
+
+pub fn chain_name(network_id: u32, chain: &Id) -> String {
+    match chain_alias(network_id, chain) {
+        Some(alias) => format!("{alias}-Chain"),
+        None => cb58(chain),
+    }
+}
+
+pub fn is_known_network(network_id: u32) -> bool {
+    known_chains(network_id).is_some()
+}
+
+pub fn is_avax(network_id: u32, asset: &Id) -> bool {
+    known_chains(network_id).is_some_and(|known| cb58(asset) == known.avax_asset)
+}
+
+/// Formats an address as wallets show it, prefixed with the alias of the chain that holds
+/// it (`P-avax1…`). Addresses on other chains get no prefix.
+pub fn address(network_id: u32, chain: &Id, short_id: &ShortId) -> String {
+    let encoded = Hrp::parse(hrp(network_id))
+        .ok()
+        .and_then(|hrp| bech32::encode::<Bech32>(hrp, short_id).ok())
+        .unwrap_or_else(|| hex::encode(short_id));
+    match chain_alias(network_id, chain) {
+        Some(alias) => format!("{alias}-{encoded}"),
+        None => encoded,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cb58_matches_known_ids() {
+        assert_eq!(cb58(&[0; 32]), "11111111111111111111111111111111LpoYY");
+        assert_eq!(node_id(&[0; 20]), "NodeID-111111111111111111116DBWJs");
+        for known in [&MAINNET, &FUJI] {
+            for id in [known.x_chain, known.c_chain, known.avax_asset] {
+                assert_eq!(cb58(&decode_cb58(id).unwrap()), id);
+            }
+        }
+        assert_eq!(decode_cb58("11111111111111111111111111111111LpoYZ"), None);
+    }
+
+    #[test]
+    fn addresses_carry_chain_alias_and_network_hrp() {
+        let x_chain: Id = decode_cb58(MAINNET.x_chain).unwrap().try_into().unwrap();
+        let p = address(MAINNET_ID, &[0; 32], &[1; 20]);
+        assert!(p.starts_with("P-avax1"));
+        assert_eq!(
+            address(MAINNET_ID, &x_chain, &[1; 20]),
+            p.replacen("P-", "X-", 1)
+        );
+        assert!(address(FUJI_ID, &[0; 32], &[1; 20]).starts_with("P-fuji1"));
+        assert!(address(MAINNET_ID, &[9; 32], &[1; 20]).starts_with("avax1"));
+
+        let (hrp, data) = bech32::decode(p.trim_start_matches("P-")).unwrap();
+        assert_eq!(hrp.as_str(), "avax");
+        assert_eq!(data, vec![1; 20]);
+    }
+}
@@ -0,0 +1,750 @@
+#[cfg(feature = "cli-plugin")]
+pub mod cli_plugin;
+
+#[cfg(feature = "cli-plugin")]
+pub use cli_plugin::{AvalancheArgs, AvalanchePlugin};
+
+mod codec;
+mod ids;
+
+pub use codec::{
+    AvalancheTransaction, Id, OutputOwners, ShortId, TransferableInput, TransferableOutput, TxKind,
+    Validator, Vm,
+};
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
+use visualsign::field_builders::{
+    create_address_field, create_amount_field, create_preview_layout, create_text_field,
+};
+use visualsign::time_fmt::{format_duration_secs, format_timestamp_ms};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayload,
+    encodings::SupportedEncodings,
+    vsptrait::{
+        ConversionResult, Transaction, TransactionParseError, VisualSignConverter,
+        VisualSignConverterFromString, VisualSignError, VisualSignOptions,
+    },
+};
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum AvalancheParserError {
+    #[error("Failed to decode transaction: {0}")]
+    FailedToDecodeTransaction(String),
+    #[error("Transaction ended unexpectedly")]
+    UnexpectedEnd,
+    #[error("Trailing bytes after transaction")]
+    TrailingBytes,
+    #[error("Unsupported {0}")]
+    UnsupportedType(String),
+}
+
+const AVAX_DECIMALS: u32 = 9;
+// Delegation fees are expressed in millionths; 10_000 is 1%.
+const PERCENT_DECIMALS: u32 = 4;
+
+fn decode_transaction(
+    raw_transaction: &str,
+    encodings: SupportedEncodings,
+) -> Result<AvalancheTransaction, AvalancheParserError> {
+    let bytes = match encodings {
+        SupportedEncodings::Hex => {
+            visualsign::encodings::decode_hex(raw_transaction).map_err(|e| {
+                AvalancheParserError::FailedToDecodeTransaction(format!(
+                    "Failed to decode hex: {e}"
+                ))
+            })?
+        }
+        SupportedEncodings::Base64 => b64.decode(raw_transaction).map_err(|e| {
+            AvalancheParserError::FailedToDecodeTransaction(format!("Failed to decode base64: {e}"))
+        })?,
+    };
+
+    AvalancheTransaction::decode(&bytes)
+}
+
+/// Wrapper for P-chain and X-chain transactions
+#[derive(Debug, Clone)]
+pub struct AvalancheTransactionWrapper {
+    transaction: AvalancheTransaction,
+}
+
+impl Transaction for AvalancheTransactionWrapper {
+    fn from_string(data: &str) -> Result<Self, TransactionParseError> {
+        // avalanchego's APIs return transactions as 0x-prefixed hex.
+        let format = SupportedEncodings::detect(data);
+        let transaction = decode_transaction(data, format)
+            .map_err(|e| TransactionParseError::DecodeError(e.to_string()))?;
+        Ok(Self { transaction })
+    }
+
+    fn transaction_type(&self) -> String {
+        "Avalanche".to_string()
+    }
+}
+
+impl AvalancheTransactionWrapper {
+    pub fn new(transaction: AvalancheTransaction) -> Self {
+        Self { transaction }
+    }
+
+    pub fn inner(&self) -> &AvalancheTransaction {
+        &self.transaction
+    }
+}
+
+/// Converter for Avalanche P-chain and X-chain transactions
+pub struct AvalancheVisualSignConverter;
+
+impl VisualSignConverter<AvalancheTransactionWrapper> for AvalancheVisualSignConverter {
+    fn to_visual_sign_payload(
+        &self,
+        transaction_wrapper: AvalancheTransactionWrapper,
+        options: VisualSignOptions,
+    ) -> Result<ConversionResult, VisualSignError> {
+        let payload = convert_to_visual_sign_payload(transaction_wrapper.inner(), options)?;
+        Ok(ConversionResult::new(payload))
+    }
+}
+
+impl VisualSignConverterFromString<AvalancheTransactionWrapper> for AvalancheVisualSignConverter {}
+
+// Public API functions
+pub fn transaction_to_visual_sign(
+    transaction: AvalancheTransaction,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let wrapper = AvalancheTransactionWrapper::new(transaction);
+    let converter = AvalancheVisualSignConverter;
+    converter
+        .to_visual_sign_payload(wrapper, options)
+        .map(|r| r.payload)
+}
+
+pub fn transaction_string_to_visual_sign(
+    transaction_data: &str,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let converter = AvalancheVisualSignConverter;
+    converter
+        .to_visual_sign_payload_from_string(transaction_data, options)
+        .map(|r| r.payload)
+}
+
+fn kind_label(kind: &TxKind) -> &'static str {
+    match kind {
+        TxKind::Base => "Base Transaction",
+        TxKind::Import { .. } => "Import",
+        TxKind::Export { .. } => "Export",
+        TxKind::AddPermissionlessValidator { .. } => "Add Permissionless Validator",
+        TxKind::AddPermissionlessDelegator { .. } => "Add Permissionless Delegator",
+    }
+}
+
+// Formatting context: the network decides the address HRP and which asset is AVAX.
+struct Display<'a> {
+    tx: &'a AvalancheTransaction,
+}
+
+impl Display<'_> {
+    /// An amount with its unit: AVAX when the asset is AVAX, raw units otherwise.
+    fn amount(&self, asset: &Id, amount: u64) -> (String, Option<&'static str>) {
+        if ids::is_avax(self.tx.network_id, asset) {
+            (
+                format_units(u128::from(amount), AVAX_DECIMALS),
+                Some("AVAX"),
+            )
+        } else {
+            (
+                format!("{amount} (raw units, asset {})", ids::cb58(asset)),
+                None,
+            )
+        }
+    }
+
+    fn amount_field(
+        &self,
+        label: &str,
+        asset: &Id,
+        amount: u64,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        match self.amount(asset, amount) {
+            (value, Some(unit)) => create_amount_field(label, &value, unit),
+            (value, None) => create_text_field(label, &value),
+        }
+    }
+
+    fn avax_field(
+        &self,
+        label: &str,
+        amount: u64,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        create_amount_field(
+            label,
+            &format_units(u128::from(amount), AVAX_DECIMALS),
+            "AVAX",
+        )
+    }
+
+    /// The owner of an output on `chain`: one address, or "k of n: …" for multisig.
+    fn owners(&self, chain: &Id, owners: &OutputOwners) -> String {
+        let addresses: Vec<String> = owners
+            .addresses
+            .iter()
+            .map(|a| ids::address(self.tx.network_id, chain, a))
+            .collect();
+        match addresses.as_slice() {
+            [single] if owners.threshold <= 1 => single.clone(),
+            _ => format!(
+                "{} of {}: {}",
+                owners.threshold,
+                addresses.len(),
+                addresses.join(", ")
+            ),
+        }
+    }
+
+    fn owners_field(
+        &self,
+        label: &str,
+        owners: &OutputOwners,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        let chain = self.tx.blockchain_id;
+        match owners.addresses.as_slice() {
+            [address] if owners.threshold <= 1 => create_address_field(
+                label,
+                &ids::address(self.tx.network_id, &chain, address),
+                None,
+                None,
+                None,
+                None,
+            ),
+            _ => create_text_field(label, &self.owners(&chain, owners)),
+        }
+    }
+
+    fn output_preview(
+        &self,
+        title: &str,
+        verb: &str,
+        chain: &Id,
+        output: &TransferableOutput,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        let to = self.owners(chain, &output.owners);
+        let (amount, unit) = self.amount(&output.asset_id, output.amount);
+        let mut fields = vec![
+            create_text_field("To", &to)?,
+            self.amount_field("Amount", &output.asset_id, output.amount)?,
+        ];
+        if output.owners.locktime > 0 {
+            fields.push(create_text_field(
+                "Locked Until",
+                &timestamp(output.owners.locktime),
+            )?);
+        }
+        if let Some(locktime) = output.stakeable_locktime {
+            fields.push(create_text_field(
+                "Stakeable Until",
+                &format!("{} (can only be staked before then)", timestamp(locktime)),
+            )?);
+        }
+        let amount = match unit {
+            Some(unit) => format!("{amount} {unit}"),
+            None => amount,
+        };
+        Ok(create_preview_layout(
+            title,
+            format!("{verb} {amount} to {to}"),
+            fields,
+        ))
+    }
+
+    fn validator_fields(
+        &self,
+        validator: &Validator,
+        subnet: &Id,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let primary = *subnet == [0; 32];
+        Ok(vec![
+            create_text_field("Node ID", &ids::node_id(&validator.node_id))?,
+            create_text_field(
+                "Subnet",
+                &if primary {
+                    "Primary Network".to_string()
+                } else {
+                    ids::cb58(subnet)
+                },
+            )?,
+            create_text_field("Start Time", &timestamp(validator.start))?,
+            create_text_field("End Time", &timestamp(validator.end))?,
+            create_text_field(
+                "Staking Duration",
+                &format_duration_secs(validator.end.saturating_sub(validator.start)),
+            )?,
+            // Primary network weight is the AVAX staked; subnets define their own units.
+            if primary {
+                self.avax_field("Stake Amount", validator.weight)?
+            } else {
+                create_text_field("Weight", &validator.weight.to_string())?
+            },
+        ])
+    }
+}
+
+fn timestamp(secs: u64) -> String {
+    i64::try_from(secs)
+        .ok()
+        .and_then(|s| s.checked_mul(1000))
+        .map_or_else(|| "invalid timestamp".to_string(), format_timestamp_ms)
+}
+
+fn convert_to_visual_sign_payload(
+    tx: &AvalancheTransaction,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let display = Display { tx };
+    let mut fields: Vec<AnnotatedPayloadField> = vec![
+        create_text_field("Network", &ids::network_name(tx.network_id))?,
+        create_text_field("Chain", &ids::chain_name(tx.network_id, &tx.blockchain_id))?,
+        create_text_field("Transaction Type", kind_label(&tx.kind))?,
+    ];
+
+    // Everything the transaction consumes and produces, to work out the AVAX burned as fee.
+    let mut consumed: Vec<&TransferableInput> = tx.inputs.iter().collect();
+    let mut produced: Vec<&TransferableOutput> = tx.outputs.iter().collect();
+
+    match &tx.kind {
+        TxKind::Base => {}
+        TxKind::Import {
+            source_chain,
+            inputs,
+        } => {
+            fields.push(create_text_field(
+                "Source Chain",
+                &ids::chain_name(tx.network_id, source_chain),
+            )?);
+            let imported: u128 = inputs
+                .iter()
+                .filter(|i| ids::is_avax(tx.network_id, &i.asset_id))
+                .map(|i| u128::from(i.amount))
+                .sum();
+            fields.push(create_amount_field(
+                "Imported Amount",
+                &format_units(imported, AVAX_DECIMALS),
+                "AVAX",
+            )?);
+            consumed.extend(inputs);
+        }
+        TxKind::Export {
+            destination_chain,
+            outputs,
+        } => {
+            fields.push(create_text_field(
+                "Destination Chain",
+                &ids::chain_name(tx.network_id, destination_chain),
+            )?);
+            for (i, output) in outputs.iter().enumerate() {
+                fields.push(display.output_preview(
+                    &format!("Export {}", i + 1),
+                    "Export",
+                    destination_chain,
+                    output,
+                )?);
+            }
+            produced.extend(outputs);
+        }
+        TxKind::AddPermissionlessValidator {
+            validator,
+            subnet,
+            bls_public_key,
+            stake,
+            validator_rewards_owner,
+            delegator_rewards_owner,
+            delegation_shares,
+        } => {
+            fields.extend(display.validator_fields(validator, subnet)?);
+            fields.push(create_text_field(
+                "Delegation Fee",
+                &format!(
+                    "{}%",
+                    format_units(u128::from(*delegation_shares), PERCENT_DECIMALS)
+                ),
+            )?);
+            if let Some(key) = bls_public_key {
+                fields.push(create_text_field(
+                    "BLS Public Key",
+                    &format!("0x{}", hex::encode(key)),
+                )?);
+            }
+            fields.push(display.owners_field("Validator Rewards Owner", validator_rewards_owner)?);
+            fields.push(display.owners_field("Delegator Rewards Owner", delegator_rewards_owner)?);
+            produced.extend(stake);
+        }
+        TxKind::AddPermissionlessDelegator {
+            validator,
+            subnet,
+            stake,
+            rewards_owner,
+        } => {
+            fields.extend(display.validator_fields(validator, subnet)?);
+            fields.push(display.owners_field("Rewards Owner", rewards_owner)?);
+            produced.extend(stake);
+        }
+    }
+
+    if !tx.memo.is_empty() {
+        let memo = String::from_utf8(tx.memo.clone())
+            .unwrap_or_else(|_| format!("0x{}", hex::encode(&tx.memo)));
+        fields.push(create_text_field("Memo", &memo)?);
+    }
+
+    let avax_in: u128 = consumed
+        .iter()
+        .filter(|i| ids::is_avax(tx.network_id, &i.asset_id))
+        .map(|i| u128::from(i.amount))
+        .sum();
+    let avax_out: u128 = produced
+        .iter()
+        .filter(|o| ids::is_avax(tx.network_id, &o.asset_id))
+        .map(|o| u128::from(o.amount))
+        .sum();
+    // Only meaningful on networks where the AVAX asset ID is known.
+    if ids::is_known_network(tx.network_id) && avax_in >= avax_out {
+        fields.push(create_amount_field(
+            "Fee",
+            &format_units(avax_in - avax_out, AVAX_DECIMALS),
+            "AVAX",
+        )?);
+    }
+
+    for (i, output) in tx.outputs.iter().enumerate() {
+        fields.push(display.output_preview(
+            &format!("Output {}", i + 1),
+            "Send",
+            &tx.blockchain_id,
+            output,
+        )?);
+    }
+
+    let title = options
+        .transaction_name
+        .unwrap_or_else(|| "Avalanche Transaction".to_string());
+
+    Ok(SignablePayload::new(
+        0,
+        title,
+        None,
+        fields
+            .into_iter()
+            .map(|af| af.signable_payload_field)
+            .collect(),
+        "AvalancheTx".to_string(),
+    ))
+}
+
+// Base units to a decimal string with integer math, trimming trailing zeros so e.g.
+// 1_500_000_000 nAVAX -> "1.5".
+fn format_units(value: u128, decimals: u32) -> String {
+    let digits = value.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return digits;
+    }
+    let padded = format!("{digits:0>width$}", width = decimals + 1);
+    let (whole, frac) = padded.split_at(padded.len() - decimals);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{frac}")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::codec::builder::Writer;
+    use visualsign::{SignablePayloadField, SignablePayloadFieldListLayout};
+
+    const MAINNET_AVAX: &str = "FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z";
+    const FUJI_AVAX: &str = "U8iRqJoiJm8xZHAacmvYyZVwqQx6uDNtQeP3CQ6fcgQk3JqnK";
+    const MAINNET_X: &str = "2oYMBNV4eNHyqk2fjjV5nVQLDbtmNJzq5s3qs3Lo6ftnC6FByM";
+    const MAINNET_C: &str = "2q9e4r6Mu3U68nU1fYjgbR6JvwrRx36CohpAX5UQxse55x1Q5";
+    const P_CHAIN: Id = [0; 32];
+    const START: u64 = 1_700_000_000;
+
+    fn id(cb58: &str) -> Id {
+        ids::decode_cb58(cb58).unwrap().try_into().unwrap()
+    }
+
+    fn to_payload(writer: Writer) -> SignablePayload {
+        let data = format!("0x{}", hex::encode(writer.0));
+        transaction_string_to_visual_sign(&data, VisualSignOptions::default()).unwrap()
+    }
+
+    fn find_field<'a>(
+        payload: &'a SignablePayload,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        payload.fields.iter().find(|f| field_label(f) == label)
+    }
+
+    fn find_detail<'a>(
+        layout: &'a SignablePayloadFieldListLayout,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        layout
+            .fields
+            .iter()
+            .map(|f| &f.signable_payload_field)
+            .find(|f| field_label(f) == label)
+    }
+
+    fn field_label(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { common, .. }
+            | SignablePayloadField::AmountV2 { common, .. }
+            | SignablePayloadField::AddressV2 { common, .. }
+            | SignablePayloadField::PreviewLayout { common, .. } => &common.label,
+            _ => "",
+        }
+    }
+
+    fn text(payload: &SignablePayload, label: &str) -> String {
+        match find_field(payload, label) {
+            Some(SignablePayloadField::TextV2 { text_v2, .. }) => text_v2.text.clone(),
+            other => panic!("expected TextV2 for {label}, got {other:?}"),
+        }
+    }
+
+    fn amount_value(field: &SignablePayloadField) -> (&str, &str) {
+        match field {
+            SignablePayloadField::AmountV2 { amount_v2, .. } => (
+                amount_v2.amount.as_str(),
+                amount_v2.abbreviation.as_deref().unwrap_or(""),
+            ),
+            _ => panic!("expected AmountV2"),
+        }
+    }
+
+    fn address_value(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::AddressV2 { address_v2, .. } => address_v2.address.as_str(),
+            _ => panic!("expected AddressV2"),
+        }
+    }
+
+    fn preview_subtitle(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => preview_layout
+                .subtitle
+                .as_ref()
+                .map(|t| t.text.as_str())
+                .unwrap_or(""),
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn preview_expanded(field: &SignablePayloadField) -> &SignablePayloadFieldListLayout {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                preview_layout.expanded.as_ref().unwrap()
+            }
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn validator(writer: Writer, weight: u64) -> Writer {
+        writer
+            .raw(&[3; 20])
+            .u64(START)
+            .u64(START + 14 * 86_400)
+            .u64(weight)
+            .raw(&[0; 32])
+    }
+
+    #[test]
+    fn add_permissionless_validator() {
+        let avax = id(MAINNET_AVAX);
+        let stake = 2_000_000_000_000;
+        let tx = Writer::base(
+            25,
+            ids::MAINNET_ID,
+            P_CHAIN,
+            avax,
+            2_001_000_000_000,
+            Some((999_000_000, [2; 20])),
+        );
+        let tx = validator(tx, stake)
+            .u32(28)
+            .raw(&[0xab; 48])
+            .raw(&[0; 96])
+            .u32(1)
+            .output(avax, stake, [4; 20])
+            .u32(11)
+            .owners([5; 20])
+            .u32(11)
+            .owners([6; 20])
+            .u32(20_000);
+        let payload = to_payload(tx);
+
+        assert_eq!(text(&payload, "Network"), "Avalanche Mainnet");
+        assert_eq!(text(&payload, "Chain"), "P-Chain");
+        assert_eq!(
+            text(&payload, "Transaction Type"),
+            "Add Permissionless Validator"
+        );
+        assert_eq!(text(&payload, "Node ID"), ids::node_id(&[3; 20]));
+        assert_eq!(text(&payload, "Subnet"), "Primary Network");
+        assert_eq!(text(&payload, "Start Time"), "2023-11-14 22:13:20 UTC");
+        assert_eq!(text(&payload, "Staking Duration"), "14 days");
+        assert_eq!(
+            amount_value(find_field(&payload, "Stake Amount").unwrap()),
+            ("2000", "AVAX")
+        );
+        assert_eq!(text(&payload, "Delegation Fee"), "2%");
+        assert_eq!(
+            text(&payload, "BLS Public Key"),
+            format!("0x{}", "ab".repeat(48))
+        );
+        let rewards = address_value(find_field(&payload, "Validator Rewards Owner").unwrap());
+        assert_eq!(rewards, ids::address(ids::MAINNET_ID, &P_CHAIN, &[5; 20]));
+        assert!(rewards.starts_with("P-avax1"));
+        assert_eq!(
+            amount_value(find_field(&payload, "Fee").unwrap()),
+            ("0.001", "AVAX")
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Output 1").unwrap()),
+            format!(
+                "Send 0.999 AVAX to {}",
+                ids::address(ids::MAINNET_ID, &P_CHAIN, &[2; 20])
+            )
+        );
+    }
+
+    #[test]
+    fn add_permissionless_delegator_on_fuji() {
+        let avax = id(FUJI_AVAX);
+        let tx = Writer::base(26, ids::FUJI_ID, P_CHAIN, avax, 25_001_000_000, None);
+        let tx = validator(tx, 25_000_000_000)
+            .u32(1)
+            .output(avax, 25_000_000_000, [4; 20])
+            .u32(11)
+            .owners([5; 20]);
+        let payload = to_payload(tx);
+
+        assert_eq!(text(&payload, "Network"), "Fuji Testnet");
+        assert_eq!(
+            text(&payload, "Transaction Type"),
+            "Add Permissionless Delegator"
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Stake Amount").unwrap()),
+            ("25", "AVAX")
+        );
+        assert!(
+            address_value(find_field(&payload, "Rewards Owner").unwrap()).starts_with("P-fuji1")
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Fee").unwrap()),
+            ("0.001", "AVAX")
+        );
+        assert!(find_field(&payload, "Delegation Fee").is_none());
+    }
+
+    #[test]
+    fn export_from_x_chain_to_c_chain() {
+        let avax = id(MAINNET_AVAX);
+        let (x_chain, c_chain) = (id(MAINNET_X), id(MAINNET_C));
+        let tx = Writer::base(
+            4,
+            ids::MAINNET_ID,
+            x_chain,
+            avax,
+            3_000_000_000,
+            Some((1_499_000_000, [7; 20])),
+        )
+        .raw(&c_chain)
+        .u32(1)
+        .output(avax, 1_500_000_000, [8; 20]);
+        let payload = to_payload(tx);
+
+        assert_eq!(text(&payload, "Chain"), "X-Chain");
+        assert_eq!(text(&payload, "Destination Chain"), "C-Chain");
+        let recipient = ids::address(ids::MAINNET_ID, &c_chain, &[8; 20]);
+        assert!(recipient.starts_with("C-avax1"));
+        let export = find_field(&payload, "Export 1").unwrap();
+        assert_eq!(
+            preview_subtitle(export),
+            format!("Export 1.5 AVAX to {recipient}")
+        );
+        assert_eq!(
+            amount_value(find_detail(preview_expanded(export), "Amount").unwrap()),
+            ("1.5", "AVAX")
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Fee").unwrap()),
+            ("0.001", "AVAX")
+        );
+        assert!(preview_subtitle(find_field(&payload, "Output 1").unwrap()).contains("X-avax1"));
+    }
+
+    #[test]
+    fn import_into_p_chain_and_foreign_assets() {
+        let avax = id(MAINNET_AVAX);
+        let tx = Writer::base(
+            17,
+            ids::MAINNET_ID,
+            P_CHAIN,
+            avax,
+            0,
+            Some((1_999_000_000, [9; 20])),
+        )
+        .raw(&id(MAINNET_X))
+        .u32(1)
+        .input(avax, 2_000_000_000);
+        let payload = to_payload(tx);
+
+        assert_eq!(text(&payload, "Source Chain"), "X-Chain");
+        assert_eq!(
+            amount_value(find_field(&payload, "Imported Amount").unwrap()),
+            ("2", "AVAX")
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Fee").unwrap()),
+            ("0.001", "AVAX")
+        );
+
+        let asset = [0x42; 32];
+        let tx = Writer::base(
+            0,
+            ids::MAINNET_ID,
+            id(MAINNET_X),
+            asset,
+            5,
+            Some((5, [1; 20])),
+        )
+        .0;
+        let payload = to_payload(Writer(tx));
+        let details = preview_expanded(find_field(&payload, "Output 1").unwrap());
+        assert_eq!(
+            match find_detail(details, "Amount").unwrap() {
+                SignablePayloadField::TextV2 { text_v2, .. } => text_v2.text.clone(),
+                _ => panic!("expected TextV2"),
+            },
+            format!("5 (raw units, asset {})", ids::cb58(&asset))
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Fee").unwrap()),
+            ("0", "AVAX")
+        );
+    }
+
+    #[test]
+    fn units_formatting() {
+        assert_eq!(format_units(0, 9), "0");
+        assert_eq!(format_units(1, 9), "0.000000001");
+        assert_eq!(format_units(25_000, 4), "2.5");
+    }
+}
@@ -0,0 +1,68 @@
+use clap::Args as ClapArgs;
+use generated::parser::ChainMetadata;
+use visualsign::registry::{Chain, TransactionConverterRegistry};
+
+/// CLI arguments specific to Bitcoin and the chains sharing its parser.
+///
+/// No Bitcoin-specific args are needed yet; the global `--network` flag is accepted but
+/// not used, since the parser reads mainnet vs testnet from the PSBT's derivation paths.
+#[derive(ClapArgs, Debug, Default, Clone)]
+pub struct BitcoinArgs {}
+
+// Litecoin and Dogecoin take the same (empty) args as Bitcoin, so one macro defines all
+// three plugins; they differ only in the chain and converter they register.
+macro_rules! utxo_plugin {
+    ($plugin:ident, $chain:ident, $wrapper:ident, $converter:ident) => {
+        #[doc = concat!("[`parser_cli_core::ChainPlugin`] implementation for ", stringify!($chain), ".")]
+        pub struct $plugin {
+            // Held for the same reason as `TronPlugin::args`: a future flag lands here
+            // without changing the struct shape.
+            #[allow(dead_code)]
+            args: BitcoinArgs,
+        }
+
+        impl $plugin {
+            #[doc = concat!("Creates a new `", stringify!($plugin), "` with the given CLI args.")]
+            #[must_use]
+            pub fn new(args: BitcoinArgs) -> Self {
+                Self { args }
+            }
+        }
+
+        impl parser_cli_core::ChainPlugin for $plugin {
+            fn chain(&self) -> Chain {
+                Chain::$chain
+            }
+
+            fn register(&self, registry: &mut TransactionConverterRegistry) {
+                registry.register::<crate::$wrapper, _>(Chain::$chain, crate::$converter);
+            }
+
+            fn create_metadata(
+                &self,
+                _network: Option<String>,
+            ) -> Result<Option<ChainMetadata>, String> {
+                Ok(None)
+            }
+        }
+    };
+}
+
+utxo_plugin!(
+    BitcoinPlugin,
+    Bitcoin,
+    BitcoinTransactionWrapper,
+    BitcoinVisualSignConverter
+);
+utxo_plugin!(
+    LitecoinPlugin,
+    Litecoin,
+    LitecoinTransactionWrapper,
+    LitecoinVisualSignConverter
+);
+utxo_plugin!(
+    DogecoinPlugin,
+    Dogecoin,
+    DogecoinTransactionWrapper,
+    DogecoinVisualSignConverter
+);
@@ -0,0 +1,513 @@
+#[cfg(feature = "cli-plugin")]
+pub mod cli_plugin;
+
+#[cfg(feature = "cli-plugin")]
+pub use cli_plugin::{BitcoinArgs, BitcoinPlugin, DogecoinPlugin, LitecoinPlugin};
+
+pub mod utxo;
+
+pub use utxo::{BITCOIN, DOGECOIN, LITECOIN, UtxoChain, UtxoTransaction};
+
+use visualsign::{
+    SignablePayload,
+    encodings::SupportedEncodings,
+    vsptrait::{
+        ConversionResult, Transaction, TransactionParseError, VisualSignConverter,
+        VisualSignConverterFromString, VisualSignError, VisualSignOptions,
+    },
+};
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum BitcoinParserError {
+    #[error("Failed to decode transaction: {0}")]
+    FailedToDecodeTransaction(String),
+}
+
+// Each chain gets its own wrapper and converter so the registry can tell them apart; all
+// of them decode the same way and differ only in the `UtxoChain` they display with.
+macro_rules! utxo_chain {
+    ($chain:expr, $name:literal, $wrapper:ident, $converter:ident) => {
+        #[doc = concat!("Wrapper for ", $name, " PSBTs and raw transactions")]
+        #[derive(Debug, Clone)]
+        pub struct $wrapper {
+            transaction: UtxoTransaction,
+        }
+
+        impl Transaction for $wrapper {
+            fn from_string(data: &str) -> Result<Self, TransactionParseError> {
+                // PSBTs are usually exchanged as base64 and raw transactions as hex; detect()
+                // tells the two apart and the decoder then tells PSBT from raw by the magic.
+                let format = SupportedEncodings::detect(data);
+                let transaction = utxo::decode_transaction(data, format)
+                    .map_err(|e| TransactionParseError::DecodeError(e.to_string()))?;
+                Ok(Self { transaction })
+            }
+
+            fn transaction_type(&self) -> String {
+                $name.to_string()
+            }
+        }
+
+        impl $wrapper {
+            pub fn new(transaction: UtxoTransaction) -> Self {
+                Self { transaction }
+            }
+
+            pub fn inner(&self) -> &UtxoTransaction {
+                &self.transaction
+            }
+        }
+
+        #[doc = concat!("Converter for ", $name, " transactions")]
+        pub struct $converter;
+
+        impl VisualSignConverter<$wrapper> for $converter {
+            fn to_visual_sign_payload(
+                &self,
+                transaction_wrapper: $wrapper,
+                options: VisualSignOptions,
+            ) -> Result<ConversionResult, VisualSignError> {
+                let payload = utxo::convert_to_visual_sign_payload(
+                    transaction_wrapper.inner(),
+                    options,
+                    &$chain,
+                )?;
+                Ok(ConversionResult::new(payload))
+            }
+        }
+
+        impl VisualSignConverterFromString<$wrapper> for $converter {}
+    };
+}
+
+utxo_chain!(
+    BITCOIN,
+    "Bitcoin",
+    BitcoinTransactionWrapper,
+    BitcoinVisualSignConverter
+);
+utxo_chain!(
+    LITECOIN,
+    "Litecoin",
+    LitecoinTransactionWrapper,
+    LitecoinVisualSignConverter
+);
+utxo_chain!(
+    DOGECOIN,
+    "Dogecoin",
+    DogecoinTransactionWrapper,
+    DogecoinVisualSignConverter
+);
+
+// Public API functions
+pub fn transaction_to_visual_sign(
+    transaction: UtxoTransaction,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let wrapper = BitcoinTransactionWrapper::new(transaction);
+    let converter = BitcoinVisualSignConverter;
+    converter
+        .to_visual_sign_payload(wrapper, options)
+        .map(|r| r.payload)
+}
+
+pub fn transaction_string_to_visual_sign(
+    transaction_data: &str,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let converter = BitcoinVisualSignConverter;
+    converter
+        .to_visual_sign_payload_from_string(transaction_data, options)
+        .map(|r| r.payload)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
+    use bitcoin::Transaction as RawTransaction;
+    use bitcoin::absolute::LockTime;
+    use bitcoin::bip32::{DerivationPath, Fingerprint, KeySource};
+    use bitcoin::hashes::Hash;
+    use bitcoin::psbt::Psbt;
+    use bitcoin::secp256k1::PublicKey;
+    use bitcoin::transaction::Version;
+    use bitcoin::{Amount, OutPoint, PubkeyHash, ScriptBuf, TxIn, TxOut, WPubkeyHash};
+    use std::str::FromStr;
+    use visualsign::{SignablePayloadField, SignablePayloadFieldListLayout};
+
+    const WALLET_FINGERPRINT: &str = "d34db33f";
+    // secp256k1 generator point; any valid key works as a bip32_derivation map key.
+    const PUBKEY_HEX: &str = "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    fn p2wpkh(byte: u8) -> ScriptBuf {
+        ScriptBuf::new_p2wpkh(&WPubkeyHash::from_byte_array([byte; 20]))
+    }
+
+    fn txout(sats: u64, script_pubkey: ScriptBuf) -> TxOut {
+        TxOut {
+            value: Amount::from_sat(sats),
+            script_pubkey,
+        }
+    }
+
+    fn previous_tx() -> RawTransaction {
+        RawTransaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn::default()],
+            output: vec![txout(100_000, p2wpkh(0x11))],
+        }
+    }
+
+    fn spending_tx(outputs: Vec<TxOut>) -> RawTransaction {
+        RawTransaction {
+            version: Version::TWO,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint {
+                    txid: previous_tx().compute_txid(),
+                    vout: 0,
+                },
+                ..Default::default()
+            }],
+            output: outputs,
+        }
+    }
+
+    fn key_source(path: &str) -> KeySource {
+        (
+            Fingerprint::from_str(WALLET_FINGERPRINT).unwrap(),
+            DerivationPath::from_str(path).unwrap(),
+        )
+    }
+
+    // 100_000 sats in, 60_000 to a third party and 39_000 back to the wallet's change path.
+    fn psbt_with_change(coin_type: u32) -> Psbt {
+        let mut psbt = Psbt::from_unsigned_tx(spending_tx(vec![
+            txout(60_000, p2wpkh(0x22)),
+            txout(39_000, p2wpkh(0x33)),
+        ]))
+        .unwrap();
+        let pubkey = PublicKey::from_str(PUBKEY_HEX).unwrap();
+        psbt.inputs[0].witness_utxo = Some(txout(100_000, p2wpkh(0x11)));
+        psbt.inputs[0]
+            .bip32_derivation
+            .insert(pubkey, key_source(&format!("m/84'/{coin_type}'/0'/0/0")));
+        psbt.outputs[1]
+            .bip32_derivation
+            .insert(pubkey, key_source(&format!("m/84'/{coin_type}'/0'/1/0")));
+        psbt
+    }
+
+    fn to_payload(data: &str) -> Result<SignablePayload, VisualSignError> {
+        transaction_string_to_visual_sign(data, VisualSignOptions::default())
+    }
+
+    fn find_field<'a>(
+        payload: &'a SignablePayload,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        payload.fields.iter().find(|f| field_label(f) == label)
+    }
+
+    fn find_detail<'a>(
+        layout: &'a SignablePayloadFieldListLayout,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        layout
+            .fields
+            .iter()
+            .map(|f| &f.signable_payload_field)
+            .find(|f| field_label(f) == label)
+    }
+
+    fn field_label(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { common, .. }
+            | SignablePayloadField::AmountV2 { common, .. }
+            | SignablePayloadField::AddressV2 { common, .. }
+            | SignablePayloadField::PreviewLayout { common, .. } => &common.label,
+            _ => "",
+        }
+    }
+
+    fn text_value(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { text_v2, .. } => &text_v2.text,
+            _ => panic!("expected TextV2"),
+        }
+    }
+
+    fn amount_value(field: &SignablePayloadField) -> (&str, &str) {
+        match field {
+            SignablePayloadField::AmountV2 { amount_v2, .. } => (
+                amount_v2.amount.as_str(),
+                amount_v2.abbreviation.as_deref().unwrap_or(""),
+            ),
+            _ => panic!("expected AmountV2"),
+        }
+    }
+
+    fn address_value(field: &SignablePayloadField) -> (&str, Option<&str>) {
+        match field {
+            SignablePayloadField::AddressV2 { address_v2, .. } => (
+                address_v2.address.as_str(),
+                address_v2.badge_text.as_deref(),
+            ),
+            _ => panic!("expected AddressV2"),
+        }
+    }
+
+    fn preview_subtitle(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => preview_layout
+                .subtitle
+                .as_ref()
+                .map(|t| t.text.as_str())
+                .unwrap_or(""),
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn preview_expanded(field: &SignablePayloadField) -> &SignablePayloadFieldListLayout {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => preview_layout
+                .expanded
+                .as_ref()
+                .expect("expanded must be Some"),
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    #[test]
+    fn psbt_shows_prevout_amounts_fee_and_change() {
+        let psbt = psbt_with_change(0);
+        let payload = to_payload(&hex::encode(psbt.serialize())).unwrap();
+
+        assert_eq!(
+            text_value(find_field(&payload, "Network").unwrap()),
+            "Bitcoin"
+        );
+        assert_eq!(text_value(find_field(&payload, "Format").unwrap()), "PSBT");
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Input 1").unwrap()),
+            "0.001 BTC from bc1qzyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3h8ffkz"
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Output 1").unwrap()),
+            "0.0006 BTC to bc1qyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zc6v074"
+        );
+
+        let change = find_field(&payload, "Output 2").unwrap();
+        assert_eq!(
+            preview_subtitle(change),
+            "0.00039 BTC change to bc1qxvenxvenxvenxvenxvenxvenxvenxven2ymjt8"
+        );
+        let details = preview_expanded(change);
+        assert_eq!(
+            address_value(find_detail(details, "To").unwrap()),
+            ("bc1qxvenxvenxvenxvenxvenxvenxvenxven2ymjt8", Some("Change"))
+        );
+        assert_eq!(
+            text_value(find_detail(details, "Derivation Path").unwrap()),
+            "m/84'/0'/0'/1/0"
+        );
+
+        assert_eq!(
+            amount_value(find_field(&payload, "Total Input").unwrap()),
+            ("0.001", "BTC")
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Sending").unwrap()),
+            ("0.0006", "BTC")
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Fee").unwrap()),
+            ("0.00001", "BTC")
+        );
+        assert!(find_field(&payload, "Lock Time").is_none());
+    }
+
+    #[test]
+    fn psbt_accepts_base64_and_reads_testnet_from_coin_type() {
+        let psbt = psbt_with_change(1);
+        let payload = to_payload(&b64.encode(psbt.serialize())).unwrap();
+
+        assert_eq!(
+            text_value(find_field(&payload, "Network").unwrap()),
+            "Bitcoin Testnet"
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Output 1").unwrap()),
+            "0.0006 BTC to tb1qyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3zjuhu9x"
+        );
+    }
+
+    #[test]
+    fn output_to_unknown_wallet_is_not_change() {
+        let mut psbt = psbt_with_change(0);
+        let (_, path) = key_source("m/84'/0'/0'/1/0");
+        for source in psbt.outputs[1].bip32_derivation.values_mut() {
+            *source = (Fingerprint::from_str("00000000").unwrap(), path.clone());
+        }
+        let payload = to_payload(&hex::encode(psbt.serialize())).unwrap();
+
+        assert!(!preview_subtitle(find_field(&payload, "Output 2").unwrap()).contains("change"));
+        assert!(find_field(&payload, "Sending").is_none());
+    }
+
+    #[test]
+    fn non_witness_utxo_must_match_the_spent_txid() {
+        let mut psbt = psbt_with_change(0);
+        let mut wrong = previous_tx();
+        wrong.output[0].value = Amount::from_sat(10_000_000);
+        psbt.inputs[0].non_witness_utxo = Some(wrong);
+
+        let err = to_payload(&hex::encode(psbt.serialize())).unwrap_err();
+        assert!(
+            matches!(&err, VisualSignError::ValidationError(msg) if msg.contains("has txid")),
+            "{err:?}"
+        );
+
+        psbt.inputs[0].non_witness_utxo = Some(previous_tx());
+        let payload = to_payload(&hex::encode(psbt.serialize())).unwrap();
+        assert_eq!(
+            amount_value(find_field(&payload, "Fee").unwrap()),
+            ("0.00001", "BTC")
+        );
+    }
+
+    #[test]
+    fn raw_transaction_has_no_input_amounts() {
+        let mut tx = spending_tx(vec![txout(60_000, p2wpkh(0x22))]);
+        tx.lock_time = LockTime::from_height(850_000).unwrap();
+        let payload = to_payload(&bitcoin::consensus::encode::serialize_hex(&tx)).unwrap();
+
+        assert_eq!(
+            text_value(find_field(&payload, "Format").unwrap()),
+            "Raw Transaction"
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Input 1").unwrap()),
+            format!("Spends {}:0", previous_tx().compute_txid())
+        );
+        assert!(find_field(&payload, "Total Input").is_none());
+        assert_eq!(
+            text_value(find_field(&payload, "Fee").unwrap()),
+            "Unknown: a raw transaction does not include input amounts"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Lock Time").unwrap()),
+            "Block 850000"
+        );
+    }
+
+    #[test]
+    fn op_return_output_shows_data_and_text() {
+        let memo = ScriptBuf::from_bytes(b"\x6a\x05hello".to_vec());
+        let binary = ScriptBuf::from_bytes(vec![0x6a, 0x02, 0x00, 0xff]);
+        let tx = spending_tx(vec![txout(0, memo), txout(0, binary)]);
+        let payload = to_payload(&bitcoin::consensus::encode::serialize_hex(&tx)).unwrap();
+
+        let memo = find_field(&payload, "Output 1").unwrap();
+        assert_eq!(preview_subtitle(memo), "OP_RETURN \"hello\"");
+        assert_eq!(
+            text_value(find_detail(preview_expanded(memo), "Data").unwrap()),
+            "68656c6c6f"
+        );
+
+        let binary = find_field(&payload, "Output 2").unwrap();
+        assert_eq!(preview_subtitle(binary), "OP_RETURN with 2 bytes of data");
+        assert!(find_detail(preview_expanded(binary), "Text").is_none());
+    }
+
+    #[test]
+    fn outputs_exceeding_inputs_are_rejected() {
+        let mut psbt = psbt_with_change(0);
+        psbt.inputs[0].witness_utxo = Some(txout(1_000, p2wpkh(0x11)));
+        let err = to_payload(&hex::encode(psbt.serialize())).unwrap_err();
+        assert!(
+            matches!(err, VisualSignError::ValidationError(_)),
+            "{err:?}"
+        );
+    }
+
+    #[test]
+    fn invalid_input_is_a_decode_error() {
+        assert!(BitcoinTransactionWrapper::from_string("70736274ff00").is_err());
+        assert!(BitcoinTransactionWrapper::from_string("not a transaction!").is_err());
+    }
+
+    #[test]
+    fn litecoin_psbt_uses_litecoin_addresses_and_unit() {
+        let psbt = psbt_with_change(2);
+        let payload = LitecoinVisualSignConverter
+            .to_visual_sign_payload_from_string(
+                &b64.encode(psbt.serialize()),
+                VisualSignOptions::default(),
+            )
+            .unwrap()
+            .payload;
+
+        assert_eq!(payload.title, "Litecoin Transaction");
+        assert_eq!(
+            text_value(find_field(&payload, "Network").unwrap()),
+            "Litecoin"
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Output 2").unwrap()),
+            "0.00039 LTC change to ltc1qxvenxvenxvenxvenxvenxvenxvenxvenwcpknh"
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Fee").unwrap()),
+            ("0.00001", "LTC")
+        );
+
+        let testnet = LitecoinVisualSignConverter
+            .to_visual_sign_payload_from_string(
+                &hex::encode(psbt_with_change(1).serialize()),
+                VisualSignOptions::default(),
+            )
+            .unwrap()
+            .payload;
+        assert_eq!(
+            preview_subtitle(find_field(&testnet, "Input 1").unwrap()),
+            "0.001 LTC from tltc1qzyg3zyg3zyg3zyg3zyg3zyg3zyg3zyg3yfsyac"
+        );
+    }
+
+    #[test]
+    fn dogecoin_has_base58_addresses_only() {
+        let p2pkh = ScriptBuf::new_p2pkh(&PubkeyHash::from_byte_array([0x22; 20]));
+        let tx = spending_tx(vec![
+            txout(500_000_000_000, p2pkh),
+            txout(100_000_000, p2wpkh(0x33)),
+        ]);
+        let payload = DogecoinVisualSignConverter
+            .to_visual_sign_payload_from_string(
+                &bitcoin::consensus::encode::serialize_hex(&tx),
+                VisualSignOptions::default(),
+            )
+            .unwrap()
+            .payload;
+
+        assert_eq!(
+            text_value(find_field(&payload, "Network").unwrap()),
+            "Dogecoin"
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Output 1").unwrap()),
+            "5000 DOGE to D8FaQQWt8SJCiCGYYBAsUn8qhXxthiYBro"
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Output 2").unwrap()),
+            "1 DOGE to a non-standard script"
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Total Output").unwrap()),
+            ("5001", "DOGE")
+        );
+    }
+}
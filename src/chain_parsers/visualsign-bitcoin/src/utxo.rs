@@ -0,0 +1,635 @@
+//! Decoding and display shared by Bitcoin and the chains that kept its transaction format.
+//!
+//! Litecoin and Dogecoin serialize transactions and PSBTs exactly like Bitcoin; what differs
+//! is how an output script is written as an address and the ticker amounts are shown in.
+//! Those differences live in a [`UtxoChain`], and everything else is parsed once here.
+
+use std::collections::BTreeSet;
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
+use bitcoin::Transaction as RawTransaction;
+use bitcoin::absolute::LockTime;
+use bitcoin::bech32::{Hrp, segwit};
+use bitcoin::bip32::{ChildNumber, DerivationPath, Fingerprint, KeySource};
+use bitcoin::psbt::{self, Psbt};
+use bitcoin::script::Instruction;
+use bitcoin::{OutPoint, Script, TxOut};
+use visualsign::field_builders::{
+    create_address_field, create_amount_field, create_preview_layout, create_text_field,
+};
+use visualsign::time_fmt::format_timestamp_ms;
+use visualsign::{
+    AnnotatedPayloadField, SignablePayload, encodings::SupportedEncodings,
+    vsptrait::VisualSignError, vsptrait::VisualSignOptions,
+};
+
+use crate::BitcoinParserError;
+
+// Every serialized PSBT starts with these five bytes (BIP-174); a consensus-encoded
+// transaction starts with its 4-byte version instead, so the two never collide.
+const PSBT_MAGIC: &[u8] = b"psbt\xff";
+
+// Bitcoin, Litecoin and Dogecoin all count in units of 10^-8 of a coin.
+const BASE_UNITS_PER_COIN: u64 = 100_000_000;
+
+/// How a chain writes output scripts as addresses on one network.
+#[derive(Debug, Clone, Copy)]
+pub struct AddressParams {
+    /// Base58Check version byte of pay-to-pubkey-hash addresses
+    pub p2pkh_prefix: u8,
+    /// Base58Check version byte of pay-to-script-hash addresses
+    pub p2sh_prefix: u8,
+    /// Human-readable part of segwit addresses, or `None` on chains without segwit
+    pub bech32_hrp: Option<&'static str>,
+}
+
+/// A Bitcoin-derived chain: its name, ticker, and address parameters per network.
+#[derive(Debug, Clone, Copy)]
+pub struct UtxoChain {
+    pub name: &'static str,
+    pub unit: &'static str,
+    pub mainnet: AddressParams,
+    pub testnet: AddressParams,
+}
+
+pub const BITCOIN: UtxoChain = UtxoChain {
+    name: "Bitcoin",
+    unit: "BTC",
+    mainnet: AddressParams {
+        p2pkh_prefix: 0x00,
+        p2sh_prefix: 0x05,
+        bech32_hrp: Some("bc"),
+    },
+    testnet: AddressParams {
+        p2pkh_prefix: 0x6f,
+        p2sh_prefix: 0xc4,
+        bech32_hrp: Some("tb"),
+    },
+};
+
+pub const LITECOIN: UtxoChain = UtxoChain {
+    name: "Litecoin",
+    unit: "LTC",
+    mainnet: AddressParams {
+        p2pkh_prefix: 0x30,
+        p2sh_prefix: 0x32,
+        bech32_hrp: Some("ltc"),
+    },
+    testnet: AddressParams {
+        p2pkh_prefix: 0x6f,
+        p2sh_prefix: 0x3a,
+        bech32_hrp: Some("tltc"),
+    },
+};
+
+pub const DOGECOIN: UtxoChain = UtxoChain {
+    name: "Dogecoin",
+    unit: "DOGE",
+    mainnet: AddressParams {
+        p2pkh_prefix: 0x1e,
+        p2sh_prefix: 0x16,
+        bech32_hrp: None,
+    },
+    testnet: AddressParams {
+        p2pkh_prefix: 0x71,
+        p2sh_prefix: 0xc4,
+        bech32_hrp: None,
+    },
+};
+
+impl AddressParams {
+    /// The address `script` pays to, or `None` for scripts with no address form here.
+    fn address(&self, script: &Script) -> Option<String> {
+        let bytes = script.as_bytes();
+        if script.is_p2pkh() {
+            return Some(base58check(self.p2pkh_prefix, bytes.get(3..23)?));
+        }
+        if script.is_p2sh() {
+            return Some(base58check(self.p2sh_prefix, bytes.get(2..22)?));
+        }
+        let version = script.witness_version()?;
+        let hrp = Hrp::parse(self.bech32_hrp?).ok()?;
+        segwit::encode(hrp, version.to_fe(), bytes.get(2..)?).ok()
+    }
+}
+
+fn base58check(prefix: u8, hash: &[u8]) -> String {
+    let mut data = Vec::with_capacity(1 + hash.len());
+    data.push(prefix);
+    data.extend_from_slice(hash);
+    bitcoin::base58::encode_check(&data)
+}
+
+/// A Bitcoin-format transaction as handed to the signer.
+#[derive(Debug, Clone)]
+pub enum UtxoTransaction {
+    /// A BIP-174 PSBT, which carries each input's previous output and the key origins
+    /// the amounts, fee and change are read from
+    Psbt(Box<Psbt>),
+    /// A consensus-encoded transaction; input amounts are not part of this encoding
+    Raw(RawTransaction),
+}
+
+impl UtxoTransaction {
+    fn unsigned_tx(&self) -> &RawTransaction {
+        match self {
+            Self::Psbt(psbt) => &psbt.unsigned_tx,
+            Self::Raw(tx) => tx,
+        }
+    }
+}
+
+pub(crate) fn decode_transaction(
+    raw_transaction: &str,
+    encodings: SupportedEncodings,
+) -> Result<UtxoTransaction, BitcoinParserError> {
+    let bytes = match encodings {
+        SupportedEncodings::Hex => {
+            visualsign::encodings::decode_hex(raw_transaction).map_err(|e| {
+                BitcoinParserError::FailedToDecodeTransaction(format!("Failed to decode hex: {e}"))
+            })?
+        }
+        SupportedEncodings::Base64 => b64.decode(raw_transaction).map_err(|e| {
+            BitcoinParserError::FailedToDecodeTransaction(format!("Failed to decode base64: {e}"))
+        })?,
+    };
+
+    parse_transaction_bytes(&bytes)
+}
+
+fn parse_transaction_bytes(bytes: &[u8]) -> Result<UtxoTransaction, BitcoinParserError> {
+    if bytes.starts_with(PSBT_MAGIC) {
+        return Psbt::deserialize(bytes)
+            .map(|psbt| UtxoTransaction::Psbt(Box::new(psbt)))
+            .map_err(|e| {
+                BitcoinParserError::FailedToDecodeTransaction(format!("Failed to parse PSBT: {e}"))
+            });
+    }
+    bitcoin::consensus::encode::deserialize::<RawTransaction>(bytes)
+        .map(UtxoTransaction::Raw)
+        .map_err(|e| {
+            BitcoinParserError::FailedToDecodeTransaction(format!(
+                "Failed to parse transaction: {e}"
+            ))
+        })
+}
+
+// What the PSBT (if any) adds to the bare transaction: the output each input spends, and
+// for outputs that go back to the signing wallet, the derivation path of their key.
+struct Context<'a> {
+    testnet: bool,
+    prevouts: Vec<Option<TxOut>>,
+    change_paths: Vec<Option<&'a DerivationPath>>,
+}
+
+impl<'a> Context<'a> {
+    fn new(transaction: &'a UtxoTransaction) -> Result<Self, VisualSignError> {
+        let psbt = match transaction {
+            UtxoTransaction::Psbt(psbt) => psbt,
+            UtxoTransaction::Raw(tx) => {
+                return Ok(Self {
+                    testnet: false,
+                    prevouts: vec![None; tx.input.len()],
+                    change_paths: vec![None; tx.output.len()],
+                });
+            }
+        };
+
+        let prevouts = psbt
+            .unsigned_tx
+            .input
+            .iter()
+            .zip(&psbt.inputs)
+            .map(|(txin, input)| psbt_prevout(input, &txin.previous_output))
+            .collect::<Result<Vec<_>, _>>()?;
+        let input_fingerprints: BTreeSet<Fingerprint> = psbt
+            .inputs
+            .iter()
+            .flat_map(input_key_sources)
+            .map(|(fingerprint, _)| *fingerprint)
+            .collect();
+        let change_paths = psbt
+            .outputs
+            .iter()
+            .map(|output| {
+                output_key_sources(output)
+                    .find(|(fingerprint, _)| input_fingerprints.contains(fingerprint))
+                    .map(|(_, path)| path)
+            })
+            .collect();
+        let testnet = psbt
+            .inputs
+            .iter()
+            .flat_map(input_key_sources)
+            .chain(psbt.outputs.iter().flat_map(output_key_sources))
+            .any(|(_, path)| is_testnet_path(path));
+
+        Ok(Self {
+            testnet,
+            prevouts,
+            change_paths,
+        })
+    }
+}
+
+// A full previous transaction is checked against the outpoint's txid, so its amount can be
+// trusted. A lone `witness_utxo` cannot be checked here, but segwit signatures commit to
+// the amount they spend, so a forged one yields a signature the network rejects.
+fn psbt_prevout(
+    input: &psbt::Input,
+    outpoint: &OutPoint,
+) -> Result<Option<TxOut>, VisualSignError> {
+    let Some(previous) = &input.non_witness_utxo else {
+        return Ok(input.witness_utxo.clone());
+    };
+    let txid = previous.compute_txid();
+    if txid != outpoint.txid {
+        return Err(VisualSignError::ValidationError(format!(
+            "previous transaction for input {outpoint} has txid {txid}"
+        )));
+    }
+    previous
+        .output
+        .get(outpoint.vout as usize)
+        .cloned()
+        .map(Some)
+        .ok_or_else(|| {
+            VisualSignError::ValidationError(format!(
+                "previous transaction {txid} has no output {}",
+                outpoint.vout
+            ))
+        })
+}
+
+fn input_key_sources(input: &psbt::Input) -> impl Iterator<Item = &KeySource> {
+    input
+        .bip32_derivation
+        .values()
+        .chain(input.tap_key_origins.values().map(|(_, source)| source))
+}
+
+fn output_key_sources(output: &psbt::Output) -> impl Iterator<Item = &KeySource> {
+    output
+        .bip32_derivation
+        .values()
+        .chain(output.tap_key_origins.values().map(|(_, source)| source))
+}
+
+// BIP-44 style paths (purposes 44', 49', 84' and 86') put the coin type second. Each
+// mainnet has its own (0' Bitcoin, 2' Litecoin, 3' Dogecoin) but every test network
+// shares 1'.
+fn is_testnet_path(path: &DerivationPath) -> bool {
+    path.into_iter().nth(1) == Some(&ChildNumber::Hardened { index: 1 })
+}
+
+pub(crate) fn convert_to_visual_sign_payload(
+    transaction: &UtxoTransaction,
+    options: VisualSignOptions,
+    chain: &UtxoChain,
+) -> Result<SignablePayload, VisualSignError> {
+    let tx = transaction.unsigned_tx();
+    let context = Context::new(transaction)?;
+    let unit = chain.unit;
+    let addresses = if context.testnet {
+        &chain.testnet
+    } else {
+        &chain.mainnet
+    };
+
+    let mut fields: Vec<AnnotatedPayloadField> = Vec::new();
+
+    let network = if context.testnet {
+        format!("{} Testnet", chain.name)
+    } else {
+        chain.name.to_string()
+    };
+    fields.push(create_text_field("Network", &network)?);
+    fields.push(create_text_field(
+        "Format",
+        match transaction {
+            UtxoTransaction::Psbt(_) => "PSBT",
+            UtxoTransaction::Raw(_) => "Raw Transaction",
+        },
+    )?);
+
+    for (i, (txin, prevout)) in tx.input.iter().zip(&context.prevouts).enumerate() {
+        fields.push(input_preview(
+            i + 1,
+            &txin.previous_output,
+            prevout.as_ref(),
+            unit,
+            addresses,
+        )?);
+    }
+    for (i, (txout, change_path)) in tx.output.iter().zip(&context.change_paths).enumerate() {
+        fields.push(output_preview(i + 1, txout, *change_path, unit, addresses)?);
+    }
+
+    let total_out = sum_base_units(tx.output.iter())?;
+    let total_in = context
+        .prevouts
+        .iter()
+        .map(Option::as_ref)
+        .collect::<Option<Vec<_>>>()
+        .map(|prevouts| sum_base_units(prevouts.into_iter()))
+        .transpose()?;
+
+    if let Some(total_in) = total_in {
+        fields.push(create_amount_field(
+            "Total Input",
+            &base_units_to_coin_string(total_in),
+            unit,
+        )?);
+    }
+    fields.push(create_amount_field(
+        "Total Output",
+        &base_units_to_coin_string(total_out),
+        unit,
+    )?);
+    if context.change_paths.iter().any(Option::is_some) {
+        let sending = sum_base_units(
+            tx.output
+                .iter()
+                .zip(&context.change_paths)
+                .filter(|(_, change_path)| change_path.is_none())
+                .map(|(txout, _)| txout),
+        )?;
+        fields.push(create_amount_field(
+            "Sending",
+            &base_units_to_coin_string(sending),
+            unit,
+        )?);
+    }
+    match total_in {
+        Some(total_in) => {
+            let fee = total_in.checked_sub(total_out).ok_or_else(|| {
+                VisualSignError::ValidationError(format!(
+                    "outputs ({} {unit}) exceed inputs ({} {unit})",
+                    base_units_to_coin_string(total_out),
+                    base_units_to_coin_string(total_in)
+                ))
+            })?;
+            fields.push(create_amount_field(
+                "Fee",
+                &base_units_to_coin_string(fee),
+                unit,
+            )?);
+        }
+        None => fields.push(create_text_field(
+            "Fee",
+            &unknown_fee_label(transaction, &context.prevouts),
+        )?),
+    }
+
+    if let Some(lock_time) = lock_time_label(tx.lock_time) {
+        fields.push(create_text_field("Lock Time", &lock_time)?);
+    }
+
+    let title = options
+        .transaction_name
+        .unwrap_or_else(|| format!("{} Transaction", chain.name));
+
+    Ok(SignablePayload::new(
+        0,
+        title,
+        None,
+        fields
+            .into_iter()
+            .map(|af| af.signable_payload_field)
+            .collect(),
+        format!("{}Tx", chain.name),
+    ))
+}
+
+fn input_preview(
+    n: usize,
+    outpoint: &OutPoint,
+    prevout: Option<&TxOut>,
+    unit: &str,
+    addresses: &AddressParams,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let mut detail_fields = vec![create_text_field("Previous Output", &outpoint.to_string())?];
+    let Some(prevout) = prevout else {
+        detail_fields.push(create_text_field("Amount", "Not in the transaction")?);
+        return Ok(create_preview_layout(
+            &format!("Input {n}"),
+            format!("Spends {outpoint}"),
+            detail_fields,
+        ));
+    };
+
+    let amount = base_units_to_coin_string(prevout.value.to_sat());
+    let subtitle = match addresses.address(&prevout.script_pubkey) {
+        Some(address) => {
+            detail_fields.push(create_address_field(
+                "From", &address, None, None, None, None,
+            )?);
+            format!("{amount} {unit} from {address}")
+        }
+        None => {
+            detail_fields.push(create_text_field(
+                "Script",
+                &hex::encode(prevout.script_pubkey.as_bytes()),
+            )?);
+            format!("{amount} {unit} from a non-standard script")
+        }
+    };
+    detail_fields.push(create_amount_field("Amount", &amount, unit)?);
+    Ok(create_preview_layout(
+        &format!("Input {n}"),
+        subtitle,
+        detail_fields,
+    ))
+}
+
+fn output_preview(
+    n: usize,
+    txout: &TxOut,
+    change_path: Option<&DerivationPath>,
+    unit: &str,
+    addresses: &AddressParams,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let amount = base_units_to_coin_string(txout.value.to_sat());
+    let mut detail_fields = Vec::new();
+
+    let subtitle = if txout.script_pubkey.is_op_return() {
+        let data = op_return_data(&txout.script_pubkey);
+        detail_fields.push(create_text_field("Data", &hex::encode(&data))?);
+        match printable_text(&data) {
+            Some(text) => {
+                detail_fields.push(create_text_field("Text", text)?);
+                format!("OP_RETURN \"{text}\"")
+            }
+            None => format!("OP_RETURN with {} bytes of data", data.len()),
+        }
+    } else {
+        match addresses.address(&txout.script_pubkey) {
+            Some(address) => {
+                detail_fields.push(create_address_field(
+                    "To",
+                    &address,
+                    None,
+                    None,
+                    None,
+                    change_path.map(|_| "Change"),
+                )?);
+                if change_path.is_some() {
+                    format!("{amount} {unit} change to {address}")
+                } else {
+                    format!("{amount} {unit} to {address}")
+                }
+            }
+            None => {
+                detail_fields.push(create_text_field(
+                    "Script",
+                    &hex::encode(txout.script_pubkey.as_bytes()),
+                )?);
+                format!("{amount} {unit} to a non-standard script")
+            }
+        }
+    };
+    detail_fields.push(create_amount_field("Amount", &amount, unit)?);
+    if let Some(path) = change_path {
+        detail_fields.push(create_text_field(
+            "Derivation Path",
+            &derivation_path_label(path),
+        )?);
+    }
+
+    Ok(create_preview_layout(
+        &format!("Output {n}"),
+        subtitle,
+        detail_fields,
+    ))
+}
+
+// The bytes pushed after OP_RETURN. A script that also carries non-push opcodes, or whose
+// last push is truncated, is shown whole (minus the OP_RETURN) instead.
+fn op_return_data(script: &Script) -> Vec<u8> {
+    let mut data = Vec::new();
+    for instruction in script.instructions().skip(1) {
+        match instruction {
+            Ok(Instruction::PushBytes(bytes)) => data.extend_from_slice(bytes.as_bytes()),
+            _ => return script.as_bytes().get(1..).unwrap_or_default().to_vec(),
+        }
+    }
+    data
+}
+
+// OP_RETURN payloads are often short ASCII tags or memos; only data that is entirely
+// printable is shown as text, so binary commitments are never half-rendered.
+fn printable_text(data: &[u8]) -> Option<&str> {
+    let text = std::str::from_utf8(data).ok()?;
+    (!text.is_empty() && !text.chars().any(char::is_control)).then_some(text)
+}
+
+fn derivation_path_label(path: &DerivationPath) -> String {
+    let mut label = "m".to_string();
+    for child in path {
+        label.push('/');
+        label.push_str(&child.to_string());
+    }
+    label
+}
+
+fn unknown_fee_label(transaction: &UtxoTransaction, prevouts: &[Option<TxOut>]) -> String {
+    match (transaction, prevouts.iter().position(Option::is_none)) {
+        (UtxoTransaction::Psbt(_), Some(index)) => format!(
+            "Unknown: the PSBT does not include the previous output of input {}",
+            index + 1
+        ),
+        _ => "Unknown: a raw transaction does not include input amounts".to_string(),
+    }
+}
+
+// Lock times below 500,000,000 are block heights, anything above is a Unix timestamp;
+// zero means the transaction is valid immediately and is not shown.
+fn lock_time_label(lock_time: LockTime) -> Option<String> {
+    match lock_time {
+        LockTime::Blocks(height) if height.to_consensus_u32() == 0 => None,
+        LockTime::Blocks(height) => Some(format!("Block {}", height.to_consensus_u32())),
+        LockTime::Seconds(time) => Some(format_timestamp_ms(
+            i64::from(time.to_consensus_u32()) * 1000,
+        )),
+    }
+}
+
+fn sum_base_units<'a>(
+    mut outputs: impl Iterator<Item = &'a TxOut>,
+) -> Result<u64, VisualSignError> {
+    outputs.try_fold(0u64, |total, txout| {
+        total.checked_add(txout.value.to_sat()).ok_or_else(|| {
+            VisualSignError::ValidationError("total amount overflows u64 base units".to_string())
+        })
+    })
+}
+
+// Base units (satoshis, litoshis, koinu) to a decimal coin string with integer math,
+// trimming trailing zeros so e.g. 150_000_000 -> "1.5" and 1_000 -> "0.00001".
+fn base_units_to_coin_string(units: u64) -> String {
+    let whole = units / BASE_UNITS_PER_COIN;
+    let frac = units % BASE_UNITS_PER_COIN;
+    if frac == 0 {
+        return whole.to_string();
+    }
+    let frac_str = format!("{frac:08}");
+    format!("{whole}.{}", frac_str.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use bitcoin::ScriptBuf;
+    use bitcoin::hashes::Hash;
+    use bitcoin::{PubkeyHash, ScriptHash, WPubkeyHash};
+
+    #[test]
+    fn addresses_use_each_chains_prefixes() {
+        let p2pkh = ScriptBuf::new_p2pkh(&PubkeyHash::from_byte_array([0; 20]));
+        let p2sh = ScriptBuf::new_p2sh(&ScriptHash::from_byte_array([0; 20]));
+        let p2wpkh = ScriptBuf::new_p2wpkh(&WPubkeyHash::from_byte_array([0; 20]));
+
+        assert_eq!(
+            BITCOIN.mainnet.address(&p2pkh).unwrap(),
+            "1111111111111111111114oLvT2"
+        );
+        assert_eq!(
+            BITCOIN.mainnet.address(&p2sh).unwrap(),
+            "31h1vYVSYuKP6AhS86fbRdMw9XHieotbST"
+        );
+        assert_eq!(
+            LITECOIN.mainnet.address(&p2pkh).unwrap(),
+            "LKDxGDJq5fF4FohAB8zJH24mDDNHDNtqsE"
+        );
+        assert!(
+            LITECOIN
+                .mainnet
+                .address(&p2wpkh)
+                .unwrap()
+                .starts_with("ltc1q")
+        );
+        assert!(
+            LITECOIN
+                .testnet
+                .address(&p2wpkh)
+                .unwrap()
+                .starts_with("tltc1q")
+        );
+        assert_eq!(
+            DOGECOIN.mainnet.address(&p2pkh).unwrap(),
+            "D596YFweJQuHY1BbjazZYmAbt8jJPbKehC"
+        );
+        // Dogecoin never activated segwit, so a witness program has no address there.
+        assert!(DOGECOIN.mainnet.address(&p2wpkh).is_none());
+    }
+
+    #[test]
+    fn base_units_to_coin_string_is_exact() {
+        assert_eq!(base_units_to_coin_string(0), "0");
+        assert_eq!(base_units_to_coin_string(1), "0.00000001");
+        assert_eq!(base_units_to_coin_string(150_000_000), "1.5");
+        assert_eq!(base_units_to_coin_string(2_100_000_000_000_000), "21000000");
+        assert_eq!(base_units_to_coin_string(u64::MAX), "184467440737.09551615");
+    }
+}
@@ -0,0 +1,51 @@
+use std::{env, fs, path::PathBuf};
+
+type BuildResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+const SELECTORS_PATH: &str = "data/selectors.txt";
+
+fn main() -> BuildResult<()> {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={SELECTORS_PATH}");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR")?);
+    let entries = parse_selectors(&fs::read_to_string(SELECTORS_PATH)?)?;
+
+    // Sorted by selector so lookups can binary search; colliding signatures stay
+    // adjacent, in file order.
+    let mut body = String::new();
+    for (selector, signature) in &entries {
+        body.push_str(&format!(
+            "    ([{:#04x}, {:#04x}, {:#04x}, {:#04x}], {signature:?}),\n",
+            selector[0], selector[1], selector[2], selector[3]
+        ));
+    }
+    let code = format!(
+        "/// `(selector, signature)` pairs from `{SELECTORS_PATH}`, sorted by selector\n\
+         static SELECTORS: &[([u8; 4], &str)] = &[\n{body}];\n"
+    );
+    fs::write(out_dir.join("selector_directory.rs"), code)?;
+
+    Ok(())
+}
+
+/// Parses `0x<selector> <signature>` lines, skipping blanks and `#` comments
+fn parse_selectors(contents: &str) -> BuildResult<Vec<([u8; 4], String)>> {
+    let mut entries = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let malformed = || format!("{SELECTORS_PATH}:{}: malformed entry '{line}'", index + 1);
+        let (selector, signature) = line.split_once(' ').ok_or_else(malformed)?;
+        let hex = selector.strip_prefix("0x").ok_or_else(malformed)?;
+        if hex.len() != 8 || !signature.contains('(') || !signature.ends_with(')') {
+            return Err(malformed().into());
+        }
+        let value = u32::from_str_radix(hex, 16).map_err(|_| malformed())?;
+        entries.push((value.to_be_bytes(), signature.to_string()));
+    }
+    entries.sort_by_key(|(selector, _)| *selector);
+    Ok(entries)
+}
@@ -19,7 +19,7 @@ use visualsign::{
 use crate::registry::ContractRegistry;
 
 /// Formats a DynSolValue into a human-readable string
-fn format_dyn_sol_value(value: &DynSolValue) -> String {
+pub(crate) fn format_dyn_sol_value(value: &DynSolValue) -> String {
     match value {
         DynSolValue::Address(addr) => format!("{addr:?}"),
         DynSolValue::Uint(val, _bits) => val.to_string(),
@@ -41,6 +41,18 @@ fn format_dyn_sol_value(value: &DynSolValue) -> String {
             format!("({})", formatted.join(", "))
         }
         DynSolValue::Function(func) => format!("0x{}", hex::encode(func.0)),
+        DynSolValue::CustomStruct {
+            name,
+            prop_names,
+            tuple,
+        } => {
+            let formatted: Vec<String> = prop_names
+                .iter()
+                .zip(tuple)
+                .map(|(prop, v)| format!("{prop}: {}", format_dyn_sol_value(v)))
+                .collect();
+            format!("{name} {{ {} }}", formatted.join(", "))
+        }
     }
 }
 
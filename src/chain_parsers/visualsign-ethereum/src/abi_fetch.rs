@@ -0,0 +1,304 @@
+//! ABI fetching from Sourcify and Etherscan with an on-disk cache
+//!
+//! Only compiled with the `abi-fetch` feature, which the CLI and dev tools
+//! enable. The parser service never makes network calls: it only sees ABIs the
+//! wallet passes in `ChainMetadata`.
+//!
+//! Lookups go to the cache first, then Sourcify, then Etherscan when an API key
+//! is configured. Every ABI is parsed before it is cached, so the cache only
+//! ever holds valid JSON ABIs, stored as `<cache_dir>/<chain_id>/<address>.json`.
+//! In offline mode only the cache is consulted.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use alloy_json_abi::JsonAbi;
+use alloy_primitives::Address;
+
+use crate::abi_registry::AbiRegistry;
+
+const SOURCIFY_URL: &str = "https://sourcify.dev/server/v2/contract";
+const ETHERSCAN_URL: &str = "https://api.etherscan.io/v2/api";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Error type for ABI fetching
+#[derive(Debug, thiserror::Error)]
+pub enum AbiFetchError {
+    /// No configured source has a verified ABI for the contract
+    #[error("No ABI found for {address} on chain {chain_id}")]
+    NotFound { chain_id: u64, address: Address },
+    /// Network or HTTP failure
+    #[error("Request to {source_name} failed: {message}")]
+    Http {
+        source_name: &'static str,
+        message: String,
+    },
+    /// The response or cached file is not a valid ABI
+    #[error("Invalid ABI from {source_name}: {message}")]
+    InvalidAbi {
+        source_name: &'static str,
+        message: String,
+    },
+    /// Reading or writing the cache failed
+    #[error("ABI cache error: {0}")]
+    Cache(String),
+}
+
+/// Where a fetched ABI came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbiSource {
+    Cache,
+    Sourcify,
+    Etherscan,
+}
+
+impl AbiSource {
+    fn name(self) -> &'static str {
+        match self {
+            AbiSource::Cache => "cache",
+            AbiSource::Sourcify => "Sourcify",
+            AbiSource::Etherscan => "Etherscan",
+        }
+    }
+}
+
+/// Resolves contract ABIs from public verification services
+pub struct AbiFetcher {
+    cache_dir: PathBuf,
+    etherscan_api_key: Option<String>,
+    offline: bool,
+    client: reqwest::blocking::Client,
+}
+
+impl AbiFetcher {
+    /// Creates a fetcher caching under `cache_dir`, using Sourcify only
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Result<Self, AbiFetchError> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| AbiFetchError::Http {
+                source_name: "client",
+                message: e.to_string(),
+            })?;
+        Ok(Self {
+            cache_dir: cache_dir.into(),
+            etherscan_api_key: None,
+            offline: false,
+            client,
+        })
+    }
+
+    /// Also queries Etherscan (API v2, any supported chain) with this key
+    pub fn with_etherscan_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.etherscan_api_key = Some(api_key.into());
+        self
+    }
+
+    /// Restricts lookups to the cache
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Returns the ABI JSON for `address` and where it came from
+    pub fn fetch(
+        &self,
+        chain_id: u64,
+        address: Address,
+    ) -> Result<(String, AbiSource), AbiFetchError> {
+        let cache_path = self.cache_path(chain_id, address);
+        if let Some(abi) = read_cached(&cache_path)? {
+            return Ok((abi, AbiSource::Cache));
+        }
+        if self.offline {
+            return Err(AbiFetchError::NotFound { chain_id, address });
+        }
+
+        let mut fetched = self.fetch_sourcify(chain_id, address)?;
+        if fetched.is_none() {
+            if let Some(api_key) = &self.etherscan_api_key {
+                fetched = self.fetch_etherscan(chain_id, address, api_key)?;
+            }
+        }
+        let (abi, source) = fetched.ok_or(AbiFetchError::NotFound { chain_id, address })?;
+        validate_abi(&abi, source)?;
+        write_cached(&cache_path, &abi)?;
+        Ok((abi, source))
+    }
+
+    /// Fetches the ABI for `address` and maps it in `registry`
+    pub fn register(
+        &self,
+        registry: &mut AbiRegistry,
+        chain_id: u64,
+        address: Address,
+    ) -> Result<AbiSource, AbiFetchError> {
+        let (abi, source) = self.fetch(chain_id, address)?;
+        let name = format!("{address:?}");
+        registry
+            .register_abi(&name, &abi)
+            .map_err(|e| AbiFetchError::InvalidAbi {
+                source_name: source.name(),
+                message: e.to_string(),
+            })?;
+        registry.map_address(chain_id, address, &name);
+        Ok(source)
+    }
+
+    fn cache_path(&self, chain_id: u64, address: Address) -> PathBuf {
+        self.cache_dir
+            .join(chain_id.to_string())
+            .join(format!("{address:?}.json"))
+    }
+
+    fn get_json(
+        &self,
+        source: AbiSource,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> Result<Option<serde_json::Value>, AbiFetchError> {
+        let http_error = |e: reqwest::Error| AbiFetchError::Http {
+            source_name: source.name(),
+            message: e.to_string(),
+        };
+        let response = request.send().map_err(http_error)?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body = response
+            .error_for_status()
+            .map_err(http_error)?
+            .text()
+            .map_err(http_error)?;
+        serde_json::from_str(&body)
+            .map(Some)
+            .map_err(|e| AbiFetchError::InvalidAbi {
+                source_name: source.name(),
+                message: e.to_string(),
+            })
+    }
+
+    /// Sourcify answers 404 for contracts it has not verified
+    fn fetch_sourcify(
+        &self,
+        chain_id: u64,
+        address: Address,
+    ) -> Result<Option<(String, AbiSource)>, AbiFetchError> {
+        let request = self
+            .client
+            .get(format!("{SOURCIFY_URL}/{chain_id}/{address}"))
+            .query(&[("fields", "abi")]);
+        let Some(body) = self.get_json(AbiSource::Sourcify, request)? else {
+            return Ok(None);
+        };
+        Ok(body
+            .get("abi")
+            .filter(|abi| abi.is_array())
+            .map(|abi| (abi.to_string(), AbiSource::Sourcify)))
+    }
+
+    /// Etherscan reports unverified contracts with `status: "0"` and the ABI,
+    /// when there is one, as a JSON string in `result`
+    fn fetch_etherscan(
+        &self,
+        chain_id: u64,
+        address: Address,
+        api_key: &str,
+    ) -> Result<Option<(String, AbiSource)>, AbiFetchError> {
+        let request = self.client.get(ETHERSCAN_URL).query(&[
+            ("chainid", chain_id.to_string().as_str()),
+            ("module", "contract"),
+            ("action", "getabi"),
+            ("address", address.to_string().as_str()),
+            ("apikey", api_key),
+        ]);
+        let Some(body) = self.get_json(AbiSource::Etherscan, request)? else {
+            return Ok(None);
+        };
+        if body.get("status").and_then(|s| s.as_str()) != Some("1") {
+            return Ok(None);
+        }
+        Ok(body
+            .get("result")
+            .and_then(|r| r.as_str())
+            .map(|abi| (abi.to_string(), AbiSource::Etherscan)))
+    }
+}
+
+fn validate_abi(abi: &str, source: AbiSource) -> Result<(), AbiFetchError> {
+    serde_json::from_str::<JsonAbi>(abi)
+        .map(|_| ())
+        .map_err(|e| AbiFetchError::InvalidAbi {
+            source_name: source.name(),
+            message: e.to_string(),
+        })
+}
+
+fn read_cached(path: &Path) -> Result<Option<String>, AbiFetchError> {
+    match std::fs::read_to_string(path) {
+        Ok(abi) => {
+            validate_abi(&abi, AbiSource::Cache)?;
+            Ok(Some(abi))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(AbiFetchError::Cache(format!("{}: {e}", path.display()))),
+    }
+}
+
+/// Writes through a temporary file so an interrupted run never leaves a
+/// truncated ABI behind
+fn write_cached(path: &Path, abi: &str) -> Result<(), AbiFetchError> {
+    let cache_error = |e: std::io::Error| AbiFetchError::Cache(format!("{}: {e}", path.display()));
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(cache_error)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, abi).map_err(cache_error)?;
+    std::fs::rename(&tmp, path).map_err(cache_error)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    const TRANSFER_ABI: &str = r#"[{"type":"function","name":"transfer","inputs":[{"name":"to","type":"address"},{"name":"amount","type":"uint256"}],"outputs":[],"stateMutability":"nonpayable"}]"#;
+
+    fn temp_cache(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("vsp_abi_fetch_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_offline_fetch_reads_cache() {
+        let dir = temp_cache("hit");
+        let address = Address::repeat_byte(0x11);
+        let fetcher = AbiFetcher::new(&dir).unwrap().offline(true);
+        write_cached(&fetcher.cache_path(1, address), TRANSFER_ABI).unwrap();
+
+        let mut registry = AbiRegistry::new();
+        let source = fetcher.register(&mut registry, 1, address).unwrap();
+        assert_eq!(source, AbiSource::Cache);
+        assert!(registry.get_abi_for_address(1, address).is_some());
+        // Cache entries are per chain
+        assert!(matches!(
+            fetcher.fetch(10, address),
+            Err(AbiFetchError::NotFound { chain_id: 10, .. })
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_corrupt_cache_entry_is_rejected() {
+        let dir = temp_cache("corrupt");
+        let address = Address::repeat_byte(0x22);
+        let fetcher = AbiFetcher::new(&dir).unwrap().offline(true);
+        write_cached(&fetcher.cache_path(1, address), "not json").unwrap();
+
+        assert!(matches!(
+            fetcher.fetch(1, address),
+            Err(AbiFetchError::InvalidAbi { .. })
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -30,8 +30,8 @@ enum AbiSignatureError {
 /// Extract and validate ABIs from `ChainMetadata`, if present.
 ///
 /// Navigates `ChainMetadata -> Ethereum -> abi_mappings` and registers each ABI
-/// with its contract address. Returns `None` if the metadata doesn't contain
-/// any Ethereum ABI mappings.
+/// with its contract address, then records the `proxy_implementations` links.
+/// Returns `None` if the metadata contains neither.
 ///
 /// The `chain_id` is needed to register address-to-ABI mappings in the registry.
 ///
@@ -73,6 +73,9 @@ enum AbiSignatureError {
 ///   an attacker who can tamper metadata could already swap the bound ABI itself.
 ///   The full `ChainMetadata` (including these fields) is still committed to by
 ///   `metadata_digest` in the signed enclave output.
+/// - **`proxy_implementations` links are unsigned** for the same reason. A link
+///   only takes effect for a destination that is not already a known contract or
+///   canonical token, so it cannot redirect the built-in decoders.
 pub fn try_extract_from_chain_metadata(
     chain_metadata: Option<&ChainMetadata>,
     chain_id: u64,
@@ -82,7 +85,7 @@ pub fn try_extract_from_chain_metadata(
     let chain_metadata::Metadata::Ethereum(ethereum) = chain_metadata.metadata.as_ref()? else {
         return None;
     };
-    if ethereum.abi_mappings.is_empty() {
+    if ethereum.abi_mappings.is_empty() && ethereum.proxy_implementations.is_empty() {
         return None;
     }
 
@@ -154,7 +157,18 @@ pub fn try_extract_from_chain_metadata(
             "Accepted {unsigned_count} unsigned ABI mapping(s): integrity/provenance unverified"
         );
     }
-    if registry.list_abis().is_empty() {
+    for (proxy, implementation) in &ethereum.proxy_implementations {
+        match (
+            proxy.parse::<alloy_primitives::Address>(),
+            implementation.parse::<alloy_primitives::Address>(),
+        ) {
+            (Ok(proxy), Ok(implementation)) => registry.link_proxy(chain_id, proxy, implementation),
+            (Err(e), _) | (_, Err(e)) => {
+                log::warn!("Skipping proxy link '{proxy}' -> '{implementation}': {e}");
+            }
+        }
+    }
+    if registry.is_empty() {
         return None;
     }
     Some(registry)
@@ -846,6 +860,9 @@ mod tests {
                 network_id: None,
                 idl: None,
                 idl_mappings: Default::default(),
+                address_lookup_tables: Default::default(),
+                markets: Default::default(),
+                tokens: Default::default(),
             })),
         };
         assert!(
@@ -859,6 +876,12 @@ mod tests {
             metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                 network_id: Some("ETHEREUM_MAINNET".to_string()),
                 abi_mappings: Default::default(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         assert!(
@@ -877,6 +900,12 @@ mod tests {
                 )])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let registry =
@@ -909,6 +938,12 @@ mod tests {
                 abi_mappings: make_abi_mappings(vec![(TEST_ADDRESS, abi)])
                     .into_iter()
                     .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
 
@@ -939,6 +974,12 @@ mod tests {
                 )])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let registry =
@@ -969,6 +1010,12 @@ mod tests {
                 ])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let registry =
@@ -1002,6 +1049,12 @@ mod tests {
                 )])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let mut unlisted_allow = SignerAllowlist::new();
@@ -1066,6 +1119,12 @@ mod tests {
                 )])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let registry =
@@ -1104,6 +1163,12 @@ mod tests {
                 )])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         // Invalid entries are skipped; with no valid entries left, result is None
@@ -1123,6 +1188,12 @@ mod tests {
                 )])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         // Invalid ABI JSON is skipped; with no valid entries left, result is None.
@@ -1145,6 +1216,12 @@ mod tests {
                 ])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         // The valid entry should be registered; the invalid one skipped
@@ -1174,6 +1251,12 @@ mod tests {
                 )])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let registry =
@@ -1203,6 +1286,12 @@ mod tests {
                 ])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let registry =
@@ -1237,6 +1326,12 @@ mod tests {
                 )])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let registry =
@@ -1291,6 +1386,12 @@ mod tests {
                 ])
                 .into_iter()
                 .collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let registry =
@@ -1307,4 +1408,71 @@ mod tests {
         assert_eq!(impl_addr, parse_addr(IMPL_ADDRESS));
         assert!(impl_abi.functions().any(|f| f.name == "transfer"));
     }
+
+    #[test]
+    fn test_extract_proxy_implementations_without_proxy_abi() {
+        let metadata = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                network_id: Some("ETHEREUM_MAINNET".to_string()),
+                abi_mappings: make_abi_mappings(vec![(
+                    IMPL_ADDRESS,
+                    signed_abi(VALID_ABI, IMPL_ADDRESS),
+                )])
+                .into_iter()
+                .collect(),
+                proxy_implementations: [(PROXY_ADDRESS.to_string(), IMPL_ADDRESS.to_string())]
+                    .into_iter()
+                    .collect(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
+            })),
+        };
+        let registry =
+            try_extract_from_chain_metadata(Some(&metadata), 1, &test_signer_allowlist())
+                .expect("has ABI");
+
+        assert_eq!(registry.get_abi_kind(1, parse_addr(PROXY_ADDRESS)), None);
+        let (impl_addr, _) = registry
+            .get_implementation_abi(1, parse_addr(PROXY_ADDRESS))
+            .expect("linked proxy resolves to implementation");
+        assert_eq!(impl_addr, parse_addr(IMPL_ADDRESS));
+    }
+
+    #[test]
+    fn test_extract_proxy_implementations_only() {
+        let metadata = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                network_id: Some("ETHEREUM_MAINNET".to_string()),
+                abi_mappings: Default::default(),
+                proxy_implementations: [
+                    (PROXY_ADDRESS.to_string(), IMPL_ADDRESS.to_string()),
+                    ("not_an_address".to_string(), IMPL_ADDRESS.to_string()),
+                ]
+                .into_iter()
+                .collect(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
+            })),
+        };
+        // No ABIs at all: the registry still carries the link so contract-type
+        // lookup can fall through to the implementation.
+        let registry =
+            try_extract_from_chain_metadata(Some(&metadata), 1, &test_signer_allowlist())
+                .expect("has a proxy link");
+        assert_eq!(
+            registry.get_implementation(1, parse_addr(PROXY_ADDRESS)),
+            Some(parse_addr(IMPL_ADDRESS))
+        );
+        assert!(
+            registry
+                .get_implementation_abi(1, parse_addr(PROXY_ADDRESS))
+                .is_none()
+        );
+    }
 }
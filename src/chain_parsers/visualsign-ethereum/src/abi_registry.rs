@@ -65,6 +65,9 @@ pub struct AbiRegistry {
     abis: Arc<BTreeMap<String, Arc<JsonAbi>>>,
     /// Maps (chain_id, contract_address) -> mapping entry (ABI name + kind + impl link)
     address_mappings: Arc<BTreeMap<(ChainId, Address), AddressMapping>>,
+    /// Maps (chain_id, proxy_address) -> implementation address, for proxies that
+    /// have no ABI entry of their own
+    proxy_links: Arc<BTreeMap<(ChainId, Address), Address>>,
 }
 
 impl AbiRegistry {
@@ -73,6 +76,7 @@ impl AbiRegistry {
         Self {
             abis: Arc::new(BTreeMap::new()),
             address_mappings: Arc::new(BTreeMap::new()),
+            proxy_links: Arc::new(BTreeMap::new()),
         }
     }
 
@@ -151,6 +155,17 @@ impl AbiRegistry {
             );
     }
 
+    /// Links a proxy address to its implementation independently of any ABI
+    /// mapped to the proxy itself.
+    ///
+    /// An implementation link declared through `map_address_with_type` takes
+    /// precedence over one recorded here.
+    pub fn link_proxy(&mut self, chain_id: ChainId, proxy: Address, implementation: Address) {
+        Arc::get_mut(&mut self.proxy_links)
+            .expect("Proxy links should be mutable")
+            .insert((chain_id, proxy), implementation);
+    }
+
     /// Gets the ABI for a specific contract address on a given chain
     ///
     /// # Arguments
@@ -174,24 +189,30 @@ impl AbiRegistry {
             .map(|m| m.abi_kind)
     }
 
+    /// Gets the implementation a proxy address delegates to: the link declared on
+    /// its proxy ABI mapping, else one recorded with `link_proxy`.
+    pub fn get_implementation(&self, chain_id: ChainId, proxy: Address) -> Option<Address> {
+        self.address_mappings
+            .get(&(chain_id, proxy))
+            .filter(|m| m.abi_kind == AbiKind::Proxy)
+            .and_then(|m| m.implementation)
+            .or_else(|| self.proxy_links.get(&(chain_id, proxy)).copied())
+    }
+
     /// Resolves a proxy address to its implementation: returns the implementation
     /// address and the ABI registered for it.
     ///
     /// Resolution is single-hop only: if the linked implementation is itself a proxy,
     /// its registered ABI is returned as-is without following the next link.
     ///
-    /// Returns `None` if the address is not a proxy, has no implementation link, or
-    /// the linked implementation address has no registered ABI.
+    /// Returns `None` if the address has no implementation link or the linked
+    /// implementation address has no registered ABI.
     pub fn get_implementation_abi(
         &self,
         chain_id: ChainId,
         proxy: Address,
     ) -> Option<(Address, Arc<JsonAbi>)> {
-        let mapping = self.address_mappings.get(&(chain_id, proxy))?;
-        if mapping.abi_kind != AbiKind::Proxy {
-            return None;
-        }
-        let implementation = mapping.implementation?;
+        let implementation = self.get_implementation(chain_id, proxy)?;
         let abi = self.get_abi_for_address(chain_id, implementation)?;
         Some((implementation, abi))
     }
@@ -213,6 +234,11 @@ impl AbiRegistry {
         self.abis.keys().map(|s| s.as_str()).collect()
     }
 
+    /// Returns true if no ABI and no proxy link is registered
+    pub fn is_empty(&self) -> bool {
+        self.abis.is_empty() && self.proxy_links.is_empty()
+    }
+
     /// Lists all address mappings for a given chain
     pub fn list_mappings_for_chain(&self, chain_id: ChainId) -> Vec<(Address, &str)> {
         self.address_mappings
@@ -388,4 +414,50 @@ mod tests {
 
         assert!(registry.get_implementation_abi(1, proxy).is_none());
     }
+
+    #[test]
+    fn test_linked_proxy_without_own_abi_resolves() {
+        let mut registry = AbiRegistry::new();
+        registry.register_abi("Impl", TEST_ABI).unwrap();
+        let proxy = addr("0x1111111111111111111111111111111111111111");
+        let implementation = addr("0x2222222222222222222222222222222222222222");
+        registry.map_address(1, implementation, "Impl");
+        registry.link_proxy(1, proxy, implementation);
+
+        assert_eq!(registry.get_abi_kind(1, proxy), None);
+        assert_eq!(registry.get_implementation(1, proxy), Some(implementation));
+        let (resolved_addr, _) = registry
+            .get_implementation_abi(1, proxy)
+            .expect("linked proxy should resolve to implementation");
+        assert_eq!(resolved_addr, implementation);
+        assert_eq!(registry.get_implementation(137, proxy), None);
+    }
+
+    #[test]
+    fn test_proxy_abi_link_takes_precedence_over_proxy_link() {
+        let mut registry = AbiRegistry::new();
+        registry.register_abi("Proxy", "[]").unwrap();
+        let proxy = addr("0x1111111111111111111111111111111111111111");
+        let declared = addr("0x2222222222222222222222222222222222222222");
+        let linked = addr("0x3333333333333333333333333333333333333333");
+        registry.map_address_with_type(1, proxy, "Proxy", AbiKind::Proxy, Some(declared));
+        registry.link_proxy(1, proxy, linked);
+
+        assert_eq!(registry.get_implementation(1, proxy), Some(declared));
+    }
+
+    #[test]
+    fn test_proxy_link_applies_to_address_with_own_abi() {
+        let mut registry = AbiRegistry::new();
+        registry.register_abi("Impl", TEST_ABI).unwrap();
+        registry.register_abi("ProxyAdmin", "[]").unwrap();
+        let proxy = addr("0x1111111111111111111111111111111111111111");
+        let implementation = addr("0x2222222222222222222222222222222222222222");
+        registry.map_address(1, implementation, "Impl");
+        registry.map_address(1, proxy, "ProxyAdmin");
+        registry.link_proxy(1, proxy, implementation);
+
+        assert_eq!(registry.get_implementation(1, proxy), Some(implementation));
+        assert!(registry.get_abi_for_address(1, proxy).is_some());
+    }
 }
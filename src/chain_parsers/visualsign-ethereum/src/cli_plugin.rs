@@ -25,6 +25,33 @@ pub struct EthereumArgs {
     /// gets an empty ABI synthesized). Format: `0xProxy:0xImpl`. Repeatable.
     #[arg(long = "abi-proxy-mappings", value_name = "0xPROXY:0xIMPL")]
     pub abi_proxy_mappings: Vec<String>,
+
+    /// Fetch the verified ABI for this contract address from Sourcify (or
+    /// Etherscan with `--etherscan-api-key`). Addresses already given an ABI file
+    /// are not fetched. Repeatable.
+    #[cfg(feature = "abi-fetch")]
+    #[arg(long = "fetch-abi", value_name = "0xADDRESS")]
+    pub fetch_abi: Vec<String>,
+
+    /// Directory for cached ABIs, one file per `<chain_id>/<address>.json`.
+    #[cfg(feature = "abi-fetch")]
+    #[arg(
+        long = "abi-cache-dir",
+        value_name = "DIR",
+        default_value = ".abi-cache"
+    )]
+    pub abi_cache_dir: std::path::PathBuf,
+
+    /// Etherscan API key, enabling Etherscan as a second source after Sourcify.
+    /// Defaults to the `ETHERSCAN_API_KEY` environment variable.
+    #[cfg(feature = "abi-fetch")]
+    #[arg(long = "etherscan-api-key", value_name = "KEY")]
+    pub etherscan_api_key: Option<String>,
+
+    /// Only use ABIs already in the cache; never make network requests.
+    #[cfg(feature = "abi-fetch")]
+    #[arg(long = "offline-abis")]
+    pub offline_abis: bool,
 }
 
 /// [`parser_cli_core::ChainPlugin`] implementation for Ethereum.
@@ -53,11 +80,22 @@ impl parser_cli_core::ChainPlugin for EthereumPlugin {
     }
 
     fn create_metadata(&self, network: Option<String>) -> Result<Option<ChainMetadata>, String> {
-        create_chain_metadata(
+        #[cfg_attr(not(feature = "abi-fetch"), allow(unused_mut))]
+        let mut metadata = create_chain_metadata(
             network,
             &self.args.abi_json_mappings,
             &self.args.abi_proxy_mappings,
-        )
+        )?;
+        #[cfg(feature = "abi-fetch")]
+        if let Some(ChainMetadata {
+            metadata: Some(Metadata::Ethereum(ethereum)),
+        }) = metadata.as_mut()
+        {
+            for warning in add_fetched_abis(ethereum, &self.args)? {
+                log::warn!("{warning}");
+            }
+        }
+        Ok(metadata)
     }
 }
 
@@ -259,6 +297,77 @@ fn apply_proxy_mappings(
     }
 }
 
+/// Fetch the `--fetch-abi` contracts' ABIs and add them to the metadata, signed
+/// like file-loaded ABIs.
+///
+/// Addresses that already have an entry (from `--abi-json-mappings` or a
+/// synthesized proxy ABI) keep it. A contract that cannot be fetched is skipped
+/// and reported in the returned warnings, so one unverified contract does not
+/// stop the run; the caller decides how to surface them.
+#[cfg(feature = "abi-fetch")]
+fn add_fetched_abis(
+    metadata: &mut EthereumMetadata,
+    args: &EthereumArgs,
+) -> Result<Vec<String>, String> {
+    let mut warnings = Vec::new();
+    if args.fetch_abi.is_empty() {
+        return Ok(warnings);
+    }
+    let network_id = metadata.network_id.as_deref().unwrap_or_default();
+    let chain_id = network_id_to_chain_id(network_id).ok_or_else(|| {
+        format!("cannot fetch ABIs for network '{network_id}': unknown network ID")
+    })?;
+    let mut fetcher = crate::abi_fetch::AbiFetcher::new(&args.abi_cache_dir)
+        .map_err(|e| e.to_string())?
+        .offline(args.offline_abis);
+    let api_key = args
+        .etherscan_api_key
+        .clone()
+        .or_else(|| std::env::var("ETHERSCAN_API_KEY").ok());
+    if let Some(api_key) = api_key {
+        fetcher = fetcher.with_etherscan_api_key(api_key);
+    }
+
+    for address in &args.fetch_abi {
+        if let Err(e) = validate_eth_address(address) {
+            warnings.push(format!("Skipping '{address}': invalid address: {e}"));
+            continue;
+        }
+        let key = normalize_eth_address(address);
+        if metadata.abi_mappings.contains_key(&key) {
+            log::debug!("'{key}' already has an ABI; not fetching");
+            continue;
+        }
+        let Ok(addr) = key.parse::<alloy_primitives::Address>() else {
+            continue;
+        };
+        let (json, source) = match fetcher.fetch(chain_id, addr) {
+            Ok(fetched) => fetched,
+            Err(e) => {
+                warnings.push(format!("Skipping '{key}': {e}"));
+                continue;
+            }
+        };
+        let signature = match sign_abi_for_cli(&json, &addr, chain_id) {
+            Ok(sig) => sig,
+            Err(e) => {
+                warnings.push(format!("Skipping '{key}': failed to sign ABI: {e}"));
+                continue;
+            }
+        };
+        log::info!("Loaded ABI for '{key}' from {source:?}");
+        metadata.abi_mappings.insert(
+            key,
+            Abi {
+                value: json,
+                signature: Some(signature),
+                ..Default::default()
+            },
+        );
+    }
+    Ok(warnings)
+}
+
 /// Creates Ethereum chain metadata from the network argument.
 /// Defaults to `ETHEREUM_MAINNET` if no network is specified.
 /// Returns an error if the network identifier is invalid.
@@ -327,6 +436,12 @@ pub(crate) fn create_chain_metadata(
         metadata: Some(Metadata::Ethereum(EthereumMetadata {
             network_id: Some(network_id),
             abi_mappings: abi_mappings.into_iter().collect(),
+            proxy_implementations: Default::default(),
+            custom_networks: Default::default(),
+            address_book: Default::default(),
+            token_prices: Default::default(),
+            tokens: Default::default(),
+            erc4626_vaults: Default::default(),
         })),
     }))
 }
@@ -639,4 +754,80 @@ mod tests {
             "0x1111111111111111111111111111111111111111"
         );
     }
+
+    #[cfg(feature = "abi-fetch")]
+    #[test]
+    fn test_fetch_abi_offline_uses_cache_and_keeps_file_mappings() {
+        let cache_dir =
+            std::env::temp_dir().join(format!("vsp_eth_tests_abi_cache_{}", std::process::id()));
+        let cached = "0x1111111111111111111111111111111111111111";
+        let chain_dir = cache_dir.join("1");
+        std::fs::create_dir_all(&chain_dir).unwrap();
+        std::fs::write(chain_dir.join(format!("{cached}.json")), "[]").unwrap();
+
+        let abi_path = write_temp_json("fetch_abi_file.json", "[]");
+        let file_mapping = format!(
+            "File:{}:0x2222222222222222222222222222222222222222",
+            abi_path.display()
+        );
+        let args = EthereumArgs {
+            abi_json_mappings: vec![file_mapping],
+            fetch_abi: vec![
+                "0x1111111111111111111111111111111111111111".to_string(),
+                "0x2222222222222222222222222222222222222222".to_string(),
+                // Not cached, and offline mode never hits the network
+                "0x3333333333333333333333333333333333333333".to_string(),
+            ],
+            abi_cache_dir: cache_dir.clone(),
+            offline_abis: true,
+            ..Default::default()
+        };
+        let meta = parser_cli_core::ChainPlugin::create_metadata(
+            &EthereumPlugin::new(args),
+            Some("1".to_string()),
+        )
+        .unwrap()
+        .unwrap();
+        let Metadata::Ethereum(eth) = meta.metadata.unwrap() else {
+            panic!("expected Ethereum metadata");
+        };
+        let keys: Vec<&String> = eth.abi_mappings.keys().collect();
+        assert_eq!(
+            keys,
+            [
+                "0x1111111111111111111111111111111111111111",
+                "0x2222222222222222222222222222222222222222"
+            ]
+        );
+        assert!(eth.abi_mappings[cached].signature.is_some());
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[cfg(feature = "abi-fetch")]
+    #[test]
+    fn test_fetch_abi_returns_skipped_contracts_as_warnings() {
+        let cache_dir = std::env::temp_dir().join(format!(
+            "vsp_eth_tests_abi_cache_warnings_{}",
+            std::process::id()
+        ));
+        let args = EthereumArgs {
+            fetch_abi: vec![
+                "not-an-address".to_string(),
+                "0x3333333333333333333333333333333333333333".to_string(),
+            ],
+            abi_cache_dir: cache_dir.clone(),
+            offline_abis: true,
+            ..Default::default()
+        };
+        let mut metadata = EthereumMetadata {
+            network_id: Some("ETHEREUM_MAINNET".to_string()),
+            ..Default::default()
+        };
+        let warnings = add_fetched_abis(&mut metadata, &args).unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].starts_with("Skipping 'not-an-address': invalid address"));
+        assert!(warnings[1].starts_with("Skipping '0x3333333333333333333333333333333333333333'"));
+        assert!(metadata.abi_mappings.is_empty());
+        let _ = std::fs::remove_dir_all(&cache_dir);
+    }
 }
@@ -0,0 +1,138 @@
+//! Risk callouts for token approvals
+//!
+//! An approval lets the spender move the owner's tokens later without asking
+//! again. Two things make that worth a warning: an unlimited amount, and a
+//! spender that is not one of the contracts in the built-in registry.
+//!
+//! Calldata alone can't tell an externally owned account from a contract, so an
+//! unregistered spender may be either and both get the same callout. Only the
+//! built-in registry counts: a contract type supplied through chain metadata
+//! comes from the same caller as the transaction and must not silence the
+//! warning.
+
+use alloy_primitives::Address;
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldListLayout, SignablePayloadFieldStaticAnnotation,
+    SignablePayloadFieldTextV2,
+};
+
+use crate::registry::ContractRegistry;
+
+/// Whether `spender` is a contract the built-in registry knows on `chain_id`
+pub(crate) fn is_known_spender(
+    registry: &ContractRegistry,
+    chain_id: u64,
+    spender: Address,
+) -> bool {
+    registry.get_contract_type(chain_id, spender).is_some()
+}
+
+/// Returns the warning text and risk level for an approval, or `None` when
+/// neither risk applies. `known_spender` is `None` when there was no registry
+/// to check the spender against.
+fn assess(unlimited: bool, known_spender: Option<bool>) -> Option<(&'static str, &'static str)> {
+    match (unlimited, known_spender) {
+        (true, Some(false)) => Some((
+            "Unlimited approval to unknown contract. The spender is not a recognized contract and may be a wallet address; it can move your entire balance of this token until the approval is revoked.",
+            "High risk",
+        )),
+        (false, Some(false)) => Some((
+            "Approval to unknown contract. The spender is not a recognized contract and may be a wallet address.",
+            "High risk",
+        )),
+        (true, _) => Some((
+            "Unlimited approval. The spender can move your entire balance of this token until the approval is revoked.",
+            "Caution",
+        )),
+        (false, _) => None,
+    }
+}
+
+/// Builds the `Warning` row for an approval, or `None` when neither risk applies
+pub(crate) fn approval_warning(
+    unlimited: bool,
+    known_spender: Option<bool>,
+) -> Option<AnnotatedPayloadField> {
+    let (text, level) = assess(unlimited, known_spender)?;
+    Some(AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.to_string(),
+                label: "Warning".to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 {
+                text: text.to_string(),
+            },
+        },
+        static_annotation: Some(SignablePayloadFieldStaticAnnotation {
+            text: level.to_string(),
+        }),
+        dynamic_annotation: None,
+    })
+}
+
+/// Adds `warning` to a preview layout: last in the expanded rows, in the
+/// condensed view, and appended to the fallback text so it survives any
+/// rendering. A `TextV2` field gets it appended to its text instead.
+pub(crate) fn attach_warning(field: &mut SignablePayloadField, warning: AnnotatedPayloadField) {
+    let text = warning.signable_payload_field.fallback_text().clone();
+    match field {
+        SignablePayloadField::PreviewLayout {
+            common,
+            preview_layout,
+        } => {
+            common.fallback_text = format!("{}. WARNING: {text}", common.fallback_text);
+            preview_layout
+                .condensed
+                .get_or_insert_with(empty_list)
+                .fields
+                .push(warning.clone());
+            preview_layout
+                .expanded
+                .get_or_insert_with(empty_list)
+                .fields
+                .push(warning);
+        }
+        SignablePayloadField::TextV2 { common, text_v2 } => {
+            text_v2.text = format!("{}. WARNING: {text}", text_v2.text);
+            common.fallback_text = format!("{}. WARNING: {text}", common.fallback_text);
+        }
+        _ => {}
+    }
+}
+
+fn empty_list() -> SignablePayloadFieldListLayout {
+    SignablePayloadFieldListLayout { fields: Vec::new() }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assess_combinations() {
+        assert!(assess(false, Some(true)).is_none());
+        assert!(assess(false, None).is_none());
+        assert_eq!(assess(true, Some(true)).unwrap().1, "Caution");
+        assert_eq!(assess(true, None).unwrap().1, "Caution");
+        let (text, level) = assess(true, Some(false)).unwrap();
+        assert!(text.starts_with("Unlimited approval to unknown contract"));
+        assert_eq!(level, "High risk");
+        assert!(
+            assess(false, Some(false))
+                .unwrap()
+                .0
+                .starts_with("Approval to unknown contract")
+        );
+    }
+
+    #[test]
+    fn test_known_spender_uses_contract_types() {
+        let (registry, _) = ContractRegistry::with_default_protocols();
+        let permit2 = crate::protocols::uniswap::config::UniswapConfig::permit2_address();
+        assert!(is_known_spender(&registry, 1, permit2));
+        assert!(!is_known_spender(&registry, 1, Address::repeat_byte(0x44)));
+    }
+}
@@ -16,6 +16,7 @@ sol! {
     interface IERC1155 {
         function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes data) external;
         function safeBatchTransferFrom(address from, address to, uint256[] ids, uint256[] values, bytes data) external;
+        function setApprovalForAll(address operator, bool approved) external;
     }
 }
 
@@ -23,7 +24,8 @@ sol! {
 pub struct ERC1155Visualizer;
 
 impl ERC1155Visualizer {
-    /// Attempts to decode and visualize ERC-1155 transfer calls.
+    /// Attempts to decode and visualize ERC-1155 transfer and operator
+    /// approval calls.
     ///
     /// # Arguments
     /// * `input` - The calldata bytes (with 4-byte function selector)
@@ -49,6 +51,14 @@ impl ERC1155Visualizer {
             if let Ok(call) = IERC1155::safeBatchTransferFromCall::abi_decode(input) {
                 return Some(Self::render_safe_batch_transfer_from(call));
             }
+        } else if selector == IERC1155::setApprovalForAllCall::SELECTOR {
+            // setApprovalForAll(address,bool), same semantics as ERC-721
+            if let Ok(call) = IERC1155::setApprovalForAllCall::abi_decode(input) {
+                return Some(super::erc721::render_set_approval_for_all(
+                    call.operator,
+                    call.approved,
+                ));
+            }
         }
 
         None
@@ -281,4 +291,21 @@ mod tests {
         // The missing value side renders the placeholder rather than panicking.
         assert!(json.contains("id 10 -> amount (none)"), "got: {json}");
     }
+
+    #[test]
+    fn test_decode_set_approval_for_all_is_flagged() {
+        let call = IERC1155::setApprovalForAllCall {
+            operator: [0x77u8; 20].into(),
+            approved: true,
+        };
+        let input = IERC1155::setApprovalForAllCall::abi_encode(&call);
+
+        let field = ERC1155Visualizer
+            .visualize_tx_commands(&input)
+            .expect("Expected PreviewLayout");
+
+        let json = serde_json::to_string(&field).expect("serializable");
+        assert!(json.contains("NFT Approval For All"), "got: {json}");
+        assert!(json.contains("High risk"), "got: {json}");
+    }
 }
@@ -1,10 +1,16 @@
+use alloy_primitives::{Address, U256};
 use alloy_sol_types::{SolCall, sol};
 use visualsign::{
     AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
     SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
-    SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldStaticAnnotation,
+    SignablePayloadFieldTextV2,
+    registry::{EntryOrigin, LayeredRegistry},
 };
 
+use super::approval_risk;
+use crate::registry::ContractRegistry;
+
 sol! {
     interface IERC20 {
         function name() external view returns (string memory);
@@ -16,6 +22,11 @@ sol! {
         function allowance(address owner, address spender) external view returns (uint256);
         function approve(address spender, uint256 amount) external returns (bool);
         function transferFrom(address from, address to, uint256 amount) external returns (bool);
+        // Not part of EIP-20, but implemented by OpenZeppelin-based tokens
+        function increaseAllowance(address spender, uint256 addedValue) external returns (bool);
+        function decreaseAllowance(address spender, uint256 subtractedValue) external returns (bool);
+        // EIP-2612
+        function permit(address owner, address spender, uint256 value, uint256 deadline, uint8 v, bytes32 r, bytes32 s) external;
 
         event Transfer(address indexed from, address indexed to, uint256 value);
         event Approval(address indexed owner, address indexed spender, uint256 value);
@@ -23,8 +34,58 @@ sol! {
 }
 pub struct ERC20Visualizer {}
 
+/// The token an ERC-20 call is sent to
+///
+/// Lets the visualizer show amounts with the token's symbol and decimals and
+/// check approval spenders against the built-in registry.
+pub struct Erc20Token<'a> {
+    pub chain_id: u64,
+    pub address: Address,
+    pub registry: &'a LayeredRegistry<ContractRegistry>,
+}
+
+impl Erc20Token<'_> {
+    /// Decimals change the value shown, so they go through the amount lookup,
+    /// which never lets wallet metadata override a built-in token and ignores
+    /// it entirely in strict mode. Also returns where the metadata came from.
+    fn format_amount(&self, amount: U256) -> Option<((String, String), EntryOrigin)> {
+        self.registry.lookup_amount_data_with_origin(
+            |r| r.format_token_amount_u256(self.chain_id, self.address, amount),
+            |r| {
+                r.get_token_origin(self.chain_id, self.address)
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Only the built-in registry vouches for a spender (see [`approval_risk`])
+    fn is_known_spender(&self, spender: Address) -> bool {
+        approval_risk::is_known_spender(self.registry.global(), self.chain_id, spender)
+    }
+}
+
 impl ERC20Visualizer {
     pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        self.visualize(input, None)
+    }
+
+    /// Same as [`Self::visualize_tx_commands`], but amounts use the token's
+    /// symbol and decimals when the registry knows it, and approvals also warn
+    /// when the spender is not a known contract. Without a token only raw
+    /// amounts are shown and only unlimited approvals are flagged.
+    pub fn visualize_for_token(
+        &self,
+        input: &[u8],
+        token: &Erc20Token<'_>,
+    ) -> Option<SignablePayloadField> {
+        self.visualize(input, Some(token))
+    }
+
+    fn visualize(
+        &self,
+        input: &[u8],
+        token: Option<&Erc20Token<'_>>,
+    ) -> Option<SignablePayloadField> {
         if input.len() < 4 {
             return None;
         }
@@ -32,204 +93,108 @@ impl ERC20Visualizer {
         if selector == IERC20::transferCall::SELECTOR {
             // transfer(address,uint256)
             if let Ok(call) = IERC20::transferCall::abi_decode(input) {
-                let mut details = Vec::new();
-                // To Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.to),
-                            label: "Recipient".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.to),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                // Amount
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AmountV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: call.amount.to_string(),
-                            label: "Amount".to_string(),
-                        },
-                        amount_v2: SignablePayloadFieldAmountV2 {
-                            amount: call.amount.to_string(),
-                            abbreviation: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                return Some(SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: format!("Transfer {} tokens to {:?}", call.amount, call.to),
-                        label: "ERC20 Transfer".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 Transfer".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: format!("Transfer {} tokens to {:?}", call.amount, call.to),
-                        }),
-                        condensed: None,
-                        expanded: Some(SignablePayloadFieldListLayout { fields: details }),
-                    },
-                });
+                let amount = TokenAmount::new(call.amount, token);
+                let subtitle = format!("Transfer {} to {:?}", amount.describe(), call.to);
+                let details = vec![address_field("Recipient", call.to), amount.field("Amount")];
+                return Some(preview("ERC20 Transfer", subtitle, details));
             }
         } else if selector == IERC20::transferFromCall::SELECTOR {
             // transferFrom(address,address,uint256)
             if let Ok(call) = IERC20::transferFromCall::abi_decode(input) {
-                let mut details = Vec::new();
-
-                // From Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.from),
-                            label: "Sender".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.from),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                // To Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.to),
-                            label: "Recipient".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.to),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                // Amount
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AmountV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: call.amount.to_string(),
-                            label: "Amount".to_string(),
-                        },
-                        amount_v2: SignablePayloadFieldAmountV2 {
-                            amount: call.amount.to_string(),
-                            abbreviation: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                let preview = SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: format!(
-                            "Transfer {} tokens from {:?} to {:?}",
-                            call.amount, call.from, call.to
-                        ),
-                        label: "ERC20 TransferFrom".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 TransferFrom".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: format!(
-                                "Transfer {} tokens from {:?} to {:?}",
-                                call.amount, call.from, call.to
-                            ),
-                        }),
-                        condensed: None,
-                        expanded: Some(SignablePayloadFieldListLayout { fields: details }),
-                    },
-                };
-                return Some(preview);
+                let amount = TokenAmount::new(call.amount, token);
+                let subtitle = format!(
+                    "Transfer {} from {:?} to {:?}",
+                    amount.describe(),
+                    call.from,
+                    call.to
+                );
+                let details = vec![
+                    address_field("Sender", call.from),
+                    address_field("Recipient", call.to),
+                    amount.field("Amount"),
+                ];
+                return Some(preview("ERC20 TransferFrom", subtitle, details));
             }
         } else if selector == IERC20::approveCall::SELECTOR {
             // approve(address,uint256)
             if let Ok(call) = IERC20::approveCall::abi_decode(input) {
-                let mut details = Vec::new();
-
-                // Spender Address
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AddressV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{:?}", call.spender),
-                            label: "Spender".to_string(),
-                        },
-                        address_v2: SignablePayloadFieldAddressV2 {
-                            address: format!("{:?}", call.spender),
-                            name: "".to_string(),
-                            memo: None,
-                            asset_label: "".to_string(),
-                            badge_text: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                // Amount
-                details.push(AnnotatedPayloadField {
-                    signable_payload_field: SignablePayloadField::AmountV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: call.amount.to_string(),
-                            label: "Amount".to_string(),
-                        },
-                        amount_v2: SignablePayloadFieldAmountV2 {
-                            amount: call.amount.to_string(),
-                            abbreviation: None,
-                        },
-                    },
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                });
-
-                let preview = SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: format!(
-                            "Approve {:?} to spend {} tokens",
-                            call.spender, call.amount
-                        ),
-                        label: "ERC20 Approve".to_string(),
-                    },
-                    preview_layout: SignablePayloadFieldPreviewLayout {
-                        title: Some(SignablePayloadFieldTextV2 {
-                            text: "ERC20 Approve".to_string(),
-                        }),
-                        subtitle: Some(SignablePayloadFieldTextV2 {
-                            text: format!(
-                                "Approve {:?} to spend {} tokens",
-                                call.spender, call.amount
-                            ),
-                        }),
-                        condensed: None,
-                        expanded: Some(SignablePayloadFieldListLayout { fields: details }),
-                    },
-                };
+                let amount = TokenAmount::new(call.amount, token);
+                let subtitle = format!("Approve {:?} to spend {}", call.spender, amount.describe());
+                let mut preview = preview(
+                    "ERC20 Approve",
+                    subtitle,
+                    vec![
+                        address_field("Spender", call.spender),
+                        amount.field("Amount"),
+                    ],
+                );
+                // Approving zero revokes the allowance
+                if !call.amount.is_zero() {
+                    add_approval_warning(&mut preview, call.spender, call.amount, token);
+                }
+                return Some(preview);
+            }
+        } else if selector == IERC20::increaseAllowanceCall::SELECTOR {
+            // increaseAllowance(address,uint256)
+            if let Ok(call) = IERC20::increaseAllowanceCall::abi_decode(input) {
+                let amount = TokenAmount::new(call.addedValue, token);
+                let subtitle = format!(
+                    "Increase allowance of {:?} by {}",
+                    call.spender,
+                    amount.describe()
+                );
+                let mut preview = preview(
+                    "ERC20 Increase Allowance",
+                    subtitle,
+                    vec![
+                        address_field("Spender", call.spender),
+                        amount.field("Amount"),
+                    ],
+                );
+                if !call.addedValue.is_zero() {
+                    add_approval_warning(&mut preview, call.spender, call.addedValue, token);
+                }
+                return Some(preview);
+            }
+        } else if selector == IERC20::decreaseAllowanceCall::SELECTOR {
+            // decreaseAllowance(address,uint256); lowering an allowance needs no warning
+            if let Ok(call) = IERC20::decreaseAllowanceCall::abi_decode(input) {
+                let amount = TokenAmount::new(call.subtractedValue, token);
+                let subtitle = format!(
+                    "Decrease allowance of {:?} by {}",
+                    call.spender,
+                    amount.describe()
+                );
+                return Some(preview(
+                    "ERC20 Decrease Allowance",
+                    subtitle,
+                    vec![
+                        address_field("Spender", call.spender),
+                        amount.field("Amount"),
+                    ],
+                ));
+            }
+        } else if selector == IERC20::permitCall::SELECTOR {
+            // permit(address,address,uint256,uint256,uint8,bytes32,bytes32): submits an
+            // EIP-2612 signature, setting the owner's allowance like `approve`
+            if let Ok(call) = IERC20::permitCall::abi_decode(input) {
+                let amount = TokenAmount::new(call.value, token);
+                let subtitle = format!(
+                    "Allow {:?} to spend {} from {:?}",
+                    call.spender,
+                    amount.describe(),
+                    call.owner
+                );
+                let details = vec![
+                    address_field("Owner", call.owner),
+                    address_field("Spender", call.spender),
+                    amount.field("Amount"),
+                    crate::eip712::deadline_row("Deadline", call.deadline),
+                ];
+                let mut preview = preview("ERC20 Permit", subtitle, details);
+                if !call.value.is_zero() {
+                    add_approval_warning(&mut preview, call.spender, call.value, token);
+                }
                 return Some(preview);
             }
         } else if selector == IERC20::balanceOfCall::SELECTOR {
@@ -427,6 +392,118 @@ impl ERC20Visualizer {
     }
 }
 
+/// An amount as shown to the signer: scaled by the token's decimals when the
+/// registry knows the token, raw units otherwise
+struct TokenAmount {
+    text: String,
+    symbol: Option<String>,
+    /// Set when the symbol and decimals come from unverified metadata
+    note: Option<String>,
+}
+
+impl TokenAmount {
+    fn new(amount: U256, token: Option<&Erc20Token<'_>>) -> Self {
+        match token.and_then(|token| token.format_amount(amount)) {
+            Some(((text, symbol), origin)) => Self {
+                text,
+                symbol: Some(symbol),
+                note: origin.annotation("symbol and decimals"),
+            },
+            None => Self {
+                text: amount.to_string(),
+                symbol: None,
+                note: None,
+            },
+        }
+    }
+
+    /// For subtitles: "1.5 USDC", or "1500000 tokens" for an unknown token
+    fn describe(&self) -> String {
+        format!(
+            "{} {}",
+            self.text,
+            self.symbol.as_deref().unwrap_or("tokens")
+        )
+    }
+
+    fn field(&self, label: &str) -> AnnotatedPayloadField {
+        let fallback_text = match &self.symbol {
+            Some(symbol) => format!("{} {symbol}", self.text),
+            None => self.text.clone(),
+        };
+        AnnotatedPayloadField {
+            signable_payload_field: SignablePayloadField::AmountV2 {
+                common: SignablePayloadFieldCommon {
+                    fallback_text,
+                    label: label.to_string(),
+                },
+                amount_v2: SignablePayloadFieldAmountV2 {
+                    amount: self.text.clone(),
+                    abbreviation: self.symbol.clone(),
+                },
+            },
+            static_annotation: self
+                .note
+                .as_ref()
+                .map(|text| SignablePayloadFieldStaticAnnotation { text: text.clone() }),
+            dynamic_annotation: None,
+        }
+    }
+}
+
+fn address_field(label: &str, address: Address) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: None,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn preview(
+    title: &str,
+    subtitle: String,
+    details: Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text: subtitle.clone(),
+            label: title.to_string(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title.to_string(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+            condensed: None,
+            expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+        },
+    }
+}
+
+fn add_approval_warning(
+    preview: &mut SignablePayloadField,
+    spender: Address,
+    amount: U256,
+    token: Option<&Erc20Token<'_>>,
+) {
+    let known_spender = token.map(|token| token.is_known_spender(spender));
+    if let Some(warning) = approval_risk::approval_warning(amount == U256::MAX, known_spender) {
+        approval_risk::attach_warning(preview, warning);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -886,4 +963,194 @@ mod tests {
         let actual = ERC20Visualizer {}.visualize_tx_commands(input);
         assert!(actual.is_none());
     }
+
+    const USDC: Address = alloy_primitives::address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+
+    fn default_registry() -> LayeredRegistry<ContractRegistry> {
+        let (registry, _) = ContractRegistry::with_default_protocols();
+        LayeredRegistry::new(std::sync::Arc::new(registry))
+    }
+
+    #[test]
+    fn test_approval_warnings_with_registry() {
+        let registry = default_registry();
+        let token = Erc20Token {
+            chain_id: 1,
+            address: Address::repeat_byte(0x99),
+            registry: &registry,
+        };
+        let unknown = Address::repeat_byte(0x44);
+        let permit2 = crate::protocols::uniswap::config::UniswapConfig::permit2_address();
+        let approve = |spender, amount| {
+            let input = IERC20::approveCall { spender, amount }.abi_encode();
+            ERC20Visualizer {}
+                .visualize_for_token(&input, &token)
+                .expect("approve should decode")
+        };
+
+        let SignablePayloadField::PreviewLayout {
+            common,
+            preview_layout,
+        } = approve(unknown, U256::MAX)
+        else {
+            panic!("expected PreviewLayout");
+        };
+        assert!(
+            common
+                .fallback_text
+                .contains("WARNING: Unlimited approval to unknown contract")
+        );
+        let condensed = preview_layout.condensed.expect("warning in condensed view");
+        assert_eq!(
+            condensed.fields[0].signable_payload_field.label(),
+            "Warning"
+        );
+        assert_eq!(
+            condensed.fields[0]
+                .static_annotation
+                .as_ref()
+                .map(|a| a.text.as_str()),
+            Some("High risk")
+        );
+
+        assert!(
+            approve(unknown, U256::from(5u64))
+                .fallback_text()
+                .contains("WARNING: Approval to unknown contract")
+        );
+        // A bounded approval to a registered contract and a revocation are quiet
+        assert!(
+            !approve(permit2, U256::from(5u64))
+                .fallback_text()
+                .contains("WARNING")
+        );
+        assert!(
+            !approve(unknown, U256::ZERO)
+                .fallback_text()
+                .contains("WARNING")
+        );
+    }
+
+    #[test]
+    fn test_decode_increase_allowance() {
+        let input = IERC20::increaseAllowanceCall {
+            spender: Address::repeat_byte(0x44),
+            addedValue: U256::MAX,
+        }
+        .abi_encode();
+
+        // Without a registry only the unlimited amount is flagged
+        let field = ERC20Visualizer {}
+            .visualize_tx_commands(&input)
+            .expect("increaseAllowance should decode");
+        assert_eq!(field.label(), "ERC20 Increase Allowance");
+        assert!(
+            field
+                .fallback_text()
+                .contains("WARNING: Unlimited approval.")
+        );
+        assert!(!field.fallback_text().contains("unknown contract"));
+    }
+
+    #[test]
+    fn test_known_token_amounts_use_symbol_and_decimals() {
+        let registry = default_registry();
+        let usdc = Erc20Token {
+            chain_id: 1,
+            address: USDC,
+            registry: &registry,
+        };
+        let input = IERC20::transferFromCall {
+            from: Address::repeat_byte(0x22),
+            to: Address::repeat_byte(0x33),
+            amount: U256::from(1_500_000u64),
+        }
+        .abi_encode();
+        let field = ERC20Visualizer {}
+            .visualize_for_token(&input, &usdc)
+            .expect("transferFrom should decode");
+        assert!(
+            field
+                .fallback_text()
+                .starts_with("Transfer 1.500000 USDC from"),
+            "got: {}",
+            field.fallback_text()
+        );
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(json.contains(r#""Abbreviation":"USDC""#), "got: {json}");
+
+        // The same token address on another chain is unknown
+        let elsewhere = Erc20Token {
+            chain_id: 999_999,
+            ..usdc
+        };
+        let field = ERC20Visualizer {}
+            .visualize_for_token(&input, &elsewhere)
+            .unwrap();
+        assert!(
+            field
+                .fallback_text()
+                .starts_with("Transfer 1500000 tokens from")
+        );
+    }
+
+    #[test]
+    fn test_decode_decrease_allowance_is_quiet() {
+        let input = IERC20::decreaseAllowanceCall {
+            spender: Address::repeat_byte(0x44),
+            subtractedValue: U256::from(2_000_000u64),
+        }
+        .abi_encode();
+        let registry = default_registry();
+        let field = ERC20Visualizer {}
+            .visualize_for_token(
+                &input,
+                &Erc20Token {
+                    chain_id: 1,
+                    address: USDC,
+                    registry: &registry,
+                },
+            )
+            .expect("decreaseAllowance should decode");
+        assert_eq!(field.label(), "ERC20 Decrease Allowance");
+        assert_eq!(
+            field.fallback_text(),
+            &format!(
+                "Decrease allowance of {:?} by 2.000000 USDC",
+                Address::repeat_byte(0x44)
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_permit() {
+        let input = IERC20::permitCall {
+            owner: Address::repeat_byte(0x11),
+            spender: Address::repeat_byte(0x44),
+            value: U256::MAX,
+            deadline: U256::from(1_735_689_600u64),
+            v: 27,
+            r: Default::default(),
+            s: Default::default(),
+        }
+        .abi_encode();
+        let field = ERC20Visualizer {}
+            .visualize_tx_commands(&input)
+            .expect("permit should decode");
+        assert_eq!(field.label(), "ERC20 Permit");
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(
+            json.contains(
+                r#""DateTimeV2":{"RenderHint":"date_time","TimeZone":"UTC","Timestamp":1735689600}"#
+            ),
+            "got: {json}"
+        );
+        assert!(
+            field
+                .fallback_text()
+                .contains("WARNING: Unlimited approval."),
+            "got: {}",
+            field.fallback_text()
+        );
+    }
 }
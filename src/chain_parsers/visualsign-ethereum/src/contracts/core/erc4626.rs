@@ -0,0 +1,564 @@
+//! ERC-4626 Tokenized Vault Standard Visualizer
+//!
+//! Provides visualization for the vault entry and exit functions (`deposit`,
+//! `mint`, `withdraw`, `redeem`). The vault's underlying asset is resolved from
+//! the registry (see [`ContractRegistry::register_vault`]) so asset amounts are
+//! shown in asset units; share amounts use the vault's own token metadata when
+//! it is registered. Besides the built-in vaults below, wallets can describe
+//! their own in `EthereumMetadata.erc4626_vaults`.
+//!
+//! Reference: <https://eips.ethereum.org/EIPS/eip-4626>
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::registry::{EntryOrigin, LayeredRegistry};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldStaticAnnotation,
+    SignablePayloadFieldTextV2,
+};
+
+use crate::registry::{ContractRegistry, ContractType};
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+// ERC-4626 entry/exit interface
+sol! {
+    interface IERC4626 {
+        function asset() external view returns (address assetTokenAddress);
+        function deposit(uint256 assets, address receiver) external returns (uint256 shares);
+        function mint(uint256 shares, address receiver) external returns (uint256 assets);
+        function withdraw(uint256 assets, address receiver, address owner) external returns (uint256 shares);
+        function redeem(uint256 shares, address receiver, address owner) external returns (uint256 assets);
+    }
+}
+
+/// Contract type marker for ERC-4626 vaults
+#[derive(Debug, Clone, Copy)]
+pub struct Erc4626Vault;
+
+impl ContractType for Erc4626Vault {}
+
+/// Static description of a well-known ERC-4626 vault deployment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VaultInfo {
+    /// Vault (share token) contract address
+    pub vault: &'static str,
+    /// Underlying asset returned by `asset()`
+    pub asset: &'static str,
+}
+
+/// Returns the built-in ERC-4626 vaults for a chain
+///
+/// Deployments outside this table can be added at runtime with
+/// [`ContractRegistry::register_vault`], or per request through wallet
+/// metadata (see [`ContractRegistry::load_wallet_vaults`]).
+pub fn known_vaults(chain_id: u64) -> &'static [VaultInfo] {
+    match chain_id {
+        networks::ethereum::MAINNET => &[
+            // Maker/Sky Savings DAI (sDAI) over DAI
+            VaultInfo {
+                vault: "0x83F20F44975D03b1b09e64809B757c47f942BEeA",
+                asset: "0x6B175474E89094C44Da98b954EedeAC495271d0F",
+            },
+            // Ethena Staked USDe (sUSDe) over USDe
+            VaultInfo {
+                vault: "0x9D39A5DE30e57443BfF2A8307A4256c8797A3497",
+                asset: "0x4c9EDD5852cd905f086C759E8383e09bff1E68B3",
+            },
+        ],
+        _ => &[],
+    }
+}
+
+/// Registers the built-in vaults and the ERC-4626 contract visualizer
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut crate::visualizer::EthereumVisualizerRegistryBuilder,
+) {
+    let chain_id = networks::ethereum::MAINNET;
+    for info in known_vaults(chain_id) {
+        if let (Ok(vault), Ok(asset)) = (info.vault.parse(), info.asset.parse()) {
+            contract_reg.register_vault(chain_id, vault, asset);
+        }
+    }
+
+    visualizer_reg.register(Box::new(ERC4626ContractVisualizer::new()));
+}
+
+/// Visualizer for ERC-4626 vault calls
+pub struct ERC4626Visualizer;
+
+impl ERC4626Visualizer {
+    /// Attempts to decode and visualize an ERC-4626 vault call
+    ///
+    /// # Arguments
+    /// * `input` - The calldata bytes (with 4-byte function selector)
+    /// * `chain_id` - The chain ID used for registry lookups
+    /// * `vault` - The vault contract address (the transaction destination)
+    /// * `registry` - Registry used to resolve the underlying asset and decimals;
+    ///   the built-in layer answers first, so wallet metadata only fills gaps
+    ///
+    /// # Returns
+    /// * `Some(field)` for `deposit`, `mint`, `withdraw` and `redeem`
+    /// * `None` if the input doesn't match one of them
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: u64,
+        vault: Address,
+        registry: Option<&LayeredRegistry<ContractRegistry>>,
+    ) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+
+        let ctx = VaultContext::resolve(chain_id, vault, registry);
+        let selector = &input[..4];
+        if selector == IERC4626::depositCall::SELECTOR {
+            let call = IERC4626::depositCall::abi_decode(input).ok()?;
+            let assets = ctx.asset_amount(call.assets);
+            let subtitle = format!("Deposit {assets} into vault, shares to {:?}", call.receiver);
+            return Some(ctx.render(
+                "ERC4626 Deposit",
+                subtitle,
+                amount_row("Assets", call.assets, &ctx.asset_units),
+                call.receiver,
+                None,
+            ));
+        }
+        if selector == IERC4626::mintCall::SELECTOR {
+            let call = IERC4626::mintCall::abi_decode(input).ok()?;
+            let shares = ctx.share_amount(call.shares);
+            let subtitle = format!("Mint {shares} vault shares to {:?}", call.receiver);
+            return Some(ctx.render(
+                "ERC4626 Mint",
+                subtitle,
+                amount_row("Shares", call.shares, &ctx.share_units),
+                call.receiver,
+                None,
+            ));
+        }
+        if selector == IERC4626::withdrawCall::SELECTOR {
+            let call = IERC4626::withdrawCall::abi_decode(input).ok()?;
+            let assets = ctx.asset_amount(call.assets);
+            let subtitle = format!("Withdraw {assets} from vault to {:?}", call.receiver);
+            return Some(ctx.render(
+                "ERC4626 Withdraw",
+                subtitle,
+                amount_row("Assets", call.assets, &ctx.asset_units),
+                call.receiver,
+                Some(call.owner),
+            ));
+        }
+        if selector == IERC4626::redeemCall::SELECTOR {
+            let call = IERC4626::redeemCall::abi_decode(input).ok()?;
+            let shares = ctx.share_amount(call.shares);
+            let subtitle = format!("Redeem {shares} vault shares to {:?}", call.receiver);
+            return Some(ctx.render(
+                "ERC4626 Redeem",
+                subtitle,
+                amount_row("Shares", call.shares, &ctx.share_units),
+                call.receiver,
+                Some(call.owner),
+            ));
+        }
+
+        None
+    }
+}
+
+/// Display units for an amount: (decimals, symbol), when known
+type Units = Option<(u8, String)>;
+
+/// Registry-derived facts about the vault being called
+struct VaultContext {
+    vault: Address,
+    vault_symbol: Option<String>,
+    vault_note: Option<String>,
+    asset: Option<Address>,
+    asset_symbol: Option<String>,
+    asset_note: Option<String>,
+    asset_units: Units,
+    share_units: Units,
+}
+
+impl VaultContext {
+    fn resolve(
+        chain_id: u64,
+        vault: Address,
+        registry: Option<&LayeredRegistry<ContractRegistry>>,
+    ) -> Self {
+        // Decimals and symbols scale the amounts, so they go through the amount
+        // lookup: built-in entries win and unverified ones carry a note.
+        let units = |token: Address| -> (Units, Option<String>) {
+            let found = registry.and_then(|registry| {
+                registry.lookup_amount_data_with_origin(
+                    |r| {
+                        Some((
+                            r.get_token_decimals(chain_id, token)?,
+                            r.get_token_symbol(chain_id, token)?,
+                        ))
+                    },
+                    |r| r.get_token_origin(chain_id, token).unwrap_or_default(),
+                )
+            });
+            match found {
+                Some((units, origin)) => (Some(units), origin.annotation("symbol and decimals")),
+                None => (None, None),
+            }
+        };
+
+        let asset = registry.and_then(|registry| {
+            registry.lookup_amount_data_with_origin(
+                |r| r.get_vault_asset(chain_id, vault),
+                |_| EntryOrigin::built_in(),
+            )
+        });
+        let (share_units, vault_note) = units(vault);
+        let (asset_units, units_note) = match asset {
+            Some((asset, _)) => units(asset),
+            None => (None, None),
+        };
+        let asset_note = asset
+            .and_then(|(_, origin)| origin.annotation("vault asset"))
+            .into_iter()
+            .chain(units_note)
+            .reduce(|a, b| format!("{a}; {b}"));
+        Self {
+            vault,
+            vault_symbol: share_units.as_ref().map(|(_, s)| s.clone()),
+            vault_note,
+            asset: asset.map(|(asset, _)| asset),
+            asset_symbol: asset_units.as_ref().map(|(_, s)| s.clone()),
+            asset_note,
+            asset_units,
+            share_units,
+        }
+    }
+
+    fn asset_amount(&self, raw: U256) -> String {
+        format_with_units(raw, &self.asset_units)
+    }
+
+    fn share_amount(&self, raw: U256) -> String {
+        format_with_units(raw, &self.share_units)
+    }
+
+    fn render(
+        &self,
+        title: &str,
+        subtitle: String,
+        amount: AnnotatedPayloadField,
+        receiver: Address,
+        owner: Option<Address>,
+    ) -> SignablePayloadField {
+        let mut details = vec![annotated(
+            address_row("Vault", &self.vault, self.vault_symbol.as_deref()),
+            &self.vault_note,
+        )];
+        match self.asset {
+            Some(asset) => details.push(annotated(
+                address_row("Asset", &asset, self.asset_symbol.as_deref()),
+                &self.asset_note,
+            )),
+            None => details.push(text_row("Asset", "Unknown (vault not registered)")),
+        }
+        details.push(amount);
+        details.push(address_row("Receiver", &receiver, None));
+        if let Some(owner) = owner {
+            details.push(address_row("Owner", &owner, None));
+        }
+
+        SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: subtitle.clone(),
+                label: title.to_string(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 {
+                    text: title.to_string(),
+                }),
+                subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+                condensed: None,
+                expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+            },
+        }
+    }
+}
+
+fn format_with_units(raw: U256, units: &Units) -> String {
+    match units {
+        Some((decimals, symbol)) => match alloy_primitives::utils::format_units(raw, *decimals) {
+            Ok(amount) => format!("{amount} {symbol}"),
+            Err(_) => format!("{raw} raw units"),
+        },
+        None => format!("{raw} raw units"),
+    }
+}
+
+fn amount_row(label: &str, raw: U256, units: &Units) -> AnnotatedPayloadField {
+    let (amount, abbreviation) = match units {
+        Some((decimals, symbol)) => match alloy_primitives::utils::format_units(raw, *decimals) {
+            Ok(amount) => (amount, Some(symbol.clone())),
+            Err(_) => (raw.to_string(), None),
+        },
+        None => (raw.to_string(), None),
+    };
+    let fallback_text = match &abbreviation {
+        Some(symbol) => format!("{amount} {symbol}"),
+        None => amount.clone(),
+    };
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AmountV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text,
+                label: label.to_string(),
+            },
+            amount_v2: SignablePayloadFieldAmountV2 {
+                amount,
+                abbreviation,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn annotated(mut row: AnnotatedPayloadField, note: &Option<String>) -> AnnotatedPayloadField {
+    row.static_annotation = note
+        .as_ref()
+        .map(|text| SignablePayloadFieldStaticAnnotation { text: text.clone() });
+    row
+}
+
+fn address_row(label: &str, address: &Address, name: Option<&str>) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: name.unwrap_or_default().to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: None,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: &str) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.to_string(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 {
+                text: text.to_string(),
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// ContractVisualizer implementation for ERC-4626 vaults
+pub struct ERC4626ContractVisualizer {
+    inner: ERC4626Visualizer,
+}
+
+impl ERC4626ContractVisualizer {
+    pub fn new() -> Self {
+        Self {
+            inner: ERC4626Visualizer,
+        }
+    }
+}
+
+impl Default for ERC4626ContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for ERC4626ContractVisualizer {
+    fn contract_type(&self) -> &str {
+        Erc4626Vault::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        Ok(self
+            .inner
+            .visualize_tx_commands(
+                &context.calldata,
+                context.chain_id,
+                context.current_contract,
+                None,
+            )
+            .map(|field| {
+                vec![AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }]
+            }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::token_metadata::{ErcStandard, TokenMetadata};
+
+    fn vault() -> Address {
+        Address::repeat_byte(0x46)
+    }
+
+    fn asset() -> Address {
+        Address::repeat_byte(0xa5)
+    }
+
+    fn layered(registry: ContractRegistry) -> LayeredRegistry<ContractRegistry> {
+        LayeredRegistry::new(std::sync::Arc::new(registry))
+    }
+
+    fn registry() -> ContractRegistry {
+        let mut registry = ContractRegistry::new();
+        registry.register_vault(1, vault(), asset());
+        for (address, symbol) in [(asset(), "USDC"), (vault(), "vUSDC")] {
+            registry
+                .register_token(
+                    1,
+                    TokenMetadata {
+                        symbol: symbol.to_string(),
+                        name: symbol.to_string(),
+                        erc_standard: ErcStandard::Erc20,
+                        contract_address: format!("{address:?}"),
+                        decimals: 6,
+                    },
+                )
+                .unwrap();
+        }
+        registry
+    }
+
+    #[test]
+    fn test_known_vaults_parse() {
+        for info in known_vaults(networks::ethereum::MAINNET) {
+            assert!(info.vault.parse::<Address>().is_ok(), "{}", info.vault);
+            assert!(info.asset.parse::<Address>().is_ok(), "{}", info.asset);
+        }
+    }
+
+    #[test]
+    fn test_deposit_resolves_underlying_asset() {
+        let input = IERC4626::depositCall {
+            assets: U256::from(2_500_000u64),
+            receiver: Address::repeat_byte(0x11),
+        }
+        .abi_encode();
+
+        let field = ERC4626Visualizer
+            .visualize_tx_commands(&input, 1, vault(), Some(&layered(registry())))
+            .unwrap();
+        assert_eq!(field.label(), "ERC4626 Deposit");
+        assert!(field.fallback_text().contains("Deposit 2.500000 USDC"));
+
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(json.contains(&format!("{:?}", asset())), "got: {json}");
+        assert!(json.contains("vUSDC"), "got: {json}");
+    }
+
+    #[test]
+    fn test_redeem_shows_shares_and_owner() {
+        let owner = Address::repeat_byte(0x22);
+        let input = IERC4626::redeemCall {
+            shares: U256::from(1_000_000u64),
+            receiver: Address::repeat_byte(0x11),
+            owner,
+        }
+        .abi_encode();
+
+        let field = ERC4626Visualizer
+            .visualize_tx_commands(&input, 1, vault(), Some(&layered(registry())))
+            .unwrap();
+        assert_eq!(field.label(), "ERC4626 Redeem");
+        assert!(field.fallback_text().contains("Redeem 1.000000 vUSDC"));
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(json.contains(&format!("{owner:?}")), "got: {json}");
+    }
+
+    #[test]
+    fn test_wallet_metadata_symbols_are_annotated() {
+        use visualsign::registry::{EntryOrigin, Provenance};
+
+        let mut registry = ContractRegistry::new();
+        registry.register_vault(1, vault(), asset());
+        registry
+            .register_token_with_origin(
+                1,
+                TokenMetadata {
+                    symbol: "USDC".to_string(),
+                    name: "USDC".to_string(),
+                    erc_standard: ErcStandard::Erc20,
+                    contract_address: format!("{:?}", asset()),
+                    decimals: 6,
+                },
+                EntryOrigin::new(Provenance::WalletMetadata),
+            )
+            .unwrap();
+        let input = IERC4626::depositCall {
+            assets: U256::from(1u64),
+            receiver: Address::repeat_byte(0x11),
+        }
+        .abi_encode();
+
+        let field = ERC4626Visualizer
+            .visualize_tx_commands(&input, 1, vault(), Some(&layered(registry)))
+            .unwrap();
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(
+            json.contains("symbol and decimals from unverified wallet metadata"),
+            "got: {json}"
+        );
+    }
+
+    #[test]
+    fn test_unregistered_vault_renders_raw_units() {
+        let input = IERC4626::withdrawCall {
+            assets: U256::from(42u64),
+            receiver: Address::repeat_byte(0x11),
+            owner: Address::repeat_byte(0x11),
+        }
+        .abi_encode();
+
+        let field = ERC4626Visualizer
+            .visualize_tx_commands(&input, 1, vault(), None)
+            .unwrap();
+        assert!(field.fallback_text().contains("42 raw units"));
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(json.contains("vault not registered"), "got: {json}");
+    }
+
+    #[test]
+    fn test_non_vault_selector_returns_none() {
+        let input = IERC4626::assetCall {}.abi_encode();
+        assert!(
+            ERC4626Visualizer
+                .visualize_tx_commands(&input, 1, vault(), None)
+                .is_none()
+        );
+    }
+}
@@ -4,10 +4,13 @@
 //!
 //! Reference: <https://eips.ethereum.org/EIPS/eip-721>
 
-#![allow(unused_imports)]
-
+use alloy_primitives::{Address, U256};
 use alloy_sol_types::{SolCall, sol};
-use visualsign::{SignablePayloadField, SignablePayloadFieldCommon, SignablePayloadFieldTextV2};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldCommon, SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
+    SignablePayloadFieldStaticAnnotation, SignablePayloadFieldTextV2,
+};
 
 // ERC-721 interface
 sol! {
@@ -24,12 +27,19 @@ sol! {
     }
 }
 
+/// Warning shown when an operator is granted control over a whole collection
+pub const APPROVAL_FOR_ALL_WARNING: &str = "The operator can transfer every token you own in this collection, including ones you acquire later, until the approval is revoked.";
+
 /// Visualizer for ERC-721 NFT contract calls
 pub struct ERC721Visualizer;
 
 impl ERC721Visualizer {
     /// Attempts to decode and visualize ERC-721 function calls
     ///
+    /// `transferFrom` and `approve` share their selectors with ERC20, so this
+    /// must only be used when the destination is known to be an ERC-721
+    /// contract. For unknown destinations use [`Self::visualize_unambiguous`].
+    ///
     /// # Arguments
     /// * `input` - The calldata bytes
     ///
@@ -41,20 +51,195 @@ impl ERC721Visualizer {
             return None;
         }
 
-        // TODO: Implement ERC-721 function decoding
-        // - transferFrom(address,address,uint256)
-        // - safeTransferFrom variants
-        // - approve(address,uint256)
-        // - setApprovalForAll(address,bool)
-        //
-        // For now, return None to use fallback visualizer
+        let selector = &input[..4];
+        if selector == IERC721::transferFromCall::SELECTOR {
+            let call = IERC721::transferFromCall::abi_decode(input).ok()?;
+            return Some(Self::render_transfer(call.from, call.to, call.tokenId));
+        }
+        if selector == IERC721::approveCall::SELECTOR {
+            let call = IERC721::approveCall::abi_decode(input).ok()?;
+            return Some(Self::render_approve(call.to, call.tokenId));
+        }
+
+        self.visualize_unambiguous(input)
+    }
+
+    /// Decodes only the ERC-721 functions whose selectors do not collide with
+    /// ERC20: both `safeTransferFrom` overloads and `setApprovalForAll`.
+    ///
+    /// Safe to call on any destination, which is how NFT calls to contracts
+    /// outside the token registry are detected.
+    pub fn visualize_unambiguous(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+
+        let selector = &input[..4];
+        if selector == IERC721::safeTransferFrom_0Call::SELECTOR {
+            let call = IERC721::safeTransferFrom_0Call::abi_decode(input).ok()?;
+            return Some(Self::render_transfer(call.from, call.to, call.tokenId));
+        }
+        if selector == IERC721::safeTransferFrom_1Call::SELECTOR {
+            let call = IERC721::safeTransferFrom_1Call::abi_decode(input).ok()?;
+            return Some(Self::render_transfer(call.from, call.to, call.tokenId));
+        }
+        if selector == IERC721::setApprovalForAllCall::SELECTOR {
+            let call = IERC721::setApprovalForAllCall::abi_decode(input).ok()?;
+            return Some(render_set_approval_for_all(call.operator, call.approved));
+        }
+
         None
     }
+
+    fn render_transfer(from: Address, to: Address, token_id: U256) -> SignablePayloadField {
+        let subtitle = format!("Transfer token {token_id} from {from:?} to {to:?}");
+        preview(
+            "ERC721 Transfer",
+            subtitle,
+            None,
+            vec![
+                address_row("From", &from),
+                address_row("To", &to),
+                text_row("Token ID", token_id.to_string()),
+            ],
+        )
+    }
+
+    fn render_approve(approved: Address, token_id: U256) -> SignablePayloadField {
+        let subtitle = if approved == Address::ZERO {
+            format!("Clear approval for token {token_id}")
+        } else {
+            format!("Approve {approved:?} to transfer token {token_id}")
+        };
+        preview(
+            "ERC721 Approve",
+            subtitle,
+            None,
+            vec![
+                address_row("Approved", &approved),
+                text_row("Token ID", token_id.to_string()),
+            ],
+        )
+    }
+}
+
+/// Renders `setApprovalForAll(operator, approved)`.
+///
+/// The signature is shared by ERC-721 and ERC-1155 with the same meaning, so
+/// both visualizers use this. Granting (`approved == true`) carries a high-risk
+/// warning, surfaced in the condensed view as well as the expanded one.
+pub(crate) fn render_set_approval_for_all(
+    operator: Address,
+    approved: bool,
+) -> SignablePayloadField {
+    if !approved {
+        let subtitle = format!("Revoke operator {operator:?} for all tokens");
+        return preview(
+            "NFT Approval For All",
+            subtitle,
+            None,
+            vec![
+                address_row("Operator", &operator),
+                text_row("Approved", "false".to_string()),
+            ],
+        );
+    }
+
+    let warning = AnnotatedPayloadField {
+        static_annotation: Some(SignablePayloadFieldStaticAnnotation {
+            text: "High risk".to_string(),
+        }),
+        ..text_row("Warning", APPROVAL_FOR_ALL_WARNING.to_string())
+    };
+    let subtitle = format!("Approve {operator:?} to transfer ALL tokens in this collection");
+    let mut field = preview(
+        "NFT Approval For All",
+        subtitle,
+        Some(vec![warning.clone()]),
+        vec![
+            address_row("Operator", &operator),
+            text_row("Approved", "true".to_string()),
+            warning,
+        ],
+    );
+    if let SignablePayloadField::PreviewLayout { common, .. } = &mut field {
+        common.fallback_text = format!(
+            "{}. WARNING: {APPROVAL_FOR_ALL_WARNING}",
+            common.fallback_text
+        );
+    }
+    field
+}
+
+fn preview(
+    title: &str,
+    subtitle: String,
+    condensed: Option<Vec<AnnotatedPayloadField>>,
+    details: Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text: subtitle.clone(),
+            label: title.to_string(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title.to_string(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+            condensed: condensed.map(|fields| SignablePayloadFieldListLayout { fields }),
+            expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+        },
+    }
+}
+
+fn address_row(label: &str, address: &Address) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: None,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
+    use alloy_primitives::hex;
+
+    fn owner() -> Address {
+        [0x11u8; 20].into()
+    }
+
+    fn recipient() -> Address {
+        [0x22u8; 20].into()
+    }
 
     #[test]
     fn test_visualize_empty_input() {
@@ -68,5 +253,120 @@ mod tests {
         assert_eq!(visualizer.visualize_tx_commands(&[0x01, 0x02]), None);
     }
 
-    // TODO: Add tests for each ERC-721 function once implemented
+    #[test]
+    fn test_visualize_unknown_selector() {
+        let input = hex!("deadbeef01020304");
+        assert!(ERC721Visualizer.visualize_tx_commands(&input).is_none());
+    }
+
+    #[test]
+    fn test_decode_transfer_from() {
+        let input = IERC721::transferFromCall {
+            from: owner(),
+            to: recipient(),
+            tokenId: U256::from(7u64),
+        }
+        .abi_encode();
+
+        let field = ERC721Visualizer.visualize_tx_commands(&input).unwrap();
+        assert_eq!(field.label(), "ERC721 Transfer");
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Transfer token 7 from {:?} to {:?}", owner(), recipient())
+        );
+    }
+
+    #[test]
+    fn test_decode_safe_transfer_from_both_overloads() {
+        let plain = IERC721::safeTransferFrom_0Call {
+            from: owner(),
+            to: recipient(),
+            tokenId: U256::from(8u64),
+        }
+        .abi_encode();
+        let with_data = IERC721::safeTransferFrom_1Call {
+            from: owner(),
+            to: recipient(),
+            tokenId: U256::from(8u64),
+            data: vec![0xaa, 0xbb].into(),
+        }
+        .abi_encode();
+
+        for input in [plain, with_data] {
+            let field = ERC721Visualizer.visualize_unambiguous(&input).unwrap();
+            assert_eq!(field.label(), "ERC721 Transfer");
+            assert!(field.fallback_text().contains("token 8"));
+        }
+    }
+
+    #[test]
+    fn test_decode_approve_and_clear() {
+        let input = IERC721::approveCall {
+            to: recipient(),
+            tokenId: U256::from(9u64),
+        }
+        .abi_encode();
+        let field = ERC721Visualizer.visualize_tx_commands(&input).unwrap();
+        assert_eq!(field.label(), "ERC721 Approve");
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Approve {:?} to transfer token 9", recipient())
+        );
+
+        let input = IERC721::approveCall {
+            to: Address::ZERO,
+            tokenId: U256::from(9u64),
+        }
+        .abi_encode();
+        let field = ERC721Visualizer.visualize_tx_commands(&input).unwrap();
+        assert_eq!(field.fallback_text(), "Clear approval for token 9");
+    }
+
+    #[test]
+    fn test_set_approval_for_all_true_is_high_risk() {
+        let input = IERC721::setApprovalForAllCall {
+            operator: recipient(),
+            approved: true,
+        }
+        .abi_encode();
+
+        let field = ERC721Visualizer.visualize_unambiguous(&input).unwrap();
+        assert!(field.fallback_text().contains("WARNING"));
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected PreviewLayout");
+        };
+        let condensed = preview_layout.condensed.unwrap().fields;
+        assert_eq!(condensed.len(), 1);
+        assert_eq!(condensed[0].signable_payload_field.label(), "Warning");
+        assert_eq!(
+            condensed[0].static_annotation.as_ref().unwrap().text,
+            "High risk"
+        );
+    }
+
+    #[test]
+    fn test_set_approval_for_all_false_is_not_flagged() {
+        let input = IERC721::setApprovalForAllCall {
+            operator: recipient(),
+            approved: false,
+        }
+        .abi_encode();
+
+        let field = ERC721Visualizer.visualize_unambiguous(&input).unwrap();
+        assert!(!field.fallback_text().contains("WARNING"));
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected PreviewLayout");
+        };
+        assert!(preview_layout.condensed.is_none());
+    }
+
+    #[test]
+    fn test_unambiguous_skips_erc20_shared_selectors() {
+        let input = IERC721::approveCall {
+            to: recipient(),
+            tokenId: U256::from(1u64),
+        }
+        .abi_encode();
+        assert!(ERC721Visualizer.visualize_unambiguous(&input).is_none());
+    }
 }
@@ -1,13 +1,18 @@
 //! Core contract standards (ERC20, ERC721, etc.)
 
+pub(crate) mod approval_risk;
 pub mod dynamic_abi;
 pub mod erc1155;
 pub mod erc20;
+pub mod erc4626;
 pub mod erc721;
 pub mod fallback;
+pub mod selector_directory;
 
 pub use dynamic_abi::DynamicAbiVisualizer;
-pub use erc20::ERC20Visualizer;
+pub use erc20::{ERC20Visualizer, Erc20Token};
 pub use erc721::ERC721Visualizer;
 pub use erc1155::ERC1155Visualizer;
+pub use erc4626::{ERC4626ContractVisualizer, ERC4626Visualizer};
 pub use fallback::FallbackVisualizer;
+pub use selector_directory::SelectorDirectoryVisualizer;
@@ -0,0 +1,316 @@
+//! Function selector directory
+//!
+//! Last-resort decoder for calls that no ABI or protocol visualizer recognizes.
+//! The selector is looked up in a bundled directory of well-known signatures
+//! (`data/selectors.txt`, compiled into a sorted table by `build.rs`) and the
+//! arguments are decoded against that signature's parameter types.
+//!
+//! A four-byte selector says what the function is probably called, not what the
+//! contract actually does with it, so the output is labelled as unverified. When
+//! several signatures share a selector, the first one whose types re-encode to
+//! exactly the calldata is used. When none fits, the arguments are listed word by
+//! word with a guessed type for each.
+
+use alloy_dyn_abi::{DynSolType, DynSolValue};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+};
+
+use crate::abi_decoder::format_dyn_sol_value;
+
+include!(concat!(env!("OUT_DIR"), "/selector_directory.rs"));
+
+/// Number of 32-byte words listed individually when the arguments can't be decoded
+const MAX_HEURISTIC_WORDS: usize = 16;
+
+/// Visualizer that names calls by their selector alone
+pub struct SelectorDirectoryVisualizer;
+
+impl SelectorDirectoryVisualizer {
+    /// Returns every known signature for `selector`, in directory order
+    pub fn lookup(selector: [u8; 4]) -> impl Iterator<Item = &'static str> {
+        let start = SELECTORS.partition_point(|(s, _)| *s < selector);
+        SELECTORS[start..]
+            .iter()
+            .take_while(move |(s, _)| *s == selector)
+            .map(|(_, signature)| *signature)
+    }
+
+    /// Renders the call if its selector is in the directory, or returns `None`
+    pub fn visualize_tx_commands(&self, input: &[u8]) -> Option<SignablePayloadField> {
+        let selector: [u8; 4] = input.get(..4)?.try_into().ok()?;
+        let args = &input[4..];
+        let candidates: Vec<&str> = Self::lookup(selector).collect();
+        let first = *candidates.first()?;
+
+        for signature in &candidates {
+            if let Some(rows) = decode_exact(signature, args) {
+                return Some(render(signature, &candidates, true, rows));
+            }
+        }
+        Some(render(first, &candidates, false, guess_words(args)))
+    }
+}
+
+/// Decodes `args` against the parameter types of `signature`, accepting the result
+/// only if it re-encodes to exactly the same bytes
+fn decode_exact(signature: &str, args: &[u8]) -> Option<Vec<AnnotatedPayloadField>> {
+    let params = &signature[signature.find('(')?..];
+    let DynSolType::Tuple(types) = DynSolType::parse(params).ok()? else {
+        return None;
+    };
+    let tuple = DynSolType::Tuple(types.clone());
+    let decoded = tuple.abi_decode_params(args).ok()?;
+    if decoded.abi_encode_params() != args {
+        return None;
+    }
+    let DynSolValue::Tuple(values) = decoded else {
+        return None;
+    };
+    Some(
+        types
+            .iter()
+            .zip(&values)
+            .enumerate()
+            .map(|(i, (ty, value))| {
+                text_row(
+                    &format!("Argument {} ({})", i + 1, ty.sol_type_name()),
+                    format_dyn_sol_value(value),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Lists the argument words with a best guess at each one's type
+fn guess_words(args: &[u8]) -> Vec<AnnotatedPayloadField> {
+    let mut rows = Vec::new();
+    let mut words = args.chunks_exact(32);
+    for (i, word) in words.by_ref().take(MAX_HEURISTIC_WORDS).enumerate() {
+        let (guess, text) = guess_word(word);
+        rows.push(text_row(&format!("Word {} ({guess}?)", i + 1), text));
+    }
+    let listed = rows.len() * 32;
+    if args.len() > listed {
+        rows.push(text_row(
+            "Remaining Data",
+            format!("0x{}", hex::encode(&args[listed..])),
+        ));
+    }
+    rows
+}
+
+/// Guesses the type of one ABI word: an address, a small unsigned or negative
+/// integer, or otherwise opaque bytes
+fn guess_word(word: &[u8]) -> (&'static str, String) {
+    let value = alloy_primitives::U256::from_be_slice(word);
+    if word[..12].iter().all(|b| *b == 0) {
+        // A 20-byte value with a non-zero leading byte is far more likely an
+        // address than an amount
+        if word[12..16].iter().any(|b| *b != 0) {
+            let address = alloy_primitives::Address::from_slice(&word[12..]);
+            return ("address", format!("{address:?}"));
+        }
+        return ("uint256", value.to_string());
+    }
+    if word[..12].iter().all(|b| *b == 0xff) {
+        let signed = alloy_primitives::I256::from_raw(value);
+        return ("int256", signed.to_string());
+    }
+    ("bytes32", format!("0x{}", hex::encode(word)))
+}
+
+fn render(
+    signature: &str,
+    candidates: &[&str],
+    decoded: bool,
+    mut rows: Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    let name = signature.split('(').next().unwrap_or(signature).to_string();
+    let mut header = vec![text_row(
+        "Signature Source",
+        "Selector directory, not verified against the contract".to_string(),
+    )];
+    let others: Vec<&str> = candidates
+        .iter()
+        .copied()
+        .filter(|c| *c != signature)
+        .collect();
+    if !others.is_empty() {
+        header.push(text_row("Other Candidates", others.join(", ")));
+    }
+    header.append(&mut rows);
+
+    let subtitle = if decoded {
+        signature.to_string()
+    } else {
+        format!("{signature}, arguments do not match")
+    };
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text: subtitle.clone(),
+            label: name.clone(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 { text: name }),
+            subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+            condensed: None,
+            expanded: Some(SignablePayloadFieldListLayout { fields: header }),
+        },
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, U256, keccak256};
+
+    fn expanded_rows(field: &SignablePayloadField) -> Vec<(String, String)> {
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected PreviewLayout");
+        };
+        preview_layout
+            .expanded
+            .as_ref()
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                let SignablePayloadField::TextV2 { common, text_v2 } = &f.signable_payload_field
+                else {
+                    panic!("expected TextV2");
+                };
+                (common.label.clone(), text_v2.text.clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_directory_selectors_match_signatures() {
+        assert!(SELECTORS.windows(2).all(|w| w[0].0 <= w[1].0));
+        for (selector, signature) in SELECTORS {
+            assert_eq!(
+                &keccak256(signature.as_bytes())[..4],
+                selector,
+                "{signature}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_known_selector_decodes_arguments() {
+        // upgradeTo(0x1111...)
+        let mut input = keccak256(b"upgradeTo(address)")[..4].to_vec();
+        input.extend(Address::repeat_byte(0x11).into_word());
+
+        let field = SelectorDirectoryVisualizer
+            .visualize_tx_commands(&input)
+            .unwrap();
+        assert_eq!(field.label(), "upgradeTo");
+        assert_eq!(field.fallback_text(), "upgradeTo(address)");
+        let rows = expanded_rows(&field);
+        assert_eq!(rows[0].0, "Signature Source");
+        assert_eq!(
+            rows[1],
+            (
+                "Argument 1 (address)".to_string(),
+                "0x1111111111111111111111111111111111111111".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_collision_lists_other_candidates() {
+        // burn(uint256) and collate_propagate_storage(bytes16) share 0x42966c68;
+        // a small integer is not a valid left-aligned bytes16
+        let mut input = vec![0x42, 0x96, 0x6c, 0x68];
+        input.extend(U256::from(500u64).to_be_bytes::<32>());
+        let field = SelectorDirectoryVisualizer
+            .visualize_tx_commands(&input)
+            .unwrap();
+        assert_eq!(field.fallback_text(), "burn(uint256)");
+        assert_eq!(
+            expanded_rows(&field)[1],
+            (
+                "Other Candidates".to_string(),
+                "collate_propagate_storage(bytes16)".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_wrong_argument_count_is_not_an_exact_fit() {
+        // upgradeTo(address) with a second word appended
+        let mut input = keccak256(b"upgradeTo(address)")[..4].to_vec();
+        input.extend(Address::repeat_byte(0x11).into_word());
+        input.extend(U256::from(1u64).to_be_bytes::<32>());
+        let field = SelectorDirectoryVisualizer
+            .visualize_tx_commands(&input)
+            .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            "upgradeTo(address), arguments do not match"
+        );
+    }
+
+    #[test]
+    fn test_mismatched_arguments_are_guessed_per_word() {
+        // transfer(address,uint256) with a negative third word appended
+        let mut input = vec![0xa9, 0x05, 0x9c, 0xbb];
+        input.extend(Address::repeat_byte(0x22).into_word());
+        input.extend(U256::from(7u64).to_be_bytes::<32>());
+        input.extend([0xff; 32]);
+        input.extend([0x01, 0x02]);
+
+        let field = SelectorDirectoryVisualizer
+            .visualize_tx_commands(&input)
+            .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            "transfer(address,uint256), arguments do not match"
+        );
+        let rows = expanded_rows(&field);
+        assert_eq!(
+            &rows[1..],
+            &[
+                (
+                    "Word 1 (address?)".to_string(),
+                    "0x2222222222222222222222222222222222222222".to_string()
+                ),
+                ("Word 2 (uint256?)".to_string(), "7".to_string()),
+                ("Word 3 (int256?)".to_string(), "-1".to_string()),
+                ("Remaining Data".to_string(), "0x0102".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unknown_selector_is_not_rendered() {
+        assert!(
+            SelectorDirectoryVisualizer
+                .visualize_tx_commands(&[0xde, 0xad, 0xbe, 0xef])
+                .is_none()
+        );
+        assert!(
+            SelectorDirectoryVisualizer
+                .visualize_tx_commands(&[0xa9, 0x05])
+                .is_none()
+        );
+    }
+}
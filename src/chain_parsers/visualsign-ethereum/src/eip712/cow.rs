@@ -0,0 +1,175 @@
+//! CoW Protocol orders
+//!
+//! A signed `Order` lets solvers settle a trade through `GPv2Settlement` at any
+//! time before `validTo`, at a price no worse than the signed limit. `kind`
+//! decides which side is exact: a `sell` order sells exactly `sellAmount` for at
+//! least `buyAmount`, a `buy` order buys exactly `buyAmount` for at most
+//! `sellAmount`. A zero `receiver` means the proceeds go to the order owner.
+//!
+//! Reference: <https://github.com/cowprotocol/contracts/blob/main/src/contracts/libraries/GPv2Order.sol>
+
+use alloy_primitives::{Address, address};
+use visualsign::{SignablePayloadField, SignablePayloadFieldStaticAnnotation};
+
+use super::{
+    TypedDataContext, address_row, amount_row, deadline_row, member_address, member_bool,
+    member_bytes, member_str, member_uint, preview, text_row,
+};
+
+/// `GPv2Settlement`, deployed at the same address on every supported chain
+const GPV2_SETTLEMENT: Address = address!("0x9008D19f58AAbD9eD0D60971565AA8510560ab41");
+
+/// Renders a CoW `Order`, or returns `None` if the message is not one or is not
+/// addressed to the settlement contract
+pub(crate) fn render(context: &TypedDataContext) -> Option<Vec<SignablePayloadField>> {
+    if context.primary_type != "Order" || context.domain.verifying_contract? != GPV2_SETTLEMENT {
+        return None;
+    }
+    let message = context.message;
+    let sell_token = member_address(message, "sellToken")?;
+    let buy_token = member_address(message, "buyToken")?;
+    let receiver = member_address(message, "receiver")?;
+    let sell_amount = member_uint(message, "sellAmount")?;
+    let buy_amount = member_uint(message, "buyAmount")?;
+    let valid_to = member_uint(message, "validTo")?;
+    let fee_amount = member_uint(message, "feeAmount")?;
+    let kind = member_str(message, "kind")?;
+    let partially_fillable = member_bool(message, "partiallyFillable")?;
+    let app_data = member_bytes(message, "appData")?;
+
+    let (sell_value, sell_symbol) = context.token_amount(sell_token, sell_amount);
+    let (buy_value, buy_symbol) = context.token_amount(buy_token, buy_amount);
+    let sell_text = token_text(&sell_value, sell_symbol.as_deref(), sell_token);
+    let buy_text = token_text(&buy_value, buy_symbol.as_deref(), buy_token);
+    let (subtitle, sell_label, buy_label) = match kind {
+        "buy" => (
+            format!("Buy {buy_text} for at most {sell_text}"),
+            "Max Sell Amount",
+            "Buy Amount",
+        ),
+        _ => (
+            format!("Sell {sell_text} for at least {buy_text}"),
+            "Sell Amount",
+            "Min Buy Amount",
+        ),
+    };
+
+    let receiver_row = if receiver == Address::ZERO {
+        text_row("Receiver", "Order owner".to_string())
+    } else {
+        let mut row = address_row("Receiver", &receiver, None);
+        row.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+            text: "Proceeds go to a different address than the signer".to_string(),
+        });
+        row
+    };
+
+    let rows = vec![
+        text_row("Kind", kind.to_string()),
+        address_row("Sell Token", &sell_token, None),
+        amount_row(sell_label, sell_value, sell_symbol),
+        address_row("Buy Token", &buy_token, None),
+        amount_row(buy_label, buy_value, buy_symbol),
+        receiver_row,
+        deadline_row("Valid Until", valid_to),
+        text_row("Fee Amount", fee_amount.to_string()),
+        text_row(
+            "Partially Fillable",
+            if partially_fillable { "Yes" } else { "No" }.to_string(),
+        ),
+        text_row("App Data", format!("0x{}", hex::encode(app_data))),
+    ];
+    Some(vec![preview("CoW Order", subtitle, rows)])
+}
+
+fn token_text(value: &str, symbol: Option<&str>, token: Address) -> String {
+    match symbol {
+        Some(symbol) => format!("{value} {symbol}"),
+        None => format!("{value} of {token:?}"),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use crate::eip712::EthereumTypedDataConverter;
+    use visualsign::vsptrait::VisualSignOptions;
+
+    fn order(kind: &str, receiver: &str) -> String {
+        format!(
+            r#"{{
+                "types": {{
+                    "EIP712Domain": [
+                        {{"name": "name", "type": "string"}},
+                        {{"name": "version", "type": "string"}},
+                        {{"name": "chainId", "type": "uint256"}},
+                        {{"name": "verifyingContract", "type": "address"}}
+                    ],
+                    "Order": [
+                        {{"name": "sellToken", "type": "address"}},
+                        {{"name": "buyToken", "type": "address"}},
+                        {{"name": "receiver", "type": "address"}},
+                        {{"name": "sellAmount", "type": "uint256"}},
+                        {{"name": "buyAmount", "type": "uint256"}},
+                        {{"name": "validTo", "type": "uint32"}},
+                        {{"name": "appData", "type": "bytes32"}},
+                        {{"name": "feeAmount", "type": "uint256"}},
+                        {{"name": "kind", "type": "string"}},
+                        {{"name": "partiallyFillable", "type": "bool"}},
+                        {{"name": "sellTokenBalance", "type": "string"}},
+                        {{"name": "buyTokenBalance", "type": "string"}}
+                    ]
+                }},
+                "primaryType": "Order",
+                "domain": {{
+                    "name": "Gnosis Protocol",
+                    "version": "v2",
+                    "chainId": 1,
+                    "verifyingContract": "0x9008D19f58AAbD9eD0D60971565AA8510560ab41"
+                }},
+                "message": {{
+                    "sellToken": "0x1111111111111111111111111111111111111111",
+                    "buyToken": "0x2222222222222222222222222222222222222222",
+                    "receiver": "{receiver}",
+                    "sellAmount": "1000",
+                    "buyAmount": "2000",
+                    "validTo": 1735689600,
+                    "appData": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                    "feeAmount": "0",
+                    "kind": "{kind}",
+                    "partiallyFillable": false,
+                    "sellTokenBalance": "erc20",
+                    "buyTokenBalance": "erc20"
+                }}
+            }}"#
+        )
+    }
+
+    fn render(data: &str) -> String {
+        let payload = EthereumTypedDataConverter::new()
+            .to_visual_sign_payload(data, VisualSignOptions::default())
+            .unwrap();
+        serde_json::to_string(&payload).unwrap()
+    }
+
+    #[test]
+    fn test_sell_order_to_owner() {
+        let json = render(&order("sell", "0x0000000000000000000000000000000000000000"));
+        assert!(
+            json.contains(
+                "Sell 1000 of 0x1111111111111111111111111111111111111111 for at least 2000 of 0x2222222222222222222222222222222222222222"
+            ),
+            "got: {json}"
+        );
+        assert!(json.contains("Order owner"), "got: {json}");
+        assert!(!json.contains("different address"), "got: {json}");
+    }
+
+    #[test]
+    fn test_buy_order_to_third_party_receiver() {
+        let json = render(&order("buy", "0x3333333333333333333333333333333333333333"));
+        assert!(json.contains("Buy 2000 of"), "got: {json}");
+        assert!(json.contains("Max Sell Amount"), "got: {json}");
+        assert!(json.contains("different address"), "got: {json}");
+    }
+}
@@ -0,0 +1,500 @@
+//! EIP-712 typed data
+//!
+//! Typed data (`eth_signTypedData_v4`) is a second input format next to RLP and
+//! JSON transactions: a JSON object with `types`, `primaryType`, `domain` and
+//! `message`. Nothing is broadcast; the signer authorizes an off-chain message
+//! that a contract later verifies, so the payload has to make clear what that
+//! contract will let the holder of the signature do.
+//!
+//! The message is checked against its declared types and hashed exactly as a
+//! wallet would sign it. Well-known messages get a protocol renderer:
+//!
+//! - [`permit`]: EIP-2612 and DAI-style token permits
+//! - [`permit2`]: Uniswap Permit2 allowance and signature-transfer permits
+//! - [`safe`]: Safe multisig transactions, with the inner call decoded
+//! - [`cow`]: CoW Protocol orders
+//!
+//! Anything else falls back to a generic listing of the message fields.
+//!
+//! Typed data input is gated behind
+//! [`DeveloperConfig::allow_typed_data`](visualsign::vsptrait::DeveloperConfig)
+//! on the string entry point of [`crate::EthereumVisualSignConverter`].
+//! [`EthereumTypedDataConverter`] is the direct, ungated entry point.
+//!
+//! Spec: <https://eips.ethereum.org/EIPS/eip-712>
+
+pub mod cow;
+pub mod permit;
+pub mod permit2;
+pub mod safe;
+
+use std::sync::Arc;
+
+use alloy_dyn_abi::{DynSolValue, eip712::TypedData};
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::Eip712Domain;
+use visualsign::registry::LayeredRegistry;
+use visualsign::vsptrait::{TransactionParseError, VisualSignError, VisualSignOptions};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayload, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+};
+
+use crate::contracts::core::approval_risk;
+use crate::networks;
+use crate::registry::ContractRegistry;
+use crate::visualizer::{EthereumVisualizerRegistry, EthereumVisualizerRegistryBuilder};
+
+/// Maximum raw typed data size accepted (1 MB), matching the JSON transaction limit.
+const MAX_TYPED_DATA_LEN: usize = 1024 * 1024;
+
+/// Returns true if the input is a JSON object carrying EIP-712 `types` and
+/// `primaryType` keys.
+///
+/// JSON transactions use a `"type": "transaction"` envelope and never carry
+/// these keys, so the two JSON formats cannot be confused.
+pub(crate) fn is_typed_data_input(data: &str) -> bool {
+    if data.len() > MAX_TYPED_DATA_LEN || !crate::eth_json::is_json_input(data) {
+        return false;
+    }
+    serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(data)
+        .map(|object| object.contains_key("primaryType") && object.contains_key("types"))
+        .unwrap_or(false)
+}
+
+/// Converts EIP-712 typed data JSON into a `SignablePayload`
+///
+/// Shares the contract and visualizer registries with the transaction converter,
+/// so token metadata and the inner calls of Safe transactions resolve the same way.
+pub struct EthereumTypedDataConverter {
+    registry: Arc<ContractRegistry>,
+    visualizer_registry: EthereumVisualizerRegistry,
+}
+
+impl EthereumTypedDataConverter {
+    /// Creates a converter with the default registry (all known protocols).
+    pub fn new() -> Self {
+        let (contract_registry, visualizer_builder) = ContractRegistry::with_default_protocols();
+        Self {
+            registry: Arc::new(contract_registry),
+            visualizer_registry: visualizer_builder.build(),
+        }
+    }
+
+    /// Creates a converter with a custom registry wrapped in Arc.
+    pub fn with_registry(registry: Arc<ContractRegistry>) -> Self {
+        Self {
+            registry,
+            visualizer_registry: EthereumVisualizerRegistryBuilder::new().build(),
+        }
+    }
+
+    /// Parses typed data JSON and renders it, validating the output charset.
+    pub fn to_visual_sign_payload(
+        &self,
+        typed_data: &str,
+        options: VisualSignOptions,
+    ) -> Result<SignablePayload, VisualSignError> {
+        let layered_registry = LayeredRegistry::new(Arc::clone(&self.registry));
+        let payload = convert_typed_data(
+            typed_data,
+            options,
+            &layered_registry,
+            &self.visualizer_registry,
+        )?;
+        payload.validate_charset()?;
+        Ok(payload)
+    }
+}
+
+impl Default for EthereumTypedDataConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Everything a protocol renderer needs to know about one typed data message
+pub(crate) struct TypedDataContext<'a> {
+    /// `domain.chainId`, when present and within `u64`
+    pub chain_id: Option<u64>,
+    pub domain: &'a Eip712Domain,
+    pub primary_type: &'a str,
+    /// The message, coerced against its declared types
+    pub message: &'a DynSolValue,
+    /// The EIP-712 digest the wallet signs
+    pub signing_hash: B256,
+    pub registry: &'a LayeredRegistry<ContractRegistry>,
+    pub visualizer_registry: &'a EthereumVisualizerRegistry,
+    pub decode_transfers: bool,
+}
+
+impl TypedDataContext<'_> {
+    /// Formats a token amount with registry decimals, or raw units when unknown
+    fn token_amount(&self, token: Address, amount: U256) -> (String, Option<String>) {
+        self.chain_id
+            .and_then(|chain_id| {
+                self.registry
+                    .lookup_amount_data(|r| r.format_token_amount_u256(chain_id, token, amount))
+            })
+            .map_or((amount.to_string(), None), |(value, symbol)| {
+                (value, Some(symbol))
+            })
+    }
+
+    /// Formats an amount of the domain chain's native currency; without a
+    /// chain id it is shown with 18 decimals and no symbol
+    fn native_amount(&self, amount: U256) -> String {
+        match self.chain_id {
+            Some(chain_id) => crate::networks::format_native_amount(chain_id, amount),
+            None => crate::fmt::format_native(amount, 18),
+        }
+    }
+
+    /// Adds the approval risk warning to a rendered permit. The spender is
+    /// checked against the built-in registry on the domain's chain; without a
+    /// chain id only an unlimited amount is flagged.
+    fn warn_on_approval(
+        &self,
+        field: &mut SignablePayloadField,
+        spender: Address,
+        unlimited: bool,
+    ) {
+        let known_spender = self.chain_id.map(|chain_id| {
+            approval_risk::is_known_spender(self.registry.global(), chain_id, spender)
+        });
+        if let Some(warning) = approval_risk::approval_warning(unlimited, known_spender) {
+            approval_risk::attach_warning(field, warning);
+        }
+    }
+}
+
+pub(crate) fn convert_typed_data(
+    data: &str,
+    options: VisualSignOptions,
+    layered_registry: &LayeredRegistry<ContractRegistry>,
+    visualizer_registry: &EthereumVisualizerRegistry,
+) -> Result<SignablePayload, VisualSignError> {
+    if data.len() > MAX_TYPED_DATA_LEN {
+        return Err(TransactionParseError::InvalidFormat(format!(
+            "typed data too large: {} bytes (max {MAX_TYPED_DATA_LEN})",
+            data.len()
+        ))
+        .into());
+    }
+    let typed_data: TypedData = serde_json::from_str(data)
+        .map_err(|e| TransactionParseError::InvalidFormat(format!("invalid typed data: {e}")))?;
+    let signing_hash = typed_data
+        .eip712_signing_hash()
+        .map_err(|e| VisualSignError::DecodeError(format!("typed data does not hash: {e}")))?;
+    let message = typed_data
+        .coerce()
+        .map_err(|e| VisualSignError::DecodeError(format!("message does not match types: {e}")))?;
+
+    let domain = &typed_data.domain;
+    let chain_id = domain.chain_id.and_then(|id| u64::try_from(id).ok());
+    let context = TypedDataContext {
+        chain_id,
+        domain,
+        primary_type: &typed_data.primary_type,
+        message: &message,
+        signing_hash,
+        registry: layered_registry,
+        visualizer_registry,
+        decode_transfers: options.decode_transfers,
+    };
+
+    let network_name = match chain_id {
+        Some(id) => networks::resolve_network_name(options.metadata.as_ref(), id),
+        None => "Any network (no chainId in domain)".to_string(),
+    };
+    let mut fields = vec![text_field("Network", network_name)];
+    if let Some(name) = &domain.name {
+        fields.push(text_field("Domain", name.to_string()));
+    }
+    if let Some(version) = &domain.version {
+        fields.push(text_field("Domain Version", version.to_string()));
+    }
+    if let Some(contract) = domain.verifying_contract {
+        fields.push(address_field("Verifying Contract", &contract));
+    }
+    fields.push(text_field("Primary Type", typed_data.primary_type.clone()));
+
+    let rendered = permit2::render(&context)
+        .or_else(|| safe::render(&context))
+        .or_else(|| cow::render(&context))
+        .or_else(|| permit::render(&context))
+        .unwrap_or_else(|| vec![render_generic(&context)]);
+    fields.extend(rendered);
+
+    fields.push(text_field("EIP-712 Digest", format!("{signing_hash:?}")));
+
+    let title = options
+        .transaction_name
+        .unwrap_or_else(|| "EIP-712 Typed Data".to_string());
+    Ok(SignablePayload::new(
+        0,
+        title,
+        None,
+        fields,
+        "EthereumTypedData".to_string(),
+    ))
+}
+
+/// Lists every message field, flattening nested structs and arrays into
+/// dotted labels (`details.token`, `calls[0].target`).
+fn render_generic(context: &TypedDataContext) -> SignablePayloadField {
+    let mut rows = Vec::new();
+    flatten(context.message, String::new(), &mut rows);
+    let title = format!("{} Message", context.primary_type);
+    preview(
+        &title,
+        format!("Sign {} message", context.primary_type),
+        rows,
+    )
+}
+
+fn flatten(value: &DynSolValue, path: String, rows: &mut Vec<AnnotatedPayloadField>) {
+    match value {
+        DynSolValue::CustomStruct {
+            prop_names, tuple, ..
+        } => {
+            for (name, inner) in prop_names.iter().zip(tuple) {
+                let child = if path.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{path}.{name}")
+                };
+                flatten(inner, child, rows);
+            }
+        }
+        DynSolValue::Array(values) | DynSolValue::FixedArray(values)
+            if values
+                .iter()
+                .any(|v| matches!(v, DynSolValue::CustomStruct { .. })) =>
+        {
+            for (i, inner) in values.iter().enumerate() {
+                flatten(inner, format!("{path}[{i}]"), rows);
+            }
+        }
+        DynSolValue::Address(address) => rows.push(address_row(&path, address, None)),
+        other => rows.push(text_row(
+            &path,
+            crate::abi_decoder::format_dyn_sol_value(other),
+        )),
+    }
+}
+
+/// Returns a named member of a struct value
+pub(crate) fn member<'a>(value: &'a DynSolValue, name: &str) -> Option<&'a DynSolValue> {
+    match value {
+        DynSolValue::CustomStruct {
+            prop_names, tuple, ..
+        } => prop_names
+            .iter()
+            .position(|prop| prop == name)
+            .and_then(|i| tuple.get(i)),
+        _ => None,
+    }
+}
+
+pub(crate) fn member_address(value: &DynSolValue, name: &str) -> Option<Address> {
+    member(value, name).and_then(DynSolValue::as_address)
+}
+
+pub(crate) fn member_uint(value: &DynSolValue, name: &str) -> Option<U256> {
+    member(value, name)
+        .and_then(DynSolValue::as_uint)
+        .map(|(v, _)| v)
+}
+
+pub(crate) fn member_bool(value: &DynSolValue, name: &str) -> Option<bool> {
+    member(value, name).and_then(DynSolValue::as_bool)
+}
+
+pub(crate) fn member_str<'a>(value: &'a DynSolValue, name: &str) -> Option<&'a str> {
+    member(value, name).and_then(DynSolValue::as_str)
+}
+
+/// `bytes` and `bytesN` members as a byte slice
+pub(crate) fn member_bytes<'a>(value: &'a DynSolValue, name: &str) -> Option<&'a [u8]> {
+    match member(value, name)? {
+        DynSolValue::Bytes(bytes) => Some(bytes),
+        DynSolValue::FixedBytes(word, size) => Some(&word[..*size]),
+        _ => None,
+    }
+}
+
+/// Row for a `uint` timestamp, treating values past `u64` as "never"
+pub(crate) fn deadline_row(label: &str, value: U256) -> AnnotatedPayloadField {
+    match u64::try_from(value) {
+        Ok(seconds) => crate::protocols::uniswap::contracts::permit2::timestamp_row(label, seconds),
+        Err(_) => text_row(label, "never".to_string()),
+    }
+}
+
+fn preview(
+    title: &str,
+    subtitle: String,
+    rows: Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text: subtitle.clone(),
+            label: title.to_string(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title.to_string(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+            condensed: None,
+            expanded: Some(SignablePayloadFieldListLayout { fields: rows }),
+        },
+    }
+}
+
+fn text_field(label: &str, text: String) -> SignablePayloadField {
+    SignablePayloadField::TextV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: text.clone(),
+            label: label.to_string(),
+        },
+        text_v2: SignablePayloadFieldTextV2 { text },
+    }
+}
+
+fn address_field(label: &str, address: &Address) -> SignablePayloadField {
+    SignablePayloadField::AddressV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: format!("{address:?}"),
+            label: label.to_string(),
+        },
+        address_v2: SignablePayloadFieldAddressV2 {
+            address: format!("{address:?}"),
+            name: "".to_string(),
+            memo: None,
+            asset_label: "".to_string(),
+            badge_text: None,
+        },
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: text_field(label, text),
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn address_row(label: &str, address: &Address, badge: Option<&str>) -> AnnotatedPayloadField {
+    let mut field = address_field(label, address);
+    if let SignablePayloadField::AddressV2 { address_v2, .. } = &mut field {
+        address_v2.badge_text = badge.map(str::to_string);
+    }
+    AnnotatedPayloadField {
+        signable_payload_field: field,
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn amount_row(label: &str, amount: String, abbreviation: Option<String>) -> AnnotatedPayloadField {
+    let fallback_text = match &abbreviation {
+        Some(symbol) => format!("{amount} {symbol}"),
+        None => amount.clone(),
+    };
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AmountV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text,
+                label: label.to_string(),
+            },
+            amount_v2: SignablePayloadFieldAmountV2 {
+                amount,
+                abbreviation,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    const MAIL: &str = r#"{
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "version", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"}
+            ],
+            "Person": [
+                {"name": "name", "type": "string"},
+                {"name": "wallet", "type": "address"}
+            ],
+            "Mail": [
+                {"name": "from", "type": "Person"},
+                {"name": "to", "type": "Person"},
+                {"name": "contents", "type": "string"}
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+            "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+            "contents": "Hello, Bob!"
+        }
+    }"#;
+
+    #[test]
+    fn test_detects_typed_data_but_not_json_transactions() {
+        assert!(is_typed_data_input(MAIL));
+        assert!(!is_typed_data_input(
+            r#"{"type": "transaction", "chainId": "0x1"}"#
+        ));
+        assert!(!is_typed_data_input("0x02f8"));
+    }
+
+    #[test]
+    fn test_generic_message_matches_spec_digest() {
+        let payload = EthereumTypedDataConverter::new()
+            .to_visual_sign_payload(MAIL, VisualSignOptions::default())
+            .unwrap();
+        assert_eq!(payload.title, "EIP-712 Typed Data");
+
+        let json = serde_json::to_string(&payload).unwrap();
+        // Digest from the EIP-712 reference example
+        assert!(
+            json.contains("0xbe609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2"),
+            "got: {json}"
+        );
+        assert!(json.contains("from.wallet"), "got: {json}");
+        assert!(json.contains("Hello, Bob!"), "got: {json}");
+        assert!(json.contains("Ether Mail"), "got: {json}");
+    }
+
+    #[test]
+    fn test_message_not_matching_types_is_rejected() {
+        let bad = MAIL.replace(
+            r#""wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826""#,
+            r#""wallet": "not an address""#,
+        );
+        assert!(
+            EthereumTypedDataConverter::new()
+                .to_visual_sign_payload(&bad, VisualSignOptions::default())
+                .is_err()
+        );
+    }
+}
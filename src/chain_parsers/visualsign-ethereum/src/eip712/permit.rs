@@ -0,0 +1,215 @@
+//! EIP-2612 token permits
+//!
+//! A `Permit` signature sets an ERC-20 allowance without an on-chain `approve`.
+//! The token is the domain's `verifyingContract`. Two shapes exist in the wild:
+//!
+//! - EIP-2612: `Permit(owner, spender, value, nonce, deadline)`
+//! - DAI: `Permit(holder, spender, nonce, expiry, allowed)`, where `allowed`
+//!   grants an unlimited allowance and `false` revokes it
+//!
+//! Spec: <https://eips.ethereum.org/EIPS/eip-2612>
+
+use alloy_primitives::U256;
+use visualsign::{SignablePayloadField, SignablePayloadFieldStaticAnnotation};
+
+use super::{
+    TypedDataContext, address_row, amount_row, deadline_row, member_address, member_bool,
+    member_uint, preview, text_row,
+};
+
+/// Renders a `Permit` message, or returns `None` for any other message
+pub(crate) fn render(context: &TypedDataContext) -> Option<Vec<SignablePayloadField>> {
+    if context.primary_type != "Permit" {
+        return None;
+    }
+    let token = context.domain.verifying_contract?;
+    let message = context.message;
+    let spender = member_address(message, "spender")?;
+    let nonce = member_uint(message, "nonce")?;
+
+    let (owner, amount, deadline) = if let Some(value) = member_uint(message, "value") {
+        (
+            member_address(message, "owner")?,
+            Some(value),
+            member_uint(message, "deadline")?,
+        )
+    } else {
+        let allowed = member_bool(message, "allowed")?;
+        (
+            member_address(message, "holder")?,
+            allowed.then_some(U256::MAX),
+            member_uint(message, "expiry")?,
+        )
+    };
+
+    let token_name = context
+        .domain
+        .name
+        .as_deref()
+        .map_or(format!("{token:?}"), str::to_string);
+    let unlimited = amount == Some(U256::MAX);
+    let (amount_text, symbol) = match amount {
+        None => ("0".to_string(), None),
+        Some(_) if unlimited => ("Unlimited".to_string(), None),
+        Some(value) => context.token_amount(token, value),
+    };
+    let shown_amount = match &symbol {
+        Some(symbol) => format!("{amount_text} {symbol}"),
+        None => format!("{amount_text} {token_name}"),
+    };
+    let subtitle = match amount {
+        None => format!("Revoke {token_name} allowance of {spender:?}"),
+        Some(_) => format!("Allow {spender:?} to spend {shown_amount}"),
+    };
+
+    let mut amount_field = amount_row("Amount", amount_text, symbol);
+    if unlimited {
+        amount_field.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+            text: "Unlimited allowance: the spender can move the full balance".to_string(),
+        });
+    }
+    let rows = vec![
+        address_row("Token", &token, None),
+        address_row("Owner", &owner, None),
+        address_row("Spender", &spender, Some("Spender")),
+        amount_field,
+        text_row("Nonce", nonce.to_string()),
+        deadline_row("Deadline", deadline),
+    ];
+    let mut field = preview("Token Permit", subtitle, rows);
+    // A zero or `allowed: false` permit revokes the allowance
+    if amount.is_some_and(|value| !value.is_zero()) {
+        context.warn_on_approval(&mut field, spender, unlimited);
+    }
+    Some(vec![field])
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::eip712::EthereumTypedDataConverter;
+    use crate::registry::ContractRegistry;
+    use crate::token_metadata::{ErcStandard, TokenMetadata};
+    use visualsign::vsptrait::VisualSignOptions;
+
+    const USDC_PERMIT: &str = r#"{
+        "types": {
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "version", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"}
+            ],
+            "Permit": [
+                {"name": "owner", "type": "address"},
+                {"name": "spender", "type": "address"},
+                {"name": "value", "type": "uint256"},
+                {"name": "nonce", "type": "uint256"},
+                {"name": "deadline", "type": "uint256"}
+            ]
+        },
+        "primaryType": "Permit",
+        "domain": {
+            "name": "USD Coin",
+            "version": "2",
+            "chainId": 1,
+            "verifyingContract": "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+        },
+        "message": {
+            "owner": "0x1111111111111111111111111111111111111111",
+            "spender": "0x2222222222222222222222222222222222222222",
+            "value": "VALUE",
+            "nonce": 0,
+            "deadline": 1735689600
+        }
+    }"#;
+
+    fn render(data: &str) -> String {
+        let mut registry = ContractRegistry::new();
+        registry
+            .register_token(
+                1,
+                TokenMetadata {
+                    symbol: "USDC".to_string(),
+                    name: "USD Coin".to_string(),
+                    erc_standard: ErcStandard::Erc20,
+                    contract_address: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+                    decimals: 6,
+                },
+            )
+            .unwrap();
+        let payload = EthereumTypedDataConverter::with_registry(Arc::new(registry))
+            .to_visual_sign_payload(data, VisualSignOptions::default())
+            .unwrap();
+        serde_json::to_string(&payload).unwrap()
+    }
+
+    #[test]
+    fn test_permit_formats_known_token_amount() {
+        let json = render(&USDC_PERMIT.replace("\"VALUE\"", "25000000"));
+        assert!(
+            json.contains(
+                "Allow 0x2222222222222222222222222222222222222222 to spend 25.000000 USDC"
+            ),
+            "got: {json}"
+        );
+        assert!(json.contains("2025-01-01"), "got: {json}");
+        assert!(!json.contains("Unlimited allowance"), "got: {json}");
+    }
+
+    #[test]
+    fn test_permit_flags_unlimited_allowance() {
+        let max = "\"0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\"";
+        let json = render(&USDC_PERMIT.replace("\"VALUE\"", max));
+        assert!(json.contains("Unlimited allowance"), "got: {json}");
+        assert!(
+            json.contains("WARNING: Unlimited approval to unknown contract"),
+            "got: {json}"
+        );
+    }
+
+    #[test]
+    fn test_dai_style_permit_revocation() {
+        let dai = r#"{
+            "types": {
+                "EIP712Domain": [
+                    {"name": "name", "type": "string"},
+                    {"name": "version", "type": "string"},
+                    {"name": "chainId", "type": "uint256"},
+                    {"name": "verifyingContract", "type": "address"}
+                ],
+                "Permit": [
+                    {"name": "holder", "type": "address"},
+                    {"name": "spender", "type": "address"},
+                    {"name": "nonce", "type": "uint256"},
+                    {"name": "expiry", "type": "uint256"},
+                    {"name": "allowed", "type": "bool"}
+                ]
+            },
+            "primaryType": "Permit",
+            "domain": {
+                "name": "Dai Stablecoin",
+                "version": "1",
+                "chainId": 1,
+                "verifyingContract": "0x6B175474E89094C44Da98b954EedeAC495271d0F"
+            },
+            "message": {
+                "holder": "0x1111111111111111111111111111111111111111",
+                "spender": "0x2222222222222222222222222222222222222222",
+                "nonce": 3,
+                "expiry": 0,
+                "allowed": false
+            }
+        }"#;
+        let json = render(dai);
+        assert!(
+            json.contains(
+                "Revoke Dai Stablecoin allowance of 0x2222222222222222222222222222222222222222"
+            ),
+            "got: {json}"
+        );
+        assert!(!json.contains("WARNING"), "got: {json}");
+    }
+}
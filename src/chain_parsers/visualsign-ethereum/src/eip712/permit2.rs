@@ -0,0 +1,284 @@
+//! Uniswap Permit2 signatures
+//!
+//! Permit2 accepts two families of signed messages, both verified by the
+//! canonical Permit2 deployment:
+//!
+//! - Allowance transfer (`PermitSingle`, `PermitBatch`): grants `spender` a
+//!   time-limited allowance that it can draw on repeatedly until `expiration`.
+//! - Signature transfer (`PermitTransferFrom`, `PermitBatchTransferFrom` and
+//!   their `Witness` variants): a one-shot transfer of at most `amount`, valid
+//!   until `deadline`. The witness binds extra protocol data (e.g. a UniswapX
+//!   order) and is listed as-is.
+//!
+//! Reference: <https://github.com/Uniswap/permit2/blob/main/src/interfaces/ISignatureTransfer.sol>
+
+use alloy_dyn_abi::DynSolValue;
+use alloy_primitives::{Address, U256};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldStaticAnnotation,
+};
+
+use super::{
+    TypedDataContext, address_row, amount_row, deadline_row, member, member_address, member_uint,
+    preview, text_row,
+};
+use crate::protocols::uniswap::config::UniswapConfig;
+
+/// Permit2 amounts are `uint160`; the maximum is the "unlimited" sentinel
+fn is_unlimited(amount: U256) -> bool {
+    amount >= (U256::from(1) << 160) - U256::from(1)
+}
+
+/// Renders a Permit2 message, or returns `None` if the message is not one or is
+/// not addressed to the canonical Permit2 contract
+pub(crate) fn render(context: &TypedDataContext) -> Option<Vec<SignablePayloadField>> {
+    if context.domain.verifying_contract? != UniswapConfig::permit2_address() {
+        return None;
+    }
+    let message = context.message;
+    let spender = member_address(message, "spender")?;
+
+    match context.primary_type {
+        "PermitSingle" | "PermitBatch" => {
+            let details = match member(message, "details")? {
+                DynSolValue::Array(items) => items.iter().collect::<Vec<_>>(),
+                single => vec![single],
+            };
+            let sig_deadline = member_uint(message, "sigDeadline")?;
+            let mut rows = vec![address_row("Spender", &spender, Some("Spender"))];
+            let mut summaries = Vec::new();
+            let mut amounts = Vec::new();
+            for (i, detail) in details.iter().enumerate() {
+                let token = member_address(detail, "token")?;
+                let amount = member_uint(detail, "amount")?;
+                amounts.push(amount);
+                let expiration = member_uint(detail, "expiration")?;
+                let nonce = member_uint(detail, "nonce")?;
+                let prefix = item_prefix(details.len(), i);
+                let (amount_field, summary) =
+                    allowance_amount(context, &format!("{prefix}Amount"), token, amount);
+                rows.push(address_row(&format!("{prefix}Token"), &token, None));
+                rows.push(amount_field);
+                rows.push(deadline_row(&format!("{prefix}Expiration"), expiration));
+                rows.push(text_row(&format!("{prefix}Nonce"), nonce.to_string()));
+                summaries.push(summary);
+            }
+            rows.push(deadline_row("Signature Deadline", sig_deadline));
+            let subtitle = format!("Allow {spender:?} to spend {}", summaries.join(", "));
+            let mut field = preview("Permit2 Allowance", subtitle, rows);
+            warn_on_amounts(context, &mut field, spender, &amounts);
+            Some(vec![field])
+        }
+        "PermitTransferFrom"
+        | "PermitBatchTransferFrom"
+        | "PermitWitnessTransferFrom"
+        | "PermitBatchWitnessTransferFrom" => {
+            let permitted = match member(message, "permitted")? {
+                DynSolValue::Array(items) => items.iter().collect::<Vec<_>>(),
+                single => vec![single],
+            };
+            let nonce = member_uint(message, "nonce")?;
+            let deadline = member_uint(message, "deadline")?;
+            let mut rows = vec![address_row("Spender", &spender, Some("Spender"))];
+            let mut summaries = Vec::new();
+            let mut amounts = Vec::new();
+            for (i, permission) in permitted.iter().enumerate() {
+                let token = member_address(permission, "token")?;
+                let amount = member_uint(permission, "amount")?;
+                amounts.push(amount);
+                let prefix = item_prefix(permitted.len(), i);
+                let (amount_field, summary) =
+                    allowance_amount(context, &format!("{prefix}Max Amount"), token, amount);
+                rows.push(address_row(&format!("{prefix}Token"), &token, None));
+                rows.push(amount_field);
+                summaries.push(summary);
+            }
+            rows.push(text_row("Nonce", nonce.to_string()));
+            rows.push(deadline_row("Deadline", deadline));
+            if let Some(witness) = member(message, "witness") {
+                rows.push(text_row(
+                    "Witness",
+                    crate::abi_decoder::format_dyn_sol_value(witness),
+                ));
+            }
+            let subtitle = format!(
+                "Allow {spender:?} to transfer up to {} once",
+                summaries.join(", ")
+            );
+            let mut field = preview("Permit2 Transfer", subtitle, rows);
+            warn_on_amounts(context, &mut field, spender, &amounts);
+            Some(vec![field])
+        }
+        _ => None,
+    }
+}
+
+/// Adds the approval risk warning unless every amount is zero; one unlimited
+/// entry makes the whole permit unlimited
+fn warn_on_amounts(
+    context: &TypedDataContext,
+    field: &mut SignablePayloadField,
+    spender: Address,
+    amounts: &[U256],
+) {
+    if amounts.iter().all(|amount| amount.is_zero()) {
+        return;
+    }
+    let unlimited = amounts.iter().any(|amount| is_unlimited(*amount));
+    context.warn_on_approval(field, spender, unlimited);
+}
+
+/// Label prefix for the rows of one entry in a batch ("#2 ") or none for a single entry
+fn item_prefix(len: usize, index: usize) -> String {
+    if len > 1 {
+        format!("#{} ", index + 1)
+    } else {
+        String::new()
+    }
+}
+
+/// Builds the amount row for one token and the short text used in the subtitle
+fn allowance_amount(
+    context: &TypedDataContext,
+    label: &str,
+    token: Address,
+    amount: U256,
+) -> (AnnotatedPayloadField, String) {
+    if is_unlimited(amount) {
+        let mut field = amount_row(label, "Unlimited".to_string(), None);
+        field.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+            text: "Unlimited allowance: the spender can move the full balance".to_string(),
+        });
+        return (field, format!("unlimited {token:?}"));
+    }
+    let (value, symbol) = context.token_amount(token, amount);
+    let summary = match &symbol {
+        Some(symbol) => format!("{value} {symbol}"),
+        None => format!("{value} of {token:?}"),
+    };
+    (amount_row(label, value, symbol), summary)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use crate::eip712::EthereumTypedDataConverter;
+    use visualsign::vsptrait::VisualSignOptions;
+
+    const DOMAIN_TYPE: &str = r#"[
+        {"name": "name", "type": "string"},
+        {"name": "chainId", "type": "uint256"},
+        {"name": "verifyingContract", "type": "address"}
+    ]"#;
+
+    fn typed_data(types: &str, primary: &str, verifying: &str, message: &str) -> String {
+        format!(
+            r#"{{
+                "types": {{"EIP712Domain": {DOMAIN_TYPE}, {types}}},
+                "primaryType": "{primary}",
+                "domain": {{"name": "Permit2", "chainId": 1, "verifyingContract": "{verifying}"}},
+                "message": {message}
+            }}"#
+        )
+    }
+
+    fn render(data: &str) -> String {
+        let payload = EthereumTypedDataConverter::new()
+            .to_visual_sign_payload(data, VisualSignOptions::default())
+            .unwrap();
+        serde_json::to_string(&payload).unwrap()
+    }
+
+    const SINGLE_TYPES: &str = r#"
+        "PermitSingle": [
+            {"name": "details", "type": "PermitDetails"},
+            {"name": "spender", "type": "address"},
+            {"name": "sigDeadline", "type": "uint256"}
+        ],
+        "PermitDetails": [
+            {"name": "token", "type": "address"},
+            {"name": "amount", "type": "uint160"},
+            {"name": "expiration", "type": "uint48"},
+            {"name": "nonce", "type": "uint48"}
+        ]"#;
+
+    const SINGLE_MESSAGE: &str = r#"{
+        "details": {
+            "token": "0x1111111111111111111111111111111111111111",
+            "amount": "0xffffffffffffffffffffffffffffffffffffffff",
+            "expiration": 1735689600,
+            "nonce": 0
+        },
+        "spender": "0x3fC91A3afd70395Cd496C647d5a6CC9D4B2b7FAD",
+        "sigDeadline": 1735689600
+    }"#;
+
+    #[test]
+    fn test_permit_single_unlimited_allowance() {
+        let json = render(&typed_data(
+            SINGLE_TYPES,
+            "PermitSingle",
+            "0x000000000022D473030F116dDEE9F6B43aC78BA3",
+            SINGLE_MESSAGE,
+        ));
+        assert!(json.contains("Permit2 Allowance"), "got: {json}");
+        assert!(
+            json.contains("Allow 0x3fc91a3afd70395cd496c647d5a6cc9d4b2b7fad to spend unlimited"),
+            "got: {json}"
+        );
+        assert!(json.contains("Unlimited allowance"), "got: {json}");
+    }
+
+    #[test]
+    fn test_permit_single_from_other_contract_is_generic() {
+        let json = render(&typed_data(
+            SINGLE_TYPES,
+            "PermitSingle",
+            "0x1234567890123456789012345678901234567890",
+            SINGLE_MESSAGE,
+        ));
+        assert!(!json.contains("Permit2 Allowance"), "got: {json}");
+        assert!(json.contains("PermitSingle Message"), "got: {json}");
+    }
+
+    #[test]
+    fn test_batch_witness_transfer_lists_each_token() {
+        let types = r#"
+            "PermitBatchWitnessTransferFrom": [
+                {"name": "permitted", "type": "TokenPermissions[]"},
+                {"name": "spender", "type": "address"},
+                {"name": "nonce", "type": "uint256"},
+                {"name": "deadline", "type": "uint256"},
+                {"name": "witness", "type": "Witness"}
+            ],
+            "TokenPermissions": [
+                {"name": "token", "type": "address"},
+                {"name": "amount", "type": "uint256"}
+            ],
+            "Witness": [
+                {"name": "user", "type": "address"}
+            ]"#;
+        let message = r#"{
+            "permitted": [
+                {"token": "0x1111111111111111111111111111111111111111", "amount": 5},
+                {"token": "0x2222222222222222222222222222222222222222", "amount": 7}
+            ],
+            "spender": "0x3333333333333333333333333333333333333333",
+            "nonce": 1,
+            "deadline": 1735689600,
+            "witness": {"user": "0x4444444444444444444444444444444444444444"}
+        }"#;
+        let json = render(&typed_data(
+            types,
+            "PermitBatchWitnessTransferFrom",
+            "0x000000000022D473030F116dDEE9F6B43aC78BA3",
+            message,
+        ));
+        assert!(json.contains("Permit2 Transfer"), "got: {json}");
+        assert!(json.contains("#2 Token"), "got: {json}");
+        assert!(
+            json.contains("Witness { user: 0x4444444444444444444444444444444444444444 }"),
+            "got: {json}"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Safe multisig transactions
+//!
+//! Owners confirm a Safe transaction by signing its `SafeTx` struct. The domain's
+//! `verifyingContract` is the Safe itself, and the EIP-712 digest is the Safe
+//! transaction hash shown by the Safe UI and hardware wallets, so it is surfaced
+//! for cross-checking. The inner call goes through the same decoder as a regular
+//! transaction, and `DELEGATECALL` operations are flagged since they run foreign
+//! code in the Safe's own storage context.
+//!
+//! Reference: <https://github.com/safe-global/safe-smart-account/blob/main/contracts/Safe.sol>
+
+use alloy_primitives::{Address, U256};
+use visualsign::{SignablePayloadField, SignablePayloadFieldStaticAnnotation};
+
+use super::{
+    TypedDataContext, address_row, member_address, member_bytes, member_uint, preview, text_row,
+};
+
+/// Renders a `SafeTx` message, or returns `None` for any other message
+pub(crate) fn render(context: &TypedDataContext) -> Option<Vec<SignablePayloadField>> {
+    if context.primary_type != "SafeTx" {
+        return None;
+    }
+    let safe = context.domain.verifying_contract?;
+    let message = context.message;
+    let to = member_address(message, "to")?;
+    let value = member_uint(message, "value")?;
+    let data = member_bytes(message, "data")?;
+    let operation = member_uint(message, "operation")?;
+    let nonce = member_uint(message, "nonce")?;
+    let safe_tx_gas = member_uint(message, "safeTxGas")?;
+    let base_gas = member_uint(message, "baseGas")?;
+    let gas_price = member_uint(message, "gasPrice")?;
+    let gas_token = member_address(message, "gasToken")?;
+    let refund_receiver = member_address(message, "refundReceiver")?;
+
+    let delegate_call = operation == U256::from(1);
+    let subtitle = if delegate_call {
+        format!("Safe {safe:?} delegatecalls {to:?}")
+    } else if data.is_empty() {
+        format!(
+            "Safe {safe:?} sends {} to {to:?}",
+            context.native_amount(value)
+        )
+    } else {
+        format!("Safe {safe:?} calls {to:?}")
+    };
+
+    let mut operation_row = text_row(
+        "Operation",
+        if delegate_call {
+            "DelegateCall".to_string()
+        } else {
+            "Call".to_string()
+        },
+    );
+    if delegate_call {
+        operation_row.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+            text: "High risk: the target runs with full control of the Safe".to_string(),
+        });
+    }
+    let mut rows = vec![
+        address_row("Safe", &safe, None),
+        address_row("To", &to, None),
+        text_row("Value", context.native_amount(value)),
+        operation_row,
+        text_row("Nonce", nonce.to_string()),
+    ];
+    // Gas refunds are paid from the Safe's balance to the refund receiver, so
+    // they only matter when a gas price is set.
+    if !gas_price.is_zero() {
+        rows.push(text_row("Safe Tx Gas", safe_tx_gas.to_string()));
+        rows.push(text_row("Base Gas", base_gas.to_string()));
+        rows.push(text_row("Gas Price", gas_price.to_string()));
+        rows.push(address_row("Gas Token", &gas_token, None));
+        rows.push(address_row("Refund Receiver", &refund_receiver, None));
+    }
+    rows.push(text_row(
+        "Safe Transaction Hash",
+        format!("{:?}", context.signing_hash),
+    ));
+
+    let mut fields = vec![preview("Safe Transaction", subtitle, rows)];
+    if !data.is_empty() {
+        fields.extend(decode_inner_call(context, to, data));
+    }
+    Some(fields)
+}
+
+/// Decodes the Safe transaction's calldata as if it were sent directly to `to`
+fn decode_inner_call(
+    context: &TypedDataContext,
+    to: Address,
+    data: &[u8],
+) -> Vec<SignablePayloadField> {
+    let decoder = crate::CallDecoder {
+        chain_id: context.chain_id.unwrap_or(1),
+        // The Safe domain is not a transaction, so the known-token short-circuit
+        // keys off the domain chain alone
+        tx_chain_id: context.chain_id,
+        layered_registry: context.registry,
+        visualizer_registry: context.visualizer_registry,
+        abi_registry: None,
+        decode_transfers: context.decode_transfers,
+    };
+    decoder.decode(Some(to), data, 0)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use crate::eip712::EthereumTypedDataConverter;
+    use visualsign::vsptrait::VisualSignOptions;
+
+    fn safe_tx(data: &str, operation: u8) -> String {
+        format!(
+            r#"{{
+                "types": {{
+                    "EIP712Domain": [
+                        {{"name": "chainId", "type": "uint256"}},
+                        {{"name": "verifyingContract", "type": "address"}}
+                    ],
+                    "SafeTx": [
+                        {{"name": "to", "type": "address"}},
+                        {{"name": "value", "type": "uint256"}},
+                        {{"name": "data", "type": "bytes"}},
+                        {{"name": "operation", "type": "uint8"}},
+                        {{"name": "safeTxGas", "type": "uint256"}},
+                        {{"name": "baseGas", "type": "uint256"}},
+                        {{"name": "gasPrice", "type": "uint256"}},
+                        {{"name": "gasToken", "type": "address"}},
+                        {{"name": "refundReceiver", "type": "address"}},
+                        {{"name": "nonce", "type": "uint256"}}
+                    ]
+                }},
+                "primaryType": "SafeTx",
+                "domain": {{"chainId": 1, "verifyingContract": "0x5555555555555555555555555555555555555555"}},
+                "message": {{
+                    "to": "0x6666666666666666666666666666666666666666",
+                    "value": "1000000000000000000",
+                    "data": "{data}",
+                    "operation": {operation},
+                    "safeTxGas": 0,
+                    "baseGas": 0,
+                    "gasPrice": 0,
+                    "gasToken": "0x0000000000000000000000000000000000000000",
+                    "refundReceiver": "0x0000000000000000000000000000000000000000",
+                    "nonce": 12
+                }}
+            }}"#
+        )
+    }
+
+    fn render(data: &str, options: VisualSignOptions) -> String {
+        let payload = EthereumTypedDataConverter::new()
+            .to_visual_sign_payload(data, options)
+            .unwrap();
+        serde_json::to_string(&payload).unwrap()
+    }
+
+    #[test]
+    fn test_safe_ether_transfer_shows_safe_tx_hash() {
+        let json = render(&safe_tx("0x", 0), VisualSignOptions::default());
+        assert!(
+            json.contains(
+                "Safe 0x5555555555555555555555555555555555555555 sends 1 ETH to 0x6666666666666666666666666666666666666666"
+            ),
+            "got: {json}"
+        );
+        assert!(json.contains("Safe Transaction Hash"), "got: {json}");
+        assert!(!json.contains("High risk"), "got: {json}");
+    }
+
+    #[test]
+    fn test_safe_delegatecall_is_flagged() {
+        let json = render(&safe_tx("0x", 1), VisualSignOptions::default());
+        assert!(json.contains("delegatecalls"), "got: {json}");
+        assert!(json.contains("High risk"), "got: {json}");
+    }
+
+    #[test]
+    fn test_safe_inner_erc20_transfer_is_decoded() {
+        // transfer(0x7777..., 1000)
+        let transfer = "0xa9059cbb\
+            0000000000000000000000007777777777777777777777777777777777777777\
+            00000000000000000000000000000000000000000000000000000000000003e8";
+        let options = VisualSignOptions {
+            decode_transfers: true,
+            ..Default::default()
+        };
+        let json = render(&safe_tx(transfer, 0), options);
+        assert!(json.contains("calls 0x6666"), "got: {json}");
+        assert!(
+            json.contains("0x7777777777777777777777777777777777777777"),
+            "got: {json}"
+        );
+    }
+}
@@ -43,6 +43,7 @@ pub(crate) enum EthJsonInput {
 pub(crate) struct EthJsonTransaction {
     from: Option<String>,
     to: Option<String>,
+    #[serde(alias = "input")]
     data: Option<String>,
     value: Option<String>,
     nonce: Option<String>,
@@ -70,13 +71,64 @@ pub(crate) fn decode_json_transaction(data: &str) -> Result<TypedTransaction, Et
             MAX_JSON_INPUT_LEN,
         )));
     }
-    let input: EthJsonInput = serde_json::from_str(data)
+    let value: serde_json::Value = serde_json::from_str(data)
+        .map_err(|e| EthereumParserError::FailedToParseJsonTransaction(e.to_string()))?;
+
+    // A bare `eth_sendTransaction` object has no envelope tag, or uses `type`
+    // for the hex transaction type ("0x0", "0x2"). Anything else is an
+    // envelope tag, so unknown tags still fail as unknown variants.
+    let is_envelope = value
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+        .is_some_and(|tag| !tag.starts_with("0x"));
+    if !is_envelope {
+        return decode_rpc_transaction(value);
+    }
+
+    let input: EthJsonInput = serde_json::from_value(value)
         .map_err(|e| EthereumParserError::FailedToParseJsonTransaction(e.to_string()))?;
     match input {
         EthJsonInput::Transaction(tx) => build_transaction(tx),
     }
 }
 
+/// Decode a bare JSON-RPC transaction object, as wallets and SDKs hold it
+/// before signing. When the object carries a `type`, it must agree with the
+/// transaction its fee fields produce, so a `"0x2"` object with only
+/// `gasPrice` is rejected rather than shown as legacy.
+fn decode_rpc_transaction(
+    mut value: serde_json::Value,
+) -> Result<TypedTransaction, EthereumParserError> {
+    let tx_type = match value.as_object_mut() {
+        Some(object) => object.remove("type"),
+        None => {
+            return Err(EthereumParserError::FailedToParseJsonTransaction(
+                "Expected a JSON object".to_string(),
+            ));
+        }
+    };
+    let tx: EthJsonTransaction = serde_json::from_value(value)
+        .map_err(|e| EthereumParserError::FailedToParseJsonTransaction(e.to_string()))?;
+    let transaction = build_transaction(tx)?;
+
+    if let Some(tx_type) = tx_type {
+        let raw = tx_type.as_str().ok_or_else(|| {
+            EthereumParserError::FailedToParseJsonTransaction(
+                "'type' must be a hex string".to_string(),
+            )
+        })?;
+        let declared = field_context("type", parse_hex_u64(raw))?;
+        let built = transaction.tx_type() as u8;
+        if declared != u64::from(built) {
+            return Err(EthereumParserError::FailedToParseJsonTransaction(format!(
+                "'type' {} does not match the fee fields, which describe a type {built:#x} transaction",
+                truncate_for_error(raw),
+            )));
+        }
+    }
+    Ok(transaction)
+}
+
 /// Wrap a parse error with the JSON field name for easier debugging.
 fn field_context<T>(
     field: &str,
@@ -768,4 +820,64 @@ mod tests {
             _ => panic!("Expected FailedToParseJsonTransaction"),
         }
     }
+
+    #[test]
+    fn test_bare_rpc_object_without_envelope() {
+        let json = r#"{
+            "from": "0x000000000000000000000000000000000000bEEF",
+            "to": "0x000000000000000000000000000000000000dEaD",
+            "value": "0x0",
+            "gas": "0x5208",
+            "maxFeePerGas": "0x4a817c800",
+            "chainId": "0x1",
+            "input": "0xabcdef"
+        }"#;
+        let tx = decode_json_transaction(json).unwrap();
+        match &tx {
+            TypedTransaction::Eip1559(inner) => {
+                assert_eq!(inner.max_fee_per_gas, 20_000_000_000);
+                assert_eq!(inner.input.as_ref(), &[0xab, 0xcd, 0xef]);
+            }
+            _ => panic!("Expected EIP-1559 transaction"),
+        }
+    }
+
+    #[test]
+    fn test_bare_rpc_object_with_matching_hex_type() {
+        let eip1559 = r#"{"type": "0x2", "chainId": "0x1", "maxFeePerGas": "0x1"}"#;
+        assert!(matches!(
+            decode_json_transaction(eip1559).unwrap(),
+            TypedTransaction::Eip1559(_)
+        ));
+
+        let legacy = r#"{"type": "0x0", "chainId": "0x1", "gasPrice": "0x1"}"#;
+        assert!(matches!(
+            decode_json_transaction(legacy).unwrap(),
+            TypedTransaction::Legacy(_)
+        ));
+    }
+
+    #[test]
+    fn test_bare_rpc_object_with_mismatched_type_rejected() {
+        let json = r#"{"type": "0x2", "chainId": "0x1", "gasPrice": "0x1"}"#;
+        match decode_json_transaction(json).unwrap_err() {
+            EthereumParserError::FailedToParseJsonTransaction(msg) => {
+                assert!(msg.contains("does not match"), "got: {msg}");
+            }
+            _ => panic!("Expected FailedToParseJsonTransaction"),
+        }
+
+        let unsupported = r#"{"type": "0x1", "chainId": "0x1", "gasPrice": "0x1"}"#;
+        assert!(decode_json_transaction(unsupported).is_err());
+    }
+
+    #[test]
+    fn test_bare_rpc_object_still_rejects_unknown_fields() {
+        let json = r#"{"chainId": "0x1", "accessList": []}"#;
+        assert!(decode_json_transaction(json).is_err());
+
+        // `input` is an alias for `data`, not a second calldata field
+        let both = r#"{"chainId": "0x1", "data": "0x01", "input": "0x02"}"#;
+        assert!(decode_json_transaction(both).is_err());
+    }
 }
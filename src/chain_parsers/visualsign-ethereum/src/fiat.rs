@@ -0,0 +1,364 @@
+//! Informational fiat values for amounts
+//!
+//! Wallets may pass prices in `EthereumMetadata.token_prices`, keyed by token
+//! address with the zero address standing for the chain's native currency.
+//! Once a transaction is rendered, every amount whose symbol has a price gets a
+//! dynamic annotation such as `~$1,530.22`. It is an estimate for the signer's
+//! benefit only: the prices come from the caller, not the chain, and the amounts
+//! themselves are never changed.
+//!
+//! Amounts carry a symbol rather than an address, so prices are matched by
+//! symbol. Only tokens the built-in registry knows can be priced, and a symbol
+//! that ends up with two different prices is left unannotated. Top-level payload
+//! fields have no annotation slot, so only amounts inside layouts are annotated.
+
+use std::collections::BTreeMap;
+
+use alloy_primitives::{Address, U256};
+use generated::parser::{ChainMetadata, chain_metadata};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldDynamicAnnotation,
+};
+
+use crate::networks::resolve_native_currency;
+use crate::registry::ContractRegistry;
+
+/// `Type` of the dynamic annotation, marking it as not part of the signed data
+pub const FIAT_ANNOTATION_TYPE: &str = "informational";
+/// `ID` of the dynamic annotation
+pub const FIAT_ANNOTATION_ID: &str = "fiat_value";
+
+/// More fractional digits than any real quote needs
+const MAX_PRICE_SCALE: u32 = 18;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Price {
+    value: Decimal,
+    currency: String,
+}
+
+/// A non-negative decimal as `mantissa / 10^scale`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Decimal {
+    mantissa: U256,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Parses plain decimal notation such as `1530.22`; anything else
+    /// (`Unlimited`, signs, exponents, separators) is `None`
+    fn parse(text: &str) -> Option<Self> {
+        let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+        let fraction = fraction.trim_end_matches('0');
+        let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+            return None;
+        }
+        let mantissa = U256::from_str_radix(&format!("{whole}{fraction}"), 10).ok()?;
+        Some(Self {
+            mantissa,
+            scale: u32::try_from(fraction.len()).ok()?,
+        })
+    }
+}
+
+/// Fiat prices for one request, indexed by the symbol amounts are shown with
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FiatPrices {
+    /// `None` marks a symbol priced more than once with different values
+    by_symbol: BTreeMap<String, Option<Price>>,
+}
+
+impl FiatPrices {
+    /// Builds the price table from `token_prices` in the request metadata, or
+    /// returns `None` when no usable price was supplied
+    pub fn from_metadata(
+        chain_metadata: Option<&ChainMetadata>,
+        chain_id: u64,
+        registry: &ContractRegistry,
+    ) -> Option<Self> {
+        let Some(chain_metadata::Metadata::Ethereum(eth_metadata)) =
+            chain_metadata.and_then(|m| m.metadata.as_ref())
+        else {
+            return None;
+        };
+        let mut prices = Self::default();
+        for (address, token_price) in &eth_metadata.token_prices {
+            let Ok(address) = address.trim().parse::<Address>() else {
+                continue;
+            };
+            let symbol = if address == Address::ZERO {
+                resolve_native_currency(chain_metadata, chain_id)
+                    .map(|native| native.symbol.to_string())
+            } else {
+                registry.get_token_symbol(chain_id, address)
+            };
+            let (Some(symbol), Some(price)) = (symbol, parse_price(token_price)) else {
+                continue;
+            };
+            prices.insert(symbol, price);
+        }
+        (!prices.by_symbol.is_empty()).then_some(prices)
+    }
+
+    fn insert(&mut self, symbol: String, price: Price) {
+        self.by_symbol
+            .entry(symbol)
+            .and_modify(|existing| {
+                if existing.as_ref() != Some(&price) {
+                    *existing = None;
+                }
+            })
+            .or_insert(Some(price));
+    }
+
+    /// Adds a fiat annotation to every priced amount nested in `fields`
+    pub fn annotate(&self, fields: &mut [SignablePayloadField]) {
+        for field in fields {
+            self.annotate_layouts(field);
+        }
+    }
+
+    fn annotate_layouts(&self, field: &mut SignablePayloadField) {
+        let lists = match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => vec![
+                preview_layout.condensed.as_mut(),
+                preview_layout.expanded.as_mut(),
+            ],
+            SignablePayloadField::ListLayout { list_layout, .. } => vec![Some(list_layout)],
+            _ => return,
+        };
+        for list in lists.into_iter().flatten() {
+            for annotated in &mut list.fields {
+                self.annotate_field(annotated);
+            }
+        }
+    }
+
+    fn annotate_field(&self, annotated: &mut AnnotatedPayloadField) {
+        let (amount, symbol) = match &annotated.signable_payload_field {
+            SignablePayloadField::AmountV2 { amount_v2, .. } => {
+                (&amount_v2.amount, &amount_v2.abbreviation)
+            }
+            SignablePayloadField::Amount { amount, .. } => (&amount.amount, &amount.abbreviation),
+            _ => return self.annotate_layouts(&mut annotated.signable_payload_field),
+        };
+        if annotated.dynamic_annotation.is_some() {
+            return;
+        }
+        let Some(Some(price)) = symbol.as_ref().and_then(|s| self.by_symbol.get(s)) else {
+            return;
+        };
+        let Some(text) = Decimal::parse(amount).and_then(|amount| fiat_text(amount, price)) else {
+            return;
+        };
+        annotated.dynamic_annotation = Some(SignablePayloadFieldDynamicAnnotation {
+            field_type: FIAT_ANNOTATION_TYPE.to_string(),
+            id: FIAT_ANNOTATION_ID.to_string(),
+            params: vec![text, price.currency.clone()],
+        });
+    }
+}
+
+/// A price needs a positive decimal value and a three-letter currency code
+fn parse_price(token_price: &generated::parser::TokenPrice) -> Option<Price> {
+    let value = Decimal::parse(token_price.price.trim())?;
+    let currency = token_price.currency.trim().to_ascii_uppercase();
+    let valid_currency = currency.len() == 3 && currency.bytes().all(|b| b.is_ascii_uppercase());
+    (valid_currency && !value.mantissa.is_zero() && value.scale <= MAX_PRICE_SCALE)
+        .then_some(Price { value, currency })
+}
+
+/// Formats `amount * price` rounded to cents, e.g. `~$1,530.22` or
+/// `~1,530.22 EUR`. Payloads are ASCII-only, so `~` stands in for "≈" and only
+/// USD gets a symbol.
+fn fiat_text(amount: Decimal, price: &Price) -> Option<String> {
+    let scale = amount.scale.checked_add(price.value.scale)?;
+    let divisor = U256::from(10u8).checked_pow(U256::from(scale))?;
+    let product = amount
+        .mantissa
+        .checked_mul(price.value.mantissa)?
+        .checked_mul(U256::from(100u8))?;
+    let cents = product.checked_add(divisor / U256::from(2u8))? / divisor;
+
+    let (prefix, value) = if cents.is_zero() && !product.is_zero() {
+        ("<", "0.01".to_string())
+    } else {
+        let whole = group_thousands(&(cents / U256::from(100u8)).to_string());
+        let fraction = u8::try_from(cents % U256::from(100u8)).ok()?;
+        ("~", format!("{whole}.{fraction:02}"))
+    };
+    Some(match price.currency.as_str() {
+        "USD" => format!("{prefix}${value}"),
+        currency => format!("{prefix}{value} {currency}"),
+    })
+}
+
+fn group_thousands(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use generated::parser::{EthereumMetadata, TokenPrice};
+    use visualsign::{
+        SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+        SignablePayloadFieldPreviewLayout,
+    };
+
+    const USDC: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+
+    fn metadata(prices: &[(&str, &str, &str)]) -> ChainMetadata {
+        ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                token_prices: prices
+                    .iter()
+                    .map(|(address, price, currency)| {
+                        (
+                            address.to_string(),
+                            TokenPrice {
+                                price: price.to_string(),
+                                currency: currency.to_string(),
+                            },
+                        )
+                    })
+                    .collect(),
+                ..Default::default()
+            })),
+        }
+    }
+
+    fn prices(entries: &[(&str, &str, &str)]) -> Option<FiatPrices> {
+        let (registry, _) = ContractRegistry::with_default_protocols();
+        FiatPrices::from_metadata(Some(&metadata(entries)), 1, &registry)
+    }
+
+    fn amount(amount: &str, symbol: &str) -> AnnotatedPayloadField {
+        AnnotatedPayloadField {
+            signable_payload_field: SignablePayloadField::AmountV2 {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: format!("{amount} {symbol}"),
+                    label: "Amount".to_string(),
+                },
+                amount_v2: SignablePayloadFieldAmountV2 {
+                    amount: amount.to_string(),
+                    abbreviation: Some(symbol.to_string()),
+                },
+            },
+            static_annotation: None,
+            dynamic_annotation: None,
+        }
+    }
+
+    fn annotate(prices: &FiatPrices, rows: Vec<AnnotatedPayloadField>) -> Vec<Option<String>> {
+        let mut fields = vec![SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: String::new(),
+                label: "Swap".to_string(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: None,
+                subtitle: None,
+                condensed: None,
+                expanded: Some(SignablePayloadFieldListLayout { fields: rows }),
+            },
+        }];
+        prices.annotate(&mut fields);
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = &fields[0] else {
+            panic!("layout changed");
+        };
+        preview_layout
+            .expanded
+            .as_ref()
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                f.dynamic_annotation.as_ref().map(|a| {
+                    assert_eq!(a.field_type, FIAT_ANNOTATION_TYPE);
+                    a.params[0].clone()
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_annotates_native_and_known_tokens() {
+        let prices = prices(&[
+            (&Address::ZERO.to_string(), "2500.5", "usd"),
+            (USDC, "0.9998", "EUR"),
+        ])
+        .unwrap();
+        let texts = annotate(
+            &prices,
+            vec![
+                amount("1.5", "ETH"),
+                amount("1530.220000", "USDC"),
+                amount("3", "DAI"),
+                amount("Unlimited", "USDC"),
+                amount("0.000001", "USDC"),
+            ],
+        );
+        assert_eq!(
+            texts,
+            vec![
+                Some("~$3,750.75".to_string()),
+                Some("~1,529.91 EUR".to_string()),
+                None,
+                None,
+                Some("<0.01 EUR".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unusable_prices_are_ignored() {
+        // Unknown token, bad currency, non-numeric and zero prices
+        assert!(
+            prices(&[
+                ("0x4444444444444444444444444444444444444444", "1", "USD"),
+                (USDC, "1", "DOLLARS"),
+                (USDC, "1e3", "USD"),
+                (USDC, "0", "USD"),
+                ("not an address", "1", "USD"),
+            ])
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn test_conflicting_prices_for_a_symbol_are_dropped() {
+        let mut prices = FiatPrices::default();
+        let price = |value: &str| {
+            parse_price(&TokenPrice {
+                price: value.to_string(),
+                currency: "USD".to_string(),
+            })
+        };
+        prices.insert("USDC".to_string(), price("1").unwrap());
+        prices.insert("USDC".to_string(), price("1.0").unwrap());
+        assert_eq!(
+            annotate(&prices, vec![amount("2", "USDC")]),
+            vec![Some("~$2.00".to_string())]
+        );
+        prices.insert("USDC".to_string(), price("1.01").unwrap());
+        assert_eq!(annotate(&prices, vec![amount("2", "USDC")]), vec![None]);
+    }
+
+    #[test]
+    fn test_group_thousands() {
+        assert_eq!(group_thousands("0"), "0");
+        assert_eq!(group_thousands("999"), "999");
+        assert_eq!(group_thousands("1000"), "1,000");
+        assert_eq!(group_thousands("1234567"), "1,234,567");
+    }
+}
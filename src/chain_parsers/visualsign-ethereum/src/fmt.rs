@@ -11,6 +11,11 @@ fn trim_trailing_zeros(s: String) -> String {
 pub fn format_ether<T: Into<ParseUnits> + ToString + Copy>(wei: T) -> String {
     trim_trailing_zeros(format_units(wei, "eth").unwrap_or_else(|_| wei.to_string()))
 }
+// Helper function to format an amount in a currency's smallest unit, for
+// currencies whose decimals don't match a named unit
+pub fn format_native<T: Into<ParseUnits> + ToString + Copy>(amount: T, decimals: u8) -> String {
+    trim_trailing_zeros(format_units(amount, decimals).unwrap_or_else(|_| amount.to_string()))
+}
 // Helper function to format wei to gwei
 pub fn format_gwei<T: Into<ParseUnits> + ToString + Copy>(wei: T) -> String {
     trim_trailing_zeros(format_units(wei, "gwei").unwrap_or_else(|_| wei.to_string()))
@@ -44,6 +49,13 @@ mod tests {
         assert_eq!("1.1", format_ether(wei));
     }
 
+    #[test]
+    fn test_format_native_decimals() {
+        assert_eq!("1.5", format_native(1_500_000u128, 6));
+        assert_eq!("0.000001", format_native(1u128, 6));
+        assert_eq!("2", format_native(2_000_000_000_000_000_000u128, 18));
+    }
+
     #[test]
     fn test_format_gwei_basic() {
         // 1 gwei = 1_000_000_000 wei
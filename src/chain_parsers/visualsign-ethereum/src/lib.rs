@@ -11,10 +11,14 @@ use crate::visualizer::CalldataVisualizer;
 use alloy_consensus::{Transaction as _, TxEnvelope, TxType, TypedTransaction};
 use alloy_rlp::{Buf, Decodable};
 use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
+use generated::parser::chain_metadata;
 use visualsign::{
-    SignablePayload, SignablePayloadField, SignablePayloadFieldAddressV2,
-    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldTextV2,
+    AnnotatedPayloadField, SignablePayload, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldStaticAnnotation,
+    SignablePayloadFieldTextV2,
     encodings::SupportedEncodings,
+    field_builders::create_uri_field,
     registry::LayeredRegistry,
     signing::SignerAllowlist,
     vsptrait::{
@@ -30,17 +34,22 @@ pub mod cli_plugin;
 pub use cli_plugin::{EthereumArgs, EthereumPlugin};
 
 pub mod abi_decoder;
+#[cfg(feature = "abi-fetch")]
+pub mod abi_fetch;
 pub mod abi_metadata;
 pub mod abi_registry;
 pub mod context;
 pub mod contracts;
+pub mod eip712;
 pub mod embedded_abis;
 pub(crate) mod eth_json;
+pub mod fiat;
 pub mod fmt;
 pub mod networks;
 pub mod protocols;
 pub mod registry;
 pub mod token_metadata;
+pub mod user_operation;
 pub mod visualizer;
 
 #[derive(Debug, Eq, PartialEq, thiserror::Error)]
@@ -91,9 +100,202 @@ fn extract_priority_fee(transaction: &TypedTransaction) -> Option<u128> {
     }
 }
 
+// Helper function to create the blob fields of EIP-4844 transactions
+fn create_blob_fields(
+    transaction: &TypedTransaction,
+    native: Option<networks::NativeCurrency<'_>>,
+) -> Vec<SignablePayloadField> {
+    let (Some(max_fee_per_blob_gas), Some(hashes)) = (
+        transaction.max_fee_per_blob_gas(),
+        transaction.blob_versioned_hashes(),
+    ) else {
+        return Vec::new();
+    };
+    let text_field = |label: String, text: String| SignablePayloadField::TextV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: text.clone(),
+            label,
+        },
+        text_v2: SignablePayloadFieldTextV2 { text },
+    };
+    let mut fields = vec![
+        text_field(
+            "Max Fee Per Blob Gas".to_string(),
+            format_fee_per_gas(max_fee_per_blob_gas, native),
+        ),
+        text_field("Blob Count".to_string(), hashes.len().to_string()),
+    ];
+    fields.extend(hashes.iter().enumerate().map(|(i, hash)| {
+        text_field(
+            format!("Blob {} Versioned Hash", i + 1),
+            format!("{hash:?}"),
+        )
+    }));
+    fields
+}
+
+// Helper function to create the authorization fields of EIP-7702 transactions.
+//
+// Each authorization installs `address` as the code of the account that signed
+// it, handing that contract full control over the account's assets until the
+// delegation is replaced. That makes the list the most important part of a
+// type-4 transaction, so it is preceded by a warning field.
+fn create_authorization_fields(transaction: &TypedTransaction) -> Vec<SignablePayloadField> {
+    let Some(authorizations) = transaction.authorization_list() else {
+        return Vec::new();
+    };
+    let warning = format!(
+        "This transaction sets the code of {} account(s). A delegate contract gets full control of the delegating account and its assets.",
+        authorizations.len()
+    );
+    let mut fields = vec![SignablePayloadField::TextV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: warning.clone(),
+            label: "Warning".to_string(),
+        },
+        text_v2: SignablePayloadFieldTextV2 { text: warning },
+    }];
+
+    for (i, authorization) in authorizations.iter().enumerate() {
+        let delegate = *authorization.address();
+        let chain_id = *authorization.chain_id();
+        let chain = if chain_id.is_zero() {
+            "any chain".to_string()
+        } else {
+            u64::try_from(chain_id).map_or_else(
+                |_| format!("chain {chain_id}"),
+                |id| networks::get_network_name(Some(id)),
+            )
+        };
+        let subtitle = if delegate.is_zero() {
+            format!("Clear the account's delegation on {chain}")
+        } else {
+            format!("Delegate the account's code to {delegate:?} on {chain}")
+        };
+
+        let mut delegate_row = AnnotatedPayloadField {
+            signable_payload_field: SignablePayloadField::AddressV2 {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: format!("{delegate:?}"),
+                    label: "Delegate".to_string(),
+                },
+                address_v2: SignablePayloadFieldAddressV2 {
+                    address: format!("{delegate:?}"),
+                    name: "".to_string(),
+                    memo: None,
+                    asset_label: "".to_string(),
+                    badge_text: Some("Delegate".to_string()),
+                },
+            },
+            static_annotation: None,
+            dynamic_annotation: None,
+        };
+        if !delegate.is_zero() {
+            delegate_row.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+                text: "Can execute any call as the delegating account".to_string(),
+            });
+        }
+        let mut chain_row = annotated_text_row("Chain ID", chain_id.to_string());
+        if chain_id.is_zero() {
+            chain_row.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+                text: "Valid on every chain: the delegation can be replayed anywhere".to_string(),
+            });
+        }
+        let rows = vec![
+            delegate_row,
+            chain_row,
+            annotated_text_row("Authorization Nonce", authorization.nonce().to_string()),
+        ];
+
+        let label = format!("Authorization {}", i + 1);
+        fields.push(SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: subtitle.clone(),
+                label: label.clone(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 { text: label }),
+                subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+                condensed: None,
+                expanded: Some(SignablePayloadFieldListLayout { fields: rows }),
+            },
+        });
+    }
+    fields
+}
+
+fn annotated_text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+// Formats a per-gas price. Gwei is 1e-9 of an 18-decimal currency, so chains
+// whose native currency has other decimals get the amount in native units.
+fn format_fee_per_gas(amount: u128, native: Option<networks::NativeCurrency<'_>>) -> String {
+    match native {
+        Some(currency) if currency.decimals != 18 => {
+            format!(
+                "{} {}",
+                currency.format(alloy_primitives::U256::from(amount)),
+                currency.symbol
+            )
+        }
+        _ => format!("{} gwei", format_gwei(amount)),
+    }
+}
+
+// Helper function to create the worst-case fee field: every unit of gas (and
+// blob gas) charged at its max fee. The base fee is burned and the tip is
+// capped by the max fee, so the signer can never pay more than this.
+fn create_max_network_fee_field(
+    transaction: &TypedTransaction,
+    native: Option<networks::NativeCurrency<'_>>,
+) -> SignablePayloadField {
+    use alloy_primitives::U256;
+
+    let execution_fee =
+        U256::from(transaction.gas_limit()) * U256::from(extract_gas_price(transaction));
+    let blob_fee = match (
+        transaction.blob_gas_used(),
+        transaction.max_fee_per_blob_gas(),
+    ) {
+        (Some(blob_gas), Some(max_fee)) => U256::from(blob_gas) * U256::from(max_fee),
+        _ => U256::ZERO,
+    };
+    let max_fee = execution_fee.saturating_add(blob_fee);
+
+    let amount = native.map_or_else(
+        || format_ether(max_fee),
+        |currency| currency.format(max_fee),
+    );
+    let symbol = native.map(|currency| currency.symbol);
+    SignablePayloadField::AmountV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: symbol.map_or_else(|| amount.clone(), |s| format!("{amount} {s}")),
+            label: "Max Network Fee".to_string(),
+        },
+        amount_v2: SignablePayloadFieldAmountV2 {
+            amount,
+            abbreviation: symbol.map(|s| s.to_string()),
+        },
+    }
+}
+
 // Helper function to create priority fee field
-fn create_priority_fee_field(max_priority_fee_per_gas: u128) -> SignablePayloadField {
-    let priority_fee_text = format!("{} gwei", format_gwei(max_priority_fee_per_gas));
+fn create_priority_fee_field(
+    max_priority_fee_per_gas: u128,
+    native: Option<networks::NativeCurrency<'_>>,
+) -> SignablePayloadField {
+    let priority_fee_text = format_fee_per_gas(max_priority_fee_per_gas, native);
     SignablePayloadField::TextV2 {
         common: SignablePayloadFieldCommon {
             fallback_text: priority_fee_text.clone(),
@@ -224,26 +426,40 @@ impl EthereumVisualSignConverter {
     /// Creates a layered registry for the current request.
     ///
     /// The global registry is shared via Arc (O(1) clone). If wallet metadata contains
-    /// token information, it's loaded into a request-scoped registry that takes precedence
-    /// during lookups. The request registry is dropped after the request completes.
+    /// an address book, token list or ERC-4626 vaults, it's loaded into a request-scoped
+    /// registry that takes precedence for display lookups; amount lookups still prefer
+    /// the global registry, and ignore wallet tokens entirely under
+    /// `options.strict_registry`, which also leaves wallet vaults unloaded.
+    /// The request registry is dropped after the request completes.
     fn create_layered_registry(
         &self,
-        _options: &VisualSignOptions,
+        options: &VisualSignOptions,
+        chain_id: u64,
     ) -> LayeredRegistry<registry::ContractRegistry> {
-        // TODO: When wallet-provided ChainMetadata includes token metadata (not just ABIs),
-        // create a request registry and use LayeredRegistry::with_request:
-        //
-        // if let Some(ref chain_metadata) = options.metadata {
-        //     if let Some(chain_metadata::Metadata::Ethereum(eth_metadata)) = &chain_metadata.metadata {
-        //         let mut request_registry = registry::ContractRegistry::new();
-        //         // Load wallet tokens into request_registry
-        //         // request_registry.load_wallet_tokens(&eth_metadata.tokens)?;
-        //         return LayeredRegistry::with_request(Arc::clone(&self.registry), request_registry);
-        //     }
-        // }
-
-        // No wallet metadata, use global registry only
-        LayeredRegistry::new(Arc::clone(&self.registry))
+        let eth_metadata = match options.metadata.as_ref().and_then(|m| m.metadata.as_ref()) {
+            Some(chain_metadata::Metadata::Ethereum(eth_metadata))
+                if !eth_metadata.address_book.is_empty()
+                    || !eth_metadata.tokens.is_empty()
+                    || !eth_metadata.erc4626_vaults.is_empty() =>
+            {
+                eth_metadata
+            }
+            // No wallet metadata, use global registry only
+            _ => return LayeredRegistry::new(Arc::clone(&self.registry)),
+        };
+        let mut request_registry = registry::ContractRegistry::new();
+        // Malformed entries are skipped; the rest of the metadata still applies
+        let _ = request_registry.load_address_book(chain_id, &eth_metadata.address_book);
+        let _ = request_registry.load_wallet_tokens(chain_id, &eth_metadata.tokens);
+        if !options.strict_registry {
+            let _ = request_registry.load_wallet_vaults(
+                chain_id,
+                &eth_metadata.erc4626_vaults,
+                &self.registry,
+            );
+        }
+        LayeredRegistry::with_request(Arc::clone(&self.registry), request_registry)
+            .with_strict(options.strict_registry)
     }
 
     /// Shared conversion logic used by both trait impls.
@@ -254,10 +470,8 @@ impl EthereumVisualSignConverter {
         transaction: TypedTransaction,
         options: VisualSignOptions,
     ) -> Result<SignablePayload, VisualSignError> {
-        let layered_registry = self.create_layered_registry(&options);
-
         match transaction.tx_type() {
-            TxType::Legacy | TxType::Eip1559 => {}
+            TxType::Legacy | TxType::Eip1559 | TxType::Eip4844 | TxType::Eip7702 => {}
             unsupported => {
                 return Err(VisualSignError::DecodeError(format!(
                     "Unsupported transaction type: {unsupported}"
@@ -267,16 +481,23 @@ impl EthereumVisualSignConverter {
 
         // Resolve chain_id: metadata > transaction > default (1 for legacy).
         let chain_id = resolve_chain_id(&transaction, &options)?;
+        let layered_registry = self.create_layered_registry(&options, chain_id);
         let metadata_abi = extract_metadata_abi(&options, chain_id, &self.abi_signers);
+        let fiat_prices =
+            fiat::FiatPrices::from_metadata(options.metadata.as_ref(), chain_id, &self.registry);
 
-        convert_to_visual_sign_payload(
+        let mut payload = convert_to_visual_sign_payload(
             transaction,
             options,
             chain_id,
             &layered_registry,
             &self.visualizer_registry,
             metadata_abi.as_ref(),
-        )
+        )?;
+        if let Some(fiat_prices) = fiat_prices {
+            fiat_prices.annotate(&mut payload.fields);
+        }
+        Ok(payload)
     }
 }
 
@@ -307,6 +528,51 @@ impl VisualSignConverterFromString<EthereumTransactionWrapper> for EthereumVisua
         transaction_data: &str,
         options: VisualSignOptions,
     ) -> Result<ConversionResult, VisualSignError> {
+        if eip712::is_typed_data_input(transaction_data) {
+            let allow_typed_data = options
+                .developer_config
+                .as_ref()
+                .is_some_and(|c| c.allow_typed_data);
+            if !allow_typed_data {
+                return Err(VisualSignError::ParseError(
+                    TransactionParseError::InvalidFormat(
+                        "EIP-712 typed data is not accepted on this endpoint".to_string(),
+                    ),
+                ));
+            }
+            // Typed data has no destination to name, so the address book is not loaded
+            let layered_registry = LayeredRegistry::new(Arc::clone(&self.registry));
+            let payload = eip712::convert_typed_data(
+                transaction_data,
+                options,
+                &layered_registry,
+                &self.visualizer_registry,
+            )?;
+            payload.validate_charset()?;
+            return Ok(ConversionResult::new(payload));
+        }
+        if user_operation::is_user_operation_input(transaction_data) {
+            let allow_user_operations = options
+                .developer_config
+                .as_ref()
+                .is_some_and(|c| c.allow_user_operations);
+            if !allow_user_operations {
+                return Err(VisualSignError::ParseError(
+                    TransactionParseError::InvalidFormat(
+                        "ERC-4337 user operations are not accepted on this endpoint".to_string(),
+                    ),
+                ));
+            }
+            let layered_registry = LayeredRegistry::new(Arc::clone(&self.registry));
+            let payload = user_operation::convert_user_operation(
+                transaction_data,
+                options,
+                &layered_registry,
+                &self.visualizer_registry,
+            )?;
+            payload.validate_charset()?;
+            return Ok(ConversionResult::new(payload));
+        }
         let wrapper = EthereumTransactionWrapper::from_string_with_options(
             transaction_data,
             options.developer_config.as_ref(),
@@ -334,12 +600,12 @@ fn extract_unsigned_from_envelope(
         TxEnvelope::Eip2930(_) => Err(EthereumParserError::UnsupportedTransactionType(
             "eip-2930".to_string(),
         )),
-        TxEnvelope::Eip4844(_) => Err(EthereumParserError::UnsupportedTransactionType(
-            "eip-4844".to_string(),
-        )),
-        TxEnvelope::Eip7702(_) => Err(EthereumParserError::UnsupportedTransactionType(
-            "eip-7702".to_string(),
+        // Blobs travel in the sidecar and are not part of what is signed, so
+        // only the transaction body is kept.
+        TxEnvelope::Eip4844(signed) => Ok(TypedTransaction::Eip4844(
+            alloy_consensus::TxEip4844Variant::TxEip4844(signed.tx().tx().clone()),
         )),
+        TxEnvelope::Eip7702(signed) => Ok(TypedTransaction::Eip7702(signed.tx().clone())),
     }
 }
 
@@ -401,11 +667,16 @@ fn decode_unsigned_transaction_bytes(
             TxType::Eip2930 => Err(EthereumParserError::UnsupportedTransactionType(
                 "eip-2930".to_string(),
             )),
-            TxType::Eip4844 => Err(EthereumParserError::UnsupportedTransactionType(
-                "eip-4844".to_string(),
+            TxType::Eip4844 => Ok(TypedTransaction::Eip4844(
+                alloy_consensus::TxEip4844Variant::TxEip4844(
+                    alloy_consensus::TxEip4844::decode(&mut buf).map_err(|e| {
+                        EthereumParserError::FailedToDecodeTransaction(e.to_string())
+                    })?,
+                ),
             )),
-            TxType::Eip7702 => Err(EthereumParserError::UnsupportedTransactionType(
-                "eip-7702".to_string(),
+            TxType::Eip7702 => Ok(TypedTransaction::Eip7702(
+                alloy_consensus::TxEip7702::decode(&mut buf)
+                    .map_err(|e| EthereumParserError::FailedToDecodeTransaction(e.to_string()))?,
             )),
             TxType::Legacy => Err(EthereumParserError::UnexpectedTransactionType(
                 "legacy".to_string(), // This shouldn't happen
@@ -509,8 +780,8 @@ fn extract_metadata_abi(
 /// Returns `Some(vec![field])` (always non-empty) if the destination is a
 /// registered canonical token. The field is either the decoded result or a
 /// raw-hex fallback when the built-in visualizer can't decode the selector
-/// (ERC721 stub returns `None` for all inputs today; the ERC1155 decoder
-/// handles its transfer selectors and returns `None` otherwise). Returning
+/// (the ERC721 and ERC1155 decoders handle their transfer and approval
+/// selectors and return `None` otherwise). Returning
 /// `Some` even with a raw-hex field is what gives
 /// callers a clean "if `Some`, you're done" contract: the downstream
 /// caller-ABI path and ERC20 `decode_transfers` fallback are both gated on
@@ -532,15 +803,15 @@ fn try_known_token_dispatch(
         .get_token_erc_standard(chain_id, to_address)?;
     let decoded = match erc_standard {
         token_metadata::ErcStandard::Erc20 => {
-            (contracts::core::ERC20Visualizer {}).visualize_tx_commands(input)
+            erc20_for_token(layered_registry, chain_id, Some(to_address), input)
         }
-        // `ERC721Visualizer::visualize_tx_commands` currently returns `None`
-        // for all inputs (no built-in ERC721 decoder yet). A follow-up can add
-        // minimal transferFrom/safeTransferFrom decoding for UX.
+        // `ERC721Visualizer` decodes transfers, approve and setApprovalForAll,
+        // including the `transferFrom`/`approve` selectors shared with ERC20.
         token_metadata::ErcStandard::Erc721 => {
             (contracts::core::ERC721Visualizer {}).visualize_tx_commands(input)
         }
-        // `ERC1155Visualizer` decodes safeTransferFrom/safeBatchTransferFrom;
+        // `ERC1155Visualizer` decodes safeTransferFrom/safeBatchTransferFrom
+        // and setApprovalForAll;
         // it returns `None` for any other selector or on decode failure, so the
         // raw-hex fallback below still applies.
         token_metadata::ErcStandard::Erc1155 => {
@@ -552,6 +823,32 @@ fn try_known_token_dispatch(
     Some(vec![field])
 }
 
+/// Runs the ERC20 visualizer against the token at `token`, resolving its
+/// symbol and decimals and checking approval spenders against the compiled-in
+/// registry. Like the known-token lookup this keys off
+/// `transaction.chain_id()`, so caller metadata cannot pick the chain a spender
+/// is looked up on; without one amounts stay raw and only unlimited approvals
+/// are flagged.
+fn erc20_for_token(
+    layered_registry: &LayeredRegistry<registry::ContractRegistry>,
+    chain_id: Option<registry::ChainId>,
+    token: Option<alloy_primitives::Address>,
+    input: &[u8],
+) -> Option<SignablePayloadField> {
+    let visualizer = contracts::core::ERC20Visualizer {};
+    match (chain_id, token) {
+        (Some(chain_id), Some(address)) => visualizer.visualize_for_token(
+            input,
+            &contracts::core::Erc20Token {
+                chain_id,
+                address,
+                registry: layered_registry,
+            },
+        ),
+        _ => visualizer.visualize_tx_commands(input),
+    }
+}
+
 /// Decode calldata using a caller-supplied ABI registry, resolving proxy
 /// destinations to their implementation ABI.
 ///
@@ -575,28 +872,27 @@ fn visualize_with_abi_registry(
     let decode = |abi| DynamicAbiVisualizer::new(abi).visualize_calldata(input, chain_id, None);
 
     // For proxy destinations prefer the linked implementation ABI: the calldata
-    // selector belongs to the implementation, not the proxy. Three paths:
+    // selector belongs to the implementation, not the proxy. The link comes from
+    // either a proxy ABI entry or `proxy_implementations`. Three paths:
     //   1. Impl ABI present and decodes selector → [implementation_address_field, decoded_field]
     //   2. Impl ABI present but selector not found → [unresolved_implementation_field]
     //      + best-effort proxy-own-ABI decode (or raw hex if that also misses)
     //   3. No impl ABI linked → fall through to proxy's own ABI (non-proxy path below)
-    if abi_reg.get_abi_kind(chain_id, to) == Some(abi_registry::AbiKind::Proxy) {
-        if let Some((impl_addr, impl_abi)) = abi_reg.get_implementation_abi(chain_id, to) {
-            if let Some(field) = decode(impl_abi) {
-                return vec![implementation_address_field(impl_addr), field];
-            }
-            // impl ABI present but selector not found — still surface the implementation
-            // address (as unresolved) and attempt proxy's own ABI as the decode fallback.
-            // If neither ABI matches, append raw hex so the signer always sees the
-            // calldata bytes even when the function is unrecognized.
-            let mut fields = vec![unresolved_implementation_field(impl_addr)];
-            if let Some(field) = abi_reg.get_abi_for_address(chain_id, to).and_then(decode) {
-                fields.push(field);
-            } else {
-                fields.push(contracts::core::FallbackVisualizer::new().visualize_hex(input));
-            }
-            return fields;
+    if let Some((impl_addr, impl_abi)) = abi_reg.get_implementation_abi(chain_id, to) {
+        if let Some(field) = decode(impl_abi) {
+            return vec![implementation_address_field(impl_addr), field];
         }
+        // impl ABI present but selector not found — still surface the implementation
+        // address (as unresolved) and attempt proxy's own ABI as the decode fallback.
+        // If neither ABI matches, append raw hex so the signer always sees the
+        // calldata bytes even when the function is unrecognized.
+        let mut fields = vec![unresolved_implementation_field(impl_addr)];
+        if let Some(field) = abi_reg.get_abi_for_address(chain_id, to).and_then(decode) {
+            fields.push(field);
+        } else {
+            fields.push(contracts::core::FallbackVisualizer::new().visualize_hex(input));
+        }
+        return fields;
     }
 
     // Non-proxy destinations and proxy fallback without a linked implementation address.
@@ -645,6 +941,378 @@ fn unresolved_implementation_field(
     }
 }
 
+/// Nesting limit for calls dispatched from batch contracts (Multicall3, ERC-4337 EntryPoint).
+/// Deeper inner calls are left undecoded.
+const MAX_NESTED_CALL_DEPTH: usize = 3;
+
+/// Everything needed to decode one call's calldata. Top-level transactions and
+/// the inner calls of a Multicall3 batch go through the same pipeline, so the
+/// known-token short-circuit and caller-ABI lock-outs apply at every depth.
+struct CallDecoder<'a> {
+    /// Resolved chain id (may come from metadata)
+    chain_id: u64,
+    /// `transaction.chain_id()`; see `try_known_token_dispatch` for why the
+    /// known-token lookup never uses the resolved `chain_id`
+    tx_chain_id: Option<u64>,
+    layered_registry: &'a LayeredRegistry<registry::ContractRegistry>,
+    visualizer_registry: &'a visualizer::EthereumVisualizerRegistry,
+    abi_registry: Option<&'a abi_registry::AbiRegistry>,
+    decode_transfers: bool,
+}
+
+impl CallDecoder<'_> {
+    /// Looks up the registered contract type of `to`. When `to` is not a known
+    /// contract but metadata links it as a proxy, the implementation's type is
+    /// used instead and the implementation address is returned alongside it.
+    ///
+    /// Proxy links are caller-supplied, so they are never consulted for an
+    /// address the registry already knows, nor for a canonical token.
+    fn resolve_contract_type(
+        &self,
+        to: alloy_primitives::Address,
+    ) -> Option<(String, Option<alloy_primitives::Address>)> {
+        if let Some(contract_type) = self
+            .layered_registry
+            .lookup(|r| r.get_contract_type(self.chain_id, to))
+        {
+            return Some((contract_type, None));
+        }
+        if self
+            .layered_registry
+            .global()
+            .get_token_erc_standard(self.tx_chain_id, to)
+            .is_some()
+        {
+            return None;
+        }
+        let implementation = self.abi_registry?.get_implementation(self.chain_id, to)?;
+        let contract_type = self
+            .layered_registry
+            .lookup(|r| r.get_contract_type(self.chain_id, implementation))?;
+        Some((contract_type, Some(implementation)))
+    }
+
+    /// Decodes `input` sent to `to`, always returning at least one field
+    /// (raw hex when nothing else matches).
+    fn decode(
+        &self,
+        to: Option<alloy_primitives::Address>,
+        input: &[u8],
+        depth: usize,
+    ) -> Vec<SignablePayloadField> {
+        let mut input_fields: Vec<SignablePayloadField> = Vec::new();
+
+        // Try to visualize using the registered visualizers
+        if let Some(to_address) = to {
+            if let Some((contract_type, implementation)) = self.resolve_contract_type(to_address) {
+                if self.visualizer_registry.get(&contract_type).is_some() {
+                    // Check if this is a Universal Router contract and visualize it
+                    if contract_type
+                        == crate::protocols::uniswap::config::UniswapUniversalRouter::short_type_id(
+                        )
+                    {
+                        if let Some(field) = (protocols::uniswap::UniversalRouterVisualizer {})
+                            .visualize_tx_commands(
+                                input,
+                                self.chain_id,
+                                Some(self.layered_registry.global()),
+                            )
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is a Permit2 contract and visualize it
+                    else if contract_type
+                        == crate::protocols::uniswap::config::Permit2Contract::short_type_id()
+                    {
+                        if let Some(field) = (protocols::uniswap::Permit2Visualizer)
+                            .visualize_tx_commands(
+                                input,
+                                self.chain_id,
+                                Some(self.layered_registry.global()),
+                            )
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is the Aave Pool and visualize it
+                    else if contract_type
+                        == crate::protocols::aave::config::AavePool::short_type_id()
+                    {
+                        if let Some(field) = (protocols::aave::PoolVisualizer)
+                            .visualize_tx_commands(
+                                input,
+                                self.chain_id,
+                                Some(self.layered_registry.global()),
+                            )
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is an Aave debt token and visualize it
+                    else if contract_type
+                        == crate::protocols::aave::config::AaveDebtToken::short_type_id()
+                    {
+                        if let Some(field) = (protocols::aave::DebtTokenVisualizer)
+                            .visualize_tx_commands(input, self.chain_id, Some(to_address))
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is an ERC-4626 vault and visualize it
+                    else if contract_type
+                        == crate::contracts::core::erc4626::Erc4626Vault::short_type_id()
+                    {
+                        if let Some(field) = (contracts::core::ERC4626Visualizer)
+                            .visualize_tx_commands(
+                                input,
+                                self.chain_id,
+                                to_address,
+                                Some(self.layered_registry),
+                            )
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is Morpho Blue and visualize it
+                    else if contract_type
+                        == crate::protocols::morpho::config::MorphoBlue::short_type_id()
+                    {
+                        if let Some(field) = (protocols::morpho::BlueVisualizer)
+                            .visualize_tx_commands(
+                                input,
+                                self.chain_id,
+                                Some(self.layered_registry.global()),
+                            )
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is a Stargate router or pool and visualize it
+                    else if contract_type
+                        == crate::protocols::stargate::config::StargateContract::short_type_id()
+                    {
+                        if let Some(field) = (protocols::stargate::StargateVisualizer)
+                            .visualize_tx_commands(input, self.chain_id, Some(to_address))
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is a native L2 bridge deposit and visualize it
+                    else if contract_type
+                        == crate::protocols::arbitrum::config::ArbitrumInbox::short_type_id()
+                    {
+                        if let Some(field) = (protocols::arbitrum::InboxVisualizer)
+                            .visualize_tx_commands(input, self.chain_id, to_address)
+                        {
+                            input_fields.push(field);
+                        }
+                    } else if contract_type
+                        == crate::protocols::optimism::config::OptimismL1StandardBridge::short_type_id()
+                    {
+                        if let Some(field) = (protocols::optimism::StandardBridgeVisualizer)
+                            .visualize_tx_commands(
+                                input,
+                                self.chain_id,
+                                to_address,
+                                Some(self.layered_registry.global()),
+                            )
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is the Polygon PoS bridge and visualize it
+                    else if contract_type
+                        == crate::protocols::polygon::config::PolygonRootChainManager::short_type_id()
+                    {
+                        if let Some(field) = (protocols::polygon::RootChainManagerVisualizer)
+                            .visualize_tx_commands(
+                                input,
+                                self.chain_id,
+                                to_address,
+                                Some(self.layered_registry.global()),
+                            )
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is a CCTP TokenMessenger and visualize it
+                    else if contract_type
+                        == crate::protocols::cctp::config::CctpTokenMessenger::short_type_id()
+                    {
+                        if let Some(field) = (protocols::cctp::TokenMessengerVisualizer)
+                            .visualize_tx_commands(
+                                input,
+                                self.chain_id,
+                                Some(self.layered_registry.global()),
+                            )
+                        {
+                            input_fields.push(field);
+                        }
+                    }
+                    // Check if this is an ERC-4337 EntryPoint bundle and decode the
+                    // calls of each user operation through this same pipeline
+                    else if contract_type
+                        == crate::protocols::erc4337::config::Erc4337EntryPoint::short_type_id()
+                    {
+                        if depth < MAX_NESTED_CALL_DEPTH {
+                            if let Some(bundle) =
+                                protocols::erc4337::EntryPointVisualizer::decode_handle_ops(input)
+                            {
+                                input_fields.extend(
+                                    protocols::erc4337::EntryPointVisualizer::render_handle_ops(
+                                        &bundle,
+                                        to_address,
+                                        self.chain_id,
+                                        |target, data| self.decode(Some(target), data, depth + 1),
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                    // Check if this is a Multicall3 batch and decode each inner call
+                    // through this same pipeline
+                    else if contract_type
+                        == crate::protocols::multicall::config::Multicall3Contract::short_type_id()
+                    {
+                        if depth < MAX_NESTED_CALL_DEPTH {
+                            if let Some(batch) =
+                                protocols::multicall::Multicall3Visualizer::decode(input)
+                            {
+                                input_fields.extend(
+                                    protocols::multicall::Multicall3Visualizer::render(
+                                        &batch,
+                                        self.chain_id,
+                                        |call| {
+                                            self.decode(
+                                                Some(call.target),
+                                                &call.call_data,
+                                                depth + 1,
+                                            )
+                                        },
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                }
+                // Say which implementation the proxy's call was decoded as
+                if let Some(implementation) = implementation {
+                    if !input_fields.is_empty() {
+                        input_fields.insert(0, implementation_address_field(implementation));
+                    }
+                }
+            }
+        }
+
+        // Known-token short-circuit: see `try_known_token_dispatch` for the
+        // full rationale (global-only consultation, `transaction.chain_id()`
+        // vs `resolve_chain_id`, pre-EIP-155 any-chain fallback, selector
+        // collisions across ERC standards). The helper guarantees a non-empty
+        // `Some` when it fires, so the `extend` flips `input_fields.is_empty()`
+        // to false and the caller-ABI path and ERC20 `decode_transfers`
+        // fallback below both skip on their existing `is_empty` gates.
+        if input_fields.is_empty() {
+            if let Some(to_address) = to {
+                if let Some(known_fields) = try_known_token_dispatch(
+                    self.layered_registry,
+                    self.tx_chain_id,
+                    to_address,
+                    input,
+                ) {
+                    input_fields.extend(known_fields);
+                }
+            }
+        }
+
+        // Credit delegation on debt tokens outside the built-in address table
+        // (other markets, forks). `approveDelegation` has no ERC20 selector
+        // collision, and it runs ahead of caller-supplied ABIs so the
+        // high-risk warning cannot be replaced by a plain decode.
+        if input_fields.is_empty() {
+            if let Some(field) = (protocols::aave::DebtTokenVisualizer).visualize_tx_commands(
+                input,
+                self.chain_id,
+                to,
+            ) {
+                input_fields.push(field);
+            }
+        }
+
+        // NFT calls on contracts outside the token registry, matched on the
+        // ERC721 selectors that don't collide with ERC20 (`safeTransferFrom`
+        // overloads and `setApprovalForAll`). Also ahead of caller-supplied
+        // ABIs so a collection-wide operator grant keeps its warning.
+        if input_fields.is_empty() {
+            if let Some(field) = (contracts::core::ERC721Visualizer {}).visualize_unambiguous(input)
+            {
+                input_fields.push(field);
+            }
+        }
+
+        // Try dynamic ABI visualization if available. Skipped for known tokens
+        // (the short-circuit above already populated `input_fields`) so
+        // caller-supplied ABIs cannot override the safe built-in decoders for
+        // canonical tokens. For proxy destinations, decode against the linked
+        // implementation ABI rather than assuming the proxy address is the
+        // implementation; this stays strictly after the known-token short-circuit,
+        // so a caller-supplied "proxy" entry can never redirect a canonical token.
+        if input_fields.is_empty() {
+            if let (Some(to_address), Some(abi_reg)) = (to, self.abi_registry) {
+                input_fields.extend(visualize_with_abi_registry(
+                    abi_reg,
+                    self.chain_id,
+                    to_address,
+                    input,
+                ));
+            }
+        }
+
+        // LayerZero OFT `send` on token contracts outside the Stargate table.
+        // After caller-supplied ABIs, which know the token better than we do,
+        // but ahead of the generic fallbacks so the destination chain is shown.
+        if input_fields.is_empty() {
+            if let Some(field) = (protocols::stargate::StargateVisualizer).visualize_tx_commands(
+                input,
+                self.chain_id,
+                to,
+            ) {
+                input_fields.push(field);
+            }
+        }
+
+        // Fallback: Try ERC20 if decode_transfers is enabled. Skipped for
+        // known tokens because the short-circuit above already populated
+        // `input_fields`: `approve(address,uint256)` and
+        // `transfer(address,uint256)` share selectors across ERC standards, so
+        // without this guard a known ERC721 or ERC1155 call would be
+        // mis-rendered as an ERC20 op whenever its own visualizer returns
+        // `None` (e.g. a selector the built-in decoder doesn't recognize),
+        // undermining the "canonical-token short-circuit wins over any other
+        // decoder" property.
+        if input_fields.is_empty() && self.decode_transfers {
+            if let Some(field) = erc20_for_token(self.layered_registry, self.tx_chain_id, to, input)
+            {
+                input_fields.push(field);
+            }
+        }
+        // Selector directory: name the function and decode its arguments from a
+        // bundled list of well-known signatures before giving up on raw hex
+        if input_fields.is_empty() {
+            if let Some(field) =
+                (contracts::core::SelectorDirectoryVisualizer).visualize_tx_commands(input)
+            {
+                input_fields.push(field);
+            }
+        }
+        if input_fields.is_empty() {
+            input_fields.push(contracts::core::FallbackVisualizer::new().visualize_hex(input));
+        }
+
+        input_fields
+    }
+}
+
 fn convert_to_visual_sign_payload(
     transaction: TypedTransaction,
     options: VisualSignOptions,
@@ -653,8 +1321,10 @@ fn convert_to_visual_sign_payload(
     visualizer_registry: &visualizer::EthereumVisualizerRegistry,
     abi_registry: Option<&abi_registry::AbiRegistry>,
 ) -> Result<SignablePayload, VisualSignError> {
-    let network_name = networks::get_network_name(Some(chain_id));
-    let fee_symbol = networks::get_fee_paying_asset_symbol(chain_id);
+    let network_name = networks::resolve_network_name(options.metadata.as_ref(), chain_id);
+    let native = networks::resolve_native_currency(options.metadata.as_ref(), chain_id);
+    let custom_network = networks::find_custom_network(options.metadata.as_ref(), chain_id);
+    let fee_symbol = native.map(|currency| currency.symbol);
 
     let mut fields = vec![SignablePayloadField::TextV2 {
         common: SignablePayloadFieldCommon {
@@ -667,27 +1337,51 @@ fn convert_to_visual_sign_payload(
         // Flag proxy destinations so the signer can see the call goes through a
         // proxy. The kind is caller-supplied (unauthenticated) metadata, so this
         // is purely informational.
-        let badge_text = match abi_registry {
-            Some(reg) if reg.get_abi_kind(chain_id, to) == Some(abi_registry::AbiKind::Proxy) => {
-                Some("Proxy".to_string())
+        let is_proxy = abi_registry.is_some_and(|reg| {
+            reg.get_abi_kind(chain_id, to) == Some(abi_registry::AbiKind::Proxy)
+                || reg.get_implementation(chain_id, to).is_some()
+        });
+        // Address-book names come from the wallet's request metadata
+        let label = layered_registry.lookup(|r| r.get_address_label(chain_id, to).cloned());
+        let badge_text = match (label.as_ref().and_then(|l| l.badge.as_deref()), is_proxy) {
+            (Some(badge), true) => Some(format!("{badge}, Proxy")),
+            (Some(badge), false) => Some(badge.to_string()),
+            (None, true) => Some("Proxy".to_string()),
+            (None, false) => None,
+        };
+        let (name, fallback_text) = match label {
+            Some(label) => {
+                let fallback_text = format!("{} ({to})", label.name);
+                (label.name, fallback_text)
             }
-            _ => None,
+            None => ("To".to_string(), to.to_string()),
         };
         fields.push(SignablePayloadField::AddressV2 {
             common: SignablePayloadFieldCommon {
-                fallback_text: to.to_string(),
+                fallback_text,
                 label: "To".to_string(),
             },
             address_v2: SignablePayloadFieldAddressV2 {
                 address: to.to_string(),
-                name: "To".to_string(),
+                name,
                 asset_label: fee_symbol.unwrap_or_default().to_string(),
                 memo: None,
                 badge_text,
             },
         });
+        // Wallet-defined networks have no built-in explorer, so link to the
+        // wallet's own. A malformed URL just leaves the link out.
+        if let Some(field) = custom_network
+            .and_then(|network| network.address_url(&to))
+            .and_then(|uri| create_uri_field("Explorer", &uri).ok())
+        {
+            fields.push(field.signable_payload_field);
+        }
     }
-    let value = format_ether(transaction.value());
+    let value = native.map_or_else(
+        || format_ether(transaction.value()),
+        |currency| currency.format(transaction.value()),
+    );
     fields.extend([
         SignablePayloadField::AmountV2 {
             common: SignablePayloadFieldCommon {
@@ -711,7 +1405,7 @@ fn convert_to_visual_sign_payload(
     ]);
 
     // Handle gas pricing based on transaction type
-    let gas_price_text = format!("{} gwei", format_gwei(extract_gas_price(&transaction)));
+    let gas_price_text = format_fee_per_gas(extract_gas_price(&transaction), native);
 
     fields.push(SignablePayloadField::TextV2 {
         common: SignablePayloadFieldCommon {
@@ -725,8 +1419,11 @@ fn convert_to_visual_sign_payload(
 
     // Add priority fee for EIP-1559, EIP-4844, and EIP-7702 transactions
     if let Some(priority_fee) = extract_priority_fee(&transaction) {
-        fields.push(create_priority_fee_field(priority_fee));
+        fields.push(create_priority_fee_field(priority_fee, native));
     }
+    fields.extend(create_blob_fields(&transaction, native));
+    fields.push(create_max_network_fee_field(&transaction, native));
+    fields.extend(create_authorization_fields(&transaction));
 
     fields.push(SignablePayloadField::TextV2 {
         common: SignablePayloadFieldCommon {
@@ -741,103 +1438,15 @@ fn convert_to_visual_sign_payload(
     // Add contract call data if present
     let input = transaction.input();
     if !input.is_empty() {
-        let mut input_fields: Vec<SignablePayloadField> = Vec::new();
-
-        // Try to visualize using the registered visualizers
-        let chain_id_val = chain_id;
-        if let Some(to_address) = transaction.to() {
-            if let Some(contract_type) =
-                layered_registry.lookup(|r| r.get_contract_type(chain_id_val, to_address))
-            {
-                if visualizer_registry.get(&contract_type).is_some() {
-                    // Check if this is a Universal Router contract and visualize it
-                    if contract_type
-                        == crate::protocols::uniswap::config::UniswapUniversalRouter::short_type_id(
-                        )
-                    {
-                        if let Some(field) = (protocols::uniswap::UniversalRouterVisualizer {})
-                            .visualize_tx_commands(
-                                input,
-                                chain_id_val,
-                                Some(layered_registry.global()),
-                            )
-                        {
-                            input_fields.push(field);
-                        }
-                    }
-                    // Check if this is a Permit2 contract and visualize it
-                    else if contract_type
-                        == crate::protocols::uniswap::config::Permit2Contract::short_type_id()
-                    {
-                        if let Some(field) = (protocols::uniswap::Permit2Visualizer)
-                            .visualize_tx_commands(
-                                input,
-                                chain_id_val,
-                                Some(layered_registry.global()),
-                            )
-                        {
-                            input_fields.push(field);
-                        }
-                    }
-                }
-            }
-        }
-
-        // Known-token short-circuit: see `try_known_token_dispatch` for the
-        // full rationale (global-only consultation, `transaction.chain_id()`
-        // vs `resolve_chain_id`, pre-EIP-155 any-chain fallback, selector
-        // collisions across ERC standards). The helper guarantees a non-empty
-        // `Some` when it fires, so the `extend` flips `input_fields.is_empty()`
-        // to false and the caller-ABI path and ERC20 `decode_transfers`
-        // fallback below both skip on their existing `is_empty` gates.
-        if input_fields.is_empty() {
-            if let Some(to_address) = transaction.to() {
-                if let Some(known_fields) = try_known_token_dispatch(
-                    layered_registry,
-                    transaction.chain_id(),
-                    to_address,
-                    input,
-                ) {
-                    input_fields.extend(known_fields);
-                }
-            }
-        }
-
-        // Try dynamic ABI visualization if available. Skipped for known tokens
-        // (the short-circuit above already populated `input_fields`) so
-        // caller-supplied ABIs cannot override the safe built-in decoders for
-        // canonical tokens. For proxy destinations, decode against the linked
-        // implementation ABI rather than assuming the proxy address is the
-        // implementation; this stays strictly after the known-token short-circuit,
-        // so a caller-supplied "proxy" entry can never redirect a canonical token.
-        if input_fields.is_empty() {
-            if let (Some(to_address), Some(abi_reg)) = (transaction.to(), abi_registry) {
-                input_fields.extend(visualize_with_abi_registry(
-                    abi_reg, chain_id, to_address, input,
-                ));
-            }
-        }
-
-        // Fallback: Try ERC20 if decode_transfers is enabled. Skipped for
-        // known tokens because the short-circuit above already populated
-        // `input_fields`: `approve(address,uint256)` and
-        // `transfer(address,uint256)` share selectors across ERC standards, so
-        // without this guard a known ERC721 or ERC1155 call would be
-        // mis-rendered as an ERC20 op whenever its own visualizer returns
-        // `None` (e.g. a selector the built-in decoder doesn't recognize),
-        // undermining the "canonical-token short-circuit wins over any other
-        // decoder" property.
-        if input_fields.is_empty() && options.decode_transfers {
-            if let Some(field) = (contracts::core::ERC20Visualizer {}).visualize_tx_commands(input)
-            {
-                input_fields.push(field);
-            }
-        }
-        if input_fields.is_empty() {
-            input_fields.push(contracts::core::FallbackVisualizer::new().visualize_hex(input));
-        }
-
-        fields.append(&mut input_fields);
+        let decoder = CallDecoder {
+            chain_id,
+            tx_chain_id: transaction.chain_id(),
+            layered_registry,
+            visualizer_registry,
+            abi_registry,
+            decode_transfers: options.decode_transfers,
+        };
+        fields.extend(decoder.decode(transaction.to(), input, 0));
     }
 
     let title = options
@@ -1011,6 +1620,16 @@ mod tests {
                         text: "20 gwei".to_string(),
                     },
                 },
+                SignablePayloadField::AmountV2 {
+                    common: SignablePayloadFieldCommon {
+                        fallback_text: "0.00042 ETH".to_string(),
+                        label: "Max Network Fee".to_string(),
+                    },
+                    amount_v2: SignablePayloadFieldAmountV2 {
+                        amount: "0.00042".to_string(),
+                        abbreviation: Some("ETH".to_string()),
+                    },
+                },
                 SignablePayloadField::TextV2 {
                     common: SignablePayloadFieldCommon {
                         fallback_text: "42".to_string(),
@@ -1091,37 +1710,545 @@ mod tests {
                         std::mem::discriminant(actual_field)
                     );
                 }
-            }
+            }
+        }
+    }
+
+    #[test]
+    fn test_transaction_with_input_data() {
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 50000,
+            to: alloy_primitives::TxKind::Call(Address::ZERO),
+            value: U256::ZERO,
+            input: Bytes::from(vec![0x12, 0x34, 0x56, 0x78]),
+        });
+
+        let options = VisualSignOptions::default();
+        let payload = transaction_to_visual_sign(tx, options).unwrap();
+
+        // Check that input data field is present (FallbackVisualizer)
+        assert!(payload.fields.iter().any(|f| f.label() == "Input Data"));
+        let input_field = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "Input Data")
+            .unwrap();
+        if let SignablePayloadField::TextV2 { text_v2, .. } = input_field {
+            assert_eq!(text_v2.text, "0x12345678");
+        }
+    }
+
+    #[test]
+    fn test_multicall3_decodes_each_inner_call() {
+        use crate::contracts::core::erc721::IERC721;
+        use crate::protocols::multicall::config::MULTICALL3_ADDRESS;
+        use crate::protocols::multicall::contracts::multicall3::IMulticall3;
+        use alloy_sol_types::SolCall;
+
+        let approval = IERC721::setApprovalForAllCall {
+            operator: Address::repeat_byte(0x33),
+            approved: true,
+        }
+        .abi_encode();
+        let input = IMulticall3::aggregate3Call {
+            calls: vec![
+                IMulticall3::Call3 {
+                    target: Address::repeat_byte(0x55),
+                    allowFailure: false,
+                    callData: approval.into(),
+                },
+                IMulticall3::Call3 {
+                    target: Address::repeat_byte(0x66),
+                    allowFailure: true,
+                    callData: vec![0xde, 0xad, 0xbe, 0xef].into(),
+                },
+            ],
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 200000,
+            to: alloy_primitives::TxKind::Call(MULTICALL3_ADDRESS),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+        let calls: Vec<_> = payload
+            .fields
+            .iter()
+            .filter(|f| f.label().starts_with("Multicall3 Call"))
+            .collect();
+        assert_eq!(calls.len(), 2);
+
+        // The first inner call goes through the same NFT detection as a
+        // top-level transaction; the second falls back to raw hex.
+        let first = serde_json::to_string(calls[0]).unwrap();
+        assert!(first.contains("NFT Approval For All"), "got: {first}");
+        assert!(first.contains("High risk"), "got: {first}");
+        let second = serde_json::to_string(calls[1]).unwrap();
+        assert!(second.contains("deadbeef"), "got: {second}");
+    }
+
+    #[test]
+    fn test_stargate_send_token_shows_destination_chain() {
+        use crate::protocols::stargate::contracts::bridge::IOFT;
+        use alloy_sol_types::SolCall;
+
+        // Stargate V2 USDC pool on mainnet, bridging to Base
+        let pool: Address = "0xc026395860Db2d07ee33e05fE50ed7bD583189C7"
+            .parse()
+            .unwrap();
+        let input = IOFT::sendTokenCall {
+            _sendParam: IOFT::SendParam {
+                dstEid: 30184,
+                to: Address::repeat_byte(0x42).into_word(),
+                amountLD: U256::from(5_000_000u64),
+                minAmountLD: U256::from(4_990_000u64),
+                extraOptions: Default::default(),
+                composeMsg: Default::default(),
+                oftCmd: Default::default(),
+            },
+            _fee: IOFT::MessagingFee {
+                nativeFee: U256::from(1_000_000_000_000u64),
+                lzTokenFee: U256::ZERO,
+            },
+            _refundAddress: Address::repeat_byte(0x11),
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 200000,
+            to: alloy_primitives::TxKind::Call(pool),
+            value: U256::from(1_000_000_000_000u64),
+            input: Bytes::from(input),
+        });
+
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+        let bridge = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "Stargate Bridge")
+            .expect("Stargate field");
+        assert!(
+            bridge
+                .fallback_text()
+                .starts_with("Bridge 5.000000 USDC to Base (LayerZero ID 30184)"),
+            "got: {}",
+            bridge.fallback_text()
+        );
+    }
+
+    #[test]
+    fn test_base_bridge_deposit_names_l2_and_recipient() {
+        use crate::protocols::optimism::contracts::standard_bridge::IL1StandardBridge;
+        use alloy_sol_types::SolCall;
+
+        let bridge: Address = "0x3154Cf16ccdb4C6d922629664174b904d80F2C35"
+            .parse()
+            .unwrap();
+        let recipient = Address::repeat_byte(0x42);
+        let input = IL1StandardBridge::depositETHToCall {
+            _to: recipient,
+            _minGasLimit: 200_000,
+            _extraData: Default::default(),
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 200000,
+            to: alloy_primitives::TxKind::Call(bridge),
+            value: U256::from(1_000_000_000_000_000_000u64),
+            input: Bytes::from(input),
+        });
+
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+        let deposit = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "OP Stack Deposit")
+            .expect("deposit field");
+        assert_eq!(
+            deposit.fallback_text(),
+            &format!("Deposit ETH to {recipient:?} on Base")
+        );
+    }
+
+    #[test]
+    fn test_polygon_deposit_for_known_erc20() {
+        use crate::protocols::polygon::contracts::root_chain_manager::IRootChainManager;
+        use alloy_sol_types::{SolCall, SolValue};
+
+        // USDC is in the built-in token registry with 6 decimals
+        let usdc: Address = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+            .parse()
+            .unwrap();
+        let manager: Address = "0xA0c68C638235ee32657e8f720a23ceC1bFc77C77"
+            .parse()
+            .unwrap();
+        let input = IRootChainManager::depositForCall {
+            user: Address::repeat_byte(0x42),
+            rootToken: usdc,
+            depositData: U256::from(3_000_000u64).abi_encode().into(),
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 200000,
+            to: alloy_primitives::TxKind::Call(manager),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+        let deposit = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "Polygon Deposit")
+            .expect("deposit field");
+        assert!(
+            deposit
+                .fallback_text()
+                .starts_with("Deposit 3.000000 USDC to"),
+            "got: {}",
+            deposit.fallback_text()
+        );
+    }
+
+    #[test]
+    fn test_cctp_burn_formats_usdc_and_destination() {
+        use crate::protocols::cctp::contracts::token_messenger::ITokenMessenger;
+        use alloy_sol_types::SolCall;
+
+        let usdc: Address = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+            .parse()
+            .unwrap();
+        let messenger: Address = "0xBd3fa81B58Ba92a82136038B25aDec7066af3155"
+            .parse()
+            .unwrap();
+        let recipient = Address::repeat_byte(0x42);
+        let input = ITokenMessenger::depositForBurnCall {
+            amount: U256::from(250_000_000u64),
+            destinationDomain: 3,
+            mintRecipient: recipient.into_word(),
+            burnToken: usdc,
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 200000,
+            to: alloy_primitives::TxKind::Call(messenger),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+        let transfer = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "CCTP Transfer")
+            .expect("CCTP field");
+        assert_eq!(
+            transfer.fallback_text(),
+            &format!("Bridge 250.000000 USDC to {recipient:?} on Arbitrum (CCTP domain 3)")
+        );
+    }
+
+    #[test]
+    fn test_entry_point_handle_ops_decodes_account_calls() {
+        use crate::protocols::erc4337::config::ENTRY_POINT_V07;
+        use crate::protocols::erc4337::contracts::entry_point::{IEntryPointV07, ISmartAccount};
+        use alloy_sol_types::SolCall;
+
+        let recipient = Address::repeat_byte(0x77);
+        let transfer = crate::contracts::core::erc20::IERC20::transferCall {
+            to: recipient,
+            amount: U256::from(1000u64),
+        }
+        .abi_encode();
+        let input = IEntryPointV07::handleOpsCall {
+            ops: vec![IEntryPointV07::PackedUserOperation {
+                sender: Address::repeat_byte(0x11),
+                nonce: U256::ZERO,
+                initCode: Bytes::new(),
+                callData: ISmartAccount::executeCall {
+                    dest: Address::repeat_byte(0x22),
+                    value: U256::ZERO,
+                    func: transfer.into(),
+                }
+                .abi_encode()
+                .into(),
+                accountGasLimits: Default::default(),
+                preVerificationGas: U256::ZERO,
+                gasFees: Default::default(),
+                paymasterAndData: Bytes::new(),
+                signature: Bytes::new(),
+            }],
+            beneficiary: Address::repeat_byte(0x33),
         }
+        .abi_encode();
+        let tx = TypedTransaction::Eip1559(alloy_consensus::TxEip1559 {
+            chain_id: ChainId::from(1u64),
+            nonce: 1,
+            gas_limit: 500_000,
+            max_fee_per_gas: 30_000_000_000u128,
+            max_priority_fee_per_gas: 1_000_000_000u128,
+            to: alloy_primitives::TxKind::Call(ENTRY_POINT_V07),
+            value: U256::ZERO,
+            access_list: Default::default(),
+            input: Bytes::from(input),
+        });
+
+        let options = VisualSignOptions {
+            decode_transfers: true,
+            ..Default::default()
+        };
+        let payload = transaction_to_visual_sign(tx, options).unwrap();
+        assert!(
+            payload
+                .fields
+                .iter()
+                .any(|f| f.label() == "ERC-4337 Bundle")
+        );
+        let json = serde_json::to_string(&payload).unwrap();
+        assert!(json.contains("Account Call 1 of 1"), "got: {json}");
+        assert!(json.contains(&format!("{recipient:?}")), "got: {json}");
     }
 
     #[test]
-    fn test_transaction_with_input_data() {
+    fn test_known_erc4626_vault_deposit_is_decoded() {
+        use crate::contracts::core::erc4626::IERC4626;
+        use alloy_sol_types::SolCall;
+
+        // sDAI on mainnet, registered as a built-in vault over DAI
+        let sdai: Address = "0x83F20F44975D03b1b09e64809B757c47f942BEeA"
+            .parse()
+            .unwrap();
+        let input = IERC4626::depositCall {
+            assets: U256::from(1_000u64),
+            receiver: Address::repeat_byte(0x11),
+        }
+        .abi_encode();
         let tx = TypedTransaction::Legacy(TxLegacy {
             chain_id: Some(ChainId::from(1u64)),
             nonce: 1,
             gas_price: 1_000_000_000u128,
-            gas_limit: 50000,
-            to: alloy_primitives::TxKind::Call(Address::ZERO),
+            gas_limit: 120000,
+            to: alloy_primitives::TxKind::Call(sdai),
             value: U256::ZERO,
-            input: Bytes::from(vec![0x12, 0x34, 0x56, 0x78]),
+            input: Bytes::from(input),
         });
 
-        let options = VisualSignOptions::default();
-        let payload = transaction_to_visual_sign(tx, options).unwrap();
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+        let field = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "ERC4626 Deposit")
+            .expect("vault deposit field should be present");
+        let rendered = serde_json::to_string(field).unwrap();
+        assert!(
+            rendered.contains("0x6b175474e89094c44da98b954eedeac495271d0f"),
+            "underlying DAI asset should be shown: {rendered}"
+        );
+    }
 
-        // Check that input data field is present (FallbackVisualizer)
-        assert!(payload.fields.iter().any(|f| f.label() == "Input Data"));
-        let input_field = payload
+    #[test]
+    fn test_wallet_erc4626_vault_deposit_is_decoded_unless_strict() {
+        use crate::contracts::core::erc4626::IERC4626;
+        use alloy_sol_types::SolCall;
+
+        let vault = Address::repeat_byte(0x46);
+        let input = IERC4626::depositCall {
+            assets: U256::from(1_500_000u64),
+            receiver: Address::repeat_byte(0x11),
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 120000,
+            to: alloy_primitives::TxKind::Call(vault),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+        // A vault over mainnet USDC, plus one claiming the built-in sDAI address
+        let metadata = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                erc4626_vaults: [
+                    (
+                        format!("{vault:?}"),
+                        "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48".to_string(),
+                    ),
+                    (
+                        "0x83F20F44975D03b1b09e64809B757c47f942BEeA".to_string(),
+                        format!("{vault:?}"),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            })),
+        };
+
+        let payload = transaction_to_visual_sign(
+            tx.clone(),
+            VisualSignOptions {
+                metadata: Some(metadata.clone()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let field = payload
             .fields
             .iter()
-            .find(|f| f.label() == "Input Data")
-            .unwrap();
-        if let SignablePayloadField::TextV2 { text_v2, .. } = input_field {
-            assert_eq!(text_v2.text, "0x12345678");
+            .find(|f| f.label() == "ERC4626 Deposit")
+            .expect("wallet vault deposit field should be present");
+        assert!(field.fallback_text().contains("Deposit 1.500000 USDC"));
+        let rendered = serde_json::to_string(field).unwrap();
+        assert!(
+            rendered.contains("vault asset from unverified wallet metadata"),
+            "wallet-supplied vault should be flagged: {rendered}"
+        );
+
+        let strict = transaction_to_visual_sign(
+            tx,
+            VisualSignOptions {
+                metadata: Some(metadata),
+                strict_registry: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(strict.fields.iter().all(|f| f.label() != "ERC4626 Deposit"));
+    }
+
+    #[test]
+    fn test_unregistered_nft_set_approval_for_all_renders_warning() {
+        use crate::contracts::core::erc721::IERC721;
+        use alloy_sol_types::SolCall;
+
+        let input = IERC721::setApprovalForAllCall {
+            operator: Address::repeat_byte(0x33),
+            approved: true,
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 80000,
+            to: alloy_primitives::TxKind::Call(Address::repeat_byte(0x55)),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+
+        let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+        let field = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "NFT Approval For All")
+            .expect("approval for all field should be present");
+        assert!(field.fallback_text().contains("WARNING"));
+        assert!(!payload.fields.iter().any(|f| f.label() == "Input Data"));
+    }
+
+    #[test]
+    fn test_aave_approve_delegation_renders_warning() {
+        use crate::protocols::aave::contracts::debt_token::ICreditDelegationToken;
+        use alloy_sol_types::SolCall;
+
+        let input = ICreditDelegationToken::approveDelegationCall {
+            delegatee: Address::repeat_byte(0x22),
+            amount: U256::MAX,
+        }
+        .abi_encode();
+
+        // Registered mainnet WETH debt token and an unregistered fork address
+        // must both surface the credit delegation warning.
+        for to in [
+            "0xeA51d7853EEFb32b6ee06b1C12E6dcCA88Be0fFE"
+                .parse::<Address>()
+                .unwrap(),
+            Address::repeat_byte(0x44),
+        ] {
+            let tx = TypedTransaction::Legacy(TxLegacy {
+                chain_id: Some(ChainId::from(1u64)),
+                nonce: 1,
+                gas_price: 1_000_000_000u128,
+                gas_limit: 80000,
+                to: alloy_primitives::TxKind::Call(to),
+                value: U256::ZERO,
+                input: Bytes::from(input.clone()),
+            });
+
+            let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+            let field = payload
+                .fields
+                .iter()
+                .find(|f| f.label() == "Aave Credit Delegation")
+                .expect("credit delegation field should be present");
+            assert!(field.fallback_text().contains("WARNING"));
+            assert!(!payload.fields.iter().any(|f| f.label() == "Input Data"));
         }
     }
 
+    #[test]
+    fn test_known_token_approve_flags_unlimited_approval_to_unknown_spender() {
+        let usdc: Address = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+            .parse()
+            .unwrap();
+        let permit2 = crate::protocols::uniswap::config::UniswapConfig::permit2_address();
+        let render = |spender: Address| {
+            let call = IERC20::approveCall {
+                spender,
+                amount: U256::MAX,
+            };
+            let tx = TypedTransaction::Legacy(TxLegacy {
+                chain_id: Some(ChainId::from(1u64)),
+                nonce: 1,
+                gas_price: 1_000_000_000u128,
+                gas_limit: 60000,
+                to: alloy_primitives::TxKind::Call(usdc),
+                value: U256::ZERO,
+                input: Bytes::from(IERC20::approveCall::abi_encode(&call)),
+            });
+            let payload = transaction_to_visual_sign(tx, VisualSignOptions::default()).unwrap();
+            payload
+                .fields
+                .iter()
+                .find(|f| f.label() == "ERC20 Approve")
+                .expect("approve field should be present")
+                .fallback_text()
+                .clone()
+        };
+
+        assert!(
+            render(Address::repeat_byte(0x44))
+                .contains("WARNING: Unlimited approval to unknown contract")
+        );
+        // Permit2 is a registered contract: still unlimited, but not unknown
+        let known = render(permit2);
+        assert!(
+            known.contains("WARNING: Unlimited approval."),
+            "got: {known}"
+        );
+        assert!(!known.contains("unknown contract"), "got: {known}");
+    }
+
     /// Regression: caller-supplied ABIs keyed to a known token address
     /// (e.g. USDC) must not override the safe built-in ERC20/ERC721 decoder.
     ///
@@ -1210,12 +2337,19 @@ mod tests {
 
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: Some(ChainMetadata {
                 metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                     network_id: Some("ETHEREUM_MAINNET".to_string()),
                     abi_mappings: abi_mappings.into_iter().collect(),
+                    proxy_implementations: Default::default(),
+                    custom_networks: Default::default(),
+                    address_book: Default::default(),
+                    token_prices: Default::default(),
+                    tokens: Default::default(),
+                    erc4626_vaults: Default::default(),
                 })),
             }),
             developer_config: None,
@@ -1364,12 +2498,19 @@ mod tests {
         // bound to the payload.
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: Some(ChainMetadata {
                 metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                     network_id: Some("POLYGON_MAINNET".to_string()),
                     abi_mappings: abi_mappings.into_iter().collect(),
+                    proxy_implementations: Default::default(),
+                    custom_networks: Default::default(),
+                    address_book: Default::default(),
+                    token_prices: Default::default(),
+                    tokens: Default::default(),
+                    erc4626_vaults: Default::default(),
                 })),
             }),
             developer_config: None,
@@ -1471,12 +2612,19 @@ mod tests {
         // registry) so any chain-id-based lookup that trusts metadata misses.
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: Some(ChainMetadata {
                 metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                     network_id: Some("POLYGON_MAINNET".to_string()),
                     abi_mappings: abi_mappings.into_iter().collect(),
+                    proxy_implementations: Default::default(),
+                    custom_networks: Default::default(),
+                    address_book: Default::default(),
+                    token_prices: Default::default(),
+                    tokens: Default::default(),
+                    erc4626_vaults: Default::default(),
                 })),
             }),
             developer_config: None,
@@ -1512,12 +2660,12 @@ mod tests {
     /// `approve(address,uint256)` selector must not be mis-rendered as an ERC20
     /// approval. The dispatch order is:
     ///
-    ///   1. `try_known_token_dispatch` fires (ERC721 visualizer returns None,
-    ///      helper substitutes a raw-hex field so its `Some` is non-empty).
+    ///   1. `try_known_token_dispatch` fires and the ERC721 visualizer decodes
+    ///      the call as an ERC721 approval, so its `Some` is non-empty.
     ///   2. Caller-ABI path skipped (`input_fields.is_empty()` is now false).
     ///   3. ERC20 `decode_transfers` fallback also skipped for the same reason,
     ///      or it would decode `approve` as an ERC20 op.
-    ///   4. Raw-hex (from the helper) is the rendered result.
+    ///   4. The ERC721 approval is the rendered result.
     ///
     /// Without the helper's non-empty `Some` contract this regresses to "ERC20
     /// Approve" output, which is the spoofing surface this fix closes.
@@ -1568,6 +2716,7 @@ mod tests {
 
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: None,
@@ -1577,17 +2726,20 @@ mod tests {
         let wrapper = EthereumTransactionWrapper::new(tx);
         let payload = converter.to_payload(wrapper, options).unwrap();
 
-        // No PreviewLayout should be emitted: ERC721 has no decoder, the
-        // ERC20 fallback is skipped for known non-ERC20 tokens, and the
-        // raw-hex fallback produces a RawData field, not a PreviewLayout.
-        let preview = payload
+        // The only PreviewLayout is the ERC721 decoder's; the ERC20 fallback
+        // is skipped for known non-ERC20 tokens.
+        let previews: Vec<_> = payload
             .fields
             .iter()
-            .find(|f| matches!(f, SignablePayloadField::PreviewLayout { .. }));
-        assert!(
-            preview.is_none(),
-            "known ERC721 + ERC20-shared selector must not produce a PreviewLayout: {payload:?}",
+            .filter(|f| matches!(f, SignablePayloadField::PreviewLayout { .. }))
+            .collect();
+        assert_eq!(
+            previews.len(),
+            1,
+            "known ERC721 + ERC20-shared selector must render one ERC721 decode: {payload:?}",
         );
+        assert_eq!(previews[0].label(), "ERC721 Approve");
+        assert!(previews[0].fallback_text().contains("token 42"));
 
         // And, defensively, no ERC20-decoder text should appear anywhere in
         // the serialized payload. Use serde_json so we scan every text-bearing
@@ -1662,6 +2814,7 @@ mod tests {
 
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: None,
@@ -1809,12 +2962,19 @@ mod tests {
 
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: Some(ChainMetadata {
                 metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                     network_id: Some("ETHEREUM_MAINNET".to_string()),
                     abi_mappings: abi_mappings.into_iter().collect(),
+                    proxy_implementations: Default::default(),
+                    custom_networks: Default::default(),
+                    address_book: Default::default(),
+                    token_prices: Default::default(),
+                    tokens: Default::default(),
+                    erc4626_vaults: Default::default(),
                 })),
             }),
             developer_config: None,
@@ -1963,12 +3123,19 @@ mod tests {
         // bound to the payload.
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: Some(ChainMetadata {
                 metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                     network_id: Some("POLYGON_MAINNET".to_string()),
                     abi_mappings: abi_mappings.into_iter().collect(),
+                    proxy_implementations: Default::default(),
+                    custom_networks: Default::default(),
+                    address_book: Default::default(),
+                    token_prices: Default::default(),
+                    tokens: Default::default(),
+                    erc4626_vaults: Default::default(),
                 })),
             }),
             developer_config: None,
@@ -2002,7 +3169,7 @@ mod tests {
     ///
     /// Same security property: a known ERC721 token called with the
     /// ERC20/ERC721 shared `approve(address,uint256)` selector must not be
-    /// mis-rendered as an ERC20 approval, and must fall through to raw hex.
+    /// mis-rendered as an ERC20 approval, and must render as an ERC721 one.
     /// Identical to the legacy original except for the
     /// `TypedTransaction::Eip1559` construction.
     #[test]
@@ -2054,6 +3221,7 @@ mod tests {
 
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: None,
@@ -2063,17 +3231,20 @@ mod tests {
         let wrapper = EthereumTransactionWrapper::new(tx);
         let payload = converter.to_payload(wrapper, options).unwrap();
 
-        // No PreviewLayout should be emitted: ERC721 has no decoder, the
-        // ERC20 fallback is skipped for known non-ERC20 tokens, and the
-        // raw-hex fallback produces a RawData field, not a PreviewLayout.
-        let preview = payload
+        // The only PreviewLayout is the ERC721 decoder's; the ERC20 fallback
+        // is skipped for known non-ERC20 tokens.
+        let previews: Vec<_> = payload
             .fields
             .iter()
-            .find(|f| matches!(f, SignablePayloadField::PreviewLayout { .. }));
-        assert!(
-            preview.is_none(),
-            "known ERC721 + ERC20-shared selector must not produce a PreviewLayout: {payload:?}",
+            .filter(|f| matches!(f, SignablePayloadField::PreviewLayout { .. }))
+            .collect();
+        assert_eq!(
+            previews.len(),
+            1,
+            "known ERC721 + ERC20-shared selector must render one ERC721 decode: {payload:?}",
         );
+        assert_eq!(previews[0].label(), "ERC721 Approve");
+        assert!(previews[0].fallback_text().contains("token 42"));
 
         // And, defensively, no ERC20-decoder text should appear anywhere in
         // the serialized payload. Use serde_json so we scan every text-bearing
@@ -2091,13 +3262,13 @@ mod tests {
     /// `layered_registry.global()` (the compiled-in layer) and deliberately
     /// NEVER the caller-supplied request layer. That choice is the load-bearing
     /// invariant that stops caller-provided metadata from poisoning the
-    /// known-token lookup. No public path builds a request layer today, so this
-    /// drives the private `try_known_token_dispatch` directly.
+    /// known-token lookup. This drives the private `try_known_token_dispatch`
+    /// directly with a hand-built request layer.
     ///
     /// This locks the `.global()` choice: a future refactor that switched the
     /// lookup to `.lookup()`/`.request()` would consult the poisoned request
-    /// entry (Erc721) first, route to the ERC721 visualizer (which returns
-    /// None), fall back to raw hex, and so drop the "Recipient"/"Amount"
+    /// entry (Erc721) first, route to the ERC721 visualizer (which has no
+    /// `transfer` decoding), fall back to raw hex, and so drop the "Recipient"/"Amount"
     /// rendering this test asserts -- failing the test.
     #[test]
     fn test_known_token_dispatch_ignores_poisoned_request_layer() {
@@ -2169,6 +3340,11 @@ mod tests {
             rendered.contains("Amount"),
             "expected built-in ERC20 'Amount' rendering (global Erc20 won): {rendered}",
         );
+        // The amount also keeps the built-in symbol and decimals
+        assert!(
+            rendered.contains("1000.000000 USDC") && !rendered.contains("EVIL"),
+            "poisoned request entry must not rescale the amount: {rendered}",
+        );
     }
 
     #[test]
@@ -2185,6 +3361,7 @@ mod tests {
 
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: false,
             transaction_name: Some("Custom Transaction Title".to_string()),
             metadata: None,
@@ -2310,7 +3487,7 @@ mod tests {
                 "Unsupported transaction type: eip-2930".to_string()
             ))
         );
-        // Test with EIP-4844 transaction (unsupported)
+        // Test with EIP-4844 transaction (sidecar not required)
         let eip4844_tx = TypedTransaction::Eip4844(alloy_consensus::TxEip4844Variant::TxEip4844(
             alloy_consensus::TxEip4844 {
                 chain_id: ChainId::from(1u64),
@@ -2328,11 +3505,9 @@ mod tests {
         ));
         assert_eq!(
             EthereumTransactionWrapper::from_string(&unsigned_to_hex(&eip4844_tx)),
-            Err(TransactionParseError::DecodeError(
-                "Unsupported transaction type: eip-4844".to_string()
-            ))
+            Ok(EthereumTransactionWrapper::new(eip4844_tx.clone())),
         );
-        // Test with EIP-7702 transaction (unsupported)
+        // Test with EIP-7702 transaction
         let eip7702_tx = TypedTransaction::Eip7702(alloy_consensus::TxEip7702 {
             chain_id: ChainId::from(1u64),
             nonce: 1,
@@ -2347,9 +3522,7 @@ mod tests {
         });
         assert_eq!(
             EthereumTransactionWrapper::from_string(&unsigned_to_hex(&eip7702_tx)),
-            Err(TransactionParseError::DecodeError(
-                "Unsupported transaction type: eip-7702".to_string()
-            ))
+            Ok(EthereumTransactionWrapper::new(eip7702_tx.clone())),
         );
     }
 
@@ -2398,6 +3571,109 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_blob_transaction_shows_blob_fee_and_hashes() {
+        let hash = alloy_primitives::B256::repeat_byte(0x01);
+        let tx = TypedTransaction::Eip4844(alloy_consensus::TxEip4844Variant::TxEip4844(
+            alloy_consensus::TxEip4844 {
+                chain_id: ChainId::from(1u64),
+                nonce: 7,
+                gas_limit: 21000,
+                max_fee_per_gas: 30_000_000_000u128,
+                max_priority_fee_per_gas: 2_000_000_000u128,
+                to: Address::ZERO,
+                value: U256::ZERO,
+                access_list: Default::default(),
+                input: Bytes::new(),
+                blob_versioned_hashes: vec![hash, hash],
+                max_fee_per_blob_gas: 10_000_000_000u128,
+            },
+        ));
+
+        let payload =
+            transaction_string_to_visual_sign(&unsigned_to_hex(&tx), VisualSignOptions::default())
+                .unwrap();
+        let text = |label: &str| {
+            payload
+                .fields
+                .iter()
+                .find(|f| f.label() == label)
+                .map(|f| f.fallback_text().to_string())
+        };
+        assert_eq!(text("Max Fee Per Blob Gas").as_deref(), Some("10 gwei"));
+        assert_eq!(text("Blob Count").as_deref(), Some("2"));
+        // 21000 gas at 30 gwei plus 2 blobs of 131072 blob gas at 10 gwei
+        assert_eq!(text("Max Network Fee").as_deref(), Some("0.00325144 ETH"));
+        assert_eq!(text("Blob 2 Versioned Hash"), Some(format!("{hash:?}")));
+    }
+
+    #[test]
+    fn test_set_code_transaction_lists_authorizations_with_warning() {
+        let delegate = Address::repeat_byte(0x77);
+        let authorization = |chain_id: u64, address: Address| {
+            alloy_eips::eip7702::Authorization {
+                chain_id: U256::from(chain_id),
+                address,
+                nonce: 4,
+            }
+            .into_signed(alloy_primitives::Signature::new(
+                U256::from(1),
+                U256::from(1),
+                false,
+            ))
+        };
+        let tx = TypedTransaction::Eip7702(alloy_consensus::TxEip7702 {
+            chain_id: ChainId::from(1u64),
+            nonce: 3,
+            gas_limit: 100_000,
+            max_fee_per_gas: 30_000_000_000u128,
+            max_priority_fee_per_gas: 2_000_000_000u128,
+            to: Address::ZERO,
+            value: U256::ZERO,
+            access_list: Default::default(),
+            input: Bytes::new(),
+            authorization_list: vec![
+                authorization(1, delegate),
+                authorization(0, delegate),
+                authorization(1, Address::ZERO),
+            ],
+        });
+
+        let payload =
+            transaction_string_to_visual_sign(&unsigned_to_hex(&tx), VisualSignOptions::default())
+                .unwrap();
+        let text = |label: &str| {
+            payload
+                .fields
+                .iter()
+                .find(|f| f.label() == label)
+                .map(|f| f.fallback_text().to_string())
+        };
+        assert!(
+            text("Warning")
+                .unwrap()
+                .contains("sets the code of 3 account(s)")
+        );
+        assert_eq!(
+            text("Authorization 1"),
+            Some(format!(
+                "Delegate the account's code to {delegate:?} on Ethereum Mainnet"
+            ))
+        );
+        assert_eq!(
+            text("Authorization 2"),
+            Some(format!(
+                "Delegate the account's code to {delegate:?} on any chain"
+            ))
+        );
+        assert_eq!(
+            text("Authorization 3").as_deref(),
+            Some("Clear the account's delegation on Ethereum Mainnet")
+        );
+        let json = serde_json::to_string(&payload).unwrap();
+        assert!(json.contains("the delegation can be replayed anywhere"));
+    }
+
     #[test]
     fn test_non_eth_chain_fee_symbol() {
         // Polygon (chain 137) should use "POL" as the fee-paying asset symbol
@@ -2435,6 +3711,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_fee_per_gas_uses_native_units_for_non_18_decimal_currencies() {
+        let eth = networks::get_native_currency(1);
+        assert_eq!(format_fee_per_gas(30_000_000_000, eth), "30 gwei");
+        assert_eq!(format_fee_per_gas(30_000_000_000, None), "30 gwei");
+        // Gwei means nothing for a 6-decimal currency
+        let six = Some(networks::NativeCurrency {
+            symbol: "USDC",
+            decimals: 6,
+        });
+        assert_eq!(format_fee_per_gas(250_000, six), "0.25 USDC");
+    }
+
     #[test]
     fn test_unknown_chain_fee_symbol() {
         // Unknown chain (999999) should have no fee-paying asset symbol:
@@ -2492,6 +3781,7 @@ mod tests {
                 &unsigned_to_hex(&tx),
                 VisualSignOptions {
                     include_intermediate_output: false,
+                    strict_registry: false,
                     decode_transfers: true,
                     transaction_name: Some("Test Transaction".to_string()),
                     metadata: None,
@@ -2562,6 +3852,16 @@ mod tests {
                             text: "2 gwei".to_string(),
                         },
                     },
+                    SignablePayloadField::AmountV2 {
+                        common: SignablePayloadFieldCommon {
+                            fallback_text: "0.00063 ETH".to_string(),
+                            label: "Max Network Fee".to_string(),
+                        },
+                        amount_v2: SignablePayloadFieldAmountV2 {
+                            amount: "0.00063".to_string(),
+                            abbreviation: Some("ETH".to_string()),
+                        },
+                    },
                     SignablePayloadField::TextV2 {
                         common: SignablePayloadFieldCommon {
                             fallback_text: "1".to_string(),
@@ -2598,6 +3898,10 @@ mod tests {
         // Should succeed with developer_config.allow_signed_transactions = true
         let developer_config = DeveloperConfig {
             allow_signed_transactions: true,
+            allow_typed_data: false,
+            allow_offchain_messages: false,
+            allow_personal_messages: false,
+            allow_user_operations: false,
         };
         let result = EthereumTransactionWrapper::from_string_with_options(
             signed_tx,
@@ -2616,6 +3920,44 @@ mod tests {
         assert_eq!(tx.nonce(), 88);
     }
 
+    #[test]
+    fn test_typed_data_requires_developer_config() {
+        let typed_data = r#"{
+            "types": {
+                "EIP712Domain": [{"name": "name", "type": "string"}],
+                "Note": [{"name": "text", "type": "string"}]
+            },
+            "primaryType": "Note",
+            "domain": {"name": "Notes"},
+            "message": {"text": "hello"}
+        }"#;
+
+        let rejected = transaction_string_to_visual_sign(typed_data, VisualSignOptions::default());
+        assert!(
+            matches!(
+                rejected,
+                Err(VisualSignError::ParseError(
+                    TransactionParseError::InvalidFormat(_)
+                ))
+            ),
+            "Typed data should be rejected by default"
+        );
+
+        let options = VisualSignOptions {
+            developer_config: Some(DeveloperConfig {
+                allow_signed_transactions: false,
+                allow_typed_data: true,
+                allow_offchain_messages: false,
+                allow_personal_messages: false,
+                allow_user_operations: false,
+            }),
+            ..Default::default()
+        };
+        let payload = transaction_string_to_visual_sign(typed_data, options).unwrap();
+        assert_eq!(payload.payload_type, "EthereumTypedData");
+        assert_eq!(payload.title, "EIP-712 Typed Data");
+    }
+
     #[test]
     fn test_proxy_with_unregistered_impl_produces_no_implementation_field() {
         // Proxy is registered and points to an implementation address, but
@@ -2653,12 +3995,19 @@ mod tests {
         });
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: false,
             transaction_name: None,
             metadata: Some(ChainMetadata {
                 metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                     network_id: Some("ETHEREUM_MAINNET".to_string()),
                     abi_mappings: abi_mappings.into_iter().collect(),
+                    proxy_implementations: Default::default(),
+                    custom_networks: Default::default(),
+                    address_book: Default::default(),
+                    token_prices: Default::default(),
+                    tokens: Default::default(),
+                    erc4626_vaults: Default::default(),
                 })),
             }),
             developer_config: None,
@@ -2718,12 +4067,19 @@ mod tests {
         });
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: false,
             transaction_name: None,
             metadata: Some(ChainMetadata {
                 metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                     network_id: Some("ETHEREUM_MAINNET".to_string()),
                     abi_mappings: abi_mappings.into_iter().collect(),
+                    proxy_implementations: Default::default(),
+                    custom_networks: Default::default(),
+                    address_book: Default::default(),
+                    token_prices: Default::default(),
+                    tokens: Default::default(),
+                    erc4626_vaults: Default::default(),
                 })),
             }),
             developer_config: None,
@@ -2805,12 +4161,19 @@ mod tests {
         });
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: false,
             transaction_name: None,
             metadata: Some(ChainMetadata {
                 metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                     network_id: Some("ETHEREUM_MAINNET".to_string()),
                     abi_mappings: abi_mappings.into_iter().collect(),
+                    proxy_implementations: Default::default(),
+                    custom_networks: Default::default(),
+                    address_book: Default::default(),
+                    token_prices: Default::default(),
+                    tokens: Default::default(),
+                    erc4626_vaults: Default::default(),
                 })),
             }),
             developer_config: None,
@@ -2842,4 +4205,168 @@ mod tests {
             Some("Proxy implementation (unresolved)"),
         );
     }
+
+    fn options_with_proxy_link(
+        abi_mappings: std::collections::BTreeMap<String, Abi>,
+        proxy: Address,
+        implementation: Address,
+    ) -> VisualSignOptions {
+        VisualSignOptions {
+            metadata: Some(ChainMetadata {
+                metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                    network_id: Some("ETHEREUM_MAINNET".to_string()),
+                    abi_mappings,
+                    proxy_implementations: [(proxy.to_string(), implementation.to_string())]
+                        .into_iter()
+                        .collect(),
+                    custom_networks: Default::default(),
+                    address_book: Default::default(),
+                    token_prices: Default::default(),
+                    tokens: Default::default(),
+                    erc4626_vaults: Default::default(),
+                })),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_proxy_implementations_link_decodes_against_implementation_abi() {
+        // The proxy has no ABI entry at all; only the implementation does.
+        let proxy: Address = "0x0000000000000000000000000000000000000030"
+            .parse()
+            .unwrap();
+        let implementation: Address = "0x0000000000000000000000000000000000000031"
+            .parse()
+            .unwrap();
+        let abi = r#"[{"type":"function","name":"doThing","inputs":[],"outputs":[],"stateMutability":"nonpayable"}]"#;
+        let abi_mappings = [(implementation.to_string(), signed_abi(abi, &implementation))]
+            .into_iter()
+            .collect();
+
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 0,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 50_000,
+            to: alloy_primitives::TxKind::Call(proxy),
+            value: U256::ZERO,
+            input: Bytes::from(keccak256(b"doThing()")[..4].to_vec()),
+        });
+        let payload = EthereumVisualSignConverter::new()
+            .to_payload(
+                EthereumTransactionWrapper::new(tx),
+                options_with_proxy_link(abi_mappings, proxy, implementation),
+            )
+            .unwrap();
+
+        let rendered = serde_json::to_string(&payload).unwrap();
+        assert!(rendered.contains("doThing"), "rendered: {rendered}");
+        let SignablePayloadField::AddressV2 { address_v2, .. } = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "Implementation")
+            .expect("Implementation field")
+        else {
+            panic!("Implementation is not AddressV2");
+        };
+        assert_eq!(address_v2.address, implementation.to_string());
+        let SignablePayloadField::AddressV2 { address_v2, .. } = payload
+            .fields
+            .iter()
+            .find(|f| f.label() == "To")
+            .expect("To field")
+        else {
+            panic!("To is not AddressV2");
+        };
+        assert_eq!(address_v2.badge_text.as_deref(), Some("Proxy"));
+    }
+
+    #[test]
+    fn test_proxy_implementations_link_uses_implementation_contract_type() {
+        use crate::protocols::cctp::contracts::token_messenger::ITokenMessenger;
+        use alloy_sol_types::SolCall;
+
+        // An unknown proxy in front of the registered CCTP TokenMessenger
+        let proxy = Address::repeat_byte(0x30);
+        let messenger: Address = "0xBd3fa81B58Ba92a82136038B25aDec7066af3155"
+            .parse()
+            .unwrap();
+        let input = ITokenMessenger::depositForBurnCall {
+            amount: U256::from(250_000_000u64),
+            destinationDomain: 3,
+            mintRecipient: Address::repeat_byte(0x42).into_word(),
+            burnToken: "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+                .parse()
+                .unwrap(),
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 200000,
+            to: alloy_primitives::TxKind::Call(proxy),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+
+        let without_link =
+            transaction_to_visual_sign(tx.clone(), VisualSignOptions::default()).unwrap();
+        assert!(
+            !without_link
+                .fields
+                .iter()
+                .any(|f| f.label() == "CCTP Transfer")
+        );
+
+        let payload = transaction_to_visual_sign(
+            tx,
+            options_with_proxy_link(Default::default(), proxy, messenger),
+        )
+        .unwrap();
+        let labels: Vec<&str> = payload.fields.iter().map(|f| f.label().as_str()).collect();
+        let transfer = labels
+            .iter()
+            .position(|l| *l == "CCTP Transfer")
+            .expect("CCTP field");
+        assert_eq!(labels[transfer - 1], "Implementation");
+    }
+
+    #[test]
+    fn test_proxy_implementations_link_cannot_redirect_canonical_token() {
+        use crate::protocols::cctp::contracts::token_messenger::ITokenMessenger;
+        use alloy_sol_types::SolCall;
+
+        let usdc: Address = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+            .parse()
+            .unwrap();
+        let messenger: Address = "0xBd3fa81B58Ba92a82136038B25aDec7066af3155"
+            .parse()
+            .unwrap();
+        let input = ITokenMessenger::depositForBurnCall {
+            amount: U256::from(250_000_000u64),
+            destinationDomain: 3,
+            mintRecipient: Address::repeat_byte(0x42).into_word(),
+            burnToken: usdc,
+        }
+        .abi_encode();
+        let tx = TypedTransaction::Legacy(TxLegacy {
+            chain_id: Some(ChainId::from(1u64)),
+            nonce: 1,
+            gas_price: 1_000_000_000u128,
+            gas_limit: 200000,
+            to: alloy_primitives::TxKind::Call(usdc),
+            value: U256::ZERO,
+            input: Bytes::from(input),
+        });
+
+        let payload = transaction_to_visual_sign(
+            tx,
+            options_with_proxy_link(Default::default(), usdc, messenger),
+        )
+        .unwrap();
+        assert!(!payload.fields.iter().any(|f| f.label() == "CCTP Transfer"));
+        assert!(!payload.fields.iter().any(|f| f.label() == "Implementation"));
+    }
 }
@@ -7,18 +7,18 @@
 
 /// Macro to define network constants and generate lookup functions from a single source.
 ///
-/// Each entry: (chain_module, network_const, chain_id, display_name, fee_paying_asset_symbol)
+/// Each entry: (chain_module, network_const, chain_id, display_name, native_symbol, native_decimals)
 /// Generates:
 /// - `id::{chain_module}::{network_const}` constants
 /// - `get_network_name()` - chain_id -> display name
-/// - `get_fee_paying_asset_symbol()` - chain_id -> fee-paying asset ticker (e.g., "ETH", "POL")
+/// - `get_native_currency()` - chain_id -> native currency ticker and decimals (e.g., "ETH", "POL")
 /// - `chain_id_to_network_id()` - chain_id -> canonical ID (e.g., "ETHEREUM_MAINNET")
 /// - `network_id_to_chain_id()` - canonical ID -> chain_id
 macro_rules! define_networks {
     (
         $(
             $chain:ident {
-                $( $network:ident = $id:expr => $display:expr, $symbol:expr ),* $(,)?
+                $( $network:ident = $id:expr => $display:expr, $symbol:expr, $decimals:expr ),* $(,)?
             }
         ),* $(,)?
     ) => {
@@ -47,14 +47,17 @@ macro_rules! define_networks {
             }
         }
 
-        /// Returns the fee-paying asset symbol for a given chain ID, if known.
+        /// Returns the native currency of a given chain ID, if known.
         ///
-        /// For known networks, returns the configured symbol (e.g., "ETH", "POL", "BNB").
-        /// For unknown chains, returns `None`.
-        pub fn get_fee_paying_asset_symbol(chain_id: u64) -> Option<&'static str> {
+        /// The native currency pays for gas and is what a transaction's `value`
+        /// is denominated in. For unknown chains, returns `None`.
+        pub fn get_native_currency(chain_id: u64) -> Option<NativeCurrency<'static>> {
             match chain_id {
                 $($(
-                    id::$chain::$network => Some($symbol),
+                    id::$chain::$network => Some(NativeCurrency {
+                        symbol: $symbol,
+                        decimals: $decimals,
+                    }),
                 )*)*
                 _ => None,
             }
@@ -90,81 +93,205 @@ macro_rules! define_networks {
 }
 
 // Define all supported networks
-// Format: chain { NETWORK = chain_id => "Display Name", "FeePayingAssetSymbol" }
+// Format: chain { NETWORK = chain_id => "Display Name", "NativeSymbol", native_decimals }
 define_networks! {
     // L1 Chains
     ethereum {
-        MAINNET = 1 => "Ethereum Mainnet", "ETH",
-        SEPOLIA = 11155111 => "Ethereum Sepolia", "ETH",
-        GOERLI = 5 => "Ethereum Goerli (deprecated)", "ETH",
-        HOLESKY = 17000 => "Ethereum Holesky", "ETH",
+        MAINNET = 1 => "Ethereum Mainnet", "ETH", 18,
+        SEPOLIA = 11155111 => "Ethereum Sepolia", "ETH", 18,
+        GOERLI = 5 => "Ethereum Goerli (deprecated)", "ETH", 18,
+        HOLESKY = 17000 => "Ethereum Holesky", "ETH", 18,
     },
     bsc {
-        MAINNET = 56 => "BNB Smart Chain Mainnet", "BNB",
-        TESTNET = 97 => "BNB Smart Chain Testnet", "BNB",
+        MAINNET = 56 => "BNB Smart Chain Mainnet", "BNB", 18,
+        TESTNET = 97 => "BNB Smart Chain Testnet", "BNB", 18,
     },
     polygon {
-        MAINNET = 137 => "Polygon Mainnet", "POL",
-        AMOY = 80002 => "Polygon Amoy", "POL",
+        MAINNET = 137 => "Polygon Mainnet", "POL", 18,
+        AMOY = 80002 => "Polygon Amoy", "POL", 18,
     },
     avalanche {
-        MAINNET = 43114 => "Avalanche C-Chain", "AVAX",
-        FUJI = 43113 => "Avalanche Fuji Testnet", "AVAX",
+        MAINNET = 43114 => "Avalanche C-Chain", "AVAX", 18,
+        FUJI = 43113 => "Avalanche Fuji Testnet", "AVAX", 18,
     },
     fantom {
-        MAINNET = 250 => "Fantom Opera", "FTM",
+        MAINNET = 250 => "Fantom Opera", "FTM", 18,
     },
     gnosis {
-        MAINNET = 100 => "Gnosis Chain", "XDAI",
+        MAINNET = 100 => "Gnosis Chain", "XDAI", 18,
     },
     celo {
-        MAINNET = 42220 => "Celo Mainnet", "CELO",
-        ALFAJORES = 44787 => "Celo Alfajores Testnet", "CELO",
+        MAINNET = 42220 => "Celo Mainnet", "CELO", 18,
+        ALFAJORES = 44787 => "Celo Alfajores Testnet", "CELO", 18,
     },
 
     // L2 Chains - Optimistic Rollups
     optimism {
-        MAINNET = 10 => "OP Mainnet", "ETH",
-        SEPOLIA = 11155420 => "OP Sepolia", "ETH",
+        MAINNET = 10 => "OP Mainnet", "ETH", 18,
+        SEPOLIA = 11155420 => "OP Sepolia", "ETH", 18,
     },
     arbitrum {
-        MAINNET = 42161 => "Arbitrum One", "ETH",
-        SEPOLIA = 421614 => "Arbitrum Sepolia", "ETH",
+        MAINNET = 42161 => "Arbitrum One", "ETH", 18,
+        SEPOLIA = 421614 => "Arbitrum Sepolia", "ETH", 18,
     },
     base {
-        MAINNET = 8453 => "Base", "ETH",
-        SEPOLIA = 84532 => "Base Sepolia", "ETH",
+        MAINNET = 8453 => "Base", "ETH", 18,
+        SEPOLIA = 84532 => "Base Sepolia", "ETH", 18,
     },
     blast {
-        MAINNET = 81457 => "Blast", "ETH",
+        MAINNET = 81457 => "Blast", "ETH", 18,
     },
     mantle {
-        MAINNET = 5000 => "Mantle", "MNT",
+        MAINNET = 5000 => "Mantle", "MNT", 18,
     },
     worldchain {
-        MAINNET = 480 => "World Chain", "ETH",
+        MAINNET = 480 => "World Chain", "ETH", 18,
     },
 
     // L2 Chains - ZK Rollups
     zksync {
-        MAINNET = 324 => "zkSync Era", "ETH",
+        MAINNET = 324 => "zkSync Era", "ETH", 18,
     },
     linea {
-        MAINNET = 59144 => "Linea", "ETH",
+        MAINNET = 59144 => "Linea", "ETH", 18,
     },
     scroll {
-        MAINNET = 534352 => "Scroll", "ETH",
+        MAINNET = 534352 => "Scroll", "ETH", 18,
     },
 
     // App-Specific Chains
     zora {
-        MAINNET = 7777777 => "Zora", "ETH",
+        MAINNET = 7777777 => "Zora", "ETH", 18,
     },
     unichain {
-        MAINNET = 130 => "Unichain", "ETH",
+        MAINNET = 130 => "Unichain", "ETH", 18,
     },
 }
 
+/// A chain's native currency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NativeCurrency<'a> {
+    /// Ticker, e.g. "ETH", "POL", "BNB"
+    pub symbol: &'a str,
+    /// Decimals of the smallest unit that `value` and gas prices are given in
+    pub decimals: u8,
+}
+
+impl NativeCurrency<'_> {
+    /// Formats an amount given in the smallest unit, without the symbol
+    pub fn format(&self, amount: alloy_primitives::U256) -> String {
+        crate::fmt::format_native(amount, self.decimals)
+    }
+}
+
+/// Returns the fee-paying asset symbol for a given chain ID, if known.
+///
+/// For known networks, returns the native currency's symbol (e.g., "ETH", "POL", "BNB").
+/// For unknown chains, returns `None`.
+pub fn get_fee_paying_asset_symbol(chain_id: u64) -> Option<&'static str> {
+    get_native_currency(chain_id).map(|currency| currency.symbol)
+}
+
+/// Formats an amount of `chain_id`'s native currency as `"1.5 POL"`.
+///
+/// Unknown chains are formatted with 18 decimals, the EVM norm, and no symbol.
+pub fn format_native_amount(chain_id: u64, amount: alloy_primitives::U256) -> String {
+    match get_native_currency(chain_id) {
+        Some(currency) => format!("{} {}", currency.format(amount), currency.symbol),
+        None => crate::fmt::format_native(amount, 18),
+    }
+}
+
+/// Largest decimals `format_units` accepts
+const MAX_NATIVE_DECIMALS: u32 = 77;
+
+/// A network defined by the wallet in `EthereumMetadata.custom_networks`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomNetwork<'a> {
+    pub chain_id: u64,
+    pub name: &'a str,
+    pub native_currency: NativeCurrency<'a>,
+    pub explorer_url: Option<&'a str>,
+}
+
+impl CustomNetwork<'_> {
+    /// The display name with the chain id, since the name itself is
+    /// caller-supplied and could imitate a well-known network
+    pub fn display_name(&self) -> String {
+        format!("{} (Chain ID: {})", self.name, self.chain_id)
+    }
+
+    /// The wallet's explorer page for `address`, if the network has an explorer
+    pub fn address_url(&self, address: &alloy_primitives::Address) -> Option<String> {
+        self.explorer_url
+            .map(|base| format!("{}/address/{address}", base.trim_end_matches('/')))
+    }
+}
+
+/// Finds the wallet's definition of `chain_id` in the chain metadata.
+///
+/// Built-in chains can't be redefined: their entries are ignored, as are
+/// entries with an empty name or symbol or decimals `format_units` can't
+/// handle. The first valid entry wins.
+pub fn find_custom_network(
+    chain_metadata: Option<&generated::parser::ChainMetadata>,
+    chain_id: u64,
+) -> Option<CustomNetwork<'_>> {
+    use generated::parser::chain_metadata;
+
+    if chain_id_to_network_id(chain_id).is_some() {
+        return None;
+    }
+    let chain_metadata::Metadata::Ethereum(eth_metadata) = chain_metadata?.metadata.as_ref()?
+    else {
+        return None;
+    };
+    eth_metadata
+        .custom_networks
+        .iter()
+        .filter(|network| network.chain_id == chain_id)
+        .find_map(|network| {
+            let name = network.name.trim();
+            let symbol = network.native_symbol.trim();
+            let decimals = network.native_decimals.unwrap_or(18);
+            if name.is_empty() || symbol.is_empty() || decimals > MAX_NATIVE_DECIMALS {
+                return None;
+            }
+            Some(CustomNetwork {
+                chain_id,
+                name,
+                native_currency: NativeCurrency {
+                    symbol,
+                    decimals: u8::try_from(decimals).ok()?,
+                },
+                explorer_url: network.explorer_url.as_deref(),
+            })
+        })
+}
+
+/// Returns the network name to display, preferring built-in definitions over
+/// the wallet's custom networks
+pub fn resolve_network_name(
+    chain_metadata: Option<&generated::parser::ChainMetadata>,
+    chain_id: u64,
+) -> String {
+    find_custom_network(chain_metadata, chain_id).map_or_else(
+        || get_network_name(Some(chain_id)),
+        |network| network.display_name(),
+    )
+}
+
+/// Returns the native currency, preferring built-in definitions over the
+/// wallet's custom networks
+pub fn resolve_native_currency(
+    chain_metadata: Option<&generated::parser::ChainMetadata>,
+    chain_id: u64,
+) -> Option<NativeCurrency<'_>> {
+    get_native_currency(chain_id).or_else(|| {
+        find_custom_network(chain_metadata, chain_id).map(|network| network.native_currency)
+    })
+}
+
 /// Parses a network identifier from either a chain ID (as string) or network name.
 ///
 /// Accepts:
@@ -221,6 +348,12 @@ pub fn parse_network(input: &str) -> Option<String> {
 ///     metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
 ///         network_id: Some("POLYGON_MAINNET".to_string()),
 ///         abi_mappings: Default::default(),
+///         proxy_implementations: Default::default(),
+///         custom_networks: Default::default(),
+///         address_book: Default::default(),
+///         token_prices: Default::default(),
+///         tokens: Default::default(),
+///         erc4626_vaults: Default::default(),
 ///     })),
 /// };
 ///
@@ -240,7 +373,11 @@ pub fn extract_chain_id_from_metadata(
             let network_id = eth_metadata.network_id.as_ref()?;
             network_id_to_chain_id(network_id)
         }
-        chain_metadata::Metadata::Solana(_) => None,
+        chain_metadata::Metadata::Solana(_)
+        | chain_metadata::Metadata::Sui(_)
+        | chain_metadata::Metadata::Tron(_)
+        | chain_metadata::Metadata::Ton(_)
+        | chain_metadata::Metadata::Hedera(_) => None,
     }
 }
 
@@ -374,6 +511,101 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_native_currency_and_amount_formatting() {
+        let pol = get_native_currency(id::polygon::MAINNET).unwrap();
+        assert_eq!(pol.symbol, "POL");
+        assert_eq!(pol.decimals, 18);
+        let amount = alloy_primitives::U256::from(1_500_000_000_000_000_000u128);
+        assert_eq!(
+            format_native_amount(id::polygon::MAINNET, amount),
+            "1.5 POL"
+        );
+        assert_eq!(format_native_amount(id::bsc::MAINNET, amount), "1.5 BNB");
+        // Unknown chains keep 18 decimals and drop the symbol
+        assert_eq!(get_native_currency(999999999), None);
+        assert_eq!(format_native_amount(999999999, amount), "1.5");
+    }
+
+    fn metadata_with_networks(
+        networks: Vec<generated::parser::EthereumNetwork>,
+    ) -> generated::parser::ChainMetadata {
+        use generated::parser::{ChainMetadata, EthereumMetadata, chain_metadata};
+        ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
+                network_id: None,
+                abi_mappings: Default::default(),
+                proxy_implementations: Default::default(),
+                custom_networks: networks,
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
+            })),
+        }
+    }
+
+    fn custom(chain_id: u64, name: &str, symbol: &str) -> generated::parser::EthereumNetwork {
+        generated::parser::EthereumNetwork {
+            chain_id,
+            name: name.to_string(),
+            native_symbol: symbol.to_string(),
+            native_decimals: None,
+            explorer_url: Some("https://explorer.acme.example".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_custom_network_resolution() {
+        let mut six_decimals = custom(99999, "Acme Appchain", "ACME");
+        six_decimals.native_decimals = Some(6);
+        let metadata = metadata_with_networks(vec![
+            custom(99999, "", "ACME"),
+            six_decimals,
+            custom(99999, "Shadowed", "SHD"),
+        ]);
+
+        // The nameless entry is skipped and the first valid one wins
+        let network = find_custom_network(Some(&metadata), 99999).unwrap();
+        assert_eq!(network.name, "Acme Appchain");
+        assert_eq!(network.explorer_url, Some("https://explorer.acme.example"));
+        assert_eq!(
+            network
+                .address_url(&alloy_primitives::Address::repeat_byte(0xab))
+                .as_deref(),
+            Some(
+                "https://explorer.acme.example/address/0xABaBaBaBABabABabAbAbABAbABabababaBaBABaB"
+            )
+        );
+        assert_eq!(
+            resolve_network_name(Some(&metadata), 99999),
+            "Acme Appchain (Chain ID: 99999)"
+        );
+        let currency = resolve_native_currency(Some(&metadata), 99999).unwrap();
+        assert_eq!((currency.symbol, currency.decimals), ("ACME", 6));
+
+        assert_eq!(find_custom_network(Some(&metadata), 88888), None);
+        assert_eq!(
+            resolve_network_name(None, 99999),
+            "Unknown Network (Chain ID: 99999)"
+        );
+    }
+
+    #[test]
+    fn test_custom_network_cannot_redefine_builtin_chain() {
+        let metadata = metadata_with_networks(vec![custom(
+            id::ethereum::MAINNET,
+            "Polygon Mainnet",
+            "POL",
+        )]);
+        assert_eq!(find_custom_network(Some(&metadata), 1), None);
+        assert_eq!(resolve_network_name(Some(&metadata), 1), "Ethereum Mainnet");
+        assert_eq!(
+            resolve_native_currency(Some(&metadata), 1).map(|c| c.symbol),
+            Some("ETH")
+        );
+    }
+
     #[test]
     fn test_get_fee_paying_asset_symbol_unknown_returns_none() {
         assert_eq!(get_fee_paying_asset_symbol(999999999), None);
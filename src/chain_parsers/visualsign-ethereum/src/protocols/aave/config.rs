@@ -0,0 +1,223 @@
+//! Aave protocol configuration
+//!
+//! Contains Pool and debt token deployments and protocol metadata.
+//!
+//! # Deployment Addresses
+//!
+//! Aave V3 market addresses are published in the address book:
+//! <https://github.com/bgd-labs/aave-address-book/tree/main/src>
+//!
+//! Each reserve has an aToken and a variable debt token (plus a stable debt token on
+//! older markets). Debt tokens are non-transferable; their only user-facing write is
+//! `approveDelegation`, which lets another account borrow against the caller's collateral.
+
+use crate::registry::{ContractRegistry, ContractType};
+use alloy_primitives::Address;
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+/// Contract type marker for Aave debt tokens (variable and stable)
+///
+/// Both debt token flavours share the `ICreditDelegationToken` interface, so they
+/// dispatch under a single contract type.
+///
+/// Reference: <https://github.com/aave/aave-v3-core/blob/master/contracts/interfaces/ICreditDelegationToken.sol>
+#[derive(Debug, Clone, Copy)]
+pub struct AaveDebtToken;
+
+impl ContractType for AaveDebtToken {}
+
+/// Contract type marker for the Aave V3 Pool
+///
+/// Reference: <https://github.com/aave/aave-v3-core/blob/master/contracts/interfaces/IPool.sol>
+#[derive(Debug, Clone, Copy)]
+pub struct AavePool;
+
+impl ContractType for AavePool {}
+
+/// Static description of a known Aave debt token deployment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebtTokenInfo {
+    /// Debt token contract address
+    pub address: &'static str,
+    /// Symbol of the underlying reserve asset (e.g., "WETH")
+    pub underlying_symbol: &'static str,
+    /// Decimals of the underlying reserve asset; debt tokens mirror them
+    pub decimals: u8,
+}
+
+/// Aave protocol configuration
+pub struct AaveConfig;
+
+impl AaveConfig {
+    /// Returns the known Aave V3 variable debt tokens for a chain
+    ///
+    /// Source: <https://github.com/bgd-labs/aave-address-book/blob/main/src/AaveV3Ethereum.sol>
+    pub fn debt_tokens(chain_id: u64) -> &'static [DebtTokenInfo] {
+        match chain_id {
+            networks::ethereum::MAINNET => &[
+                DebtTokenInfo {
+                    address: "0xeA51d7853EEFb32b6ee06b1C12E6dcCA88Be0fFE",
+                    underlying_symbol: "WETH",
+                    decimals: 18,
+                },
+                DebtTokenInfo {
+                    address: "0x72E95b8931767C79bA4EeE721354d6E99a61D004",
+                    underlying_symbol: "USDC",
+                    decimals: 6,
+                },
+                DebtTokenInfo {
+                    address: "0x6df1C1E379bC5a00a7b4C6e67A203333772f45A8",
+                    underlying_symbol: "USDT",
+                    decimals: 6,
+                },
+            ],
+            _ => &[],
+        }
+    }
+
+    /// Returns the Aave V3 Pool address for a chain
+    ///
+    /// Source: <https://github.com/bgd-labs/aave-address-book/tree/main/src>
+    pub fn pool_address(chain_id: u64) -> Option<&'static str> {
+        match chain_id {
+            networks::ethereum::MAINNET => Some("0x87870Bca3F3fD6335C3F4ce8392D69350B4fA4E2"),
+            networks::arbitrum::MAINNET
+            | networks::optimism::MAINNET
+            | networks::polygon::MAINNET
+            | networks::avalanche::MAINNET => Some("0x794a61358D6845594F94dc1DB02A252b5b4814aD"),
+            networks::base::MAINNET => Some("0xA238Dd80C259a72e81d7e4664a9801593F98d1c5"),
+            _ => None,
+        }
+    }
+
+    /// Returns the chain IDs with a known Pool deployment
+    pub fn pool_chains() -> &'static [u64] {
+        &[
+            networks::ethereum::MAINNET,
+            networks::arbitrum::MAINNET,
+            networks::optimism::MAINNET,
+            networks::polygon::MAINNET,
+            networks::avalanche::MAINNET,
+            networks::base::MAINNET,
+        ]
+    }
+
+    /// Returns the label of an eMode category on a chain's main market
+    ///
+    /// Categories are configured per market by governance, so only long-standing
+    /// ones are listed; category 0 always means eMode is off.
+    pub fn emode_category_name(chain_id: u64, category: u8) -> Option<&'static str> {
+        match (chain_id, category) {
+            (_, 0) => Some("None"),
+            (networks::ethereum::MAINNET | networks::base::MAINNET, 1) => Some("ETH correlated"),
+            (
+                networks::arbitrum::MAINNET
+                | networks::optimism::MAINNET
+                | networks::polygon::MAINNET
+                | networks::avalanche::MAINNET,
+                1,
+            ) => Some("Stablecoins"),
+            (
+                networks::arbitrum::MAINNET
+                | networks::optimism::MAINNET
+                | networks::polygon::MAINNET
+                | networks::avalanche::MAINNET,
+                2,
+            ) => Some("ETH correlated"),
+            _ => None,
+        }
+    }
+
+    /// Registers every known Pool under the `AavePool` contract type
+    pub fn register_pools(registry: &mut ContractRegistry) {
+        for &chain_id in Self::pool_chains() {
+            let addresses = Self::pool_address(chain_id)
+                .and_then(|s| s.parse().ok())
+                .into_iter()
+                .collect();
+            registry.register_contract_typed::<AavePool>(chain_id, addresses);
+        }
+    }
+
+    /// Returns the chain IDs with known debt token deployments
+    pub fn debt_token_chains() -> &'static [u64] {
+        &[networks::ethereum::MAINNET]
+    }
+
+    /// Looks up a known debt token by address on a chain
+    pub fn debt_token_info(chain_id: u64, address: Address) -> Option<&'static DebtTokenInfo> {
+        Self::debt_tokens(chain_id)
+            .iter()
+            .find(|info| info.address.parse::<Address>().ok() == Some(address))
+    }
+
+    /// Registers all known debt tokens under the `AaveDebtToken` contract type
+    pub fn register_debt_tokens(registry: &mut ContractRegistry) {
+        for &chain_id in Self::debt_token_chains() {
+            let addresses = Self::debt_tokens(chain_id)
+                .iter()
+                .filter_map(|info| info.address.parse().ok())
+                .collect();
+            registry.register_contract_typed::<AaveDebtToken>(chain_id, addresses);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debt_token_addresses_parse() {
+        for &chain_id in AaveConfig::debt_token_chains() {
+            for info in AaveConfig::debt_tokens(chain_id) {
+                assert!(
+                    info.address.parse::<Address>().is_ok(),
+                    "invalid debt token address {}",
+                    info.address
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pool_addresses_parse() {
+        for &chain_id in AaveConfig::pool_chains() {
+            let address = AaveConfig::pool_address(chain_id).unwrap();
+            assert!(address.parse::<Address>().is_ok(), "invalid pool {address}");
+        }
+    }
+
+    #[test]
+    fn test_emode_category_names() {
+        assert_eq!(
+            AaveConfig::emode_category_name(networks::ethereum::MAINNET, 1),
+            Some("ETH correlated")
+        );
+        assert_eq!(
+            AaveConfig::emode_category_name(networks::arbitrum::MAINNET, 1),
+            Some("Stablecoins")
+        );
+        assert_eq!(AaveConfig::emode_category_name(12345, 0), Some("None"));
+        assert_eq!(
+            AaveConfig::emode_category_name(networks::ethereum::MAINNET, 200),
+            None
+        );
+    }
+
+    #[test]
+    fn test_debt_token_info_lookup() {
+        let weth_debt: Address = "0xeA51d7853EEFb32b6ee06b1C12E6dcCA88Be0fFE"
+            .parse()
+            .unwrap();
+        let info = AaveConfig::debt_token_info(networks::ethereum::MAINNET, weth_debt).unwrap();
+        assert_eq!(info.underlying_symbol, "WETH");
+        assert_eq!(info.decimals, 18);
+
+        assert!(AaveConfig::debt_token_info(networks::base::MAINNET, weth_debt).is_none());
+        assert!(AaveConfig::debt_token_info(networks::ethereum::MAINNET, Address::ZERO).is_none());
+    }
+}
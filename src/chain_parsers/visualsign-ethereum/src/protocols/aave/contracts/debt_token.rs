@@ -0,0 +1,349 @@
+//! Aave Debt Token Visualizer
+//!
+//! Aave variable and stable debt tokens implement credit delegation: the holder of
+//! collateral can call `approveDelegation` to let another account borrow on their
+//! behalf. The delegator carries the debt, so this is rendered with an explicit warning.
+//!
+//! Reference: <https://aave.com/docs/developers/smart-contracts/tokenization#credit-delegation>
+
+use alloy_primitives::{Address, U256, utils::format_units};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldCommon, SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
+    SignablePayloadFieldStaticAnnotation, SignablePayloadFieldTextV2,
+};
+
+use crate::protocols::aave::config::AaveConfig;
+use crate::registry::ContractType;
+
+sol! {
+    interface ICreditDelegationToken {
+        function approveDelegation(address delegatee, uint256 amount) external;
+        function borrowAllowance(address fromUser, address toUser) external view returns (uint256);
+    }
+}
+
+/// Text of the warning field attached to every credit delegation approval
+pub const CREDIT_DELEGATION_WARNING: &str = "Credit delegation lets the delegatee borrow against your collateral. You are liable for the debt and may be liquidated if it is not repaid.";
+
+/// Visualizer for Aave debt token calls
+pub struct DebtTokenVisualizer;
+
+impl DebtTokenVisualizer {
+    /// Attempts to decode and visualize a debt token call
+    ///
+    /// # Arguments
+    /// * `input` - The calldata bytes (with 4-byte function selector)
+    /// * `chain_id` - The chain ID used to look up the debt token's underlying asset
+    /// * `debt_token` - The destination address, if known
+    ///
+    /// # Returns
+    /// * `Some(field)` for `approveDelegation` and `borrowAllowance`
+    /// * `None` if the input doesn't match a debt token function
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: u64,
+        debt_token: Option<Address>,
+    ) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+        let selector = &input[..4];
+
+        if selector == ICreditDelegationToken::approveDelegationCall::SELECTOR {
+            let call = ICreditDelegationToken::approveDelegationCall::abi_decode(input).ok()?;
+            return Some(Self::decode_approve_delegation(call, chain_id, debt_token));
+        }
+
+        if selector == ICreditDelegationToken::borrowAllowanceCall::SELECTOR {
+            let call = ICreditDelegationToken::borrowAllowanceCall::abi_decode(input).ok()?;
+            let text = format!(
+                "Query borrow allowance delegated by {} to {}",
+                call.fromUser, call.toUser
+            );
+            return Some(SignablePayloadField::TextV2 {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: text.clone(),
+                    label: "Aave Borrow Allowance".to_string(),
+                },
+                text_v2: SignablePayloadFieldTextV2 { text },
+            });
+        }
+
+        None
+    }
+
+    fn decode_approve_delegation(
+        call: ICreditDelegationToken::approveDelegationCall,
+        chain_id: u64,
+        debt_token: Option<Address>,
+    ) -> SignablePayloadField {
+        let info = debt_token.and_then(|addr| AaveConfig::debt_token_info(chain_id, addr));
+
+        let amount_display = if call.amount == U256::MAX {
+            "Unlimited".to_string()
+        } else {
+            match info {
+                Some(info) => {
+                    let formatted = format_units(call.amount, info.decimals)
+                        .map(|s| s.trim_end_matches('0').trim_end_matches('.').to_string())
+                        .unwrap_or_else(|_| call.amount.to_string());
+                    format!("{formatted} {}", info.underlying_symbol)
+                }
+                None => call.amount.to_string(),
+            }
+        };
+
+        let title_text = "Aave Credit Delegation".to_string();
+        let subtitle_text = if call.amount == U256::MAX {
+            format!("Delegate unlimited borrowing power to {}", call.delegatee)
+        } else {
+            format!(
+                "Delegate {amount_display} of borrowing power to {}",
+                call.delegatee
+            )
+        };
+
+        let warning = AnnotatedPayloadField {
+            signable_payload_field: SignablePayloadField::TextV2 {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: CREDIT_DELEGATION_WARNING.to_string(),
+                    label: "Warning".to_string(),
+                },
+                text_v2: SignablePayloadFieldTextV2 {
+                    text: CREDIT_DELEGATION_WARNING.to_string(),
+                },
+            },
+            static_annotation: Some(SignablePayloadFieldStaticAnnotation {
+                text: "High risk".to_string(),
+            }),
+            dynamic_annotation: None,
+        };
+
+        let mut expanded_fields = vec![AnnotatedPayloadField {
+            signable_payload_field: SignablePayloadField::AddressV2 {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: call.delegatee.to_string(),
+                    label: "Delegatee".to_string(),
+                },
+                address_v2: SignablePayloadFieldAddressV2 {
+                    address: call.delegatee.to_string(),
+                    name: "Delegatee".to_string(),
+                    asset_label: String::new(),
+                    memo: None,
+                    badge_text: None,
+                },
+            },
+            static_annotation: None,
+            dynamic_annotation: None,
+        }];
+        if let Some(token) = debt_token {
+            expanded_fields.push(AnnotatedPayloadField {
+                signable_payload_field: SignablePayloadField::AddressV2 {
+                    common: SignablePayloadFieldCommon {
+                        fallback_text: token.to_string(),
+                        label: "Debt Token".to_string(),
+                    },
+                    address_v2: SignablePayloadFieldAddressV2 {
+                        address: token.to_string(),
+                        name: "Debt Token".to_string(),
+                        asset_label: info
+                            .map(|info| info.underlying_symbol.to_string())
+                            .unwrap_or_default(),
+                        memo: None,
+                        badge_text: None,
+                    },
+                },
+                static_annotation: None,
+                dynamic_annotation: None,
+            });
+        }
+        expanded_fields.push(AnnotatedPayloadField {
+            signable_payload_field: SignablePayloadField::TextV2 {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: amount_display.clone(),
+                    label: "Amount".to_string(),
+                },
+                text_v2: SignablePayloadFieldTextV2 {
+                    text: amount_display,
+                },
+            },
+            static_annotation: None,
+            dynamic_annotation: None,
+        });
+        expanded_fields.push(warning.clone());
+
+        SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{subtitle_text}. WARNING: {CREDIT_DELEGATION_WARNING}"),
+                label: title_text.clone(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 { text: title_text }),
+                subtitle: Some(SignablePayloadFieldTextV2 {
+                    text: subtitle_text,
+                }),
+                condensed: Some(SignablePayloadFieldListLayout {
+                    fields: vec![warning],
+                }),
+                expanded: Some(SignablePayloadFieldListLayout {
+                    fields: expanded_fields,
+                }),
+            },
+        }
+    }
+}
+
+/// ContractVisualizer implementation for Aave debt tokens
+pub struct DebtTokenContractVisualizer {
+    inner: DebtTokenVisualizer,
+}
+
+impl DebtTokenContractVisualizer {
+    pub fn new() -> Self {
+        Self {
+            inner: DebtTokenVisualizer,
+        }
+    }
+}
+
+impl Default for DebtTokenContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for DebtTokenContractVisualizer {
+    fn contract_type(&self) -> &str {
+        crate::protocols::aave::config::AaveDebtToken::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        Ok(self
+            .inner
+            .visualize_tx_commands(
+                &context.calldata,
+                context.chain_id,
+                Some(context.current_contract),
+            )
+            .map(|field| {
+                vec![AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }]
+            }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn weth_debt_token() -> Address {
+        "0xeA51d7853EEFb32b6ee06b1C12E6dcCA88Be0fFE"
+            .parse()
+            .unwrap()
+    }
+
+    fn encode_approve_delegation(amount: U256) -> Vec<u8> {
+        ICreditDelegationToken::approveDelegationCall {
+            delegatee: Address::repeat_byte(0x22),
+            amount,
+        }
+        .abi_encode()
+    }
+
+    #[test]
+    fn test_visualize_empty_input() {
+        assert_eq!(
+            DebtTokenVisualizer.visualize_tx_commands(&[], 1, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_visualize_unrelated_selector() {
+        let input = [0xa9, 0x05, 0x9c, 0xbb, 0x00];
+        assert_eq!(
+            DebtTokenVisualizer.visualize_tx_commands(&input, 1, None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_approve_delegation_known_token_formats_amount() {
+        let input = encode_approve_delegation(U256::from(1_500_000_000_000_000_000u128));
+        let field = DebtTokenVisualizer
+            .visualize_tx_commands(&input, 1, Some(weth_debt_token()))
+            .expect("approveDelegation should decode");
+
+        let SignablePayloadField::PreviewLayout {
+            common,
+            preview_layout,
+        } = field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        assert_eq!(common.label, "Aave Credit Delegation");
+        assert!(common.fallback_text.contains("WARNING"));
+        assert_eq!(
+            preview_layout.subtitle.unwrap().text,
+            format!(
+                "Delegate 1.5 WETH of borrowing power to {}",
+                Address::repeat_byte(0x22)
+            )
+        );
+
+        let condensed = preview_layout.condensed.unwrap().fields;
+        assert_eq!(condensed.len(), 1);
+        assert_eq!(condensed[0].signable_payload_field.label(), "Warning");
+        assert_eq!(
+            condensed[0].static_annotation.as_ref().unwrap().text,
+            "High risk"
+        );
+
+        let labels: Vec<String> = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| f.signable_payload_field.label().clone())
+            .collect();
+        assert_eq!(labels, vec!["Delegatee", "Debt Token", "Amount", "Warning"]);
+    }
+
+    #[test]
+    fn test_approve_delegation_unlimited_unknown_token() {
+        let input = encode_approve_delegation(U256::MAX);
+        let field = DebtTokenVisualizer
+            .visualize_tx_commands(&input, 1, Some(Address::repeat_byte(0x33)))
+            .expect("approveDelegation should decode");
+
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected PreviewLayout");
+        };
+        assert_eq!(
+            preview_layout.subtitle.unwrap().text,
+            format!(
+                "Delegate unlimited borrowing power to {}",
+                Address::repeat_byte(0x22)
+            )
+        );
+    }
+
+    #[test]
+    fn test_approve_delegation_truncated_calldata() {
+        let input = encode_approve_delegation(U256::from(1u64));
+        assert_eq!(
+            DebtTokenVisualizer.visualize_tx_commands(&input[..20], 1, None),
+            None
+        );
+    }
+}
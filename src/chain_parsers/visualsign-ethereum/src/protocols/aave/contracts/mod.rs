@@ -0,0 +1,7 @@
+//! Aave protocol contract visualizers
+
+pub mod debt_token;
+pub mod pool;
+
+pub use debt_token::{DebtTokenContractVisualizer, DebtTokenVisualizer};
+pub use pool::{PoolContractVisualizer, PoolVisualizer};
@@ -0,0 +1,361 @@
+//! Aave V3 Pool Visualizer
+//!
+//! Decodes the Pool calls that change how an account's positions are treated
+//! rather than moving funds: `setUserEMode`, `setUserUseReserveAsCollateral` and
+//! `swapBorrowRateMode`. Each names the eMode category or reserve it affects.
+//!
+//! Reference: <https://aave.com/docs/developers/smart-contracts/pool>
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldCommon, SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
+    SignablePayloadFieldStaticAnnotation, SignablePayloadFieldTextV2,
+};
+
+use crate::protocols::aave::config::AaveConfig;
+use crate::registry::{ContractRegistry, ContractType};
+
+sol! {
+    interface IPool {
+        function setUserEMode(uint8 categoryId) external;
+        function setUserUseReserveAsCollateral(address asset, bool useAsCollateral) external;
+        function swapBorrowRateMode(address asset, uint256 interestRateMode) external;
+    }
+}
+
+/// Text of the warning attached when a reserve stops counting as collateral
+pub const DISABLE_COLLATERAL_WARNING: &str =
+    "Disabling collateral lowers your health factor. Open borrows may become liquidatable.";
+
+/// Visualizer for Aave V3 Pool calls
+pub struct PoolVisualizer;
+
+impl PoolVisualizer {
+    /// Attempts to decode and visualize a Pool call
+    ///
+    /// # Arguments
+    /// * `input` - The calldata bytes (with 4-byte function selector)
+    /// * `chain_id` - The chain ID, used for eMode category names and reserve symbols
+    /// * `registry` - Optional contract registry for reserve symbols
+    ///
+    /// # Returns
+    /// * `Some(field)` for the eMode and reserve configuration calls
+    /// * `None` if the input doesn't match one of them
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+        let selector = &input[..4];
+        let symbol = |asset: Address| {
+            registry
+                .and_then(|r| r.get_token_symbol(chain_id, asset))
+                .unwrap_or_else(|| format!("{asset:?}"))
+        };
+
+        if selector == IPool::setUserEModeCall::SELECTOR {
+            let call = IPool::setUserEModeCall::abi_decode(input).ok()?;
+            let category = match AaveConfig::emode_category_name(chain_id, call.categoryId) {
+                Some(name) => format!("{name} (category {})", call.categoryId),
+                None => format!("Category {}", call.categoryId),
+            };
+            let subtitle = if call.categoryId == 0 {
+                "Disable efficiency mode".to_string()
+            } else {
+                format!("Enable efficiency mode: {category}")
+            };
+            return Some(preview(
+                "Aave Set eMode",
+                subtitle,
+                vec![text_row("Category", category)],
+                None,
+            ));
+        }
+
+        if selector == IPool::setUserUseReserveAsCollateralCall::SELECTOR {
+            let call = IPool::setUserUseReserveAsCollateralCall::abi_decode(input).ok()?;
+            let symbol = symbol(call.asset);
+            let (subtitle, setting, warning) = if call.useAsCollateral {
+                (format!("Use {symbol} as collateral"), "Enabled", None)
+            } else {
+                (
+                    format!("Stop using {symbol} as collateral"),
+                    "Disabled",
+                    Some(DISABLE_COLLATERAL_WARNING),
+                )
+            };
+            return Some(preview(
+                "Aave Collateral Setting",
+                subtitle,
+                vec![
+                    reserve_row(call.asset, &symbol),
+                    text_row("Use As Collateral", setting.to_string()),
+                ],
+                warning,
+            ));
+        }
+
+        if selector == IPool::swapBorrowRateModeCall::SELECTOR {
+            let call = IPool::swapBorrowRateModeCall::abi_decode(input).ok()?;
+            let symbol = symbol(call.asset);
+            let mode = call.interestRateMode;
+            // The argument is the debt's current mode, which is swapped for the other
+            let (subtitle, current) = match swapped_rate_modes(mode) {
+                Some((from, to)) => (
+                    format!("Switch {symbol} debt from {from} to {to} rate"),
+                    format!("{from} ({mode})"),
+                ),
+                None => (
+                    format!("Switch {symbol} debt from unknown rate mode {mode}"),
+                    format!("unknown ({mode})"),
+                ),
+            };
+            return Some(preview(
+                "Aave Swap Borrow Rate",
+                subtitle,
+                vec![
+                    reserve_row(call.asset, &symbol),
+                    text_row("Current Rate Mode", current),
+                ],
+                None,
+            ));
+        }
+
+        None
+    }
+}
+
+/// Maps Aave's `interestRateMode` (1 stable, 2 variable) to the mode it is
+/// swapped from and to
+fn swapped_rate_modes(mode: U256) -> Option<(&'static str, &'static str)> {
+    if mode == U256::from(1u8) {
+        Some(("stable", "variable"))
+    } else if mode == U256::from(2u8) {
+        Some(("variable", "stable"))
+    } else {
+        None
+    }
+}
+
+fn preview(
+    title: &str,
+    subtitle: String,
+    mut details: Vec<AnnotatedPayloadField>,
+    warning: Option<&str>,
+) -> SignablePayloadField {
+    let mut fallback_text = subtitle.clone();
+    let mut condensed = None;
+    if let Some(warning) = warning {
+        let field = AnnotatedPayloadField {
+            static_annotation: Some(SignablePayloadFieldStaticAnnotation {
+                text: "Caution".to_string(),
+            }),
+            ..text_row("Warning", warning.to_string())
+        };
+        fallback_text = format!("{fallback_text}. WARNING: {warning}");
+        condensed = Some(SignablePayloadFieldListLayout {
+            fields: vec![field.clone()],
+        });
+        details.push(field);
+    }
+
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text,
+            label: title.to_string(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title.to_string(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+            condensed,
+            expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+        },
+    }
+}
+
+fn reserve_row(asset: Address, symbol: &str) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{asset:?}"),
+                label: "Reserve".to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{asset:?}"),
+                name: "Reserve".to_string(),
+                asset_label: symbol.to_string(),
+                memo: None,
+                badge_text: None,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// ContractVisualizer implementation for the Aave Pool
+pub struct PoolContractVisualizer {
+    inner: PoolVisualizer,
+}
+
+impl PoolContractVisualizer {
+    pub fn new() -> Self {
+        Self {
+            inner: PoolVisualizer,
+        }
+    }
+}
+
+impl Default for PoolContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for PoolContractVisualizer {
+    fn contract_type(&self) -> &str {
+        crate::protocols::aave::config::AavePool::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        Ok(self
+            .inner
+            .visualize_tx_commands(&context.calldata, context.chain_id, None)
+            .map(|field| {
+                vec![AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }]
+            }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    const WETH: Address = alloy_primitives::address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+
+    fn visualize(input: &[u8], chain_id: u64) -> SignablePayloadField {
+        let (registry, _) = ContractRegistry::with_default_protocols();
+        PoolVisualizer
+            .visualize_tx_commands(input, chain_id, Some(&registry))
+            .expect("call should decode")
+    }
+
+    #[test]
+    fn test_visualize_empty_input() {
+        assert_eq!(PoolVisualizer.visualize_tx_commands(&[], 1, None), None);
+    }
+
+    #[test]
+    fn test_set_user_emode_names_category() {
+        let enable = IPool::setUserEModeCall { categoryId: 1 }.abi_encode();
+        assert_eq!(
+            visualize(&enable, 1).fallback_text(),
+            "Enable efficiency mode: ETH correlated (category 1)"
+        );
+        assert_eq!(
+            visualize(&enable, 42161).fallback_text(),
+            "Enable efficiency mode: Stablecoins (category 1)"
+        );
+
+        let unknown = IPool::setUserEModeCall { categoryId: 9 }.abi_encode();
+        assert_eq!(
+            visualize(&unknown, 1).fallback_text(),
+            "Enable efficiency mode: Category 9"
+        );
+
+        let disable = IPool::setUserEModeCall { categoryId: 0 }.abi_encode();
+        let field = visualize(&disable, 1);
+        assert_eq!(field.label(), "Aave Set eMode");
+        assert_eq!(field.fallback_text(), "Disable efficiency mode");
+    }
+
+    #[test]
+    fn test_collateral_toggle() {
+        let enable = IPool::setUserUseReserveAsCollateralCall {
+            asset: WETH,
+            useAsCollateral: true,
+        }
+        .abi_encode();
+        let field = visualize(&enable, 1);
+        assert_eq!(field.fallback_text(), "Use WETH as collateral");
+
+        let disable = IPool::setUserUseReserveAsCollateralCall {
+            asset: WETH,
+            useAsCollateral: false,
+        }
+        .abi_encode();
+        let field = visualize(&disable, 1);
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Stop using WETH as collateral. WARNING: {DISABLE_COLLATERAL_WARNING}")
+        );
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected PreviewLayout");
+        };
+        let labels: Vec<String> = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| f.signable_payload_field.label().clone())
+            .collect();
+        assert_eq!(labels, vec!["Reserve", "Use As Collateral", "Warning"]);
+        assert_eq!(preview_layout.condensed.unwrap().fields.len(), 1);
+    }
+
+    #[test]
+    fn test_swap_borrow_rate_mode() {
+        let input = IPool::swapBorrowRateModeCall {
+            asset: WETH,
+            interestRateMode: U256::from(2u8),
+        }
+        .abi_encode();
+        assert_eq!(
+            visualize(&input, 1).fallback_text(),
+            "Switch WETH debt from variable to stable rate"
+        );
+
+        let unknown_asset = IPool::swapBorrowRateModeCall {
+            asset: Address::repeat_byte(0x44),
+            interestRateMode: U256::from(1u8),
+        }
+        .abi_encode();
+        assert_eq!(
+            visualize(&unknown_asset, 1).fallback_text(),
+            &format!(
+                "Switch {:?} debt from stable to variable rate",
+                Address::repeat_byte(0x44)
+            )
+        );
+    }
+}
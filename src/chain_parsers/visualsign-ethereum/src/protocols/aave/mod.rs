@@ -0,0 +1,68 @@
+//! Aave protocol implementation
+//!
+//! This module contains contract visualizers, configuration, and registration
+//! logic for the Aave lending protocol.
+
+pub mod config;
+pub mod contracts;
+
+use crate::registry::ContractRegistry;
+use crate::visualizer::EthereumVisualizerRegistryBuilder;
+
+pub use config::AaveConfig;
+pub use contracts::{
+    DebtTokenContractVisualizer, DebtTokenVisualizer, PoolContractVisualizer, PoolVisualizer,
+};
+
+/// Registers all Aave protocol contracts and visualizers
+///
+/// # Arguments
+/// * `contract_reg` - The contract registry to register addresses
+/// * `visualizer_reg` - The visualizer registry to register visualizers
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    AaveConfig::register_pools(contract_reg);
+    AaveConfig::register_debt_tokens(contract_reg);
+
+    visualizer_reg.register(Box::new(PoolContractVisualizer::new()));
+    visualizer_reg.register(Box::new(DebtTokenContractVisualizer::new()));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::aave::config::{AaveDebtToken, AavePool, networks};
+    use crate::registry::ContractType;
+
+    #[test]
+    fn test_register_aave_debt_tokens() {
+        let mut contract_reg = ContractRegistry::new();
+        let mut visualizer_reg = EthereumVisualizerRegistryBuilder::new();
+
+        register(&mut contract_reg, &mut visualizer_reg);
+
+        for info in AaveConfig::debt_tokens(networks::ethereum::MAINNET) {
+            let address = info.address.parse().unwrap();
+            assert_eq!(
+                contract_reg.get_contract_type(networks::ethereum::MAINNET, address),
+                Some(AaveDebtToken::short_type_id().to_string())
+            );
+        }
+
+        let pool = AaveConfig::pool_address(networks::ethereum::MAINNET)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(
+            contract_reg.get_contract_type(networks::ethereum::MAINNET, pool),
+            Some(AavePool::short_type_id().to_string())
+        );
+
+        let visualizers = visualizer_reg.build();
+        assert!(visualizers.get(AaveDebtToken::short_type_id()).is_some());
+        assert!(visualizers.get(AavePool::short_type_id()).is_some());
+    }
+}
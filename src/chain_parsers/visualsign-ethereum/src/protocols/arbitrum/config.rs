@@ -0,0 +1,71 @@
+//! Arbitrum native bridge configuration
+//!
+//! Deposits from L1 go through the rollup's `Inbox` contract, which creates a
+//! retryable ticket executed on the destination L2.
+//!
+//! Source: <https://docs.arbitrum.io/build-decentralized-apps/reference/contract-addresses>
+
+use crate::registry::{ContractRegistry, ContractType};
+use alloy_primitives::{Address, address};
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+/// Contract type marker for the Arbitrum delayed Inbox
+#[derive(Debug, Clone, Copy)]
+pub struct ArbitrumInbox;
+
+impl ContractType for ArbitrumInbox {}
+
+/// An L1 Inbox deployment and the L2 it delivers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InboxDeployment {
+    /// Chain the Inbox is deployed on
+    pub l1_chain_id: u64,
+    /// Inbox contract address
+    pub address: Address,
+    /// Chain that receives the deposit
+    pub l2_chain_id: u64,
+}
+
+/// Known Inbox deployments on L1
+const INBOXES: &[InboxDeployment] = &[
+    InboxDeployment {
+        l1_chain_id: networks::ethereum::MAINNET,
+        address: address!("0x4Dbd4fc535Ac27206064B68FfCf827b0A60BAB3f"),
+        l2_chain_id: networks::arbitrum::MAINNET,
+    },
+    InboxDeployment {
+        l1_chain_id: networks::ethereum::SEPOLIA,
+        address: address!("0xaAe29B0366299461418F5324a79Afc425BE5ae21"),
+        l2_chain_id: networks::arbitrum::SEPOLIA,
+    },
+];
+
+/// Arbitrum native bridge configuration
+pub struct ArbitrumConfig;
+
+impl ArbitrumConfig {
+    /// Returns every known Inbox deployment
+    pub fn inboxes() -> &'static [InboxDeployment] {
+        INBOXES
+    }
+
+    /// Returns the L2 chain ID an Inbox delivers to
+    pub fn l2_chain_id(l1_chain_id: u64, inbox: Address) -> Option<u64> {
+        Self::inboxes()
+            .iter()
+            .find(|d| d.l1_chain_id == l1_chain_id && d.address == inbox)
+            .map(|d| d.l2_chain_id)
+    }
+
+    /// Registers every known Inbox under the `ArbitrumInbox` type
+    pub fn register_contracts(registry: &mut ContractRegistry) {
+        for deployment in Self::inboxes() {
+            registry.register_contract_typed::<ArbitrumInbox>(
+                deployment.l1_chain_id,
+                vec![deployment.address],
+            );
+        }
+    }
+}
@@ -0,0 +1,351 @@
+//! Arbitrum Inbox Visualizer
+//!
+//! Decodes L1 → L2 deposits sent through the Arbitrum delayed Inbox: `depositEth`
+//! and `createRetryableTicket` (plus its `unsafe` variant, which skips address
+//! aliasing of the refund addresses). The destination L2 and the L2 recipient are
+//! always shown so a deposit to the wrong chain or address is visible before signing.
+//!
+//! Reference: <https://github.com/OffchainLabs/nitro-contracts/blob/main/src/bridge/IInbox.sol>
+
+use alloy_primitives::{Address, U256, utils::format_ether};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldCommon, SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
+    SignablePayloadFieldStaticAnnotation, SignablePayloadFieldTextV2,
+};
+
+use crate::networks::get_network_name;
+use crate::protocols::arbitrum::config::{ArbitrumConfig, ArbitrumInbox};
+use crate::registry::ContractType;
+
+sol! {
+    interface IInbox {
+        function depositEth() external payable returns (uint256);
+
+        function createRetryableTicket(
+            address to,
+            uint256 l2CallValue,
+            uint256 maxSubmissionCost,
+            address excessFeeRefundAddress,
+            address callValueRefundAddress,
+            uint256 gasLimit,
+            uint256 maxFeePerGas,
+            bytes calldata data
+        ) external payable returns (uint256);
+
+        function unsafeCreateRetryableTicket(
+            address to,
+            uint256 l2CallValue,
+            uint256 maxSubmissionCost,
+            address excessFeeRefundAddress,
+            address callValueRefundAddress,
+            uint256 gasLimit,
+            uint256 maxFeePerGas,
+            bytes calldata data
+        ) external payable returns (uint256);
+    }
+}
+
+/// Visualizer for Arbitrum Inbox deposits
+pub struct InboxVisualizer;
+
+impl InboxVisualizer {
+    /// Attempts to decode and visualize an Inbox deposit
+    ///
+    /// # Arguments
+    /// * `input` - The calldata bytes (with 4-byte function selector)
+    /// * `chain_id` - The L1 chain ID
+    /// * `inbox` - The Inbox contract address, used to find the destination L2
+    ///
+    /// # Returns
+    /// * `Some(field)` for `depositEth`, `createRetryableTicket` and `unsafeCreateRetryableTicket`
+    /// * `None` if the input doesn't match one of them
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: u64,
+        inbox: Address,
+    ) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+        let selector = &input[..4];
+        let destination = ArbitrumConfig::l2_chain_id(chain_id, inbox)
+            .map_or("Unknown L2".to_string(), |id| get_network_name(Some(id)));
+
+        if selector == IInbox::depositEthCall::SELECTOR {
+            IInbox::depositEthCall::abi_decode(input).ok()?;
+            let subtitle = format!("Deposit ETH to your own address on {destination}");
+            let details = vec![
+                text_row("Destination Chain", destination),
+                text_row("Method", "depositEth".to_string()),
+                text_row(
+                    "Recipient",
+                    "Sender (same address on L2, aliased if the sender is a contract)".to_string(),
+                ),
+            ];
+            return Some(preview(subtitle, details));
+        }
+
+        let (method, call) = if selector == IInbox::createRetryableTicketCall::SELECTOR {
+            let c = IInbox::createRetryableTicketCall::abi_decode(input).ok()?;
+            (
+                "createRetryableTicket",
+                RetryableTicket {
+                    to: c.to,
+                    l2_call_value: c.l2CallValue,
+                    max_submission_cost: c.maxSubmissionCost,
+                    excess_fee_refund_address: c.excessFeeRefundAddress,
+                    call_value_refund_address: c.callValueRefundAddress,
+                    gas_limit: c.gasLimit,
+                    max_fee_per_gas: c.maxFeePerGas,
+                    data: c.data.to_vec(),
+                },
+            )
+        } else if selector == IInbox::unsafeCreateRetryableTicketCall::SELECTOR {
+            let c = IInbox::unsafeCreateRetryableTicketCall::abi_decode(input).ok()?;
+            (
+                "unsafeCreateRetryableTicket",
+                RetryableTicket {
+                    to: c.to,
+                    l2_call_value: c.l2CallValue,
+                    max_submission_cost: c.maxSubmissionCost,
+                    excess_fee_refund_address: c.excessFeeRefundAddress,
+                    call_value_refund_address: c.callValueRefundAddress,
+                    gas_limit: c.gasLimit,
+                    max_fee_per_gas: c.maxFeePerGas,
+                    data: c.data.to_vec(),
+                },
+            )
+        } else {
+            return None;
+        };
+
+        let subtitle = format!(
+            "Send {} ETH to {:?} on {destination}",
+            format_ether(call.l2_call_value),
+            call.to
+        );
+        let mut details = vec![
+            text_row("Destination Chain", destination),
+            text_row("Method", method.to_string()),
+            address_row("Recipient", &call.to, Some("L2 recipient")),
+            text_row(
+                "L2 Call Value",
+                format!("{} ETH", format_ether(call.l2_call_value)),
+            ),
+            text_row(
+                "Max Submission Cost",
+                format!("{} ETH", format_ether(call.max_submission_cost)),
+            ),
+            text_row("Gas Limit", call.gas_limit.to_string()),
+            text_row("Max Fee Per Gas", format!("{} wei", call.max_fee_per_gas)),
+            address_row("Excess Fee Refund", &call.excess_fee_refund_address, None),
+        ];
+
+        // If the ticket is never redeemed the L2 call value goes to this address,
+        // not to `to`, so a mismatch is worth flagging.
+        let mut refund_row =
+            address_row("Call Value Refund", &call.call_value_refund_address, None);
+        if call.call_value_refund_address != call.to {
+            refund_row.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+                text: "Differs from the recipient".to_string(),
+            });
+        }
+        details.push(refund_row);
+
+        details.push(text_row(
+            "L2 Calldata",
+            if call.data.is_empty() {
+                "None".to_string()
+            } else {
+                format!("0x{}", hex::encode(&call.data))
+            },
+        ));
+
+        Some(preview(subtitle, details))
+    }
+}
+
+/// Arguments shared by the two retryable ticket entry points
+struct RetryableTicket {
+    to: Address,
+    l2_call_value: U256,
+    max_submission_cost: U256,
+    excess_fee_refund_address: Address,
+    call_value_refund_address: Address,
+    gas_limit: U256,
+    max_fee_per_gas: U256,
+    data: Vec<u8>,
+}
+
+fn preview(subtitle: String, details: Vec<AnnotatedPayloadField>) -> SignablePayloadField {
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text: subtitle.clone(),
+            label: "Arbitrum Deposit".to_string(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: "Arbitrum Deposit".to_string(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+            condensed: None,
+            expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+        },
+    }
+}
+
+fn address_row(label: &str, address: &Address, badge: Option<&str>) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: badge.map(str::to_string),
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// ContractVisualizer implementation for the Arbitrum Inbox
+pub struct InboxContractVisualizer {
+    inner: InboxVisualizer,
+}
+
+impl InboxContractVisualizer {
+    pub fn new() -> Self {
+        Self {
+            inner: InboxVisualizer,
+        }
+    }
+}
+
+impl Default for InboxContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for InboxContractVisualizer {
+    fn contract_type(&self) -> &str {
+        ArbitrumInbox::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        Ok(self
+            .inner
+            .visualize_tx_commands(
+                &context.calldata,
+                context.chain_id,
+                context.current_contract,
+            )
+            .map(|field| {
+                vec![AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }]
+            }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const INBOX: Address = address!("0x4Dbd4fc535Ac27206064B68FfCf827b0A60BAB3f");
+
+    fn ticket(to: Address, refund: Address) -> Vec<u8> {
+        IInbox::createRetryableTicketCall {
+            to,
+            l2CallValue: U256::from(500_000_000_000_000_000u64),
+            maxSubmissionCost: U256::from(1_000_000_000_000u64),
+            excessFeeRefundAddress: to,
+            callValueRefundAddress: refund,
+            gasLimit: U256::from(100_000u64),
+            maxFeePerGas: U256::from(100_000_000u64),
+            data: Default::default(),
+        }
+        .abi_encode()
+    }
+
+    #[test]
+    fn test_deposit_eth_names_destination() {
+        let input = IInbox::depositEthCall {}.abi_encode();
+        let field = InboxVisualizer
+            .visualize_tx_commands(&input, 1, INBOX)
+            .unwrap();
+        assert_eq!(field.label(), "Arbitrum Deposit");
+        assert_eq!(
+            field.fallback_text(),
+            "Deposit ETH to your own address on Arbitrum One"
+        );
+    }
+
+    #[test]
+    fn test_retryable_ticket_shows_recipient_and_value() {
+        let to = Address::repeat_byte(0x42);
+        let field = InboxVisualizer
+            .visualize_tx_commands(&ticket(to, to), 1, INBOX)
+            .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Send 0.500000000000000000 ETH to {to:?} on Arbitrum One")
+        );
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(!json.contains("Differs from the recipient"), "got: {json}");
+    }
+
+    #[test]
+    fn test_retryable_ticket_flags_foreign_refund_address() {
+        let to = Address::repeat_byte(0x42);
+        let field = InboxVisualizer
+            .visualize_tx_commands(&ticket(to, Address::repeat_byte(0x66)), 1, INBOX)
+            .unwrap();
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(json.contains("Differs from the recipient"), "got: {json}");
+    }
+
+    #[test]
+    fn test_unknown_inbox_and_selector() {
+        let input = IInbox::depositEthCall {}.abi_encode();
+        let field = InboxVisualizer
+            .visualize_tx_commands(&input, 1, Address::repeat_byte(0x01))
+            .unwrap();
+        assert!(field.fallback_text().ends_with("on Unknown L2"));
+        assert!(
+            InboxVisualizer
+                .visualize_tx_commands(&[0xde, 0xad, 0xbe, 0xef], 1, INBOX)
+                .is_none()
+        );
+    }
+}
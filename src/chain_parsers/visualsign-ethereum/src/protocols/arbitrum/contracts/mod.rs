@@ -0,0 +1,5 @@
+//! Arbitrum bridge contract visualizers
+
+pub mod inbox;
+
+pub use inbox::{InboxContractVisualizer, InboxVisualizer};
@@ -0,0 +1,56 @@
+//! Arbitrum native bridge
+//!
+//! L1 deposits into Arbitrum go through the delayed Inbox. Decoding them shows
+//! which rollup and which L2 address receive the funds.
+
+pub mod config;
+pub mod contracts;
+
+use crate::registry::ContractRegistry;
+use crate::visualizer::EthereumVisualizerRegistryBuilder;
+
+pub use config::ArbitrumConfig;
+pub use contracts::{InboxContractVisualizer, InboxVisualizer};
+
+/// Registers Arbitrum Inbox deployments and their visualizer
+///
+/// # Arguments
+/// * `contract_reg` - The contract registry to register addresses
+/// * `visualizer_reg` - The visualizer registry to register visualizers
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    ArbitrumConfig::register_contracts(contract_reg);
+
+    visualizer_reg.register(Box::new(InboxContractVisualizer::new()));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::arbitrum::config::ArbitrumInbox;
+    use crate::registry::ContractType;
+
+    #[test]
+    fn test_register_arbitrum() {
+        let mut contract_reg = ContractRegistry::new();
+        let mut visualizer_reg = EthereumVisualizerRegistryBuilder::new();
+
+        register(&mut contract_reg, &mut visualizer_reg);
+
+        for deployment in ArbitrumConfig::inboxes() {
+            assert_eq!(
+                contract_reg.get_contract_type(deployment.l1_chain_id, deployment.address),
+                Some(ArbitrumInbox::short_type_id().to_string())
+            );
+        }
+        assert!(
+            visualizer_reg
+                .build()
+                .get(ArbitrumInbox::short_type_id())
+                .is_some()
+        );
+    }
+}
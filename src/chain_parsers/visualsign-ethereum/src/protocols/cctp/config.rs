@@ -0,0 +1,106 @@
+//! Circle CCTP configuration
+//!
+//! Cross-Chain Transfer Protocol burns USDC through a `TokenMessenger` on the
+//! source chain and mints it on the destination. Chains are identified by CCTP
+//! domain IDs rather than chain IDs.
+//!
+//! Sources:
+//! - <https://developers.circle.com/stablecoins/evm-smart-contracts>
+//! - <https://developers.circle.com/stablecoins/supported-domains>
+
+use crate::registry::{ContractRegistry, ContractType};
+use alloy_primitives::{Address, address};
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+/// Contract type marker for CCTP `TokenMessenger` (V1 and V2)
+#[derive(Debug, Clone, Copy)]
+pub struct CctpTokenMessenger;
+
+impl ContractType for CctpTokenMessenger {}
+
+/// CCTP V2 `TokenMessengerV2`, deployed at the same address on every EVM domain
+pub const TOKEN_MESSENGER_V2: Address = address!("0x28b5a0e9C621a5BadaA536219b3a228C8168cf5d");
+
+/// How a domain encodes the bytes32 `mintRecipient`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecipientEncoding {
+    /// 20-byte EVM address, left-padded with zeros
+    Evm,
+    /// 32-byte Solana account, shown in base58
+    Solana,
+    /// Any other 32-byte account, shown as hex
+    Hex,
+}
+
+/// A CCTP destination domain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CctpDomain {
+    /// Display name of the chain
+    pub name: &'static str,
+    /// How to render the mint recipient
+    pub recipient: RecipientEncoding,
+}
+
+/// Circle CCTP configuration
+pub struct CctpConfig;
+
+impl CctpConfig {
+    /// Returns the V1 `TokenMessenger` address for a chain
+    pub fn token_messenger_v1(chain_id: u64) -> Option<Address> {
+        let address = match chain_id {
+            networks::ethereum::MAINNET => address!("0xBd3fa81B58Ba92a82136038B25aDec7066af3155"),
+            networks::avalanche::MAINNET => address!("0x6B25532e1060CE10cc3B0A99e5683b91BFDe6982"),
+            networks::optimism::MAINNET => address!("0x2B4069517957735bE00ceE0fadAE88a26365528f"),
+            networks::arbitrum::MAINNET => address!("0x19330d10D9Cc8751218eaf51E8885D058642E08A"),
+            networks::base::MAINNET => address!("0x1682Ae6375C4E4A97e4B583BC394c861A46D8962"),
+            networks::polygon::MAINNET => address!("0x9daF8c91AEFAE50b9c0E69629D3F6Ca40cA3B3FE"),
+            _ => return None,
+        };
+        Some(address)
+    }
+
+    /// Returns the chain IDs with a known CCTP deployment
+    pub fn chains() -> &'static [u64] {
+        &[
+            networks::ethereum::MAINNET,
+            networks::avalanche::MAINNET,
+            networks::optimism::MAINNET,
+            networks::arbitrum::MAINNET,
+            networks::base::MAINNET,
+            networks::polygon::MAINNET,
+            networks::unichain::MAINNET,
+            networks::linea::MAINNET,
+        ]
+    }
+
+    /// Translates a CCTP domain ID to its chain
+    pub fn domain(domain: u32) -> Option<CctpDomain> {
+        let (name, recipient) = match domain {
+            0 => ("Ethereum", RecipientEncoding::Evm),
+            1 => ("Avalanche", RecipientEncoding::Evm),
+            2 => ("OP Mainnet", RecipientEncoding::Evm),
+            3 => ("Arbitrum", RecipientEncoding::Evm),
+            4 => ("Noble", RecipientEncoding::Hex),
+            5 => ("Solana", RecipientEncoding::Solana),
+            6 => ("Base", RecipientEncoding::Evm),
+            7 => ("Polygon PoS", RecipientEncoding::Evm),
+            8 => ("Sui", RecipientEncoding::Hex),
+            9 => ("Aptos", RecipientEncoding::Hex),
+            10 => ("Unichain", RecipientEncoding::Evm),
+            11 => ("Linea", RecipientEncoding::Evm),
+            _ => return None,
+        };
+        Some(CctpDomain { name, recipient })
+    }
+
+    /// Registers every known TokenMessenger under the `CctpTokenMessenger` type
+    pub fn register_contracts(registry: &mut ContractRegistry) {
+        for &chain_id in Self::chains() {
+            let mut addresses = vec![TOKEN_MESSENGER_V2];
+            addresses.extend(Self::token_messenger_v1(chain_id));
+            registry.register_contract_typed::<CctpTokenMessenger>(chain_id, addresses);
+        }
+    }
+}
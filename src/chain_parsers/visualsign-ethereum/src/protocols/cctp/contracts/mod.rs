@@ -0,0 +1,5 @@
+//! CCTP contract visualizers
+
+pub mod token_messenger;
+
+pub use token_messenger::{TokenMessengerContractVisualizer, TokenMessengerVisualizer};
@@ -0,0 +1,425 @@
+//! CCTP TokenMessenger Visualizer
+//!
+//! Decodes USDC burns that start a CCTP transfer: V1 `depositForBurn` and
+//! `depositForBurnWithCaller`, and the V2 `depositForBurn` with fee and finality
+//! parameters. The destination domain is translated to a chain name and the
+//! bytes32 `mintRecipient` is rendered in the destination chain's address format.
+//!
+//! References:
+//! - <https://github.com/circlefin/evm-cctp-contracts/blob/master/src/TokenMessenger.sol>
+//! - <https://github.com/circlefin/evm-cctp-contracts/blob/master/src/v2/TokenMessengerV2.sol>
+
+use alloy_primitives::{Address, B256, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+};
+
+use crate::protocols::cctp::config::{CctpConfig, CctpTokenMessenger, RecipientEncoding};
+use crate::registry::{ContractRegistry, ContractType};
+
+sol! {
+    interface ITokenMessenger {
+        function depositForBurn(
+            uint256 amount,
+            uint32 destinationDomain,
+            bytes32 mintRecipient,
+            address burnToken
+        ) external returns (uint64 nonce);
+
+        function depositForBurnWithCaller(
+            uint256 amount,
+            uint32 destinationDomain,
+            bytes32 mintRecipient,
+            address burnToken,
+            bytes32 destinationCaller
+        ) external returns (uint64 nonce);
+    }
+
+    interface ITokenMessengerV2 {
+        function depositForBurn(
+            uint256 amount,
+            uint32 destinationDomain,
+            bytes32 mintRecipient,
+            address burnToken,
+            bytes32 destinationCaller,
+            uint256 maxFee,
+            uint32 minFinalityThreshold
+        ) external;
+    }
+}
+
+/// Visualizer for CCTP TokenMessenger burns
+pub struct TokenMessengerVisualizer;
+
+/// Fields common to every `depositForBurn` flavour
+struct Burn {
+    method: &'static str,
+    amount: U256,
+    destination_domain: u32,
+    mint_recipient: B256,
+    burn_token: Address,
+    destination_caller: Option<B256>,
+    max_fee: Option<U256>,
+    min_finality_threshold: Option<u32>,
+}
+
+impl TokenMessengerVisualizer {
+    /// Attempts to decode and visualize a CCTP burn
+    ///
+    /// # Arguments
+    /// * `input` - The calldata bytes (with 4-byte function selector)
+    /// * `chain_id` - The source chain ID
+    /// * `registry` - Optional registry used to format the burned amount
+    ///
+    /// # Returns
+    /// * `Some(field)` for the V1 and V2 `depositForBurn*` calls
+    /// * `None` if the input doesn't match one of them
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+        let selector = &input[..4];
+
+        let burn = if selector == ITokenMessenger::depositForBurnCall::SELECTOR {
+            let c = ITokenMessenger::depositForBurnCall::abi_decode(input).ok()?;
+            Burn {
+                method: "depositForBurn",
+                amount: c.amount,
+                destination_domain: c.destinationDomain,
+                mint_recipient: c.mintRecipient,
+                burn_token: c.burnToken,
+                destination_caller: None,
+                max_fee: None,
+                min_finality_threshold: None,
+            }
+        } else if selector == ITokenMessenger::depositForBurnWithCallerCall::SELECTOR {
+            let c = ITokenMessenger::depositForBurnWithCallerCall::abi_decode(input).ok()?;
+            Burn {
+                method: "depositForBurnWithCaller",
+                amount: c.amount,
+                destination_domain: c.destinationDomain,
+                mint_recipient: c.mintRecipient,
+                burn_token: c.burnToken,
+                destination_caller: Some(c.destinationCaller),
+                max_fee: None,
+                min_finality_threshold: None,
+            }
+        } else if selector == ITokenMessengerV2::depositForBurnCall::SELECTOR {
+            let c = ITokenMessengerV2::depositForBurnCall::abi_decode(input).ok()?;
+            Burn {
+                method: "depositForBurn (V2)",
+                amount: c.amount,
+                destination_domain: c.destinationDomain,
+                mint_recipient: c.mintRecipient,
+                burn_token: c.burnToken,
+                destination_caller: Some(c.destinationCaller),
+                max_fee: Some(c.maxFee),
+                min_finality_threshold: Some(c.minFinalityThreshold),
+            }
+        } else {
+            return None;
+        };
+
+        Some(Self::render(burn, chain_id, registry))
+    }
+
+    fn render(
+        burn: Burn,
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) -> SignablePayloadField {
+        let domain = CctpConfig::domain(burn.destination_domain);
+        let destination = match domain {
+            Some(d) => format!("{} (CCTP domain {})", d.name, burn.destination_domain),
+            None => format!("Unknown (CCTP domain {})", burn.destination_domain),
+        };
+        let encoding = domain.map_or(RecipientEncoding::Hex, |d| d.recipient);
+        let recipient = format_recipient(&burn.mint_recipient, encoding);
+
+        let format = |raw: U256| {
+            registry
+                .and_then(|r| r.format_token_amount_u256(chain_id, burn.burn_token, raw))
+                .map_or((raw.to_string(), None), |(a, s)| (a, Some(s)))
+        };
+        let (amount, symbol) = format(burn.amount);
+        let amount_text = match &symbol {
+            Some(s) => format!("{amount} {s}"),
+            None => format!("{amount} of {:?}", burn.burn_token),
+        };
+        let subtitle = format!("Bridge {amount_text} to {recipient} on {destination}");
+
+        let mut details = vec![
+            text_row("Method", burn.method.to_string()),
+            text_row("Destination Chain", destination),
+            recipient_row(&recipient),
+            address_row("Burn Token", &burn.burn_token),
+            amount_row("Amount", amount, symbol),
+        ];
+        if let Some(max_fee) = burn.max_fee {
+            let (fee, fee_symbol) = format(max_fee);
+            details.push(amount_row("Max Fee", fee, fee_symbol));
+        }
+        if let Some(threshold) = burn.min_finality_threshold {
+            details.push(text_row("Min Finality Threshold", threshold.to_string()));
+        }
+        if let Some(caller) = burn.destination_caller {
+            // A zero caller lets anyone relay the message on the destination chain.
+            let text = if caller.is_zero() {
+                "Any".to_string()
+            } else {
+                format_recipient(&caller, encoding)
+            };
+            details.push(text_row("Destination Caller", text));
+        }
+
+        SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: subtitle.clone(),
+                label: "CCTP Transfer".to_string(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 {
+                    text: "CCTP Transfer".to_string(),
+                }),
+                subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+                condensed: None,
+                expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+            },
+        }
+    }
+}
+
+/// Renders a bytes32 account in the destination chain's native format
+///
+/// EVM recipients whose upper 12 bytes are not zero cannot be a real address,
+/// so they fall back to hex rather than silently truncating.
+fn format_recipient(word: &B256, encoding: RecipientEncoding) -> String {
+    match encoding {
+        RecipientEncoding::Evm if word[..12].iter().all(|b| *b == 0) => {
+            format!("{:?}", Address::from_word(*word))
+        }
+        RecipientEncoding::Solana => bs58::encode(word.as_slice()).into_string(),
+        _ => format!("{word:?}"),
+    }
+}
+
+fn recipient_row(recipient: &str) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: recipient.to_string(),
+                label: "Mint Recipient".to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: recipient.to_string(),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: Some("Destination chain".to_string()),
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn amount_row(label: &str, amount: String, abbreviation: Option<String>) -> AnnotatedPayloadField {
+    let fallback_text = match &abbreviation {
+        Some(symbol) => format!("{amount} {symbol}"),
+        None => amount.clone(),
+    };
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AmountV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text,
+                label: label.to_string(),
+            },
+            amount_v2: SignablePayloadFieldAmountV2 {
+                amount,
+                abbreviation,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn address_row(label: &str, address: &Address) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: None,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// ContractVisualizer implementation for CCTP TokenMessenger
+pub struct TokenMessengerContractVisualizer {
+    inner: TokenMessengerVisualizer,
+}
+
+impl TokenMessengerContractVisualizer {
+    pub fn new() -> Self {
+        Self {
+            inner: TokenMessengerVisualizer,
+        }
+    }
+}
+
+impl Default for TokenMessengerContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for TokenMessengerContractVisualizer {
+    fn contract_type(&self) -> &str {
+        CctpTokenMessenger::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        Ok(self
+            .inner
+            .visualize_tx_commands(&context.calldata, context.chain_id, None)
+            .map(|field| {
+                vec![AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }]
+            }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn usdc() -> Address {
+        "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+            .parse()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_v1_burn_to_evm_domain() {
+        let recipient = Address::repeat_byte(0x42);
+        let input = ITokenMessenger::depositForBurnCall {
+            amount: U256::from(100_000_000u64),
+            destinationDomain: 6,
+            mintRecipient: recipient.into_word(),
+            burnToken: usdc(),
+        }
+        .abi_encode();
+
+        let field = TokenMessengerVisualizer
+            .visualize_tx_commands(&input, 1, None)
+            .unwrap();
+        assert_eq!(field.label(), "CCTP Transfer");
+        assert_eq!(
+            field.fallback_text(),
+            &format!(
+                "Bridge 100000000 of {:?} to {recipient:?} on Base (CCTP domain 6)",
+                usdc()
+            )
+        );
+    }
+
+    #[test]
+    fn test_solana_recipient_is_base58() {
+        let input = ITokenMessenger::depositForBurnWithCallerCall {
+            amount: U256::from(1u64),
+            destinationDomain: 5,
+            mintRecipient: B256::repeat_byte(0x01),
+            burnToken: usdc(),
+            destinationCaller: B256::ZERO,
+        }
+        .abi_encode();
+
+        let field = TokenMessengerVisualizer
+            .visualize_tx_commands(&input, 1, None)
+            .unwrap();
+        let expected = bs58::encode([0x01u8; 32]).into_string();
+        assert!(
+            field
+                .fallback_text()
+                .contains(&format!("to {expected} on Solana")),
+            "got: {}",
+            field.fallback_text()
+        );
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(json.contains("\"Any\""), "got: {json}");
+    }
+
+    #[test]
+    fn test_v2_burn_shows_fee_and_unknown_domain() {
+        let input = ITokenMessengerV2::depositForBurnCall {
+            amount: U256::from(5u64),
+            destinationDomain: 99,
+            mintRecipient: B256::repeat_byte(0x07),
+            burnToken: usdc(),
+            destinationCaller: B256::ZERO,
+            maxFee: U256::from(1u64),
+            minFinalityThreshold: 1000,
+        }
+        .abi_encode();
+
+        let field = TokenMessengerVisualizer
+            .visualize_tx_commands(&input, 1, None)
+            .unwrap();
+        assert!(
+            field
+                .fallback_text()
+                .ends_with("on Unknown (CCTP domain 99)")
+        );
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(json.contains("Max Fee"), "got: {json}");
+        assert!(json.contains("Min Finality Threshold"), "got: {json}");
+    }
+
+    #[test]
+    fn test_evm_recipient_with_dirty_upper_bytes_is_not_truncated() {
+        let word = B256::repeat_byte(0x09);
+        assert_eq!(
+            format_recipient(&word, RecipientEncoding::Evm),
+            format!("{word:?}")
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! Circle Cross-Chain Transfer Protocol (CCTP)
+//!
+//! CCTP moves native USDC between chains by burning it on the source chain and
+//! minting it on the destination. Burns are decoded into the destination chain
+//! and the mint recipient in that chain's address format.
+
+pub mod config;
+pub mod contracts;
+
+use crate::registry::ContractRegistry;
+use crate::visualizer::EthereumVisualizerRegistryBuilder;
+
+pub use config::CctpConfig;
+pub use contracts::{TokenMessengerContractVisualizer, TokenMessengerVisualizer};
+
+/// Registers CCTP TokenMessenger deployments and their visualizer
+///
+/// # Arguments
+/// * `contract_reg` - The contract registry to register addresses
+/// * `visualizer_reg` - The visualizer registry to register visualizers
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    CctpConfig::register_contracts(contract_reg);
+
+    visualizer_reg.register(Box::new(TokenMessengerContractVisualizer::new()));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::cctp::config::{CctpTokenMessenger, TOKEN_MESSENGER_V2};
+    use crate::registry::ContractType;
+
+    #[test]
+    fn test_register_cctp() {
+        let mut contract_reg = ContractRegistry::new();
+        let mut visualizer_reg = EthereumVisualizerRegistryBuilder::new();
+
+        register(&mut contract_reg, &mut visualizer_reg);
+
+        let expected = Some(CctpTokenMessenger::short_type_id().to_string());
+        for &chain_id in CctpConfig::chains() {
+            assert_eq!(
+                contract_reg.get_contract_type(chain_id, TOKEN_MESSENGER_V2),
+                expected
+            );
+            if let Some(v1) = CctpConfig::token_messenger_v1(chain_id) {
+                assert_eq!(contract_reg.get_contract_type(chain_id, v1), expected);
+            }
+        }
+        assert!(
+            visualizer_reg
+                .build()
+                .get(CctpTokenMessenger::short_type_id())
+                .is_some()
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! ERC-4337 EntryPoint configuration
+//!
+//! EntryPoints are deployed through the deterministic CREATE2 deployer, so each
+//! release sits at the same address on every chain.
+//!
+//! Source: <https://github.com/eth-infinitism/account-abstraction/releases>
+
+use crate::registry::{ContractRegistry, ContractType};
+use alloy_primitives::{Address, address};
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+/// Contract type marker for ERC-4337 EntryPoints
+#[derive(Debug, Clone, Copy)]
+pub struct Erc4337EntryPoint;
+
+impl ContractType for Erc4337EntryPoint {}
+
+/// EntryPoint v0.6 (unpacked `UserOperation`)
+pub const ENTRY_POINT_V06: Address = address!("0x5FF137D4b0FDCD49DcA30c7CF57E578a026d2789");
+
+/// EntryPoint v0.7 (`PackedUserOperation`)
+pub const ENTRY_POINT_V07: Address = address!("0x0000000071727De22E5E9d8BAf0edAc6f37da032");
+
+/// EntryPoint v0.8 (`PackedUserOperation`, EIP-712 user operation hash)
+pub const ENTRY_POINT_V08: Address = address!("0x4337084D9E255Ff0702461CF8895CE9E3b5Ff108");
+
+/// ERC-4337 EntryPoint configuration
+pub struct Erc4337Config;
+
+impl Erc4337Config {
+    /// Returns every canonical EntryPoint address
+    pub fn entry_points() -> [Address; 3] {
+        [ENTRY_POINT_V06, ENTRY_POINT_V07, ENTRY_POINT_V08]
+    }
+
+    /// Returns the EntryPoint release deployed at `address`, if it is a canonical one
+    pub fn version(address: Address) -> Option<&'static str> {
+        match address {
+            ENTRY_POINT_V06 => Some("v0.6"),
+            ENTRY_POINT_V07 => Some("v0.7"),
+            ENTRY_POINT_V08 => Some("v0.8"),
+            _ => None,
+        }
+    }
+
+    /// Returns the chain IDs where the EntryPoints are registered
+    pub fn chains() -> &'static [u64] {
+        &[
+            // Mainnets
+            networks::ethereum::MAINNET,
+            networks::optimism::MAINNET,
+            networks::bsc::MAINNET,
+            networks::gnosis::MAINNET,
+            networks::polygon::MAINNET,
+            networks::base::MAINNET,
+            networks::arbitrum::MAINNET,
+            networks::celo::MAINNET,
+            networks::avalanche::MAINNET,
+            networks::linea::MAINNET,
+            networks::scroll::MAINNET,
+            networks::blast::MAINNET,
+            networks::worldchain::MAINNET,
+            networks::unichain::MAINNET,
+            // Testnets
+            networks::ethereum::SEPOLIA,
+            networks::base::SEPOLIA,
+            networks::arbitrum::SEPOLIA,
+            networks::optimism::SEPOLIA,
+        ]
+    }
+
+    /// Registers every EntryPoint release on every supported chain
+    pub fn register_contracts(registry: &mut ContractRegistry) {
+        for &chain_id in Self::chains() {
+            registry.register_contract_typed::<Erc4337EntryPoint>(
+                chain_id,
+                Self::entry_points().to_vec(),
+            );
+        }
+    }
+}
@@ -0,0 +1,780 @@
+//! ERC-4337 EntryPoint Visualizer
+//!
+//! Decodes bundler `handleOps` calls for both EntryPoint ABIs: v0.6 takes the
+//! unpacked `UserOperation`, v0.7 and later take `PackedUserOperation` with gas
+//! limits and fees packed into `bytes32` words. Both are normalized into
+//! [`UserOperation`] so the same renderer serves calldata and JSON input.
+//!
+//! A user operation's `callData` is a call from the EntryPoint to the smart
+//! account itself, usually `execute` or `executeBatch`. Those wrappers are
+//! unpacked so each call the account will make is decoded on its own; anything
+//! else is decoded as a call to the account.
+//!
+//! Reference: <https://eips.ethereum.org/EIPS/eip-4337>
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldCommon, SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
+    SignablePayloadFieldStaticAnnotation, SignablePayloadFieldTextV2,
+};
+
+use crate::fmt::format_gwei;
+use crate::networks::format_native_amount;
+use crate::protocols::erc4337::config::{Erc4337Config, Erc4337EntryPoint};
+use crate::registry::ContractType;
+
+sol! {
+    interface IEntryPointV06 {
+        struct UserOperation {
+            address sender;
+            uint256 nonce;
+            bytes initCode;
+            bytes callData;
+            uint256 callGasLimit;
+            uint256 verificationGasLimit;
+            uint256 preVerificationGas;
+            uint256 maxFeePerGas;
+            uint256 maxPriorityFeePerGas;
+            bytes paymasterAndData;
+            bytes signature;
+        }
+
+        function handleOps(UserOperation[] calldata ops, address payable beneficiary) external;
+    }
+
+    interface IEntryPointV07 {
+        struct PackedUserOperation {
+            address sender;
+            uint256 nonce;
+            bytes initCode;
+            bytes callData;
+            bytes32 accountGasLimits;
+            uint256 preVerificationGas;
+            bytes32 gasFees;
+            bytes paymasterAndData;
+            bytes signature;
+        }
+
+        function handleOps(PackedUserOperation[] calldata ops, address payable beneficiary) external;
+    }
+
+    interface ISmartAccount {
+        function execute(address dest, uint256 value, bytes calldata func) external;
+        function executeBatch(address[] calldata dest, uint256[] calldata value, bytes[] calldata func) external;
+    }
+
+    interface ISmartAccountV06 {
+        function executeBatch(address[] calldata dest, bytes[] calldata func) external;
+    }
+}
+
+/// A user operation with gas fields and `initCode`/`paymasterAndData` unpacked
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserOperation {
+    pub sender: Address,
+    pub nonce: U256,
+    /// Set when the operation deploys the account first
+    pub factory: Option<Address>,
+    pub factory_data: Vec<u8>,
+    pub call_data: Vec<u8>,
+    pub call_gas_limit: U256,
+    pub verification_gas_limit: U256,
+    pub pre_verification_gas: U256,
+    pub max_fee_per_gas: U256,
+    pub max_priority_fee_per_gas: U256,
+    /// Set when a paymaster sponsors the operation
+    pub paymaster: Option<Address>,
+    /// Only present in the v0.7+ `paymasterAndData` layout
+    pub paymaster_verification_gas_limit: Option<U256>,
+    /// Only present in the v0.7+ `paymasterAndData` layout
+    pub paymaster_post_op_gas_limit: Option<U256>,
+    pub paymaster_data: Vec<u8>,
+}
+
+impl UserOperation {
+    /// Builds a user operation from the v0.6 (unpacked) field layout
+    fn from_v06(op: IEntryPointV06::UserOperation) -> Self {
+        let mut user_op = Self {
+            sender: op.sender,
+            nonce: op.nonce,
+            call_data: op.callData.to_vec(),
+            call_gas_limit: op.callGasLimit,
+            verification_gas_limit: op.verificationGasLimit,
+            pre_verification_gas: op.preVerificationGas,
+            max_fee_per_gas: op.maxFeePerGas,
+            max_priority_fee_per_gas: op.maxPriorityFeePerGas,
+            ..Default::default()
+        };
+        user_op.set_init_code(&op.initCode);
+        user_op.set_paymaster_and_data(&op.paymasterAndData, false);
+        user_op
+    }
+
+    /// Builds a user operation from the v0.7+ `PackedUserOperation`
+    fn from_packed(op: IEntryPointV07::PackedUserOperation) -> Self {
+        let (verification_gas_limit, call_gas_limit) =
+            split_u128_pair(op.accountGasLimits.as_slice());
+        let (max_priority_fee_per_gas, max_fee_per_gas) = split_u128_pair(op.gasFees.as_slice());
+        let mut user_op = Self {
+            sender: op.sender,
+            nonce: op.nonce,
+            call_data: op.callData.to_vec(),
+            call_gas_limit,
+            verification_gas_limit,
+            pre_verification_gas: op.preVerificationGas,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            ..Default::default()
+        };
+        user_op.set_init_code(&op.initCode);
+        user_op.set_paymaster_and_data(&op.paymasterAndData, true);
+        user_op
+    }
+
+    /// Parses a user operation from its JSON-RPC form
+    ///
+    /// Accepts the v0.6 layout (`initCode`, `paymasterAndData`), the v0.7 RPC
+    /// layout (`factory`, `paymaster`, ... as separate fields) and the packed
+    /// on-chain layout (`accountGasLimits`, `gasFees`). Quantities may be hex
+    /// (`"0x..."`) or decimal strings, or JSON numbers.
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, String> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| "user operation must be a JSON object".to_string())?;
+        let sender = json_address(object.get("sender"), "sender")?
+            .ok_or_else(|| "missing sender".to_string())?;
+        let nonce =
+            json_u256(object.get("nonce"), "nonce")?.ok_or_else(|| "missing nonce".to_string())?;
+        let call_data = json_bytes(object.get("callData"), "callData")?
+            .ok_or_else(|| "missing callData".to_string())?;
+        let quantity = |key: &str| json_u256(object.get(key), key).map(Option::unwrap_or_default);
+
+        let mut user_op = Self {
+            sender,
+            nonce,
+            call_data,
+            pre_verification_gas: quantity("preVerificationGas")?,
+            ..Default::default()
+        };
+
+        let packed = object.contains_key("accountGasLimits");
+        if packed {
+            let account_gas_limits =
+                json_bytes(object.get("accountGasLimits"), "accountGasLimits")?.unwrap_or_default();
+            let gas_fees = json_bytes(object.get("gasFees"), "gasFees")?.unwrap_or_default();
+            if account_gas_limits.len() != 32 || gas_fees.len() != 32 {
+                return Err("accountGasLimits and gasFees must be 32 bytes".to_string());
+            }
+            (user_op.verification_gas_limit, user_op.call_gas_limit) =
+                split_u128_pair(&account_gas_limits);
+            (user_op.max_priority_fee_per_gas, user_op.max_fee_per_gas) =
+                split_u128_pair(&gas_fees);
+        } else {
+            user_op.call_gas_limit = quantity("callGasLimit")?;
+            user_op.verification_gas_limit = quantity("verificationGasLimit")?;
+            user_op.max_fee_per_gas = quantity("maxFeePerGas")?;
+            user_op.max_priority_fee_per_gas = quantity("maxPriorityFeePerGas")?;
+        }
+
+        if let Some(init_code) = json_bytes(object.get("initCode"), "initCode")? {
+            user_op.set_init_code(&init_code);
+        } else if let Some(factory) = json_address(object.get("factory"), "factory")? {
+            user_op.factory = Some(factory);
+            user_op.factory_data =
+                json_bytes(object.get("factoryData"), "factoryData")?.unwrap_or_default();
+        }
+
+        if let Some(paymaster_and_data) =
+            json_bytes(object.get("paymasterAndData"), "paymasterAndData")?
+        {
+            user_op.set_paymaster_and_data(&paymaster_and_data, packed);
+        } else if let Some(paymaster) = json_address(object.get("paymaster"), "paymaster")? {
+            user_op.paymaster = Some(paymaster);
+            user_op.paymaster_verification_gas_limit = json_u256(
+                object.get("paymasterVerificationGasLimit"),
+                "paymasterVerificationGasLimit",
+            )?;
+            user_op.paymaster_post_op_gas_limit = json_u256(
+                object.get("paymasterPostOpGasLimit"),
+                "paymasterPostOpGasLimit",
+            )?;
+            user_op.paymaster_data =
+                json_bytes(object.get("paymasterData"), "paymasterData")?.unwrap_or_default();
+        }
+        Ok(user_op)
+    }
+
+    /// `initCode` is `factory || factoryData`, or empty for a deployed account
+    fn set_init_code(&mut self, init_code: &[u8]) {
+        if init_code.len() >= 20 {
+            self.factory = Some(Address::from_slice(&init_code[..20]));
+            self.factory_data = init_code[20..].to_vec();
+        }
+    }
+
+    /// `paymasterAndData` is `paymaster || data` in v0.6; v0.7 inserts the
+    /// paymaster verification and post-op gas limits (16 bytes each) before the data
+    fn set_paymaster_and_data(&mut self, paymaster_and_data: &[u8], packed: bool) {
+        if paymaster_and_data.len() < 20 {
+            return;
+        }
+        self.paymaster = Some(Address::from_slice(&paymaster_and_data[..20]));
+        let rest = &paymaster_and_data[20..];
+        if packed && rest.len() >= 32 {
+            let (verification, post_op) = split_u128_pair(&rest[..32]);
+            self.paymaster_verification_gas_limit = Some(verification);
+            self.paymaster_post_op_gas_limit = Some(post_op);
+            self.paymaster_data = rest[32..].to_vec();
+        } else {
+            self.paymaster_data = rest.to_vec();
+        }
+    }
+}
+
+/// Splits a packed `bytes32` into its high and low 128-bit halves
+fn split_u128_pair(word: &[u8]) -> (U256, U256) {
+    (
+        U256::from_be_slice(&word[..16]),
+        U256::from_be_slice(&word[16..32]),
+    )
+}
+
+fn json_u256(value: Option<&serde_json::Value>, key: &str) -> Result<Option<U256>, String> {
+    match value {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::Number(n)) => n
+            .as_u64()
+            .map(|n| Some(U256::from(n)))
+            .ok_or_else(|| format!("{key} must be a non-negative integer")),
+        Some(serde_json::Value::String(s)) => {
+            let parsed = match s.strip_prefix("0x") {
+                Some(hex_digits) => U256::from_str_radix(hex_digits, 16),
+                None => U256::from_str_radix(s, 10),
+            };
+            parsed
+                .map(Some)
+                .map_err(|e| format!("{key} is not a valid quantity: {e}"))
+        }
+        Some(_) => Err(format!("{key} must be a string or number")),
+    }
+}
+
+fn json_bytes(value: Option<&serde_json::Value>, key: &str) -> Result<Option<Vec<u8>>, String> {
+    match value {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(s)) => {
+            let hex_digits = s
+                .strip_prefix("0x")
+                .ok_or_else(|| format!("{key} must be 0x-prefixed hex"))?;
+            hex::decode(hex_digits)
+                .map(Some)
+                .map_err(|e| format!("{key} is not valid hex: {e}"))
+        }
+        Some(_) => Err(format!("{key} must be a hex string")),
+    }
+}
+
+fn json_address(value: Option<&serde_json::Value>, key: &str) -> Result<Option<Address>, String> {
+    match value {
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(serde_json::Value::String(s)) => s
+            .parse::<Address>()
+            .map(Some)
+            .map_err(|e| format!("{key} is not a valid address: {e}")),
+        Some(_) => Err(format!("{key} must be an address string")),
+    }
+}
+
+/// A decoded `handleOps` bundle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandleOps {
+    pub ops: Vec<UserOperation>,
+    /// Receives the gas fees collected from the operations
+    pub beneficiary: Address,
+}
+
+/// Visualizer for ERC-4337 EntryPoint bundles and user operations
+pub struct EntryPointVisualizer;
+
+impl EntryPointVisualizer {
+    /// Decodes a `handleOps` call for either EntryPoint ABI
+    ///
+    /// # Returns
+    /// * `Some(bundle)` for v0.6 or v0.7+ `handleOps`
+    /// * `None` if the input doesn't match either
+    pub fn decode_handle_ops(input: &[u8]) -> Option<HandleOps> {
+        if input.len() < 4 {
+            return None;
+        }
+        let selector = &input[..4];
+        if selector == IEntryPointV06::handleOpsCall::SELECTOR {
+            let call = IEntryPointV06::handleOpsCall::abi_decode(input).ok()?;
+            return Some(HandleOps {
+                ops: call.ops.into_iter().map(UserOperation::from_v06).collect(),
+                beneficiary: call.beneficiary,
+            });
+        }
+        if selector == IEntryPointV07::handleOpsCall::SELECTOR {
+            let call = IEntryPointV07::handleOpsCall::abi_decode(input).ok()?;
+            return Some(HandleOps {
+                ops: call
+                    .ops
+                    .into_iter()
+                    .map(UserOperation::from_packed)
+                    .collect(),
+                beneficiary: call.beneficiary,
+            });
+        }
+        None
+    }
+
+    /// Renders a bundle: a summary followed by one PreviewLayout per operation
+    ///
+    /// `decode_call(target, calldata)` produces the fields for each call an
+    /// account makes; see [`Self::render_user_operation`].
+    pub fn render_handle_ops<F>(
+        bundle: &HandleOps,
+        entry_point: Address,
+        chain_id: u64,
+        decode_call: F,
+    ) -> Vec<SignablePayloadField>
+    where
+        F: Fn(Address, &[u8]) -> Vec<SignablePayloadField>,
+    {
+        let total = bundle.ops.len();
+        let version = Erc4337Config::version(entry_point).unwrap_or("unknown version");
+        let subtitle = format!(
+            "Execute {total} user operation(s), gas fees to {:?}",
+            bundle.beneficiary
+        );
+        let summary = vec![
+            address_row("EntryPoint", &entry_point, Some(version)),
+            address_row("Beneficiary", &bundle.beneficiary, None),
+            text_row("Operations", total.to_string()),
+        ];
+        let mut fields = vec![preview("ERC-4337 Bundle", subtitle, summary)];
+        fields.extend(bundle.ops.iter().enumerate().map(|(i, op)| {
+            Self::render_user_operation(
+                op,
+                &format!("User Operation {} of {total}", i + 1),
+                chain_id,
+                &decode_call,
+            )
+        }));
+        fields
+    }
+
+    /// Renders one user operation
+    ///
+    /// `decode_call(target, calldata)` is invoked for each call the account
+    /// makes; when `callData` is not a recognized `execute`/`executeBatch`
+    /// wrapper it is invoked once with the account itself as the target. Call
+    /// values are shown in `chain_id`'s native currency.
+    pub fn render_user_operation<F>(
+        op: &UserOperation,
+        title: &str,
+        chain_id: u64,
+        decode_call: F,
+    ) -> SignablePayloadField
+    where
+        F: Fn(Address, &[u8]) -> Vec<SignablePayloadField>,
+    {
+        let mut subtitle = format!("Execute from smart account {:?}", op.sender);
+        if op.factory.is_some() {
+            subtitle.push_str(" (deploys the account)");
+        }
+        if let Some(paymaster) = op.paymaster {
+            subtitle.push_str(&format!(", gas paid by {paymaster:?}"));
+        }
+
+        let mut rows = vec![
+            address_row("Sender", &op.sender, Some("Smart account")),
+            text_row("Nonce", op.nonce.to_string()),
+        ];
+        if let Some(factory) = op.factory {
+            let mut row = address_row("Factory", &factory, None);
+            row.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+                text: "Deploys the account before executing".to_string(),
+            });
+            rows.push(row);
+            rows.push(text_row("Factory Data", hex_or_none(&op.factory_data)));
+        }
+        rows.extend([
+            text_row("Call Gas Limit", op.call_gas_limit.to_string()),
+            text_row(
+                "Verification Gas Limit",
+                op.verification_gas_limit.to_string(),
+            ),
+            text_row("Pre-Verification Gas", op.pre_verification_gas.to_string()),
+            text_row(
+                "Max Fee Per Gas",
+                format!("{} gwei", format_gwei(op.max_fee_per_gas)),
+            ),
+            text_row(
+                "Max Priority Fee Per Gas",
+                format!("{} gwei", format_gwei(op.max_priority_fee_per_gas)),
+            ),
+        ]);
+        match op.paymaster {
+            Some(paymaster) => {
+                rows.push(address_row("Paymaster", &paymaster, Some("Paymaster")));
+                if let Some(limit) = op.paymaster_verification_gas_limit {
+                    rows.push(text_row(
+                        "Paymaster Verification Gas Limit",
+                        limit.to_string(),
+                    ));
+                }
+                if let Some(limit) = op.paymaster_post_op_gas_limit {
+                    rows.push(text_row("Paymaster Post-Op Gas Limit", limit.to_string()));
+                }
+                rows.push(text_row("Paymaster Data", hex_or_none(&op.paymaster_data)));
+            }
+            None => rows.push(text_row("Paymaster", "None (account pays gas)".to_string())),
+        }
+        rows.extend(
+            decode_account_call(op, chain_id, &decode_call)
+                .into_iter()
+                .map(|field| AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }),
+        );
+
+        preview(title, subtitle, rows)
+    }
+}
+
+/// Unpacks `execute`/`executeBatch` into the account's individual calls
+fn decode_account_call<F>(
+    op: &UserOperation,
+    chain_id: u64,
+    decode_call: &F,
+) -> Vec<SignablePayloadField>
+where
+    F: Fn(Address, &[u8]) -> Vec<SignablePayloadField>,
+{
+    let input = op.call_data.as_slice();
+    if input.is_empty() {
+        return Vec::new();
+    }
+    let calls: Vec<(Address, U256, Vec<u8>)> =
+        if let Ok(call) = ISmartAccount::executeCall::abi_decode(input) {
+            vec![(call.dest, call.value, call.func.to_vec())]
+        } else if let Ok(call) = ISmartAccount::executeBatchCall::abi_decode(input) {
+            if call.dest.len() != call.func.len()
+                || (!call.value.is_empty() && call.value.len() != call.dest.len())
+            {
+                return decode_call(op.sender, input);
+            }
+            call.dest
+                .iter()
+                .enumerate()
+                .map(|(i, dest)| {
+                    let value = call.value.get(i).copied().unwrap_or_default();
+                    (*dest, value, call.func[i].to_vec())
+                })
+                .collect()
+        } else if let Ok(call) = ISmartAccountV06::executeBatchCall::abi_decode(input) {
+            if call.dest.len() != call.func.len() {
+                return decode_call(op.sender, input);
+            }
+            call.dest
+                .iter()
+                .zip(call.func.iter())
+                .map(|(dest, func)| (*dest, U256::ZERO, func.to_vec()))
+                .collect()
+        } else {
+            return decode_call(op.sender, input);
+        };
+
+    let total = calls.len();
+    calls
+        .iter()
+        .enumerate()
+        .map(|(i, (target, value, data))| {
+            let title = format!("Account Call {} of {total}", i + 1);
+            let mut subtitle = format!("Call {target:?}");
+            if !value.is_zero() {
+                subtitle.push_str(&format!(" with {}", format_native_amount(chain_id, *value)));
+            }
+            let mut rows = vec![
+                address_row("Target", target, None),
+                text_row("Value", format_native_amount(chain_id, *value)),
+            ];
+            if !data.is_empty() {
+                rows.extend(decode_call(*target, data).into_iter().map(|field| {
+                    AnnotatedPayloadField {
+                        signable_payload_field: field,
+                        static_annotation: None,
+                        dynamic_annotation: None,
+                    }
+                }));
+            }
+            preview(&title, subtitle, rows)
+        })
+        .collect()
+}
+
+fn hex_or_none(data: &[u8]) -> String {
+    if data.is_empty() {
+        "None".to_string()
+    } else {
+        format!("0x{}", hex::encode(data))
+    }
+}
+
+fn preview(
+    title: &str,
+    subtitle: String,
+    rows: Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text: subtitle.clone(),
+            label: title.to_string(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title.to_string(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+            condensed: None,
+            expanded: Some(SignablePayloadFieldListLayout { fields: rows }),
+        },
+    }
+}
+
+fn address_row(label: &str, address: &Address, badge: Option<&str>) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: badge.map(str::to_string),
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// ContractVisualizer implementation for the EntryPoint
+///
+/// The visualizer context has no contract registry, so account calls are shown
+/// as raw calldata here; the main conversion path decodes them fully.
+pub struct EntryPointContractVisualizer;
+
+impl EntryPointContractVisualizer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for EntryPointContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for EntryPointContractVisualizer {
+    fn contract_type(&self) -> &str {
+        Erc4337EntryPoint::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        let Some(bundle) = EntryPointVisualizer::decode_handle_ops(&context.calldata) else {
+            return Ok(None);
+        };
+        let fields = EntryPointVisualizer::render_handle_ops(
+            &bundle,
+            context.current_contract,
+            context.chain_id,
+            |_, data| vec![crate::contracts::core::FallbackVisualizer::new().visualize_hex(data)],
+        );
+        Ok(Some(
+            fields
+                .into_iter()
+                .map(|field| AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::erc4337::config::{ENTRY_POINT_V06, ENTRY_POINT_V07};
+    use alloy_primitives::B256;
+
+    fn hex_inner(_: Address, data: &[u8]) -> Vec<SignablePayloadField> {
+        vec![crate::contracts::core::FallbackVisualizer::new().visualize_hex(data)]
+    }
+
+    fn packed_pair(high: u128, low: u128) -> B256 {
+        let mut word = [0u8; 32];
+        word[..16].copy_from_slice(&high.to_be_bytes());
+        word[16..].copy_from_slice(&low.to_be_bytes());
+        B256::from(word)
+    }
+
+    #[test]
+    fn test_packed_handle_ops_unpacks_gas_and_paymaster() {
+        let paymaster = Address::repeat_byte(0x99);
+        let mut paymaster_and_data = paymaster.to_vec();
+        paymaster_and_data.extend_from_slice(packed_pair(50_000, 20_000).as_slice());
+        paymaster_and_data.extend_from_slice(&[0xab, 0xcd]);
+
+        let input = IEntryPointV07::handleOpsCall {
+            ops: vec![IEntryPointV07::PackedUserOperation {
+                sender: Address::repeat_byte(0x11),
+                nonce: U256::from(7),
+                initCode: Default::default(),
+                callData: ISmartAccount::executeCall {
+                    dest: Address::repeat_byte(0x22),
+                    value: U256::from(1_000_000_000_000_000_000u64),
+                    func: Default::default(),
+                }
+                .abi_encode()
+                .into(),
+                accountGasLimits: packed_pair(100_000, 200_000),
+                preVerificationGas: U256::from(50_000),
+                gasFees: packed_pair(1_000_000_000, 30_000_000_000),
+                paymasterAndData: paymaster_and_data.into(),
+                signature: Default::default(),
+            }],
+            beneficiary: Address::repeat_byte(0x33),
+        }
+        .abi_encode();
+
+        let bundle = EntryPointVisualizer::decode_handle_ops(&input).unwrap();
+        let op = &bundle.ops[0];
+        assert_eq!(op.verification_gas_limit, U256::from(100_000));
+        assert_eq!(op.call_gas_limit, U256::from(200_000));
+        assert_eq!(op.max_priority_fee_per_gas, U256::from(1_000_000_000u64));
+        assert_eq!(op.max_fee_per_gas, U256::from(30_000_000_000u64));
+        assert_eq!(op.paymaster, Some(paymaster));
+        assert_eq!(op.paymaster_post_op_gas_limit, Some(U256::from(20_000)));
+        assert_eq!(op.paymaster_data, vec![0xab, 0xcd]);
+
+        let fields =
+            EntryPointVisualizer::render_handle_ops(&bundle, ENTRY_POINT_V07, 1, hex_inner);
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[1].label(), "User Operation 1 of 1");
+        let json = serde_json::to_string(&fields[1]).unwrap();
+        assert!(json.contains("Account Call 1 of 1"), "got: {json}");
+        assert!(json.contains("with 1 ETH"), "got: {json}");
+    }
+
+    #[test]
+    fn test_v06_handle_ops_with_factory_and_batch() {
+        let factory = Address::repeat_byte(0x44);
+        let mut init_code = factory.to_vec();
+        init_code.extend_from_slice(&[0x01, 0x02]);
+        let input = IEntryPointV06::handleOpsCall {
+            ops: vec![IEntryPointV06::UserOperation {
+                sender: Address::repeat_byte(0x11),
+                nonce: U256::ZERO,
+                initCode: init_code.into(),
+                callData: ISmartAccountV06::executeBatchCall {
+                    dest: vec![Address::repeat_byte(0x22), Address::repeat_byte(0x23)],
+                    func: vec![vec![0xde, 0xad].into(), vec![0xbe, 0xef].into()],
+                }
+                .abi_encode()
+                .into(),
+                callGasLimit: U256::from(1),
+                verificationGasLimit: U256::from(2),
+                preVerificationGas: U256::from(3),
+                maxFeePerGas: U256::from(4),
+                maxPriorityFeePerGas: U256::from(5),
+                paymasterAndData: Default::default(),
+                signature: Default::default(),
+            }],
+            beneficiary: Address::repeat_byte(0x33),
+        }
+        .abi_encode();
+
+        let bundle = EntryPointVisualizer::decode_handle_ops(&input).unwrap();
+        assert_eq!(bundle.ops[0].factory, Some(factory));
+        let fields =
+            EntryPointVisualizer::render_handle_ops(&bundle, ENTRY_POINT_V06, 1, hex_inner);
+        assert!(fields[1].fallback_text().contains("(deploys the account)"));
+        let json = serde_json::to_string(&fields[1]).unwrap();
+        assert!(json.contains("Account Call 2 of 2"), "got: {json}");
+        assert!(json.contains("None (account pays gas)"), "got: {json}");
+    }
+
+    #[test]
+    fn test_user_operation_json_rpc_v07_layout() {
+        let json = serde_json::json!({
+            "sender": "0x1111111111111111111111111111111111111111",
+            "nonce": "0x1",
+            "factory": "0x4444444444444444444444444444444444444444",
+            "factoryData": "0x0102",
+            "callData": "0x",
+            "callGasLimit": "0x30d40",
+            "verificationGasLimit": "100000",
+            "preVerificationGas": 50000,
+            "maxFeePerGas": "0x6fc23ac00",
+            "maxPriorityFeePerGas": "0x3b9aca00",
+            "paymaster": "0x9999999999999999999999999999999999999999",
+            "paymasterVerificationGasLimit": "0xc350",
+            "paymasterPostOpGasLimit": "0x4e20",
+            "paymasterData": "0xabcd",
+            "signature": "0x"
+        });
+        let op = UserOperation::from_json(&json).unwrap();
+        assert_eq!(op.call_gas_limit, U256::from(200_000));
+        assert_eq!(op.verification_gas_limit, U256::from(100_000));
+        assert_eq!(op.max_fee_per_gas, U256::from(30_000_000_000u64));
+        assert_eq!(op.factory_data, vec![0x01, 0x02]);
+        assert_eq!(
+            op.paymaster_verification_gas_limit,
+            Some(U256::from(50_000))
+        );
+    }
+
+    #[test]
+    fn test_user_operation_json_rejects_bad_fields() {
+        assert!(UserOperation::from_json(&serde_json::json!({"nonce": "0x0"})).is_err());
+        assert!(
+            UserOperation::from_json(&serde_json::json!({
+                "sender": "0x1111111111111111111111111111111111111111",
+                "nonce": "0x0",
+                "callData": "0x",
+                "accountGasLimits": "0x01"
+            }))
+            .is_err()
+        );
+    }
+}
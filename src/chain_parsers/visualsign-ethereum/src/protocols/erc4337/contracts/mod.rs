@@ -0,0 +1,7 @@
+//! ERC-4337 contract visualizers
+
+pub mod entry_point;
+
+pub use entry_point::{
+    EntryPointContractVisualizer, EntryPointVisualizer, HandleOps, UserOperation,
+};
@@ -0,0 +1,59 @@
+//! ERC-4337 account abstraction
+//!
+//! Smart accounts act through user operations that bundlers submit to a shared
+//! EntryPoint. Bundles are decoded into their user operations, and each
+//! operation's calls are decoded through the same pipeline as a transaction.
+
+pub mod config;
+pub mod contracts;
+
+use crate::registry::ContractRegistry;
+use crate::visualizer::EthereumVisualizerRegistryBuilder;
+
+pub use config::Erc4337Config;
+pub use contracts::{EntryPointContractVisualizer, EntryPointVisualizer, HandleOps, UserOperation};
+
+/// Registers the EntryPoint deployments and their visualizer
+///
+/// # Arguments
+/// * `contract_reg` - The contract registry to register addresses
+/// * `visualizer_reg` - The visualizer registry to register visualizers
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    Erc4337Config::register_contracts(contract_reg);
+
+    visualizer_reg.register(Box::new(EntryPointContractVisualizer::new()));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::erc4337::config::Erc4337EntryPoint;
+    use crate::registry::ContractType;
+
+    #[test]
+    fn test_register_erc4337() {
+        let mut contract_reg = ContractRegistry::new();
+        let mut visualizer_reg = EthereumVisualizerRegistryBuilder::new();
+
+        register(&mut contract_reg, &mut visualizer_reg);
+
+        for &chain_id in Erc4337Config::chains() {
+            for entry_point in Erc4337Config::entry_points() {
+                assert_eq!(
+                    contract_reg.get_contract_type(chain_id, entry_point),
+                    Some(Erc4337EntryPoint::short_type_id().to_string())
+                );
+            }
+        }
+        assert!(
+            visualizer_reg
+                .build()
+                .get(Erc4337EntryPoint::short_type_id())
+                .is_some()
+        );
+    }
+}
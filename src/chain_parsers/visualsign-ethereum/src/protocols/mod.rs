@@ -1,3 +1,12 @@
+pub mod aave;
+pub mod arbitrum;
+pub mod cctp;
+pub mod erc4337;
+pub mod morpho;
+pub mod multicall;
+pub mod optimism;
+pub mod polygon;
+pub mod stargate;
 pub mod uniswap;
 
 use crate::registry::ContractRegistry;
@@ -14,4 +23,32 @@ pub fn register_all(
 ) {
     // Register Uniswap protocol
     uniswap::register(contract_reg, visualizer_reg);
+
+    // Register Aave protocol
+    aave::register(contract_reg, visualizer_reg);
+
+    // Register Multicall3
+    multicall::register(contract_reg, visualizer_reg);
+
+    // Register Stargate bridge
+    stargate::register(contract_reg, visualizer_reg);
+
+    // Register native L2 bridges (Arbitrum Inbox, OP Stack L1StandardBridge)
+    arbitrum::register(contract_reg, visualizer_reg);
+    optimism::register(contract_reg, visualizer_reg);
+
+    // Register Polygon PoS bridge
+    polygon::register(contract_reg, visualizer_reg);
+
+    // Register Circle CCTP
+    cctp::register(contract_reg, visualizer_reg);
+
+    // Register ERC-4337 EntryPoints
+    erc4337::register(contract_reg, visualizer_reg);
+
+    // Register well-known ERC-4626 vaults (sDAI, sUSDe, ...)
+    crate::contracts::core::erc4626::register(contract_reg, visualizer_reg);
+
+    // Register Morpho protocol
+    morpho::register(contract_reg, visualizer_reg);
 }
@@ -0,0 +1,75 @@
+//! Morpho protocol configuration
+//!
+//! Morpho Blue is a single immutable contract holding every lending market. A
+//! market is identified by its `MarketParams` (loan token, collateral token,
+//! oracle, interest rate model and LLTV), so calls carry the full parameters
+//! rather than a pool address.
+//!
+//! # Deployment Addresses
+//!
+//! - <https://docs.morpho.org/get-started/resources/addresses/>
+
+use crate::registry::{ContractRegistry, ContractType};
+use alloy_primitives::Address;
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+/// Contract type marker for the Morpho Blue contract
+#[derive(Debug, Clone, Copy)]
+pub struct MorphoBlue;
+
+impl ContractType for MorphoBlue {}
+
+/// Morpho protocol configuration
+pub struct MorphoConfig;
+
+impl MorphoConfig {
+    /// Returns the Blue deployment addresses (as strings) for a chain
+    fn blue_address_strs(chain_id: u64) -> &'static [&'static str] {
+        match chain_id {
+            networks::ethereum::MAINNET | networks::base::MAINNET => {
+                &["0xBBBBBbbBBb9cC5e90e3b3Af64bdAF62C37EEFFCb"]
+            }
+            _ => &[],
+        }
+    }
+
+    /// Returns the Blue deployment addresses for a chain
+    pub fn blue_addresses(chain_id: u64) -> Vec<Address> {
+        Self::blue_address_strs(chain_id)
+            .iter()
+            .filter_map(|s| s.parse().ok())
+            .collect()
+    }
+
+    /// Returns the chain IDs where Blue is deployed
+    pub fn blue_chains() -> &'static [u64] {
+        &[networks::ethereum::MAINNET, networks::base::MAINNET]
+    }
+
+    /// Registers every known Blue deployment under the `MorphoBlue` contract type
+    pub fn register_contracts(registry: &mut ContractRegistry) {
+        for &chain_id in Self::blue_chains() {
+            registry
+                .register_contract_typed::<MorphoBlue>(chain_id, Self::blue_addresses(chain_id));
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blue_addresses_parse() {
+        for &chain_id in MorphoConfig::blue_chains() {
+            assert_eq!(
+                MorphoConfig::blue_addresses(chain_id).len(),
+                MorphoConfig::blue_address_strs(chain_id).len(),
+                "invalid Blue address on chain {chain_id}"
+            );
+        }
+    }
+}
@@ -0,0 +1,648 @@
+//! Morpho Blue Visualizer
+//!
+//! Decodes the market operations on the Morpho Blue singleton: `supply`,
+//! `withdraw`, `borrow`, `repay`, `supplyCollateral`, `withdrawCollateral` and
+//! `liquidate`. Every call carries its market's `MarketParams`, which are
+//! expanded into the loan and collateral tokens, the LLTV and the market ID.
+//!
+//! Loan-side calls take either `assets` or `shares` (the other is zero); both
+//! are shown as given, since converting shares needs on-chain market state.
+//!
+//! Reference: <https://github.com/morpho-org/morpho-blue/blob/main/src/interfaces/IMorpho.sol>
+
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+use alloy_sol_types::{SolCall, SolValue, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+};
+
+use crate::registry::{ContractRegistry, ContractType};
+
+sol! {
+    interface IMorpho {
+        struct MarketParams {
+            address loanToken;
+            address collateralToken;
+            address oracle;
+            address irm;
+            uint256 lltv;
+        }
+
+        function supply(
+            MarketParams memory marketParams,
+            uint256 assets,
+            uint256 shares,
+            address onBehalf,
+            bytes memory data
+        ) external returns (uint256 assetsSupplied, uint256 sharesSupplied);
+
+        function withdraw(
+            MarketParams memory marketParams,
+            uint256 assets,
+            uint256 shares,
+            address onBehalf,
+            address receiver
+        ) external returns (uint256 assetsWithdrawn, uint256 sharesWithdrawn);
+
+        function borrow(
+            MarketParams memory marketParams,
+            uint256 assets,
+            uint256 shares,
+            address onBehalf,
+            address receiver
+        ) external returns (uint256 assetsBorrowed, uint256 sharesBorrowed);
+
+        function repay(
+            MarketParams memory marketParams,
+            uint256 assets,
+            uint256 shares,
+            address onBehalf,
+            bytes memory data
+        ) external returns (uint256 assetsRepaid, uint256 sharesRepaid);
+
+        function supplyCollateral(
+            MarketParams memory marketParams,
+            uint256 assets,
+            address onBehalf,
+            bytes memory data
+        ) external;
+
+        function withdrawCollateral(
+            MarketParams memory marketParams,
+            uint256 assets,
+            address onBehalf,
+            address receiver
+        ) external;
+
+        function liquidate(
+            MarketParams memory marketParams,
+            address borrower,
+            uint256 seizedAssets,
+            uint256 repaidShares,
+            bytes memory data
+        ) external returns (uint256, uint256);
+    }
+}
+
+use IMorpho::MarketParams;
+
+/// LLTVs are WAD-scaled: 1e18 is 100%
+const WAD: u128 = 1_000_000_000_000_000_000;
+
+/// Visualizer for Morpho Blue calls
+pub struct BlueVisualizer;
+
+/// Which side of a market an amount is denominated in
+#[derive(Clone, Copy)]
+enum Side {
+    Loan,
+    Collateral,
+}
+
+impl BlueVisualizer {
+    /// Attempts to decode and visualize Morpho Blue market operations
+    ///
+    /// # Arguments
+    /// * `input` - The calldata bytes (with 4-byte function selector)
+    /// * `chain_id` - The chain ID for token lookups
+    /// * `registry` - Optional contract registry for token metadata
+    ///
+    /// # Returns
+    /// * `Some(field)` if a recognized Morpho Blue function is found
+    /// * `None` if the input doesn't match any Morpho Blue function
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+        let market = |params: MarketParams| Market {
+            params,
+            chain_id,
+            registry,
+        };
+        let selector = &input[..4];
+
+        if selector == IMorpho::supplyCall::SELECTOR {
+            let c = IMorpho::supplyCall::abi_decode(input).ok()?;
+            let market = market(c.marketParams);
+            let amount = market.loan_amount(c.assets, c.shares);
+            Some(market.render(
+                "Supply",
+                format!("Supply {amount} to {}", market.name()),
+                vec![
+                    market.amount_row("Assets", Side::Loan, c.assets, c.shares),
+                    address_row("On Behalf Of", c.onBehalf),
+                ],
+                &c.data,
+            ))
+        } else if selector == IMorpho::withdrawCall::SELECTOR {
+            let c = IMorpho::withdrawCall::abi_decode(input).ok()?;
+            let market = market(c.marketParams);
+            let amount = market.loan_amount(c.assets, c.shares);
+            Some(market.render(
+                "Withdraw",
+                format!("Withdraw {amount} from {}", market.name()),
+                vec![
+                    market.amount_row("Assets", Side::Loan, c.assets, c.shares),
+                    address_row("On Behalf Of", c.onBehalf),
+                    address_row("Receiver", c.receiver),
+                ],
+                &Bytes::new(),
+            ))
+        } else if selector == IMorpho::borrowCall::SELECTOR {
+            let c = IMorpho::borrowCall::abi_decode(input).ok()?;
+            let market = market(c.marketParams);
+            let amount = market.loan_amount(c.assets, c.shares);
+            Some(market.render(
+                "Borrow",
+                format!("Borrow {amount} from {}", market.name()),
+                vec![
+                    market.amount_row("Assets", Side::Loan, c.assets, c.shares),
+                    address_row("On Behalf Of", c.onBehalf),
+                    address_row("Receiver", c.receiver),
+                ],
+                &Bytes::new(),
+            ))
+        } else if selector == IMorpho::repayCall::SELECTOR {
+            let c = IMorpho::repayCall::abi_decode(input).ok()?;
+            let market = market(c.marketParams);
+            let amount = market.loan_amount(c.assets, c.shares);
+            Some(market.render(
+                "Repay",
+                format!("Repay {amount} to {}", market.name()),
+                vec![
+                    market.amount_row("Assets", Side::Loan, c.assets, c.shares),
+                    address_row("On Behalf Of", c.onBehalf),
+                ],
+                &c.data,
+            ))
+        } else if selector == IMorpho::supplyCollateralCall::SELECTOR {
+            let c = IMorpho::supplyCollateralCall::abi_decode(input).ok()?;
+            let market = market(c.marketParams);
+            let amount = market.describe(Side::Collateral, c.assets);
+            Some(market.render(
+                "Supply Collateral",
+                format!("Supply {amount} as collateral to {}", market.name()),
+                vec![
+                    market.amount_row("Collateral", Side::Collateral, c.assets, U256::ZERO),
+                    address_row("On Behalf Of", c.onBehalf),
+                ],
+                &c.data,
+            ))
+        } else if selector == IMorpho::withdrawCollateralCall::SELECTOR {
+            let c = IMorpho::withdrawCollateralCall::abi_decode(input).ok()?;
+            let market = market(c.marketParams);
+            let amount = market.describe(Side::Collateral, c.assets);
+            Some(market.render(
+                "Withdraw Collateral",
+                format!("Withdraw {amount} of collateral from {}", market.name()),
+                vec![
+                    market.amount_row("Collateral", Side::Collateral, c.assets, U256::ZERO),
+                    address_row("On Behalf Of", c.onBehalf),
+                    address_row("Receiver", c.receiver),
+                ],
+                &Bytes::new(),
+            ))
+        } else if selector == IMorpho::liquidateCall::SELECTOR {
+            let c = IMorpho::liquidateCall::abi_decode(input).ok()?;
+            let market = market(c.marketParams);
+            // Like the loan-side calls, exactly one of the two amounts is set
+            let subtitle = if c.seizedAssets.is_zero() {
+                format!(
+                    "Liquidate {:?} in {}, repaying {} borrow shares",
+                    c.borrower,
+                    market.name(),
+                    c.repaidShares
+                )
+            } else {
+                format!(
+                    "Liquidate {:?} in {}, seizing {}",
+                    c.borrower,
+                    market.name(),
+                    market.describe(Side::Collateral, c.seizedAssets)
+                )
+            };
+            Some(market.render(
+                "Liquidate",
+                subtitle,
+                vec![
+                    address_row("Borrower", c.borrower),
+                    market.amount_row(
+                        "Seized Collateral",
+                        Side::Collateral,
+                        c.seizedAssets,
+                        U256::ZERO,
+                    ),
+                    text_row("Repaid Shares", c.repaidShares.to_string()),
+                ],
+                &c.data,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// A market's parameters plus what is needed to name its tokens
+struct Market<'a> {
+    params: MarketParams,
+    chain_id: u64,
+    registry: Option<&'a ContractRegistry>,
+}
+
+impl Market<'_> {
+    /// Market ID as computed on-chain: `keccak256(abi.encode(marketParams))`
+    fn id(&self) -> B256 {
+        keccak256(self.params.abi_encode())
+    }
+
+    fn token(&self, side: Side) -> Address {
+        match side {
+            Side::Loan => self.params.loanToken,
+            Side::Collateral => self.params.collateralToken,
+        }
+    }
+
+    fn symbol(&self, token: Address) -> String {
+        self.registry
+            .and_then(|r| r.get_token_symbol(self.chain_id, token))
+            .unwrap_or_else(|| format!("{token:?}"))
+    }
+
+    /// e.g. `the wstETH/USDC (86% LLTV) market`, collateral first
+    fn name(&self) -> String {
+        format!(
+            "the {}/{} ({} LLTV) market",
+            self.symbol(self.params.collateralToken),
+            self.symbol(self.params.loanToken),
+            format_lltv(self.params.lltv)
+        )
+    }
+
+    fn format(&self, side: Side, raw: U256) -> (String, Option<String>) {
+        self.registry
+            .and_then(|r| r.format_token_amount_u256(self.chain_id, self.token(side), raw))
+            .map_or((raw.to_string(), None), |(amount, symbol)| {
+                (amount, Some(symbol))
+            })
+    }
+
+    /// `1.5 USDC`, or the raw amount and token address when the token is unknown
+    fn describe(&self, side: Side, raw: U256) -> String {
+        match self.format(side, raw) {
+            (amount, Some(symbol)) => format!("{amount} {symbol}"),
+            (amount, None) => format!("{amount} of {:?}", self.token(side)),
+        }
+    }
+
+    fn loan_amount(&self, assets: U256, shares: U256) -> String {
+        if assets.is_zero() && !shares.is_zero() {
+            format!("{shares} shares")
+        } else {
+            self.describe(Side::Loan, assets)
+        }
+    }
+
+    /// An amount row, or a shares row when the call is denominated in shares
+    fn amount_row(
+        &self,
+        label: &str,
+        side: Side,
+        assets: U256,
+        shares: U256,
+    ) -> AnnotatedPayloadField {
+        if assets.is_zero() && !shares.is_zero() {
+            return text_row("Shares", shares.to_string());
+        }
+        let (amount, symbol) = self.format(side, assets);
+        amount_row(label, amount, symbol)
+    }
+
+    fn render(
+        &self,
+        action: &str,
+        subtitle: String,
+        rows: Vec<AnnotatedPayloadField>,
+        callback: &Bytes,
+    ) -> SignablePayloadField {
+        let title = format!("Morpho {action}");
+        let mut details = vec![
+            text_row("Market ID", format!("{:?}", self.id())),
+            address_row("Loan Token", self.params.loanToken),
+            address_row("Collateral Token", self.params.collateralToken),
+            text_row("LLTV", format_lltv(self.params.lltv)),
+            address_row("Oracle", self.params.oracle),
+            address_row("Interest Rate Model", self.params.irm),
+        ];
+        details.extend(rows);
+        // Non-empty data makes Morpho call back into the sender before pulling
+        // tokens, which is how bundlers chain actions
+        if !callback.is_empty() {
+            details.push(text_row(
+                "Callback Data",
+                format!("{} bytes", callback.len()),
+            ));
+        }
+
+        SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: subtitle.clone(),
+                label: title.clone(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 { text: title }),
+                subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+                condensed: None,
+                expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+            },
+        }
+    }
+}
+
+/// Formats a WAD-scaled LLTV as a percentage with up to two decimals, e.g. `86%`
+/// or `94.5%`
+fn format_lltv(lltv: U256) -> String {
+    // Basis points of a percent: 1e18 is 10000.00%
+    let hundredths = lltv.saturating_mul(U256::from(10_000u64)) / U256::from(WAD);
+    let whole = hundredths / U256::from(100u64);
+    let fraction = (hundredths % U256::from(100u64)).to::<u64>();
+    match fraction {
+        0 => format!("{whole}%"),
+        f if f % 10 == 0 => format!("{whole}.{}%", f / 10),
+        f => format!("{whole}.{f:02}%"),
+    }
+}
+
+fn amount_row(label: &str, amount: String, abbreviation: Option<String>) -> AnnotatedPayloadField {
+    let fallback_text = match &abbreviation {
+        Some(symbol) => format!("{amount} {symbol}"),
+        None => amount.clone(),
+    };
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AmountV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text,
+                label: label.to_string(),
+            },
+            amount_v2: SignablePayloadFieldAmountV2 {
+                amount,
+                abbreviation,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn address_row(label: &str, address: Address) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: None,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// CalldataVisualizer implementation for Blue
+impl crate::visualizer::CalldataVisualizer for BlueVisualizer {
+    fn visualize_calldata(
+        &self,
+        calldata: &[u8],
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) -> Option<SignablePayloadField> {
+        self.visualize_tx_commands(calldata, chain_id, registry)
+    }
+}
+
+/// ContractVisualizer implementation for Blue
+pub struct BlueContractVisualizer {
+    inner: BlueVisualizer,
+}
+
+impl BlueContractVisualizer {
+    pub fn new() -> Self {
+        Self {
+            inner: BlueVisualizer,
+        }
+    }
+}
+
+impl Default for BlueContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for BlueContractVisualizer {
+    fn contract_type(&self) -> &str {
+        crate::protocols::morpho::config::MorphoBlue::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        Ok(self
+            .inner
+            .visualize_tx_commands(&context.calldata, context.chain_id, None)
+            .map(|field| {
+                vec![AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }]
+            }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    const USDC: Address = alloy_primitives::address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+    const WETH: Address = alloy_primitives::address!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2");
+
+    fn weth_usdc() -> MarketParams {
+        MarketParams {
+            loanToken: USDC,
+            collateralToken: WETH,
+            oracle: Address::repeat_byte(0x0a),
+            irm: Address::repeat_byte(0x0b),
+            lltv: U256::from(860_000_000_000_000_000u64),
+        }
+    }
+
+    fn visualize(input: &[u8]) -> SignablePayloadField {
+        let (registry, _) = ContractRegistry::with_default_protocols();
+        BlueVisualizer
+            .visualize_tx_commands(input, 1, Some(&registry))
+            .expect("call should decode")
+    }
+
+    fn expanded(field: &SignablePayloadField) -> Vec<(String, String)> {
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected a preview layout, got {field:?}");
+        };
+        preview_layout
+            .expanded
+            .as_ref()
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_visualize_empty_input() {
+        assert_eq!(BlueVisualizer.visualize_tx_commands(&[], 1, None), None);
+    }
+
+    #[test]
+    fn test_supply_expands_market_params() {
+        let input = IMorpho::supplyCall {
+            marketParams: weth_usdc(),
+            assets: U256::from(1_500_000_000u64),
+            shares: U256::ZERO,
+            onBehalf: Address::repeat_byte(0x11),
+            data: Bytes::new(),
+        }
+        .abi_encode();
+        let field = visualize(&input);
+        assert_eq!(field.label(), "Morpho Supply");
+        assert_eq!(
+            field.fallback_text(),
+            "Supply 1500.000000 USDC to the WETH/USDC (86% LLTV) market"
+        );
+        let rows = expanded(&field);
+        assert!(rows.contains(&("LLTV".to_string(), "86%".to_string())));
+        assert!(rows.contains(&("Assets".to_string(), "1500.000000 USDC".to_string())));
+        assert!(!rows.iter().any(|(label, _)| label == "Callback Data"));
+        let (_, id) = rows.iter().find(|(label, _)| label == "Market ID").unwrap();
+        assert_eq!(id, &format!("{:?}", keccak256(weth_usdc().abi_encode())));
+    }
+
+    #[test]
+    fn test_repay_in_shares_with_callback() {
+        let input = IMorpho::repayCall {
+            marketParams: weth_usdc(),
+            assets: U256::ZERO,
+            shares: U256::from(42u64),
+            onBehalf: Address::repeat_byte(0x11),
+            data: Bytes::from(vec![1, 2, 3]),
+        }
+        .abi_encode();
+        let field = visualize(&input);
+        assert_eq!(
+            field.fallback_text(),
+            "Repay 42 shares to the WETH/USDC (86% LLTV) market"
+        );
+        let rows = expanded(&field);
+        assert!(rows.contains(&("Shares".to_string(), "42".to_string())));
+        assert!(rows.contains(&("Callback Data".to_string(), "3 bytes".to_string())));
+    }
+
+    #[test]
+    fn test_collateral_and_borrow() {
+        let supply = IMorpho::supplyCollateralCall {
+            marketParams: weth_usdc(),
+            assets: U256::from(500_000_000_000_000_000u64),
+            onBehalf: Address::repeat_byte(0x11),
+            data: Bytes::new(),
+        }
+        .abi_encode();
+        assert_eq!(
+            visualize(&supply).fallback_text(),
+            "Supply 0.500000000000000000 WETH as collateral to the WETH/USDC (86% LLTV) market"
+        );
+
+        let borrow = IMorpho::borrowCall {
+            marketParams: weth_usdc(),
+            assets: U256::from(10_000_000_000u64),
+            shares: U256::ZERO,
+            onBehalf: Address::repeat_byte(0x11),
+            receiver: Address::repeat_byte(0x22),
+        }
+        .abi_encode();
+        let field = visualize(&borrow);
+        assert_eq!(field.label(), "Morpho Borrow");
+        assert!(expanded(&field).contains(&(
+            "Receiver".to_string(),
+            format!("{:?}", Address::repeat_byte(0x22))
+        )));
+    }
+
+    #[test]
+    fn test_liquidate_unknown_tokens() {
+        let mut params = weth_usdc();
+        params.collateralToken = Address::repeat_byte(0xcc);
+        params.lltv = U256::from(945_000_000_000_000_000u64);
+        let input = IMorpho::liquidateCall {
+            marketParams: params,
+            borrower: Address::repeat_byte(0x33),
+            seizedAssets: U256::from(7u64),
+            repaidShares: U256::ZERO,
+            data: Bytes::new(),
+        }
+        .abi_encode();
+        let field = visualize(&input);
+        let collateral = format!("{:?}", Address::repeat_byte(0xcc));
+        assert_eq!(
+            field.fallback_text(),
+            &format!(
+                "Liquidate {:?} in the {collateral}/USDC (94.5% LLTV) market, seizing 7 of {collateral}",
+                Address::repeat_byte(0x33)
+            )
+        );
+    }
+
+    #[test]
+    fn test_format_lltv() {
+        assert_eq!(format_lltv(U256::from(WAD)), "100%");
+        assert_eq!(format_lltv(U256::from(625_000_000_000_000_000u64)), "62.5%");
+        assert_eq!(format_lltv(U256::from(915_000_000_000_000_000u64)), "91.5%");
+        assert_eq!(
+            format_lltv(U256::from(965_500_000_000_000_000u64)),
+            "96.55%"
+        );
+        assert_eq!(format_lltv(U256::ZERO), "0%");
+    }
+}
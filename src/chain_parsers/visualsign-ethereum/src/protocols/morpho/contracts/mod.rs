@@ -0,0 +1,5 @@
+//! Morpho protocol contract visualizers
+
+pub mod blue;
+
+pub use blue::{BlueContractVisualizer, BlueVisualizer};
@@ -0,0 +1,56 @@
+//! Morpho lending protocol
+//!
+//! Decodes the core Morpho Blue market operations: supplying and withdrawing
+//! loan assets, posting and withdrawing collateral, borrowing, repaying and
+//! liquidating, with each market shown by its token pair and LLTV.
+
+pub mod config;
+pub mod contracts;
+
+use crate::registry::ContractRegistry;
+use crate::visualizer::EthereumVisualizerRegistryBuilder;
+
+pub use config::MorphoConfig;
+pub use contracts::{BlueContractVisualizer, BlueVisualizer};
+
+/// Registers all Morpho protocol contracts and visualizers
+///
+/// # Arguments
+/// * `contract_reg` - The contract registry to register addresses
+/// * `visualizer_reg` - The visualizer registry to register visualizers
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    MorphoConfig::register_contracts(contract_reg);
+
+    visualizer_reg.register(Box::new(BlueContractVisualizer::new()));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::morpho::config::MorphoBlue;
+    use crate::registry::ContractType;
+
+    #[test]
+    fn test_register_morpho_contracts() {
+        let mut contract_reg = ContractRegistry::new();
+        let mut visualizer_reg = EthereumVisualizerRegistryBuilder::new();
+
+        register(&mut contract_reg, &mut visualizer_reg);
+
+        for &chain_id in MorphoConfig::blue_chains() {
+            for address in MorphoConfig::blue_addresses(chain_id) {
+                assert_eq!(
+                    contract_reg.get_contract_type(chain_id, address),
+                    Some(MorphoBlue::short_type_id().to_string())
+                );
+            }
+        }
+
+        let visualizers = visualizer_reg.build();
+        assert!(visualizers.get(MorphoBlue::short_type_id()).is_some());
+    }
+}
@@ -0,0 +1,66 @@
+//! Multicall3 configuration
+//!
+//! Multicall3 is deployed with a keyless transaction, so it sits at the same address
+//! on every chain that supports pre-EIP-155 deployments.
+//!
+//! Source: <https://github.com/mds1/multicall3#deployments>
+
+use crate::registry::{ContractRegistry, ContractType};
+use alloy_primitives::{Address, address};
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+/// Contract type marker for Multicall3
+#[derive(Debug, Clone, Copy)]
+pub struct Multicall3Contract;
+
+impl ContractType for Multicall3Contract {}
+
+/// Canonical Multicall3 address
+pub const MULTICALL3_ADDRESS: Address = address!("0xcA11bde05977b3631167028862bE2a173976CA11");
+
+/// Multicall3 configuration
+pub struct Multicall3Config;
+
+impl Multicall3Config {
+    /// Returns the chain IDs where Multicall3 is deployed at the canonical address
+    ///
+    /// zkSync Era is deliberately absent: its deployment lives at a different address.
+    pub fn chains() -> &'static [u64] {
+        &[
+            // Mainnets
+            networks::ethereum::MAINNET,
+            networks::optimism::MAINNET,
+            networks::bsc::MAINNET,
+            networks::gnosis::MAINNET,
+            networks::polygon::MAINNET,
+            networks::fantom::MAINNET,
+            networks::base::MAINNET,
+            networks::arbitrum::MAINNET,
+            networks::celo::MAINNET,
+            networks::avalanche::MAINNET,
+            networks::linea::MAINNET,
+            networks::scroll::MAINNET,
+            networks::blast::MAINNET,
+            networks::mantle::MAINNET,
+            networks::zora::MAINNET,
+            networks::worldchain::MAINNET,
+            networks::unichain::MAINNET,
+            // Testnets
+            networks::ethereum::SEPOLIA,
+            networks::ethereum::HOLESKY,
+            networks::base::SEPOLIA,
+            networks::arbitrum::SEPOLIA,
+            networks::optimism::SEPOLIA,
+        ]
+    }
+
+    /// Registers Multicall3 on every supported chain
+    pub fn register_contracts(registry: &mut ContractRegistry) {
+        for &chain_id in Self::chains() {
+            registry
+                .register_contract_typed::<Multicall3Contract>(chain_id, vec![MULTICALL3_ADDRESS]);
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Multicall contract visualizers
+
+pub mod multicall3;
+
+pub use multicall3::{Multicall3ContractVisualizer, Multicall3Visualizer};
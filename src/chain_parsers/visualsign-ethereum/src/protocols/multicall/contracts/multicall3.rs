@@ -0,0 +1,381 @@
+//! Multicall3 Visualizer
+//!
+//! Decodes `aggregate`, `tryAggregate`, `aggregate3` and `aggregate3Value` into their
+//! inner calls and renders one PreviewLayout per sub-call. Decoding of the inner
+//! calldata is supplied by the caller so each sub-call goes through the full
+//! dispatch pipeline (registry lookups, known-token lock-out, caller ABIs).
+//!
+//! Reference: <https://github.com/mds1/multicall3/blob/main/src/Multicall3.sol>
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldCommon, SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
+    SignablePayloadFieldTextV2,
+};
+
+use crate::networks::format_native_amount;
+use crate::protocols::multicall::config::Multicall3Contract;
+use crate::registry::ContractType;
+
+sol! {
+    interface IMulticall3 {
+        struct Call {
+            address target;
+            bytes callData;
+        }
+
+        struct Call3 {
+            address target;
+            bool allowFailure;
+            bytes callData;
+        }
+
+        struct Call3Value {
+            address target;
+            bool allowFailure;
+            uint256 value;
+            bytes callData;
+        }
+
+        function aggregate(Call[] calldata calls) external payable returns (uint256 blockNumber, bytes[] memory returnData);
+        function tryAggregate(bool requireSuccess, Call[] calldata calls) external payable;
+        function aggregate3(Call3[] calldata calls) external payable;
+        function aggregate3Value(Call3Value[] calldata calls) external payable;
+    }
+}
+
+/// One inner call of a Multicall3 batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubCall {
+    /// Contract the inner call is sent to
+    pub target: Address,
+    /// Whether the batch continues if this call reverts
+    pub allow_failure: bool,
+    /// Native value forwarded with the call (only `aggregate3Value` sets this)
+    pub value: U256,
+    /// Inner calldata
+    pub call_data: Vec<u8>,
+}
+
+/// A decoded Multicall3 batch
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MulticallBatch {
+    /// Name of the Multicall3 entry point that was called
+    pub method: &'static str,
+    /// Inner calls in execution order
+    pub calls: Vec<SubCall>,
+}
+
+/// Visualizer for Multicall3 batches
+pub struct Multicall3Visualizer;
+
+impl Multicall3Visualizer {
+    /// Decodes a Multicall3 batch call into its inner calls
+    ///
+    /// # Returns
+    /// * `Some(batch)` for `aggregate`, `tryAggregate`, `aggregate3` and `aggregate3Value`
+    /// * `None` if the input doesn't match one of them
+    pub fn decode(input: &[u8]) -> Option<MulticallBatch> {
+        if input.len() < 4 {
+            return None;
+        }
+        let selector = &input[..4];
+
+        if selector == IMulticall3::aggregateCall::SELECTOR {
+            let call = IMulticall3::aggregateCall::abi_decode(input).ok()?;
+            return Some(MulticallBatch {
+                method: "aggregate",
+                calls: call
+                    .calls
+                    .into_iter()
+                    .map(|c| SubCall {
+                        target: c.target,
+                        allow_failure: false,
+                        value: U256::ZERO,
+                        call_data: c.callData.to_vec(),
+                    })
+                    .collect(),
+            });
+        }
+        if selector == IMulticall3::tryAggregateCall::SELECTOR {
+            let call = IMulticall3::tryAggregateCall::abi_decode(input).ok()?;
+            let allow_failure = !call.requireSuccess;
+            return Some(MulticallBatch {
+                method: "tryAggregate",
+                calls: call
+                    .calls
+                    .into_iter()
+                    .map(|c| SubCall {
+                        target: c.target,
+                        allow_failure,
+                        value: U256::ZERO,
+                        call_data: c.callData.to_vec(),
+                    })
+                    .collect(),
+            });
+        }
+        if selector == IMulticall3::aggregate3Call::SELECTOR {
+            let call = IMulticall3::aggregate3Call::abi_decode(input).ok()?;
+            return Some(MulticallBatch {
+                method: "aggregate3",
+                calls: call
+                    .calls
+                    .into_iter()
+                    .map(|c| SubCall {
+                        target: c.target,
+                        allow_failure: c.allowFailure,
+                        value: U256::ZERO,
+                        call_data: c.callData.to_vec(),
+                    })
+                    .collect(),
+            });
+        }
+        if selector == IMulticall3::aggregate3ValueCall::SELECTOR {
+            let call = IMulticall3::aggregate3ValueCall::abi_decode(input).ok()?;
+            return Some(MulticallBatch {
+                method: "aggregate3Value",
+                calls: call
+                    .calls
+                    .into_iter()
+                    .map(|c| SubCall {
+                        target: c.target,
+                        allow_failure: c.allowFailure,
+                        value: c.value,
+                        call_data: c.callData.to_vec(),
+                    })
+                    .collect(),
+            });
+        }
+
+        None
+    }
+
+    /// Renders one PreviewLayout per inner call
+    ///
+    /// `decode_inner` produces the fields for a sub-call's calldata; it is not
+    /// called for sub-calls with empty calldata (plain value transfers). Values
+    /// are shown in `chain_id`'s native currency.
+    pub fn render<F>(
+        batch: &MulticallBatch,
+        chain_id: u64,
+        decode_inner: F,
+    ) -> Vec<SignablePayloadField>
+    where
+        F: Fn(&SubCall) -> Vec<SignablePayloadField>,
+    {
+        let total = batch.calls.len();
+        batch
+            .calls
+            .iter()
+            .enumerate()
+            .map(|(i, call)| {
+                let title = format!("Multicall3 Call {} of {total}", i + 1);
+                let mut subtitle = format!("Call {:?}", call.target);
+                if call.value > U256::ZERO {
+                    subtitle.push_str(&format!(
+                        " with {}",
+                        format_native_amount(chain_id, call.value)
+                    ));
+                }
+                if call.allow_failure {
+                    subtitle.push_str(" (may fail)");
+                }
+
+                let mut details = vec![address_row("Target", &call.target)];
+                if call.value > U256::ZERO {
+                    details.push(text_row(
+                        "Value",
+                        format_native_amount(chain_id, call.value),
+                    ));
+                }
+                details.push(text_row("Allow Failure", call.allow_failure.to_string()));
+                if !call.call_data.is_empty() {
+                    details.extend(decode_inner(call).into_iter().map(|field| {
+                        AnnotatedPayloadField {
+                            signable_payload_field: field,
+                            static_annotation: None,
+                            dynamic_annotation: None,
+                        }
+                    }));
+                }
+
+                SignablePayloadField::PreviewLayout {
+                    common: SignablePayloadFieldCommon {
+                        fallback_text: subtitle.clone(),
+                        label: title.clone(),
+                    },
+                    preview_layout: SignablePayloadFieldPreviewLayout {
+                        title: Some(SignablePayloadFieldTextV2 { text: title }),
+                        subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+                        condensed: None,
+                        expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+fn address_row(label: &str, address: &Address) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: None,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// ContractVisualizer implementation for Multicall3
+///
+/// The visualizer context has no contract registry, so inner calls are shown
+/// as raw calldata here; the main conversion path decodes them fully.
+pub struct Multicall3ContractVisualizer;
+
+impl Multicall3ContractVisualizer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for Multicall3ContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for Multicall3ContractVisualizer {
+    fn contract_type(&self) -> &str {
+        Multicall3Contract::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        let Some(batch) = Multicall3Visualizer::decode(&context.calldata) else {
+            return Ok(None);
+        };
+        let fields = Multicall3Visualizer::render(&batch, context.chain_id, |call| {
+            vec![crate::contracts::core::FallbackVisualizer::new().visualize_hex(&call.call_data)]
+        });
+        Ok(Some(
+            fields
+                .into_iter()
+                .map(|field| AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn hex_inner(call: &SubCall) -> Vec<SignablePayloadField> {
+        vec![crate::contracts::core::FallbackVisualizer::new().visualize_hex(&call.call_data)]
+    }
+
+    #[test]
+    fn test_decode_aggregate3() {
+        let input = IMulticall3::aggregate3Call {
+            calls: vec![
+                IMulticall3::Call3 {
+                    target: Address::repeat_byte(0x11),
+                    allowFailure: false,
+                    callData: vec![0xde, 0xad, 0xbe, 0xef].into(),
+                },
+                IMulticall3::Call3 {
+                    target: Address::repeat_byte(0x22),
+                    allowFailure: true,
+                    callData: vec![0x01, 0x02, 0x03, 0x04].into(),
+                },
+            ],
+        }
+        .abi_encode();
+
+        let batch = Multicall3Visualizer::decode(&input).unwrap();
+        assert_eq!(batch.method, "aggregate3");
+        assert_eq!(batch.calls.len(), 2);
+        assert!(batch.calls[1].allow_failure);
+
+        let fields = Multicall3Visualizer::render(&batch, 1, hex_inner);
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].label(), "Multicall3 Call 1 of 2");
+        assert!(fields[1].fallback_text().ends_with("(may fail)"));
+    }
+
+    #[test]
+    fn test_decode_try_aggregate_maps_require_success() {
+        let input = IMulticall3::tryAggregateCall {
+            requireSuccess: false,
+            calls: vec![IMulticall3::Call {
+                target: Address::repeat_byte(0x11),
+                callData: vec![0xde, 0xad, 0xbe, 0xef].into(),
+            }],
+        }
+        .abi_encode();
+
+        let batch = Multicall3Visualizer::decode(&input).unwrap();
+        assert_eq!(batch.method, "tryAggregate");
+        assert!(batch.calls[0].allow_failure);
+    }
+
+    #[test]
+    fn test_aggregate3_value_shows_value_and_skips_empty_calldata() {
+        let input = IMulticall3::aggregate3ValueCall {
+            calls: vec![IMulticall3::Call3Value {
+                target: Address::repeat_byte(0x33),
+                allowFailure: false,
+                value: U256::from(1_500_000_000_000_000_000u128),
+                callData: Default::default(),
+            }],
+        }
+        .abi_encode();
+
+        let batch = Multicall3Visualizer::decode(&input).unwrap();
+        let fields = Multicall3Visualizer::render(&batch, 1, |_| panic!("no calldata to decode"));
+        assert!(fields[0].fallback_text().contains("with 1.5 ETH"));
+
+        // Values are in the chain's native currency
+        let fields = Multicall3Visualizer::render(&batch, 137, |_| panic!("no calldata to decode"));
+        assert!(fields[0].fallback_text().contains("with 1.5 POL"));
+    }
+
+    #[test]
+    fn test_decode_unknown_selector() {
+        assert!(Multicall3Visualizer::decode(&[0xde, 0xad, 0xbe, 0xef]).is_none());
+        assert!(Multicall3Visualizer::decode(&[]).is_none());
+    }
+}
@@ -0,0 +1,57 @@
+//! Multicall3 batching contract
+//!
+//! Multicall3 executes a list of calls in one transaction. Each inner call is
+//! decoded through the same pipeline as a top-level transaction, so signers see
+//! what every sub-call does rather than an opaque byte array.
+
+pub mod config;
+pub mod contracts;
+
+use crate::registry::ContractRegistry;
+use crate::visualizer::EthereumVisualizerRegistryBuilder;
+
+pub use config::Multicall3Config;
+pub use contracts::{Multicall3ContractVisualizer, Multicall3Visualizer};
+
+/// Registers Multicall3 deployments and its visualizer
+///
+/// # Arguments
+/// * `contract_reg` - The contract registry to register addresses
+/// * `visualizer_reg` - The visualizer registry to register visualizers
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    Multicall3Config::register_contracts(contract_reg);
+
+    visualizer_reg.register(Box::new(Multicall3ContractVisualizer::new()));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::multicall::config::{MULTICALL3_ADDRESS, Multicall3Contract};
+    use crate::registry::ContractType;
+
+    #[test]
+    fn test_register_multicall3() {
+        let mut contract_reg = ContractRegistry::new();
+        let mut visualizer_reg = EthereumVisualizerRegistryBuilder::new();
+
+        register(&mut contract_reg, &mut visualizer_reg);
+
+        for &chain_id in Multicall3Config::chains() {
+            assert_eq!(
+                contract_reg.get_contract_type(chain_id, MULTICALL3_ADDRESS),
+                Some(Multicall3Contract::short_type_id().to_string())
+            );
+        }
+        assert!(
+            visualizer_reg
+                .build()
+                .get(Multicall3Contract::short_type_id())
+                .is_some()
+        );
+    }
+}
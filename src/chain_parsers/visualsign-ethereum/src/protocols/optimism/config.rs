@@ -0,0 +1,83 @@
+//! OP Stack native bridge configuration
+//!
+//! Each OP Stack chain has its own `L1StandardBridge` on Ethereum; the bridge
+//! address is what tells us which L2 a deposit lands on.
+//!
+//! Sources:
+//! - <https://docs.optimism.io/chain/addresses>
+//! - <https://docs.base.org/base-chain/network-information/base-contracts>
+
+use crate::registry::{ContractRegistry, ContractType};
+use alloy_primitives::{Address, address};
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+/// Contract type marker for OP Stack `L1StandardBridge` proxies
+#[derive(Debug, Clone, Copy)]
+pub struct OptimismL1StandardBridge;
+
+impl ContractType for OptimismL1StandardBridge {}
+
+/// An L1StandardBridge deployment and the L2 it delivers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeDeployment {
+    /// Chain the bridge is deployed on
+    pub l1_chain_id: u64,
+    /// L1StandardBridge proxy address
+    pub address: Address,
+    /// Chain that receives the deposit
+    pub l2_chain_id: u64,
+}
+
+/// Known L1StandardBridge deployments on L1
+const BRIDGES: &[BridgeDeployment] = &[
+    BridgeDeployment {
+        l1_chain_id: networks::ethereum::MAINNET,
+        address: address!("0x99C9fc46f92E8a1c0deC1b1747d010903E884bE1"),
+        l2_chain_id: networks::optimism::MAINNET,
+    },
+    BridgeDeployment {
+        l1_chain_id: networks::ethereum::MAINNET,
+        address: address!("0x3154Cf16ccdb4C6d922629664174b904d80F2C35"),
+        l2_chain_id: networks::base::MAINNET,
+    },
+    BridgeDeployment {
+        l1_chain_id: networks::ethereum::SEPOLIA,
+        address: address!("0xFBb0621E0B23b5478B630BD55a5f21f67730B0F1"),
+        l2_chain_id: networks::optimism::SEPOLIA,
+    },
+    BridgeDeployment {
+        l1_chain_id: networks::ethereum::SEPOLIA,
+        address: address!("0xfd0Bf71F60660E2f608ed56e1659C450eB113120"),
+        l2_chain_id: networks::base::SEPOLIA,
+    },
+];
+
+/// OP Stack native bridge configuration
+pub struct OptimismConfig;
+
+impl OptimismConfig {
+    /// Returns every known L1StandardBridge deployment
+    pub fn bridges() -> &'static [BridgeDeployment] {
+        BRIDGES
+    }
+
+    /// Returns the L2 chain ID a bridge delivers to
+    pub fn l2_chain_id(l1_chain_id: u64, bridge: Address) -> Option<u64> {
+        Self::bridges()
+            .iter()
+            .find(|d| d.l1_chain_id == l1_chain_id && d.address == bridge)
+            .map(|d| d.l2_chain_id)
+    }
+
+    /// Registers every known bridge under the `OptimismL1StandardBridge` type
+    pub fn register_contracts(registry: &mut ContractRegistry) {
+        for deployment in Self::bridges() {
+            registry.register_contract_typed::<OptimismL1StandardBridge>(
+                deployment.l1_chain_id,
+                vec![deployment.address],
+            );
+        }
+    }
+}
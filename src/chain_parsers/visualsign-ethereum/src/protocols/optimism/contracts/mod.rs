@@ -0,0 +1,5 @@
+//! OP Stack bridge contract visualizers
+
+pub mod standard_bridge;
+
+pub use standard_bridge::{StandardBridgeContractVisualizer, StandardBridgeVisualizer};
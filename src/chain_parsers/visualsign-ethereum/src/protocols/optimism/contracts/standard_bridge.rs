@@ -0,0 +1,365 @@
+//! OP Stack L1StandardBridge Visualizer
+//!
+//! Decodes ETH and ERC20 deposits into an OP Stack chain (OP Mainnet, Base, ...).
+//! The destination L2 comes from the bridge address and the L2 recipient is always
+//! shown; the `*To` variants send to an arbitrary address, the plain variants
+//! credit the sender's own address on L2.
+//!
+//! Reference: <https://github.com/ethereum-optimism/optimism/blob/develop/packages/contracts-bedrock/src/L1/L1StandardBridge.sol>
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+};
+
+use crate::networks::get_network_name;
+use crate::protocols::optimism::config::{OptimismConfig, OptimismL1StandardBridge};
+use crate::registry::{ContractRegistry, ContractType};
+
+sol! {
+    interface IL1StandardBridge {
+        function depositETH(uint32 _minGasLimit, bytes calldata _extraData) external payable;
+        function depositETHTo(address _to, uint32 _minGasLimit, bytes calldata _extraData) external payable;
+        function depositERC20(
+            address _l1Token,
+            address _l2Token,
+            uint256 _amount,
+            uint32 _minGasLimit,
+            bytes calldata _extraData
+        ) external;
+        function depositERC20To(
+            address _l1Token,
+            address _l2Token,
+            address _to,
+            uint256 _amount,
+            uint32 _minGasLimit,
+            bytes calldata _extraData
+        ) external;
+    }
+}
+
+/// Visualizer for OP Stack L1StandardBridge deposits
+pub struct StandardBridgeVisualizer;
+
+/// ERC20 leg of a deposit
+struct TokenDeposit {
+    l1_token: Address,
+    l2_token: Address,
+    amount: U256,
+}
+
+impl StandardBridgeVisualizer {
+    /// Attempts to decode and visualize a bridge deposit
+    ///
+    /// # Arguments
+    /// * `input` - The calldata bytes (with 4-byte function selector)
+    /// * `chain_id` - The L1 chain ID
+    /// * `bridge` - The bridge contract address, used to find the destination L2
+    /// * `registry` - Optional registry used to format the deposited token amount
+    ///
+    /// # Returns
+    /// * `Some(field)` for the four `deposit*` entry points
+    /// * `None` if the input doesn't match one of them
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: u64,
+        bridge: Address,
+        registry: Option<&ContractRegistry>,
+    ) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+        let selector = &input[..4];
+
+        // (method, explicit recipient, token leg, min gas limit)
+        let (method, recipient, token, min_gas_limit) =
+            if selector == IL1StandardBridge::depositETHCall::SELECTOR {
+                let c = IL1StandardBridge::depositETHCall::abi_decode(input).ok()?;
+                ("depositETH", None, None, c._minGasLimit)
+            } else if selector == IL1StandardBridge::depositETHToCall::SELECTOR {
+                let c = IL1StandardBridge::depositETHToCall::abi_decode(input).ok()?;
+                ("depositETHTo", Some(c._to), None, c._minGasLimit)
+            } else if selector == IL1StandardBridge::depositERC20Call::SELECTOR {
+                let c = IL1StandardBridge::depositERC20Call::abi_decode(input).ok()?;
+                let token = TokenDeposit {
+                    l1_token: c._l1Token,
+                    l2_token: c._l2Token,
+                    amount: c._amount,
+                };
+                ("depositERC20", None, Some(token), c._minGasLimit)
+            } else if selector == IL1StandardBridge::depositERC20ToCall::SELECTOR {
+                let c = IL1StandardBridge::depositERC20ToCall::abi_decode(input).ok()?;
+                let token = TokenDeposit {
+                    l1_token: c._l1Token,
+                    l2_token: c._l2Token,
+                    amount: c._amount,
+                };
+                ("depositERC20To", Some(c._to), Some(token), c._minGasLimit)
+            } else {
+                return None;
+            };
+
+        let destination = OptimismConfig::l2_chain_id(chain_id, bridge)
+            .map_or("Unknown L2".to_string(), |id| get_network_name(Some(id)));
+        let recipient_text = recipient.map_or("your own address".to_string(), |a| format!("{a:?}"));
+
+        let mut details = vec![
+            text_row("Destination Chain", destination.clone()),
+            text_row("Method", method.to_string()),
+        ];
+        match recipient {
+            Some(to) => details.push(address_row("Recipient", &to, Some("L2 recipient"))),
+            None => details.push(text_row(
+                "Recipient",
+                "Sender (same address on L2)".to_string(),
+            )),
+        }
+
+        let subtitle = match &token {
+            Some(deposit) => {
+                let (amount, symbol) = registry
+                    .and_then(|r| {
+                        r.format_token_amount_u256(chain_id, deposit.l1_token, deposit.amount)
+                    })
+                    .map_or((deposit.amount.to_string(), None), |(a, s)| (a, Some(s)));
+                let amount_text = match &symbol {
+                    Some(s) => format!("{amount} {s}"),
+                    None => format!("{amount} of {:?}", deposit.l1_token),
+                };
+                details.push(address_row("L1 Token", &deposit.l1_token, None));
+                details.push(address_row("L2 Token", &deposit.l2_token, None));
+                details.push(amount_row("Amount", amount, symbol));
+                format!("Deposit {amount_text} to {recipient_text} on {destination}")
+            }
+            None => format!("Deposit ETH to {recipient_text} on {destination}"),
+        };
+        details.push(text_row("Min Gas Limit", min_gas_limit.to_string()));
+
+        Some(SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: subtitle.clone(),
+                label: "OP Stack Deposit".to_string(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 {
+                    text: "OP Stack Deposit".to_string(),
+                }),
+                subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+                condensed: None,
+                expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+            },
+        })
+    }
+}
+
+fn amount_row(label: &str, amount: String, abbreviation: Option<String>) -> AnnotatedPayloadField {
+    let fallback_text = match &abbreviation {
+        Some(symbol) => format!("{amount} {symbol}"),
+        None => amount.clone(),
+    };
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AmountV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text,
+                label: label.to_string(),
+            },
+            amount_v2: SignablePayloadFieldAmountV2 {
+                amount,
+                abbreviation,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn address_row(label: &str, address: &Address, badge: Option<&str>) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: badge.map(str::to_string),
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// ContractVisualizer implementation for OP Stack L1StandardBridge
+pub struct StandardBridgeContractVisualizer {
+    inner: StandardBridgeVisualizer,
+}
+
+impl StandardBridgeContractVisualizer {
+    pub fn new() -> Self {
+        Self {
+            inner: StandardBridgeVisualizer,
+        }
+    }
+}
+
+impl Default for StandardBridgeContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for StandardBridgeContractVisualizer {
+    fn contract_type(&self) -> &str {
+        OptimismL1StandardBridge::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        Ok(self
+            .inner
+            .visualize_tx_commands(
+                &context.calldata,
+                context.chain_id,
+                context.current_contract,
+                None,
+            )
+            .map(|field| {
+                vec![AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }]
+            }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::token_metadata::{ErcStandard, TokenMetadata};
+    use alloy_primitives::address;
+
+    const OP_BRIDGE: Address = address!("0x99C9fc46f92E8a1c0deC1b1747d010903E884bE1");
+    const BASE_BRIDGE: Address = address!("0x3154Cf16ccdb4C6d922629664174b904d80F2C35");
+
+    #[test]
+    fn test_deposit_eth_to_names_chain_and_recipient() {
+        let to = Address::repeat_byte(0x42);
+        let input = IL1StandardBridge::depositETHToCall {
+            _to: to,
+            _minGasLimit: 200_000,
+            _extraData: Default::default(),
+        }
+        .abi_encode();
+
+        let field = StandardBridgeVisualizer
+            .visualize_tx_commands(&input, 1, BASE_BRIDGE, None)
+            .unwrap();
+        assert_eq!(field.label(), "OP Stack Deposit");
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Deposit ETH to {to:?} on Base")
+        );
+    }
+
+    #[test]
+    fn test_deposit_eth_to_self() {
+        let input = IL1StandardBridge::depositETHCall {
+            _minGasLimit: 200_000,
+            _extraData: Default::default(),
+        }
+        .abi_encode();
+
+        let field = StandardBridgeVisualizer
+            .visualize_tx_commands(&input, 1, OP_BRIDGE, None)
+            .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            "Deposit ETH to your own address on OP Mainnet"
+        );
+    }
+
+    #[test]
+    fn test_deposit_erc20_to_uses_registry_decimals() {
+        let usdc: Address = "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+            .parse()
+            .unwrap();
+        let mut registry = ContractRegistry::new();
+        registry
+            .register_token(
+                1,
+                TokenMetadata {
+                    symbol: "USDC".to_string(),
+                    name: "USD Coin".to_string(),
+                    erc_standard: ErcStandard::Erc20,
+                    contract_address: format!("{usdc:?}"),
+                    decimals: 6,
+                },
+            )
+            .unwrap();
+
+        let to = Address::repeat_byte(0x42);
+        let input = IL1StandardBridge::depositERC20ToCall {
+            _l1Token: usdc,
+            _l2Token: Address::repeat_byte(0x0b),
+            _to: to,
+            _amount: U256::from(25_000_000u64),
+            _minGasLimit: 200_000,
+            _extraData: Default::default(),
+        }
+        .abi_encode();
+
+        let field = StandardBridgeVisualizer
+            .visualize_tx_commands(&input, 1, OP_BRIDGE, Some(&registry))
+            .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Deposit 25.000000 USDC to {to:?} on OP Mainnet")
+        );
+
+        let unknown = StandardBridgeVisualizer
+            .visualize_tx_commands(&input, 1, OP_BRIDGE, None)
+            .unwrap();
+        assert!(
+            unknown
+                .fallback_text()
+                .starts_with(&format!("Deposit 25000000 of {usdc:?}")),
+            "got: {}",
+            unknown.fallback_text()
+        );
+    }
+
+    #[test]
+    fn test_unknown_selector_returns_none() {
+        assert!(
+            StandardBridgeVisualizer
+                .visualize_tx_commands(&[0xde, 0xad, 0xbe, 0xef], 1, OP_BRIDGE, None)
+                .is_none()
+        );
+    }
+}
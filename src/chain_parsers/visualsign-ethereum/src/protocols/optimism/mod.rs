@@ -0,0 +1,57 @@
+//! OP Stack native bridge
+//!
+//! Deposits into OP Mainnet, Base and other OP Stack chains go through each
+//! chain's `L1StandardBridge`. Decoding them shows which L2 and which address
+//! receive the funds.
+
+pub mod config;
+pub mod contracts;
+
+use crate::registry::ContractRegistry;
+use crate::visualizer::EthereumVisualizerRegistryBuilder;
+
+pub use config::OptimismConfig;
+pub use contracts::{StandardBridgeContractVisualizer, StandardBridgeVisualizer};
+
+/// Registers L1StandardBridge deployments and their visualizer
+///
+/// # Arguments
+/// * `contract_reg` - The contract registry to register addresses
+/// * `visualizer_reg` - The visualizer registry to register visualizers
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    OptimismConfig::register_contracts(contract_reg);
+
+    visualizer_reg.register(Box::new(StandardBridgeContractVisualizer::new()));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::optimism::config::OptimismL1StandardBridge;
+    use crate::registry::ContractType;
+
+    #[test]
+    fn test_register_optimism() {
+        let mut contract_reg = ContractRegistry::new();
+        let mut visualizer_reg = EthereumVisualizerRegistryBuilder::new();
+
+        register(&mut contract_reg, &mut visualizer_reg);
+
+        for deployment in OptimismConfig::bridges() {
+            assert_eq!(
+                contract_reg.get_contract_type(deployment.l1_chain_id, deployment.address),
+                Some(OptimismL1StandardBridge::short_type_id().to_string())
+            );
+        }
+        assert!(
+            visualizer_reg
+                .build()
+                .get(OptimismL1StandardBridge::short_type_id())
+                .is_some()
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Polygon PoS bridge configuration
+//!
+//! Deposits from Ethereum into Polygon PoS and exits back to Ethereum both go
+//! through the `RootChainManager` proxy on L1.
+//!
+//! Source: <https://docs.polygon.technology/pos/how-to/bridging/l1-l2-communication/state-transfer/>
+//! and the `static.polygon.technology` network address files.
+
+use crate::registry::{ContractRegistry, ContractType};
+use alloy_primitives::{Address, address};
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+/// Contract type marker for the Polygon PoS `RootChainManager`
+#[derive(Debug, Clone, Copy)]
+pub struct PolygonRootChainManager;
+
+impl ContractType for PolygonRootChainManager {}
+
+/// A RootChainManager deployment and the child chain it bridges to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RootChainManagerDeployment {
+    /// Chain the RootChainManager is deployed on
+    pub l1_chain_id: u64,
+    /// RootChainManager proxy address
+    pub address: Address,
+    /// Polygon PoS chain on the other side of the bridge
+    pub child_chain_id: u64,
+}
+
+/// Known RootChainManager deployments on L1
+const ROOT_CHAIN_MANAGERS: &[RootChainManagerDeployment] = &[
+    RootChainManagerDeployment {
+        l1_chain_id: networks::ethereum::MAINNET,
+        address: address!("0xA0c68C638235ee32657e8f720a23ceC1bFc77C77"),
+        child_chain_id: networks::polygon::MAINNET,
+    },
+    RootChainManagerDeployment {
+        l1_chain_id: networks::ethereum::SEPOLIA,
+        address: address!("0x34F5A25B627f50Bb3f5cAb72807c4D4F405a9232"),
+        child_chain_id: networks::polygon::AMOY,
+    },
+];
+
+/// Polygon PoS bridge configuration
+pub struct PolygonConfig;
+
+impl PolygonConfig {
+    /// Returns every known RootChainManager deployment
+    pub fn root_chain_managers() -> &'static [RootChainManagerDeployment] {
+        ROOT_CHAIN_MANAGERS
+    }
+
+    /// Returns the Polygon chain ID a RootChainManager bridges to
+    pub fn child_chain_id(l1_chain_id: u64, manager: Address) -> Option<u64> {
+        Self::root_chain_managers()
+            .iter()
+            .find(|d| d.l1_chain_id == l1_chain_id && d.address == manager)
+            .map(|d| d.child_chain_id)
+    }
+
+    /// Registers every known RootChainManager under the `PolygonRootChainManager` type
+    pub fn register_contracts(registry: &mut ContractRegistry) {
+        for deployment in Self::root_chain_managers() {
+            registry.register_contract_typed::<PolygonRootChainManager>(
+                deployment.l1_chain_id,
+                vec![deployment.address],
+            );
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Polygon PoS bridge contract visualizers
+
+pub mod root_chain_manager;
+
+pub use root_chain_manager::{RootChainManagerContractVisualizer, RootChainManagerVisualizer};
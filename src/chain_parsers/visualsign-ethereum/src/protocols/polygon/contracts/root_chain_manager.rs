@@ -0,0 +1,455 @@
+//! Polygon RootChainManager Visualizer
+//!
+//! Decodes `depositEtherFor`, `depositFor` and `exit` on the Polygon PoS bridge.
+//! `depositFor` carries a predicate-specific `depositData` blob; it is decoded
+//! according to the root token's standard:
+//!
+//! - ERC20: `abi.encode(uint256 amount)`
+//! - ERC721: `abi.encode(uint256 tokenId)` or `abi.encode(uint256[] tokenIds)`
+//! - ERC1155: `abi.encode(uint256[] ids, uint256[] amounts, bytes data)`
+//!
+//! Tokens missing from the registry are treated as ERC20 when the blob is a
+//! single word, and shown as raw bytes otherwise.
+//!
+//! Reference: <https://github.com/maticnetwork/pos-portal/blob/master/contracts/root/RootChainManager/RootChainManager.sol>
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::{SolCall, SolValue, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+};
+
+use crate::networks::get_network_name;
+use crate::protocols::polygon::config::{PolygonConfig, PolygonRootChainManager};
+use crate::registry::{ContractRegistry, ContractType};
+use crate::token_metadata::ErcStandard;
+
+sol! {
+    interface IRootChainManager {
+        function depositEtherFor(address user) external payable;
+        function depositFor(address user, address rootToken, bytes calldata depositData) external;
+        function exit(bytes calldata inputData) external;
+    }
+}
+
+/// Visualizer for Polygon PoS bridge calls
+pub struct RootChainManagerVisualizer;
+
+impl RootChainManagerVisualizer {
+    /// Attempts to decode and visualize a RootChainManager call
+    ///
+    /// # Arguments
+    /// * `input` - The calldata bytes (with 4-byte function selector)
+    /// * `chain_id` - The L1 chain ID
+    /// * `manager` - The RootChainManager address, used to find the child chain
+    /// * `registry` - Optional registry used to identify and format the root token
+    ///
+    /// # Returns
+    /// * `Some(field)` for `depositEtherFor`, `depositFor` and `exit`
+    /// * `None` if the input doesn't match one of them
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: u64,
+        manager: Address,
+        registry: Option<&ContractRegistry>,
+    ) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+        let selector = &input[..4];
+        let child_chain = PolygonConfig::child_chain_id(chain_id, manager)
+            .map_or("Unknown Polygon chain".to_string(), |id| {
+                get_network_name(Some(id))
+            });
+
+        if selector == IRootChainManager::depositEtherForCall::SELECTOR {
+            let call = IRootChainManager::depositEtherForCall::abi_decode(input).ok()?;
+            let subtitle = format!("Deposit ETH to {:?} on {child_chain}", call.user);
+            let details = vec![
+                text_row("Destination Chain", child_chain),
+                text_row("Method", "depositEtherFor".to_string()),
+                address_row("Recipient", &call.user, Some("L2 recipient")),
+            ];
+            return Some(preview("Polygon Deposit", subtitle, details));
+        }
+
+        if selector == IRootChainManager::depositForCall::SELECTOR {
+            let call = IRootChainManager::depositForCall::abi_decode(input).ok()?;
+            let deposit =
+                decode_deposit_data(chain_id, call.rootToken, &call.depositData, registry);
+            let subtitle = format!(
+                "Deposit {} to {:?} on {child_chain}",
+                deposit.summary, call.user
+            );
+            let mut details = vec![
+                text_row("Destination Chain", child_chain),
+                text_row("Method", "depositFor".to_string()),
+                address_row("Recipient", &call.user, Some("L2 recipient")),
+                address_row("Root Token", &call.rootToken, None),
+            ];
+            details.extend(deposit.rows);
+            return Some(preview("Polygon Deposit", subtitle, details));
+        }
+
+        if selector == IRootChainManager::exitCall::SELECTOR {
+            let call = IRootChainManager::exitCall::abi_decode(input).ok()?;
+            // The exit payload is an RLP-encoded burn proof; the token and amount
+            // live inside the L2 receipt, which we don't unpack here.
+            let subtitle = format!("Withdraw from {child_chain} to Ethereum");
+            let details = vec![
+                text_row("Source Chain", child_chain),
+                text_row("Method", "exit".to_string()),
+                text_row(
+                    "Exit Proof",
+                    format!("{} bytes (burn proof from L2)", call.inputData.len()),
+                ),
+            ];
+            return Some(preview("Polygon Withdrawal", subtitle, details));
+        }
+
+        None
+    }
+}
+
+/// Decoded `depositData`, as a one-line summary plus detail rows
+struct DepositData {
+    summary: String,
+    rows: Vec<AnnotatedPayloadField>,
+}
+
+fn decode_deposit_data(
+    chain_id: u64,
+    root_token: Address,
+    data: &[u8],
+    registry: Option<&ContractRegistry>,
+) -> DepositData {
+    let standard = registry.and_then(|r| r.get_token_erc_standard(Some(chain_id), root_token));
+
+    match standard {
+        Some(ErcStandard::Erc721) => {
+            let ids = if data.len() == 32 {
+                U256::abi_decode(data).ok().map(|id| vec![id])
+            } else {
+                Vec::<U256>::abi_decode(data).ok()
+            };
+            if let Some(ids) = ids {
+                let list = join(&ids);
+                let label = if ids.len() == 1 {
+                    "Token ID"
+                } else {
+                    "Token IDs"
+                };
+                return DepositData {
+                    summary: format!("NFT {label} {list}"),
+                    rows: vec![text_row(label, list)],
+                };
+            }
+        }
+        Some(ErcStandard::Erc1155) => {
+            if let Ok((ids, amounts, _)) =
+                <(Vec<U256>, Vec<U256>, alloy_primitives::Bytes)>::abi_decode_params(data)
+            {
+                let pairs: Vec<String> = ids
+                    .iter()
+                    .zip(amounts.iter())
+                    .map(|(id, amount)| format!("{amount} of ID {id}"))
+                    .collect();
+                let list = pairs.join(", ");
+                return DepositData {
+                    summary: format!("ERC1155 {list}"),
+                    rows: vec![text_row("Tokens", list)],
+                };
+            }
+        }
+        // Registered ERC20s, and unregistered tokens whose payload is a single
+        // word, which is the ERC20 predicate's layout.
+        Some(ErcStandard::Erc20) | None if data.len() == 32 => {
+            if let Ok(amount) = U256::abi_decode(data) {
+                let formatted =
+                    registry.and_then(|r| r.format_token_amount_u256(chain_id, root_token, amount));
+                let (text, symbol) = match formatted {
+                    Some((value, symbol)) => (value, Some(symbol)),
+                    None => (amount.to_string(), None),
+                };
+                let summary = match &symbol {
+                    Some(symbol) => format!("{text} {symbol}"),
+                    None => format!("{text} of {root_token:?}"),
+                };
+                return DepositData {
+                    summary,
+                    rows: vec![amount_row("Amount", text, symbol)],
+                };
+            }
+        }
+        _ => {}
+    }
+
+    let raw = format!("0x{}", hex::encode(data));
+    DepositData {
+        summary: format!("tokens of {root_token:?}"),
+        rows: vec![text_row("Deposit Data", raw)],
+    }
+}
+
+fn join(values: &[U256]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn preview(
+    title: &str,
+    subtitle: String,
+    details: Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text: subtitle.clone(),
+            label: title.to_string(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title.to_string(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+            condensed: None,
+            expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+        },
+    }
+}
+
+fn amount_row(label: &str, amount: String, abbreviation: Option<String>) -> AnnotatedPayloadField {
+    let fallback_text = match &abbreviation {
+        Some(symbol) => format!("{amount} {symbol}"),
+        None => amount.clone(),
+    };
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AmountV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text,
+                label: label.to_string(),
+            },
+            amount_v2: SignablePayloadFieldAmountV2 {
+                amount,
+                abbreviation,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn address_row(label: &str, address: &Address, badge: Option<&str>) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: badge.map(str::to_string),
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// ContractVisualizer implementation for the Polygon RootChainManager
+pub struct RootChainManagerContractVisualizer {
+    inner: RootChainManagerVisualizer,
+}
+
+impl RootChainManagerContractVisualizer {
+    pub fn new() -> Self {
+        Self {
+            inner: RootChainManagerVisualizer,
+        }
+    }
+}
+
+impl Default for RootChainManagerContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for RootChainManagerContractVisualizer {
+    fn contract_type(&self) -> &str {
+        PolygonRootChainManager::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        Ok(self
+            .inner
+            .visualize_tx_commands(
+                &context.calldata,
+                context.chain_id,
+                context.current_contract,
+                None,
+            )
+            .map(|field| {
+                vec![AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }]
+            }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::token_metadata::TokenMetadata;
+    use alloy_primitives::address;
+
+    const MANAGER: Address = address!("0xA0c68C638235ee32657e8f720a23ceC1bFc77C77");
+
+    fn registry_with(token: Address, symbol: &str, standard: ErcStandard) -> ContractRegistry {
+        let mut registry = ContractRegistry::new();
+        registry
+            .register_token(
+                1,
+                TokenMetadata {
+                    symbol: symbol.to_string(),
+                    name: symbol.to_string(),
+                    erc_standard: standard,
+                    contract_address: format!("{token:?}"),
+                    decimals: 6,
+                },
+            )
+            .unwrap();
+        registry
+    }
+
+    fn deposit_for(token: Address, data: Vec<u8>) -> Vec<u8> {
+        IRootChainManager::depositForCall {
+            user: Address::repeat_byte(0x42),
+            rootToken: token,
+            depositData: data.into(),
+        }
+        .abi_encode()
+    }
+
+    #[test]
+    fn test_deposit_ether_for() {
+        let user = Address::repeat_byte(0x42);
+        let input = IRootChainManager::depositEtherForCall { user }.abi_encode();
+        let field = RootChainManagerVisualizer
+            .visualize_tx_commands(&input, 1, MANAGER, None)
+            .unwrap();
+        assert_eq!(field.label(), "Polygon Deposit");
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Deposit ETH to {user:?} on Polygon Mainnet")
+        );
+    }
+
+    #[test]
+    fn test_deposit_for_erc20_formats_amount() {
+        let usdc = Address::repeat_byte(0xaa);
+        let registry = registry_with(usdc, "USDC", ErcStandard::Erc20);
+        let input = deposit_for(usdc, U256::from(12_500_000u64).abi_encode());
+
+        let field = RootChainManagerVisualizer
+            .visualize_tx_commands(&input, 1, MANAGER, Some(&registry))
+            .unwrap();
+        assert!(
+            field
+                .fallback_text()
+                .starts_with("Deposit 12.500000 USDC to"),
+            "got: {}",
+            field.fallback_text()
+        );
+    }
+
+    #[test]
+    fn test_deposit_for_unknown_token_single_word_is_amount() {
+        let token = Address::repeat_byte(0xbb);
+        let input = deposit_for(token, U256::from(7u64).abi_encode());
+        let field = RootChainManagerVisualizer
+            .visualize_tx_commands(&input, 1, MANAGER, None)
+            .unwrap();
+        assert!(
+            field
+                .fallback_text()
+                .starts_with(&format!("Deposit 7 of {token:?}")),
+            "got: {}",
+            field.fallback_text()
+        );
+    }
+
+    #[test]
+    fn test_deposit_for_erc721_batch_and_erc1155() {
+        let nft = Address::repeat_byte(0xcc);
+        let registry = registry_with(nft, "NFT", ErcStandard::Erc721);
+        let ids = vec![U256::from(1u64), U256::from(2u64)];
+        let input = deposit_for(nft, ids.abi_encode());
+        let field = RootChainManagerVisualizer
+            .visualize_tx_commands(&input, 1, MANAGER, Some(&registry))
+            .unwrap();
+        assert!(field.fallback_text().contains("NFT Token IDs 1, 2"));
+
+        let multi = Address::repeat_byte(0xdd);
+        let registry = registry_with(multi, "ITEMS", ErcStandard::Erc1155);
+        let data = (
+            vec![U256::from(5u64)],
+            vec![U256::from(3u64)],
+            alloy_primitives::Bytes::new(),
+        )
+            .abi_encode_params();
+        let input = deposit_for(multi, data);
+        let field = RootChainManagerVisualizer
+            .visualize_tx_commands(&input, 1, MANAGER, Some(&registry))
+            .unwrap();
+        assert!(
+            field.fallback_text().contains("ERC1155 3 of ID 5"),
+            "got: {}",
+            field.fallback_text()
+        );
+    }
+
+    #[test]
+    fn test_exit_is_a_withdrawal() {
+        let input = IRootChainManager::exitCall {
+            inputData: vec![0xf9; 64].into(),
+        }
+        .abi_encode();
+        let field = RootChainManagerVisualizer
+            .visualize_tx_commands(&input, 1, MANAGER, None)
+            .unwrap();
+        assert_eq!(field.label(), "Polygon Withdrawal");
+        assert_eq!(
+            field.fallback_text(),
+            "Withdraw from Polygon Mainnet to Ethereum"
+        );
+    }
+}
@@ -0,0 +1,56 @@
+//! Polygon PoS bridge
+//!
+//! Deposits into Polygon PoS and exits back to Ethereum go through the
+//! `RootChainManager`. Deposit data is decoded into the token amount or IDs.
+
+pub mod config;
+pub mod contracts;
+
+use crate::registry::ContractRegistry;
+use crate::visualizer::EthereumVisualizerRegistryBuilder;
+
+pub use config::PolygonConfig;
+pub use contracts::{RootChainManagerContractVisualizer, RootChainManagerVisualizer};
+
+/// Registers RootChainManager deployments and their visualizer
+///
+/// # Arguments
+/// * `contract_reg` - The contract registry to register addresses
+/// * `visualizer_reg` - The visualizer registry to register visualizers
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    PolygonConfig::register_contracts(contract_reg);
+
+    visualizer_reg.register(Box::new(RootChainManagerContractVisualizer::new()));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::polygon::config::PolygonRootChainManager;
+    use crate::registry::ContractType;
+
+    #[test]
+    fn test_register_polygon() {
+        let mut contract_reg = ContractRegistry::new();
+        let mut visualizer_reg = EthereumVisualizerRegistryBuilder::new();
+
+        register(&mut contract_reg, &mut visualizer_reg);
+
+        for deployment in PolygonConfig::root_chain_managers() {
+            assert_eq!(
+                contract_reg.get_contract_type(deployment.l1_chain_id, deployment.address),
+                Some(PolygonRootChainManager::short_type_id().to_string())
+            );
+        }
+        assert!(
+            visualizer_reg
+                .build()
+                .get(PolygonRootChainManager::short_type_id())
+                .is_some()
+        );
+    }
+}
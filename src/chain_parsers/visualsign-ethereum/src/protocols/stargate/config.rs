@@ -0,0 +1,186 @@
+//! Stargate protocol configuration
+//!
+//! Contains router and pool deployments plus the LayerZero chain identifier tables.
+//!
+//! # Deployment Addresses
+//!
+//! - V1 (Router): <https://stargateprotocol.gitbook.io/stargate/developers/contract-addresses/mainnet>
+//! - V2 (Pools / OFTs): <https://stargateprotocol.gitbook.io/stargate/v2-developer-docs/technical-reference/mainnet-contracts>
+//!
+//! LayerZero identifies chains with its own numbering: V1 uses `uint16` chain IDs
+//! (e.g. 101 for Ethereum) and V2 uses `uint32` endpoint IDs, which are the V1 ID
+//! offset by 30000 on mainnets (e.g. 30101) and 40000 on testnets.
+
+use crate::registry::{ContractRegistry, ContractType};
+use alloy_primitives::Address;
+
+/// Re-export chain ID constants from crate::networks::id
+pub use crate::networks::id as networks;
+
+/// Contract type marker for Stargate routers and pools
+///
+/// The V1 Router (`swap`) and the V2 pools (`send`/`sendToken`) are decoded by the
+/// same visualizer, which tells them apart by selector.
+#[derive(Debug, Clone, Copy)]
+pub struct StargateContract;
+
+impl ContractType for StargateContract {}
+
+/// Static description of a Stargate V2 pool or OFT deployment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StargatePoolInfo {
+    /// Pool (or OFT) contract address
+    pub address: &'static str,
+    /// Symbol of the bridged asset
+    pub symbol: &'static str,
+    /// Local decimals of the bridged asset (`amountLD` is in these units)
+    pub decimals: u8,
+}
+
+/// Stargate protocol configuration
+pub struct StargateConfig;
+
+impl StargateConfig {
+    /// Returns the Stargate V1 Router addresses for a chain
+    pub fn router_addresses(chain_id: u64) -> &'static [&'static str] {
+        match chain_id {
+            networks::ethereum::MAINNET => &["0x8731d54E9D02c286767d56ac03e8037C07e01e98"],
+            _ => &[],
+        }
+    }
+
+    /// Returns the Stargate V2 pools for a chain
+    pub fn pools(chain_id: u64) -> &'static [StargatePoolInfo] {
+        match chain_id {
+            networks::ethereum::MAINNET => &[
+                StargatePoolInfo {
+                    address: "0x77b2043768d28E9C9aB44E1aBfC95944bcE57931",
+                    symbol: "ETH",
+                    decimals: 18,
+                },
+                StargatePoolInfo {
+                    address: "0xc026395860Db2d07ee33e05fE50ed7bD583189C7",
+                    symbol: "USDC",
+                    decimals: 6,
+                },
+                StargatePoolInfo {
+                    address: "0x933597a323Eb81cAe705C5bC29985172fd5A3973",
+                    symbol: "USDT",
+                    decimals: 6,
+                },
+            ],
+            _ => &[],
+        }
+    }
+
+    /// Returns the chain IDs with known Stargate deployments
+    pub fn chains() -> &'static [u64] {
+        &[networks::ethereum::MAINNET]
+    }
+
+    /// Looks up a known V2 pool by address on a chain
+    pub fn pool_info(chain_id: u64, address: Address) -> Option<&'static StargatePoolInfo> {
+        Self::pools(chain_id)
+            .iter()
+            .find(|info| info.address.parse::<Address>().ok() == Some(address))
+    }
+
+    /// Returns the (symbol, local decimals) of a V1 pool ID on the source chain
+    ///
+    /// Pool IDs are global, but decimals are local: USDT on BNB Chain has 18.
+    pub fn v1_pool_asset(chain_id: u64, pool_id: u64) -> Option<(&'static str, u8)> {
+        match (pool_id, chain_id) {
+            (1, _) => Some(("USDC", 6)),
+            (2, networks::bsc::MAINNET) => Some(("USDT", 18)),
+            (2, _) => Some(("USDT", 6)),
+            (3, _) => Some(("DAI", 18)),
+            (13, _) => Some(("ETH", 18)),
+            _ => None,
+        }
+    }
+
+    /// Translates a LayerZero V1 chain ID or V2 endpoint ID to an EVM chain ID
+    ///
+    /// Source: <https://docs.layerzero.network/v2/deployments/deployed-contracts>
+    pub fn layerzero_to_chain_id(lz_id: u32) -> Option<u64> {
+        // V2 mainnet endpoint IDs are the V1 IDs offset by 30000.
+        let v1_id = if (30_000..40_000).contains(&lz_id) {
+            lz_id - 30_000
+        } else {
+            lz_id
+        };
+        let chain_id = match v1_id {
+            101 => networks::ethereum::MAINNET,
+            102 => networks::bsc::MAINNET,
+            106 => networks::avalanche::MAINNET,
+            109 => networks::polygon::MAINNET,
+            110 => networks::arbitrum::MAINNET,
+            111 => networks::optimism::MAINNET,
+            112 => networks::fantom::MAINNET,
+            125 => networks::celo::MAINNET,
+            145 => networks::gnosis::MAINNET,
+            165 => networks::zksync::MAINNET,
+            181 => networks::mantle::MAINNET,
+            183 => networks::linea::MAINNET,
+            184 => networks::base::MAINNET,
+            195 => networks::zora::MAINNET,
+            214 => networks::scroll::MAINNET,
+            243 => networks::blast::MAINNET,
+            40161 => networks::ethereum::SEPOLIA,
+            _ => return None,
+        };
+        Some(chain_id)
+    }
+
+    /// Registers every known router and pool under the `StargateContract` type
+    pub fn register_contracts(registry: &mut ContractRegistry) {
+        for &chain_id in Self::chains() {
+            let addresses = Self::router_addresses(chain_id)
+                .iter()
+                .copied()
+                .chain(Self::pools(chain_id).iter().map(|info| info.address))
+                .filter_map(|s| s.parse().ok())
+                .collect();
+            registry.register_contract_typed::<StargateContract>(chain_id, addresses);
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_addresses_parse() {
+        for &chain_id in StargateConfig::chains() {
+            for s in StargateConfig::router_addresses(chain_id) {
+                assert!(s.parse::<Address>().is_ok(), "{s}");
+            }
+            for info in StargateConfig::pools(chain_id) {
+                assert!(info.address.parse::<Address>().is_ok(), "{}", info.address);
+            }
+        }
+    }
+
+    #[test]
+    fn test_layerzero_ids_map_to_chains() {
+        assert_eq!(StargateConfig::layerzero_to_chain_id(110), Some(42161));
+        assert_eq!(StargateConfig::layerzero_to_chain_id(30110), Some(42161));
+        assert_eq!(StargateConfig::layerzero_to_chain_id(30184), Some(8453));
+        assert_eq!(StargateConfig::layerzero_to_chain_id(40161), Some(11155111));
+        assert_eq!(StargateConfig::layerzero_to_chain_id(30999), None);
+    }
+
+    #[test]
+    fn test_v1_pool_decimals_are_local() {
+        assert_eq!(
+            StargateConfig::v1_pool_asset(networks::ethereum::MAINNET, 2),
+            Some(("USDT", 6))
+        );
+        assert_eq!(
+            StargateConfig::v1_pool_asset(networks::bsc::MAINNET, 2),
+            Some(("USDT", 18))
+        );
+    }
+}
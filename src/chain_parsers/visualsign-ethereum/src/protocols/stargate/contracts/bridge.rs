@@ -0,0 +1,455 @@
+//! Stargate Bridge Visualizer
+//!
+//! Decodes Stargate V1 Router `swap` and the V2 / LayerZero OFT `send` and
+//! `sendToken` calls. Destination LayerZero IDs are translated to network names and
+//! the bridged token, amount, minimum amount and destination address are shown.
+//!
+//! References:
+//! - <https://github.com/stargate-protocol/stargate/blob/main/contracts/Router.sol>
+//! - <https://github.com/stargate-protocol/stargate-v2/blob/main/packages/stg-evm-v2/src/StargateBase.sol>
+//! - <https://github.com/LayerZero-Labs/LayerZero-v2/blob/main/packages/layerzero-v2/evm/oapp/contracts/oft/interfaces/IOFT.sol>
+
+use alloy_primitives::{Address, B256, U256, utils::format_units};
+use alloy_sol_types::{SolCall, sol};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+};
+
+use crate::networks::get_network_name;
+use crate::protocols::stargate::config::{StargateConfig, StargateContract};
+use crate::registry::ContractType;
+
+sol! {
+    interface IStargateRouter {
+        struct lzTxObj {
+            uint256 dstGasForCall;
+            uint256 dstNativeAmount;
+            bytes dstNativeAddr;
+        }
+
+        function swap(
+            uint16 _dstChainId,
+            uint256 _srcPoolId,
+            uint256 _dstPoolId,
+            address _refundAddress,
+            uint256 _amountLD,
+            uint256 _minAmountLD,
+            lzTxObj memory _lzTxParams,
+            bytes calldata _to,
+            bytes calldata _payload
+        ) external payable;
+    }
+
+    interface IOFT {
+        struct SendParam {
+            uint32 dstEid;
+            bytes32 to;
+            uint256 amountLD;
+            uint256 minAmountLD;
+            bytes extraOptions;
+            bytes composeMsg;
+            bytes oftCmd;
+        }
+
+        struct MessagingFee {
+            uint256 nativeFee;
+            uint256 lzTokenFee;
+        }
+
+        function send(SendParam calldata _sendParam, MessagingFee calldata _fee, address _refundAddress) external payable;
+        function sendToken(SendParam calldata _sendParam, MessagingFee calldata _fee, address _refundAddress) external payable;
+    }
+}
+
+/// Visualizer for Stargate and LayerZero OFT bridge calls
+pub struct StargateVisualizer;
+
+/// Fields shared by every bridge call once decoded
+struct BridgeTransfer {
+    method: &'static str,
+    lz_destination: u32,
+    token: Option<(&'static str, u8)>,
+    amount: U256,
+    min_amount: U256,
+    recipient: String,
+    refund_address: Address,
+}
+
+impl StargateVisualizer {
+    /// Attempts to decode and visualize a bridge call
+    ///
+    /// # Arguments
+    /// * `input` - The calldata bytes (with 4-byte function selector)
+    /// * `chain_id` - The source chain ID
+    /// * `contract` - The destination contract, used to identify the bridged token
+    ///
+    /// # Returns
+    /// * `Some(field)` for `swap`, `send` and `sendToken`
+    /// * `None` if the input doesn't match one of them
+    pub fn visualize_tx_commands(
+        &self,
+        input: &[u8],
+        chain_id: u64,
+        contract: Option<Address>,
+    ) -> Option<SignablePayloadField> {
+        if input.len() < 4 {
+            return None;
+        }
+        let selector = &input[..4];
+
+        if selector == IStargateRouter::swapCall::SELECTOR {
+            let call = IStargateRouter::swapCall::abi_decode(input).ok()?;
+            let pool_id = u64::try_from(call._srcPoolId).ok();
+            return Some(Self::render(BridgeTransfer {
+                method: "swap",
+                lz_destination: u32::from(call._dstChainId),
+                token: pool_id.and_then(|id| StargateConfig::v1_pool_asset(chain_id, id)),
+                amount: call._amountLD,
+                min_amount: call._minAmountLD,
+                recipient: bytes_to_recipient(&call._to),
+                refund_address: call._refundAddress,
+            }));
+        }
+
+        let (method, send_param, refund_address) = if selector == IOFT::sendCall::SELECTOR {
+            let call = IOFT::sendCall::abi_decode(input).ok()?;
+            ("send", call._sendParam, call._refundAddress)
+        } else if selector == IOFT::sendTokenCall::SELECTOR {
+            let call = IOFT::sendTokenCall::abi_decode(input).ok()?;
+            ("sendToken", call._sendParam, call._refundAddress)
+        } else {
+            return None;
+        };
+
+        let token = contract
+            .and_then(|address| StargateConfig::pool_info(chain_id, address))
+            .map(|info| (info.symbol, info.decimals));
+        Some(Self::render(BridgeTransfer {
+            method,
+            lz_destination: send_param.dstEid,
+            token,
+            amount: send_param.amountLD,
+            min_amount: send_param.minAmountLD,
+            recipient: bytes32_to_recipient(&send_param.to),
+            refund_address,
+        }))
+    }
+
+    fn render(transfer: BridgeTransfer) -> SignablePayloadField {
+        let destination = destination_label(transfer.lz_destination);
+        let amount = format_amount(transfer.amount, transfer.token);
+        let subtitle = format!(
+            "Bridge {amount} to {destination}, recipient {}",
+            transfer.recipient
+        );
+
+        let details = vec![
+            text_row("Method", transfer.method.to_string()),
+            text_row("Destination Chain", destination),
+            text_row(
+                "Token",
+                transfer
+                    .token
+                    .map_or("Unknown".to_string(), |(symbol, _)| symbol.to_string()),
+            ),
+            amount_row("Amount", transfer.amount, transfer.token),
+            amount_row("Min Amount", transfer.min_amount, transfer.token),
+            recipient_row(&transfer.recipient),
+            address_row("Refund Address", &transfer.refund_address),
+        ];
+
+        SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: subtitle.clone(),
+                label: "Stargate Bridge".to_string(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 {
+                    text: "Stargate Bridge".to_string(),
+                }),
+                subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+                condensed: None,
+                expanded: Some(SignablePayloadFieldListLayout { fields: details }),
+            },
+        }
+    }
+}
+
+/// "Arbitrum One (LayerZero ID 30110)", or the raw ID for unknown destinations
+fn destination_label(lz_id: u32) -> String {
+    match StargateConfig::layerzero_to_chain_id(lz_id) {
+        Some(chain_id) => format!(
+            "{} (LayerZero ID {lz_id})",
+            get_network_name(Some(chain_id))
+        ),
+        None => format!("Unknown (LayerZero ID {lz_id})"),
+    }
+}
+
+fn format_amount(raw: U256, token: Option<(&'static str, u8)>) -> String {
+    match token {
+        Some((symbol, decimals)) => match format_units(raw, decimals) {
+            Ok(amount) => format!("{amount} {symbol}"),
+            Err(_) => format!("{raw} {symbol} (raw)"),
+        },
+        None => format!("{raw} (raw units)"),
+    }
+}
+
+/// V1 `_to` is the packed 20-byte destination address on EVM chains
+fn bytes_to_recipient(to: &[u8]) -> String {
+    match <[u8; 20]>::try_from(to) {
+        Ok(bytes) => format!("{:?}", Address::from(bytes)),
+        Err(_) => format!("0x{}", hex::encode(to)),
+    }
+}
+
+/// V2 `to` is a left-padded bytes32; EVM addresses fit in the low 20 bytes,
+/// anything else (e.g. a Solana account) is shown as the full word.
+fn bytes32_to_recipient(to: &B256) -> String {
+    if to[..12].iter().all(|b| *b == 0) {
+        format!("{:?}", Address::from_word(*to))
+    } else {
+        format!("{to:?}")
+    }
+}
+
+fn amount_row(label: &str, raw: U256, token: Option<(&'static str, u8)>) -> AnnotatedPayloadField {
+    let (amount, abbreviation) = match token {
+        Some((symbol, decimals)) => match format_units(raw, decimals) {
+            Ok(amount) => (amount, Some(symbol.to_string())),
+            Err(_) => (raw.to_string(), None),
+        },
+        None => (raw.to_string(), None),
+    };
+    let fallback_text = match &abbreviation {
+        Some(symbol) => format!("{amount} {symbol}"),
+        None => amount.clone(),
+    };
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AmountV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text,
+                label: label.to_string(),
+            },
+            amount_v2: SignablePayloadFieldAmountV2 {
+                amount,
+                abbreviation,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn recipient_row(recipient: &str) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: recipient.to_string(),
+                label: "Recipient".to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: recipient.to_string(),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: Some("Destination chain".to_string()),
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn address_row(label: &str, address: &Address) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{address:?}"),
+                label: label.to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{address:?}"),
+                name: "".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: None,
+            },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
+}
+
+/// ContractVisualizer implementation for Stargate routers and pools
+pub struct StargateContractVisualizer {
+    inner: StargateVisualizer,
+}
+
+impl StargateContractVisualizer {
+    pub fn new() -> Self {
+        Self {
+            inner: StargateVisualizer,
+        }
+    }
+}
+
+impl Default for StargateContractVisualizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::visualizer::ContractVisualizer for StargateContractVisualizer {
+    fn contract_type(&self) -> &str {
+        StargateContract::short_type_id()
+    }
+
+    fn visualize(
+        &self,
+        context: &crate::context::VisualizerContext,
+    ) -> Result<Option<Vec<AnnotatedPayloadField>>, visualsign::vsptrait::VisualSignError> {
+        Ok(self
+            .inner
+            .visualize_tx_commands(
+                &context.calldata,
+                context.chain_id,
+                Some(context.current_contract),
+            )
+            .map(|field| {
+                vec![AnnotatedPayloadField {
+                    signable_payload_field: field,
+                    static_annotation: None,
+                    dynamic_annotation: None,
+                }]
+            }))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn usdc_pool() -> Address {
+        "0xc026395860Db2d07ee33e05fE50ed7bD583189C7"
+            .parse()
+            .unwrap()
+    }
+
+    fn send_param(dst_eid: u32, to: B256) -> IOFT::SendParam {
+        IOFT::SendParam {
+            dstEid: dst_eid,
+            to,
+            amountLD: U256::from(1_000_000_000u64),
+            minAmountLD: U256::from(995_000_000u64),
+            extraOptions: Default::default(),
+            composeMsg: Default::default(),
+            oftCmd: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_v2_send_token_on_known_pool() {
+        let recipient = Address::repeat_byte(0x42);
+        let input = IOFT::sendTokenCall {
+            _sendParam: send_param(30110, recipient.into_word()),
+            _fee: IOFT::MessagingFee {
+                nativeFee: U256::from(1u64),
+                lzTokenFee: U256::ZERO,
+            },
+            _refundAddress: Address::repeat_byte(0x11),
+        }
+        .abi_encode();
+
+        let field = StargateVisualizer
+            .visualize_tx_commands(&input, 1, Some(usdc_pool()))
+            .unwrap();
+        assert_eq!(field.label(), "Stargate Bridge");
+        assert_eq!(
+            field.fallback_text(),
+            &format!(
+                "Bridge 1000.000000 USDC to Arbitrum One (LayerZero ID 30110), recipient {recipient:?}"
+            )
+        );
+        let json = serde_json::to_string(&field).unwrap();
+        assert!(json.contains("995.000000"), "got: {json}");
+    }
+
+    #[test]
+    fn test_oft_send_unknown_contract_and_non_evm_recipient() {
+        let to = B256::repeat_byte(0x99);
+        let input = IOFT::sendCall {
+            _sendParam: send_param(30168, to),
+            _fee: IOFT::MessagingFee {
+                nativeFee: U256::from(1u64),
+                lzTokenFee: U256::ZERO,
+            },
+            _refundAddress: Address::repeat_byte(0x11),
+        }
+        .abi_encode();
+
+        let field = StargateVisualizer
+            .visualize_tx_commands(&input, 1, Some(Address::repeat_byte(0x77)))
+            .unwrap();
+        let text = field.fallback_text();
+        assert!(text.contains("1000000000 (raw units)"), "got: {text}");
+        assert!(text.contains("Unknown (LayerZero ID 30168)"), "got: {text}");
+        assert!(text.contains(&format!("{to:?}")), "got: {text}");
+    }
+
+    #[test]
+    fn test_v1_router_swap() {
+        let recipient = Address::repeat_byte(0x42);
+        let input = IStargateRouter::swapCall {
+            _dstChainId: 184,
+            _srcPoolId: U256::from(1u64),
+            _dstPoolId: U256::from(1u64),
+            _refundAddress: Address::repeat_byte(0x11),
+            _amountLD: U256::from(2_500_000u64),
+            _minAmountLD: U256::from(2_490_000u64),
+            _lzTxParams: IStargateRouter::lzTxObj {
+                dstGasForCall: U256::ZERO,
+                dstNativeAmount: U256::ZERO,
+                dstNativeAddr: Default::default(),
+            },
+            _to: recipient.to_vec().into(),
+            _payload: Default::default(),
+        }
+        .abi_encode();
+
+        let field = StargateVisualizer
+            .visualize_tx_commands(&input, 1, None)
+            .unwrap();
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Bridge 2.500000 USDC to Base (LayerZero ID 184), recipient {recipient:?}")
+        );
+    }
+
+    #[test]
+    fn test_unrelated_selector_returns_none() {
+        assert!(
+            StargateVisualizer
+                .visualize_tx_commands(&[0xde, 0xad, 0xbe, 0xef], 1, None)
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Stargate contract visualizers
+
+pub mod bridge;
+
+pub use bridge::{StargateContractVisualizer, StargateVisualizer};
@@ -0,0 +1,61 @@
+//! Stargate / LayerZero bridge
+//!
+//! Stargate moves assets between chains over LayerZero. Bridge calls are decoded
+//! into the destination network, bridged token, amount, minimum amount received
+//! and destination address.
+
+pub mod config;
+pub mod contracts;
+
+use crate::registry::ContractRegistry;
+use crate::visualizer::EthereumVisualizerRegistryBuilder;
+
+pub use config::StargateConfig;
+pub use contracts::{StargateContractVisualizer, StargateVisualizer};
+
+/// Registers Stargate routers and pools and their visualizer
+///
+/// # Arguments
+/// * `contract_reg` - The contract registry to register addresses
+/// * `visualizer_reg` - The visualizer registry to register visualizers
+pub fn register(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    StargateConfig::register_contracts(contract_reg);
+
+    visualizer_reg.register(Box::new(StargateContractVisualizer::new()));
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::protocols::stargate::config::StargateContract;
+    use crate::registry::ContractType;
+    use alloy_primitives::Address;
+
+    #[test]
+    fn test_register_stargate() {
+        let mut contract_reg = ContractRegistry::new();
+        let mut visualizer_reg = EthereumVisualizerRegistryBuilder::new();
+
+        register(&mut contract_reg, &mut visualizer_reg);
+
+        for &chain_id in StargateConfig::chains() {
+            for info in StargateConfig::pools(chain_id) {
+                let address: Address = info.address.parse().unwrap();
+                assert_eq!(
+                    contract_reg.get_contract_type(chain_id, address),
+                    Some(StargateContract::short_type_id().to_string())
+                );
+            }
+        }
+        assert!(
+            visualizer_reg
+                .build()
+                .get(StargateContract::short_type_id())
+                .is_some()
+        );
+    }
+}
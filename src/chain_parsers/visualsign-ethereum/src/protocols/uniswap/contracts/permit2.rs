@@ -7,14 +7,16 @@
 
 #![allow(unused_imports)]
 
-use alloy_primitives::{Address, U160, U256};
+use alloy_primitives::{Address, B256, U160, U256};
 use alloy_sol_types::{SolCall, sol};
-use chrono::{TimeZone, Utc};
+use visualsign::field_builders::create_datetime_field;
+use visualsign::time_fmt::format_timestamp_secs;
 use visualsign::{
-    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    AnnotatedPayloadField, DateTimeRenderHint, SignablePayloadField, SignablePayloadFieldCommon,
     SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
 };
 
+use crate::contracts::core::approval_risk;
 use crate::registry::{ContractRegistry, ContractType};
 
 // Permit2 interface (simplified)
@@ -37,6 +39,66 @@ sol! {
         uint48 expiration;
         uint48 nonce;
     }
+
+    /// Batch overloads of the allowance calls, plus revocation
+    interface IAllowanceTransfer {
+        function permit(address owner, PermitBatch calldata permitBatch, bytes calldata signature) external;
+        function transferFrom(AllowanceTransferDetails[] calldata transferDetails) external;
+        function lockdown(TokenSpenderPair[] calldata approvals) external;
+        function invalidateNonces(address token, address spender, uint48 newNonce) external;
+    }
+
+    interface ISignatureTransfer {
+        function permitTransferFrom(PermitTransferFrom memory permit, SignatureTransferDetails calldata transferDetails, address owner, bytes calldata signature) external;
+        function permitWitnessTransferFrom(PermitTransferFrom memory permit, SignatureTransferDetails calldata transferDetails, address owner, bytes32 witness, string calldata witnessTypeString, bytes calldata signature) external;
+        function invalidateUnorderedNonces(uint256 wordPos, uint256 mask) external;
+    }
+
+    /// Batch overloads of the signature transfer calls
+    interface ISignatureTransferBatch {
+        function permitTransferFrom(PermitBatchTransferFrom memory permit, SignatureTransferDetails[] calldata transferDetails, address owner, bytes calldata signature) external;
+        function permitWitnessTransferFrom(PermitBatchTransferFrom memory permit, SignatureTransferDetails[] calldata transferDetails, address owner, bytes32 witness, string calldata witnessTypeString, bytes calldata signature) external;
+    }
+
+    struct PermitBatch {
+        PermitDetails[] details;
+        address spender;
+        uint256 sigDeadline;
+    }
+
+    struct AllowanceTransferDetails {
+        address from;
+        address to;
+        uint160 amount;
+        address token;
+    }
+
+    struct TokenSpenderPair {
+        address token;
+        address spender;
+    }
+
+    struct TokenPermissions {
+        address token;
+        uint256 amount;
+    }
+
+    struct PermitTransferFrom {
+        TokenPermissions permitted;
+        uint256 nonce;
+        uint256 deadline;
+    }
+
+    struct PermitBatchTransferFrom {
+        TokenPermissions[] permitted;
+        uint256 nonce;
+        uint256 deadline;
+    }
+
+    struct SignatureTransferDetails {
+        address to;
+        uint256 requestedAmount;
+    }
 }
 
 /// Formats a Unix timestamp (seconds since epoch) for display.
@@ -51,23 +113,18 @@ sol! {
 /// timestamp passed to consumers is unchanged):
 /// - `u64::MAX` is treated as a "never" sentinel.
 /// - Values inside chrono's representable range render as
-///   `"YYYY-MM-DD HH:MM UTC"`.
+///   `"YYYY-MM-DD HH:MM:SS UTC"` via [`format_timestamp_secs`].
 /// - Values above chrono's max year (year 9999), which `uint48` can reach
 ///   (max ~year 8,925,512), fall back to `"unix:<value>"`.
-fn format_unix_timestamp_seconds_u64(value: u64) -> String {
+pub(crate) fn format_unix_timestamp_seconds_u64(value: u64) -> String {
     if value == u64::MAX {
         return "never".to_string();
     }
 
-    let signed = match i64::try_from(value) {
-        Ok(v) => v,
-        Err(_) => return format!("unix:{value}"),
-    };
-
-    match Utc.timestamp_opt(signed, 0).single() {
-        Some(dt) => dt.format("%Y-%m-%d %H:%M UTC").to_string(),
-        None => format!("unix:{value}"),
-    }
+    i64::try_from(value)
+        .ok()
+        .and_then(format_timestamp_secs)
+        .unwrap_or_else(|| format!("unix:{value}"))
 }
 
 /// Visualizer for Permit2 contract calls
@@ -107,6 +164,69 @@ impl Permit2Visualizer {
             return Some(Self::decode_permit(call, chain_id, registry));
         }
 
+        // The remaining standard ABI calls have to be tried before the custom
+        // permit layout below, which accepts almost any 192 bytes
+        if let Ok(call) = IAllowanceTransfer::permitCall::abi_decode(input) {
+            return Some(Self::decode_permit_batch(call, chain_id, registry));
+        }
+        if let Ok(call) = IAllowanceTransfer::transferFromCall::abi_decode(input) {
+            return Some(Self::decode_transfer_from_batch(call, chain_id, registry));
+        }
+        if let Ok(call) = IAllowanceTransfer::lockdownCall::abi_decode(input) {
+            return Some(Self::decode_lockdown(call, chain_id, registry));
+        }
+        if let Ok(call) = IAllowanceTransfer::invalidateNoncesCall::abi_decode(input) {
+            return Some(Self::decode_invalidate_nonces(call, chain_id, registry));
+        }
+        if let Ok(call) = ISignatureTransfer::invalidateUnorderedNoncesCall::abi_decode(input) {
+            return Some(Self::decode_invalidate_unordered_nonces(call));
+        }
+        if let Ok(call) = ISignatureTransfer::permitTransferFromCall::abi_decode(input) {
+            let transfer = SignatureTransfer {
+                owner: call.owner,
+                permitted: vec![call.permit.permitted],
+                transfers: vec![call.transferDetails],
+                nonce: call.permit.nonce,
+                deadline: call.permit.deadline,
+                witness: None,
+            };
+            return Some(transfer.visualize(chain_id, registry));
+        }
+        if let Ok(call) = ISignatureTransfer::permitWitnessTransferFromCall::abi_decode(input) {
+            let transfer = SignatureTransfer {
+                owner: call.owner,
+                permitted: vec![call.permit.permitted],
+                transfers: vec![call.transferDetails],
+                nonce: call.permit.nonce,
+                deadline: call.permit.deadline,
+                witness: Some((call.witness, call.witnessTypeString)),
+            };
+            return Some(transfer.visualize(chain_id, registry));
+        }
+        if let Ok(call) = ISignatureTransferBatch::permitTransferFromCall::abi_decode(input) {
+            let transfer = SignatureTransfer {
+                owner: call.owner,
+                permitted: call.permit.permitted,
+                transfers: call.transferDetails,
+                nonce: call.permit.nonce,
+                deadline: call.permit.deadline,
+                witness: None,
+            };
+            return Some(transfer.visualize(chain_id, registry));
+        }
+        if let Ok(call) = ISignatureTransferBatch::permitWitnessTransferFromCall::abi_decode(input)
+        {
+            let transfer = SignatureTransfer {
+                owner: call.owner,
+                permitted: call.permit.permitted,
+                transfers: call.transferDetails,
+                nonce: call.permit.nonce,
+                deadline: call.permit.deadline,
+                witness: Some((call.witness, call.witnessTypeString)),
+            };
+            return Some(transfer.visualize(chain_id, registry));
+        }
+
         // Try custom permit encoding (used by Universal Router)
         if let Ok(params) = Self::decode_custom_permit_params(input) {
             let call = IPermit2::permitCall {
@@ -168,12 +288,32 @@ impl Permit2Visualizer {
             call.spender, amount_str, token_symbol, token_symbol, expiration_str
         );
 
-        SignablePayloadField::TextV2 {
+        let mut field = SignablePayloadField::TextV2 {
             common: SignablePayloadFieldCommon {
                 fallback_text: text.clone(),
                 label: "Permit2 Approve".to_string(),
             },
             text_v2: SignablePayloadFieldTextV2 { text },
+        };
+        Self::add_approval_warning(&mut field, call.spender, call.amount, chain_id, registry);
+        field
+    }
+
+    /// Flags an unlimited or unknown-spender allowance; zero amounts revoke
+    /// and are left alone
+    fn add_approval_warning(
+        field: &mut SignablePayloadField,
+        spender: Address,
+        amount: U160,
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) {
+        if amount.is_zero() {
+            return;
+        }
+        let known_spender = registry.map(|r| approval_risk::is_known_spender(r, chain_id, spender));
+        if let Some(warning) = approval_risk::approval_warning(amount == U160::MAX, known_spender) {
+            approval_risk::attach_warning(field, warning);
         }
     }
 
@@ -214,16 +354,6 @@ impl Permit2Visualizer {
             .to_string()
             .parse()
             .unwrap_or(0);
-        let expiration_str = format_unix_timestamp_seconds_u64(expiration_u64);
-
-        // Format sig deadline timestamp. `sigDeadline` is `uint256`; use a
-        // checked narrowing so a value above `u64::MAX` renders as
-        // `unix:<original>` rather than silently collapsing to 0 (which
-        // would display as the Unix epoch).
-        let sig_deadline_str = match u64::try_from(call.permitSingle.sigDeadline) {
-            Ok(v) => format_unix_timestamp_seconds_u64(v),
-            Err(_) => format!("unix:{}", call.permitSingle.sigDeadline),
-        };
 
         // Determine if amount is "unlimited" (max u160)
         let amount_display = if call.permitSingle.details.amount == U160::MAX {
@@ -281,35 +411,11 @@ impl Permit2Visualizer {
                 static_annotation: None,
                 dynamic_annotation: None,
             },
-            AnnotatedPayloadField {
-                signable_payload_field: SignablePayloadField::TextV2 {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: expiration_str.clone(),
-                        label: "Expires".to_string(),
-                    },
-                    text_v2: SignablePayloadFieldTextV2 {
-                        text: expiration_str,
-                    },
-                },
-                static_annotation: None,
-                dynamic_annotation: None,
-            },
-            AnnotatedPayloadField {
-                signable_payload_field: SignablePayloadField::TextV2 {
-                    common: SignablePayloadFieldCommon {
-                        fallback_text: sig_deadline_str.clone(),
-                        label: "Sig Deadline".to_string(),
-                    },
-                    text_v2: SignablePayloadFieldTextV2 {
-                        text: sig_deadline_str,
-                    },
-                },
-                static_annotation: None,
-                dynamic_annotation: None,
-            },
+            timestamp_row("Expires", expiration_u64),
+            deadline_row("Sig Deadline", call.permitSingle.sigDeadline),
         ];
 
-        SignablePayloadField::PreviewLayout {
+        let mut field = SignablePayloadField::PreviewLayout {
             common: SignablePayloadFieldCommon {
                 fallback_text: subtitle_text.clone(),
                 label: title_text.clone(),
@@ -324,7 +430,15 @@ impl Permit2Visualizer {
                     fields: expanded_fields,
                 }),
             },
-        }
+        };
+        Self::add_approval_warning(
+            &mut field,
+            call.permitSingle.spender,
+            call.permitSingle.details.amount,
+            chain_id,
+            registry,
+        );
+        field
     }
 
     /// Decodes transferFrom function call
@@ -357,6 +471,305 @@ impl Permit2Visualizer {
             text_v2: SignablePayloadFieldTextV2 { text },
         }
     }
+
+    /// Decodes the batch permit overload, one row per token allowance
+    fn decode_permit_batch(
+        call: IAllowanceTransfer::permitCall,
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) -> SignablePayloadField {
+        let batch = call.permitBatch;
+        let mut symbols = Vec::new();
+        let mut rows = vec![text_row("Spender", batch.spender.to_string())];
+        for (index, details) in batch.details.iter().enumerate() {
+            let (amount, symbol) = token_amount(
+                details.token,
+                U256::from(details.amount),
+                chain_id,
+                registry,
+            );
+            let amount = if details.amount == U160::MAX {
+                "Unlimited".to_string()
+            } else {
+                amount
+            };
+            rows.push(text_row(
+                &format!("Token {}", index + 1),
+                format!(
+                    "{amount} {symbol}, expires {}, nonce {}",
+                    format_unix_timestamp_seconds_u64(details.expiration.to()),
+                    details.nonce
+                ),
+            ));
+            symbols.push(symbol);
+        }
+        rows.push(deadline_row("Sig Deadline", batch.sigDeadline));
+
+        let subtitle = format!("Permit {} to spend {}", batch.spender, symbols.join(", "));
+        let mut field = preview("Permit2 Permit Batch", subtitle, rows);
+        // The largest allowance decides the warning: unlimited if any is
+        // unlimited, none if every entry is a zero-amount revoke
+        if let Some(amount) = batch.details.iter().map(|d| d.amount).max() {
+            Self::add_approval_warning(&mut field, batch.spender, amount, chain_id, registry);
+        }
+        field
+    }
+
+    /// Decodes the batch transferFrom overload, one row per transfer
+    fn decode_transfer_from_batch(
+        call: IAllowanceTransfer::transferFromCall,
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) -> SignablePayloadField {
+        let mut amounts = Vec::new();
+        let mut rows = Vec::new();
+        for (index, transfer) in call.transferDetails.iter().enumerate() {
+            let (amount, symbol) = token_amount(
+                transfer.token,
+                U256::from(transfer.amount),
+                chain_id,
+                registry,
+            );
+            rows.push(text_row(
+                &format!("Transfer {}", index + 1),
+                format!(
+                    "{amount} {symbol} from {} to {}",
+                    transfer.from, transfer.to
+                ),
+            ));
+            amounts.push(format!("{amount} {symbol}"));
+        }
+
+        let subtitle = format!("Transfer {}", amounts.join(", "));
+        preview("Permit2 Transfer Batch", subtitle, rows)
+    }
+
+    /// Decodes lockdown, which zeroes the listed token/spender allowances
+    fn decode_lockdown(
+        call: IAllowanceTransfer::lockdownCall,
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) -> SignablePayloadField {
+        let rows = call
+            .approvals
+            .iter()
+            .enumerate()
+            .map(|(index, pair)| {
+                let (_, symbol) = token_amount(pair.token, U256::ZERO, chain_id, registry);
+                text_row(
+                    &format!("Revoke {}", index + 1),
+                    format!("{symbol} allowance of {}", pair.spender),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let subtitle = match rows.len() {
+            1 => "Revoke 1 Permit2 allowance".to_string(),
+            count => format!("Revoke {count} Permit2 allowances"),
+        };
+        preview("Permit2 Lockdown", subtitle, rows)
+    }
+
+    /// Decodes invalidateNonces, which cancels every outstanding permit
+    /// signature for the pair below the new nonce
+    fn decode_invalidate_nonces(
+        call: IAllowanceTransfer::invalidateNoncesCall,
+        chain_id: u64,
+        registry: Option<&ContractRegistry>,
+    ) -> SignablePayloadField {
+        let (_, symbol) = token_amount(call.token, U256::ZERO, chain_id, registry);
+        let text = format!(
+            "Invalidate {symbol} permit signatures for {} with nonce below {}",
+            call.spender, call.newNonce
+        );
+
+        SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: "Permit2 Invalidate Nonces".to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        }
+    }
+
+    /// Decodes invalidateUnorderedNonces. Signature transfer nonces are bits
+    /// in a bitmap, so the nonces cancelled are `wordPos * 256 + bit` for each
+    /// bit set in `mask`.
+    fn decode_invalidate_unordered_nonces(
+        call: ISignatureTransfer::invalidateUnorderedNoncesCall,
+    ) -> SignablePayloadField {
+        let bits = (0..256usize)
+            .filter(|bit| call.mask.bit(*bit))
+            .collect::<Vec<_>>();
+        let nonces = bits
+            .iter()
+            .map(|bit| {
+                call.wordPos
+                    .checked_mul(U256::from(256u16))
+                    .and_then(|base| base.checked_add(U256::from(*bit)))
+            })
+            .collect::<Option<Vec<_>>>();
+
+        let text = match nonces {
+            _ if bits.is_empty() => format!("Invalidate no nonces in word {}", call.wordPos),
+            Some(nonces) if nonces.len() <= MAX_LISTED_NONCES => format!(
+                "Invalidate signature transfer nonces {}",
+                nonces
+                    .iter()
+                    .map(U256::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            _ => format!(
+                "Invalidate {} signature transfer nonces in word {}",
+                bits.len(),
+                call.wordPos
+            ),
+        };
+
+        SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: "Permit2 Invalidate Nonces".to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        }
+    }
+}
+
+/// Nonces listed individually by `invalidateUnorderedNonces` before the text
+/// switches to a count
+const MAX_LISTED_NONCES: usize = 8;
+
+/// A signature transfer, single or batch, with or without a witness
+struct SignatureTransfer {
+    owner: Address,
+    permitted: Vec<TokenPermissions>,
+    transfers: Vec<SignatureTransferDetails>,
+    nonce: U256,
+    deadline: U256,
+    witness: Option<(B256, String)>,
+}
+
+impl SignatureTransfer {
+    fn visualize(self, chain_id: u64, registry: Option<&ContractRegistry>) -> SignablePayloadField {
+        let single = self.transfers.len() == 1;
+        let mut amounts = Vec::new();
+        let mut rows = vec![text_row("Owner", self.owner.to_string())];
+        for (index, (permitted, transfer)) in self.permitted.iter().zip(&self.transfers).enumerate()
+        {
+            let (requested, symbol) = token_amount(
+                permitted.token,
+                transfer.requestedAmount,
+                chain_id,
+                registry,
+            );
+            let (signed, _) = token_amount(permitted.token, permitted.amount, chain_id, registry);
+            let signed = if permitted.amount == U256::MAX {
+                "an unlimited amount".to_string()
+            } else {
+                format!("{signed} {symbol}")
+            };
+            let label = if single {
+                "Transfer".to_string()
+            } else {
+                format!("Transfer {}", index + 1)
+            };
+            rows.push(text_row(
+                &label,
+                format!(
+                    "{requested} {symbol} to {} (signed for up to {signed})",
+                    transfer.to
+                ),
+            ));
+            amounts.push(format!("{requested} {symbol} to {}", transfer.to));
+        }
+        rows.push(text_row("Nonce", self.nonce.to_string()));
+        rows.push(deadline_row("Deadline", self.deadline));
+
+        let title = if let Some((witness, witness_type)) = self.witness {
+            rows.push(text_row("Witness", witness.to_string()));
+            rows.push(text_row("Witness Type", witness_type));
+            "Permit2 Witness Transfer"
+        } else {
+            "Permit2 Signature Transfer"
+        };
+
+        let subtitle = format!("Transfer {} from {}", amounts.join(", "), self.owner);
+        preview(title, subtitle, rows)
+    }
+}
+
+/// Formats `amount` of `token` with its registered decimals, returning the
+/// amount and symbol. Unknown tokens fall back to the raw amount and address.
+fn token_amount(
+    token: Address,
+    amount: U256,
+    chain_id: u64,
+    registry: Option<&ContractRegistry>,
+) -> (String, String) {
+    let symbol = registry
+        .and_then(|r| r.get_token_symbol(chain_id, token))
+        .unwrap_or_else(|| format!("{token:?}"));
+    let amount = registry
+        .and_then(|r| r.format_token_amount_u256(chain_id, token, amount))
+        .map(|(amount, _)| amount)
+        .unwrap_or_else(|| amount.to_string());
+    (amount, symbol)
+}
+
+/// Formats a `uint256` deadline, rendering values above `u64::MAX` raw
+/// Row for a Unix-seconds deadline or expiration: a `DateTimeV2` field for real
+/// timestamps, text for `never` and values past the calendar
+pub(crate) fn timestamp_row(label: &str, seconds: u64) -> AnnotatedPayloadField {
+    i64::try_from(seconds)
+        .ok()
+        .and_then(|signed| {
+            create_datetime_field(label, signed, DateTimeRenderHint::DateTime, Some("UTC")).ok()
+        })
+        .unwrap_or_else(|| text_row(label, format_unix_timestamp_seconds_u64(seconds)))
+}
+
+fn deadline_row(label: &str, deadline: U256) -> AnnotatedPayloadField {
+    match u64::try_from(deadline) {
+        Ok(seconds) => timestamp_row(label, seconds),
+        Err(_) => text_row(label, format!("unix:{deadline}")),
+    }
+}
+
+fn preview(
+    title: &str,
+    subtitle: String,
+    rows: Vec<AnnotatedPayloadField>,
+) -> SignablePayloadField {
+    SignablePayloadField::PreviewLayout {
+        common: SignablePayloadFieldCommon {
+            fallback_text: subtitle.clone(),
+            label: title.to_string(),
+        },
+        preview_layout: SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title.to_string(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 { text: subtitle }),
+            condensed: None,
+            expanded: Some(SignablePayloadFieldListLayout { fields: rows }),
+        },
+    }
+}
+
+fn text_row(label: &str, text: String) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        signable_payload_field: SignablePayloadField::TextV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: text.clone(),
+                label: label.to_string(),
+            },
+            text_v2: SignablePayloadFieldTextV2 { text },
+        },
+        static_annotation: None,
+        dynamic_annotation: None,
+    }
 }
 
 /// CalldataVisualizer implementation for Permit2
@@ -457,13 +870,16 @@ mod tests {
         // 2024-01-01T00:00:00 UTC = 1704067200.
         assert_eq!(
             format_unix_timestamp_seconds_u64(1_704_067_200),
-            "2024-01-01 00:00 UTC"
+            "2024-01-01 00:00:00 UTC"
         );
     }
 
     #[test]
     fn test_format_unix_timestamp_seconds_u64_epoch() {
-        assert_eq!(format_unix_timestamp_seconds_u64(0), "1970-01-01 00:00 UTC");
+        assert_eq!(
+            format_unix_timestamp_seconds_u64(0),
+            "1970-01-01 00:00:00 UTC"
+        );
     }
 
     #[test]
@@ -749,4 +1165,307 @@ mod tests {
             other => panic!("expected TextV2, got {other:?}"),
         }
     }
+
+    #[test]
+    fn test_visualize_approve_to_unknown_spender_warns() {
+        let (registry, _) = crate::registry::ContractRegistry::with_default_protocols();
+        let call = IPermit2::approveCall {
+            token: weth_mainnet_address(),
+            spender: [0x22u8; 20].into(),
+            amount: U160::MAX,
+            expiration: U48::from(1_704_067_200u64),
+        };
+        let input = IPermit2::approveCall::abi_encode(&call);
+
+        let field = Permit2Visualizer
+            .visualize_tx_commands(&input, 1, Some(&registry))
+            .expect("approve should decode");
+        match field {
+            SignablePayloadField::TextV2 { text_v2, .. } => assert!(
+                text_v2
+                    .text
+                    .contains("WARNING: Unlimited approval to unknown contract"),
+                "got: {}",
+                text_v2.text
+            ),
+            other => panic!("expected TextV2, got {other:?}"),
+        }
+    }
+
+    const USDC: Address = alloy_primitives::address!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48");
+
+    fn visualize(input: &[u8]) -> SignablePayloadField {
+        let (registry, _) = crate::registry::ContractRegistry::with_default_protocols();
+        Permit2Visualizer
+            .visualize_tx_commands(input, 1, Some(&registry))
+            .expect("call should decode")
+    }
+
+    fn expanded_texts(field: &SignablePayloadField) -> Vec<(String, String)> {
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = field else {
+            panic!("expected PreviewLayout, got {field:?}");
+        };
+        preview_layout
+            .expanded
+            .as_ref()
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_visualize_permit_batch_lists_each_token() {
+        let spender = Address::repeat_byte(0x22);
+        let call = IAllowanceTransfer::permitCall {
+            owner: Address::repeat_byte(0x55),
+            permitBatch: PermitBatch {
+                details: vec![
+                    PermitDetails {
+                        token: USDC,
+                        amount: U160::from(2_500_000u64),
+                        expiration: U48::from(1_704_067_200u64),
+                        nonce: U48::from(3u64),
+                    },
+                    PermitDetails {
+                        token: weth_mainnet_address(),
+                        amount: U160::MAX,
+                        expiration: U48::from(1_704_067_200u64),
+                        nonce: U48::from(0u64),
+                    },
+                ],
+                spender,
+                sigDeadline: U256::from(1_704_067_200u64),
+            },
+            signature: alloy_primitives::Bytes::default(),
+        };
+
+        let field = visualize(&call.abi_encode());
+        assert_eq!(field.label(), "Permit2 Permit Batch");
+        assert!(
+            field
+                .fallback_text()
+                .starts_with(&format!("Permit {spender} to spend USDC, WETH. WARNING: ")),
+            "got: {}",
+            field.fallback_text()
+        );
+        let rows = expanded_texts(&field);
+        assert_eq!(
+            rows[1],
+            (
+                "Token 1".to_string(),
+                "2.500000 USDC, expires 2024-01-01 00:00:00 UTC, nonce 3".to_string()
+            )
+        );
+        assert_eq!(
+            rows[2].1,
+            "Unlimited WETH, expires 2024-01-01 00:00:00 UTC, nonce 0"
+        );
+        assert_eq!(rows[3].0, "Sig Deadline");
+        assert_eq!(rows.last().unwrap().0, "Warning");
+    }
+
+    #[test]
+    fn test_visualize_transfer_from_batch() {
+        let from = Address::repeat_byte(0x11);
+        let to = Address::repeat_byte(0x22);
+        let call = IAllowanceTransfer::transferFromCall {
+            transferDetails: vec![AllowanceTransferDetails {
+                from,
+                to,
+                amount: U160::from(1_000_000u64),
+                token: USDC,
+            }],
+        };
+
+        let field = visualize(&call.abi_encode());
+        assert_eq!(field.label(), "Permit2 Transfer Batch");
+        assert_eq!(field.fallback_text(), "Transfer 1.000000 USDC");
+        assert_eq!(
+            expanded_texts(&field)[0].1,
+            format!("1.000000 USDC from {from} to {to}")
+        );
+    }
+
+    #[test]
+    fn test_visualize_lockdown_and_invalidate_nonces() {
+        let spender = Address::repeat_byte(0x22);
+        let lockdown = IAllowanceTransfer::lockdownCall {
+            approvals: vec![
+                TokenSpenderPair {
+                    token: USDC,
+                    spender,
+                },
+                TokenSpenderPair {
+                    token: weth_mainnet_address(),
+                    spender,
+                },
+            ],
+        };
+        let field = visualize(&lockdown.abi_encode());
+        assert_eq!(field.label(), "Permit2 Lockdown");
+        assert_eq!(field.fallback_text(), "Revoke 2 Permit2 allowances");
+        assert_eq!(
+            expanded_texts(&field)[1],
+            (
+                "Revoke 2".to_string(),
+                format!("WETH allowance of {spender}")
+            )
+        );
+
+        let invalidate = IAllowanceTransfer::invalidateNoncesCall {
+            token: USDC,
+            spender,
+            newNonce: U48::from(7u64),
+        };
+        assert_eq!(
+            visualize(&invalidate.abi_encode()).fallback_text(),
+            &format!("Invalidate USDC permit signatures for {spender} with nonce below 7")
+        );
+    }
+
+    #[test]
+    fn test_visualize_invalidate_unordered_nonces() {
+        let call = ISignatureTransfer::invalidateUnorderedNoncesCall {
+            wordPos: U256::from(2u64),
+            mask: U256::from(0b101u64),
+        };
+        assert_eq!(
+            visualize(&call.abi_encode()).fallback_text(),
+            "Invalidate signature transfer nonces 512, 514"
+        );
+
+        let call = ISignatureTransfer::invalidateUnorderedNoncesCall {
+            wordPos: U256::from(2u64),
+            mask: U256::MAX,
+        };
+        assert_eq!(
+            visualize(&call.abi_encode()).fallback_text(),
+            "Invalidate 256 signature transfer nonces in word 2"
+        );
+    }
+
+    #[test]
+    fn test_visualize_permit_transfer_from() {
+        let owner = Address::repeat_byte(0x55);
+        let to = Address::repeat_byte(0x66);
+        let call = ISignatureTransfer::permitTransferFromCall {
+            permit: PermitTransferFrom {
+                permitted: TokenPermissions {
+                    token: USDC,
+                    amount: U256::from(5_000_000u64),
+                },
+                nonce: U256::from(42u64),
+                deadline: U256::from(1_704_067_200u64),
+            },
+            transferDetails: SignatureTransferDetails {
+                to,
+                requestedAmount: U256::from(1_500_000u64),
+            },
+            owner,
+            signature: alloy_primitives::Bytes::default(),
+        };
+
+        let field = visualize(&call.abi_encode());
+        assert_eq!(field.label(), "Permit2 Signature Transfer");
+        assert_eq!(
+            field.fallback_text(),
+            &format!("Transfer 1.500000 USDC to {to} from {owner}")
+        );
+        let rows = expanded_texts(&field);
+        assert_eq!(
+            rows[1],
+            (
+                "Transfer".to_string(),
+                format!("1.500000 USDC to {to} (signed for up to 5.000000 USDC)")
+            )
+        );
+        assert_eq!(rows[2], ("Nonce".to_string(), "42".to_string()));
+        assert_eq!(
+            rows[3],
+            (
+                "Deadline".to_string(),
+                "2024-01-01 00:00:00 UTC".to_string()
+            )
+        );
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } = &field else {
+            panic!("expected preview layout");
+        };
+        let deadline = &preview_layout.expanded.as_ref().unwrap().fields[3];
+        assert!(matches!(
+            &deadline.signable_payload_field,
+            SignablePayloadField::DateTimeV2 { date_time_v2, .. }
+                if date_time_v2.timestamp == 1_704_067_200
+        ));
+    }
+
+    #[test]
+    fn test_visualize_batch_permit_witness_transfer_from() {
+        let to = Address::repeat_byte(0x66);
+        let witness = B256::repeat_byte(0xab);
+        let call = ISignatureTransferBatch::permitWitnessTransferFromCall {
+            permit: PermitBatchTransferFrom {
+                permitted: vec![
+                    TokenPermissions {
+                        token: USDC,
+                        amount: U256::MAX,
+                    },
+                    TokenPermissions {
+                        token: weth_mainnet_address(),
+                        amount: U256::from(10u64).pow(U256::from(18u64)),
+                    },
+                ],
+                nonce: U256::from(1u64),
+                deadline: U256::from(1_704_067_200u64),
+            },
+            transferDetails: vec![
+                SignatureTransferDetails {
+                    to,
+                    requestedAmount: U256::from(2_000_000u64),
+                },
+                SignatureTransferDetails {
+                    to,
+                    requestedAmount: U256::from(10u64).pow(U256::from(17u64)),
+                },
+            ],
+            owner: Address::repeat_byte(0x55),
+            witness,
+            witnessTypeString: "ExampleTrade witness)ExampleTrade(uint256 id)".to_string(),
+            signature: alloy_primitives::Bytes::default(),
+        };
+
+        let field = visualize(&call.abi_encode());
+        assert_eq!(field.label(), "Permit2 Witness Transfer");
+        let rows = expanded_texts(&field);
+        let labels: Vec<&str> = rows.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec![
+                "Owner",
+                "Transfer 1",
+                "Transfer 2",
+                "Nonce",
+                "Deadline",
+                "Witness",
+                "Witness Type"
+            ]
+        );
+        assert_eq!(
+            rows[1].1,
+            format!("2.000000 USDC to {to} (signed for up to an unlimited amount)")
+        );
+        assert_eq!(
+            rows[2].1,
+            format!(
+                "0.100000000000000000 WETH to {to} (signed for up to 1.000000000000000000 WETH)"
+            )
+        );
+        assert_eq!(rows[5].1, witness.to_string());
+    }
 }
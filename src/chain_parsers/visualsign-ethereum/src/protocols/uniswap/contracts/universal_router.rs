@@ -1,8 +1,12 @@
 use alloy_primitives::{Address, Bytes, U256};
 use alloy_sol_types::{SolCall as _, SolType, SolValue, sol};
-use chrono::{TimeZone, Utc};
 use num_enum::TryFromPrimitive;
-use visualsign::{SignablePayloadField, SignablePayloadFieldCommon, SignablePayloadFieldTextV2};
+use visualsign::field_builders::create_datetime_field;
+use visualsign::time_fmt::format_timestamp_secs;
+use visualsign::{
+    DateTimeRenderHint, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldPercentage, SignablePayloadFieldTextV2, basis_points_to_percent,
+};
 
 use crate::protocols::uniswap::contracts::permit2::Permit2Visualizer;
 use crate::registry::{ContractRegistry, ContractType};
@@ -224,13 +228,7 @@ impl UniversalRouterVisualizer {
                 Ok(val) => val,
                 Err(_) => return None,
             };
-            let deadline = if deadline_val > 0 {
-                Utc.timestamp_opt(deadline_val, 0)
-                    .single()
-                    .map(|dt| dt.to_string())
-            } else {
-                None
-            };
+            let deadline = (deadline_val > 0).then_some(deadline_val);
             return Self::visualize_commands(
                 &call.commands.0,
                 &call.inputs,
@@ -258,10 +256,11 @@ impl UniversalRouterVisualizer {
     fn visualize_commands(
         commands: &[u8],
         inputs: &[alloy_primitives::Bytes],
-        deadline: Option<String>,
+        deadline: Option<i64>,
         chain_id: u64,
         registry: Option<&ContractRegistry>,
     ) -> Option<SignablePayloadField> {
+        let deadline_text = deadline.and_then(format_timestamp_secs);
         let mapped = map_commands(commands);
         // Display-only view that preserves the historical fallback-text format
         // (a flat `Vec<Command>`). The pairing with `inputs` below uses the
@@ -361,19 +360,21 @@ impl UniversalRouterVisualizer {
         }
 
         // Deadline field (optional)
-        if let Some(dl) = &deadline {
-            detail_fields.push(SignablePayloadField::TextV2 {
-                common: SignablePayloadFieldCommon {
-                    fallback_text: dl.clone(),
-                    label: "Deadline".to_string(),
-                },
-                text_v2: SignablePayloadFieldTextV2 { text: dl.clone() },
-            });
+        if let Some(field) = deadline.and_then(|seconds| {
+            create_datetime_field(
+                "Deadline",
+                seconds,
+                DateTimeRenderHint::DateTime,
+                Some("UTC"),
+            )
+            .ok()
+        }) {
+            detail_fields.push(field.signable_payload_field);
         }
 
         Some(SignablePayloadField::PreviewLayout {
             common: SignablePayloadFieldCommon {
-                fallback_text: if let Some(dl) = &deadline {
+                fallback_text: if let Some(dl) = &deadline_text {
                     format!(
                         "Uniswap Universal Router Execute: {} commands ({:?}), deadline {}",
                         mapped_cmds_only.len(),
@@ -393,7 +394,7 @@ impl UniversalRouterVisualizer {
                 title: Some(visualsign::SignablePayloadFieldTextV2 {
                     text: "Uniswap Universal Router Execute".to_string(),
                 }),
-                subtitle: if let Some(dl) = &deadline {
+                subtitle: if let Some(dl) = &deadline_text {
                     Some(visualsign::SignablePayloadFieldTextV2 {
                         text: format!("{} commands, deadline {}", mapped_cmds_only.len(), dl),
                     })
@@ -417,6 +418,19 @@ impl UniversalRouterVisualizer {
         })
     }
 
+    /// V3 pool fees are in hundredths of a basis point, so the 0.3% tier is 3000 and
+    /// the 0.01% tier is 100.
+    fn fee_to_basis_points(fee: u32) -> String {
+        match fee % 100 {
+            0 => (fee / 100).to_string(),
+            hundredths => format!(
+                "{}.{}",
+                fee / 100,
+                format!("{hundredths:02}").trim_end_matches('0')
+            ),
+        }
+    }
+
     /// Decodes a Uniswap V3 swap path into raw byte positions.
     ///
     /// Paths are encoded as: token(20) | fee(3) | token(20) | fee(3) | ... | token(20).
@@ -529,7 +543,8 @@ impl UniversalRouterVisualizer {
 
         // Calculate first-hop fee percentage. For multi-hop paths each hop may use a
         // different fee tier; we surface the first one and disclose the hop count.
-        let fee_pct = fee as f64 / 10000.0;
+        let fee_bps = Self::fee_to_basis_points(fee);
+        let fee_pct = basis_points_to_percent(&fee_bps);
         let text = if hops == 1 {
             format!(
                 "Swap {amount_in_str} {token_in_symbol} for >={amount_out_min_str} {token_out_symbol} via V3 ({fee_pct}% fee)"
@@ -595,13 +610,13 @@ impl UniversalRouterVisualizer {
                 dynamic_annotation: None,
             },
             visualsign::AnnotatedPayloadField {
-                signable_payload_field: SignablePayloadField::TextV2 {
+                signable_payload_field: SignablePayloadField::Percentage {
                     common: SignablePayloadFieldCommon {
                         fallback_text: format!("{fee_pct}%"),
                         label: "Fee Tier".to_string(),
                     },
-                    text_v2: SignablePayloadFieldTextV2 {
-                        text: format!("{fee_pct}%"),
+                    percentage: SignablePayloadFieldPercentage {
+                        basis_points: fee_bps,
                     },
                 },
                 static_annotation: None,
@@ -650,21 +665,11 @@ impl UniversalRouterVisualizer {
             .and_then(|r| r.get_token_symbol(chain_id, params.token))
             .unwrap_or_else(|| format!("{:?}", params.token));
 
-        // Convert bips to percentage (10000 bips = 100%).
-        // Bips realistically fits in u128 (Uniswap caps at 10_000), but the on-chain
-        // type is uint256, so we must surface oversized values rather than silently
-        // displaying "0%" for a transfer that pays out the entire balance.
-        let percentage_str = match u128::try_from(params.bips) {
-            Ok(bips_value) => {
-                let bips_pct = (bips_value as f64) / 100.0;
-                if bips_pct >= 1.0 {
-                    format!("{bips_pct:.2}%")
-                } else {
-                    format!("{bips_pct:.4}%")
-                }
-            }
-            Err(_) => format!("{} bips (raw)", params.bips),
-        };
+        // Convert bips to percentage (10000 bips = 100%). Uniswap caps bips at
+        // 10_000, but the on-chain type is uint256; the conversion works on the decimal
+        // string, so an oversized value shows in full rather than truncated to "0%".
+        let bips = params.bips.to_string();
+        let percentage_str = format!("{}%", basis_points_to_percent(&bips));
 
         // Create individual parameter fields
         let fields = vec![
@@ -682,14 +687,12 @@ impl UniversalRouterVisualizer {
                 dynamic_annotation: None,
             },
             visualsign::AnnotatedPayloadField {
-                signable_payload_field: SignablePayloadField::TextV2 {
+                signable_payload_field: SignablePayloadField::Percentage {
                     common: SignablePayloadFieldCommon {
                         fallback_text: percentage_str.clone(),
                         label: "Percentage".to_string(),
                     },
-                    text_v2: SignablePayloadFieldTextV2 {
-                        text: percentage_str.clone(),
-                    },
+                    percentage: SignablePayloadFieldPercentage { basis_points: bips },
                 },
                 static_annotation: None,
                 dynamic_annotation: None,
@@ -888,7 +891,8 @@ impl UniversalRouterVisualizer {
 
         // Calculate first-hop fee percentage. For multi-hop paths each hop may use a
         // different fee tier; we surface the first one and disclose the hop count.
-        let fee_pct = fee as f64 / 10000.0;
+        let fee_bps = Self::fee_to_basis_points(fee);
+        let fee_pct = basis_points_to_percent(&fee_bps);
         let text = if hops == 1 {
             format!(
                 "Swap <={amount_in_max_str} {token_in_symbol} for {amount_out_str} {token_out_symbol} via V3 ({fee_pct}% fee)"
@@ -954,13 +958,13 @@ impl UniversalRouterVisualizer {
                 dynamic_annotation: None,
             },
             visualsign::AnnotatedPayloadField {
-                signable_payload_field: SignablePayloadField::TextV2 {
+                signable_payload_field: SignablePayloadField::Percentage {
                     common: SignablePayloadFieldCommon {
                         fallback_text: format!("{fee_pct}%"),
                         label: "Fee Tier".to_string(),
                     },
-                    text_v2: SignablePayloadFieldTextV2 {
-                        text: format!("{fee_pct}%"),
+                    percentage: SignablePayloadFieldPercentage {
+                        basis_points: fee_bps,
                     },
                 },
                 static_annotation: None,
@@ -1554,10 +1558,11 @@ impl crate::visualizer::ContractVisualizer for UniversalRouterContractVisualizer
 mod tests {
     use super::*;
     use alloy_primitives::{Bytes, U256};
+    use chrono::TimeZone;
     use visualsign::{
         AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
-        SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout,
-        SignablePayloadFieldTextV2,
+        SignablePayloadFieldDateTimeV2, SignablePayloadFieldListLayout,
+        SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
     };
 
     fn encode_execute_call(commands: &[u8], inputs: Vec<Vec<u8>>, deadline: u64) -> Vec<u8> {
@@ -1645,13 +1650,15 @@ mod tests {
                                 dynamic_annotation: None,
                             },
                             AnnotatedPayloadField {
-                                signable_payload_field: SignablePayloadField::TextV2 {
+                                signable_payload_field: SignablePayloadField::DateTimeV2 {
                                     common: SignablePayloadFieldCommon {
                                         fallback_text: deadline_str.clone(),
                                         label: "Deadline".to_string(),
                                     },
-                                    text_v2: SignablePayloadFieldTextV2 {
-                                        text: deadline_str.clone(),
+                                    date_time_v2: SignablePayloadFieldDateTimeV2 {
+                                        render_hint: DateTimeRenderHint::DateTime,
+                                        time_zone: Some("UTC".to_string()),
+                                        timestamp: deadline as i64,
                                     },
                                 },
                                 static_annotation: None,
@@ -1816,13 +1823,15 @@ mod tests {
                                 dynamic_annotation: None,
                             },
                             AnnotatedPayloadField {
-                                signable_payload_field: SignablePayloadField::TextV2 {
+                                signable_payload_field: SignablePayloadField::DateTimeV2 {
                                     common: SignablePayloadFieldCommon {
                                         fallback_text: deadline_str.clone(),
                                         label: "Deadline".to_string(),
                                     },
-                                    text_v2: SignablePayloadFieldTextV2 {
-                                        text: deadline_str.clone(),
+                                    date_time_v2: SignablePayloadFieldDateTimeV2 {
+                                        render_hint: DateTimeRenderHint::DateTime,
+                                        time_zone: Some("UTC".to_string()),
+                                        timestamp: deadline as i64,
                                     },
                                 },
                                 static_annotation: None,
@@ -2882,7 +2891,7 @@ mod tests {
 
     /// Regression: a PAY_PORTION with `bips` greater than `u128::MAX` must not
     /// silently render as "0%". `bips` is a `uint256` on-chain, so an adversarial
-    /// value above `u128::MAX` must surface as a raw figure rather than a
+    /// value above `u128::MAX` must surface in full rather than as a
     /// percentage that would tell the signer they are paying out nothing.
     #[test]
     fn test_decode_pay_portion_bips_above_u128_max_is_not_zero() {
@@ -2906,22 +2915,23 @@ mod tests {
                     .fields
                     .iter()
                     .find_map(|f| match &f.signable_payload_field {
-                        SignablePayloadField::TextV2 { common, text_v2 }
+                        SignablePayloadField::Percentage { common, percentage }
                             if common.label == "Percentage" =>
                         {
-                            Some(text_v2.text.clone())
+                            Some((
+                                common.fallback_text.clone(),
+                                percentage.basis_points.clone(),
+                            ))
                         }
                         _ => None,
                     })
                     .expect("Percentage field present");
-                // The bug: "0%" / "0.0000%". The fix must surface the raw bips value.
-                assert!(
-                    !percentage.starts_with('0'),
-                    "bips above u128::MAX rendered as a near-zero percentage: {percentage}"
-                );
-                assert!(
-                    percentage.contains("bips (raw)"),
-                    "oversized bips must surface as a raw figure, got: {percentage}"
+                // The bug: "0%" / "0.0000%". The fix must surface the full bips value.
+                let (fallback, basis_points) = percentage;
+                assert_eq!(basis_points, bips.to_string());
+                assert_eq!(
+                    fallback, "3402823669209384634633746074317682114.56%",
+                    "bips above u128::MAX rendered as a near-zero percentage"
                 );
             }
             _ => panic!("Expected PreviewLayout for Pay Portion"),
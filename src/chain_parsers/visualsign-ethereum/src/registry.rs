@@ -1,11 +1,21 @@
 use crate::networks::network_id_to_chain_id;
-use crate::token_metadata::{ChainMetadata, TokenMetadata};
+use crate::token_metadata::{ChainMetadata, ErcStandard, TokenMetadata};
 use alloy_primitives::{Address, U256, utils::format_units};
 use std::collections::BTreeMap;
+use visualsign::registry::{EntryOrigin, Provenance};
 
 /// Type alias for chain ID to avoid depending on external chain types
 pub type ChainId = u64;
 
+/// Wallet-provided name for an address, from the request's address book
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressLabel {
+    /// Display name, e.g. "Anchorage Cold Wallet 3"
+    pub name: String,
+    /// Short tag shown next to the name
+    pub badge: Option<String>,
+}
+
 /// Well-known addresses that protocols can register and look up
 ///
 /// This enum provides type safety for well-known contract addresses,
@@ -84,9 +94,15 @@ pub struct ContractRegistry {
     type_to_addresses: BTreeMap<(ChainId, String), Vec<Address>>,
     /// Maps (chain_id, token_address) to token metadata
     token_metadata: BTreeMap<(ChainId, Address), TokenMetadata>,
+    /// Maps (chain_id, token_address) to where its metadata came from
+    token_origins: BTreeMap<(ChainId, Address), EntryOrigin>,
     /// Maps (well_known_address, optional_chain_id) to address
     /// For chain-specific addresses, use Some(chain_id); for universal addresses, use None
     well_known_addresses: BTreeMap<(WellKnownAddress, Option<ChainId>), Address>,
+    /// Maps (chain_id, ERC-4626 vault address) to the vault's underlying asset
+    vault_assets: BTreeMap<(ChainId, Address), Address>,
+    /// Maps (chain_id, address) to its address-book name
+    address_labels: BTreeMap<(ChainId, Address), AddressLabel>,
 }
 
 impl ContractRegistry {
@@ -96,7 +112,10 @@ impl ContractRegistry {
             address_to_type: BTreeMap::new(),
             type_to_addresses: BTreeMap::new(),
             token_metadata: BTreeMap::new(),
+            token_origins: BTreeMap::new(),
             well_known_addresses: BTreeMap::new(),
+            vault_assets: BTreeMap::new(),
+            address_labels: BTreeMap::new(),
         }
     }
 
@@ -185,15 +204,39 @@ impl ContractRegistry {
         &mut self,
         chain_id: ChainId,
         metadata: TokenMetadata,
+    ) -> Result<(), String> {
+        self.register_token_with_origin(chain_id, metadata, EntryOrigin::built_in())
+    }
+
+    /// Registers token metadata, recording where it came from
+    ///
+    /// Same as [`Self::register_token`], which records a built-in origin.
+    ///
+    /// # Errors
+    /// Returns an error if the contract address cannot be parsed as a valid Ethereum address
+    pub fn register_token_with_origin(
+        &mut self,
+        chain_id: ChainId,
+        metadata: TokenMetadata,
+        origin: EntryOrigin,
     ) -> Result<(), String> {
         let address: Address = metadata
             .contract_address
             .parse()
             .map_err(|_| format!("Invalid contract address: {}", metadata.contract_address))?;
         self.token_metadata.insert((chain_id, address), metadata);
+        self.token_origins.insert((chain_id, address), origin);
         Ok(())
     }
 
+    /// Gets the provenance and trust tier of a registered token's metadata
+    ///
+    /// # Returns
+    /// `Some(origin)` if the token is registered, `None` otherwise
+    pub fn get_token_origin(&self, chain_id: ChainId, token: Address) -> Option<EntryOrigin> {
+        self.token_origins.get(&(chain_id, token)).copied()
+    }
+
     /// Gets the contract type for a specific address on a chain
     ///
     /// # Arguments
@@ -220,6 +263,196 @@ impl ContractRegistry {
             .map(|m| m.symbol.clone())
     }
 
+    /// Gets the decimals for a specific token on a chain
+    ///
+    /// # Returns
+    /// `Some(decimals)` if the token is registered, `None` otherwise
+    pub fn get_token_decimals(&self, chain_id: ChainId, token: Address) -> Option<u8> {
+        self.token_metadata
+            .get(&(chain_id, token))
+            .map(|m| m.decimals)
+    }
+
+    /// Registers an ERC-4626 vault and its underlying asset
+    ///
+    /// The vault is registered under the `Erc4626Vault` contract type so calls to it
+    /// dispatch to the ERC-4626 visualizer, which uses the asset to render amounts.
+    ///
+    /// # Arguments
+    /// * `chain_id` - The chain ID
+    /// * `vault` - The vault (share token) address
+    /// * `asset` - The underlying asset returned by the vault's `asset()`
+    pub fn register_vault(&mut self, chain_id: ChainId, vault: Address, asset: Address) {
+        let contract_type =
+            crate::contracts::core::erc4626::Erc4626Vault::short_type_id().to_string();
+        self.address_to_type
+            .insert((chain_id, vault), contract_type.clone());
+        // Vaults are registered one at a time, so add to the type's address list
+        // rather than replacing it like `register_contract_typed` does.
+        let vaults = self
+            .type_to_addresses
+            .entry((chain_id, contract_type))
+            .or_default();
+        if !vaults.contains(&vault) {
+            vaults.push(vault);
+        }
+        self.vault_assets.insert((chain_id, vault), asset);
+    }
+
+    /// Gets the underlying asset of a registered ERC-4626 vault
+    ///
+    /// # Returns
+    /// `Some(asset)` if the vault is registered, `None` otherwise
+    pub fn get_vault_asset(&self, chain_id: ChainId, vault: Address) -> Option<Address> {
+        self.vault_assets.get(&(chain_id, vault)).copied()
+    }
+
+    /// Loads wallet-supplied ERC-4626 vaults for `chain_id`
+    ///
+    /// Keys and values must be 0x-prefixed addresses. A vault that `known` (the
+    /// built-in registry) already has as a contract or token is rejected, so a
+    /// request can't re-route calls to a known contract through the vault
+    /// visualizer. Valid entries are registered even when others are rejected.
+    ///
+    /// # Returns
+    /// `Ok(())` if every entry loaded, `Err(String)` describing the rejected ones otherwise
+    pub fn load_wallet_vaults(
+        &mut self,
+        chain_id: ChainId,
+        vaults: &BTreeMap<String, String>,
+        known: &ContractRegistry,
+    ) -> Result<(), String> {
+        let mut errors = Vec::new();
+        for (vault, asset) in vaults {
+            let Ok(vault_address) = vault.parse::<Address>() else {
+                errors.push(format!("invalid vault address: {vault}"));
+                continue;
+            };
+            let Ok(asset_address) = asset.parse::<Address>() else {
+                errors.push(format!("invalid asset address for vault {vault}: {asset}"));
+                continue;
+            };
+            if known.get_contract_type(chain_id, vault_address).is_some()
+                || known.get_token_symbol(chain_id, vault_address).is_some()
+            {
+                errors.push(format!("vault {vault} is already a known contract"));
+                continue;
+            }
+            self.register_vault(chain_id, vault_address, asset_address);
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+
+    /// Names an address, e.g. a counterparty from the wallet's address book
+    pub fn register_address_label(
+        &mut self,
+        chain_id: ChainId,
+        address: Address,
+        label: AddressLabel,
+    ) {
+        self.address_labels.insert((chain_id, address), label);
+    }
+
+    /// Gets the name registered for an address
+    pub fn get_address_label(&self, chain_id: ChainId, address: Address) -> Option<&AddressLabel> {
+        self.address_labels.get(&(chain_id, address))
+    }
+
+    /// Loads a wallet address book for `chain_id`
+    ///
+    /// Keys must be 0x-prefixed addresses and names non-empty; a blank badge is
+    /// dropped. Valid entries are registered even when others are rejected.
+    ///
+    /// # Returns
+    /// `Ok(())` if every entry loaded, `Err(String)` describing the rejected ones otherwise
+    pub fn load_address_book(
+        &mut self,
+        chain_id: ChainId,
+        address_book: &BTreeMap<String, generated::parser::AddressLabel>,
+    ) -> Result<(), String> {
+        let mut errors = Vec::new();
+        for (address, entry) in address_book {
+            let Ok(parsed) = address.parse::<Address>() else {
+                errors.push(format!("invalid address-book address: {address}"));
+                continue;
+            };
+            let name = entry.name.trim();
+            if name.is_empty() {
+                errors.push(format!("empty address-book name for {address}"));
+                continue;
+            }
+            let badge = entry
+                .badge
+                .as_deref()
+                .map(str::trim)
+                .filter(|badge| !badge.is_empty())
+                .map(str::to_string);
+            self.register_address_label(
+                chain_id,
+                parsed,
+                AddressLabel {
+                    name: name.to_string(),
+                    badge,
+                },
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+
+    /// Loads wallet-supplied ERC-20 token metadata for `chain_id`
+    ///
+    /// Keys must be 0x-prefixed addresses, symbols non-empty and decimals fit
+    /// in a `u8`. Entries are tagged [`Provenance::WalletMetadata`]; valid ones
+    /// are registered even when others are rejected.
+    ///
+    /// # Returns
+    /// `Ok(())` if every entry loaded, `Err(String)` describing the rejected ones otherwise
+    pub fn load_wallet_tokens(
+        &mut self,
+        chain_id: ChainId,
+        tokens: &BTreeMap<String, generated::parser::EthereumToken>,
+    ) -> Result<(), String> {
+        let mut errors = Vec::new();
+        for (address, token) in tokens {
+            let symbol = token.symbol.trim();
+            if symbol.is_empty() {
+                errors.push(format!("empty token symbol for {address}"));
+                continue;
+            }
+            let Ok(decimals) = u8::try_from(token.decimals) else {
+                errors.push(format!("token decimals out of range for {address}"));
+                continue;
+            };
+            let metadata = TokenMetadata {
+                symbol: symbol.to_string(),
+                name: token.name.clone(),
+                erc_standard: ErcStandard::Erc20,
+                contract_address: address.clone(),
+                decimals,
+            };
+            if let Err(error) = self.register_token_with_origin(
+                chain_id,
+                metadata,
+                EntryOrigin::new(Provenance::WalletMetadata),
+            ) {
+                errors.push(error);
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+
     /// Gets the ERC standard for a registered token.
     ///
     /// Used by the dispatcher to recognize known tokens and route them to the
@@ -407,6 +640,21 @@ impl ContractRegistry {
     /// # Returns
     /// `Ok(())` on success, `Err(String)` if network_id is unknown or any token registration fails
     pub fn load_chain_metadata(&mut self, chain_metadata: &ChainMetadata) -> Result<(), String> {
+        self.load_chain_metadata_with_origin(
+            chain_metadata,
+            EntryOrigin::new(Provenance::WalletMetadata),
+        )
+    }
+
+    /// Loads token metadata from a ChainMetadata structure with an explicit origin
+    ///
+    /// Use this for operator-provided deployment bundles, which share the wallet
+    /// format but are trusted: `EntryOrigin::new(Provenance::DeploymentBundle)`.
+    pub fn load_chain_metadata_with_origin(
+        &mut self,
+        chain_metadata: &ChainMetadata,
+        origin: EntryOrigin,
+    ) -> Result<(), String> {
         let chain_id = network_id_to_chain_id(&chain_metadata.network_id)
             .ok_or_else(|| format!("Unknown network ID: {}", chain_metadata.network_id))?;
 
@@ -414,7 +662,8 @@ impl ContractRegistry {
             .assets
             .values()
             .filter_map(|token_metadata| {
-                self.register_token(chain_id, token_metadata.clone()).err()
+                self.register_token_with_origin(chain_id, token_metadata.clone(), origin)
+                    .err()
             })
             .collect();
 
@@ -470,6 +719,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_load_wallet_vaults_rejects_known_contracts() {
+        let mut known = ContractRegistry::new();
+        known
+            .register_token(
+                1,
+                create_token_metadata(
+                    "USDC",
+                    "USD Coin",
+                    "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                    6,
+                ),
+            )
+            .unwrap();
+        let vault = Address::repeat_byte(0x46);
+        let vaults: BTreeMap<String, String> = [
+            (format!("{vault:?}"), format!("{:?}", usdc_address())),
+            (format!("{:?}", usdc_address()), format!("{vault:?}")),
+            ("not-an-address".to_string(), format!("{vault:?}")),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut registry = ContractRegistry::new();
+        let error = registry.load_wallet_vaults(1, &vaults, &known).unwrap_err();
+        assert!(error.contains("already a known contract"), "{error}");
+        assert!(error.contains("invalid vault address"), "{error}");
+        assert_eq!(registry.get_vault_asset(1, vault), Some(usdc_address()));
+        assert_eq!(registry.get_vault_asset(1, usdc_address()), None);
+    }
+
     #[test]
     fn test_registry_new() {
         let registry = ContractRegistry::new();
@@ -738,6 +1018,32 @@ mod tests {
             registry.get_token_symbol(1, dai_address()),
             Some("DAI".to_string())
         );
+        let origin = registry.get_token_origin(1, usdc_address()).unwrap();
+        assert_eq!(origin.provenance, Provenance::WalletMetadata);
+        assert!(!origin.is_verified());
+    }
+
+    #[test]
+    fn test_token_origin_defaults_to_built_in() {
+        let mut registry = ContractRegistry::new();
+        let usdc = create_token_metadata(
+            "USDC",
+            "USD Coin",
+            "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+            6,
+        );
+        registry.register_token(1, usdc.clone()).unwrap();
+        assert_eq!(
+            registry.get_token_origin(1, usdc_address()),
+            Some(EntryOrigin::built_in())
+        );
+
+        registry
+            .register_token_with_origin(1, usdc, EntryOrigin::new(Provenance::DeploymentBundle))
+            .unwrap();
+        let origin = registry.get_token_origin(1, usdc_address()).unwrap();
+        assert_eq!(origin.provenance, Provenance::DeploymentBundle);
+        assert!(origin.is_verified());
     }
 
     #[test]
@@ -1115,4 +1421,98 @@ mod tests {
             "Update this test when adding new WellKnownAddress variants"
         );
     }
+
+    #[test]
+    fn test_load_address_book_skips_malformed_entries() {
+        let entry = |name: &str, badge: Option<&str>| generated::parser::AddressLabel {
+            name: name.to_string(),
+            badge: badge.map(str::to_string),
+        };
+        let book: BTreeMap<String, generated::parser::AddressLabel> = [
+            (
+                "0x1111111111111111111111111111111111111111".to_string(),
+                entry(" Cold Wallet 3 ", Some("Internal")),
+            ),
+            (
+                "0x2222222222222222222222222222222222222222".to_string(),
+                entry("Exchange", Some("  ")),
+            ),
+            (
+                "0x3333333333333333333333333333333333333333".to_string(),
+                entry("", None),
+            ),
+            ("not-an-address".to_string(), entry("Nobody", None)),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut registry = ContractRegistry::new();
+        let err = registry.load_address_book(1, &book).unwrap_err();
+        assert!(err.contains("not-an-address"), "got: {err}");
+        assert!(err.contains("0x3333"), "got: {err}");
+
+        let cold = registry
+            .get_address_label(1, Address::repeat_byte(0x11))
+            .unwrap();
+        assert_eq!(cold.name, "Cold Wallet 3");
+        assert_eq!(cold.badge.as_deref(), Some("Internal"));
+        let exchange = registry
+            .get_address_label(1, Address::repeat_byte(0x22))
+            .unwrap();
+        assert_eq!(exchange.badge, None);
+        assert!(
+            registry
+                .get_address_label(1, Address::repeat_byte(0x33))
+                .is_none()
+        );
+        // Names are per chain
+        assert!(
+            registry
+                .get_address_label(137, Address::repeat_byte(0x11))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_load_wallet_tokens_tags_entries_and_skips_malformed_ones() {
+        let token = |symbol: &str, decimals: u32| generated::parser::EthereumToken {
+            symbol: symbol.to_string(),
+            name: "Token".to_string(),
+            decimals,
+        };
+        let tokens: BTreeMap<String, generated::parser::EthereumToken> = [
+            (
+                "0x1111111111111111111111111111111111111111".to_string(),
+                token(" ACME ", 6),
+            ),
+            (
+                "0x2222222222222222222222222222222222222222".to_string(),
+                token("BIG", 256),
+            ),
+            (
+                "0x3333333333333333333333333333333333333333".to_string(),
+                token("", 18),
+            ),
+            ("not-an-address".to_string(), token("BAD", 18)),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut registry = ContractRegistry::new();
+        let err = registry.load_wallet_tokens(1, &tokens).unwrap_err();
+        assert!(err.contains("not-an-address"), "got: {err}");
+        assert!(err.contains("0x2222"), "got: {err}");
+        assert!(err.contains("0x3333"), "got: {err}");
+
+        let acme = Address::repeat_byte(0x11);
+        assert_eq!(registry.get_token_symbol(1, acme).as_deref(), Some("ACME"));
+        assert_eq!(registry.get_token_decimals(1, acme), Some(6));
+        let origin = registry.get_token_origin(1, acme).unwrap();
+        assert_eq!(origin.provenance, Provenance::WalletMetadata);
+        assert!(!origin.is_verified());
+        assert_eq!(
+            registry.get_token_symbol(1, Address::repeat_byte(0x22)),
+            None
+        );
+    }
 }
@@ -0,0 +1,316 @@
+//! ERC-4337 user operations as input
+//!
+//! Smart account wallets sign user operations rather than transactions. This
+//! converter takes the JSON a wallet passes to `eth_sendUserOperation`, either
+//! the bare operation or wrapped as
+//! `{"userOperation": {...}, "entryPoint": "0x...", "chainId": "0x1"}`, and
+//! renders it with the same EntryPoint renderer used for `handleOps` calldata.
+//!
+//! User operations carry no chain id of their own. It is taken from the
+//! envelope or from `chain_metadata`; when both are present they must agree.
+//!
+//! The main Ethereum converter also accepts this JSON on its string entry
+//! point when `DeveloperConfig::allow_user_operations` is set.
+
+use std::sync::Arc;
+
+use alloy_primitives::Address;
+use visualsign::registry::LayeredRegistry;
+use visualsign::vsptrait::{TransactionParseError, VisualSignError, VisualSignOptions};
+use visualsign::{
+    SignablePayload, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldCommon, SignablePayloadFieldTextV2,
+};
+
+use crate::networks;
+use crate::protocols::erc4337::{EntryPointVisualizer, Erc4337Config, UserOperation};
+use crate::registry::ContractRegistry;
+use crate::visualizer::{EthereumVisualizerRegistry, EthereumVisualizerRegistryBuilder};
+
+/// Maximum raw input size accepted (1 MB), matching the JSON transaction limit.
+const MAX_USER_OPERATION_LEN: usize = 1024 * 1024;
+
+/// Converts ERC-4337 user operation JSON into a `SignablePayload`
+pub struct EthereumUserOperationConverter {
+    registry: Arc<ContractRegistry>,
+    visualizer_registry: EthereumVisualizerRegistry,
+}
+
+impl EthereumUserOperationConverter {
+    /// Creates a converter with the default registry (all known protocols).
+    pub fn new() -> Self {
+        let (contract_registry, visualizer_builder) = ContractRegistry::with_default_protocols();
+        Self {
+            registry: Arc::new(contract_registry),
+            visualizer_registry: visualizer_builder.build(),
+        }
+    }
+
+    /// Creates a converter with a custom registry wrapped in Arc.
+    pub fn with_registry(registry: Arc<ContractRegistry>) -> Self {
+        Self {
+            registry,
+            visualizer_registry: EthereumVisualizerRegistryBuilder::new().build(),
+        }
+    }
+
+    /// Parses user operation JSON and renders it, validating the output charset.
+    pub fn to_visual_sign_payload(
+        &self,
+        user_operation: &str,
+        options: VisualSignOptions,
+    ) -> Result<SignablePayload, VisualSignError> {
+        let layered_registry = LayeredRegistry::new(Arc::clone(&self.registry));
+        let payload = convert_user_operation(
+            user_operation,
+            options,
+            &layered_registry,
+            &self.visualizer_registry,
+        )?;
+        payload.validate_charset()?;
+        Ok(payload)
+    }
+}
+
+impl Default for EthereumUserOperationConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether `data` looks like user operation JSON, bare or wrapped in an
+/// envelope, rather than a JSON transaction
+pub(crate) fn is_user_operation_input(data: &str) -> bool {
+    if data.len() > MAX_USER_OPERATION_LEN || !crate::eth_json::is_json_input(data) {
+        return false;
+    }
+    serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(data)
+        .map(|object| {
+            object.contains_key("userOperation")
+                || (object.contains_key("sender") && object.contains_key("callData"))
+        })
+        .unwrap_or(false)
+}
+
+pub(crate) fn convert_user_operation(
+    user_operation: &str,
+    options: VisualSignOptions,
+    layered_registry: &LayeredRegistry<ContractRegistry>,
+    visualizer_registry: &EthereumVisualizerRegistry,
+) -> Result<SignablePayload, VisualSignError> {
+    if user_operation.len() > MAX_USER_OPERATION_LEN {
+        return Err(TransactionParseError::InvalidFormat(format!(
+            "user operation too large: {} bytes (max {MAX_USER_OPERATION_LEN})",
+            user_operation.len()
+        ))
+        .into());
+    }
+    let value: serde_json::Value = serde_json::from_str(user_operation)
+        .map_err(|e| TransactionParseError::InvalidFormat(format!("invalid JSON: {e}")))?;
+    let (op_value, envelope) = match value.get("userOperation") {
+        Some(inner) => (inner, Some(&value)),
+        None => (&value, None),
+    };
+    let op = UserOperation::from_json(op_value).map_err(|e| {
+        TransactionParseError::InvalidFormat(format!("invalid user operation: {e}"))
+    })?;
+
+    let entry_point = envelope
+        .and_then(|e| e.get("entryPoint"))
+        .map(|v| {
+            v.as_str()
+                .and_then(|s| s.parse::<Address>().ok())
+                .ok_or_else(|| {
+                    TransactionParseError::InvalidFormat(
+                        "entryPoint is not a valid address".to_string(),
+                    )
+                })
+        })
+        .transpose()?;
+    let chain_id = resolve_chain_id(envelope, &options)?;
+
+    let decoder = crate::CallDecoder {
+        chain_id,
+        tx_chain_id: Some(chain_id),
+        layered_registry,
+        visualizer_registry,
+        abi_registry: None,
+        decode_transfers: options.decode_transfers,
+    };
+
+    let network_name = networks::resolve_network_name(options.metadata.as_ref(), chain_id);
+    let mut fields = vec![SignablePayloadField::TextV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: network_name.clone(),
+            label: "Network".to_string(),
+        },
+        text_v2: SignablePayloadFieldTextV2 { text: network_name },
+    }];
+    if let Some(entry_point) = entry_point {
+        fields.push(SignablePayloadField::AddressV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{entry_point:?}"),
+                label: "EntryPoint".to_string(),
+            },
+            address_v2: SignablePayloadFieldAddressV2 {
+                address: format!("{entry_point:?}"),
+                name: "EntryPoint".to_string(),
+                memo: None,
+                asset_label: "".to_string(),
+                badge_text: Some(
+                    Erc4337Config::version(entry_point)
+                        .unwrap_or("Unknown EntryPoint")
+                        .to_string(),
+                ),
+            },
+        });
+    }
+    fields.push(EntryPointVisualizer::render_user_operation(
+        &op,
+        "User Operation",
+        chain_id,
+        |target, data| decoder.decode(Some(target), data, 1),
+    ));
+
+    let title = options
+        .transaction_name
+        .unwrap_or_else(|| "ERC-4337 User Operation".to_string());
+    Ok(SignablePayload::new(
+        0,
+        title,
+        None,
+        fields,
+        "EthereumUserOperation".to_string(),
+    ))
+}
+
+/// Resolves the chain from the envelope's `chainId` and `chain_metadata`,
+/// refusing to render when they disagree
+fn resolve_chain_id(
+    envelope: Option<&serde_json::Value>,
+    options: &VisualSignOptions,
+) -> Result<u64, VisualSignError> {
+    let envelope_chain_id = envelope
+        .and_then(|e| e.get("chainId"))
+        .map(|v| {
+            let parsed = match v {
+                serde_json::Value::Number(n) => n.as_u64(),
+                serde_json::Value::String(s) => match s.strip_prefix("0x") {
+                    Some(hex_digits) => u64::from_str_radix(hex_digits, 16).ok(),
+                    None => s.parse().ok(),
+                },
+                _ => None,
+            };
+            parsed.ok_or_else(|| {
+                TransactionParseError::InvalidFormat("chainId is not a valid chain id".to_string())
+            })
+        })
+        .transpose()?;
+    let metadata_chain_id = networks::extract_chain_id_from_metadata(options.metadata.as_ref());
+
+    match (envelope_chain_id, metadata_chain_id) {
+        (Some(envelope_id), Some(meta_id)) if envelope_id != meta_id => {
+            Err(VisualSignError::ValidationError(format!(
+                "chain_id mismatch: user operation declares chain_id {envelope_id} but chain_metadata.network_id resolves to chain_id {meta_id}"
+            )))
+        }
+        (Some(id), _) | (None, Some(id)) => Ok(id),
+        (None, None) => Err(VisualSignError::DecodeError(
+            "Unable to determine chain_id: provide chainId alongside the user operation or chain_metadata".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    // execute(0x2222..., 0, transfer(0x7777..., 1000))
+    const EXECUTE_TRANSFER: &str = "0xb61d27f6\
+        0000000000000000000000002222222222222222222222222222222222222222\
+        0000000000000000000000000000000000000000000000000000000000000000\
+        0000000000000000000000000000000000000000000000000000000000000060\
+        0000000000000000000000000000000000000000000000000000000000000044\
+        a9059cbb\
+        0000000000000000000000007777777777777777777777777777777777777777\
+        00000000000000000000000000000000000000000000000000000000000003e8\
+        00000000000000000000000000000000000000000000000000000000";
+
+    fn envelope(chain_id: &str) -> String {
+        format!(
+            r#"{{
+                "chainId": "{chain_id}",
+                "entryPoint": "0x0000000071727De22E5E9d8BAf0edAc6f37da032",
+                "userOperation": {{
+                    "sender": "0x1111111111111111111111111111111111111111",
+                    "nonce": "0x0",
+                    "callData": "{EXECUTE_TRANSFER}",
+                    "callGasLimit": "0x30d40",
+                    "verificationGasLimit": "0x186a0",
+                    "preVerificationGas": "0xc350",
+                    "maxFeePerGas": "0x6fc23ac00",
+                    "maxPriorityFeePerGas": "0x3b9aca00",
+                    "signature": "0x"
+                }}
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_user_operation_decodes_account_call() {
+        let options = VisualSignOptions {
+            decode_transfers: true,
+            ..Default::default()
+        };
+        let payload = EthereumUserOperationConverter::new()
+            .to_visual_sign_payload(&envelope("0x1"), options)
+            .unwrap();
+        assert_eq!(payload.title, "ERC-4337 User Operation");
+        let json = serde_json::to_string(&payload).unwrap();
+        assert!(json.contains("v0.7"), "got: {json}");
+        assert!(json.contains("Account Call 1 of 1"), "got: {json}");
+        assert!(
+            json.contains("0x7777777777777777777777777777777777777777"),
+            "got: {json}"
+        );
+    }
+
+    #[test]
+    fn test_user_operation_requires_chain_id() {
+        let bare = r#"{
+            "sender": "0x1111111111111111111111111111111111111111",
+            "nonce": "0x0",
+            "callData": "0x"
+        }"#;
+        let result = EthereumUserOperationConverter::new()
+            .to_visual_sign_payload(bare, VisualSignOptions::default());
+        assert!(matches!(result, Err(VisualSignError::DecodeError(_))));
+    }
+
+    #[test]
+    fn test_user_operation_on_transaction_entry_point_requires_developer_config() {
+        let input = envelope("0x1");
+        assert!(is_user_operation_input(&input));
+
+        let rejected =
+            crate::transaction_string_to_visual_sign(&input, VisualSignOptions::default());
+        assert!(matches!(
+            rejected,
+            Err(VisualSignError::ParseError(
+                TransactionParseError::InvalidFormat(_)
+            ))
+        ));
+
+        let options = VisualSignOptions {
+            developer_config: Some(visualsign::vsptrait::DeveloperConfig {
+                allow_user_operations: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let payload = crate::transaction_string_to_visual_sign(&input, options).unwrap();
+        assert_eq!(payload.payload_type, "EthereumUserOperation");
+        assert_eq!(payload.title, "ERC-4337 User Operation");
+    }
+}
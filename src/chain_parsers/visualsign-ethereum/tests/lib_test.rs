@@ -4,13 +4,14 @@ use alloy_primitives::U256;
 use alloy_rlp::Encodable;
 use alloy_sol_types::{SolCall, sol};
 use generated::parser::{
-    Abi, ChainMetadata, EthereumMetadata, SignatureMetadata, chain_metadata::Metadata,
+    Abi, AddressLabel, ChainMetadata, EthereumMetadata, EthereumNetwork, EthereumToken,
+    SignatureMetadata, TokenPrice, chain_metadata::Metadata,
 };
 use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
-use visualsign::SignablePayload;
 use visualsign::vsptrait::{VisualSignConverterFromString, VisualSignError, VisualSignOptions};
+use visualsign::{SignablePayload, SignablePayloadField};
 use visualsign_ethereum::EthereumVisualSignConverter;
 use visualsign_ethereum::transaction_string_to_visual_sign;
 
@@ -102,7 +103,7 @@ fn fixture_path(name: &str) -> PathBuf {
     path
 }
 
-static FIXTURES: [&str; 7] = [
+static FIXTURES: [&str; 8] = [
     "1559",
     "legacy",
     "uniswap-v2swap",
@@ -111,6 +112,8 @@ static FIXTURES: [&str; 7] = [
     "json-legacy",
     // Real mainnet Universal Router V2.1.1 swap (Permit2 permit + V3 USDC->USDT).
     "json-uniswap-ur-v2_1_1",
+    // Morpho Blue supply of 1000 USDC to a WETH/USDC market.
+    "morpho-blue",
 ];
 
 #[test]
@@ -131,6 +134,7 @@ fn test_with_fixtures() {
         // Create options for the transaction
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: None,
@@ -200,6 +204,7 @@ fn test_ethereum_charset_validation() {
         // Create options for the transaction
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: None,
@@ -267,6 +272,7 @@ fn test_trait_path_without_abi_metadata() {
 
     let options = VisualSignOptions {
         include_intermediate_output: false,
+        strict_registry: false,
         decode_transfers: true,
         transaction_name: None,
         metadata: None,
@@ -347,12 +353,19 @@ fn test_abi_from_metadata_decodes_function() {
     // on `BTreeMap` internally and we collect at the FFI point.
     let options = VisualSignOptions {
         include_intermediate_output: false,
+        strict_registry: false,
         decode_transfers: true,
         transaction_name: None,
         metadata: Some(ChainMetadata {
             metadata: Some(Metadata::Ethereum(EthereumMetadata {
                 network_id: Some("ETHEREUM_MAINNET".to_string()),
                 abi_mappings: abi_mappings.into_iter().collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         }),
         developer_config: None,
@@ -448,12 +461,19 @@ fn test_proxy_decodes_via_implementation_abi() {
 
     let options = VisualSignOptions {
         include_intermediate_output: false,
+        strict_registry: false,
         decode_transfers: true,
         transaction_name: None,
         metadata: Some(ChainMetadata {
             metadata: Some(Metadata::Ethereum(EthereumMetadata {
                 network_id: Some("ETHEREUM_MAINNET".to_string()),
                 abi_mappings: abi_mappings.into_iter().collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         }),
         developer_config: None,
@@ -541,12 +561,19 @@ fn test_proxy_entry_cannot_override_canonical_token() {
 
     let options = VisualSignOptions {
         include_intermediate_output: false,
+        strict_registry: false,
         decode_transfers: true,
         transaction_name: None,
         metadata: Some(ChainMetadata {
             metadata: Some(Metadata::Ethereum(EthereumMetadata {
                 network_id: Some("ETHEREUM_MAINNET".to_string()),
                 abi_mappings: abi_mappings.into_iter().collect(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         }),
         developer_config: None,
@@ -596,12 +623,19 @@ fn test_chain_id_mismatch_rejected() {
     // the tx-declared chain_id in the transaction bytes. Parser must refuse.
     let options = VisualSignOptions {
         include_intermediate_output: false,
+        strict_registry: false,
         decode_transfers: true,
         transaction_name: None,
         metadata: Some(ChainMetadata {
             metadata: Some(Metadata::Ethereum(EthereumMetadata {
                 network_id: Some("POLYGON_MAINNET".to_string()),
                 abi_mappings: Default::default(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         }),
         developer_config: None,
@@ -656,12 +690,19 @@ fn test_chain_id_matching_metadata_succeeds() {
 
     let options = VisualSignOptions {
         include_intermediate_output: false,
+        strict_registry: false,
         decode_transfers: true,
         transaction_name: None,
         metadata: Some(ChainMetadata {
             metadata: Some(Metadata::Ethereum(EthereumMetadata {
                 network_id: Some("POLYGON_MAINNET".to_string()),
                 abi_mappings: Default::default(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         }),
         developer_config: None,
@@ -678,6 +719,292 @@ fn test_chain_id_matching_metadata_succeeds() {
     );
 }
 
+#[test]
+fn test_custom_network_renders_name_and_native_currency() {
+    let tx = TxEip1559 {
+        chain_id: 99999,
+        nonce: 0,
+        gas_limit: 21_000,
+        max_fee_per_gas: 20_000_000_000,
+        max_priority_fee_per_gas: 1_000_000_000,
+        to: alloy_primitives::TxKind::Call(
+            "0x000000000000000000000000000000000000dEaD"
+                .parse()
+                .unwrap(),
+        ),
+        value: U256::from(2_500_000_000_000_000_000u64),
+        ..Default::default()
+    };
+    let mut buf = Vec::new();
+    buf.push(0x02);
+    tx.encode(&mut buf);
+    let tx_hex = format!("0x{}", hex::encode(&buf));
+
+    let options = VisualSignOptions {
+        include_intermediate_output: false,
+        strict_registry: false,
+        decode_transfers: true,
+        transaction_name: None,
+        metadata: Some(ChainMetadata {
+            metadata: Some(Metadata::Ethereum(EthereumMetadata {
+                network_id: None,
+                abi_mappings: Default::default(),
+                proxy_implementations: Default::default(),
+                custom_networks: vec![EthereumNetwork {
+                    chain_id: 99999,
+                    name: "Acme Appchain".to_string(),
+                    native_symbol: "ACME".to_string(),
+                    native_decimals: None,
+                    explorer_url: Some("https://explorer.acme.example/".to_string()),
+                }],
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
+            })),
+        }),
+        developer_config: None,
+    };
+
+    let converter = EthereumVisualSignConverter::with_signers(test_abi_signer_allowlist());
+    let payload = converter.to_payload_from_string(&tx_hex, options).unwrap();
+    let json = payload.to_json().unwrap();
+    assert!(
+        json.contains("Acme Appchain (Chain ID: 99999)"),
+        "got: {json}"
+    );
+    assert!(json.contains("2.5 ACME"), "got: {json}");
+    assert!(!json.contains("Unknown Network"), "got: {json}");
+    let explorer = payload
+        .fields
+        .iter()
+        .find(|f| f.label() == "Explorer")
+        .expect("custom network destinations link to the wallet's explorer");
+    assert_eq!(
+        explorer.fallback_text(),
+        "https://explorer.acme.example/address/0x000000000000000000000000000000000000dEaD"
+    );
+}
+
+#[test]
+fn test_address_book_names_the_destination() {
+    let tx = TxEip1559 {
+        chain_id: 1,
+        nonce: 0,
+        gas_limit: 21_000,
+        max_fee_per_gas: 20_000_000_000,
+        max_priority_fee_per_gas: 1_000_000_000,
+        to: alloy_primitives::TxKind::Call(
+            "0x000000000000000000000000000000000000dEaD"
+                .parse()
+                .unwrap(),
+        ),
+        value: U256::from(1_000_000_000_000_000_000u64),
+        ..Default::default()
+    };
+    let mut buf = Vec::new();
+    buf.push(0x02);
+    tx.encode(&mut buf);
+    let tx_hex = format!("0x{}", hex::encode(&buf));
+
+    let options = VisualSignOptions {
+        include_intermediate_output: false,
+        strict_registry: false,
+        decode_transfers: true,
+        transaction_name: None,
+        metadata: Some(ChainMetadata {
+            metadata: Some(Metadata::Ethereum(EthereumMetadata {
+                network_id: Some("ETHEREUM_MAINNET".to_string()),
+                abi_mappings: Default::default(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                // Lowercase keys match the checksummed destination
+                address_book: [(
+                    "0x000000000000000000000000000000000000dead".to_string(),
+                    AddressLabel {
+                        name: "Anchorage Cold Wallet 3".to_string(),
+                        badge: Some("Internal".to_string()),
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
+            })),
+        }),
+        developer_config: None,
+    };
+
+    let converter = EthereumVisualSignConverter::with_signers(test_abi_signer_allowlist());
+    let payload = converter.to_payload_from_string(&tx_hex, options).unwrap();
+    let to_field = payload.fields.iter().find(|f| f.label() == "To").unwrap();
+    let SignablePayloadField::AddressV2 { common, address_v2 } = to_field else {
+        panic!("expected AddressV2 for To, got: {to_field:?}");
+    };
+    assert_eq!(address_v2.name, "Anchorage Cold Wallet 3");
+    assert_eq!(address_v2.badge_text.as_deref(), Some("Internal"));
+    assert_eq!(
+        address_v2.address,
+        "0x000000000000000000000000000000000000dEaD"
+    );
+    assert_eq!(
+        common.fallback_text,
+        "Anchorage Cold Wallet 3 (0x000000000000000000000000000000000000dEaD)"
+    );
+}
+
+/// Converts an ERC-20 `transfer` of `amount` sent to `token` with the given
+/// wallet token list
+fn convert_transfer_with_wallet_tokens(
+    token: &str,
+    amount: u64,
+    tokens: BTreeMap<String, EthereumToken>,
+    strict_registry: bool,
+) -> String {
+    sol! {
+        function transfer(address to, uint256 amount) external returns (bool);
+    }
+
+    let calldata = transferCall {
+        to: "0x000000000000000000000000000000000000dEaD"
+            .parse()
+            .unwrap(),
+        amount: U256::from(amount),
+    }
+    .abi_encode();
+    let tx = TxEip1559 {
+        chain_id: 1,
+        gas_limit: 60_000,
+        max_fee_per_gas: 20_000_000_000,
+        max_priority_fee_per_gas: 1_000_000_000,
+        to: alloy_primitives::TxKind::Call(token.parse().unwrap()),
+        input: calldata.into(),
+        ..Default::default()
+    };
+    let mut buf = vec![0x02];
+    tx.encode(&mut buf);
+    let tx_hex = format!("0x{}", hex::encode(&buf));
+
+    let options = VisualSignOptions {
+        decode_transfers: true,
+        strict_registry,
+        metadata: Some(ChainMetadata {
+            metadata: Some(Metadata::Ethereum(EthereumMetadata {
+                network_id: Some("ETHEREUM_MAINNET".to_string()),
+                tokens,
+                ..Default::default()
+            })),
+        }),
+        ..Default::default()
+    };
+    let converter = EthereumVisualSignConverter::with_signers(test_abi_signer_allowlist());
+    let payload = converter.to_payload_from_string(&tx_hex, options).unwrap();
+    serde_json::to_string(&payload).unwrap()
+}
+
+#[test]
+fn test_wallet_tokens_only_fill_in_unknown_tokens() {
+    let acme = "0x00000000000000000000000000000000000ac3e0";
+    let wallet_tokens = |address: &str, symbol: &str, decimals: u32| {
+        [(
+            address.to_string(),
+            EthereumToken {
+                symbol: symbol.to_string(),
+                name: symbol.to_string(),
+                decimals,
+            },
+        )]
+        .into_iter()
+        .collect::<BTreeMap<_, _>>()
+    };
+
+    // Unknown token: the wallet's symbol and decimals apply, flagged as unverified
+    let json =
+        convert_transfer_with_wallet_tokens(acme, 2_500_000, wallet_tokens(acme, "ACME", 6), false);
+    assert!(json.contains("2.500000 ACME"), "got: {json}");
+    assert!(
+        json.contains("symbol and decimals from unverified wallet metadata"),
+        "got: {json}"
+    );
+
+    // Built-in USDC: the wallet cannot rescale or rename it
+    let usdc = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+    let json =
+        convert_transfer_with_wallet_tokens(usdc, 2_500_000, wallet_tokens(usdc, "FAKE", 2), false);
+    assert!(json.contains("2.500000 USDC"), "got: {json}");
+    assert!(!json.contains("FAKE"), "got: {json}");
+    assert!(!json.contains("unverified wallet metadata"), "got: {json}");
+
+    // Strict mode: wallet tokens are ignored and the amount stays raw
+    let json =
+        convert_transfer_with_wallet_tokens(acme, 2_500_000, wallet_tokens(acme, "ACME", 6), true);
+    assert!(json.contains("2500000"), "got: {json}");
+    assert!(!json.contains("ACME"), "got: {json}");
+}
+
+#[test]
+fn test_token_prices_annotate_amounts_with_fiat_value() {
+    sol! {
+        function transfer(address to, uint256 amount) external returns (bool);
+    }
+
+    let calldata = transferCall {
+        to: "0x000000000000000000000000000000000000dEaD"
+            .parse()
+            .unwrap(),
+        amount: U256::from(1_530_220_000u64),
+    }
+    .abi_encode();
+    let tx = TxEip1559 {
+        chain_id: 1,
+        gas_limit: 60_000,
+        max_fee_per_gas: 20_000_000_000,
+        max_priority_fee_per_gas: 1_000_000_000,
+        to: alloy_primitives::TxKind::Call(
+            "0xA0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"
+                .parse()
+                .unwrap(),
+        ),
+        input: calldata.into(),
+        ..Default::default()
+    };
+    let mut buf = vec![0x02];
+    tx.encode(&mut buf);
+    let tx_hex = format!("0x{}", hex::encode(&buf));
+
+    let options = VisualSignOptions {
+        include_intermediate_output: false,
+        strict_registry: false,
+        decode_transfers: true,
+        transaction_name: None,
+        metadata: Some(ChainMetadata {
+            metadata: Some(Metadata::Ethereum(EthereumMetadata {
+                network_id: Some("ETHEREUM_MAINNET".to_string()),
+                token_prices: [(
+                    "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48".to_string(),
+                    TokenPrice {
+                        price: "1".to_string(),
+                        currency: "USD".to_string(),
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            })),
+        }),
+        developer_config: None,
+    };
+
+    let converter = EthereumVisualSignConverter::with_signers(test_abi_signer_allowlist());
+    let payload = converter.to_payload_from_string(&tx_hex, options).unwrap();
+    let json = serde_json::to_string(&payload).unwrap();
+    assert!(
+        json.contains(r#""DynamicAnnotation":{"ID":"fiat_value","Params":["~$1,530.22","USD"],"Type":"informational"}"#),
+        "got: {json}"
+    );
+}
+
 #[test]
 fn test_non_ascii_payload_is_rejected_by_converter() {
     // Regression for PRS-224: the Ethereum override of
@@ -703,6 +1030,7 @@ fn test_non_ascii_payload_is_rejected_by_converter() {
 
     let options = VisualSignOptions {
         include_intermediate_output: false,
+        strict_registry: false,
         decode_transfers: true,
         transaction_name: Some("Send \u{202E}evil".to_string()),
         metadata: None,
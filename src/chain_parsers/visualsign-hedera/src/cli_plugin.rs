@@ -0,0 +1,42 @@
+use clap::Args as ClapArgs;
+use generated::parser::ChainMetadata;
+use visualsign::registry::{Chain, TransactionConverterRegistry};
+
+/// CLI arguments specific to Hedera.
+///
+/// No Hedera-specific args are needed yet; the global `--network` flag is accepted but
+/// not used. Contract ABIs come from `HederaMetadata`, which the CLI does not build.
+#[derive(ClapArgs, Debug, Default, Clone)]
+pub struct HederaArgs {}
+
+/// [`parser_cli_core::ChainPlugin`] implementation for Hedera.
+pub struct HederaPlugin {
+    // Empty today; kept so a Hedera flag can be added without changing the struct shape.
+    #[allow(dead_code)]
+    args: HederaArgs,
+}
+
+impl HederaPlugin {
+    /// Creates a new `HederaPlugin` with the given CLI args.
+    #[must_use]
+    pub fn new(args: HederaArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl parser_cli_core::ChainPlugin for HederaPlugin {
+    fn chain(&self) -> Chain {
+        Chain::Hedera
+    }
+
+    fn register(&self, registry: &mut TransactionConverterRegistry) {
+        registry.register::<crate::HederaTransactionWrapper, _>(
+            Chain::Hedera,
+            crate::HederaVisualSignConverter,
+        );
+    }
+
+    fn create_metadata(&self, _network: Option<String>) -> Result<Option<ChainMetadata>, String> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,900 @@
+#[cfg(feature = "cli-plugin")]
+pub mod cli_plugin;
+
+#[cfg(feature = "cli-plugin")]
+pub use cli_plugin::{HederaArgs, HederaPlugin};
+
+pub mod proto;
+
+use std::sync::Arc;
+
+use alloy_json_abi::JsonAbi;
+use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
+use generated::parser::{HederaMetadata, chain_metadata};
+use prost::Message as _;
+use proto::{
+    AccountAmount, AccountId, ContractCallTransactionBody, ContractId, TokenId, TransactionBody,
+    TransactionId, account_id, contract_id, transaction_body::Data,
+};
+use visualsign::field_builders::{
+    create_address_field, create_amount_field, create_preview_layout, create_text_field,
+};
+use visualsign::time_fmt::{format_duration_secs, format_timestamp_ms};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayload,
+    encodings::SupportedEncodings,
+    vsptrait::{
+        ConversionResult, Transaction, TransactionParseError, VisualSignConverter,
+        VisualSignConverterFromString, VisualSignError, VisualSignOptions,
+    },
+};
+use visualsign_ethereum::abi_decoder::AbiDecoder;
+use visualsign_ethereum::contracts::core::{
+    ERC20Visualizer, ERC721Visualizer, FallbackVisualizer, SelectorDirectoryVisualizer,
+};
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum HederaParserError {
+    #[error("Failed to decode transaction: {0}")]
+    FailedToDecodeTransaction(String),
+    #[error(
+        "No supported transaction body found (expected CryptoTransfer, TokenAssociate, ConsensusSubmitMessage or ContractCall)"
+    )]
+    UnsupportedTransaction,
+}
+
+// 1 HBAR = 10^8 tinybars.
+const HBAR_DECIMALS: u32 = 8;
+// The Ethereum decoder takes a chain ID; Hedera mainnet's EVM chain ID is 295.
+const HEDERA_CHAIN_ID: u64 = 295;
+
+fn decode_transaction(
+    raw_transaction: &str,
+    encodings: SupportedEncodings,
+) -> Result<TransactionBody, HederaParserError> {
+    let bytes = match encodings {
+        SupportedEncodings::Hex => {
+            visualsign::encodings::decode_hex(raw_transaction).map_err(|e| {
+                HederaParserError::FailedToDecodeTransaction(format!("Failed to decode hex: {e}"))
+            })?
+        }
+        SupportedEncodings::Base64 => b64.decode(raw_transaction).map_err(|e| {
+            HederaParserError::FailedToDecodeTransaction(format!("Failed to decode base64: {e}"))
+        })?,
+    };
+
+    decode_body(&bytes)
+}
+
+/// Finds the `TransactionBody` in a `Transaction`, a `SignedTransaction` or the bare body
+/// bytes a wallet signs.
+///
+/// The three messages share field numbers with different meanings, so bytes can decode as
+/// the wrong one; an interpretation is only accepted when it yields a body of a supported
+/// type.
+fn decode_body(bytes: &[u8]) -> Result<TransactionBody, HederaParserError> {
+    fn supported_body(bytes: &[u8]) -> Option<TransactionBody> {
+        TransactionBody::decode(bytes)
+            .ok()
+            .filter(|body| body.data.is_some())
+    }
+    fn signed_body(bytes: &[u8]) -> Option<TransactionBody> {
+        proto::SignedTransaction::decode(bytes)
+            .ok()
+            .and_then(|signed| supported_body(&signed.body_bytes))
+    }
+
+    proto::Transaction::decode(bytes)
+        .ok()
+        .and_then(|tx| {
+            if tx.signed_transaction_bytes.is_empty() {
+                supported_body(&tx.body_bytes)
+            } else {
+                signed_body(&tx.signed_transaction_bytes)
+            }
+        })
+        .or_else(|| signed_body(bytes))
+        .or_else(|| supported_body(bytes))
+        .ok_or(HederaParserError::UnsupportedTransaction)
+}
+
+/// Wrapper for Hedera transaction bodies
+#[derive(Debug, Clone)]
+pub struct HederaTransactionWrapper {
+    transaction: TransactionBody,
+}
+
+impl Transaction for HederaTransactionWrapper {
+    fn from_string(data: &str) -> Result<Self, TransactionParseError> {
+        let format = SupportedEncodings::detect(data);
+        let transaction = decode_transaction(data, format)
+            .map_err(|e| TransactionParseError::DecodeError(e.to_string()))?;
+        Ok(Self { transaction })
+    }
+
+    fn transaction_type(&self) -> String {
+        "Hedera".to_string()
+    }
+}
+
+impl HederaTransactionWrapper {
+    pub fn new(transaction: TransactionBody) -> Self {
+        Self { transaction }
+    }
+
+    pub fn inner(&self) -> &TransactionBody {
+        &self.transaction
+    }
+}
+
+/// Converter for Hedera transactions
+pub struct HederaVisualSignConverter;
+
+impl VisualSignConverter<HederaTransactionWrapper> for HederaVisualSignConverter {
+    fn to_visual_sign_payload(
+        &self,
+        transaction_wrapper: HederaTransactionWrapper,
+        options: VisualSignOptions,
+    ) -> Result<ConversionResult, VisualSignError> {
+        let payload = convert_to_visual_sign_payload(transaction_wrapper.inner(), options)?;
+        Ok(ConversionResult::new(payload))
+    }
+}
+
+impl VisualSignConverterFromString<HederaTransactionWrapper> for HederaVisualSignConverter {}
+
+// Public API functions
+pub fn transaction_to_visual_sign(
+    transaction: TransactionBody,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let wrapper = HederaTransactionWrapper::new(transaction);
+    let converter = HederaVisualSignConverter;
+    converter
+        .to_visual_sign_payload(wrapper, options)
+        .map(|r| r.payload)
+}
+
+pub fn transaction_string_to_visual_sign(
+    transaction_data: &str,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let converter = HederaVisualSignConverter;
+    converter
+        .to_visual_sign_payload_from_string(transaction_data, options)
+        .map(|r| r.payload)
+}
+
+fn data_label(data: &Data) -> &'static str {
+    match data {
+        Data::ContractCall(_) => "Contract Call",
+        Data::CryptoTransfer(_) => "Crypto Transfer",
+        Data::ConsensusSubmitMessage(_) => "Consensus Submit Message",
+        Data::TokenAssociate(_) => "Token Associate",
+    }
+}
+
+fn account(id: Option<&AccountId>) -> String {
+    let Some(id) = id else {
+        return "unknown account".to_string();
+    };
+    match &id.account {
+        Some(account_id::Account::AccountNum(num)) => {
+            format!("{}.{}.{num}", id.shard_num, id.realm_num)
+        }
+        // Hedera writes aliases in place of the account number, in hex.
+        Some(account_id::Account::Alias(alias)) => {
+            format!("{}.{}.{}", id.shard_num, id.realm_num, hex::encode(alias))
+        }
+        None => format!("{}.{}.?", id.shard_num, id.realm_num),
+    }
+}
+
+fn token(id: Option<&TokenId>) -> String {
+    id.map_or_else(
+        || "unknown token".to_string(),
+        |t| format!("{}.{}.{}", t.shard_num, t.realm_num, t.token_num),
+    )
+}
+
+fn transaction_id(id: &TransactionId) -> String {
+    let start = id.transaction_valid_start.clone().unwrap_or_default();
+    let mut formatted = format!(
+        "{}@{}.{:09}",
+        account(id.account_id.as_ref()),
+        start.seconds,
+        start.nanos
+    );
+    if id.nonce != 0 {
+        formatted.push_str(&format!("/{}", id.nonce));
+    }
+    if id.scheduled {
+        formatted.push_str("?scheduled");
+    }
+    formatted
+}
+
+fn hbar(tinybars: i64) -> String {
+    let value = format_units(u128::from(tinybars.unsigned_abs()), HBAR_DECIMALS);
+    if tinybars < 0 {
+        format!("-{value}")
+    } else {
+        value
+    }
+}
+
+/// The contract ID and its EVM address: contracts created through the Hedera API have
+/// "long-zero" addresses that encode shard, realm and number.
+fn contract(id: Option<&ContractId>) -> (String, Option<String>) {
+    let Some(id) = id else {
+        return ("unknown contract".to_string(), None);
+    };
+    match &id.contract {
+        Some(contract_id::Contract::ContractNum(num)) => {
+            let mut address = Vec::with_capacity(20);
+            address.extend_from_slice(&(id.shard_num as u32).to_be_bytes());
+            address.extend_from_slice(&id.realm_num.to_be_bytes());
+            address.extend_from_slice(&num.to_be_bytes());
+            (
+                format!("{}.{}.{num}", id.shard_num, id.realm_num),
+                Some(format!("0x{}", hex::encode(address))),
+            )
+        }
+        Some(contract_id::Contract::EvmAddress(address)) => {
+            let address = format!("0x{}", hex::encode(address));
+            (address.clone(), Some(address))
+        }
+        None => (format!("{}.{}.?", id.shard_num, id.realm_num), None),
+    }
+}
+
+/// One side of a transfer, e.g. "0.0.1001: 1.5 HBAR (allowance)".
+fn transfer_row(entry: &AccountAmount, amount: String) -> String {
+    let mut row = format!("{}: {amount}", account(entry.account_id.as_ref()));
+    if entry.is_approval {
+        row.push_str(" (allowance)");
+    }
+    row
+}
+
+/// A preview for a list of balance changes that sum to zero, with "Send X from A to B" as
+/// the subtitle when there is one sender and one receiver.
+fn transfer_preview(
+    title: &str,
+    transfers: &[AccountAmount],
+    format_amount: impl Fn(i64) -> String,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let (debits, credits): (Vec<&AccountAmount>, Vec<&AccountAmount>) =
+        transfers.iter().partition(|t| t.amount < 0);
+    let mut fields = Vec::with_capacity(transfers.len());
+    for debit in &debits {
+        fields.push(create_text_field(
+            "From",
+            &transfer_row(debit, format_amount(debit.amount.saturating_neg())),
+        )?);
+    }
+    for credit in &credits {
+        fields.push(create_text_field(
+            "To",
+            &transfer_row(credit, format_amount(credit.amount)),
+        )?);
+    }
+
+    let subtitle = match (debits.as_slice(), credits.as_slice()) {
+        ([from], [to]) => format!(
+            "Send {} from {} to {}",
+            format_amount(to.amount),
+            account(from.account_id.as_ref()),
+            account(to.account_id.as_ref())
+        ),
+        _ => {
+            let total: i64 = credits
+                .iter()
+                .fold(0i64, |sum, c| sum.saturating_add(c.amount));
+            format!(
+                "Send {} from {} to {} accounts",
+                format_amount(total),
+                debits.len(),
+                credits.len()
+            )
+        }
+    };
+    Ok(create_preview_layout(title, subtitle, fields))
+}
+
+fn abi_for<'a>(
+    metadata: Option<&'a HederaMetadata>,
+    contract_id: &str,
+    evm_address: Option<&str>,
+) -> Option<&'a str> {
+    let mappings = &metadata?.abi_mappings;
+    mappings
+        .get(contract_id)
+        .or_else(|| {
+            let evm_address = evm_address?;
+            mappings
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(evm_address))
+                .map(|(_, abi)| abi)
+        })
+        .map(|abi| abi.value.as_str())
+}
+
+fn contract_call_fields(
+    call: &ContractCallTransactionBody,
+    metadata: Option<&HederaMetadata>,
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let (id, evm_address) = contract(call.contract_id.as_ref());
+    let mut fields = vec![
+        create_text_field(
+            "Contract",
+            &match &evm_address {
+                Some(address) if *address != id => format!("{id} ({address})"),
+                _ => id.clone(),
+            },
+        )?,
+        create_text_field("Gas Limit", &call.gas.to_string())?,
+    ];
+    if call.amount != 0 {
+        fields.push(create_amount_field("Amount", &hbar(call.amount), "HBAR")?);
+    }
+
+    // Same order as the Ethereum parser: a caller-supplied ABI first, then the standard
+    // interfaces (HTS tokens answer ERC-20 and ERC-721 calls), then the selector alone.
+    let input = &call.function_parameters;
+    let abi_field = abi_for(metadata, &id, evm_address.as_deref())
+        .and_then(|json| serde_json::from_str::<JsonAbi>(json).ok())
+        .and_then(|abi| {
+            AbiDecoder::new(Arc::new(abi))
+                .visualize(input, HEDERA_CHAIN_ID, None)
+                .ok()
+        });
+    let call_field = abi_field
+        .or_else(|| (ERC20Visualizer {}).visualize_tx_commands(input))
+        .or_else(|| ERC721Visualizer.visualize_tx_commands(input))
+        .or_else(|| SelectorDirectoryVisualizer.visualize_tx_commands(input))
+        .unwrap_or_else(|| FallbackVisualizer::new().visualize_hex(input));
+    fields.push(AnnotatedPayloadField {
+        signable_payload_field: call_field,
+        static_annotation: None,
+        dynamic_annotation: None,
+    });
+    Ok(fields)
+}
+
+fn convert_to_visual_sign_payload(
+    body: &TransactionBody,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let metadata = match options
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.metadata.as_ref())
+    {
+        Some(chain_metadata::Metadata::Hedera(hedera_metadata)) => Some(hedera_metadata),
+        _ => None,
+    };
+
+    let mut fields: Vec<AnnotatedPayloadField> = vec![create_text_field("Network", "Hedera")?];
+    if let Some(id) = &body.transaction_id {
+        fields.push(create_text_field("Transaction ID", &transaction_id(id))?);
+        fields.push(create_address_field(
+            "Payer",
+            &account(id.account_id.as_ref()),
+            None,
+            None,
+            None,
+            None,
+        )?);
+        if let Some(start) = &id.transaction_valid_start {
+            let ms = start
+                .seconds
+                .saturating_mul(1000)
+                .saturating_add(i64::from(start.nanos / 1_000_000));
+            fields.push(create_text_field("Valid Start", &format_timestamp_ms(ms))?);
+        }
+    }
+    if let Some(duration) = &body.transaction_valid_duration {
+        fields.push(create_text_field(
+            "Valid Duration",
+            &format_duration_secs(duration.seconds.max(0) as u64),
+        )?);
+    }
+    if body.node_account_id.is_some() {
+        fields.push(create_text_field(
+            "Node",
+            &account(body.node_account_id.as_ref()),
+        )?);
+    }
+    fields.push(create_amount_field(
+        "Max Fee",
+        &format_units(u128::from(body.transaction_fee), HBAR_DECIMALS),
+        "HBAR",
+    )?);
+
+    // decode_body only accepts bodies with data, but a caller can build one without.
+    let data = body
+        .data
+        .as_ref()
+        .ok_or_else(|| VisualSignError::DecodeError("Transaction body has no data".to_string()))?;
+    fields.push(create_text_field("Transaction Type", data_label(data))?);
+
+    match data {
+        Data::CryptoTransfer(transfer) => {
+            if let Some(hbar_transfers) = &transfer.transfers {
+                if !hbar_transfers.account_amounts.is_empty() {
+                    fields.push(transfer_preview(
+                        "HBAR Transfer",
+                        &hbar_transfers.account_amounts,
+                        |amount| format!("{} HBAR", hbar(amount)),
+                    )?);
+                }
+            }
+            for list in &transfer.token_transfers {
+                let token_id = token(list.token.as_ref());
+                if !list.transfers.is_empty() {
+                    // Without the decimals the sender committed to, only raw units are safe.
+                    let decimals = list.expected_decimals.as_ref().map(|d| d.value);
+                    fields.push(transfer_preview(
+                        &format!("Token Transfer ({token_id})"),
+                        &list.transfers,
+                        |amount| match decimals {
+                            Some(decimals) => format!(
+                                "{} of token {token_id}",
+                                format_units(u128::from(amount.unsigned_abs()), decimals)
+                            ),
+                            None => format!("{amount} raw units of token {token_id}"),
+                        },
+                    )?);
+                }
+                for nft in &list.nft_transfers {
+                    let from = account(nft.sender_account_id.as_ref());
+                    let to = account(nft.receiver_account_id.as_ref());
+                    let mut nft_fields = vec![
+                        create_text_field("Token", &token_id)?,
+                        create_text_field("Serial Number", &nft.serial_number.to_string())?,
+                        create_text_field("From", &from)?,
+                        create_text_field("To", &to)?,
+                    ];
+                    if nft.is_approval {
+                        nft_fields.push(create_text_field("Allowance", "Sent using an allowance")?);
+                    }
+                    fields.push(create_preview_layout(
+                        "NFT Transfer",
+                        format!("Send {token_id} #{} from {from} to {to}", nft.serial_number),
+                        nft_fields,
+                    ));
+                }
+            }
+        }
+        Data::TokenAssociate(associate) => {
+            fields.push(create_address_field(
+                "Account",
+                &account(associate.account.as_ref()),
+                None,
+                None,
+                None,
+                None,
+            )?);
+            let tokens: Vec<String> = associate.tokens.iter().map(|t| token(Some(t))).collect();
+            fields.push(create_text_field("Tokens", &tokens.join(", "))?);
+        }
+        Data::ConsensusSubmitMessage(submit) => {
+            fields.push(create_text_field(
+                "Topic",
+                &submit.topic_id.as_ref().map_or_else(
+                    || "unknown topic".to_string(),
+                    |t| format!("{}.{}.{}", t.shard_num, t.realm_num, t.topic_num),
+                ),
+            )?);
+            let message = String::from_utf8(submit.message.clone())
+                .unwrap_or_else(|_| format!("0x{}", hex::encode(&submit.message)));
+            fields.push(create_text_field("Message", &message)?);
+            if let Some(chunk) = &submit.chunk_info {
+                fields.push(create_text_field(
+                    "Chunk",
+                    &format!("{} of {}", chunk.number, chunk.total),
+                )?);
+            }
+        }
+        Data::ContractCall(call) => fields.extend(contract_call_fields(call, metadata)?),
+    }
+
+    if !body.memo.is_empty() {
+        fields.push(create_text_field("Memo", &body.memo)?);
+    }
+
+    let title = options
+        .transaction_name
+        .unwrap_or_else(|| "Hedera Transaction".to_string());
+
+    Ok(SignablePayload::new(
+        0,
+        title,
+        None,
+        fields
+            .into_iter()
+            .map(|af| af.signable_payload_field)
+            .collect(),
+        "HederaTx".to_string(),
+    ))
+}
+
+// Base units to a decimal string with integer math, trimming trailing zeros so e.g.
+// 150_000_000 tinybars -> "1.5".
+fn format_units(value: u128, decimals: u32) -> String {
+    let digits = value.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return digits;
+    }
+    let padded = format!("{digits:0>width$}", width = decimals + 1);
+    let (whole, frac) = padded.split_at(padded.len() - decimals);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{frac}")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use generated::parser::{Abi, ChainMetadata};
+    use proto::{
+        ConsensusMessageChunkInfo, ConsensusSubmitMessageTransactionBody,
+        CryptoTransferTransactionBody, Duration, NftTransfer, Timestamp,
+        TokenAssociateTransactionBody, TokenTransferList, TopicId, TransferList, UInt32Value,
+    };
+    use visualsign::{SignablePayloadField, SignablePayloadFieldListLayout};
+
+    fn account_id(num: i64) -> Option<AccountId> {
+        Some(AccountId {
+            shard_num: 0,
+            realm_num: 0,
+            account: Some(account_id::Account::AccountNum(num)),
+        })
+    }
+
+    fn token_id(num: i64) -> TokenId {
+        TokenId {
+            shard_num: 0,
+            realm_num: 0,
+            token_num: num,
+        }
+    }
+
+    fn amount(num: i64, amount: i64) -> AccountAmount {
+        AccountAmount {
+            account_id: account_id(num),
+            amount,
+            is_approval: false,
+        }
+    }
+
+    fn body(data: Data) -> TransactionBody {
+        TransactionBody {
+            transaction_id: Some(TransactionId {
+                transaction_valid_start: Some(Timestamp {
+                    seconds: 1_700_000_000,
+                    nanos: 5,
+                }),
+                account_id: account_id(1001),
+                scheduled: false,
+                nonce: 0,
+            }),
+            node_account_id: account_id(3),
+            transaction_fee: 200_000_000,
+            transaction_valid_duration: Some(Duration { seconds: 120 }),
+            memo: String::new(),
+            data: Some(data),
+        }
+    }
+
+    fn signed(body: &TransactionBody) -> Vec<u8> {
+        proto::SignedTransaction {
+            body_bytes: body.encode_to_vec(),
+        }
+        .encode_to_vec()
+    }
+
+    fn to_payload(bytes: &[u8], options: VisualSignOptions) -> SignablePayload {
+        transaction_string_to_visual_sign(&format!("0x{}", hex::encode(bytes)), options).unwrap()
+    }
+
+    fn find_field<'a>(
+        payload: &'a SignablePayload,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        payload.fields.iter().find(|f| field_label(f) == label)
+    }
+
+    fn find_detail<'a>(
+        layout: &'a SignablePayloadFieldListLayout,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        layout
+            .fields
+            .iter()
+            .map(|f| &f.signable_payload_field)
+            .find(|f| field_label(f) == label)
+    }
+
+    fn field_label(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { common, .. }
+            | SignablePayloadField::AmountV2 { common, .. }
+            | SignablePayloadField::AddressV2 { common, .. }
+            | SignablePayloadField::PreviewLayout { common, .. } => &common.label,
+            _ => "",
+        }
+    }
+
+    fn text_value(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { text_v2, .. } => text_v2.text.as_str(),
+            other => panic!("expected TextV2, got {other:?}"),
+        }
+    }
+
+    fn text(payload: &SignablePayload, label: &str) -> String {
+        text_value(find_field(payload, label).unwrap()).to_string()
+    }
+
+    fn amount_value(field: &SignablePayloadField) -> (&str, &str) {
+        match field {
+            SignablePayloadField::AmountV2 { amount_v2, .. } => (
+                amount_v2.amount.as_str(),
+                amount_v2.abbreviation.as_deref().unwrap_or(""),
+            ),
+            _ => panic!("expected AmountV2"),
+        }
+    }
+
+    fn preview_subtitle(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => preview_layout
+                .subtitle
+                .as_ref()
+                .map(|t| t.text.as_str())
+                .unwrap_or(""),
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn preview_expanded(field: &SignablePayloadField) -> &SignablePayloadFieldListLayout {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                preview_layout.expanded.as_ref().unwrap()
+            }
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn hbar_transfer() -> TransactionBody {
+        body(Data::CryptoTransfer(CryptoTransferTransactionBody {
+            transfers: Some(TransferList {
+                account_amounts: vec![amount(1001, -150_000_000), amount(2002, 150_000_000)],
+            }),
+            token_transfers: vec![],
+        }))
+    }
+
+    #[test]
+    fn hbar_transfer_in_every_envelope() {
+        let body = hbar_transfer();
+        let transaction = proto::Transaction {
+            body_bytes: vec![],
+            signed_transaction_bytes: signed(&body),
+        };
+        let legacy = proto::Transaction {
+            body_bytes: body.encode_to_vec(),
+            signed_transaction_bytes: vec![],
+        };
+        for bytes in [
+            transaction.encode_to_vec(),
+            legacy.encode_to_vec(),
+            signed(&body),
+            body.encode_to_vec(),
+        ] {
+            let payload = to_payload(&bytes, VisualSignOptions::default());
+            assert_eq!(text(&payload, "Transaction Type"), "Crypto Transfer");
+            assert_eq!(
+                text(&payload, "Transaction ID"),
+                "0.0.1001@1700000000.000000005"
+            );
+            assert_eq!(text(&payload, "Node"), "0.0.3");
+            assert_eq!(text(&payload, "Valid Duration"), "2 minutes");
+            assert_eq!(
+                amount_value(find_field(&payload, "Max Fee").unwrap()),
+                ("2", "HBAR")
+            );
+            assert_eq!(
+                preview_subtitle(find_field(&payload, "HBAR Transfer").unwrap()),
+                "Send 1.5 HBAR from 0.0.1001 to 0.0.2002"
+            );
+        }
+    }
+
+    #[test]
+    fn token_and_nft_transfers() {
+        let mut approved = amount(1001, -2_500_000);
+        approved.is_approval = true;
+        let payload = to_payload(
+            &signed(&body(Data::CryptoTransfer(CryptoTransferTransactionBody {
+                transfers: None,
+                token_transfers: vec![
+                    TokenTransferList {
+                        token: Some(token_id(456_858)),
+                        transfers: vec![approved, amount(2002, 2_000_000), amount(3003, 500_000)],
+                        nft_transfers: vec![],
+                        expected_decimals: Some(UInt32Value { value: 6 }),
+                    },
+                    TokenTransferList {
+                        token: Some(token_id(777)),
+                        transfers: vec![amount(1001, -10), amount(2002, 10)],
+                        nft_transfers: vec![NftTransfer {
+                            sender_account_id: account_id(1001),
+                            receiver_account_id: account_id(2002),
+                            serial_number: 42,
+                            is_approval: false,
+                        }],
+                        expected_decimals: None,
+                    },
+                ],
+            }))),
+            VisualSignOptions::default(),
+        );
+
+        assert!(find_field(&payload, "HBAR Transfer").is_none());
+        let usdc = find_field(&payload, "Token Transfer (0.0.456858)").unwrap();
+        assert_eq!(
+            preview_subtitle(usdc),
+            "Send 2.5 of token 0.0.456858 from 1 to 2 accounts"
+        );
+        assert_eq!(
+            text_value(find_detail(preview_expanded(usdc), "From").unwrap()),
+            "0.0.1001: 2.5 of token 0.0.456858 (allowance)"
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Token Transfer (0.0.777)").unwrap()),
+            "Send 10 raw units of token 0.0.777 from 0.0.1001 to 0.0.2002"
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "NFT Transfer").unwrap()),
+            "Send 0.0.777 #42 from 0.0.1001 to 0.0.2002"
+        );
+    }
+
+    #[test]
+    fn token_associate_and_consensus_message() {
+        let payload = to_payload(
+            &signed(&body(Data::TokenAssociate(TokenAssociateTransactionBody {
+                account: account_id(1001),
+                tokens: vec![token_id(456_858), token_id(777)],
+            }))),
+            VisualSignOptions::default(),
+        );
+        assert_eq!(text(&payload, "Transaction Type"), "Token Associate");
+        assert_eq!(text(&payload, "Tokens"), "0.0.456858, 0.0.777");
+
+        let mut submit = body(Data::ConsensusSubmitMessage(
+            ConsensusSubmitMessageTransactionBody {
+                topic_id: Some(TopicId {
+                    shard_num: 0,
+                    realm_num: 0,
+                    topic_num: 5005,
+                }),
+                message: b"hello topic".to_vec(),
+                chunk_info: Some(ConsensusMessageChunkInfo {
+                    initial_transaction_id: None,
+                    total: 3,
+                    number: 1,
+                }),
+            },
+        ));
+        submit.memo = "ops note".to_string();
+        let payload = to_payload(&signed(&submit), VisualSignOptions::default());
+        assert_eq!(text(&payload, "Topic"), "0.0.5005");
+        assert_eq!(text(&payload, "Message"), "hello topic");
+        assert_eq!(text(&payload, "Chunk"), "1 of 3");
+        assert_eq!(text(&payload, "Memo"), "ops note");
+    }
+
+    fn contract_call(function_parameters: Vec<u8>) -> Vec<u8> {
+        signed(&body(Data::ContractCall(ContractCallTransactionBody {
+            contract_id: Some(ContractId {
+                shard_num: 0,
+                realm_num: 0,
+                contract: Some(contract_id::Contract::ContractNum(1234)),
+            }),
+            gas: 100_000,
+            amount: 50_000_000,
+            function_parameters,
+        })))
+    }
+
+    #[test]
+    fn contract_call_with_metadata_abi() {
+        // setValue(uint256) with 7
+        let mut calldata = hex::decode("55241077").unwrap();
+        calldata.extend_from_slice(&[0; 31]);
+        calldata.push(7);
+        let abi = r#"[{"type":"function","name":"setValue","inputs":[{"name":"value","type":"uint256"}],"outputs":[],"stateMutability":"nonpayable"}]"#;
+        let options = VisualSignOptions {
+            metadata: Some(ChainMetadata {
+                metadata: Some(chain_metadata::Metadata::Hedera(HederaMetadata {
+                    abi_mappings: [(
+                        "0.0.1234".to_string(),
+                        Abi {
+                            value: abi.to_string(),
+                            ..Default::default()
+                        },
+                    )]
+                    .into(),
+                })),
+            }),
+            ..Default::default()
+        };
+        let payload = to_payload(&contract_call(calldata.clone()), options);
+
+        assert_eq!(
+            text(&payload, "Contract"),
+            "0.0.1234 (0x00000000000000000000000000000000000004d2)"
+        );
+        assert_eq!(text(&payload, "Gas Limit"), "100000");
+        assert_eq!(
+            amount_value(find_field(&payload, "Amount").unwrap()),
+            ("0.5", "HBAR")
+        );
+        assert!(find_field(&payload, "setValue").is_some());
+
+        // Without the ABI the call is shown as raw calldata.
+        let payload = to_payload(&contract_call(calldata), VisualSignOptions::default());
+        assert!(find_field(&payload, "setValue").is_none());
+        assert!(find_field(&payload, "Input Data").is_some());
+    }
+
+    #[test]
+    fn contract_call_falls_back_to_erc20() {
+        // transfer(0x...2002, 1000)
+        let mut calldata = hex::decode("a9059cbb").unwrap();
+        calldata.extend_from_slice(&[0; 30]);
+        calldata.extend_from_slice(&[0x20, 0x02]);
+        calldata.extend_from_slice(&[0; 30]);
+        calldata.extend_from_slice(&1000u16.to_be_bytes());
+        let payload = to_payload(&contract_call(calldata), VisualSignOptions::default());
+
+        assert!(find_field(&payload, "Input Data").is_none());
+        assert!(
+            payload
+                .fields
+                .iter()
+                .any(|f| matches!(f, SignablePayloadField::PreviewLayout { .. }))
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_bytes() {
+        assert!(
+            transaction_string_to_visual_sign("0xdeadbeef", VisualSignOptions::default()).is_err()
+        );
+        // A body without a supported transaction type.
+        let empty = TransactionBody {
+            data: None,
+            ..hbar_transfer()
+        };
+        assert_eq!(
+            decode_body(&empty.encode_to_vec()).unwrap_err(),
+            HederaParserError::UnsupportedTransaction
+        );
+    }
+
+    #[test]
+    fn units_formatting() {
+        assert_eq!(hbar(-1), "-0.00000001");
+        assert_eq!(format_units(150_000_000, 8), "1.5");
+    }
+}
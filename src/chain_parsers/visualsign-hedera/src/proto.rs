@@ -0,0 +1,235 @@
+// The subset of the Hedera API protobufs (hashgraph/hedera-protobufs) this crate reads.
+// Field numbers match the upstream `.proto` files; fields and transaction types not listed
+// here are skipped by prost as unknown fields.
+
+#![allow(clippy::derive_partial_eq_without_eq)]
+
+/// The envelope a wallet submits. Current SDKs fill `signed_transaction_bytes`; the body
+/// bytes at tag 4 are the deprecated pre-`SignedTransaction` layout.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Transaction {
+    #[prost(bytes = "vec", tag = "4")]
+    pub body_bytes: Vec<u8>,
+    #[prost(bytes = "vec", tag = "5")]
+    pub signed_transaction_bytes: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SignedTransaction {
+    #[prost(bytes = "vec", tag = "1")]
+    pub body_bytes: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TransactionBody {
+    #[prost(message, optional, tag = "1")]
+    pub transaction_id: Option<TransactionId>,
+    #[prost(message, optional, tag = "2")]
+    pub node_account_id: Option<AccountId>,
+    #[prost(uint64, tag = "3")]
+    pub transaction_fee: u64,
+    #[prost(message, optional, tag = "4")]
+    pub transaction_valid_duration: Option<Duration>,
+    #[prost(string, tag = "6")]
+    pub memo: String,
+    #[prost(oneof = "transaction_body::Data", tags = "7, 14, 27, 40")]
+    pub data: Option<transaction_body::Data>,
+}
+
+pub mod transaction_body {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Data {
+        #[prost(message, tag = "7")]
+        ContractCall(super::ContractCallTransactionBody),
+        #[prost(message, tag = "14")]
+        CryptoTransfer(super::CryptoTransferTransactionBody),
+        #[prost(message, tag = "27")]
+        ConsensusSubmitMessage(super::ConsensusSubmitMessageTransactionBody),
+        #[prost(message, tag = "40")]
+        TokenAssociate(super::TokenAssociateTransactionBody),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TransactionId {
+    #[prost(message, optional, tag = "1")]
+    pub transaction_valid_start: Option<Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub account_id: Option<AccountId>,
+    #[prost(bool, tag = "3")]
+    pub scheduled: bool,
+    #[prost(int32, tag = "4")]
+    pub nonce: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Timestamp {
+    #[prost(int64, tag = "1")]
+    pub seconds: i64,
+    #[prost(int32, tag = "2")]
+    pub nanos: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Duration {
+    #[prost(int64, tag = "1")]
+    pub seconds: i64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AccountId {
+    #[prost(int64, tag = "1")]
+    pub shard_num: i64,
+    #[prost(int64, tag = "2")]
+    pub realm_num: i64,
+    #[prost(oneof = "account_id::Account", tags = "3, 4")]
+    pub account: Option<account_id::Account>,
+}
+
+pub mod account_id {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Account {
+        #[prost(int64, tag = "3")]
+        AccountNum(i64),
+        /// A public key or EVM address standing in for an account that may not exist yet
+        #[prost(bytes, tag = "4")]
+        Alias(Vec<u8>),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TokenId {
+    #[prost(int64, tag = "1")]
+    pub shard_num: i64,
+    #[prost(int64, tag = "2")]
+    pub realm_num: i64,
+    #[prost(int64, tag = "3")]
+    pub token_num: i64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TopicId {
+    #[prost(int64, tag = "1")]
+    pub shard_num: i64,
+    #[prost(int64, tag = "2")]
+    pub realm_num: i64,
+    #[prost(int64, tag = "3")]
+    pub topic_num: i64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ContractId {
+    #[prost(int64, tag = "1")]
+    pub shard_num: i64,
+    #[prost(int64, tag = "2")]
+    pub realm_num: i64,
+    #[prost(oneof = "contract_id::Contract", tags = "3, 4")]
+    pub contract: Option<contract_id::Contract>,
+}
+
+pub mod contract_id {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Contract {
+        #[prost(int64, tag = "3")]
+        ContractNum(i64),
+        #[prost(bytes, tag = "4")]
+        EvmAddress(Vec<u8>),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CryptoTransferTransactionBody {
+    #[prost(message, optional, tag = "1")]
+    pub transfers: Option<TransferList>,
+    #[prost(message, repeated, tag = "2")]
+    pub token_transfers: Vec<TokenTransferList>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TransferList {
+    #[prost(message, repeated, tag = "1")]
+    pub account_amounts: Vec<AccountAmount>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AccountAmount {
+    #[prost(message, optional, tag = "1")]
+    pub account_id: Option<AccountId>,
+    #[prost(sint64, tag = "2")]
+    pub amount: i64,
+    #[prost(bool, tag = "3")]
+    pub is_approval: bool,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TokenTransferList {
+    #[prost(message, optional, tag = "1")]
+    pub token: Option<TokenId>,
+    #[prost(message, repeated, tag = "2")]
+    pub transfers: Vec<AccountAmount>,
+    #[prost(message, repeated, tag = "3")]
+    pub nft_transfers: Vec<NftTransfer>,
+    #[prost(message, optional, tag = "4")]
+    pub expected_decimals: Option<UInt32Value>,
+}
+
+/// `google.protobuf.UInt32Value`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct UInt32Value {
+    #[prost(uint32, tag = "1")]
+    pub value: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct NftTransfer {
+    #[prost(message, optional, tag = "1")]
+    pub sender_account_id: Option<AccountId>,
+    #[prost(message, optional, tag = "2")]
+    pub receiver_account_id: Option<AccountId>,
+    #[prost(int64, tag = "3")]
+    pub serial_number: i64,
+    #[prost(bool, tag = "4")]
+    pub is_approval: bool,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TokenAssociateTransactionBody {
+    #[prost(message, optional, tag = "1")]
+    pub account: Option<AccountId>,
+    #[prost(message, repeated, tag = "2")]
+    pub tokens: Vec<TokenId>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusSubmitMessageTransactionBody {
+    #[prost(message, optional, tag = "1")]
+    pub topic_id: Option<TopicId>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub message: Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub chunk_info: Option<ConsensusMessageChunkInfo>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ConsensusMessageChunkInfo {
+    #[prost(message, optional, tag = "1")]
+    pub initial_transaction_id: Option<TransactionId>,
+    #[prost(int32, tag = "2")]
+    pub total: i32,
+    #[prost(int32, tag = "3")]
+    pub number: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ContractCallTransactionBody {
+    #[prost(message, optional, tag = "1")]
+    pub contract_id: Option<ContractId>,
+    #[prost(int64, tag = "2")]
+    pub gas: i64,
+    /// Tinybars sent with the call
+    #[prost(int64, tag = "3")]
+    pub amount: i64,
+    /// Solidity calldata: a 4-byte selector followed by ABI-encoded arguments
+    #[prost(bytes = "vec", tag = "4")]
+    pub function_parameters: Vec<u8>,
+}
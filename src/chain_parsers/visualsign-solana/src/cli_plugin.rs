@@ -101,6 +101,9 @@ pub(crate) fn create_chain_metadata(idl_json_mappings: &[String]) -> Option<Chai
             network_id: None,
             idl: None,
             idl_mappings: idl_mappings.into_iter().collect(),
+            address_lookup_tables: Default::default(),
+            markets: Default::default(),
+            tokens: Default::default(),
         })),
     })
 }
@@ -1,8 +1,11 @@
-use crate::core::{InstructionVisualizer, VisualizerContext, visualize_with_any};
+use crate::core::{InstructionVisualizer, MarketSymbols, VisualizerContext, visualize_with_any};
 use crate::idl::IdlRegistry;
+use crate::utils::TokenRegistry;
 use solana_parser::solana::parser::parse_transaction;
 use solana_parser::solana::structs::SolanaAccount;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transaction::Transaction as SolanaTransaction;
+use std::collections::BTreeMap;
 use visualsign::AnnotatedPayloadField;
 use visualsign::errors::{TransactionParseError, VisualSignError};
 #[cfg(feature = "diagnostics")]
@@ -32,6 +35,8 @@ pub struct DecodeInstructionsResult {
 pub fn decode_instructions(
     transaction: &SolanaTransaction,
     idl_registry: &IdlRegistry,
+    markets: &BTreeMap<Pubkey, MarketSymbols>,
+    tokens: &TokenRegistry,
     lint_config: &LintConfig,
 ) -> DecodeInstructionsResult {
     // available_visualizers is generated at build time by build.rs
@@ -81,7 +86,9 @@ pub fn decode_instructions(
             writable: false,
         };
 
-        let context = VisualizerContext::new(&sender, ci, account_keys, idl_registry, i);
+        let context = VisualizerContext::new(&sender, ci, account_keys, idl_registry, i)
+            .with_markets(markets)
+            .with_tokens(tokens);
 
         match visualize_with_any(&visualizers_refs, &context) {
             Some(Ok(viz_result)) => fields.push(viz_result.field),
@@ -111,6 +118,8 @@ pub fn decode_instructions(
 pub fn decode_instructions(
     transaction: &SolanaTransaction,
     idl_registry: &IdlRegistry,
+    markets: &BTreeMap<Pubkey, MarketSymbols>,
+    tokens: &TokenRegistry,
 ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
     let visualizers: Vec<Box<dyn InstructionVisualizer>> = available_visualizers();
     let visualizers_refs: Vec<&dyn InstructionVisualizer> =
@@ -133,7 +142,9 @@ pub fn decode_instructions(
             writable: false,
         };
 
-        let context = VisualizerContext::new(&sender, ci, account_keys, idl_registry, i);
+        let context = VisualizerContext::new(&sender, ci, account_keys, idl_registry, i)
+            .with_markets(markets)
+            .with_tokens(tokens);
 
         match visualize_with_any(&visualizers_refs, &context) {
             Some(Ok(viz_result)) => fields.push(viz_result.field),
@@ -373,7 +384,7 @@ mod off_tests {
     fn test_empty_account_keys_returns_err() {
         let tx = tx_with(vec![], vec![]);
         let registry = IdlRegistry::new();
-        let result = decode_instructions(&tx, &registry);
+        let result = decode_instructions(&tx, &registry, &Default::default(), &Default::default());
         let Err(VisualSignError::DecodeError(msg)) = result else {
             panic!("expected DecodeError, got {result:?}");
         };
@@ -395,7 +406,8 @@ mod off_tests {
             }],
         );
         let registry = IdlRegistry::new();
-        let fields = decode_instructions(&tx, &registry).expect("OOB program_id should not abort");
+        let fields = decode_instructions(&tx, &registry, &Default::default(), &Default::default())
+            .expect("OOB program_id should not abort");
         assert_eq!(fields.len(), 1, "exactly one rendered instruction");
     }
 
@@ -415,8 +427,8 @@ mod off_tests {
             }],
         );
         let registry = IdlRegistry::new();
-        let fields =
-            decode_instructions(&tx, &registry).expect("OOB account_index should not abort");
+        let fields = decode_instructions(&tx, &registry, &Default::default(), &Default::default())
+            .expect("OOB account_index should not abort");
         assert_eq!(fields.len(), 1);
     }
 
@@ -439,7 +451,7 @@ mod off_tests {
             }],
         );
         let registry = IdlRegistry::new();
-        let fields = decode_instructions(&tx, &registry)
+        let fields = decode_instructions(&tx, &registry, &Default::default(), &Default::default())
             .expect("v0+ALT account must not abort an IDL preset");
         assert_eq!(fields.len(), 1, "one field per instruction");
     }
@@ -468,7 +480,7 @@ mod off_tests {
             }],
         );
         let registry = IdlRegistry::new();
-        let fields = decode_instructions(&tx, &registry)
+        let fields = decode_instructions(&tx, &registry, &Default::default(), &Default::default())
             .expect("v0+ALT account must not abort an IDL preset");
         assert_eq!(fields.len(), 1);
 
@@ -563,7 +575,13 @@ mod tests {
         let tx = tx_with_oob_program_id();
         let registry = IdlRegistry::new();
         let config = LintConfig::default();
-        let result = decode_instructions(&tx, &registry, &config);
+        let result = decode_instructions(
+            &tx,
+            &registry,
+            &Default::default(),
+            &Default::default(),
+            &config,
+        );
         let fields = [result.fields, result.diagnostics].concat();
 
         let warns: Vec<_> = fields
@@ -603,7 +621,13 @@ mod tests {
         let tx = tx_with_oob_account_index();
         let registry = IdlRegistry::new();
         let config = LintConfig::default();
-        let result = decode_instructions(&tx, &registry, &config);
+        let result = decode_instructions(
+            &tx,
+            &registry,
+            &Default::default(),
+            &Default::default(),
+            &config,
+        );
         let fields = [result.fields, result.diagnostics].concat();
 
         let warns: Vec<_> = fields
@@ -669,7 +693,13 @@ mod tests {
         };
         let registry = IdlRegistry::new();
         let config = LintConfig::default();
-        let result = decode_instructions(&tx, &registry, &config);
+        let result = decode_instructions(
+            &tx,
+            &registry,
+            &Default::default(),
+            &Default::default(),
+            &config,
+        );
         let fields = [result.fields, result.diagnostics].concat();
 
         let passes: Vec<_> = fields
@@ -737,7 +767,13 @@ mod tests {
         };
         let registry = IdlRegistry::new();
         let config = LintConfig::default();
-        let result = decode_instructions(&tx, &registry, &config);
+        let result = decode_instructions(
+            &tx,
+            &registry,
+            &Default::default(),
+            &Default::default(),
+            &config,
+        );
         let fields = [result.fields, result.diagnostics].concat();
 
         let warns: Vec<_> = fields
@@ -3,10 +3,14 @@ use std::collections::BTreeMap;
 use ::visualsign::AnnotatedPayloadField;
 use ::visualsign::errors::VisualSignError;
 use solana_parser::solana::structs::SolanaAccount;
+use solana_sdk::message::v0::MessageAddressTableLookup;
 use solana_sdk::pubkey::Pubkey;
 
+use crate::utils::{SwapTokenInfo, TokenRegistry};
+
 mod accounts;
 mod instructions;
+mod net_flow;
 mod txtypes;
 mod visualsign;
 
@@ -34,6 +38,18 @@ pub enum VisualizerKind {
     Payments(&'static str),
 }
 
+/// Base and quote token symbols of an order book market, supplied by the
+/// caller through `SolanaMetadata.markets`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarketSymbols {
+    pub base: String,
+    pub quote: String,
+}
+
+static NO_MARKETS: BTreeMap<Pubkey, MarketSymbols> = BTreeMap::new();
+
+static NO_TOKENS: TokenRegistry = TokenRegistry::new();
+
 /// Resolution of a compiled instruction's program_id_index.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProgramRef<'a> {
@@ -73,6 +89,11 @@ pub enum AccountRef<'a> {
 /// `unknown_program` preset is the canonical example: it renders
 /// `unresolved(N)` strings so the user still sees *something* for an
 /// instruction no specific visualizer could handle.
+///
+/// For v0 transactions whose lookup tables were not supplied, the context
+/// also carries the message's `address_table_lookups`, so
+/// [`VisualizerContext::unresolved_account_label`] can name the table and
+/// entry an unresolved index loads from.
 #[derive(Debug, Clone)]
 pub struct VisualizerContext<'a> {
     sender: &'a SolanaAccount,
@@ -87,6 +108,16 @@ pub struct VisualizerContext<'a> {
     /// `SignV1`/`SignV2` payloads) must increment this when building a child
     /// context, so the recursion can be bounded at the trait boundary.
     call_depth: usize,
+    /// Lookups of a v0 message whose loaded addresses are *not* part of
+    /// `account_keys`. Empty for legacy messages and for v0 messages whose
+    /// tables were resolved.
+    address_table_lookups: &'a [MessageAddressTableLookup],
+    /// Caller-supplied market symbols, keyed by market address. Empty unless
+    /// attached with `with_markets`.
+    markets: &'a BTreeMap<Pubkey, MarketSymbols>,
+    /// Caller-supplied token metadata. Empty unless attached with
+    /// `with_tokens`, in which case only the built-in tokens are known.
+    tokens: &'a TokenRegistry,
 }
 
 impl<'a> VisualizerContext<'a> {
@@ -104,9 +135,41 @@ impl<'a> VisualizerContext<'a> {
             idl_registry,
             instruction_index,
             call_depth: 0,
+            address_table_lookups: &[],
+            markets: &NO_MARKETS,
+            tokens: &NO_TOKENS,
         }
     }
 
+    /// Attach the v0 message's address table lookups, for a context whose
+    /// `account_keys` holds only the static keys. Never attach them when
+    /// `account_keys` already includes the loaded addresses: indices past the
+    /// static keys would then be mapped to the wrong table entries.
+    #[must_use]
+    pub fn with_address_table_lookups(
+        mut self,
+        address_table_lookups: &'a [MessageAddressTableLookup],
+    ) -> Self {
+        self.address_table_lookups = address_table_lookups;
+        self
+    }
+
+    /// Attach the caller-supplied market symbols so order book visualizers
+    /// can name the market an instruction trades on.
+    #[must_use]
+    pub fn with_markets(mut self, markets: &'a BTreeMap<Pubkey, MarketSymbols>) -> Self {
+        self.markets = markets;
+        self
+    }
+
+    /// Attach the caller-supplied token metadata so amounts of tokens
+    /// outside the built-in list are scaled by their real decimals.
+    #[must_use]
+    pub fn with_tokens(mut self, tokens: &'a TokenRegistry) -> Self {
+        self.tokens = tokens;
+        self
+    }
+
     /// Set the CPI call depth for this context. Returns the modified context
     /// so it can be chained at construction sites: `VisualizerContext::new(...)
     /// .with_call_depth(parent.call_depth().saturating_add(1))`.
@@ -187,6 +250,59 @@ impl<'a> VisualizerContext<'a> {
         self.account_keys
     }
 
+    /// Address table lookups attached with `with_address_table_lookups`.
+    pub fn address_table_lookups(&self) -> &'a [MessageAddressTableLookup] {
+        self.address_table_lookups
+    }
+
+    /// Symbols of the market at `market`, if the caller supplied them.
+    pub fn market_symbols(&self, market: &Pubkey) -> Option<&'a MarketSymbols> {
+        self.markets.get(market)
+    }
+
+    /// The caller-supplied token metadata attached with `with_tokens`.
+    pub fn tokens(&self) -> &'a TokenRegistry {
+        self.tokens
+    }
+
+    /// Symbol, decimals and scaled `amount` of the token at `mint`, preferring
+    /// caller-supplied metadata over the built-in list.
+    pub fn token_info(&self, mint: &str, amount: u64) -> SwapTokenInfo {
+        self.tokens.token_info(mint, amount)
+    }
+
+    /// Map an index past the static account keys to the lookup table it is
+    /// loaded from and its position in that table. Loaded addresses follow
+    /// the static keys as every table's writable entries, then every table's
+    /// readonly entries.
+    pub fn lookup_table_entry(&self, raw_index: u8) -> Option<(&'a Pubkey, u8)> {
+        let mut offset = usize::from(raw_index).checked_sub(self.account_keys.len())?;
+        for readonly in [false, true] {
+            for lookup in self.address_table_lookups {
+                let indexes = if readonly {
+                    &lookup.readonly_indexes
+                } else {
+                    &lookup.writable_indexes
+                };
+                match indexes.get(offset) {
+                    Some(&entry) => return Some((&lookup.account_key, entry)),
+                    None => offset -= indexes.len(),
+                }
+            }
+        }
+        None
+    }
+
+    /// Display placeholder for an account index that did not resolve:
+    /// `unresolved(N)`, extended with the lookup table and entry when the
+    /// index is loaded through one.
+    pub fn unresolved_account_label(&self, raw_index: u8) -> String {
+        match self.lookup_table_entry(raw_index) {
+            Some((table, entry)) => format!("unresolved({raw_index} via {table}[{entry}])"),
+            None => format!("unresolved({raw_index})"),
+        }
+    }
+
     /// Resolve the program_id, returning Err if the index is out of bounds.
     /// For visualizers that can't proceed without a known program.
     pub fn resolve_program_id(&self) -> Result<Pubkey, VisualSignError> {
@@ -242,7 +358,9 @@ impl InstructionView {
         let accounts = (0..context.num_accounts())
             .map(|i| match context.account(i) {
                 Some(AccountRef::Resolved(pk)) => pk.to_string(),
-                Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+                Some(AccountRef::Unresolved { raw_index }) => {
+                    context.unresolved_account_label(raw_index)
+                }
                 // `i` is in `0..num_accounts()`, so this arm is unreachable in
                 // practice, but we keep a total fallback to preserve infallibility.
                 None => format!("unresolved(oob:{i})"),
@@ -451,4 +569,46 @@ mod tests {
         assert_eq!(view.accounts[1], "unresolved(50)");
         assert_eq!(ctx.data(), &[0xDE, 0xAD]);
     }
+
+    #[test]
+    fn test_lookup_table_entry_follows_v0_loading_order() {
+        let keys = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        let (table_a, table_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let lookups = vec![
+            MessageAddressTableLookup {
+                account_key: table_a,
+                writable_indexes: vec![5],
+                readonly_indexes: vec![7],
+            },
+            MessageAddressTableLookup {
+                account_key: table_b,
+                writable_indexes: vec![3],
+                readonly_indexes: vec![],
+            },
+        ];
+        let ci = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![1, 3, 4],
+            data: vec![],
+        };
+        let sender = SolanaAccount {
+            account_key: keys[0].to_string(),
+            signer: false,
+            writable: false,
+        };
+        let registry = crate::idl::IdlRegistry::new();
+        let ctx = VisualizerContext::new(&sender, &ci, &keys, &registry, 0)
+            .with_address_table_lookups(&lookups);
+
+        assert_eq!(ctx.lookup_table_entry(1), None);
+        assert_eq!(ctx.lookup_table_entry(2), Some((&table_a, 5)));
+        assert_eq!(ctx.lookup_table_entry(3), Some((&table_b, 3)));
+        assert_eq!(ctx.lookup_table_entry(4), Some((&table_a, 7)));
+        assert_eq!(ctx.lookup_table_entry(5), None);
+
+        let view = InstructionView::from_context(&ctx);
+        assert_eq!(view.accounts[0], keys[1].to_string());
+        assert_eq!(view.accounts[1], format!("unresolved(3 via {table_b}[3])"));
+        assert_eq!(view.accounts[2], format!("unresolved(4 via {table_a}[7])"));
+    }
 }
@@ -0,0 +1,490 @@
+//! Net balance changes of the fee payer across a whole transaction
+//!
+//! Sums every System Program and SPL Token / Token-2022 transfer, mint and
+//! burn that moves value into or out of the fee payer, so a transaction made
+//! of many instructions reads as one line (`-1.2 SOL, +150 USDC`). Token
+//! accounts count as the fee payer's when they are its associated token
+//! account or are initialized with it as owner in the same transaction.
+//!
+//! Amounts moved inside other programs (swaps, lending) are settled by CPI at
+//! execution time and are not part of this sum; their presets show them.
+
+use crate::utils::{TokenRegistry, format_token_amount};
+use solana_sdk::instruction::CompiledInstruction;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{BTreeMap, BTreeSet};
+use visualsign::{SignablePayloadField, SignablePayloadFieldCommon, SignablePayloadFieldTextV2};
+
+const SOL_DECIMALS: u8 = 9;
+
+// System Program instruction tags (u32 little-endian)
+const SYSTEM_CREATE_ACCOUNT: u32 = 0;
+const SYSTEM_TRANSFER: u32 = 2;
+const SYSTEM_TRANSFER_WITH_SEED: u32 = 11;
+
+// SPL Token instruction tags, shared by Token-2022
+const TOKEN_INITIALIZE_ACCOUNT: u8 = 1;
+const TOKEN_TRANSFER: u8 = 3;
+const TOKEN_MINT_TO: u8 = 7;
+const TOKEN_BURN: u8 = 8;
+const TOKEN_TRANSFER_CHECKED: u8 = 12;
+const TOKEN_MINT_TO_CHECKED: u8 = 14;
+const TOKEN_BURN_CHECKED: u8 = 15;
+const TOKEN_INITIALIZE_ACCOUNT2: u8 = 16;
+const TOKEN_INITIALIZE_ACCOUNT3: u8 = 18;
+
+/// What a balance change is denominated in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Asset {
+    Sol,
+    Mint(Pubkey),
+    /// A token account whose mint the transaction does not reveal
+    TokenAccount(Pubkey),
+}
+
+/// Net change of one asset, with the decimals if an instruction stated them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NetFlow {
+    pub delta: i128,
+    pub decimals: Option<u8>,
+}
+
+/// A resolved token instruction, in the accounts it moves value between
+struct TokenMovement {
+    /// Token account debited, if any
+    source: Option<Pubkey>,
+    /// Token account credited, if any
+    destination: Option<Pubkey>,
+    authority: Option<Pubkey>,
+    mint: Option<Pubkey>,
+    amount: u64,
+    decimals: Option<u8>,
+}
+
+fn is_token_program(program_id: &Pubkey) -> bool {
+    *program_id == spl_token::id() || *program_id == spl_token_2022::id()
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    let bytes = data.get(offset..offset + 8)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+fn instruction_accounts(
+    instruction: &CompiledInstruction,
+    account_keys: &[Pubkey],
+) -> Option<Vec<Pubkey>> {
+    instruction
+        .accounts
+        .iter()
+        .map(|&index| account_keys.get(usize::from(index)).copied())
+        .collect()
+}
+
+fn associated_token_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &spl_associated_token_account::id(),
+    )
+    .0
+}
+
+fn token_movement(data: &[u8], accounts: &[Pubkey]) -> Option<TokenMovement> {
+    let account = |index: usize| accounts.get(index).copied();
+    let (&tag, _) = data.split_first()?;
+    let amount = read_u64(data, 1);
+    let movement = match tag {
+        TOKEN_TRANSFER => TokenMovement {
+            source: account(0),
+            destination: account(1),
+            authority: account(2),
+            mint: None,
+            amount: amount?,
+            decimals: None,
+        },
+        TOKEN_TRANSFER_CHECKED => TokenMovement {
+            source: account(0),
+            destination: account(2),
+            authority: account(3),
+            mint: account(1),
+            amount: amount?,
+            decimals: data.get(9).copied(),
+        },
+        TOKEN_MINT_TO | TOKEN_MINT_TO_CHECKED => TokenMovement {
+            source: None,
+            destination: account(1),
+            authority: None,
+            mint: account(0),
+            amount: amount?,
+            decimals: (tag == TOKEN_MINT_TO_CHECKED)
+                .then(|| data.get(9).copied())
+                .flatten(),
+        },
+        TOKEN_BURN | TOKEN_BURN_CHECKED => TokenMovement {
+            source: account(0),
+            destination: None,
+            authority: account(2),
+            mint: account(1),
+            amount: amount?,
+            decimals: (tag == TOKEN_BURN_CHECKED)
+                .then(|| data.get(9).copied())
+                .flatten(),
+        },
+        _ => return None,
+    };
+    Some(movement)
+}
+
+/// Token accounts the transaction shows to be the fee payer's, and the mint of
+/// every token account it names one for
+fn index_token_accounts(
+    fee_payer: &Pubkey,
+    account_keys: &[Pubkey],
+    instructions: &[CompiledInstruction],
+) -> (BTreeSet<Pubkey>, BTreeMap<Pubkey, Pubkey>) {
+    let mut owned = BTreeSet::new();
+    let mut mints = BTreeMap::new();
+    for instruction in instructions {
+        let Some(program_id) = account_keys.get(usize::from(instruction.program_id_index)) else {
+            continue;
+        };
+        let Some(accounts) = instruction_accounts(instruction, account_keys) else {
+            continue;
+        };
+        let data = instruction.data.as_slice();
+
+        if *program_id == spl_associated_token_account::id() {
+            // Create / CreateIdempotent: [payer, ata, wallet, mint, system, token program]
+            if let [_, ata, wallet, mint, ..] = accounts.as_slice() {
+                mints.insert(*ata, *mint);
+                if wallet == fee_payer {
+                    owned.insert(*ata);
+                }
+            }
+            continue;
+        }
+        if !is_token_program(program_id) {
+            continue;
+        }
+        match data.first().copied() {
+            Some(TOKEN_INITIALIZE_ACCOUNT) => {
+                if let [account, mint, owner, ..] = accounts.as_slice() {
+                    mints.insert(*account, *mint);
+                    if owner == fee_payer {
+                        owned.insert(*account);
+                    }
+                }
+            }
+            Some(TOKEN_INITIALIZE_ACCOUNT2 | TOKEN_INITIALIZE_ACCOUNT3) => {
+                if let [account, mint, ..] = accounts.as_slice() {
+                    mints.insert(*account, *mint);
+                    if data.get(1..33) == Some(fee_payer.as_ref()) {
+                        owned.insert(*account);
+                    }
+                }
+            }
+            _ => {
+                if let Some(TokenMovement {
+                    source,
+                    destination,
+                    mint: Some(mint),
+                    ..
+                }) = token_movement(data, &accounts)
+                {
+                    for account in [source, destination].into_iter().flatten() {
+                        mints.insert(account, mint);
+                    }
+                }
+            }
+        }
+    }
+
+    // The fee payer's associated token account for every mint seen
+    let derived: Vec<Pubkey> = mints
+        .values()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .flat_map(|mint| {
+            [spl_token::id(), spl_token_2022::id()]
+                .map(|token_program| associated_token_address(fee_payer, mint, &token_program))
+        })
+        .collect();
+    owned.extend(derived);
+    (owned, mints)
+}
+
+/// A change to one of the fee payer's balances made by a single instruction
+type Change = (Asset, i128, Option<u8>);
+
+fn system_changes(fee_payer: &Pubkey, data: &[u8], accounts: &[Pubkey]) -> Vec<Change> {
+    let Some(tag) = data.get(..4).and_then(|tag| tag.try_into().ok()) else {
+        return Vec::new();
+    };
+    // (from, to) positions in the account list
+    let (from, to) = match u32::from_le_bytes(tag) {
+        SYSTEM_CREATE_ACCOUNT | SYSTEM_TRANSFER => (0, 1),
+        SYSTEM_TRANSFER_WITH_SEED => (0, 2),
+        _ => return Vec::new(),
+    };
+    let Some(lamports) = read_u64(data, 4).map(i128::from) else {
+        return Vec::new();
+    };
+    let mut changes = Vec::new();
+    if accounts.get(from) == Some(fee_payer) {
+        changes.push((Asset::Sol, -lamports, Some(SOL_DECIMALS)));
+    }
+    if accounts.get(to) == Some(fee_payer) {
+        changes.push((Asset::Sol, lamports, Some(SOL_DECIMALS)));
+    }
+    changes
+}
+
+fn token_changes(
+    fee_payer: &Pubkey,
+    owned: &BTreeSet<Pubkey>,
+    mints: &BTreeMap<Pubkey, Pubkey>,
+    data: &[u8],
+    accounts: &[Pubkey],
+) -> Vec<Change> {
+    let Some(movement) = token_movement(data, accounts) else {
+        return Vec::new();
+    };
+    let amount = i128::from(movement.amount);
+    let asset_of = |account: Pubkey| match movement.mint.or_else(|| mints.get(&account).copied()) {
+        Some(mint) => Asset::Mint(mint),
+        None => Asset::TokenAccount(account),
+    };
+    let mut changes = Vec::new();
+    // Debited when the fee payer signs for the source or owns it
+    if let Some(source) = movement
+        .source
+        .filter(|source| movement.authority.as_ref() == Some(fee_payer) || owned.contains(source))
+    {
+        changes.push((asset_of(source), -amount, movement.decimals));
+    }
+    if let Some(destination) = movement
+        .destination
+        .filter(|destination| owned.contains(destination))
+    {
+        changes.push((asset_of(destination), amount, movement.decimals));
+    }
+    changes
+}
+
+/// The fee payer's net balance changes across a transaction
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetFlows {
+    /// Net change per asset; assets that net to zero are left out
+    pub flows: BTreeMap<Asset, NetFlow>,
+    /// Instructions that moved value into or out of the fee payer
+    pub instruction_count: usize,
+}
+
+/// Net change per asset for the fee payer (`account_keys[0]`). Instructions
+/// whose accounts do not all resolve are skipped.
+pub fn compute_net_flows(
+    account_keys: &[Pubkey],
+    instructions: &[CompiledInstruction],
+) -> NetFlows {
+    let mut net_flows = NetFlows::default();
+    let Some(fee_payer) = account_keys.first() else {
+        return net_flows;
+    };
+    let (owned, mints) = index_token_accounts(fee_payer, account_keys, instructions);
+
+    for instruction in instructions {
+        let Some(program_id) = account_keys.get(usize::from(instruction.program_id_index)) else {
+            continue;
+        };
+        let Some(accounts) = instruction_accounts(instruction, account_keys) else {
+            continue;
+        };
+        let data = instruction.data.as_slice();
+        let changes = if *program_id == solana_sdk::system_program::ID {
+            system_changes(fee_payer, data, &accounts)
+        } else if is_token_program(program_id) {
+            token_changes(fee_payer, &owned, &mints, data, &accounts)
+        } else {
+            continue;
+        };
+
+        if !changes.is_empty() {
+            net_flows.instruction_count += 1;
+        }
+        for (asset, delta, decimals) in changes {
+            let flow = net_flows.flows.entry(asset).or_default();
+            flow.delta = flow.delta.saturating_add(delta);
+            flow.decimals = flow.decimals.or(decimals);
+        }
+    }
+
+    net_flows.flows.retain(|_, flow| flow.delta != 0);
+    net_flows
+}
+
+fn format_flow(asset: &Asset, flow: &NetFlow, tokens: &TokenRegistry) -> String {
+    let sign = if flow.delta < 0 { "-" } else { "+" };
+    let magnitude = u64::try_from(flow.delta.unsigned_abs()).unwrap_or(u64::MAX);
+    match asset {
+        Asset::Sol => format!("{sign}{} SOL", format_token_amount(magnitude, SOL_DECIMALS)),
+        Asset::Mint(mint) => {
+            let info = tokens.token_info(&mint.to_string(), magnitude);
+            // Decimals checked by the token program win over lookup tables
+            let amount = match flow.decimals {
+                Some(decimals) => format_token_amount(magnitude, decimals),
+                None => info.human_readable_amount,
+            };
+            format!("{sign}{amount} {}", info.symbol)
+        }
+        Asset::TokenAccount(account) => format!("{sign}{magnitude} (token account {account})"),
+    }
+}
+
+/// One-line summary of the fee payer's net balance changes. `None` unless at
+/// least two instructions move value, since a single instruction already
+/// shows its own amount.
+pub fn create_net_flow_field(
+    account_keys: &[Pubkey],
+    instructions: &[CompiledInstruction],
+    tokens: &TokenRegistry,
+) -> Option<SignablePayloadField> {
+    let net_flows = compute_net_flows(account_keys, instructions);
+    if net_flows.instruction_count < 2 || net_flows.flows.is_empty() {
+        return None;
+    }
+    let text = net_flows
+        .flows
+        .iter()
+        .map(|(asset, flow)| format_flow(asset, flow, tokens))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(SignablePayloadField::TextV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: text.clone(),
+            label: "Net Token Flow".to_string(),
+        },
+        text_v2: SignablePayloadFieldTextV2 { text },
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    /// System Program transfer, with the program at account index 1
+    fn system_transfer(from: u8, to: u8, lamports: u64) -> CompiledInstruction {
+        let mut data = SYSTEM_TRANSFER.to_le_bytes().to_vec();
+        data.extend_from_slice(&lamports.to_le_bytes());
+        CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![from, to],
+            data,
+        }
+    }
+
+    fn transfer_checked(
+        program_id_index: u8,
+        accounts: Vec<u8>,
+        amount: u64,
+        decimals: u8,
+    ) -> CompiledInstruction {
+        let mut data = vec![TOKEN_TRANSFER_CHECKED];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.push(decimals);
+        CompiledInstruction {
+            program_id_index,
+            accounts,
+            data,
+        }
+    }
+
+    #[test]
+    fn test_sums_sol_and_token_flows() {
+        let fee_payer = Pubkey::new_unique();
+        let counterparty = Pubkey::new_unique();
+        let usdc = Pubkey::from_str(USDC).unwrap();
+        let payer_ata = associated_token_address(&fee_payer, &usdc, &spl_token::id());
+        let counterparty_ata = Pubkey::new_unique();
+        let account_keys = vec![
+            fee_payer,
+            solana_sdk::system_program::ID,
+            counterparty,
+            spl_token::id(),
+            payer_ata,
+            usdc,
+            counterparty_ata,
+        ];
+        let instructions = vec![
+            system_transfer(0, 2, 1_000_000_000),
+            system_transfer(0, 2, 200_000_000),
+            // Counterparty pays the fee payer 150 USDC
+            transfer_checked(3, vec![6, 5, 4, 2], 150_000_000, 6),
+        ];
+
+        let field =
+            create_net_flow_field(&account_keys, &instructions, &TokenRegistry::new()).unwrap();
+        assert_eq!(field.fallback_text(), "-1.2 SOL, +150 USDC");
+    }
+
+    #[test]
+    fn test_outgoing_transfer_without_mint_uses_initialized_account() {
+        let fee_payer = Pubkey::new_unique();
+        let token_account = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let account_keys = vec![
+            fee_payer,
+            spl_token::id(),
+            token_account,
+            mint,
+            Pubkey::new_unique(),
+        ];
+
+        let mut initialize = vec![TOKEN_INITIALIZE_ACCOUNT3];
+        initialize.extend_from_slice(fee_payer.as_ref());
+        let mut transfer = vec![TOKEN_TRANSFER];
+        transfer.extend_from_slice(&500u64.to_le_bytes());
+        let instructions = vec![
+            CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![2, 3],
+                data: initialize,
+            },
+            CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![2, 4, 0],
+                data: transfer,
+            },
+        ];
+
+        let net_flows = compute_net_flows(&account_keys, &instructions);
+        assert_eq!(net_flows.instruction_count, 1);
+        assert_eq!(net_flows.flows[&Asset::Mint(mint)].delta, -500);
+    }
+
+    #[test]
+    fn test_no_field_without_aggregate() {
+        let account_keys = vec![
+            Pubkey::new_unique(),
+            solana_sdk::system_program::ID,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let transfer = system_transfer(2, 3, 5);
+        assert!(create_net_flow_field(&account_keys, &[transfer], &TokenRegistry::new()).is_none());
+
+        // A lone transfer already shows its own amount
+        let transfer = system_transfer(0, 3, 5);
+        assert!(create_net_flow_field(&account_keys, &[transfer], &TokenRegistry::new()).is_none());
+
+        // Out of range account indexes are skipped, not guessed
+        let unresolved = system_transfer(0, 9, 5);
+        assert_eq!(
+            compute_net_flows(&account_keys, &[unresolved]),
+            NetFlows::default()
+        );
+    }
+}
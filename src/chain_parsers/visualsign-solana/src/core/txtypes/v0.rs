@@ -1,10 +1,13 @@
 #[cfg(feature = "diagnostics")]
 use crate::core::DecodeInstructionsResult;
 use crate::core::{
-    InstructionVisualizer, SolanaAccount, VisualizerContext, available_visualizers,
+    InstructionVisualizer, MarketSymbols, SolanaAccount, VisualizerContext, available_visualizers,
     visualize_with_any,
 };
+use crate::utils::TokenRegistry;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transaction::VersionedTransaction;
+use std::collections::BTreeMap;
 #[cfg(feature = "diagnostics")]
 use visualsign::field_builders::create_diagnostic_field;
 use visualsign::{
@@ -20,7 +23,6 @@ pub fn decode_v0_transfers(
 ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
     use crate::presets::jupiter_swap::{JUPITER_IDL_JSON, JUPITER_PROGRAM_ID};
     use solana_parser::solana::parser::parse_transaction;
-    use std::collections::BTreeMap;
 
     // Serialize the full versioned transaction
     let transaction_bytes = bincode::serialize(versioned_tx).map_err(|e| {
@@ -132,20 +134,23 @@ pub fn decode_v0_transfers(
 
 /// Decode V0 transaction instructions using the visualizer framework.
 /// This works for all V0 transactions, including those with lookup tables.
+/// `account_keys` is either the message's static keys or, when the lookup
+/// tables were supplied, the full list from [`resolve_v0_account_keys`].
 /// Always succeeds -- data quality issues become diagnostics, per-instruction
 /// failures are collected in errors.
 #[cfg(feature = "diagnostics")]
 pub fn decode_v0_instructions(
     v0_message: &solana_sdk::message::v0::Message,
+    account_keys: &[Pubkey],
     idl_registry: &crate::idl::IdlRegistry,
+    markets: &BTreeMap<Pubkey, MarketSymbols>,
+    tokens: &TokenRegistry,
     lint_config: &visualsign::lint::LintConfig,
 ) -> DecodeInstructionsResult {
     let visualizers: Vec<Box<dyn InstructionVisualizer>> = available_visualizers();
     let visualizers_refs: Vec<&dyn InstructionVisualizer> =
         visualizers.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
 
-    let account_keys = &v0_message.account_keys;
-
     if account_keys.is_empty() {
         let severity = lint_config.severity_for(
             "transaction::empty_account_keys",
@@ -177,6 +182,14 @@ pub fn decode_v0_instructions(
         lint_config,
     );
 
+    // Table lookups only describe placeholders while the loaded addresses are
+    // missing from `account_keys`; once resolved, every valid index is a key.
+    let unresolved_lookups = if account_keys.len() == v0_message.account_keys.len() {
+        v0_message.address_table_lookups.as_slice()
+    } else {
+        &[]
+    };
+
     // Visualization: process every instruction (no skipping)
     let mut fields: Vec<AnnotatedPayloadField> = Vec::new();
     let mut errors: Vec<(usize, VisualSignError)> = Vec::new();
@@ -188,7 +201,10 @@ pub fn decode_v0_instructions(
             writable: false,
         };
 
-        let context = VisualizerContext::new(&sender, ci, account_keys, idl_registry, i);
+        let context = VisualizerContext::new(&sender, ci, account_keys, idl_registry, i)
+            .with_address_table_lookups(unresolved_lookups)
+            .with_markets(markets)
+            .with_tokens(tokens);
 
         match visualize_with_any(&visualizers_refs, &context) {
             Some(Ok(viz_result)) => fields.push(viz_result.field),
@@ -215,20 +231,29 @@ pub fn decode_v0_instructions(
 #[cfg(not(feature = "diagnostics"))]
 pub fn decode_v0_instructions(
     v0_message: &solana_sdk::message::v0::Message,
+    account_keys: &[Pubkey],
     idl_registry: &crate::idl::IdlRegistry,
+    markets: &BTreeMap<Pubkey, MarketSymbols>,
+    tokens: &TokenRegistry,
 ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
     let visualizers: Vec<Box<dyn InstructionVisualizer>> = available_visualizers();
     let visualizers_refs: Vec<&dyn InstructionVisualizer> =
         visualizers.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
 
-    let account_keys = &v0_message.account_keys;
-
     if account_keys.is_empty() {
         return Err(VisualSignError::DecodeError(
             "v0 transaction has no account keys".to_string(),
         ));
     }
 
+    // Table lookups only describe placeholders while the loaded addresses are
+    // missing from `account_keys`; once resolved, every valid index is a key.
+    let unresolved_lookups = if account_keys.len() == v0_message.account_keys.len() {
+        v0_message.address_table_lookups.as_slice()
+    } else {
+        &[]
+    };
+
     let mut fields: Vec<AnnotatedPayloadField> = Vec::new();
     for (i, ci) in v0_message.instructions.iter().enumerate() {
         let sender = SolanaAccount {
@@ -237,7 +262,10 @@ pub fn decode_v0_instructions(
             writable: false,
         };
 
-        let context = VisualizerContext::new(&sender, ci, account_keys, idl_registry, i);
+        let context = VisualizerContext::new(&sender, ci, account_keys, idl_registry, i)
+            .with_address_table_lookups(unresolved_lookups)
+            .with_markets(markets)
+            .with_tokens(tokens);
 
         match visualize_with_any(&visualizers_refs, &context) {
             Some(Ok(viz_result)) => fields.push(viz_result.field),
@@ -257,6 +285,55 @@ pub fn decode_v0_instructions(
     Ok(fields)
 }
 
+/// Build the full account key list of a V0 message from caller-supplied lookup
+/// table contents: the static keys, then every table's writable addresses, then
+/// every table's readonly addresses, matching the runtime's loading order.
+///
+/// Returns `None` when a referenced table was not supplied or an index falls
+/// outside the supplied table; callers then keep the static keys so the
+/// ALT-backed accounts render as placeholders rather than wrong addresses.
+pub fn resolve_v0_account_keys(
+    v0_message: &solana_sdk::message::v0::Message,
+    lookup_tables: &BTreeMap<Pubkey, Vec<Pubkey>>,
+) -> Option<Vec<Pubkey>> {
+    if v0_message.address_table_lookups.is_empty() {
+        return None;
+    }
+
+    let mut writable = Vec::new();
+    let mut readonly = Vec::new();
+    for lookup in &v0_message.address_table_lookups {
+        let Some(table) = lookup_tables.get(&lookup.account_key) else {
+            tracing::debug!(
+                "Address lookup table {} not supplied; leaving its accounts unresolved",
+                lookup.account_key
+            );
+            return None;
+        };
+        for (indexes, out) in [
+            (&lookup.writable_indexes, &mut writable),
+            (&lookup.readonly_indexes, &mut readonly),
+        ] {
+            for &index in indexes {
+                let Some(address) = table.get(usize::from(index)) else {
+                    tracing::warn!(
+                        "Address lookup table {} has {} entries but index {index} was requested; leaving its accounts unresolved",
+                        lookup.account_key,
+                        table.len()
+                    );
+                    return None;
+                };
+                out.push(*address);
+            }
+        }
+    }
+
+    let mut account_keys = v0_message.account_keys.clone();
+    account_keys.extend(writable);
+    account_keys.extend(readonly);
+    Some(account_keys)
+}
+
 /// Create a rich address lookup table field with detailed information
 /// Reuses the advanced preview layout pattern to avoid top-level ListLayout restriction
 pub fn create_address_lookup_table_field(
@@ -451,7 +528,13 @@ mod off_tests {
     fn test_empty_account_keys_returns_err() {
         let msg = v0_message(vec![], vec![]);
         let registry = crate::idl::IdlRegistry::new();
-        let result = decode_v0_instructions(&msg, &registry);
+        let result = decode_v0_instructions(
+            &msg,
+            &msg.account_keys,
+            &registry,
+            &Default::default(),
+            &Default::default(),
+        );
         let Err(VisualSignError::DecodeError(text)) = result else {
             panic!("expected DecodeError, got {result:?}");
         };
@@ -470,7 +553,14 @@ mod off_tests {
             }],
         );
         let registry = crate::idl::IdlRegistry::new();
-        let fields = decode_v0_instructions(&msg, &registry).expect("OOB should not abort");
+        let fields = decode_v0_instructions(
+            &msg,
+            &msg.account_keys,
+            &registry,
+            &Default::default(),
+            &Default::default(),
+        )
+        .expect("OOB should not abort");
         assert_eq!(fields.len(), 1);
     }
 }
@@ -535,7 +625,14 @@ mod tests {
         let msg = v0_message_with_oob_program_id();
         let registry = crate::idl::IdlRegistry::new();
         let config = LintConfig::default();
-        let result = decode_v0_instructions(&msg, &registry, &config);
+        let result = decode_v0_instructions(
+            &msg,
+            &msg.account_keys,
+            &registry,
+            &Default::default(),
+            &Default::default(),
+            &config,
+        );
         let fields = [result.fields, result.diagnostics].concat();
 
         let warns: Vec<_> = fields
@@ -574,7 +671,14 @@ mod tests {
         let msg = v0_message_with_oob_program_id_and_oob_account();
         let registry = crate::idl::IdlRegistry::new();
         let config = LintConfig::default();
-        let result = decode_v0_instructions(&msg, &registry, &config);
+        let result = decode_v0_instructions(
+            &msg,
+            &msg.account_keys,
+            &registry,
+            &Default::default(),
+            &Default::default(),
+            &config,
+        );
         let fields = [result.fields, result.diagnostics].concat();
 
         let warns: Vec<_> = fields
@@ -640,7 +744,14 @@ mod tests {
         };
         let registry = crate::idl::IdlRegistry::new();
         let config = LintConfig::default();
-        let result = decode_v0_instructions(&msg, &registry, &config);
+        let result = decode_v0_instructions(
+            &msg,
+            &msg.account_keys,
+            &registry,
+            &Default::default(),
+            &Default::default(),
+            &config,
+        );
         let fields = [result.fields, result.diagnostics].concat();
 
         let passes: Vec<_> = fields
@@ -1,8 +1,11 @@
+use crate::core::net_flow::create_net_flow_field;
 use crate::core::txtypes::{
     create_address_lookup_table_field, decode_v0_instructions, decode_v0_transfers,
+    resolve_v0_account_keys,
 };
 use crate::core::{
-    create_accounts_advanced_preview_layout, decode_accounts, decode_v0_accounts, instructions,
+    MarketSymbols, create_accounts_advanced_preview_layout, decode_accounts, decode_v0_accounts,
+    instructions,
 };
 use crate::idl::IdlRegistry;
 use crate::idl::builtin_programs::{
@@ -11,10 +14,14 @@ use crate::idl::builtin_programs::{
 use crate::idl::signature::{
     authorized_idl_signers, convert_proto_signature, validate_idl_signature,
 };
+use crate::offchain_message::{is_offchain_message, offchain_message_to_visual_sign};
+use crate::presets::compute_budget::create_priority_fee_field;
+use crate::utils::{RegisteredToken, TokenRegistry, escape_untrusted_text};
 use base64::{self, Engine};
 use solana_sdk::{
-    message::VersionedMessage,
+    message::{MessageHeader, VersionedMessage},
     pubkey::Pubkey,
+    signature::Signature,
     transaction::{Transaction as SolanaTransaction, VersionedTransaction},
 };
 use std::collections::BTreeMap;
@@ -38,6 +45,13 @@ use crate::intermediate::extract_solana_intermediate_output;
 /// and are deserialized on the hot path, so we apply a tighter bound here.
 const MAX_IDL_JSON_BYTES: usize = 1_024 * 1_024;
 
+/// Maximum number of IDL mappings honoured per request.
+///
+/// Each accepted mapping is decoded and indexed when the registry is built,
+/// so the size cap alone still lets a request carry hundreds of megabytes of
+/// IDLs. Mappings beyond the cap are dropped in program-id order.
+const MAX_IDL_MAPPINGS: usize = 32;
+
 /// Append decode errors as diagnostics and lint diagnostics to the output fields.
 /// decode::visualizer_error is intentionally not routed through LintConfig --
 /// visualizer failures are always surfaced so consumers know which
@@ -131,6 +145,115 @@ impl SolanaTransactionWrapper {
             Self::Versioned(tx) => Some(tx),
         }
     }
+
+    /// Signature slots, one per required signer; unsigned slots are all zeros
+    pub fn signatures(&self) -> &[Signature] {
+        match self {
+            Self::Legacy(tx) => &tx.signatures,
+            Self::Versioned(tx) => &tx.signatures,
+        }
+    }
+
+    /// Whether any required signer has already signed
+    pub fn is_signed(&self) -> bool {
+        has_signature(self.signatures())
+    }
+}
+
+fn has_signature(signatures: &[Signature]) -> bool {
+    signatures
+        .iter()
+        .any(|signature| *signature != Signature::default())
+}
+
+fn text_field(label: &str, text: String) -> SignablePayloadField {
+    SignablePayloadField::TextV2 {
+        common: SignablePayloadFieldCommon {
+            fallback_text: text.clone(),
+            label: label.to_string(),
+        },
+        text_v2: visualsign::SignablePayloadFieldTextV2 { text },
+    }
+}
+
+/// Who pays for and who must sign the transaction. The fee payer is always
+/// the first account key; the header orders the required signers first,
+/// writable ones ahead of read-only ones.
+fn create_signer_fields(
+    account_keys: &[Pubkey],
+    header: &MessageHeader,
+) -> Vec<SignablePayloadField> {
+    let Some(fee_payer) = account_keys.first() else {
+        return Vec::new();
+    };
+    let writable_signers = account_keys
+        .iter()
+        .take(usize::from(
+            header
+                .num_required_signatures
+                .saturating_sub(header.num_readonly_signed_accounts),
+        ))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    vec![
+        text_field("Fee Payer", fee_payer.to_string()),
+        text_field(
+            "Required Signatures",
+            header.num_required_signatures.to_string(),
+        ),
+        text_field("Writable Signers", writable_signers.join(", ")),
+    ]
+}
+
+/// Which required signers have already signed. Signed transactions are always
+/// accepted; these fields are only added when the caller opts in through
+/// `allow_signed_transactions` and at least one signature is present.
+/// Signers are the first `num_required_signatures` static account keys, in
+/// the same order as the signature slots.
+fn create_signature_status_fields(
+    signatures: &[Signature],
+    account_keys: &[Pubkey],
+    num_required_signatures: u8,
+    options: &VisualSignOptions,
+) -> Vec<SignablePayloadField> {
+    let allow_signed = options
+        .developer_config
+        .as_ref()
+        .is_some_and(|c| c.allow_signed_transactions);
+    if !allow_signed || !has_signature(signatures) {
+        return Vec::new();
+    }
+
+    let signers = account_keys
+        .iter()
+        .take(usize::from(num_required_signatures))
+        .enumerate()
+        .map(|(index, signer)| {
+            let signed = signatures
+                .get(index)
+                .is_some_and(|signature| *signature != Signature::default());
+            (signer, signed)
+        })
+        .collect::<Vec<_>>();
+    let signed_count = signers.iter().filter(|(_, signed)| *signed).count();
+
+    let mut fields = vec![text_field(
+        "Signatures",
+        format!(
+            "{signed_count} of {} required signers signed",
+            signers.len()
+        ),
+    )];
+    for (signer, signed) in signers {
+        let label = if signed {
+            "Signed By"
+        } else {
+            "Awaiting Signature"
+        };
+        fields.push(text_field(label, signer.to_string()));
+    }
+    fields
 }
 
 /// Extract IDL mappings from VisualSignOptions metadata.
@@ -153,7 +276,9 @@ impl SolanaTransactionWrapper {
 ///    System Program could relabel `lamports` or hide the destination via
 ///    the `unknown_program` IDL decode path. Refusing the body closes that
 ///    gap.
-/// 3. The IDL JSON is rejected if it exceeds `MAX_IDL_JSON_BYTES`.
+/// 3. The IDL JSON is rejected if it exceeds `MAX_IDL_JSON_BYTES`, and at
+///    most `MAX_IDL_MAPPINGS` mappings are considered per request (the first
+///    ones by program id, so the choice does not depend on map order).
 /// 4. If `Idl.signature` is present, it must verify (ed25519 over the shared
 ///    domain-separated prehash that binds the program id to the IDL JSON, via
 ///    `verify_strict`) AND the signer must appear in the authorized-signer
@@ -212,8 +337,20 @@ fn extract_idl_mappings_with_signers(
         return BTreeMap::new();
     };
 
+    // The proto field is a hash map; sort before applying the count cap so
+    // the same request always keeps the same mappings.
+    let mut sorted: Vec<_> = mappings.iter().collect();
+    sorted.sort_unstable_by_key(|(program_id, _)| *program_id);
+    if sorted.len() > MAX_IDL_MAPPINGS {
+        tracing::warn!(
+            "Ignoring {} IDL mappings beyond the per-request limit of {MAX_IDL_MAPPINGS}",
+            sorted.len() - MAX_IDL_MAPPINGS
+        );
+        sorted.truncate(MAX_IDL_MAPPINGS);
+    }
+
     let mut out: BTreeMap<String, (String, String)> = BTreeMap::new();
-    for (program_id, idl) in mappings {
+    for (program_id, idl) in sorted {
         // 1. Validate program_id parses as a Solana Pubkey (cheap, fail fast).
         //    Keep the parsed key: its 32 bytes bind the IDL signature prehash
         //    in step 4.
@@ -292,6 +429,50 @@ fn extract_idl_mappings_with_signers(
     out
 }
 
+/// Extract caller-supplied address lookup table contents from the options.
+///
+/// A table whose own address or any entry fails to parse is dropped whole:
+/// skipping a single entry would shift every later index and resolve lookups
+/// to the wrong accounts.
+fn extract_address_lookup_tables(options: &VisualSignOptions) -> BTreeMap<Pubkey, Vec<Pubkey>> {
+    let Some(tables) = options
+        .metadata
+        .as_ref()
+        .and_then(|meta| meta.metadata.as_ref())
+        .and_then(|m| {
+            if let generated::parser::chain_metadata::Metadata::Solana(solana_meta) = m {
+                Some(&solana_meta.address_lookup_tables)
+            } else {
+                None
+            }
+        })
+    else {
+        return BTreeMap::new();
+    };
+
+    let mut out = BTreeMap::new();
+    for (table_address, table) in tables {
+        let Ok(table_key) = Pubkey::from_str(table_address) else {
+            tracing::warn!("Skipping address lookup table with invalid address '{table_address}'");
+            continue;
+        };
+        let addresses: Result<Vec<Pubkey>, _> = table
+            .addresses
+            .iter()
+            .map(|address| Pubkey::from_str(address))
+            .collect();
+        match addresses {
+            Ok(addresses) => {
+                out.insert(table_key, addresses);
+            }
+            Err(e) => tracing::warn!(
+                "Skipping address lookup table '{table_address}': invalid entry: {e}"
+            ),
+        }
+    }
+    out
+}
+
 /// Extract the program name from an IDL JSON string
 fn extract_name_from_idl_json(idl_json: &str) -> Option<String> {
     let value: serde_json::Value = serde_json::from_str(idl_json).ok()?;
@@ -307,6 +488,93 @@ fn extract_name_from_idl_json(idl_json: &str) -> Option<String> {
     value.get("name").and_then(|n| n.as_str()).map(String::from)
 }
 
+/// Extract caller-supplied order book market symbols from the options.
+///
+/// Markets with an invalid address are skipped. Symbols come from the caller,
+/// not the chain, so they are escaped before they reach the payload.
+fn extract_markets(options: &VisualSignOptions) -> BTreeMap<Pubkey, MarketSymbols> {
+    let Some(markets) = options
+        .metadata
+        .as_ref()
+        .and_then(|meta| meta.metadata.as_ref())
+        .and_then(|m| {
+            if let generated::parser::chain_metadata::Metadata::Solana(solana_meta) = m {
+                Some(&solana_meta.markets)
+            } else {
+                None
+            }
+        })
+    else {
+        return BTreeMap::new();
+    };
+
+    let mut out = BTreeMap::new();
+    for (market_address, market) in markets {
+        let Ok(market_key) = Pubkey::from_str(market_address) else {
+            tracing::warn!("Skipping market with invalid address '{market_address}'");
+            continue;
+        };
+        out.insert(
+            market_key,
+            MarketSymbols {
+                base: escape_untrusted_text(&market.base_symbol),
+                quote: escape_untrusted_text(&market.quote_symbol),
+            },
+        );
+    }
+    out
+}
+
+/// Extract caller-supplied token metadata from the options.
+///
+/// Tokens with an invalid mint address or more decimals than a `u8` holds are
+/// skipped. Symbols and names are escaped like the market symbols above.
+/// Under `options.strict_registry` the caller's tokens are ignored entirely, so
+/// only the built-in token list can name an amount.
+fn extract_tokens(options: &VisualSignOptions) -> TokenRegistry {
+    if options.strict_registry {
+        return TokenRegistry::new();
+    }
+    let Some(tokens) = options
+        .metadata
+        .as_ref()
+        .and_then(|meta| meta.metadata.as_ref())
+        .and_then(|m| {
+            if let generated::parser::chain_metadata::Metadata::Solana(solana_meta) = m {
+                Some(&solana_meta.tokens)
+            } else {
+                None
+            }
+        })
+    else {
+        return TokenRegistry::new();
+    };
+
+    let mut registry = TokenRegistry::new();
+    for (mint, token) in tokens {
+        if Pubkey::from_str(mint).is_err() {
+            tracing::warn!("Skipping token with invalid mint '{mint}'");
+            continue;
+        }
+        let Ok(decimals) = u8::try_from(token.decimals) else {
+            tracing::warn!(
+                "Skipping token '{mint}' with out-of-range decimals {}",
+                token.decimals
+            );
+            continue;
+        };
+        registry.insert(
+            mint.clone(),
+            RegisteredToken {
+                symbol: escape_untrusted_text(&token.symbol),
+                name: escape_untrusted_text(&token.name),
+                decimals,
+            },
+        );
+    }
+    registry
+}
+
 /// Create an IDL registry from VisualSignOptions metadata
 fn create_idl_registry_from_options(
     options: &VisualSignOptions,
@@ -438,7 +706,44 @@ fn build_intermediate_bytes(
     }
 }
 
-impl VisualSignConverterFromString<SolanaTransactionWrapper> for SolanaVisualSignConverter {}
+impl VisualSignConverterFromString<SolanaTransactionWrapper> for SolanaVisualSignConverter {
+    fn to_visual_sign_payload_from_string(
+        &self,
+        transaction_data: &str,
+        options: VisualSignOptions,
+    ) -> Result<ConversionResult, VisualSignError> {
+        if let Some(message) = decode_offchain_message(transaction_data) {
+            let allow_offchain_messages = options
+                .developer_config
+                .as_ref()
+                .is_some_and(|c| c.allow_offchain_messages);
+            if !allow_offchain_messages {
+                return Err(VisualSignError::ParseError(
+                    TransactionParseError::InvalidFormat(
+                        "Solana off-chain messages are not accepted on this endpoint".to_string(),
+                    ),
+                ));
+            }
+            let payload = offchain_message_to_visual_sign(&message, options)?;
+            payload.validate_charset()?;
+            return Ok(ConversionResult::new(payload));
+        }
+        let transaction = SolanaTransactionWrapper::from_string(transaction_data)
+            .map_err(VisualSignError::ParseError)?;
+        self.to_validated_visual_sign_payload(transaction, options)
+    }
+}
+
+/// The bytes of `data` if it encodes an off-chain message envelope
+fn decode_offchain_message(data: &str) -> Option<Vec<u8>> {
+    let bytes = match SupportedEncodings::detect(data) {
+        SupportedEncodings::Base64 => base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .ok()?,
+        SupportedEncodings::Hex => visualsign::encodings::decode_hex(data).ok()?,
+    };
+    is_offchain_message(&bytes).then_some(bytes)
+}
 
 /// Public API function for ease of use with legacy transactions
 pub fn transaction_to_visual_sign(
@@ -485,6 +790,8 @@ fn convert_to_visual_sign_payload(
 
     // Create IDL registry from options metadata
     let idl_registry = create_idl_registry_from_options(options)?;
+    let markets = extract_markets(options);
+    let tokens = extract_tokens(options);
 
     let mut fields = vec![SignablePayloadField::TextV2 {
         common: SignablePayloadFieldCommon {
@@ -495,6 +802,18 @@ fn convert_to_visual_sign_payload(
             text: "Solana".to_string(),
         },
     }];
+    fields.extend(create_signer_fields(&message.account_keys, &message.header));
+    fields.extend(create_net_flow_field(
+        &message.account_keys,
+        &message.instructions,
+        &tokens,
+    ));
+    fields.extend(create_signature_status_fields(
+        &transaction.signatures,
+        &message.account_keys,
+        message.header.num_required_signatures,
+        options,
+    ));
 
     if decode_transfers {
         let transfer_fields = instructions::decode_transfers(transaction)?;
@@ -507,7 +826,13 @@ fn convert_to_visual_sign_payload(
 
     // Process instructions with visualizers
     #[cfg(feature = "diagnostics")]
-    let decode_result = instructions::decode_instructions(transaction, &idl_registry, lint_config);
+    let decode_result = instructions::decode_instructions(
+        transaction,
+        &idl_registry,
+        &markets,
+        &tokens,
+        lint_config,
+    );
     #[cfg(feature = "diagnostics")]
     fields.extend(
         decode_result
@@ -518,7 +843,8 @@ fn convert_to_visual_sign_payload(
 
     #[cfg(not(feature = "diagnostics"))]
     {
-        let decoded_fields = instructions::decode_instructions(transaction, &idl_registry)?;
+        let decoded_fields =
+            instructions::decode_instructions(transaction, &idl_registry, &markets, &tokens)?;
         fields.extend(
             decoded_fields
                 .iter()
@@ -526,6 +852,12 @@ fn convert_to_visual_sign_payload(
         );
     }
 
+    if let Some(priority_fee) =
+        create_priority_fee_field(&message.instructions, &message.account_keys)?
+    {
+        fields.push(priority_fee);
+    }
+
     // Decode and sort accounts using the dedicated function
     let accounts = decode_accounts(message)?;
 
@@ -590,18 +922,20 @@ fn convert_v0_to_visual_sign_payload(
     options: &VisualSignOptions,
     #[cfg(feature = "diagnostics")] lint_config: &visualsign::lint::LintConfig,
 ) -> Result<SignablePayload, VisualSignError> {
-    // NOTE: the parser does not perform on-chain ALT resolution, so any
-    // instruction or account that references an entry in
-    // `address_table_lookups` cannot be fully resolved here. We previously
-    // fail-closed and rejected such transactions outright (#324), but that
-    // blocks all ALT-backed V0 transactions (e.g. aggregator swaps) from being
-    // signed. The proper fix is to pass resolved ALT contents into the parser
-    // from the caller; until that lands we render with graceful degradation --
-    // ALT-backed accounts surface as placeholders via decode_v0_instructions.
-    // TODO: re-introduce ALT-aware rendering once ALT data is supplied.
+    // NOTE: the parser does not perform on-chain ALT resolution. Callers may
+    // supply table contents in `SolanaMetadata.address_lookup_tables`; when
+    // every referenced table is present, instruction accounts resolve to real
+    // addresses. Otherwise we render with graceful degradation -- ALT-backed
+    // accounts surface as placeholders via decode_v0_instructions -- rather
+    // than rejecting the transaction outright (#324, reverted).
+    let lookup_tables = extract_address_lookup_tables(options);
+    let account_keys = resolve_v0_account_keys(v0_message, &lookup_tables)
+        .unwrap_or_else(|| v0_message.account_keys.clone());
 
     // Create IDL registry from options metadata
     let idl_registry = create_idl_registry_from_options(options)?;
+    let markets = extract_markets(options);
+    let tokens = extract_tokens(options);
 
     // Decode and sort accounts using the dedicated function
     let accounts = decode_v0_accounts(v0_message)?;
@@ -615,6 +949,21 @@ fn convert_v0_to_visual_sign_payload(
             text: "Solana (V0)".to_string(),
         },
     }];
+    fields.extend(create_signer_fields(
+        &v0_message.account_keys,
+        &v0_message.header,
+    ));
+    fields.extend(create_net_flow_field(
+        &account_keys,
+        &v0_message.instructions,
+        &tokens,
+    ));
+    fields.extend(create_signature_status_fields(
+        &versioned_tx.signatures,
+        &v0_message.account_keys,
+        v0_message.header.num_required_signatures,
+        options,
+    ));
 
     // Add address lookup table information if present
     if !v0_message.address_table_lookups.is_empty() {
@@ -625,7 +974,14 @@ fn convert_v0_to_visual_sign_payload(
     // Directly process V0 instructions using the visualizer framework
     // This approach works for all V0 transactions, including those with lookup tables
     #[cfg(feature = "diagnostics")]
-    let v0_result = decode_v0_instructions(v0_message, &idl_registry, lint_config);
+    let v0_result = decode_v0_instructions(
+        v0_message,
+        &account_keys,
+        &idl_registry,
+        &markets,
+        &tokens,
+        lint_config,
+    );
     #[cfg(feature = "diagnostics")]
     for (index, instruction_field) in v0_result.fields.iter().enumerate() {
         tracing::debug!(
@@ -637,7 +993,7 @@ fn convert_v0_to_visual_sign_payload(
     }
 
     #[cfg(not(feature = "diagnostics"))]
-    match decode_v0_instructions(v0_message, &idl_registry) {
+    match decode_v0_instructions(v0_message, &account_keys, &idl_registry, &markets, &tokens) {
         Ok(v0_fields) => {
             for (index, instruction_field) in v0_fields.iter().enumerate() {
                 tracing::debug!(
@@ -687,6 +1043,12 @@ fn convert_v0_to_visual_sign_payload(
         }
     }
 
+    if let Some(priority_fee) =
+        create_priority_fee_field(&v0_message.instructions, &v0_message.account_keys)?
+    {
+        fields.push(priority_fee);
+    }
+
     // Add Accounts field at the bottom using PreviewLayout instead of ListLayout
     let preview_layout_advanced = create_accounts_advanced_preview_layout("Accounts", &accounts)?;
     fields.push(preview_layout_advanced);
@@ -823,6 +1185,51 @@ mod tests {
         assert!(json_result.is_ok());
     }
 
+    #[test]
+    fn test_signer_fields_follow_network() {
+        let solana_transfer_message = "AgABA3Lgs31rdjnEG5FRyrm2uAi4f+erGdyJl0UtJyMMLGzC9wF+t3qhmhpj3vI369n5Ef5xRLms/Vn8J/Lc7bmoIkAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMBafBISARibJ+I25KpHkjLe53ZrqQcLWGy8n97yWD7mAQICAQAMAgAAAADKmjsAAAAA";
+        let payload = payload_from_b64(&create_transaction_with_empty_signatures(
+            solana_transfer_message,
+        ));
+
+        let top: Vec<(&str, &str)> = payload.fields[..4]
+            .iter()
+            .map(|f| (f.label().as_str(), f.fallback_text().as_str()))
+            .collect();
+        assert_eq!(
+            top,
+            vec![
+                ("Network", "Solana"),
+                ("Fee Payer", "8jSCrV9xWkmMRSyf6xH3phL7SretagdqP3LRqkUYUp73"),
+                ("Required Signatures", "2"),
+                (
+                    "Writable Signers",
+                    "8jSCrV9xWkmMRSyf6xH3phL7SretagdqP3LRqkUYUp73, HdD2N8HDzNEM6vwAq5mBLiUbgy1P9wyJfbASt93ndDsD"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_signer_fields_exclude_readonly_signers() {
+        let keys: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let header = MessageHeader {
+            num_required_signatures: 3,
+            num_readonly_signed_accounts: 1,
+            num_readonly_unsigned_accounts: 0,
+        };
+        let fields = create_signer_fields(&keys, &header);
+        let writable = fields
+            .iter()
+            .find(|f| f.label() == "Writable Signers")
+            .unwrap();
+        assert_eq!(
+            writable.fallback_text(),
+            &format!("{}, {}", keys[0], keys[1])
+        );
+        assert!(create_signer_fields(&[], &header).is_empty());
+    }
+
     #[test]
     fn test_solana_transaction_trait() {
         let solana_transfer_message = "AgABA3Lgs31rdjnEG5FRyrm2uAi4f+erGdyJl0UtJyMMLGzC9wF+t3qhmhpj3vI369n5Ef5xRLms/Vn8J/Lc7bmoIkAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMBafBISARibJ+I25KpHkjLe53ZrqQcLWGy8n97yWD7mAQICAQAMAgAAAADKmjsAAAAA";
@@ -853,6 +1260,7 @@ mod tests {
             solana_tx,
             VisualSignOptions {
                 include_intermediate_output: false,
+                strict_registry: false,
                 metadata: None,
                 decode_transfers: true,
                 transaction_name: Some("Solana Transaction".to_string()),
@@ -937,6 +1345,7 @@ mod tests {
             solana_tx,
             VisualSignOptions {
                 include_intermediate_output: false,
+                strict_registry: false,
                 metadata: None,
                 decode_transfers: true,
                 transaction_name: Some("V0 Transaction".to_string()),
@@ -1105,6 +1514,7 @@ mod tests {
             legacy_tx,
             VisualSignOptions {
                 include_intermediate_output: false,
+                strict_registry: false,
                 metadata: None,
                 decode_transfers: true,
                 transaction_name: Some("Legacy Transfer Test".to_string()),
@@ -1150,6 +1560,7 @@ mod tests {
             v0_tx,
             VisualSignOptions {
                 include_intermediate_output: false,
+                strict_registry: false,
                 metadata: None,
                 decode_transfers: true,
                 transaction_name: Some("V0 Transfer Test".to_string()),
@@ -1278,6 +1689,7 @@ mod tests {
                     wrapper,
                     VisualSignOptions {
                         include_intermediate_output: false,
+                        strict_registry: false,
                         metadata: None,
                         decode_transfers: true,
                         transaction_name: Some("Manual V0 Transfer Test".to_string()),
@@ -1429,6 +1841,7 @@ mod tests {
             tx,
             VisualSignOptions {
                 include_intermediate_output: false,
+                strict_registry: false,
                 metadata: None,
                 decode_transfers: true,
                 transaction_name: Some("SPL Token Test".to_string()),
@@ -1506,6 +1919,7 @@ mod tests {
             SolanaTransactionWrapper::Legacy(transaction),
             VisualSignOptions {
                 include_intermediate_output: false,
+                strict_registry: false,
                 decode_transfers: false,
                 metadata: None,
                 transaction_name: Some("Unknown Program Test".to_string()),
@@ -1571,6 +1985,7 @@ mod tests {
         };
         let options = VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             metadata: None,
             decode_transfers: false,
             transaction_name: None,
@@ -1605,9 +2020,10 @@ mod tests {
     }
 
     // Regression tests for V0 transactions that reference address lookup table
-    // entries. The parser cannot resolve ALT contents offline, so these render
-    // with graceful degradation (ALT-backed accounts surface as placeholders)
-    // rather than being rejected outright. The earlier fail-closed behavior
+    // entries. The parser cannot resolve ALT contents offline, so unless the
+    // caller supplies the tables these render with graceful degradation
+    // (ALT-backed accounts surface as placeholders) rather than being
+    // rejected outright. The earlier fail-closed behavior
     // (#324) blocked all ALT-backed V0 transactions from being signed and was
     // reverted pending a design that passes resolved ALT data into the parser.
     mod v0_alt_rendering {
@@ -1638,6 +2054,7 @@ mod tests {
         fn default_options() -> VisualSignOptions {
             VisualSignOptions {
                 include_intermediate_output: false,
+                strict_registry: false,
                 metadata: None,
                 decode_transfers: false,
                 transaction_name: Some("V0 ALT Regression".to_string()),
@@ -1645,7 +2062,44 @@ mod tests {
             }
         }
 
+        fn options_with_tables(tables: &[(Pubkey, Vec<Pubkey>)]) -> VisualSignOptions {
+            let address_lookup_tables: BTreeMap<String, generated::parser::AddressLookupTable> =
+                tables
+                    .iter()
+                    .map(|(table, addresses)| {
+                        (
+                            table.to_string(),
+                            generated::parser::AddressLookupTable {
+                                addresses: addresses.iter().map(|a| a.to_string()).collect(),
+                            },
+                        )
+                    })
+                    .collect();
+            VisualSignOptions {
+                metadata: Some(generated::parser::ChainMetadata {
+                    metadata: Some(generated::parser::chain_metadata::Metadata::Solana(
+                        generated::parser::SolanaMetadata {
+                            network_id: None,
+                            idl: None,
+                            idl_mappings: Default::default(),
+                            address_lookup_tables: address_lookup_tables.into_iter().collect(),
+                            markets: Default::default(),
+                            tokens: Default::default(),
+                        },
+                    )),
+                }),
+                ..default_options()
+            }
+        }
+
         fn convert(v0_message: &V0Message) -> Result<SignablePayload, VisualSignError> {
+            convert_with_options(v0_message, &default_options())
+        }
+
+        fn convert_with_options(
+            v0_message: &V0Message,
+            options: &VisualSignOptions,
+        ) -> Result<SignablePayload, VisualSignError> {
             let versioned_tx = VersionedTransaction {
                 signatures: vec![],
                 message: VersionedMessage::V0(v0_message.clone()),
@@ -1657,12 +2111,38 @@ mod tests {
                 v0_message,
                 false,
                 None,
-                &default_options(),
+                options,
                 #[cfg(feature = "diagnostics")]
                 &lint_config,
             )
         }
 
+        /// A system transfer from the fee payer to an account loaded through
+        /// an ALT (writable entry 1 of `table`)
+        fn transfer_to_alt_account(table: Pubkey) -> V0Message {
+            let payer = Pubkey::new_unique();
+            let ix = CompiledInstruction {
+                program_id_index: 1,
+                accounts: vec![0, 2],
+                data: bincode::serialize(
+                    &solana_system_interface::instruction::SystemInstruction::Transfer {
+                        lamports: 1_000_000_000,
+                    },
+                )
+                .unwrap(),
+            };
+            let alt = MessageAddressTableLookup {
+                account_key: table,
+                writable_indexes: vec![1],
+                readonly_indexes: vec![],
+            };
+            make_v0(
+                vec![payer, solana_sdk::system_program::ID],
+                vec![ix],
+                vec![alt],
+            )
+        }
+
         #[test]
         fn renders_v0_when_account_index_lives_behind_an_alt() {
             // Instruction's program_id is in-range, but two of its accounts
@@ -1727,6 +2207,70 @@ mod tests {
             );
         }
 
+        #[test]
+        fn supplied_lookup_table_resolves_instruction_accounts() {
+            let table = Pubkey::new_unique();
+            let entries = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+            let msg = transfer_to_alt_account(table);
+
+            let payload =
+                convert_with_options(&msg, &options_with_tables(&[(table, entries.clone())]))
+                    .unwrap();
+            let json = serde_json::to_string(&payload).unwrap();
+            assert!(
+                json.contains(&entries[1].to_string()),
+                "recipient loaded through the ALT should be shown: {json}"
+            );
+            assert!(!json.contains("unresolved(2)"));
+        }
+
+        #[test]
+        fn missing_lookup_table_keeps_placeholders() {
+            let msg = transfer_to_alt_account(Pubkey::new_unique());
+            let other_table = (Pubkey::new_unique(), vec![Pubkey::new_unique(); 2]);
+
+            let payload =
+                convert_with_options(&msg, &options_with_tables(&[other_table.clone()])).unwrap();
+            let json = serde_json::to_string(&payload).unwrap();
+            assert!(json.contains("unresolved(2)"), "{json}");
+            assert!(!json.contains(&other_table.1[1].to_string()));
+        }
+
+        #[test]
+        fn resolve_orders_writable_before_readonly_across_tables() {
+            let (table_a, table_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+            let a: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+            let b: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+            let static_key = Pubkey::new_unique();
+            let msg = make_v0(
+                vec![static_key],
+                vec![],
+                vec![
+                    MessageAddressTableLookup {
+                        account_key: table_a,
+                        writable_indexes: vec![2],
+                        readonly_indexes: vec![0],
+                    },
+                    MessageAddressTableLookup {
+                        account_key: table_b,
+                        writable_indexes: vec![1],
+                        readonly_indexes: vec![2],
+                    },
+                ],
+            );
+            let tables = BTreeMap::from([(table_a, a.clone()), (table_b, b.clone())]);
+
+            assert_eq!(
+                resolve_v0_account_keys(&msg, &tables).unwrap(),
+                vec![static_key, a[2], b[1], a[0], b[2]]
+            );
+
+            // An index past the end of a supplied table means the table does
+            // not match the transaction; nothing is resolved.
+            let short = BTreeMap::from([(table_a, a[..2].to_vec()), (table_b, b)]);
+            assert!(resolve_v0_account_keys(&msg, &short).is_none());
+        }
+
         #[test]
         fn malformed_v0_without_alts_keeps_existing_behavior() {
             // No ALTs: an OOB index here is just a malformed transaction, not
@@ -1767,12 +2311,16 @@ mod tests {
         idl_mappings.insert(program_id.to_string(), idl);
         VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             metadata: Some(generated::parser::ChainMetadata {
                 metadata: Some(generated::parser::chain_metadata::Metadata::Solana(
                     generated::parser::SolanaMetadata {
                         network_id: Some("SOLANA_MAINNET".to_string()),
                         idl: None,
                         idl_mappings: idl_mappings.into_iter().collect(),
+                        address_lookup_tables: Default::default(),
+                        markets: Default::default(),
+                        tokens: Default::default(),
                     },
                 )),
             }),
@@ -1850,6 +2398,44 @@ mod tests {
         );
     }
 
+    /// Only the first `MAX_IDL_MAPPINGS` mappings by program id are kept.
+    #[test]
+    fn test_extract_idl_mappings_enforces_count_limit() {
+        let mut idl_mappings: BTreeMap<String, generated::parser::Idl> = BTreeMap::new();
+        for _ in 0..MAX_IDL_MAPPINGS + 5 {
+            idl_mappings.insert(
+                Pubkey::new_unique().to_string(),
+                generated::parser::Idl {
+                    value: r#"{"instructions":[]}"#.to_string(),
+                    idl_type: None,
+                    idl_version: None,
+                    signature: None,
+                    program_name: Some("Custom".to_string()),
+                },
+            );
+        }
+        let expected: Vec<String> = idl_mappings
+            .keys()
+            .take(MAX_IDL_MAPPINGS)
+            .cloned()
+            .collect();
+
+        let mut options = make_options_with_idl_mapping(
+            "9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin",
+            generated::parser::Idl::default(),
+        );
+        if let Some(generated::parser::chain_metadata::Metadata::Solana(solana_meta)) = options
+            .metadata
+            .as_mut()
+            .and_then(|meta| meta.metadata.as_mut())
+        {
+            solana_meta.idl_mappings = idl_mappings.into_iter().collect();
+        }
+
+        let mappings = extract_idl_mappings(&options);
+        assert_eq!(mappings.keys().cloned().collect::<Vec<_>>(), expected);
+    }
+
     /// IDL with a signature that fails to verify is dropped. Mirrors the
     /// Ethereum ABI path so callers that opt in to signing get the same
     /// "tampered bodies are rejected" guarantee.
@@ -2089,4 +2675,181 @@ mod tests {
         let mappings = extract_idl_mappings(&options);
         assert_eq!(mappings.len(), 1);
     }
+
+    /// Caller-supplied tokens are escaped, and entries with an invalid mint
+    /// or decimals past `u8` are dropped rather than failing the request.
+    #[test]
+    fn test_extract_tokens_validates_entries() {
+        let token = |symbol: &str, decimals: u32| generated::parser::SolanaToken {
+            symbol: symbol.to_string(),
+            name: format!("{symbol} Token"),
+            decimals,
+        };
+        let mut tokens: BTreeMap<String, generated::parser::SolanaToken> = BTreeMap::new();
+        tokens.insert(
+            "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN".to_string(),
+            token("JUP\u{202e}", 6),
+        );
+        tokens.insert("not_a_base58_pubkey".to_string(), token("BAD", 6));
+        tokens.insert(
+            "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263".to_string(),
+            token("BONK", 256),
+        );
+        let options = VisualSignOptions {
+            include_intermediate_output: false,
+            strict_registry: false,
+            metadata: Some(generated::parser::ChainMetadata {
+                metadata: Some(generated::parser::chain_metadata::Metadata::Solana(
+                    generated::parser::SolanaMetadata {
+                        network_id: None,
+                        idl: None,
+                        idl_mappings: Default::default(),
+                        address_lookup_tables: Default::default(),
+                        markets: Default::default(),
+                        tokens: tokens.into_iter().collect(),
+                    },
+                )),
+            }),
+            decode_transfers: false,
+            transaction_name: None,
+            developer_config: None,
+        };
+
+        let registry = extract_tokens(&options);
+        let jup = registry
+            .get("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN")
+            .expect("valid token kept");
+        assert_eq!(jup.symbol, "JUP<U+202E>");
+        assert_eq!(jup.decimals, 6);
+        assert!(registry.get("not_a_base58_pubkey").is_none());
+        assert!(
+            registry
+                .get("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_extract_tokens_ignored_under_strict_registry() {
+        let mut tokens: BTreeMap<String, generated::parser::SolanaToken> = BTreeMap::new();
+        tokens.insert(
+            "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN".to_string(),
+            generated::parser::SolanaToken {
+                symbol: "JUP".to_string(),
+                name: "Jupiter".to_string(),
+                decimals: 6,
+            },
+        );
+        let options = VisualSignOptions {
+            include_intermediate_output: false,
+            strict_registry: true,
+            metadata: Some(generated::parser::ChainMetadata {
+                metadata: Some(generated::parser::chain_metadata::Metadata::Solana(
+                    generated::parser::SolanaMetadata {
+                        network_id: None,
+                        idl: None,
+                        idl_mappings: Default::default(),
+                        address_lookup_tables: Default::default(),
+                        markets: Default::default(),
+                        tokens: tokens.into_iter().collect(),
+                    },
+                )),
+            }),
+            decode_transfers: false,
+            transaction_name: None,
+            developer_config: None,
+        };
+
+        let registry = extract_tokens(&options);
+        assert!(
+            registry
+                .get("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_offchain_message_requires_developer_config() {
+        let mut envelope = crate::offchain_message::SIGNING_DOMAIN.to_vec();
+        envelope.extend_from_slice(&[0, 0, 5, 0]);
+        envelope.extend_from_slice(b"hello");
+        let data = hex::encode(&envelope);
+
+        let rejected = SolanaVisualSignConverter
+            .to_visual_sign_payload_from_string(&data, VisualSignOptions::default());
+        assert!(matches!(
+            rejected,
+            Err(VisualSignError::ParseError(
+                TransactionParseError::InvalidFormat(_)
+            ))
+        ));
+
+        let options = VisualSignOptions {
+            developer_config: Some(visualsign::vsptrait::DeveloperConfig {
+                allow_offchain_messages: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let payload = SolanaVisualSignConverter
+            .to_visual_sign_payload_from_string(&data, options)
+            .unwrap()
+            .payload;
+        assert_eq!(payload.payload_type, "SolanaOffchainMessage");
+        assert_eq!(payload.title, "Solana Off-chain Message");
+    }
+
+    #[test]
+    fn test_partially_signed_transaction_lists_signers_under_developer_config() {
+        use solana_sdk::{hash::Hash, instruction::CompiledInstruction, message::Message};
+
+        let fee_payer = Pubkey::new_unique();
+        let co_signer = Pubkey::new_unique();
+        let message = Message {
+            header: solana_sdk::message::MessageHeader {
+                num_required_signatures: 2,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            },
+            account_keys: vec![fee_payer, co_signer, solana_sdk::system_program::ID],
+            recent_blockhash: Hash::default(),
+            instructions: vec![CompiledInstruction {
+                program_id_index: 2,
+                accounts: vec![0, 1],
+                data: vec![2, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0],
+            }],
+        };
+        // The fee payer has signed, the co-signer has not
+        let transaction = SolanaTransaction {
+            signatures: vec![Signature::from([7u8; 64]), Signature::default()],
+            message,
+        };
+        let data = base64::engine::general_purpose::STANDARD
+            .encode(bincode::serialize(&transaction).unwrap());
+
+        // Signed transactions parse by default, without the signer status
+        let payload =
+            transaction_string_to_visual_sign(&data, VisualSignOptions::default()).unwrap();
+        assert!(payload.fields.iter().all(|f| f.label() != "Signatures"));
+
+        let options = VisualSignOptions {
+            developer_config: Some(visualsign::vsptrait::DeveloperConfig {
+                allow_signed_transactions: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let payload = transaction_string_to_visual_sign(&data, options).unwrap();
+        let text = |label: &str| {
+            payload
+                .fields
+                .iter()
+                .find(|f| f.label() == label)
+                .map(|f| f.fallback_text().clone())
+                .unwrap_or_else(|| panic!("missing field {label}"))
+        };
+        assert_eq!(text("Signatures"), "1 of 2 required signers signed");
+        assert_eq!(text("Signed By"), fee_payer.to_string());
+        assert_eq!(text("Awaiting Signature"), co_signer.to_string());
+    }
 }
@@ -0,0 +1,180 @@
+//! Discriminator index for Anchor programs
+//!
+//! Anchor prefixes every instruction with the first 8 bytes of
+//! `sha256("global:<instruction_name>")`. [`AnchorMatcher`] is built once per
+//! IDL when the registry is created, so matching an instruction is a map
+//! lookup and a program becomes decodable by registering its IDL, with no
+//! discriminator constants to maintain.
+
+use solana_parser::Idl;
+use std::collections::BTreeMap;
+
+/// Length of an Anchor instruction discriminator
+pub const DISCRIMINATOR_LEN: usize = 8;
+
+/// The instruction an Anchor discriminator resolves to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnchorInstruction {
+    pub name: String,
+    /// Account names in the order the instruction expects its accounts
+    pub account_names: Vec<String>,
+}
+
+/// Maps each instruction of one IDL from its discriminator
+#[derive(Debug, Clone, Default)]
+pub struct AnchorMatcher {
+    instructions: BTreeMap<[u8; DISCRIMINATOR_LEN], AnchorInstruction>,
+}
+
+impl AnchorMatcher {
+    /// Index every instruction in `idl`. Discriminators the IDL declares are
+    /// used as-is; instructions without one get the Anchor sighash of their
+    /// name. Declared discriminators that are not 8 bytes long (non-Anchor
+    /// programs) are skipped.
+    pub fn from_idl(idl: &Idl) -> Self {
+        let mut instructions = BTreeMap::new();
+        for instruction in &idl.instructions {
+            let discriminator = match &instruction.discriminator {
+                Some(declared) => match <[u8; DISCRIMINATOR_LEN]>::try_from(declared.as_slice()) {
+                    Ok(discriminator) => discriminator,
+                    Err(_) => continue,
+                },
+                None => anchor_discriminator(&instruction.name),
+            };
+            instructions.insert(
+                discriminator,
+                AnchorInstruction {
+                    name: instruction.name.clone(),
+                    account_names: instruction
+                        .accounts
+                        .iter()
+                        .map(|account| account.name.clone())
+                        .collect(),
+                },
+            );
+        }
+        Self { instructions }
+    }
+
+    /// The instruction whose discriminator prefixes `data`, if any
+    pub fn match_instruction(&self, data: &[u8]) -> Option<&AnchorInstruction> {
+        let (discriminator, _) = data.split_first_chunk::<DISCRIMINATOR_LEN>()?;
+        self.instructions.get(discriminator)
+    }
+
+    /// Pair the account names of the instruction in `data` with `accounts`.
+    /// Accounts past the IDL's list (remaining accounts) are left out.
+    pub fn named_accounts(&self, data: &[u8], accounts: &[String]) -> BTreeMap<String, String> {
+        self.match_instruction(data)
+            .map(|instruction| {
+                instruction
+                    .account_names
+                    .iter()
+                    .zip(accounts)
+                    .map(|(name, account)| (name.clone(), account.clone()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+}
+
+/// `sha256("global:<name>")[..8]`, the discriminator Anchor derives for an
+/// instruction. `name` is converted to snake case first, since legacy IDLs
+/// list instructions in camel case while Anchor hashes the Rust function name.
+pub fn anchor_discriminator(name: &str) -> [u8; DISCRIMINATOR_LEN] {
+    let preimage = format!("global:{}", to_snake_case(name));
+    let hash = solana_sdk::hash::hash(preimage.as_bytes()).to_bytes();
+    let mut discriminator = [0u8; DISCRIMINATOR_LEN];
+    discriminator.copy_from_slice(&hash[..DISCRIMINATOR_LEN]);
+    discriminator
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 && !snake.ends_with('_') {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::decode_idl_data;
+
+    fn test_idl() -> Idl {
+        decode_idl_data(
+            &serde_json::json!({
+                "instructions": [
+                    {"name": "deposit", "accounts": [
+                        {"name": "user", "isMut": true, "isSigner": true},
+                        {"name": "vault", "isMut": true, "isSigner": false}
+                    ], "args": [{"name": "amount", "type": "u64"}]},
+                    {"name": "closeVault", "accounts": [], "args": []}
+                ],
+                "types": []
+            })
+            .to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_anchor_discriminator_matches_sighash() {
+        let expected = solana_sdk::hash::hash(b"global:close_vault").to_bytes();
+        assert_eq!(anchor_discriminator("closeVault"), expected[..8]);
+        assert_eq!(anchor_discriminator("close_vault"), expected[..8]);
+    }
+
+    #[test]
+    fn test_matcher_resolves_instruction_and_accounts() {
+        let matcher = AnchorMatcher::from_idl(&test_idl());
+        assert_eq!(matcher.len(), 2);
+
+        let mut data = anchor_discriminator("deposit").to_vec();
+        data.extend_from_slice(&42u64.to_le_bytes());
+        assert_eq!(matcher.match_instruction(&data).unwrap().name, "deposit");
+
+        let accounts = ["A".to_string(), "B".to_string(), "extra".to_string()];
+        let named = matcher.named_accounts(&data, &accounts);
+        assert_eq!(
+            named.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("user".to_string(), "A".to_string()),
+                ("vault".to_string(), "B".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matcher_rejects_unknown_and_short_data() {
+        let matcher = AnchorMatcher::from_idl(&test_idl());
+        assert!(
+            matcher
+                .match_instruction(&[0xde, 0xad, 0xbe, 0xef, 0, 1, 2, 3])
+                .is_none()
+        );
+        assert!(matcher.match_instruction(&[1, 2, 3]).is_none());
+        assert!(
+            matcher
+                .named_accounts(&[1, 2, 3], &["A".to_string()])
+                .is_empty()
+        );
+    }
+}
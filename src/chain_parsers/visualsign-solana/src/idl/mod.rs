@@ -3,13 +3,25 @@
 //! This module provides utilities for managing Anchor IDLs and integrating them
 //! with the solana_parser library for instruction decoding.
 
+pub mod anchor;
 pub mod builtin_programs;
 pub mod signature;
 
+use crate::idl::anchor::{AnchorInstruction, AnchorMatcher};
 use crate::idl::builtin_programs::{canonical_name, is_trusted_program};
 use solana_parser::{CustomIdl, CustomIdlConfig, Idl, ProgramType, decode_idl_data};
 use solana_sdk::pubkey::Pubkey;
 use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+use visualsign::registry::{EntryOrigin, Provenance};
+
+/// Maximum size of a single IDL file read by [`IdlRegistry::from_directory`] (10 MB)
+pub const MAX_IDL_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Maximum number of IDL files [`IdlRegistry::from_directory`] will load
+pub const MAX_IDL_FILES: usize = 512;
 
 /// Registry for managing program IDLs (program_id -> CustomIdlConfig)
 ///
@@ -28,6 +40,9 @@ pub struct IdlRegistry {
     names: BTreeMap<String, String>,
     /// Maps program_id -> IDL name from metadata.name in JSON
     idl_names: BTreeMap<String, String>,
+    /// Maps program_id -> discriminator index of its IDL, computed once when
+    /// the registry is built
+    matchers: BTreeMap<String, AnchorMatcher>,
 }
 
 impl IdlRegistry {
@@ -37,6 +52,7 @@ impl IdlRegistry {
             configs: BTreeMap::new(),
             names: BTreeMap::new(),
             idl_names: BTreeMap::new(),
+            matchers: BTreeMap::new(),
         }
     }
 
@@ -69,6 +85,7 @@ impl IdlRegistry {
         let mut configs = BTreeMap::new();
         let mut names = BTreeMap::new();
         let mut idl_names = BTreeMap::new();
+        let mut matchers = BTreeMap::new();
 
         for (program_id, (idl_json, program_name)) in idl_mappings {
             // Refuse IDL overrides for trusted built-ins. See doc comment above.
@@ -85,6 +102,12 @@ impl IdlRegistry {
                 }
             }
 
+            // Index instruction discriminators up front so matching an
+            // instruction later is a lookup rather than a scan of the IDL
+            if let Ok(idl) = decode_idl_data(&idl_json) {
+                matchers.insert(program_id.clone(), AnchorMatcher::from_idl(&idl));
+            }
+
             // Convert IDL JSON to solana_parser CustomIdlConfig
             // override_builtin = true so user IDLs override built-in ones
             let config = CustomIdlConfig::from_json(idl_json, true);
@@ -96,9 +119,84 @@ impl IdlRegistry {
             configs,
             names,
             idl_names,
+            matchers,
         })
     }
 
+    /// Create a registry from a directory of Anchor IDL files
+    ///
+    /// Every `*.json` file directly inside `dir` is read; subdirectories are
+    /// not searched. The program id comes from the IDL's `metadata.address`
+    /// (falling back to the top-level `address` used by Anchor 0.30+ IDLs)
+    /// and the display name from `metadata.name`. JSON files without an
+    /// address are skipped, since they cannot be tied to a program.
+    ///
+    /// Mappings for trusted built-ins are dropped as in [`Self::from_idl_mappings`].
+    ///
+    /// # Errors
+    /// Returns `Err` if the directory cannot be read, it holds more than
+    /// [`MAX_IDL_FILES`] JSON files, a file exceeds [`MAX_IDL_FILE_BYTES`] or is
+    /// not valid JSON, an address is not a valid pubkey, or two files declare
+    /// the same program.
+    pub fn from_directory(dir: impl AsRef<Path>) -> Result<Self, Box<dyn std::error::Error>> {
+        let dir = dir.as_ref();
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+                paths.push(path);
+            }
+        }
+        if paths.len() > MAX_IDL_FILES {
+            return Err(format!(
+                "{} holds {} IDL files (limit {MAX_IDL_FILES})",
+                dir.display(),
+                paths.len()
+            )
+            .into());
+        }
+        // Sorted so errors and skips are reported in a stable order
+        paths.sort();
+
+        let mut idl_mappings = BTreeMap::new();
+        for path in paths {
+            let idl_json = read_idl_file(&path)?;
+            let value: serde_json::Value = serde_json::from_str(&idl_json)
+                .map_err(|e| format!("Invalid JSON in {}: {e}", path.display()))?;
+
+            let metadata = value.get("metadata");
+            let Some(address) = metadata
+                .and_then(|m| m.get("address"))
+                .or_else(|| value.get("address"))
+                .and_then(|a| a.as_str())
+            else {
+                tracing::warn!("Skipping {}: no program address in IDL", path.display());
+                continue;
+            };
+            let program_id = Pubkey::from_str(address)
+                .map_err(|e| format!("Invalid program address in {}: {e}", path.display()))?
+                .to_string();
+
+            let program_name = metadata
+                .and_then(|m| m.get("name"))
+                .or_else(|| value.get("name"))
+                .and_then(|n| n.as_str())
+                .map(String::from)
+                .unwrap_or_else(|| format!("Program {}", &program_id[..8]));
+
+            if idl_mappings.contains_key(&program_id) {
+                return Err(format!(
+                    "Duplicate IDL for program {program_id} in {}",
+                    path.display()
+                )
+                .into());
+            }
+            idl_mappings.insert(program_id, (idl_json, program_name));
+        }
+
+        Self::from_idl_mappings(idl_mappings)
+    }
+
     /// Get all IDL configs for use with solana_parser
     ///
     /// This returns the custom user-provided IDLs. Built-in IDLs are handled
@@ -175,6 +273,30 @@ impl IdlRegistry {
         self.idl_names.get(&program_id_str).cloned()
     }
 
+    /// Get the provenance and trust tier of the IDL used for a program
+    ///
+    /// Custom IDLs only ever come from request metadata, so they are reported
+    /// as unverified wallet metadata; built-in IDLs are verified. Returns `None`
+    /// when no IDL is available.
+    pub fn get_origin(&self, program_id: &Pubkey) -> Option<EntryOrigin> {
+        let program_id_str = program_id.to_string();
+        if self.configs.contains_key(&program_id_str) {
+            return Some(EntryOrigin::new(Provenance::WalletMetadata));
+        }
+        ProgramType::from_program_id(&program_id_str).map(|_| EntryOrigin::built_in())
+    }
+
+    /// Match `data` against the discriminators of the program's registered
+    /// IDL, returning the instruction's name and account names
+    pub fn match_instruction(&self, program_id: &str, data: &[u8]) -> Option<&AnchorInstruction> {
+        self.matchers.get(program_id)?.match_instruction(data)
+    }
+
+    /// The discriminator index built for the program's registered IDL
+    pub fn anchor_matcher(&self, program_id: &str) -> Option<&AnchorMatcher> {
+        self.matchers.get(program_id)
+    }
+
     /// Get the parsed Idl for a program if available
     pub fn get_idl(&self, program_id: &str) -> Option<Idl> {
         if let Some(config) = self.configs.get(program_id) {
@@ -188,11 +310,117 @@ impl IdlRegistry {
     }
 }
 
+/// Read an IDL file, refusing anything larger than [`MAX_IDL_FILE_BYTES`].
+/// The read itself is bounded so a file growing after the check cannot
+/// bypass the limit.
+fn read_idl_file(path: &Path) -> Result<String, Box<dyn std::error::Error>> {
+    let file =
+        std::fs::File::open(path).map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    let mut idl_json = String::new();
+    file.take(MAX_IDL_FILE_BYTES + 1)
+        .read_to_string(&mut idl_json)
+        .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    if idl_json.len() as u64 > MAX_IDL_FILE_BYTES {
+        return Err(format!(
+            "{} exceeds maximum IDL size (> {MAX_IDL_FILE_BYTES} bytes)",
+            path.display()
+        )
+        .into());
+    }
+    Ok(idl_json)
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
-    use std::str::FromStr;
+
+    fn temp_idl_dir(label: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "vsp_idl_dir_{label}_{}_{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_from_directory_loads_idls_by_metadata_address() {
+        let dir = temp_idl_dir("load");
+        let program = Pubkey::new_unique();
+        std::fs::write(
+            dir.join("vault.json"),
+            serde_json::json!({
+                "metadata": {"name": "vault", "address": program.to_string()},
+                "instructions": [{"name": "deposit", "accounts": [], "args": []}],
+                "types": []
+            })
+            .to_string(),
+        )
+        .unwrap();
+        // No address: skipped rather than failing the whole directory
+        std::fs::write(dir.join("orphan.json"), r#"{"instructions":[]}"#).unwrap();
+        // Not JSON by extension: ignored
+        std::fs::write(dir.join("README.md"), "not an idl").unwrap();
+        // Trusted built-ins are still refused
+        std::fs::write(
+            dir.join("system.json"),
+            r#"{"metadata":{"name":"evil","address":"11111111111111111111111111111111"},"instructions":[]}"#,
+        )
+        .unwrap();
+
+        let registry = IdlRegistry::from_directory(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(registry.get_all_configs().len(), 1);
+        assert!(registry.has_idl(&program));
+        assert_eq!(registry.get_program_name(&program), "vault");
+        assert_eq!(registry.get_idl_name(&program).as_deref(), Some("vault"));
+        let deposit = anchor::anchor_discriminator("deposit");
+        assert_eq!(
+            registry
+                .match_instruction(&program.to_string(), &deposit)
+                .map(|ix| ix.name.as_str()),
+            Some("deposit")
+        );
+    }
+
+    #[test]
+    fn test_from_directory_rejects_invalid_entries() {
+        let dir = temp_idl_dir("invalid_address");
+        std::fs::write(
+            dir.join("bad.json"),
+            r#"{"metadata":{"address":"not-a-pubkey"},"instructions":[]}"#,
+        )
+        .unwrap();
+        assert!(IdlRegistry::from_directory(&dir).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let dir = temp_idl_dir("duplicate");
+        let idl = serde_json::json!({
+            "metadata": {"address": Pubkey::new_unique().to_string()},
+            "instructions": []
+        })
+        .to_string();
+        std::fs::write(dir.join("a.json"), &idl).unwrap();
+        std::fs::write(dir.join("b.json"), &idl).unwrap();
+        assert!(IdlRegistry::from_directory(&dir).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let missing =
+            std::env::temp_dir().join(format!("vsp_idl_dir_missing_{}", std::process::id()));
+        assert!(IdlRegistry::from_directory(missing).is_err());
+    }
+
+    #[test]
+    fn test_get_origin_unknown_program_is_none() {
+        let registry = IdlRegistry::new();
+        assert_eq!(registry.get_origin(&Pubkey::new_unique()), None);
+    }
 
     #[test]
     fn test_new_registry_is_empty() {
@@ -383,4 +611,30 @@ mod tests {
             Some("My Custom Program".to_string())
         );
     }
+
+    #[test]
+    fn test_registry_indexes_discriminators_at_build_time() {
+        let custom_id = "9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin";
+        let custom_idl = r#"{"instructions":[{"name":"deposit","accounts":[{"name":"user","isMut":true,"isSigner":true}],"args":[]}],"types":[]}"#;
+
+        let mut mappings = BTreeMap::new();
+        mappings.insert(
+            custom_id.to_string(),
+            (custom_idl.to_string(), "Custom".to_string()),
+        );
+        let registry = IdlRegistry::from_idl_mappings(mappings).unwrap();
+
+        let data = anchor::anchor_discriminator("deposit");
+        let matched = registry.match_instruction(custom_id, &data).unwrap();
+        assert_eq!(matched.name, "deposit");
+        assert_eq!(matched.account_names, vec!["user".to_string()]);
+        assert!(registry.match_instruction(custom_id, &[0; 8]).is_none());
+
+        // Trusted programs never get a caller-supplied index
+        assert!(
+            registry
+                .match_instruction("11111111111111111111111111111111", &data)
+                .is_none()
+        );
+    }
 }
@@ -11,6 +11,7 @@ mod integrations;
 /// `ParsedTransactionPayload.intermediate_output`. Published so downstream
 /// consumers can `borsh::from_slice` into the same schema the parser produces.
 pub mod intermediate;
+pub mod offchain_message;
 mod presets;
 pub mod utils;
 
@@ -42,6 +43,7 @@ mod tests {
                     transaction_wrapper,
                     VisualSignOptions {
                         include_intermediate_output: false,
+                        strict_registry: false,
                         metadata: None,
                         decode_transfers: true,
                         transaction_name: Some(description.to_string()),
@@ -101,6 +103,7 @@ mod tests {
                 transaction_wrapper,
                 VisualSignOptions {
                     include_intermediate_output: false,
+                    strict_registry: false,
                     metadata: None,
                     decode_transfers: true,
                     transaction_name: Some("Unicode Escape Test".to_string()),
@@ -0,0 +1,558 @@
+//! Solana off-chain messages and Sign-In-With-Solana
+//!
+//! Wallets sign arbitrary text for dapps (`signMessage`). The off-chain message
+//! envelope (`\xffsolana offchain` + header + body) keeps such a signature from
+//! ever being valid for a transaction, because no transaction message starts
+//! with `0xff`. Two version 0 header layouts are in use and both are accepted:
+//!
+//! - the current spec: application domain, format, signer list, length
+//! - the original `solana-sdk` / Ledger layout: format, length
+//!
+//! A body in the Sign-In-With-Solana (SIWS) text format, modeled on EIP-4361,
+//! is shown field by field so the signer sees which site is asking, the nonce
+//! and when the sign-in expires.
+//!
+//! Raw messages (no envelope) are checked before being shown: bytes that decode
+//! as a transaction, or are exactly the size of a hash or signature, are
+//! refused, since signing them could authorize something other than a message.
+//!
+//! Envelope input is gated behind
+//! [`DeveloperConfig::allow_offchain_messages`](visualsign::vsptrait::DeveloperConfig)
+//! on the string entry point of [`crate::SolanaVisualSignConverter`].
+//! [`offchain_message_to_visual_sign`] is the direct, ungated entry point.
+//!
+//! Spec: <https://github.com/solana-foundation/SRFCs/discussions/3>
+
+use crate::utils::escape_untrusted_text;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::VersionedTransaction;
+use std::str::FromStr;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::create_text_field;
+use visualsign::vsptrait::{TransactionParseError, VisualSignOptions};
+use visualsign::{AnnotatedPayloadField, SignablePayload};
+
+/// Prefix of every off-chain message envelope
+pub const SIGNING_DOMAIN: &[u8; 16] = b"\xffsolana offchain";
+
+const APPLICATION_DOMAIN_LEN: usize = 32;
+
+/// Last line of the SIWS preamble, after the requesting domain
+const SIWS_PREAMBLE: &str = " wants you to sign in with your Solana account:";
+
+/// How the message body is encoded, from the envelope header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Printable ASCII and newlines only
+    RestrictedAscii,
+    /// UTF-8, short enough for hardware wallets to display
+    LimitedUtf8,
+    /// UTF-8 of any length
+    ExtendedUtf8,
+}
+
+impl MessageFormat {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::RestrictedAscii),
+            1 => Some(Self::LimitedUtf8),
+            2 => Some(Self::ExtendedUtf8),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::RestrictedAscii => "Restricted ASCII",
+            Self::LimitedUtf8 => "Limited UTF-8",
+            Self::ExtendedUtf8 => "Extended UTF-8",
+        }
+    }
+}
+
+/// A decoded off-chain message envelope
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffchainMessage {
+    pub version: u8,
+    /// Set by the current header layout only
+    pub application_domain: Option<Pubkey>,
+    pub format: MessageFormat,
+    /// Accounts expected to sign; empty for the original header layout
+    pub signers: Vec<Pubkey>,
+    pub text: String,
+}
+
+/// Fields of a Sign-In-With-Solana message. Only the domain and address are
+/// required; everything else is left as written by the requesting site.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SiwsMessage {
+    pub domain: String,
+    pub address: String,
+    pub statement: Option<String>,
+    pub uri: Option<String>,
+    pub version: Option<String>,
+    pub chain_id: Option<String>,
+    pub nonce: Option<String>,
+    pub issued_at: Option<String>,
+    pub expiration_time: Option<String>,
+    pub not_before: Option<String>,
+    pub request_id: Option<String>,
+    pub resources: Vec<String>,
+}
+
+/// Whether `bytes` carry the off-chain message signing domain
+pub fn is_offchain_message(bytes: &[u8]) -> bool {
+    bytes.starts_with(SIGNING_DOMAIN)
+}
+
+/// Decode an off-chain message envelope. The header layout is picked by which
+/// one accounts for every byte of the input.
+pub fn parse_offchain_message(bytes: &[u8]) -> Result<OffchainMessage, TransactionParseError> {
+    let rest = bytes
+        .strip_prefix(SIGNING_DOMAIN.as_slice())
+        .ok_or_else(|| {
+            TransactionParseError::InvalidFormat("missing off-chain message signing domain".into())
+        })?;
+    let (&version, header) = rest.split_first().ok_or_else(|| {
+        TransactionParseError::InvalidFormat("off-chain message has no header".into())
+    })?;
+    if version != 0 {
+        return Err(TransactionParseError::UnsupportedVersion(format!(
+            "off-chain message version {version}"
+        )));
+    }
+
+    let (application_domain, format, signers, body) = split_current_header(header)
+        .or_else(|| split_legacy_header(header))
+        .ok_or_else(|| {
+            TransactionParseError::InvalidFormat(
+                "off-chain message header does not match its length".into(),
+            )
+        })?;
+    let format = MessageFormat::from_byte(format).ok_or_else(|| {
+        TransactionParseError::InvalidFormat(format!("unknown off-chain message format {format}"))
+    })?;
+
+    let text = std::str::from_utf8(body)
+        .map_err(|_| {
+            TransactionParseError::InvalidFormat("off-chain message body is not UTF-8".into())
+        })?
+        .to_string();
+    if format == MessageFormat::RestrictedAscii
+        && !text.chars().all(|c| c == '\n' || (' '..='~').contains(&c))
+    {
+        return Err(TransactionParseError::InvalidFormat(
+            "off-chain message body is not restricted ASCII".into(),
+        ));
+    }
+
+    Ok(OffchainMessage {
+        version,
+        application_domain,
+        format,
+        signers,
+        text,
+    })
+}
+
+type SplitHeader<'a> = (Option<Pubkey>, u8, Vec<Pubkey>, &'a [u8]);
+
+/// `domain[32] format[1] signer_count[1] signers[32 * n] length[2] body`
+fn split_current_header(header: &[u8]) -> Option<SplitHeader<'_>> {
+    let (domain, rest) = header.split_first_chunk::<APPLICATION_DOMAIN_LEN>()?;
+    let (&[format, signer_count], rest) = rest.split_first_chunk::<2>()?;
+    if signer_count == 0 {
+        return None;
+    }
+    let (signers, rest) = rest.split_at_checked(usize::from(signer_count) * 32)?;
+    let body = length_prefixed(rest)?;
+    let signers = signers
+        .chunks_exact(32)
+        .map(|key| Pubkey::try_from(key).ok())
+        .collect::<Option<Vec<_>>>()?;
+    Some((Some(Pubkey::new_from_array(*domain)), format, signers, body))
+}
+
+/// `format[1] length[2] body`
+fn split_legacy_header(header: &[u8]) -> Option<SplitHeader<'_>> {
+    let (&format, rest) = header.split_first()?;
+    Some((None, format, Vec::new(), length_prefixed(rest)?))
+}
+
+/// The body after a little-endian u16 length, if it is exactly that long
+fn length_prefixed(bytes: &[u8]) -> Option<&[u8]> {
+    let (length, body) = bytes.split_first_chunk::<2>()?;
+    (usize::from(u16::from_le_bytes(*length)) == body.len() && !body.is_empty()).then_some(body)
+}
+
+/// Parse `text` as a Sign-In-With-Solana message. Returns `None` unless the
+/// preamble and account address are present.
+pub fn parse_siws(text: &str) -> Option<SiwsMessage> {
+    let mut lines = text.lines();
+    let domain = lines.next()?.strip_suffix(SIWS_PREAMBLE)?;
+    let address = lines.next()?;
+    if domain.is_empty() || Pubkey::from_str(address).is_err() {
+        return None;
+    }
+
+    let mut siws = SiwsMessage {
+        domain: domain.to_string(),
+        address: address.to_string(),
+        ..Default::default()
+    };
+    let mut in_resources = false;
+    for line in lines.filter(|line| !line.is_empty()) {
+        if in_resources {
+            if let Some(resource) = line.strip_prefix("- ") {
+                siws.resources.push(resource.to_string());
+                continue;
+            }
+            in_resources = false;
+        }
+        let (slot, value) = match line.split_once(": ") {
+            Some(("URI", value)) => (&mut siws.uri, value),
+            Some(("Version", value)) => (&mut siws.version, value),
+            Some(("Chain ID", value)) => (&mut siws.chain_id, value),
+            Some(("Nonce", value)) => (&mut siws.nonce, value),
+            Some(("Issued At", value)) => (&mut siws.issued_at, value),
+            Some(("Expiration Time", value)) => (&mut siws.expiration_time, value),
+            Some(("Not Before", value)) => (&mut siws.not_before, value),
+            Some(("Request ID", value)) => (&mut siws.request_id, value),
+            _ if line == "Resources:" => {
+                in_resources = true;
+                continue;
+            }
+            // The statement is the only free text, ahead of every field
+            _ if siws.statement.is_none() && siws.uri.is_none() => (&mut siws.statement, line),
+            _ => return None,
+        };
+        if slot.replace(value.to_string()).is_some() {
+            // A repeated field means the text is ambiguous
+            return None;
+        }
+    }
+    Some(siws)
+}
+
+/// Whether `bytes` are exactly a serialized Solana transaction or
+/// transaction message
+fn is_solana_transaction(bytes: &[u8]) -> bool {
+    let transaction_size = bincode::deserialize::<VersionedTransaction>(bytes)
+        .ok()
+        .and_then(|tx| bincode::serialized_size(&tx).ok());
+    let message_size = bincode::deserialize::<VersionedMessage>(bytes)
+        .ok()
+        .filter(|message| message.header().num_required_signatures > 0)
+        .and_then(|message| bincode::serialized_size(&message).ok());
+    [transaction_size, message_size].contains(&Some(bytes.len() as u64))
+}
+
+/// Whether `text` is nothing but a hash or signature, in base58 or hex
+fn looks_like_hash(text: &str) -> bool {
+    let text = text.trim();
+    let hex = text.strip_prefix("0x").unwrap_or(text);
+    Pubkey::from_str(text).is_ok()
+        || Signature::from_str(text).is_ok()
+        || ((hex.len() == 64 || hex.len() == 128) && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
+/// Refuse raw bytes that could stand for something other than a message
+fn check_raw_message(bytes: &[u8]) -> Result<&str, TransactionParseError> {
+    if is_solana_transaction(bytes) {
+        return Err(TransactionParseError::InvalidFormat(
+            "message is a serialized Solana transaction, not an off-chain message".into(),
+        ));
+    }
+    let text = std::str::from_utf8(bytes).ok();
+    if text.is_none() && (bytes.len() == 32 || bytes.len() == 64) {
+        return Err(TransactionParseError::InvalidFormat(format!(
+            "message is {} raw bytes, the size of a transaction hash or signature",
+            bytes.len()
+        )));
+    }
+    text.ok_or_else(|| TransactionParseError::InvalidFormat("message is not UTF-8".into()))
+}
+
+/// Convert an off-chain message to a payload. `bytes` is either a full
+/// envelope or the raw message a wallet was asked to sign.
+pub fn offchain_message_to_visual_sign(
+    bytes: &[u8],
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let mut fields = vec![create_text_field("Network", "Solana")?];
+
+    let (text, signers) = if is_offchain_message(bytes) {
+        let message = parse_offchain_message(bytes)?;
+        fields.push(create_text_field("Message Format", message.format.label())?);
+        if let Some(domain) = message.application_domain {
+            fields.push(create_text_field(
+                "Application Domain",
+                &domain.to_string(),
+            )?);
+        }
+        for signer in &message.signers {
+            fields.push(create_text_field("Signer", &signer.to_string())?);
+        }
+        (message.text, message.signers)
+    } else {
+        (check_raw_message(bytes)?.to_string(), Vec::new())
+    };
+
+    let siws = parse_siws(&text);
+    match &siws {
+        Some(siws) => fields.extend(siws_fields(siws, &signers)?),
+        None => {
+            if looks_like_hash(&text) {
+                fields.push(create_text_field(
+                    "Warning",
+                    "Message is only a hash or signature; it may stand for a transaction",
+                )?);
+            }
+            fields.push(create_text_field("Message", &escape_untrusted_text(&text))?);
+        }
+    }
+
+    let title = options.transaction_name.unwrap_or_else(|| {
+        if siws.is_some() {
+            "Sign In With Solana".to_string()
+        } else {
+            "Solana Off-chain Message".to_string()
+        }
+    });
+    Ok(SignablePayload::new(
+        0,
+        title,
+        None,
+        fields
+            .into_iter()
+            .map(|f| f.signable_payload_field)
+            .collect(),
+        "SolanaOffchainMessage".to_string(),
+    ))
+}
+
+fn siws_fields(
+    siws: &SiwsMessage,
+    signers: &[Pubkey],
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let mut fields = vec![
+        create_text_field("Domain", &escape_untrusted_text(&siws.domain))?,
+        create_text_field("Account", &siws.address)?,
+    ];
+    if !signers.is_empty() && !signers.iter().any(|s| s.to_string() == siws.address) {
+        fields.push(create_text_field(
+            "Warning",
+            "Sign-in account is not one of the message signers",
+        )?);
+    }
+    for (label, value) in [
+        ("Statement", &siws.statement),
+        ("URI", &siws.uri),
+        ("Version", &siws.version),
+        ("Chain ID", &siws.chain_id),
+        ("Nonce", &siws.nonce),
+        ("Issued At", &siws.issued_at),
+        ("Expiration Time", &siws.expiration_time),
+        ("Not Before", &siws.not_before),
+        ("Request ID", &siws.request_id),
+    ] {
+        if let Some(value) = value {
+            fields.push(create_text_field(label, &escape_untrusted_text(value))?);
+        }
+    }
+    for resource in &siws.resources {
+        fields.push(create_text_field(
+            "Resource",
+            &escape_untrusted_text(resource),
+        )?);
+    }
+    Ok(fields)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+    use solana_sdk::message::Message;
+
+    fn siws_text(address: &Pubkey) -> String {
+        format!(
+            "example.com wants you to sign in with your Solana account:\n\
+             {address}\n\
+             \n\
+             Sign in to Example: read only\n\
+             \n\
+             URI: https://example.com/login\n\
+             Version: 1\n\
+             Chain ID: mainnet\n\
+             Nonce: 32891756\n\
+             Issued At: 2024-01-01T00:00:00Z\n\
+             Expiration Time: 2024-01-01T00:10:00Z\n\
+             Resources:\n\
+             - https://example.com/terms"
+        )
+    }
+
+    fn current_envelope(format: u8, signers: &[Pubkey], body: &[u8]) -> Vec<u8> {
+        let mut bytes = SIGNING_DOMAIN.to_vec();
+        bytes.push(0);
+        bytes.extend_from_slice(&[7u8; 32]);
+        bytes.push(format);
+        bytes.push(signers.len() as u8);
+        for signer in signers {
+            bytes.extend_from_slice(signer.as_ref());
+        }
+        bytes.extend_from_slice(&(body.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(body);
+        bytes
+    }
+
+    fn legacy_envelope(format: u8, body: &[u8]) -> Vec<u8> {
+        let mut bytes = SIGNING_DOMAIN.to_vec();
+        bytes.extend_from_slice(&[0, format]);
+        bytes.extend_from_slice(&(body.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(body);
+        bytes
+    }
+
+    fn rows(payload: &SignablePayload) -> Vec<(String, String)> {
+        payload
+            .fields
+            .iter()
+            .map(|f| (f.label().clone(), f.fallback_text().clone()))
+            .collect()
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    #[test]
+    fn test_parses_both_header_layouts() {
+        let signer = Pubkey::new_unique();
+        let current = parse_offchain_message(&current_envelope(0, &[signer], b"hello")).unwrap();
+        assert_eq!(
+            current.application_domain,
+            Some(Pubkey::new_from_array([7; 32]))
+        );
+        assert_eq!(current.signers, vec![signer]);
+        assert_eq!(current.text, "hello");
+
+        let legacy = parse_offchain_message(&legacy_envelope(1, "h\u{e9}llo".as_bytes())).unwrap();
+        assert_eq!(legacy.application_domain, None);
+        assert_eq!(legacy.format, MessageFormat::LimitedUtf8);
+        assert_eq!(legacy.text, "h\u{e9}llo");
+    }
+
+    #[test]
+    fn test_rejects_malformed_envelopes() {
+        let mut truncated = legacy_envelope(0, b"hello");
+        truncated.pop();
+        assert!(parse_offchain_message(&truncated).is_err());
+        assert!(parse_offchain_message(&legacy_envelope(0, "h\u{e9}llo".as_bytes())).is_err());
+        assert!(parse_offchain_message(&legacy_envelope(3, b"hello")).is_err());
+
+        let mut next_version = legacy_envelope(0, b"hello");
+        next_version[SIGNING_DOMAIN.len()] = 1;
+        assert!(matches!(
+            parse_offchain_message(&next_version),
+            Err(TransactionParseError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn test_siws_message_shows_domain_nonce_and_expiry() {
+        let signer = Pubkey::new_unique();
+        let envelope = current_envelope(0, &[signer], siws_text(&signer).as_bytes());
+        let payload =
+            offchain_message_to_visual_sign(&envelope, VisualSignOptions::default()).unwrap();
+
+        assert_eq!(payload.title, "Sign In With Solana");
+        assert_eq!(payload.payload_type, "SolanaOffchainMessage");
+        let rows = rows(&payload);
+        assert_eq!(row(&rows, "Domain"), "example.com");
+        assert_eq!(row(&rows, "Account"), signer.to_string());
+        assert_eq!(row(&rows, "Statement"), "Sign in to Example: read only");
+        assert_eq!(row(&rows, "Nonce"), "32891756");
+        assert_eq!(row(&rows, "Expiration Time"), "2024-01-01T00:10:00Z");
+        assert_eq!(row(&rows, "Resource"), "https://example.com/terms");
+        assert!(rows.iter().all(|(label, _)| label != "Warning"));
+        payload.validate_charset().unwrap();
+    }
+
+    #[test]
+    fn test_siws_account_must_be_a_signer() {
+        let envelope = current_envelope(
+            0,
+            &[Pubkey::new_unique()],
+            siws_text(&Pubkey::new_unique()).as_bytes(),
+        );
+        let payload =
+            offchain_message_to_visual_sign(&envelope, VisualSignOptions::default()).unwrap();
+        assert_eq!(
+            row(&rows(&payload), "Warning"),
+            "Sign-in account is not one of the message signers"
+        );
+    }
+
+    #[test]
+    fn test_plain_message_is_escaped() {
+        let payload = offchain_message_to_visual_sign(
+            &legacy_envelope(2, "line one\nline \u{202e}two".as_bytes()),
+            VisualSignOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(payload.title, "Solana Off-chain Message");
+        let rows = rows(&payload);
+        assert_eq!(row(&rows, "Message Format"), "Extended UTF-8");
+        assert_eq!(row(&rows, "Message"), "line one<U+000A>line <U+202E>two");
+    }
+
+    #[test]
+    fn test_raw_transaction_and_hash_are_refused() {
+        let from = Pubkey::new_unique();
+        let message = Message::new(
+            &[Instruction::new_with_bytes(
+                Pubkey::default(),
+                &[2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+                vec![
+                    AccountMeta::new(from, true),
+                    AccountMeta::new(Pubkey::new_unique(), false),
+                ],
+            )],
+            Some(&from),
+        );
+        let serialized = bincode::serialize(&message).unwrap();
+        assert!(
+            offchain_message_to_visual_sign(&serialized, VisualSignOptions::default()).is_err()
+        );
+        assert!(
+            offchain_message_to_visual_sign(&[0xab; 32], VisualSignOptions::default()).is_err()
+        );
+
+        // A hash spelled out as text is shown, with a warning
+        let signature = Signature::from([9u8; 64]).to_string();
+        let payload =
+            offchain_message_to_visual_sign(signature.as_bytes(), VisualSignOptions::default())
+                .unwrap();
+        assert!(row(&rows(&payload), "Warning").contains("hash or signature"));
+    }
+
+    #[test]
+    fn test_parse_siws_rejects_other_text() {
+        assert!(parse_siws("hello").is_none());
+        assert!(
+            parse_siws("example.com wants you to sign in with your Solana account:\nnot-a-key")
+                .is_none()
+        );
+        let address = Pubkey::new_unique();
+        let repeated = format!("{}\nNonce: 2", siws_text(&address));
+        assert!(parse_siws(&repeated).is_none());
+    }
+}
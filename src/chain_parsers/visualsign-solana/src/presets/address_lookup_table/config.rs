@@ -0,0 +1,26 @@
+//! Configuration for Address Lookup Table program integration
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::BTreeMap;
+
+pub struct AddressLookupTableConfig;
+
+impl SolanaIntegrationConfig for AddressLookupTableConfig {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| {
+            let mut programs = BTreeMap::new();
+            let mut alt_instructions = BTreeMap::new();
+            alt_instructions.insert("*", vec!["*"]);
+            programs.insert(
+                "AddressLookupTab1e1111111111111111111111111",
+                alt_instructions,
+            );
+            SolanaIntegrationConfigData { programs }
+        })
+    }
+}
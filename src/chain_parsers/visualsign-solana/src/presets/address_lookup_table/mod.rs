@@ -0,0 +1,321 @@
+//! Address Lookup Table program preset for Solana
+//! Handles table management on the native Address Lookup Table Program
+//! (AddressLookupTab1e1111111111111111111111111): creating, extending,
+//! freezing, deactivating and closing the tables v0 transactions load
+//! accounts from.
+
+mod config;
+
+use crate::core::{
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
+};
+use config::AddressLookupTableConfig;
+use solana_address_lookup_table_interface::instruction::ProgramInstruction;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{create_number_field, create_text_field};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
+
+// Create a static instance that we can reference
+static ADDRESS_LOOKUP_TABLE_CONFIG: AddressLookupTableConfig = AddressLookupTableConfig;
+
+pub struct AddressLookupTableVisualizer;
+
+impl InstructionVisualizer for AddressLookupTableVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        let instruction =
+            bincode::deserialize::<ProgramInstruction>(context.data()).map_err(|e| {
+                VisualSignError::DecodeError(format!(
+                    "Failed to parse address lookup table instruction: {e}"
+                ))
+            })?;
+
+        create_address_lookup_table_preview_layout(&instruction, context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&ADDRESS_LOOKUP_TABLE_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Payments("AddressLookupTable")
+    }
+}
+
+/// Renders the account at `position` in the instruction's account list
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+/// Title and condensed rows for the instructions that only name the table
+fn table_action(
+    title: &str,
+    lookup_table: &str,
+) -> Result<(String, Vec<AnnotatedPayloadField>), VisualSignError> {
+    let condensed_fields = vec![
+        create_text_field("Instruction", title)?,
+        create_text_field("Lookup Table", lookup_table)?,
+    ];
+    Ok((title.to_string(), condensed_fields))
+}
+
+fn create_address_lookup_table_preview_layout(
+    instruction: &ProgramInstruction,
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id_str = match context.program_id() {
+        ProgramRef::Resolved(pk) => pk.to_string(),
+        ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
+    };
+    let lookup_table = account_str(context, 0);
+    let authority = account_str(context, 1);
+
+    let (title, condensed_fields, mut expanded_fields) = match instruction {
+        // Accounts: [0] lookup table, [1] authority, [2] payer (signer),
+        // [3] system program
+        ProgramInstruction::CreateLookupTable {
+            recent_slot,
+            bump_seed,
+        } => {
+            let (title, condensed_fields) = table_action("Create Lookup Table", &lookup_table)?;
+            let expanded_fields = vec![
+                create_text_field("Lookup Table", &lookup_table)?,
+                create_text_field("Authority", &authority)?,
+                create_text_field("Payer", &account_str(context, 2))?,
+                create_number_field("Recent Slot", &recent_slot.to_string(), "")?,
+                create_number_field("Bump Seed", &bump_seed.to_string(), "")?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] lookup table, [1] authority
+        ProgramInstruction::FreezeLookupTable => {
+            let (title, condensed_fields) = table_action("Freeze Lookup Table", &lookup_table)?;
+            let expanded_fields = vec![
+                create_text_field("Lookup Table", &lookup_table)?,
+                create_text_field("Authority", &authority)?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] lookup table, [1] authority, then optionally
+        // [2] payer (signer) and [3] system program when the table needs
+        // more rent
+        ProgramInstruction::ExtendLookupTable { new_addresses } => {
+            let count = new_addresses.len();
+            let title = format!(
+                "Extend Lookup Table: {count} address{}",
+                if count == 1 { "" } else { "es" }
+            );
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Lookup Table", &lookup_table)?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Lookup Table", &lookup_table)?,
+                create_text_field("Authority", &authority)?,
+            ];
+            if context.num_accounts() > 2 {
+                expanded_fields.push(create_text_field("Payer", &account_str(context, 2))?);
+            }
+            expanded_fields.push(create_number_field(
+                "New Addresses",
+                &count.to_string(),
+                "",
+            )?);
+            for (i, address) in new_addresses.iter().enumerate() {
+                expanded_fields.push(create_text_field(
+                    &format!("Address {}", i + 1),
+                    &address.to_string(),
+                )?);
+            }
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] lookup table, [1] authority
+        ProgramInstruction::DeactivateLookupTable => {
+            let (title, condensed_fields) = table_action("Deactivate Lookup Table", &lookup_table)?;
+            let expanded_fields = vec![
+                create_text_field("Lookup Table", &lookup_table)?,
+                create_text_field("Authority", &authority)?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] lookup table, [1] authority, [2] rent recipient
+        ProgramInstruction::CloseLookupTable => {
+            let title = "Close Lookup Table".to_string();
+            let recipient = account_str(context, 2);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Lookup Table", &lookup_table)?,
+                create_text_field("Recipient", &recipient)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Lookup Table", &lookup_table)?,
+                create_text_field("Authority", &authority)?,
+                create_text_field("Recipient", &recipient)?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+    };
+
+    expanded_fields.insert(0, create_text_field("Program ID", &program_id_str)?);
+    expanded_fields.push(create_text_field("Raw Data", &hex::encode(context.data()))?);
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(context.data())
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
+
+    /// Visualizes `instruction` with the Address Lookup Table Program at
+    /// index 0 and `accounts` after it, and returns the preview's title and
+    /// expanded rows
+    fn visualize(
+        instruction: &ProgramInstruction,
+        accounts: &[Pubkey],
+    ) -> (String, Vec<(String, String)>) {
+        let mut account_keys = vec![solana_address_lookup_table_interface::program::ID];
+        account_keys.extend_from_slice(accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data: bincode::serialize(instruction).unwrap(),
+        };
+        let sender = SolanaAccount {
+            account_key: accounts[0].to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = AddressLookupTableVisualizer
+            .visualize_tx_commands(&context)
+            .unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect();
+        (preview_layout.title.unwrap().text, rows)
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    fn unique_keys(n: usize) -> Vec<Pubkey> {
+        (0..n).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_create_lookup_table() {
+        let keys = unique_keys(4);
+        let (title, rows) = visualize(
+            &ProgramInstruction::CreateLookupTable {
+                recent_slot: 250_000_000,
+                bump_seed: 254,
+            },
+            &keys,
+        );
+
+        assert_eq!(title, "Create Lookup Table");
+        assert_eq!(row(&rows, "Lookup Table"), keys[0].to_string());
+        assert_eq!(row(&rows, "Authority"), keys[1].to_string());
+        assert_eq!(row(&rows, "Payer"), keys[2].to_string());
+        assert_eq!(row(&rows, "Recent Slot"), "250000000");
+    }
+
+    #[test]
+    fn test_extend_lists_every_new_address() {
+        let keys = unique_keys(4);
+        let new_addresses = unique_keys(2);
+        let (title, rows) = visualize(
+            &ProgramInstruction::ExtendLookupTable {
+                new_addresses: new_addresses.clone(),
+            },
+            &keys,
+        );
+
+        assert_eq!(title, "Extend Lookup Table: 2 addresses");
+        assert_eq!(row(&rows, "Payer"), keys[2].to_string());
+        assert_eq!(row(&rows, "Address 1"), new_addresses[0].to_string());
+        assert_eq!(row(&rows, "Address 2"), new_addresses[1].to_string());
+    }
+
+    #[test]
+    fn test_extend_without_payer() {
+        let keys = unique_keys(2);
+        let (title, rows) = visualize(
+            &ProgramInstruction::ExtendLookupTable {
+                new_addresses: vec![Pubkey::new_unique()],
+            },
+            &keys,
+        );
+
+        assert_eq!(title, "Extend Lookup Table: 1 address");
+        assert!(rows.iter().all(|(label, _)| label != "Payer"));
+    }
+
+    #[test]
+    fn test_close_lookup_table_shows_recipient() {
+        let keys = unique_keys(3);
+        let (title, rows) = visualize(&ProgramInstruction::CloseLookupTable, &keys);
+
+        assert_eq!(title, "Close Lookup Table");
+        assert_eq!(row(&rows, "Authority"), keys[1].to_string());
+        assert_eq!(row(&rows, "Recipient"), keys[2].to_string());
+    }
+}
@@ -5,16 +5,35 @@ mod config;
 use crate::core::{
     InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext, VisualizerKind,
 };
+use crate::utils::format_token_amount;
 use borsh::de::BorshDeserialize;
 use config::ComputeBudgetConfig;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::CompiledInstruction;
+use solana_sdk::pubkey::Pubkey;
 use visualsign::errors::VisualSignError;
-use visualsign::field_builders::{create_number_field, create_raw_data_field, create_text_field};
+use visualsign::field_builders::{
+    create_amount_field, create_number_field, create_raw_data_field, create_text_field,
+};
 use visualsign::{
     AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
     SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
 };
 
+const COMPUTE_BUDGET_PROGRAM_ID: &str = "ComputeBudget111111111111111111111111111111";
+
+/// Compute units the runtime allots each instruction when the transaction
+/// does not set its own limit
+const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u64 = 200_000;
+
+/// Most compute units a single transaction can request
+const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+
+const MICRO_LAMPORTS_PER_LAMPORT: u128 = 1_000_000;
+
+/// Lamports are 1e-9 SOL
+const SOL_DECIMALS: u8 = 9;
+
 // Create a static instance that we can reference
 static COMPUTE_BUDGET_CONFIG: ComputeBudgetConfig = ComputeBudgetConfig;
 
@@ -157,3 +176,132 @@ fn create_compute_budget_preview_layout(
         },
     })
 }
+
+/// Transaction-level "Priority Fee" summary: the compute unit limit times the
+/// compute unit price, rounded up to whole lamports as the runtime charges it.
+///
+/// Without an explicit `SetComputeUnitLimit` the runtime default of 200k units
+/// per non-compute-budget instruction applies, so the figure is an upper
+/// bound rather than an exact charge. Returns `None` when the transaction sets
+/// no compute unit price and therefore pays no priority fee.
+pub(crate) fn create_priority_fee_field(
+    instructions: &[CompiledInstruction],
+    account_keys: &[Pubkey],
+) -> Result<Option<SignablePayloadField>, VisualSignError> {
+    let mut unit_limit = None;
+    let mut unit_price = None;
+    let mut other_instructions: u64 = 0;
+
+    for instruction in instructions {
+        let is_compute_budget = account_keys
+            .get(instruction.program_id_index as usize)
+            .is_some_and(|program_id| program_id.to_string() == COMPUTE_BUDGET_PROGRAM_ID);
+        if !is_compute_budget {
+            other_instructions += 1;
+            continue;
+        }
+        match ComputeBudgetInstruction::try_from_slice(&instruction.data) {
+            Ok(ComputeBudgetInstruction::SetComputeUnitLimit(units)) => {
+                unit_limit = Some(u64::from(units));
+            }
+            Ok(ComputeBudgetInstruction::SetComputeUnitPrice(micro_lamports)) => {
+                unit_price = Some(micro_lamports);
+            }
+            _ => {}
+        }
+    }
+
+    let Some(unit_price) = unit_price.filter(|price| *price > 0) else {
+        return Ok(None);
+    };
+    let unit_limit = unit_limit
+        .unwrap_or(other_instructions.saturating_mul(DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT))
+        .min(MAX_COMPUTE_UNIT_LIMIT);
+
+    let fee_micro_lamports = u128::from(unit_limit) * u128::from(unit_price);
+    let fee_lamports = fee_micro_lamports.div_ceil(MICRO_LAMPORTS_PER_LAMPORT);
+    let fee_lamports = u64::try_from(fee_lamports).unwrap_or(u64::MAX);
+
+    let field = create_amount_field(
+        "Priority Fee",
+        &format_token_amount(fee_lamports, SOL_DECIMALS),
+        "SOL",
+    )?;
+    Ok(Some(field.signable_payload_field))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use borsh::to_vec;
+    use std::str::FromStr;
+
+    fn compute_budget_instruction(instruction: ComputeBudgetInstruction) -> CompiledInstruction {
+        CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data: to_vec(&instruction).unwrap(),
+        }
+    }
+
+    fn other_instruction() -> CompiledInstruction {
+        CompiledInstruction {
+            program_id_index: 1,
+            accounts: vec![],
+            data: vec![2, 0, 0, 0],
+        }
+    }
+
+    fn priority_fee(instructions: &[CompiledInstruction]) -> Option<String> {
+        let account_keys = vec![
+            Pubkey::from_str(COMPUTE_BUDGET_PROGRAM_ID).unwrap(),
+            Pubkey::new_unique(),
+        ];
+        create_priority_fee_field(instructions, &account_keys)
+            .unwrap()
+            .map(|field| field.fallback_text().clone())
+    }
+
+    #[test]
+    fn test_priority_fee_from_limit_and_price() {
+        let instructions = [
+            compute_budget_instruction(ComputeBudgetInstruction::SetComputeUnitLimit(400_000)),
+            compute_budget_instruction(ComputeBudgetInstruction::SetComputeUnitPrice(50_000)),
+            other_instruction(),
+        ];
+        assert_eq!(priority_fee(&instructions).as_deref(), Some("0.00002 SOL"));
+    }
+
+    #[test]
+    fn test_priority_fee_rounds_up_to_whole_lamports() {
+        let instructions = [
+            compute_budget_instruction(ComputeBudgetInstruction::SetComputeUnitLimit(1_000)),
+            compute_budget_instruction(ComputeBudgetInstruction::SetComputeUnitPrice(1)),
+        ];
+        assert_eq!(
+            priority_fee(&instructions).as_deref(),
+            Some("0.000000001 SOL")
+        );
+    }
+
+    #[test]
+    fn test_priority_fee_uses_default_limit_per_instruction() {
+        let instructions = [
+            compute_budget_instruction(ComputeBudgetInstruction::SetComputeUnitPrice(1_000_000)),
+            other_instruction(),
+            other_instruction(),
+        ];
+        // 2 x 200k units at 1 lamport per unit
+        assert_eq!(priority_fee(&instructions).as_deref(), Some("0.0004 SOL"));
+    }
+
+    #[test]
+    fn test_no_priority_fee_without_price() {
+        let instructions = [
+            compute_budget_instruction(ComputeBudgetInstruction::SetComputeUnitLimit(400_000)),
+            other_instruction(),
+        ];
+        assert_eq!(priority_fee(&instructions), None);
+    }
+}
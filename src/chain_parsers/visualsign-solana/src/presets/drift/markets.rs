@@ -0,0 +1,72 @@
+//! Drift market indexes as listed on mainnet. Instructions only carry the
+//! index, so these tables turn it into the name a trader recognises. Indexes
+//! missing here render as `Perp Market {n}` / `Spot Market {n}`.
+
+/// Base asset amounts on perp orders use 1e9 precision
+pub(crate) const BASE_PRECISION_DECIMALS: u8 = 9;
+
+/// Order prices are quoted in USD with 1e6 precision
+pub(crate) const PRICE_PRECISION_DECIMALS: u8 = 6;
+
+const PERP_MARKETS: [&str; 25] = [
+    "SOL-PERP",
+    "BTC-PERP",
+    "ETH-PERP",
+    "APT-PERP",
+    "1MBONK-PERP",
+    "POL-PERP",
+    "ARB-PERP",
+    "DOGE-PERP",
+    "BNB-PERP",
+    "SUI-PERP",
+    "1MPEPE-PERP",
+    "OP-PERP",
+    "RENDER-PERP",
+    "XRP-PERP",
+    "HNT-PERP",
+    "INJ-PERP",
+    "LINK-PERP",
+    "RLB-PERP",
+    "PYTH-PERP",
+    "TIA-PERP",
+    "JTO-PERP",
+    "SEI-PERP",
+    "AVAX-PERP",
+    "WIF-PERP",
+    "JUP-PERP",
+];
+
+/// Spot market symbol and the decimals of its mint, which deposit and
+/// withdraw amounts are denominated in
+const SPOT_MARKETS: [(&str, u8); 12] = [
+    ("USDC", 6),
+    ("SOL", 9),
+    ("mSOL", 9),
+    ("wBTC", 8),
+    ("wETH", 8),
+    ("USDT", 6),
+    ("jitoSOL", 9),
+    ("PYTH", 6),
+    ("bSOL", 9),
+    ("JTO", 9),
+    ("WIF", 6),
+    ("JUP", 6),
+];
+
+pub(crate) fn perp_market_name(index: u16) -> String {
+    PERP_MARKETS.get(usize::from(index)).map_or_else(
+        || format!("Perp Market {index}"),
+        |name| (*name).to_string(),
+    )
+}
+
+/// The base asset of a perp market, e.g. `SOL` for `SOL-PERP`
+pub(crate) fn perp_base_symbol(index: u16) -> Option<&'static str> {
+    PERP_MARKETS
+        .get(usize::from(index))
+        .and_then(|name| name.strip_suffix("-PERP"))
+}
+
+pub(crate) fn spot_market(index: u16) -> Option<(&'static str, u8)> {
+    SPOT_MARKETS.get(usize::from(index)).copied()
+}
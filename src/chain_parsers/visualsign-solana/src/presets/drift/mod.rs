@@ -1,11 +1,17 @@
 //! Drift Protocol preset implementation for Solana
 
 mod config;
+mod markets;
 
 use crate::core::{
     InstructionVisualizer, SolanaIntegrationConfig, VisualizerContext, VisualizerKind,
 };
+use crate::utils::format_token_amount;
 use config::DriftConfig;
+use markets::{
+    BASE_PRECISION_DECIMALS, PRICE_PRECISION_DECIMALS, perp_base_symbol, perp_market_name,
+    spot_market,
+};
 use solana_parser::{
     Idl, SolanaParsedInstructionData, decode_idl_data, parse_instruction_with_idl,
 };
@@ -154,15 +160,22 @@ fn build_parsed_fields(
     let mut condensed_fields = vec![];
     let mut expanded_fields = vec![];
 
+    let summary_fields = build_summary_fields(parsed)?;
+
     condensed_fields.push(create_text_field("Program", "Drift")?);
     condensed_fields.push(create_text_field("Instruction", &parsed.instruction_name)?);
-    for (key, value) in &parsed.program_call_args {
-        condensed_fields.push(create_text_field(key, &format_arg_value(value))?);
+    if summary_fields.is_empty() {
+        for (key, value) in &parsed.program_call_args {
+            condensed_fields.push(create_text_field(key, &format_arg_value(value))?);
+        }
+    } else {
+        condensed_fields.extend(summary_fields.iter().cloned());
     }
 
     expanded_fields.push(create_text_field("Program ID", program_id)?);
     expanded_fields.push(create_text_field("Instruction", &parsed.instruction_name)?);
     expanded_fields.push(create_text_field("Discriminator", &parsed.discriminator)?);
+    expanded_fields.extend(summary_fields);
 
     for (account_name, account_address) in &instruction.named_accounts {
         let label = if parsed.program_call_args.contains_key(account_name) {
@@ -185,6 +198,107 @@ fn build_parsed_fields(
     Ok((title, condensed_fields, expanded_fields))
 }
 
+/// Name of a unit enum variant, which the IDL parser renders either as a bare
+/// string or as a single-key object
+fn enum_variant(value: &serde_json::Value) -> Option<&str> {
+    match value {
+        serde_json::Value::String(s) => Some(s),
+        serde_json::Value::Object(map) if map.len() == 1 => map.keys().next().map(String::as_str),
+        _ => None,
+    }
+}
+
+fn market_index(value: &serde_json::Value) -> Option<u16> {
+    value
+        .get("marketIndex")
+        .and_then(serde_json::Value::as_u64)
+        .and_then(|index| u16::try_from(index).ok())
+}
+
+/// Market, direction, size and price rows for perp orders, and market and
+/// amount rows for collateral deposits and withdrawals. Empty for every
+/// other instruction, which keep the raw argument dump.
+fn build_summary_fields(
+    parsed: &SolanaParsedInstructionData,
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let args = serde_json::Value::Object(parsed.program_call_args.clone());
+    let mut fields = vec![];
+
+    match parsed.instruction_name.as_str() {
+        "placePerpOrder" | "placeAndTakePerpOrder" => {
+            let Some(params) = args.get("params") else {
+                return Ok(fields);
+            };
+            let Some(index) = market_index(params) else {
+                return Ok(fields);
+            };
+            fields.push(create_text_field("Market", &perp_market_name(index))?);
+            if let Some(direction) = params.get("direction").and_then(enum_variant) {
+                fields.push(create_text_field("Direction", direction)?);
+            }
+            if let Some(order_type) = params.get("orderType").and_then(enum_variant) {
+                fields.push(create_text_field("Order Type", order_type)?);
+            }
+            if let Some(size) = params
+                .get("baseAssetAmount")
+                .and_then(serde_json::Value::as_u64)
+            {
+                let size = format_token_amount(size, BASE_PRECISION_DECIMALS);
+                let size = match perp_base_symbol(index) {
+                    Some(symbol) => format!("{size} {symbol}"),
+                    None => size,
+                };
+                fields.push(create_text_field("Size", &size)?);
+            }
+            if let Some(price) = params.get("price").and_then(serde_json::Value::as_u64) {
+                // Market and oracle orders leave the limit price at zero
+                let price = if price == 0 {
+                    "Market".to_string()
+                } else {
+                    format!(
+                        "{} USD",
+                        format_token_amount(price, PRICE_PRECISION_DECIMALS)
+                    )
+                };
+                fields.push(create_text_field("Price", &price)?);
+            }
+            if params
+                .get("reduceOnly")
+                .and_then(serde_json::Value::as_bool)
+                == Some(true)
+            {
+                fields.push(create_text_field("Reduce Only", "Yes")?);
+            }
+        }
+        "deposit" | "withdraw" => {
+            let Some(index) = market_index(&args) else {
+                return Ok(fields);
+            };
+            let market = spot_market(index);
+            let market_name = market.map_or_else(
+                || format!("Spot Market {index}"),
+                |(symbol, _)| symbol.to_string(),
+            );
+            fields.push(create_text_field("Market", &market_name)?);
+            if let Some(amount) = args.get("amount").and_then(serde_json::Value::as_u64) {
+                let amount = match market {
+                    Some((symbol, decimals)) => {
+                        format!("{} {symbol}", format_token_amount(amount, decimals))
+                    }
+                    None => amount.to_string(),
+                };
+                fields.push(create_text_field("Amount", &amount)?);
+            }
+            if args.get("reduceOnly").and_then(serde_json::Value::as_bool) == Some(true) {
+                fields.push(create_text_field("Reduce Only", "Yes")?);
+            }
+        }
+        _ => {}
+    }
+
+    Ok(fields)
+}
+
 fn build_fallback_fields(
     program_id: &str,
 ) -> Result<
@@ -250,8 +364,7 @@ mod tests {
 
     #[test]
     fn test_build_fallback_fields_renders_unknown_instruction() {
-        let (title, condensed, expanded) =
-            build_fallback_fields(DRIFT_PROGRAM_ID).unwrap();
+        let (title, condensed, expanded) = build_fallback_fields(DRIFT_PROGRAM_ID).unwrap();
         assert_eq!(title, "Drift: Unknown Instruction");
         assert_eq!(condensed.len(), 2);
         assert_eq!(expanded.len(), 2);
@@ -261,13 +374,106 @@ mod tests {
     fn test_build_parsed_fields_renders_deposit_instruction() {
         let instruction = make_parsed_instruction("deposit");
         let (title, condensed, expanded) =
-            build_parsed_fields(&instruction, DRIFT_PROGRAM_ID)
-                .unwrap();
+            build_parsed_fields(&instruction, DRIFT_PROGRAM_ID).unwrap();
         assert_eq!(title, "Drift: deposit");
         assert!(!condensed.is_empty());
         assert!(!expanded.is_empty());
     }
 
+    fn with_args(instruction_name: &str, args: serde_json::Value) -> DriftParsedInstruction {
+        let mut instruction = make_parsed_instruction(instruction_name);
+        let serde_json::Value::Object(args) = args else {
+            panic!("args must be a JSON object");
+        };
+        instruction.parsed.program_call_args = args;
+        instruction
+    }
+
+    fn labels_and_values(fields: &[AnnotatedPayloadField]) -> Vec<(String, String)> {
+        fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_place_perp_order_summary() {
+        let instruction = with_args(
+            "placePerpOrder",
+            serde_json::json!({
+                "params": {
+                    "orderType": {"Limit": {}},
+                    "marketType": {"Perp": {}},
+                    "direction": "Short",
+                    "baseAssetAmount": 1_500_000_000u64,
+                    "price": 142_250_000u64,
+                    "marketIndex": 0,
+                    "reduceOnly": true,
+                }
+            }),
+        );
+        let (_, condensed, _) = build_parsed_fields(&instruction, DRIFT_PROGRAM_ID).unwrap();
+
+        assert_eq!(
+            labels_and_values(&condensed)[2..],
+            [
+                ("Market".to_string(), "SOL-PERP".to_string()),
+                ("Direction".to_string(), "Short".to_string()),
+                ("Order Type".to_string(), "Limit".to_string()),
+                ("Size".to_string(), "1.5 SOL".to_string()),
+                ("Price".to_string(), "142.25 USD".to_string()),
+                ("Reduce Only".to_string(), "Yes".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_market_order_on_unlisted_market() {
+        let instruction = with_args(
+            "placeAndTakePerpOrder",
+            serde_json::json!({
+                "params": {
+                    "orderType": "Market",
+                    "direction": "Long",
+                    "baseAssetAmount": 2_000_000_000u64,
+                    "price": 0u64,
+                    "marketIndex": 999,
+                    "reduceOnly": false,
+                },
+                "successCondition": null,
+            }),
+        );
+        let (_, _, expanded) = build_parsed_fields(&instruction, DRIFT_PROGRAM_ID).unwrap();
+        let rows = labels_and_values(&expanded);
+
+        assert!(rows.contains(&("Market".to_string(), "Perp Market 999".to_string())));
+        assert!(rows.contains(&("Size".to_string(), "2".to_string())));
+        assert!(rows.contains(&("Price".to_string(), "Market".to_string())));
+        assert!(rows.iter().all(|(label, _)| label != "Reduce Only"));
+    }
+
+    #[test]
+    fn test_deposit_amount_uses_spot_market_decimals() {
+        let instruction = with_args(
+            "deposit",
+            serde_json::json!({"marketIndex": 0, "amount": 250_000_000u64, "reduceOnly": false}),
+        );
+        let (_, condensed, _) = build_parsed_fields(&instruction, DRIFT_PROGRAM_ID).unwrap();
+
+        assert_eq!(
+            labels_and_values(&condensed)[2..],
+            [
+                ("Market".to_string(), "USDC".to_string()),
+                ("Amount".to_string(), "250 USDC".to_string()),
+            ]
+        );
+    }
+
     #[test]
     fn test_append_raw_data_appends_one_field() {
         let data = &[0x01u8, 0x02, 0x03];
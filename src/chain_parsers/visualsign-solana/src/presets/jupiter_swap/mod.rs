@@ -6,12 +6,13 @@ use crate::core::{
     AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
     VisualizerKind,
 };
-use crate::utils::{SwapTokenInfo, get_token_info};
+use crate::utils::{SwapTokenInfo, TokenRegistry};
 use config::JupiterSwapConfig;
 use solana_parser::{Idl, decode_idl_data, parse_instruction_with_idl};
 use visualsign::errors::VisualSignError;
 use visualsign::field_builders::{
-    create_amount_field, create_number_field, create_raw_data_field, create_text_field,
+    create_amount_field, create_number_field, create_percentage_field, create_preview_layout,
+    create_raw_data_field, create_text_field,
 };
 use visualsign::{
     AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
@@ -28,18 +29,21 @@ pub enum JupiterSwapInstruction {
         out_token: Option<SwapTokenInfo>,
         slippage_bps: u16,
         platform_fee_bps: u8,
+        route_plan: Vec<RouteHop>,
     },
     ExactOutRoute {
         in_token: Option<SwapTokenInfo>,
         out_token: Option<SwapTokenInfo>,
         slippage_bps: u16,
         platform_fee_bps: u8,
+        route_plan: Vec<RouteHop>,
     },
     SharedAccountsRoute {
         in_token: Option<SwapTokenInfo>,
         out_token: Option<SwapTokenInfo>,
         slippage_bps: u16,
         platform_fee_bps: u8,
+        route_plan: Vec<RouteHop>,
     },
     RouteV2 {
         in_token: Option<SwapTokenInfo>,
@@ -47,6 +51,7 @@ pub enum JupiterSwapInstruction {
         slippage_bps: u16,
         platform_fee_bps: u16,
         positive_slippage_bps: u16,
+        route_plan: Vec<RouteHop>,
     },
     ExactOutRouteV2 {
         in_token: Option<SwapTokenInfo>,
@@ -54,6 +59,7 @@ pub enum JupiterSwapInstruction {
         slippage_bps: u16,
         platform_fee_bps: u16,
         positive_slippage_bps: u16,
+        route_plan: Vec<RouteHop>,
     },
     SharedAccountsRouteV2 {
         in_token: Option<SwapTokenInfo>,
@@ -61,6 +67,7 @@ pub enum JupiterSwapInstruction {
         slippage_bps: u16,
         platform_fee_bps: u16,
         positive_slippage_bps: u16,
+        route_plan: Vec<RouteHop>,
     },
     SharedAccountsExactOutRouteV2 {
         in_token: Option<SwapTokenInfo>,
@@ -68,6 +75,7 @@ pub enum JupiterSwapInstruction {
         slippage_bps: u16,
         platform_fee_bps: u16,
         positive_slippage_bps: u16,
+        route_plan: Vec<RouteHop>,
     },
     Unknown {
         /// Optional instruction name from IDL if available
@@ -89,14 +97,14 @@ impl InstructionVisualizer for JupiterSwapVisualizer {
             .map(|i| match context.account(i) {
                 Some(AccountRef::Resolved(pk)) => pk.to_string(),
                 Some(AccountRef::Unresolved { raw_index }) => {
-                    format!("unresolved({raw_index})")
+                    context.unresolved_account_label(raw_index)
                 }
                 None => "unknown".to_string(),
             })
             .collect();
 
         let jupiter_instruction =
-            parse_jupiter_swap_instruction(context.data(), &instruction_accounts)
+            parse_jupiter_swap_instruction(context.data(), &instruction_accounts, context.tokens())
                 .map_err(|e| VisualSignError::DecodeError(e.to_string()))?;
 
         create_jupiter_preview_layout(&jupiter_instruction, context)
@@ -132,10 +140,69 @@ fn extract_u64_arg(
         .ok_or_else(|| format!("Missing or invalid argument: {name}").into())
 }
 
+/// One hop of a route plan: the AMM it swaps through and the share of its
+/// input it takes. Indexes refer to positions in the route's token ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RouteHop {
+    pub amm: String,
+    /// Share of the input in basis points; v1 plans carry whole percents
+    pub share_bps: u16,
+    pub input_index: u8,
+    pub output_index: u8,
+}
+
+/// Decode `route_plan` from the IDL-parsed args. Steps the parser rendered in
+/// an unexpected shape are skipped rather than failing the whole swap.
+fn parse_route_plan(args: &serde_json::Map<String, serde_json::Value>) -> Vec<RouteHop> {
+    args.get("route_plan")
+        .and_then(serde_json::Value::as_array)
+        .map(|steps| steps.iter().filter_map(parse_route_hop).collect())
+        .unwrap_or_default()
+}
+
+fn parse_route_hop(step: &serde_json::Value) -> Option<RouteHop> {
+    // `Swap` is an enum: unit variants come back as a bare string, variants
+    // with fields as a single-key object
+    let amm = match step.get("swap")? {
+        serde_json::Value::String(name) => name.clone(),
+        serde_json::Value::Object(map) if map.len() == 1 => map.keys().next()?.clone(),
+        _ => return None,
+    };
+    // RoutePlanStepV2 carries `bps`, RoutePlanStep a `percent`
+    let share_bps = match step.get("bps").and_then(serde_json::Value::as_u64) {
+        Some(bps) => u16::try_from(bps).ok()?,
+        None => u16::try_from(step.get("percent")?.as_u64()?.checked_mul(100)?).ok()?,
+    };
+    let index = |name: &str| {
+        step.get(name)
+            .and_then(serde_json::Value::as_u64)
+            .and_then(|i| u8::try_from(i).ok())
+    };
+    Some(RouteHop {
+        amm,
+        share_bps,
+        input_index: index("input_index")?,
+        output_index: index("output_index")?,
+    })
+}
+
+/// Formats basis points as a percentage, e.g. 5050 as `50.5%`
+fn format_share(bps: u16) -> String {
+    let whole = bps / 100;
+    match bps % 100 {
+        0 => format!("{whole}%"),
+        fraction => {
+            let fraction = format!("{fraction:02}");
+            format!("{whole}.{}%", fraction.trim_end_matches('0'))
+        }
+    }
+}
+
 /// Parse Jupiter instruction using IDL-based approach
 fn parse_jupiter_instruction_with_idl(
     data: &[u8],
     accounts: &[String],
+    tokens: &TokenRegistry,
 ) -> Result<JupiterSwapInstruction, Box<dyn std::error::Error>> {
     let idl = get_jupiter_idl().ok_or("Jupiter IDL not available")?;
 
@@ -156,16 +223,19 @@ fn parse_jupiter_instruction_with_idl(
             )?)?;
 
             // Get token info (preserve current logic)
-            let in_token = accounts.first().map(|addr| get_token_info(addr, in_amount));
+            let in_token = accounts
+                .first()
+                .map(|addr| tokens.token_info(addr, in_amount));
             let out_token = accounts
                 .get(5)
-                .map(|addr| get_token_info(addr, quoted_out_amount));
+                .map(|addr| tokens.token_info(addr, quoted_out_amount));
 
             Ok(JupiterSwapInstruction::Route {
                 in_token,
                 out_token,
                 slippage_bps,
                 platform_fee_bps,
+                route_plan: parse_route_plan(&parsed.program_call_args),
             })
         }
         "exact_out_route" => {
@@ -181,14 +251,17 @@ fn parse_jupiter_instruction_with_idl(
 
             let in_token = accounts
                 .first()
-                .map(|addr| get_token_info(addr, quoted_in_amount));
-            let out_token = accounts.get(5).map(|addr| get_token_info(addr, out_amount));
+                .map(|addr| tokens.token_info(addr, quoted_in_amount));
+            let out_token = accounts
+                .get(5)
+                .map(|addr| tokens.token_info(addr, out_amount));
 
             Ok(JupiterSwapInstruction::ExactOutRoute {
                 in_token,
                 out_token,
                 slippage_bps,
                 platform_fee_bps,
+                route_plan: parse_route_plan(&parsed.program_call_args),
             })
         }
         "shared_accounts_route" => {
@@ -202,25 +275,30 @@ fn parse_jupiter_instruction_with_idl(
                 "platform_fee_bps",
             )?)?;
 
-            let in_token = accounts.first().map(|addr| get_token_info(addr, in_amount));
+            let in_token = accounts
+                .first()
+                .map(|addr| tokens.token_info(addr, in_amount));
             let out_token = accounts
                 .get(5)
-                .map(|addr| get_token_info(addr, quoted_out_amount));
+                .map(|addr| tokens.token_info(addr, quoted_out_amount));
 
             Ok(JupiterSwapInstruction::SharedAccountsRoute {
                 in_token,
                 out_token,
                 slippage_bps,
                 platform_fee_bps,
+                route_plan: parse_route_plan(&parsed.program_call_args),
             })
         }
-        "route_v2" => parse_route_v2(&parsed.program_call_args, accounts, false, false),
-        "exact_out_route_v2" => parse_route_v2(&parsed.program_call_args, accounts, true, false),
+        "route_v2" => parse_route_v2(&parsed.program_call_args, accounts, tokens, false, false),
+        "exact_out_route_v2" => {
+            parse_route_v2(&parsed.program_call_args, accounts, tokens, true, false)
+        }
         "shared_accounts_route_v2" => {
-            parse_route_v2(&parsed.program_call_args, accounts, false, true)
+            parse_route_v2(&parsed.program_call_args, accounts, tokens, false, true)
         }
         "shared_accounts_exact_out_route_v2" => {
-            parse_route_v2(&parsed.program_call_args, accounts, true, true)
+            parse_route_v2(&parsed.program_call_args, accounts, tokens, true, true)
         }
         _ => Ok(JupiterSwapInstruction::Unknown {
             instruction_name: Some(parsed.instruction_name.clone()),
@@ -233,6 +311,7 @@ fn parse_jupiter_instruction_with_idl(
 fn parse_route_v2(
     args: &serde_json::Map<String, serde_json::Value>,
     accounts: &[String],
+    tokens: &TokenRegistry,
     exact_out: bool,
     shared: bool,
 ) -> Result<JupiterSwapInstruction, Box<dyn std::error::Error>> {
@@ -254,12 +333,14 @@ fn parse_route_v2(
         (in_amount, quoted_out_amount)
     };
 
+    let route_plan = parse_route_plan(args);
+
     let in_token = accounts
         .get(source_mint_idx)
-        .map(|addr| get_token_info(addr, in_amount));
+        .map(|addr| tokens.token_info(addr, in_amount));
     let out_token = accounts
         .get(destination_mint_idx)
-        .map(|addr| get_token_info(addr, out_amount));
+        .map(|addr| tokens.token_info(addr, out_amount));
 
     Ok(match (exact_out, shared) {
         (false, false) => JupiterSwapInstruction::RouteV2 {
@@ -268,6 +349,7 @@ fn parse_route_v2(
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            route_plan,
         },
         (true, false) => JupiterSwapInstruction::ExactOutRouteV2 {
             in_token,
@@ -275,6 +357,7 @@ fn parse_route_v2(
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            route_plan,
         },
         (false, true) => JupiterSwapInstruction::SharedAccountsRouteV2 {
             in_token,
@@ -282,6 +365,7 @@ fn parse_route_v2(
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            route_plan,
         },
         (true, true) => JupiterSwapInstruction::SharedAccountsExactOutRouteV2 {
             in_token,
@@ -289,6 +373,7 @@ fn parse_route_v2(
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            route_plan,
         },
     })
 }
@@ -296,12 +381,13 @@ fn parse_route_v2(
 fn parse_jupiter_swap_instruction(
     data: &[u8],
     accounts: &[String],
+    tokens: &TokenRegistry,
 ) -> Result<JupiterSwapInstruction, &'static str> {
     if data.len() < 8 {
         return Err("Invalid instruction data length");
     }
 
-    match parse_jupiter_instruction_with_idl(data, accounts) {
+    match parse_jupiter_instruction_with_idl(data, accounts, tokens) {
         Ok(instruction) => Ok(instruction),
         Err(e) => {
             tracing::warn!("Failed to parse Jupiter instruction with IDL: {e}");
@@ -319,18 +405,21 @@ fn format_jupiter_swap_instruction(instruction: &JupiterSwapInstruction) -> Stri
             out_token,
             slippage_bps,
             platform_fee_bps,
+            ..
         }
         | JupiterSwapInstruction::ExactOutRoute {
             in_token,
             out_token,
             slippage_bps,
             platform_fee_bps,
+            ..
         }
         | JupiterSwapInstruction::SharedAccountsRoute {
             in_token,
             out_token,
             slippage_bps,
             platform_fee_bps,
+            ..
         } => {
             let instruction_type = match instruction {
                 JupiterSwapInstruction::Route { .. } => "Jupiter Swap",
@@ -364,6 +453,7 @@ fn format_jupiter_swap_instruction(instruction: &JupiterSwapInstruction) -> Stri
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            ..
         }
         | JupiterSwapInstruction::ExactOutRouteV2 {
             in_token,
@@ -371,6 +461,7 @@ fn format_jupiter_swap_instruction(instruction: &JupiterSwapInstruction) -> Stri
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            ..
         }
         | JupiterSwapInstruction::SharedAccountsRouteV2 {
             in_token,
@@ -378,6 +469,7 @@ fn format_jupiter_swap_instruction(instruction: &JupiterSwapInstruction) -> Stri
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            ..
         }
         | JupiterSwapInstruction::SharedAccountsExactOutRouteV2 {
             in_token,
@@ -385,6 +477,7 @@ fn format_jupiter_swap_instruction(instruction: &JupiterSwapInstruction) -> Stri
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            ..
         } => {
             let instruction_type = match instruction {
                 JupiterSwapInstruction::RouteV2 { .. } => "Jupiter Swap V2",
@@ -491,6 +584,37 @@ fn create_jupiter_preview_layout(
     })
 }
 
+/// One nested preview per route hop, titled with the AMM and its share of
+/// the input
+fn create_route_plan_fields(
+    route_plan: &[RouteHop],
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    route_plan
+        .iter()
+        .enumerate()
+        .map(
+            |(i, hop)| -> Result<AnnotatedPayloadField, VisualSignError> {
+                let share = format_share(hop.share_bps);
+                let fields = vec![
+                    create_text_field("AMM", &hop.amm)
+                        .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
+                    create_text_field("Percent of Input", &share)
+                        .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
+                    create_number_field("Input Token Index", &hop.input_index.to_string(), "")
+                        .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
+                    create_number_field("Output Token Index", &hop.output_index.to_string(), "")
+                        .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
+                ];
+                Ok(create_preview_layout(
+                    &format!("Route Hop {}", i + 1),
+                    format!("{}: {share} of input", hop.amm),
+                    fields,
+                ))
+            },
+        )
+        .collect()
+}
+
 fn create_jupiter_swap_expanded_fields(
     instruction: &JupiterSwapInstruction,
     context: &VisualizerContext,
@@ -510,18 +634,21 @@ fn create_jupiter_swap_expanded_fields(
             out_token,
             slippage_bps,
             platform_fee_bps,
+            route_plan,
         }
         | JupiterSwapInstruction::ExactOutRoute {
             in_token,
             out_token,
             slippage_bps,
             platform_fee_bps,
+            route_plan,
         }
         | JupiterSwapInstruction::SharedAccountsRoute {
             in_token,
             out_token,
             slippage_bps,
             platform_fee_bps,
+            route_plan,
         } => {
             // Add input token fields
             if let Some(token) = in_token {
@@ -556,17 +683,19 @@ fn create_jupiter_swap_expanded_fields(
 
             // Add slippage field
             fields.push(
-                create_number_field("Slippage", &slippage_bps.to_string(), "bps")
+                create_percentage_field("Slippage", &slippage_bps.to_string())
                     .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
             );
 
             // Add platform fee field if non-zero
             if *platform_fee_bps > 0 {
                 fields.push(
-                    create_number_field("Platform Fee", &platform_fee_bps.to_string(), "bps")
+                    create_percentage_field("Platform Fee", &platform_fee_bps.to_string())
                         .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
                 );
             }
+
+            fields.extend(create_route_plan_fields(route_plan)?);
         }
         JupiterSwapInstruction::RouteV2 {
             in_token,
@@ -574,6 +703,7 @@ fn create_jupiter_swap_expanded_fields(
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            route_plan,
         }
         | JupiterSwapInstruction::ExactOutRouteV2 {
             in_token,
@@ -581,6 +711,7 @@ fn create_jupiter_swap_expanded_fields(
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            route_plan,
         }
         | JupiterSwapInstruction::SharedAccountsRouteV2 {
             in_token,
@@ -588,6 +719,7 @@ fn create_jupiter_swap_expanded_fields(
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            route_plan,
         }
         | JupiterSwapInstruction::SharedAccountsExactOutRouteV2 {
             in_token,
@@ -595,6 +727,7 @@ fn create_jupiter_swap_expanded_fields(
             slippage_bps,
             platform_fee_bps,
             positive_slippage_bps,
+            route_plan,
         } => {
             if let Some(token) = in_token {
                 fields.extend([
@@ -627,27 +760,28 @@ fn create_jupiter_swap_expanded_fields(
             }
 
             fields.push(
-                create_number_field("Slippage", &slippage_bps.to_string(), "bps")
+                create_percentage_field("Slippage", &slippage_bps.to_string())
                     .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
             );
 
             if *platform_fee_bps > 0 {
                 fields.push(
-                    create_number_field("Platform Fee", &platform_fee_bps.to_string(), "bps")
+                    create_percentage_field("Platform Fee", &platform_fee_bps.to_string())
                         .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
                 );
             }
 
             if *positive_slippage_bps > 0 {
                 fields.push(
-                    create_number_field(
+                    create_percentage_field(
                         "Positive Slippage",
                         &positive_slippage_bps.to_string(),
-                        "bps",
                     )
                     .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
                 );
             }
+
+            fields.extend(create_route_plan_fields(route_plan)?);
         }
         JupiterSwapInstruction::Unknown { instruction_name } => {
             let status_text = if let Some(name) = instruction_name {
@@ -675,6 +809,7 @@ fn create_jupiter_swap_expanded_fields(
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
     use super::*;
+    use crate::utils::RegisteredToken;
     use solana_parser::solana::structs::SolanaAccount;
     use solana_sdk::instruction::CompiledInstruction;
     use solana_sdk::pubkey::Pubkey;
@@ -748,7 +883,8 @@ mod tests {
         let data = fixture_instruction_data();
         let accounts = fixture_accounts();
 
-        let parsed = parse_jupiter_swap_instruction(&data, &accounts).unwrap();
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &accounts, &TokenRegistry::new()).unwrap();
 
         match parsed {
             JupiterSwapInstruction::Route { slippage_bps, .. } => {
@@ -774,7 +910,7 @@ mod tests {
         assert!(has_program_id, "Should have Program ID field");
 
         let has_slippage = fields.iter().any(|f| {
-            if let SignablePayloadField::Number { common, .. } = &f.signable_payload_field {
+            if let SignablePayloadField::Percentage { common, .. } = &f.signable_payload_field {
                 common.label == "Slippage"
             } else {
                 false
@@ -790,7 +926,8 @@ mod tests {
         let data = fixture_instruction_data();
         let accounts = fixture_accounts();
 
-        let result = parse_jupiter_swap_instruction(&data, &accounts).unwrap();
+        let result =
+            parse_jupiter_swap_instruction(&data, &accounts, &TokenRegistry::new()).unwrap();
 
         match result {
             JupiterSwapInstruction::Route { slippage_bps, .. } => {
@@ -812,9 +949,9 @@ mod tests {
                         && field.get("Type").and_then(|t| t.as_str()) == Some("text_v2")
                 });
 
-                // `number` fields render as `amount_v2` on the wire (VSP has no
-                // `number` type; numbers are unitless/with-unit amounts); the
-                // in-memory variant stays Number.
+                // `percentage` fields render as `amount_v2` on the wire (VSP has no
+                // `percentage` type), with `%` as the abbreviation; the in-memory
+                // variant stays Percentage.
                 let has_slippage = fields_array.iter().any(|field| {
                     field.get("Label").and_then(|l| l.as_str()) == Some("Slippage")
                         && field.get("Type").and_then(|t| t.as_str()) == Some("amount_v2")
@@ -857,6 +994,7 @@ mod tests {
             out_token: None,
             slippage_bps: 50,
             platform_fee_bps: 100,
+            route_plan: vec![],
         };
 
         let formatted = format_jupiter_swap_instruction(&instruction);
@@ -870,7 +1008,7 @@ mod tests {
         let fields = create_jupiter_swap_expanded_fields(&instruction, &tcd.context()).unwrap();
 
         let has_platform_fee = fields.iter().any(|f| {
-            if let SignablePayloadField::Number { common, .. } = &f.signable_payload_field {
+            if let SignablePayloadField::Percentage { common, .. } = &f.signable_payload_field {
                 common.label == "Platform Fee"
             } else {
                 false
@@ -892,7 +1030,9 @@ mod tests {
 
         let accounts = vec!["JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4".to_string()];
 
-        let result = parse_jupiter_swap_instruction(&garbage_data, &accounts).unwrap();
+        let result =
+            parse_jupiter_swap_instruction(&garbage_data, &accounts, &TokenRegistry::new())
+                .unwrap();
         assert!(
             matches!(
                 result,
@@ -1000,7 +1140,8 @@ mod tests {
         let data: Vec<u8> = [discriminator, body].concat();
         let accounts = fixture_accounts();
 
-        let parsed = parse_jupiter_swap_instruction(&data, &accounts).unwrap();
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &accounts, &TokenRegistry::new()).unwrap();
 
         match &parsed {
             JupiterSwapInstruction::ExactOutRoute {
@@ -1008,6 +1149,7 @@ mod tests {
                 out_token,
                 slippage_bps,
                 platform_fee_bps,
+                ..
             } => {
                 assert_eq!(*slippage_bps, 50, "Slippage should be 50 bps");
                 assert_eq!(*platform_fee_bps, 0, "Platform fee should be 0");
@@ -1046,7 +1188,7 @@ mod tests {
         assert!(has_program_id, "Should have Program ID field");
 
         let has_slippage = fields.iter().any(|f| {
-            if let SignablePayloadField::Number { common, .. } = &f.signable_payload_field {
+            if let SignablePayloadField::Percentage { common, .. } = &f.signable_payload_field {
                 common.label == "Slippage"
             } else {
                 false
@@ -1064,7 +1206,8 @@ mod tests {
         let data: Vec<u8> = [discriminator, id_byte, body].concat();
         let accounts = fixture_accounts();
 
-        let parsed = parse_jupiter_swap_instruction(&data, &accounts).unwrap();
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &accounts, &TokenRegistry::new()).unwrap();
 
         match &parsed {
             JupiterSwapInstruction::SharedAccountsRoute {
@@ -1072,6 +1215,7 @@ mod tests {
                 out_token,
                 slippage_bps,
                 platform_fee_bps,
+                ..
             } => {
                 assert_eq!(*slippage_bps, 50, "Slippage should be 50 bps");
                 assert_eq!(*platform_fee_bps, 0, "Platform fee should be 0");
@@ -1110,7 +1254,7 @@ mod tests {
         assert!(has_program_id, "Should have Program ID field");
 
         let has_slippage = fields.iter().any(|f| {
-            if let SignablePayloadField::Number { common, .. } = &f.signable_payload_field {
+            if let SignablePayloadField::Percentage { common, .. } = &f.signable_payload_field {
                 common.label == "Slippage"
             } else {
                 false
@@ -1162,7 +1306,8 @@ mod tests {
         let data: Vec<u8> = [discriminator, body].concat();
         let accounts = fixture_accounts();
 
-        let parsed = parse_jupiter_swap_instruction(&data, &accounts).unwrap();
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &accounts, &TokenRegistry::new()).unwrap();
 
         match &parsed {
             JupiterSwapInstruction::RouteV2 {
@@ -1171,6 +1316,7 @@ mod tests {
                 slippage_bps,
                 platform_fee_bps,
                 positive_slippage_bps,
+                ..
             } => {
                 assert_eq!(*slippage_bps, 50);
                 assert_eq!(*platform_fee_bps, 0);
@@ -1191,7 +1337,7 @@ mod tests {
         let fields = create_jupiter_swap_expanded_fields(&parsed, &tcd.context()).unwrap();
 
         let has_positive_slippage = fields.iter().any(|f| {
-            if let SignablePayloadField::Number { common, .. } = &f.signable_payload_field {
+            if let SignablePayloadField::Percentage { common, .. } = &f.signable_payload_field {
                 common.label == "Positive Slippage"
             } else {
                 false
@@ -1212,7 +1358,8 @@ mod tests {
         let data: Vec<u8> = [discriminator, body].concat();
         let accounts = fixture_accounts();
 
-        let parsed = parse_jupiter_swap_instruction(&data, &accounts).unwrap();
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &accounts, &TokenRegistry::new()).unwrap();
 
         match &parsed {
             JupiterSwapInstruction::ExactOutRouteV2 {
@@ -1221,6 +1368,7 @@ mod tests {
                 slippage_bps,
                 platform_fee_bps,
                 positive_slippage_bps,
+                ..
             } => {
                 assert_eq!(*slippage_bps, 50);
                 assert_eq!(*platform_fee_bps, 0);
@@ -1247,7 +1395,8 @@ mod tests {
         let data: Vec<u8> = [discriminator, id_byte, body].concat();
         let accounts = fixture_accounts_shared_v2();
 
-        let parsed = parse_jupiter_swap_instruction(&data, &accounts).unwrap();
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &accounts, &TokenRegistry::new()).unwrap();
 
         match &parsed {
             JupiterSwapInstruction::SharedAccountsRouteV2 {
@@ -1256,6 +1405,7 @@ mod tests {
                 slippage_bps,
                 platform_fee_bps,
                 positive_slippage_bps,
+                ..
             } => {
                 assert_eq!(*slippage_bps, 50);
                 assert_eq!(*platform_fee_bps, 10);
@@ -1283,7 +1433,8 @@ mod tests {
         let data: Vec<u8> = [discriminator, id_byte, body].concat();
         let accounts = fixture_accounts_shared_v2();
 
-        let parsed = parse_jupiter_swap_instruction(&data, &accounts).unwrap();
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &accounts, &TokenRegistry::new()).unwrap();
 
         match &parsed {
             JupiterSwapInstruction::SharedAccountsExactOutRouteV2 {
@@ -1301,4 +1452,138 @@ mod tests {
         let formatted = format_jupiter_swap_instruction(&parsed);
         assert!(formatted.contains("Jupiter Shared Accounts Exact Out Route V2"));
     }
+
+    #[test]
+    fn test_registered_token_scales_amount() {
+        // route_v2 with the destination mint (accounts[4]) registered by the caller
+        let discriminator = hex::decode("bb64facc31c4af14").expect("valid hex");
+        let body = build_route_v2_body(2_000_000, 1_550_653, 50, 0, 0);
+        let data: Vec<u8> = [discriminator, body].concat();
+        let accounts = fixture_accounts();
+
+        let mut tokens = TokenRegistry::new();
+        tokens.insert(
+            accounts[4].clone(),
+            RegisteredToken {
+                symbol: "JUP".to_string(),
+                name: "Jupiter".to_string(),
+                decimals: 6,
+            },
+        );
+        let parsed = parse_jupiter_swap_instruction(&data, &accounts, &tokens).unwrap();
+
+        let JupiterSwapInstruction::RouteV2 { out_token, .. } = &parsed else {
+            panic!("Expected RouteV2, got {parsed:?}");
+        };
+        let out_token = out_token.as_ref().unwrap();
+        assert_eq!(out_token.symbol, "JUP");
+        assert_eq!(out_token.decimals, 6);
+        assert_eq!(out_token.human_readable_amount, "1.550653");
+
+        // Without the registry the same mint is unknown and left unscaled
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &accounts, &TokenRegistry::new()).unwrap();
+        let JupiterSwapInstruction::RouteV2 { out_token, .. } = &parsed else {
+            panic!("Expected RouteV2, got {parsed:?}");
+        };
+        assert_eq!(out_token.as_ref().unwrap().human_readable_amount, "1550653");
+    }
+
+    /// Labels and fallback texts of the nested route hop previews
+    fn route_hop_previews(fields: &[AnnotatedPayloadField]) -> Vec<(String, String)> {
+        fields
+            .iter()
+            .filter_map(|f| match &f.signable_payload_field {
+                SignablePayloadField::PreviewLayout { common, .. } => {
+                    Some((common.label.clone(), common.fallback_text.clone()))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_route_plan_hop_from_fixture() {
+        let data = fixture_instruction_data();
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &fixture_accounts(), &TokenRegistry::new())
+                .unwrap();
+
+        let JupiterSwapInstruction::Route { route_plan, .. } = &parsed else {
+            panic!("Expected Route instruction, got {parsed:?}");
+        };
+        assert_eq!(
+            route_plan,
+            &vec![RouteHop {
+                amm: "WhirlpoolSwapV2".to_string(),
+                share_bps: 10_000,
+                input_index: 0,
+                output_index: 1,
+            }]
+        );
+
+        let tcd = TestContextData::new(&data);
+        let fields = create_jupiter_swap_expanded_fields(&parsed, &tcd.context()).unwrap();
+        assert_eq!(
+            route_hop_previews(&fields),
+            vec![(
+                "Route Hop 1".to_string(),
+                "WhirlpoolSwapV2: 100% of input".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_route_v2_split_route_plan() {
+        // route_v2 discriminator, then a two-hop plan splitting the input
+        // 60/40 between Raydium (variant 7) and MeteoraDlmm (variant 38)
+        let mut data = hex::decode("bb64facc31c4af14").expect("valid hex");
+        let mut body = build_route_v2_body(2_000_000, 1_550_653, 50, 0, 0);
+        body.truncate(body.len() - 4);
+        body.extend_from_slice(&2u32.to_le_bytes());
+        for (variant, bps) in [(7u8, 6_000u16), (38, 4_000)] {
+            body.push(variant);
+            body.extend_from_slice(&bps.to_le_bytes());
+            body.extend_from_slice(&[0, 1]);
+        }
+        data.extend_from_slice(&body);
+
+        let parsed =
+            parse_jupiter_swap_instruction(&data, &fixture_accounts(), &TokenRegistry::new())
+                .unwrap();
+        let tcd = TestContextData::new(&data);
+        let fields = create_jupiter_swap_expanded_fields(&parsed, &tcd.context()).unwrap();
+
+        assert_eq!(
+            route_hop_previews(&fields),
+            vec![
+                (
+                    "Route Hop 1".to_string(),
+                    "Raydium: 60% of input".to_string()
+                ),
+                (
+                    "Route Hop 2".to_string(),
+                    "MeteoraDlmm: 40% of input".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_route_plan_skips_malformed_steps() {
+        let args = serde_json::json!({
+            "route_plan": [
+                { "swap": { "Whirlpool": { "a_to_b": false } }, "percent": 50, "input_index": 0, "output_index": 1 },
+                { "swap": "Raydium", "bps": 2_525, "input_index": 1, "output_index": 2 },
+                { "swap": 3, "percent": 50, "input_index": 0, "output_index": 1 },
+            ]
+        });
+        let route_plan = parse_route_plan(args.as_object().unwrap());
+
+        assert_eq!(route_plan.len(), 2);
+        assert_eq!(route_plan[0].amm, "Whirlpool");
+        assert_eq!(route_plan[0].share_bps, 5_000);
+        assert_eq!(format_share(route_plan[1].share_bps), "25.25%");
+        assert_eq!(format_share(10_050), "100.5%");
+    }
 }
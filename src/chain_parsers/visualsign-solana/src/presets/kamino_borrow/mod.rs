@@ -6,6 +6,7 @@ use crate::core::{
     InstructionView, InstructionVisualizer, SolanaIntegrationConfig, VisualizerContext,
     VisualizerKind,
 };
+use crate::utils::TokenRegistry;
 use config::KaminoBorrowConfig;
 use solana_parser::{
     Idl, SolanaParsedInstructionData, decode_idl_data, parse_instruction_with_idl,
@@ -35,12 +36,15 @@ impl InstructionVisualizer for KaminoBorrowVisualizer {
         let data = context.data();
 
         let instruction_data_hex = hex::encode(data);
-        let fallback_text = format!("Program ID: {}\nData: {instruction_data_hex}", view.program_id);
+        let fallback_text = format!(
+            "Program ID: {}\nData: {instruction_data_hex}",
+            view.program_id
+        );
 
         let parsed = parse_kamino_borrow_instruction(data, &view.accounts);
 
         let (title, condensed_fields, expanded_fields) = match parsed {
-            Ok(parsed) => build_parsed_fields(&parsed, &view.program_id),
+            Ok(parsed) => build_parsed_fields(&parsed, &view.program_id, context.tokens()),
             Err(_) => build_fallback_fields(&view.program_id),
         };
 
@@ -103,14 +107,11 @@ fn parse_kamino_borrow_instruction(
     Ok(KaminoBorrowParsedInstruction {
         parsed,
         named_accounts,
+        accounts: accounts.to_vec(),
     })
 }
 
-fn build_named_accounts(
-    data: &[u8],
-    idl: &Idl,
-    accounts: &[String],
-) -> BTreeMap<String, String> {
+fn build_named_accounts(data: &[u8], idl: &Idl, accounts: &[String]) -> BTreeMap<String, String> {
     let mut named_accounts = BTreeMap::new();
 
     let idl_instruction = idl.instructions.iter().find(|inst| {
@@ -133,11 +134,93 @@ fn build_named_accounts(
 struct KaminoBorrowParsedInstruction {
     parsed: SolanaParsedInstructionData,
     named_accounts: BTreeMap<String, String>,
+    /// Accounts in instruction order. The V2 instructions group their accounts
+    /// into nested structs, so positional lookups go through this list rather
+    /// than `named_accounts`.
+    accounts: Vec<String>,
+}
+
+/// A lending action a user signs: what it does, the amount argument, and the
+/// position of the reserve's liquidity mint in the account list when the
+/// instruction carries one. V2 variants take the V1 accounts first, in the
+/// same order, so the positions hold for both.
+struct LendingAction {
+    action: &'static str,
+    amount_arg: &'static str,
+    mint_position: Option<usize>,
+}
+
+fn lending_action(instruction_name: &str) -> Option<LendingAction> {
+    let (action, amount_arg, mint_position) = match instruction_name
+        .strip_suffix("V2")
+        .unwrap_or(instruction_name)
+    {
+        "depositReserveLiquidity" => ("Deposit", "liquidityAmount", Some(4)),
+        "depositReserveLiquidityAndObligationCollateral" => ("Deposit", "liquidityAmount", Some(5)),
+        "depositObligationCollateral" => ("Deposit Collateral", "collateralAmount", None),
+        "borrowObligationLiquidity" => ("Borrow", "liquidityAmount", Some(5)),
+        "repayObligationLiquidity" => ("Repay", "liquidityAmount", Some(4)),
+        "withdrawObligationCollateralAndRedeemReserveCollateral" => {
+            ("Withdraw", "collateralAmount", Some(5))
+        }
+        "withdrawObligationCollateral" => ("Withdraw Collateral", "collateralAmount", None),
+        _ => return None,
+    };
+    Some(LendingAction {
+        action,
+        amount_arg,
+        mint_position,
+    })
+}
+
+/// Action, token and amount rows for deposits, borrows, repayments and
+/// withdrawals. Liquidity amounts are scaled by the mint's decimals when the
+/// token is known; collateral amounts are in the reserve's cTokens, whose
+/// value depends on the exchange rate, so they stay in base units.
+fn build_summary_fields(
+    instruction: &KaminoBorrowParsedInstruction,
+    tokens: &TokenRegistry,
+) -> Vec<AnnotatedPayloadField> {
+    let mut fields = vec![];
+    let Some(lending) = lending_action(&instruction.parsed.instruction_name) else {
+        return fields;
+    };
+    let mint = lending
+        .mint_position
+        .and_then(|position| instruction.accounts.get(position));
+    let amount = instruction
+        .parsed
+        .program_call_args
+        .get(lending.amount_arg)
+        .and_then(serde_json::Value::as_u64);
+
+    if let Ok(f) = create_text_field("Action", lending.action) {
+        fields.push(f);
+    }
+    if let Some(f) = mint.and_then(|mint| create_text_field("Token", mint).ok()) {
+        fields.push(f);
+    }
+    if let Some(amount) = amount {
+        let rendered = match mint {
+            Some(mint) if lending.amount_arg == "liquidityAmount" => {
+                let info = tokens.token_info(mint, amount);
+                format!("{} {}", info.human_readable_amount, info.symbol)
+            }
+            Some(_) => format!("{amount} (collateral base units)"),
+            None => amount.to_string(),
+        };
+        if let Ok(f) = create_text_field("Amount", &rendered) {
+            fields.push(f);
+        }
+    }
+
+    fields
 }
 
 fn build_parsed_fields(
     instruction: &KaminoBorrowParsedInstruction,
     program_id: &str,
+    tokens: &TokenRegistry,
 ) -> (
     String,
     Vec<AnnotatedPayloadField>,
@@ -155,10 +238,15 @@ fn build_parsed_fields(
     if let Ok(f) = create_text_field("Instruction", &parsed.instruction_name) {
         condensed_fields.push(f);
     }
-    for (key, value) in &parsed.program_call_args {
-        if let Ok(f) = create_text_field(key, &format_arg_value(value)) {
-            condensed_fields.push(f);
+    let summary_fields = build_summary_fields(instruction, tokens);
+    if summary_fields.is_empty() {
+        for (key, value) in &parsed.program_call_args {
+            if let Ok(f) = create_text_field(key, &format_arg_value(value)) {
+                condensed_fields.push(f);
+            }
         }
+    } else {
+        condensed_fields.extend(summary_fields.iter().cloned());
     }
 
     if let Ok(f) = create_text_field("Program ID", program_id) {
@@ -170,6 +258,7 @@ fn build_parsed_fields(
     if let Ok(f) = create_text_field("Discriminator", &parsed.discriminator) {
         expanded_fields.push(f);
     }
+    expanded_fields.extend(summary_fields);
 
     for (account_name, account_address) in &instruction.named_accounts {
         if let Ok(f) = create_text_field(account_name, account_address) {
@@ -268,6 +357,91 @@ mod tests {
         }
     }
 
+    const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    fn make_instruction(
+        instruction_name: &str,
+        args: serde_json::Value,
+        accounts: &[&str],
+    ) -> KaminoBorrowParsedInstruction {
+        let serde_json::Value::Object(program_call_args) = args else {
+            panic!("args must be a JSON object");
+        };
+        KaminoBorrowParsedInstruction {
+            parsed: SolanaParsedInstructionData {
+                instruction_name: instruction_name.to_string(),
+                discriminator: "00".to_string(),
+                named_accounts: Default::default(),
+                program_call_args,
+                idl_source: solana_parser::IdlSource::Custom,
+                idl_hash: String::new(),
+            },
+            named_accounts: BTreeMap::new(),
+            accounts: accounts.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    fn rows(fields: &[AnnotatedPayloadField]) -> Vec<(String, String)> {
+        fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_borrow_v2_resolves_mint_and_scales_amount() {
+        let mut accounts = vec!["other"; 12];
+        accounts[5] = USDC_MINT;
+        let instruction = make_instruction(
+            "borrowObligationLiquidityV2",
+            serde_json::json!({"liquidityAmount": 125_500_000u64}),
+            &accounts,
+        );
+
+        let (_, condensed, _) = build_parsed_fields(
+            &instruction,
+            KAMINO_BORROW_PROGRAM_ID,
+            &TokenRegistry::new(),
+        );
+        assert_eq!(
+            rows(&condensed)[2..],
+            [
+                ("Action".to_string(), "Borrow".to_string()),
+                ("Token".to_string(), USDC_MINT.to_string()),
+                ("Amount".to_string(), "125.5 USDC".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_withdraw_keeps_collateral_amount_in_base_units() {
+        let mut accounts = vec!["other"; 14];
+        accounts[5] = USDC_MINT;
+        let instruction = make_instruction(
+            "withdrawObligationCollateralAndRedeemReserveCollateral",
+            serde_json::json!({"collateralAmount": 42u64}),
+            &accounts,
+        );
+
+        let summary = rows(&build_summary_fields(&instruction, &TokenRegistry::new()));
+        assert!(summary.contains(&("Action".to_string(), "Withdraw".to_string())));
+        assert!(summary.contains(&(
+            "Amount".to_string(),
+            "42 (collateral base units)".to_string()
+        )));
+    }
+
+    #[test]
+    fn test_other_instructions_have_no_summary() {
+        let instruction = make_instruction("refreshReserve", serde_json::json!({}), &[]);
+        assert!(build_summary_fields(&instruction, &TokenRegistry::new()).is_empty());
+    }
+
     #[test]
     fn test_unknown_discriminator_returns_error() {
         let garbage_data = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09];
@@ -0,0 +1,28 @@
+//! Configuration for SPL Memo program integration
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::BTreeMap;
+
+pub struct MemoConfig;
+
+impl SolanaIntegrationConfig for MemoConfig {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| {
+            let mut programs = BTreeMap::new();
+            for program_id in [
+                "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+                "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo",
+            ] {
+                let mut memo_instructions = BTreeMap::new();
+                memo_instructions.insert("*", vec!["*"]);
+                programs.insert(program_id, memo_instructions);
+            }
+            SolanaIntegrationConfigData { programs }
+        })
+    }
+}
@@ -0,0 +1,218 @@
+//! SPL Memo preset for Solana
+//! Handles the Memo Program (MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr) and
+//! its legacy v1 deployment (Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo)
+
+mod config;
+
+use crate::core::{
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
+};
+use crate::utils::escape_untrusted_text;
+use config::MemoConfig;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{create_number_field, create_raw_data_field, create_text_field};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
+
+/// Longest memo text, in characters after escaping, shown before truncating.
+/// The full memo is always available in the Raw Data row.
+const MAX_MEMO_DISPLAY_CHARS: usize = 280;
+
+// Create a static instance that we can reference
+static MEMO_CONFIG: MemoConfig = MemoConfig;
+
+pub struct MemoVisualizer;
+
+impl InstructionVisualizer for MemoVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        create_memo_preview_layout(context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&MEMO_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Payments("Memo")
+    }
+}
+
+/// Cuts `text` to `MAX_MEMO_DISPLAY_CHARS`, marking the cut. The input is
+/// already ASCII, so slicing by byte index is safe.
+fn truncate_memo(text: String) -> String {
+    if text.len() <= MAX_MEMO_DISPLAY_CHARS {
+        return text;
+    }
+    format!("{}... (truncated)", &text[..MAX_MEMO_DISPLAY_CHARS])
+}
+
+fn create_memo_preview_layout(
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id_str = match context.program_id() {
+        ProgramRef::Resolved(pk) => pk.to_string(),
+        ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
+    };
+    let data = context.data();
+
+    // The Memo program rejects memos that are not valid UTF-8, so such an
+    // instruction can only fail on chain; show the bytes rather than guess.
+    let (title, memo_text) = match std::str::from_utf8(data) {
+        Ok(text) => (
+            "Memo".to_string(),
+            truncate_memo(escape_untrusted_text(text)),
+        ),
+        Err(_) => (
+            "Memo (invalid UTF-8)".to_string(),
+            "Invalid UTF-8, see Raw Data".to_string(),
+        ),
+    };
+
+    let condensed_fields = vec![create_text_field("Memo", &memo_text)?];
+
+    let mut expanded_fields = vec![
+        create_text_field("Program ID", &program_id_str)?,
+        create_text_field("Memo", &memo_text)?,
+        create_number_field("Length", &data.len().to_string(), "bytes")?,
+    ];
+    // Accounts: any number of signers the memo is attributed to
+    for i in 0..context.num_accounts() {
+        let signer = match context.account(i) {
+            Some(AccountRef::Resolved(pk)) => pk.to_string(),
+            Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+            None => continue,
+        };
+        expanded_fields.push(create_text_field(&format!("Signer {}", i + 1), &signer)?);
+    }
+    expanded_fields.push(create_raw_data_field(data, Some(hex::encode(data)))?);
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(data)
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    /// Visualizes a memo with `signers` as its accounts and returns the
+    /// preview's title and expanded rows
+    fn visualize(memo: &[u8], signers: &[Pubkey]) -> (String, Vec<(String, String)>) {
+        let mut account_keys =
+            vec![Pubkey::from_str("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr").unwrap()];
+        account_keys.extend_from_slice(signers);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=signers.len() as u8).collect(),
+            data: memo.to_vec(),
+        };
+        let sender = SolanaAccount {
+            account_key: Pubkey::new_unique().to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = MemoVisualizer.visualize_tx_commands(&context).unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect();
+        (preview_layout.title.unwrap().text, rows)
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    #[test]
+    fn test_plain_memo_with_signer() {
+        let signer = Pubkey::new_unique();
+        let (title, rows) = visualize(b"invoice 42", &[signer]);
+
+        assert_eq!(title, "Memo");
+        assert_eq!(row(&rows, "Memo"), "invoice 42");
+        assert_eq!(row(&rows, "Length"), "10 bytes");
+        assert_eq!(row(&rows, "Signer 1"), signer.to_string());
+        assert_eq!(row(&rows, "Raw Data"), hex::encode(b"invoice 42"));
+    }
+
+    #[test]
+    fn test_control_characters_are_escaped() {
+        let (_, rows) = visualize(b"pay\nme", &[]);
+
+        assert_eq!(row(&rows, "Memo"), "pay<U+000A>me");
+    }
+
+    #[test]
+    fn test_long_memo_is_truncated_but_raw_bytes_kept() {
+        let memo = "x".repeat(MAX_MEMO_DISPLAY_CHARS + 20);
+        let (_, rows) = visualize(memo.as_bytes(), &[]);
+
+        let shown = row(&rows, "Memo");
+        assert!(shown.ends_with("... (truncated)"));
+        assert_eq!(
+            shown.len(),
+            MAX_MEMO_DISPLAY_CHARS + "... (truncated)".len()
+        );
+        assert_eq!(row(&rows, "Raw Data"), hex::encode(memo.as_bytes()));
+    }
+
+    #[test]
+    fn test_invalid_utf8_memo() {
+        let (title, rows) = visualize(&[0x66, 0xff, 0x6f], &[]);
+
+        assert_eq!(title, "Memo (invalid UTF-8)");
+        assert_eq!(row(&rows, "Raw Data"), "66ff6f");
+    }
+}
@@ -0,0 +1,26 @@
+//! Configuration for Metaplex Token Metadata program integration
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::BTreeMap;
+
+pub struct MetaplexTokenMetadataConfig;
+
+impl SolanaIntegrationConfig for MetaplexTokenMetadataConfig {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| {
+            let mut programs = BTreeMap::new();
+            let mut metadata_instructions = BTreeMap::new();
+            metadata_instructions.insert("*", vec!["*"]);
+            programs.insert(
+                "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+                metadata_instructions,
+            );
+            SolanaIntegrationConfigData { programs }
+        })
+    }
+}
@@ -0,0 +1,837 @@
+//! Metaplex Token Metadata preset for Solana
+//! Handles the NFT lifecycle on the Token Metadata Program
+//! (metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s): creating metadata and master
+//! editions, minting, transferring, collection verification and metadata
+//! updates. Instructions are Borsh-encoded behind a one-byte variant index;
+//! only the leading fields each layout needs are decoded.
+
+mod config;
+
+use crate::core::{
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
+};
+use crate::utils::escape_untrusted_text;
+use borsh::BorshDeserialize;
+use config::MetaplexTokenMetadataConfig;
+use solana_sdk::pubkey::Pubkey;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{create_number_field, create_text_field};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldStaticAnnotation,
+};
+
+// `MetadataInstruction` variant indexes rendered by this preset
+const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+const CREATE_MASTER_EDITION_V3: u8 = 17;
+const VERIFY_COLLECTION: u8 = 18;
+const UNVERIFY_COLLECTION: u8 = 22;
+const SET_AND_VERIFY_COLLECTION: u8 = 25;
+const VERIFY_SIZED_COLLECTION_ITEM: u8 = 30;
+const UNVERIFY_SIZED_COLLECTION_ITEM: u8 = 31;
+const SET_AND_VERIFY_SIZED_COLLECTION_ITEM: u8 = 32;
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+const CREATE: u8 = 42;
+const MINT: u8 = 43;
+const TRANSFER: u8 = 49;
+const UPDATE: u8 = 50;
+const VERIFY: u8 = 52;
+const UNVERIFY: u8 = 53;
+
+// Create a static instance that we can reference
+static METAPLEX_TOKEN_METADATA_CONFIG: MetaplexTokenMetadataConfig = MetaplexTokenMetadataConfig;
+
+pub struct MetaplexTokenMetadataVisualizer;
+
+impl InstructionVisualizer for MetaplexTokenMetadataVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        create_token_metadata_preview_layout(context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&METAPLEX_TOKEN_METADATA_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Payments("MetaplexTokenMetadata")
+    }
+}
+
+#[derive(BorshDeserialize)]
+struct Creator {
+    address: [u8; 32],
+    verified: bool,
+    share: u8,
+}
+
+#[derive(BorshDeserialize)]
+struct Collection {
+    verified: bool,
+    key: [u8; 32],
+}
+
+#[derive(BorshDeserialize)]
+struct Uses {
+    _use_method: u8,
+    _remaining: u64,
+    _total: u64,
+}
+
+/// Present on collection parent NFTs
+#[derive(BorshDeserialize)]
+enum CollectionDetails {
+    V1 { _size: u64 },
+    V2 { _padding: [u8; 8] },
+}
+
+/// Metadata fields shared by the legacy and unified instruction sets
+#[derive(BorshDeserialize)]
+struct Data {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    creators: Option<Vec<Creator>>,
+}
+
+#[derive(BorshDeserialize)]
+struct DataV2 {
+    data: Data,
+    collection: Option<Collection>,
+    _uses: Option<Uses>,
+}
+
+#[derive(BorshDeserialize)]
+struct CreateMetadataAccountArgsV3 {
+    data: DataV2,
+    is_mutable: bool,
+    collection_details: Option<CollectionDetails>,
+}
+
+#[derive(BorshDeserialize)]
+struct UpdateMetadataAccountArgsV2 {
+    data: Option<DataV2>,
+    update_authority: Option<[u8; 32]>,
+    primary_sale_happened: Option<bool>,
+    is_mutable: Option<bool>,
+}
+
+#[derive(BorshDeserialize)]
+struct CreateMasterEditionArgs {
+    max_supply: Option<u64>,
+}
+
+/// Leading fields of `CreateArgs::V1`; decimals and print supply follow
+#[derive(BorshDeserialize)]
+enum CreateArgs {
+    V1 { asset_data: AssetData },
+}
+
+#[derive(BorshDeserialize)]
+struct AssetData {
+    data: Data,
+    _primary_sale_happened: bool,
+    is_mutable: bool,
+    token_standard: u8,
+    collection: Option<Collection>,
+    _uses: Option<Uses>,
+    collection_details: Option<CollectionDetails>,
+}
+
+/// Leading field of `MintArgs::V1` and `TransferArgs::V1`; authorization data
+/// for programmable NFTs follows
+#[derive(BorshDeserialize)]
+enum AmountArgs {
+    V1 { amount: u64 },
+}
+
+/// Leading fields of `UpdateArgs::V1` and `UpdateArgs::AsUpdateAuthorityV2`
+#[derive(BorshDeserialize)]
+struct UpdateArgsPrefix {
+    new_update_authority: Option<[u8; 32]>,
+    data: Option<Data>,
+}
+
+#[derive(BorshDeserialize)]
+enum VerificationArgs {
+    CreatorV1,
+    CollectionV1,
+}
+
+/// Decodes the leading fields of `args` as `T`, ignoring any trailing bytes
+fn decode_args<T: BorshDeserialize>(args: &[u8], name: &str) -> Result<T, VisualSignError> {
+    T::deserialize(&mut &args[..]).map_err(|e| {
+        VisualSignError::DecodeError(format!("Failed to parse {name} instruction: {e}"))
+    })
+}
+
+/// Renders the account at `position` in the instruction's account list
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+/// On-chain strings are fixed-width and NUL-padded, and chosen by whoever
+/// built the transaction
+fn display_text(text: &str) -> String {
+    escape_untrusted_text(text.trim_end_matches('\0'))
+}
+
+/// Royalty in percent, e.g. 250 basis points as "2.5%"
+fn format_basis_points(basis_points: u16) -> String {
+    let whole = basis_points / 100;
+    let fraction = basis_points % 100;
+    if fraction == 0 {
+        format!("{whole}%")
+    } else {
+        format!(
+            "{whole}.{}%",
+            format!("{fraction:02}").trim_end_matches('0')
+        )
+    }
+}
+
+fn token_standard_name(token_standard: u8) -> &'static str {
+    match token_standard {
+        0 => "NonFungible",
+        1 => "FungibleAsset",
+        2 => "Fungible",
+        3 => "NonFungibleEdition",
+        4 => "ProgrammableNonFungible",
+        5 => "ProgrammableNonFungibleEdition",
+        _ => "Unknown",
+    }
+}
+
+/// Short name for the instructions rendered with the generic layout
+fn instruction_name(discriminator: u8) -> &'static str {
+    match discriminator {
+        7 => "Sign Metadata",
+        26 => "Freeze Delegated Account",
+        27 => "Thaw Delegated Account",
+        28 => "Remove Creator Verification",
+        29 => "Burn NFT",
+        34 => "Set Collection Size",
+        35 => "Set Token Standard",
+        37 => "Burn Edition NFT",
+        41 => "Burn",
+        44 => "Delegate",
+        45 => "Revoke",
+        46 => "Lock",
+        47 => "Unlock",
+        48 => "Migrate",
+        51 => "Use",
+        54 => "Collect",
+        55 => "Print",
+        _ => "Token Metadata Instruction",
+    }
+}
+
+/// Rows describing an NFT's name, symbol, URI, royalty and creators
+fn data_fields(data: &Data) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let mut fields = vec![
+        create_text_field("Name", &display_text(&data.name))?,
+        create_text_field("Symbol", &display_text(&data.symbol))?,
+        create_text_field("URI", &display_text(&data.uri))?,
+        create_text_field(
+            "Royalty",
+            &format_basis_points(data.seller_fee_basis_points),
+        )?,
+    ];
+    for (i, creator) in data.creators.iter().flatten().enumerate() {
+        fields.push(create_text_field(
+            &format!("Creator {}", i + 1),
+            &format!(
+                "{} ({}% share, {})",
+                Pubkey::new_from_array(creator.address),
+                creator.share,
+                if creator.verified {
+                    "verified"
+                } else {
+                    "unverified"
+                }
+            ),
+        )?);
+    }
+    Ok(fields)
+}
+
+fn collection_field(collection: &Collection) -> Result<AnnotatedPayloadField, VisualSignError> {
+    create_text_field(
+        "Collection",
+        &format!(
+            "{} ({})",
+            Pubkey::new_from_array(collection.key),
+            if collection.verified {
+                "verified"
+            } else {
+                "unverified"
+            }
+        ),
+    )
+}
+
+/// The update authority can rewrite every field of the metadata, so handing
+/// it to another account is called out on the row itself
+fn new_update_authority_field(
+    new_authority: &[u8; 32],
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let mut field = create_text_field(
+        "New Update Authority",
+        &Pubkey::new_from_array(*new_authority).to_string(),
+    )?;
+    field.static_annotation = Some(SignablePayloadFieldStaticAnnotation {
+        text: "Transfers control of this NFT's metadata to another account".to_string(),
+    });
+    Ok(field)
+}
+
+/// Title and rows for the instructions that mark an NFT as part of a
+/// collection, or take it out again
+fn collection_verification(
+    title: &str,
+    metadata: &str,
+    collection_authority: &str,
+    collection_mint: &str,
+) -> Result<
+    (
+        String,
+        Vec<AnnotatedPayloadField>,
+        Vec<AnnotatedPayloadField>,
+    ),
+    VisualSignError,
+> {
+    let condensed_fields = vec![
+        create_text_field("Instruction", title)?,
+        create_text_field("Collection Mint", collection_mint)?,
+    ];
+    let expanded_fields = vec![
+        create_text_field("Metadata", metadata)?,
+        create_text_field("Collection Authority", collection_authority)?,
+        create_text_field("Collection Mint", collection_mint)?,
+    ];
+    Ok((title.to_string(), condensed_fields, expanded_fields))
+}
+
+fn create_token_metadata_preview_layout(
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id_str = match context.program_id() {
+        ProgramRef::Resolved(pk) => pk.to_string(),
+        ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
+    };
+    let Some((&discriminator, args)) = context.data().split_first() else {
+        return Err(VisualSignError::DecodeError(
+            "Empty token metadata instruction".to_string(),
+        ));
+    };
+
+    let (title, condensed_fields, mut expanded_fields) = match discriminator {
+        // Accounts: [0] metadata, [1] mint, [2] mint authority, [3] payer,
+        // [4] update authority, [5] system program
+        CREATE_METADATA_ACCOUNT_V3 => {
+            let create: CreateMetadataAccountArgsV3 = decode_args(args, "create metadata")?;
+            let name = display_text(&create.data.data.name);
+            let title = format!("Create NFT Metadata: {name}");
+            let mut condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Mint", &account_str(context, 1))?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Metadata", &account_str(context, 0))?,
+                create_text_field("Mint", &account_str(context, 1))?,
+                create_text_field("Update Authority", &account_str(context, 4))?,
+            ];
+            expanded_fields.extend(data_fields(&create.data.data)?);
+            if let Some(collection) = &create.data.collection {
+                condensed_fields.push(collection_field(collection)?);
+                expanded_fields.push(collection_field(collection)?);
+            }
+            if create.collection_details.is_some() {
+                expanded_fields.push(create_text_field("Collection NFT", "Yes")?);
+            }
+            expanded_fields.push(create_text_field(
+                "Mutable",
+                if create.is_mutable { "Yes" } else { "No" },
+            )?);
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] metadata, [1] master edition, [2] mint,
+        // [3] mint authority, [4] payer, [5] update authority
+        CREATE => {
+            let CreateArgs::V1 { asset_data } = decode_args(args, "create")?;
+            let name = display_text(&asset_data.data.name);
+            let title = format!("Create NFT: {name}");
+            let mut condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Mint", &account_str(context, 2))?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Metadata", &account_str(context, 0))?,
+                create_text_field("Mint", &account_str(context, 2))?,
+                create_text_field("Update Authority", &account_str(context, 5))?,
+                create_text_field(
+                    "Token Standard",
+                    token_standard_name(asset_data.token_standard),
+                )?,
+            ];
+            expanded_fields.extend(data_fields(&asset_data.data)?);
+            if let Some(collection) = &asset_data.collection {
+                condensed_fields.push(collection_field(collection)?);
+                expanded_fields.push(collection_field(collection)?);
+            }
+            if asset_data.collection_details.is_some() {
+                expanded_fields.push(create_text_field("Collection NFT", "Yes")?);
+            }
+            expanded_fields.push(create_text_field(
+                "Mutable",
+                if asset_data.is_mutable { "Yes" } else { "No" },
+            )?);
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] edition, [1] mint, [2] update authority,
+        // [3] mint authority, [4] payer, [5] metadata
+        CREATE_MASTER_EDITION_V3 => {
+            let edition: CreateMasterEditionArgs = decode_args(args, "create master edition")?;
+            let title = "Create Master Edition".to_string();
+            let max_supply = edition
+                .max_supply
+                .map_or_else(|| "Unlimited".to_string(), |supply| supply.to_string());
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Mint", &account_str(context, 1))?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Master Edition", &account_str(context, 0))?,
+                create_text_field("Mint", &account_str(context, 1))?,
+                create_text_field("Update Authority", &account_str(context, 2))?,
+                create_text_field("Max Supply", &max_supply)?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] token account, [1] token owner, [2] metadata,
+        // [3] master edition, [4] token record, [5] mint, [6] authority
+        MINT => {
+            let AmountArgs::V1 { amount } = decode_args(args, "mint")?;
+            let title = format!("Mint NFT: {amount}");
+            let mint = account_str(context, 5);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Mint", &mint)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Mint", &mint)?,
+                create_text_field("Token Account", &account_str(context, 0))?,
+                create_text_field("Token Owner", &account_str(context, 1))?,
+                create_text_field("Authority", &account_str(context, 6))?,
+                create_number_field("Amount", &amount.to_string(), "")?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] source token account, [1] source owner,
+        // [2] destination token account, [3] destination owner, [4] mint,
+        // [5] metadata, [6] edition, [7] owner token record,
+        // [8] destination token record, [9] authority
+        TRANSFER => {
+            let AmountArgs::V1 { amount } = decode_args(args, "transfer")?;
+            let title = format!("Transfer NFT: {amount}");
+            let destination_owner = account_str(context, 3);
+            let mint = account_str(context, 4);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("To", &destination_owner)?,
+                create_text_field("Mint", &mint)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Mint", &mint)?,
+                create_text_field("From", &account_str(context, 1))?,
+                create_text_field("To", &destination_owner)?,
+                create_text_field("Source Token Account", &account_str(context, 0))?,
+                create_text_field("Destination Token Account", &account_str(context, 2))?,
+                create_text_field("Authority", &account_str(context, 9))?,
+                create_number_field("Amount", &amount.to_string(), "")?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] metadata, [1] collection authority, [2] payer,
+        // [3] collection mint, [4] collection metadata, [5] collection edition
+        VERIFY_COLLECTION | VERIFY_SIZED_COLLECTION_ITEM => collection_verification(
+            "Verify Collection",
+            &account_str(context, 0),
+            &account_str(context, 1),
+            &account_str(context, 3),
+        )?,
+        // Accounts: [0] metadata, [1] collection authority, [2] collection
+        // mint, [3] collection metadata, [4] collection edition
+        UNVERIFY_COLLECTION => collection_verification(
+            "Unverify Collection",
+            &account_str(context, 0),
+            &account_str(context, 1),
+            &account_str(context, 2),
+        )?,
+        // Accounts: as VerifySizedCollectionItem
+        UNVERIFY_SIZED_COLLECTION_ITEM => collection_verification(
+            "Unverify Collection",
+            &account_str(context, 0),
+            &account_str(context, 1),
+            &account_str(context, 3),
+        )?,
+        // Accounts: [0] metadata, [1] collection authority, [2] payer,
+        // [3] update authority, [4] collection mint, [5] collection metadata,
+        // [6] collection edition
+        SET_AND_VERIFY_COLLECTION | SET_AND_VERIFY_SIZED_COLLECTION_ITEM => {
+            collection_verification(
+                "Set and Verify Collection",
+                &account_str(context, 0),
+                &account_str(context, 1),
+                &account_str(context, 4),
+            )?
+        }
+        // Accounts: [0] authority, [1] delegate record, [2] metadata,
+        // [3] collection mint, [4] collection metadata, [5] collection edition
+        VERIFY | UNVERIFY => {
+            let verification: VerificationArgs = decode_args(args, "verify")?;
+            let action = if discriminator == VERIFY {
+                "Verify"
+            } else {
+                "Unverify"
+            };
+            match verification {
+                VerificationArgs::CollectionV1 => collection_verification(
+                    &format!("{action} Collection"),
+                    &account_str(context, 2),
+                    &account_str(context, 0),
+                    &account_str(context, 3),
+                )?,
+                VerificationArgs::CreatorV1 => {
+                    let title = format!("{action} Creator");
+                    let creator = account_str(context, 0);
+                    let condensed_fields = vec![
+                        create_text_field("Instruction", &title)?,
+                        create_text_field("Creator", &creator)?,
+                    ];
+                    let expanded_fields = vec![
+                        create_text_field("Metadata", &account_str(context, 2))?,
+                        create_text_field("Creator", &creator)?,
+                    ];
+                    (title, condensed_fields, expanded_fields)
+                }
+            }
+        }
+        // Accounts: [0] metadata, [1] update authority
+        UPDATE_METADATA_ACCOUNT_V2 => {
+            let update: UpdateMetadataAccountArgsV2 = decode_args(args, "update metadata")?;
+            let mut expanded_fields = vec![
+                create_text_field("Metadata", &account_str(context, 0))?,
+                create_text_field("Update Authority", &account_str(context, 1))?,
+            ];
+            let mut condensed_fields = Vec::new();
+            if let Some(new_authority) = &update.update_authority {
+                condensed_fields.push(new_update_authority_field(new_authority)?);
+                expanded_fields.push(new_update_authority_field(new_authority)?);
+            }
+            if let Some(data) = &update.data {
+                expanded_fields.extend(data_fields(&data.data)?);
+                if let Some(collection) = &data.collection {
+                    expanded_fields.push(collection_field(collection)?);
+                }
+            }
+            if let Some(primary_sale_happened) = update.primary_sale_happened {
+                expanded_fields.push(create_text_field(
+                    "Primary Sale Happened",
+                    if primary_sale_happened { "Yes" } else { "No" },
+                )?);
+            }
+            if let Some(is_mutable) = update.is_mutable {
+                expanded_fields.push(create_text_field(
+                    "Mutable",
+                    if is_mutable { "Yes" } else { "No" },
+                )?);
+            }
+            let title = if update.update_authority.is_some() {
+                "Update Metadata: Change Update Authority".to_string()
+            } else {
+                "Update Metadata".to_string()
+            };
+            condensed_fields.insert(0, create_text_field("Instruction", &title)?);
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] authority, [1] delegate record, [2] token account,
+        // [3] mint, [4] metadata, [5] edition, [6] payer
+        UPDATE => {
+            let Some((&variant, update_args)) = args.split_first() else {
+                return Err(VisualSignError::DecodeError(
+                    "Failed to parse update instruction: missing arguments".to_string(),
+                ));
+            };
+            // V1 and AsUpdateAuthorityV2 lead with the new authority and
+            // data, AsAuthorityItemDelegateV2 with just the authority; the
+            // other delegate variants cannot change either
+            let (new_authority, data) = match variant {
+                0 | 1 => {
+                    let prefix: UpdateArgsPrefix = decode_args(update_args, "update")?;
+                    (prefix.new_update_authority, prefix.data)
+                }
+                2 => (decode_args(update_args, "update")?, None),
+                _ => (None, None),
+            };
+            let mint = account_str(context, 3);
+            let mut condensed_fields = vec![create_text_field("Mint", &mint)?];
+            let mut expanded_fields = vec![
+                create_text_field("Mint", &mint)?,
+                create_text_field("Metadata", &account_str(context, 4))?,
+                create_text_field("Authority", &account_str(context, 0))?,
+            ];
+            if let Some(new_authority) = &new_authority {
+                condensed_fields.push(new_update_authority_field(new_authority)?);
+                expanded_fields.push(new_update_authority_field(new_authority)?);
+            }
+            if let Some(data) = &data {
+                expanded_fields.extend(data_fields(data)?);
+            }
+            let title = if new_authority.is_some() {
+                "Update Metadata: Change Update Authority".to_string()
+            } else {
+                "Update Metadata".to_string()
+            };
+            condensed_fields.insert(0, create_text_field("Instruction", &title)?);
+            (title, condensed_fields, expanded_fields)
+        }
+        _ => {
+            let title = instruction_name(discriminator).to_string();
+            let condensed_fields = vec![create_text_field("Instruction", &title)?];
+            let expanded_fields = vec![create_number_field(
+                "Discriminator",
+                &discriminator.to_string(),
+                "",
+            )?];
+            (title, condensed_fields, expanded_fields)
+        }
+    };
+
+    expanded_fields.insert(0, create_text_field("Program ID", &program_id_str)?);
+    expanded_fields.push(create_text_field("Raw Data", &hex::encode(context.data()))?);
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(context.data())
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use std::str::FromStr;
+
+    /// Borsh encoding of a string: u32 length prefix, then the bytes
+    fn borsh_string(out: &mut Vec<u8>, s: &str) {
+        out.extend_from_slice(&(s.len() as u32).to_le_bytes());
+        out.extend_from_slice(s.as_bytes());
+    }
+
+    /// `Data` with one verified creator holding the whole share
+    fn borsh_data(out: &mut Vec<u8>, name: &str, creator: &Pubkey) {
+        borsh_string(out, name);
+        borsh_string(out, "ART");
+        borsh_string(out, "https://example.com/1.json");
+        out.extend_from_slice(&500u16.to_le_bytes());
+        out.push(1); // Some(creators)
+        out.extend_from_slice(&1u32.to_le_bytes());
+        out.extend_from_slice(creator.as_ref());
+        out.push(1); // verified
+        out.push(100); // share
+    }
+
+    /// Visualizes `data` with the Token Metadata Program at index 0 and
+    /// `accounts` after it, and returns the preview's title, condensed rows
+    /// and expanded fields
+    fn visualize(
+        data: Vec<u8>,
+        accounts: &[Pubkey],
+    ) -> (
+        String,
+        Vec<AnnotatedPayloadField>,
+        Vec<AnnotatedPayloadField>,
+    ) {
+        let mut account_keys =
+            vec![Pubkey::from_str("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s").unwrap()];
+        account_keys.extend_from_slice(accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data,
+        };
+        let sender = SolanaAccount {
+            account_key: accounts[0].to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = MetaplexTokenMetadataVisualizer
+            .visualize_tx_commands(&context)
+            .unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        (
+            preview_layout.title.unwrap().text,
+            preview_layout.condensed.unwrap().fields,
+            preview_layout.expanded.unwrap().fields,
+        )
+    }
+
+    fn row<'a>(fields: &'a [AnnotatedPayloadField], label: &str) -> &'a AnnotatedPayloadField {
+        fields
+            .iter()
+            .find(|f| f.signable_payload_field.label() == label)
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    fn text<'a>(fields: &'a [AnnotatedPayloadField], label: &str) -> &'a str {
+        row(fields, label).signable_payload_field.fallback_text()
+    }
+
+    fn unique_keys(n: usize) -> Vec<Pubkey> {
+        (0..n).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_create_metadata_shows_name_and_collection() {
+        let keys = unique_keys(5);
+        let (creator, collection) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+        borsh_data(&mut data, "Degen Ape #42\0\0\0", &creator);
+        data.extend_from_slice(&[1, 0]); // Some(collection), unverified
+        data.extend_from_slice(collection.as_ref());
+        data.push(0); // uses: None
+        data.push(1); // is_mutable
+        data.push(0); // collection_details: None
+
+        let (title, condensed, expanded) = visualize(data, &keys);
+
+        assert_eq!(title, "Create NFT Metadata: Degen Ape #42");
+        assert_eq!(
+            text(&condensed, "Collection"),
+            format!("{collection} (unverified)")
+        );
+        assert_eq!(text(&expanded, "Mint"), keys[1].to_string());
+        assert_eq!(text(&expanded, "Update Authority"), keys[4].to_string());
+        assert_eq!(text(&expanded, "Royalty"), "5%");
+        assert_eq!(
+            text(&expanded, "Creator 1"),
+            format!("{creator} (100% share, verified)")
+        );
+        assert_eq!(text(&expanded, "Mutable"), "Yes");
+    }
+
+    #[test]
+    fn test_update_authority_change_is_flagged() {
+        let keys = unique_keys(2);
+        let new_authority = Pubkey::new_unique();
+        let mut data = vec![UPDATE_METADATA_ACCOUNT_V2];
+        data.push(0); // data: None
+        data.push(1); // Some(update_authority)
+        data.extend_from_slice(new_authority.as_ref());
+        data.extend_from_slice(&[0, 0]); // primary_sale_happened, is_mutable: None
+
+        let (title, condensed, _) = visualize(data, &keys);
+
+        assert_eq!(title, "Update Metadata: Change Update Authority");
+        let flagged = row(&condensed, "New Update Authority");
+        assert_eq!(
+            flagged.signable_payload_field.fallback_text(),
+            &new_authority.to_string()
+        );
+        assert!(flagged.static_annotation.is_some());
+    }
+
+    #[test]
+    fn test_update_v1_without_authority_change() {
+        let keys = unique_keys(5);
+        let mut data = vec![UPDATE, 0];
+        data.push(0); // new_update_authority: None
+        data.push(1); // Some(data)
+        borsh_data(&mut data, "Renamed", &Pubkey::new_unique());
+
+        let (title, condensed, expanded) = visualize(data, &keys);
+
+        assert_eq!(title, "Update Metadata");
+        assert!(
+            condensed
+                .iter()
+                .all(|f| f.signable_payload_field.label() != "New Update Authority")
+        );
+        assert_eq!(text(&expanded, "Name"), "Renamed");
+        assert_eq!(text(&expanded, "Mint"), keys[3].to_string());
+    }
+
+    #[test]
+    fn test_transfer_shows_destination_owner() {
+        let keys = unique_keys(10);
+        let mut data = vec![TRANSFER, 0];
+        data.extend_from_slice(&1u64.to_le_bytes());
+        data.push(0); // authorization_data: None
+
+        let (title, condensed, expanded) = visualize(data, &keys);
+
+        assert_eq!(title, "Transfer NFT: 1");
+        assert_eq!(text(&condensed, "To"), keys[3].to_string());
+        assert_eq!(text(&expanded, "From"), keys[1].to_string());
+        assert_eq!(text(&expanded, "Mint"), keys[4].to_string());
+    }
+
+    #[test]
+    fn test_verify_collection() {
+        let keys = unique_keys(6);
+        let (title, condensed, _) = visualize(vec![VERIFY_COLLECTION], &keys);
+
+        assert_eq!(title, "Verify Collection");
+        assert_eq!(text(&condensed, "Collection Mint"), keys[3].to_string());
+    }
+
+    #[test]
+    fn test_format_basis_points() {
+        assert_eq!(format_basis_points(0), "0%");
+        assert_eq!(format_basis_points(250), "2.5%");
+        assert_eq!(format_basis_points(75), "0.75%");
+        assert_eq!(format_basis_points(10_000), "100%");
+    }
+}
@@ -0,0 +1,26 @@
+//! Configuration for OpenBook v2 order book program integration
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::BTreeMap;
+
+pub struct OpenbookV2Config;
+
+impl SolanaIntegrationConfig for OpenbookV2Config {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| {
+            let mut programs = BTreeMap::new();
+            let mut openbook_instructions = BTreeMap::new();
+            openbook_instructions.insert("*", vec!["*"]);
+            programs.insert(
+                "opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb",
+                openbook_instructions,
+            );
+            SolanaIntegrationConfigData { programs }
+        })
+    }
+}
@@ -0,0 +1,442 @@
+//! OpenBook v2 preset for Solana
+//! Handles order placement, cancellation and settlement on the OpenBook v2
+//! order book (opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb). Instructions
+//! only reference the market account, so the base/quote pair is shown when
+//! the caller supplies it through `SolanaMetadata.markets`. Prices and sizes
+//! are in the market's lots, which need the market account to convert.
+
+mod config;
+
+use crate::core::{
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
+};
+use borsh::BorshDeserialize;
+use config::OpenbookV2Config;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::create_text_field;
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
+
+// sha256("global:<instruction_name>")[..8]
+const PLACE_ORDER: [u8; 8] = [51, 194, 155, 175, 109, 130, 96, 106];
+const CANCEL_ORDER: [u8; 8] = [95, 129, 237, 240, 8, 49, 223, 132];
+const SETTLE_FUNDS: [u8; 8] = [238, 64, 163, 96, 75, 171, 16, 33];
+
+// Create a static instance that we can reference
+static OPENBOOK_V2_CONFIG: OpenbookV2Config = OpenbookV2Config;
+
+pub struct OpenbookV2Visualizer;
+
+impl InstructionVisualizer for OpenbookV2Visualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        create_openbook_v2_preview_layout(context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&OPENBOOK_V2_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Dex("OpenBook v2")
+    }
+}
+
+/// `PlaceOrderArgs` as laid out by the program
+#[derive(BorshDeserialize)]
+struct PlaceOrderArgs {
+    side: u8,
+    price_lots: i64,
+    max_base_lots: i64,
+    max_quote_lots_including_fees: i64,
+    client_order_id: u64,
+    order_type: u8,
+    expiry_timestamp: u64,
+    self_trade_behavior: u8,
+    limit: u8,
+}
+
+fn side_name(side: u8) -> String {
+    match side {
+        0 => "Buy".to_string(),
+        1 => "Sell".to_string(),
+        other => format!("Unknown ({other})"),
+    }
+}
+
+fn order_type_name(order_type: u8) -> String {
+    match order_type {
+        0 => "Limit".to_string(),
+        1 => "Immediate or Cancel".to_string(),
+        2 => "Post Only".to_string(),
+        3 => "Market".to_string(),
+        4 => "Post Only Slide".to_string(),
+        5 => "Fill or Kill".to_string(),
+        other => format!("Unknown ({other})"),
+    }
+}
+
+fn self_trade_behavior_name(behavior: u8) -> String {
+    match behavior {
+        0 => "Decrement Take".to_string(),
+        1 => "Cancel Provide".to_string(),
+        2 => "Abort Transaction".to_string(),
+        other => format!("Unknown ({other})"),
+    }
+}
+
+/// Renders the account at `position` in the instruction's account list
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+/// The `BASE/QUOTE` pair of the market account at `position`, when the
+/// caller supplied symbols for it
+fn market_pair(context: &VisualizerContext, position: usize) -> Option<String> {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => context
+            .market_symbols(pk)
+            .map(|symbols| format!("{}/{}", symbols.base, symbols.quote)),
+        _ => None,
+    }
+}
+
+fn create_openbook_v2_preview_layout(
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id_str = match context.program_id() {
+        ProgramRef::Resolved(pk) => pk.to_string(),
+        ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
+    };
+    let data = context.data();
+    let (discriminator, args) = data.split_first_chunk::<8>().ok_or_else(|| {
+        VisualSignError::DecodeError("OpenBook v2 instruction data too short".into())
+    })?;
+    let decode_err = |e: std::io::Error| {
+        VisualSignError::DecodeError(format!("Failed to parse OpenBook v2 instruction: {e}"))
+    };
+
+    let (title, condensed_fields, mut expanded_fields) = match *discriminator {
+        PLACE_ORDER => {
+            // Accounts: [0] signer, [1] open orders, [3] user token account, [4] market
+            let order = PlaceOrderArgs::deserialize(&mut &args[..]).map_err(decode_err)?;
+            let side = side_name(order.side);
+            let expiry = match order.expiry_timestamp {
+                0 => "None".to_string(),
+                timestamp => timestamp.to_string(),
+            };
+            order_fields(
+                context,
+                format!("Place {side} Order"),
+                4,
+                &[
+                    ("Side", side),
+                    ("Order Type", order_type_name(order.order_type)),
+                    ("Price (lots)", order.price_lots.to_string()),
+                    ("Max Base (lots)", order.max_base_lots.to_string()),
+                ],
+                &[
+                    (
+                        "Max Quote incl. Fees (lots)",
+                        order.max_quote_lots_including_fees.to_string(),
+                    ),
+                    ("Client Order ID", order.client_order_id.to_string()),
+                    ("Expiry Timestamp", expiry),
+                    (
+                        "Self Trade Behavior",
+                        self_trade_behavior_name(order.self_trade_behavior),
+                    ),
+                    ("Match Limit", order.limit.to_string()),
+                    ("Owner", account_str(context, 0)),
+                    ("Open Orders Account", account_str(context, 1)),
+                    ("Token Account", account_str(context, 3)),
+                ],
+            )?
+        }
+        CANCEL_ORDER => {
+            // Accounts: [0] signer, [1] open orders, [2] market
+            let order_id = u128::deserialize(&mut &args[..]).map_err(decode_err)?;
+            order_fields(
+                context,
+                "Cancel Order".to_string(),
+                2,
+                &[("Order ID", order_id.to_string())],
+                &[
+                    ("Owner", account_str(context, 0)),
+                    ("Open Orders Account", account_str(context, 1)),
+                ],
+            )?
+        }
+        SETTLE_FUNDS => {
+            // Accounts: [0] owner, [2] open orders, [3] market, [7] user base, [8] user quote
+            order_fields(
+                context,
+                "Settle Funds".to_string(),
+                3,
+                &[],
+                &[
+                    ("Owner", account_str(context, 0)),
+                    ("Open Orders Account", account_str(context, 2)),
+                    ("Base Token Account", account_str(context, 7)),
+                    ("Quote Token Account", account_str(context, 8)),
+                ],
+            )?
+        }
+        _ => {
+            let title = "OpenBook v2 Instruction".to_string();
+            let condensed_fields = vec![create_text_field("Instruction", &title)?];
+            let expanded_fields = vec![create_text_field(
+                "Discriminator",
+                &hex::encode(discriminator),
+            )?];
+            (title, condensed_fields, expanded_fields)
+        }
+    };
+
+    expanded_fields.insert(0, create_text_field("Program ID", &program_id_str)?);
+    expanded_fields.push(create_text_field("Raw Data", &hex::encode(data))?);
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(data)
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+/// Title, condensed and expanded rows for an order book instruction. The
+/// market is named by its pair when known; `summary` rows are shown in both
+/// views and `details` only in the expanded one.
+fn order_fields(
+    context: &VisualizerContext,
+    action: String,
+    market_position: usize,
+    summary: &[(&str, String)],
+    details: &[(&str, String)],
+) -> Result<
+    (
+        String,
+        Vec<AnnotatedPayloadField>,
+        Vec<AnnotatedPayloadField>,
+    ),
+    VisualSignError,
+> {
+    let market_account = account_str(context, market_position);
+    let pair = market_pair(context, market_position);
+    let title = match &pair {
+        Some(pair) => format!("OpenBook v2 {action}: {pair}"),
+        None => format!("OpenBook v2 {action}"),
+    };
+    let market = pair.unwrap_or_else(|| market_account.clone());
+
+    let mut condensed_fields = vec![
+        create_text_field("Instruction", &action)?,
+        create_text_field("Market", &market)?,
+    ];
+    let mut expanded_fields = vec![
+        create_text_field("Instruction", &action)?,
+        create_text_field("Market", &market)?,
+        create_text_field("Market Account", &market_account)?,
+    ];
+    for (label, value) in summary {
+        condensed_fields.push(create_text_field(label, value)?);
+        expanded_fields.push(create_text_field(label, value)?);
+    }
+    for (label, value) in details {
+        expanded_fields.push(create_text_field(label, value)?);
+    }
+    Ok((title, condensed_fields, expanded_fields))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::core::MarketSymbols;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
+    use std::collections::BTreeMap;
+    use std::str::FromStr;
+
+    /// Visualizes `data` with the OpenBook v2 program at index 0 and
+    /// `accounts` after it, and returns the preview's title and expanded rows
+    fn visualize(
+        data: Vec<u8>,
+        accounts: &[Pubkey],
+        markets: &BTreeMap<Pubkey, MarketSymbols>,
+    ) -> (String, Vec<(String, String)>) {
+        let mut account_keys =
+            vec![Pubkey::from_str("opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb").unwrap()];
+        account_keys.extend_from_slice(accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data,
+        };
+        let sender = SolanaAccount {
+            account_key: Pubkey::new_unique().to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0)
+            .with_markets(markets);
+
+        let field = OpenbookV2Visualizer
+            .visualize_tx_commands(&context)
+            .unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect();
+        (preview_layout.title.unwrap().text, rows)
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    fn accounts() -> Vec<Pubkey> {
+        (0..12).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    fn sol_usdc(market: Pubkey) -> BTreeMap<Pubkey, MarketSymbols> {
+        BTreeMap::from([(
+            market,
+            MarketSymbols {
+                base: "SOL".to_string(),
+                quote: "USDC".to_string(),
+            },
+        )])
+    }
+
+    fn place_order_data(side: u8, order_type: u8) -> Vec<u8> {
+        let mut data = PLACE_ORDER.to_vec();
+        data.push(side);
+        data.extend_from_slice(&15_000i64.to_le_bytes());
+        data.extend_from_slice(&250i64.to_le_bytes());
+        data.extend_from_slice(&3_760_000i64.to_le_bytes());
+        data.extend_from_slice(&42u64.to_le_bytes());
+        data.push(order_type);
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.push(0);
+        data.push(10);
+        data
+    }
+
+    #[test]
+    fn test_discriminators_match_anchor_sighash() {
+        for (name, discriminator) in [
+            ("place_order", PLACE_ORDER),
+            ("cancel_order", CANCEL_ORDER),
+            ("settle_funds", SETTLE_FUNDS),
+        ] {
+            let hash = solana_sdk::hash::hash(format!("global:{name}").as_bytes());
+            assert_eq!(hash.to_bytes()[..8], discriminator, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_place_order_resolves_market_symbols() {
+        let accounts = accounts();
+        let (title, rows) = visualize(place_order_data(0, 2), &accounts, &sol_usdc(accounts[4]));
+
+        assert_eq!(title, "OpenBook v2 Place Buy Order: SOL/USDC");
+        assert_eq!(row(&rows, "Market"), "SOL/USDC");
+        assert_eq!(row(&rows, "Market Account"), accounts[4].to_string());
+        assert_eq!(row(&rows, "Side"), "Buy");
+        assert_eq!(row(&rows, "Order Type"), "Post Only");
+        assert_eq!(row(&rows, "Price (lots)"), "15000");
+        assert_eq!(row(&rows, "Max Base (lots)"), "250");
+        assert_eq!(row(&rows, "Max Quote incl. Fees (lots)"), "3760000");
+        assert_eq!(row(&rows, "Client Order ID"), "42");
+        assert_eq!(row(&rows, "Expiry Timestamp"), "None");
+        assert_eq!(row(&rows, "Token Account"), accounts[3].to_string());
+    }
+
+    #[test]
+    fn test_place_order_without_metadata_shows_market_account() {
+        let accounts = accounts();
+        let (title, rows) = visualize(place_order_data(1, 1), &accounts, &BTreeMap::new());
+
+        assert_eq!(title, "OpenBook v2 Place Sell Order");
+        assert_eq!(row(&rows, "Market"), accounts[4].to_string());
+        assert_eq!(row(&rows, "Order Type"), "Immediate or Cancel");
+    }
+
+    #[test]
+    fn test_cancel_order_shows_order_id() {
+        let accounts = accounts();
+        let order_id: u128 = 1 << 70;
+        let mut data = CANCEL_ORDER.to_vec();
+        data.extend_from_slice(&order_id.to_le_bytes());
+        let (title, rows) = visualize(data, &accounts, &sol_usdc(accounts[2]));
+
+        assert_eq!(title, "OpenBook v2 Cancel Order: SOL/USDC");
+        assert_eq!(row(&rows, "Order ID"), order_id.to_string());
+        assert_eq!(row(&rows, "Open Orders Account"), accounts[1].to_string());
+    }
+
+    #[test]
+    fn test_settle_funds_lists_destination_accounts() {
+        let accounts = accounts();
+        let (title, rows) = visualize(SETTLE_FUNDS.to_vec(), &accounts, &sol_usdc(accounts[3]));
+
+        assert_eq!(title, "OpenBook v2 Settle Funds: SOL/USDC");
+        assert_eq!(row(&rows, "Base Token Account"), accounts[7].to_string());
+        assert_eq!(row(&rows, "Quote Token Account"), accounts[8].to_string());
+    }
+
+    #[test]
+    fn test_other_instruction_shows_discriminator() {
+        let (title, rows) = visualize(vec![1, 2, 3, 4, 5, 6, 7, 8], &[], &BTreeMap::new());
+
+        assert_eq!(title, "OpenBook v2 Instruction");
+        assert_eq!(row(&rows, "Discriminator"), "0102030405060708");
+    }
+}
@@ -1,4 +1,5 @@
-//! Orca Whirlpool preset — generic IDL-driven visualizer.
+//! Orca Whirlpool preset — generic IDL-driven visualizer, with token pair,
+//! tick range and amount summaries for swaps and position management.
 
 mod config;
 
@@ -6,6 +7,7 @@ use crate::core::{
     InstructionView, InstructionVisualizer, SolanaIntegrationConfig, VisualizerContext,
     VisualizerKind,
 };
+use crate::utils::TokenRegistry;
 use config::OrcaWhirlpoolConfig;
 use solana_parser::{
     Idl, SolanaParsedInstructionData, decode_idl_data, parse_instruction_with_idl,
@@ -37,18 +39,27 @@ impl InstructionVisualizer for OrcaWhirlpoolVisualizer {
 
         let parsed = parse_orca_whirlpool_instruction(data, &view.accounts)?;
         let named_accounts = build_named_accounts(&parsed, &view.accounts);
+        let summary_fields = build_summary_fields(&parsed, context.tokens())?;
 
         let title_text = format!("{ORCA_WHIRLPOOL_DISPLAY_NAME}: {}", parsed.instruction_name);
 
+        let mut condensed_fields = vec![
+            create_text_field("Instruction", &title_text)
+                .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
+        ];
+        condensed_fields.extend(summary_fields.iter().cloned());
         let condensed = SignablePayloadFieldListLayout {
-            fields: vec![
-                create_text_field("Instruction", &title_text)
-                    .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
-            ],
+            fields: condensed_fields,
         };
 
         let expanded = SignablePayloadFieldListLayout {
-            fields: build_expanded_fields(&parsed, &named_accounts, &view.program_id, data)?,
+            fields: build_expanded_fields(
+                &parsed,
+                &named_accounts,
+                &summary_fields,
+                &view.program_id,
+                data,
+            )?,
         };
 
         let preview_layout = SignablePayloadFieldPreviewLayout {
@@ -62,7 +73,11 @@ impl InstructionVisualizer for OrcaWhirlpoolVisualizer {
             expanded: Some(expanded),
         };
 
-        let fallback_text = format!("Program ID: {}\nData: {}", view.program_id, hex::encode(data));
+        let fallback_text = format!(
+            "Program ID: {}\nData: {}",
+            view.program_id,
+            hex::encode(data)
+        );
 
         Ok(AnnotatedPayloadField {
             static_annotation: None,
@@ -130,9 +145,167 @@ fn build_named_accounts(
     named
 }
 
+/// The pool's token mints, which only the `_v2` instructions carry as
+/// accounts. Legacy instructions name just the whirlpool.
+fn token_pair(parsed: &SolanaParsedInstructionData) -> Option<(&str, &str)> {
+    let mint_a = parsed.named_accounts.get("token_mint_a")?;
+    let mint_b = parsed.named_accounts.get("token_mint_b")?;
+    Some((mint_a.as_str(), mint_b.as_str()))
+}
+
+/// Renders `amount` of `mint` scaled by its decimals when the mint is a known
+/// token, and as raw base units otherwise
+fn token_amount(tokens: &TokenRegistry, mint: Option<&str>, amount: u64) -> String {
+    match mint {
+        Some(mint) => {
+            let info = tokens.token_info(mint, amount);
+            format!("{} {}", info.human_readable_amount, info.symbol)
+        }
+        None => amount.to_string(),
+    }
+}
+
+fn token_symbol(tokens: &TokenRegistry, mint: Option<&str>, fallback: &str) -> String {
+    mint.map_or_else(|| fallback.to_string(), |m| tokens.token_info(m, 0).symbol)
+}
+
+fn arg_u64(parsed: &SolanaParsedInstructionData, name: &str) -> Option<u64> {
+    parsed.program_call_args.get(name)?.as_u64()
+}
+
+/// Summary rows for the instructions users sign most often: the token pair
+/// (or whirlpool), the tick range of a new position, and the swap or
+/// liquidity amounts. Other instructions get no summary.
+fn build_summary_fields(
+    parsed: &SolanaParsedInstructionData,
+    tokens: &TokenRegistry,
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let name = parsed.instruction_name.as_str();
+    let has_summary = matches!(
+        name,
+        "swap"
+            | "swap_v2"
+            | "open_position"
+            | "open_position_with_metadata"
+            | "open_position_with_token_extensions"
+            | "increase_liquidity"
+            | "increase_liquidity_v2"
+            | "decrease_liquidity"
+            | "decrease_liquidity_v2"
+            | "collect_fees"
+            | "collect_fees_v2"
+    );
+    if !has_summary {
+        return Ok(Vec::new());
+    }
+
+    let mut rows: Vec<(&str, String)> = Vec::new();
+    let pair = token_pair(parsed);
+    let (mint_a, mint_b) = (pair.map(|(a, _)| a), pair.map(|(_, b)| b));
+    let symbol_a = token_symbol(tokens, mint_a, "Token A");
+    let symbol_b = token_symbol(tokens, mint_b, "Token B");
+
+    if pair.is_some() {
+        rows.push(("Token Pair", format!("{symbol_a} / {symbol_b}")));
+    } else if let Some(whirlpool) = parsed.named_accounts.get("whirlpool") {
+        rows.push(("Whirlpool", whirlpool.clone()));
+    }
+
+    match name {
+        "swap" | "swap_v2" => {
+            let a_to_b = parsed
+                .program_call_args
+                .get("a_to_b")
+                .and_then(serde_json::Value::as_bool);
+            let exact_in = parsed
+                .program_call_args
+                .get("amount_specified_is_input")
+                .and_then(serde_json::Value::as_bool);
+            if let (Some(a_to_b), Some(exact_in)) = (a_to_b, exact_in) {
+                let ((from, from_mint), (to, to_mint)) = if a_to_b {
+                    ((&symbol_a, mint_a), (&symbol_b, mint_b))
+                } else {
+                    ((&symbol_b, mint_b), (&symbol_a, mint_a))
+                };
+                rows.push(("Direction", format!("{from} to {to}")));
+                let amount = arg_u64(parsed, "amount");
+                let threshold = arg_u64(parsed, "other_amount_threshold");
+                let ((amount_label, amount_mint), (threshold_label, threshold_mint)) = if exact_in {
+                    (("Amount In", from_mint), ("Minimum Amount Out", to_mint))
+                } else {
+                    (("Amount Out", to_mint), ("Maximum Amount In", from_mint))
+                };
+                if let Some(amount) = amount {
+                    rows.push((amount_label, token_amount(tokens, amount_mint, amount)));
+                }
+                if let Some(threshold) = threshold {
+                    rows.push((
+                        threshold_label,
+                        token_amount(tokens, threshold_mint, threshold),
+                    ));
+                }
+            }
+        }
+        "open_position" | "open_position_with_metadata" | "open_position_with_token_extensions" => {
+            let lower = parsed.program_call_args.get("tick_lower_index");
+            let upper = parsed.program_call_args.get("tick_upper_index");
+            if let (Some(lower), Some(upper)) = (lower, upper) {
+                rows.push((
+                    "Tick Range",
+                    format!("{} to {}", format_arg_value(lower), format_arg_value(upper)),
+                ));
+            }
+            if let Some(position_mint) = parsed.named_accounts.get("position_mint") {
+                rows.push(("Position Mint", position_mint.clone()));
+            }
+        }
+        "increase_liquidity"
+        | "increase_liquidity_v2"
+        | "decrease_liquidity"
+        | "decrease_liquidity_v2" => {
+            let [(label_a, arg_a), (label_b, arg_b)] = if name.starts_with("increase") {
+                [
+                    ("Max Token A", "token_max_a"),
+                    ("Max Token B", "token_max_b"),
+                ]
+            } else {
+                [
+                    ("Min Token A", "token_min_a"),
+                    ("Min Token B", "token_min_b"),
+                ]
+            };
+            if let Some(position) = parsed.named_accounts.get("position") {
+                rows.push(("Position", position.clone()));
+            }
+            if let Some(liquidity) = parsed.program_call_args.get("liquidity_amount") {
+                rows.push(("Liquidity", format_arg_value(liquidity)));
+            }
+            if let Some(amount) = arg_u64(parsed, arg_a) {
+                rows.push((label_a, token_amount(tokens, mint_a, amount)));
+            }
+            if let Some(amount) = arg_u64(parsed, arg_b) {
+                rows.push((label_b, token_amount(tokens, mint_b, amount)));
+            }
+        }
+        _ => {
+            if let Some(position) = parsed.named_accounts.get("position") {
+                rows.push(("Position", position.clone()));
+            }
+        }
+    }
+
+    rows.into_iter()
+        .map(|(label, value)| {
+            create_text_field(label, &value)
+                .map_err(|e| VisualSignError::ConversionError(e.to_string()))
+        })
+        .collect()
+}
+
 fn build_expanded_fields(
     parsed: &SolanaParsedInstructionData,
     named_accounts: &[(String, String)],
+    summary_fields: &[AnnotatedPayloadField],
     program_id: &str,
     data: &[u8],
 ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
@@ -146,6 +319,7 @@ fn build_expanded_fields(
         create_text_field("Instruction Name", &parsed.instruction_name)
             .map_err(|e| VisualSignError::ConversionError(e.to_string()))?,
     );
+    fields.extend(summary_fields.iter().cloned());
 
     for (name, pubkey) in named_accounts {
         let label = format!("Account: {name}");
@@ -235,5 +409,191 @@ mod tests {
         assert!(result.is_err(), "Short data should return an error");
     }
 
+    const SOL_MINT: &str = "11111111111111111111111111111112";
+    const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    fn parsed_instruction(
+        name: &str,
+        accounts: &[(&str, &str)],
+        args: serde_json::Value,
+    ) -> SolanaParsedInstructionData {
+        // Collected rather than named: the upstream map type is a `HashMap`
+        let named_accounts = accounts
+            .iter()
+            .map(|(account, pubkey)| (account.to_string(), pubkey.to_string()))
+            .collect();
+        let serde_json::Value::Object(program_call_args) = args else {
+            panic!("args must be a JSON object");
+        };
+        SolanaParsedInstructionData {
+            instruction_name: name.to_string(),
+            discriminator: String::new(),
+            named_accounts,
+            program_call_args,
+            idl_source: solana_parser::IdlSource::Custom,
+            idl_hash: String::new(),
+        }
+    }
+
+    fn summary(parsed: &SolanaParsedInstructionData) -> Vec<(String, String)> {
+        build_summary_fields(parsed, &TokenRegistry::new())
+            .unwrap()
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect()
+    }
+
+    fn rows(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|(l, v)| (l.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_swap_v2_exact_in_b_to_a() {
+        let parsed = parsed_instruction(
+            "swap_v2",
+            &[("token_mint_a", SOL_MINT), ("token_mint_b", USDC_MINT)],
+            serde_json::json!({
+                "amount": 25_000_000u64,
+                "other_amount_threshold": 150_000_000u64,
+                "sqrt_price_limit": "0",
+                "amount_specified_is_input": true,
+                "a_to_b": false,
+            }),
+        );
+
+        assert_eq!(
+            summary(&parsed),
+            rows(&[
+                ("Token Pair", "SOL / USDC"),
+                ("Direction", "USDC to SOL"),
+                ("Amount In", "25 USDC"),
+                ("Minimum Amount Out", "0.15 SOL"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_legacy_swap_exact_out_uses_whirlpool_and_raw_amounts() {
+        let whirlpool = "Czfq3xZZDmsdGdUyrNLtRhGc47cXcZtLG4crryfu44zE";
+        let parsed = parsed_instruction(
+            "swap",
+            &[("whirlpool", whirlpool)],
+            serde_json::json!({
+                "amount": 1_000u64,
+                "other_amount_threshold": 2_000u64,
+                "sqrt_price_limit": "0",
+                "amount_specified_is_input": false,
+                "a_to_b": true,
+            }),
+        );
+
+        assert_eq!(
+            summary(&parsed),
+            rows(&[
+                ("Whirlpool", whirlpool),
+                ("Direction", "Token A to Token B"),
+                ("Amount Out", "1000"),
+                ("Maximum Amount In", "2000"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_open_position_shows_tick_range() {
+        let parsed = parsed_instruction(
+            "open_position",
+            &[("whirlpool", "pool"), ("position_mint", "mint")],
+            serde_json::json!({
+                "bumps": {"position_bump": 255},
+                "tick_lower_index": -4_416,
+                "tick_upper_index": 2_048,
+            }),
+        );
+
+        assert_eq!(
+            summary(&parsed),
+            rows(&[
+                ("Whirlpool", "pool"),
+                ("Tick Range", "-4416 to 2048"),
+                ("Position Mint", "mint"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_liquidity_bounds() {
+        let increase = parsed_instruction(
+            "increase_liquidity_v2",
+            &[
+                ("position", "pos"),
+                ("token_mint_a", SOL_MINT),
+                ("token_mint_b", USDC_MINT),
+            ],
+            serde_json::json!({
+                "liquidity_amount": "340282366920938463463",
+                "token_max_a": 2_000_000_000u64,
+                "token_max_b": 300_000_000u64,
+            }),
+        );
+        assert_eq!(
+            summary(&increase),
+            rows(&[
+                ("Token Pair", "SOL / USDC"),
+                ("Position", "pos"),
+                ("Liquidity", "340282366920938463463"),
+                ("Max Token A", "2 SOL"),
+                ("Max Token B", "300 USDC"),
+            ])
+        );
+
+        let decrease = parsed_instruction(
+            "decrease_liquidity",
+            &[("whirlpool", "pool"), ("position", "pos")],
+            serde_json::json!({
+                "liquidity_amount": "5",
+                "token_min_a": 1u64,
+                "token_min_b": 2u64,
+            }),
+        );
+        assert_eq!(
+            summary(&decrease),
+            rows(&[
+                ("Whirlpool", "pool"),
+                ("Position", "pos"),
+                ("Liquidity", "5"),
+                ("Min Token A", "1"),
+                ("Min Token B", "2"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_collect_fees_and_unsummarised_instructions() {
+        let collect = parsed_instruction(
+            "collect_fees",
+            &[("whirlpool", "pool"), ("position", "pos")],
+            serde_json::json!({}),
+        );
+        assert_eq!(
+            summary(&collect),
+            rows(&[("Whirlpool", "pool"), ("Position", "pos")])
+        );
+
+        let other = parsed_instruction(
+            "update_fees_and_rewards",
+            &[("whirlpool", "pool")],
+            serde_json::json!({}),
+        );
+        assert!(summary(&other).is_empty());
+    }
+
     mod fixture_tests;
 }
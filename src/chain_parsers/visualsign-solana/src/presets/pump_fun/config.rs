@@ -0,0 +1,26 @@
+//! Configuration for Pump.fun bonding curve program integration
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::BTreeMap;
+
+pub struct PumpFunConfig;
+
+impl SolanaIntegrationConfig for PumpFunConfig {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| {
+            let mut programs = BTreeMap::new();
+            let mut pump_instructions = BTreeMap::new();
+            pump_instructions.insert("*", vec!["*"]);
+            programs.insert(
+                "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+                pump_instructions,
+            );
+            SolanaIntegrationConfigData { programs }
+        })
+    }
+}
@@ -0,0 +1,335 @@
+//! Pump.fun preset for Solana
+//! Handles trades against a token's bonding curve on the Pump.fun program
+//! (6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P). Instructions are Anchor
+//! encoded: an 8-byte discriminator followed by Borsh arguments. Newer
+//! program versions append optional arguments, so only the leading fields
+//! are decoded.
+
+mod config;
+
+use crate::core::{
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
+};
+use crate::utils::format_token_amount;
+use borsh::BorshDeserialize;
+use config::PumpFunConfig;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::create_text_field;
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
+
+// sha256("global:<instruction_name>")[..8]
+const BUY: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+const SELL: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+const BUY_EXACT_SOL_IN: [u8; 8] = [56, 252, 116, 8, 158, 223, 205, 95];
+
+/// Every Pump.fun token is minted with 6 decimals
+const PUMP_TOKEN_DECIMALS: u8 = 6;
+
+/// Lamports are 1e-9 SOL
+const SOL_DECIMALS: u8 = 9;
+
+// Create a static instance that we can reference
+static PUMP_FUN_CONFIG: PumpFunConfig = PumpFunConfig;
+
+pub struct PumpFunVisualizer;
+
+impl InstructionVisualizer for PumpFunVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        create_pump_fun_preview_layout(context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&PUMP_FUN_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Dex("Pump.fun")
+    }
+}
+
+/// Two u64 arguments shared by every trade instruction; what they mean
+/// depends on the instruction (see `create_pump_fun_preview_layout`)
+#[derive(BorshDeserialize)]
+struct TradeArgs {
+    first: u64,
+    second: u64,
+}
+
+/// Renders the account at `position` in the instruction's account list
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+fn token_amount(amount: u64) -> String {
+    format_token_amount(amount, PUMP_TOKEN_DECIMALS)
+}
+
+fn sol_amount(lamports: u64) -> String {
+    format!("{} SOL", format_token_amount(lamports, SOL_DECIMALS))
+}
+
+fn create_pump_fun_preview_layout(
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id_str = match context.program_id() {
+        ProgramRef::Resolved(pk) => pk.to_string(),
+        ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
+    };
+    let data = context.data();
+    let (discriminator, args) = data.split_first_chunk::<8>().ok_or_else(|| {
+        VisualSignError::DecodeError("Pump.fun instruction data too short".into())
+    })?;
+
+    // Trade accounts: [2] token mint, [3] bonding curve, [6] user
+    let trade = match *discriminator {
+        BUY | SELL | BUY_EXACT_SOL_IN => {
+            let args = TradeArgs::deserialize(&mut &args[..]).map_err(|e| {
+                VisualSignError::DecodeError(format!("Failed to parse Pump.fun trade: {e}"))
+            })?;
+            Some(args)
+        }
+        _ => None,
+    };
+
+    let (title, condensed_fields, mut expanded_fields) = match (*discriminator, trade) {
+        (BUY, Some(TradeArgs { first, second })) => {
+            // buy(amount: tokens out, max_sol_cost)
+            let title = format!("Pump.fun Buy: {} tokens", token_amount(first));
+            trade_fields(
+                context,
+                title,
+                [
+                    ("Token Amount", token_amount(first)),
+                    ("Max SOL Cost", sol_amount(second)),
+                ],
+            )?
+        }
+        (BUY_EXACT_SOL_IN, Some(TradeArgs { first, second })) => {
+            // buy_exact_sol_in(spendable_sol_in, min_tokens_out)
+            let title = format!("Pump.fun Buy: {}", sol_amount(first));
+            trade_fields(
+                context,
+                title,
+                [
+                    ("SOL Amount", sol_amount(first)),
+                    ("Min Tokens Out", token_amount(second)),
+                ],
+            )?
+        }
+        (SELL, Some(TradeArgs { first, second })) => {
+            // sell(amount: tokens in, min_sol_output)
+            let title = format!("Pump.fun Sell: {} tokens", token_amount(first));
+            trade_fields(
+                context,
+                title,
+                [
+                    ("Token Amount", token_amount(first)),
+                    ("Min SOL Output", sol_amount(second)),
+                ],
+            )?
+        }
+        _ => {
+            let title = "Pump.fun Instruction".to_string();
+            let condensed_fields = vec![create_text_field("Instruction", &title)?];
+            let expanded_fields = vec![create_text_field(
+                "Discriminator",
+                &hex::encode(discriminator),
+            )?];
+            (title, condensed_fields, expanded_fields)
+        }
+    };
+
+    expanded_fields.insert(0, create_text_field("Program ID", &program_id_str)?);
+    expanded_fields.push(create_text_field("Raw Data", &hex::encode(data))?);
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(data)
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+/// Title, condensed and expanded rows for a bonding curve trade: the token
+/// mint and trader, then the instruction's amount and slippage bound
+fn trade_fields(
+    context: &VisualizerContext,
+    title: String,
+    amounts: [(&str, String); 2],
+) -> Result<
+    (
+        String,
+        Vec<AnnotatedPayloadField>,
+        Vec<AnnotatedPayloadField>,
+    ),
+    VisualSignError,
+> {
+    let mint = account_str(context, 2);
+    let mut condensed_fields = vec![
+        create_text_field("Instruction", &title)?,
+        create_text_field("Token Mint", &mint)?,
+    ];
+    let mut expanded_fields = vec![
+        create_text_field("Token Mint", &mint)?,
+        create_text_field("Bonding Curve", &account_str(context, 3))?,
+        create_text_field("User", &account_str(context, 6))?,
+    ];
+    for (label, value) in amounts {
+        condensed_fields.push(create_text_field(label, &value)?);
+        expanded_fields.push(create_text_field(label, &value)?);
+    }
+    Ok((title, condensed_fields, expanded_fields))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    /// Visualizes `data` with the Pump.fun program at index 0 and `accounts`
+    /// after it, and returns the preview's title and expanded rows
+    fn visualize(data: Vec<u8>, accounts: &[Pubkey]) -> (String, Vec<(String, String)>) {
+        let mut account_keys =
+            vec![Pubkey::from_str("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P").unwrap()];
+        account_keys.extend_from_slice(accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data,
+        };
+        let sender = SolanaAccount {
+            account_key: Pubkey::new_unique().to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = PumpFunVisualizer.visualize_tx_commands(&context).unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect();
+        (preview_layout.title.unwrap().text, rows)
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    fn trade_data(discriminator: [u8; 8], first: u64, second: u64) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&first.to_le_bytes());
+        data.extend_from_slice(&second.to_le_bytes());
+        data
+    }
+
+    fn trade_accounts() -> Vec<Pubkey> {
+        (0..12).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_discriminators_match_anchor_sighash() {
+        for (name, discriminator) in [
+            ("buy", BUY),
+            ("sell", SELL),
+            ("buy_exact_sol_in", BUY_EXACT_SOL_IN),
+        ] {
+            let hash = solana_sdk::hash::hash(format!("global:{name}").as_bytes());
+            assert_eq!(hash.to_bytes()[..8], discriminator, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_buy_shows_mint_and_max_sol_cost() {
+        let accounts = trade_accounts();
+        let (title, rows) = visualize(trade_data(BUY, 35_000_000_000, 1_050_000_000), &accounts);
+
+        assert_eq!(title, "Pump.fun Buy: 35000 tokens");
+        assert_eq!(row(&rows, "Token Mint"), accounts[2].to_string());
+        assert_eq!(row(&rows, "User"), accounts[6].to_string());
+        assert_eq!(row(&rows, "Token Amount"), "35000");
+        assert_eq!(row(&rows, "Max SOL Cost"), "1.05 SOL");
+    }
+
+    #[test]
+    fn test_sell_with_trailing_optional_args() {
+        let accounts = trade_accounts();
+        let mut data = trade_data(SELL, 1_500_000, 20_000_000);
+        // Newer program versions append an optional flag
+        data.extend_from_slice(&[1, 1]);
+        let (title, rows) = visualize(data, &accounts);
+
+        assert_eq!(title, "Pump.fun Sell: 1.5 tokens");
+        assert_eq!(row(&rows, "Min SOL Output"), "0.02 SOL");
+    }
+
+    #[test]
+    fn test_buy_exact_sol_in() {
+        let (title, rows) = visualize(
+            trade_data(BUY_EXACT_SOL_IN, 500_000_000, 12_000_000),
+            &trade_accounts(),
+        );
+
+        assert_eq!(title, "Pump.fun Buy: 0.5 SOL");
+        assert_eq!(row(&rows, "Min Tokens Out"), "12");
+    }
+
+    #[test]
+    fn test_other_instruction_shows_discriminator() {
+        let (title, rows) = visualize(vec![1, 2, 3, 4, 5, 6, 7, 8], &[]);
+
+        assert_eq!(title, "Pump.fun Instruction");
+        assert_eq!(row(&rows, "Discriminator"), "0102030405060708");
+    }
+}
@@ -0,0 +1,31 @@
+//! Configuration for Sanctum router and Infinity pool integration
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::BTreeMap;
+
+/// Sanctum router (stakedex), which routes SOL and LSTs through stake accounts
+pub(crate) const SANCTUM_ROUTER_PROGRAM_ID: &str = "stkitrT1Uoy18Dk1fTrgPw8W6MVzoCfWYTVKKjbeLo";
+
+/// Sanctum Infinity, the multi-LST pool behind the INF token
+pub(crate) const SANCTUM_INFINITY_PROGRAM_ID: &str = "5ocnV1qiCgaQR8Jb8xWnVbApfaygJ8tNoZfgPwsgx9kx";
+
+pub struct SanctumConfig;
+
+impl SolanaIntegrationConfig for SanctumConfig {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| {
+            let mut programs = BTreeMap::new();
+            for program_id in [SANCTUM_ROUTER_PROGRAM_ID, SANCTUM_INFINITY_PROGRAM_ID] {
+                let mut sanctum_instructions = BTreeMap::new();
+                sanctum_instructions.insert("*", vec!["*"]);
+                programs.insert(program_id, sanctum_instructions);
+            }
+            SolanaIntegrationConfigData { programs }
+        })
+    }
+}
@@ -0,0 +1,501 @@
+//! Sanctum preset for Solana
+//! Handles liquid staking token (LST) routes through the Sanctum router
+//! (stkitrT1Uoy18Dk1fTrgPw8W6MVzoCfWYTVKKjbeLo), which stakes SOL into an
+//! LST, unstakes an LST back to SOL or swaps LSTs via a stake account, and
+//! through the Sanctum Infinity pool
+//! (5ocnV1qiCgaQR8Jb8xWnVbApfaygJ8tNoZfgPwsgx9kx), which swaps LSTs and
+//! mints or burns INF. Both programs use a one-byte discriminator followed
+//! by Borsh arguments.
+
+mod config;
+
+use crate::core::{
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
+};
+use crate::utils::{format_token_amount, get_token_info};
+use borsh::BorshDeserialize;
+use config::{SANCTUM_INFINITY_PROGRAM_ID, SANCTUM_ROUTER_PROGRAM_ID, SanctumConfig};
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::create_text_field;
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
+
+// Router (stakedex) instructions
+const STAKE_WRAPPED_SOL: u8 = 0;
+const SWAP_VIA_STAKE: u8 = 1;
+const WITHDRAW_WRAPPED_SOL: u8 = 8;
+
+// Infinity (S controller) instructions
+const SWAP_EXACT_IN: u8 = 1;
+const SWAP_EXACT_OUT: u8 = 2;
+const ADD_LIQUIDITY: u8 = 3;
+const REMOVE_LIQUIDITY: u8 = 4;
+
+/// SOL and every LST Sanctum routes are 9-decimal tokens
+const LST_DECIMALS: u8 = 9;
+
+/// Mints commonly routed through Sanctum. Other mints are shown by their
+/// truncated address.
+const KNOWN_LSTS: [(&str, &str); 8] = [
+    ("So11111111111111111111111111111111111111112", "SOL"),
+    ("5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm", "INF"),
+    ("J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn", "jitoSOL"),
+    ("mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So", "mSOL"),
+    ("bSo13r4TkiE4KumL71LsHTPpL2euBYLFx6h9HP3piy1", "bSOL"),
+    ("jupSoLaHXQiZZTSfEWMTRRgpnyFm8f6sZdosWBjx93v", "JupSOL"),
+    ("he1iusmfkpAdwvxLNGV8Y1iSbj4rUy6yMhEA3fotn9A", "hSOL"),
+    ("7dHbWXmci3dT8UFYWYZweBLXgycu7Y3iL6trKn1Y7ARj", "stSOL"),
+];
+
+// Create a static instance that we can reference
+static SANCTUM_CONFIG: SanctumConfig = SanctumConfig;
+
+pub struct SanctumVisualizer;
+
+impl InstructionVisualizer for SanctumVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        create_sanctum_preview_layout(context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&SANCTUM_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Dex("Sanctum")
+    }
+}
+
+/// Infinity swap arguments; `amount` is the exact side and `limit` the
+/// slippage bound on the other side
+#[derive(BorshDeserialize)]
+struct InfinitySwapArgs {
+    _src_lst_value_calc_accs: u8,
+    _dst_lst_value_calc_accs: u8,
+    _src_lst_index: u32,
+    _dst_lst_index: u32,
+    limit: u64,
+    amount: u64,
+}
+
+/// Infinity add/remove liquidity arguments: the amount put in and the
+/// minimum received
+#[derive(BorshDeserialize)]
+struct InfinityLiquidityArgs {
+    _lst_value_calc_accs: u8,
+    _lst_index: u32,
+    amount: u64,
+    min_out: u64,
+}
+
+/// One side of an LST route: the mint's account position and the amount
+/// rows shown for it
+struct RouteLeg<'a> {
+    mint_position: usize,
+    amounts: &'a [(&'a str, u64)],
+}
+
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+/// Symbol of `mint`, preferring caller-supplied token metadata over the
+/// built-in LST list
+fn lst_symbol(context: &VisualizerContext, mint: &str) -> String {
+    if let Some(token) = context.tokens().get(mint) {
+        return token.symbol.clone();
+    }
+    KNOWN_LSTS
+        .iter()
+        .find(|(address, _)| *address == mint)
+        .map_or_else(
+            || get_token_info(mint, 0).symbol,
+            |(_, symbol)| (*symbol).to_string(),
+        )
+}
+
+fn create_sanctum_preview_layout(
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id_str = match context.program_id() {
+        ProgramRef::Resolved(pk) => pk.to_string(),
+        ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
+    };
+    let data = context.data();
+    let (discriminator, args) = data
+        .split_first()
+        .ok_or_else(|| VisualSignError::DecodeError("Sanctum instruction data empty".into()))?;
+    let decode_err = |e: std::io::Error| {
+        VisualSignError::DecodeError(format!("Failed to parse Sanctum instruction: {e}"))
+    };
+
+    let (title, condensed_fields, mut expanded_fields) =
+        match (program_id_str.as_str(), *discriminator) {
+            (SANCTUM_ROUTER_PROGRAM_ID, STAKE_WRAPPED_SOL) => {
+                // Accounts: [0] user, [6] LST mint, [7] wSOL mint
+                let amount = u64::deserialize(&mut &args[..]).map_err(decode_err)?;
+                route_fields(
+                    context,
+                    "Sanctum Stake",
+                    RouteLeg {
+                        mint_position: 7,
+                        amounts: &[("Input Amount", amount)],
+                    },
+                    RouteLeg {
+                        mint_position: 6,
+                        amounts: &[],
+                    },
+                )?
+            }
+            (SANCTUM_ROUTER_PROGRAM_ID, SWAP_VIA_STAKE) => {
+                // Accounts: [0] user, [5] source LST mint, [6] destination LST mint
+                let amount = u64::deserialize(&mut &args[..]).map_err(decode_err)?;
+                route_fields(
+                    context,
+                    "Sanctum Swap",
+                    RouteLeg {
+                        mint_position: 5,
+                        amounts: &[("Input Amount", amount)],
+                    },
+                    RouteLeg {
+                        mint_position: 6,
+                        amounts: &[],
+                    },
+                )?
+            }
+            (SANCTUM_ROUTER_PROGRAM_ID, WITHDRAW_WRAPPED_SOL) => {
+                // Accounts: [0] user, [4] LST mint, [5] wSOL mint
+                let amount = u64::deserialize(&mut &args[..]).map_err(decode_err)?;
+                route_fields(
+                    context,
+                    "Sanctum Unstake",
+                    RouteLeg {
+                        mint_position: 4,
+                        amounts: &[("Input Amount", amount)],
+                    },
+                    RouteLeg {
+                        mint_position: 5,
+                        amounts: &[],
+                    },
+                )?
+            }
+            (SANCTUM_INFINITY_PROGRAM_ID, SWAP_EXACT_IN) => {
+                // Accounts: [0] signer, [1] source LST mint, [2] destination LST mint
+                let swap = InfinitySwapArgs::deserialize(&mut &args[..]).map_err(decode_err)?;
+                route_fields(
+                    context,
+                    "Sanctum Infinity Swap",
+                    RouteLeg {
+                        mint_position: 1,
+                        amounts: &[("Input Amount", swap.amount)],
+                    },
+                    RouteLeg {
+                        mint_position: 2,
+                        amounts: &[("Minimum Output Amount", swap.limit)],
+                    },
+                )?
+            }
+            (SANCTUM_INFINITY_PROGRAM_ID, SWAP_EXACT_OUT) => {
+                let swap = InfinitySwapArgs::deserialize(&mut &args[..]).map_err(decode_err)?;
+                route_fields(
+                    context,
+                    "Sanctum Infinity Swap",
+                    RouteLeg {
+                        mint_position: 1,
+                        amounts: &[("Maximum Input Amount", swap.limit)],
+                    },
+                    RouteLeg {
+                        mint_position: 2,
+                        amounts: &[("Output Amount", swap.amount)],
+                    },
+                )?
+            }
+            (SANCTUM_INFINITY_PROGRAM_ID, ADD_LIQUIDITY) => {
+                // Accounts: [0] signer, [1] LST mint, [4] INF mint
+                let liquidity =
+                    InfinityLiquidityArgs::deserialize(&mut &args[..]).map_err(decode_err)?;
+                route_fields(
+                    context,
+                    "Sanctum Infinity Deposit",
+                    RouteLeg {
+                        mint_position: 1,
+                        amounts: &[("Input Amount", liquidity.amount)],
+                    },
+                    RouteLeg {
+                        mint_position: 4,
+                        amounts: &[("Minimum Output Amount", liquidity.min_out)],
+                    },
+                )?
+            }
+            (SANCTUM_INFINITY_PROGRAM_ID, REMOVE_LIQUIDITY) => {
+                let liquidity =
+                    InfinityLiquidityArgs::deserialize(&mut &args[..]).map_err(decode_err)?;
+                route_fields(
+                    context,
+                    "Sanctum Infinity Withdraw",
+                    RouteLeg {
+                        mint_position: 4,
+                        amounts: &[("Input Amount", liquidity.amount)],
+                    },
+                    RouteLeg {
+                        mint_position: 1,
+                        amounts: &[("Minimum Output Amount", liquidity.min_out)],
+                    },
+                )?
+            }
+            _ => {
+                let title = "Sanctum Instruction".to_string();
+                let condensed_fields = vec![create_text_field("Instruction", &title)?];
+                let expanded_fields = vec![create_text_field(
+                    "Discriminator",
+                    &discriminator.to_string(),
+                )?];
+                (title, condensed_fields, expanded_fields)
+            }
+        };
+
+    expanded_fields.insert(0, create_text_field("Program ID", &program_id_str)?);
+    expanded_fields.push(create_text_field("Raw Data", &hex::encode(data))?);
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(data)
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+/// Title, condensed and expanded rows for a route from one LST to another.
+/// Every route's signer is account 0.
+fn route_fields(
+    context: &VisualizerContext,
+    action: &str,
+    input: RouteLeg<'_>,
+    output: RouteLeg<'_>,
+) -> Result<
+    (
+        String,
+        Vec<AnnotatedPayloadField>,
+        Vec<AnnotatedPayloadField>,
+    ),
+    VisualSignError,
+> {
+    let input_mint = account_str(context, input.mint_position);
+    let output_mint = account_str(context, output.mint_position);
+    let input_symbol = lst_symbol(context, &input_mint);
+    let output_symbol = lst_symbol(context, &output_mint);
+    let title = format!("{action}: {input_symbol} to {output_symbol}");
+
+    let mut condensed_fields = vec![
+        create_text_field("Instruction", &title)?,
+        create_text_field("Input Token", &input_symbol)?,
+        create_text_field("Output Token", &output_symbol)?,
+    ];
+    let mut expanded_fields = vec![
+        create_text_field("User", &account_str(context, 0))?,
+        create_text_field("Input Token", &input_symbol)?,
+        create_text_field("Input Mint", &input_mint)?,
+        create_text_field("Output Token", &output_symbol)?,
+        create_text_field("Output Mint", &output_mint)?,
+    ];
+    for (leg, symbol) in [(&input, &input_symbol), (&output, &output_symbol)] {
+        for (label, amount) in leg.amounts {
+            let value = format!("{} {symbol}", format_token_amount(*amount, LST_DECIMALS));
+            condensed_fields.push(create_text_field(label, &value)?);
+            expanded_fields.push(create_text_field(label, &value)?);
+        }
+    }
+    Ok((title, condensed_fields, expanded_fields))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    const WSOL: &str = "So11111111111111111111111111111111111111112";
+    const JITOSOL: &str = "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn";
+    const MSOL: &str = "mSoLzYCxHdYgdzU16g5QSh3i5K3z3KZK7ytfqcJm7So";
+    const INF: &str = "5oVNBeEEQvYi1cX3ir8Dx5n1P7pdxydbGF2X4TxVusJm";
+
+    /// Visualizes `data` with `program_id` at index 0 and 12 accounts after
+    /// it, where `mints` places known mints at the given positions, and
+    /// returns the preview's title and expanded rows
+    fn visualize(
+        program_id: &str,
+        data: Vec<u8>,
+        mints: &[(usize, &str)],
+    ) -> (String, Vec<(String, String)>) {
+        let mut account_keys = vec![Pubkey::from_str(program_id).unwrap()];
+        let mut accounts: Vec<Pubkey> = (0..12).map(|_| Pubkey::new_unique()).collect();
+        for (position, mint) in mints {
+            accounts[*position] = Pubkey::from_str(mint).unwrap();
+        }
+        account_keys.extend_from_slice(&accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data,
+        };
+        let sender = SolanaAccount {
+            account_key: Pubkey::new_unique().to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = SanctumVisualizer.visualize_tx_commands(&context).unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect();
+        (preview_layout.title.unwrap().text, rows)
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    fn router_data(discriminator: u8, amount: u64) -> Vec<u8> {
+        let mut data = vec![discriminator];
+        data.extend_from_slice(&amount.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_stake_wrapped_sol_into_jitosol() {
+        let (title, rows) = visualize(
+            SANCTUM_ROUTER_PROGRAM_ID,
+            router_data(STAKE_WRAPPED_SOL, 2_500_000_000),
+            &[(6, JITOSOL), (7, WSOL)],
+        );
+
+        assert_eq!(title, "Sanctum Stake: SOL to jitoSOL");
+        assert_eq!(row(&rows, "Input Amount"), "2.5 SOL");
+        assert_eq!(row(&rows, "Output Mint"), JITOSOL);
+    }
+
+    #[test]
+    fn test_swap_via_stake_between_lsts() {
+        let mut data = router_data(SWAP_VIA_STAKE, 1_000_000_000);
+        // bridge_stake_seed
+        data.extend_from_slice(&7u32.to_le_bytes());
+        let (title, rows) = visualize(SANCTUM_ROUTER_PROGRAM_ID, data, &[(5, JITOSOL), (6, MSOL)]);
+
+        assert_eq!(title, "Sanctum Swap: jitoSOL to mSOL");
+        assert_eq!(row(&rows, "Input Amount"), "1 jitoSOL");
+    }
+
+    #[test]
+    fn test_withdraw_wrapped_sol_unstakes_lst() {
+        let (title, rows) = visualize(
+            SANCTUM_ROUTER_PROGRAM_ID,
+            router_data(WITHDRAW_WRAPPED_SOL, 750_000_000),
+            &[(4, JITOSOL), (5, WSOL)],
+        );
+
+        assert_eq!(title, "Sanctum Unstake: jitoSOL to SOL");
+        assert_eq!(row(&rows, "Input Amount"), "0.75 jitoSOL");
+    }
+
+    #[test]
+    fn test_infinity_swap_exact_in_into_inf_route() {
+        let mut data = vec![SWAP_EXACT_IN, 1, 1];
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&990_000_000u64.to_le_bytes());
+        data.extend_from_slice(&1_000_000_000u64.to_le_bytes());
+        let (title, rows) = visualize(
+            SANCTUM_INFINITY_PROGRAM_ID,
+            data,
+            &[(1, MSOL), (2, JITOSOL)],
+        );
+
+        assert_eq!(title, "Sanctum Infinity Swap: mSOL to jitoSOL");
+        assert_eq!(row(&rows, "Input Amount"), "1 mSOL");
+        assert_eq!(row(&rows, "Minimum Output Amount"), "0.99 jitoSOL");
+    }
+
+    #[test]
+    fn test_infinity_add_liquidity_mints_inf() {
+        let mut data = vec![ADD_LIQUIDITY, 1];
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(&5_000_000_000u64.to_le_bytes());
+        data.extend_from_slice(&4_000_000_000u64.to_le_bytes());
+        let (title, rows) = visualize(SANCTUM_INFINITY_PROGRAM_ID, data, &[(1, JITOSOL), (4, INF)]);
+
+        assert_eq!(title, "Sanctum Infinity Deposit: jitoSOL to INF");
+        assert_eq!(row(&rows, "Input Amount"), "5 jitoSOL");
+        assert_eq!(row(&rows, "Minimum Output Amount"), "4 INF");
+    }
+
+    #[test]
+    fn test_unknown_mint_uses_truncated_address() {
+        let (title, _) = visualize(
+            SANCTUM_ROUTER_PROGRAM_ID,
+            router_data(STAKE_WRAPPED_SOL, 1),
+            &[(7, WSOL)],
+        );
+
+        assert!(title.starts_with("Sanctum Stake: SOL to "));
+        assert!(title.contains("..."));
+    }
+
+    #[test]
+    fn test_other_instruction_shows_discriminator() {
+        let (title, rows) = visualize(SANCTUM_ROUTER_PROGRAM_ID, vec![2], &[]);
+
+        assert_eq!(title, "Sanctum Instruction");
+        assert_eq!(row(&rows, "Discriminator"), "2");
+    }
+}
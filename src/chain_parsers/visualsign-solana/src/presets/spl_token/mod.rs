@@ -7,6 +7,7 @@ use crate::core::{
     AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
     VisualizerKind,
 };
+use crate::utils::{format_token_amount, get_token_lookup_table};
 use config::SplTokenConfig;
 use solana_program::program_option::COption;
 use spl_token::instruction::{AuthorityType, TokenInstruction};
@@ -134,6 +135,46 @@ fn format_coption_pubkey(coption: &COption<solana_sdk::pubkey::Pubkey>) -> Strin
     }
 }
 
+/// Largest `decimals` scaled in a checked instruction. Matches the Token2022
+/// preset: real mints stay well below this, so anything larger is shown as
+/// the raw amount rather than as a misleading scaled one.
+const MAX_TOKEN_DECIMALS: u8 = 18;
+
+/// Formats the amount of a `*Checked` instruction using the decimals it
+/// declares. The token program rejects the instruction if those decimals do not
+/// match the mint, so they are as trustworthy as the mint's own metadata. The
+/// symbol is appended when the mint is in the static token table.
+fn format_checked_amount(amount: u64, decimals: u8, mint: Option<&String>) -> String {
+    if decimals > MAX_TOKEN_DECIMALS {
+        return format!("{amount} ({decimals} decimals)");
+    }
+    let formatted = format_token_amount(amount, decimals);
+    let symbol = mint.and_then(|mint| {
+        get_token_lookup_table()
+            .get(mint.as_str())
+            .filter(|token| token.decimals == decimals)
+            .map(|token| token.symbol)
+    });
+    match symbol {
+        Some(symbol) => format!("{formatted} {symbol}"),
+        None => formatted,
+    }
+}
+
+/// Amount rows shared by the checked instructions: the formatted amount, the
+/// raw base-unit amount and the declared decimals.
+fn checked_amount_fields(
+    formatted_amount: &str,
+    amount: u64,
+    decimals: u8,
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    Ok(vec![
+        create_text_field("Amount", formatted_amount)?,
+        create_number_field("Raw Amount", &amount.to_string(), "")?,
+        create_text_field("Decimals", &decimals.to_string())?,
+    ])
+}
+
 fn create_token_preview_layout(
     token_instruction: &TokenInstruction,
     instruction: &InstructionView,
@@ -178,16 +219,24 @@ fn create_token_preview_layout(
             )
         }
         TokenInstruction::MintToChecked { amount, decimals } => {
-            let instruction_name = format!("Mint To: {amount} (decimals: {decimals})");
+            let formatted_amount =
+                format_checked_amount(*amount, *decimals, instruction.accounts.first());
+            let instruction_name = format!("Mint To: {formatted_amount}");
 
-            let condensed_fields = vec![create_text_field("Instruction", &instruction_name)?];
+            let condensed_fields = vec![
+                create_text_field("Instruction", &instruction_name)?,
+                create_text_field("Amount", &formatted_amount)?,
+            ];
 
             let mut expanded_fields = vec![
                 create_text_field("Program ID", &instruction.program_id)?,
                 create_text_field("Instruction", "Mint To (Checked)")?,
-                create_text_field("Amount", &amount.to_string())?,
-                create_text_field("Decimals", &decimals.to_string())?,
             ];
+            expanded_fields.extend(checked_amount_fields(
+                &formatted_amount,
+                *amount,
+                *decimals,
+            )?);
 
             // MintToChecked accounts: [0] mint, [1] destination account, [2] mint authority
             if let Some(mint_account) = instruction.accounts.first() {
@@ -301,15 +350,23 @@ fn create_token_preview_layout(
         }
         TokenInstruction::TransferChecked { amount, decimals } => {
             let instruction_name = "Transfer (Checked)";
+            let formatted_amount =
+                format_checked_amount(*amount, *decimals, instruction.accounts.get(1));
 
-            let condensed_fields = vec![create_text_field("Instruction", instruction_name)?];
+            let condensed_fields = vec![
+                create_text_field("Instruction", instruction_name)?,
+                create_text_field("Amount", &formatted_amount)?,
+            ];
 
             let mut expanded_fields = vec![
                 create_text_field("Program ID", &instruction.program_id)?,
                 create_text_field("Instruction", instruction_name)?,
-                create_text_field("Amount", &amount.to_string())?,
-                create_text_field("Decimals", &decimals.to_string())?,
             ];
+            expanded_fields.extend(checked_amount_fields(
+                &formatted_amount,
+                *amount,
+                *decimals,
+            )?);
 
             // TransferChecked accounts: [0] source account, [1] mint, [2] destination account, [3] owner
             if let Some(source) = instruction.accounts.first() {
@@ -379,15 +436,23 @@ fn create_token_preview_layout(
         }
         TokenInstruction::BurnChecked { amount, decimals } => {
             let instruction_name = "Burn (Checked)";
+            let formatted_amount =
+                format_checked_amount(*amount, *decimals, instruction.accounts.get(1));
 
-            let condensed_fields = vec![create_text_field("Instruction", instruction_name)?];
+            let condensed_fields = vec![
+                create_text_field("Instruction", instruction_name)?,
+                create_text_field("Amount", &formatted_amount)?,
+            ];
 
             let mut expanded_fields = vec![
                 create_text_field("Program ID", &instruction.program_id)?,
                 create_text_field("Instruction", instruction_name)?,
-                create_text_field("Amount", &amount.to_string())?,
-                create_text_field("Decimals", &decimals.to_string())?,
             ];
+            expanded_fields.extend(checked_amount_fields(
+                &formatted_amount,
+                *amount,
+                *decimals,
+            )?);
 
             // BurnChecked accounts: [0] token account to burn from, [1] mint, [2] owner
             if let Some(account) = instruction.accounts.first() {
@@ -458,15 +523,23 @@ fn create_token_preview_layout(
         }
         TokenInstruction::ApproveChecked { amount, decimals } => {
             let instruction_name = "Approve (Checked)";
+            let formatted_amount =
+                format_checked_amount(*amount, *decimals, instruction.accounts.get(1));
 
-            let condensed_fields = vec![create_text_field("Instruction", instruction_name)?];
+            let condensed_fields = vec![
+                create_text_field("Instruction", instruction_name)?,
+                create_text_field("Amount", &formatted_amount)?,
+            ];
 
             let mut expanded_fields = vec![
                 create_text_field("Program ID", &instruction.program_id)?,
                 create_text_field("Instruction", instruction_name)?,
-                create_text_field("Amount", &amount.to_string())?,
-                create_text_field("Decimals", &decimals.to_string())?,
             ];
+            expanded_fields.extend(checked_amount_fields(
+                &formatted_amount,
+                *amount,
+                *decimals,
+            )?);
 
             // ApproveChecked accounts: [0] source account, [1] mint, [2] delegate, [3] owner
             if let Some(source) = instruction.accounts.first() {
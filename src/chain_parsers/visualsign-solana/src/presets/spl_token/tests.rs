@@ -700,6 +700,51 @@ fn test_transfer_checked_visualization_with_decimals() {
     }
 }
 
+#[test]
+fn test_transfer_checked_with_oversized_decimals_shows_raw_amount() {
+    let owner = Pubkey::new_unique();
+    let instruction = token_instruction::transfer_checked(
+        &spl_token::id(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &owner,
+        &[],
+        2500,
+        19,
+    )
+    .unwrap();
+
+    let sender = SolanaAccount {
+        account_key: owner.to_string(),
+        signer: false,
+        writable: false,
+    };
+    let (compiled, account_keys) = compile_for_test(&instruction);
+    let idl_registry = crate::idl::IdlRegistry::new();
+    let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+    let result = SplTokenVisualizer
+        .visualize_tx_commands(&context)
+        .expect("decimals past the supported range still visualize");
+    let SignablePayloadField::PreviewLayout { preview_layout, .. } = result.signable_payload_field
+    else {
+        panic!("Expected PreviewLayout");
+    };
+    let amount = preview_layout
+        .expanded
+        .as_ref()
+        .unwrap()
+        .fields
+        .iter()
+        .find(|field| field.signable_payload_field.label() == "Amount")
+        .expect("Amount field");
+    assert_eq!(
+        amount.signable_payload_field.fallback_text(),
+        "2500 (19 decimals)"
+    );
+}
+
 #[test]
 fn test_set_authority_with_mint_tokens() {
     // Test SetAuthority with MintTokens authority type
@@ -1193,6 +1238,103 @@ fn test_v0_unresolved_accounts_render_placeholder_not_err() {
     );
 }
 
+fn visualize_for_test(instruction: &Instruction) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let sender = SolanaAccount {
+        account_key: Pubkey::new_unique().to_string(),
+        signer: false,
+        writable: false,
+    };
+    let (compiled, account_keys) = compile_for_test(instruction);
+    let idl_registry = crate::idl::IdlRegistry::new();
+    let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+    SplTokenVisualizer.visualize_tx_commands(&context)
+}
+
+#[test]
+fn test_transfer_checked_formats_amount_with_mint_symbol() {
+    let usdc = Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap();
+    let instruction = token_instruction::transfer_checked(
+        &spl_token::id(),
+        &Pubkey::new_unique(),
+        &usdc,
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &[],
+        2_500_000,
+        6,
+    )
+    .unwrap();
+
+    let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+        visualize_for_test(&instruction)
+            .unwrap()
+            .signable_payload_field
+    else {
+        panic!("Expected PreviewLayout");
+    };
+    let text_of = |fields: &[AnnotatedPayloadField], label: &str| {
+        fields
+            .iter()
+            .find(|f| f.signable_payload_field.label() == label)
+            .map(|f| f.signable_payload_field.fallback_text().clone())
+    };
+
+    let condensed = preview_layout.condensed.unwrap().fields;
+    assert_eq!(text_of(&condensed, "Amount").as_deref(), Some("2.5 USDC"));
+
+    let expanded = preview_layout.expanded.unwrap().fields;
+    assert_eq!(text_of(&expanded, "Amount").as_deref(), Some("2.5 USDC"));
+    assert_eq!(text_of(&expanded, "Raw Amount").as_deref(), Some("2500000"));
+    assert_eq!(text_of(&expanded, "Decimals").as_deref(), Some("6"));
+}
+
+#[test]
+fn test_checked_amount_without_known_mint_uses_decimals_only() {
+    let instruction = token_instruction::burn_checked(
+        &spl_token::id(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &[],
+        1_234_500_000,
+        9,
+    )
+    .unwrap();
+
+    let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+        visualize_for_test(&instruction)
+            .unwrap()
+            .signable_payload_field
+    else {
+        panic!("Expected PreviewLayout");
+    };
+    let amount = preview_layout
+        .expanded
+        .unwrap()
+        .fields
+        .into_iter()
+        .find(|f| f.signable_payload_field.label() == "Amount")
+        .map(|f| f.signable_payload_field.fallback_text().clone());
+    assert_eq!(amount.as_deref(), Some("1.2345"));
+}
+
+#[test]
+fn test_checked_amount_rejects_out_of_range_decimals() {
+    let instruction = token_instruction::mint_to_checked(
+        &spl_token::id(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &Pubkey::new_unique(),
+        &[],
+        1,
+        19,
+    )
+    .unwrap();
+
+    let err = visualize_for_test(&instruction).unwrap_err();
+    assert!(err.to_string().contains("decimals 19"), "{err}");
+}
+
 /// Load a transaction fixture and test field extraction
 mod fixture_tests {
     use super::*;
@@ -1,4 +1,5 @@
 use super::SQUADS_MULTISIG_PROGRAM_ID;
+use super::v3::SQUADS_V3_PROGRAM_ID;
 use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
 use std::collections::BTreeMap;
 
@@ -15,7 +16,8 @@ impl SolanaIntegrationConfig for SquadsMultisigConfig {
             let mut programs = BTreeMap::new();
             let mut instructions = BTreeMap::new();
             instructions.insert("*", vec!["*"]);
-            programs.insert(SQUADS_MULTISIG_PROGRAM_ID, instructions);
+            programs.insert(SQUADS_MULTISIG_PROGRAM_ID, instructions.clone());
+            programs.insert(SQUADS_V3_PROGRAM_ID, instructions);
             SolanaIntegrationConfigData { programs }
         })
     }
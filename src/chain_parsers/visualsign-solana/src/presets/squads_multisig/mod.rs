@@ -1,6 +1,8 @@
-//! Squads v4 Multisig preset implementation for Solana
+//! Squads Multisig preset implementation for Solana: v4 through its IDL,
+//! and the v3 transaction lifecycle in `v3`
 
 mod config;
+mod v3;
 
 use crate::core::{
     InstructionVisualizer, MAX_CALL_DEPTH, SolanaIntegrationConfig, VisualizerContext,
@@ -165,12 +167,18 @@ impl InstructionVisualizer for SquadsMultisigVisualizer {
         let instruction_data_hex = hex::encode(data);
         let fallback_text = format!("Program ID: {program_id_str}\nData: {instruction_data_hex}");
 
-        let parsed = parse_squads_instruction(data, &accounts);
-
-        let (title, condensed_fields, expanded_fields) = match parsed {
-            Ok(parsed) => build_parsed_fields(&parsed, &program_id_str, context)?,
-            Err(_) => build_fallback_fields(&program_id_str)?,
-        };
+        let (title, condensed_fields, expanded_fields) =
+            if program_id_str == v3::SQUADS_V3_PROGRAM_ID {
+                match v3::build_fields(data, &accounts, &program_id_str, context) {
+                    Ok(fields) => fields,
+                    Err(_) => build_fallback_fields(&program_id_str)?,
+                }
+            } else {
+                match parse_squads_instruction(data, &accounts) {
+                    Ok(parsed) => build_parsed_fields(&parsed, &program_id_str, context)?,
+                    Err(_) => build_fallback_fields(&program_id_str)?,
+                }
+            };
 
         let condensed = SignablePayloadFieldListLayout {
             fields: condensed_fields,
@@ -291,7 +299,118 @@ fn build_parsed_fields(
         }
     }
 
-    build_generic_fields(parsed, &instruction.named_accounts, program_id)
+    match parsed.instruction_name.as_str() {
+        "proposalApprove" => build_proposal_vote_fields(
+            parsed,
+            &instruction.named_accounts,
+            program_id,
+            "Approve Proposal",
+        ),
+        "proposalReject" => build_proposal_vote_fields(
+            parsed,
+            &instruction.named_accounts,
+            program_id,
+            "Reject Proposal",
+        ),
+        "vaultTransactionExecute" => build_vault_transaction_execute_fields(
+            parsed,
+            &instruction.named_accounts,
+            program_id,
+            context,
+        ),
+        _ => build_generic_fields(parsed, &instruction.named_accounts, program_id),
+    }
+}
+
+/// Number of fixed accounts `vaultTransactionExecute` takes before the vault
+/// transaction's own accounts: multisig, proposal, transaction, member.
+const VAULT_TRANSACTION_EXECUTE_FIXED_ACCOUNTS: usize = 4;
+
+/// proposalApprove / proposalReject: a member's vote on a pending proposal,
+/// with the optional memo they attached.
+fn build_proposal_vote_fields(
+    parsed: &SolanaParsedInstructionData,
+    named_accounts: &BTreeMap<String, String>,
+    program_id: &str,
+    action: &str,
+) -> Result<SquadsPreviewFields, VisualSignError> {
+    let title = format!("Squads Multisig: {action}");
+    let proposal = named_accounts
+        .get("proposal")
+        .map_or("unknown", String::as_str);
+    let memo = parsed
+        .program_call_args
+        .get("args")
+        .and_then(|args| args.get("memo"))
+        .and_then(|v| v.as_str());
+
+    let mut condensed_fields = vec![
+        create_text_field("Program", "Squads Multisig")?,
+        create_text_field("Instruction", action)?,
+        create_text_field("Proposal", proposal)?,
+    ];
+    if let Some(memo) = memo {
+        condensed_fields.push(create_text_field("Memo", memo)?);
+    }
+
+    let mut expanded_fields = vec![
+        create_text_field("Program ID", program_id)?,
+        create_text_field("Instruction", &parsed.instruction_name)?,
+        create_text_field("Discriminator", &parsed.discriminator)?,
+    ];
+    for (account_name, account_address) in named_accounts {
+        expanded_fields.push(create_text_field(account_name, account_address)?);
+    }
+    expanded_fields.push(create_text_field("Memo", memo.unwrap_or("None"))?);
+
+    Ok((title, condensed_fields, expanded_fields))
+}
+
+/// vaultTransactionExecute carries no instruction data of its own: the inner
+/// message was stored by vaultTransactionCreate and is only readable on
+/// chain. Show which transaction runs and how many accounts it touches.
+fn build_vault_transaction_execute_fields(
+    parsed: &SolanaParsedInstructionData,
+    named_accounts: &BTreeMap<String, String>,
+    program_id: &str,
+    context: &VisualizerContext,
+) -> Result<SquadsPreviewFields, VisualSignError> {
+    let title = "Squads Multisig: Execute Vault Transaction".to_string();
+    let transaction = named_accounts
+        .get("transaction")
+        .map_or("unknown", String::as_str);
+    let proposal = named_accounts
+        .get("proposal")
+        .map_or("unknown", String::as_str);
+    let inner_accounts = context
+        .num_accounts()
+        .saturating_sub(VAULT_TRANSACTION_EXECUTE_FIXED_ACCOUNTS);
+
+    let condensed_fields = vec![
+        create_text_field("Program", "Squads Multisig")?,
+        create_text_field("Instruction", "Execute Vault Transaction")?,
+        create_text_field("Transaction", transaction)?,
+        create_text_field("Proposal", proposal)?,
+    ];
+
+    let mut expanded_fields = vec![
+        create_text_field("Program ID", program_id)?,
+        create_text_field("Instruction", &parsed.instruction_name)?,
+        create_text_field("Discriminator", &parsed.discriminator)?,
+    ];
+    for (account_name, account_address) in named_accounts {
+        expanded_fields.push(create_text_field(account_name, account_address)?);
+    }
+    expanded_fields.push(create_text_field(
+        "Inner Accounts",
+        &inner_accounts.to_string(),
+    )?);
+    expanded_fields.push(create_text_field(
+        "Inner Instructions",
+        "Stored in the transaction account; shown when the vault transaction is created",
+    )?);
+
+    Ok((title, condensed_fields, expanded_fields))
 }
 
 /// Try to decode the nested transaction message inside vaultTransactionCreate.
@@ -972,4 +1091,45 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_proposal_approve_shows_proposal_and_memo() -> TestResult {
+        let mut args = serde_json::Map::new();
+        args.insert(
+            "args".to_string(),
+            serde_json::json!({ "memo": "payroll batch 7" }),
+        );
+        let parsed = SolanaParsedInstructionData {
+            instruction_name: "proposalApprove".to_string(),
+            discriminator: "00".to_string(),
+            named_accounts: Default::default(),
+            program_call_args: args,
+            idl_source: solana_parser::IdlSource::Custom,
+            idl_hash: String::new(),
+        };
+        let proposal = make_pubkey(3).to_string();
+        let mut named = BTreeMap::new();
+        named.insert("proposal".to_string(), proposal.clone());
+
+        let (title, condensed, _expanded) = build_proposal_vote_fields(
+            &parsed,
+            &named,
+            SQUADS_MULTISIG_PROGRAM_ID,
+            "Approve Proposal",
+        )?;
+
+        assert_eq!(title, "Squads Multisig: Approve Proposal");
+        let condensed: Vec<(String, String)> = condensed
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect();
+        assert!(condensed.contains(&("Proposal".to_string(), proposal)));
+        assert!(condensed.contains(&("Memo".to_string(), "payroll batch 7".to_string())));
+        Ok(())
+    }
 }
@@ -0,0 +1,274 @@
+//! Squads v3 (SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu) instructions.
+//!
+//! There is no v3 IDL in this tree, so the transaction lifecycle instructions
+//! are decoded by hand from their Anchor discriminators. v3 stores each inner
+//! instruction in its own account via `add_instruction`, whose data carries
+//! the full instruction; that is where the nested visualization happens.
+
+use super::{SquadsPreviewFields, visualize_inner_instructions};
+use crate::core::VisualizerContext;
+use borsh::BorshDeserialize;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::create_text_field;
+
+pub(crate) const SQUADS_V3_PROGRAM_ID: &str = "SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu";
+
+// sha256("global:<instruction_name>")[..8]
+const CREATE_TRANSACTION: [u8; 8] = [227, 193, 53, 239, 55, 126, 112, 105];
+const ADD_INSTRUCTION: [u8; 8] = [11, 70, 136, 166, 202, 55, 246, 74];
+const ACTIVATE_TRANSACTION: [u8; 8] = [56, 17, 0, 163, 135, 11, 135, 32];
+const APPROVE_TRANSACTION: [u8; 8] = [224, 39, 88, 181, 36, 59, 155, 122];
+const REJECT_TRANSACTION: [u8; 8] = [47, 141, 218, 192, 80, 97, 209, 116];
+const CANCEL_TRANSACTION: [u8; 8] = [65, 191, 19, 127, 230, 26, 214, 142];
+const EXECUTE_TRANSACTION: [u8; 8] = [231, 173, 49, 91, 235, 24, 68, 19];
+const EXECUTE_INSTRUCTION: [u8; 8] = [48, 18, 40, 40, 75, 74, 147, 110];
+
+#[derive(BorshDeserialize)]
+struct MsAccountMeta {
+    pubkey: [u8; 32],
+    is_signer: bool,
+    is_writable: bool,
+}
+
+#[derive(BorshDeserialize)]
+struct IncomingInstruction {
+    program_id: [u8; 32],
+    keys: Vec<MsAccountMeta>,
+    data: Vec<u8>,
+}
+
+impl IncomingInstruction {
+    fn to_instruction(&self) -> Instruction {
+        Instruction {
+            program_id: Pubkey::new_from_array(self.program_id),
+            accounts: self
+                .keys
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: Pubkey::new_from_array(meta.pubkey),
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: self.data.clone(),
+        }
+    }
+}
+
+enum SquadsV3Instruction {
+    CreateTransaction { authority_index: u32 },
+    AddInstruction(IncomingInstruction),
+    ActivateTransaction,
+    ApproveTransaction,
+    RejectTransaction,
+    CancelTransaction,
+    ExecuteTransaction { account_list: Vec<u8> },
+    ExecuteInstruction,
+}
+
+fn parse_instruction(data: &[u8]) -> Result<SquadsV3Instruction, VisualSignError> {
+    let (discriminator, args) = data
+        .split_first_chunk::<8>()
+        .ok_or_else(|| VisualSignError::DecodeError("Squads v3 data too short".into()))?;
+    let decode_err = |e: std::io::Error| {
+        VisualSignError::DecodeError(format!("Failed to parse Squads v3 instruction: {e}"))
+    };
+
+    Ok(match *discriminator {
+        CREATE_TRANSACTION => SquadsV3Instruction::CreateTransaction {
+            authority_index: u32::try_from_slice(args).map_err(decode_err)?,
+        },
+        ADD_INSTRUCTION => SquadsV3Instruction::AddInstruction(
+            IncomingInstruction::try_from_slice(args).map_err(decode_err)?,
+        ),
+        ACTIVATE_TRANSACTION => SquadsV3Instruction::ActivateTransaction,
+        APPROVE_TRANSACTION => SquadsV3Instruction::ApproveTransaction,
+        REJECT_TRANSACTION => SquadsV3Instruction::RejectTransaction,
+        CANCEL_TRANSACTION => SquadsV3Instruction::CancelTransaction,
+        EXECUTE_TRANSACTION => SquadsV3Instruction::ExecuteTransaction {
+            account_list: Vec::<u8>::try_from_slice(args).map_err(decode_err)?,
+        },
+        EXECUTE_INSTRUCTION => SquadsV3Instruction::ExecuteInstruction,
+        _ => {
+            return Err(VisualSignError::DecodeError(
+                "Unknown Squads v3 instruction".into(),
+            ));
+        }
+    })
+}
+
+/// The vault ("authority") PDA a v3 transaction signs as:
+/// `[b"squad", multisig, authority_index (u32 LE), b"authority"]`.
+fn authority_pda(multisig: &Pubkey, authority_index: u32) -> Option<Pubkey> {
+    let program_id = Pubkey::from_str(SQUADS_V3_PROGRAM_ID).ok()?;
+    let (pda, _bump) = Pubkey::find_program_address(
+        &[
+            b"squad",
+            multisig.as_ref(),
+            &authority_index.to_le_bytes(),
+            b"authority",
+        ],
+        &program_id,
+    );
+    Some(pda)
+}
+
+/// Decodes a Squads v3 instruction and builds its preview fields. Returns
+/// `Err` when the data is not a recognised v3 instruction, so the caller can
+/// fall back to the generic unknown-instruction display.
+pub(super) fn build_fields(
+    data: &[u8],
+    accounts: &[AccountMeta],
+    program_id: &str,
+    context: &VisualizerContext,
+) -> Result<SquadsPreviewFields, VisualSignError> {
+    let instruction = parse_instruction(data)?;
+    let account = |position: usize| {
+        accounts
+            .get(position)
+            .map_or_else(|| "unknown".to_string(), |meta| meta.pubkey.to_string())
+    };
+
+    // Every v3 instruction starts with [0] multisig, [1] transaction
+    let (action, account_names, mut extra_fields): (&str, &[&str], _) = match &instruction {
+        SquadsV3Instruction::CreateTransaction { authority_index } => {
+            let mut extra = vec![create_text_field(
+                "Authority Index",
+                &authority_index.to_string(),
+            )?];
+            if let Some(vault) = accounts
+                .first()
+                .and_then(|multisig| authority_pda(&multisig.pubkey, *authority_index))
+            {
+                extra.push(create_text_field("Vault", &vault.to_string())?);
+            }
+            (
+                "Create Transaction",
+                &["Multisig", "Transaction", "Creator"][..],
+                extra,
+            )
+        }
+        SquadsV3Instruction::AddInstruction(incoming) => {
+            // v3 inner instructions sign as the vault PDA, which depends on the
+            // authority index chosen at creation and is not known here
+            let inner_fields =
+                visualize_inner_instructions(&[incoming.to_instruction()], context, None)?;
+            (
+                "Add Instruction",
+                &["Multisig", "Transaction", "Instruction", "Creator"][..],
+                inner_fields,
+            )
+        }
+        SquadsV3Instruction::ActivateTransaction => (
+            "Activate Transaction",
+            &["Multisig", "Transaction", "Creator"][..],
+            Vec::new(),
+        ),
+        SquadsV3Instruction::ApproveTransaction => (
+            "Approve Transaction",
+            &["Multisig", "Transaction", "Member"][..],
+            Vec::new(),
+        ),
+        SquadsV3Instruction::RejectTransaction => (
+            "Reject Transaction",
+            &["Multisig", "Transaction", "Member"][..],
+            Vec::new(),
+        ),
+        SquadsV3Instruction::CancelTransaction => (
+            "Cancel Transaction",
+            &["Multisig", "Transaction", "Member"][..],
+            Vec::new(),
+        ),
+        SquadsV3Instruction::ExecuteTransaction { account_list } => (
+            "Execute Transaction",
+            &["Multisig", "Transaction", "Member"][..],
+            vec![create_text_field(
+                "Inner Accounts",
+                &account_list.len().to_string(),
+            )?],
+        ),
+        SquadsV3Instruction::ExecuteInstruction => (
+            "Execute Instruction",
+            &["Multisig", "Transaction", "Instruction", "Member"][..],
+            Vec::new(),
+        ),
+    };
+
+    let title = format!("Squads Multisig v3: {action}");
+    let condensed_fields = vec![
+        create_text_field("Program", "Squads Multisig v3")?,
+        create_text_field("Instruction", action)?,
+        create_text_field("Transaction", &account(1))?,
+    ];
+
+    let mut expanded_fields = vec![
+        create_text_field("Program ID", program_id)?,
+        create_text_field("Instruction", action)?,
+    ];
+    for (position, name) in account_names.iter().enumerate() {
+        expanded_fields.push(create_text_field(name, &account(position))?);
+    }
+    expanded_fields.append(&mut extra_fields);
+
+    Ok((title, condensed_fields, expanded_fields))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discriminators_match_anchor_sighash() {
+        for (name, discriminator) in [
+            ("create_transaction", CREATE_TRANSACTION),
+            ("add_instruction", ADD_INSTRUCTION),
+            ("activate_transaction", ACTIVATE_TRANSACTION),
+            ("approve_transaction", APPROVE_TRANSACTION),
+            ("reject_transaction", REJECT_TRANSACTION),
+            ("cancel_transaction", CANCEL_TRANSACTION),
+            ("execute_transaction", EXECUTE_TRANSACTION),
+            ("execute_instruction", EXECUTE_INSTRUCTION),
+        ] {
+            let hash = solana_sdk::hash::hash(format!("global:{name}").as_bytes());
+            assert_eq!(hash.to_bytes()[..8], discriminator, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_parse_add_instruction_carries_inner_instruction() {
+        let program_id = Pubkey::new_unique();
+        let key = Pubkey::new_unique();
+        let mut data = ADD_INSTRUCTION.to_vec();
+        data.extend_from_slice(program_id.as_ref());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(key.as_ref());
+        data.extend_from_slice(&[1, 0]);
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&[7, 8, 9]);
+
+        let SquadsV3Instruction::AddInstruction(incoming) = parse_instruction(&data).unwrap()
+        else {
+            panic!("expected AddInstruction");
+        };
+        let instruction = incoming.to_instruction();
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(
+            instruction.accounts,
+            vec![AccountMeta::new_readonly(key, true)]
+        );
+        assert_eq!(instruction.data, vec![7, 8, 9]);
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_discriminator_and_trailing_bytes() {
+        assert!(parse_instruction(&[0u8; 8]).is_err());
+
+        let mut data = CREATE_TRANSACTION.to_vec();
+        data.extend_from_slice(&[1, 0, 0, 0, 0xff]);
+        assert!(parse_instruction(&data).is_err());
+    }
+}
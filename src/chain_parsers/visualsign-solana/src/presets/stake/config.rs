@@ -0,0 +1,26 @@
+//! Configuration for Stake program integration
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::BTreeMap;
+
+pub struct StakeConfig;
+
+impl SolanaIntegrationConfig for StakeConfig {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| {
+            let mut programs = BTreeMap::new();
+            let mut stake_instructions = BTreeMap::new();
+            stake_instructions.insert("*", vec!["*"]);
+            programs.insert(
+                "Stake11111111111111111111111111111111111111",
+                stake_instructions,
+            );
+            SolanaIntegrationConfigData { programs }
+        })
+    }
+}
@@ -0,0 +1,621 @@
+//! Stake program preset for Solana
+//! Handles the native Stake Program (Stake11111111111111111111111111111111111111)
+
+mod config;
+
+use crate::core::{
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
+};
+use crate::utils::format_token_amount;
+use config::StakeConfig;
+use solana_stake_interface::instruction::{LockupArgs, StakeInstruction};
+use solana_stake_interface::state::{Lockup, StakeAuthorize};
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{
+    create_address_field, create_amount_field, create_number_field, create_text_field,
+};
+use visualsign::time_fmt::format_timestamp_secs;
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
+
+/// Lamports are 1e-9 SOL
+const SOL_DECIMALS: u8 = 9;
+
+// Create a static instance that we can reference
+static STAKE_CONFIG: StakeConfig = StakeConfig;
+
+pub struct StakeVisualizer;
+
+impl InstructionVisualizer for StakeVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        let stake_instruction =
+            bincode::deserialize::<StakeInstruction>(context.data()).map_err(|e| {
+                VisualSignError::DecodeError(format!("Failed to parse stake instruction: {e}"))
+            })?;
+
+        create_stake_preview_layout(&stake_instruction, context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&STAKE_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::StakingPools("Stake")
+    }
+}
+
+fn format_sol(lamports: u64) -> String {
+    format_token_amount(lamports, SOL_DECIMALS)
+}
+
+/// Renders the account at `position` in the instruction's account list
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+fn authority_type(stake_authorize: &StakeAuthorize) -> &'static str {
+    match stake_authorize {
+        StakeAuthorize::Staker => "Stake Authority",
+        StakeAuthorize::Withdrawer => "Withdraw Authority",
+    }
+}
+
+/// Summarises a lockup as set at initialization. The all-zero lockup is the
+/// common case and means the stake can be withdrawn as soon as it is inactive.
+fn format_lockup(lockup: &Lockup) -> String {
+    if lockup.unix_timestamp == 0 && lockup.epoch == 0 {
+        return "None".to_string();
+    }
+    let until = format_timestamp_secs(lockup.unix_timestamp)
+        .map(|time| format!("{time} (unix time {})", lockup.unix_timestamp))
+        .unwrap_or_else(|| format!("unix time {}", lockup.unix_timestamp));
+    format!(
+        "Until {until} or epoch {}, custodian {}",
+        lockup.epoch, lockup.custodian
+    )
+}
+
+/// Rows for the lockup fields a `SetLockup` changes; fields left as `None`
+/// keep their current value on chain
+fn lockup_change_fields(args: &LockupArgs) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let mut fields = Vec::new();
+    if let Some(unix_timestamp) = args.unix_timestamp {
+        fields.push(create_number_field(
+            "Lockup Unix Timestamp",
+            &unix_timestamp.to_string(),
+            "",
+        )?);
+    }
+    if let Some(epoch) = args.epoch {
+        fields.push(create_number_field("Lockup Epoch", &epoch.to_string(), "")?);
+    }
+    if let Some(custodian) = &args.custodian {
+        fields.push(create_text_field("New Custodian", &custodian.to_string())?);
+    }
+    Ok(fields)
+}
+
+/// Adds the optional lockup custodian signer at `position`, if present
+fn push_custodian(
+    fields: &mut Vec<AnnotatedPayloadField>,
+    context: &VisualizerContext,
+    position: usize,
+) -> Result<(), VisualSignError> {
+    if context.account(position).is_some() {
+        fields.push(create_text_field(
+            "Lockup Custodian",
+            &account_str(context, position),
+        )?);
+    }
+    Ok(())
+}
+
+fn create_stake_preview_layout(
+    instruction: &StakeInstruction,
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id_str = match context.program_id() {
+        ProgramRef::Resolved(pk) => pk.to_string(),
+        ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
+    };
+    let stake_account = account_str(context, 0);
+
+    let (title, condensed_fields, mut expanded_fields) = match instruction {
+        // Accounts: [0] stake account, [1] rent sysvar
+        StakeInstruction::Initialize(authorized, lockup) => {
+            let title = "Initialize Stake Account".to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Stake Account", &stake_account)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Stake Authority", &authorized.staker.to_string())?,
+                create_text_field("Withdraw Authority", &authorized.withdrawer.to_string())?,
+                create_text_field("Lockup", &format_lockup(lockup))?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] rent sysvar, [2] stake authority,
+        // [3] withdraw authority (signer)
+        StakeInstruction::InitializeChecked => {
+            let title = "Initialize Stake Account".to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Stake Account", &stake_account)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Stake Authority", &account_str(context, 2))?,
+                create_text_field("Withdraw Authority", &account_str(context, 3))?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] clock sysvar, [2] current authority,
+        // [3] optional lockup custodian
+        StakeInstruction::Authorize(new_authority, stake_authorize) => {
+            let authority_type = authority_type(stake_authorize);
+            let title = format!("Change {authority_type}");
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("New Authority", &new_authority.to_string())?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Authority Type", authority_type)?,
+                create_text_field("Current Authority", &account_str(context, 2))?,
+                create_text_field("New Authority", &new_authority.to_string())?,
+            ];
+            push_custodian(&mut expanded_fields, context, 3)?;
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] clock sysvar, [2] current authority,
+        // [3] new authority (signer), [4] optional lockup custodian
+        StakeInstruction::AuthorizeChecked(stake_authorize) => {
+            let authority_type = authority_type(stake_authorize);
+            let title = format!("Change {authority_type}");
+            let new_authority = account_str(context, 3);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Authority Type", authority_type)?,
+                create_text_field("Current Authority", &account_str(context, 2))?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            push_custodian(&mut expanded_fields, context, 4)?;
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] base of the derived current authority,
+        // [2] clock sysvar, [3] optional lockup custodian
+        StakeInstruction::AuthorizeWithSeed(args) => {
+            let authority_type = authority_type(&args.stake_authorize);
+            let title = format!("Change {authority_type}");
+            let new_authority = args.new_authorized_pubkey.to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Authority Type", authority_type)?,
+                create_text_field("Authority Base", &account_str(context, 1))?,
+                create_text_field("Authority Seed", &args.authority_seed)?,
+                create_text_field("Authority Owner", &args.authority_owner.to_string())?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            push_custodian(&mut expanded_fields, context, 3)?;
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] base of the derived current authority,
+        // [2] clock sysvar, [3] new authority (signer), [4] optional lockup custodian
+        StakeInstruction::AuthorizeCheckedWithSeed(args) => {
+            let authority_type = authority_type(&args.stake_authorize);
+            let title = format!("Change {authority_type}");
+            let new_authority = account_str(context, 3);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Authority Type", authority_type)?,
+                create_text_field("Authority Base", &account_str(context, 1))?,
+                create_text_field("Authority Seed", &args.authority_seed)?,
+                create_text_field("Authority Owner", &args.authority_owner.to_string())?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            push_custodian(&mut expanded_fields, context, 4)?;
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] vote account, [2] clock sysvar,
+        // [3] stake history sysvar, [4] stake config, [5] stake authority
+        StakeInstruction::DelegateStake => {
+            let vote_account = account_str(context, 1);
+            let title = "Delegate Stake".to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Validator Vote Account", &vote_account)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Validator Vote Account", &vote_account)?,
+                create_text_field("Stake Authority", &account_str(context, 5))?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] clock sysvar, [2] stake authority
+        StakeInstruction::Deactivate => {
+            let title = "Deactivate Stake".to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Stake Account", &stake_account)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Stake Authority", &account_str(context, 2))?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] recipient, [2] clock sysvar,
+        // [3] stake history sysvar, [4] withdraw authority, [5] optional lockup custodian
+        StakeInstruction::Withdraw(lamports) => {
+            let title = format!("Withdraw Stake: {} SOL", format_sol(*lamports));
+            let recipient = account_str(context, 1);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Recipient", &recipient)?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Recipient", &recipient)?,
+                create_text_field("Withdraw Authority", &account_str(context, 4))?,
+                create_amount_field("Amount", &format_sol(*lamports), "SOL")?,
+            ];
+            push_custodian(&mut expanded_fields, context, 5)?;
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] uninitialized split stake account,
+        // [2] stake authority
+        StakeInstruction::Split(lamports) => {
+            let title = format!("Split Stake: {} SOL", format_sol(*lamports));
+            let split_account = account_str(context, 1);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("New Stake Account", &split_account)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_address_field(
+                    "New Stake Account",
+                    &split_account,
+                    Some("New Stake Account"),
+                    None,
+                    None,
+                    Some("New"),
+                )?,
+                create_text_field("Stake Authority", &account_str(context, 2))?,
+                create_amount_field("Amount", &format_sol(*lamports), "SOL")?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] destination stake account, [1] source stake account,
+        // [2] clock sysvar, [3] stake history sysvar, [4] stake authority
+        StakeInstruction::Merge => {
+            let title = "Merge Stake".to_string();
+            let source = account_str(context, 1);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Source Stake Account", &source)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Destination Stake Account", &stake_account)?,
+                create_text_field("Source Stake Account", &source)?,
+                create_text_field("Stake Authority", &account_str(context, 4))?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] lockup authority or withdraw authority
+        StakeInstruction::SetLockup(args) => {
+            let title = "Set Stake Lockup".to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Stake Account", &stake_account)?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Lockup Authority", &account_str(context, 1))?,
+            ];
+            expanded_fields.extend(lockup_change_fields(args)?);
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] stake account, [1] lockup authority or withdraw authority,
+        // [2] optional new lockup custodian (signer)
+        StakeInstruction::SetLockupChecked(args) => {
+            let title = "Set Stake Lockup".to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Stake Account", &stake_account)?,
+            ];
+            let mut expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Lockup Authority", &account_str(context, 1))?,
+            ];
+            expanded_fields.extend(lockup_change_fields(&LockupArgs {
+                unix_timestamp: args.unix_timestamp,
+                epoch: args.epoch,
+                custodian: None,
+            })?);
+            if context.account(2).is_some() {
+                expanded_fields.push(create_text_field(
+                    "New Custodian",
+                    &account_str(context, 2),
+                )?);
+            }
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] delinquent stake account, [1] delinquent vote account,
+        // [2] reference vote account
+        StakeInstruction::DeactivateDelinquent => {
+            let title = "Deactivate Delinquent Stake".to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Stake Account", &stake_account)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Stake Account", &stake_account)?,
+                create_text_field("Delinquent Vote Account", &account_str(context, 1))?,
+                create_text_field("Reference Vote Account", &account_str(context, 2))?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] source stake account, [1] destination stake account,
+        // [2] stake authority
+        StakeInstruction::MoveStake(lamports) | StakeInstruction::MoveLamports(lamports) => {
+            let what = if matches!(instruction, StakeInstruction::MoveStake(_)) {
+                "Move Stake"
+            } else {
+                "Move Lamports"
+            };
+            let title = format!("{what}: {} SOL", format_sol(*lamports));
+            let destination = account_str(context, 1);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Destination Stake Account", &destination)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Source Stake Account", &stake_account)?,
+                create_text_field("Destination Stake Account", &destination)?,
+                create_text_field("Stake Authority", &account_str(context, 2))?,
+                create_amount_field("Amount", &format_sol(*lamports), "SOL")?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        _ => {
+            let title = format!("Stake: {instruction:?}");
+            let condensed_fields = vec![create_text_field("Instruction", &title)?];
+            let expanded_fields = vec![create_text_field(
+                "Instruction Data",
+                &format!("{instruction:?}"),
+            )?];
+            (title, condensed_fields, expanded_fields)
+        }
+    };
+
+    expanded_fields.insert(0, create_text_field("Program ID", &program_id_str)?);
+    expanded_fields.push(create_text_field("Raw Data", &hex::encode(context.data()))?);
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(context.data())
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
+    use solana_stake_interface::state::Authorized;
+
+    /// Visualizes `instruction` with the Stake Program at index 0 and
+    /// `accounts` after it, and returns the preview's title and expanded rows
+    fn visualize(
+        instruction: &StakeInstruction,
+        accounts: &[Pubkey],
+    ) -> (String, Vec<(String, String)>) {
+        let mut account_keys = vec![solana_stake_interface::program::ID];
+        account_keys.extend_from_slice(accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data: bincode::serialize(instruction).unwrap(),
+        };
+        let sender = SolanaAccount {
+            account_key: accounts[0].to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = StakeVisualizer.visualize_tx_commands(&context).unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect();
+        (preview_layout.title.unwrap().text, rows)
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    #[test]
+    fn test_format_lockup_renders_date_and_raw_timestamp() {
+        assert_eq!(format_lockup(&Lockup::default()), "None");
+
+        let custodian = Pubkey::new_unique();
+        let lockup = Lockup {
+            unix_timestamp: 1_700_000_000,
+            epoch: 600,
+            custodian,
+        };
+        assert_eq!(
+            format_lockup(&lockup),
+            format!(
+                "Until 2023-11-14 22:13:20 UTC (unix time 1700000000) or epoch 600, custodian {custodian}"
+            )
+        );
+    }
+
+    fn unique_keys(n: usize) -> Vec<Pubkey> {
+        (0..n).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_delegate_shows_vote_account() {
+        let keys = unique_keys(6);
+        let (title, rows) = visualize(&StakeInstruction::DelegateStake, &keys);
+
+        assert_eq!(title, "Delegate Stake");
+        assert_eq!(row(&rows, "Stake Account"), keys[0].to_string());
+        assert_eq!(row(&rows, "Validator Vote Account"), keys[1].to_string());
+        assert_eq!(row(&rows, "Stake Authority"), keys[5].to_string());
+    }
+
+    #[test]
+    fn test_deactivate() {
+        let keys = unique_keys(3);
+        let (title, rows) = visualize(&StakeInstruction::Deactivate, &keys);
+
+        assert_eq!(title, "Deactivate Stake");
+        assert_eq!(row(&rows, "Stake Authority"), keys[2].to_string());
+    }
+
+    #[test]
+    fn test_withdraw_in_sol() {
+        let keys = unique_keys(5);
+        let (title, rows) = visualize(&StakeInstruction::Withdraw(1_250_000_000), &keys);
+
+        assert_eq!(title, "Withdraw Stake: 1.25 SOL");
+        assert_eq!(row(&rows, "Recipient"), keys[1].to_string());
+        assert_eq!(row(&rows, "Withdraw Authority"), keys[4].to_string());
+        assert_eq!(row(&rows, "Amount"), "1.25 SOL");
+        assert!(rows.iter().all(|(label, _)| label != "Lockup Custodian"));
+    }
+
+    #[test]
+    fn test_split_labels_new_stake_account() {
+        let keys = unique_keys(3);
+        let (title, rows) = visualize(&StakeInstruction::Split(500_000_000), &keys);
+
+        assert_eq!(title, "Split Stake: 0.5 SOL");
+        assert_eq!(row(&rows, "New Stake Account"), keys[1].to_string());
+        assert_eq!(row(&rows, "Amount"), "0.5 SOL");
+    }
+
+    #[test]
+    fn test_authorize_withdrawer_with_custodian() {
+        let keys = unique_keys(4);
+        let new_authority = Pubkey::new_unique();
+        let (title, rows) = visualize(
+            &StakeInstruction::Authorize(new_authority, StakeAuthorize::Withdrawer),
+            &keys,
+        );
+
+        assert_eq!(title, "Change Withdraw Authority");
+        assert_eq!(row(&rows, "Current Authority"), keys[2].to_string());
+        assert_eq!(row(&rows, "New Authority"), new_authority.to_string());
+        assert_eq!(row(&rows, "Lockup Custodian"), keys[3].to_string());
+    }
+
+    #[test]
+    fn test_initialize_reports_authorities_and_lockup() {
+        let keys = unique_keys(2);
+        let staker = Pubkey::new_unique();
+        let withdrawer = Pubkey::new_unique();
+        let (title, rows) = visualize(
+            &StakeInstruction::Initialize(Authorized { staker, withdrawer }, Lockup::default()),
+            &keys,
+        );
+
+        assert_eq!(title, "Initialize Stake Account");
+        assert_eq!(row(&rows, "Stake Authority"), staker.to_string());
+        assert_eq!(row(&rows, "Withdraw Authority"), withdrawer.to_string());
+        assert_eq!(row(&rows, "Lockup"), "None");
+    }
+
+    #[test]
+    fn test_set_lockup_lists_only_changed_fields() {
+        let keys = unique_keys(2);
+        let (title, rows) = visualize(
+            &StakeInstruction::SetLockup(LockupArgs {
+                unix_timestamp: None,
+                epoch: Some(700),
+                custodian: None,
+            }),
+            &keys,
+        );
+
+        assert_eq!(title, "Set Stake Lockup");
+        assert_eq!(row(&rows, "Lockup Authority"), keys[1].to_string());
+        assert_eq!(row(&rows, "Lockup Epoch"), "700");
+        assert!(
+            rows.iter()
+                .all(|(label, _)| label != "Lockup Unix Timestamp" && label != "New Custodian")
+        );
+    }
+}
@@ -1,16 +1,24 @@
 //! Stakepool program preset for Solana
+//! Deposits and withdrawals show the pool, its token mint, the amounts moved
+//! and the stake accounts involved; account positions follow the
+//! `spl_stake_pool::instruction` builders.
 
 mod config;
 
 use crate::core::{
-    InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext, VisualizerKind,
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
 };
+use crate::utils::format_token_amount;
 use config::StakepoolConfig;
 use spl_stake_pool::instruction::StakePoolInstruction;
 use visualsign::errors::VisualSignError;
 use visualsign::field_builders::create_text_field;
 use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
 
+/// Lamports are 1e-9 SOL
+const SOL_DECIMALS: u8 = 9;
+
 // Create a static instance that we can reference
 static STAKEPOOL_CONFIG: StakepoolConfig = StakepoolConfig;
 
@@ -44,13 +52,24 @@ fn create_stakepool_preview_layout(
     };
     let instruction_name = format_stake_pool_instruction(instruction);
 
-    let condensed_fields = vec![create_text_field("Instruction", &instruction_name)?];
+    let mut condensed_fields = vec![create_text_field("Instruction", &instruction_name)?];
 
-    let expanded_fields = vec![create_text_field(
+    let mut expanded_fields = vec![create_text_field(
         "Stake Pool Instruction",
         &instruction_name,
     )?];
 
+    if let Some(rows) = deposit_withdraw_rows(instruction, context) {
+        // The row after the pool and its mint is the amount moved, or the
+        // stake account itself for a plain stake deposit
+        if let Some((label, value)) = rows.get(2) {
+            condensed_fields.push(create_text_field(label, value)?);
+        }
+        for (label, value) in &rows {
+            expanded_fields.push(create_text_field(label, value)?);
+        }
+    }
+
     let condensed = visualsign::SignablePayloadFieldListLayout {
         fields: condensed_fields,
     };
@@ -86,6 +105,123 @@ fn create_stakepool_preview_layout(
     })
 }
 
+/// Renders the account at `position` in the instruction's account list
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+fn sol_amount(lamports: u64) -> String {
+    format!("{} SOL", format_token_amount(lamports, SOL_DECIMALS))
+}
+
+/// Detail rows for deposits and withdrawals: the pool, its token mint, the
+/// amount moved, then any slippage bound and the accounts funds move between.
+/// `None` for every other instruction.
+fn deposit_withdraw_rows(
+    instruction: &StakePoolInstruction,
+    context: &VisualizerContext,
+) -> Option<Vec<(&'static str, String)>> {
+    let mint_position = match instruction {
+        StakePoolInstruction::DepositSol(_)
+        | StakePoolInstruction::DepositSolWithSlippage { .. }
+        | StakePoolInstruction::WithdrawSol(_)
+        | StakePoolInstruction::WithdrawSolWithSlippage { .. } => 7,
+        StakePoolInstruction::DepositStake
+        | StakePoolInstruction::DepositStakeWithSlippage { .. } => 10,
+        StakePoolInstruction::WithdrawStake(_)
+        | StakePoolInstruction::WithdrawStakeWithSlippage { .. } => 9,
+        _ => return None,
+    };
+    let mint = account_str(context, mint_position);
+    let pool_tokens = |amount: u64| {
+        let info = context.token_info(&mint, amount);
+        format!("{} {}", info.human_readable_amount, info.symbol)
+    };
+
+    let mut rows = vec![
+        ("Stake Pool", account_str(context, 0)),
+        ("Pool Token Mint", mint.clone()),
+    ];
+    match instruction {
+        StakePoolInstruction::DepositSol(lamports) => {
+            rows.push(("Deposit Amount", sol_amount(*lamports)));
+        }
+        StakePoolInstruction::DepositSolWithSlippage {
+            lamports_in,
+            minimum_pool_tokens_out,
+        } => {
+            rows.push(("Deposit Amount", sol_amount(*lamports_in)));
+            rows.push((
+                "Minimum Pool Tokens Out",
+                pool_tokens(*minimum_pool_tokens_out),
+            ));
+        }
+        StakePoolInstruction::WithdrawSol(pool_tokens_in) => {
+            rows.push(("Pool Tokens Burned", pool_tokens(*pool_tokens_in)));
+        }
+        StakePoolInstruction::WithdrawSolWithSlippage {
+            pool_tokens_in,
+            minimum_lamports_out,
+        } => {
+            rows.push(("Pool Tokens Burned", pool_tokens(*pool_tokens_in)));
+            rows.push(("Minimum SOL Out", sol_amount(*minimum_lamports_out)));
+        }
+        StakePoolInstruction::DepositStake => {}
+        StakePoolInstruction::DepositStakeWithSlippage {
+            minimum_pool_tokens_out,
+        } => {
+            rows.push((
+                "Minimum Pool Tokens Out",
+                pool_tokens(*minimum_pool_tokens_out),
+            ));
+        }
+        StakePoolInstruction::WithdrawStake(pool_tokens_in) => {
+            rows.push(("Pool Tokens Burned", pool_tokens(*pool_tokens_in)));
+        }
+        StakePoolInstruction::WithdrawStakeWithSlippage {
+            pool_tokens_in,
+            minimum_lamports_out,
+        } => {
+            rows.push(("Pool Tokens Burned", pool_tokens(*pool_tokens_in)));
+            rows.push(("Minimum SOL Out", sol_amount(*minimum_lamports_out)));
+        }
+        _ => {}
+    }
+
+    let accounts: &[(&'static str, usize)] = match instruction {
+        StakePoolInstruction::DepositSol(_)
+        | StakePoolInstruction::DepositSolWithSlippage { .. } => {
+            &[("Funding Account", 3), ("Pool Tokens To", 4)]
+        }
+        StakePoolInstruction::WithdrawSol(_)
+        | StakePoolInstruction::WithdrawSolWithSlippage { .. } => {
+            &[("Pool Tokens From", 3), ("SOL Destination", 5)]
+        }
+        StakePoolInstruction::DepositStake
+        | StakePoolInstruction::DepositStakeWithSlippage { .. } => &[
+            ("Deposited Stake Account", 4),
+            ("Validator Stake Account", 5),
+            ("Pool Tokens To", 7),
+        ],
+        _ => &[
+            ("Validator Stake Account", 3),
+            ("Destination Stake Account", 4),
+            ("New Stake Authority", 5),
+            ("Pool Tokens From", 7),
+        ],
+    };
+    rows.extend(
+        accounts
+            .iter()
+            .map(|(label, position)| (*label, account_str(context, *position))),
+    );
+    Some(rows)
+}
+
 fn parse_stake_pool_instruction(data: &[u8]) -> Result<StakePoolInstruction, VisualSignError> {
     use borsh::de::BorshDeserialize;
 
@@ -139,3 +275,149 @@ fn get_stake_pool_instruction_name(instruction: &StakePoolInstruction) -> &'stat
         StakePoolInstruction::Redelegate { .. } => "Redelegate",
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    const JITOSOL_MINT: &str = "J1toso1uCk3RLmjorhTtrVwY9HJ7X8V9yYac6Y7kGCPn";
+
+    /// Visualizes `instruction` with the stake pool program at index 0 and
+    /// `accounts` after it, and returns the condensed and expanded rows
+    fn visualize(
+        instruction: &StakePoolInstruction,
+        accounts: &[Pubkey],
+    ) -> (Vec<(String, String)>, Vec<(String, String)>) {
+        let mut account_keys =
+            vec![Pubkey::from_str("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy").unwrap()];
+        account_keys.extend_from_slice(accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data: borsh::to_vec(instruction).unwrap(),
+        };
+        let sender = SolanaAccount {
+            account_key: Pubkey::new_unique().to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = StakepoolVisualizer.visualize_tx_commands(&context).unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = |layout: Option<visualsign::SignablePayloadFieldListLayout>| {
+            layout
+                .unwrap()
+                .fields
+                .iter()
+                .map(|f| {
+                    (
+                        f.signable_payload_field.label().clone(),
+                        f.signable_payload_field.fallback_text().clone(),
+                    )
+                })
+                .collect()
+        };
+        (
+            rows(preview_layout.condensed),
+            rows(preview_layout.expanded),
+        )
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    /// Pool accounts with `mint` at `mint_position`
+    fn pool_accounts(mint_position: usize) -> Vec<Pubkey> {
+        let mut accounts: Vec<Pubkey> = (0..15).map(|_| Pubkey::new_unique()).collect();
+        accounts[mint_position] = Pubkey::from_str(JITOSOL_MINT).unwrap();
+        accounts
+    }
+
+    #[test]
+    fn test_deposit_sol_shows_amount_and_accounts() {
+        let accounts = pool_accounts(7);
+        let (condensed, expanded) =
+            visualize(&StakePoolInstruction::DepositSol(10_000_000_000), &accounts);
+
+        assert_eq!(row(&condensed, "Deposit Amount"), "10 SOL");
+        assert_eq!(row(&expanded, "Stake Pool"), accounts[0].to_string());
+        assert_eq!(row(&expanded, "Pool Token Mint"), JITOSOL_MINT);
+        assert_eq!(row(&expanded, "Funding Account"), accounts[3].to_string());
+        assert_eq!(row(&expanded, "Pool Tokens To"), accounts[4].to_string());
+    }
+
+    #[test]
+    fn test_withdraw_sol_with_slippage_shows_bound() {
+        let accounts = pool_accounts(7);
+        let (condensed, expanded) = visualize(
+            &StakePoolInstruction::WithdrawSolWithSlippage {
+                pool_tokens_in: 1_500_000_000,
+                minimum_lamports_out: 1_600_000_000,
+            },
+            &accounts,
+        );
+
+        // Pool mints missing from the token list stay in base units
+        assert_eq!(
+            row(&condensed, "Pool Tokens Burned"),
+            "1500000000 J1to...GCPn"
+        );
+        assert_eq!(row(&expanded, "Minimum SOL Out"), "1.6 SOL");
+        assert_eq!(row(&expanded, "SOL Destination"), accounts[5].to_string());
+    }
+
+    #[test]
+    fn test_stake_deposit_and_withdrawal_show_validator_stake_accounts() {
+        let accounts = pool_accounts(10);
+        let (condensed, expanded) = visualize(&StakePoolInstruction::DepositStake, &accounts);
+        assert_eq!(
+            row(&condensed, "Deposited Stake Account"),
+            accounts[4].to_string()
+        );
+        assert_eq!(
+            row(&expanded, "Validator Stake Account"),
+            accounts[5].to_string()
+        );
+
+        let accounts = pool_accounts(9);
+        let (_, expanded) = visualize(&StakePoolInstruction::WithdrawStake(42), &accounts);
+        assert_eq!(row(&expanded, "Pool Token Mint"), JITOSOL_MINT);
+        assert_eq!(
+            row(&expanded, "Validator Stake Account"),
+            accounts[3].to_string()
+        );
+        assert_eq!(
+            row(&expanded, "Destination Stake Account"),
+            accounts[4].to_string()
+        );
+        assert_eq!(
+            row(&expanded, "New Stake Authority"),
+            accounts[5].to_string()
+        );
+    }
+
+    #[test]
+    fn test_other_instructions_only_show_name() {
+        let (condensed, expanded) = visualize(
+            &StakePoolInstruction::UpdateStakePoolBalance,
+            &pool_accounts(7),
+        );
+        assert_eq!(condensed.len(), 1);
+        assert_eq!(expanded.len(), 1);
+    }
+}
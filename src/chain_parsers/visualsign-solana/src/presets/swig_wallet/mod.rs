@@ -2341,6 +2341,7 @@ mod tests {
                 tx_wrapper,
                 VisualSignOptions {
                     include_intermediate_output: false,
+                    strict_registry: false,
                     decode_transfers: true,
                     transaction_name: Some(description.to_string()),
                     metadata: None,
@@ -2414,8 +2415,8 @@ mod tests {
             .collect();
         assert_eq!(
             display_fields.len(),
-            3,
-            "Expected three display fields (network, instruction, accounts)"
+            6,
+            "Expected six display fields (network + 3 signer fields + instruction + accounts)"
         );
 
         // Network field
@@ -2428,7 +2429,7 @@ mod tests {
         }
 
         // Instruction field
-        let instruction_layout = match &payload.fields[1] {
+        let instruction_layout = match &payload.fields[4] {
             SignablePayloadField::PreviewLayout {
                 common,
                 preview_layout,
@@ -2502,7 +2503,7 @@ mod tests {
         assert_text_field(expanded_fields, "Actions (hex)", "0700000008000000");
 
         // Accounts field (diagnostics are appended after accounts)
-        match &payload.fields[2] {
+        match &payload.fields[5] {
             SignablePayloadField::PreviewLayout {
                 common,
                 preview_layout,
@@ -2552,12 +2553,12 @@ mod tests {
             .collect();
         assert_eq!(
             display_fields.len(),
-            5,
-            "Expected five display fields (network + 3 instructions + accounts)"
+            8,
+            "Expected eight display fields (network + 3 signer fields + 3 instructions + accounts)"
         );
 
         // Instruction 1 - Compute budget
-        let compute_layout = match &payload.fields[1] {
+        let compute_layout = match &payload.fields[4] {
             SignablePayloadField::PreviewLayout {
                 common,
                 preview_layout,
@@ -2592,7 +2593,7 @@ mod tests {
         assert_text_field(compute_expanded, "Raw Data", "0280969800");
 
         // Instruction 2 - secp256r1 verification
-        let secp_layout = match &payload.fields[2] {
+        let secp_layout = match &payload.fields[5] {
             SignablePayloadField::PreviewLayout {
                 common,
                 preview_layout,
@@ -2621,7 +2622,7 @@ mod tests {
         );
 
         // Instruction 3 - Swig sign v2
-        let swig_layout = match &payload.fields[3] {
+        let swig_layout = match &payload.fields[6] {
             SignablePayloadField::PreviewLayout {
                 common,
                 preview_layout,
@@ -2674,7 +2675,7 @@ mod tests {
         );
 
         // Accounts preview
-        let accounts_layout = match &payload.fields[4] {
+        let accounts_layout = match &payload.fields[7] {
             SignablePayloadField::PreviewLayout {
                 common,
                 preview_layout,
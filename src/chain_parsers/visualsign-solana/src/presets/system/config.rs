@@ -3,6 +3,25 @@
 use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
 use std::collections::BTreeMap;
 
+/// Jito's tip payment accounts. A transfer to one of these pays the block
+/// engine to land the surrounding bundle, so it is shown as an MEV tip
+/// rather than an ordinary payment. Jito publishes this list; keep it in sync
+/// when new tip accounts are announced.
+pub const JITO_TIP_ACCOUNTS: [&str; 8] = [
+    "96gYZGLnJYVFmbjzopPSU6QiEV5fGqZNyN9nmNhvrZU5",
+    "HFqU5x63VTqvQss8hp11i4wVV8bD44PvwucfZ2bU7gRe",
+    "Cw8CFyM9FkoMi7K7Crf6HNQqf4uEMzpKw6QNghXLvLkY",
+    "ADaUMid9yfUytqMBgopwjb2DTLSokTSzL1zt6iGPaS49",
+    "DfXygSm4jCyNCybVYYK6DwvWqjKee8pbDmJGcLWNDXjh",
+    "ADuUkR4vqLUMWXxW9gh6D6L8pMSawimctcNZ5pGwDcEt",
+    "DttWaMuVvTiduZRnguLF7jNxTgiMBZ1hyAumKUiL2KRL",
+    "3AVi9Tg9Uo68tJfuvoKvqKNWKkC5wPdSSdeBnizKZ6jT",
+];
+
+pub fn is_jito_tip_account(address: &str) -> bool {
+    JITO_TIP_ACCOUNTS.contains(&address)
+}
+
 pub struct SystemConfig;
 
 impl SolanaIntegrationConfig for SystemConfig {
@@ -6,13 +6,17 @@ use crate::core::{
     AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
     VisualizerKind,
 };
-use config::SystemConfig;
+use crate::utils::format_token_amount;
+use config::{SystemConfig, is_jito_tip_account};
 use solana_program::system_instruction::SystemInstruction;
 use visualsign::errors::VisualSignError;
-use visualsign::{
-    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAmountV2,
-    SignablePayloadFieldCommon,
+use visualsign::field_builders::{
+    create_address_field, create_amount_field, create_number_field, create_text_field,
 };
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
+
+/// Lamports are 1e-9 SOL
+const SOL_DECIMALS: u8 = 9;
 
 // Create a static instance that we can reference
 static SYSTEM_CONFIG: SystemConfig = SystemConfig;
@@ -41,149 +45,171 @@ impl InstructionVisualizer for SystemVisualizer {
     }
 }
 
+/// Formats a lamport amount in SOL, e.g. `1_500_000_000` as `"1.5"`
+fn format_sol(lamports: u64) -> String {
+    format_token_amount(lamports, SOL_DECIMALS)
+}
+
+/// Renders the account at `position` in the instruction's account list
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+fn sol_field(label: &str, lamports: u64) -> Result<AnnotatedPayloadField, VisualSignError> {
+    create_amount_field(label, &format_sol(lamports), "SOL")
+}
+
+/// Address row for an account the instruction brings into existence, badged so
+/// it stands apart from the existing accounts around it
+fn new_account_field(address: &str) -> Result<AnnotatedPayloadField, VisualSignError> {
+    create_address_field(
+        "New Account",
+        address,
+        Some("New Account"),
+        None,
+        None,
+        Some("New"),
+    )
+}
+
 fn create_system_preview_layout(
     instruction: &SystemInstruction,
     context: &VisualizerContext,
 ) -> Result<AnnotatedPayloadField, VisualSignError> {
-    use visualsign::field_builders::*;
-
     let program_id_str = match context.program_id() {
         ProgramRef::Resolved(pk) => pk.to_string(),
         ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
     };
 
-    match instruction {
+    let (title, condensed_fields, expanded_fields) = match instruction {
+        // Accounts: [0] funding account, [1] recipient
+        SystemInstruction::Transfer { lamports }
+            if is_jito_tip_account(&account_str(context, 1)) =>
+        {
+            let title = format!("MEV Tip: {} SOL", format_sol(*lamports));
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Bundle Context", "Jito bundle tip")?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Program ID", &program_id_str)?,
+                create_text_field("From", &account_str(context, 0))?,
+                create_text_field("Tip Account", &account_str(context, 1))?,
+                sol_field("MEV Tip", *lamports)?,
+                create_text_field(
+                    "Bundle Context",
+                    "Jito bundle tip, paid to the block engine to land this transaction's bundle",
+                )?,
+                create_text_field("Raw Data", &hex::encode(context.data()))?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
         SystemInstruction::Transfer { lamports } => {
-            let condensed_fields = vec![create_text_field(
-                "Instruction",
-                &format!("Transfer: {lamports} lamports"),
-            )?];
-
+            let title = format!("Transfer: {} SOL", format_sol(*lamports));
+            let condensed_fields = vec![create_text_field("Instruction", &title)?];
             let expanded_fields = vec![
                 create_text_field("Program ID", &program_id_str)?,
-                AnnotatedPayloadField {
-                    static_annotation: None,
-                    dynamic_annotation: None,
-                    signable_payload_field: SignablePayloadField::AmountV2 {
-                        common: SignablePayloadFieldCommon {
-                            fallback_text: format!("{} SOL", (*lamports as f64) / 1_000_000_000.0),
-                            label: "Transfer Amount".to_string(),
-                        },
-                        amount_v2: SignablePayloadFieldAmountV2 {
-                            amount: lamports.to_string(),
-                            abbreviation: Some("lamports".to_string()),
-                        },
-                    },
-                },
+                create_text_field("From", &account_str(context, 0))?,
+                create_text_field("To", &account_str(context, 1))?,
+                sol_field("Transfer Amount", *lamports)?,
                 create_text_field("Raw Data", &hex::encode(context.data()))?,
             ];
-
-            let condensed = visualsign::SignablePayloadFieldListLayout {
-                fields: condensed_fields,
-            };
-            let expanded = visualsign::SignablePayloadFieldListLayout {
-                fields: expanded_fields,
-            };
-
-            let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
-                title: Some(visualsign::SignablePayloadFieldTextV2 {
-                    text: format!("Transfer: {lamports} lamports"),
-                }),
-                subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
-                    text: String::new(),
-                }),
-                condensed: Some(condensed),
-                expanded: Some(expanded),
-            };
-
-            Ok(AnnotatedPayloadField {
-                static_annotation: None,
-                dynamic_annotation: None,
-                signable_payload_field: SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        label: format!("Instruction {}", context.instruction_index() + 1),
-                        fallback_text: format!(
-                            "Program ID: {}\nData: {}",
-                            program_id_str,
-                            hex::encode(context.data())
-                        ),
-                    },
-                    preview_layout,
-                },
-            })
+            (title, condensed_fields, expanded_fields)
         }
+        // Accounts: [0] funding account, [1] new account
         SystemInstruction::CreateAccount {
             lamports,
             space,
             owner,
         } => {
-            let new_account = match context.account(1) {
-                Some(AccountRef::Resolved(pk)) => pk.to_string(),
-                Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
-                None => "unknown".to_string(),
-            };
-            let payer = match context.account(0) {
-                Some(AccountRef::Resolved(pk)) => pk.to_string(),
-                Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
-                None => "unknown".to_string(),
-            };
-
+            let new_account = account_str(context, 1);
             let condensed_fields = vec![
                 create_text_field("Action", "Create Account")?,
+                create_text_field("New Account", &new_account)?,
                 create_text_field("Space", &format!("{space} bytes"))?,
-                create_text_field(
-                    "Rent",
-                    &format!("{} SOL", (*lamports as f64) / 1_000_000_000.0),
-                )?,
+                create_text_field("Rent", &format!("{} SOL", format_sol(*lamports)))?,
             ];
-
             let expanded_fields = vec![
                 create_text_field("Action", "Create Account")?,
+                new_account_field(&new_account)?,
+                create_text_field("Payer", &account_str(context, 0))?,
+                create_number_field("Space (bytes)", &space.to_string(), "")?,
+                sol_field("Rent", *lamports)?,
+                create_text_field("Owner Program", &owner.to_string())?,
+                create_text_field("Program", "System Program")?,
+            ];
+            (
+                "Create Account".to_string(),
+                condensed_fields,
+                expanded_fields,
+            )
+        }
+        // Accounts: [0] funding account, [1] new account derived from base and
+        // seed, [2] base account when it differs from the funding account
+        SystemInstruction::CreateAccountWithSeed {
+            base,
+            seed,
+            lamports,
+            space,
+            owner,
+        } => {
+            let new_account = account_str(context, 1);
+            let condensed_fields = vec![
+                create_text_field("Action", "Create Account With Seed")?,
                 create_text_field("New Account", &new_account)?,
-                create_text_field("Payer", &payer)?,
+                create_text_field("Space", &format!("{space} bytes"))?,
+                create_text_field("Rent", &format!("{} SOL", format_sol(*lamports)))?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Action", "Create Account With Seed")?,
+                new_account_field(&new_account)?,
+                create_text_field("Payer", &account_str(context, 0))?,
+                create_text_field("Base", &base.to_string())?,
+                create_text_field("Seed", seed)?,
                 create_number_field("Space (bytes)", &space.to_string(), "")?,
-                create_number_field("Rent (lamports)", &lamports.to_string(), "")?,
-                create_text_field(
-                    "Rent (SOL)",
-                    &format!("{}", (*lamports as f64) / 1_000_000_000.0),
-                )?,
+                sol_field("Rent", *lamports)?,
                 create_text_field("Owner Program", &owner.to_string())?,
                 create_text_field("Program", "System Program")?,
             ];
-
-            let condensed = visualsign::SignablePayloadFieldListLayout {
-                fields: condensed_fields,
-            };
-            let expanded = visualsign::SignablePayloadFieldListLayout {
-                fields: expanded_fields,
-            };
-
-            let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
-                title: Some(visualsign::SignablePayloadFieldTextV2 {
-                    text: "Create Account".to_string(),
-                }),
-                subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
-                    text: String::new(),
-                }),
-                condensed: Some(condensed),
-                expanded: Some(expanded),
-            };
-
-            Ok(AnnotatedPayloadField {
-                static_annotation: None,
-                dynamic_annotation: None,
-                signable_payload_field: SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        label: format!("Instruction {}", context.instruction_index() + 1),
-                        fallback_text: format!(
-                            "Program ID: {}\nData: {}",
-                            program_id_str,
-                            hex::encode(context.data())
-                        ),
-                    },
-                    preview_layout,
-                },
-            })
+            (
+                "Create Account With Seed".to_string(),
+                condensed_fields,
+                expanded_fields,
+            )
+        }
+        // Accounts: [0] account to allocate
+        SystemInstruction::Allocate { space } => {
+            let account = account_str(context, 0);
+            let condensed_fields = vec![
+                create_text_field("Action", "Allocate")?,
+                create_text_field("Space", &format!("{space} bytes"))?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Action", "Allocate")?,
+                create_text_field("Account", &account)?,
+                create_number_field("Space (bytes)", &space.to_string(), "")?,
+                create_text_field("Program", "System Program")?,
+            ];
+            ("Allocate".to_string(), condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] account to assign
+        SystemInstruction::Assign { owner } => {
+            let account = account_str(context, 0);
+            let condensed_fields = vec![
+                create_text_field("Action", "Assign")?,
+                create_text_field("New Owner Program", &owner.to_string())?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Action", "Assign")?,
+                create_text_field("Account", &account)?,
+                create_text_field("New Owner Program", &owner.to_string())?,
+                create_text_field("Program", "System Program")?,
+            ];
+            ("Assign".to_string(), condensed_fields, expanded_fields)
         }
         _ => {
             let instruction_name = account_labels::system_instruction_label(instruction);
@@ -198,40 +224,195 @@ fn create_system_preview_layout(
                 create_text_field("Program", "System Program")?,
                 create_text_field("Instruction Data", &format!("{instruction:?}"))?,
             ];
+            (instruction_name, condensed_fields, expanded_fields)
+        }
+    };
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(context.data())
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
 
-            let condensed = visualsign::SignablePayloadFieldListLayout {
-                fields: condensed_fields,
-            };
-            let expanded = visualsign::SignablePayloadFieldListLayout {
-                fields: expanded_fields,
-            };
-
-            let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
-                title: Some(visualsign::SignablePayloadFieldTextV2 {
-                    text: instruction_name.to_string(),
-                }),
-                subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
-                    text: String::new(),
-                }),
-                condensed: Some(condensed),
-                expanded: Some(expanded),
-            };
-
-            Ok(AnnotatedPayloadField {
-                static_annotation: None,
-                dynamic_annotation: None,
-                signable_payload_field: SignablePayloadField::PreviewLayout {
-                    common: SignablePayloadFieldCommon {
-                        label: format!("Instruction {}", context.instruction_index() + 1),
-                        fallback_text: format!(
-                            "Program ID: {}\nData: {}",
-                            program_id_str,
-                            hex::encode(context.data())
-                        ),
-                    },
-                    preview_layout,
-                },
+    /// Visualizes `instruction` with the System Program at index 0 and
+    /// `accounts` after it, and returns the preview's title and expanded rows
+    fn visualize(
+        instruction: &SystemInstruction,
+        accounts: &[Pubkey],
+    ) -> (String, Vec<(String, String)>) {
+        let mut account_keys = vec![solana_system_interface::program::ID];
+        account_keys.extend_from_slice(accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data: bincode::serialize(instruction).unwrap(),
+        };
+        let sender = SolanaAccount {
+            account_key: accounts[0].to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = SystemVisualizer.visualize_tx_commands(&context).unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
             })
-        }
+            .collect();
+        (preview_layout.title.unwrap().text, rows)
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    #[test]
+    fn test_format_sol() {
+        assert_eq!(format_sol(1_000_000_000), "1");
+        assert_eq!(format_sol(1_500_000_000), "1.5");
+        assert_eq!(format_sol(10_000), "0.00001");
+        assert_eq!(format_sol(0), "0");
+    }
+
+    #[test]
+    fn test_transfer_in_sol_with_accounts() {
+        let from = Pubkey::new_unique();
+        let to = Pubkey::new_unique();
+        let (title, rows) = visualize(
+            &SystemInstruction::Transfer {
+                lamports: 2_500_000_000,
+            },
+            &[from, to],
+        );
+
+        assert_eq!(title, "Transfer: 2.5 SOL");
+        assert_eq!(row(&rows, "From"), from.to_string());
+        assert_eq!(row(&rows, "To"), to.to_string());
+        assert_eq!(row(&rows, "Transfer Amount"), "2.5 SOL");
+    }
+
+    #[test]
+    fn test_transfer_to_jito_tip_account_is_mev_tip() {
+        let from = Pubkey::new_unique();
+        let tip_account = config::JITO_TIP_ACCOUNTS[3].parse::<Pubkey>().unwrap();
+        let (title, rows) = visualize(
+            &SystemInstruction::Transfer { lamports: 100_000 },
+            &[from, tip_account],
+        );
+
+        assert_eq!(title, "MEV Tip: 0.0001 SOL");
+        assert_eq!(row(&rows, "Tip Account"), tip_account.to_string());
+        assert_eq!(row(&rows, "MEV Tip"), "0.0001 SOL");
+        assert!(row(&rows, "Bundle Context").starts_with("Jito bundle tip"));
+        assert!(rows.iter().all(|(label, _)| label != "Transfer Amount"));
+    }
+
+    #[test]
+    fn test_create_account_labels_new_account() {
+        let payer = Pubkey::new_unique();
+        let new_account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (title, rows) = visualize(
+            &SystemInstruction::CreateAccount {
+                lamports: 2_039_280,
+                space: 165,
+                owner,
+            },
+            &[payer, new_account],
+        );
+
+        assert_eq!(title, "Create Account");
+        assert_eq!(row(&rows, "New Account"), new_account.to_string());
+        assert_eq!(row(&rows, "Payer"), payer.to_string());
+        assert_eq!(row(&rows, "Rent"), "0.00203928 SOL");
+        assert_eq!(row(&rows, "Owner Program"), owner.to_string());
+    }
+
+    #[test]
+    fn test_create_account_with_seed() {
+        let payer = Pubkey::new_unique();
+        let base = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let new_account = Pubkey::new_unique();
+        let (title, rows) = visualize(
+            &SystemInstruction::CreateAccountWithSeed {
+                base,
+                seed: "vault".to_string(),
+                lamports: 1_000_000,
+                space: 0,
+                owner,
+            },
+            &[payer, new_account, base],
+        );
+
+        assert_eq!(title, "Create Account With Seed");
+        assert_eq!(row(&rows, "New Account"), new_account.to_string());
+        assert_eq!(row(&rows, "Base"), base.to_string());
+        assert_eq!(row(&rows, "Seed"), "vault");
+        assert_eq!(row(&rows, "Rent"), "0.001 SOL");
+    }
+
+    #[test]
+    fn test_allocate_and_assign() {
+        let account = Pubkey::new_unique();
+        let (title, rows) = visualize(&SystemInstruction::Allocate { space: 200 }, &[account]);
+        assert_eq!(title, "Allocate");
+        assert_eq!(row(&rows, "Account"), account.to_string());
+        assert_eq!(row(&rows, "Space (bytes)"), "200");
+
+        let owner = Pubkey::new_unique();
+        let (title, rows) = visualize(&SystemInstruction::Assign { owner }, &[account]);
+        assert_eq!(title, "Assign");
+        assert_eq!(row(&rows, "Account"), account.to_string());
+        assert_eq!(row(&rows, "New Owner Program"), owner.to_string());
     }
 }
@@ -0,0 +1,670 @@
+//! Token 2022 extension instructions.
+//!
+//! Extension instructions share a prefix byte per extension followed by a
+//! sub-instruction byte; the token metadata interface instead uses 8-byte
+//! discriminators. `TokenInstruction::unpack` only reports which extension an
+//! instruction belongs to, so the payloads are decoded here by hand.
+
+use super::{MAX_TOKEN_DECIMALS, Token2022Instruction};
+use crate::utils::escape_untrusted_text;
+use borsh::BorshDeserialize;
+use solana_sdk::pubkey::Pubkey;
+
+const TRANSFER_FEE_EXTENSION: u8 = 26;
+const CONFIDENTIAL_TRANSFER_EXTENSION: u8 = 27;
+const INTEREST_BEARING_MINT_EXTENSION: u8 = 33;
+const CONFIDENTIAL_TRANSFER_FEE_EXTENSION: u8 = 37;
+const METADATA_POINTER_EXTENSION: u8 = 39;
+
+// sha256("spl_token_metadata_interface:<name>")[..8]
+const METADATA_INITIALIZE: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+const METADATA_UPDATE_FIELD: [u8; 8] = [221, 233, 49, 45, 181, 202, 220, 200];
+const METADATA_REMOVE_KEY: [u8; 8] = [234, 18, 32, 56, 89, 141, 37, 181];
+const METADATA_UPDATE_AUTHORITY: [u8; 8] = [215, 228, 166, 228, 84, 100, 86, 123];
+const METADATA_EMIT: [u8; 8] = [250, 166, 180, 250, 13, 12, 184, 70];
+
+const CONFIDENTIAL_TRANSFER_INSTRUCTIONS: [&str; 13] = [
+    "Initialize Mint",
+    "Update Mint",
+    "Configure Account",
+    "Approve Account",
+    "Empty Account",
+    "Deposit",
+    "Withdraw",
+    "Transfer",
+    "Apply Pending Balance",
+    "Enable Confidential Credits",
+    "Disable Confidential Credits",
+    "Enable Non-Confidential Credits",
+    "Disable Non-Confidential Credits",
+];
+
+const CONFIDENTIAL_TRANSFER_FEE_INSTRUCTIONS: [&str; 6] = [
+    "Initialize Confidential Transfer Fee Config",
+    "Withdraw Withheld Tokens From Mint",
+    "Withdraw Withheld Tokens From Accounts",
+    "Harvest Withheld Tokens To Mint",
+    "Enable Harvest To Mint",
+    "Disable Harvest To Mint",
+];
+
+#[derive(BorshDeserialize)]
+struct MetadataInitialize {
+    name: String,
+    symbol: String,
+    uri: String,
+}
+
+#[derive(BorshDeserialize)]
+enum MetadataField {
+    Name,
+    Symbol,
+    Uri,
+    Key(String),
+}
+
+#[derive(BorshDeserialize)]
+struct MetadataUpdateField {
+    field: MetadataField,
+    value: String,
+}
+
+#[derive(BorshDeserialize)]
+struct MetadataRemoveKey {
+    idempotent: bool,
+    key: String,
+}
+
+/// Little-endian reader over an extension payload
+struct Reader<'a> {
+    data: &'a [u8],
+    what: &'static str,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let (head, rest) = self
+            .data
+            .split_first_chunk::<N>()
+            .ok_or_else(|| format!("Invalid {}: insufficient data", self.what))?;
+        self.data = rest;
+        Ok(*head)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        self.take().map(u16::from_le_bytes)
+    }
+
+    fn i16(&mut self) -> Result<i16, String> {
+        self.take().map(i16::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        self.take().map(u64::from_le_bytes)
+    }
+
+    /// `OptionalNonZeroPubkey`: 32 bytes, all zero meaning none
+    fn optional_nonzero_pubkey(&mut self) -> Result<String, String> {
+        let key = Pubkey::new_from_array(self.take()?);
+        Ok(if key == Pubkey::default() {
+            "None".to_string()
+        } else {
+            key.to_string()
+        })
+    }
+
+    /// `COption<Pubkey>`: a tag byte, then the key when the tag is 1
+    fn coption_pubkey(&mut self) -> Result<String, String> {
+        match self.u8()? {
+            0 => Ok("None".to_string()),
+            1 => Ok(Pubkey::new_from_array(self.take()?).to_string()),
+            _ => Err(format!("Invalid {}: invalid option flag", self.what)),
+        }
+    }
+}
+
+/// Pairs the first `names.len()` accounts with their role names
+fn named_accounts(
+    accounts: &[String],
+    names: &[&'static str],
+    what: &str,
+) -> Result<Vec<(&'static str, String)>, String> {
+    if accounts.len() < names.len() {
+        return Err(format!("Invalid {what}: insufficient accounts"));
+    }
+    Ok(names
+        .iter()
+        .copied()
+        .zip(accounts.iter().cloned())
+        .collect())
+}
+
+fn extension(
+    name: &str,
+    params: Vec<(&'static str, String)>,
+    accounts: Vec<(&'static str, String)>,
+) -> Token2022Instruction {
+    Token2022Instruction::Extension {
+        name: name.to_string(),
+        params,
+        accounts,
+    }
+}
+
+/// Decodes transfer fee, interest bearing mint, metadata pointer, token
+/// metadata and confidential transfer instructions. Returns `None` for data
+/// that belongs to none of them.
+pub(super) fn parse_extension_instruction(
+    data: &[u8],
+    accounts: &[String],
+) -> Option<Result<Token2022Instruction, String>> {
+    if let Some(discriminator) = data.first_chunk::<8>() {
+        let args = &data[8..];
+        match *discriminator {
+            METADATA_INITIALIZE => return Some(parse_metadata_initialize(args, accounts)),
+            METADATA_UPDATE_FIELD => return Some(parse_metadata_update_field(args, accounts)),
+            METADATA_REMOVE_KEY => return Some(parse_metadata_remove_key(args, accounts)),
+            METADATA_UPDATE_AUTHORITY => {
+                return Some(parse_metadata_update_authority(args, accounts));
+            }
+            METADATA_EMIT => {
+                return Some(
+                    named_accounts(accounts, &["Metadata"], "emit")
+                        .map(|accounts| extension("Emit Token Metadata", Vec::new(), accounts)),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    let (&prefix, rest) = data.split_first()?;
+    let (&sub_instruction, payload) = match prefix {
+        TRANSFER_FEE_EXTENSION
+        | CONFIDENTIAL_TRANSFER_EXTENSION
+        | INTEREST_BEARING_MINT_EXTENSION
+        | CONFIDENTIAL_TRANSFER_FEE_EXTENSION
+        | METADATA_POINTER_EXTENSION => match rest.split_first() {
+            Some(split) => split,
+            None => return Some(Err("Invalid extension instruction: missing data".into())),
+        },
+        _ => return None,
+    };
+
+    Some(match prefix {
+        TRANSFER_FEE_EXTENSION => parse_transfer_fee(sub_instruction, payload, accounts),
+        INTEREST_BEARING_MINT_EXTENSION => {
+            parse_interest_bearing_mint(sub_instruction, payload, accounts)
+        }
+        METADATA_POINTER_EXTENSION => parse_metadata_pointer(sub_instruction, payload, accounts),
+        CONFIDENTIAL_TRANSFER_EXTENSION => Ok(confidential(
+            "Confidential Transfer",
+            &CONFIDENTIAL_TRANSFER_INSTRUCTIONS,
+            sub_instruction,
+            accounts,
+        )),
+        _ => Ok(confidential(
+            "Confidential Transfer Fee",
+            &CONFIDENTIAL_TRANSFER_FEE_INSTRUCTIONS,
+            sub_instruction,
+            accounts,
+        )),
+    })
+}
+
+fn parse_transfer_fee(
+    sub_instruction: u8,
+    payload: &[u8],
+    accounts: &[String],
+) -> Result<Token2022Instruction, String> {
+    match sub_instruction {
+        0 => {
+            let mut reader = Reader {
+                data: payload,
+                what: "initializeTransferFeeConfig",
+            };
+            let config_authority = reader.coption_pubkey()?;
+            let withdraw_authority = reader.coption_pubkey()?;
+            let fee_bps = reader.u16()?;
+            let maximum_fee = reader.u64()?;
+            Ok(extension(
+                "Initialize Transfer Fee Config",
+                vec![
+                    ("Transfer Fee", format!("{fee_bps} bps")),
+                    ("Maximum Fee", maximum_fee.to_string()),
+                    ("Transfer Fee Config Authority", config_authority),
+                    ("Withdraw Withheld Authority", withdraw_authority),
+                ],
+                named_accounts(accounts, &["Mint"], "initializeTransferFeeConfig")?,
+            ))
+        }
+        1 => {
+            let mut reader = Reader {
+                data: payload,
+                what: "transferCheckedWithFee",
+            };
+            let amount = reader.u64()?;
+            let decimals = reader.u8()?;
+            let fee = reader.u64()?;
+            if decimals > MAX_TOKEN_DECIMALS {
+                return Err(format!(
+                    "Invalid transferCheckedWithFee: decimals {decimals} exceeds maximum supported value {MAX_TOKEN_DECIMALS}"
+                ));
+            }
+            if accounts.len() < 4 {
+                return Err("Invalid transferCheckedWithFee: insufficient accounts".to_string());
+            }
+            Ok(Token2022Instruction::TransferCheckedWithFee {
+                amount,
+                decimals,
+                fee,
+                source: accounts[0].clone(),
+                mint: accounts[1].clone(),
+                destination: accounts[2].clone(),
+                authority: accounts[3].clone(),
+            })
+        }
+        2 => Ok(extension(
+            "Withdraw Withheld Tokens From Mint",
+            Vec::new(),
+            named_accounts(
+                accounts,
+                &["Mint", "Destination", "Withdraw Authority"],
+                "withdrawWithheldTokensFromMint",
+            )?,
+        )),
+        3 => {
+            let mut reader = Reader {
+                data: payload,
+                what: "withdrawWithheldTokensFromAccounts",
+            };
+            let num_token_accounts = reader.u8()?;
+            Ok(extension(
+                "Withdraw Withheld Tokens From Accounts",
+                vec![("Source Accounts", num_token_accounts.to_string())],
+                named_accounts(
+                    accounts,
+                    &["Mint", "Destination", "Withdraw Authority"],
+                    "withdrawWithheldTokensFromAccounts",
+                )?,
+            ))
+        }
+        4 => Ok(extension(
+            "Harvest Withheld Tokens To Mint",
+            vec![(
+                "Source Accounts",
+                accounts.len().saturating_sub(1).to_string(),
+            )],
+            named_accounts(accounts, &["Mint"], "harvestWithheldTokensToMint")?,
+        )),
+        5 => {
+            let mut reader = Reader {
+                data: payload,
+                what: "setTransferFee",
+            };
+            let fee_bps = reader.u16()?;
+            let maximum_fee = reader.u64()?;
+            Ok(extension(
+                "Set Transfer Fee",
+                vec![
+                    ("Transfer Fee", format!("{fee_bps} bps")),
+                    ("Maximum Fee", maximum_fee.to_string()),
+                ],
+                named_accounts(
+                    accounts,
+                    &["Mint", "Transfer Fee Config Authority"],
+                    "setTransferFee",
+                )?,
+            ))
+        }
+        other => Err(format!(
+            "Unsupported Token 2022 instruction: unknown transfer fee instruction {other}"
+        )),
+    }
+}
+
+fn parse_interest_bearing_mint(
+    sub_instruction: u8,
+    payload: &[u8],
+    accounts: &[String],
+) -> Result<Token2022Instruction, String> {
+    match sub_instruction {
+        0 => {
+            let mut reader = Reader {
+                data: payload,
+                what: "initializeInterestBearingMint",
+            };
+            let rate_authority = reader.optional_nonzero_pubkey()?;
+            let rate = reader.i16()?;
+            Ok(extension(
+                "Initialize Interest Bearing Mint",
+                vec![
+                    ("Interest Rate", format!("{rate} bps")),
+                    ("Rate Authority", rate_authority),
+                ],
+                named_accounts(accounts, &["Mint"], "initializeInterestBearingMint")?,
+            ))
+        }
+        1 => {
+            let mut reader = Reader {
+                data: payload,
+                what: "updateInterestRate",
+            };
+            let rate = reader.i16()?;
+            Ok(extension(
+                "Update Interest Rate",
+                vec![("Interest Rate", format!("{rate} bps"))],
+                named_accounts(accounts, &["Mint", "Rate Authority"], "updateInterestRate")?,
+            ))
+        }
+        other => Err(format!(
+            "Unsupported Token 2022 instruction: unknown interest bearing mint instruction {other}"
+        )),
+    }
+}
+
+fn parse_metadata_pointer(
+    sub_instruction: u8,
+    payload: &[u8],
+    accounts: &[String],
+) -> Result<Token2022Instruction, String> {
+    match sub_instruction {
+        0 => {
+            let mut reader = Reader {
+                data: payload,
+                what: "initializeMetadataPointer",
+            };
+            let authority = reader.optional_nonzero_pubkey()?;
+            let metadata_address = reader.optional_nonzero_pubkey()?;
+            Ok(extension(
+                "Initialize Metadata Pointer",
+                vec![
+                    ("Metadata Address", metadata_address),
+                    ("Metadata Pointer Authority", authority),
+                ],
+                named_accounts(accounts, &["Mint"], "initializeMetadataPointer")?,
+            ))
+        }
+        1 => {
+            let mut reader = Reader {
+                data: payload,
+                what: "updateMetadataPointer",
+            };
+            let metadata_address = reader.optional_nonzero_pubkey()?;
+            Ok(extension(
+                "Update Metadata Pointer",
+                vec![("Metadata Address", metadata_address)],
+                named_accounts(
+                    accounts,
+                    &["Mint", "Metadata Pointer Authority"],
+                    "updateMetadataPointer",
+                )?,
+            ))
+        }
+        other => Err(format!(
+            "Unsupported Token 2022 instruction: unknown metadata pointer instruction {other}"
+        )),
+    }
+}
+
+/// Confidential transfer payloads are ciphertexts and proof references, so
+/// these are identified by name with the account they act on
+fn confidential(
+    family: &str,
+    names: &[&str],
+    sub_instruction: u8,
+    accounts: &[String],
+) -> Token2022Instruction {
+    let name = match names.get(usize::from(sub_instruction)) {
+        Some(action) => format!("{family}: {action}"),
+        None => format!("{family}: Instruction {sub_instruction}"),
+    };
+    let accounts = accounts
+        .first()
+        .map(|account| vec![("Account", account.clone())])
+        .unwrap_or_default();
+    extension(&name, Vec::new(), accounts)
+}
+
+fn parse_metadata_initialize(
+    args: &[u8],
+    accounts: &[String],
+) -> Result<Token2022Instruction, String> {
+    let metadata = MetadataInitialize::try_from_slice(args)
+        .map_err(|e| format!("Invalid initializeTokenMetadata: {e}"))?;
+    Ok(extension(
+        "Initialize Token Metadata",
+        vec![
+            ("Name", escape_untrusted_text(&metadata.name)),
+            ("Symbol", escape_untrusted_text(&metadata.symbol)),
+            ("URI", escape_untrusted_text(&metadata.uri)),
+        ],
+        named_accounts(
+            accounts,
+            &["Metadata", "Update Authority", "Mint", "Mint Authority"],
+            "initializeTokenMetadata",
+        )?,
+    ))
+}
+
+fn parse_metadata_update_field(
+    args: &[u8],
+    accounts: &[String],
+) -> Result<Token2022Instruction, String> {
+    let update = MetadataUpdateField::try_from_slice(args)
+        .map_err(|e| format!("Invalid updateTokenMetadataField: {e}"))?;
+    let field = match update.field {
+        MetadataField::Name => "Name".to_string(),
+        MetadataField::Symbol => "Symbol".to_string(),
+        MetadataField::Uri => "URI".to_string(),
+        MetadataField::Key(key) => escape_untrusted_text(&key),
+    };
+    Ok(extension(
+        "Update Token Metadata Field",
+        vec![
+            ("Field", field),
+            ("Value", escape_untrusted_text(&update.value)),
+        ],
+        named_accounts(
+            accounts,
+            &["Metadata", "Update Authority"],
+            "updateTokenMetadataField",
+        )?,
+    ))
+}
+
+fn parse_metadata_remove_key(
+    args: &[u8],
+    accounts: &[String],
+) -> Result<Token2022Instruction, String> {
+    let remove = MetadataRemoveKey::try_from_slice(args)
+        .map_err(|e| format!("Invalid removeTokenMetadataKey: {e}"))?;
+    Ok(extension(
+        "Remove Token Metadata Key",
+        vec![
+            ("Key", escape_untrusted_text(&remove.key)),
+            ("Idempotent", remove.idempotent.to_string()),
+        ],
+        named_accounts(
+            accounts,
+            &["Metadata", "Update Authority"],
+            "removeTokenMetadataKey",
+        )?,
+    ))
+}
+
+fn parse_metadata_update_authority(
+    args: &[u8],
+    accounts: &[String],
+) -> Result<Token2022Instruction, String> {
+    let mut reader = Reader {
+        data: args,
+        what: "updateTokenMetadataAuthority",
+    };
+    let new_authority = reader.optional_nonzero_pubkey()?;
+    Ok(extension(
+        "Update Token Metadata Authority",
+        vec![("New Update Authority", new_authority)],
+        named_accounts(
+            accounts,
+            &["Metadata", "Current Update Authority"],
+            "updateTokenMetadataAuthority",
+        )?,
+    ))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn accounts(n: usize) -> Vec<String> {
+        (0..n).map(|_| Pubkey::new_unique().to_string()).collect()
+    }
+
+    fn borsh_string(s: &str) -> Vec<u8> {
+        let mut out = (s.len() as u32).to_le_bytes().to_vec();
+        out.extend_from_slice(s.as_bytes());
+        out
+    }
+
+    fn parse(data: &[u8], accounts: &[String]) -> Token2022Instruction {
+        parse_extension_instruction(data, accounts)
+            .expect("extension instruction")
+            .unwrap()
+    }
+
+    fn params_of(instruction: &Token2022Instruction) -> (&str, &[(&'static str, String)]) {
+        match instruction {
+            Token2022Instruction::Extension { name, params, .. } => {
+                (name.as_str(), params.as_slice())
+            }
+            _ => panic!("expected Extension"),
+        }
+    }
+
+    #[test]
+    fn test_metadata_discriminators_match_interface_hash() {
+        for (name, discriminator) in [
+            ("initialize_account", METADATA_INITIALIZE),
+            ("updating_field", METADATA_UPDATE_FIELD),
+            ("remove_key_ix", METADATA_REMOVE_KEY),
+            ("update_the_authority", METADATA_UPDATE_AUTHORITY),
+            ("emitter", METADATA_EMIT),
+        ] {
+            let hash =
+                solana_sdk::hash::hash(format!("spl_token_metadata_interface:{name}").as_bytes());
+            assert_eq!(hash.to_bytes()[..8], discriminator, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_transfer_checked_with_fee() {
+        let accounts = accounts(4);
+        let mut data = vec![TRANSFER_FEE_EXTENSION, 1];
+        data.extend_from_slice(&1_500_000u64.to_le_bytes());
+        data.push(6);
+        data.extend_from_slice(&1_500u64.to_le_bytes());
+
+        let Token2022Instruction::TransferCheckedWithFee {
+            amount,
+            decimals,
+            fee,
+            destination,
+            ..
+        } = parse(&data, &accounts)
+        else {
+            panic!("expected TransferCheckedWithFee");
+        };
+        assert_eq!((amount, decimals, fee), (1_500_000, 6, 1_500));
+        assert_eq!(destination, accounts[2]);
+    }
+
+    #[test]
+    fn test_set_transfer_fee() {
+        let mut data = vec![TRANSFER_FEE_EXTENSION, 5];
+        data.extend_from_slice(&250u16.to_le_bytes());
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+
+        let instruction = parse(&data, &accounts(2));
+        let (name, params) = params_of(&instruction);
+        assert_eq!(name, "Set Transfer Fee");
+        assert_eq!(params[0], ("Transfer Fee", "250 bps".to_string()));
+        assert_eq!(params[1], ("Maximum Fee", "5000".to_string()));
+    }
+
+    #[test]
+    fn test_update_interest_rate_accepts_negative_rate() {
+        let mut data = vec![INTEREST_BEARING_MINT_EXTENSION, 1];
+        data.extend_from_slice(&(-125i16).to_le_bytes());
+
+        let instruction = parse(&data, &accounts(2));
+        let (name, params) = params_of(&instruction);
+        assert_eq!(name, "Update Interest Rate");
+        assert_eq!(params[0], ("Interest Rate", "-125 bps".to_string()));
+    }
+
+    #[test]
+    fn test_initialize_metadata_pointer() {
+        let metadata = Pubkey::new_unique();
+        let mut data = vec![METADATA_POINTER_EXTENSION, 0];
+        data.extend_from_slice(&[0; 32]);
+        data.extend_from_slice(metadata.as_ref());
+
+        let instruction = parse(&data, &accounts(1));
+        let (_, params) = params_of(&instruction);
+        assert_eq!(params[0], ("Metadata Address", metadata.to_string()));
+        assert_eq!(
+            params[1],
+            ("Metadata Pointer Authority", "None".to_string())
+        );
+    }
+
+    #[test]
+    fn test_token_metadata_initialize_escapes_strings() {
+        let mut data = METADATA_INITIALIZE.to_vec();
+        data.extend(borsh_string("My\nToken"));
+        data.extend(borsh_string("MYT"));
+        data.extend(borsh_string("https://example.com/m.json"));
+
+        let instruction = parse(&data, &accounts(4));
+        let (name, params) = params_of(&instruction);
+        assert_eq!(name, "Initialize Token Metadata");
+        assert_eq!(params[0], ("Name", "My<U+000A>Token".to_string()));
+        assert_eq!(params[1], ("Symbol", "MYT".to_string()));
+    }
+
+    #[test]
+    fn test_token_metadata_update_custom_field() {
+        let mut data = METADATA_UPDATE_FIELD.to_vec();
+        data.push(3);
+        data.extend(borsh_string("website"));
+        data.extend(borsh_string("https://example.com"));
+
+        let instruction = parse(&data, &accounts(2));
+        let (_, params) = params_of(&instruction);
+        assert_eq!(params[0], ("Field", "website".to_string()));
+        assert_eq!(params[1], ("Value", "https://example.com".to_string()));
+    }
+
+    #[test]
+    fn test_confidential_transfer_identified_by_name() {
+        let instruction = parse(&[CONFIDENTIAL_TRANSFER_EXTENSION, 7, 0xaa], &accounts(3));
+        assert_eq!(params_of(&instruction).0, "Confidential Transfer: Transfer");
+
+        let instruction = parse(&[CONFIDENTIAL_TRANSFER_EXTENSION, 42], &accounts(1));
+        assert_eq!(
+            params_of(&instruction).0,
+            "Confidential Transfer: Instruction 42"
+        );
+    }
+
+    #[test]
+    fn test_other_prefixes_are_not_extensions() {
+        assert!(parse_extension_instruction(&[12, 0, 0], &accounts(4)).is_none());
+        assert!(parse_extension_instruction(&[], &accounts(1)).is_none());
+    }
+}
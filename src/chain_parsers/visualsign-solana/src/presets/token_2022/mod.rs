@@ -1,6 +1,7 @@
 //! Token 2022 preset implementation for Solana
 
 mod config;
+mod extensions;
 
 use crate::core::{
     AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
@@ -130,6 +131,48 @@ enum Token2022Instruction {
         current_authority: String,
         new_authority: Option<String>,
     },
+    TransferChecked {
+        amount: u64,
+        decimals: u8,
+        source: String,
+        mint: String,
+        destination: String,
+        authority: String,
+    },
+    ApproveChecked {
+        amount: u64,
+        decimals: u8,
+        source: String,
+        mint: String,
+        delegate: String,
+        owner: String,
+    },
+    Revoke {
+        source: String,
+        owner: String,
+    },
+    InitializeMint2 {
+        decimals: u8,
+        mint: String,
+        mint_authority: String,
+        freeze_authority: Option<String>,
+    },
+    TransferCheckedWithFee {
+        amount: u64,
+        decimals: u8,
+        fee: u64,
+        source: String,
+        mint: String,
+        destination: String,
+        authority: String,
+    },
+    /// Extension instruction shown by name, with its decoded parameters and
+    /// named accounts in display order
+    Extension {
+        name: String,
+        params: Vec<(&'static str, String)>,
+        accounts: Vec<(&'static str, String)>,
+    },
 }
 
 fn parse_token_2022_instruction(
@@ -206,6 +249,12 @@ fn parse_token_2022_instruction(
         });
     }
 
+    // Transfer fee, interest bearing, metadata and confidential transfer
+    // extensions carry payloads `TokenInstruction::unpack` does not decode
+    if let Some(result) = extensions::parse_extension_instruction(data, accounts) {
+        return result;
+    }
+
     // Try to parse as standard TokenInstruction first
     if let Ok(sdk_instruction) = TokenInstruction::unpack(data) {
         match sdk_instruction {
@@ -245,6 +294,76 @@ fn parse_token_2022_instruction(
                     authority: accounts[2].clone(),
                 });
             }
+            TokenInstruction::TransferChecked { amount, decimals } => {
+                if accounts.len() < 4 {
+                    return Err("Invalid transferChecked: insufficient accounts".to_string());
+                }
+                if decimals > MAX_TOKEN_DECIMALS {
+                    return Err(format!(
+                        "Invalid transferChecked: decimals {decimals} exceeds maximum supported value {MAX_TOKEN_DECIMALS}"
+                    ));
+                }
+
+                return Ok(Token2022Instruction::TransferChecked {
+                    amount,
+                    decimals,
+                    source: accounts[0].clone(),
+                    mint: accounts[1].clone(),
+                    destination: accounts[2].clone(),
+                    authority: accounts[3].clone(),
+                });
+            }
+            TokenInstruction::ApproveChecked { amount, decimals } => {
+                if accounts.len() < 4 {
+                    return Err("Invalid approveChecked: insufficient accounts".to_string());
+                }
+                if decimals > MAX_TOKEN_DECIMALS {
+                    return Err(format!(
+                        "Invalid approveChecked: decimals {decimals} exceeds maximum supported value {MAX_TOKEN_DECIMALS}"
+                    ));
+                }
+
+                return Ok(Token2022Instruction::ApproveChecked {
+                    amount,
+                    decimals,
+                    source: accounts[0].clone(),
+                    mint: accounts[1].clone(),
+                    delegate: accounts[2].clone(),
+                    owner: accounts[3].clone(),
+                });
+            }
+            TokenInstruction::Revoke => {
+                if accounts.len() < 2 {
+                    return Err("Invalid revoke: insufficient accounts".to_string());
+                }
+
+                return Ok(Token2022Instruction::Revoke {
+                    source: accounts[0].clone(),
+                    owner: accounts[1].clone(),
+                });
+            }
+            TokenInstruction::InitializeMint2 {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => {
+                if accounts.is_empty() {
+                    return Err("Invalid initializeMint2: insufficient accounts".to_string());
+                }
+                if decimals > MAX_TOKEN_DECIMALS {
+                    return Err(format!(
+                        "Invalid initializeMint2: decimals {decimals} exceeds maximum supported value {MAX_TOKEN_DECIMALS}"
+                    ));
+                }
+
+                return Ok(Token2022Instruction::InitializeMint2 {
+                    decimals,
+                    mint: accounts[0].clone(),
+                    mint_authority: mint_authority.to_string(),
+                    // `COption`, not `Option`
+                    freeze_authority: freeze_authority.map_or(None, |pk| Some(pk.to_string())),
+                });
+            }
             TokenInstruction::FreezeAccount => {
                 if accounts.len() < 3 {
                     return Err("Invalid freezeAccount: insufficient accounts".to_string());
@@ -535,6 +654,171 @@ fn create_token_2022_preview_layout(
                 create_raw_data_field(context.data(), None)?,
             ];
 
+            (title, condensed, expanded)
+        }
+        Token2022Instruction::TransferChecked {
+            amount,
+            decimals,
+            source,
+            mint,
+            destination,
+            authority,
+        } => {
+            let formatted_amount = format_token_amount(*amount, *decimals);
+            let title = format!("Transfer Checked: {formatted_amount} tokens");
+
+            let condensed = vec![
+                create_text_field("Action", "Transfer Checked")?,
+                create_text_field("Amount", &formatted_amount)?,
+            ];
+
+            let expanded = vec![
+                create_text_field("Instruction", "Transfer Checked")?,
+                create_text_field("Amount", &formatted_amount)?,
+                create_number_field("Raw Amount", &amount.to_string(), "")?,
+                create_number_field("Decimals", &decimals.to_string(), "")?,
+                create_text_field("Source Account", source)?,
+                create_text_field("Mint", mint)?,
+                create_text_field("Destination Account", destination)?,
+                create_text_field("Authority", authority)?,
+                create_text_field("Program ID", &resolve_program_id(context))?,
+                create_raw_data_field(context.data(), None)?,
+            ];
+
+            (title, condensed, expanded)
+        }
+        Token2022Instruction::ApproveChecked {
+            amount,
+            decimals,
+            source,
+            mint,
+            delegate,
+            owner,
+        } => {
+            let formatted_amount = format_token_amount(*amount, *decimals);
+            let title = format!("Approve Checked: {formatted_amount} tokens");
+
+            let condensed = vec![
+                create_text_field("Action", "Approve Checked")?,
+                create_text_field("Amount", &formatted_amount)?,
+                create_text_field("Delegate", delegate)?,
+            ];
+
+            let expanded = vec![
+                create_text_field("Instruction", "Approve Checked")?,
+                create_text_field("Amount", &formatted_amount)?,
+                create_number_field("Raw Amount", &amount.to_string(), "")?,
+                create_number_field("Decimals", &decimals.to_string(), "")?,
+                create_text_field("Token Account", source)?,
+                create_text_field("Mint", mint)?,
+                create_text_field("Delegate", delegate)?,
+                create_text_field("Owner", owner)?,
+                create_text_field("Program ID", &resolve_program_id(context))?,
+                create_raw_data_field(context.data(), None)?,
+            ];
+
+            (title, condensed, expanded)
+        }
+        Token2022Instruction::Revoke { source, owner } => {
+            let title = "Revoke Delegate".to_string();
+
+            let condensed = vec![create_text_field("Action", "Revoke Delegate")?];
+
+            let expanded = vec![
+                create_text_field("Instruction", "Revoke")?,
+                create_text_field("Token Account", source)?,
+                create_text_field("Owner", owner)?,
+                create_text_field("Program ID", &resolve_program_id(context))?,
+                create_raw_data_field(context.data(), None)?,
+            ];
+
+            (title, condensed, expanded)
+        }
+        Token2022Instruction::InitializeMint2 {
+            decimals,
+            mint,
+            mint_authority,
+            freeze_authority,
+        } => {
+            let title = "Initialize Mint".to_string();
+            let freeze_authority_display = freeze_authority
+                .clone()
+                .unwrap_or_else(|| "None".to_string());
+
+            let condensed = vec![
+                create_text_field("Action", "Initialize Mint")?,
+                create_text_field("Mint", mint)?,
+            ];
+
+            let expanded = vec![
+                create_text_field("Instruction", "Initialize Mint 2")?,
+                create_text_field("Mint", mint)?,
+                create_number_field("Decimals", &decimals.to_string(), "")?,
+                create_text_field("Mint Authority", mint_authority)?,
+                create_text_field("Freeze Authority", &freeze_authority_display)?,
+                create_text_field("Program ID", &resolve_program_id(context))?,
+                create_raw_data_field(context.data(), None)?,
+            ];
+
+            (title, condensed, expanded)
+        }
+        Token2022Instruction::TransferCheckedWithFee {
+            amount,
+            decimals,
+            fee,
+            source,
+            mint,
+            destination,
+            authority,
+        } => {
+            let formatted_amount = format_token_amount(*amount, *decimals);
+            let formatted_fee = format_token_amount(*fee, *decimals);
+            let title = format!("Transfer Checked With Fee: {formatted_amount} tokens");
+
+            let condensed = vec![
+                create_text_field("Action", "Transfer Checked With Fee")?,
+                create_text_field("Amount", &formatted_amount)?,
+                create_text_field("Fee", &formatted_fee)?,
+            ];
+
+            let expanded = vec![
+                create_text_field("Instruction", "Transfer Checked With Fee")?,
+                create_text_field("Amount", &formatted_amount)?,
+                create_text_field("Fee", &formatted_fee)?,
+                create_number_field("Raw Amount", &amount.to_string(), "")?,
+                create_number_field("Decimals", &decimals.to_string(), "")?,
+                create_text_field("Source Account", source)?,
+                create_text_field("Mint", mint)?,
+                create_text_field("Destination Account", destination)?,
+                create_text_field("Authority", authority)?,
+                create_text_field("Program ID", &resolve_program_id(context))?,
+                create_raw_data_field(context.data(), None)?,
+            ];
+
+            (title, condensed, expanded)
+        }
+        Token2022Instruction::Extension {
+            name,
+            params,
+            accounts,
+        } => {
+            let title = name.clone();
+
+            let mut condensed = vec![create_text_field("Action", name)?];
+            for (label, value) in params {
+                condensed.push(create_text_field(label, value)?);
+            }
+
+            let mut expanded = vec![create_text_field("Instruction", name)?];
+            for (label, value) in params.iter().chain(accounts) {
+                expanded.push(create_text_field(label, value)?);
+            }
+            expanded.push(create_text_field(
+                "Program ID",
+                &resolve_program_id(context),
+            )?);
+            expanded.push(create_raw_data_field(context.data(), None)?);
+
             (title, condensed, expanded)
         }
     };
@@ -642,6 +926,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_approve_checked_and_revoke() {
+        let accounts = dummy_account_strings(4);
+        let mut data = vec![13u8];
+        data.extend_from_slice(&2_500_000u64.to_le_bytes());
+        data.push(6);
+
+        match parse_token_2022_instruction(&data, &accounts).unwrap() {
+            Token2022Instruction::ApproveChecked {
+                amount,
+                decimals,
+                delegate,
+                ..
+            } => {
+                assert_eq!((amount, decimals), (2_500_000, 6));
+                assert_eq!(delegate, accounts[2]);
+            }
+            _ => panic!("expected ApproveChecked"),
+        }
+
+        match parse_token_2022_instruction(&[5u8], &accounts).unwrap() {
+            Token2022Instruction::Revoke { source, owner } => {
+                assert_eq!(source, accounts[0]);
+                assert_eq!(owner, accounts[1]);
+            }
+            _ => panic!("expected Revoke"),
+        }
+    }
+
+    #[test]
+    fn test_initialize_mint2_with_and_without_freeze_authority() {
+        let accounts = dummy_account_strings(1);
+        let mint_authority = Pubkey::new_unique();
+        let freeze_authority = Pubkey::new_unique();
+
+        // InitializeMint2: [20, decimals, mint_authority, COption<freeze_authority>]
+        let mut data = vec![20u8, 9];
+        data.extend_from_slice(mint_authority.as_ref());
+        data.push(1);
+        data.extend_from_slice(freeze_authority.as_ref());
+        match parse_token_2022_instruction(&data, &accounts).unwrap() {
+            Token2022Instruction::InitializeMint2 {
+                decimals,
+                mint_authority: parsed_mint_authority,
+                freeze_authority: parsed_freeze_authority,
+                ..
+            } => {
+                assert_eq!(decimals, 9);
+                assert_eq!(parsed_mint_authority, mint_authority.to_string());
+                assert_eq!(parsed_freeze_authority, Some(freeze_authority.to_string()));
+            }
+            _ => panic!("expected InitializeMint2"),
+        }
+
+        data.truncate(34);
+        data.push(0);
+        match parse_token_2022_instruction(&data, &accounts).unwrap() {
+            Token2022Instruction::InitializeMint2 {
+                freeze_authority, ..
+            } => assert_eq!(freeze_authority, None),
+            _ => panic!("expected InitializeMint2"),
+        }
+    }
+
     #[test]
     fn test_mint_to_checked_accepts_typical_decimals() {
         let accounts = dummy_account_strings(3);
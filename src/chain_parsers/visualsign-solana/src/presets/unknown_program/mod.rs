@@ -209,9 +209,10 @@ fn try_idl_parsing(
                 });
             }
 
-            // Add each argument as a separate field in condensed view
+            // Add each argument as a separate field, after the accounts in the
+            // expanded view so it lists everything the instruction carries
             for (key, value) in &parsed.program_call_args {
-                condensed_fields.push(AnnotatedPayloadField {
+                let arg_field = AnnotatedPayloadField {
                     signable_payload_field: SignablePayloadField::TextV2 {
                         common: SignablePayloadFieldCommon {
                             fallback_text: value.to_string(),
@@ -223,7 +224,9 @@ fn try_idl_parsing(
                     },
                     static_annotation: None,
                     dynamic_annotation: None,
-                });
+                };
+                condensed_fields.push(arg_field.clone());
+                expanded_fields.push(arg_field);
             }
         }
         Err(_) => {
@@ -350,27 +353,14 @@ fn try_parse_with_idl(
     let parsed: SolanaParsedInstructionData =
         parse_instruction_with_idl(instruction_data, &program_id_str, &idl)?;
 
-    // Manually create the named_accounts map by matching instruction accounts with IDL
-    let mut named_accounts = BTreeMap::new();
-
-    // Find the matching instruction in the IDL to get account names
-    if let Some(idl_instruction) = idl.instructions.iter().find(|inst| {
-        if let Some(ref disc) = inst.discriminator {
-            instruction_data.len() >= 8 && &instruction_data[0..8] == disc.as_slice()
-        } else {
-            false
-        }
-    }) {
-        // Match each account in the instruction with its name from the IDL
-        for index in 0..context.num_accounts() {
-            if let Some(idl_account) = idl_instruction.accounts.get(index) {
-                named_accounts.insert(
-                    idl_account.name.clone(),
-                    resolve_account_str(context, index),
-                );
-            }
-        }
-    }
+    // Name the accounts from the discriminator index built with the registry
+    let accounts: Vec<String> = (0..context.num_accounts())
+        .map(|index| resolve_account_str(context, index))
+        .collect();
+    let named_accounts = idl_registry
+        .anchor_matcher(&program_id_str)
+        .map(|matcher| matcher.named_accounts(instruction_data, &accounts))
+        .unwrap_or_default();
 
     Ok((parsed, named_accounts))
 }
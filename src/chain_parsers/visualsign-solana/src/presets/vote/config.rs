@@ -0,0 +1,26 @@
+//! Configuration for Vote program integration
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::BTreeMap;
+
+pub struct VoteConfig;
+
+impl SolanaIntegrationConfig for VoteConfig {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| {
+            let mut programs = BTreeMap::new();
+            let mut vote_instructions = BTreeMap::new();
+            vote_instructions.insert("*", vec!["*"]);
+            programs.insert(
+                "Vote111111111111111111111111111111111111111",
+                vote_instructions,
+            );
+            SolanaIntegrationConfigData { programs }
+        })
+    }
+}
@@ -0,0 +1,398 @@
+//! Vote program preset for Solana
+//! Handles the validator-operator instructions of the native Vote Program
+//! (Vote111111111111111111111111111111111111111). Consensus votes themselves
+//! fall through to a generic layout.
+
+mod config;
+
+use crate::core::{
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
+};
+use crate::utils::format_token_amount;
+use config::VoteConfig;
+use solana_vote_interface::instruction::VoteInstruction;
+use solana_vote_interface::state::VoteAuthorize;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{create_amount_field, create_number_field, create_text_field};
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
+
+/// Lamports are 1e-9 SOL
+const SOL_DECIMALS: u8 = 9;
+
+// Create a static instance that we can reference
+static VOTE_CONFIG: VoteConfig = VoteConfig;
+
+pub struct VoteVisualizer;
+
+impl InstructionVisualizer for VoteVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        let vote_instruction =
+            bincode::deserialize::<VoteInstruction>(context.data()).map_err(|e| {
+                VisualSignError::DecodeError(format!("Failed to parse vote instruction: {e}"))
+            })?;
+
+        create_vote_preview_layout(&vote_instruction, context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&VOTE_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::StakingPools("Vote")
+    }
+}
+
+/// Renders the account at `position` in the instruction's account list
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+fn authority_type(vote_authorize: &VoteAuthorize) -> &'static str {
+    match vote_authorize {
+        VoteAuthorize::Voter => "Vote Authority",
+        VoteAuthorize::Withdrawer => "Withdraw Authority",
+    }
+}
+
+/// Title and condensed rows shared by every authority change
+fn authority_change(
+    authority_type: &str,
+    new_authority: &str,
+) -> Result<(String, Vec<AnnotatedPayloadField>), VisualSignError> {
+    let title = format!("Change {authority_type}");
+    let condensed_fields = vec![
+        create_text_field("Instruction", &title)?,
+        create_text_field("New Authority", new_authority)?,
+    ];
+    Ok((title, condensed_fields))
+}
+
+/// Short name for the consensus instructions validators send every slot
+fn vote_instruction_name(instruction: &VoteInstruction) -> &'static str {
+    match instruction {
+        VoteInstruction::Vote(_) => "Vote",
+        VoteInstruction::VoteSwitch(..) => "Vote Switch",
+        VoteInstruction::UpdateVoteState(_) => "Update Vote State",
+        VoteInstruction::UpdateVoteStateSwitch(..) => "Update Vote State Switch",
+        VoteInstruction::CompactUpdateVoteState(_) => "Compact Update Vote State",
+        VoteInstruction::CompactUpdateVoteStateSwitch(..) => "Compact Update Vote State Switch",
+        VoteInstruction::TowerSync(_) => "Tower Sync",
+        VoteInstruction::TowerSyncSwitch(..) => "Tower Sync Switch",
+        _ => "Vote Program Instruction",
+    }
+}
+
+fn create_vote_preview_layout(
+    instruction: &VoteInstruction,
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id_str = match context.program_id() {
+        ProgramRef::Resolved(pk) => pk.to_string(),
+        ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
+    };
+    let vote_account = account_str(context, 0);
+
+    let (title, condensed_fields, mut expanded_fields) = match instruction {
+        // Accounts: [0] vote account, [1] rent sysvar, [2] clock sysvar,
+        // [3] validator identity (signer)
+        VoteInstruction::InitializeAccount(vote_init) => {
+            let title = "Initialize Vote Account".to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Vote Account", &vote_account)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Vote Account", &vote_account)?,
+                create_text_field("Validator Identity", &vote_init.node_pubkey.to_string())?,
+                create_text_field("Vote Authority", &vote_init.authorized_voter.to_string())?,
+                create_text_field(
+                    "Withdraw Authority",
+                    &vote_init.authorized_withdrawer.to_string(),
+                )?,
+                create_number_field("Commission", &vote_init.commission.to_string(), "%")?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] vote account, [1] clock sysvar, [2] current authority
+        VoteInstruction::Authorize(new_authority, vote_authorize) => {
+            let authority_type = authority_type(vote_authorize);
+            let new_authority = new_authority.to_string();
+            let (title, condensed_fields) = authority_change(authority_type, &new_authority)?;
+            let expanded_fields = vec![
+                create_text_field("Vote Account", &vote_account)?,
+                create_text_field("Authority Type", authority_type)?,
+                create_text_field("Current Authority", &account_str(context, 2))?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] vote account, [1] clock sysvar, [2] current authority,
+        // [3] new authority (signer)
+        VoteInstruction::AuthorizeChecked(vote_authorize) => {
+            let authority_type = authority_type(vote_authorize);
+            let new_authority = account_str(context, 3);
+            let (title, condensed_fields) = authority_change(authority_type, &new_authority)?;
+            let expanded_fields = vec![
+                create_text_field("Vote Account", &vote_account)?,
+                create_text_field("Authority Type", authority_type)?,
+                create_text_field("Current Authority", &account_str(context, 2))?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] vote account, [1] clock sysvar, [2] base of the derived
+        // current authority
+        VoteInstruction::AuthorizeWithSeed(args) => {
+            let authority_type = authority_type(&args.authorization_type);
+            let new_authority = args.new_authority.to_string();
+            let (title, condensed_fields) = authority_change(authority_type, &new_authority)?;
+            let expanded_fields = vec![
+                create_text_field("Vote Account", &vote_account)?,
+                create_text_field("Authority Type", authority_type)?,
+                create_text_field("Authority Base", &account_str(context, 2))?,
+                create_text_field("Authority Seed", &args.current_authority_derived_key_seed)?,
+                create_text_field(
+                    "Authority Owner",
+                    &args.current_authority_derived_key_owner.to_string(),
+                )?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] vote account, [1] clock sysvar, [2] base of the derived
+        // current authority, [3] new authority (signer)
+        VoteInstruction::AuthorizeCheckedWithSeed(args) => {
+            let authority_type = authority_type(&args.authorization_type);
+            let new_authority = account_str(context, 3);
+            let (title, condensed_fields) = authority_change(authority_type, &new_authority)?;
+            let expanded_fields = vec![
+                create_text_field("Vote Account", &vote_account)?,
+                create_text_field("Authority Type", authority_type)?,
+                create_text_field("Authority Base", &account_str(context, 2))?,
+                create_text_field("Authority Seed", &args.current_authority_derived_key_seed)?,
+                create_text_field(
+                    "Authority Owner",
+                    &args.current_authority_derived_key_owner.to_string(),
+                )?,
+                create_text_field("New Authority", &new_authority)?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] vote account, [1] recipient, [2] withdraw authority
+        VoteInstruction::Withdraw(lamports) => {
+            let amount = format_token_amount(*lamports, SOL_DECIMALS);
+            let title = format!("Withdraw Vote Rewards: {amount} SOL");
+            let recipient = account_str(context, 1);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Recipient", &recipient)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Vote Account", &vote_account)?,
+                create_text_field("Recipient", &recipient)?,
+                create_text_field("Withdraw Authority", &account_str(context, 2))?,
+                create_amount_field("Amount", &amount, "SOL")?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] vote account, [1] withdraw authority
+        VoteInstruction::UpdateCommission(commission) => {
+            let title = format!("Update Commission: {commission}%");
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Vote Account", &vote_account)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Vote Account", &vote_account)?,
+                create_number_field("New Commission", &commission.to_string(), "%")?,
+                create_text_field("Withdraw Authority", &account_str(context, 1))?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        // Accounts: [0] vote account, [1] new validator identity (signer),
+        // [2] withdraw authority
+        VoteInstruction::UpdateValidatorIdentity => {
+            let title = "Update Validator Identity".to_string();
+            let new_identity = account_str(context, 1);
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("New Validator Identity", &new_identity)?,
+            ];
+            let expanded_fields = vec![
+                create_text_field("Vote Account", &vote_account)?,
+                create_text_field("New Validator Identity", &new_identity)?,
+                create_text_field("Withdraw Authority", &account_str(context, 2))?,
+            ];
+            (title, condensed_fields, expanded_fields)
+        }
+        _ => {
+            let title = vote_instruction_name(instruction).to_string();
+            let condensed_fields = vec![
+                create_text_field("Instruction", &title)?,
+                create_text_field("Vote Account", &vote_account)?,
+            ];
+            let expanded_fields = vec![create_text_field("Vote Account", &vote_account)?];
+            (title, condensed_fields, expanded_fields)
+        }
+    };
+
+    expanded_fields.insert(0, create_text_field("Program ID", &program_id_str)?);
+    expanded_fields.push(create_text_field("Raw Data", &hex::encode(context.data()))?);
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(context.data())
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
+
+    /// Visualizes `instruction` with the Vote Program at index 0 and
+    /// `accounts` after it, and returns the preview's title and expanded rows
+    fn visualize(
+        instruction: &VoteInstruction,
+        accounts: &[Pubkey],
+    ) -> (String, Vec<(String, String)>) {
+        let mut account_keys = vec![solana_vote_interface::program::ID];
+        account_keys.extend_from_slice(accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data: bincode::serialize(instruction).unwrap(),
+        };
+        let sender = SolanaAccount {
+            account_key: accounts[0].to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = VoteVisualizer.visualize_tx_commands(&context).unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect();
+        (preview_layout.title.unwrap().text, rows)
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    fn unique_keys(n: usize) -> Vec<Pubkey> {
+        (0..n).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_withdraw_in_sol() {
+        let keys = unique_keys(3);
+        let (title, rows) = visualize(&VoteInstruction::Withdraw(3_000_000_000), &keys);
+
+        assert_eq!(title, "Withdraw Vote Rewards: 3 SOL");
+        assert_eq!(row(&rows, "Vote Account"), keys[0].to_string());
+        assert_eq!(row(&rows, "Recipient"), keys[1].to_string());
+        assert_eq!(row(&rows, "Withdraw Authority"), keys[2].to_string());
+        assert_eq!(row(&rows, "Amount"), "3 SOL");
+    }
+
+    #[test]
+    fn test_update_commission() {
+        let keys = unique_keys(2);
+        let (title, rows) = visualize(&VoteInstruction::UpdateCommission(7), &keys);
+
+        assert_eq!(title, "Update Commission: 7%");
+        assert_eq!(row(&rows, "New Commission"), "7 %");
+        assert_eq!(row(&rows, "Withdraw Authority"), keys[1].to_string());
+    }
+
+    #[test]
+    fn test_authorize_checked_reads_new_authority_from_accounts() {
+        let keys = unique_keys(4);
+        let (title, rows) = visualize(
+            &VoteInstruction::AuthorizeChecked(VoteAuthorize::Withdrawer),
+            &keys,
+        );
+
+        assert_eq!(title, "Change Withdraw Authority");
+        assert_eq!(row(&rows, "Current Authority"), keys[2].to_string());
+        assert_eq!(row(&rows, "New Authority"), keys[3].to_string());
+    }
+
+    #[test]
+    fn test_authorize_voter() {
+        let keys = unique_keys(3);
+        let new_voter = Pubkey::new_unique();
+        let (title, rows) = visualize(
+            &VoteInstruction::Authorize(new_voter, VoteAuthorize::Voter),
+            &keys,
+        );
+
+        assert_eq!(title, "Change Vote Authority");
+        assert_eq!(row(&rows, "New Authority"), new_voter.to_string());
+    }
+
+    #[test]
+    fn test_update_validator_identity() {
+        let keys = unique_keys(3);
+        let (title, rows) = visualize(&VoteInstruction::UpdateValidatorIdentity, &keys);
+
+        assert_eq!(title, "Update Validator Identity");
+        assert_eq!(row(&rows, "New Validator Identity"), keys[1].to_string());
+    }
+}
@@ -0,0 +1,87 @@
+//! Wormhole chain IDs and how each chain encodes a 32-byte recipient
+
+use solana_sdk::pubkey::Pubkey;
+
+/// How a destination chain's address fits in Wormhole's 32-byte field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AddressFormat {
+    /// 20-byte address, left-padded with zeros
+    Evm,
+    /// 32-byte ed25519 public key, shown in base58
+    Solana,
+    /// 32-byte account address, shown as `0x` hex (Sui, Aptos)
+    Move,
+    /// No decoder here; shown as the raw 32 bytes
+    Raw,
+}
+
+/// Chains the Wormhole guardians sign for, by Wormhole chain ID
+const CHAINS: &[(u16, &str, AddressFormat)] = &[
+    (1, "Solana", AddressFormat::Solana),
+    (2, "Ethereum", AddressFormat::Evm),
+    (3, "Terra", AddressFormat::Raw),
+    (4, "BNB Smart Chain", AddressFormat::Evm),
+    (5, "Polygon", AddressFormat::Evm),
+    (6, "Avalanche", AddressFormat::Evm),
+    (7, "Oasis", AddressFormat::Evm),
+    (8, "Algorand", AddressFormat::Raw),
+    (9, "Aurora", AddressFormat::Evm),
+    (10, "Fantom", AddressFormat::Evm),
+    (11, "Karura", AddressFormat::Evm),
+    (12, "Acala", AddressFormat::Evm),
+    (13, "Klaytn", AddressFormat::Evm),
+    (14, "Celo", AddressFormat::Evm),
+    (15, "NEAR", AddressFormat::Raw),
+    (16, "Moonbeam", AddressFormat::Evm),
+    (18, "Terra 2", AddressFormat::Raw),
+    (19, "Injective", AddressFormat::Raw),
+    (20, "Osmosis", AddressFormat::Raw),
+    (21, "Sui", AddressFormat::Move),
+    (22, "Aptos", AddressFormat::Move),
+    (23, "Arbitrum", AddressFormat::Evm),
+    (24, "Optimism", AddressFormat::Evm),
+    (25, "Gnosis", AddressFormat::Evm),
+    (26, "Pythnet", AddressFormat::Solana),
+    (28, "XPLA", AddressFormat::Raw),
+    (30, "Base", AddressFormat::Evm),
+    (32, "Sei", AddressFormat::Raw),
+    (34, "Scroll", AddressFormat::Evm),
+    (35, "Mantle", AddressFormat::Evm),
+    (36, "Blast", AddressFormat::Evm),
+    (38, "Linea", AddressFormat::Evm),
+    (39, "Berachain", AddressFormat::Evm),
+    (3104, "Wormchain", AddressFormat::Raw),
+];
+
+fn lookup(chain_id: u16) -> Option<(&'static str, AddressFormat)> {
+    CHAINS
+        .iter()
+        .find(|(id, _, _)| *id == chain_id)
+        .map(|(_, name, format)| (*name, *format))
+}
+
+/// Display name of a Wormhole chain ID, with the ID kept alongside so an
+/// unlisted chain is still identifiable
+pub fn chain_name(chain_id: u16) -> String {
+    match lookup(chain_id) {
+        Some((name, _)) => format!("{name} ({chain_id})"),
+        None => format!("Unknown chain ({chain_id})"),
+    }
+}
+
+/// Render a Wormhole recipient in the destination chain's address format.
+/// An EVM recipient whose padding is not all zeros cannot be a valid
+/// address there, so it is shown as raw bytes rather than truncated.
+pub fn format_recipient(chain_id: u16, address: &[u8; 32]) -> String {
+    let format = lookup(chain_id).map_or(AddressFormat::Raw, |(_, format)| format);
+    match format {
+        AddressFormat::Evm => match address.split_at(12) {
+            (padding, evm) if padding.iter().all(|b| *b == 0) => {
+                format!("0x{}", hex::encode(evm))
+            }
+            _ => format!("0x{}", hex::encode(address)),
+        },
+        AddressFormat::Solana => Pubkey::new_from_array(*address).to_string(),
+        AddressFormat::Move | AddressFormat::Raw => format!("0x{}", hex::encode(address)),
+    }
+}
@@ -0,0 +1,25 @@
+//! Configuration for Wormhole token bridge program integration
+
+use crate::core::{SolanaIntegrationConfig, SolanaIntegrationConfigData};
+use std::collections::BTreeMap;
+
+pub const WORMHOLE_TOKEN_BRIDGE_PROGRAM_ID: &str = "wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb";
+
+pub struct WormholeTokenBridgeConfig;
+
+impl SolanaIntegrationConfig for WormholeTokenBridgeConfig {
+    fn new() -> Self {
+        Self
+    }
+
+    fn data(&self) -> &SolanaIntegrationConfigData {
+        static DATA: std::sync::OnceLock<SolanaIntegrationConfigData> = std::sync::OnceLock::new();
+        DATA.get_or_init(|| {
+            let mut programs = BTreeMap::new();
+            let mut bridge_instructions = BTreeMap::new();
+            bridge_instructions.insert("*", vec!["*"]);
+            programs.insert(WORMHOLE_TOKEN_BRIDGE_PROGRAM_ID, bridge_instructions);
+            SolanaIntegrationConfigData { programs }
+        })
+    }
+}
@@ -0,0 +1,438 @@
+//! Wormhole token bridge preset for Solana
+//! Handles outbound transfers on the Wormhole token bridge program
+//! (wormDTUJ6AWPNvk59vGQbDvGJmqbDTdgWgAqcLBCgUb). Instructions are a one-byte
+//! index followed by Borsh arguments. `TransferNative` locks a Solana token in
+//! the bridge's custody; `TransferWrapped` burns a wrapped token to release the
+//! original on its home chain. The recipient is a 32-byte Wormhole address,
+//! rendered in the destination chain's own format.
+
+mod chains;
+mod config;
+
+use crate::core::{
+    AccountRef, InstructionVisualizer, ProgramRef, SolanaIntegrationConfig, VisualizerContext,
+    VisualizerKind,
+};
+use borsh::BorshDeserialize;
+use chains::{chain_name, format_recipient};
+use config::WormholeTokenBridgeConfig;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::create_text_field;
+use visualsign::{AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon};
+
+const TRANSFER_WRAPPED: u8 = 4;
+const TRANSFER_NATIVE: u8 = 5;
+const TRANSFER_WRAPPED_WITH_PAYLOAD: u8 = 11;
+const TRANSFER_NATIVE_WITH_PAYLOAD: u8 = 12;
+
+// Create a static instance that we can reference
+static WORMHOLE_TOKEN_BRIDGE_CONFIG: WormholeTokenBridgeConfig = WormholeTokenBridgeConfig;
+
+pub struct WormholeTokenBridgeVisualizer;
+
+impl InstructionVisualizer for WormholeTokenBridgeVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        create_wormhole_preview_layout(context)
+    }
+
+    fn get_config(&self) -> Option<&dyn SolanaIntegrationConfig> {
+        Some(&WORMHOLE_TOKEN_BRIDGE_CONFIG)
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Payments("Wormhole Token Bridge")
+    }
+}
+
+/// Arguments of `TransferNative` and `TransferWrapped`
+#[derive(BorshDeserialize)]
+struct TransferData {
+    _nonce: u32,
+    amount: u64,
+    fee: u64,
+    target_address: [u8; 32],
+    target_chain: u16,
+}
+
+/// Arguments of the `*WithPayload` variants, which hand the tokens to a
+/// contract on the destination chain together with an opaque payload
+#[derive(BorshDeserialize)]
+struct TransferWithPayloadData {
+    _nonce: u32,
+    amount: u64,
+    target_address: [u8; 32],
+    target_chain: u16,
+    payload: Vec<u8>,
+}
+
+/// The fields shared by every transfer variant
+struct Transfer {
+    amount: u64,
+    relayer_fee: Option<u64>,
+    target_address: [u8; 32],
+    target_chain: u16,
+    payload: Option<Vec<u8>>,
+}
+
+impl From<TransferData> for Transfer {
+    fn from(data: TransferData) -> Self {
+        Self {
+            amount: data.amount,
+            relayer_fee: Some(data.fee),
+            target_address: data.target_address,
+            target_chain: data.target_chain,
+            payload: None,
+        }
+    }
+}
+
+impl From<TransferWithPayloadData> for Transfer {
+    fn from(data: TransferWithPayloadData) -> Self {
+        Self {
+            amount: data.amount,
+            relayer_fee: None,
+            target_address: data.target_address,
+            target_chain: data.target_chain,
+            payload: Some(data.payload),
+        }
+    }
+}
+
+/// Renders the account at `position` in the instruction's account list
+fn account_str(context: &VisualizerContext, position: usize) -> String {
+    match context.account(position) {
+        Some(AccountRef::Resolved(pk)) => pk.to_string(),
+        Some(AccountRef::Unresolved { raw_index }) => format!("unresolved({raw_index})"),
+        None => "unknown".to_string(),
+    }
+}
+
+fn parse_transfer(instruction: u8, args: &[u8]) -> Result<Option<Transfer>, VisualSignError> {
+    let parse_error = |e: borsh::io::Error| {
+        VisualSignError::DecodeError(format!("Failed to parse Wormhole transfer: {e}"))
+    };
+    let transfer = match instruction {
+        TRANSFER_WRAPPED | TRANSFER_NATIVE => TransferData::deserialize(&mut &args[..])
+            .map_err(parse_error)?
+            .into(),
+        TRANSFER_WRAPPED_WITH_PAYLOAD | TRANSFER_NATIVE_WITH_PAYLOAD => {
+            TransferWithPayloadData::deserialize(&mut &args[..])
+                .map_err(parse_error)?
+                .into()
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(transfer))
+}
+
+fn create_wormhole_preview_layout(
+    context: &VisualizerContext,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let program_id_str = match context.program_id() {
+        ProgramRef::Resolved(pk) => pk.to_string(),
+        ProgramRef::Unresolved { raw_index } => format!("unresolved({raw_index})"),
+    };
+    let data = context.data();
+    let (&instruction, args) = data.split_first().ok_or_else(|| {
+        VisualSignError::DecodeError("Wormhole token bridge instruction data is empty".into())
+    })?;
+
+    let (title, condensed_fields, mut expanded_fields) = match parse_transfer(instruction, args)? {
+        Some(transfer) => transfer_fields(context, instruction, &transfer)?,
+        None => {
+            let title = "Wormhole Token Bridge Instruction".to_string();
+            let condensed_fields = vec![create_text_field("Instruction", &title)?];
+            let expanded_fields = vec![create_text_field(
+                "Instruction Index",
+                &instruction.to_string(),
+            )?];
+            (title, condensed_fields, expanded_fields)
+        }
+    };
+
+    expanded_fields.insert(0, create_text_field("Program ID", &program_id_str)?);
+    expanded_fields.push(create_text_field("Raw Data", &hex::encode(data))?);
+
+    let preview_layout = visualsign::SignablePayloadFieldPreviewLayout {
+        title: Some(visualsign::SignablePayloadFieldTextV2 { text: title }),
+        subtitle: Some(visualsign::SignablePayloadFieldTextV2 {
+            text: String::new(),
+        }),
+        condensed: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: condensed_fields,
+        }),
+        expanded: Some(visualsign::SignablePayloadFieldListLayout {
+            fields: expanded_fields,
+        }),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                label: format!("Instruction {}", context.instruction_index() + 1),
+                fallback_text: format!(
+                    "Program ID: {}\nData: {}",
+                    program_id_str,
+                    hex::encode(data)
+                ),
+            },
+            preview_layout,
+        },
+    })
+}
+
+/// Title, condensed and expanded rows for an outbound transfer: the token
+/// and amount leaving Solana, then where and to whom it is delivered
+fn transfer_fields(
+    context: &VisualizerContext,
+    instruction: u8,
+    transfer: &Transfer,
+) -> Result<
+    (
+        String,
+        Vec<AnnotatedPayloadField>,
+        Vec<AnnotatedPayloadField>,
+    ),
+    VisualSignError,
+> {
+    // Native transfers take the mint at [3]; wrapped ones list the token
+    // owner there and the wrapped mint at [4]. [2] is always the source.
+    let (kind, mint_position) = match instruction {
+        TRANSFER_NATIVE | TRANSFER_NATIVE_WITH_PAYLOAD => ("Native", 3),
+        _ => ("Wrapped", 4),
+    };
+    let mint = account_str(context, mint_position);
+    let token = context.token_info(&mint, transfer.amount);
+    let amount = format!("{} {}", token.human_readable_amount, token.symbol);
+    let destination = chain_name(transfer.target_chain);
+    let recipient = format_recipient(transfer.target_chain, &transfer.target_address);
+
+    let title = format!("Wormhole Transfer: {amount} to {destination}");
+    let condensed_fields = vec![
+        create_text_field("Instruction", &title)?,
+        create_text_field("Destination Chain", &destination)?,
+        create_text_field("Recipient", &recipient)?,
+    ];
+    let mut expanded_fields = vec![
+        create_text_field("Transfer Type", kind)?,
+        create_text_field("Amount", &amount)?,
+        create_text_field("Token Mint", &mint)?,
+        create_text_field("From", &account_str(context, 2))?,
+        create_text_field("Destination Chain", &destination)?,
+        create_text_field("Recipient", &recipient)?,
+    ];
+    if let Some(fee) = transfer.relayer_fee {
+        let fee = context.token_info(&mint, fee);
+        expanded_fields.push(create_text_field(
+            "Relayer Fee",
+            &format!("{} {}", fee.human_readable_amount, fee.symbol),
+        )?);
+    }
+    if let Some(payload) = &transfer.payload {
+        expanded_fields.push(create_text_field("Payload", &hex::encode(payload))?);
+    }
+    Ok((title, condensed_fields, expanded_fields))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use solana_parser::solana::structs::SolanaAccount;
+    use solana_sdk::instruction::CompiledInstruction;
+    use solana_sdk::pubkey::Pubkey;
+    use std::str::FromStr;
+
+    const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    /// Visualizes `data` with the token bridge program at index 0 and
+    /// `accounts` after it, and returns the preview's title and expanded rows
+    fn visualize(data: Vec<u8>, accounts: &[Pubkey]) -> (String, Vec<(String, String)>) {
+        let mut account_keys =
+            vec![Pubkey::from_str(config::WORMHOLE_TOKEN_BRIDGE_PROGRAM_ID).unwrap()];
+        account_keys.extend_from_slice(accounts);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: (1..=accounts.len() as u8).collect(),
+            data,
+        };
+        let sender = SolanaAccount {
+            account_key: Pubkey::new_unique().to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+
+        let field = WormholeTokenBridgeVisualizer
+            .visualize_tx_commands(&context)
+            .unwrap();
+        let SignablePayloadField::PreviewLayout { preview_layout, .. } =
+            field.signable_payload_field
+        else {
+            panic!("expected PreviewLayout");
+        };
+        let rows = preview_layout
+            .expanded
+            .unwrap()
+            .fields
+            .iter()
+            .map(|f| {
+                (
+                    f.signable_payload_field.label().clone(),
+                    f.signable_payload_field.fallback_text().clone(),
+                )
+            })
+            .collect();
+        (preview_layout.title.unwrap().text, rows)
+    }
+
+    fn row<'a>(rows: &'a [(String, String)], label: &str) -> &'a str {
+        rows.iter()
+            .find(|(l, _)| l == label)
+            .map(|(_, text)| text.as_str())
+            .unwrap_or_else(|| panic!("missing row {label}"))
+    }
+
+    fn transfer_data(
+        instruction: u8,
+        amount: u64,
+        fee: u64,
+        target_address: [u8; 32],
+        target_chain: u16,
+    ) -> Vec<u8> {
+        let mut data = vec![instruction];
+        data.extend_from_slice(&7u32.to_le_bytes());
+        data.extend_from_slice(&amount.to_le_bytes());
+        data.extend_from_slice(&fee.to_le_bytes());
+        data.extend_from_slice(&target_address);
+        data.extend_from_slice(&target_chain.to_le_bytes());
+        data
+    }
+
+    /// Bridge accounts with `mint` at `mint_position`
+    fn bridge_accounts(mint: Pubkey, mint_position: usize) -> Vec<Pubkey> {
+        let mut accounts: Vec<Pubkey> = (0..17).map(|_| Pubkey::new_unique()).collect();
+        accounts[mint_position] = mint;
+        accounts
+    }
+
+    fn evm_address(last: [u8; 20]) -> [u8; 32] {
+        let mut address = [0u8; 32];
+        address[12..].copy_from_slice(&last);
+        address
+    }
+
+    #[test]
+    fn test_native_transfer_to_ethereum() {
+        let accounts = bridge_accounts(Pubkey::from_str(USDC_MINT).unwrap(), 3);
+        let recipient = evm_address([0xab; 20]);
+        let (title, rows) = visualize(
+            transfer_data(TRANSFER_NATIVE, 25_000_000, 0, recipient, 2),
+            &accounts,
+        );
+
+        assert_eq!(title, "Wormhole Transfer: 25 USDC to Ethereum (2)");
+        assert_eq!(row(&rows, "Transfer Type"), "Native");
+        assert_eq!(row(&rows, "From"), accounts[2].to_string());
+        assert_eq!(row(&rows, "Token Mint"), USDC_MINT);
+        assert_eq!(row(&rows, "Recipient"), format!("0x{}", "ab".repeat(20)));
+        assert_eq!(row(&rows, "Relayer Fee"), "0 USDC");
+    }
+
+    #[test]
+    fn test_wrapped_transfer_to_sui_keeps_full_address() {
+        let wrapped_mint = Pubkey::new_unique();
+        let accounts = bridge_accounts(wrapped_mint, 4);
+        let (_, rows) = visualize(
+            transfer_data(TRANSFER_WRAPPED, 1_000, 10, [0x11; 32], 21),
+            &accounts,
+        );
+
+        assert_eq!(row(&rows, "Transfer Type"), "Wrapped");
+        assert_eq!(row(&rows, "Token Mint"), wrapped_mint.to_string());
+        assert_eq!(row(&rows, "Destination Chain"), "Sui (21)");
+        assert_eq!(row(&rows, "Recipient"), format!("0x{}", "11".repeat(32)));
+    }
+
+    #[test]
+    fn test_evm_recipient_with_dirty_padding_is_not_truncated() {
+        let mut recipient = evm_address([0xcd; 20]);
+        recipient[0] = 1;
+        assert_eq!(
+            format_recipient(30, &recipient),
+            format!("0x{}", hex::encode(recipient))
+        );
+    }
+
+    #[test]
+    fn test_native_transfer_with_payload_to_solana() {
+        let accounts = bridge_accounts(Pubkey::new_unique(), 3);
+        let recipient = Pubkey::new_unique();
+        let mut data = vec![TRANSFER_NATIVE_WITH_PAYLOAD];
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&500u64.to_le_bytes());
+        data.extend_from_slice(&recipient.to_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&[0xde, 0xad, 0x01]);
+        // cpi_program_id: None
+        data.push(0);
+        let (_, rows) = visualize(data, &accounts);
+
+        assert_eq!(row(&rows, "Destination Chain"), "Solana (1)");
+        assert_eq!(row(&rows, "Recipient"), recipient.to_string());
+        assert_eq!(row(&rows, "Payload"), "dead01");
+        assert!(rows.iter().all(|(label, _)| label != "Relayer Fee"));
+    }
+
+    #[test]
+    fn test_unknown_chain_keeps_raw_recipient() {
+        let accounts = bridge_accounts(Pubkey::new_unique(), 3);
+        let (title, rows) = visualize(
+            transfer_data(TRANSFER_NATIVE, 1, 0, [0x22; 32], 9999),
+            &accounts,
+        );
+
+        assert!(title.ends_with("to Unknown chain (9999)"), "{title}");
+        assert_eq!(row(&rows, "Recipient"), format!("0x{}", "22".repeat(32)));
+    }
+
+    #[test]
+    fn test_other_instruction_shows_index() {
+        let (title, rows) = visualize(vec![2, 0, 0], &[]);
+
+        assert_eq!(title, "Wormhole Token Bridge Instruction");
+        assert_eq!(row(&rows, "Instruction Index"), "2");
+    }
+
+    #[test]
+    fn test_truncated_transfer_is_an_error() {
+        let mut data = transfer_data(TRANSFER_NATIVE, 1, 0, [0; 32], 2);
+        data.truncate(10);
+        let compiled = CompiledInstruction {
+            program_id_index: 0,
+            accounts: vec![],
+            data,
+        };
+        let account_keys =
+            vec![Pubkey::from_str(config::WORMHOLE_TOKEN_BRIDGE_PROGRAM_ID).unwrap()];
+        let sender = SolanaAccount {
+            account_key: Pubkey::new_unique().to_string(),
+            signer: true,
+            writable: true,
+        };
+        let idl_registry = crate::idl::IdlRegistry::new();
+        let context = VisualizerContext::new(&sender, &compiled, &account_keys, &idl_registry, 0);
+        assert!(
+            WormholeTokenBridgeVisualizer
+                .visualize_tx_commands(&context)
+                .is_err()
+        );
+    }
+}
@@ -101,6 +101,23 @@ pub fn format_token_amount(amount: u64, decimals: u8) -> String {
     }
 }
 
+/// Renders untrusted on-chain text (memos, NFT names, URIs) as printable
+/// ASCII. Anything that could disguise or reflow the display (control
+/// characters, non-ASCII lookalikes) is shown as a visible `<U+XXXX>` code
+/// point instead of being passed through. Backslashes are escaped the same way
+/// so the text can never form a JSON escape sequence.
+pub fn escape_untrusted_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == ' ' || (c.is_ascii_graphic() && c != '\\') {
+            escaped.push(c);
+        } else {
+            escaped.push_str(&format!("<U+{:04X}>", c as u32));
+        }
+    }
+    escaped
+}
+
 /// Enhanced swap instruction with token information
 #[derive(Debug, Clone)]
 pub struct SwapTokenInfo {
@@ -117,14 +134,13 @@ pub fn get_token_info(address: &str, amount: u64) -> SwapTokenInfo {
     let token_lookup = get_token_lookup_table();
 
     if let Some(token_info) = token_lookup.get(address) {
-        SwapTokenInfo {
-            address: address.to_string(),
-            symbol: token_info.symbol.to_string(),
-            name: token_info.name.to_string(),
-            decimals: token_info.decimals,
+        known_token_info(
+            address,
+            token_info.symbol,
+            token_info.name,
+            token_info.decimals,
             amount,
-            human_readable_amount: format_token_amount(amount, token_info.decimals),
-        }
+        )
     } else {
         // Unknown token - show truncated address
         let truncated = if address.len() > ADDRESS_TRUNCATION_LENGTH {
@@ -144,6 +160,70 @@ pub fn get_token_info(address: &str, amount: u64) -> SwapTokenInfo {
     }
 }
 
+fn known_token_info(
+    address: &str,
+    symbol: &str,
+    name: &str,
+    decimals: u8,
+    amount: u64,
+) -> SwapTokenInfo {
+    SwapTokenInfo {
+        address: address.to_string(),
+        symbol: symbol.to_string(),
+        name: name.to_string(),
+        decimals,
+        amount,
+        human_readable_amount: format_token_amount(amount, decimals),
+    }
+}
+
+/// Token metadata supplied by the caller for a single mint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredToken {
+    pub symbol: String,
+    pub name: String,
+    pub decimals: u8,
+}
+
+/// Per-request token metadata, keyed by mint address, layered over the
+/// built-in lookup table. Entries here win over the built-in ones, and mints
+/// known to neither fall back to the truncated-address rendering of
+/// [`get_token_info`].
+#[derive(Debug, Clone, Default)]
+pub struct TokenRegistry {
+    tokens: BTreeMap<String, RegisteredToken>,
+}
+
+impl TokenRegistry {
+    pub const fn new() -> Self {
+        Self {
+            tokens: BTreeMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, mint: String, token: RegisteredToken) {
+        self.tokens.insert(mint, token);
+    }
+
+    pub fn get(&self, mint: &str) -> Option<&RegisteredToken> {
+        self.tokens.get(mint)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Like [`get_token_info`], but consults the caller's tokens first
+    pub fn token_info(&self, address: &str, amount: u64) -> SwapTokenInfo {
+        match self.tokens.get(address) {
+            Some(token) => {
+                known_token_info(address, &token.symbol, &token.name, token.decimals, amount)
+            }
+            None => get_token_info(address, amount),
+        }
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
@@ -169,6 +249,53 @@ mod tests {
         assert_eq!(format_token_amount(0, 64), "0");
     }
 
+    #[test]
+    fn test_escape_untrusted_text() {
+        assert_eq!(escape_untrusted_text("invoice 42"), "invoice 42");
+        assert_eq!(
+            escape_untrusted_text("a\nb\u{202e}c"),
+            "a<U+000A>b<U+202E>c"
+        );
+        assert_eq!(escape_untrusted_text("C:\\users"), "C:<U+005C>users");
+        assert_eq!(escape_untrusted_text("caf\u{e9}"), "caf<U+00E9>");
+    }
+
+    #[test]
+    fn test_token_registry_overrides_and_falls_back() {
+        let mut registry = TokenRegistry::new();
+        registry.insert(
+            "JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN".to_string(),
+            RegisteredToken {
+                symbol: "JUP".to_string(),
+                name: "Jupiter".to_string(),
+                decimals: 6,
+            },
+        );
+        registry.insert(
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string(),
+            RegisteredToken {
+                symbol: "USDC.e".to_string(),
+                name: "Override".to_string(),
+                decimals: 6,
+            },
+        );
+
+        let jup = registry.token_info("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN", 2_500_000);
+        assert_eq!(jup.symbol, "JUP");
+        assert_eq!(jup.human_readable_amount, "2.5");
+
+        let usdc = registry.token_info("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", 1);
+        assert_eq!(usdc.symbol, "USDC.e");
+
+        let usdt = registry.token_info("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", 1_000_000);
+        assert_eq!(usdt.symbol, "USDT");
+        assert_eq!(usdt.human_readable_amount, "1");
+
+        let unknown = registry.token_info("So1111111111111111111111111111111111111112", 42);
+        assert_eq!(unknown.symbol, "So11...1112");
+        assert_eq!(unknown.human_readable_amount, "42");
+    }
+
     /// Regression: decimals >= 20 must not trigger a divide-by-zero
     /// panic. `10_u64.pow(20)` overflows in debug and wraps in release; for
     /// `decimals == 64` the wrapped value is exactly `0` because `10^64 mod
@@ -199,6 +326,7 @@ pub mod test_utils {
             data,
             VisualSignOptions {
                 include_intermediate_output: false,
+                strict_registry: false,
                 metadata: None,
                 decode_transfers: true,
                 transaction_name: None,
@@ -104,6 +104,9 @@ pub fn options_with_idl(program_id: &Pubkey, idl_json: &str, name: &str) -> Visu
                 idl_mappings: idl_mappings.into_iter().collect(),
                 network_id: None,
                 idl: None,
+                address_lookup_tables: Default::default(),
+                markets: Default::default(),
+                tokens: Default::default(),
             })),
         }),
         ..VisualSignOptions::default()
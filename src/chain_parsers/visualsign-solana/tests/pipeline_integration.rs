@@ -32,7 +32,8 @@ use common::{
 // ── Concrete integration tests ────────────────────────────────────────────────
 
 /// Happy path: valid discriminator + correctly serialized args.
-/// Verifies the IDL code path is taken and arg values appear in condensed fields.
+/// Verifies the IDL code path is taken and arg values appear in condensed and
+/// expanded fields.
 #[test]
 fn pipeline_idl_path_correct_data() {
     let program_id = Pubkey::new_unique();
@@ -70,6 +71,10 @@ fn pipeline_idl_path_correct_data() {
         Some("deposit".into())
     );
     assert_eq!(find_text(&condensed.fields, "amount"), Some("42".into()));
+
+    // The expanded view repeats the decoded args after the accounts
+    let expanded = layout.expanded.as_ref().unwrap();
+    assert_eq!(find_text(&expanded.fields, "amount"), Some("42".into()));
 }
 
 /// IDL is registered but the instruction data has a non-matching discriminator.
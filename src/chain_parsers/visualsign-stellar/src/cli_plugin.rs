@@ -0,0 +1,42 @@
+use clap::Args as ClapArgs;
+use generated::parser::ChainMetadata;
+use visualsign::registry::{Chain, TransactionConverterRegistry};
+
+/// CLI arguments specific to Stellar.
+///
+/// No Stellar-specific args are needed yet; the global `--network` flag is accepted but
+/// not used, since a transaction envelope renders the same on every network.
+#[derive(ClapArgs, Debug, Default, Clone)]
+pub struct StellarArgs {}
+
+/// [`parser_cli_core::ChainPlugin`] implementation for Stellar.
+pub struct StellarPlugin {
+    // Empty today; kept so a Stellar flag can be added without changing the struct shape.
+    #[allow(dead_code)]
+    args: StellarArgs,
+}
+
+impl StellarPlugin {
+    /// Creates a new `StellarPlugin` with the given CLI args.
+    #[must_use]
+    pub fn new(args: StellarArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl parser_cli_core::ChainPlugin for StellarPlugin {
+    fn chain(&self) -> Chain {
+        Chain::Stellar
+    }
+
+    fn register(&self, registry: &mut TransactionConverterRegistry) {
+        registry.register::<crate::StellarTransactionWrapper, _>(
+            Chain::Stellar,
+            crate::StellarVisualSignConverter,
+        );
+    }
+
+    fn create_metadata(&self, _network: Option<String>) -> Result<Option<ChainMetadata>, String> {
+        Ok(None)
+    }
+}
@@ -0,0 +1,699 @@
+#[cfg(feature = "cli-plugin")]
+pub mod cli_plugin;
+
+#[cfg(feature = "cli-plugin")]
+pub use cli_plugin::{StellarArgs, StellarPlugin};
+
+mod operations;
+mod scval;
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
+use stellar_xdr::curr::{
+    AccountId, FeeBumpTransactionInnerTx, Limits, Memo, MuxedAccount, Operation, Preconditions,
+    PublicKey, ReadXdr, TimeBounds, TransactionEnvelope, Uint256,
+};
+use visualsign::field_builders::{create_address_field, create_amount_field, create_text_field};
+use visualsign::time_fmt::format_timestamp_ms;
+use visualsign::{
+    AnnotatedPayloadField, SignablePayload,
+    encodings::SupportedEncodings,
+    vsptrait::{
+        ConversionResult, Transaction, TransactionParseError, VisualSignConverter,
+        VisualSignConverterFromString, VisualSignError, VisualSignOptions,
+    },
+};
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum StellarParserError {
+    #[error("Failed to decode transaction: {0}")]
+    FailedToDecodeTransaction(String),
+}
+
+// Bounds recursion while reading nested Soroban values; real envelopes stay far below it.
+const XDR_DEPTH_LIMIT: u32 = 500;
+
+const STROOPS_PER_XLM: u64 = 10_000_000;
+
+fn decode_transaction(
+    raw_transaction: &str,
+    encodings: SupportedEncodings,
+) -> Result<TransactionEnvelope, StellarParserError> {
+    let bytes = match encodings {
+        SupportedEncodings::Hex => {
+            visualsign::encodings::decode_hex(raw_transaction).map_err(|e| {
+                StellarParserError::FailedToDecodeTransaction(format!("Failed to decode hex: {e}"))
+            })?
+        }
+        SupportedEncodings::Base64 => b64.decode(raw_transaction).map_err(|e| {
+            StellarParserError::FailedToDecodeTransaction(format!("Failed to decode base64: {e}"))
+        })?,
+    };
+
+    let limits = Limits {
+        depth: XDR_DEPTH_LIMIT,
+        len: bytes.len(),
+    };
+    TransactionEnvelope::from_xdr(&bytes, limits).map_err(|e| {
+        StellarParserError::FailedToDecodeTransaction(format!(
+            "Failed to parse transaction envelope XDR: {e}"
+        ))
+    })
+}
+
+/// Wrapper for Stellar transaction envelopes
+#[derive(Debug, Clone)]
+pub struct StellarTransactionWrapper {
+    envelope: TransactionEnvelope,
+}
+
+impl Transaction for StellarTransactionWrapper {
+    fn from_string(data: &str) -> Result<Self, TransactionParseError> {
+        // Wallets and Horizon exchange envelopes as base64 XDR; hex is accepted as well.
+        let format = SupportedEncodings::detect(data);
+        let envelope = decode_transaction(data, format)
+            .map_err(|e| TransactionParseError::DecodeError(e.to_string()))?;
+        Ok(Self { envelope })
+    }
+
+    fn transaction_type(&self) -> String {
+        "Stellar".to_string()
+    }
+}
+
+impl StellarTransactionWrapper {
+    pub fn new(envelope: TransactionEnvelope) -> Self {
+        Self { envelope }
+    }
+
+    pub fn inner(&self) -> &TransactionEnvelope {
+        &self.envelope
+    }
+}
+
+/// Converter for Stellar transactions
+pub struct StellarVisualSignConverter;
+
+impl VisualSignConverter<StellarTransactionWrapper> for StellarVisualSignConverter {
+    fn to_visual_sign_payload(
+        &self,
+        transaction_wrapper: StellarTransactionWrapper,
+        options: VisualSignOptions,
+    ) -> Result<ConversionResult, VisualSignError> {
+        let payload = convert_to_visual_sign_payload(transaction_wrapper.inner(), options)?;
+        Ok(ConversionResult::new(payload))
+    }
+}
+
+impl VisualSignConverterFromString<StellarTransactionWrapper> for StellarVisualSignConverter {}
+
+// Public API functions
+pub fn transaction_to_visual_sign(
+    envelope: TransactionEnvelope,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let wrapper = StellarTransactionWrapper::new(envelope);
+    let converter = StellarVisualSignConverter;
+    converter
+        .to_visual_sign_payload(wrapper, options)
+        .map(|r| r.payload)
+}
+
+pub fn transaction_string_to_visual_sign(
+    transaction_data: &str,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let converter = StellarVisualSignConverter;
+    converter
+        .to_visual_sign_payload_from_string(transaction_data, options)
+        .map(|r| r.payload)
+}
+
+// The parts of a transaction that are shown, whichever envelope version carried them. For a
+// fee bump these are the inner transaction's, with the outer fee payer alongside.
+struct Envelope<'a> {
+    source_account: String,
+    fee: i64,
+    seq_num: i64,
+    memo: &'a Memo,
+    time_bounds: Option<&'a TimeBounds>,
+    operations: &'a [Operation],
+    fee_source: Option<String>,
+}
+
+impl<'a> Envelope<'a> {
+    fn new(envelope: &'a TransactionEnvelope) -> Self {
+        match envelope {
+            TransactionEnvelope::TxV0(v0) => Self {
+                source_account: ed25519_strkey(&v0.tx.source_account_ed25519),
+                fee: i64::from(v0.tx.fee),
+                seq_num: v0.tx.seq_num.0,
+                memo: &v0.tx.memo,
+                time_bounds: v0.tx.time_bounds.as_ref(),
+                operations: v0.tx.operations.as_slice(),
+                fee_source: None,
+            },
+            TransactionEnvelope::Tx(v1) => Self::from_v1(&v1.tx),
+            TransactionEnvelope::TxFeeBump(fee_bump) => {
+                let FeeBumpTransactionInnerTx::Tx(inner) = &fee_bump.tx.inner_tx;
+                Self {
+                    fee: fee_bump.tx.fee,
+                    fee_source: Some(muxed_strkey(&fee_bump.tx.fee_source)),
+                    ..Self::from_v1(&inner.tx)
+                }
+            }
+        }
+    }
+
+    fn from_v1(tx: &'a stellar_xdr::curr::Transaction) -> Self {
+        let time_bounds = match &tx.cond {
+            Preconditions::None => None,
+            Preconditions::Time(bounds) => Some(bounds),
+            Preconditions::V2(v2) => v2.time_bounds.as_ref(),
+        };
+        Self {
+            source_account: muxed_strkey(&tx.source_account),
+            fee: i64::from(tx.fee),
+            seq_num: tx.seq_num.0,
+            memo: &tx.memo,
+            time_bounds,
+            operations: tx.operations.as_slice(),
+            fee_source: None,
+        }
+    }
+}
+
+fn convert_to_visual_sign_payload(
+    envelope: &TransactionEnvelope,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let tx = Envelope::new(envelope);
+
+    let mut fields: Vec<AnnotatedPayloadField> = vec![
+        create_text_field("Network", "Stellar")?,
+        create_address_field("Source Account", &tx.source_account, None, None, None, None)?,
+    ];
+    if let Some(fee_source) = &tx.fee_source {
+        fields.push(create_address_field(
+            "Fee Source",
+            fee_source,
+            None,
+            None,
+            None,
+            Some("Fee Bump"),
+        )?);
+    }
+    fields.push(create_amount_field(
+        "Max Fee",
+        &stroops_to_xlm_string(tx.fee),
+        "XLM",
+    )?);
+    fields.push(create_text_field(
+        "Sequence Number",
+        &tx.seq_num.to_string(),
+    )?);
+    if let Some(memo) = memo_label(tx.memo) {
+        fields.push(create_text_field("Memo", &memo)?);
+    }
+    if let Some(bounds) = tx.time_bounds {
+        if bounds.min_time.0 != 0 {
+            fields.push(create_text_field(
+                "Valid From",
+                &time_label(bounds.min_time.0),
+            )?);
+        }
+        if bounds.max_time.0 != 0 {
+            fields.push(create_text_field(
+                "Valid Until",
+                &time_label(bounds.max_time.0),
+            )?);
+        }
+    }
+
+    let mut warnings = Vec::new();
+    let mut operation_fields = Vec::new();
+    for (i, operation) in tx.operations.iter().enumerate() {
+        let rendered = operations::render_operation(i + 1, operation, &tx.source_account)?;
+        warnings.extend(rendered.warnings);
+        operation_fields.push(rendered.field);
+    }
+    // Account-control changes are listed ahead of the operations so they are read before
+    // the details of any single operation.
+    for warning in &warnings {
+        fields.push(create_text_field("Warning", warning)?);
+    }
+    fields.extend(operation_fields);
+
+    let title = options
+        .transaction_name
+        .unwrap_or_else(|| "Stellar Transaction".to_string());
+
+    Ok(SignablePayload::new(
+        0,
+        title,
+        None,
+        fields
+            .into_iter()
+            .map(|af| af.signable_payload_field)
+            .collect(),
+        "StellarTx".to_string(),
+    ))
+}
+
+fn memo_label(memo: &Memo) -> Option<String> {
+    match memo {
+        Memo::None => None,
+        Memo::Text(text) => Some(text.to_utf8_string_lossy()),
+        Memo::Id(id) => Some(format!("ID {id}")),
+        Memo::Hash(hash) => Some(format!("Hash {}", hex::encode(hash.0))),
+        Memo::Return(hash) => Some(format!("Return {}", hex::encode(hash.0))),
+    }
+}
+
+fn time_label(seconds: u64) -> String {
+    i64::try_from(seconds)
+        .ok()
+        .and_then(|s| s.checked_mul(1000))
+        .map(format_timestamp_ms)
+        .unwrap_or_else(|| format!("{seconds} (Unix time)"))
+}
+
+pub(crate) fn ed25519_strkey(key: &Uint256) -> String {
+    stellar_strkey::ed25519::PublicKey(key.0).to_string()
+}
+
+pub(crate) fn account_id_strkey(account: &AccountId) -> String {
+    let PublicKey::PublicKeyTypeEd25519(key) = &account.0;
+    ed25519_strkey(key)
+}
+
+pub(crate) fn muxed_strkey(account: &MuxedAccount) -> String {
+    match account {
+        MuxedAccount::Ed25519(key) => ed25519_strkey(key),
+        MuxedAccount::MuxedEd25519(muxed) => stellar_strkey::ed25519::MuxedAccount {
+            ed25519: muxed.ed25519.0,
+            id: muxed.id,
+        }
+        .to_string(),
+    }
+}
+
+pub(crate) fn contract_strkey(hash: &[u8; 32]) -> String {
+    stellar_strkey::Contract(*hash).to_string()
+}
+
+// Stroops to a decimal string with integer math, trimming trailing zeros so e.g.
+// 15_000_000 -> "1.5" and 100 -> "0.00001". Amounts are signed in XDR; the network
+// rejects negative ones, but they are rendered rather than hidden.
+pub(crate) fn stroops_to_xlm_string(stroops: i64) -> String {
+    let sign = if stroops < 0 { "-" } else { "" };
+    let magnitude = stroops.unsigned_abs();
+    let whole = magnitude / STROOPS_PER_XLM;
+    let frac = magnitude % STROOPS_PER_XLM;
+    if frac == 0 {
+        return format!("{sign}{whole}");
+    }
+    let frac_str = format!("{frac:07}");
+    format!("{sign}{whole}.{}", frac_str.trim_end_matches('0'))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use stellar_xdr::curr::{
+        AlphaNum4, Asset, AssetCode4, ChangeTrustAsset, ChangeTrustOp, FeeBumpTransaction,
+        FeeBumpTransactionEnvelope, FeeBumpTransactionExt, Hash, HostFunction, Int128Parts,
+        InvokeContractArgs, InvokeHostFunctionOp, MuxedAccountMed25519, OperationBody,
+        PathPaymentStrictSendOp, PaymentOp, ScAddress, ScSymbol, ScVal, SequenceNumber,
+        SetOptionsOp, Signer, SignerKey, TimePoint, TransactionExt, TransactionV1Envelope, VecM,
+        WriteXdr,
+    };
+    use visualsign::{SignablePayloadField, SignablePayloadFieldListLayout};
+
+    const ACCOUNT_1: &str = "GAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQDZ7H";
+    const ACCOUNT_2: &str = "GABAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEJXA";
+    const ACCOUNT_3: &str = "GABQGAYDAMBQGAYDAMBQGAYDAMBQGAYDAMBQGAYDAMBQGAYDAMBQHGPC";
+    const ACCOUNT_4: &str = "GACAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAJJHP";
+    const CONTRACT_1: &str = "CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526";
+    const CONTRACT_3: &str = "CABQGAYDAMBQGAYDAMBQGAYDAMBQGAYDAMBQGAYDAMBQGAYDAMBQGCK3";
+    const MUXED_1_ID_7: &str =
+        "MAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAAAAAAAAAAAA6TAI";
+
+    fn muxed(byte: u8) -> MuxedAccount {
+        MuxedAccount::Ed25519(Uint256([byte; 32]))
+    }
+
+    fn account_id(byte: u8) -> AccountId {
+        AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([byte; 32])))
+    }
+
+    fn usdc() -> Asset {
+        Asset::CreditAlphanum4(AlphaNum4 {
+            asset_code: AssetCode4(*b"USDC"),
+            issuer: account_id(3),
+        })
+    }
+
+    fn operation(body: OperationBody) -> Operation {
+        Operation {
+            source_account: None,
+            body,
+        }
+    }
+
+    fn v1_envelope(operations: Vec<Operation>) -> TransactionV1Envelope {
+        TransactionV1Envelope {
+            tx: stellar_xdr::curr::Transaction {
+                source_account: muxed(1),
+                fee: 200,
+                seq_num: SequenceNumber(42),
+                cond: Preconditions::Time(TimeBounds {
+                    min_time: TimePoint(0),
+                    max_time: TimePoint(1_700_000_000),
+                }),
+                memo: Memo::Text("invoice 7".try_into().unwrap()),
+                operations: operations.try_into().unwrap(),
+                ext: TransactionExt::V0,
+            },
+            signatures: VecM::default(),
+        }
+    }
+
+    fn to_payload(envelope: &TransactionEnvelope) -> SignablePayload {
+        let encoded = b64.encode(envelope.to_xdr(Limits::none()).unwrap());
+        transaction_string_to_visual_sign(&encoded, VisualSignOptions::default()).unwrap()
+    }
+
+    fn payload_for(operations: Vec<Operation>) -> SignablePayload {
+        to_payload(&TransactionEnvelope::Tx(v1_envelope(operations)))
+    }
+
+    fn find_field<'a>(
+        payload: &'a SignablePayload,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        payload.fields.iter().find(|f| field_label(f) == label)
+    }
+
+    fn find_detail<'a>(
+        layout: &'a SignablePayloadFieldListLayout,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        layout
+            .fields
+            .iter()
+            .map(|f| &f.signable_payload_field)
+            .find(|f| field_label(f) == label)
+    }
+
+    fn field_label(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { common, .. }
+            | SignablePayloadField::AmountV2 { common, .. }
+            | SignablePayloadField::AddressV2 { common, .. }
+            | SignablePayloadField::PreviewLayout { common, .. } => &common.label,
+            _ => "",
+        }
+    }
+
+    fn text_value(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { text_v2, .. } => &text_v2.text,
+            _ => panic!("expected TextV2"),
+        }
+    }
+
+    fn amount_value(field: &SignablePayloadField) -> (&str, &str) {
+        match field {
+            SignablePayloadField::AmountV2 { amount_v2, .. } => (
+                amount_v2.amount.as_str(),
+                amount_v2.abbreviation.as_deref().unwrap_or(""),
+            ),
+            _ => panic!("expected AmountV2"),
+        }
+    }
+
+    fn address_value(field: &SignablePayloadField) -> (&str, Option<&str>) {
+        match field {
+            SignablePayloadField::AddressV2 { address_v2, .. } => (
+                address_v2.address.as_str(),
+                address_v2.badge_text.as_deref(),
+            ),
+            _ => panic!("expected AddressV2"),
+        }
+    }
+
+    fn preview_subtitle(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => preview_layout
+                .subtitle
+                .as_ref()
+                .map(|t| t.text.as_str())
+                .unwrap_or(""),
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn preview_expanded(field: &SignablePayloadField) -> &SignablePayloadFieldListLayout {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                preview_layout.expanded.as_ref().unwrap()
+            }
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn warnings(payload: &SignablePayload) -> Vec<&str> {
+        payload
+            .fields
+            .iter()
+            .filter(|f| field_label(f) == "Warning")
+            .map(text_value)
+            .collect()
+    }
+
+    #[test]
+    fn payment_shows_header_and_destination() {
+        let payload = payload_for(vec![operation(OperationBody::Payment(PaymentOp {
+            destination: muxed(2),
+            asset: usdc(),
+            amount: 125_000_000,
+        }))]);
+
+        assert_eq!(payload.title, "Stellar Transaction");
+        assert_eq!(
+            address_value(find_field(&payload, "Source Account").unwrap()).0,
+            ACCOUNT_1
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Max Fee").unwrap()),
+            ("0.00002", "XLM")
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Sequence Number").unwrap()),
+            "42"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Memo").unwrap()),
+            "invoice 7"
+        );
+        assert!(find_field(&payload, "Valid Until").is_some());
+        assert!(find_field(&payload, "Valid From").is_none());
+        assert!(warnings(&payload).is_empty());
+
+        let op = find_field(&payload, "Operation 1").unwrap();
+        assert_eq!(
+            preview_subtitle(op),
+            format!("Send 12.5 USDC to {ACCOUNT_2}")
+        );
+        let details = preview_expanded(op);
+        assert_eq!(
+            address_value(find_detail(details, "Destination").unwrap()).0,
+            ACCOUNT_2
+        );
+        assert_eq!(
+            amount_value(find_detail(details, "Amount").unwrap()),
+            ("12.5", "USDC")
+        );
+        assert_eq!(
+            address_value(find_detail(details, "Asset Issuer").unwrap()).0,
+            ACCOUNT_3
+        );
+    }
+
+    #[test]
+    fn set_options_flags_signer_and_threshold_changes() {
+        let payload = payload_for(vec![operation(OperationBody::SetOptions(SetOptionsOp {
+            inflation_dest: None,
+            clear_flags: None,
+            set_flags: Some(0b1001),
+            master_weight: Some(0),
+            low_threshold: Some(1),
+            med_threshold: Some(2),
+            high_threshold: Some(2),
+            home_domain: None,
+            signer: Some(Signer {
+                key: SignerKey::Ed25519(Uint256([4; 32])),
+                weight: 2,
+            }),
+        }))]);
+
+        let warnings = warnings(&payload);
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].contains(ACCOUNT_4));
+        assert!(warnings[0].contains(ACCOUNT_1));
+        assert!(warnings.iter().any(|w| w.contains("can no longer sign")));
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.contains("low 1, medium 2, high 2"))
+        );
+
+        let op = find_field(&payload, "Operation 1").unwrap();
+        assert_eq!(
+            preview_subtitle(op),
+            "Changes signer, master weight, thresholds, flags"
+        );
+        assert_eq!(
+            text_value(find_detail(preview_expanded(op), "Set Flags").unwrap()),
+            "AUTH_REQUIRED, AUTH_CLAWBACK_ENABLED"
+        );
+    }
+
+    #[test]
+    fn invoke_contract_renders_arguments() {
+        let payload = payload_for(vec![operation(OperationBody::InvokeHostFunction(
+            InvokeHostFunctionOp {
+                host_function: HostFunction::InvokeContract(InvokeContractArgs {
+                    contract_address: ScAddress::Contract(Hash([1; 32])),
+                    function_name: ScSymbol("transfer".try_into().unwrap()),
+                    args: vec![
+                        ScVal::Address(ScAddress::Account(account_id(2))),
+                        ScVal::Address(ScAddress::Contract(Hash([3; 32]))),
+                        ScVal::I128(Int128Parts { hi: 0, lo: 1000 }),
+                    ]
+                    .try_into()
+                    .unwrap(),
+                }),
+                auth: VecM::default(),
+            },
+        ))]);
+
+        let op = find_field(&payload, "Operation 1").unwrap();
+        assert_eq!(
+            preview_subtitle(op),
+            format!("Call transfer on {CONTRACT_1}")
+        );
+        let details = preview_expanded(op);
+        assert_eq!(
+            address_value(find_detail(details, "Contract").unwrap()).0,
+            CONTRACT_1
+        );
+        assert_eq!(
+            text_value(find_detail(details, "Arg 1").unwrap()),
+            ACCOUNT_2
+        );
+        assert_eq!(
+            text_value(find_detail(details, "Arg 2").unwrap()),
+            CONTRACT_3
+        );
+        assert_eq!(text_value(find_detail(details, "Arg 3").unwrap()), "1000");
+    }
+
+    #[test]
+    fn path_payment_and_trustline_removal() {
+        let payload = payload_for(vec![
+            operation(OperationBody::PathPaymentStrictSend(
+                PathPaymentStrictSendOp {
+                    send_asset: Asset::Native,
+                    send_amount: 100_000_000,
+                    destination: muxed(2),
+                    dest_asset: usdc(),
+                    dest_min: 9_500_000,
+                    path: [*b"EURC", *b"USDT"]
+                        .map(|code| {
+                            Asset::CreditAlphanum4(AlphaNum4 {
+                                asset_code: AssetCode4(code),
+                                issuer: account_id(3),
+                            })
+                        })
+                        .to_vec()
+                        .try_into()
+                        .unwrap(),
+                },
+            )),
+            operation(OperationBody::ChangeTrust(ChangeTrustOp {
+                line: ChangeTrustAsset::CreditAlphanum4(AlphaNum4 {
+                    asset_code: AssetCode4(*b"USDC"),
+                    issuer: account_id(3),
+                }),
+                limit: 0,
+            })),
+        ]);
+
+        let swap = find_field(&payload, "Operation 1").unwrap();
+        assert_eq!(
+            preview_subtitle(swap),
+            format!("Send 10 XLM to {ACCOUNT_2}, who receives at least 0.95 USDC")
+        );
+        assert_eq!(
+            amount_value(find_detail(preview_expanded(swap), "Minimum Received").unwrap()),
+            ("0.95", "USDC")
+        );
+        assert_eq!(
+            text_value(find_detail(preview_expanded(swap), "Path").unwrap()),
+            "EURC -> USDT"
+        );
+
+        let trust = find_field(&payload, "Operation 2").unwrap();
+        assert_eq!(preview_subtitle(trust), "Remove trustline for USDC");
+    }
+
+    #[test]
+    fn fee_bump_shows_fee_source_and_outer_fee() {
+        let envelope = TransactionEnvelope::TxFeeBump(FeeBumpTransactionEnvelope {
+            tx: FeeBumpTransaction {
+                fee_source: MuxedAccount::MuxedEd25519(MuxedAccountMed25519 {
+                    id: 7,
+                    ed25519: Uint256([1; 32]),
+                }),
+                fee: 5_000,
+                inner_tx: FeeBumpTransactionInnerTx::Tx(v1_envelope(vec![operation(
+                    OperationBody::AccountMerge(muxed(2)),
+                )])),
+                ext: FeeBumpTransactionExt::V0,
+            },
+            signatures: VecM::default(),
+        });
+        let payload = to_payload(&envelope);
+
+        assert_eq!(
+            address_value(find_field(&payload, "Fee Source").unwrap()),
+            (MUXED_1_ID_7, Some("Fee Bump"))
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Max Fee").unwrap()),
+            ("0.0005", "XLM")
+        );
+        assert!(warnings(&payload)[0].contains("deleted"));
+    }
+
+    #[test]
+    fn rejects_trailing_bytes() {
+        let envelope = TransactionEnvelope::Tx(v1_envelope(vec![]));
+        let mut bytes = envelope.to_xdr(Limits::none()).unwrap();
+        bytes.extend_from_slice(&[0, 0, 0, 0]);
+        let result =
+            transaction_string_to_visual_sign(&b64.encode(bytes), VisualSignOptions::default());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn stroops_formatting() {
+        assert_eq!(stroops_to_xlm_string(0), "0");
+        assert_eq!(stroops_to_xlm_string(1), "0.0000001");
+        assert_eq!(stroops_to_xlm_string(15_000_000), "1.5");
+        assert_eq!(stroops_to_xlm_string(-100), "-0.00001");
+        assert_eq!(stroops_to_xlm_string(i64::MAX), "922337203685.4775807");
+    }
+}
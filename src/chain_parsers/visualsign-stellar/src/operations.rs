@@ -0,0 +1,469 @@
+// One preview per operation, plus warnings for operations that change who controls the
+// account. Those are returned separately so the payload can list them before any detail.
+
+use stellar_xdr::curr::{
+    AccountId, AlphaNum4, AlphaNum12, Asset, ChangeTrustAsset, ChangeTrustOp, ContractExecutable,
+    HostFunction, InvokeHostFunctionOp, LiquidityPoolParameters, Operation, OperationBody,
+    PathPaymentStrictReceiveOp, PathPaymentStrictSendOp, SetOptionsOp, SignerKey,
+};
+use visualsign::AnnotatedPayloadField;
+use visualsign::field_builders::{
+    create_address_field, create_amount_field, create_preview_layout, create_text_field,
+};
+use visualsign::vsptrait::VisualSignError;
+
+use crate::{account_id_strkey, ed25519_strkey, muxed_strkey, scval, stroops_to_xlm_string};
+
+// Account flags from the protocol's AccountFlags enum; only issuers set them.
+const ACCOUNT_FLAGS: [(u32, &str); 4] = [
+    (0x1, "AUTH_REQUIRED"),
+    (0x2, "AUTH_REVOCABLE"),
+    (0x4, "AUTH_IMMUTABLE"),
+    (0x8, "AUTH_CLAWBACK_ENABLED"),
+];
+
+pub(crate) struct RenderedOperation {
+    pub field: AnnotatedPayloadField,
+    pub warnings: Vec<String>,
+}
+
+/// Renders operation `n` (1-based). `tx_source` is the transaction's source account, which
+/// the operation acts on unless it names its own.
+pub(crate) fn render_operation(
+    n: usize,
+    operation: &Operation,
+    tx_source: &str,
+) -> Result<RenderedOperation, VisualSignError> {
+    let mut fields = vec![create_text_field("Type", type_label(&operation.body))?];
+    let account = match &operation.source_account {
+        Some(source) => {
+            let source = muxed_strkey(source);
+            fields.push(create_address_field(
+                "Source Account",
+                &source,
+                None,
+                None,
+                None,
+                None,
+            )?);
+            source
+        }
+        None => tx_source.to_string(),
+    };
+    let mut warnings = Vec::new();
+
+    let subtitle = match &operation.body {
+        OperationBody::CreateAccount(op) => {
+            let destination = account_id_strkey(&op.destination);
+            let balance = stroops_to_xlm_string(op.starting_balance);
+            fields.push(create_address_field(
+                "Destination",
+                &destination,
+                None,
+                None,
+                None,
+                None,
+            )?);
+            fields.push(create_amount_field("Starting Balance", &balance, "XLM")?);
+            format!("Create {destination} with {balance} XLM")
+        }
+        OperationBody::Payment(op) => {
+            let destination = muxed_strkey(&op.destination);
+            let amount = stroops_to_xlm_string(op.amount);
+            let code = asset_code(&op.asset);
+            fields.push(create_address_field(
+                "Destination",
+                &destination,
+                None,
+                None,
+                None,
+                None,
+            )?);
+            fields.push(create_amount_field("Amount", &amount, &code)?);
+            push_issuer(&mut fields, "Asset Issuer", &op.asset)?;
+            format!("Send {amount} {code} to {destination}")
+        }
+        OperationBody::PathPaymentStrictSend(op) => path_payment_strict_send(&mut fields, op)?,
+        OperationBody::PathPaymentStrictReceive(op) => {
+            path_payment_strict_receive(&mut fields, op)?
+        }
+        OperationBody::ChangeTrust(op) => change_trust(&mut fields, op)?,
+        OperationBody::SetOptions(op) => set_options(&mut fields, &mut warnings, op, &account)?,
+        OperationBody::AccountMerge(destination) => {
+            let destination = muxed_strkey(destination);
+            fields.push(create_address_field(
+                "Destination",
+                &destination,
+                None,
+                None,
+                None,
+                None,
+            )?);
+            warnings.push(format!(
+                "Merges {account} into {destination}: the account is deleted and its entire \
+                 XLM balance is sent to the destination"
+            ));
+            format!("Merge account into {destination}")
+        }
+        OperationBody::InvokeHostFunction(op) => invoke_host_function(&mut fields, op)?,
+        other => format!("{} operation", other.name()),
+    };
+
+    Ok(RenderedOperation {
+        field: create_preview_layout(&format!("Operation {n}"), subtitle, fields),
+        warnings,
+    })
+}
+
+fn type_label(body: &OperationBody) -> &'static str {
+    match body {
+        OperationBody::CreateAccount(_) => "Create Account",
+        OperationBody::Payment(_) => "Payment",
+        OperationBody::PathPaymentStrictSend(_) => "Path Payment (Strict Send)",
+        OperationBody::PathPaymentStrictReceive(_) => "Path Payment (Strict Receive)",
+        OperationBody::ChangeTrust(_) => "Change Trust",
+        OperationBody::SetOptions(_) => "Set Options",
+        OperationBody::AccountMerge(_) => "Account Merge",
+        OperationBody::InvokeHostFunction(_) => "Invoke Host Function",
+        other => other.name(),
+    }
+}
+
+fn path_payment_strict_send(
+    fields: &mut Vec<AnnotatedPayloadField>,
+    op: &PathPaymentStrictSendOp,
+) -> Result<String, VisualSignError> {
+    let destination = muxed_strkey(&op.destination);
+    let send_amount = stroops_to_xlm_string(op.send_amount);
+    let send_code = asset_code(&op.send_asset);
+    let dest_min = stroops_to_xlm_string(op.dest_min);
+    let dest_code = asset_code(&op.dest_asset);
+
+    fields.push(create_address_field(
+        "Destination",
+        &destination,
+        None,
+        None,
+        None,
+        None,
+    )?);
+    fields.push(create_amount_field(
+        "Send Amount",
+        &send_amount,
+        &send_code,
+    )?);
+    push_issuer(fields, "Send Asset Issuer", &op.send_asset)?;
+    fields.push(create_amount_field(
+        "Minimum Received",
+        &dest_min,
+        &dest_code,
+    )?);
+    push_issuer(fields, "Receive Asset Issuer", &op.dest_asset)?;
+    push_path(fields, op.path.as_slice())?;
+
+    Ok(format!(
+        "Send {send_amount} {send_code} to {destination}, who receives at least {dest_min} \
+         {dest_code}"
+    ))
+}
+
+fn path_payment_strict_receive(
+    fields: &mut Vec<AnnotatedPayloadField>,
+    op: &PathPaymentStrictReceiveOp,
+) -> Result<String, VisualSignError> {
+    let destination = muxed_strkey(&op.destination);
+    let send_max = stroops_to_xlm_string(op.send_max);
+    let send_code = asset_code(&op.send_asset);
+    let dest_amount = stroops_to_xlm_string(op.dest_amount);
+    let dest_code = asset_code(&op.dest_asset);
+
+    fields.push(create_address_field(
+        "Destination",
+        &destination,
+        None,
+        None,
+        None,
+        None,
+    )?);
+    fields.push(create_amount_field("Maximum Sent", &send_max, &send_code)?);
+    push_issuer(fields, "Send Asset Issuer", &op.send_asset)?;
+    fields.push(create_amount_field(
+        "Amount Received",
+        &dest_amount,
+        &dest_code,
+    )?);
+    push_issuer(fields, "Receive Asset Issuer", &op.dest_asset)?;
+    push_path(fields, op.path.as_slice())?;
+
+    Ok(format!(
+        "Send at most {send_max} {send_code} so {destination} receives {dest_amount} \
+         {dest_code}"
+    ))
+}
+
+// A limit of zero deletes the trustline; i64::MAX is what wallets send for "no limit".
+fn change_trust(
+    fields: &mut Vec<AnnotatedPayloadField>,
+    op: &ChangeTrustOp,
+) -> Result<String, VisualSignError> {
+    let (code, issuer) = match &op.line {
+        ChangeTrustAsset::Native => ("XLM".to_string(), None),
+        ChangeTrustAsset::CreditAlphanum4(AlphaNum4 { asset_code, issuer }) => {
+            (code_label(&asset_code.0), Some(issuer))
+        }
+        ChangeTrustAsset::CreditAlphanum12(AlphaNum12 { asset_code, issuer }) => {
+            (code_label(&asset_code.0), Some(issuer))
+        }
+        ChangeTrustAsset::PoolShare(LiquidityPoolParameters::LiquidityPoolConstantProduct(
+            pool,
+        )) => (
+            format!(
+                "{}/{} pool share",
+                asset_code(&pool.asset_a),
+                asset_code(&pool.asset_b)
+            ),
+            None,
+        ),
+    };
+    fields.push(create_text_field("Asset", &code)?);
+    if let Some(issuer) = issuer {
+        fields.push(issuer_field("Asset Issuer", issuer)?);
+    }
+
+    Ok(match op.limit {
+        0 => {
+            fields.push(create_text_field("Limit", "0 (removes the trustline)")?);
+            format!("Remove trustline for {code}")
+        }
+        i64::MAX => {
+            fields.push(create_text_field("Limit", "Unlimited")?);
+            format!("Trust {code}")
+        }
+        limit => {
+            let limit = stroops_to_xlm_string(limit);
+            fields.push(create_amount_field("Limit", &limit, &code)?);
+            format!("Trust {code} up to {limit}")
+        }
+    })
+}
+
+// Signers, the master key weight and the thresholds decide who can sign for the account,
+// so each change to them is raised as a warning as well as listed in the preview.
+fn set_options(
+    fields: &mut Vec<AnnotatedPayloadField>,
+    warnings: &mut Vec<String>,
+    op: &SetOptionsOp,
+    account: &str,
+) -> Result<String, VisualSignError> {
+    let mut changes = Vec::new();
+
+    if let Some(signer) = &op.signer {
+        let key = signer_key_label(&signer.key);
+        if signer.weight == 0 {
+            fields.push(create_text_field("Remove Signer", &key)?);
+            warnings.push(format!("Removes signer {key} from {account}"));
+        } else {
+            fields.push(create_text_field("Signer", &key)?);
+            fields.push(create_text_field(
+                "Signer Weight",
+                &signer.weight.to_string(),
+            )?);
+            warnings.push(format!(
+                "Sets signer {key} on {account} to weight {}: that key can sign for the account",
+                signer.weight
+            ));
+        }
+        changes.push("signer");
+    }
+    if let Some(weight) = op.master_weight {
+        fields.push(create_text_field("Master Weight", &weight.to_string())?);
+        warnings.push(if weight == 0 {
+            format!(
+                "Sets the master key weight of {account} to 0: the account's own key can no \
+                 longer sign"
+            )
+        } else {
+            format!("Changes the master key weight of {account} to {weight}")
+        });
+        changes.push("master weight");
+    }
+    let thresholds = [op.low_threshold, op.med_threshold, op.high_threshold];
+    if thresholds.iter().any(Option::is_some) {
+        let [low, medium, high] = thresholds.map(|t| {
+            t.map(|t| t.to_string())
+                .unwrap_or_else(|| "unchanged".to_string())
+        });
+        let label = format!("low {low}, medium {medium}, high {high}");
+        fields.push(create_text_field("Thresholds", &label)?);
+        warnings.push(format!(
+            "Changes the signing thresholds of {account} to {label}"
+        ));
+        changes.push("thresholds");
+    }
+    if let Some(flags) = op.set_flags.filter(|f| *f != 0) {
+        fields.push(create_text_field("Set Flags", &flags_label(flags))?);
+    }
+    if let Some(flags) = op.clear_flags.filter(|f| *f != 0) {
+        fields.push(create_text_field("Clear Flags", &flags_label(flags))?);
+    }
+    if op.set_flags.is_some_and(|f| f != 0) || op.clear_flags.is_some_and(|f| f != 0) {
+        changes.push("flags");
+    }
+    if let Some(domain) = &op.home_domain {
+        fields.push(create_text_field(
+            "Home Domain",
+            &domain.to_utf8_string_lossy(),
+        )?);
+        changes.push("home domain");
+    }
+    if let Some(destination) = &op.inflation_dest {
+        fields.push(issuer_field("Inflation Destination", destination)?);
+        changes.push("inflation destination");
+    }
+
+    Ok(if changes.is_empty() {
+        "No changes".to_string()
+    } else {
+        format!("Changes {}", changes.join(", "))
+    })
+}
+
+fn invoke_host_function(
+    fields: &mut Vec<AnnotatedPayloadField>,
+    op: &InvokeHostFunctionOp,
+) -> Result<String, VisualSignError> {
+    let subtitle = match &op.host_function {
+        HostFunction::InvokeContract(call) => {
+            let contract = scval::address_strkey(&call.contract_address);
+            let function = call.function_name.0.to_utf8_string_lossy();
+            fields.push(create_address_field(
+                "Contract", &contract, None, None, None, None,
+            )?);
+            fields.push(create_text_field("Function", &function)?);
+            for (i, arg) in call.args.iter().enumerate() {
+                fields.push(create_text_field(
+                    &format!("Arg {}", i + 1),
+                    &scval::render(arg),
+                )?);
+            }
+            format!("Call {function} on {contract}")
+        }
+        HostFunction::CreateContract(args) => {
+            fields.push(create_text_field(
+                "Executable",
+                &executable_label(&args.executable),
+            )?);
+            "Create a contract".to_string()
+        }
+        HostFunction::CreateContractV2(args) => {
+            fields.push(create_text_field(
+                "Executable",
+                &executable_label(&args.executable),
+            )?);
+            for (i, arg) in args.constructor_args.iter().enumerate() {
+                fields.push(create_text_field(
+                    &format!("Constructor Arg {}", i + 1),
+                    &scval::render(arg),
+                )?);
+            }
+            "Create a contract".to_string()
+        }
+        HostFunction::UploadContractWasm(code) => {
+            fields.push(create_text_field(
+                "Code Size",
+                &format!("{} bytes", code.len()),
+            )?);
+            "Upload contract code".to_string()
+        }
+    };
+    if !op.auth.is_empty() {
+        fields.push(create_text_field(
+            "Authorizations",
+            &format!("{} authorization entries", op.auth.len()),
+        )?);
+    }
+    Ok(subtitle)
+}
+
+fn executable_label(executable: &ContractExecutable) -> String {
+    match executable {
+        ContractExecutable::Wasm(hash) => format!("Wasm {}", hex::encode(hash.0)),
+        ContractExecutable::StellarAsset => "Stellar Asset Contract".to_string(),
+    }
+}
+
+fn asset_code(asset: &Asset) -> String {
+    match asset {
+        Asset::Native => "XLM".to_string(),
+        Asset::CreditAlphanum4(a) => code_label(&a.asset_code.0),
+        Asset::CreditAlphanum12(a) => code_label(&a.asset_code.0),
+    }
+}
+
+// Asset codes are NUL-padded to 4 or 12 bytes.
+fn code_label(code: &[u8]) -> String {
+    let end = code.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+    String::from_utf8_lossy(code.get(..end).unwrap_or_default()).into_owned()
+}
+
+fn push_issuer(
+    fields: &mut Vec<AnnotatedPayloadField>,
+    label: &str,
+    asset: &Asset,
+) -> Result<(), VisualSignError> {
+    match asset {
+        Asset::Native => {}
+        Asset::CreditAlphanum4(a) => fields.push(issuer_field(label, &a.issuer)?),
+        Asset::CreditAlphanum12(a) => fields.push(issuer_field(label, &a.issuer)?),
+    }
+    Ok(())
+}
+
+fn issuer_field(
+    label: &str,
+    account: &AccountId,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    create_address_field(label, &account_id_strkey(account), None, None, None, None)
+}
+
+fn push_path(
+    fields: &mut Vec<AnnotatedPayloadField>,
+    path: &[Asset],
+) -> Result<(), VisualSignError> {
+    if !path.is_empty() {
+        let hops: Vec<String> = path.iter().map(asset_code).collect();
+        fields.push(create_text_field("Path", &hops.join(" -> "))?);
+    }
+    Ok(())
+}
+
+fn signer_key_label(key: &SignerKey) -> String {
+    match key {
+        SignerKey::Ed25519(key) => ed25519_strkey(key),
+        SignerKey::PreAuthTx(hash) => format!(
+            "{} (pre-authorized transaction)",
+            stellar_strkey::PreAuthTx(hash.0)
+        ),
+        SignerKey::HashX(hash) => format!("{} (hash preimage)", stellar_strkey::HashX(hash.0)),
+        SignerKey::Ed25519SignedPayload(signed) => format!(
+            "{} (signed payload)",
+            stellar_strkey::ed25519::SignedPayload {
+                ed25519: signed.ed25519.0,
+                payload: signed.payload.to_vec(),
+            }
+        ),
+    }
+}
+
+fn flags_label(flags: u32) -> String {
+    let mut names: Vec<String> = ACCOUNT_FLAGS
+        .iter()
+        .filter(|(bit, _)| flags & bit != 0)
+        .map(|(_, name)| (*name).to_string())
+        .collect();
+    let known = ACCOUNT_FLAGS.iter().fold(0, |acc, (bit, _)| acc | bit);
+    if flags & !known != 0 {
+        names.push(format!("unknown 0x{:x}", flags & !known));
+    }
+    names.join(", ")
+}
@@ -0,0 +1,138 @@
+// Generic rendering of Soroban `ScVal` arguments. Contracts have no on-chain ABI the
+// parser can consult, so arguments are shown by their XDR type: integers in decimal,
+// addresses as strkeys, symbols bare, strings quoted, and vectors/maps in brackets.
+
+use stellar_xdr::curr::{ScAddress, ScVal};
+use visualsign::time_fmt::{format_duration_secs, format_timestamp_secs};
+
+use crate::{account_id_strkey, contract_strkey};
+
+// Nesting beyond this is summarized; the XDR reader already bounds depth, this keeps a
+// single argument readable.
+const MAX_RENDER_DEPTH: usize = 4;
+
+/// Renders `value` as a single line of text.
+pub fn render(value: &ScVal) -> String {
+    render_at(value, 0)
+}
+
+fn render_at(value: &ScVal, depth: usize) -> String {
+    match value {
+        ScVal::Bool(b) => b.to_string(),
+        ScVal::Void => "void".to_string(),
+        ScVal::U32(n) => n.to_string(),
+        ScVal::I32(n) => n.to_string(),
+        ScVal::U64(n) => n.to_string(),
+        ScVal::I64(n) => n.to_string(),
+        ScVal::Timepoint(t) => match i64::try_from(t.0).ok().and_then(format_timestamp_secs) {
+            Some(time) => format!("{time} (timepoint {})", t.0),
+            None => format!("timepoint {}", t.0),
+        },
+        ScVal::Duration(d) => format!("{} ({} seconds)", format_duration_secs(d.0), d.0),
+        ScVal::U128(parts) => ((u128::from(parts.hi) << 64) | u128::from(parts.lo)).to_string(),
+        ScVal::I128(parts) => ((i128::from(parts.hi) << 64) | i128::from(parts.lo)).to_string(),
+        ScVal::U256(parts) => format!(
+            "0x{:016x}{:016x}{:016x}{:016x}",
+            parts.hi_hi, parts.hi_lo, parts.lo_hi, parts.lo_lo
+        ),
+        ScVal::I256(parts) => format!(
+            "0x{:016x}{:016x}{:016x}{:016x}",
+            parts.hi_hi as u64, parts.hi_lo, parts.lo_hi, parts.lo_lo
+        ),
+        ScVal::Bytes(bytes) => format!("0x{}", hex::encode(bytes.0.as_slice())),
+        ScVal::String(s) => format!("{:?}", String::from_utf8_lossy(s.0.as_slice())),
+        ScVal::Symbol(s) => String::from_utf8_lossy(s.0.as_slice()).into_owned(),
+        ScVal::Address(address) => address_strkey(address),
+        ScVal::Vec(items) => {
+            let items = items.as_ref().map(|v| v.0.as_slice()).unwrap_or_default();
+            if depth >= MAX_RENDER_DEPTH {
+                return format!("[{} items]", items.len());
+            }
+            let rendered: Vec<String> = items.iter().map(|v| render_at(v, depth + 1)).collect();
+            format!("[{}]", rendered.join(", "))
+        }
+        ScVal::Map(entries) => {
+            let entries = entries.as_ref().map(|m| m.0.as_slice()).unwrap_or_default();
+            if depth >= MAX_RENDER_DEPTH {
+                return format!("{{{} entries}}", entries.len());
+            }
+            let rendered: Vec<String> = entries
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{}: {}",
+                        render_at(&entry.key, depth + 1),
+                        render_at(&entry.val, depth + 1)
+                    )
+                })
+                .collect();
+            format!("{{{}}}", rendered.join(", "))
+        }
+        // Errors, ledger keys and contract instances never appear as call arguments in
+        // practice; their XDR type name is enough to flag them.
+        other => format!("<{}>", other.name()),
+    }
+}
+
+pub fn address_strkey(address: &ScAddress) -> String {
+    match address {
+        ScAddress::Account(account) => account_id_strkey(account),
+        ScAddress::Contract(hash) => contract_strkey(&hash.0),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use stellar_xdr::curr::{Duration, Int128Parts, ScMap, ScMapEntry, ScSymbol, ScVec, TimePoint};
+
+    fn symbol(s: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(s.try_into().unwrap()))
+    }
+
+    #[test]
+    fn renders_nested_values() {
+        let negative = ScVal::I128(Int128Parts {
+            hi: -1,
+            lo: u64::MAX,
+        });
+        let map = ScVal::Map(Some(ScMap(
+            vec![ScMapEntry {
+                key: symbol("amount"),
+                val: ScVal::U64(5),
+            }]
+            .try_into()
+            .unwrap(),
+        )));
+        let value = ScVal::Vec(Some(ScVec(
+            vec![negative, map, ScVal::Bool(true)].try_into().unwrap(),
+        )));
+        assert_eq!(render(&value), "[-1, {amount: 5}, true]");
+    }
+
+    #[test]
+    fn renders_timepoints_and_durations() {
+        assert_eq!(
+            render(&ScVal::Timepoint(TimePoint(1_700_000_000))),
+            "2023-11-14 22:13:20 UTC (timepoint 1700000000)"
+        );
+        assert_eq!(
+            render(&ScVal::Timepoint(TimePoint(u64::MAX))),
+            format!("timepoint {}", u64::MAX)
+        );
+        assert_eq!(
+            render(&ScVal::Duration(Duration(90_000))),
+            "1 day 1 hour (90000 seconds)"
+        );
+    }
+
+    #[test]
+    fn deep_nesting_is_summarized() {
+        let mut value = ScVal::U32(1);
+        for _ in 0..6 {
+            value = ScVal::Vec(Some(ScVec(vec![value].try_into().unwrap())));
+        }
+        assert_eq!(render(&value), "[[[[[1 items]]]]]");
+    }
+}
@@ -11,12 +11,21 @@
 //!   by the first visualizer that reports it can handle a command.
 //! - If a visualizer returns an error for the selected command, the entire decode flow
 //!   for that command fails and the error is propagated.
-
+//! - A `MoveCall` that no visualizer claims is rendered by the generic fallback in
+//!   `fallback`, so unknown packages still show their call target and arguments.
+//! - `Publish` and `Upgrade` are rendered by `package` from the raw transaction,
+//!   since the JSON-RPC view drops their module bytecode.
+
+use crate::core::fallback::visualize_move_call;
+use crate::core::flow::summarize_flow;
+use crate::core::package::visualize_package_command;
 use crate::core::{CommandVisualizer, VisualizerContext, visualize_with_any};
 
+use generated::parser::SuiMetadata;
 use sui_json_rpc_types::{
-    SuiTransactionBlockData, SuiTransactionBlockDataAPI, SuiTransactionBlockKind,
+    SuiCommand, SuiTransactionBlockData, SuiTransactionBlockDataAPI, SuiTransactionBlockKind,
 };
+use sui_types::transaction::TransactionData;
 
 use visualsign::AnnotatedPayloadField;
 use visualsign::errors::VisualSignError;
@@ -29,7 +38,10 @@ include!(concat!(env!("OUT_DIR"), "/generated_visualizers.rs"));
 /// - Returns an empty vector for non-programmable transactions.
 /// - Errors if any chosen visualizer fails while rendering a command.
 pub fn decode_commands(
+    transaction: &TransactionData,
     block_data: &SuiTransactionBlockData,
+    metadata: Option<&SuiMetadata>,
+    strict_registry: bool,
 ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
     let (tx_commands, tx_inputs) = match block_data.transaction() {
         SuiTransactionBlockKind::ProgrammableTransaction(tx) => (&tx.commands, &tx.inputs),
@@ -47,17 +59,57 @@ pub fn decode_commands(
     tx_commands
         .iter()
         .enumerate()
-        .filter_map(|(command_index, _)| {
-            visualize_with_any(
-                &visualizers_refs,
-                &VisualizerContext::new(block_data.sender(), command_index, tx_commands, tx_inputs),
-            )
+        .filter_map(|(command_index, command)| {
+            let context =
+                VisualizerContext::new(block_data.sender(), command_index, tx_commands, tx_inputs)
+                    .with_metadata(metadata)
+                    .with_strict_registry(strict_registry);
+            match visualize_with_any(&visualizers_refs, &context) {
+                Some(res) => Some(res.map(|viz_result| viz_result.field)),
+                None => match command {
+                    SuiCommand::MoveCall(pwc) => {
+                        Some(visualize_move_call(&context, pwc).map(|field| vec![field]))
+                    }
+                    SuiCommand::Publish(_) | SuiCommand::Upgrade(..) => Some(
+                        visualize_package_command(&context, transaction)
+                            .map(|field| field.into_iter().collect()),
+                    ),
+                    _ => None,
+                },
+            }
         })
-        .map(|res| res.map(|viz_result| viz_result.field))
         .collect::<Result<Vec<Vec<AnnotatedPayloadField>>, _>>()
         .map(|nested| nested.into_iter().flatten().collect())
 }
 
+/// Summarizes how command outputs feed later commands, if any do.
+pub fn decode_flow(
+    block_data: &SuiTransactionBlockData,
+    metadata: Option<&SuiMetadata>,
+    strict_registry: bool,
+) -> Result<Option<AnnotatedPayloadField>, VisualSignError> {
+    let (tx_commands, tx_inputs) = match block_data.transaction() {
+        SuiTransactionBlockKind::ProgrammableTransaction(tx) => (&tx.commands, &tx.inputs),
+        _ => return Ok(None),
+    };
+
+    let visualizers: Vec<Box<dyn CommandVisualizer>> = available_visualizers();
+    let visualizers_refs: Vec<&dyn CommandVisualizer> = visualizers
+        .iter()
+        .map(std::convert::AsRef::as_ref)
+        .collect::<Vec<_>>();
+
+    let contexts: Vec<VisualizerContext> = (0..tx_commands.len())
+        .map(|command_index| {
+            VisualizerContext::new(block_data.sender(), command_index, tx_commands, tx_inputs)
+                .with_metadata(metadata)
+                .with_strict_registry(strict_registry)
+        })
+        .collect();
+
+    summarize_flow(&visualizers_refs, &contexts)
+}
+
 pub fn decode_transfers(
     block_data: &SuiTransactionBlockData,
 ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
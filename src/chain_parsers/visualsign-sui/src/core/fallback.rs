@@ -0,0 +1,191 @@
+//! Generic rendering for `MoveCall` commands that no preset claims.
+//!
+//! The parser has no module ABIs, so pure arguments are decoded by their BCS
+//! shape: the byte length picks the most likely primitive, and length-prefixed
+//! byte strings are shown as text when printable. Every guess is labelled with
+//! the type it was read as so the signer can tell it apart from a real decode.
+
+use sui_json_rpc_types::{SuiArgument, SuiCallArg, SuiProgrammableMoveCall};
+use sui_types::base_types::SuiAddress;
+
+use visualsign::{
+    AnnotatedPayloadField,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_preview_layout, create_text_field},
+};
+
+use crate::core::VisualizerContext;
+use crate::core::transaction::create_object_arg_field;
+use crate::utils::truncate_address;
+
+/// Renders a `MoveCall` as `package::module::function` with its type arguments
+/// and a best-effort reading of each argument.
+pub fn visualize_move_call(
+    context: &VisualizerContext,
+    pwc: &SuiProgrammableMoveCall,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let package = pwc.package.to_hex_literal();
+
+    let mut fields = vec![
+        create_address_field("Package", &package, None, None, None, None)?,
+        create_text_field("Module", &pwc.module)?,
+        create_text_field("Function", &pwc.function)?,
+    ];
+    for (index, type_argument) in pwc.type_arguments.iter().enumerate() {
+        fields.push(create_text_field(
+            &format!("Type Argument {index}"),
+            type_argument,
+        )?);
+    }
+    for (index, argument) in pwc.arguments.iter().enumerate() {
+        fields.push(argument_field(context, index, *argument)?);
+    }
+
+    Ok(create_preview_layout(
+        &format!(
+            "Move Call: {}::{}::{}",
+            truncate_address(&package),
+            pwc.module,
+            pwc.function
+        ),
+        format!("{package}::{}::{}", pwc.module, pwc.function),
+        fields,
+    ))
+}
+
+fn argument_field(
+    context: &VisualizerContext,
+    index: usize,
+    argument: SuiArgument,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let label = format!("Argument {index}");
+    let input = match argument {
+        SuiArgument::GasCoin => return create_text_field(&label, "Gas coin"),
+        SuiArgument::Result(command) => {
+            return create_text_field(&label, &format!("Result of command {command}"));
+        }
+        SuiArgument::NestedResult(command, result) => {
+            return create_text_field(&label, &format!("Result {result} of command {command}"));
+        }
+        SuiArgument::Input(input) => context.inputs().get(usize::from(input)),
+    };
+
+    match input {
+        Some(SuiCallArg::Object(object)) => create_object_arg_field(&label, object),
+        Some(SuiCallArg::Pure(value)) => {
+            let json = value.value().to_json_value();
+            let text = match &json {
+                serde_json::Value::String(text) => text.clone(),
+                _ if value.value_type().is_none() => {
+                    serde_json::from_value::<Vec<u8>>(json.clone())
+                        .map_or_else(|_| json.to_string(), |bytes| describe_pure_bytes(&bytes))
+                }
+                _ => json.to_string(),
+            };
+            create_text_field(&label, &text)
+        }
+        None => create_text_field(&label, "Missing input"),
+    }
+}
+
+/// Guesses the Move type of an untyped pure argument from its BCS encoding.
+fn describe_pure_bytes(bytes: &[u8]) -> String {
+    let byte_vector = decode_byte_vector(bytes);
+    if let Some(text) = byte_vector
+        .and_then(|payload| std::str::from_utf8(payload).ok())
+        .filter(|text| text.chars().all(|c| c.is_ascii_graphic() || c == ' '))
+    {
+        return format!("\"{text}\" (string)");
+    }
+
+    match bytes {
+        [byte @ (0 | 1)] => format!("{} (bool or u8)", *byte == 1),
+        [byte] => format!("{byte} (u8)"),
+        &[a, b] => format!("{} (u16)", u16::from_le_bytes([a, b])),
+        &[a, b, c, d] => format!("{} (u32)", u32::from_le_bytes([a, b, c, d])),
+        _ => {
+            if let Ok(le) = <[u8; 8]>::try_from(bytes) {
+                format!("{} (u64)", u64::from_le_bytes(le))
+            } else if let Ok(le) = <[u8; 16]>::try_from(bytes) {
+                format!("{} (u128)", u128::from_le_bytes(le))
+            } else if let Ok(address) = SuiAddress::from_bytes(bytes) {
+                format!("{address} (address)")
+            } else if let Some(payload) = byte_vector {
+                format!("0x{} (vector<u8>)", hex_encode(payload))
+            } else {
+                format!("0x{} (raw bytes)", hex_encode(bytes))
+            }
+        }
+    }
+}
+
+/// Reads a `vector<u8>`, which BCS encodes as a ULEB128 length followed by the
+/// bytes. Only an exact length match is accepted.
+fn decode_byte_vector(bytes: &[u8]) -> Option<&[u8]> {
+    let (length, prefix_len) = read_uleb128(bytes)?;
+    let payload = bytes.get(prefix_len..)?;
+    (!payload.is_empty() && usize::try_from(length).ok()? == payload.len()).then_some(payload)
+}
+
+fn read_uleb128(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value: u64 = 0;
+    for (index, byte) in bytes.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+    None
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+
+    #[test]
+    fn test_describe_pure_bytes() {
+        assert_eq!(describe_pure_bytes(&[1]), "true (bool or u8)");
+        assert_eq!(describe_pure_bytes(&[200]), "200 (u8)");
+        assert_eq!(
+            describe_pure_bytes(&1_000_000_u64.to_le_bytes()),
+            "1000000 (u64)"
+        );
+        assert_eq!(describe_pure_bytes(&7_u128.to_le_bytes()), "7 (u128)");
+        assert_eq!(
+            describe_pure_bytes(&[0x11; 32]),
+            "0x1111111111111111111111111111111111111111111111111111111111111111 (address)"
+        );
+        assert_eq!(describe_pure_bytes(b"\x05hello"), "\"hello\" (string)");
+        assert_eq!(
+            describe_pure_bytes(&[5, 0xde, 0xad, 0xbe, 0xef, 0x00]),
+            "0xdeadbeef00 (vector<u8>)"
+        );
+        assert_eq!(describe_pure_bytes(&[9, 9, 9]), "0x090909 (raw bytes)");
+    }
+
+    #[test]
+    fn test_unknown_move_call_fallback() {
+        // 0x4242..::game::play<0x2::sui::SUI>(object, 500u64, "hi", Gas)
+        let test_data = "AAADAQBvb29vb29vb29vb29vb29vb29vb29vb29vb29vb29vbwEAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAAj0AQAAAAAAAAADAmhpAQBCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQgRnYW1lBHBsYXkBBwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACA3N1aQNTVUkABAEAAAEBAAECAACrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwHNzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NzQEAAAAAAAAAICIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6voAwAAAAAAAICWmAAAAAAAAA==";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Move Call: 0x4242...4242::game::play");
+        assert_has_field_with_value(&payload, "Function", "play");
+        assert_has_field(&payload, "Type Argument 0");
+        assert_has_field_with_value(
+            &payload,
+            "Argument 0",
+            "0x6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f6f",
+        );
+        assert_has_field_with_value(&payload, "Argument 1", "500 (u64)");
+        assert_has_field_with_value(&payload, "Argument 2", "\"hi\" (string)");
+        assert_has_field_with_value(&payload, "Argument 3", "Gas coin");
+    }
+}
@@ -0,0 +1,191 @@
+//! High-level summary of how values move between the commands of a PTB.
+//!
+//! Commands are linked whenever one consumes a `Result`/`NestedResult` of
+//! another. Each connected group is rendered as its steps in command order,
+//! e.g. "Split 5 SUI -> Swap on Cetus -> Transfer output to 0xabcd...1234", so a
+//! reviewer can follow a multi-command transaction without reading every field.
+
+use sui_json_rpc_types::{SuiArgument, SuiCommand, SuiProgrammableMoveCall};
+
+use visualsign::{
+    AnnotatedPayloadField, errors::VisualSignError, field_builders::create_text_field,
+};
+
+use crate::core::{CommandVisualizer, VisualizerContext, VisualizerKind};
+use crate::utils::{decode_number, format_sui, get_address_value, truncate_address};
+
+/// Builds the "Transaction Flow" field.
+///
+/// Returns `None` when no command consumes another command's output, since
+/// the individual command fields already tell the whole story.
+pub fn summarize_flow(
+    visualizers: &[&dyn CommandVisualizer],
+    contexts: &[VisualizerContext],
+) -> Result<Option<AnnotatedPayloadField>, VisualSignError> {
+    let Some(first) = contexts.first() else {
+        return Ok(None);
+    };
+    let commands = first.commands();
+
+    let mut groups: Vec<usize> = (0..commands.len()).collect();
+    let mut linked = false;
+    for (index, command) in commands.iter().enumerate() {
+        for producer in command_arguments(command)
+            .into_iter()
+            .filter_map(result_command)
+            .filter(|producer| *producer < index)
+        {
+            let (a, b) = (
+                find_group(&mut groups, producer),
+                find_group(&mut groups, index),
+            );
+            groups[a.max(b)] = a.min(b);
+            linked = true;
+        }
+    }
+    if !linked {
+        return Ok(None);
+    }
+
+    let mut chains: Vec<(usize, Vec<String>)> = Vec::new();
+    for context in contexts {
+        let group = find_group(&mut groups, context.command_index());
+        let step = describe_step(visualizers, context);
+        match chains.iter_mut().find(|(root, _)| *root == group) {
+            Some((_, steps)) => steps.push(step),
+            None => chains.push((group, vec![step])),
+        }
+    }
+
+    let summary = chains
+        .into_iter()
+        .map(|(_, steps)| steps.join(" -> "))
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    create_text_field("Transaction Flow", &summary).map(Some)
+}
+
+fn find_group(groups: &mut [usize], mut index: usize) -> usize {
+    while groups[index] != index {
+        groups[index] = groups[groups[index]];
+        index = groups[index];
+    }
+    index
+}
+
+fn result_command(argument: SuiArgument) -> Option<usize> {
+    match argument {
+        SuiArgument::Result(command) | SuiArgument::NestedResult(command, _) => {
+            Some(usize::from(command))
+        }
+        SuiArgument::GasCoin | SuiArgument::Input(_) => None,
+    }
+}
+
+fn command_arguments(command: &SuiCommand) -> Vec<SuiArgument> {
+    match command {
+        SuiCommand::MoveCall(pwc) => pwc.arguments.clone(),
+        SuiCommand::TransferObjects(objects, recipient) => {
+            objects.iter().copied().chain([*recipient]).collect()
+        }
+        SuiCommand::SplitCoins(coin, amounts) => {
+            [*coin].into_iter().chain(amounts.iter().copied()).collect()
+        }
+        SuiCommand::MergeCoins(target, sources) => [*target]
+            .into_iter()
+            .chain(sources.iter().copied())
+            .collect(),
+        SuiCommand::MakeMoveVec(_, elements) => elements.clone(),
+        SuiCommand::Upgrade(_, _, ticket) => vec![*ticket],
+        SuiCommand::Publish(_) => vec![],
+    }
+}
+
+fn describe_step(visualizers: &[&dyn CommandVisualizer], context: &VisualizerContext) -> String {
+    let Some(command) = context.commands().get(context.command_index()) else {
+        return "Unknown command".to_string();
+    };
+
+    match command {
+        SuiCommand::MoveCall(pwc) => {
+            let kind = visualizers
+                .iter()
+                .find_map(|visualizer| visualizer.can_handle(context).then(|| visualizer.kind()));
+            describe_move_call(pwc, kind)
+        }
+        SuiCommand::TransferObjects(objects, recipient) => {
+            let what = match objects.as_slice() {
+                [object] if result_command(*object).is_some() => "output".to_string(),
+                _ => format!("{} object(s)", objects.len()),
+            };
+            let to = get_address_value(&[*recipient], context.inputs(), 0).map_or_else(
+                |_| "a computed address".to_string(),
+                |address| truncate_address(&address.to_string()),
+            );
+            format!("Transfer {what} to {to}")
+        }
+        SuiCommand::SplitCoins(coin, amounts) => {
+            let total = amounts.iter().try_fold(0_u64, |sum, amount| {
+                let SuiArgument::Input(index) = amount else {
+                    return None;
+                };
+                let value =
+                    decode_number::<u64>(context.inputs().get(usize::from(*index))?).ok()?;
+                sum.checked_add(value)
+            });
+            match (coin, total) {
+                (SuiArgument::GasCoin, Some(total)) => format!("Split {} SUI", format_sui(total)),
+                _ => format!("Split into {} coin(s)", amounts.len()),
+            }
+        }
+        SuiCommand::MergeCoins(_, sources) => format!("Merge {} coin(s)", sources.len()),
+        SuiCommand::MakeMoveVec(_, elements) => format!("Make vector of {}", elements.len()),
+        SuiCommand::Publish(_) => "Publish package".to_string(),
+        SuiCommand::Upgrade(..) => "Upgrade package".to_string(),
+    }
+}
+
+/// Names a call after the protocol that claimed it, falling back to the
+/// truncated call target for packages no visualizer knows.
+fn describe_move_call(pwc: &SuiProgrammableMoveCall, kind: Option<VisualizerKind>) -> String {
+    let call = format!("{}::{}", pwc.module, pwc.function);
+    match kind {
+        Some(VisualizerKind::Dex(name)) if pwc.function.contains("swap") => {
+            format!("Swap on {name}")
+        }
+        Some(
+            VisualizerKind::Dex(name)
+            | VisualizerKind::Derivatives(name)
+            | VisualizerKind::Lending(name)
+            | VisualizerKind::StakingPools(name)
+            | VisualizerKind::Payments(name)
+            | VisualizerKind::Nft(name),
+        ) => format!("{call} on {name}"),
+        None => format!(
+            "Call {}::{call}",
+            truncate_address(&pwc.package.to_hex_literal())
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::assert_has_field_with_value;
+
+    #[test]
+    fn test_flow_follows_results_across_commands() {
+        // Split 5 SUI off gas, pass it to 0x4242..::game::play and transfer the
+        // result to 0xbebe..; separately merge 0x2222.. into 0x1111..
+        let test_data = "AAAEAAgA8gUqAQAAAAAgvr6+vr6+vr6+vr6+vr6+vr6+vr6+vr6+vr6+vr6+vr4BABERERERERERERERERERERERERERERERERERERERERERAQAAAAAAAAAgEREREREREREREREREREREREREREREREREREREREREREBACIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiAQAAAAAAAAAgEREREREREREREREREREREREREREREREREREREREREREEAgABAQAAAEJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCQkJCBGdhbWUEcGxheQABAgAAAQECAQABAQADAQIAAQEDAKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NAQAAAAAAAAAgIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiKrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq+gDAAAAAAAAgJaYAAAAAAAA";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field_with_value(
+            &payload,
+            "Transaction Flow",
+            "Split 5 SUI -> Call 0x4242...4242::game::play -> Transfer output to 0xbebe...bebe; Merge 1 coin(s)",
+        );
+    }
+}
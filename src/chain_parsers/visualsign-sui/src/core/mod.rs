@@ -2,24 +2,35 @@
 //!
 //! - `chain_config`: declarative macros for package/module/function layouts and typed getters.
 //! - `commands`: walks transaction commands and dispatches to available visualizers.
+//! - `fallback`: generic rendering for move calls that no visualizer claims.
+//! - `flow`: one-line summary of how command results feed later commands.
 //! - `helper`: chain resolution utilities (module cache adapters, etc.).
+//! - `package`: `Publish` and `Upgrade` commands, read from the raw transaction.
 //! - `transaction`: raw decoding and helpers for titles/network/details.
 //! - `visualsign`: public API surface for converting to `VisualSign` payloads.
 
 mod chain_config;
 mod commands;
+mod fallback;
+mod flow;
 mod helper;
+mod package;
 mod transaction;
 mod visualsign;
 
 use std::collections::BTreeMap;
+use std::str::FromStr;
 
+use generated::parser::{SuiCoinInfo, SuiMetadata, SuiStakedSui};
 use sui_json_rpc_types::{SuiCallArg, SuiCommand};
-use sui_types::base_types::SuiAddress;
+use sui_types::base_types::{ObjectID, SuiAddress};
 
 use ::visualsign::AnnotatedPayloadField;
 use ::visualsign::errors::VisualSignError;
+
+use crate::utils::{CoinAmount, CoinInfo, SuiCoin};
 pub use helper::SuiModuleResolver;
+pub(crate) use transaction::create_object_arg_field;
 pub use visualsign::{
     SuiTransactionWrapper, SuiVisualSignConverter, transaction_string_to_visual_sign,
     transaction_to_visual_sign,
@@ -30,12 +41,16 @@ pub use visualsign::{
 pub enum VisualizerKind {
     /// Decentralized exchange protocols (e.g., AMMs, DEX aggregators)
     Dex(&'static str),
+    /// Perpetual futures and other derivatives venues
+    Derivatives(&'static str),
     /// Lending/borrowing protocols
     Lending(&'static str),
     /// Validator or pooled staking without liquid derivative tokens
     StakingPools(&'static str),
     /// Payment and simple transfer-related operations
     Payments(&'static str),
+    /// NFT trading through kiosks and transfer policies
+    Nft(&'static str),
 }
 
 pub struct SuiIntegrationConfigData {
@@ -74,6 +89,10 @@ pub struct VisualizerContext<'a> {
     commands: &'a [SuiCommand],
     /// All input arguments for the transaction.
     inputs: &'a [SuiCallArg],
+    /// Wallet-supplied metadata, such as validator names.
+    metadata: Option<&'a SuiMetadata>,
+    /// Whether coin amounts may only use the built-in coin list.
+    strict_registry: bool,
 }
 
 impl<'a> VisualizerContext<'a> {
@@ -89,9 +108,26 @@ impl<'a> VisualizerContext<'a> {
             command_index,
             commands,
             inputs,
+            metadata: None,
+            strict_registry: false,
         }
     }
 
+    /// Attaches wallet-supplied metadata to the context.
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: Option<&'a SuiMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Ignores the metadata's coin entries, so only built-in coins get a
+    /// symbol and decimals.
+    #[must_use]
+    pub fn with_strict_registry(mut self, strict_registry: bool) -> Self {
+        self.strict_registry = strict_registry;
+        self
+    }
+
     /// Returns the sender address.
     pub fn sender(&self) -> &SuiAddress {
         self.sender
@@ -111,6 +147,67 @@ impl<'a> VisualizerContext<'a> {
     pub fn inputs(&self) -> &[SuiCallArg] {
         self.inputs
     }
+
+    /// Returns the display name the metadata gives for a validator address.
+    pub fn validator_name(&self, validator: &SuiAddress) -> Option<&str> {
+        self.metadata?
+            .validators
+            .iter()
+            .find_map(|(address, name)| {
+                SuiAddress::from_str(address)
+                    .is_ok_and(|address| address == *validator)
+                    .then_some(name.as_str())
+            })
+    }
+
+    /// Returns what the metadata says a `StakedSui` object holds.
+    pub fn staked_sui(&self, object_id: &ObjectID) -> Option<&SuiStakedSui> {
+        self.metadata?.staked_sui.iter().find_map(|(id, stake)| {
+            ObjectID::from_str(id)
+                .is_ok_and(|id| id == *object_id)
+                .then_some(stake)
+        })
+    }
+
+    /// Returns the symbol and decimals for `coin`. Built-in coins always use
+    /// the built-in list, so a request can't change how SUI or USDC amounts
+    /// are scaled; the metadata only describes coins the list doesn't know,
+    /// and not at all under strict registry mode.
+    pub fn coin_info(&self, coin: &SuiCoin) -> Option<CoinInfo> {
+        if let Some(known) = coin.known_info() {
+            return Some(known);
+        }
+        if self.strict_registry {
+            return None;
+        }
+        let coin_type = coin.coin_type();
+        self.metadata?.coins.iter().find_map(|(key, info)| {
+            let key = SuiCoin::from_str(key).ok()?;
+            if key.coin_type() == coin_type {
+                coin_info_from_metadata(info)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Formats a base-unit amount of `coin` for display.
+    pub fn coin_amount(&self, amount: impl Into<u128>, coin: &SuiCoin) -> CoinAmount {
+        CoinAmount::new(amount.into(), coin, self.coin_info(coin).as_ref())
+    }
+}
+
+/// Metadata entries are supplied by the caller, so a symbol that could
+/// disguise the amount (whitespace, control characters, non-ASCII) or
+/// decimals that do not fit a `u8` are ignored.
+fn coin_info_from_metadata(info: &SuiCoinInfo) -> Option<CoinInfo> {
+    let decimals = u8::try_from(info.decimals).ok()?;
+    (!info.symbol.is_empty() && info.symbol.chars().all(|c| c.is_ascii_graphic())).then(|| {
+        CoinInfo {
+            symbol: info.symbol.clone(),
+            decimals,
+        }
+    })
 }
 
 /// Trait for visualizing Sui transaction commands.
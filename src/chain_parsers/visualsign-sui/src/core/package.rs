@@ -0,0 +1,242 @@
+//! Rendering for `Publish` and `Upgrade` commands.
+//!
+//! The JSON-RPC view of these commands keeps only the dependency IDs, so the
+//! module bytecode is read from the raw `TransactionData`. An upgrade is
+//! authorized by a ticket from `0x2::package::authorize_upgrade`; when that
+//! call is in the same PTB, the `UpgradeCap` and policy it was given are shown
+//! alongside the upgrade.
+
+use sui_json_rpc_types::{SuiArgument, SuiCallArg, SuiCommand};
+use sui_types::base_types::ObjectID;
+use sui_types::transaction::{Command, TransactionData, TransactionDataAPI, TransactionKind};
+
+use visualsign::{
+    AnnotatedPayloadField,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_preview_layout, create_text_field},
+};
+
+use crate::core::VisualizerContext;
+use crate::core::transaction::create_object_arg_field;
+use crate::utils::{decode_number, truncate_address};
+
+/// Renders the command at `context.command_index()` if it publishes or
+/// upgrades a package.
+pub fn visualize_package_command(
+    context: &VisualizerContext,
+    transaction: &TransactionData,
+) -> Result<Option<AnnotatedPayloadField>, VisualSignError> {
+    let TransactionKind::ProgrammableTransaction(ptb) = transaction.kind() else {
+        return Ok(None);
+    };
+
+    match ptb.commands.get(context.command_index()) {
+        Some(Command::Publish(modules, dependencies)) => {
+            visualize_publish(modules, dependencies).map(Some)
+        }
+        Some(Command::Upgrade(modules, dependencies, package, _)) => {
+            visualize_upgrade(context, modules, dependencies, package).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+fn visualize_publish(
+    modules: &[Vec<u8>],
+    dependencies: &[ObjectID],
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let mut fields = module_fields(modules)?;
+    fields.extend(dependency_fields(dependencies)?);
+
+    Ok(create_preview_layout(
+        "Publish Package",
+        format!("Publish {}", describe_modules(modules)),
+        fields,
+    ))
+}
+
+fn visualize_upgrade(
+    context: &VisualizerContext,
+    modules: &[Vec<u8>],
+    dependencies: &[ObjectID],
+    package: &ObjectID,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let package = package.to_hex_literal();
+
+    let mut fields = vec![create_address_field(
+        "Package",
+        &package,
+        None,
+        None,
+        None,
+        Some("Upgraded"),
+    )?];
+    fields.extend(module_fields(modules)?);
+    fields.extend(authorization_fields(context)?);
+    fields.extend(dependency_fields(dependencies)?);
+
+    Ok(create_preview_layout(
+        "Upgrade Package",
+        format!(
+            "Upgrade {} with {}",
+            truncate_address(&package),
+            describe_modules(modules)
+        ),
+        fields,
+    ))
+}
+
+fn module_fields(modules: &[Vec<u8>]) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    Ok(vec![
+        create_text_field("Modules", &modules.len().to_string())?,
+        create_text_field(
+            "Bytecode Size",
+            &format!("{} bytes", bytecode_size(modules)),
+        )?,
+    ])
+}
+
+fn dependency_fields(
+    dependencies: &[ObjectID],
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    dependencies
+        .iter()
+        .enumerate()
+        .map(|(index, dependency)| {
+            create_address_field(
+                &format!("Dependency {index}"),
+                &dependency.to_hex_literal(),
+                None,
+                None,
+                None,
+                None,
+            )
+        })
+        .collect()
+}
+
+/// The `UpgradeCap` and policy behind the upgrade ticket, or a pointer to the
+/// ticket when it was not produced by `authorize_upgrade` in this PTB.
+fn authorization_fields(
+    context: &VisualizerContext,
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let Some(SuiCommand::Upgrade(_, _, ticket)) = context.commands().get(context.command_index())
+    else {
+        return Ok(vec![]);
+    };
+
+    let authorize_call = match ticket {
+        SuiArgument::Result(command) | SuiArgument::NestedResult(command, _) => {
+            match context.commands().get(usize::from(*command)) {
+                Some(SuiCommand::MoveCall(pwc))
+                    if pwc.package.to_hex_literal() == "0x2"
+                        && pwc.module == "package"
+                        && pwc.function == "authorize_upgrade" =>
+                {
+                    Some(pwc)
+                }
+                _ => None,
+            }
+        }
+        SuiArgument::GasCoin | SuiArgument::Input(_) => None,
+    };
+    let Some(pwc) = authorize_call else {
+        return Ok(vec![create_text_field(
+            "Upgrade Ticket",
+            &describe_ticket(*ticket),
+        )?]);
+    };
+
+    let input = |index: usize| match pwc.arguments.get(index) {
+        Some(SuiArgument::Input(input)) => context.inputs().get(usize::from(*input)),
+        _ => None,
+    };
+
+    let mut fields = vec![];
+    if let Some(SuiCallArg::Object(cap)) = input(0) {
+        fields.push(create_object_arg_field("Upgrade Cap", cap)?);
+    }
+    if let Some(policy) = input(1).and_then(|policy| decode_number::<u8>(policy).ok()) {
+        fields.push(create_text_field(
+            "Upgrade Policy",
+            &describe_policy(policy),
+        )?);
+    }
+    Ok(fields)
+}
+
+fn describe_ticket(ticket: SuiArgument) -> String {
+    match ticket {
+        SuiArgument::GasCoin => "Gas coin".to_string(),
+        SuiArgument::Input(index) => format!("Input {index}"),
+        SuiArgument::Result(command) => format!("Result of command {command}"),
+        SuiArgument::NestedResult(command, result) => {
+            format!("Result {result} of command {command}")
+        }
+    }
+}
+
+/// Names the policies defined in `0x2::package`; a cap can only be
+/// restricted further, never loosened.
+fn describe_policy(policy: u8) -> String {
+    match policy {
+        0 => "Compatible".to_string(),
+        128 => "Additive".to_string(),
+        192 => "Dependency-only".to_string(),
+        other => format!("Unknown ({other})"),
+    }
+}
+
+fn bytecode_size(modules: &[Vec<u8>]) -> usize {
+    modules.iter().map(Vec::len).sum()
+}
+
+fn describe_modules(modules: &[Vec<u8>]) -> String {
+    format!(
+        "{} module(s), {} bytes",
+        modules.len(),
+        bytecode_size(modules)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+
+    #[test]
+    fn test_publish_package() {
+        // Publish two modules (4 and 6 bytes) depending on 0x1 and 0x2, then
+        // transfer the UpgradeCap to the sender.
+        let test_data = "AAABACCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwIEAgShHOsLBqEc6wsGAAIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACAQECAAABAACrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwHNzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NzQEAAAAAAAAAICIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6voAwAAAAAAAICWmAAAAAAAAA==";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Publish Package");
+        assert_has_field_with_value(&payload, "Modules", "2");
+        assert_has_field_with_value(&payload, "Bytecode Size", "10 bytes");
+        assert_has_field_with_value(&payload, "Dependency 1", "0x2");
+    }
+
+    #[test]
+    fn test_upgrade_package_shows_upgrade_cap() {
+        // authorize_upgrade(cap 0x7777.., policy 0, digest), upgrade 0x5555..
+        // with one module, then commit_upgrade.
+        let test_data = "AAADAQB3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3dwEAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAAEAACEgmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZkDAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACB3BhY2thZ2URYXV0aG9yaXplX3VwZ3JhZGUAAwEAAAEBAAECAAYBB6Ec6wsGAAACAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAlVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVAgAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACB3BhY2thZ2UOY29tbWl0X3VwZ3JhZGUAAgEAAAIBAKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NAQAAAAAAAAAgIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiKrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq+gDAAAAAAAAgJaYAAAAAAAA";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Upgrade Package");
+        assert_has_field_with_value(
+            &payload,
+            "Package",
+            "0x5555555555555555555555555555555555555555555555555555555555555555",
+        );
+        assert_has_field_with_value(&payload, "Modules", "1");
+        assert_has_field_with_value(
+            &payload,
+            "Upgrade Cap",
+            "0x7777777777777777777777777777777777777777777777777777777777777777",
+        );
+        assert_has_field_with_value(&payload, "Upgrade Policy", "Compatible");
+    }
+}
@@ -1,7 +1,7 @@
 use super::determine_transaction_type_string;
 
 use sui_json_rpc_types::{SuiTransactionBlockData, SuiTransactionBlockDataAPI};
-use sui_types::transaction::TransactionData;
+use sui_types::transaction::{TransactionData, TransactionDataAPI, TransactionExpiration};
 
 use visualsign::{
     AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
@@ -16,6 +16,28 @@ pub fn get_tx_network() -> Result<AnnotatedPayloadField, VisualSignError> {
     create_text_field("Network", "Sui Network")
 }
 
+/// Flags transactions whose gas is paid by someone other than the sender.
+///
+/// The sponsor co-signs and owns the gas coins, so the signer should see
+/// who it is before approving.
+pub fn get_tx_sponsor(
+    block_data: &SuiTransactionBlockData,
+) -> Result<Option<AnnotatedPayloadField>, VisualSignError> {
+    if !is_sponsored(block_data) {
+        return Ok(None);
+    }
+
+    create_address_field(
+        "Gas Sponsor",
+        &block_data.gas_data().owner.to_string(),
+        None,
+        None,
+        None,
+        Some("Sponsored"),
+    )
+    .map(Some)
+}
+
 pub fn get_tx_details(
     tx_data: &TransactionData,
     block_data: &SuiTransactionBlockData,
@@ -23,12 +45,17 @@ pub fn get_tx_details(
     let payload_fields: Vec<AnnotatedPayloadField> = vec![create_tx_type_fields(block_data)?]
         .into_iter()
         .chain(create_tx_gas_fields(block_data)?)
+        .chain([create_tx_expiration_field(tx_data)?])
         .chain(create_tx_data_fields(tx_data)?)
         .collect();
 
     {
         let title_text = "Transaction Details".to_string();
-        let subtitle_text = format!("Gas: {} MIST", block_data.gas_data().budget);
+        let subtitle_text = if is_sponsored(block_data) {
+            format!("Gas: {} MIST (sponsored)", block_data.gas_data().budget)
+        } else {
+            format!("Gas: {} MIST", block_data.gas_data().budget)
+        };
 
         let condensed = SignablePayloadFieldListLayout {
             fields: vec![
@@ -38,6 +65,7 @@ pub fn get_tx_details(
                     &block_data.gas_data().budget.to_string(),
                     "MIST",
                 )?,
+                create_tx_expiration_field(tx_data)?,
             ],
         };
 
@@ -79,18 +107,28 @@ fn create_tx_type_fields(
     )
 }
 
+fn is_sponsored(block_data: &SuiTransactionBlockData) -> bool {
+    block_data.gas_data().owner != *block_data.sender()
+}
+
 fn create_tx_gas_fields(
     block_data: &SuiTransactionBlockData,
 ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
-    Ok(vec![
-        create_address_field(
-            "Gas Owner",
-            &block_data.gas_data().owner.to_string(),
-            None,
-            None,
-            None,
-            None,
-        )?,
+    let mut fields = vec![create_address_field(
+        "Gas Owner",
+        &block_data.gas_data().owner.to_string(),
+        None,
+        None,
+        None,
+        None,
+    )?];
+
+    if let Some(sponsor) = get_tx_sponsor(block_data)? {
+        fields.push(sponsor);
+        fields.push(create_text_field("Gas Payer", "Sponsor (not the sender)")?);
+    }
+
+    fields.extend([
         create_amount_field(
             "Gas Budget",
             &block_data.gas_data().budget.to_string(),
@@ -101,7 +139,22 @@ fn create_tx_gas_fields(
             &block_data.gas_data().price.to_string(),
             "MIST",
         )?,
-    ])
+    ]);
+
+    Ok(fields)
+}
+
+/// A signed transaction without an expiration stays valid until its gas coin
+/// version is consumed, so the signer should know how long it can be replayed.
+fn create_tx_expiration_field(
+    tx_data: &TransactionData,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let text = match tx_data.expiration() {
+        TransactionExpiration::Epoch(epoch) => format!("Expires at epoch {epoch}"),
+        TransactionExpiration::None => "No expiration".to_string(),
+    };
+
+    create_text_field("Expiration", &text)
 }
 
 fn create_tx_data_fields(
@@ -1,5 +1,9 @@
 mod common;
 mod decoder;
+mod objects;
+mod sender;
 
-pub use common::{get_tx_details, get_tx_network};
+pub use common::{get_tx_details, get_tx_network, get_tx_sponsor};
 pub use decoder::{decode_transaction, determine_transaction_type_string};
+pub use objects::{create_object_arg_field, get_tx_object_inputs};
+pub use sender::get_tx_sender_account;
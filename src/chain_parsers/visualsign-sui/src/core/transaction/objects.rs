@@ -0,0 +1,112 @@
+use sui_json_rpc_types::{
+    SuiCallArg, SuiObjectArg, SuiTransactionBlockData, SuiTransactionBlockDataAPI,
+    SuiTransactionBlockKind,
+};
+
+use visualsign::{
+    AnnotatedPayloadField,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_preview_layout},
+};
+
+/// Lists every object the transaction takes as input, with how it is passed.
+///
+/// Owned objects are pinned to a version, shared objects are sequenced by
+/// consensus and are only writable when passed mutably, and receiving objects
+/// are pulled out of another object's inbox. Together these bound what the
+/// PTB can modify.
+pub fn get_tx_object_inputs(
+    block_data: &SuiTransactionBlockData,
+) -> Result<Option<AnnotatedPayloadField>, VisualSignError> {
+    let SuiTransactionBlockKind::ProgrammableTransaction(tx) = block_data.transaction() else {
+        return Ok(None);
+    };
+
+    let objects: Vec<(usize, &SuiObjectArg)> = tx
+        .inputs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, input)| match input {
+            SuiCallArg::Object(object) => Some((index, object)),
+            SuiCallArg::Pure(_) => None,
+        })
+        .collect();
+    if objects.is_empty() {
+        return Ok(None);
+    }
+
+    let (mut owned, mut shared, mut mutable_shared, mut receiving) = (0, 0, 0, 0);
+    for (_, object) in &objects {
+        match object {
+            SuiObjectArg::ImmOrOwnedObject { .. } => owned += 1,
+            SuiObjectArg::SharedObject { mutable, .. } => {
+                shared += 1;
+                if *mutable {
+                    mutable_shared += 1;
+                }
+            }
+            SuiObjectArg::Receiving { .. } => receiving += 1,
+        }
+    }
+
+    let mut summary = vec![
+        format!("{owned} owned"),
+        format!("{shared} shared ({mutable_shared} mutable)"),
+    ];
+    if receiving > 0 {
+        summary.push(format!("{receiving} receiving"));
+    }
+
+    let fields = objects
+        .into_iter()
+        .map(|(index, object)| create_object_arg_field(&format!("Input {index}"), object))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Some(create_preview_layout(
+        "Object Inputs",
+        summary.join(", "),
+        fields,
+    )))
+}
+
+/// Renders an object input as its ID, badged with how it is passed and
+/// annotated with the version it is bound to.
+pub fn create_object_arg_field(
+    label: &str,
+    object: &SuiObjectArg,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let (object_id, badge, memo) = match object {
+        SuiObjectArg::ImmOrOwnedObject {
+            object_id, version, ..
+        } => (object_id, "Owned", format!("Version {}", version.value())),
+        SuiObjectArg::SharedObject {
+            object_id,
+            initial_shared_version,
+            mutable,
+        } => (
+            object_id,
+            if *mutable {
+                "Shared, Mutable"
+            } else {
+                "Shared, Read-only"
+            },
+            format!("Initial shared version {}", initial_shared_version.value()),
+        ),
+        SuiObjectArg::Receiving {
+            object_id, version, ..
+        } => (
+            object_id,
+            "Receiving",
+            format!("Version {}", version.value()),
+        ),
+    };
+
+    create_address_field(
+        label,
+        &object_id.to_string(),
+        None,
+        Some(&memo),
+        None,
+        Some(badge),
+    )
+}
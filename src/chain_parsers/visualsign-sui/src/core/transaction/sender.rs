@@ -0,0 +1,91 @@
+use std::str::FromStr;
+
+use generated::parser::{SuiAccount, SuiMetadata, SuiMultisig, SuiZkLogin};
+
+use sui_json_rpc_types::{SuiTransactionBlockData, SuiTransactionBlockDataAPI};
+use sui_types::base_types::SuiAddress;
+
+use visualsign::{
+    AnnotatedPayloadField,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_preview_layout, create_text_field},
+};
+
+/// Describes the sender's account when wallet metadata lists it as a multisig
+/// or `zkLogin` address.
+///
+/// Nothing in the transaction itself says what kind of key controls the
+/// sender, so this is only shown for addresses the caller has vouched for.
+pub fn get_tx_sender_account(
+    block_data: &SuiTransactionBlockData,
+    metadata: Option<&SuiMetadata>,
+) -> Result<Option<AnnotatedPayloadField>, VisualSignError> {
+    let Some(sui_metadata) = metadata else {
+        return Ok(None);
+    };
+
+    let sender = block_data.sender();
+    let account = sui_metadata.accounts.iter().find_map(|(address, account)| {
+        SuiAddress::from_str(address)
+            .is_ok_and(|address| address == *sender)
+            .then_some(account)
+    });
+
+    match account {
+        Some(SuiAccount {
+            multisig: Some(multisig),
+            ..
+        }) => multisig_account_field(multisig).map(Some),
+        Some(SuiAccount {
+            zk_login: Some(zk_login),
+            ..
+        }) => zk_login_account_field(zk_login).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn multisig_account_field(
+    multisig: &SuiMultisig,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let total_weight: u64 = multisig
+        .members
+        .iter()
+        .map(|member| u64::from(member.weight))
+        .sum();
+
+    let mut fields = vec![
+        create_text_field("Account Type", "Multisig")?,
+        create_text_field("Threshold", &multisig.threshold.to_string())?,
+        create_text_field("Total Weight", &total_weight.to_string())?,
+    ];
+    for (index, member) in multisig.members.iter().enumerate() {
+        fields.push(create_address_field(
+            &format!("Member {}", index + 1),
+            &member.address,
+            None,
+            None,
+            None,
+            Some(&format!("Weight {}", member.weight)),
+        )?);
+    }
+
+    Ok(create_preview_layout(
+        "Sender Account",
+        format!(
+            "Multisig: weight {} of {total_weight} required",
+            multisig.threshold
+        ),
+        fields,
+    ))
+}
+
+fn zk_login_account_field(zk_login: &SuiZkLogin) -> Result<AnnotatedPayloadField, VisualSignError> {
+    Ok(create_preview_layout(
+        "Sender Account",
+        format!("zkLogin via {}", zk_login.issuer),
+        vec![
+            create_text_field("Account Type", "zkLogin")?,
+            create_text_field("Issuer", &zk_login.issuer)?,
+        ],
+    ))
+}
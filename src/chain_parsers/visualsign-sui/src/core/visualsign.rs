@@ -1,13 +1,18 @@
 //! Public conversion entry points and wrapper types for `VisualSign` on Sui.
 
-use crate::core::commands::decode_commands;
+use crate::core::commands::{decode_commands, decode_flow};
 use crate::core::helper::SuiModuleResolver;
 use crate::core::transaction::{
     decode_transaction, determine_transaction_type_string, get_tx_details, get_tx_network,
+    get_tx_object_inputs, get_tx_sender_account, get_tx_sponsor,
 };
 
+use crate::personal_message::{is_personal_message, personal_message_to_visual_sign};
+
+use base64::Engine;
 use move_bytecode_utils::module_cache::SyncModuleCache;
 
+use generated::parser::chain_metadata;
 use sui_json_rpc_types::SuiTransactionBlockData;
 use sui_types::transaction::TransactionData;
 
@@ -16,7 +21,7 @@ use visualsign::{
     SignablePayload, SignablePayloadField,
     encodings::SupportedEncodings,
     vsptrait::{
-        ConversionResult, Transaction, TransactionParseError, VisualSignConverter,
+        ChainMetadata, ConversionResult, Transaction, TransactionParseError, VisualSignConverter,
         VisualSignConverterFromString, VisualSignError, VisualSignOptions,
     },
 };
@@ -59,7 +64,44 @@ impl Transaction for SuiTransactionWrapper {
 /// Converter that knows how to format Sui transactions for `VisualSign`.
 pub struct SuiVisualSignConverter;
 
-impl VisualSignConverterFromString<SuiTransactionWrapper> for SuiVisualSignConverter {}
+impl VisualSignConverterFromString<SuiTransactionWrapper> for SuiVisualSignConverter {
+    fn to_visual_sign_payload_from_string(
+        &self,
+        transaction_data: &str,
+        options: VisualSignOptions,
+    ) -> Result<ConversionResult, VisualSignError> {
+        if let Some(message) = decode_personal_message(transaction_data) {
+            let allow_personal_messages = options
+                .developer_config
+                .as_ref()
+                .is_some_and(|c| c.allow_personal_messages);
+            if !allow_personal_messages {
+                return Err(VisualSignError::ParseError(
+                    TransactionParseError::InvalidFormat(
+                        "Sui personal messages are not accepted on this endpoint".to_string(),
+                    ),
+                ));
+            }
+            let payload = personal_message_to_visual_sign(&message, options)?;
+            payload.validate_charset()?;
+            return Ok(ConversionResult::new(payload));
+        }
+        let transaction = SuiTransactionWrapper::from_string(transaction_data)
+            .map_err(VisualSignError::ParseError)?;
+        self.to_validated_visual_sign_payload(transaction, options)
+    }
+}
+
+/// The bytes of `data` if it encodes a personal message intent
+fn decode_personal_message(data: &str) -> Option<Vec<u8>> {
+    let bytes = match SupportedEncodings::detect(data) {
+        SupportedEncodings::Base64 => base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .ok()?,
+        SupportedEncodings::Hex => visualsign::encodings::decode_hex(data).ok()?,
+    };
+    is_personal_message(&bytes).then_some(bytes)
+}
 
 impl VisualSignConverter<SuiTransactionWrapper> for SuiVisualSignConverter {
     fn to_visual_sign_payload(
@@ -75,6 +117,8 @@ impl VisualSignConverter<SuiTransactionWrapper> for SuiVisualSignConverter {
             transaction,
             options.decode_transfers,
             options.transaction_name,
+            options.metadata.as_ref(),
+            options.strict_registry,
         )?;
         Ok(ConversionResult::new(payload))
     }
@@ -85,6 +129,8 @@ fn convert_to_visual_sign_payload(
     transaction: &TransactionData,
     decode_transfers: bool,
     title: Option<String>,
+    metadata: Option<&ChainMetadata>,
+    strict_registry: bool,
 ) -> Result<SignablePayload, VisualSignError> {
     let block_data: SuiTransactionBlockData = SuiTransactionBlockData::try_from_with_module_cache(
         transaction.clone(),
@@ -92,7 +138,16 @@ fn convert_to_visual_sign_payload(
     )
     .map_err(|e| VisualSignError::ParseError(TransactionParseError::DecodeError(e.to_string())))?;
 
+    let metadata = match metadata.and_then(|metadata| metadata.metadata.as_ref()) {
+        Some(chain_metadata::Metadata::Sui(sui_metadata)) => Some(sui_metadata),
+        _ => None,
+    };
+
     let mut fields: Vec<SignablePayloadField> = vec![get_tx_network()?.signable_payload_field];
+    fields.extend(
+        get_tx_sender_account(&block_data, metadata)?.map(|field| field.signable_payload_field),
+    );
+    fields.extend(get_tx_sponsor(&block_data)?.map(|field| field.signable_payload_field));
 
     if decode_transfers {
         fields.extend(
@@ -103,11 +158,16 @@ fn convert_to_visual_sign_payload(
     }
 
     fields.extend(
-        decode_commands(&block_data)?
+        decode_commands(transaction, &block_data, metadata, strict_registry)?
             .iter()
             .map(|e| e.signable_payload_field.clone()),
     );
 
+    fields.extend(
+        decode_flow(&block_data, metadata, strict_registry)?
+            .map(|field| field.signable_payload_field),
+    );
+    fields.extend(get_tx_object_inputs(&block_data)?.map(|field| field.signable_payload_field));
     fields.push(get_tx_details(transaction, &block_data)?.signable_payload_field);
 
     let title = title.unwrap_or_else(|| determine_transaction_type_string(&block_data).to_string());
@@ -154,6 +214,7 @@ pub fn transaction_string_to_visual_sign(
 mod tests {
     use super::*;
     use crate::utils::payload_from_b64;
+    use visualsign::test_utils::assert_has_field_with_value;
 
     #[test]
     fn test_sui_transaction_to_vsp() {
@@ -200,5 +261,124 @@ mod tests {
             transaction_preview.is_some(),
             "Should have Transaction Details layout"
         );
+        assert!(
+            !payload.fields.iter().any(|f| f.label() == "Gas Sponsor"),
+            "Gas paid by the sender should not be flagged as sponsored"
+        );
+        assert_has_field_with_value(&payload, "Expiration", "No expiration");
+    }
+
+    #[test]
+    fn test_transaction_expiration_epoch() {
+        // Coin transfer that stops being valid after epoch 812
+        let test_data = "AAACAAjoAwAAAAAAAAAgd3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3cCAgABAQAAAQECAAABAQCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwHNzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NzQEAAAAAAAAAICIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6voAwAAAAAAAICWmAAAAAAAASwDAAAAAAAA";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field_with_value(&payload, "Expiration", "Expires at epoch 812");
+    }
+
+    #[test]
+    fn test_multisig_sender_from_metadata() {
+        use generated::parser::{SuiAccount, SuiMetadata, SuiMultisig, SuiMultisigMember};
+
+        let sender = format!("0x{}", "ab".repeat(32));
+        let member = |byte: &str, weight| SuiMultisigMember {
+            address: format!("0x{}", byte.repeat(32)),
+            weight,
+        };
+        let metadata = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Sui(SuiMetadata {
+                accounts: [(
+                    sender,
+                    SuiAccount {
+                        multisig: Some(SuiMultisig {
+                            threshold: 2,
+                            members: vec![member("01", 1), member("02", 1), member("03", 1)],
+                        }),
+                        zk_login: None,
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            })),
+        };
+
+        let payload = transaction_string_to_visual_sign(
+            "AAADAQCIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiAEAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAAjoAwAAAAAAAAAgd3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3cCAgEAAAEBAQABAQIAAAECAKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NAQAAAAAAAAAgIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiLv7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+gDAAAAAAAAgJaYAAAAAAAA",
+            VisualSignOptions {
+                decode_transfers: true,
+                metadata: Some(metadata),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_has_field_with_value(&payload, "Account Type", "Multisig");
+        assert_has_field_with_value(&payload, "Threshold", "2");
+        assert_has_field_with_value(&payload, "Total Weight", "3");
+        assert_has_field_with_value(
+            &payload,
+            "Member 3",
+            "0x0303030303030303030303030303030303030303030303030303030303030303",
+        );
+
+        // Without metadata nothing is known about the sender
+        let payload = payload_from_b64(
+            "AAADAQCIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiAEAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAAjoAwAAAAAAAAAgd3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3cCAgEAAAEBAQABAQIAAAECAKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NAQAAAAAAAAAgIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiLv7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+gDAAAAAAAAgJaYAAAAAAAA",
+        );
+        assert!(!payload.fields.iter().any(|f| f.label() == "Sender Account"));
+    }
+
+    #[test]
+    fn test_sponsored_transaction() {
+        // Coin transfer whose gas is owned by 0xefef.. rather than the sender 0xabab..
+        let test_data = "AAADAQCIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiAEAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAAjoAwAAAAAAAAAgd3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3cCAgEAAAEBAQABAQIAAAECAKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NAQAAAAAAAAAgIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiLv7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+/v7+gDAAAAAAAAgJaYAAAAAAAA";
+
+        let payload = payload_from_b64(test_data);
+        let sponsor = payload.fields.iter().find(|f| f.label() == "Gas Sponsor");
+        assert!(
+            matches!(
+                sponsor,
+                Some(SignablePayloadField::AddressV2 { address_v2, .. })
+                    if address_v2.address
+                        == "0xefefefefefefefefefefefefefefefefefefefefefefefefefefefefefefefef"
+                        && address_v2.badge_text.as_deref() == Some("Sponsored")
+            ),
+            "Sponsored transaction should surface the sponsor with a badge, got {sponsor:?}"
+        );
+        assert_has_field_with_value(&payload, "Gas Payer", "Sponsor (not the sender)");
+    }
+
+    #[test]
+    fn test_object_inputs() {
+        // SuiNS register: the SuiNS object is shared mutably, the clock read-only
+        let test_data = "AAAGAQFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQEAAAAAAAAAAQAMC2V4YW1wbGUuc3VpAAECAAgAkC9QCQAAAAEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAYBAAAAAAAAAAAAIKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAwIAAQEDAACdRR+gE5/vj3wfC9XX5Ft/qdu4TC5jwoGcer0Kf310nQhyZWdpc3RlcghyZWdpc3RlcgAFAQAAAQEAAQIAAgAAAQQAAQECAQABBQCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwHNzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NzQEAAAAAAAAAICIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6voAwAAAAAAAICWmAAAAAAAAA==";
+
+        let payload = payload_from_b64(test_data);
+        let Some(SignablePayloadField::PreviewLayout {
+            common,
+            preview_layout,
+        }) = payload.fields.iter().find(|f| f.label() == "Object Inputs")
+        else {
+            panic!("Object Inputs should be a preview layout");
+        };
+        assert_eq!(common.fallback_text, "0 owned, 2 shared (1 mutable)");
+
+        let clock = preview_layout
+            .expanded
+            .iter()
+            .flat_map(|expanded| &expanded.fields)
+            .map(|field| &field.signable_payload_field)
+            .find(|field| field.label() == "Input 4");
+        assert!(
+            matches!(
+                clock,
+                Some(SignablePayloadField::AddressV2 { address_v2, .. })
+                    if address_v2.badge_text.as_deref() == Some("Shared, Read-only")
+                        && address_v2.memo.as_deref() == Some("Initial shared version 1")
+            ),
+            "Clock should be a read-only shared object, got {clock:?}"
+        );
     }
 }
@@ -8,6 +8,7 @@
 
 mod core;
 mod integrations;
+pub mod personal_message;
 mod presets;
 mod utils;
 
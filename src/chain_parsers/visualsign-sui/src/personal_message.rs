@@ -0,0 +1,297 @@
+//! Sui personal messages (`signPersonalMessage`)
+//!
+//! Wallets sign arbitrary bytes for dapps by wrapping them in an intent
+//! message: a three-byte intent (scope, version, app id) followed by the BCS
+//! encoding of `PersonalMessage { message: vector<u8> }`. The intent scope is
+//! what keeps a message signature from ever authorizing a transaction, so an
+//! intent whose scope is `TransactionData` is refused here; those bytes belong
+//! on the transaction path.
+//!
+//! Raw message bytes (no intent) are checked before being shown: bytes that
+//! decode as a transaction, or are exactly the size of a digest, are refused,
+//! since signing them could authorize something other than a message.
+//!
+//! Intent input is gated behind
+//! [`DeveloperConfig::allow_personal_messages`](visualsign::vsptrait::DeveloperConfig)
+//! on the string entry point of [`crate::SuiVisualSignConverter`].
+//! [`personal_message_to_visual_sign`] is the direct, ungated entry point.
+
+use sui_types::transaction::{SenderSignedData, TransactionData};
+use visualsign::SignablePayload;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::create_text_field;
+use visualsign::vsptrait::{TransactionParseError, VisualSignOptions};
+
+/// `IntentScope::TransactionData`
+const TRANSACTION_DATA_SCOPE: u8 = 0;
+/// `IntentScope::PersonalMessage`
+const PERSONAL_MESSAGE_SCOPE: u8 = 3;
+/// `IntentVersion::V0` and `AppId::Sui`
+const INTENT_VERSION: u8 = 0;
+const INTENT_APP_ID: u8 = 0;
+
+/// Intent prefix of every personal message signed for Sui
+pub const PERSONAL_MESSAGE_INTENT: [u8; 3] =
+    [PERSONAL_MESSAGE_SCOPE, INTENT_VERSION, INTENT_APP_ID];
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Whether `bytes` carry the personal message intent
+pub fn is_personal_message(bytes: &[u8]) -> bool {
+    bytes.starts_with(&PERSONAL_MESSAGE_INTENT)
+}
+
+/// Decode an intent message holding a personal message and return the
+/// message bytes.
+pub fn parse_personal_message(bytes: &[u8]) -> Result<Vec<u8>, TransactionParseError> {
+    let (&[scope, version, app_id], message) = bytes.split_first_chunk::<3>().ok_or_else(|| {
+        TransactionParseError::InvalidFormat("intent message is shorter than its intent".into())
+    })?;
+    match scope {
+        PERSONAL_MESSAGE_SCOPE => {}
+        TRANSACTION_DATA_SCOPE => {
+            return Err(TransactionParseError::InvalidFormat(
+                "intent scope is TransactionData; parse it as a transaction, not a personal message"
+                    .into(),
+            ));
+        }
+        other => {
+            return Err(TransactionParseError::InvalidFormat(format!(
+                "intent scope {other} is not a personal message"
+            )));
+        }
+    }
+    if version != INTENT_VERSION {
+        return Err(TransactionParseError::UnsupportedVersion(format!(
+            "intent version {version}"
+        )));
+    }
+    if app_id != INTENT_APP_ID {
+        return Err(TransactionParseError::InvalidFormat(format!(
+            "intent app id {app_id} is not Sui"
+        )));
+    }
+
+    bcs::from_bytes::<Vec<u8>>(message).map_err(|e| {
+        TransactionParseError::InvalidFormat(format!("invalid personal message body: {e}"))
+    })
+}
+
+/// Whether `bytes` are a Sui transaction, signed or not, with or without the
+/// `TransactionData` intent
+fn is_sui_transaction(bytes: &[u8]) -> bool {
+    let unwrapped = bytes
+        .strip_prefix(&[TRANSACTION_DATA_SCOPE, INTENT_VERSION, INTENT_APP_ID])
+        .unwrap_or(bytes);
+    bcs::from_bytes::<TransactionData>(bytes).is_ok()
+        || bcs::from_bytes::<TransactionData>(unwrapped).is_ok()
+        || bcs::from_bytes::<SenderSignedData>(bytes).is_ok()
+}
+
+/// Refuse raw bytes that could stand for something other than a message
+fn check_raw_message(bytes: &[u8]) -> Result<(), TransactionParseError> {
+    if is_sui_transaction(bytes) {
+        return Err(TransactionParseError::InvalidFormat(
+            "message is a serialized Sui transaction, not a personal message".into(),
+        ));
+    }
+    if std::str::from_utf8(bytes).is_err() && bytes.len() == 32 {
+        return Err(TransactionParseError::InvalidFormat(
+            "message is 32 raw bytes, the size of a transaction digest".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Whether `text` is nothing but a digest, in base58 or hex
+fn looks_like_digest(text: &str) -> bool {
+    let text = text.trim();
+    let hex = text.strip_prefix("0x").unwrap_or(text);
+    (hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+        || ((43..=44).contains(&text.len()) && text.chars().all(|c| BASE58_ALPHABET.contains(c)))
+}
+
+/// Printable ASCII is shown as is; anything else, including `\`, is written
+/// as `<U+XXXX>` so it cannot hide or rearrange the text around it.
+fn escape_untrusted_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == ' ' || (c.is_ascii_graphic() && c != '\\') {
+            escaped.push(c);
+        } else {
+            escaped.push_str(&format!("<U+{:04X}>", c as u32));
+        }
+    }
+    escaped
+}
+
+/// Convert a personal message to a payload. `bytes` is either the intent
+/// message a wallet signs or the raw message it was asked to sign.
+pub fn personal_message_to_visual_sign(
+    bytes: &[u8],
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let mut fields = vec![create_text_field("Network", "Sui Network")?];
+
+    let message = if is_personal_message(bytes) {
+        fields.push(create_text_field("Intent", "Personal Message")?);
+        parse_personal_message(bytes)?
+    } else {
+        check_raw_message(bytes)?;
+        bytes.to_vec()
+    };
+
+    match std::str::from_utf8(&message) {
+        Ok(text) => {
+            if looks_like_digest(text) {
+                fields.push(create_text_field(
+                    "Warning",
+                    "Message is only a digest; it may stand for a transaction",
+                )?);
+            }
+            fields.push(create_text_field("Message", &escape_untrusted_text(text))?);
+        }
+        Err(_) => {
+            let hex: String = message.iter().map(|byte| format!("{byte:02x}")).collect();
+            fields.push(create_text_field(
+                "Warning",
+                "Message is not text; review the raw bytes",
+            )?);
+            fields.push(create_text_field("Message", &format!("0x{hex}"))?);
+        }
+    }
+
+    Ok(SignablePayload::new(
+        0,
+        options
+            .transaction_name
+            .unwrap_or_else(|| "Sui Personal Message".to_string()),
+        None,
+        fields
+            .into_iter()
+            .map(|f| f.signable_payload_field)
+            .collect(),
+        "SuiPersonalMessage".to_string(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SuiVisualSignConverter;
+    use base64::Engine;
+    use visualsign::test_utils::assert_has_field_with_value;
+    use visualsign::vsptrait::{DeveloperConfig, VisualSignConverterFromString};
+
+    // A one-command PTB, used to check that transactions are never shown as messages.
+    const TRANSACTION: &str = "AAADAQAREREREREREREREREREREREREREREREREREREREREREQcAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAQAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIgMAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAQAzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMwkAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAQMBAAACAQEAAQIAq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sBzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc0BAAAAAAAAACAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIqurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6ur6AMAAAAAAACAlpgAAAAAAAA=";
+
+    fn intent_message(scope: u8, message: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![scope, INTENT_VERSION, INTENT_APP_ID];
+        bytes.extend(bcs::to_bytes(&message.to_vec()).unwrap());
+        bytes
+    }
+
+    fn transaction_bytes() -> Vec<u8> {
+        base64::engine::general_purpose::STANDARD
+            .decode(TRANSACTION)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_personal_message_intent() {
+        let bytes = intent_message(PERSONAL_MESSAGE_SCOPE, b"Sign in to example.com");
+        assert!(is_personal_message(&bytes));
+
+        let payload =
+            personal_message_to_visual_sign(&bytes, VisualSignOptions::default()).unwrap();
+        assert_eq!(payload.title, "Sui Personal Message");
+        assert_has_field_with_value(&payload, "Intent", "Personal Message");
+        assert_has_field_with_value(&payload, "Message", "Sign in to example.com");
+    }
+
+    #[test]
+    fn test_message_text_is_escaped() {
+        let bytes = intent_message(PERSONAL_MESSAGE_SCOPE, "Pay\u{202e}01\n".as_bytes());
+
+        let payload =
+            personal_message_to_visual_sign(&bytes, VisualSignOptions::default()).unwrap();
+        assert_has_field_with_value(&payload, "Message", "Pay<U+202E>01<U+000A>");
+    }
+
+    #[test]
+    fn test_binary_message_is_shown_as_hex() {
+        let bytes = intent_message(PERSONAL_MESSAGE_SCOPE, &[0xde, 0xad, 0xbe, 0xef]);
+
+        let payload =
+            personal_message_to_visual_sign(&bytes, VisualSignOptions::default()).unwrap();
+        assert_has_field_with_value(&payload, "Message", "0xdeadbeef");
+        assert_has_field_with_value(
+            &payload,
+            "Warning",
+            "Message is not text; review the raw bytes",
+        );
+    }
+
+    #[test]
+    fn test_digest_message_is_flagged() {
+        let digest = "7Je4yeXMvvEHFcRSTD4WYv3eSsaDk2zqvdoSxWXdUYGx";
+        let payload =
+            personal_message_to_visual_sign(digest.as_bytes(), VisualSignOptions::default())
+                .unwrap();
+        assert_has_field_with_value(
+            &payload,
+            "Warning",
+            "Message is only a digest; it may stand for a transaction",
+        );
+    }
+
+    #[test]
+    fn test_transaction_data_intent_is_rejected() {
+        let mut bytes = vec![TRANSACTION_DATA_SCOPE, INTENT_VERSION, INTENT_APP_ID];
+        bytes.extend(transaction_bytes());
+
+        assert!(parse_personal_message(&bytes).is_err());
+        assert!(personal_message_to_visual_sign(&bytes, VisualSignOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_raw_transaction_is_rejected() {
+        let result =
+            personal_message_to_visual_sign(&transaction_bytes(), VisualSignOptions::default());
+        assert!(result.is_err());
+        assert!(
+            personal_message_to_visual_sign(&[0xff; 32], VisualSignOptions::default()).is_err()
+        );
+    }
+
+    #[test]
+    fn test_trailing_bytes_are_rejected() {
+        let mut bytes = intent_message(PERSONAL_MESSAGE_SCOPE, b"hello");
+        bytes.push(0);
+        assert!(parse_personal_message(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_personal_message_requires_developer_config() {
+        let data = base64::engine::general_purpose::STANDARD
+            .encode(intent_message(PERSONAL_MESSAGE_SCOPE, b"hello"));
+
+        let rejected = SuiVisualSignConverter
+            .to_visual_sign_payload_from_string(&data, VisualSignOptions::default());
+        assert!(rejected.is_err());
+
+        let options = VisualSignOptions {
+            developer_config: Some(DeveloperConfig {
+                allow_personal_messages: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let payload = SuiVisualSignConverter
+            .to_visual_sign_payload_from_string(&data, options)
+            .unwrap()
+            .payload;
+        assert_has_field_with_value(&payload, "Message", "hello");
+    }
+}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+
+crate::chain_config! {
+    config AFTERMATH_CONFIG as Config;
+
+    aftermath_amm => {
+        package_id => 0xc4049b2d1cc0f6e017fda8260e4377cecd236bd7f56a54fee120816e72e2e0dd,
+        modules as AftermathAmmModules: {
+            swap as Swap => SwapFunctions: {
+                swap_exact_in as SwapExactIn => SwapExactInIndexes(
+                    expected_coin_out as ExpectedCoinOut: u64 => 7 => get_expected_coin_out,
+                    allowable_slippage as AllowableSlippage: u64 => 8 => get_allowable_slippage,
+                ),
+            },
+        }
+    },
+
+    aftermath_staking => {
+        package_id => 0x7f6ce7ade63857c4fd16ef7783fed2dfc4d7fb7e40615abdb653030b76aef0c6,
+        modules as AftermathStakingModules: {
+            staked_sui_vault as StakedSuiVault => StakedSuiVaultFunctions: {
+                request_stake as RequestStake => RequestStakeIndexes(),
+                request_unstake as RequestUnstake => RequestUnstakeIndexes(),
+                request_unstake_atomic as RequestUnstakeAtomic => RequestUnstakeAtomicIndexes(),
+            },
+        }
+    },
+}
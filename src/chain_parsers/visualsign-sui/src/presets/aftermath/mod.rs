@@ -0,0 +1,453 @@
+mod config;
+
+use std::str::FromStr;
+
+use config::{
+    AFTERMATH_CONFIG, AftermathAmmModules, AftermathStakingModules, Config,
+    StakedSuiVaultFunctions, SwapExactInIndexes, SwapFunctions,
+};
+
+use crate::core::{CommandVisualizer, SuiIntegrationConfig, VisualizerContext, VisualizerKind};
+use crate::utils::{
+    SuiCoin, get_address_value, get_object_value, get_split_coin_amount, get_tx_type_arg,
+    truncate_address,
+};
+
+use sui_json_rpc_types::{SuiArgument, SuiCommand, SuiProgrammableMoveCall};
+
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_text_field},
+};
+
+/// `swap_exact_in<L, CoinIn, CoinOut>(pool, ..., coin_in, expected_coin_out,
+/// allowable_slippage)` argument positions
+const SWAP_POOL_ARG: usize = 0;
+const SWAP_COIN_IN_ARG: usize = 6;
+const SWAP_COIN_IN_TYPE: usize = 1;
+const SWAP_COIN_OUT_TYPE: usize = 2;
+
+/// `request_stake(vault, safe, sui_system, referral_vault, coin, validator)`
+const STAKE_COIN_ARG: usize = 4;
+const STAKE_VALIDATOR_ARG: usize = 5;
+/// `request_unstake[_atomic](vault, safe, referral_vault, afsui_coin)`
+const UNSTAKE_COIN_ARG: usize = 3;
+const AFSUI_COIN_TYPE: &str =
+    "0xf325ce1300e8dac124071d3152c5c5ee6174914f8bc2161e88329cf579246efc::afsui::AFSUI";
+
+/// Slippage is a fraction with 18 decimals; 10^14 is one hundredth of a percent
+const SLIPPAGE_HUNDREDTH_PERCENT: u64 = 100_000_000_000_000;
+
+pub struct AftermathVisualizer;
+
+impl CommandVisualizer for AftermathVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let Some(SuiCommand::MoveCall(pwc)) = context.commands().get(context.command_index())
+        else {
+            return Err(VisualSignError::MissingData(
+                "Expected a `MoveCall` for Aftermath parsing".into(),
+            ));
+        };
+
+        if let Ok(AftermathAmmModules::Swap) = AftermathAmmModules::try_from(pwc.module.as_str()) {
+            return match pwc.function.as_str().try_into()? {
+                SwapFunctions::SwapExactIn => Self::handle_swap_exact_in(context, pwc),
+            };
+        }
+
+        match AftermathStakingModules::try_from(pwc.module.as_str())? {
+            AftermathStakingModules::StakedSuiVault => match pwc.function.as_str().try_into()? {
+                StakedSuiVaultFunctions::RequestStake => Self::handle_request_stake(context, pwc),
+                StakedSuiVaultFunctions::RequestUnstake => {
+                    Self::handle_request_unstake(context, pwc, "Unstake")
+                }
+                StakedSuiVaultFunctions::RequestUnstakeAtomic => {
+                    Self::handle_request_unstake(context, pwc, "Atomic Unstake")
+                }
+            },
+        }
+    }
+
+    fn get_config(&self) -> Option<&dyn SuiIntegrationConfig> {
+        Some(AFTERMATH_CONFIG.get_or_init(Config::new))
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Dex("Aftermath")
+    }
+}
+
+impl AftermathVisualizer {
+    fn handle_swap_exact_in(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let coin_in: SuiCoin =
+            get_tx_type_arg(&pwc.type_arguments, SWAP_COIN_IN_TYPE).unwrap_or_default();
+        let coin_out: SuiCoin =
+            get_tx_type_arg(&pwc.type_arguments, SWAP_COIN_OUT_TYPE).unwrap_or_default();
+        let expected_out =
+            SwapExactInIndexes::get_expected_coin_out(context.inputs(), &pwc.arguments)?;
+        let expected_out = context.coin_amount(expected_out, &coin_out);
+        let slippage =
+            SwapExactInIndexes::get_allowable_slippage(context.inputs(), &pwc.arguments)?;
+
+        let hops = swap_hops(context.commands());
+        let hop_number = hops
+            .iter()
+            .position(|(index, _)| *index == context.command_index())
+            .map_or(1, |position| position + 1);
+
+        let amount_in_field = match pwc.arguments.get(SWAP_COIN_IN_ARG) {
+            Some(coin_arg) => {
+                match get_split_coin_amount(context.commands(), context.inputs(), coin_arg) {
+                    Some(amount) => context.coin_amount(amount, &coin_in).field("Amount In")?,
+                    None => create_text_field(
+                        "Amount In",
+                        &feeding_hop(&hops, coin_arg).map_or_else(
+                            || "N/A".to_string(),
+                            |hop| format!("Output of hop {hop}"),
+                        ),
+                    )?,
+                }
+            }
+            None => create_text_field("Amount In", "N/A")?,
+        };
+
+        let title_text = format!(
+            "Aftermath: Swap {} -> {} (hop {} of {})",
+            coin_in.symbol(),
+            coin_out.symbol(),
+            hop_number,
+            hops.len()
+        );
+        let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![
+                create_text_field("Route", &route_summary(&hops))?,
+                create_text_field(
+                    "Summary",
+                    &format!(
+                        "Hop {}: swap {} for at least {} (slippage {})",
+                        hop_number,
+                        coin_in.symbol(),
+                        expected_out,
+                        format_slippage(slippage)
+                    ),
+                )?,
+            ],
+        };
+
+        let mut expanded_fields = vec![
+            create_address_field(
+                "User Address",
+                &context.sender().to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?,
+            create_text_field("Hop", &format!("{} of {}", hop_number, hops.len()))?,
+        ];
+        if let Ok(pool) = get_object_value(&pwc.arguments, context.inputs(), SWAP_POOL_ARG) {
+            expanded_fields.push(create_address_field(
+                "Pool",
+                &pool.to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?);
+        }
+        expanded_fields.extend([
+            create_text_field("Coin In", &coin_in.to_string())?,
+            create_text_field("Coin Out", &coin_out.to_string())?,
+            amount_in_field,
+            expected_out.field("Expected Amount Out")?,
+            create_text_field("Allowable Slippage", &format_slippage(slippage))?,
+        ]);
+
+        Ok(vec![preview_field(
+            "Aftermath Swap",
+            title_text,
+            subtitle_text,
+            condensed,
+            expanded_fields,
+        )])
+    }
+
+    fn handle_request_stake(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let amount = pwc
+            .arguments
+            .get(STAKE_COIN_ARG)
+            .and_then(|coin| get_split_coin_amount(context.commands(), context.inputs(), coin));
+        let validator = get_address_value(&pwc.arguments, context.inputs(), STAKE_VALIDATOR_ARG);
+
+        let (title_text, amount_field) = match amount {
+            Some(amount) => {
+                let amount = context.coin_amount(amount, &SuiCoin::sui());
+                (
+                    format!("Aftermath: Stake {amount} for afSUI"),
+                    amount.field("Amount")?,
+                )
+            }
+            None => (
+                "Aftermath: Stake SUI for afSUI".to_string(),
+                create_text_field("Amount", "N/A SUI")?,
+            ),
+        };
+        let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![amount_field.clone()],
+        };
+
+        let mut expanded_fields = vec![create_address_field(
+            "From",
+            &context.sender().to_string(),
+            None,
+            None,
+            None,
+            None,
+        )?];
+        if let Ok(validator) = validator {
+            expanded_fields.push(create_address_field(
+                "Validator",
+                &validator.to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?);
+        }
+        expanded_fields.push(amount_field);
+
+        Ok(vec![preview_field(
+            "Aftermath Stake",
+            title_text,
+            subtitle_text,
+            condensed,
+            expanded_fields,
+        )])
+    }
+
+    fn handle_request_unstake(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+        action: &str,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let amount = pwc
+            .arguments
+            .get(UNSTAKE_COIN_ARG)
+            .and_then(|coin| get_split_coin_amount(context.commands(), context.inputs(), coin));
+
+        let (title_text, amount_field) = match amount {
+            Some(amount) => {
+                let afsui = SuiCoin::from_str(AFSUI_COIN_TYPE).unwrap_or_default();
+                let amount = context.coin_amount(amount, &afsui);
+                (
+                    format!("Aftermath: {action} {amount}"),
+                    amount.field("Amount")?,
+                )
+            }
+            None => (
+                format!("Aftermath: {action} afSUI"),
+                create_text_field("Amount", "N/A afSUI")?,
+            ),
+        };
+        let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![amount_field.clone()],
+        };
+
+        let expanded_fields = vec![
+            create_address_field(
+                "From",
+                &context.sender().to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?,
+            amount_field,
+        ];
+
+        Ok(vec![preview_field(
+            &format!("Aftermath {action}"),
+            title_text,
+            subtitle_text,
+            condensed,
+            expanded_fields,
+        )])
+    }
+}
+
+/// Every Aftermath `swap_exact_in` in the PTB, in execution order. A routed
+/// trade is a chain of these, each spending the previous hop's output.
+fn swap_hops(commands: &[SuiCommand]) -> Vec<(usize, &SuiProgrammableMoveCall)> {
+    let config = AFTERMATH_CONFIG.get_or_init(Config::new);
+    commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| match command {
+            SuiCommand::MoveCall(pwc)
+                if config.can_handle(&pwc.package.to_hex_literal(), &pwc.module, &pwc.function)
+                    && SwapFunctions::try_from(pwc.function.as_str()).is_ok() =>
+            {
+                Some((index, pwc.as_ref()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// 1-based number of the hop whose output `coin_arg` consumes, if any
+fn feeding_hop(
+    hops: &[(usize, &SuiProgrammableMoveCall)],
+    coin_arg: &SuiArgument,
+) -> Option<usize> {
+    let command_index = match coin_arg {
+        SuiArgument::Result(index) | SuiArgument::NestedResult(index, _) => *index as usize,
+        _ => return None,
+    };
+    hops.iter()
+        .position(|(index, _)| *index == command_index)
+        .map(|position| position + 1)
+}
+
+/// "SUI -> USDC -> CETUS" from the coin types of each hop
+fn route_summary(hops: &[(usize, &SuiProgrammableMoveCall)]) -> String {
+    let symbol = |pwc: &SuiProgrammableMoveCall, index| {
+        get_tx_type_arg::<SuiCoin>(&pwc.type_arguments, index)
+            .unwrap_or_default()
+            .symbol()
+            .to_string()
+    };
+
+    let mut route: Vec<String> = hops
+        .first()
+        .map(|(_, pwc)| symbol(pwc, SWAP_COIN_IN_TYPE))
+        .into_iter()
+        .collect();
+    route.extend(hops.iter().map(|(_, pwc)| symbol(pwc, SWAP_COIN_OUT_TYPE)));
+    route.join(" -> ")
+}
+
+/// Render an 18-decimal slippage fraction as a percentage, to 0.01%
+fn format_slippage(slippage: u64) -> String {
+    let hundredths = slippage / SLIPPAGE_HUNDREDTH_PERCENT;
+    let fraction = format!("{:02}", hundredths % 100);
+    match fraction.trim_end_matches('0') {
+        "" => format!("{}%", hundredths / 100),
+        fraction => format!("{}.{fraction}%", hundredths / 100),
+    }
+}
+
+fn preview_field(
+    label: &str,
+    title_text: String,
+    subtitle_text: String,
+    condensed: SignablePayloadFieldListLayout,
+    expanded_fields: Vec<AnnotatedPayloadField>,
+) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: title_text.clone(),
+                label: label.to_string(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 { text: title_text }),
+                subtitle: Some(SignablePayloadFieldTextV2 {
+                    text: subtitle_text,
+                }),
+                condensed: Some(condensed),
+                expanded: Some(SignablePayloadFieldListLayout {
+                    fields: expanded_fields,
+                }),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::{
+        assert_has_field, assert_has_field_with_value, assert_has_fields_with_values_with_context,
+    };
+
+    #[test]
+    fn test_aftermath_two_hop_route() {
+        // SplitCoins(GasCoin, [1 SUI]) -> swap_exact_in<_, SUI, USDC> ->
+        // swap_exact_in<_, USDC, CETUS> on the first hop's output -> transfer
+        let test_data = "AAANAAgAypo7AAAAAAEB8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fHx8fEBAAAAAAAAAAEBAeHh4eHh4eHh4eHh4eHh4eHh4eHh4eHh4eHh4eHh4eHhAQAAAAAAAAABAQHi4uLi4uLi4uLi4uLi4uLi4uLi4uLi4uLi4uLi4uLi4gEAAAAAAAAAAQEB4+Pj4+Pj4+Pj4+Pj4+Pj4+Pj4+Pj4+Pj4+Pj4+Pj4+MBAAAAAAAAAAEBAeTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTkAQAAAAAAAAABAQHl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5QEAAAAAAAAAAQAI4Gc1AAAAAAAACAAAwW/yhiMAAQHy8vLy8vLy8vLy8vLy8vLy8vLy8vLy8vLy8vLy8vLy8gEAAAAAAAAAAQAIAJAvUAkAAAAACACA4Dd5wxEAACCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwQCAAEBAAAAxASbLRzA9uAX/agmDkN3zs0ja9f1alT+4SCBbnLi4N0Ec3dhcA1zd2FwX2V4YWN0X2luAwehoaGhoaGhoaGhoaGhoaGhoaGhoaGhoaGhoaGhoaGhoQVhZl9scAVBRl9MUAAHAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIDc3VpA1NVSQAH26NGcuMMsGWx+T46tVMYdo/W/vZsFZQsn3y4RuL5AOcEdXNkYwRVU0RDAAkBAQABAgABAwABBAABBQABBgADAAAAAAEHAAEIAADEBJstHMD24Bf9qCYOQ3fOzSNr1/VqVP7hIIFucuLg3QRzd2FwDXN3YXBfZXhhY3RfaW4DB6KioqKioqKioqKioqKioqKioqKioqKioqKioqKioqKiBWFmX2xwBUFGX0xQAAfbo0Zy4wywZbH5Pjq1Uxh2j9b+9mwVlCyffLhG4vkA5wR1c2RjBFVTREMABwaGSm+SGASGCTDbbdvi4WrN+FBElep0gWN6HIuaj+VLBWNldHVzBUNFVFVTAAkBCQABAgABAwABBAABBQABBgACAQABCgABCwABAQICAAEMAKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NAQAAAAAAAAAgIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiKrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq+gDAAAAAAAAgJaYAAAAAAAA";
+
+        let payload = payload_from_b64(test_data);
+        let context = "two-hop Aftermath route";
+        assert_has_fields_with_values_with_context(
+            &payload,
+            "Hop",
+            &["1 of 2".to_string(), "2 of 2".to_string()],
+            context,
+        );
+        assert_has_fields_with_values_with_context(
+            &payload,
+            "Route",
+            &[
+                "SUI -> USDC -> CETUS".to_string(),
+                "SUI -> USDC -> CETUS".to_string(),
+            ],
+            context,
+        );
+        assert_has_fields_with_values_with_context(
+            &payload,
+            "Amount In",
+            &["1".to_string(), "Output of hop 1".to_string()],
+            context,
+        );
+        assert_has_fields_with_values_with_context(
+            &payload,
+            "Allowable Slippage",
+            &["1%".to_string(), "0.5%".to_string()],
+            context,
+        );
+    }
+
+    #[test]
+    fn test_aftermath_stake_for_afsui() {
+        let test_data = "AAAHAAgAlDV3AAAAAAEBsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbEBAAAAAAAAAAEBAbKysrKysrKysrKysrKysrKysrKysrKysrKysrKysrKyAQAAAAAAAAABAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABQEAAAAAAAAAAQEBs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7Ozs7MBAAAAAAAAAAEAIHZ2dnZ2dnZ2dnZ2dnZ2dnZ2dnZ2dnZ2dnZ2dnZ2dnZ2ACCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwMCAAEBAAAAf2znreY4V8T9Fu93g/7S38TX+35AYVq9tlMDC3au8MYQc3Rha2VkX3N1aV92YXVsdA1yZXF1ZXN0X3N0YWtlAAYBAQABAgABAwABBAADAAAAAAEFAAEBAgEAAQYAq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sBzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc0BAAAAAAAAACAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIqurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6ur6AMAAAAAAACAlpgAAAAAAAA=";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Aftermath Stake");
+        assert_has_field_with_value(&payload, "Amount", "2");
+        assert_has_field_with_value(
+            &payload,
+            "Validator",
+            "0x7676767676767676767676767676767676767676767676767676767676767676",
+        );
+    }
+
+    #[test]
+    fn test_format_slippage() {
+        assert_eq!(format_slippage(10_000_000_000_000_000), "1%");
+        assert_eq!(format_slippage(5_000_000_000_000_000), "0.5%");
+        assert_eq!(format_slippage(1_230_000_000_000_000), "0.12%");
+        assert_eq!(format_slippage(0), "0%");
+    }
+}
@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+// Bluefin v2 perpetuals. Every amount, price, quantity and leverage argument
+// is a u128 fixed-point number with 18 decimals.
+crate::chain_config! {
+    config BLUEFIN_CONFIG as Config;
+
+    bluefin_mainnet => {
+        package_id => 0xcb4e1ee2a3d6323c70e7b06a8638de6736982cbdc08317d33e6f098747e2b438,
+        modules as BluefinModules: {
+            exchange as Exchange => ExchangeFunctions: {
+                trade as Trade => TradeIndexes(
+                    maker_flags as MakerFlags: u8 => 9 => get_maker_flags,
+                    maker_price as MakerPrice: u128 => 10 => get_maker_price,
+                    maker_quantity as MakerQuantity: u128 => 11 => get_maker_quantity,
+                    maker_leverage as MakerLeverage: u128 => 12 => get_maker_leverage,
+                    taker_flags as TakerFlags: u8 => 18 => get_taker_flags,
+                    taker_price as TakerPrice: u128 => 19 => get_taker_price,
+                    taker_quantity as TakerQuantity: u128 => 20 => get_taker_quantity,
+                    taker_leverage as TakerLeverage: u128 => 21 => get_taker_leverage,
+                    fill_quantity as FillQuantity: u128 => 27 => get_fill_quantity,
+                    fill_price as FillPrice: u128 => 28 => get_fill_price,
+                ),
+                add_margin as AddMargin => AddMarginIndexes(
+                    amount as Amount: u128 => 7 => get_amount,
+                ),
+                remove_margin as RemoveMargin => RemoveMarginIndexes(
+                    amount as Amount: u128 => 7 => get_amount,
+                ),
+                adjust_leverage as AdjustLeverage => AdjustLeverageIndexes(
+                    leverage as Leverage: u128 => 7 => get_leverage,
+                ),
+            },
+        }
+    },
+}
@@ -0,0 +1,388 @@
+mod config;
+
+use config::{
+    AddMarginIndexes, AdjustLeverageIndexes, BLUEFIN_CONFIG, Config, ExchangeFunctions,
+    RemoveMarginIndexes, TradeIndexes,
+};
+
+use crate::core::{CommandVisualizer, SuiIntegrationConfig, VisualizerContext, VisualizerKind};
+use crate::utils::{get_address_value, get_object_value, truncate_address};
+
+use sui_json_rpc_types::{SuiCommand, SuiProgrammableMoveCall};
+use sui_types::base_types::SuiAddress;
+
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_amount_field, create_text_field},
+};
+
+/// Bluefin fixed-point numbers carry 18 decimals
+const BLUEFIN_DECIMALS: usize = 18;
+
+/// Bluefin settles margin in USDC
+const MARGIN_SYMBOL: &str = "USDC";
+
+/// `Perpetual` object (the market) in every supported `exchange` call
+const PERPETUAL_ARG: usize = 1;
+/// Sub-account owner in the margin and leverage calls
+const MARGIN_ACCOUNT_ARG: usize = 6;
+/// Maker and taker order owners in `trade`
+const MAKER_ADDRESS_ARG: usize = 15;
+const TAKER_ADDRESS_ARG: usize = 24;
+
+/// Order flag bits, as packed by the Bluefin order book
+const FLAG_IOC: u8 = 1 << 0;
+const FLAG_POST_ONLY: u8 = 1 << 1;
+const FLAG_REDUCE_ONLY: u8 = 1 << 2;
+const FLAG_IS_BUY: u8 = 1 << 3;
+
+pub struct BluefinVisualizer;
+
+impl CommandVisualizer for BluefinVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let Some(SuiCommand::MoveCall(pwc)) = context.commands().get(context.command_index())
+        else {
+            return Err(VisualSignError::MissingData(
+                "Expected a `MoveCall` for Bluefin parsing".into(),
+            ));
+        };
+
+        match pwc.function.as_str().try_into()? {
+            ExchangeFunctions::Trade => Self::handle_trade(context, pwc),
+            ExchangeFunctions::AddMargin => {
+                let amount = AddMarginIndexes::get_amount(context.inputs(), &pwc.arguments)?;
+                Self::handle_margin(context, pwc, "Add Margin", amount)
+            }
+            ExchangeFunctions::RemoveMargin => {
+                let amount = RemoveMarginIndexes::get_amount(context.inputs(), &pwc.arguments)?;
+                Self::handle_margin(context, pwc, "Remove Margin", amount)
+            }
+            ExchangeFunctions::AdjustLeverage => Self::handle_adjust_leverage(context, pwc),
+        }
+    }
+
+    fn get_config(&self) -> Option<&dyn SuiIntegrationConfig> {
+        Some(BLUEFIN_CONFIG.get_or_init(Config::new))
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Derivatives("Bluefin")
+    }
+}
+
+/// One side of a matched trade
+struct Order {
+    flags: u8,
+    price: u128,
+    quantity: u128,
+    leverage: u128,
+    owner: Option<SuiAddress>,
+}
+
+impl Order {
+    fn direction(&self) -> &'static str {
+        if self.flags & FLAG_IS_BUY != 0 {
+            "Long"
+        } else {
+            "Short"
+        }
+    }
+
+    /// Execution flags set on the order, or "Limit" when none are
+    fn order_type(&self) -> String {
+        let names: Vec<&str> = [
+            (FLAG_IOC, "IOC"),
+            (FLAG_POST_ONLY, "Post Only"),
+            (FLAG_REDUCE_ONLY, "Reduce Only"),
+        ]
+        .into_iter()
+        .filter(|(flag, _)| self.flags & flag != 0)
+        .map(|(_, name)| name)
+        .collect();
+
+        if names.is_empty() {
+            "Limit".to_string()
+        } else {
+            names.join(", ")
+        }
+    }
+
+    fn fields(&self, side: &str) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let mut fields = Vec::new();
+        if let Some(owner) = self.owner {
+            fields.push(create_address_field(
+                &format!("{side} Account"),
+                &owner.to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?);
+        }
+        fields.extend([
+            create_text_field(&format!("{side} Direction"), self.direction())?,
+            create_text_field(&format!("{side} Order Type"), &self.order_type())?,
+            create_text_field(&format!("{side} Size"), &format_fixed(self.quantity))?,
+            create_amount_field(
+                &format!("{side} Price"),
+                &format_fixed(self.price),
+                MARGIN_SYMBOL,
+            )?,
+            create_text_field(
+                &format!("{side} Leverage"),
+                &format!("{}x", format_fixed(self.leverage)),
+            )?,
+        ]);
+        Ok(fields)
+    }
+}
+
+impl BluefinVisualizer {
+    fn handle_trade(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let inputs = context.inputs();
+        let args = &pwc.arguments;
+
+        let maker = Order {
+            flags: TradeIndexes::get_maker_flags(inputs, args)?,
+            price: TradeIndexes::get_maker_price(inputs, args)?,
+            quantity: TradeIndexes::get_maker_quantity(inputs, args)?,
+            leverage: TradeIndexes::get_maker_leverage(inputs, args)?,
+            owner: get_address_value(args, inputs, MAKER_ADDRESS_ARG).ok(),
+        };
+        let taker = Order {
+            flags: TradeIndexes::get_taker_flags(inputs, args)?,
+            price: TradeIndexes::get_taker_price(inputs, args)?,
+            quantity: TradeIndexes::get_taker_quantity(inputs, args)?,
+            leverage: TradeIndexes::get_taker_leverage(inputs, args)?,
+            owner: get_address_value(args, inputs, TAKER_ADDRESS_ARG).ok(),
+        };
+        let fill_quantity = format_fixed(TradeIndexes::get_fill_quantity(inputs, args)?);
+        let fill_price = format_fixed(TradeIndexes::get_fill_price(inputs, args)?);
+        let market = get_object_value(args, inputs, PERPETUAL_ARG)?.to_string();
+
+        let title_text = format!(
+            "Bluefin: {} {} at {} {} ({}x)",
+            taker.direction(),
+            fill_quantity,
+            fill_price,
+            MARGIN_SYMBOL,
+            format_fixed(taker.leverage)
+        );
+        let subtitle_text = format!("Market {}", truncate_address(&market));
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![create_text_field(
+                "Summary",
+                &format!(
+                    "{} {} at {} {} with {}x leverage",
+                    taker.direction(),
+                    fill_quantity,
+                    fill_price,
+                    MARGIN_SYMBOL,
+                    format_fixed(taker.leverage)
+                ),
+            )?],
+        };
+
+        let mut expanded_fields = vec![
+            create_address_field("Market", &market, None, None, None, None)?,
+            create_text_field("Fill Size", &fill_quantity)?,
+            create_amount_field("Fill Price", &fill_price, MARGIN_SYMBOL)?,
+        ];
+        expanded_fields.extend(taker.fields("Taker")?);
+        expanded_fields.extend(maker.fields("Maker")?);
+
+        Ok(vec![preview_field(
+            "Bluefin Trade",
+            title_text,
+            subtitle_text,
+            condensed,
+            expanded_fields,
+        )])
+    }
+
+    fn handle_margin(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+        action: &str,
+        amount: u128,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let amount = format_fixed(amount);
+        let market = get_object_value(&pwc.arguments, context.inputs(), PERPETUAL_ARG)?;
+
+        let title_text = format!("Bluefin: {action} {amount} {MARGIN_SYMBOL}");
+        let subtitle_text = format!("Market {}", truncate_address(&market.to_string()));
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![create_amount_field("Amount", &amount, MARGIN_SYMBOL)?],
+        };
+
+        let expanded_fields = vec![
+            create_address_field("Market", &market.to_string(), None, None, None, None)?,
+            Self::account_field(context, pwc)?,
+            create_amount_field("Amount", &amount, MARGIN_SYMBOL)?,
+        ];
+
+        Ok(vec![preview_field(
+            &format!("Bluefin {action}"),
+            title_text,
+            subtitle_text,
+            condensed,
+            expanded_fields,
+        )])
+    }
+
+    fn handle_adjust_leverage(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let leverage = format!(
+            "{}x",
+            format_fixed(AdjustLeverageIndexes::get_leverage(
+                context.inputs(),
+                &pwc.arguments
+            )?)
+        );
+        let market = get_object_value(&pwc.arguments, context.inputs(), PERPETUAL_ARG)?;
+
+        let title_text = format!("Bluefin: Adjust Leverage to {leverage}");
+        let subtitle_text = format!("Market {}", truncate_address(&market.to_string()));
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![create_text_field("Leverage", &leverage)?],
+        };
+
+        let expanded_fields = vec![
+            create_address_field("Market", &market.to_string(), None, None, None, None)?,
+            Self::account_field(context, pwc)?,
+            create_text_field("Leverage", &leverage)?,
+        ];
+
+        Ok(vec![preview_field(
+            "Bluefin Adjust Leverage",
+            title_text,
+            subtitle_text,
+            condensed,
+            expanded_fields,
+        )])
+    }
+
+    /// The sub-account being changed, falling back to the sender when the
+    /// argument is not a pure address
+    fn account_field(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<AnnotatedPayloadField, VisualSignError> {
+        let account = get_address_value(&pwc.arguments, context.inputs(), MARGIN_ACCOUNT_ARG)
+            .unwrap_or(*context.sender());
+        create_address_field("Account", &account.to_string(), None, None, None, None)
+    }
+}
+
+fn preview_field(
+    label: &str,
+    title_text: String,
+    subtitle_text: String,
+    condensed: SignablePayloadFieldListLayout,
+    expanded_fields: Vec<AnnotatedPayloadField>,
+) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: title_text.clone(),
+                label: label.to_string(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 { text: title_text }),
+                subtitle: Some(SignablePayloadFieldTextV2 {
+                    text: subtitle_text,
+                }),
+                condensed: Some(condensed),
+                expanded: Some(SignablePayloadFieldListLayout {
+                    fields: expanded_fields,
+                }),
+            },
+        },
+    }
+}
+
+/// Render an 18-decimal fixed-point value without trailing zeros
+fn format_fixed(value: u128) -> String {
+    let digits = format!("{value:0>width$}", width = BLUEFIN_DECIMALS + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - BLUEFIN_DECIMALS);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{fraction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+
+    #[test]
+    fn test_bluefin_add_margin() {
+        // add_margin(clock, perpetual, bank, sub_accounts, sequencer, tx_hash,
+        // sender, 250e18, price_oracle)
+        let test_data = "AAAJAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABgEAAAAAAAAAAAEBXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl4BAAAAAAAAAAEBAbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxAQAAAAAAAAABAQHCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwgEAAAAAAAAAAQEB09PT09PT09PT09PT09PT09PT09PT09PT09PT09PT09MBAAAAAAAAAAEAISABAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQAgq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sAEAAAqHdxa3KNDQAAAAAAAAABAeTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTkAQAAAAAAAAAAAQDLTh7io9YyPHDnsGqGON5nNpgsvcCDF9M+bwmHR+K0OAhleGNoYW5nZQphZGRfbWFyZ2luAAkBAAABAQABAgABAwABBAABBQABBgABBwABCACrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwHNzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NzQEAAAAAAAAAICIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6voAwAAAAAAAICWmAAAAAAAAA==";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Bluefin Add Margin");
+        assert_has_field_with_value(&payload, "Amount", "250");
+        assert_has_field_with_value(
+            &payload,
+            "Market",
+            "0x5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e5e",
+        );
+    }
+
+    #[test]
+    fn test_bluefin_adjust_leverage() {
+        let test_data = "AAAJAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABgEAAAAAAAAAAAEBXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl5eXl4BAAAAAAAAAAEBAbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxsbGxAQAAAAAAAAABAQHCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwgEAAAAAAAAAAQEB09PT09PT09PT09PT09PT09PT09PT09PT09PT09PT09MBAAAAAAAAAAEAISABAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQAgq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sAEAAA9ESCkWNFAAAAAAAAAAABAeTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTk5OTkAQAAAAAAAAAAAQDLTh7io9YyPHDnsGqGON5nNpgsvcCDF9M+bwmHR+K0OAhleGNoYW5nZQ9hZGp1c3RfbGV2ZXJhZ2UACQEAAAEBAAECAAEDAAEEAAEFAAEGAAEHAAEIAKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NAQAAAAAAAAAgIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiKrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq+gDAAAAAAAAgJaYAAAAAAAA";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Bluefin Adjust Leverage");
+        assert_has_field_with_value(&payload, "Leverage", "5x");
+    }
+
+    #[test]
+    fn test_format_fixed() {
+        assert_eq!(format_fixed(0), "0");
+        assert_eq!(format_fixed(5_000_000_000_000_000_000), "5");
+        assert_eq!(format_fixed(1_500_000_000_000_000), "0.0015");
+        assert_eq!(format_fixed(2_345_600_000_000_000_000_000), "2345.6");
+    }
+
+    #[test]
+    fn test_order_flags() {
+        let order = Order {
+            flags: FLAG_IS_BUY | FLAG_REDUCE_ONLY | FLAG_IOC,
+            price: 0,
+            quantity: 0,
+            leverage: 0,
+            owner: None,
+        };
+        assert_eq!(order.direction(), "Long");
+        assert_eq!(order.order_type(), "IOC, Reduce Only");
+
+        let order = Order { flags: 0, ..order };
+        assert_eq!(order.direction(), "Short");
+        assert_eq!(order.order_type(), "Limit");
+    }
+}
@@ -10,9 +10,9 @@ use config::{
 };
 
 use crate::core::{CommandVisualizer, SuiIntegrationConfig, VisualizerContext, VisualizerKind};
-use crate::utils::{SuiCoin, get_object_value, get_tx_type_arg, truncate_address};
+use crate::utils::{CoinAmount, SuiCoin, get_object_value, get_tx_type_arg, truncate_address};
 
-use sui_json_rpc_types::{SuiCommand, SuiProgrammableMoveCall};
+use sui_json_rpc_types::{SuiArgument, SuiCommand, SuiProgrammableMoveCall};
 
 use crate::presets::cetus::config::{
     PoolScriptOpenPositionWithLiquidityWithAllIndexes, PoolScriptV3Functions,
@@ -268,8 +268,14 @@ impl CetusVisualizer {
 
         let (input_coin, output_coin): (SuiCoin, SuiCoin) =
             Self::determine_input_output_coins(is_a2b, pwc);
-        let (primary_label, primary_symbol, limit_label, limit_symbol) =
-            Self::determine_primary_limit_labels(&input_coin, &output_coin, by_amount_in);
+        let (primary_label, primary, limit_label, limit) = Self::primary_limit_amounts(
+            context,
+            &input_coin,
+            &output_coin,
+            by_amount_in,
+            amount,
+            amount_limit,
+        );
 
         let mut list_layout_fields = vec![
             create_address_field(
@@ -280,9 +286,9 @@ impl CetusVisualizer {
                 None,
                 None,
             )?,
-            create_amount_field(primary_label, &amount.to_string(), primary_symbol)?,
+            primary.field(primary_label)?,
             create_text_field("Input Coin", &input_coin.to_string())?,
-            create_amount_field(limit_label, &amount_limit.to_string(), limit_symbol)?,
+            limit.field(limit_label)?,
             create_text_field("Output Coin", &output_coin.to_string())?,
         ];
 
@@ -298,10 +304,9 @@ impl CetusVisualizer {
         )?);
 
         let title_text = format!(
-            "CetusAMM Swap: {} From {} To {}",
-            amount,
-            input_coin.base_unit_symbol(),
-            output_coin.base_unit_symbol()
+            "CetusAMM Swap: {primary} From {} To {}",
+            input_coin.symbol(),
+            output_coin.symbol()
         );
         let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
 
@@ -310,17 +315,9 @@ impl CetusVisualizer {
             fields: vec![create_text_field(
                 "Summary",
                 &format!(
-                    "Swap {} to {} ({}: {} {}, Price Limit: {})",
-                    input_coin.base_unit_symbol(),
-                    output_coin.base_unit_symbol(),
-                    limit_label,
-                    amount_limit,
-                    if by_amount_in {
-                        output_coin.base_unit_symbol()
-                    } else {
-                        input_coin.base_unit_symbol()
-                    },
-                    price_hint,
+                    "Swap {} to {} ({limit_label}: {limit}, Price Limit: {price_hint})",
+                    input_coin.symbol(),
+                    output_coin.symbol(),
                 ),
             )?],
         };
@@ -366,24 +363,29 @@ impl CetusVisualizer {
         }
     }
 
-    fn determine_primary_limit_labels<'a>(
-        input_coin: &'a SuiCoin,
-        output_coin: &'a SuiCoin,
+    /// Labels the swap amount and its slippage limit. An exact-in swap fixes
+    /// the input and bounds the output; an exact-out swap does the reverse.
+    fn primary_limit_amounts(
+        context: &VisualizerContext,
+        input_coin: &SuiCoin,
+        output_coin: &SuiCoin,
         by_amount_in: bool,
-    ) -> (&'a str, &'a str, &'a str, &'a str) {
+        amount: u64,
+        amount_limit: u64,
+    ) -> (&'static str, CoinAmount, &'static str, CoinAmount) {
         if by_amount_in {
             (
                 "Amount In",
-                input_coin.base_unit_symbol(),
+                context.coin_amount(amount, input_coin),
                 "Min Out",
-                output_coin.base_unit_symbol(),
+                context.coin_amount(amount_limit, output_coin),
             )
         } else {
             (
                 "Amount Out",
-                output_coin.base_unit_symbol(),
+                context.coin_amount(amount, output_coin),
                 "Max In",
-                input_coin.base_unit_symbol(),
+                context.coin_amount(amount_limit, input_coin),
             )
         }
     }
@@ -400,8 +402,14 @@ impl CetusVisualizer {
         let (input_coin, output_coin): (SuiCoin, SuiCoin) =
             Self::determine_input_output_coins(is_a2b, pwc);
 
-        let (primary_label, primary_symbol, limit_label, limit_symbol) =
-            Self::determine_primary_limit_labels(&input_coin, &output_coin, by_amount_in);
+        let (primary_label, primary, limit_label, limit) = Self::primary_limit_amounts(
+            context,
+            &input_coin,
+            &output_coin,
+            by_amount_in,
+            amount,
+            amount_limit,
+        );
 
         let mut list_layout_fields = vec![
             create_address_field(
@@ -420,17 +428,16 @@ impl CetusVisualizer {
                 None,
                 None,
             )?,
-            create_amount_field(primary_label, &amount.to_string(), primary_symbol)?,
+            primary.field(primary_label)?,
             create_text_field("Input Coin", &input_coin.to_string())?,
-            create_amount_field(limit_label, &amount_limit.to_string(), limit_symbol)?,
+            limit.field(limit_label)?,
             create_text_field("Output Coin", &output_coin.to_string())?,
         ];
 
         let title_text = format!(
-            "CetusAMM Swap: {} From {} To {}",
-            amount,
-            input_coin.base_unit_symbol(),
-            output_coin.base_unit_symbol()
+            "CetusAMM Swap: {primary} From {} To {}",
+            input_coin.symbol(),
+            output_coin.symbol()
         );
         let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
 
@@ -445,16 +452,9 @@ impl CetusVisualizer {
             fields: vec![create_text_field(
                 "Summary",
                 &format!(
-                    "Swap {} to {} ({}: {} {})",
-                    input_coin.base_unit_symbol(),
-                    output_coin.base_unit_symbol(),
-                    limit_label,
-                    amount_limit,
-                    if by_amount_in {
-                        output_coin.base_unit_symbol()
-                    } else {
-                        input_coin.base_unit_symbol()
-                    }
+                    "Swap {} to {} ({limit_label}: {limit})",
+                    input_coin.symbol(),
+                    output_coin.symbol()
                 ),
             )?],
         };
@@ -488,8 +488,10 @@ impl CetusVisualizer {
         pwc: &SuiProgrammableMoveCall,
     ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
         let coin: SuiCoin = get_tx_type_arg(&pwc.type_arguments, 0).unwrap_or_default();
-        let threshold =
-            RouterCheckCoinThresholdIndexes::get_threshold(context.inputs(), &pwc.arguments)?;
+        let threshold = context.coin_amount(
+            RouterCheckCoinThresholdIndexes::get_threshold(context.inputs(), &pwc.arguments)?,
+            &coin,
+        );
 
         let list_layout_fields = vec![
             create_address_field(
@@ -501,24 +503,16 @@ impl CetusVisualizer {
                 None,
             )?,
             create_text_field("Coin", &coin.to_string())?,
-            create_amount_field("Threshold", &threshold.to_string(), coin.base_unit_symbol())?,
+            threshold.field("Threshold")?,
         ];
 
-        let title_text = format!(
-            "Cetus Router: Check Coin Threshold {} {}",
-            threshold,
-            coin.base_unit_symbol()
-        );
+        let title_text = format!("Cetus Router: Check Coin Threshold {threshold}");
         let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
 
         let condensed = SignablePayloadFieldListLayout {
             fields: vec![create_text_field(
                 "Summary",
-                &format!(
-                    "Check {} balance threshold {}",
-                    coin.base_unit_symbol(),
-                    threshold
-                ),
+                &format!("Check {} balance threshold {threshold}", coin.symbol()),
             )?],
         };
 
@@ -569,9 +563,14 @@ impl CetusVisualizer {
                 None,
                 None,
             )?,
+            position_field(context, pwc)?,
             create_text_field("Pool Coin A", &coin_a.to_string())?,
             create_text_field("Pool Coin B", &coin_b.to_string())?,
             create_text_field("Reward Coin", &reward_coin.to_string())?,
+            create_text_field(
+                "Collects",
+                &format!("All accrued {} rewards", reward_coin.symbol()),
+            )?,
         ];
 
         let title_text = format!(
@@ -584,10 +583,11 @@ impl CetusVisualizer {
             fields: vec![create_text_field(
                 "Summary",
                 &format!(
-                    "Collect rewards ({}) from pool {}/{}",
+                    "Collect rewards ({}) from pool {}/{} for {}",
                     reward_coin.base_unit_symbol(),
                     coin_a.base_unit_symbol(),
-                    coin_b.base_unit_symbol()
+                    coin_b.base_unit_symbol(),
+                    describe_position(context, pwc)
                 ),
             )?],
         };
@@ -638,8 +638,17 @@ impl CetusVisualizer {
                 None,
                 None,
             )?,
+            position_field(context, pwc)?,
             create_text_field("Pool Coin A", &coin_a.to_string())?,
             create_text_field("Pool Coin B", &coin_b.to_string())?,
+            create_text_field(
+                "Collects",
+                &format!(
+                    "All accrued {} and {} fees",
+                    coin_a.symbol(),
+                    coin_b.symbol()
+                ),
+            )?,
         ];
 
         let title_text = "CetusAMM Collect Fee".to_string();
@@ -649,9 +658,10 @@ impl CetusVisualizer {
             fields: vec![create_text_field(
                 "Summary",
                 &format!(
-                    "Collect fee from pool {}/{}",
+                    "Collect fee from pool {}/{} for {}",
                     coin_a.base_unit_symbol(),
-                    coin_b.base_unit_symbol()
+                    coin_b.base_unit_symbol(),
+                    describe_position(context, pwc)
                 ),
             )?],
         };
@@ -700,6 +710,8 @@ impl CetusVisualizer {
         min_a: u64,
         min_b: u64,
     ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let min_a = context.coin_amount(min_a, coin_a);
+        let min_b = context.coin_amount(min_b, coin_b);
         let list_layout_fields = vec![
             create_address_field(
                 "Pool Address",
@@ -717,10 +729,12 @@ impl CetusVisualizer {
                 None,
                 None,
             )?,
+            position_field(context, pwc)?,
             create_text_field("Pool Coin A", &coin_a.to_string())?,
             create_text_field("Pool Coin B", &coin_b.to_string())?,
-            create_amount_field("Min Out A", &min_a.to_string(), coin_a.base_unit_symbol())?,
-            create_amount_field("Min Out B", &min_b.to_string(), coin_b.base_unit_symbol())?,
+            create_text_field("Liquidity", "All")?,
+            min_a.field("Min Out A")?,
+            min_b.field("Min Out B")?,
         ];
 
         let title_text = "CetusAMM Close Position".to_string();
@@ -729,13 +743,7 @@ impl CetusVisualizer {
         let condensed = SignablePayloadFieldListLayout {
             fields: vec![create_text_field(
                 "Summary",
-                &format!(
-                    "Close position and withdraw at least {} {} and {} {}",
-                    min_a,
-                    coin_a.base_unit_symbol(),
-                    min_b,
-                    coin_b.base_unit_symbol()
-                ),
+                &format!("Close position and withdraw at least {min_a} and {min_b}"),
             )?],
         };
 
@@ -786,6 +794,8 @@ impl CetusVisualizer {
         min_a: u64,
         min_b: u64,
     ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let min_a = context.coin_amount(min_a, coin_a);
+        let min_b = context.coin_amount(min_b, coin_b);
         let list_layout_fields = vec![
             create_address_field(
                 "Pool Address",
@@ -803,11 +813,12 @@ impl CetusVisualizer {
                 None,
                 None,
             )?,
+            position_field(context, pwc)?,
             create_text_field("Pool Coin A", &coin_a.to_string())?,
             create_text_field("Pool Coin B", &coin_b.to_string())?,
             create_amount_field("Liquidity", &liquidity.to_string(), "RAW")?,
-            create_amount_field("Min Out A", &min_a.to_string(), coin_a.base_unit_symbol())?,
-            create_amount_field("Min Out B", &min_b.to_string(), coin_b.base_unit_symbol())?,
+            min_a.field("Min Out A")?,
+            min_b.field("Min Out B")?,
         ];
 
         let title_text = "CetusAMM Remove Liquidity".to_string();
@@ -816,14 +827,10 @@ impl CetusVisualizer {
             fields: vec![create_text_field(
                 "Summary",
                 &format!(
-                    "Remove liquidity {} from {}/{} (min {} {}, {} {})",
+                    "Remove liquidity {} from {}/{} (min {min_a}, {min_b})",
                     liquidity,
-                    coin_a.base_unit_symbol(),
-                    coin_b.base_unit_symbol(),
-                    min_a,
-                    coin_a.base_unit_symbol(),
-                    min_b,
-                    coin_b.base_unit_symbol()
+                    coin_a.symbol(),
+                    coin_b.symbol()
                 ),
             )?],
         };
@@ -863,6 +870,8 @@ impl CetusVisualizer {
             AddLiquidityByFixCoinIndexes::get_is_fix_a(context.inputs(), &pwc.arguments)?;
 
         let fix_coin = if is_fix_a { &coin_a } else { &coin_b };
+        let amount_a = context.coin_amount(amount_a, &coin_a);
+        let amount_b = context.coin_amount(amount_b, &coin_b);
 
         let list_layout_fields = vec![
             create_address_field(
@@ -881,11 +890,12 @@ impl CetusVisualizer {
                 None,
                 None,
             )?,
+            position_field(context, pwc)?,
             create_text_field("Pool Coin A", &coin_a.to_string())?,
             create_text_field("Pool Coin B", &coin_b.to_string())?,
             create_text_field("Fix Coin", &fix_coin.to_string())?,
-            create_amount_field("Amount A", &amount_a.to_string(), coin_a.base_unit_symbol())?,
-            create_amount_field("Amount B", &amount_b.to_string(), coin_b.base_unit_symbol())?,
+            amount_a.field("Amount A")?,
+            amount_b.field("Amount B")?,
         ];
 
         let title_text = "CetusAMM Add Liquidity (Fix Coin)".to_string();
@@ -894,12 +904,8 @@ impl CetusVisualizer {
             fields: vec![create_text_field(
                 "Summary",
                 &format!(
-                    "Add liquidity with {} fixed (A: {} {}, B: {} {})",
-                    fix_coin.base_unit_symbol(),
-                    amount_a,
-                    coin_a.base_unit_symbol(),
-                    amount_b,
-                    coin_b.base_unit_symbol()
+                    "Add liquidity with {} fixed (A: {amount_a}, B: {amount_b})",
+                    fix_coin.symbol()
                 ),
             )?],
         };
@@ -979,6 +985,11 @@ impl CetusVisualizer {
         tick_upper_idx: u32,
     ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
         let fix_coin = if is_fix_a { &coin_a } else { &coin_b };
+        let amount_a = context.coin_amount(amount_a, coin_a);
+        let amount_b = context.coin_amount(amount_b, coin_b);
+        // Cetus `I32` ticks travel as the two's-complement bits of a `u32`.
+        let tick_lower = tick_lower_idx.cast_signed();
+        let tick_upper = tick_upper_idx.cast_signed();
         let list_layout_fields = vec![
             create_address_field(
                 "Pool Address",
@@ -999,10 +1010,10 @@ impl CetusVisualizer {
             create_text_field("Pool Coin A", &coin_a.to_string())?,
             create_text_field("Pool Coin B", &coin_b.to_string())?,
             create_text_field("Fix Coin", &fix_coin.to_string())?,
-            create_amount_field("Amount A", &amount_a.to_string(), coin_a.base_unit_symbol())?,
-            create_amount_field("Amount B", &amount_b.to_string(), coin_b.base_unit_symbol())?,
-            create_text_field("Tick Lower Index", &tick_lower_idx.to_string())?,
-            create_text_field("Tick Upper Index", &tick_upper_idx.to_string())?,
+            amount_a.field("Amount A")?,
+            amount_b.field("Amount B")?,
+            create_text_field("Tick Lower Index", &tick_lower.to_string())?,
+            create_text_field("Tick Upper Index", &tick_upper.to_string())?,
         ];
 
         let title_text = "CetusAMM Open Position With Liquidity".to_string();
@@ -1011,12 +1022,8 @@ impl CetusVisualizer {
             fields: vec![create_text_field(
                 "Summary",
                 &format!(
-                    "Open position with {} fixed (A: {} {}, B: {} {})",
-                    fix_coin.base_unit_symbol(),
-                    amount_a,
-                    coin_a.base_unit_symbol(),
-                    amount_b,
-                    coin_b.base_unit_symbol()
+                    "Open position in ticks [{tick_lower}, {tick_upper}] with {} fixed (A: {amount_a}, B: {amount_b})",
+                    fix_coin.symbol()
                 ),
             )?],
         };
@@ -1135,22 +1142,68 @@ impl CetusVisualizer {
     }
 }
 
+/// Cetus position entry points take the position NFT after the global
+/// config and the pool.
+const POSITION_ARG_INDEX: usize = 2;
+
+/// The position NFT is usually an owned input, but a PTB can also act on a
+/// position it opened in an earlier command.
+fn position_field(
+    context: &VisualizerContext,
+    pwc: &SuiProgrammableMoveCall,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    match pwc.arguments.get(POSITION_ARG_INDEX) {
+        Some(SuiArgument::Input(_)) => create_address_field(
+            "Position",
+            &get_object_value(&pwc.arguments, context.inputs(), POSITION_ARG_INDEX)?.to_string(),
+            None,
+            None,
+            None,
+            None,
+        ),
+        Some(SuiArgument::Result(command) | SuiArgument::NestedResult(command, _)) => {
+            create_text_field("Position", &format!("Result of command {command}"))
+        }
+        Some(SuiArgument::GasCoin) | None => Err(VisualSignError::MissingData(
+            "Position argument not found".into(),
+        )),
+    }
+}
+
+fn describe_position(context: &VisualizerContext, pwc: &SuiProgrammableMoveCall) -> String {
+    match pwc.arguments.get(POSITION_ARG_INDEX) {
+        Some(SuiArgument::Input(_)) => {
+            get_object_value(&pwc.arguments, context.inputs(), POSITION_ARG_INDEX).map_or_else(
+                |_| "unknown position".to_string(),
+                |position| format!("position {}", truncate_address(&position.to_string())),
+            )
+        }
+        Some(SuiArgument::Result(command) | SuiArgument::NestedResult(command, _)) => {
+            format!("position from command {command}")
+        }
+        Some(SuiArgument::GasCoin) | None => "unknown position".to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::transaction_string_to_visual_sign;
     use crate::utils::{payload_from_b64, run_aggregated_fixture};
 
+    use generated::parser::{ChainMetadata, SuiCoinInfo, SuiMetadata, chain_metadata};
     use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+    use visualsign::vsptrait::VisualSignOptions;
 
     const CETUS_SWAP_LABEL: &str = "CetusAMM Swap Command";
 
+    // https://suivision.xyz/txblock/7Je4yeXMvvEHFcRSTD4WYv3eSsaDk2zqvdoSxWXdUYGx
+    const SWAP_B2A_TX: &str = "AQAAAAAACQEAEXs/ewhS1RZrUZQ2xQEliCJn40SK4PvEV75r2SGFMXhjUsAjAAAAACBSKqlrLdPXYeuzckz31NAkeSO09qmNPv/pkWggJMTC2QAIuMbAAQAAAAABAdqkYpJjLDxNjzHyPqD5s2oo/zZ36WhJgORDhAOmej2PLgUYAAAAAAAAAQFK94o+ni1sq8pdp5wea/9ImVZqQhMh/DtaYZZkAXpg1nkOqBoAAAAAAQABAQAIuMbAAQAAAAAACI0+GgMAAAAAABCvMxuoMn+7NbHE/v8AAAAAAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABgEAAAAAAAAAAAMCAQAAAQEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgRjb2luBHplcm8BB9ujRnLjDLBlsfk+OrVTGHaP1v72bBWULJ98uEbi+QDnBHVzZGMEVVNEQwAAALLbcUL6gyEKfXjZwSrEnAQ7PLvUgiJP6m49oAqlpa4tDnBvb2xfc2NyaXB0X3YyCHN3YXBfYjJhAgfbo0Zy4wywZbH5Pjq1Uxh2j9b+9mwVlCyffLhG4vkA5wR1c2RjBFVTREMAB7eETiiahBDlD7PKSNaeuc8p4n0iPvkDU/4b2OJ/+PP4BGNvaW4EQ09JTgAJAQIAAQMAAgEAAgAAAQQAAQUAAQYAAQcAAQgArltnUkfA5IdctLm9N6YO1bz4kng0TThA3StCbiinZoUBZI8YcdbCiGOtIFCZV/M9U6lZTgf3lg6t7feHRsBBqR1jUsAjAAAAACCmwR6aeqn8D632smpzU9fbDhP3vPOQhgc806IrzekPH65bZ1JHwOSHXLS5vTemDtW8+JJ4NE04QN0rQm4op2aFBQIAAAAAAAC8YDQAAAAAAAABYQAdbFpPHuOPe/TYRMttj4FSzAN1ErZdI75GooTkFmiIVkvCM+lnSS3pR/qQt6j7K3gsrtBExfgOL/dffWapvuMEyeP1ig9kZWEaY4lMw99QxRTo2PcUhKsb1gquOOAGXP8=";
+
     #[test]
     fn test_cetus_amm_swap_b2a_commands() {
-        // https://suivision.xyz/txblock/7Je4yeXMvvEHFcRSTD4WYv3eSsaDk2zqvdoSxWXdUYGx
-        let test_data = "AQAAAAAACQEAEXs/ewhS1RZrUZQ2xQEliCJn40SK4PvEV75r2SGFMXhjUsAjAAAAACBSKqlrLdPXYeuzckz31NAkeSO09qmNPv/pkWggJMTC2QAIuMbAAQAAAAABAdqkYpJjLDxNjzHyPqD5s2oo/zZ36WhJgORDhAOmej2PLgUYAAAAAAAAAQFK94o+ni1sq8pdp5wea/9ImVZqQhMh/DtaYZZkAXpg1nkOqBoAAAAAAQABAQAIuMbAAQAAAAAACI0+GgMAAAAAABCvMxuoMn+7NbHE/v8AAAAAAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABgEAAAAAAAAAAAMCAQAAAQEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgRjb2luBHplcm8BB9ujRnLjDLBlsfk+OrVTGHaP1v72bBWULJ98uEbi+QDnBHVzZGMEVVNEQwAAALLbcUL6gyEKfXjZwSrEnAQ7PLvUgiJP6m49oAqlpa4tDnBvb2xfc2NyaXB0X3YyCHN3YXBfYjJhAgfbo0Zy4wywZbH5Pjq1Uxh2j9b+9mwVlCyffLhG4vkA5wR1c2RjBFVTREMAB7eETiiahBDlD7PKSNaeuc8p4n0iPvkDU/4b2OJ/+PP4BGNvaW4EQ09JTgAJAQIAAQMAAgEAAgAAAQQAAQUAAQYAAQcAAQgArltnUkfA5IdctLm9N6YO1bz4kng0TThA3StCbiinZoUBZI8YcdbCiGOtIFCZV/M9U6lZTgf3lg6t7feHRsBBqR1jUsAjAAAAACCmwR6aeqn8D632smpzU9fbDhP3vPOQhgc806IrzekPH65bZ1JHwOSHXLS5vTemDtW8+JJ4NE04QN0rQm4op2aFBQIAAAAAAAC8YDQAAAAAAAABYQAdbFpPHuOPe/TYRMttj4FSzAN1ErZdI75GooTkFmiIVkvCM+lnSS3pR/qQt6j7K3gsrtBExfgOL/dffWapvuMEyeP1ig9kZWEaY4lMw99QxRTo2PcUhKsb1gquOOAGXP8=";
-
-        let payload = payload_from_b64(test_data);
+        let payload = payload_from_b64(SWAP_B2A_TX);
         assert_has_field(&payload, CETUS_SWAP_LABEL);
 
         assert_has_field_with_value(
@@ -1164,7 +1217,7 @@ mod tests {
             "Input Coin",
             "0xb7844e289a8410e50fb3ca48d69eb9cf29e27d223ef90353fe1bd8e27ff8f3f8::coin::COIN",
         );
-        assert_has_field_with_value(&payload, "Min Out", "52051597");
+        assert_has_field_with_value(&payload, "Min Out", "52.051597");
         assert_has_field_with_value(
             &payload,
             "Output Coin",
@@ -1172,6 +1225,101 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cetus_swap_scales_amounts_from_coin_metadata() {
+        let metadata = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Sui(SuiMetadata {
+                coins: [(
+                    "0xb7844e289a8410e50fb3ca48d69eb9cf29e27d223ef90353fe1bd8e27ff8f3f8::coin::COIN"
+                        .to_string(),
+                    SuiCoinInfo {
+                        symbol: "WBTC".to_string(),
+                        decimals: 8,
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            })),
+        };
+
+        let payload = transaction_string_to_visual_sign(
+            SWAP_B2A_TX,
+            VisualSignOptions {
+                metadata: Some(metadata),
+                ..Default::default()
+            },
+        )
+        .expect("swap transaction should parse");
+
+        assert_has_field_with_value(&payload, "Amount In", "0.29411");
+        assert_has_field_with_value(&payload, "Min Out", "52.051597");
+    }
+
+    #[test]
+    fn test_cetus_swap_ignores_coin_metadata_under_strict_registry() {
+        let metadata = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Sui(SuiMetadata {
+                coins: [(
+                    "0xb7844e289a8410e50fb3ca48d69eb9cf29e27d223ef90353fe1bd8e27ff8f3f8::coin::COIN"
+                        .to_string(),
+                    SuiCoinInfo {
+                        symbol: "WBTC".to_string(),
+                        decimals: 8,
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            })),
+        };
+
+        let payload = transaction_string_to_visual_sign(
+            SWAP_B2A_TX,
+            VisualSignOptions {
+                metadata: Some(metadata),
+                strict_registry: true,
+                ..Default::default()
+            },
+        )
+        .expect("swap transaction should parse");
+
+        // The unknown coin stays in base units; built-in USDC is still scaled
+        assert_has_field_with_value(&payload, "Amount In", "29411000");
+        assert_has_field_with_value(&payload, "Min Out", "52.051597");
+    }
+
+    #[test]
+    fn test_cetus_swap_metadata_cannot_rescale_known_coins() {
+        let metadata = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Sui(SuiMetadata {
+                coins: [(
+                    "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC"
+                        .to_string(),
+                    SuiCoinInfo {
+                        symbol: "USDC".to_string(),
+                        decimals: 2,
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            })),
+        };
+
+        let payload = transaction_string_to_visual_sign(
+            SWAP_B2A_TX,
+            VisualSignOptions {
+                metadata: Some(metadata),
+                ..Default::default()
+            },
+        )
+        .expect("swap transaction should parse");
+
+        // USDC is a built-in coin, so its 6 decimals win over the request's 2
+        assert_has_field_with_value(&payload, "Min Out", "52.051597");
+    }
+
     #[test]
     fn test_cetus_amm_swap_a2b_commands() {
         // https://suivision.xyz/txblock/7t6iLtevYDEpXrr3rhpmDcwf8cMMV1sgspppvvnXiguR
@@ -1185,13 +1333,13 @@ mod tests {
             "User Address",
             "0x1c688a151ecb2fc4a701648d267551160bbb3fe6ab0c82d6cac068ed6cc982c9",
         );
-        assert_has_field_with_value(&payload, "Max In", "1728516520");
+        assert_has_field_with_value(&payload, "Max In", "1728.51652");
         assert_has_field_with_value(
             &payload,
             "Input Coin",
             "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC",
         );
-        assert_has_field_with_value(&payload, "Amount Out", "500000000000");
+        assert_has_field_with_value(&payload, "Amount Out", "500");
         assert_has_field_with_value(&payload, "Output Coin", "0x2::sui::SUI");
     }
 
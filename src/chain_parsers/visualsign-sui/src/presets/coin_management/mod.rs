@@ -0,0 +1,213 @@
+//! Coin management PTBs: transactions made only of `SplitCoins` and
+//! `MergeCoins`, such as consolidating dust into one coin.
+//!
+//! A split that feeds a transfer or a Move call is already shown by whatever
+//! consumes it, so this visualizer only claims a command when nothing else in
+//! the transaction does.
+
+use crate::core::{
+    CommandVisualizer, SuiIntegrationConfig, VisualizerContext, VisualizerKind,
+    create_object_arg_field,
+};
+use crate::utils::{decode_number, format_sui, truncate_address};
+
+use sui_json_rpc_types::{SuiArgument, SuiCallArg, SuiCommand, SuiObjectArg};
+
+use visualsign::{
+    AnnotatedPayloadField,
+    errors::VisualSignError,
+    field_builders::{create_amount_field, create_preview_layout, create_text_field},
+};
+
+pub struct CoinManagementVisualizer;
+
+impl CommandVisualizer for CoinManagementVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        match context.commands().get(context.command_index()) {
+            Some(SuiCommand::SplitCoins(coin, amounts)) => {
+                visualize_split(context, *coin, amounts).map(|field| vec![field])
+            }
+            Some(SuiCommand::MergeCoins(target, sources)) => {
+                visualize_merge(context, *target, sources).map(|field| vec![field])
+            }
+            _ => Err(VisualSignError::MissingData(
+                "Expected `SplitCoins` or `MergeCoins` for coin management parsing".into(),
+            )),
+        }
+    }
+
+    fn get_config(&self) -> Option<&dyn SuiIntegrationConfig> {
+        None
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Payments("Coin Management")
+    }
+
+    fn can_handle(&self, context: &VisualizerContext) -> bool {
+        let is_coin_command = |command: &SuiCommand| {
+            matches!(
+                command,
+                SuiCommand::SplitCoins(..) | SuiCommand::MergeCoins(..)
+            )
+        };
+
+        context
+            .commands()
+            .get(context.command_index())
+            .is_some_and(is_coin_command)
+            && context.commands().iter().all(is_coin_command)
+    }
+}
+
+fn visualize_split(
+    context: &VisualizerContext,
+    coin: SuiArgument,
+    amounts: &[SuiArgument],
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let is_gas_coin = matches!(coin, SuiArgument::GasCoin);
+
+    let mut fields = vec![coin_field(context, "Source Coin", coin)?];
+    let mut total: Option<u64> = Some(0);
+    for (index, amount) in amounts.iter().enumerate() {
+        let label = format!("Amount {}", index + 1);
+        let value = resolve_amount(context, *amount);
+        total = total
+            .zip(value)
+            .and_then(|(sum, value)| sum.checked_add(value));
+        fields.push(match value {
+            Some(mist) if is_gas_coin => create_amount_field(&label, &format_sui(mist), "SUI")?,
+            Some(value) => create_text_field(&label, &value.to_string())?,
+            None => create_text_field(&label, &describe_argument(context, *amount))?,
+        });
+    }
+
+    let subtitle = match total {
+        Some(total) if is_gas_coin => format!("{} SUI from the gas coin", format_sui(total)),
+        Some(total) => format!("{total} from {}", describe_argument(context, coin)),
+        None => format!("From {}", describe_argument(context, coin)),
+    };
+
+    Ok(create_preview_layout(
+        "Split Coins",
+        format!("Split into {} coin(s): {subtitle}", amounts.len()),
+        fields,
+    ))
+}
+
+fn visualize_merge(
+    context: &VisualizerContext,
+    target: SuiArgument,
+    sources: &[SuiArgument],
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let mut fields = vec![coin_field(context, "Target Coin", target)?];
+    for (index, source) in sources.iter().enumerate() {
+        fields.push(coin_field(
+            context,
+            &format!("Merged Coin {}", index + 1),
+            *source,
+        )?);
+    }
+
+    Ok(create_preview_layout(
+        "Merge Coins",
+        format!(
+            "Merge {} coin(s) into {}",
+            sources.len(),
+            describe_argument(context, target)
+        ),
+        fields,
+    ))
+}
+
+/// A coin is either the gas coin, an object input, or the output of an
+/// earlier command in the same PTB.
+fn coin_field(
+    context: &VisualizerContext,
+    label: &str,
+    coin: SuiArgument,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    match object_input(context, coin) {
+        Some(object) => create_object_arg_field(label, object),
+        None => create_text_field(label, &describe_argument(context, coin)),
+    }
+}
+
+fn object_input<'c>(
+    context: &'c VisualizerContext<'_>,
+    coin: SuiArgument,
+) -> Option<&'c SuiObjectArg> {
+    let SuiArgument::Input(index) = coin else {
+        return None;
+    };
+    match context.inputs().get(usize::from(index))? {
+        SuiCallArg::Object(object) => Some(object),
+        SuiCallArg::Pure(_) => None,
+    }
+}
+
+fn resolve_amount(context: &VisualizerContext, amount: SuiArgument) -> Option<u64> {
+    let SuiArgument::Input(index) = amount else {
+        return None;
+    };
+    decode_number::<u64>(context.inputs().get(usize::from(index))?).ok()
+}
+
+fn describe_argument(context: &VisualizerContext, argument: SuiArgument) -> String {
+    match argument {
+        SuiArgument::GasCoin => "Gas coin".to_string(),
+        SuiArgument::Input(index) => match object_input(context, argument) {
+            Some(
+                SuiObjectArg::ImmOrOwnedObject { object_id, .. }
+                | SuiObjectArg::SharedObject { object_id, .. }
+                | SuiObjectArg::Receiving { object_id, .. },
+            ) => truncate_address(&object_id.to_string()),
+            None => format!("Input {index}"),
+        },
+        SuiArgument::Result(command) => format!("Result of command {command}"),
+        SuiArgument::NestedResult(command, result) => {
+            format!("Result {result} of command {command}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+
+    #[test]
+    fn test_merge_owned_coins() {
+        // Merge 0x2222.. and 0x3333.. into 0x1111..
+        let test_data = "AAADAQAREREREREREREREREREREREREREREREREREREREREREQcAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAQAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIgMAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAQAzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMwkAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAQMBAAACAQEAAQIAq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sBzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc0BAAAAAAAAACAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIqurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6ur6AMAAAAAAACAlpgAAAAAAAA=";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Merge Coins");
+        assert_has_field_with_value(
+            &payload,
+            "Target Coin",
+            "0x1111111111111111111111111111111111111111111111111111111111111111",
+        );
+        assert_has_field_with_value(
+            &payload,
+            "Merged Coin 2",
+            "0x3333333333333333333333333333333333333333333333333333333333333333",
+        );
+    }
+
+    #[test]
+    fn test_split_gas_into_owned_coin() {
+        // Split 1 SUI and 2.5 SUI off the gas coin, then merge both into 0x1111..
+        let test_data = "AAADAAgAypo7AAAAAAAIAPkClQAAAAABABERERERERERERERERERERERERERERERERERERERERERBwAAAAAAAAAgERERERERERERERERERERERERERERERERERERERERERECAgACAQAAAQEAAwECAAIDAAAAAAMAAAEAq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sBzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc0BAAAAAAAAACAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIqurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6ur6AMAAAAAAACAlpgAAAAAAAA=";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Split Coins");
+        assert_has_field_with_value(&payload, "Source Coin", "Gas coin");
+        assert_has_field_with_value(&payload, "Amount 2", "2.5");
+        assert_has_field_with_value(&payload, "Merged Coin 1", "Result 0 of command 0");
+    }
+}
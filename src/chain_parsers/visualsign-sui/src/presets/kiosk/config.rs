@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+crate::chain_config! {
+    config KIOSK_CONFIG as Config;
+
+    sui_framework => {
+        package_id => 0x2,
+        modules as KioskModules: {
+            kiosk as Kiosk => KioskFunctions: {
+                place as Place => PlaceIndexes(),
+                place_and_list as PlaceAndList => PlaceAndListIndexes(
+                    price as Price: u64 => 3 => get_price,
+                ),
+                list as List => ListIndexes(
+                    price as Price: u64 => 3 => get_price,
+                ),
+                delist as Delist => DelistIndexes(),
+                purchase as Purchase => PurchaseIndexes(),
+            },
+            transfer_policy as TransferPolicy => TransferPolicyFunctions: {
+                confirm_request as ConfirmRequest => ConfirmRequestIndexes(),
+            },
+        }
+    },
+
+    kiosk_rules => {
+        package_id => 0x434b5bd8f6a7b05fede0ff46c6e511d71ea326ed38056e3bcd681d2d7c2a7879,
+        modules as KioskRulesModules: {
+            royalty_rule as RoyaltyRule => RoyaltyRuleFunctions: {
+                pay as Pay => PayIndexes(),
+            },
+            kiosk_lock_rule as KioskLockRule => KioskLockRuleFunctions: {
+                prove as Prove => ProveIndexes(),
+            },
+        }
+    },
+}
@@ -0,0 +1,296 @@
+mod config;
+
+use config::{
+    Config, KIOSK_CONFIG, KioskFunctions, KioskLockRuleFunctions, KioskModules, KioskRulesModules,
+    ListIndexes, PlaceAndListIndexes, RoyaltyRuleFunctions, TransferPolicyFunctions,
+};
+
+use crate::core::{CommandVisualizer, SuiIntegrationConfig, VisualizerContext, VisualizerKind};
+use crate::utils::{
+    format_sui, get_address_value, get_object_value, get_split_coin_amount, truncate_address,
+};
+
+use sui_json_rpc_types::{SuiArgument, SuiCallArg, SuiCommand, SuiProgrammableMoveCall};
+
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_amount_field, create_text_field},
+};
+
+/// Argument position of the item, or its `ID`, in `kiosk::place`, `list`,
+/// `place_and_list` and `delist`
+const OWNER_ITEM_ARG: usize = 2;
+/// `kiosk::purchase(kiosk, id, payment)` is called by the buyer and takes no cap
+const PURCHASE_ITEM_ARG: usize = 1;
+const PURCHASE_PAYMENT_ARG: usize = 2;
+const ROYALTY_PAYMENT_ARG: usize = 2;
+
+const OWNER_OBJECTS: &[(&str, usize)] = &[("Kiosk", 0), ("Kiosk Owner Cap", 1)];
+
+pub struct KioskVisualizer;
+
+impl CommandVisualizer for KioskVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let Some(SuiCommand::MoveCall(pwc)) = context.commands().get(context.command_index())
+        else {
+            return Err(VisualSignError::MissingData(
+                "Expected a `MoveCall` for Kiosk parsing".into(),
+            ));
+        };
+
+        let inputs = context.inputs();
+        let args = &pwc.arguments;
+
+        let action = match KioskModules::try_from(pwc.module.as_str()) {
+            Ok(KioskModules::Kiosk) => match pwc.function.as_str().try_into()? {
+                KioskFunctions::Place => KioskAction {
+                    name: "Kiosk Place",
+                    item: get_object_value(args, inputs, OWNER_ITEM_ARG)
+                        .ok()
+                        .map(|item| item.to_string()),
+                    price: None,
+                    objects: OWNER_OBJECTS,
+                },
+                KioskFunctions::PlaceAndList => KioskAction {
+                    name: "Kiosk Place And List",
+                    item: get_object_value(args, inputs, OWNER_ITEM_ARG)
+                        .ok()
+                        .map(|item| item.to_string()),
+                    price: Some((
+                        "Listing Price",
+                        Some(PlaceAndListIndexes::get_price(inputs, args)?),
+                    )),
+                    objects: OWNER_OBJECTS,
+                },
+                KioskFunctions::List => KioskAction {
+                    name: "Kiosk List",
+                    item: pure_item_id(args, inputs, OWNER_ITEM_ARG),
+                    price: Some(("Listing Price", Some(ListIndexes::get_price(inputs, args)?))),
+                    objects: OWNER_OBJECTS,
+                },
+                KioskFunctions::Delist => KioskAction {
+                    name: "Kiosk Delist",
+                    item: pure_item_id(args, inputs, OWNER_ITEM_ARG),
+                    price: None,
+                    objects: OWNER_OBJECTS,
+                },
+                KioskFunctions::Purchase => KioskAction {
+                    name: "Kiosk Purchase",
+                    item: pure_item_id(args, inputs, PURCHASE_ITEM_ARG),
+                    price: Some((
+                        "Payment",
+                        args.get(PURCHASE_PAYMENT_ARG).and_then(|coin| {
+                            get_split_coin_amount(context.commands(), inputs, coin)
+                        }),
+                    )),
+                    objects: &[("Kiosk", 0)],
+                },
+            },
+            Ok(KioskModules::TransferPolicy) => match pwc.function.as_str().try_into()? {
+                TransferPolicyFunctions::ConfirmRequest => KioskAction {
+                    name: "Transfer Policy Confirm Request",
+                    item: None,
+                    price: None,
+                    objects: &[("Transfer Policy", 0)],
+                },
+            },
+            Err(_) => match KioskRulesModules::try_from(pwc.module.as_str())? {
+                KioskRulesModules::RoyaltyRule => match pwc.function.as_str().try_into()? {
+                    RoyaltyRuleFunctions::Pay => KioskAction {
+                        name: "Royalty Payment",
+                        item: None,
+                        price: Some((
+                            "Royalty Amount",
+                            args.get(ROYALTY_PAYMENT_ARG).and_then(|coin| {
+                                get_split_coin_amount(context.commands(), inputs, coin)
+                            }),
+                        )),
+                        objects: &[("Transfer Policy", 0)],
+                    },
+                },
+                KioskRulesModules::KioskLockRule => match pwc.function.as_str().try_into()? {
+                    KioskLockRuleFunctions::Prove => KioskAction {
+                        name: "Kiosk Lock Proof",
+                        item: None,
+                        price: None,
+                        objects: &[("Kiosk", 1)],
+                    },
+                },
+            },
+        };
+
+        action.render(context, pwc)
+    }
+
+    fn get_config(&self) -> Option<&dyn SuiIntegrationConfig> {
+        Some(KIOSK_CONFIG.get_or_init(Config::new))
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Nft("Sui Kiosk")
+    }
+}
+
+/// A Kiosk or Transfer Policy call reduced to what the signer needs to check
+struct KioskAction {
+    name: &'static str,
+    item: Option<String>,
+    /// Label and amount in MIST. The amount is `None` when a whole coin
+    /// object is passed, since its balance is not part of the transaction.
+    price: Option<(&'static str, Option<u64>)>,
+    /// Labels and argument positions of the kiosk objects the call touches
+    objects: &'static [(&'static str, usize)],
+}
+
+impl KioskAction {
+    fn render(
+        &self,
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        // Every Kiosk and Transfer Policy function is generic over the item type
+        let item_type = pwc.type_arguments.first().map(String::as_str);
+        let item_name = item_type
+            .and_then(|item_type| item_type.rsplit("::").next())
+            .unwrap_or("item");
+
+        let title_text = match self.price {
+            Some((_, Some(amount))) => {
+                format!("{}: {item_name} for {} SUI", self.name, format_sui(amount))
+            }
+            _ => format!("{}: {item_name}", self.name),
+        };
+        let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
+
+        let mut condensed_fields = vec![create_text_field(
+            "Item Type",
+            item_type.unwrap_or("Unknown"),
+        )?];
+        let mut expanded_fields = vec![create_address_field(
+            "User Address",
+            &context.sender().to_string(),
+            None,
+            None,
+            None,
+            None,
+        )?];
+
+        if let Some(item) = &self.item {
+            let item_field = create_address_field("Item ID", item, None, None, None, None)?;
+            condensed_fields.push(item_field.clone());
+            expanded_fields.push(item_field);
+        }
+        if let Some((label, amount)) = self.price {
+            let price_field = match amount {
+                Some(amount) => create_amount_field(label, &format_sui(amount), "SUI")?,
+                None => create_text_field(label, "N/A")?,
+            };
+            condensed_fields.push(price_field.clone());
+            expanded_fields.push(price_field);
+        }
+        // Kiosks and policies created earlier in the same PTB are `Result`
+        // arguments with no input to show.
+        for (label, index) in self.objects {
+            if let Ok(object) = get_object_value(&pwc.arguments, context.inputs(), *index) {
+                expanded_fields.push(create_address_field(
+                    label,
+                    &object.to_string(),
+                    None,
+                    None,
+                    None,
+                    None,
+                )?);
+            }
+        }
+        expanded_fields.push(create_text_field(
+            "Item Type",
+            item_type.unwrap_or("Unknown"),
+        )?);
+
+        let preview_layout = SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title_text.clone(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 {
+                text: subtitle_text,
+            }),
+            condensed: Some(SignablePayloadFieldListLayout {
+                fields: condensed_fields,
+            }),
+            expanded: Some(SignablePayloadFieldListLayout {
+                fields: expanded_fields,
+            }),
+        };
+
+        Ok(vec![AnnotatedPayloadField {
+            static_annotation: None,
+            dynamic_annotation: None,
+            signable_payload_field: SignablePayloadField::PreviewLayout {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: title_text,
+                    label: self.name.to_string(),
+                },
+                preview_layout,
+            },
+        }])
+    }
+}
+
+/// Kiosk functions address listed items by their `ID`, which is passed as a
+/// pure 32-byte value
+fn pure_item_id(args: &[SuiArgument], inputs: &[SuiCallArg], arg_index: usize) -> Option<String> {
+    get_address_value(args, inputs, arg_index)
+        .ok()
+        .map(|id| id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+
+    #[test]
+    fn test_kiosk_list() {
+        // kiosk::list<Punk>(kiosk, cap, id, 1.5 SUI)
+        let test_data = "AAAEAQEREREREREREREREREREREREREREREREREREREREREREQEAAAAAAAAAAQEAIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIBAAAAAAAAACAREREREREREREREREREREREREREREREREREREREREREQAgMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMACAAvaFkAAAAAAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgVraW9zawRsaXN0AQfe3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3gVwdW5rcwRQdW5rAAQBAAABAQABAgABAwCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwHNzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NzQEAAAAAAAAAICIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6voAwAAAAAAAICWmAAAAAAAAA==";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Kiosk List");
+        assert_has_field_with_value(&payload, "Listing Price", "1.5");
+        assert_has_field_with_value(
+            &payload,
+            "Item ID",
+            "0x3333333333333333333333333333333333333333333333333333333333333333",
+        );
+        assert_has_field_with_value(
+            &payload,
+            "Kiosk Owner Cap",
+            "0x2222222222222222222222222222222222222222222222222222222222222222",
+        );
+    }
+
+    #[test]
+    fn test_kiosk_purchase_with_royalty() {
+        // purchase for 12 SUI, pay a 0.6 SUI royalty, confirm the request and
+        // send the item to another address
+        let test_data = "AAAGAAgAeEHLAgAAAAEBEREREREREREREREREREREREREREREREREREREREREREBAAAAAAAAAAEAIDMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzAQFERERERERERERERERERERERERERERERERERERERERERAEAAAAAAAAAAAAIAEbDIwAAAAAAIHd3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3BgIAAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgVraW9zawhwdXJjaGFzZQEH3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t4FcHVua3MEUHVuawADAQEAAQIAAgAAAgABAQQAAENLW9j2p7Bf7eD/RsblEdceoybtOAVuO81oHS18Knh5DHJveWFsdHlfcnVsZQNwYXkBB97e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7eBXB1bmtzBFB1bmsAAwEDAAMBAAEAAgIAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACD3RyYW5zZmVyX3BvbGljeQ9jb25maXJtX3JlcXVlc3QBB97e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7e3t7eBXB1bmtzBFB1bmsAAgEDAAMBAAEAAQEDAQAAAAEFAKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NAQAAAAAAAAAgIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiKrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq+gDAAAAAAAAgJaYAAAAAAAA";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Kiosk Purchase");
+        assert_has_field_with_value(&payload, "Payment", "12");
+        assert_has_field(&payload, "Royalty Payment");
+        assert_has_field_with_value(&payload, "Royalty Amount", "0.6");
+        assert_has_field(&payload, "Transfer Policy Confirm Request");
+        assert_has_field_with_value(
+            &payload,
+            "Transfer Policy",
+            "0x4444444444444444444444444444444444444444444444444444444444444444",
+        );
+    }
+}
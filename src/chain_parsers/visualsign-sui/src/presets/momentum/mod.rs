@@ -13,7 +13,7 @@ use crate::presets::momentum::config::{
     AddLiquidityIndexes, FlashSwapIndexes, RemoveLiquidityIndexes,
 };
 use visualsign::errors::VisualSignError;
-use visualsign::field_builders::{create_address_field, create_amount_field};
+use visualsign::field_builders::create_address_field;
 use visualsign::{
     AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
     SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
@@ -110,16 +110,12 @@ impl MomentumVisualizer {
             create_text_field("Pool Coin A", &coin_1.to_string())?,
             create_text_field("Pool Coin B", &coin_2.to_string())?,
             create_text_field("Liquidity", &liquidity.to_string())?,
-            create_amount_field(
-                "Min Amount X",
-                &min_amount_x.to_string(),
-                coin_1.base_unit_symbol(),
-            )?,
-            create_amount_field(
-                "Min Amount Y",
-                &min_amount_y.to_string(),
-                coin_2.base_unit_symbol(),
-            )?,
+            context
+                .coin_amount(min_amount_x, &coin_1)
+                .field("Min Amount X")?,
+            context
+                .coin_amount(min_amount_y, &coin_2)
+                .field("Min Amount Y")?,
         ];
 
         {
@@ -255,16 +251,12 @@ impl MomentumVisualizer {
             )?,
             create_text_field("Pool Coin A", &coin_1.to_string())?,
             create_text_field("Pool Coin B", &coin_2.to_string())?,
-            create_amount_field(
-                "Min Amount X",
-                &min_amount_x.to_string(),
-                coin_1.base_unit_symbol(),
-            )?,
-            create_amount_field(
-                "Min Amount Y",
-                &min_amount_y.to_string(),
-                coin_2.base_unit_symbol(),
-            )?,
+            context
+                .coin_amount(min_amount_x, &coin_1)
+                .field("Min Amount X")?,
+            context
+                .coin_amount(min_amount_y, &coin_2)
+                .field("Min Amount Y")?,
         ];
 
         let title_text = format!(
@@ -0,0 +1,33 @@
+#![allow(dead_code)]
+
+crate::chain_config! {
+    config SCALLOP_CONFIG as Config;
+
+    scallop_mainnet => {
+        package_id => 0xefe8b36d5b2e43728cc323298626b83177803521d195cfb11e15b910e892fddf,
+        modules as ScallopModules: {
+            deposit_collateral as DepositCollateral => DepositCollateralFunctions: {
+                deposit_collateral as DepositCollateral => DepositCollateralIndexes(),
+            },
+            withdraw_collateral as WithdrawCollateral => WithdrawCollateralFunctions: {
+                withdraw_collateral as WithdrawCollateral => WithdrawCollateralIndexes(
+                    amount as Amount: u64 => 5 => get_amount,
+                ),
+                withdraw_collateral_entry as WithdrawCollateralEntry => WithdrawCollateralEntryIndexes(
+                    amount as Amount: u64 => 5 => get_amount,
+                ),
+            },
+            borrow as Borrow => BorrowFunctions: {
+                borrow as Borrow => BorrowIndexes(
+                    amount as Amount: u64 => 5 => get_amount,
+                ),
+                borrow_entry as BorrowEntry => BorrowEntryIndexes(
+                    amount as Amount: u64 => 5 => get_amount,
+                ),
+            },
+            repay as Repay => RepayFunctions: {
+                repay as Repay => RepayIndexes(),
+            },
+        }
+    },
+}
@@ -0,0 +1,261 @@
+mod config;
+
+use config::{
+    BorrowEntryIndexes, BorrowFunctions, BorrowIndexes, Config, DepositCollateralFunctions,
+    RepayFunctions, SCALLOP_CONFIG, ScallopModules, WithdrawCollateralEntryIndexes,
+    WithdrawCollateralFunctions, WithdrawCollateralIndexes,
+};
+
+use crate::core::{CommandVisualizer, SuiIntegrationConfig, VisualizerContext, VisualizerKind};
+use crate::utils::{
+    SuiCoin, get_object_value, get_split_coin_amount, get_tx_type_arg, truncate_address,
+};
+
+use sui_json_rpc_types::{SuiCommand, SuiProgrammableMoveCall};
+
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_text_field},
+};
+
+pub struct ScallopVisualizer;
+
+impl CommandVisualizer for ScallopVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let Some(SuiCommand::MoveCall(pwc)) = context.commands().get(context.command_index())
+        else {
+            return Err(VisualSignError::MissingData(
+                "Expected a `MoveCall` for Scallop parsing".into(),
+            ));
+        };
+
+        let action = match ScallopModules::try_from(pwc.module.as_str())? {
+            ScallopModules::DepositCollateral => match pwc.function.as_str().try_into()? {
+                DepositCollateralFunctions::DepositCollateral => ScallopAction {
+                    name: "Deposit Collateral",
+                    amount_label: "Deposit Amount",
+                    amount: pwc.arguments.get(3).and_then(|coin| {
+                        get_split_coin_amount(context.commands(), context.inputs(), coin)
+                    }),
+                    objects: &[("Obligation", 1), ("Market", 2)],
+                },
+            },
+            ScallopModules::WithdrawCollateral => {
+                let amount = match pwc.function.as_str().try_into()? {
+                    WithdrawCollateralFunctions::WithdrawCollateral => {
+                        WithdrawCollateralIndexes::get_amount(context.inputs(), &pwc.arguments)?
+                    }
+                    WithdrawCollateralFunctions::WithdrawCollateralEntry => {
+                        WithdrawCollateralEntryIndexes::get_amount(
+                            context.inputs(),
+                            &pwc.arguments,
+                        )?
+                    }
+                };
+                ScallopAction {
+                    name: "Withdraw Collateral",
+                    amount_label: "Withdraw Amount",
+                    amount: Some(amount),
+                    objects: &[("Obligation", 1), ("Obligation Key", 2), ("Market", 3)],
+                }
+            }
+            ScallopModules::Borrow => {
+                let amount = match pwc.function.as_str().try_into()? {
+                    BorrowFunctions::Borrow => {
+                        BorrowIndexes::get_amount(context.inputs(), &pwc.arguments)?
+                    }
+                    BorrowFunctions::BorrowEntry => {
+                        BorrowEntryIndexes::get_amount(context.inputs(), &pwc.arguments)?
+                    }
+                };
+                ScallopAction {
+                    name: "Borrow",
+                    amount_label: "Borrow Amount",
+                    amount: Some(amount),
+                    objects: &[("Obligation", 1), ("Obligation Key", 2), ("Market", 3)],
+                }
+            }
+            ScallopModules::Repay => match pwc.function.as_str().try_into()? {
+                RepayFunctions::Repay => ScallopAction {
+                    name: "Repay",
+                    amount_label: "Repay Amount",
+                    amount: pwc.arguments.get(3).and_then(|coin| {
+                        get_split_coin_amount(context.commands(), context.inputs(), coin)
+                    }),
+                    objects: &[("Obligation", 1), ("Market", 2)],
+                },
+            },
+        };
+
+        action.render(context, pwc)
+    }
+
+    fn get_config(&self) -> Option<&dyn SuiIntegrationConfig> {
+        Some(SCALLOP_CONFIG.get_or_init(Config::new))
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Lending("Scallop")
+    }
+}
+
+/// What a Scallop user call does, resolved from its module and arguments.
+/// Every supported call takes the coin type as its only type argument.
+struct ScallopAction {
+    name: &'static str,
+    amount_label: &'static str,
+    /// `None` when a whole coin object is passed, since its balance is not
+    /// part of the transaction
+    amount: Option<u64>,
+    /// Labels and argument positions of the Scallop objects the call touches
+    objects: &'static [(&'static str, usize)],
+}
+
+impl ScallopAction {
+    fn render(
+        &self,
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let coin: SuiCoin = get_tx_type_arg(&pwc.type_arguments, 0).unwrap_or_default();
+        let amount = self.amount.map(|amount| context.coin_amount(amount, &coin));
+        let amount_str = amount
+            .as_ref()
+            .map_or_else(|| format!("N/A {}", coin.symbol()), ToString::to_string);
+
+        let title_text = format!("Scallop: {} {amount_str}", self.name);
+        let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
+
+        let amount_field = match &amount {
+            Some(amount) => amount.field(self.amount_label)?,
+            None => create_text_field(self.amount_label, "N/A")?,
+        };
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![create_text_field(
+                "Summary",
+                &format!("{} {amount_str} on Scallop", self.name),
+            )?],
+        };
+
+        let mut expanded_fields = vec![create_address_field(
+            "User Address",
+            &context.sender().to_string(),
+            None,
+            None,
+            None,
+            None,
+        )?];
+        // Objects created earlier in the same PTB (e.g. a freshly opened
+        // obligation) are `Result` arguments with no input to show.
+        for (label, index) in self.objects {
+            if let Ok(object) = get_object_value(&pwc.arguments, context.inputs(), *index) {
+                expanded_fields.push(create_address_field(
+                    label,
+                    &object.to_string(),
+                    None,
+                    None,
+                    None,
+                    None,
+                )?);
+            }
+        }
+        expanded_fields.push(create_text_field("Coin", &coin.to_string())?);
+        expanded_fields.push(amount_field);
+
+        let preview_layout = SignablePayloadFieldPreviewLayout {
+            title: Some(SignablePayloadFieldTextV2 {
+                text: title_text.clone(),
+            }),
+            subtitle: Some(SignablePayloadFieldTextV2 {
+                text: subtitle_text,
+            }),
+            condensed: Some(condensed),
+            expanded: Some(SignablePayloadFieldListLayout {
+                fields: expanded_fields,
+            }),
+        };
+
+        Ok(vec![AnnotatedPayloadField {
+            static_annotation: None,
+            dynamic_annotation: None,
+            signable_payload_field: SignablePayloadField::PreviewLayout {
+                common: SignablePayloadFieldCommon {
+                    fallback_text: title_text,
+                    label: format!("Scallop {}", self.name),
+                },
+                preview_layout,
+            },
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+
+    const USDC: &str =
+        "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC";
+
+    #[test]
+    fn test_scallop_borrow() {
+        // borrow<SUI>(version, obligation, obligation_key, market, registry,
+        // 2_000_000_000, x_oracle, clock), borrowed coin sent to the sender
+        let test_data = "AAAJAQEREREREREREREREREREREREREREREREREREREREREREQEAAAAAAAAAAAEBIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIBAAAAAAAAAAEBADMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzAQAAAAAAAAAgEREREREREREREREREREREREREREREREREREREREREREBAUREREREREREREREREREREREREREREREREREREREREREAQAAAAAAAAABAQFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQEAAAAAAAAAAAAIAJQ1dwAAAAABAXd3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3AQAAAAAAAAABAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABgEAAAAAAAAAAAAgq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sCAO/os21bLkNyjMMjKYYmuDF3gDUh0ZXPsR4VuRDokv3fBmJvcnJvdwZib3Jyb3cBBwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACA3N1aQNTVUkACAEAAAEBAAECAAEDAAEEAAEFAAEGAAEHAAEBAgAAAQgAq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sBzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc0BAAAAAAAAACAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIqurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6ur6AMAAAAAAACAlpgAAAAAAAA=";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Scallop Borrow");
+        assert_has_field_with_value(&payload, "Borrow Amount", "2");
+        assert_has_field_with_value(&payload, "Coin", "0x2::sui::SUI");
+        assert_has_field_with_value(
+            &payload,
+            "Obligation Key",
+            "0x3333333333333333333333333333333333333333333333333333333333333333",
+        );
+    }
+
+    #[test]
+    fn test_scallop_withdraw_collateral_entry() {
+        let test_data = "AAAIAQEREREREREREREREREREREREREREREREREREREREREREQEAAAAAAAAAAAEBIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIBAAAAAAAAAAEBADMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzAQAAAAAAAAAgEREREREREREREREREREREREREREREREREREREREREREBAUREREREREREREREREREREREREREREREREREREREREREAQAAAAAAAAABAQFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQEAAAAAAAAAAAAIsHELAAAAAAABAXd3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3d3AQAAAAAAAAABAQEAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABgEAAAAAAAAAAAEA7+izbVsuQ3KMwyMphia4MXeANSHRlc+xHhW5EOiS/d8Td2l0aGRyYXdfY29sbGF0ZXJhbBl3aXRoZHJhd19jb2xsYXRlcmFsX2VudHJ5AQfbo0Zy4wywZbH5Pjq1Uxh2j9b+9mwVlCyffLhG4vkA5wR1c2RjBFVTREMACAEAAAEBAAECAAEDAAEEAAEFAAEGAAEHAKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NAQAAAAAAAAAgIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiKrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq+gDAAAAAAAAgJaYAAAAAAAA";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Scallop Withdraw Collateral");
+        assert_has_field_with_value(&payload, "Withdraw Amount", "0.75");
+        assert_has_field_with_value(&payload, "Coin", USDC);
+    }
+
+    #[test]
+    fn test_scallop_deposit_collateral_from_split_gas() {
+        // SplitCoins(GasCoin, [10_000_000_000]) feeds `Result(0)` into
+        // deposit_collateral<SUI>
+        let test_data = "AAAEAQEREREREREREREREREREREREREREREREREREREREREREQEAAAAAAAAAAAEBIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIBAAAAAAAAAAEBAUREREREREREREREREREREREREREREREREREREREREREAQAAAAAAAAABAAgA5AtUAgAAAAICAAEBAwAA7+izbVsuQ3KMwyMphia4MXeANSHRlc+xHhW5EOiS/d8SZGVwb3NpdF9jb2xsYXRlcmFsEmRlcG9zaXRfY29sbGF0ZXJhbAEHAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAIDc3VpA1NVSQAEAQAAAQEAAQIAAgAAq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sBzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc0BAAAAAAAAACAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIqurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6ur6AMAAAAAAACAlpgAAAAAAAA=";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Scallop Deposit Collateral");
+        assert_has_field_with_value(&payload, "Deposit Amount", "10");
+        assert_has_field_with_value(
+            &payload,
+            "Market",
+            "0x4444444444444444444444444444444444444444444444444444444444444444",
+        );
+    }
+
+    #[test]
+    fn test_scallop_repay_from_split_coin() {
+        // SplitCoins(usdc_coin, [500_000]) feeds `NestedResult(0, 0)` into
+        // repay<USDC>
+        let test_data = "AAAGAQEREREREREREREREREREREREREREREREREREREREREREQEAAAAAAAAAAAEBIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIBAAAAAAAAAAEBAUREREREREREREREREREREREREREREREREREREREREREAQAAAAAAAAABAQCZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmQEAAAAAAAAAIBERERERERERERERERERERERERERERERERERERERERERAAggoQcAAAAAAAEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAYBAAAAAAAAAAACAgEDAAEBBAAA7+izbVsuQ3KMwyMphia4MXeANSHRlc+xHhW5EOiS/d8FcmVwYXkFcmVwYXkBB9ujRnLjDLBlsfk+OrVTGHaP1v72bBWULJ98uEbi+QDnBHVzZGMEVVNEQwAFAQAAAQEAAQIAAwAAAAABBQCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwHNzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NzQEAAAAAAAAAICIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6voAwAAAAAAAICWmAAAAAAAAA==";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Scallop Repay");
+        assert_has_field_with_value(&payload, "Repay Amount", "0.5");
+        assert_has_field_with_value(&payload, "Coin", USDC);
+    }
+}
@@ -1,10 +1,13 @@
 mod config;
 
+use std::str::FromStr;
+
 use config::{Config, NATIVE_STAKING_CONFIG, SuiSystemFunctions};
 
 use crate::core::{CommandVisualizer, SuiIntegrationConfig, VisualizerContext, VisualizerKind};
 use crate::utils::{
-    decode_number, get_index, parse_numeric_argument, parse_result_command_index, truncate_address,
+    SuiCoin, decode_number, get_index, get_object_value, parse_numeric_argument,
+    parse_result_command_index, truncate_address,
 };
 
 use sui_json_rpc_types::{SuiArgument, SuiCallArg, SuiCommand, SuiProgrammableMoveCall};
@@ -14,9 +17,12 @@ use visualsign::{
     AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
     SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
     errors::VisualSignError,
-    field_builders::{create_address_field, create_amount_field, create_text_field},
+    field_builders::{create_address_field, create_text_field},
 };
 
+/// `request_withdraw_stake(wrapper, staked_sui)`
+const STAKED_SUI_ARG: usize = 1;
+
 pub struct SuiNativeStakingVisualizer;
 
 impl CommandVisualizer for SuiNativeStakingVisualizer {
@@ -54,23 +60,33 @@ impl SuiNativeStakingVisualizer {
         let amount = get_stake_amount(context.commands(), context.inputs(), &pwc.arguments)
             .unwrap_or_default();
         let receiver = get_stake_receiver(context.inputs(), &pwc.arguments).unwrap_or_default();
+        let validator_name = context.validator_name(&receiver);
+        let validator_text = validator_name.map_or_else(
+            || truncate_address(&receiver.to_string()),
+            ToString::to_string,
+        );
 
         {
+            let stake_text = validator_name
+                .map_or_else(|| "Stake".to_string(), |name| format!("Stake to {name}"));
             let (title_text, amount_field) = match amount {
-                Some(amount) => (
-                    format!("Stake: {amount} MIST"),
-                    create_amount_field("Amount", &amount.to_string(), "MIST")?,
-                ),
+                Some(amount) => {
+                    let amount = context.coin_amount(amount, &SuiCoin::sui());
+                    (format!("{stake_text}: {amount}"), amount.field("Amount")?)
+                }
                 None => (
-                    "Stake Command".to_string(),
-                    create_text_field("Amount", "N/A MIST")?,
+                    if validator_name.is_some() {
+                        stake_text
+                    } else {
+                        "Stake Command".to_string()
+                    },
+                    create_text_field("Amount", "N/A SUI")?,
                 ),
             };
 
             let subtitle_text = format!(
-                "From {} to validator {}",
+                "From {} to validator {validator_text}",
                 truncate_address(&context.sender().to_string()),
-                truncate_address(&receiver.to_string())
             );
 
             let condensed = SignablePayloadFieldListLayout {
@@ -90,7 +106,7 @@ impl SuiNativeStakingVisualizer {
                     create_address_field(
                         "Validator",
                         &receiver.to_string(),
-                        None,
+                        validator_name,
                         None,
                         None,
                         None,
@@ -122,31 +138,70 @@ impl SuiNativeStakingVisualizer {
 
     fn handle_withdraw_stake(
         context: &VisualizerContext,
-        _pwc: &SuiProgrammableMoveCall,
+        pwc: &SuiProgrammableMoveCall,
     ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
-        let title_text = "Withdraw Stake".to_string();
+        // The amount withdrawn lives in the `StakedSui` object, not the
+        // transaction, so it is only known when metadata describes the object
+        let staked_sui_id = get_object_value(&pwc.arguments, context.inputs(), STAKED_SUI_ARG).ok();
+        let stake = staked_sui_id.and_then(|id| context.staked_sui(&id));
+
+        let principal = stake.map(|stake| context.coin_amount(stake.principal, &SuiCoin::sui()));
+        let title_text = match &principal {
+            Some(principal) => format!("Withdraw Stake: {principal}"),
+            None => "Withdraw Stake".to_string(),
+        };
         let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
 
-        let condensed = SignablePayloadFieldListLayout {
-            fields: vec![create_address_field(
-                "From",
-                &context.sender().to_string(),
+        let from_field = create_address_field(
+            "From",
+            &context.sender().to_string(),
+            None,
+            None,
+            None,
+            None,
+        )?;
+
+        let mut condensed_fields = vec![from_field.clone()];
+        let mut expanded_fields = vec![from_field];
+        if let Some(id) = staked_sui_id {
+            expanded_fields.push(create_address_field(
+                "Staked SUI Object",
+                &id.to_string(),
                 None,
                 None,
                 None,
                 None,
-            )?],
+            )?);
+        }
+        if let (Some(stake), Some(principal)) = (stake, principal) {
+            let principal_field = principal.field("Principal")?;
+            condensed_fields.push(principal_field.clone());
+            expanded_fields.push(principal_field);
+            expanded_fields.push(create_text_field(
+                "Activation Epoch",
+                &stake.activation_epoch.to_string(),
+            )?);
+            if !stake.validator.is_empty() {
+                let name = SuiAddress::from_str(&stake.validator)
+                    .ok()
+                    .and_then(|validator| context.validator_name(&validator));
+                expanded_fields.push(create_address_field(
+                    "Validator",
+                    &stake.validator,
+                    name,
+                    None,
+                    None,
+                    None,
+                )?);
+            }
+        }
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: condensed_fields,
         };
 
         let expanded = SignablePayloadFieldListLayout {
-            fields: vec![create_address_field(
-                "From",
-                &context.sender().to_string(),
-                None,
-                None,
-                None,
-                None,
-            )?],
+            fields: expanded_fields,
         };
 
         Ok(vec![AnnotatedPayloadField {
@@ -200,10 +255,9 @@ fn get_stake_amount(
     // commands vector. Using `parse_result_command_index` keeps the semantic
     // distinction explicit and prevents `Input(N)` from being silently
     // dereferenced against the commands vector.
-    let stake_arg = args
-        .get(1)
-        .copied()
-        .ok_or(VisualSignError::MissingData("Stake argument missing".into()))?;
+    let stake_arg = args.get(1).copied().ok_or(VisualSignError::MissingData(
+        "Stake argument missing".into(),
+    ))?;
     let command = commands
         .get(parse_result_command_index(stake_arg)? as usize)
         .ok_or(VisualSignError::MissingData("Command not found".into()))?;
@@ -224,25 +278,114 @@ fn get_stake_amount(
 
 #[cfg(test)]
 mod tests {
+    use crate::transaction_string_to_visual_sign;
     use crate::utils::payload_from_b64;
 
-    use visualsign::test_utils::assert_has_field;
+    use generated::parser::{ChainMetadata, SuiMetadata, SuiStakedSui, chain_metadata};
+    use visualsign::SignablePayloadField;
+    use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+    use visualsign::vsptrait::VisualSignOptions;
+
+    // https://suivision.xyz/txblock/4cccJLKehRtyRQY7TaNUJiM4ipauWCn8S3GNJr9RtfCN
+    const STAKE_TX: &str = "AQAAAAAAAwAIAGKs63UDAAABAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFAQAAAAAAAAABACAArnjT5bpda43jJFVHT1KBG5VhfLrTnr9Pni2vZxh0BwICAAEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMKc3VpX3N5c3RlbRFyZXF1ZXN0X2FkZF9zdGFrZQADAQEAAgAAAQIAchml1wtdzMahHtnC+vK/PAN3Y1Nua3n0b+llLNlP63sS91480t7crkx10tMf1GBphnFn9ImRSCkSz+/vgVnXpCH+wrgjAAAAACA0F4UpabC9/7RFUiBnEiOjfQUh7WwycuwxC4HXNWCB87xhtd+38zkA5oow9A8dNJZLJLmExMhHZtVr2Z54J5dCM8+6IwAAAAAgVo0BnK/9uyVcuP4Dh6Zz/AoGPRcuforA522PgiEMj+ExGC1sSX2Iz5VaSZKDG0S4hUquzd+gIG6HrubmTB4+H2xQvCMAAAAAIMZWzEKhYGfx/BBVEOwj0BPKog1L9vsjFOMVGz+Ccz/1UjA6TZRCYu97v9k62s814RDTXBDCysramrxWkw8rC4WG1rojAAAAACC5twStwiG1CYMchoX6fuLsxbpZflZqa/Nfqgor4F2FZD+WWCYBIOd63H/RJp8L1dGzXJ1a2ccCShJ+PDrr52JQ4je7IwAAAAAg7IkUrK8NWz3Eqvt/v5sge65N6ulWG3jZxCTcK7qRbWUL/tH0Ysraua6BptIBZqYGaxV6xC9vWMfTe+Ip5jE+I7ZevCMAAAAAIGAAQtBVw7aOXRphh8b9pv3jgnyzT/YC574vRTCI9OQilIwD9rHfpNGU2fTQS6FUiyT02WUBUSJwU89ZEeWB8sh8ULsjAAAAACDofQBTuJq5tRuROvF8G+iXBf97nefwvk7EABk3ozFDv3KMQb/vp6PKjBZPNJAWeGNGlwQXmLmssjlgiaetA+5XRmK7IwAAAAAgIV9blUqSik4sllVwRF2L+ubVGWFHQhtmNFBZpuwBd2bKy8PFJe+VJiA++e9bXK/fjvCK0RpZ7VprD2eEwy3ODYi/uyMAAAAAIMvIs1NC8//tjFVBz5SbJj9qqLh2qbF1RfNZW0wx5Mo6X9Lx7+LuoE25ZFW5oSw44lmJ2vPae4KQ0R1kTfbRGiave7sjAAAAACCzmP55RKlPOqGJBdfS6eY+UjmlpTSGvTHP8hUWk7T4OYEUDI6TTxeUK1AnF+Xhiklt9fcZXZ1PVWiEiNq/u0Utz367IwAAAAAgZ42PQNaZfltc5MVc9Ja6ZzBJDrXsdgINGVW76jVNbyn+XOhRQaock9U7J1O371bGZeEAoriHNfGn3CkXGDnwX0GAuyMAAAAAIN7MiSc0QEvu9npIm1Prv2ORlUh992gEVMXByCyltfE/Coezo8orpYDdndeF2vFkJ/+vhmHQGWvxEyYkwnqcHzQ/jrsjAAAAACC/BIZAoP2mo+07tcbjR+dPEmQCZdGr/tU/LE/Pr+uap2LuRhUG8chU5FnphmyErbq6yYw3AlBGynionKP1QlgD0pK7IwAAAAAgSCHwEJRXpc21CWcbjZ1zC6seZmFxLA1/2ox1kg/3NNwjh8ocklBDNJQ0p018bGQnQ1/fmbQ3PASM6321c8Q49XCuuyMAAAAAIPuRIPYEeaHC3ghIxae9SYvjlctN+ICS/+f264nO4GHm8qdjD3lvHnR5iRAhWQ2grQ0fhVTojNHw4gzZfrjBkj0fgLwjAAAAACBWkHgrTPBmmqWNSjcdrfkH9/WSO7dGCgObuL+Z4XdhcXkbWK1fLyah0wbPUVlQKnJ04TEMb/pJ5VZQX3JUGT96alK8IwAAAAAgN0wfiUZurekECwSJYJTnNzs5zQOXSVbwxUOBZuZe13Xjle13WuEg8ZzCrsUDk9vveQAEPGoX5ilfN0bUCxE+YOw4vCMAAAAAIEiOQkW7xn/ypzTHbgEBr+2ria56PZNqDNGxoSlqcAqCchml1wtdzMahHtnC+vK/PAN3Y1Nua3n0b+llLNlP63shAgAAAAAAANChEAAAAAAAAAFhAAMXK+XvLV700RIKRRVecODdz7ix6ld6Xd7n3OA4FNQF9dctGN8cnisaVnkxhpmWExq9udXFE5taXf+6oPYdOwvQTyj2+JV1sMgV1T5PRxv9WG+kbKk5wGHh3oKpRtlEUw==";
 
     #[test]
     fn test_stake_commands() {
-        // https://suivision.xyz/txblock/4cccJLKehRtyRQY7TaNUJiM4ipauWCn8S3GNJr9RtfCN
-        let test_data = "AQAAAAAAAwAIAGKs63UDAAABAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAFAQAAAAAAAAABACAArnjT5bpda43jJFVHT1KBG5VhfLrTnr9Pni2vZxh0BwICAAEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAMKc3VpX3N5c3RlbRFyZXF1ZXN0X2FkZF9zdGFrZQADAQEAAgAAAQIAchml1wtdzMahHtnC+vK/PAN3Y1Nua3n0b+llLNlP63sS91480t7crkx10tMf1GBphnFn9ImRSCkSz+/vgVnXpCH+wrgjAAAAACA0F4UpabC9/7RFUiBnEiOjfQUh7WwycuwxC4HXNWCB87xhtd+38zkA5oow9A8dNJZLJLmExMhHZtVr2Z54J5dCM8+6IwAAAAAgVo0BnK/9uyVcuP4Dh6Zz/AoGPRcuforA522PgiEMj+ExGC1sSX2Iz5VaSZKDG0S4hUquzd+gIG6HrubmTB4+H2xQvCMAAAAAIMZWzEKhYGfx/BBVEOwj0BPKog1L9vsjFOMVGz+Ccz/1UjA6TZRCYu97v9k62s814RDTXBDCysramrxWkw8rC4WG1rojAAAAACC5twStwiG1CYMchoX6fuLsxbpZflZqa/Nfqgor4F2FZD+WWCYBIOd63H/RJp8L1dGzXJ1a2ccCShJ+PDrr52JQ4je7IwAAAAAg7IkUrK8NWz3Eqvt/v5sge65N6ulWG3jZxCTcK7qRbWUL/tH0Ysraua6BptIBZqYGaxV6xC9vWMfTe+Ip5jE+I7ZevCMAAAAAIGAAQtBVw7aOXRphh8b9pv3jgnyzT/YC574vRTCI9OQilIwD9rHfpNGU2fTQS6FUiyT02WUBUSJwU89ZEeWB8sh8ULsjAAAAACDofQBTuJq5tRuROvF8G+iXBf97nefwvk7EABk3ozFDv3KMQb/vp6PKjBZPNJAWeGNGlwQXmLmssjlgiaetA+5XRmK7IwAAAAAgIV9blUqSik4sllVwRF2L+ubVGWFHQhtmNFBZpuwBd2bKy8PFJe+VJiA++e9bXK/fjvCK0RpZ7VprD2eEwy3ODYi/uyMAAAAAIMvIs1NC8//tjFVBz5SbJj9qqLh2qbF1RfNZW0wx5Mo6X9Lx7+LuoE25ZFW5oSw44lmJ2vPae4KQ0R1kTfbRGiave7sjAAAAACCzmP55RKlPOqGJBdfS6eY+UjmlpTSGvTHP8hUWk7T4OYEUDI6TTxeUK1AnF+Xhiklt9fcZXZ1PVWiEiNq/u0Utz367IwAAAAAgZ42PQNaZfltc5MVc9Ja6ZzBJDrXsdgINGVW76jVNbyn+XOhRQaock9U7J1O371bGZeEAoriHNfGn3CkXGDnwX0GAuyMAAAAAIN7MiSc0QEvu9npIm1Prv2ORlUh992gEVMXByCyltfE/Coezo8orpYDdndeF2vFkJ/+vhmHQGWvxEyYkwnqcHzQ/jrsjAAAAACC/BIZAoP2mo+07tcbjR+dPEmQCZdGr/tU/LE/Pr+uap2LuRhUG8chU5FnphmyErbq6yYw3AlBGynionKP1QlgD0pK7IwAAAAAgSCHwEJRXpc21CWcbjZ1zC6seZmFxLA1/2ox1kg/3NNwjh8ocklBDNJQ0p018bGQnQ1/fmbQ3PASM6321c8Q49XCuuyMAAAAAIPuRIPYEeaHC3ghIxae9SYvjlctN+ICS/+f264nO4GHm8qdjD3lvHnR5iRAhWQ2grQ0fhVTojNHw4gzZfrjBkj0fgLwjAAAAACBWkHgrTPBmmqWNSjcdrfkH9/WSO7dGCgObuL+Z4XdhcXkbWK1fLyah0wbPUVlQKnJ04TEMb/pJ5VZQX3JUGT96alK8IwAAAAAgN0wfiUZurekECwSJYJTnNzs5zQOXSVbwxUOBZuZe13Xjle13WuEg8ZzCrsUDk9vveQAEPGoX5ilfN0bUCxE+YOw4vCMAAAAAIEiOQkW7xn/ypzTHbgEBr+2ria56PZNqDNGxoSlqcAqCchml1wtdzMahHtnC+vK/PAN3Y1Nua3n0b+llLNlP63shAgAAAAAAANChEAAAAAAAAAFhAAMXK+XvLV700RIKRRVecODdz7ix6ld6Xd7n3OA4FNQF9dctGN8cnisaVnkxhpmWExq9udXFE5taXf+6oPYdOwvQTyj2+JV1sMgV1T5PRxv9WG+kbKk5wGHh3oKpRtlEUw==";
-
-        let payload = payload_from_b64(test_data);
+        let payload = payload_from_b64(STAKE_TX);
         assert_has_field(&payload, "Stake Command");
     }
 
     #[test]
-    fn test_withdraw_commands() {
-        // https://suivision.xyz/txblock/4cccJLKehRtyRQY7TaNUJiM4ipauWCn8S3GNJr9RtfCN
-        let test_data = "AQAAAAAAAgEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAUBAAAAAAAAAAEBADtuZRRZcXabYn2eLpOPGq3onyss/0Kyuv3BoB3PQPiIJHpFHQAAAAAgDlI1Bti2mpZBb/rDxYkyB+lyANUGRTtYgKbRoBow53cBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAADCnN1aV9zeXN0ZW0WcmVxdWVzdF93aXRoZHJhd19zdGFrZQACAQAAAQEAPmYGcGNxVi5pj8Tk1ufHEB6SYs6TFjQYj+JG7623BnUCN8ccpwVmcafDNOXvnEAo6kzltjdniobA56to42fHdUio9wcjAAAAACDQVC4fMhsmX6OlHpAhyPR8LaRzgu43Bj8xrhlRY6YKG/Yv6m2ncHpPhbrEkOrSiyh1ID3T4FARE+raMUofCsQPqPcHIwAAAAAg5qp+jjoniUXPNG4N0/9XDFSpoUt0isbEUMiXjNtGivA+ZgZwY3FWLmmPxOTW58cQHpJizpMWNBiP4kbvrbcGdSECAAAAAAAADAqcAAAAAAAAAWEAkj0EN51BkbIUE/6lMi967MHGsBMl2i8TtntUnFhlC2rK8AW2fGQxc8mg1gTbV+2eHs1CsZ9m67cU4CWzA+9PAg//ECUrmzUzzsg0xYRgwDQDy9lAF8e6bpAa8/5Yec6s";
+    fn test_stake_to_named_validator() {
+        let metadata = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Sui(SuiMetadata {
+                validators: [(
+                    "0x00ae78d3e5ba5d6b8de32455474f52811b95617cbad39ebf4f9e2daf67187407"
+                        .to_string(),
+                    "Figment".to_string(),
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            })),
+        };
+
+        let payload = transaction_string_to_visual_sign(
+            STAKE_TX,
+            VisualSignOptions {
+                metadata: Some(metadata),
+                ..Default::default()
+            },
+        )
+        .expect("stake transaction should parse");
 
-        let payload = payload_from_b64(test_data);
+        let stake = payload.fields.iter().find(|f| f.label() == "Stake Command");
+        assert!(
+            matches!(
+                stake,
+                Some(SignablePayloadField::PreviewLayout { preview_layout, .. })
+                    if preview_layout
+                        .title
+                        .as_ref()
+                        .is_some_and(|title| title.text.starts_with("Stake to Figment: "))
+            ),
+            "Stake title should name the validator, got {stake:?}"
+        );
+        assert_has_field_with_value(
+            &payload,
+            "Validator",
+            "0x00ae78d3e5ba5d6b8de32455474f52811b95617cbad39ebf4f9e2daf67187407",
+        );
+    }
+
+    // https://suivision.xyz/txblock/4cccJLKehRtyRQY7TaNUJiM4ipauWCn8S3GNJr9RtfCN
+    const WITHDRAW_TX: &str = "AQAAAAAAAgEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAUBAAAAAAAAAAEBADtuZRRZcXabYn2eLpOPGq3onyss/0Kyuv3BoB3PQPiIJHpFHQAAAAAgDlI1Bti2mpZBb/rDxYkyB+lyANUGRTtYgKbRoBow53cBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAADCnN1aV9zeXN0ZW0WcmVxdWVzdF93aXRoZHJhd19zdGFrZQACAQAAAQEAPmYGcGNxVi5pj8Tk1ufHEB6SYs6TFjQYj+JG7623BnUCN8ccpwVmcafDNOXvnEAo6kzltjdniobA56to42fHdUio9wcjAAAAACDQVC4fMhsmX6OlHpAhyPR8LaRzgu43Bj8xrhlRY6YKG/Yv6m2ncHpPhbrEkOrSiyh1ID3T4FARE+raMUofCsQPqPcHIwAAAAAg5qp+jjoniUXPNG4N0/9XDFSpoUt0isbEUMiXjNtGivA+ZgZwY3FWLmmPxOTW58cQHpJizpMWNBiP4kbvrbcGdSECAAAAAAAADAqcAAAAAAAAAWEAkj0EN51BkbIUE/6lMi967MHGsBMl2i8TtntUnFhlC2rK8AW2fGQxc8mg1gTbV+2eHs1CsZ9m67cU4CWzA+9PAg//ECUrmzUzzsg0xYRgwDQDy9lAF8e6bpAa8/5Yec6s";
+
+    #[test]
+    fn test_withdraw_commands() {
+        let payload = payload_from_b64(WITHDRAW_TX);
         assert_has_field(&payload, "Withdraw Command");
     }
+
+    #[test]
+    fn test_withdraw_with_staked_sui_metadata() {
+        let validator = "0x00ae78d3e5ba5d6b8de32455474f52811b95617cbad39ebf4f9e2daf67187407";
+        let metadata = ChainMetadata {
+            metadata: Some(chain_metadata::Metadata::Sui(SuiMetadata {
+                validators: [(validator.to_string(), "Figment".to_string())]
+                    .into_iter()
+                    .collect(),
+                staked_sui: [(
+                    "0x3b6e65145971769b627d9e2e938f1aade89f2b2cff42b2bafdc1a01dcf40f888"
+                        .to_string(),
+                    SuiStakedSui {
+                        principal: 5_000_000_000,
+                        activation_epoch: 612,
+                        validator: validator.to_string(),
+                    },
+                )]
+                .into_iter()
+                .collect(),
+                ..Default::default()
+            })),
+        };
+
+        let payload = transaction_string_to_visual_sign(
+            WITHDRAW_TX,
+            VisualSignOptions {
+                metadata: Some(metadata),
+                ..Default::default()
+            },
+        )
+        .expect("withdraw transaction should parse");
+
+        assert_has_field_with_value(&payload, "Principal", "5");
+        assert_has_field_with_value(&payload, "Activation Epoch", "612");
+        assert_has_field_with_value(&payload, "Validator", validator);
+        assert_has_field_with_value(
+            &payload,
+            "Staked SUI Object",
+            "0x3b6e65145971769b627d9e2e938f1aade89f2b2cff42b2bafdc1a01dcf40f888",
+        );
+    }
 }
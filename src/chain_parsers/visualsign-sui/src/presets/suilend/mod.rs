@@ -113,21 +113,18 @@ impl SuilendVisualizer {
         let package: SuiPackage = get_tx_type_arg(&pwc.type_arguments, 0).unwrap_or_default();
         let reserve_index =
             BorrowRequestIndexes::get_reserve_array_index(context.inputs(), &pwc.arguments)?;
-        let amount = BorrowRequestIndexes::get_amount(context.inputs(), &pwc.arguments)?;
+        let amount = context.coin_amount(
+            BorrowRequestIndexes::get_amount(context.inputs(), &pwc.arguments)?,
+            &coin,
+        );
 
-        let title_text = format!("Suilend: Borrow Request {} {}", amount, coin.symbol());
+        let title_text = format!("Suilend: Borrow Request {amount}");
         let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
 
         let condensed = SignablePayloadFieldListLayout {
             fields: vec![create_text_field(
                 "Summary",
-                &format!(
-                    "Borrow {} {} from reserve #{} via {}",
-                    amount,
-                    coin.symbol(),
-                    reserve_index,
-                    package
-                ),
+                &format!("Borrow {amount} from reserve #{reserve_index} via {package}"),
             )?],
         };
 
@@ -160,7 +157,7 @@ impl SuilendVisualizer {
                 create_text_field("Pool Address", &package.to_string())?,
                 create_text_field("Borrowed Coin", &coin.to_string())?,
                 create_text_field("Borrowed Reserve Index", &reserve_index.to_string())?,
-                create_amount_field("Borrowed Amount", &amount.to_string(), coin.symbol())?,
+                amount.field("Borrowed Amount")?,
             ],
         };
 
@@ -880,22 +877,21 @@ impl SuilendVisualizer {
         let amount = get_repay_amount(context.commands(), context.inputs(), &pwc.arguments)
             .unwrap_or_default();
 
-        let (title_text, amount_str, amount_field) = match amount {
-            Some(amount) => (
-                format!("Suilend: Repay {} {}", amount, coin.symbol()),
-                amount.to_string(),
-                create_amount_field("Repay Amount", &amount.to_string(), coin.symbol())?,
-            ),
+        let (amount_str, amount_field) = match amount {
+            Some(amount) => {
+                let amount = context.coin_amount(amount, &coin);
+                (amount.to_string(), amount.field("Repay Amount")?)
+            }
             None => (
-                format!("Suilend: Repay {} {}", "N/A", coin.symbol()),
-                "N/A".to_string(),
+                format!("N/A {}", coin.symbol()),
                 create_text_field("Repay Amount", "N/A")?,
             ),
         };
+        let title_text = format!("Suilend: Repay {amount_str}");
 
         let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
 
-        let mut summary = format!("Repay {} {} via {}", amount_str, coin.symbol(), package);
+        let mut summary = format!("Repay {amount_str} via {package}");
         write!(&mut summary, " (reserve #{reserve_index})")
             .expect("templating a u64 into a string");
 
@@ -0,0 +1,27 @@
+#![allow(dead_code)]
+
+crate::chain_config! {
+    config SUINS_CONFIG as Config;
+
+    suins_registration => {
+        package_id => 0x9d451fa0139fef8f7c1f0bd5d7e45b7fa9dbb84c2e63c2819c7abd0a7f7d749d,
+        modules as RegistrationModules: {
+            register as Register => RegisterFunctions: {
+                register as Register => RegisterIndexes(
+                    no_years as NoYears: u8 => 2 => get_no_years,
+                ),
+            },
+        }
+    },
+
+    suins_renewal => {
+        package_id => 0xd5e5f74126e7934e35991643b0111c3361827fc0564c83fa810668837c6f0b0f,
+        modules as RenewalModules: {
+            renew as Renew => RenewFunctions: {
+                renew as Renew => RenewIndexes(
+                    no_years as NoYears: u8 => 2 => get_no_years,
+                ),
+            },
+        }
+    },
+}
@@ -0,0 +1,251 @@
+mod config;
+
+use config::{
+    Config, RegisterFunctions, RegisterIndexes, RegistrationModules, RenewFunctions, RenewIndexes,
+    RenewalModules, SUINS_CONFIG,
+};
+
+use crate::core::{CommandVisualizer, SuiIntegrationConfig, VisualizerContext, VisualizerKind};
+use crate::utils::{
+    format_sui, get_object_value, get_split_coin_amount, get_string_value, truncate_address,
+};
+
+use sui_json_rpc_types::{SuiCommand, SuiProgrammableMoveCall};
+
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_amount_field, create_text_field},
+};
+
+/// `register::register(suins, domain_name, no_years, payment, clock)`
+const REGISTER_DOMAIN_ARG: usize = 1;
+const REGISTER_PAYMENT_ARG: usize = 3;
+/// `renew::renew(suins, nft, no_years, payment, clock)`. The domain lives in
+/// the registration NFT, so it is not part of the transaction.
+const RENEW_NFT_ARG: usize = 1;
+const RENEW_PAYMENT_ARG: usize = 3;
+const SUINS_ARG: usize = 0;
+
+pub struct SuinsVisualizer;
+
+impl CommandVisualizer for SuinsVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let Some(SuiCommand::MoveCall(pwc)) = context.commands().get(context.command_index())
+        else {
+            return Err(VisualSignError::MissingData(
+                "Expected a `MoveCall` for SuiNS parsing".into(),
+            ));
+        };
+
+        if let Ok(RegistrationModules::Register) =
+            RegistrationModules::try_from(pwc.module.as_str())
+        {
+            return match pwc.function.as_str().try_into()? {
+                RegisterFunctions::Register => Self::handle_register(context, pwc),
+            };
+        }
+
+        match RenewalModules::try_from(pwc.module.as_str())? {
+            RenewalModules::Renew => match pwc.function.as_str().try_into()? {
+                RenewFunctions::Renew => Self::handle_renew(context, pwc),
+            },
+        }
+    }
+
+    fn get_config(&self) -> Option<&dyn SuiIntegrationConfig> {
+        Some(SUINS_CONFIG.get_or_init(Config::new))
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Payments("SuiNS")
+    }
+}
+
+impl SuinsVisualizer {
+    fn handle_register(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let domain = get_string_value(&pwc.arguments, context.inputs(), REGISTER_DOMAIN_ARG)?;
+        let no_years = RegisterIndexes::get_no_years(context.inputs(), &pwc.arguments)?;
+        let price = payment_amount(context, pwc, REGISTER_PAYMENT_ARG);
+
+        let title_text = format!("SuiNS Register: {domain} for {}", format_years(no_years));
+
+        let mut expanded_fields = vec![
+            create_text_field("Domain", &domain)?,
+            create_text_field("Duration", &format_years(no_years))?,
+            price_field(price)?,
+        ];
+        expanded_fields.extend(common_fields(context, pwc)?);
+
+        Ok(vec![preview_field(
+            "SuiNS Register",
+            title_text,
+            context,
+            vec![create_text_field("Domain", &domain)?, price_field(price)?],
+            expanded_fields,
+        )])
+    }
+
+    fn handle_renew(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let no_years = RenewIndexes::get_no_years(context.inputs(), &pwc.arguments)?;
+        let price = payment_amount(context, pwc, RENEW_PAYMENT_ARG);
+
+        let title_text = format!("SuiNS Renew: {}", format_years(no_years));
+
+        let mut expanded_fields = vec![
+            create_text_field("Duration", &format_years(no_years))?,
+            price_field(price)?,
+        ];
+        // A registration NFT minted earlier in the same PTB has no input to show
+        if let Ok(nft) = get_object_value(&pwc.arguments, context.inputs(), RENEW_NFT_ARG) {
+            expanded_fields.push(create_address_field(
+                "Registration NFT",
+                &nft.to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?);
+        }
+        expanded_fields.extend(common_fields(context, pwc)?);
+
+        Ok(vec![preview_field(
+            "SuiNS Renew",
+            title_text,
+            context,
+            vec![
+                create_text_field("Duration", &format_years(no_years))?,
+                price_field(price)?,
+            ],
+            expanded_fields,
+        )])
+    }
+}
+
+/// The registration fee is paid with a coin split off in the same PTB. A whole
+/// coin object passed in has no amount in the transaction.
+fn payment_amount(
+    context: &VisualizerContext,
+    pwc: &SuiProgrammableMoveCall,
+    arg_index: usize,
+) -> Option<u64> {
+    pwc.arguments
+        .get(arg_index)
+        .and_then(|coin| get_split_coin_amount(context.commands(), context.inputs(), coin))
+}
+
+fn price_field(price: Option<u64>) -> Result<AnnotatedPayloadField, VisualSignError> {
+    match price {
+        Some(price) => create_amount_field("Price", &format_sui(price), "SUI"),
+        None => create_text_field("Price", "N/A"),
+    }
+}
+
+fn format_years(no_years: u8) -> String {
+    if no_years == 1 {
+        "1 year".to_string()
+    } else {
+        format!("{no_years} years")
+    }
+}
+
+fn common_fields(
+    context: &VisualizerContext,
+    pwc: &SuiProgrammableMoveCall,
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let mut fields = vec![create_address_field(
+        "User Address",
+        &context.sender().to_string(),
+        None,
+        None,
+        None,
+        None,
+    )?];
+    if let Ok(suins) = get_object_value(&pwc.arguments, context.inputs(), SUINS_ARG) {
+        fields.push(create_address_field(
+            "SuiNS Object",
+            &suins.to_string(),
+            None,
+            None,
+            None,
+            None,
+        )?);
+    }
+    Ok(fields)
+}
+
+fn preview_field(
+    label: &str,
+    title_text: String,
+    context: &VisualizerContext,
+    condensed_fields: Vec<AnnotatedPayloadField>,
+    expanded_fields: Vec<AnnotatedPayloadField>,
+) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: title_text.clone(),
+                label: label.to_string(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 { text: title_text }),
+                subtitle: Some(SignablePayloadFieldTextV2 {
+                    text: format!("From {}", truncate_address(&context.sender().to_string())),
+                }),
+                condensed: Some(SignablePayloadFieldListLayout {
+                    fields: condensed_fields,
+                }),
+                expanded: Some(SignablePayloadFieldListLayout {
+                    fields: expanded_fields,
+                }),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+
+    #[test]
+    fn test_suins_register() {
+        // register "example.sui" for 2 years, paying 40 SUI split from gas
+        let test_data = "AAAGAQFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQEAAAAAAAAAAQAMC2V4YW1wbGUuc3VpAAECAAgAkC9QCQAAAAEBAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAYBAAAAAAAAAAAAIKurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urAwIAAQEDAACdRR+gE5/vj3wfC9XX5Ft/qdu4TC5jwoGcer0Kf310nQhyZWdpc3RlcghyZWdpc3RlcgAFAQAAAQEAAQIAAgAAAQQAAQECAQABBQCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwHNzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3NzQEAAAAAAAAAICIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6voAwAAAAAAAICWmAAAAAAAAA==";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "SuiNS Register");
+        assert_has_field_with_value(&payload, "Domain", "example.sui");
+        assert_has_field_with_value(&payload, "Duration", "2 years");
+        assert_has_field_with_value(&payload, "Price", "40");
+    }
+
+    #[test]
+    fn test_suins_renew() {
+        // renew an owned registration NFT for 1 year at 20 SUI
+        let test_data = "AAAFAQFVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVQEAAAAAAAAAAQEAZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmZmYBAAAAAAAAACAREREREREREREREREREREREREREREREREREREREREREQABAQAIAMgXqAQAAAABAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGAQAAAAAAAAAAAgIAAQEDAADV5fdBJueTTjWZFkOwERwzYYJ/wFZMg/qBBmiDfG8LDwVyZW5ldwVyZW5ldwAFAQAAAQEAAQIAAgAAAQQAq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sBzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc0BAAAAAAAAACAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIqurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6ur6AMAAAAAAACAlpgAAAAAAAA=";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "SuiNS Renew");
+        assert_has_field_with_value(&payload, "Duration", "1 year");
+        assert_has_field_with_value(&payload, "Price", "20");
+        assert_has_field_with_value(
+            &payload,
+            "Registration NFT",
+            "0x6666666666666666666666666666666666666666666666666666666666666666",
+        );
+    }
+}
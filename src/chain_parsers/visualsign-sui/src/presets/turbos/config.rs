@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+crate::chain_config! {
+    config TURBOS_CONFIG as Config;
+
+    turbos_mainnet => {
+        package_id => 0x91bfbc386a41afcfd9b2533058d7e915a1d3829089cc268ff4333d54d6339ca1,
+        modules as TurbosModules: {
+            swap_router as SwapRouter => SwapRouterFunctions: {
+                swap_a_b as SwapA2B => SwapA2BIndexes(
+                    amount as Amount: u64 => 2 => get_amount,
+                    amount_threshold as AmountThreshold: u64 => 3 => get_amount_threshold,
+                    sqrt_price_limit as SqrtPriceLimit: u128 => 4 => get_sqrt_price_limit,
+                    is_exact_in as IsExactIn: bool => 5 => get_is_exact_in,
+                    deadline as Deadline: u64 => 7 => get_deadline,
+                ),
+                swap_b_a as SwapB2A => SwapB2AIndexes(
+                    amount as Amount: u64 => 2 => get_amount,
+                    amount_threshold as AmountThreshold: u64 => 3 => get_amount_threshold,
+                    sqrt_price_limit as SqrtPriceLimit: u128 => 4 => get_sqrt_price_limit,
+                    is_exact_in as IsExactIn: bool => 5 => get_is_exact_in,
+                    deadline as Deadline: u64 => 7 => get_deadline,
+                ),
+            },
+            position_manager as PositionManager => PositionManagerFunctions: {
+                mint as Mint => MintIndexes(
+                    tick_lower_index_abs as TickLowerIndexAbs: u32 => 4 => get_tick_lower_index_abs,
+                    tick_lower_index_is_neg as TickLowerIndexIsNeg: bool => 5 => get_tick_lower_index_is_neg,
+                    tick_upper_index_abs as TickUpperIndexAbs: u32 => 6 => get_tick_upper_index_abs,
+                    tick_upper_index_is_neg as TickUpperIndexIsNeg: bool => 7 => get_tick_upper_index_is_neg,
+                    amount_a_desired as AmountADesired: u64 => 8 => get_amount_a_desired,
+                    amount_b_desired as AmountBDesired: u64 => 9 => get_amount_b_desired,
+                    amount_a_min as AmountAMin: u64 => 10 => get_amount_a_min,
+                    amount_b_min as AmountBMin: u64 => 11 => get_amount_b_min,
+                    deadline as Deadline: u64 => 13 => get_deadline,
+                ),
+                collect as Collect => CollectIndexes(
+                    amount_a_max as AmountAMax: u64 => 3 => get_amount_a_max,
+                    amount_b_max as AmountBMax: u64 => 4 => get_amount_b_max,
+                    deadline as Deadline: u64 => 6 => get_deadline,
+                ),
+            },
+        }
+    },
+}
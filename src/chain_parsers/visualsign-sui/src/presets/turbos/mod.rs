@@ -0,0 +1,418 @@
+mod config;
+
+use config::{
+    CollectIndexes, Config, MintIndexes, PositionManagerFunctions, SwapA2BIndexes, SwapB2AIndexes,
+    SwapRouterFunctions, TURBOS_CONFIG, TurbosModules,
+};
+
+use crate::core::{CommandVisualizer, SuiIntegrationConfig, VisualizerContext, VisualizerKind};
+use crate::utils::{
+    SuiCoin, get_address_value, get_object_value, get_tx_type_arg, truncate_address,
+};
+
+use sui_json_rpc_types::{SuiCommand, SuiProgrammableMoveCall};
+
+use visualsign::{
+    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldCommon,
+    SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+    errors::VisualSignError,
+    field_builders::{create_address_field, create_text_field},
+};
+
+/// Every Turbos call here takes the pool first and is generic over
+/// `<CoinTypeA, CoinTypeB, FeeType>`
+const POOL_ARG: usize = 0;
+const COIN_A_TYPE: usize = 0;
+const COIN_B_TYPE: usize = 1;
+
+const SWAP_RECIPIENT_ARG: usize = 6;
+const MINT_RECIPIENT_ARG: usize = 12;
+const COLLECT_POSITION_ARG: usize = 2;
+const COLLECT_RECIPIENT_ARG: usize = 5;
+
+pub struct TurbosVisualizer;
+
+impl CommandVisualizer for TurbosVisualizer {
+    fn visualize_tx_commands(
+        &self,
+        context: &VisualizerContext,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let Some(SuiCommand::MoveCall(pwc)) = context.commands().get(context.command_index())
+        else {
+            return Err(VisualSignError::MissingData(
+                "Expected a `MoveCall` for Turbos parsing".into(),
+            ));
+        };
+
+        match pwc.module.as_str().try_into()? {
+            TurbosModules::SwapRouter => match pwc.function.as_str().try_into()? {
+                SwapRouterFunctions::SwapA2B => Self::handle_swap(true, context, pwc),
+                SwapRouterFunctions::SwapB2A => Self::handle_swap(false, context, pwc),
+            },
+            TurbosModules::PositionManager => match pwc.function.as_str().try_into()? {
+                PositionManagerFunctions::Mint => Self::handle_mint(context, pwc),
+                PositionManagerFunctions::Collect => Self::handle_collect(context, pwc),
+            },
+        }
+    }
+
+    fn get_config(&self) -> Option<&dyn SuiIntegrationConfig> {
+        Some(TURBOS_CONFIG.get_or_init(Config::new))
+    }
+
+    fn kind(&self) -> VisualizerKind {
+        VisualizerKind::Dex("Turbos")
+    }
+}
+
+impl TurbosVisualizer {
+    fn handle_swap(
+        is_a2b: bool,
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let (amount, amount_threshold, sqrt_price_limit, is_exact_in, deadline) = if is_a2b {
+            (
+                SwapA2BIndexes::get_amount(context.inputs(), &pwc.arguments)?,
+                SwapA2BIndexes::get_amount_threshold(context.inputs(), &pwc.arguments)?,
+                SwapA2BIndexes::get_sqrt_price_limit(context.inputs(), &pwc.arguments)?,
+                SwapA2BIndexes::get_is_exact_in(context.inputs(), &pwc.arguments)?,
+                SwapA2BIndexes::get_deadline(context.inputs(), &pwc.arguments)?,
+            )
+        } else {
+            (
+                SwapB2AIndexes::get_amount(context.inputs(), &pwc.arguments)?,
+                SwapB2AIndexes::get_amount_threshold(context.inputs(), &pwc.arguments)?,
+                SwapB2AIndexes::get_sqrt_price_limit(context.inputs(), &pwc.arguments)?,
+                SwapB2AIndexes::get_is_exact_in(context.inputs(), &pwc.arguments)?,
+                SwapB2AIndexes::get_deadline(context.inputs(), &pwc.arguments)?,
+            )
+        };
+
+        let (coin_a, coin_b) = pool_coins(pwc);
+        let (input_coin, output_coin) = if is_a2b {
+            (coin_a, coin_b)
+        } else {
+            (coin_b, coin_a)
+        };
+        let (amount_label, amount, threshold_label, threshold) = if is_exact_in {
+            (
+                "Amount In",
+                context.coin_amount(amount, &input_coin),
+                "Min Amount Out",
+                context.coin_amount(amount_threshold, &output_coin),
+            )
+        } else {
+            (
+                "Amount Out",
+                context.coin_amount(amount, &output_coin),
+                "Max Amount In",
+                context.coin_amount(amount_threshold, &input_coin),
+            )
+        };
+
+        let title_text = format!(
+            "Turbos Swap: {amount} From {} To {}",
+            input_coin.symbol(),
+            output_coin.symbol()
+        );
+        let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![create_text_field(
+                "Summary",
+                &format!(
+                    "Swap {} to {} ({threshold_label}: {threshold})",
+                    input_coin.symbol(),
+                    output_coin.symbol(),
+                ),
+            )?],
+        };
+
+        let price_limit_text = if sqrt_price_limit == 0 {
+            "None".to_string()
+        } else {
+            sqrt_price_limit.to_string()
+        };
+
+        let mut expanded_fields = vec![
+            create_address_field(
+                "Pool Address",
+                &get_object_value(&pwc.arguments, context.inputs(), POOL_ARG)?.to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?,
+            create_address_field(
+                "User Address",
+                &context.sender().to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?,
+            amount.field(amount_label)?,
+            create_text_field("Input Coin", &input_coin.to_string())?,
+            threshold.field(threshold_label)?,
+            create_text_field("Output Coin", &output_coin.to_string())?,
+            create_text_field("Sqrt Price Limit", &price_limit_text)?,
+        ];
+        expanded_fields.extend(recipient_field(context, pwc, SWAP_RECIPIENT_ARG)?);
+        expanded_fields.push(create_text_field("Deadline (ms)", &deadline.to_string())?);
+
+        Ok(vec![preview_field(
+            "Turbos Swap Command",
+            title_text,
+            subtitle_text,
+            condensed,
+            expanded_fields,
+        )])
+    }
+
+    fn handle_mint(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let inputs = context.inputs();
+        let args = &pwc.arguments;
+        let tick_lower = signed_tick(
+            MintIndexes::get_tick_lower_index_abs(inputs, args)?,
+            MintIndexes::get_tick_lower_index_is_neg(inputs, args)?,
+        );
+        let tick_upper = signed_tick(
+            MintIndexes::get_tick_upper_index_abs(inputs, args)?,
+            MintIndexes::get_tick_upper_index_is_neg(inputs, args)?,
+        );
+        let amount_a_desired = MintIndexes::get_amount_a_desired(inputs, args)?;
+        let amount_b_desired = MintIndexes::get_amount_b_desired(inputs, args)?;
+        let amount_a_min = MintIndexes::get_amount_a_min(inputs, args)?;
+        let amount_b_min = MintIndexes::get_amount_b_min(inputs, args)?;
+        let deadline = MintIndexes::get_deadline(inputs, args)?;
+
+        let (coin_a, coin_b) = pool_coins(pwc);
+        let amount_a_desired = context.coin_amount(amount_a_desired, &coin_a);
+        let amount_b_desired = context.coin_amount(amount_b_desired, &coin_b);
+
+        let title_text = format!("Turbos Open Position: {amount_a_desired} + {amount_b_desired}");
+        let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![create_text_field(
+                "Summary",
+                &format!(
+                    "Provide {amount_a_desired} and {amount_b_desired} in ticks [{tick_lower}, {tick_upper}]"
+                ),
+            )?],
+        };
+
+        let mut expanded_fields = vec![
+            create_address_field(
+                "Pool Address",
+                &get_object_value(args, inputs, POOL_ARG)?.to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?,
+            create_address_field(
+                "User Address",
+                &context.sender().to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?,
+            create_text_field("Coin A", &coin_a.to_string())?,
+            create_text_field("Coin B", &coin_b.to_string())?,
+            create_text_field("Tick Lower", &tick_lower.to_string())?,
+            create_text_field("Tick Upper", &tick_upper.to_string())?,
+            amount_a_desired.field("Amount A Desired")?,
+            amount_b_desired.field("Amount B Desired")?,
+            context
+                .coin_amount(amount_a_min, &coin_a)
+                .field("Min Amount A")?,
+            context
+                .coin_amount(amount_b_min, &coin_b)
+                .field("Min Amount B")?,
+        ];
+        expanded_fields.extend(recipient_field(context, pwc, MINT_RECIPIENT_ARG)?);
+        expanded_fields.push(create_text_field("Deadline (ms)", &deadline.to_string())?);
+
+        Ok(vec![preview_field(
+            "Turbos Open Position Command",
+            title_text,
+            subtitle_text,
+            condensed,
+            expanded_fields,
+        )])
+    }
+
+    fn handle_collect(
+        context: &VisualizerContext,
+        pwc: &SuiProgrammableMoveCall,
+    ) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+        let amount_a_max = CollectIndexes::get_amount_a_max(context.inputs(), &pwc.arguments)?;
+        let amount_b_max = CollectIndexes::get_amount_b_max(context.inputs(), &pwc.arguments)?;
+        let deadline = CollectIndexes::get_deadline(context.inputs(), &pwc.arguments)?;
+
+        let (coin_a, coin_b) = pool_coins(pwc);
+
+        let amount_a_max = context.coin_amount(amount_a_max, &coin_a);
+        let amount_b_max = context.coin_amount(amount_b_max, &coin_b);
+
+        let title_text = format!(
+            "Turbos Collect Fees: {}/{}",
+            coin_a.symbol(),
+            coin_b.symbol()
+        );
+        let subtitle_text = format!("From {}", truncate_address(&context.sender().to_string()));
+
+        let condensed = SignablePayloadFieldListLayout {
+            fields: vec![create_text_field(
+                "Summary",
+                &format!("Collect up to {amount_a_max} and {amount_b_max} in fees"),
+            )?],
+        };
+
+        let mut expanded_fields = vec![
+            create_address_field(
+                "Pool Address",
+                &get_object_value(&pwc.arguments, context.inputs(), POOL_ARG)?.to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?,
+            create_address_field(
+                "User Address",
+                &context.sender().to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?,
+        ];
+        if let Ok(position) =
+            get_object_value(&pwc.arguments, context.inputs(), COLLECT_POSITION_ARG)
+        {
+            expanded_fields.push(create_address_field(
+                "Position NFT",
+                &position.to_string(),
+                None,
+                None,
+                None,
+                None,
+            )?);
+        }
+        expanded_fields.extend([
+            create_text_field("Coin A", &coin_a.to_string())?,
+            create_text_field("Coin B", &coin_b.to_string())?,
+            amount_a_max.field("Max Amount A")?,
+            amount_b_max.field("Max Amount B")?,
+        ]);
+        expanded_fields.extend(recipient_field(context, pwc, COLLECT_RECIPIENT_ARG)?);
+        expanded_fields.push(create_text_field("Deadline (ms)", &deadline.to_string())?);
+
+        Ok(vec![preview_field(
+            "Turbos Collect Command",
+            title_text,
+            subtitle_text,
+            condensed,
+            expanded_fields,
+        )])
+    }
+}
+
+fn pool_coins(pwc: &SuiProgrammableMoveCall) -> (SuiCoin, SuiCoin) {
+    (
+        get_tx_type_arg(&pwc.type_arguments, COIN_A_TYPE).unwrap_or_default(),
+        get_tx_type_arg(&pwc.type_arguments, COIN_B_TYPE).unwrap_or_default(),
+    )
+}
+
+/// Turbos passes ticks as an absolute value plus a sign flag
+fn signed_tick(abs: u32, is_neg: bool) -> i64 {
+    if is_neg {
+        -i64::from(abs)
+    } else {
+        i64::from(abs)
+    }
+}
+
+/// The recipient of the call's output coins or position, if it is a pure
+/// address input
+fn recipient_field(
+    context: &VisualizerContext,
+    pwc: &SuiProgrammableMoveCall,
+    arg_index: usize,
+) -> Result<Option<AnnotatedPayloadField>, VisualSignError> {
+    get_address_value(&pwc.arguments, context.inputs(), arg_index)
+        .ok()
+        .map(|recipient| {
+            create_address_field("Recipient", &recipient.to_string(), None, None, None, None)
+        })
+        .transpose()
+}
+
+fn preview_field(
+    label: &str,
+    title_text: String,
+    subtitle_text: String,
+    condensed: SignablePayloadFieldListLayout,
+    expanded_fields: Vec<AnnotatedPayloadField>,
+) -> AnnotatedPayloadField {
+    AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::PreviewLayout {
+            common: SignablePayloadFieldCommon {
+                fallback_text: title_text.clone(),
+                label: label.to_string(),
+            },
+            preview_layout: SignablePayloadFieldPreviewLayout {
+                title: Some(SignablePayloadFieldTextV2 { text: title_text }),
+                subtitle: Some(SignablePayloadFieldTextV2 {
+                    text: subtitle_text,
+                }),
+                condensed: Some(condensed),
+                expanded: Some(SignablePayloadFieldListLayout {
+                    fields: expanded_fields,
+                }),
+            },
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::payload_from_b64;
+
+    use visualsign::test_utils::{assert_has_field, assert_has_field_with_value};
+
+    #[test]
+    fn test_turbos_swap_b_a_exact_in() {
+        // swap_b_a<SUI, USDC, FEE3000BPS>: spend 25 USDC for at least 7 SUI
+        let test_data = "AAAKAQFwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcAEAAAAAAAAAAQEAwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcEBAAAAAAAAACAREREREREREREREREREREREREREREREREREREREREREQAIQHh9AQAAAAAACACGO6EBAAAAABAAAAAAAAAAAAAAAAAAAAAAAAEBACCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwAIAMAsyJkBAAABAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGAQAAAAAAAAAAAQHw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8AEAAAAAAAAAAQIFAQcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgRjb2luBENvaW4BB9ujRnLjDLBlsfk+OrVTGHaP1v72bBWULJ98uEbi+QDnBHVzZGMEVVNEQwABAQEAAJG/vDhqQa/P2bJTMFjX6RWh04KQicwmj/QzPVTWM5yhC3N3YXBfcm91dGVyCHN3YXBfYl9hAwcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgNzdWkDU1VJAAfbo0Zy4wywZbH5Pjq1Uxh2j9b+9mwVlCyffLhG4vkA5wR1c2RjBFVTREMAB5G/vDhqQa/P2bJTMFjX6RWh04KQicwmj/QzPVTWM5yhCmZlZTMwMDBicHMKRkVFMzAwMEJQUwAKAQAAAgAAAQIAAQMAAQQAAQUAAQYAAQcAAQgAAQkAq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sBzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc0BAAAAAAAAACAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIqurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6ur6AMAAAAAAACAlpgAAAAAAAA=";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Turbos Swap Command");
+        assert_has_field_with_value(&payload, "Amount In", "25");
+        assert_has_field_with_value(&payload, "Min Amount Out", "7");
+        assert_has_field_with_value(&payload, "Output Coin", "0x2::sui::SUI");
+        assert_has_field_with_value(&payload, "Sqrt Price Limit", "None");
+    }
+
+    #[test]
+    fn test_turbos_mint_with_negative_lower_tick() {
+        // mint<SUI, USDC, FEE3000BPS> over ticks [-60, 120]
+        let test_data = "AAAQAQFwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcHBwcAEAAAAAAAAAAQEB4ODg4ODg4ODg4ODg4ODg4ODg4ODg4ODg4ODg4ODg4OABAAAAAAAAAAEBAMLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCwsLCAQAAAAAAAAAgEREREREREREREREREREREREREREREREREREREREREREBAMPDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDAQAAAAAAAAAgEREREREREREREREREREREREREREREREREREREREREREABDwAAAAAAQEABHgAAAAAAQAACADKmjsAAAAAAAhA4TMAAAAAAAAIgDMCOwAAAAAACHBcMwAAAAAAACCrq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urqwAIAMAsyJkBAAABAQAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAGAQAAAAAAAAAAAQHw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8PDw8AEAAAAAAAAAAQMFAQcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgRjb2luBENvaW4BBwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACA3N1aQNTVUkAAQECAAUBBwAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAACBGNvaW4EQ29pbgEH26NGcuMMsGWx+T46tVMYdo/W/vZsFZQsn3y4RuL5AOcEdXNkYwRVU0RDAAEBAwAAkb+8OGpBr8/ZslMwWNfpFaHTgpCJzCaP9DM9VNYznKEQcG9zaXRpb25fbWFuYWdlcgRtaW50AwcAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAgNzdWkDU1VJAAfbo0Zy4wywZbH5Pjq1Uxh2j9b+9mwVlCyffLhG4vkA5wR1c2RjBFVTREMAB5G/vDhqQa/P2bJTMFjX6RWh04KQicwmj/QzPVTWM5yhCmZlZTMwMDBicHMKRkVFMzAwMEJQUwAQAQAAAQEAAgAAAgEAAQQAAQUAAQYAAQcAAQgAAQkAAQoAAQsAAQwAAQ0AAQ4AAQ8Aq6urq6urq6urq6urq6urq6urq6urq6urq6urq6urq6sBzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc3Nzc0BAAAAAAAAACAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIqurq6urq6urq6urq6urq6urq6urq6urq6urq6urq6ur6AMAAAAAAACAlpgAAAAAAAA=";
+
+        let payload = payload_from_b64(test_data);
+        assert_has_field(&payload, "Turbos Open Position Command");
+        assert_has_field_with_value(&payload, "Tick Lower", "-60");
+        assert_has_field_with_value(&payload, "Tick Upper", "120");
+        assert_has_field_with_value(&payload, "Amount A Desired", "1");
+        assert_has_field_with_value(&payload, "Min Amount B", "3.366");
+    }
+}
@@ -1,3 +1,46 @@
+use visualsign::{
+    AnnotatedPayloadField, errors::VisualSignError, field_builders::create_amount_field,
+};
+
+/// Decimal places between MIST and SUI.
+const SUI_DECIMALS: u8 = 9;
+
+/// Coins whose amounts can be scaled without metadata, keyed by normalized
+/// coin type (see [`SuiCoin::coin_type`]).
+const KNOWN_COINS: &[(&str, &str, u8)] = &[
+    ("0x2::sui::SUI", "SUI", SUI_DECIMALS),
+    (
+        "0xdba34672e30cb065b1f93e3ab55318768fd6fef66c15942c9f7cb846e2f900e7::usdc::USDC",
+        "USDC",
+        6,
+    ),
+    (
+        "0x375f70cf2ae4c00bf37117d0c85a2c71545e6ee05c4a5c7d282cd66a4504b068::usdt::USDT",
+        "USDT",
+        6,
+    ),
+    (
+        "0x6864a6f921804860930db6ddbe2e16acdf8504495ea7481637a1c8b9a8fe54b::cetus::CETUS",
+        "CETUS",
+        9,
+    ),
+    (
+        "0xdeeb7a4662eec9f2f3def03fb937a663dddaa2e215b8078a284d026b7946c270::deep::DEEP",
+        "DEEP",
+        6,
+    ),
+    (
+        "0x356a26eb9e012a68958082340d4c4116e7f55615cf27affcff209cf0ae544f59::wal::WAL",
+        "WAL",
+        9,
+    ),
+    (
+        "0xf325ce1300e8dac124071d3152c5c5ee6174914f8bc2161e88329cf579246efc::afsui::AFSUI",
+        "afSUI",
+        9,
+    ),
+];
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SuiCoin {
     pub address: String,
@@ -30,10 +73,44 @@ impl std::str::FromStr for SuiCoin {
 }
 
 impl SuiCoin {
+    /// The native coin, `0x2::sui::SUI`
+    pub fn sui() -> Self {
+        SuiCoin {
+            address: "0x2".to_string(),
+            name: "sui".to_string(),
+            symbol: "SUI".to_string(),
+        }
+    }
+
     pub fn symbol(&self) -> &str {
         &self.symbol
     }
 
+    /// The coin type with its package address in short form, so that
+    /// `0x0…02::sui::SUI` and `0x2::sui::SUI` compare equal.
+    pub fn coin_type(&self) -> String {
+        let hex = self
+            .address
+            .strip_prefix("0x")
+            .unwrap_or(&self.address)
+            .trim_start_matches('0')
+            .to_ascii_lowercase();
+        let hex = if hex.is_empty() { "0" } else { hex.as_str() };
+        format!("0x{hex}::{}::{}", self.name, self.symbol)
+    }
+
+    /// Display info for coins the parser knows without metadata.
+    pub fn known_info(&self) -> Option<CoinInfo> {
+        let coin_type = self.coin_type();
+        KNOWN_COINS
+            .iter()
+            .find(|(known, ..)| *known == coin_type)
+            .map(|(_, symbol, decimals)| CoinInfo {
+                symbol: (*symbol).to_string(),
+                decimals: *decimals,
+            })
+    }
+
     pub fn base_unit_symbol(&self) -> &str {
         if self.address == "0x2"
             && self.name.eq_ignore_ascii_case("sui")
@@ -62,6 +139,69 @@ impl Default for SuiCoin {
     }
 }
 
+/// Symbol and decimals used to display amounts of a coin type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinInfo {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+/// An amount ready for display, in whole coins when the decimals are known
+/// and in base units otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoinAmount {
+    pub amount: String,
+    pub symbol: String,
+}
+
+impl CoinAmount {
+    pub fn new(amount: u128, coin: &SuiCoin, info: Option<&CoinInfo>) -> Self {
+        match info {
+            Some(info) => CoinAmount {
+                amount: format_units(amount, info.decimals),
+                symbol: info.symbol.clone(),
+            },
+            None => CoinAmount {
+                amount: amount.to_string(),
+                symbol: coin.base_unit_symbol().to_string(),
+            },
+        }
+    }
+
+    pub fn field(&self, label: &str) -> Result<AnnotatedPayloadField, VisualSignError> {
+        create_amount_field(label, &self.amount, &self.symbol)
+    }
+}
+
+impl std::fmt::Display for CoinAmount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.symbol)
+    }
+}
+
+/// Formats a base-unit amount with `decimals` fractional digits, dropping
+/// trailing fractional zeros
+pub fn format_units(amount: u128, decimals: u8) -> String {
+    let digits = amount.to_string();
+    let decimals = usize::from(decimals);
+    if decimals == 0 {
+        return digits;
+    }
+    let digits = format!("{digits:0>width$}", width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{fraction}")
+    }
+}
+
+/// Formats a MIST amount in SUI, dropping trailing fractional zeros
+pub fn format_sui(mist: u64) -> String {
+    format_units(mist.into(), SUI_DECIMALS)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CoinObject {
     Sui,
@@ -91,3 +231,47 @@ impl Default for CoinObject {
         CoinObject::UnknownObject(String::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_sui() {
+        assert_eq!(format_sui(0), "0");
+        assert_eq!(format_sui(2_000_000_000), "2");
+        assert_eq!(format_sui(1_500_000_000), "1.5");
+        assert_eq!(format_sui(1), "0.000000001");
+    }
+
+    #[test]
+    fn test_format_units() {
+        assert_eq!(format_units(25_000_000, 6), "25");
+        assert_eq!(format_units(3_366_000, 6), "3.366");
+        assert_eq!(format_units(42, 0), "42");
+        assert_eq!(format_units(5, 255).len(), 257);
+    }
+
+    #[test]
+    fn test_known_coin_info_normalizes_address() {
+        let sui: SuiCoin =
+            "0x0000000000000000000000000000000000000000000000000000000000000002::sui::SUI"
+                .parse()
+                .expect("coin type parses");
+        assert_eq!(sui.coin_type(), "0x2::sui::SUI");
+        assert_eq!(
+            sui.known_info(),
+            Some(CoinInfo {
+                symbol: "SUI".to_string(),
+                decimals: 9
+            })
+        );
+
+        let unknown: SuiCoin = "0x1234::meme::MEME".parse().expect("coin type parses");
+        assert_eq!(unknown.known_info(), None);
+        assert_eq!(
+            CoinAmount::new(1_500, &unknown, None).to_string(),
+            "1500 MEME"
+        );
+    }
+}
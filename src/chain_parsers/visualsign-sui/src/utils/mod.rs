@@ -7,12 +7,12 @@ mod test_helpers;
 mod tx_args;
 
 pub use address::truncate_address;
-pub use coin::{CoinObject, SuiCoin};
+pub use coin::{CoinAmount, CoinInfo, CoinObject, SuiCoin, format_sui};
 pub use numeric::decode_number;
 pub use package::SuiPackage;
 pub use tx_args::{
-    get_index, get_nested_result_value, get_object_value, get_tx_type_arg, parse_numeric_argument,
-    parse_result_command_index,
+    get_address_value, get_index, get_nested_result_value, get_object_value, get_split_coin_amount,
+    get_string_value, get_tx_type_arg, parse_numeric_argument, parse_result_command_index,
 };
 
 #[cfg(test)]
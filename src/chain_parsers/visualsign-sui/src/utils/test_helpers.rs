@@ -70,6 +70,7 @@ pub fn payload_from_b64(data: &str) -> SignablePayload {
         data,
         VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: None,
@@ -85,6 +86,7 @@ pub fn payload_from_b64_with_context(data: &str, context: &str) -> SignablePaylo
         data,
         VisualSignOptions {
             include_intermediate_output: false,
+            strict_registry: false,
             decode_transfers: true,
             transaction_name: None,
             metadata: None,
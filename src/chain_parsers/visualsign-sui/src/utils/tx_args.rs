@@ -1,7 +1,9 @@
-use sui_json_rpc_types::{SuiArgument, SuiCallArg};
-use sui_types::base_types::ObjectID;
+use sui_json_rpc_types::{SuiArgument, SuiCallArg, SuiCommand};
+use sui_types::base_types::{ObjectID, SuiAddress};
 use visualsign::errors::VisualSignError;
 
+use crate::utils::decode_number;
+
 /// Gets the index from the Sui arguments array (expects a single argument).
 ///
 /// Only `SuiArgument::Input(N)` is accepted, since the returned `u16` is used
@@ -109,6 +111,86 @@ pub fn get_object_value(
     }
 }
 
+/// Reads a pure `address` argument.
+pub fn get_address_value(
+    sui_args: &[SuiArgument],
+    sui_inputs: &[SuiCallArg],
+    arg_index: usize,
+) -> Result<SuiAddress, VisualSignError> {
+    let input = sui_inputs
+        .get(get_index(sui_args, Some(arg_index))? as usize)
+        .ok_or(VisualSignError::MissingData(
+            "Address input not found".into(),
+        ))?;
+
+    input
+        .pure()
+        .ok_or(VisualSignError::MissingData(
+            "Expected a pure address input".into(),
+        ))?
+        .to_sui_address()
+        .map_err(|e| VisualSignError::ConversionError(e.to_string()))
+}
+
+/// Reads a pure Move `String` argument.
+///
+/// Raw BCS inputs carry a ULEB128 length prefix followed by UTF-8 bytes;
+/// inputs that already have a resolved type come through as JSON strings.
+pub fn get_string_value(
+    sui_args: &[SuiArgument],
+    sui_inputs: &[SuiCallArg],
+    arg_index: usize,
+) -> Result<String, VisualSignError> {
+    let input = sui_inputs
+        .get(get_index(sui_args, Some(arg_index))? as usize)
+        .ok_or(VisualSignError::MissingData(
+            "String input not found".into(),
+        ))?;
+
+    let value = input
+        .pure()
+        .ok_or(VisualSignError::MissingData(
+            "Expected a pure string input".into(),
+        ))?
+        .value()
+        .to_json_value();
+
+    match value {
+        serde_json::Value::String(string) => Ok(string),
+        bytes => {
+            let bytes: Vec<u8> = serde_json::from_value(bytes)
+                .map_err(|e| VisualSignError::DecodeError(format!("Invalid string bytes: {e}")))?;
+            bcs::from_bytes::<String>(&bytes)
+                .map_err(|e| VisualSignError::DecodeError(format!("Invalid string: {e}")))
+        }
+    }
+}
+
+/// Amount of a coin argument split off by an earlier `SplitCoins` command.
+///
+/// Only `Result`/`NestedResult` arguments are followed, since those index the
+/// commands vector. A coin passed directly as an input, or produced by any
+/// other command, has no amount in the transaction and yields `None`.
+pub fn get_split_coin_amount(
+    commands: &[SuiCommand],
+    inputs: &[SuiCallArg],
+    coin_arg: &SuiArgument,
+) -> Option<u64> {
+    let (command_index, amount_index) = match coin_arg {
+        SuiArgument::Result(command_index) => (*command_index, 0),
+        SuiArgument::NestedResult(command_index, amount_index) => (*command_index, *amount_index),
+        _ => return None,
+    };
+
+    let SuiCommand::SplitCoins(_, amounts) = commands.get(command_index as usize)? else {
+        return None;
+    };
+    let amount_arg = amounts.get(amount_index as usize)?;
+    let amount_input = inputs.get(parse_numeric_argument(*amount_arg).ok()? as usize)?;
+
+    decode_number::<u64>(amount_input).ok()
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
 mod tests {
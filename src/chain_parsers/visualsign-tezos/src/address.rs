@@ -0,0 +1,127 @@
+// Tezos base58check strings: a version prefix that makes the string start with `tz1`,
+// `KT1`, `edpk` and so on, the payload, and the first four bytes of its double SHA-256.
+
+use sha2::{Digest, Sha256};
+
+const TZ1: &[u8] = &[6, 161, 159];
+const TZ2: &[u8] = &[6, 161, 161];
+const TZ3: &[u8] = &[6, 161, 164];
+const TZ4: &[u8] = &[6, 161, 166];
+const KT1: &[u8] = &[2, 90, 121];
+const BLOCK_HASH: &[u8] = &[1, 52];
+const EDPK: &[u8] = &[13, 15, 37, 217];
+const SPPK: &[u8] = &[3, 254, 226, 86];
+const P2PK: &[u8] = &[3, 178, 139, 127];
+const BLPK: &[u8] = &[6, 149, 135, 204];
+
+/// Bytes in a public key hash, after its one-byte curve tag.
+pub const PKH_LEN: usize = 20;
+
+fn base58check(prefix: &[u8], payload: &[u8]) -> String {
+    let mut data = prefix.to_vec();
+    data.extend_from_slice(payload);
+    let digest = Sha256::digest(Sha256::digest(&data));
+    data.extend_from_slice(&digest[..4]);
+    bs58::encode(data).into_string()
+}
+
+#[cfg(test)]
+pub fn decode_base58check(s: &str) -> Option<Vec<u8>> {
+    let data = bs58::decode(s).into_vec().ok()?;
+    let (body, sum) = data.split_at(data.len().checked_sub(4)?);
+    let digest = Sha256::digest(Sha256::digest(body));
+    (digest[..4] == *sum).then(|| body.to_vec())
+}
+
+/// An implicit account (`tz1`…`tz4`) from its curve tag and hash, or `None` for an
+/// unknown curve.
+pub fn implicit(curve: u8, hash: &[u8; PKH_LEN]) -> Option<String> {
+    let prefix = match curve {
+        0 => TZ1,
+        1 => TZ2,
+        2 => TZ3,
+        3 => TZ4,
+        _ => return None,
+    };
+    Some(base58check(prefix, hash))
+}
+
+pub fn originated(hash: &[u8; PKH_LEN]) -> String {
+    base58check(KT1, hash)
+}
+
+pub fn block_hash(hash: &[u8; 32]) -> String {
+    base58check(BLOCK_HASH, hash)
+}
+
+/// Public keys as they appear in a reveal: a curve tag followed by the key. Returns the
+/// key's length in bytes and its base58 prefix.
+pub fn public_key_layout(curve: u8) -> Option<(usize, &'static [u8])> {
+    match curve {
+        0 => Some((32, EDPK)),
+        1 => Some((33, SPPK)),
+        2 => Some((33, P2PK)),
+        3 => Some((48, BLPK)),
+        _ => None,
+    }
+}
+
+pub fn public_key(prefix: &[u8], key: &[u8]) -> String {
+    base58check(prefix, key)
+}
+
+/// An address in the 22-byte form Michelson uses for `address` values: tag 0 plus an
+/// implicit account's curve and hash, or tag 1 plus a contract hash and a padding byte.
+pub fn from_contract_bytes(bytes: &[u8]) -> Option<String> {
+    match bytes {
+        [0, curve, hash @ ..] if hash.len() == PKH_LEN => implicit(*curve, hash.try_into().ok()?),
+        [1, rest @ ..] if rest.len() == PKH_LEN + 1 => {
+            Some(originated(rest.get(..PKH_LEN)?.try_into().ok()?))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    const TZ1_ADDRESS: &str = "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx";
+    const KT1_ADDRESS: &str = "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn";
+
+    #[test]
+    fn encodes_known_addresses() {
+        let tz1: [u8; PKH_LEN] = hex::decode("02298c03ed7d454a101eb7022bc95f7e5f41ac78")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let kt1: [u8; PKH_LEN] = hex::decode("a3d0f58d8964bd1b37fb0a0c197b38cf46608d49")
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(implicit(0, &tz1).unwrap(), TZ1_ADDRESS);
+        assert_eq!(originated(&kt1), KT1_ADDRESS);
+        assert_eq!(decode_base58check(KT1_ADDRESS).unwrap()[3..], kt1);
+        assert!(implicit(4, &tz1).is_none());
+    }
+
+    #[test]
+    fn michelson_address_bytes() {
+        let mut implicit_bytes = vec![0, 0];
+        implicit_bytes.extend_from_slice(&decode_base58check(TZ1_ADDRESS).unwrap()[3..]);
+        assert_eq!(
+            from_contract_bytes(&implicit_bytes).as_deref(),
+            Some(TZ1_ADDRESS)
+        );
+
+        let mut originated_bytes = vec![1];
+        originated_bytes.extend_from_slice(&decode_base58check(KT1_ADDRESS).unwrap()[3..]);
+        originated_bytes.push(0);
+        assert_eq!(
+            from_contract_bytes(&originated_bytes).as_deref(),
+            Some(KT1_ADDRESS)
+        );
+        assert!(from_contract_bytes(&originated_bytes[1..]).is_none());
+    }
+}
@@ -0,0 +1,42 @@
+use clap::Args as ClapArgs;
+use generated::parser::ChainMetadata;
+use visualsign::registry::{Chain, TransactionConverterRegistry};
+
+/// CLI arguments specific to Tezos.
+///
+/// No Tezos-specific args are needed yet. The global `--network` flag is accepted but
+/// not used: forged operations only reference a block, not a network.
+#[derive(ClapArgs, Debug, Default, Clone)]
+pub struct TezosArgs {}
+
+/// [`parser_cli_core::ChainPlugin`] implementation for Tezos.
+pub struct TezosPlugin {
+    // Empty today; kept so a Tezos flag can be added without changing the struct shape.
+    #[allow(dead_code)]
+    args: TezosArgs,
+}
+
+impl TezosPlugin {
+    /// Creates a new `TezosPlugin` with the given CLI args.
+    #[must_use]
+    pub fn new(args: TezosArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl parser_cli_core::ChainPlugin for TezosPlugin {
+    fn chain(&self) -> Chain {
+        Chain::Tezos
+    }
+
+    fn register(&self, registry: &mut TransactionConverterRegistry) {
+        registry.register::<crate::TezosTransactionWrapper, _>(
+            Chain::Tezos,
+            crate::TezosVisualSignConverter,
+        );
+    }
+
+    fn create_metadata(&self, _network: Option<String>) -> Result<Option<ChainMetadata>, String> {
+        Ok(None)
+    }
+}
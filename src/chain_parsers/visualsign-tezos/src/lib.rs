@@ -0,0 +1,631 @@
+#[cfg(feature = "cli-plugin")]
+pub mod cli_plugin;
+
+#[cfg(feature = "cli-plugin")]
+pub use cli_plugin::{TezosArgs, TezosPlugin};
+
+mod address;
+mod micheline;
+mod operation;
+
+pub use micheline::{Micheline, TokenTransfer};
+pub use operation::{ForgedOperation, Operation, OperationKind, Parameters};
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
+use visualsign::field_builders::{
+    create_address_field, create_amount_field, create_preview_layout, create_text_field,
+};
+use visualsign::{
+    AnnotatedPayloadField, SignablePayload,
+    encodings::SupportedEncodings,
+    vsptrait::{
+        ConversionResult, Transaction, TransactionParseError, VisualSignConverter,
+        VisualSignConverterFromString, VisualSignError, VisualSignOptions,
+    },
+};
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum TezosParserError {
+    #[error("Failed to decode transaction: {0}")]
+    FailedToDecodeTransaction(String),
+    #[error("Operation ended unexpectedly")]
+    UnexpectedEnd,
+    #[error("Number too large")]
+    NumberTooLarge,
+    #[error("Invalid Micheline: {0}")]
+    InvalidMicheline(String),
+    #[error("Unsupported operation tag {0}")]
+    UnsupportedOperation(u8),
+}
+
+// 1 tez = 10^6 mutez.
+const TEZ_DECIMALS: u32 = 6;
+
+fn decode_transaction(
+    raw_transaction: &str,
+    encodings: SupportedEncodings,
+) -> Result<ForgedOperation, TezosParserError> {
+    let bytes = match encodings {
+        SupportedEncodings::Hex => {
+            visualsign::encodings::decode_hex(raw_transaction).map_err(|e| {
+                TezosParserError::FailedToDecodeTransaction(format!("Failed to decode hex: {e}"))
+            })?
+        }
+        SupportedEncodings::Base64 => b64.decode(raw_transaction).map_err(|e| {
+            TezosParserError::FailedToDecodeTransaction(format!("Failed to decode base64: {e}"))
+        })?,
+    };
+
+    ForgedOperation::decode(&bytes)
+}
+
+/// Wrapper for forged Tezos operations
+#[derive(Debug, Clone)]
+pub struct TezosTransactionWrapper {
+    transaction: ForgedOperation,
+}
+
+impl Transaction for TezosTransactionWrapper {
+    fn from_string(data: &str) -> Result<Self, TransactionParseError> {
+        // Octez and Taquito hand out forged operations as unprefixed hex.
+        let format = SupportedEncodings::detect(data);
+        let transaction = decode_transaction(data, format)
+            .map_err(|e| TransactionParseError::DecodeError(e.to_string()))?;
+        Ok(Self { transaction })
+    }
+
+    fn transaction_type(&self) -> String {
+        "Tezos".to_string()
+    }
+}
+
+impl TezosTransactionWrapper {
+    pub fn new(transaction: ForgedOperation) -> Self {
+        Self { transaction }
+    }
+
+    pub fn inner(&self) -> &ForgedOperation {
+        &self.transaction
+    }
+}
+
+/// Converter for Tezos operations
+pub struct TezosVisualSignConverter;
+
+impl VisualSignConverter<TezosTransactionWrapper> for TezosVisualSignConverter {
+    fn to_visual_sign_payload(
+        &self,
+        transaction_wrapper: TezosTransactionWrapper,
+        options: VisualSignOptions,
+    ) -> Result<ConversionResult, VisualSignError> {
+        let payload = convert_to_visual_sign_payload(transaction_wrapper.inner(), options)?;
+        Ok(ConversionResult::new(payload))
+    }
+}
+
+impl VisualSignConverterFromString<TezosTransactionWrapper> for TezosVisualSignConverter {}
+
+// Public API functions
+pub fn transaction_to_visual_sign(
+    transaction: ForgedOperation,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let wrapper = TezosTransactionWrapper::new(transaction);
+    let converter = TezosVisualSignConverter;
+    converter
+        .to_visual_sign_payload(wrapper, options)
+        .map(|r| r.payload)
+}
+
+pub fn transaction_string_to_visual_sign(
+    transaction_data: &str,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let converter = TezosVisualSignConverter;
+    converter
+        .to_visual_sign_payload_from_string(transaction_data, options)
+        .map(|r| r.payload)
+}
+
+fn kind_label(kind: &OperationKind) -> &'static str {
+    match kind {
+        OperationKind::Reveal { .. } => "Reveal",
+        OperationKind::Transaction { .. } => "Transaction",
+        OperationKind::Origination { .. } => "Origination",
+        OperationKind::Delegation { .. } => "Delegation",
+    }
+}
+
+fn tez(mutez: u64) -> String {
+    format_units(u128::from(mutez), TEZ_DECIMALS)
+}
+
+fn address_field(label: &str, address: &str) -> Result<AnnotatedPayloadField, VisualSignError> {
+    create_address_field(label, address, None, None, None, None)
+}
+
+/// Token amounts are shown in raw units: the operation doesn't say how many decimals the
+/// token uses.
+fn token_amount(transfer: &TokenTransfer) -> String {
+    match transfer.token_id {
+        Some(id) => format!("{} of token #{id}", transfer.amount),
+        None => format!("{} tokens", transfer.amount),
+    }
+}
+
+/// Fields and subtitle for a transaction: a plain tez transfer, a token transfer, or
+/// any other contract call.
+fn transaction_details(
+    amount: u64,
+    destination: &str,
+    parameters: Option<&Parameters>,
+) -> Result<(String, Vec<AnnotatedPayloadField>), VisualSignError> {
+    let mut fields = vec![
+        address_field("Destination", destination)?,
+        create_amount_field("Amount", &tez(amount), "XTZ")?,
+    ];
+    let Some(parameters) = parameters else {
+        return Ok((format!("Send {} XTZ to {destination}", tez(amount)), fields));
+    };
+
+    fields.push(create_text_field("Entrypoint", &parameters.entrypoint)?);
+    let transfers = micheline::token_transfers(&parameters.entrypoint, &parameters.value);
+    let subtitle = match transfers.as_deref() {
+        Some([transfer]) => format!(
+            "Transfer {} of {destination} from {} to {}",
+            token_amount(transfer),
+            transfer.from,
+            transfer.to
+        ),
+        Some(transfers) => format!("{} token transfers on {destination}", transfers.len()),
+        None if amount > 0 => format!(
+            "Call {} on {destination} with {} XTZ",
+            parameters.entrypoint,
+            tez(amount)
+        ),
+        None => format!("Call {} on {destination}", parameters.entrypoint),
+    };
+    match &transfers {
+        Some(transfers) => {
+            let standard = if transfers.iter().any(|t| t.token_id.is_some()) {
+                "FA2"
+            } else {
+                "FA1.2"
+            };
+            fields.push(create_text_field("Token Standard", standard)?);
+            for transfer in transfers {
+                fields.push(create_text_field(
+                    "Token Transfer",
+                    &format!(
+                        "{} from {} to {}",
+                        token_amount(transfer),
+                        transfer.from,
+                        transfer.to
+                    ),
+                )?);
+            }
+        }
+        None => fields.push(create_text_field(
+            "Parameters",
+            &parameters.value.to_string(),
+        )?),
+    }
+    Ok((subtitle, fields))
+}
+
+fn operation_preview(
+    title: &str,
+    operation: &Operation,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let (subtitle, mut fields) = match &operation.kind {
+        OperationKind::Reveal { public_key } => (
+            format!("Reveal the public key of {}", operation.source),
+            vec![create_text_field("Public Key", public_key)?],
+        ),
+        OperationKind::Transaction {
+            amount,
+            destination,
+            parameters,
+        } => transaction_details(*amount, destination, parameters.as_ref())?,
+        OperationKind::Origination {
+            balance,
+            delegate,
+            code,
+            storage,
+        } => {
+            let mut fields = vec![create_amount_field("Balance", &tez(*balance), "XTZ")?];
+            if let Some(delegate) = delegate {
+                fields.push(address_field("Delegate", delegate)?);
+            }
+            if let Some(parameter_type) = code.parameter_type() {
+                fields.push(create_text_field(
+                    "Parameter Type",
+                    &parameter_type.to_string(),
+                )?);
+            }
+            fields.push(create_text_field("Initial Storage", &storage.to_string())?);
+            (
+                format!("Originate a contract with {} XTZ", tez(*balance)),
+                fields,
+            )
+        }
+        OperationKind::Delegation {
+            delegate: Some(delegate),
+        } => (
+            format!("Delegate to {delegate}"),
+            vec![address_field("Delegate", delegate)?],
+        ),
+        OperationKind::Delegation { delegate: None } => (
+            "Remove the delegate".to_string(),
+            vec![create_text_field(
+                "Delegate",
+                "None (withdraws delegation)",
+            )?],
+        ),
+    };
+
+    fields.insert(0, address_field("Source", &operation.source)?);
+    fields.extend([
+        create_amount_field("Fee", &tez(operation.fee), "XTZ")?,
+        create_text_field("Counter", &operation.counter.to_string())?,
+        create_text_field("Gas Limit", &operation.gas_limit.to_string())?,
+        create_text_field("Storage Limit", &operation.storage_limit.to_string())?,
+    ]);
+    Ok(create_preview_layout(title, subtitle, fields))
+}
+
+fn convert_to_visual_sign_payload(
+    forged: &ForgedOperation,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let mut fields: Vec<AnnotatedPayloadField> = vec![
+        create_text_field("Network", "Tezos")?,
+        create_text_field("Branch", &address::block_hash(&forged.branch))?,
+    ];
+
+    let kinds: Vec<&str> = forged
+        .contents
+        .iter()
+        .map(|op| kind_label(&op.kind))
+        .collect();
+    fields.push(create_text_field("Operations", &kinds.join(", "))?);
+
+    let total_fee: u64 = forged
+        .contents
+        .iter()
+        .fold(0, |sum, op| sum.saturating_add(op.fee));
+    fields.push(create_amount_field("Total Fee", &tez(total_fee), "XTZ")?);
+
+    // Tez leaving the source: transfers plus the balance of new contracts.
+    let total_sent: u64 = forged
+        .contents
+        .iter()
+        .map(|op| match op.kind {
+            OperationKind::Transaction { amount, .. } => amount,
+            OperationKind::Origination { balance, .. } => balance,
+            _ => 0,
+        })
+        .fold(0, u64::saturating_add);
+    if total_sent > 0 {
+        fields.push(create_amount_field(
+            "Total Amount",
+            &tez(total_sent),
+            "XTZ",
+        )?);
+    }
+
+    for (i, operation) in forged.contents.iter().enumerate() {
+        fields.push(operation_preview(
+            &format!("Operation {}: {}", i + 1, kind_label(&operation.kind)),
+            operation,
+        )?);
+    }
+
+    let title = options
+        .transaction_name
+        .unwrap_or_else(|| "Tezos Transaction".to_string());
+
+    Ok(SignablePayload::new(
+        0,
+        title,
+        None,
+        fields
+            .into_iter()
+            .map(|af| af.signable_payload_field)
+            .collect(),
+        "TezosTx".to_string(),
+    ))
+}
+
+// Base units to a decimal string with integer math, trimming trailing zeros so e.g.
+// 1_500_000 mutez -> "1.5".
+fn format_units(value: u128, decimals: u32) -> String {
+    let digits = value.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return digits;
+    }
+    let padded = format!("{digits:0>width$}", width = decimals + 1);
+    let (whole, frac) = padded.split_at(padded.len() - decimals);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{frac}")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::micheline::builder::{int, pair, seq, string};
+    use crate::operation::builder::Writer;
+    use visualsign::{SignablePayloadField, SignablePayloadFieldListLayout};
+
+    const REVEAL: u8 = 107;
+    const TRANSACTION: u8 = 108;
+    const ORIGINATION: u8 = 109;
+    const DELEGATION: u8 = 110;
+
+    fn tz1(hash: u8) -> String {
+        address::implicit(0, &[hash; 20]).unwrap()
+    }
+
+    fn kt1(hash: u8) -> String {
+        address::originated(&[hash; 20])
+    }
+
+    fn to_payload(writer: Writer) -> SignablePayload {
+        transaction_string_to_visual_sign(&hex::encode(writer.0), VisualSignOptions::default())
+            .unwrap()
+    }
+
+    fn find_field<'a>(
+        payload: &'a SignablePayload,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        payload.fields.iter().find(|f| field_label(f) == label)
+    }
+
+    fn find_detail<'a>(
+        layout: &'a SignablePayloadFieldListLayout,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        layout
+            .fields
+            .iter()
+            .map(|f| &f.signable_payload_field)
+            .find(|f| field_label(f) == label)
+    }
+
+    fn field_label(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { common, .. }
+            | SignablePayloadField::AmountV2 { common, .. }
+            | SignablePayloadField::AddressV2 { common, .. }
+            | SignablePayloadField::PreviewLayout { common, .. } => &common.label,
+            _ => "",
+        }
+    }
+
+    fn text_value(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { text_v2, .. } => text_v2.text.as_str(),
+            other => panic!("expected TextV2, got {other:?}"),
+        }
+    }
+
+    fn amount_value(field: &SignablePayloadField) -> (&str, &str) {
+        match field {
+            SignablePayloadField::AmountV2 { amount_v2, .. } => (
+                amount_v2.amount.as_str(),
+                amount_v2.abbreviation.as_deref().unwrap_or(""),
+            ),
+            _ => panic!("expected AmountV2"),
+        }
+    }
+
+    fn address_value(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::AddressV2 { address_v2, .. } => address_v2.address.as_str(),
+            _ => panic!("expected AddressV2"),
+        }
+    }
+
+    fn preview_subtitle(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => preview_layout
+                .subtitle
+                .as_ref()
+                .map(|t| t.text.as_str())
+                .unwrap_or(""),
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn preview_expanded(field: &SignablePayloadField) -> &SignablePayloadFieldListLayout {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                preview_layout.expanded.as_ref().unwrap()
+            }
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    #[test]
+    fn reveal_and_tez_transfer() {
+        let forged = Writer::branch()
+            .manager(REVEAL, 1, 374)
+            .u8(0)
+            .raw(&[0x11; 32])
+            .manager(TRANSACTION, 1, 1_420)
+            .nat(1_500_000)
+            .implicit(2)
+            .u8(0);
+        let payload = to_payload(forged);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Operations").unwrap()),
+            "Reveal, Transaction"
+        );
+        assert!(
+            text_value(find_field(&payload, "Branch").unwrap()).starts_with('B'),
+            "branch is a block hash"
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Total Fee").unwrap()),
+            ("0.001794", "XTZ")
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Total Amount").unwrap()),
+            ("1.5", "XTZ")
+        );
+
+        let reveal = find_field(&payload, "Operation 1: Reveal").unwrap();
+        assert!(
+            text_value(find_detail(preview_expanded(reveal), "Public Key").unwrap())
+                .starts_with("edpk")
+        );
+
+        let transfer = find_field(&payload, "Operation 2: Transaction").unwrap();
+        assert_eq!(
+            preview_subtitle(transfer),
+            format!("Send 1.5 XTZ to {}", tz1(2))
+        );
+        let details = preview_expanded(transfer);
+        assert_eq!(
+            address_value(find_detail(details, "Source").unwrap()),
+            tz1(1)
+        );
+        assert_eq!(
+            text_value(find_detail(details, "Storage Limit").unwrap()),
+            "300"
+        );
+    }
+
+    fn call(entrypoint: &str, value: &[u8]) -> Writer {
+        Writer::branch()
+            .manager(TRANSACTION, 1, 2_000)
+            .nat(0)
+            .originated(9)
+            .u8(0xff)
+            .u8(255)
+            .u8(entrypoint.len() as u8)
+            .raw(entrypoint.as_bytes())
+            .sized(value)
+    }
+
+    #[test]
+    fn fa12_and_fa2_transfers() {
+        let fa12 = pair(string(&tz1(1)), pair(string(&tz1(2)), int(25_000)));
+        let payload = to_payload(call("transfer", &fa12));
+        let op = find_field(&payload, "Operation 1: Transaction").unwrap();
+        assert_eq!(
+            preview_subtitle(op),
+            format!(
+                "Transfer 25000 tokens of {} from {} to {}",
+                kt1(9),
+                tz1(1),
+                tz1(2)
+            )
+        );
+        assert_eq!(
+            text_value(find_detail(preview_expanded(op), "Token Standard").unwrap()),
+            "FA1.2"
+        );
+        assert!(find_field(&payload, "Total Amount").is_none());
+
+        let fa2 = seq(&[pair(
+            string(&tz1(1)),
+            seq(&[
+                pair(string(&tz1(2)), pair(int(0), int(10))),
+                pair(string(&tz1(3)), pair(int(5), int(1))),
+            ]),
+        )]);
+        let payload = to_payload(call("transfer", &fa2));
+        let op = find_field(&payload, "Operation 1: Transaction").unwrap();
+        assert_eq!(
+            preview_subtitle(op),
+            format!("2 token transfers on {}", kt1(9))
+        );
+        let details = preview_expanded(op);
+        assert_eq!(
+            text_value(find_detail(details, "Token Standard").unwrap()),
+            "FA2"
+        );
+        assert_eq!(
+            text_value(find_detail(details, "Token Transfer").unwrap()),
+            format!("10 of token #0 from {} to {}", tz1(1), tz1(2))
+        );
+    }
+
+    #[test]
+    fn other_contract_calls_show_michelson() {
+        let payload = to_payload(call("mint", &pair(string("a"), int(-1))));
+        let op = find_field(&payload, "Operation 1: Transaction").unwrap();
+        assert_eq!(preview_subtitle(op), format!("Call mint on {}", kt1(9)));
+        assert_eq!(
+            text_value(find_detail(preview_expanded(op), "Parameters").unwrap()),
+            "Pair \"a\" -1"
+        );
+    }
+
+    #[test]
+    fn delegation_and_origination() {
+        let payload = to_payload(
+            Writer::branch()
+                .manager(DELEGATION, 1, 400)
+                .u8(0xff)
+                .u8(0)
+                .raw(&[4; 20]),
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Operation 1: Delegation").unwrap()),
+            format!("Delegate to {}", tz1(4))
+        );
+
+        let payload = to_payload(Writer::branch().manager(DELEGATION, 1, 400).u8(0));
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Operation 1: Delegation").unwrap()),
+            "Remove the delegate"
+        );
+
+        // parameter nat ; storage nat ; code {}
+        let code = seq(&[
+            vec![0x05, 0, 0x03, 98],
+            vec![0x05, 1, 0x03, 98],
+            [vec![0x05, 2], seq(&[])].concat(),
+        ]);
+        let payload = to_payload(
+            Writer::branch()
+                .manager(ORIGINATION, 1, 1_000)
+                .nat(2_000_000)
+                .u8(0)
+                .sized(&code)
+                .sized(&int(42)),
+        );
+        let op = find_field(&payload, "Operation 1: Origination").unwrap();
+        assert_eq!(preview_subtitle(op), "Originate a contract with 2 XTZ");
+        let details = preview_expanded(op);
+        assert_eq!(
+            text_value(find_detail(details, "Parameter Type").unwrap()),
+            "nat"
+        );
+        assert_eq!(
+            text_value(find_detail(details, "Initial Storage").unwrap()),
+            "42"
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Total Amount").unwrap()),
+            ("2", "XTZ")
+        );
+    }
+
+    #[test]
+    fn units_formatting() {
+        assert_eq!(format_units(0, 6), "0");
+        assert_eq!(format_units(1, 6), "0.000001");
+        assert_eq!(format_units(1_500_000, 6), "1.5");
+    }
+}
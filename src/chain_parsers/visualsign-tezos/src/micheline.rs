@@ -0,0 +1,528 @@
+// Micheline, the syntax of Michelson code and data, in the binary form used inside
+// operations, and printing it back in the usual Michelson notation. Also recognizes the
+// FA1.2 (TZIP-7) and FA2 (TZIP-12) `transfer` parameters.
+
+use crate::TezosParserError;
+use crate::address;
+use crate::operation::Reader;
+
+// Deep nesting is legal but never needed for display; this bounds the recursion.
+const MAX_DEPTH: usize = 64;
+
+const PRIM_PAIR: u8 = 7;
+const PRIM_PARAMETER: u8 = 0;
+
+/// Michelson primitives by their binary code.
+const PRIMITIVES: &[&str] = &[
+    "parameter",
+    "storage",
+    "code",
+    "False",
+    "Elt",
+    "Left",
+    "None",
+    "Pair",
+    "Right",
+    "Some",
+    "True",
+    "Unit",
+    "PACK",
+    "UNPACK",
+    "BLAKE2B",
+    "SHA256",
+    "SHA512",
+    "ABS",
+    "ADD",
+    "AMOUNT",
+    "AND",
+    "BALANCE",
+    "CAR",
+    "CDR",
+    "CHECK_SIGNATURE",
+    "COMPARE",
+    "CONCAT",
+    "CONS",
+    "CREATE_ACCOUNT",
+    "CREATE_CONTRACT",
+    "IMPLICIT_ACCOUNT",
+    "DIP",
+    "DROP",
+    "DUP",
+    "EDIV",
+    "EMPTY_MAP",
+    "EMPTY_SET",
+    "EQ",
+    "EXEC",
+    "FAILWITH",
+    "GE",
+    "GET",
+    "GT",
+    "HASH_KEY",
+    "IF",
+    "IF_CONS",
+    "IF_LEFT",
+    "IF_NONE",
+    "INT",
+    "LAMBDA",
+    "LE",
+    "LEFT",
+    "LOOP",
+    "LSL",
+    "LSR",
+    "LT",
+    "MAP",
+    "MEM",
+    "MUL",
+    "NEG",
+    "NEQ",
+    "NIL",
+    "NONE",
+    "NOT",
+    "NOW",
+    "OR",
+    "PAIR",
+    "PUSH",
+    "RIGHT",
+    "SIZE",
+    "SOME",
+    "SOURCE",
+    "SENDER",
+    "SELF",
+    "STEPS_TO_QUOTA",
+    "SUB",
+    "SWAP",
+    "TRANSFER_TOKENS",
+    "SET_DELEGATE",
+    "UNIT",
+    "UPDATE",
+    "XOR",
+    "ITER",
+    "LOOP_LEFT",
+    "ADDRESS",
+    "CONTRACT",
+    "ISNAT",
+    "CAST",
+    "RENAME",
+    "bool",
+    "contract",
+    "int",
+    "key",
+    "key_hash",
+    "lambda",
+    "list",
+    "map",
+    "big_map",
+    "nat",
+    "option",
+    "or",
+    "pair",
+    "set",
+    "signature",
+    "string",
+    "bytes",
+    "mutez",
+    "timestamp",
+    "unit",
+    "operation",
+    "address",
+    "SLICE",
+    "DIG",
+    "DUG",
+    "EMPTY_BIG_MAP",
+    "APPLY",
+    "chain_id",
+    "CHAIN_ID",
+    "LEVEL",
+    "SELF_ADDRESS",
+    "never",
+    "NEVER",
+    "UNPAIR",
+    "VOTING_POWER",
+    "TOTAL_VOTING_POWER",
+    "KECCAK",
+    "SHA3",
+    "PAIRING_CHECK",
+    "bls12_381_g1",
+    "bls12_381_g2",
+    "bls12_381_fr",
+    "sapling_state",
+    "sapling_transaction_deprecated",
+    "SAPLING_EMPTY_STATE",
+    "SAPLING_VERIFY_UPDATE",
+    "ticket",
+    "TICKET_DEPRECATED",
+    "READ_TICKET",
+    "SPLIT_TICKET",
+    "JOIN_TICKETS",
+    "GET_AND_UPDATE",
+    "chest",
+    "chest_key",
+    "OPEN_CHEST",
+    "VIEW",
+    "view",
+    "constant",
+    "SUB_MUTEZ",
+    "tx_rollup_l2_address",
+    "MIN_BLOCK_TIME",
+    "sapling_transaction",
+    "EMIT",
+    "Lambda_rec",
+    "LAMBDA_REC",
+    "TICKET",
+    "BYTES",
+    "NAT",
+    "Ticket",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Micheline {
+    Int(i128),
+    String(String),
+    Bytes(Vec<u8>),
+    Seq(Vec<Micheline>),
+    Prim {
+        prim: u8,
+        args: Vec<Micheline>,
+        annots: Vec<String>,
+    },
+}
+
+impl Micheline {
+    /// Decodes one expression that spans all of `bytes`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, TezosParserError> {
+        let mut reader = Reader::new(bytes);
+        let value = read(&mut reader, 0)?;
+        if !reader.is_empty() {
+            return Err(TezosParserError::InvalidMicheline(
+                "trailing bytes after expression".to_string(),
+            ));
+        }
+        Ok(value)
+    }
+
+    /// The argument of the `parameter` section of a contract's code: its parameter type.
+    pub fn parameter_type(&self) -> Option<&Micheline> {
+        let Self::Seq(sections) = self else {
+            return None;
+        };
+        sections.iter().find_map(|section| match section {
+            Self::Prim { prim, args, .. } if *prim == PRIM_PARAMETER => args.first(),
+            _ => None,
+        })
+    }
+
+    /// The elements of a right comb of pairs, so `Pair a (Pair b c)` and `Pair a b c` both
+    /// give `[a, b, c]`.
+    fn comb(&self) -> Option<Vec<&Micheline>> {
+        let Self::Prim { prim, args, .. } = self else {
+            return None;
+        };
+        if *prim != PRIM_PAIR || args.len() < 2 {
+            return None;
+        }
+        let (last, init) = args.split_last()?;
+        let mut items: Vec<&Micheline> = init.iter().collect();
+        match last.comb() {
+            Some(rest) => items.extend(rest),
+            None => items.push(last),
+        }
+        Some(items)
+    }
+
+    /// An `address` value, written as a string or in its 22-byte binary form. Either may
+    /// be followed by an entrypoint, which is dropped.
+    fn address(&self) -> Option<String> {
+        match self {
+            Self::String(s) => Some(s.split('%').next().unwrap_or_default().to_string()),
+            Self::Bytes(bytes) => address::from_contract_bytes(bytes.get(..22)?),
+            _ => None,
+        }
+    }
+
+    fn nat(&self) -> Option<u128> {
+        match self {
+            Self::Int(n) => u128::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+}
+
+fn read(reader: &mut Reader, depth: usize) -> Result<Micheline, TezosParserError> {
+    if depth > MAX_DEPTH {
+        return Err(TezosParserError::InvalidMicheline(
+            "expression nested too deeply".to_string(),
+        ));
+    }
+    let tag = reader.u8()?;
+    let value = match tag {
+        0x00 => Micheline::Int(reader.int()?),
+        0x01 => Micheline::String(utf8(reader.sized()?)?),
+        0x02 => Micheline::Seq(read_all(reader.sized()?, depth)?),
+        0x03..=0x08 => {
+            let prim = reader.u8()?;
+            let arg_count = usize::from((tag - 0x03) / 2);
+            let args = (0..arg_count)
+                .map(|_| read(reader, depth + 1))
+                .collect::<Result<_, _>>()?;
+            let annots = if tag % 2 == 0 {
+                annotations(reader.sized()?)?
+            } else {
+                vec![]
+            };
+            Micheline::Prim { prim, args, annots }
+        }
+        0x09 => {
+            let prim = reader.u8()?;
+            let args = read_all(reader.sized()?, depth)?;
+            let annots = annotations(reader.sized()?)?;
+            Micheline::Prim { prim, args, annots }
+        }
+        0x0a => Micheline::Bytes(reader.sized()?.to_vec()),
+        other => {
+            return Err(TezosParserError::InvalidMicheline(format!(
+                "unknown node tag {other:#04x}"
+            )));
+        }
+    };
+    Ok(value)
+}
+
+fn read_all(bytes: &[u8], depth: usize) -> Result<Vec<Micheline>, TezosParserError> {
+    let mut reader = Reader::new(bytes);
+    let mut items = Vec::new();
+    while !reader.is_empty() {
+        items.push(read(&mut reader, depth + 1)?);
+    }
+    Ok(items)
+}
+
+fn utf8(bytes: &[u8]) -> Result<String, TezosParserError> {
+    String::from_utf8(bytes.to_vec())
+        .map_err(|_| TezosParserError::InvalidMicheline("string is not UTF-8".to_string()))
+}
+
+fn annotations(bytes: &[u8]) -> Result<Vec<String>, TezosParserError> {
+    Ok(utf8(bytes)?
+        .split(' ')
+        .filter(|a| !a.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+impl std::fmt::Display for Micheline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::String(s) => write!(f, "{s:?}"),
+            Self::Bytes(bytes) => write!(f, "0x{}", hex::encode(bytes)),
+            Self::Seq(items) if items.is_empty() => write!(f, "{{}}"),
+            Self::Seq(items) => {
+                write!(f, "{{ ")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ; ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, " }}")
+            }
+            Self::Prim { prim, args, annots } => {
+                match PRIMITIVES.get(usize::from(*prim)) {
+                    Some(name) => write!(f, "{name}")?,
+                    None => write!(f, "<primitive {prim}>")?,
+                }
+                for annot in annots {
+                    write!(f, " {annot}")?;
+                }
+                for arg in args {
+                    match arg {
+                        Self::Prim { args, annots, .. }
+                            if !args.is_empty() || !annots.is_empty() =>
+                        {
+                            write!(f, " ({arg})")?
+                        }
+                        _ => write!(f, " {arg}")?,
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// One movement of tokens in an FA1.2 or FA2 `transfer` call. FA1.2 contracts hold a
+/// single token, so `token_id` is `None` for them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenTransfer {
+    pub from: String,
+    pub to: String,
+    pub token_id: Option<u128>,
+    pub amount: u128,
+}
+
+/// The transfers in a call to a token contract's `transfer` entrypoint, or `None` when
+/// the parameter doesn't have the FA1.2 or FA2 shape.
+///
+/// FA1.2 takes `Pair from (Pair to value)`; FA2 takes a list of
+/// `Pair from [Pair to (Pair token_id amount)]`.
+pub fn token_transfers(entrypoint: &str, value: &Micheline) -> Option<Vec<TokenTransfer>> {
+    if entrypoint != "transfer" {
+        return None;
+    }
+    match value {
+        Micheline::Seq(batches) => {
+            let mut transfers = Vec::new();
+            for batch in batches {
+                let [from, txs] = batch.comb()?[..] else {
+                    return None;
+                };
+                let from = from.address()?;
+                let Micheline::Seq(txs) = txs else {
+                    return None;
+                };
+                for tx in txs {
+                    let [to, token_id, amount] = tx.comb()?[..] else {
+                        return None;
+                    };
+                    transfers.push(TokenTransfer {
+                        from: from.clone(),
+                        to: to.address()?,
+                        token_id: Some(token_id.nat()?),
+                        amount: amount.nat()?,
+                    });
+                }
+            }
+            // An empty batch moves nothing; show the raw parameter instead.
+            (!transfers.is_empty()).then_some(transfers)
+        }
+        _ => {
+            let [from, to, amount] = value.comb()?[..] else {
+                return None;
+            };
+            Some(vec![TokenTransfer {
+                from: from.address()?,
+                to: to.address()?,
+                token_id: None,
+                amount: amount.nat()?,
+            }])
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod builder {
+    //! Encodes Micheline, for constructing test inputs.
+
+    use super::*;
+
+    pub fn int(n: i128) -> Vec<u8> {
+        let mut out = vec![0x00];
+        let negative = n < 0;
+        let mut magnitude = n.unsigned_abs();
+        let mut first = (magnitude & 0x3f) as u8 | if negative { 0x40 } else { 0 };
+        magnitude >>= 6;
+        if magnitude > 0 {
+            first |= 0x80;
+        }
+        out.push(first);
+        while magnitude > 0 {
+            let byte = (magnitude & 0x7f) as u8;
+            magnitude >>= 7;
+            out.push(if magnitude > 0 { byte | 0x80 } else { byte });
+        }
+        out
+    }
+
+    pub fn string(s: &str) -> Vec<u8> {
+        sized(0x01, s.as_bytes())
+    }
+
+    pub fn bytes(b: &[u8]) -> Vec<u8> {
+        sized(0x0a, b)
+    }
+
+    pub fn seq(items: &[Vec<u8>]) -> Vec<u8> {
+        sized(0x02, &items.concat())
+    }
+
+    pub fn pair(a: Vec<u8>, b: Vec<u8>) -> Vec<u8> {
+        [vec![0x07, PRIM_PAIR], a, b].concat()
+    }
+
+    /// `Pair` with any number of arguments, in the generic encoding.
+    pub fn pair_n(args: &[Vec<u8>]) -> Vec<u8> {
+        let mut out = vec![0x09, PRIM_PAIR];
+        out.extend_from_slice(&(args.concat().len() as u32).to_be_bytes());
+        out.extend_from_slice(&args.concat());
+        out.extend_from_slice(&0u32.to_be_bytes());
+        out
+    }
+
+    fn sized(tag: u8, body: &[u8]) -> Vec<u8> {
+        let mut out = vec![tag];
+        out.extend_from_slice(&(body.len() as u32).to_be_bytes());
+        out.extend_from_slice(body);
+        out
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::builder::*;
+    use super::*;
+
+    const TZ1: &str = "tz1KqTpEZ7Yob7QbPE4Hy4Wo8fHG8LhKxZSx";
+    const KT1: &str = "KT1PWx2mnDueood7fEmfbBDKx1D9BAnnXitn";
+
+    #[test]
+    fn prints_michelson() {
+        // Pair (Left -5) { "a" ; 0x01 } with an annotated prim: Some %x Unit
+        let left = [vec![0x05, 5], int(-5)].concat();
+        let some = [vec![0x06, 9, 0x03, 11], vec![0, 0, 0, 2], b"%x".to_vec()].concat();
+        let value = pair_n(&[left, seq(&[string("a"), bytes(&[1])]), some]);
+        assert_eq!(
+            Micheline::decode(&value).unwrap().to_string(),
+            "Pair (Left -5) { \"a\" ; 0x01 } (Some %x Unit)"
+        );
+        assert!(Micheline::decode(&[0x0b]).is_err());
+        assert!(Micheline::decode(&[int(1), int(2)].concat()).is_err());
+    }
+
+    #[test]
+    fn recognizes_token_transfers() {
+        let mut kt1_bytes = vec![1];
+        kt1_bytes.extend_from_slice(&address::decode_base58check(KT1).unwrap()[3..]);
+        kt1_bytes.push(0);
+
+        let fa12 = pair(string(TZ1), pair(bytes(&kt1_bytes), int(1_000)));
+        let fa12 = Micheline::decode(&fa12).unwrap();
+        assert_eq!(
+            token_transfers("transfer", &fa12).unwrap(),
+            vec![TokenTransfer {
+                from: TZ1.to_string(),
+                to: KT1.to_string(),
+                token_id: None,
+                amount: 1_000,
+            }]
+        );
+        assert!(token_transfers("approve", &fa12).is_none());
+
+        let fa2 = seq(&[pair(
+            string(TZ1),
+            seq(&[
+                pair_n(&[string(KT1), int(3), int(7)]),
+                pair(string(TZ1), pair(int(4), int(8))),
+            ]),
+        )]);
+        let transfers = token_transfers("transfer", &Micheline::decode(&fa2).unwrap()).unwrap();
+        assert_eq!(transfers.len(), 2);
+        assert_eq!(transfers[0].to, KT1);
+        assert_eq!(transfers[1].token_id, Some(4));
+        assert_eq!(transfers[1].amount, 8);
+
+        // A list of something else isn't a transfer.
+        let other = Micheline::decode(&seq(&[int(1)])).unwrap();
+        assert!(token_transfers("transfer", &other).is_none());
+    }
+}
@@ -0,0 +1,478 @@
+// Decoding for forged Tezos operations: a 32-byte branch (the block the operation is
+// built on) followed by a list of operation contents, each starting with a one-byte tag.
+// Numbers are zarith varints (little-endian base-128), lengths are big-endian u32, and
+// parameters and scripts are Micheline in its binary form.
+
+use crate::TezosParserError;
+use crate::address::{self, PKH_LEN};
+use crate::micheline::Micheline;
+
+// Operation tags since protocol 005 (Babylon).
+const TAG_REVEAL: u8 = 107;
+const TAG_TRANSACTION: u8 = 108;
+const TAG_ORIGINATION: u8 = 109;
+const TAG_DELEGATION: u8 = 110;
+
+// Wallets sign the forged bytes behind this watermark byte and append a 64-byte signature.
+const WATERMARK: u8 = 3;
+const SIGNATURE_LEN: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForgedOperation {
+    pub branch: [u8; 32],
+    pub contents: Vec<Operation>,
+    /// Whether the bytes ended with a signature.
+    pub signed: bool,
+}
+
+/// The fields every manager operation carries, whatever it does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Operation {
+    pub source: String,
+    /// Fee in mutez
+    pub fee: u64,
+    pub counter: u128,
+    pub gas_limit: u128,
+    /// Bytes of new storage the operation may pay for
+    pub storage_limit: u128,
+    pub kind: OperationKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OperationKind {
+    Reveal {
+        public_key: String,
+    },
+    Transaction {
+        /// Amount in mutez
+        amount: u64,
+        destination: String,
+        parameters: Option<Parameters>,
+    },
+    Origination {
+        /// Initial balance in mutez
+        balance: u64,
+        delegate: Option<String>,
+        code: Micheline,
+        storage: Micheline,
+    },
+    /// Sets the source's delegate, or removes it when `delegate` is `None`.
+    Delegation {
+        delegate: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    pub entrypoint: String,
+    pub value: Micheline,
+}
+
+impl ForgedOperation {
+    /// Decodes forged operation bytes, with or without the signing watermark in front and
+    /// a signature behind. The plain form is tried first, and its error is returned when
+    /// no form decodes.
+    pub fn decode(bytes: &[u8]) -> Result<Self, TezosParserError> {
+        let plain = Self::decode_exact(bytes, false);
+        if plain.is_ok() {
+            return plain;
+        }
+        let unwatermarked = match bytes.first() {
+            Some(&WATERMARK) => &bytes[1..],
+            _ => bytes,
+        };
+        let candidates = [
+            (
+                bytes
+                    .len()
+                    .checked_sub(SIGNATURE_LEN)
+                    .map(|end| &bytes[..end]),
+                true,
+            ),
+            (Some(unwatermarked), false),
+            (
+                unwatermarked
+                    .len()
+                    .checked_sub(SIGNATURE_LEN)
+                    .map(|end| &unwatermarked[..end]),
+                true,
+            ),
+        ];
+        candidates
+            .into_iter()
+            .filter_map(|(body, signed)| Some((body?, signed)))
+            .find_map(|(body, signed)| Self::decode_exact(body, signed).ok())
+            .map_or(plain, Ok)
+    }
+
+    fn decode_exact(bytes: &[u8], signed: bool) -> Result<Self, TezosParserError> {
+        let mut reader = Reader::new(bytes);
+        let branch = reader.array()?;
+        let mut contents = Vec::new();
+        while !reader.is_empty() {
+            contents.push(read_operation(&mut reader)?);
+        }
+        if contents.is_empty() {
+            return Err(TezosParserError::FailedToDecodeTransaction(
+                "operation has no contents".to_string(),
+            ));
+        }
+        Ok(Self {
+            branch,
+            contents,
+            signed,
+        })
+    }
+}
+
+fn read_operation(reader: &mut Reader) -> Result<Operation, TezosParserError> {
+    let tag = reader.u8()?;
+    if !matches!(
+        tag,
+        TAG_REVEAL | TAG_TRANSACTION | TAG_ORIGINATION | TAG_DELEGATION
+    ) {
+        return Err(TezosParserError::UnsupportedOperation(tag));
+    }
+    let source = reader.public_key_hash()?;
+    let fee = reader.mutez()?;
+    let counter = reader.nat()?;
+    let gas_limit = reader.nat()?;
+    let storage_limit = reader.nat()?;
+
+    let kind = match tag {
+        TAG_REVEAL => {
+            let curve = reader.u8()?;
+            let (len, prefix) = address::public_key_layout(curve).ok_or_else(|| {
+                TezosParserError::FailedToDecodeTransaction(format!(
+                    "unknown public key curve {curve}"
+                ))
+            })?;
+            OperationKind::Reveal {
+                public_key: address::public_key(prefix, reader.take(len)?),
+            }
+        }
+        TAG_TRANSACTION => {
+            let amount = reader.mutez()?;
+            let destination = reader.contract()?;
+            let parameters = if reader.bool()? {
+                let entrypoint = reader.entrypoint()?;
+                let value = Micheline::decode(reader.sized()?)?;
+                Some(Parameters { entrypoint, value })
+            } else {
+                None
+            };
+            OperationKind::Transaction {
+                amount,
+                destination,
+                parameters,
+            }
+        }
+        TAG_ORIGINATION => {
+            let balance = reader.mutez()?;
+            let delegate = if reader.bool()? {
+                Some(reader.public_key_hash()?)
+            } else {
+                None
+            };
+            let code = Micheline::decode(reader.sized()?)?;
+            let storage = Micheline::decode(reader.sized()?)?;
+            OperationKind::Origination {
+                balance,
+                delegate,
+                code,
+                storage,
+            }
+        }
+        _ => OperationKind::Delegation {
+            delegate: if reader.bool()? {
+                Some(reader.public_key_hash()?)
+            } else {
+                None
+            },
+        },
+    };
+
+    Ok(Operation {
+        source,
+        fee,
+        counter,
+        gas_limit,
+        storage_limit,
+        kind,
+    })
+}
+
+pub(crate) struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    pub(crate) fn take(&mut self, n: usize) -> Result<&'a [u8], TezosParserError> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(TezosParserError::UnexpectedEnd)?;
+        let slice = self.bytes.get(self.pos..end).unwrap_or_default();
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], TezosParserError> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, TezosParserError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, TezosParserError> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    fn bool(&mut self) -> Result<bool, TezosParserError> {
+        match self.u8()? {
+            0x00 => Ok(false),
+            0xff => Ok(true),
+            other => Err(TezosParserError::FailedToDecodeTransaction(format!(
+                "invalid boolean byte {other:#04x}"
+            ))),
+        }
+    }
+
+    /// A u32 length followed by that many bytes.
+    pub(crate) fn sized(&mut self) -> Result<&'a [u8], TezosParserError> {
+        let len = self.u32()? as usize;
+        self.take(len)
+    }
+
+    /// A zarith natural number.
+    pub(crate) fn nat(&mut self) -> Result<u128, TezosParserError> {
+        let mut value: u128 = 0;
+        let mut shift = 0u32;
+        loop {
+            let byte = self.u8()?;
+            let bits = u128::from(byte & 0x7f);
+            if bits != 0 && (shift >= 128 || bits.leading_zeros() < shift) {
+                return Err(TezosParserError::NumberTooLarge);
+            }
+            if shift < 128 {
+                value |= bits << shift;
+            }
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift = shift.saturating_add(7);
+        }
+    }
+
+    /// A zarith integer: the first byte holds the sign in bit 6 and six bits of magnitude.
+    pub(crate) fn int(&mut self) -> Result<i128, TezosParserError> {
+        let first = self.u8()?;
+        let negative = first & 0x40 != 0;
+        let mut magnitude = u128::from(first & 0x3f);
+        if first & 0x80 != 0 {
+            // The rest is a natural number shifted past the first byte's six bits.
+            let rest = self.nat()?;
+            if rest.leading_zeros() < 6 {
+                return Err(TezosParserError::NumberTooLarge);
+            }
+            magnitude |= rest << 6;
+        }
+        let value = i128::try_from(magnitude).map_err(|_| TezosParserError::NumberTooLarge)?;
+        Ok(if negative { -value } else { value })
+    }
+
+    fn mutez(&mut self) -> Result<u64, TezosParserError> {
+        u64::try_from(self.nat()?).map_err(|_| TezosParserError::NumberTooLarge)
+    }
+
+    fn public_key_hash(&mut self) -> Result<String, TezosParserError> {
+        let curve = self.u8()?;
+        let hash: [u8; PKH_LEN] = self.array()?;
+        address::implicit(curve, &hash).ok_or_else(|| {
+            TezosParserError::FailedToDecodeTransaction(format!(
+                "unknown public key hash curve {curve}"
+            ))
+        })
+    }
+
+    /// A destination: an implicit account, or a contract hash and a padding byte.
+    fn contract(&mut self) -> Result<String, TezosParserError> {
+        let bytes = self.take(PKH_LEN + 2)?;
+        address::from_contract_bytes(bytes).ok_or_else(|| {
+            TezosParserError::FailedToDecodeTransaction(format!(
+                "invalid destination 0x{}",
+                hex::encode(bytes)
+            ))
+        })
+    }
+
+    /// Common entrypoints have one-byte tags; others are written out by name.
+    fn entrypoint(&mut self) -> Result<String, TezosParserError> {
+        let name = match self.u8()? {
+            0 => "default",
+            1 => "root",
+            2 => "do",
+            3 => "set_delegate",
+            4 => "remove_delegate",
+            5 => "deposit",
+            6 => "stake",
+            7 => "unstake",
+            8 => "finalize_unstake",
+            9 => "set_delegate_parameters",
+            255 => {
+                let len = usize::from(self.u8()?);
+                return String::from_utf8(self.take(len)?.to_vec()).map_err(|_| {
+                    TezosParserError::FailedToDecodeTransaction(
+                        "entrypoint name is not UTF-8".to_string(),
+                    )
+                });
+            }
+            other => {
+                return Err(TezosParserError::FailedToDecodeTransaction(format!(
+                    "unknown entrypoint tag {other}"
+                )));
+            }
+        };
+        Ok(name.to_string())
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod builder {
+    //! Forges operations, for constructing test inputs.
+
+    use super::*;
+
+    #[derive(Default)]
+    pub struct Writer(pub Vec<u8>);
+
+    impl Writer {
+        pub fn u8(mut self, v: u8) -> Self {
+            self.0.push(v);
+            self
+        }
+
+        pub fn raw(mut self, bytes: &[u8]) -> Self {
+            self.0.extend_from_slice(bytes);
+            self
+        }
+
+        pub fn nat(mut self, mut v: u128) -> Self {
+            loop {
+                let byte = (v & 0x7f) as u8;
+                v >>= 7;
+                if v == 0 {
+                    self.0.push(byte);
+                    return self;
+                }
+                self.0.push(byte | 0x80);
+            }
+        }
+
+        pub fn sized(mut self, bytes: &[u8]) -> Self {
+            self.0
+                .extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+            self.raw(bytes)
+        }
+
+        /// A branch of `[1; 32]`, ready for contents.
+        pub fn branch() -> Self {
+            Self::default().raw(&[1; 32])
+        }
+
+        /// An operation's tag and manager fields, with a tz1 source of `[source; 20]`.
+        pub fn manager(self, tag: u8, source: u8, fee: u128) -> Self {
+            self.u8(tag)
+                .u8(0)
+                .raw(&[source; PKH_LEN])
+                .nat(fee)
+                .nat(7)
+                .nat(10_000)
+                .nat(300)
+        }
+
+        pub fn implicit(self, hash: u8) -> Self {
+            self.u8(0).u8(0).raw(&[hash; PKH_LEN])
+        }
+
+        pub fn originated(self, hash: u8) -> Self {
+            self.u8(1).raw(&[hash; PKH_LEN]).u8(0)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::builder::Writer;
+    use super::*;
+
+    #[test]
+    fn zarith_numbers() {
+        let bytes = Writer::default()
+            .nat(300)
+            .nat(0)
+            .nat(u128::from(u64::MAX))
+            .0;
+        let mut reader = Reader::new(&bytes);
+        assert_eq!(reader.nat().unwrap(), 300);
+        assert_eq!(reader.nat().unwrap(), 0);
+        assert_eq!(reader.nat().unwrap(), u128::from(u64::MAX));
+        assert!(reader.is_empty());
+
+        // -100: sign bit, 36 in the first six bits, continuation; then 1 (<< 6 = 64).
+        let mut reader = Reader::new(&[0xe4, 0x01]);
+        assert_eq!(reader.int().unwrap(), -100);
+
+        let mut reader = Reader::new(&[0xff; 20]);
+        assert_eq!(reader.nat(), Err(TezosParserError::NumberTooLarge));
+    }
+
+    #[test]
+    fn watermark_and_signature_are_optional() {
+        let forged = Writer::branch()
+            .manager(TAG_DELEGATION, 2, 1_000)
+            .u8(0xff)
+            .u8(0)
+            .raw(&[3; PKH_LEN])
+            .0;
+        let mut signed = vec![WATERMARK];
+        signed.extend_from_slice(&forged);
+        signed.extend_from_slice(&[9; SIGNATURE_LEN]);
+
+        let plain = ForgedOperation::decode(&forged).unwrap();
+        assert!(!plain.signed);
+        let from_signed = ForgedOperation::decode(&signed).unwrap();
+        assert!(from_signed.signed);
+        assert_eq!(plain.contents, from_signed.contents);
+        assert_eq!(
+            plain.contents[0].kind,
+            OperationKind::Delegation {
+                delegate: address::implicit(0, &[3; PKH_LEN])
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_operations() {
+        let endorsement = Writer::branch().u8(21).raw(&[0; 40]).0;
+        assert_eq!(
+            ForgedOperation::decode(&endorsement),
+            Err(TezosParserError::UnsupportedOperation(21))
+        );
+        assert!(ForgedOperation::decode(&Writer::branch().0).is_err());
+    }
+}
@@ -0,0 +1,175 @@
+// TON addresses: the `MsgAddress` TL-B forms found in messages, and the user-friendly
+// base64 form wallets display.
+
+use base64::{Engine as _, engine::general_purpose::STANDARD, engine::general_purpose::URL_SAFE};
+
+use crate::TonParserError;
+use crate::cell::CellParser;
+
+const TAG_BOUNCEABLE: u8 = 0x11;
+const TAG_NON_BOUNCEABLE: u8 = 0x51;
+const TAG_TESTNET: u8 = 0x80;
+
+/// A standard internal address: a workchain and a 256-bit account ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TonAddress {
+    pub workchain: i8,
+    pub hash: [u8; 32],
+}
+
+impl TonAddress {
+    /// The user-friendly form: url-safe base64 of a tag byte, the workchain, the account ID
+    /// and a CRC16. Bounceable addresses start with `EQ`, non-bounceable ones with `UQ`.
+    pub fn to_friendly(&self, bounceable: bool) -> String {
+        let mut bytes = Vec::with_capacity(36);
+        bytes.push(if bounceable {
+            TAG_BOUNCEABLE
+        } else {
+            TAG_NON_BOUNCEABLE
+        });
+        bytes.push(self.workchain as u8);
+        bytes.extend_from_slice(&self.hash);
+        let crc = crc16_xmodem(&bytes);
+        bytes.extend_from_slice(&crc.to_be_bytes());
+        URL_SAFE.encode(bytes)
+    }
+
+    pub fn to_raw(&self) -> String {
+        format!("{}:{}", self.workchain, hex::encode(self.hash))
+    }
+
+    /// Parses the raw (`0:<hex>`) or user-friendly form, in either base64 alphabet.
+    pub fn parse(s: &str) -> Option<Self> {
+        if let Some((workchain, hash)) = s.split_once(':') {
+            let hash = hex::decode(hash).ok()?.try_into().ok()?;
+            return Some(Self {
+                workchain: workchain.parse().ok()?,
+                hash,
+            });
+        }
+        let bytes = URL_SAFE.decode(s).or_else(|_| STANDARD.decode(s)).ok()?;
+        let bytes: [u8; 36] = bytes.try_into().ok()?;
+        let (body, crc) = bytes.split_at(34);
+        if crc16_xmodem(body).to_be_bytes() != crc {
+            return None;
+        }
+        if !matches!(
+            body.first()? & !TAG_TESTNET,
+            TAG_BOUNCEABLE | TAG_NON_BOUNCEABLE
+        ) {
+            return None;
+        }
+        Some(Self {
+            workchain: *body.get(1)? as i8,
+            hash: body.get(2..34)?.try_into().ok()?,
+        })
+    }
+}
+
+/// `MsgAddress`, as it appears in message headers and payloads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MsgAddress {
+    None,
+    External,
+    Internal(TonAddress),
+}
+
+impl MsgAddress {
+    pub fn load(parser: &mut CellParser<'_>) -> Result<Self, TonParserError> {
+        match parser.load_uint(2)? {
+            0b00 => Ok(Self::None),
+            0b01 => {
+                let len = parser.load_uint(9)? as usize;
+                for _ in 0..len {
+                    parser.load_bit()?;
+                }
+                Ok(Self::External)
+            }
+            0b10 => {
+                // Anycast addresses were never enabled on mainnet.
+                if parser.load_bit()? {
+                    return Err(TonParserError::UnsupportedMessage(
+                        "anycast address".to_string(),
+                    ));
+                }
+                let workchain = parser.load_u8()? as i8;
+                let hash = parser.load_bytes(32)?;
+                Ok(Self::Internal(TonAddress {
+                    workchain,
+                    hash: hash.try_into().unwrap_or([0; 32]),
+                }))
+            }
+            _ => Err(TonParserError::UnsupportedMessage(
+                "variable-length address".to_string(),
+            )),
+        }
+    }
+
+    /// Loads an address that must be a standard internal one (`MsgAddressInt`).
+    pub fn load_internal(parser: &mut CellParser<'_>) -> Result<TonAddress, TonParserError> {
+        match Self::load(parser)? {
+            Self::Internal(address) => Ok(address),
+            _ => Err(TonParserError::UnsupportedMessage(
+                "expected an internal address".to_string(),
+            )),
+        }
+    }
+}
+
+fn crc16_xmodem(bytes: &[u8]) -> u16 {
+    let mut crc = 0u16;
+    for byte in bytes {
+        crc ^= u16::from(*byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn friendly_and_raw_forms_round_trip() {
+        let address = TonAddress {
+            workchain: 0,
+            hash: [0x11; 32],
+        };
+        let bounceable = address.to_friendly(true);
+        let non_bounceable = address.to_friendly(false);
+        assert!(bounceable.starts_with("EQ"));
+        assert!(non_bounceable.starts_with("UQ"));
+        assert_eq!(TonAddress::parse(&bounceable), Some(address));
+        assert_eq!(TonAddress::parse(&non_bounceable), Some(address));
+        assert_eq!(TonAddress::parse(&address.to_raw()), Some(address));
+        assert_eq!(TonAddress::parse("0:1234"), None);
+    }
+
+    #[test]
+    fn rejects_bad_checksum() {
+        let mut friendly = TonAddress {
+            workchain: -1,
+            hash: [0x22; 32],
+        }
+        .to_friendly(true);
+        let replacement = if friendly.as_bytes()[10] == b'A' {
+            "B"
+        } else {
+            "A"
+        };
+        friendly.replace_range(10..11, replacement);
+        assert_eq!(TonAddress::parse(&friendly), None);
+    }
+
+    #[test]
+    fn crc16_check_value() {
+        assert_eq!(crc16_xmodem(b"123456789"), 0x31c3);
+    }
+}
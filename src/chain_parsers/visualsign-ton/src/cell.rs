@@ -0,0 +1,489 @@
+// Bag-of-cells (BoC) decoding and a bit reader over the decoded cells. TON serializes every
+// message as a tree of cells of up to 1023 bits and four references each; a BoC is that
+// tree flattened into a list in which every cell only references cells after it.
+
+use std::sync::Arc;
+
+use crate::TonParserError;
+
+const BOC_MAGIC: [u8; 4] = [0xb5, 0xee, 0x9c, 0x72];
+const MAX_CELL_REFS: usize = 4;
+const MAX_CELL_BITS: usize = 1023;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    data: Vec<u8>,
+    bit_len: usize,
+    refs: Vec<Arc<Cell>>,
+}
+
+impl Cell {
+    pub fn parser(&self) -> CellParser<'_> {
+        CellParser {
+            cell: self,
+            bit: 0,
+            next_ref: 0,
+        }
+    }
+}
+
+fn invalid(message: impl Into<String>) -> TonParserError {
+    TonParserError::InvalidCell(message.into())
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], TonParserError> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or_else(|| invalid("bag of cells is truncated"))?;
+        let slice = self.bytes.get(self.pos..end).unwrap_or_default();
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, TonParserError> {
+        Ok(self.take(1)?.first().copied().unwrap_or_default())
+    }
+
+    // Big-endian unsigned integer of `size` bytes (at most 8).
+    fn uint(&mut self, size: usize) -> Result<usize, TonParserError> {
+        let value = self
+            .take(size)?
+            .iter()
+            .fold(0u64, |acc, b| (acc << 8) | u64::from(*b));
+        usize::try_from(value).map_err(|_| invalid("bag of cells size does not fit in memory"))
+    }
+}
+
+// A cell as stored in the BoC, before its references are resolved.
+struct RawCell {
+    data: Vec<u8>,
+    bit_len: usize,
+    refs: Vec<usize>,
+}
+
+/// Decodes a serialized bag of cells with exactly one root and returns that root.
+pub fn deserialize_boc(bytes: &[u8]) -> Result<Arc<Cell>, TonParserError> {
+    let mut reader = ByteReader { bytes, pos: 0 };
+    if reader.take(4)? != BOC_MAGIC {
+        return Err(invalid("not a bag of cells (bad magic)"));
+    }
+    let flags = reader.u8()?;
+    let has_index = flags & 0x80 != 0;
+    let has_crc32c = flags & 0x40 != 0;
+    let ref_size = usize::from(flags & 0x07);
+    if !(1..=4).contains(&ref_size) {
+        return Err(invalid(format!("invalid reference size {ref_size}")));
+    }
+    let offset_size = usize::from(reader.u8()?);
+    if !(1..=8).contains(&offset_size) {
+        return Err(invalid(format!("invalid offset size {offset_size}")));
+    }
+    let cell_count = reader.uint(ref_size)?;
+    let root_count = reader.uint(ref_size)?;
+    let _absent_count = reader.uint(ref_size)?;
+    let cells_size = reader.uint(offset_size)?;
+    if root_count != 1 {
+        return Err(invalid(format!(
+            "expected a single root cell, found {root_count}"
+        )));
+    }
+    let root_index = reader.uint(ref_size)?;
+    if has_index {
+        let index_size = cell_count
+            .checked_mul(offset_size)
+            .ok_or_else(|| invalid("cell index size overflows"))?;
+        reader.take(index_size)?;
+    }
+
+    let mut cells_reader = ByteReader {
+        bytes: reader.take(cells_size)?,
+        pos: 0,
+    };
+    if has_crc32c {
+        let checked_len = reader.pos;
+        let stored = reader.take(4)?;
+        let expected = crc32c(bytes.get(..checked_len).unwrap_or_default()).to_le_bytes();
+        if stored != expected {
+            return Err(invalid("bag of cells checksum mismatch"));
+        }
+    }
+    if reader.pos != bytes.len() {
+        return Err(invalid("trailing bytes after bag of cells"));
+    }
+
+    let mut raw_cells = Vec::new();
+    for index in 0..cell_count {
+        raw_cells.push(read_raw_cell(
+            &mut cells_reader,
+            index,
+            cell_count,
+            ref_size,
+        )?);
+    }
+    if cells_reader.pos != cells_reader.bytes.len() {
+        return Err(invalid("cell data is longer than its cells"));
+    }
+
+    // References always point forward, so building from the last cell back resolves every
+    // reference before it is needed and rules out cycles.
+    let mut cells: Vec<Option<Arc<Cell>>> = vec![None; cell_count];
+    for (index, raw) in raw_cells.into_iter().enumerate().rev() {
+        let refs = raw
+            .refs
+            .iter()
+            .map(|r| cells.get(*r).cloned().flatten())
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| invalid(format!("cell {index} references an unknown cell")))?;
+        if let Some(slot) = cells.get_mut(index) {
+            *slot = Some(Arc::new(Cell {
+                data: raw.data,
+                bit_len: raw.bit_len,
+                refs,
+            }));
+        }
+    }
+    cells
+        .get(root_index)
+        .cloned()
+        .flatten()
+        .ok_or_else(|| invalid(format!("root index {root_index} is out of range")))
+}
+
+fn read_raw_cell(
+    reader: &mut ByteReader<'_>,
+    index: usize,
+    cell_count: usize,
+    ref_size: usize,
+) -> Result<RawCell, TonParserError> {
+    let d1 = reader.u8()?;
+    let d2 = reader.u8()?;
+    let ref_count = usize::from(d1 & 0x07);
+    let is_exotic = d1 & 0x08 != 0;
+    let with_hashes = d1 & 0x10 != 0;
+    let level_mask = d1 >> 5;
+    if ref_count > MAX_CELL_REFS {
+        return Err(invalid(format!("cell {index} has {ref_count} references")));
+    }
+    // Pruned branches and Merkle proofs only appear in proofs, never in a message to sign.
+    if is_exotic {
+        return Err(invalid(format!("cell {index} is an exotic cell")));
+    }
+    if with_hashes {
+        let hash_count = level_mask.count_ones() as usize + 1;
+        reader.take(hash_count * (32 + 2))?;
+    }
+
+    let data_len = usize::from(d2).div_ceil(2);
+    let data = reader.take(data_len)?.to_vec();
+    let bit_len = if d2 % 2 == 0 {
+        data_len * 8
+    } else {
+        // An incomplete last byte is padded with a single 1 bit followed by zeros.
+        let last = data.last().copied().unwrap_or_default();
+        if last == 0 {
+            return Err(invalid(format!("cell {index} has no completion tag")));
+        }
+        data_len * 8 - last.trailing_zeros() as usize - 1
+    };
+    if bit_len > MAX_CELL_BITS {
+        return Err(invalid(format!("cell {index} holds {bit_len} bits")));
+    }
+
+    let mut refs = Vec::with_capacity(ref_count);
+    for _ in 0..ref_count {
+        let target = reader.uint(ref_size)?;
+        if target <= index || target >= cell_count {
+            return Err(invalid(format!(
+                "cell {index} references cell {target} out of order"
+            )));
+        }
+        refs.push(target);
+    }
+    Ok(RawCell {
+        data,
+        bit_len,
+        refs,
+    })
+}
+
+// CRC-32C (Castagnoli), reflected, as used by the BoC checksum.
+fn crc32c(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0x82f6_3b78
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Reads a cell's bits and references front to back.
+#[derive(Debug, Clone)]
+pub struct CellParser<'a> {
+    cell: &'a Cell,
+    bit: usize,
+    next_ref: usize,
+}
+
+impl<'a> CellParser<'a> {
+    pub fn remaining_bits(&self) -> usize {
+        self.cell.bit_len - self.bit
+    }
+
+    pub fn remaining_refs(&self) -> usize {
+        self.cell.refs.len() - self.next_ref
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining_bits() == 0 && self.remaining_refs() == 0
+    }
+
+    pub fn load_bit(&mut self) -> Result<bool, TonParserError> {
+        if self.remaining_bits() == 0 {
+            return Err(TonParserError::CellUnderflow);
+        }
+        let byte = self
+            .cell
+            .data
+            .get(self.bit / 8)
+            .copied()
+            .unwrap_or_default();
+        let bit = byte & (0x80 >> (self.bit % 8)) != 0;
+        self.bit += 1;
+        Ok(bit)
+    }
+
+    /// Loads an unsigned big-endian integer of up to 128 bits.
+    pub fn load_u128(&mut self, bits: usize) -> Result<u128, TonParserError> {
+        if bits > 128 {
+            return Err(invalid(format!("cannot load a {bits}-bit integer")));
+        }
+        if bits > self.remaining_bits() {
+            return Err(TonParserError::CellUnderflow);
+        }
+        let mut value = 0u128;
+        for _ in 0..bits {
+            value = (value << 1) | u128::from(self.load_bit()?);
+        }
+        Ok(value)
+    }
+
+    /// Loads an unsigned big-endian integer of up to 64 bits.
+    pub fn load_uint(&mut self, bits: usize) -> Result<u64, TonParserError> {
+        if bits > 64 {
+            return Err(invalid(format!("cannot load a {bits}-bit integer")));
+        }
+        Ok(self.load_u128(bits)? as u64)
+    }
+
+    pub fn load_u8(&mut self) -> Result<u8, TonParserError> {
+        Ok(self.load_uint(8)? as u8)
+    }
+
+    pub fn load_u32(&mut self) -> Result<u32, TonParserError> {
+        Ok(self.load_uint(32)? as u32)
+    }
+
+    pub fn load_bytes(&mut self, len: usize) -> Result<Vec<u8>, TonParserError> {
+        if len.saturating_mul(8) > self.remaining_bits() {
+            return Err(TonParserError::CellUnderflow);
+        }
+        (0..len).map(|_| self.load_u8()).collect()
+    }
+
+    /// Loads `Grams`/`Coins`: a 4-bit byte length followed by that many bytes.
+    pub fn load_coins(&mut self) -> Result<u128, TonParserError> {
+        let len = self.load_uint(4)? as usize;
+        self.load_u128(len * 8)
+    }
+
+    pub fn load_ref(&mut self) -> Result<&'a Cell, TonParserError> {
+        let cell = self
+            .cell
+            .refs
+            .get(self.next_ref)
+            .ok_or(TonParserError::CellUnderflow)?;
+        self.next_ref += 1;
+        Ok(cell)
+    }
+
+    /// Reads the first 32 bits without consuming them.
+    pub fn peek_u32(&self) -> Option<u32> {
+        self.clone().load_u32().ok()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod builder {
+    //! Builds cells and serializes them to a BoC, for constructing test messages.
+
+    use super::*;
+
+    #[derive(Default)]
+    pub struct CellBuilder {
+        bits: Vec<bool>,
+        refs: Vec<Arc<Cell>>,
+    }
+
+    impl CellBuilder {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn bit(mut self, bit: bool) -> Self {
+            self.bits.push(bit);
+            self
+        }
+
+        pub fn uint(mut self, value: u128, bits: usize) -> Self {
+            for i in (0..bits).rev() {
+                self.bits.push(i < 128 && (value >> i) & 1 == 1);
+            }
+            self
+        }
+
+        pub fn bytes(self, bytes: &[u8]) -> Self {
+            bytes
+                .iter()
+                .fold(self, |b, byte| b.uint(u128::from(*byte), 8))
+        }
+
+        pub fn coins(self, value: u128) -> Self {
+            let len = (128 - value.leading_zeros() as usize).div_ceil(8);
+            self.uint(len as u128, 4).uint(value, len * 8)
+        }
+
+        /// `addr_std` without anycast.
+        pub fn address(self, workchain: i8, hash: [u8; 32]) -> Self {
+            self.uint(0b100, 3)
+                .uint(u128::from(workchain as u8), 8)
+                .bytes(&hash)
+        }
+
+        pub fn reference(mut self, cell: Cell) -> Self {
+            self.refs.push(Arc::new(cell));
+            self
+        }
+
+        pub fn build(self) -> Cell {
+            assert!(self.bits.len() <= MAX_CELL_BITS && self.refs.len() <= MAX_CELL_REFS);
+            let mut data = vec![0u8; self.bits.len().div_ceil(8)];
+            for (i, bit) in self.bits.iter().enumerate() {
+                if *bit {
+                    data[i / 8] |= 0x80 >> (i % 8);
+                }
+            }
+            if self.bits.len() % 8 != 0 {
+                data[self.bits.len() / 8] |= 0x80 >> (self.bits.len() % 8);
+            }
+            Cell {
+                data,
+                bit_len: self.bits.len(),
+                refs: self.refs,
+            }
+        }
+    }
+
+    /// Serializes `root` with one-byte references, two-byte offsets and a CRC32C.
+    pub fn serialize_boc(root: &Cell) -> Vec<u8> {
+        let mut order: Vec<&Cell> = Vec::new();
+        collect(root, &mut order);
+
+        // Cells are laid out in pre-order, so a cell's children follow it, each one after
+        // the whole subtree of the child before it.
+        let mut cells = Vec::new();
+        for (index, cell) in order.iter().enumerate() {
+            let d2 = (cell.bit_len / 8) * 2 + usize::from(cell.bit_len % 8 != 0);
+            cells.push(cell.refs.len() as u8);
+            cells.push(d2 as u8);
+            cells.extend_from_slice(&cell.data);
+            let mut child = index + 1;
+            for r in &cell.refs {
+                cells.push(child as u8);
+                child += subtree_size(r);
+            }
+        }
+
+        let mut boc = BOC_MAGIC.to_vec();
+        boc.push(0x40 | 1);
+        boc.push(2);
+        boc.extend_from_slice(&[order.len() as u8, 1, 0]);
+        boc.extend_from_slice(&(cells.len() as u16).to_be_bytes());
+        boc.push(0);
+        boc.extend_from_slice(&cells);
+        let crc = crc32c(&boc);
+        boc.extend_from_slice(&crc.to_le_bytes());
+        boc
+    }
+
+    fn collect<'a>(cell: &'a Cell, order: &mut Vec<&'a Cell>) {
+        order.push(cell);
+        for r in &cell.refs {
+            collect(r, order);
+        }
+    }
+
+    fn subtree_size(cell: &Cell) -> usize {
+        1 + cell.refs.iter().map(|r| subtree_size(r)).sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::builder::*;
+    use super::*;
+
+    #[test]
+    fn boc_round_trip_preserves_bits_and_refs() {
+        let leaf = CellBuilder::new().uint(0b101, 3).build();
+        let root = CellBuilder::new()
+            .uint(0xdead_beef, 32)
+            .coins(1_500_000_000)
+            .reference(leaf.clone())
+            .build();
+        let decoded = deserialize_boc(&serialize_boc(&root)).unwrap();
+        assert_eq!(*decoded, root);
+
+        let mut parser = decoded.parser();
+        assert_eq!(parser.load_u32().unwrap(), 0xdead_beef);
+        assert_eq!(parser.load_coins().unwrap(), 1_500_000_000);
+        assert_eq!(parser.remaining_bits(), 0);
+        assert_eq!(parser.load_ref().unwrap().parser().remaining_bits(), 3);
+        assert!(matches!(
+            parser.load_bit(),
+            Err(TonParserError::CellUnderflow)
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_checksum_and_trailing_bytes() {
+        let root = CellBuilder::new().uint(7, 8).build();
+        let mut boc = serialize_boc(&root);
+        let last = boc.len() - 1;
+        boc[last] ^= 1;
+        assert!(deserialize_boc(&boc).is_err());
+
+        let mut boc = serialize_boc(&root);
+        boc.push(0);
+        assert!(deserialize_boc(&boc).is_err());
+    }
+
+    #[test]
+    fn crc32c_check_value() {
+        assert_eq!(crc32c(b"123456789"), 0xe306_9283);
+    }
+}
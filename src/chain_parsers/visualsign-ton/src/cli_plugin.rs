@@ -0,0 +1,40 @@
+use clap::Args as ClapArgs;
+use generated::parser::ChainMetadata;
+use visualsign::registry::{Chain, TransactionConverterRegistry};
+
+/// CLI arguments specific to TON.
+///
+/// No TON-specific args are needed yet; the global `--network` flag is accepted but
+/// not used. Jetton symbols come from `TonMetadata`, which the CLI does not build.
+#[derive(ClapArgs, Debug, Default, Clone)]
+pub struct TonArgs {}
+
+/// [`parser_cli_core::ChainPlugin`] implementation for TON.
+pub struct TonPlugin {
+    // Empty today; kept so a TON flag can be added without changing the struct shape.
+    #[allow(dead_code)]
+    args: TonArgs,
+}
+
+impl TonPlugin {
+    /// Creates a new `TonPlugin` with the given CLI args.
+    #[must_use]
+    pub fn new(args: TonArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl parser_cli_core::ChainPlugin for TonPlugin {
+    fn chain(&self) -> Chain {
+        Chain::Ton
+    }
+
+    fn register(&self, registry: &mut TransactionConverterRegistry) {
+        registry
+            .register::<crate::TonTransactionWrapper, _>(Chain::Ton, crate::TonVisualSignConverter);
+    }
+
+    fn create_metadata(&self, _network: Option<String>) -> Result<Option<ChainMetadata>, String> {
+        Ok(None)
+    }
+}
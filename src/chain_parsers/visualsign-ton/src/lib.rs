@@ -0,0 +1,842 @@
+#[cfg(feature = "cli-plugin")]
+pub mod cli_plugin;
+
+#[cfg(feature = "cli-plugin")]
+pub use cli_plugin::{TonArgs, TonPlugin};
+
+mod address;
+mod cell;
+mod message;
+
+pub use address::{MsgAddress, TonAddress};
+pub use message::{
+    Body, JettonTransfer, OtherAction, OutMessage, TonTransaction, WalletRequest, WalletVersion,
+};
+
+use std::collections::BTreeMap;
+
+use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
+use generated::parser::{TonMetadata, chain_metadata};
+use visualsign::field_builders::{
+    create_address_field, create_amount_field, create_preview_layout, create_text_field,
+};
+use visualsign::time_fmt::format_timestamp_ms;
+use visualsign::{
+    AnnotatedPayloadField, SignablePayload,
+    encodings::SupportedEncodings,
+    vsptrait::{
+        ConversionResult, Transaction, TransactionParseError, VisualSignConverter,
+        VisualSignConverterFromString, VisualSignError, VisualSignOptions,
+    },
+};
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum TonParserError {
+    #[error("Failed to decode transaction: {0}")]
+    FailedToDecodeTransaction(String),
+    #[error("Invalid bag of cells: {0}")]
+    InvalidCell(String),
+    #[error("Cell underflow: read past the end of a cell")]
+    CellUnderflow,
+    #[error("Unsupported message: {0}")]
+    UnsupportedMessage(String),
+}
+
+const TON_DECIMALS: u32 = 9;
+// Jetton metadata stores decimals as a uint8.
+const MAX_JETTON_DECIMALS: u32 = 255;
+
+// Send mode flags (the mode byte next to each message).
+const MODE_PAY_FEES_SEPARATELY: u8 = 1;
+const MODE_IGNORE_ERRORS: u8 = 2;
+const MODE_BOUNCE_ON_ACTION_FAIL: u8 = 16;
+const MODE_DESTROY_IF_ZERO: u8 = 32;
+const MODE_CARRY_INBOUND_VALUE: u8 = 64;
+const MODE_CARRY_ALL_BALANCE: u8 = 128;
+
+fn decode_transaction(
+    raw_transaction: &str,
+    encodings: SupportedEncodings,
+) -> Result<TonTransaction, TonParserError> {
+    let bytes = match encodings {
+        SupportedEncodings::Hex => {
+            visualsign::encodings::decode_hex(raw_transaction).map_err(|e| {
+                TonParserError::FailedToDecodeTransaction(format!("Failed to decode hex: {e}"))
+            })?
+        }
+        SupportedEncodings::Base64 => b64.decode(raw_transaction).map_err(|e| {
+            TonParserError::FailedToDecodeTransaction(format!("Failed to decode base64: {e}"))
+        })?,
+    };
+
+    let root = cell::deserialize_boc(&bytes)?;
+    TonTransaction::parse(&root)
+}
+
+/// Wrapper for signed TON wallet messages
+#[derive(Debug, Clone)]
+pub struct TonTransactionWrapper {
+    transaction: TonTransaction,
+}
+
+impl Transaction for TonTransactionWrapper {
+    fn from_string(data: &str) -> Result<Self, TransactionParseError> {
+        // Wallets hand out the external message as a base64 BoC; hex is accepted as well.
+        let format = SupportedEncodings::detect(data);
+        let transaction = decode_transaction(data, format)
+            .map_err(|e| TransactionParseError::DecodeError(e.to_string()))?;
+        Ok(Self { transaction })
+    }
+
+    fn transaction_type(&self) -> String {
+        "TON".to_string()
+    }
+}
+
+impl TonTransactionWrapper {
+    pub fn new(transaction: TonTransaction) -> Self {
+        Self { transaction }
+    }
+
+    pub fn inner(&self) -> &TonTransaction {
+        &self.transaction
+    }
+}
+
+/// Converter for TON transactions
+pub struct TonVisualSignConverter;
+
+impl VisualSignConverter<TonTransactionWrapper> for TonVisualSignConverter {
+    fn to_visual_sign_payload(
+        &self,
+        transaction_wrapper: TonTransactionWrapper,
+        options: VisualSignOptions,
+    ) -> Result<ConversionResult, VisualSignError> {
+        let payload = convert_to_visual_sign_payload(transaction_wrapper.inner(), options)?;
+        Ok(ConversionResult::new(payload))
+    }
+}
+
+impl VisualSignConverterFromString<TonTransactionWrapper> for TonVisualSignConverter {}
+
+// Public API functions
+pub fn transaction_to_visual_sign(
+    transaction: TonTransaction,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let wrapper = TonTransactionWrapper::new(transaction);
+    let converter = TonVisualSignConverter;
+    converter
+        .to_visual_sign_payload(wrapper, options)
+        .map(|r| r.payload)
+}
+
+pub fn transaction_string_to_visual_sign(
+    transaction_data: &str,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let converter = TonVisualSignConverter;
+    converter
+        .to_visual_sign_payload_from_string(transaction_data, options)
+        .map(|r| r.payload)
+}
+
+struct JettonInfo {
+    symbol: String,
+    decimals: u32,
+}
+
+// Jetton wallets are per-owner contracts, so there is no built-in list to fall back on;
+// only the caller can say which jetton a given wallet holds. Keys that are not valid
+// addresses and out-of-range decimals are dropped.
+fn jetton_registry(metadata: Option<&TonMetadata>) -> BTreeMap<TonAddress, JettonInfo> {
+    metadata
+        .into_iter()
+        .flat_map(|metadata| &metadata.jettons)
+        .filter(|(_, jetton)| jetton.decimals <= MAX_JETTON_DECIMALS)
+        .filter_map(|(address, jetton)| {
+            Some((
+                TonAddress::parse(address)?,
+                JettonInfo {
+                    symbol: jetton.symbol.clone(),
+                    decimals: jetton.decimals,
+                },
+            ))
+        })
+        .collect()
+}
+
+fn convert_to_visual_sign_payload(
+    transaction: &TonTransaction,
+    options: VisualSignOptions,
+) -> Result<SignablePayload, VisualSignError> {
+    let metadata = match options
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.metadata.as_ref())
+    {
+        Some(chain_metadata::Metadata::Ton(ton_metadata)) => Some(ton_metadata),
+        _ => None,
+    };
+    let jettons = jetton_registry(metadata);
+    let request = &transaction.request;
+
+    let mut fields: Vec<AnnotatedPayloadField> = vec![
+        create_text_field("Network", "TON")?,
+        create_address_field(
+            "Wallet",
+            &transaction.wallet.to_friendly(false),
+            None,
+            None,
+            None,
+            None,
+        )?,
+        create_text_field("Wallet Version", request.version.label())?,
+        create_text_field("Seqno", &request.seqno.to_string())?,
+        create_text_field(
+            "Valid Until",
+            &format_timestamp_ms(i64::from(request.valid_until) * 1000),
+        )?,
+    ];
+    if transaction.deploys_wallet {
+        fields.push(create_text_field(
+            "Wallet Deployment",
+            "This message also deploys the wallet contract",
+        )?);
+    }
+
+    for warning in warnings(request) {
+        fields.push(create_text_field("Warning", &warning)?);
+    }
+    if let Some(OtherAction::RemovePlugin(plugin)) = &request.other_action {
+        fields.push(create_text_field(
+            "Wallet Action",
+            &format!("Remove plugin {}", plugin.to_friendly(true)),
+        )?);
+    }
+
+    if request.messages.len() > 1 {
+        let total: u128 = request.messages.iter().map(|m| m.value).sum();
+        fields.push(create_amount_field(
+            "Total Value",
+            &format_units(total, TON_DECIMALS),
+            "TON",
+        )?);
+    }
+    for (i, message) in request.messages.iter().enumerate() {
+        fields.push(message_preview(i + 1, message, &jettons)?);
+    }
+
+    let title = options
+        .transaction_name
+        .unwrap_or_else(|| "TON Transaction".to_string());
+
+    Ok(SignablePayload::new(
+        0,
+        title,
+        None,
+        fields
+            .into_iter()
+            .map(|af| af.signable_payload_field)
+            .collect(),
+        "TonTx".to_string(),
+    ))
+}
+
+// Send modes that move more than the amount shown, and actions that let another contract
+// take funds, are raised ahead of the messages.
+fn warnings(request: &WalletRequest) -> Vec<String> {
+    let mut warnings = Vec::new();
+    for (i, message) in request.messages.iter().enumerate() {
+        let n = i + 1;
+        if message.mode & MODE_CARRY_ALL_BALANCE != 0 {
+            warnings.push(format!(
+                "Message {n} sends the wallet's entire remaining balance, not just the amount \
+                 shown"
+            ));
+        }
+        if message.mode & MODE_DESTROY_IF_ZERO != 0 {
+            warnings.push(format!(
+                "Message {n} destroys the wallet if its balance reaches zero"
+            ));
+        }
+    }
+    match &request.other_action {
+        Some(OtherAction::DeployAndInstallPlugin) => warnings.push(
+            "Deploys and installs a plugin that can request funds from this wallet".to_string(),
+        ),
+        Some(OtherAction::InstallPlugin(plugin)) => warnings.push(format!(
+            "Installs plugin {}, which can request funds from this wallet",
+            plugin.to_friendly(true)
+        )),
+        Some(OtherAction::ExtendedActions) => warnings.push(
+            "Includes extended wallet actions, which can add or remove extensions or disable \
+             signing with the wallet key"
+                .to_string(),
+        ),
+        Some(OtherAction::RemovePlugin(_)) | None => {}
+    }
+    warnings
+}
+
+fn message_preview(
+    n: usize,
+    message: &OutMessage,
+    jettons: &BTreeMap<TonAddress, JettonInfo>,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    // Wallets show bounceable (EQ…) or non-bounceable (UQ…) addresses to match the flag the
+    // message is actually sent with.
+    let to = message.destination.to_friendly(message.bounce);
+    let amount = format_units(message.value, TON_DECIMALS);
+    let mut fields = Vec::new();
+
+    let subtitle = match &message.body {
+        Body::JettonTransfer(transfer) => {
+            let jetton = jettons.get(&message.destination);
+            let recipient = msg_address_label(&transfer.destination);
+            fields.push(create_address_field(
+                "Jetton Wallet",
+                &to,
+                jetton
+                    .map(|j| format!("{} jetton wallet", j.symbol))
+                    .as_deref(),
+                None,
+                None,
+                None,
+            )?);
+            let token_amount = match jetton {
+                Some(jetton) => {
+                    let value = format_units(transfer.amount, jetton.decimals);
+                    fields.push(create_amount_field("Token Amount", &value, &jetton.symbol)?);
+                    format!("{value} {}", jetton.symbol)
+                }
+                None => {
+                    fields.push(create_text_field(
+                        "Token Amount",
+                        &format!("{} (raw units, unknown jetton)", transfer.amount),
+                    )?);
+                    format!("{} raw units of an unknown jetton", transfer.amount)
+                }
+            };
+            fields.push(create_address_field(
+                "Recipient",
+                &recipient,
+                None,
+                None,
+                None,
+                None,
+            )?);
+            fields.push(create_address_field(
+                "Response Destination",
+                &msg_address_label(&transfer.response_destination),
+                None,
+                None,
+                None,
+                None,
+            )?);
+            fields.push(create_amount_field(
+                "Forward TON Amount",
+                &format_units(transfer.forward_ton_amount, TON_DECIMALS),
+                "TON",
+            )?);
+            match &transfer.forward_payload {
+                Body::Empty => {}
+                Body::Comment(comment) => {
+                    fields.push(create_text_field("Forward Comment", comment)?);
+                }
+                other => fields.push(create_text_field("Forward Payload", &payload_label(other))?),
+            }
+            if transfer.custom_payload {
+                fields.push(create_text_field("Custom Payload", "Included")?);
+            }
+            fields.push(create_amount_field("Attached TON", &amount, "TON")?);
+            format!("Send {token_amount} to {recipient}")
+        }
+        body => {
+            fields.push(create_address_field("To", &to, None, None, None, None)?);
+            fields.push(create_amount_field("Amount", &amount, "TON")?);
+            let send = format!("Send {amount} TON to {to}");
+            match body {
+                Body::Empty => send,
+                Body::Comment(comment) => {
+                    fields.push(create_text_field("Comment", comment)?);
+                    format!("{send} with comment \"{comment}\"")
+                }
+                other => {
+                    let label = payload_label(other);
+                    fields.push(create_text_field("Payload", &label)?);
+                    format!("{send} with {}", label.to_lowercase())
+                }
+            }
+        }
+    };
+
+    fields.push(create_text_field(
+        "Send Mode",
+        &send_mode_label(message.mode),
+    )?);
+    if message.state_init {
+        fields.push(create_text_field(
+            "State Init",
+            "Included: deploys a contract at the destination",
+        )?);
+    }
+    if message.extra_currencies {
+        fields.push(create_text_field(
+            "Extra Currencies",
+            "Included, not shown",
+        )?);
+    }
+
+    Ok(create_preview_layout(
+        &format!("Message {n}"),
+        subtitle,
+        fields,
+    ))
+}
+
+fn payload_label(body: &Body) -> String {
+    match body {
+        Body::Empty => "Empty".to_string(),
+        Body::Comment(comment) => format!("Comment \"{comment}\""),
+        Body::EncryptedComment => "Encrypted comment".to_string(),
+        Body::JettonTransfer(_) => "Jetton transfer".to_string(),
+        Body::Operation(op) => format!("Operation 0x{op:08x}"),
+        Body::Unrecognized => "Unrecognized data".to_string(),
+    }
+}
+
+fn msg_address_label(address: &MsgAddress) -> String {
+    match address {
+        MsgAddress::Internal(address) => address.to_friendly(false),
+        MsgAddress::External => "External address".to_string(),
+        MsgAddress::None => "None".to_string(),
+    }
+}
+
+fn send_mode_label(mode: u8) -> String {
+    let flags = [
+        (MODE_CARRY_ALL_BALANCE, "sends the entire balance"),
+        (MODE_CARRY_INBOUND_VALUE, "adds the inbound message value"),
+        (MODE_DESTROY_IF_ZERO, "destroys the wallet if empty"),
+        (MODE_BOUNCE_ON_ACTION_FAIL, "bounces on action failure"),
+        (MODE_IGNORE_ERRORS, "ignores errors"),
+        (MODE_PAY_FEES_SEPARATELY, "pays fees separately"),
+    ];
+    let set: Vec<&str> = flags
+        .iter()
+        .filter(|(bit, _)| mode & bit != 0)
+        .map(|(_, label)| *label)
+        .collect();
+    if set.is_empty() {
+        mode.to_string()
+    } else {
+        format!("{mode} ({})", set.join(", "))
+    }
+}
+
+// Base units to a decimal string with integer math, trimming trailing zeros so e.g.
+// 1_500_000_000 nanotons -> "1.5".
+fn format_units(value: u128, decimals: u32) -> String {
+    let digits = value.to_string();
+    let decimals = decimals as usize;
+    if decimals == 0 {
+        return digits;
+    }
+    let padded = format!("{digits:0>width$}", width = decimals + 1);
+    let (whole, frac) = padded.split_at(padded.len() - decimals);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{frac}")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use crate::cell::Cell;
+    use crate::cell::builder::{CellBuilder, serialize_boc};
+    use generated::parser::{ChainMetadata, TonJetton};
+    use visualsign::{SignablePayloadField, SignablePayloadFieldListLayout};
+
+    const WALLET: &str = "UQAREREREREREREREREREREREREREREREREREREREREREbvW";
+    const BOB_BOUNCEABLE: &str = "EQAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIp3C";
+    const BOB: &str = "UQAiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIiIsAH";
+    const JETTON_WALLET_BOUNCEABLE: &str = "EQAzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzMzM7SN";
+    const CAROL: &str = "UQBERERERERERERERERERERERERERERERERERERERERERDel";
+    const PLUGIN: &str = "EQBVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVVUMv";
+
+    fn internal_message(bounce: bool, destination: u8, value: u128, body: Option<Cell>) -> Cell {
+        let builder = CellBuilder::new()
+            .bit(false)
+            .bit(true)
+            .bit(bounce)
+            .bit(false)
+            .uint(0, 2)
+            .address(0, [destination; 32])
+            .coins(value)
+            .bit(false)
+            .coins(0)
+            .coins(0)
+            .uint(0, 64)
+            .uint(0, 32)
+            .bit(false);
+        match body {
+            Some(body) => builder.bit(true).reference(body).build(),
+            None => builder.bit(false).build(),
+        }
+    }
+
+    fn comment(text: &str) -> Cell {
+        CellBuilder::new()
+            .uint(0, 32)
+            .bytes(text.as_bytes())
+            .build()
+    }
+
+    fn jetton_transfer(amount: u128, forward_comment: &str) -> Cell {
+        CellBuilder::new()
+            .uint(u128::from(message::OP_JETTON_TRANSFER), 32)
+            .uint(0, 64)
+            .coins(amount)
+            .address(0, [0x44; 32])
+            .address(0, [0x11; 32])
+            .bit(false)
+            .coins(1)
+            .bit(true)
+            .reference(comment(forward_comment))
+            .build()
+    }
+
+    fn v3_or_v4_body(op: Option<u8>, messages: Vec<(u8, Cell)>) -> CellBuilder {
+        let mut builder = CellBuilder::new()
+            .uint(0, 512)
+            .uint(698_983_191, 32)
+            .uint(1_700_000_000, 32)
+            .uint(7, 32);
+        if let Some(op) = op {
+            builder = builder.uint(u128::from(op), 8);
+        }
+        for (mode, message) in messages {
+            builder = builder.uint(u128::from(mode), 8).reference(message);
+        }
+        builder
+    }
+
+    fn v5_body(messages: Vec<(u8, Cell)>) -> Cell {
+        let mut list = CellBuilder::new().build();
+        for (mode, message) in messages {
+            list = CellBuilder::new()
+                .reference(list)
+                .uint(0x0ec3_c86d, 32)
+                .uint(u128::from(mode), 8)
+                .reference(message)
+                .build();
+        }
+        CellBuilder::new()
+            .uint(0x7369_676e, 32)
+            .uint(2_147_483_409, 32)
+            .uint(1_700_000_000, 32)
+            .uint(3, 32)
+            .bit(true)
+            .reference(list)
+            .bit(false)
+            .uint(0, 512)
+            .build()
+    }
+
+    fn external_message(body: Cell) -> String {
+        let message = CellBuilder::new()
+            .uint(0b10, 2)
+            .uint(0, 2)
+            .address(0, [0x11; 32])
+            .coins(0)
+            .bit(false)
+            .bit(true)
+            .reference(body)
+            .build();
+        b64.encode(serialize_boc(&message))
+    }
+
+    fn to_payload(data: &str, metadata: Option<TonMetadata>) -> SignablePayload {
+        let options = VisualSignOptions {
+            metadata: metadata.map(|ton| ChainMetadata {
+                metadata: Some(chain_metadata::Metadata::Ton(ton)),
+            }),
+            ..Default::default()
+        };
+        transaction_string_to_visual_sign(data, options).unwrap()
+    }
+
+    fn usdt_metadata(key: &str) -> TonMetadata {
+        TonMetadata {
+            jettons: [(
+                key.to_string(),
+                TonJetton {
+                    symbol: "USDT".to_string(),
+                    decimals: 6,
+                },
+            )]
+            .into(),
+        }
+    }
+
+    fn find_field<'a>(
+        payload: &'a SignablePayload,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        payload.fields.iter().find(|f| field_label(f) == label)
+    }
+
+    fn find_detail<'a>(
+        layout: &'a SignablePayloadFieldListLayout,
+        label: &str,
+    ) -> Option<&'a SignablePayloadField> {
+        layout
+            .fields
+            .iter()
+            .map(|f| &f.signable_payload_field)
+            .find(|f| field_label(f) == label)
+    }
+
+    fn field_label(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { common, .. }
+            | SignablePayloadField::AmountV2 { common, .. }
+            | SignablePayloadField::AddressV2 { common, .. }
+            | SignablePayloadField::PreviewLayout { common, .. } => &common.label,
+            _ => "",
+        }
+    }
+
+    fn text_value(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::TextV2 { text_v2, .. } => &text_v2.text,
+            _ => panic!("expected TextV2"),
+        }
+    }
+
+    fn amount_value(field: &SignablePayloadField) -> (&str, &str) {
+        match field {
+            SignablePayloadField::AmountV2 { amount_v2, .. } => (
+                amount_v2.amount.as_str(),
+                amount_v2.abbreviation.as_deref().unwrap_or(""),
+            ),
+            _ => panic!("expected AmountV2"),
+        }
+    }
+
+    fn address_value(field: &SignablePayloadField) -> (&str, &str) {
+        match field {
+            SignablePayloadField::AddressV2 { address_v2, .. } => {
+                (address_v2.address.as_str(), address_v2.name.as_str())
+            }
+            _ => panic!("expected AddressV2"),
+        }
+    }
+
+    fn preview_subtitle(field: &SignablePayloadField) -> &str {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => preview_layout
+                .subtitle
+                .as_ref()
+                .map(|t| t.text.as_str())
+                .unwrap_or(""),
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn preview_expanded(field: &SignablePayloadField) -> &SignablePayloadFieldListLayout {
+        match field {
+            SignablePayloadField::PreviewLayout { preview_layout, .. } => {
+                preview_layout.expanded.as_ref().unwrap()
+            }
+            _ => panic!("expected PreviewLayout"),
+        }
+    }
+
+    fn warnings(payload: &SignablePayload) -> Vec<&str> {
+        payload
+            .fields
+            .iter()
+            .filter(|f| field_label(f) == "Warning")
+            .map(text_value)
+            .collect()
+    }
+
+    #[test]
+    fn v4_transfer_with_comment() {
+        let body = v3_or_v4_body(
+            Some(0),
+            vec![(
+                3,
+                internal_message(false, 0x22, 1_500_000_000, Some(comment("Thanks!"))),
+            )],
+        )
+        .build();
+        let payload = to_payload(&external_message(body), None);
+
+        assert_eq!(payload.title, "TON Transaction");
+        assert_eq!(
+            address_value(find_field(&payload, "Wallet").unwrap()).0,
+            WALLET
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Wallet Version").unwrap()),
+            "v4"
+        );
+        assert_eq!(text_value(find_field(&payload, "Seqno").unwrap()), "7");
+        assert!(warnings(&payload).is_empty());
+        assert!(find_field(&payload, "Total Value").is_none());
+
+        let message = find_field(&payload, "Message 1").unwrap();
+        assert_eq!(
+            preview_subtitle(message),
+            format!("Send 1.5 TON to {BOB} with comment \"Thanks!\"")
+        );
+        let details = preview_expanded(message);
+        assert_eq!(
+            amount_value(find_detail(details, "Amount").unwrap()),
+            ("1.5", "TON")
+        );
+        assert_eq!(
+            text_value(find_detail(details, "Send Mode").unwrap()),
+            "3 (ignores errors, pays fees separately)"
+        );
+    }
+
+    #[test]
+    fn v5_expands_messages_in_order_and_resolves_jetton() {
+        let body = v5_body(vec![
+            (3, internal_message(true, 0x22, 100_000_000, None)),
+            (
+                3,
+                internal_message(
+                    true,
+                    0x33,
+                    50_000_000,
+                    Some(jetton_transfer(2_500_000, "order 42")),
+                ),
+            ),
+        ]);
+        let data = external_message(body);
+        let payload = to_payload(&data, Some(usdt_metadata(JETTON_WALLET_BOUNCEABLE)));
+
+        assert_eq!(
+            text_value(find_field(&payload, "Wallet Version").unwrap()),
+            "v5"
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Total Value").unwrap()),
+            ("0.15", "TON")
+        );
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Message 1").unwrap()),
+            format!("Send 0.1 TON to {BOB_BOUNCEABLE}")
+        );
+
+        let jetton = find_field(&payload, "Message 2").unwrap();
+        assert_eq!(
+            preview_subtitle(jetton),
+            format!("Send 2.5 USDT to {CAROL}")
+        );
+        let details = preview_expanded(jetton);
+        assert_eq!(
+            address_value(find_detail(details, "Jetton Wallet").unwrap()),
+            (JETTON_WALLET_BOUNCEABLE, "USDT jetton wallet")
+        );
+        assert_eq!(
+            amount_value(find_detail(details, "Token Amount").unwrap()),
+            ("2.5", "USDT")
+        );
+        assert_eq!(
+            address_value(find_detail(details, "Response Destination").unwrap()).0,
+            WALLET
+        );
+        assert_eq!(
+            text_value(find_detail(details, "Forward Comment").unwrap()),
+            "order 42"
+        );
+        assert_eq!(
+            amount_value(find_detail(details, "Attached TON").unwrap()),
+            ("0.05", "TON")
+        );
+
+        // Raw-form keys resolve the same wallet; without metadata the amount stays raw.
+        let raw_key = format!("0:{}", "33".repeat(32));
+        let payload = to_payload(&data, Some(usdt_metadata(&raw_key)));
+        assert_eq!(
+            preview_subtitle(find_field(&payload, "Message 2").unwrap()),
+            format!("Send 2.5 USDT to {CAROL}")
+        );
+        let payload = to_payload(&data, None);
+        assert_eq!(
+            text_value(
+                find_detail(
+                    preview_expanded(find_field(&payload, "Message 2").unwrap()),
+                    "Token Amount"
+                )
+                .unwrap()
+            ),
+            "2500000 (raw units, unknown jetton)"
+        );
+    }
+
+    #[test]
+    fn v3_send_all_balance_and_destroy_are_warned() {
+        let body = v3_or_v4_body(
+            None,
+            vec![(128 | 32, internal_message(false, 0x22, 0, None))],
+        )
+        .build();
+        let payload = to_payload(&external_message(body), None);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Wallet Version").unwrap()),
+            "v3"
+        );
+        let warnings = warnings(&payload);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("entire remaining balance"));
+        assert!(warnings[1].contains("destroys the wallet"));
+    }
+
+    #[test]
+    fn v4_plugin_install_is_warned() {
+        let body = v3_or_v4_body(Some(2), vec![])
+            .uint(0, 8)
+            .bytes(&[0x55; 32])
+            .coins(0)
+            .uint(0, 64)
+            .build();
+        let payload = to_payload(&external_message(body), None);
+
+        let warnings = warnings(&payload);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains(PLUGIN));
+        assert!(find_field(&payload, "Message 1").is_none());
+    }
+
+    #[test]
+    fn rejects_internal_messages_and_garbage() {
+        let internal = b64.encode(serialize_boc(&internal_message(true, 0x22, 1, None)));
+        assert!(
+            transaction_string_to_visual_sign(&internal, VisualSignOptions::default()).is_err()
+        );
+        assert!(
+            transaction_string_to_visual_sign("b5ee9c72", VisualSignOptions::default()).is_err()
+        );
+    }
+
+    #[test]
+    fn units_formatting() {
+        assert_eq!(format_units(0, 9), "0");
+        assert_eq!(format_units(1, 9), "0.000000001");
+        assert_eq!(format_units(1_500_000_000, 9), "1.5");
+        assert_eq!(format_units(42, 0), "42");
+        assert_eq!(format_units(123_000, 2), "1230");
+    }
+}
@@ -0,0 +1,415 @@
+// The external message a wallet signs, and the internal messages it asks the wallet to
+// send. Only the standard wallet contracts are understood: v3 and v4 put the signature
+// first and list up to four messages by reference; v5 tags its body, chains its actions
+// through an out-action list, and signs at the end.
+
+use crate::TonParserError;
+use crate::address::{MsgAddress, TonAddress};
+use crate::cell::{Cell, CellParser};
+
+const SIGNATURE_BITS: usize = 512;
+const WALLET_V5_EXTERNAL_SIGNED: u32 = 0x7369_676e;
+const ACTION_SEND_MSG: u32 = 0x0ec3_c86d;
+// A wallet sends at most 255 messages per transaction (the out-action list limit).
+const MAX_ACTIONS: usize = 255;
+
+pub const OP_COMMENT: u32 = 0;
+pub const OP_ENCRYPTED_COMMENT: u32 = 0x2167_da4b;
+pub const OP_JETTON_TRANSFER: u32 = 0x0f8a_7ea5;
+
+/// A signed external message to a wallet contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TonTransaction {
+    /// The wallet the message is addressed to
+    pub wallet: TonAddress,
+    /// The message carries the wallet's StateInit, i.e. it also deploys the wallet
+    pub deploys_wallet: bool,
+    pub request: WalletRequest,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WalletVersion {
+    V3,
+    V4,
+    V5,
+}
+
+impl WalletVersion {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::V3 => "v3",
+            Self::V4 => "v4",
+            Self::V5 => "v5",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WalletRequest {
+    pub version: WalletVersion,
+    pub wallet_id: u32,
+    pub valid_until: u32,
+    pub seqno: u32,
+    pub messages: Vec<OutMessage>,
+    pub other_action: Option<OtherAction>,
+}
+
+/// Wallet actions other than sending messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OtherAction {
+    /// v4: deploy a plugin contract and allow it to request funds
+    DeployAndInstallPlugin,
+    /// v4: allow an existing contract to request funds from the wallet
+    InstallPlugin(TonAddress),
+    /// v4: revoke a plugin
+    RemovePlugin(TonAddress),
+    /// v5: extended actions, which add or remove extensions or toggle signature auth
+    ExtendedActions,
+}
+
+/// An internal message the wallet is asked to send.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutMessage {
+    pub mode: u8,
+    pub bounce: bool,
+    pub destination: TonAddress,
+    /// Value attached to the message, in nanotons
+    pub value: u128,
+    pub extra_currencies: bool,
+    pub state_init: bool,
+    pub body: Body,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Body {
+    Empty,
+    Comment(String),
+    EncryptedComment,
+    JettonTransfer(Box<JettonTransfer>),
+    /// A body with an op code this parser does not decode
+    Operation(u32),
+    /// A body too short to carry an op code, or a comment that is not UTF-8
+    Unrecognized,
+}
+
+/// `transfer#0f8a7ea5` (TEP-74), sent to the sender's own jetton wallet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JettonTransfer {
+    pub query_id: u64,
+    /// Amount in the jetton's base units
+    pub amount: u128,
+    /// Owner of the receiving jetton wallet
+    pub destination: MsgAddress,
+    /// Where excess TON is returned
+    pub response_destination: MsgAddress,
+    pub custom_payload: bool,
+    pub forward_ton_amount: u128,
+    pub forward_payload: Body,
+}
+
+impl TonTransaction {
+    pub fn parse(root: &Cell) -> Result<Self, TonParserError> {
+        let mut parser = root.parser();
+        // ext_in_msg_info$10 src:MsgAddressExt dest:MsgAddressInt import_fee:Grams
+        if parser.load_uint(2)? != 0b10 {
+            return Err(TonParserError::UnsupportedMessage(
+                "expected an external inbound message".to_string(),
+            ));
+        }
+        MsgAddress::load(&mut parser)?;
+        let wallet = MsgAddress::load_internal(&mut parser)?;
+        parser.load_coins()?;
+        let deploys_wallet = load_state_init(&mut parser)?;
+        let mut body = load_either(&mut parser)?;
+        let request = parse_wallet_request(&mut body)?;
+        Ok(Self {
+            wallet,
+            deploys_wallet,
+            request,
+        })
+    }
+}
+
+fn parse_wallet_request(body: &mut CellParser<'_>) -> Result<WalletRequest, TonParserError> {
+    if body.peek_u32() == Some(WALLET_V5_EXTERNAL_SIGNED) {
+        return parse_v5_request(body);
+    }
+    if body.remaining_bits() < SIGNATURE_BITS + 96 {
+        return Err(TonParserError::UnsupportedMessage(
+            "message body is not a wallet v3, v4 or v5 request".to_string(),
+        ));
+    }
+    skip_bits(body, SIGNATURE_BITS)?;
+    let wallet_id = body.load_u32()?;
+    let valid_until = body.load_u32()?;
+    let seqno = body.load_u32()?;
+
+    // v3 follows the header with one mode byte per message reference; v4 puts an op byte
+    // in front of them.
+    let refs = body.remaining_refs();
+    let version = if body.remaining_bits() == 8 * refs {
+        WalletVersion::V3
+    } else {
+        WalletVersion::V4
+    };
+    let mut other_action = None;
+    if version == WalletVersion::V4 {
+        match body.load_u8()? {
+            0 => {}
+            1 => other_action = Some(OtherAction::DeployAndInstallPlugin),
+            op @ (2 | 3) => {
+                let workchain = body.load_u8()? as i8;
+                let hash = body.load_bytes(32)?;
+                let plugin = TonAddress {
+                    workchain,
+                    hash: hash.try_into().unwrap_or([0; 32]),
+                };
+                return Ok(WalletRequest {
+                    version,
+                    wallet_id,
+                    valid_until,
+                    seqno,
+                    messages: Vec::new(),
+                    other_action: Some(if op == 2 {
+                        OtherAction::InstallPlugin(plugin)
+                    } else {
+                        OtherAction::RemovePlugin(plugin)
+                    }),
+                });
+            }
+            op => {
+                return Err(TonParserError::UnsupportedMessage(format!(
+                    "unknown wallet v4 operation {op}"
+                )));
+            }
+        }
+    }
+
+    let mut messages = Vec::new();
+    if other_action.is_none() {
+        while body.remaining_refs() > 0 {
+            let mode = body.load_u8()?;
+            messages.push(parse_out_message(mode, body.load_ref()?)?);
+        }
+        if body.remaining_bits() != 0 {
+            return Err(TonParserError::UnsupportedMessage(
+                "unexpected data after the wallet's messages".to_string(),
+            ));
+        }
+    }
+    Ok(WalletRequest {
+        version,
+        wallet_id,
+        valid_until,
+        seqno,
+        messages,
+        other_action,
+    })
+}
+
+// external_signed#7369676e wallet_id:uint32 valid_until:uint32 msg_seqno:uint32
+//   actions:(Maybe ^OutList) has_other_actions:(## 1) other_actions:(has_other_actions?^ActionList)
+//   signature:bits512
+fn parse_v5_request(body: &mut CellParser<'_>) -> Result<WalletRequest, TonParserError> {
+    body.load_u32()?;
+    let wallet_id = body.load_u32()?;
+    let valid_until = body.load_u32()?;
+    let seqno = body.load_u32()?;
+    let messages = if body.load_bit()? {
+        parse_out_list(body.load_ref()?)?
+    } else {
+        Vec::new()
+    };
+    let other_action = if body.load_bit()? {
+        body.load_ref()?;
+        Some(OtherAction::ExtendedActions)
+    } else {
+        None
+    };
+    if body.remaining_bits() != SIGNATURE_BITS {
+        return Err(TonParserError::UnsupportedMessage(
+            "wallet v5 request does not end with a signature".to_string(),
+        ));
+    }
+    Ok(WalletRequest {
+        version: WalletVersion::V5,
+        wallet_id,
+        valid_until,
+        seqno,
+        messages,
+        other_action,
+    })
+}
+
+// out_list$_ prev:^(OutList n) action:OutAction; the head of the list is the last action,
+// so the list is walked back to front and then reversed.
+fn parse_out_list(head: &Cell) -> Result<Vec<OutMessage>, TonParserError> {
+    let mut messages = Vec::new();
+    let mut cell = head;
+    loop {
+        let mut parser = cell.parser();
+        if parser.is_empty() {
+            break;
+        }
+        if messages.len() == MAX_ACTIONS {
+            return Err(TonParserError::UnsupportedMessage(
+                "out-action list is longer than 255 actions".to_string(),
+            ));
+        }
+        let prev = parser.load_ref()?;
+        let tag = parser.load_u32()?;
+        if tag != ACTION_SEND_MSG {
+            return Err(TonParserError::UnsupportedMessage(format!(
+                "out action 0x{tag:08x}"
+            )));
+        }
+        let mode = parser.load_u8()?;
+        messages.push(parse_out_message(mode, parser.load_ref()?)?);
+        cell = prev;
+    }
+    messages.reverse();
+    Ok(messages)
+}
+
+// int_msg_info$0 ihr_disabled:Bool bounce:Bool bounced:Bool src:MsgAddress dest:MsgAddressInt
+//   value:CurrencyCollection ihr_fee:Grams fwd_fee:Grams created_lt:uint64 created_at:uint32
+fn parse_out_message(mode: u8, cell: &Cell) -> Result<OutMessage, TonParserError> {
+    let mut parser = cell.parser();
+    if parser.load_bit()? {
+        return Err(TonParserError::UnsupportedMessage(
+            "wallet can only send internal messages".to_string(),
+        ));
+    }
+    parser.load_bit()?;
+    let bounce = parser.load_bit()?;
+    parser.load_bit()?;
+    MsgAddress::load(&mut parser)?;
+    let destination = MsgAddress::load_internal(&mut parser)?;
+    let value = parser.load_coins()?;
+    let extra_currencies = parser.load_bit()?;
+    if extra_currencies {
+        parser.load_ref()?;
+    }
+    parser.load_coins()?;
+    parser.load_coins()?;
+    parser.load_uint(64)?;
+    parser.load_u32()?;
+    let state_init = load_state_init(&mut parser)?;
+    let mut body = load_either(&mut parser)?;
+    Ok(OutMessage {
+        mode,
+        bounce,
+        destination,
+        value,
+        extra_currencies,
+        state_init,
+        body: decode_body(&mut body, true)?,
+    })
+}
+
+// init:(Maybe (Either StateInit ^StateInit)); only its presence matters for display.
+fn load_state_init(parser: &mut CellParser<'_>) -> Result<bool, TonParserError> {
+    if !parser.load_bit()? {
+        return Ok(false);
+    }
+    if parser.load_bit()? {
+        parser.load_ref()?;
+        return Ok(true);
+    }
+    // split_depth:(Maybe (## 5)) special:(Maybe TickTock) code:(Maybe ^Cell)
+    // data:(Maybe ^Cell) library:(Maybe ^Cell)
+    if parser.load_bit()? {
+        parser.load_uint(5)?;
+    }
+    if parser.load_bit()? {
+        parser.load_uint(2)?;
+    }
+    for _ in 0..3 {
+        if parser.load_bit()? {
+            parser.load_ref()?;
+        }
+    }
+    Ok(true)
+}
+
+// (Either X ^X): the rest of this cell, or the next reference.
+fn load_either<'a>(parser: &mut CellParser<'a>) -> Result<CellParser<'a>, TonParserError> {
+    if parser.remaining_bits() == 0 && parser.remaining_refs() == 0 {
+        return Ok(parser.clone());
+    }
+    if parser.load_bit()? {
+        Ok(parser.load_ref()?.parser())
+    } else {
+        Ok(parser.clone())
+    }
+}
+
+fn skip_bits(parser: &mut CellParser<'_>, bits: usize) -> Result<(), TonParserError> {
+    for _ in 0..bits {
+        parser.load_bit()?;
+    }
+    Ok(())
+}
+
+// Message bodies start with a 32-bit op code. Jetton transfers are only decoded at the top
+// level; a forward payload is limited to comments.
+fn decode_body(body: &mut CellParser<'_>, allow_jetton: bool) -> Result<Body, TonParserError> {
+    if body.is_empty() {
+        return Ok(Body::Empty);
+    }
+    if body.remaining_bits() < 32 {
+        return Ok(Body::Unrecognized);
+    }
+    match body.load_u32()? {
+        OP_COMMENT => Ok(load_snake_text(body)?.map_or(Body::Unrecognized, Body::Comment)),
+        OP_ENCRYPTED_COMMENT => Ok(Body::EncryptedComment),
+        OP_JETTON_TRANSFER if allow_jetton => {
+            Ok(Body::JettonTransfer(Box::new(load_jetton_transfer(body)?)))
+        }
+        op => Ok(Body::Operation(op)),
+    }
+}
+
+// transfer#0f8a7ea5 query_id:uint64 amount:(VarUInteger 16) destination:MsgAddress
+//   response_destination:MsgAddress custom_payload:(Maybe ^Cell)
+//   forward_ton_amount:(VarUInteger 16) forward_payload:(Either Cell ^Cell)
+fn load_jetton_transfer(body: &mut CellParser<'_>) -> Result<JettonTransfer, TonParserError> {
+    let query_id = body.load_uint(64)?;
+    let amount = body.load_coins()?;
+    let destination = MsgAddress::load(body)?;
+    let response_destination = MsgAddress::load(body)?;
+    let custom_payload = body.load_bit()?;
+    if custom_payload {
+        body.load_ref()?;
+    }
+    let forward_ton_amount = body.load_coins()?;
+    // Some wallets drop the forward payload bit entirely when there is no payload.
+    let forward_payload = decode_body(&mut load_either(body)?, false)?;
+    Ok(JettonTransfer {
+        query_id,
+        amount,
+        destination,
+        response_destination,
+        custom_payload,
+        forward_ton_amount,
+        forward_payload,
+    })
+}
+
+// Text stored "snake" style: the bytes of this cell, then of its first reference, and so
+// on. Returns None for text that is not whole bytes of valid UTF-8.
+fn load_snake_text(parser: &mut CellParser<'_>) -> Result<Option<String>, TonParserError> {
+    let mut bytes = Vec::new();
+    let mut current = parser.clone();
+    loop {
+        if current.remaining_bits() % 8 != 0 {
+            return Ok(None);
+        }
+        bytes.extend(current.load_bytes(current.remaining_bits() / 8)?);
+        if current.remaining_refs() == 0 {
+            break;
+        }
+        current = current.load_ref()?.parser();
+    }
+    Ok(String::from_utf8(bytes).ok())
+}
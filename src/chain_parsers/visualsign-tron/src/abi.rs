@@ -0,0 +1,133 @@
+// Solidity ABI reading shared by the TriggerSmartContract decoders. Tron contracts use the
+// Ethereum ABI unchanged; the one difference is that an address word holds the 20 bytes
+// that follow Tron's 0x41 prefix.
+
+pub const WORD_LEN: usize = 32;
+
+pub type Word = [u8; WORD_LEN];
+
+/// The `index`th 32-byte word of `args`.
+pub fn word(args: &[u8], index: usize) -> Option<Word> {
+    let start = index.checked_mul(WORD_LEN)?;
+    args.get(start..start.checked_add(WORD_LEN)?)?
+        .try_into()
+        .ok()
+}
+
+/// The `index`th word of `args` as a 21-byte Tron address.
+///
+/// An ABI address is left-padded with 12 zero bytes; anything else in the padding means
+/// the word is not an address, so this returns `None`.
+pub fn address(args: &[u8], index: usize) -> Option<Vec<u8>> {
+    let word = word(args, index)?;
+    let (padding, address) = word.split_at(12);
+    if padding.iter().any(|b| *b != 0) {
+        return None;
+    }
+    let mut tron_address = vec![crate::TRON_MAINNET_PREFIX];
+    tron_address.extend_from_slice(address);
+    Some(tron_address)
+}
+
+/// The `index`th word of `args` as a u64, `None` when the value does not fit.
+pub fn u64_word(args: &[u8], index: usize) -> Option<u64> {
+    let word = word(args, index)?;
+    let (high, low) = word.split_at(WORD_LEN - 8);
+    if high.iter().any(|b| *b != 0) {
+        return None;
+    }
+    Some(u64::from_be_bytes(low.try_into().ok()?))
+}
+
+fn usize_word(args: &[u8], index: usize) -> Option<usize> {
+    usize::try_from(u64_word(args, index)?).ok()
+}
+
+/// The encoding that the head word at `index` points to, e.g. a dynamic tuple's own
+/// arguments. Offsets are relative to the start of the enclosing tuple, which is `args`
+/// itself at the top level.
+pub fn tail(args: &[u8], index: usize) -> Option<&[u8]> {
+    args.get(usize_word(args, index)?..)
+}
+
+/// The dynamic `bytes` value whose offset is the `index`th word of `args`.
+pub fn bytes(args: &[u8], index: usize) -> Option<&[u8]> {
+    let tail = tail(args, index)?;
+    let len = usize_word(tail, 0)?;
+    tail.get(WORD_LEN..WORD_LEN.checked_add(len)?)
+}
+
+/// The dynamic `address[]` value whose offset is the `index`th word of `args`.
+pub fn address_array(args: &[u8], index: usize) -> Option<Vec<Vec<u8>>> {
+    let tail = tail(args, index)?;
+    let len = usize_word(tail, 0)?;
+    let items = tail.get(WORD_LEN..)?;
+    // Every element needs a word, so a length beyond the data is rejected before
+    // allocating for it.
+    if len > items.len() / WORD_LEN {
+        return None;
+    }
+    (0..len).map(|i| address(items, i)).collect()
+}
+
+// Big-endian 256-bit word to a base-10 string by repeated long division, so amounts above
+// u128 (including the all-ones unlimited allowance) are still shown exactly.
+pub fn word_to_decimal(word: &Word) -> String {
+    let mut value = *word;
+    let mut digits = Vec::new();
+    while value.iter().any(|b| *b != 0) {
+        let mut remainder = 0u32;
+        for byte in value.iter_mut() {
+            let current = (remainder << 8) | u32::from(*byte);
+            *byte = (current / 10) as u8;
+            remainder = current % 10;
+        }
+        digits.push(b'0' + remainder as u8);
+    }
+    if digits.is_empty() {
+        return "0".to_string();
+    }
+    digits.reverse();
+    String::from_utf8(digits).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn word_from_u128(value: u128) -> Word {
+        let mut word = [0u8; WORD_LEN];
+        word[16..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    #[test]
+    fn word_to_decimal_is_exact_beyond_u128() {
+        assert_eq!(word_to_decimal(&[0; WORD_LEN]), "0");
+        assert_eq!(word_to_decimal(&word_from_u128(1_500_000)), "1500000");
+        assert_eq!(
+            word_to_decimal(&word_from_u128(u128::MAX)),
+            u128::MAX.to_string()
+        );
+        assert_eq!(
+            word_to_decimal(&[0xff; WORD_LEN]),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+    }
+
+    #[test]
+    fn address_array_rejects_length_beyond_data() {
+        let mut args = word_from_u128(0x20).to_vec();
+        args.extend_from_slice(&word_from_u128(u128::MAX));
+        assert!(address_array(&args, 0).is_none());
+
+        let mut args = word_from_u128(0x20).to_vec();
+        args.extend_from_slice(&word_from_u128(1));
+        args.extend_from_slice(&word_from_u128(0xabcd));
+        let addresses = address_array(&args, 0).unwrap();
+        assert_eq!(addresses.len(), 1);
+        assert_eq!(addresses[0][0], crate::TRON_MAINNET_PREFIX);
+        assert_eq!(&addresses[0][19..], &[0xab, 0xcd]);
+    }
+}
@@ -4,18 +4,33 @@ pub mod cli_plugin;
 #[cfg(feature = "cli-plugin")]
 pub use cli_plugin::{TronArgs, TronPlugin};
 
+mod abi;
+mod permissions;
+mod protocols;
+mod registry;
+mod trc10;
+mod trc20;
+
 use anychain_tron::protocol::Tron::{Transaction as TronTransaction, transaction};
+use anychain_tron::protocol::account_contract::AccountPermissionUpdateContract;
+use anychain_tron::protocol::asset_issue_contract::TransferAssetContract;
 use anychain_tron::protocol::balance_contract::{
     DelegateResourceContract, FreezeBalanceV2Contract, TransferContract,
     UnDelegateResourceContract, UnfreezeBalanceV2Contract, WithdrawBalanceContract,
     WithdrawExpireUnfreezeContract,
 };
 use anychain_tron::protocol::common::ResourceCode;
+use anychain_tron::protocol::smart_contract::TriggerSmartContract;
 use anychain_tron::protocol::witness_contract::VoteWitnessContract;
 use base64::{Engine as _, engine::general_purpose::STANDARD as b64};
+use generated::parser::{TronMetadata, chain_metadata};
 use protobuf::Message;
+use registry::TronRegistry;
 use visualsign::field_builders::{create_address_field, create_amount_field, create_text_field};
-use visualsign::time_fmt::{format_relative_ms, format_timestamp_ms};
+use visualsign::registry::LayeredRegistry;
+use visualsign::time_fmt::{
+    format_duration_secs, format_duration_with_raw, format_relative_ms, format_timestamp_ms,
+};
 use visualsign::{
     AnnotatedPayloadField, SignablePayload, SignablePayloadField, SignablePayloadFieldCommon,
     SignablePayloadFieldListLayout, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
@@ -138,6 +153,16 @@ fn convert_to_visual_sign_payload(
     raw_data: transaction::Raw,
     options: VisualSignOptions,
 ) -> Result<SignablePayload, VisualSignError> {
+    let metadata = match options
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.metadata.as_ref())
+    {
+        Some(chain_metadata::Metadata::Tron(tron_metadata)) => Some(tron_metadata),
+        _ => None,
+    };
+    let registry = registry::layered_registry(metadata, options.strict_registry);
+
     let mut fields: Vec<AnnotatedPayloadField> = Vec::new();
 
     fields.push(create_text_field("Network", "Tron")?);
@@ -151,12 +176,31 @@ fn convert_to_visual_sign_payload(
         "Expiration",
         &render_time_field(raw_data.expiration, now_ms),
     )?);
+    if let Some(window) = validity_window_label(raw_data.timestamp, raw_data.expiration) {
+        fields.push(create_text_field("Valid For", &window)?);
+    }
 
     fields.push(create_amount_field(
         "Fee Limit",
         &sun_to_trx_string(raw_data.fee_limit),
         "TRX",
     )?);
+    // fee_limit only caps the TRX burned for energy, which only smart contract execution
+    // consumes; on any other contract it is ignored.
+    if raw_data.contract.iter().any(|contract| {
+        contract.parameter.as_ref().is_some_and(|parameter| {
+            SMART_CONTRACT_TYPE_URLS.contains(&parameter.type_url.as_str())
+        })
+    }) {
+        fields.push(create_text_field(
+            "Max Energy Burn",
+            &max_energy_burn_label(raw_data.fee_limit),
+        )?);
+    }
+    fields.push(create_text_field(
+        "Estimated Bandwidth",
+        &bandwidth_label(&raw_data),
+    )?);
 
     fields.push(create_text_field(
         "Ref Block",
@@ -171,7 +215,13 @@ fn convert_to_visual_sign_payload(
     for contract in raw_data.contract.iter() {
         match contract.parameter.as_ref() {
             Some(parameter) => {
-                decode_contract(&parameter.type_url, &parameter.value, &mut fields)?;
+                decode_contract(
+                    &parameter.type_url,
+                    &parameter.value,
+                    metadata,
+                    &registry,
+                    &mut fields,
+                )?;
             }
             None => {
                 // Make malformed/incomplete transactions visible to the signer rather than
@@ -200,6 +250,8 @@ fn convert_to_visual_sign_payload(
 fn decode_contract(
     type_url: &str,
     value: &[u8],
+    metadata: Option<&TronMetadata>,
+    registry: &LayeredRegistry<TronRegistry>,
     fields: &mut Vec<AnnotatedPayloadField>,
 ) -> Result<(), VisualSignError> {
     match type_url {
@@ -211,28 +263,31 @@ fn decode_contract(
                 "Contract Type",
                 "TransferContract (TRX Transfer)",
             )?);
-            fields.push(create_address_field(
-                "From",
-                &address_to_base58(&transfer.owner_address),
-                None,
-                None,
-                None,
-                None,
-            )?);
-            fields.push(create_address_field(
-                "To",
-                &address_to_base58(&transfer.to_address),
-                None,
-                None,
-                None,
-                None,
-            )?);
+            fields.push(address_field("From", &transfer.owner_address, registry)?);
+            fields.push(address_field("To", &transfer.to_address, registry)?);
             fields.push(create_amount_field(
                 "Amount",
                 &sun_to_trx_string(transfer.amount),
                 "TRX",
             )?);
         }
+        "type.googleapis.com/protocol.TransferAssetContract" => {
+            let transfer = TransferAssetContract::parse_from_bytes(value).map_err(|e| {
+                VisualSignError::ConversionError(format!("decode TransferAssetContract: {e}"))
+            })?;
+            fields.push(create_text_field(
+                "Contract Type",
+                "TransferAssetContract (TRC-10 Transfer)",
+            )?);
+            fields.push(address_field("From", &transfer.owner_address, registry)?);
+            fields.push(address_field("To", &transfer.to_address, registry)?);
+            fields.extend(trc10::asset_transfer_fields(
+                &transfer.asset_name,
+                transfer.amount,
+                metadata,
+                registry.is_strict(),
+            )?);
+        }
         "type.googleapis.com/protocol.FreezeBalanceV2Contract" => {
             let freeze = FreezeBalanceV2Contract::parse_from_bytes(value).map_err(|e| {
                 VisualSignError::ConversionError(format!("decode FreezeBalanceV2Contract: {e}"))
@@ -241,14 +296,7 @@ fn decode_contract(
                 "Contract Type",
                 "FreezeBalanceV2 (Stake)",
             )?);
-            fields.push(create_address_field(
-                "Owner",
-                &address_to_base58(&freeze.owner_address),
-                None,
-                None,
-                None,
-                None,
-            )?);
+            fields.push(address_field("Owner", &freeze.owner_address, registry)?);
             fields.push(create_amount_field(
                 "Frozen Balance",
                 &sun_to_trx_string(freeze.frozen_balance),
@@ -258,6 +306,13 @@ fn decode_contract(
                 "Resource",
                 &resource_label(freeze.resource),
             )?);
+            fields.push(create_text_field(
+                "Lockup",
+                &format!(
+                    "Staked TRX is locked until unstaked, then withdrawable after a \
+                     {UNSTAKING_PERIOD_DAYS}-day waiting period"
+                ),
+            )?);
         }
         "type.googleapis.com/protocol.UnfreezeBalanceV2Contract" => {
             let unfreeze = UnfreezeBalanceV2Contract::parse_from_bytes(value).map_err(|e| {
@@ -267,14 +322,7 @@ fn decode_contract(
                 "Contract Type",
                 "UnfreezeBalanceV2 (Unstake)",
             )?);
-            fields.push(create_address_field(
-                "Owner",
-                &address_to_base58(&unfreeze.owner_address),
-                None,
-                None,
-                None,
-                None,
-            )?);
+            fields.push(address_field("Owner", &unfreeze.owner_address, registry)?);
             fields.push(create_amount_field(
                 "Unfreeze Balance",
                 &sun_to_trx_string(unfreeze.unfreeze_balance),
@@ -284,6 +332,13 @@ fn decode_contract(
                 "Resource",
                 &resource_label(unfreeze.resource),
             )?);
+            fields.push(create_text_field(
+                "Lockup",
+                &format!(
+                    "Withdrawable after a {UNSTAKING_PERIOD_DAYS}-day waiting period; \
+                     votes are revoked if the remaining TRON Power no longer covers them"
+                ),
+            )?);
         }
         "type.googleapis.com/protocol.WithdrawExpireUnfreezeContract" => {
             let withdraw =
@@ -296,13 +351,12 @@ fn decode_contract(
                 "Contract Type",
                 "WithdrawExpireUnfreeze (Claim Unfrozen)",
             )?);
-            fields.push(create_address_field(
-                "Owner",
-                &address_to_base58(&withdraw.owner_address),
-                None,
-                None,
-                None,
-                None,
+            fields.push(address_field("Owner", &withdraw.owner_address, registry)?);
+            // The amount is whatever unstaked TRX has finished its waiting period at
+            // execution time; the contract itself carries only the owner.
+            fields.push(create_text_field(
+                "Effect",
+                "Returns all unstaked TRX whose waiting period has ended to the available balance",
             )?);
         }
         "type.googleapis.com/protocol.WithdrawBalanceContract" => {
@@ -316,35 +370,18 @@ fn decode_contract(
                 "Contract Type",
                 "WithdrawBalance (Claim Rewards)",
             )?);
-            fields.push(create_address_field(
-                "Owner",
-                &address_to_base58(&withdraw.owner_address),
-                None,
-                None,
-                None,
-                None,
-            )?);
+            fields.push(address_field("Owner", &withdraw.owner_address, registry)?);
         }
         "type.googleapis.com/protocol.DelegateResourceContract" => {
             let delegate = DelegateResourceContract::parse_from_bytes(value).map_err(|e| {
                 VisualSignError::ConversionError(format!("decode DelegateResourceContract: {e}"))
             })?;
             fields.push(create_text_field("Contract Type", "DelegateResource")?);
-            fields.push(create_address_field(
-                "Owner",
-                &address_to_base58(&delegate.owner_address),
-                None,
-                None,
-                None,
-                None,
-            )?);
-            fields.push(create_address_field(
+            fields.push(address_field("Owner", &delegate.owner_address, registry)?);
+            fields.push(address_field(
                 "Receiver",
-                &address_to_base58(&delegate.receiver_address),
-                None,
-                None,
-                None,
-                None,
+                &delegate.receiver_address,
+                registry,
             )?);
             fields.push(create_text_field(
                 "Resource",
@@ -366,7 +403,25 @@ fn decode_contract(
             if delegate.lock || delegate.lock_period != 0 {
                 fields.push(create_text_field(
                     "Lock Period",
-                    &delegate.lock_period.to_string(),
+                    &lock_period_label(delegate.lock_period),
+                )?);
+            }
+            // Scams ask victims to delegate resources to an address "for activation" or
+            // similar; spell out who gets what, and that a locked delegation cannot be
+            // taken back early.
+            let resource = resource_label(delegate.resource);
+            fields.push(create_text_field(
+                "Effect",
+                &format!(
+                    "The receiver can use the {resource} of {} TRX staked by the owner; the TRX \
+                     stays with the owner",
+                    sun_to_trx_string(delegate.balance)
+                ),
+            )?);
+            if delegate.lock {
+                fields.push(create_text_field(
+                    "Warning",
+                    "Locked delegation: the owner cannot undelegate until the lock period ends",
                 )?);
             }
         }
@@ -375,21 +430,11 @@ fn decode_contract(
                 VisualSignError::ConversionError(format!("decode UnDelegateResourceContract: {e}"))
             })?;
             fields.push(create_text_field("Contract Type", "UnDelegateResource")?);
-            fields.push(create_address_field(
-                "Owner",
-                &address_to_base58(&undelegate.owner_address),
-                None,
-                None,
-                None,
-                None,
-            )?);
-            fields.push(create_address_field(
+            fields.push(address_field("Owner", &undelegate.owner_address, registry)?);
+            fields.push(address_field(
                 "Receiver",
-                &address_to_base58(&undelegate.receiver_address),
-                None,
-                None,
-                None,
-                None,
+                &undelegate.receiver_address,
+                registry,
             )?);
             fields.push(create_text_field(
                 "Resource",
@@ -400,31 +445,46 @@ fn decode_contract(
                 &sun_to_trx_string(undelegate.balance),
                 "TRX",
             )?);
+            fields.push(create_text_field(
+                "Effect",
+                &format!(
+                    "Takes back the {} delegated to the receiver for the owner's own use",
+                    resource_label(undelegate.resource)
+                ),
+            )?);
+        }
+        "type.googleapis.com/protocol.AccountPermissionUpdateContract" => {
+            let update = AccountPermissionUpdateContract::parse_from_bytes(value).map_err(|e| {
+                VisualSignError::ConversionError(format!(
+                    "decode AccountPermissionUpdateContract: {e}"
+                ))
+            })?;
+            fields.push(create_text_field(
+                "Contract Type",
+                "AccountPermissionUpdate (Change Account Control)",
+            )?);
+            fields.extend(permissions::permission_update_fields(&update, registry)?);
         }
         "type.googleapis.com/protocol.VoteWitnessContract" => {
             let vote = VoteWitnessContract::parse_from_bytes(value).map_err(|e| {
                 VisualSignError::ConversionError(format!("decode VoteWitnessContract: {e}"))
             })?;
             fields.push(create_text_field("Contract Type", "Vote Witness")?);
-            fields.push(create_address_field(
-                "Owner",
-                &address_to_base58(&vote.owner_address),
-                None,
-                None,
-                None,
-                None,
-            )?);
+            fields.push(address_field("Owner", &vote.owner_address, registry)?);
 
             let mut detail_fields: Vec<AnnotatedPayloadField> = Vec::new();
             for (i, v) in vote.votes.iter().enumerate() {
                 let n = i + 1;
-                detail_fields.push(create_address_field(
+                let witness = address_to_base58(&v.vote_address);
+                let witness_name = metadata
+                    .and_then(|metadata| metadata.witnesses.get(&witness))
+                    .map(String::as_str);
+                detail_fields.push(labeled_address_field(
                     &format!("Vote {n} (SR)"),
-                    &address_to_base58(&v.vote_address),
-                    None,
-                    None,
-                    None,
+                    &witness,
+                    witness_name,
                     None,
+                    registry,
                 )?);
                 detail_fields.push(create_text_field(
                     &format!("Vote {n} (Count)"),
@@ -471,6 +531,51 @@ fn decode_contract(
                 dynamic_annotation: None,
             });
         }
+        "type.googleapis.com/protocol.TriggerSmartContract" => {
+            let trigger = TriggerSmartContract::parse_from_bytes(value).map_err(|e| {
+                VisualSignError::ConversionError(format!("decode TriggerSmartContract: {e}"))
+            })?;
+            let contract_address = address_to_base58(&trigger.contract_address);
+            fields.push(create_text_field(
+                "Contract Type",
+                "TriggerSmartContract (Contract Call)",
+            )?);
+            fields.push(address_field("Owner", &trigger.owner_address, registry)?);
+            fields.push(labeled_address_field(
+                "Contract",
+                &contract_address,
+                None,
+                None,
+                registry,
+            )?);
+            // TRX sent along with the call is easy to miss next to a token amount, so it
+            // is only shown (and always shown) when non-zero.
+            if trigger.call_value != 0 {
+                fields.push(create_amount_field(
+                    "Call Value",
+                    &sun_to_trx_string(trigger.call_value),
+                    "TRX",
+                )?);
+            }
+            let call = protocols::ContractCall {
+                owner: &address_to_base58(&trigger.owner_address),
+                contract: &contract_address,
+                call_value: trigger.call_value,
+                data: &trigger.data,
+                registry,
+            };
+            let call_fields =
+                match trc20::decode_trc20_call(&contract_address, &trigger.data, registry)? {
+                    Some(call_fields) => Some(call_fields),
+                    None => protocols::decode_protocol_call(&call)?,
+                };
+            match call_fields {
+                Some(call_fields) => fields.extend(call_fields),
+                None => {
+                    fields.push(create_text_field("Data", &hex::encode(&trigger.data))?);
+                }
+            }
+        }
         other => {
             fields.push(create_text_field(
                 "Contract Type",
@@ -519,6 +624,45 @@ fn address_to_base58(address_bytes: &[u8]) -> String {
     bs58::encode(address_bytes).with_check().into_string()
 }
 
+// Address field for a raw Tron address, named from the request's address book when the
+// address is listed there.
+fn address_field(
+    label: &str,
+    address_bytes: &[u8],
+    registry: &LayeredRegistry<TronRegistry>,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    labeled_address_field(
+        label,
+        &address_to_base58(address_bytes),
+        None,
+        None,
+        registry,
+    )
+}
+
+// A `name` the caller already knows (e.g. a super representative's) wins over the address
+// book; otherwise the book supplies both the name and the badge.
+fn labeled_address_field(
+    label: &str,
+    address: &str,
+    name: Option<&str>,
+    memo: Option<&str>,
+    registry: &LayeredRegistry<TronRegistry>,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    if name.is_some() {
+        return create_address_field(label, address, name, memo, None, None);
+    }
+    let book_label = registry.lookup(|r| r.get_address_label(address).cloned());
+    create_address_field(
+        label,
+        address,
+        book_label.as_ref().map(|l| l.name.as_str()),
+        memo,
+        None,
+        book_label.as_ref().and_then(|l| l.badge.as_deref()),
+    )
+}
+
 fn resource_label(resource: protobuf::EnumOrUnknown<ResourceCode>) -> String {
     match resource.enum_value() {
         Ok(ResourceCode::BANDWIDTH) => "BANDWIDTH".to_string(),
@@ -528,6 +672,75 @@ fn resource_label(resource: protobuf::EnumOrUnknown<ResourceCode>) -> String {
     }
 }
 
+// Stake 2.0 unstaking delay. It is a network parameter (`unfreezeDelayDays`), 14 days on
+// mainnet since Stake 2.0 launched.
+const UNSTAKING_PERIOD_DAYS: u32 = 14;
+
+// Tron produces a block every 3 seconds, which is what DelegateResource lock periods count.
+const TRON_BLOCK_INTERVAL_SECS: u64 = 3;
+
+// Render a lock period in blocks as "3 days (86400 blocks)". Zero (lock until manual
+// undelegate) and out-of-range negatives keep the bare raw value.
+fn lock_period_label(lock_period: i64) -> String {
+    match u64::try_from(lock_period) {
+        Ok(blocks) if blocks > 0 => {
+            format_duration_with_raw(blocks, TRON_BLOCK_INTERVAL_SECS, "blocks")
+        }
+        _ => lock_period.to_string(),
+    }
+}
+
+const SMART_CONTRACT_TYPE_URLS: &[&str] = &[
+    "type.googleapis.com/protocol.TriggerSmartContract",
+    "type.googleapis.com/protocol.CreateSmartContract",
+];
+
+fn max_energy_burn_label(fee_limit: i64) -> String {
+    if fee_limit <= 0 {
+        return "None: the call can only use staked energy and fails if that runs out".to_string();
+    }
+    format!(
+        "Up to {} TRX once the owner's staked energy is used up",
+        sun_to_trx_string(fee_limit)
+    )
+}
+
+// Bandwidth is charged per byte of the signed transaction plus a fixed 64-byte reserve per
+// contract for its result (`MAX_RESULT_SIZE_IN_TX` in java-tron). The estimate assumes a
+// single signature; each extra multi-sig signature adds another 67 bytes.
+const SIGNATURE_BYTES: u64 = 67;
+const RESULT_RESERVE_BYTES: u64 = 64;
+
+// TRX burned per bandwidth point when free and staked bandwidth are exhausted. It is a
+// network parameter (`getTransactionFee`), 1000 SUN on mainnet.
+const BANDWIDTH_PRICE_SUN: i64 = 1_000;
+
+fn bandwidth_label(raw_data: &transaction::Raw) -> String {
+    let raw_len = raw_data.compute_size();
+    // `raw_data` is field 1 of Transaction: one tag byte plus a varint length prefix.
+    let raw_field_len = 1 + protobuf::rt::compute_raw_varint64_size(raw_len) + raw_len;
+    let points =
+        raw_field_len + SIGNATURE_BYTES + RESULT_RESERVE_BYTES * raw_data.contract.len() as u64;
+    let burn = i64::try_from(points)
+        .unwrap_or(i64::MAX)
+        .saturating_mul(BANDWIDTH_PRICE_SUN);
+    format!(
+        "About {points} points; burns up to {} TRX if the owner has no free or staked \
+         bandwidth left",
+        sun_to_trx_string(burn)
+    )
+}
+
+// How long the transaction stays valid after it was created. Unset or inverted timestamps
+// are left out rather than rendered as a nonsensical duration.
+fn validity_window_label(timestamp: i64, expiration: i64) -> Option<String> {
+    if timestamp <= 0 || expiration <= timestamp {
+        return None;
+    }
+    let secs = u64::try_from((expiration - timestamp) / 1000).ok()?;
+    Some(format_duration_secs(secs))
+}
+
 // Convert an i64 SUN amount to a TRX decimal string using integer math, so the displayed
 // number is a byte-exact representation of the on-chain SUN value at any magnitude
 // (f64-based division would round the trailing digits above 2^53 SUN). Output omits the
@@ -552,6 +765,23 @@ fn sun_to_trx_string(sun: i64) -> String {
     }
 }
 
+// Insert the decimal point `decimals` places from the right of an integer string and trim
+// trailing fractional zeros, e.g. ("1500000", 6) -> "1.5".
+fn scale_decimal(raw: &str, decimals: u8) -> String {
+    let decimals = usize::from(decimals);
+    if decimals == 0 {
+        return raw.to_string();
+    }
+    let padded = format!("{raw:0>width$}", width = decimals + 1);
+    let (whole, frac) = padded.split_at(padded.len() - decimals);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{frac}")
+    }
+}
+
 // Renders "<UTC> (<ms> ms[, <relative>])" — the relative tag is omitted when
 // the timestamp is outside chrono's representable range so signers still see
 // the raw bytes without a misleading "N years ago".
@@ -719,6 +949,14 @@ mod tests {
             address_value(find_field(&payload, "Owner").unwrap()),
             "TFNKTGCp3EuZ9qsVXo7gEXwiJwSw1g55aX"
         );
+        // 120-byte raw_data + 2-byte field header + one signature + one result reserve.
+        assert_eq!(
+            text_value(find_field(&payload, "Estimated Bandwidth").unwrap()),
+            "About 253 points; burns up to 0.253 TRX if the owner has no free or staked \
+             bandwidth left"
+        );
+        // Staking never runs contract code, so fee_limit does not apply.
+        assert!(find_field(&payload, "Max Energy Burn").is_none());
     }
 
     #[test]
@@ -770,6 +1008,7 @@ mod tests {
             "ENERGY"
         );
         assert_eq!(text_value(find_field(&payload, "Lock").unwrap()), "false");
+        assert!(find_field(&payload, "Warning").is_none());
     }
 
     #[test]
@@ -853,6 +1092,10 @@ mod tests {
             text_value(find_field(&payload, "Resource").unwrap()),
             "ENERGY"
         );
+        assert_eq!(
+            text_value(find_field(&payload, "Lockup").unwrap()),
+            "Staked TRX is locked until unstaked, then withdrawable after a 14-day waiting period"
+        );
     }
 
     #[test]
@@ -883,6 +1126,11 @@ mod tests {
             text_value(find_field(&payload, "Resource").unwrap()),
             "BANDWIDTH"
         );
+        assert_eq!(
+            text_value(find_field(&payload, "Lockup").unwrap()),
+            "Withdrawable after a 14-day waiting period; votes are revoked if the remaining TRON \
+             Power no longer covers them"
+        );
     }
 
     #[test]
@@ -906,6 +1154,7 @@ mod tests {
             "WithdrawExpireUnfreeze (Claim Unfrozen)"
         );
         assert!(address_value(find_field(&payload, "Owner").unwrap()).starts_with('T'));
+        assert!(find_field(&payload, "Effect").is_some());
     }
 
     #[test]
@@ -965,7 +1214,16 @@ mod tests {
         assert_eq!(text_value(find_field(&payload, "Lock").unwrap()), "true");
         assert_eq!(
             text_value(find_field(&payload, "Lock Period").unwrap()),
-            "86400"
+            "3 days (86400 blocks)"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Effect").unwrap()),
+            "The receiver can use the ENERGY of 1.5 TRX staked by the owner; the TRX stays with \
+             the owner"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Warning").unwrap()),
+            "Locked delegation: the owner cannot undelegate until the lock period ends"
         );
         assert_eq!(
             text_value(find_field(&payload, "Resource").unwrap()),
@@ -1000,6 +1258,10 @@ mod tests {
         assert_eq!(amount, "2");
         // Lock fields are absent on UnDelegate.
         assert!(find_field(&payload, "Lock").is_none());
+        assert_eq!(
+            text_value(find_field(&payload, "Effect").unwrap()),
+            "Takes back the BANDWIDTH delegated to the receiver for the owner's own use"
+        );
     }
 
     #[test]
@@ -1135,6 +1397,141 @@ mod tests {
         );
     }
 
+    #[test]
+    fn vote_witness_names_known_srs_from_metadata() {
+        let sr = receiver_bytes();
+        let bytes = build_vote_witness_bytes(&owner_bytes(), &[(&sr, 10)]);
+        let raw =
+            build_raw_with_contract("type.googleapis.com/protocol.VoteWitnessContract", bytes);
+        let metadata = TronMetadata {
+            witnesses: [(address_to_base58(&sr), "Example SR".to_string())]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let options = VisualSignOptions {
+            metadata: Some(generated::parser::ChainMetadata {
+                metadata: Some(chain_metadata::Metadata::Tron(metadata)),
+            }),
+            ..Default::default()
+        };
+        let payload = to_payload(
+            TronTransactionWrapper::from_string(&encode_hex(&raw)).unwrap(),
+            options,
+        )
+        .unwrap();
+
+        let votes_field = find_field(&payload, "Votes").expect("Votes preview layout");
+        match &preview_layout_expanded(votes_field).fields[0].signable_payload_field {
+            SignablePayloadField::AddressV2 { address_v2, .. } => {
+                assert_eq!(address_v2.address, address_to_base58(&sr));
+                assert_eq!(address_v2.name, "Example SR");
+            }
+            _ => panic!("expected AddressV2"),
+        }
+    }
+
+    fn permission(
+        permission_type: anychain_tron::protocol::Tron::permission::PermissionType,
+        threshold: i64,
+        keys: &[(Vec<u8>, i64)],
+        operations: Vec<u8>,
+    ) -> anychain_tron::protocol::Tron::Permission {
+        anychain_tron::protocol::Tron::Permission {
+            type_: permission_type.into(),
+            threshold,
+            keys: keys
+                .iter()
+                .map(|(address, weight)| anychain_tron::protocol::Tron::Key {
+                    address: address.clone(),
+                    weight: *weight,
+                    ..Default::default()
+                })
+                .collect(),
+            operations,
+            ..Default::default()
+        }
+    }
+
+    fn permission_update_payload(update: AccountPermissionUpdateContract) -> SignablePayload {
+        let raw = build_raw_with_contract(
+            "type.googleapis.com/protocol.AccountPermissionUpdateContract",
+            update.write_to_bytes().unwrap(),
+        );
+        to_payload(
+            TronTransactionWrapper::from_string(&encode_hex(&raw)).unwrap(),
+            VisualSignOptions::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn account_permission_update_warns_and_lists_keys() {
+        use anychain_tron::protocol::Tron::permission::PermissionType;
+
+        let mut operations = vec![0u8; 32];
+        operations[0] = 0b0000_0010; // Transfer
+        let update = AccountPermissionUpdateContract {
+            owner_address: owner_bytes(),
+            owner: MessageField::some(permission(
+                PermissionType::Owner,
+                2,
+                &[(owner_bytes(), 1), (receiver_bytes(), 1)],
+                vec![],
+            )),
+            actives: vec![permission(
+                PermissionType::Active,
+                1,
+                &[(receiver_bytes(), 1)],
+                operations,
+            )],
+            ..Default::default()
+        };
+        let payload = permission_update_payload(update);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Contract Type").unwrap()),
+            "AccountPermissionUpdate (Change Account Control)"
+        );
+        assert!(find_field(&payload, "Warning").is_some());
+        assert!(find_field(&payload, "Owner Key").is_none());
+
+        let owner_permission = find_field(&payload, "Owner Permission").unwrap();
+        assert_eq!(
+            preview_layout_subtitle(owner_permission),
+            "Threshold 2 of total weight 2 across 2 key(s)"
+        );
+        let active = preview_layout_expanded(find_field(&payload, "Active Permission 1").unwrap());
+        let operations = active
+            .fields
+            .iter()
+            .find(|f| field_label(&f.signable_payload_field) == "Operations")
+            .unwrap();
+        assert_eq!(text_value(&operations.signable_payload_field), "Transfer");
+    }
+
+    #[test]
+    fn account_permission_update_flags_removed_owner_key() {
+        use anychain_tron::protocol::Tron::permission::PermissionType;
+
+        let update = AccountPermissionUpdateContract {
+            owner_address: owner_bytes(),
+            owner: MessageField::some(permission(
+                PermissionType::Owner,
+                1,
+                &[(receiver_bytes(), 1)],
+                vec![],
+            )),
+            ..Default::default()
+        };
+        let payload = permission_update_payload(update);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Owner Key").unwrap()),
+            "Removed: the account's own key is not in the new owner permission"
+        );
+    }
+
     #[test]
     fn vote_witness_empty_votes_renders_zero_summary() {
         let owner = owner_bytes();
@@ -1283,6 +1680,15 @@ mod tests {
         assert_eq!(sun_to_trx_string(i64::MAX), "9223372036854.775807");
     }
 
+    #[test]
+    fn scale_decimal_trims_fraction() {
+        assert_eq!(scale_decimal("1500000", 6), "1.5");
+        assert_eq!(scale_decimal("5", 6), "0.000005");
+        assert_eq!(scale_decimal("7000000", 6), "7");
+        assert_eq!(scale_decimal("0", 6), "0");
+        assert_eq!(scale_decimal("42", 0), "42");
+    }
+
     #[test]
     fn resource_label_surfaces_unknown_enum_values() {
         // protobuf wire values outside {0,1,2} must render as UNKNOWN(n), not silently
@@ -1291,6 +1697,307 @@ mod tests {
         assert_eq!(resource_label(unknown), "UNKNOWN(99)");
     }
 
+    fn trc20_calldata(selector: &str, args: &[&str]) -> Vec<u8> {
+        let mut data = hex::decode(selector).unwrap();
+        for arg in args {
+            data.extend(hex::decode(format!("{arg:0>64}")).unwrap());
+        }
+        data
+    }
+
+    fn trigger_payload(contract: Vec<u8>, data: Vec<u8>, call_value: i64) -> SignablePayload {
+        let inner = TriggerSmartContract {
+            owner_address: owner_bytes(),
+            contract_address: contract,
+            call_value,
+            data,
+            ..Default::default()
+        };
+        let raw = build_raw_with_contract(
+            "type.googleapis.com/protocol.TriggerSmartContract",
+            inner.write_to_bytes().unwrap(),
+        );
+        to_payload(
+            TronTransactionWrapper::from_string(&encode_hex(&raw)).unwrap(),
+            VisualSignOptions::default(),
+        )
+        .unwrap()
+    }
+
+    fn transfer_asset_payload(
+        asset_name: &[u8],
+        amount: i64,
+        metadata: Option<TronMetadata>,
+    ) -> SignablePayload {
+        transfer_asset_payload_with_options(
+            asset_name,
+            amount,
+            VisualSignOptions {
+                metadata: metadata.map(|tron| generated::parser::ChainMetadata {
+                    metadata: Some(chain_metadata::Metadata::Tron(tron)),
+                }),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn transfer_asset_payload_with_options(
+        asset_name: &[u8],
+        amount: i64,
+        options: VisualSignOptions,
+    ) -> SignablePayload {
+        let inner = TransferAssetContract {
+            asset_name: asset_name.to_vec(),
+            owner_address: owner_bytes(),
+            to_address: receiver_bytes(),
+            amount,
+            ..Default::default()
+        };
+        let raw = build_raw_with_contract(
+            "type.googleapis.com/protocol.TransferAssetContract",
+            inner.write_to_bytes().unwrap(),
+        );
+        to_payload(
+            TronTransactionWrapper::from_string(&encode_hex(&raw)).unwrap(),
+            options,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn trc10_transfer_of_known_asset_scales_amount() {
+        let payload = transfer_asset_payload(b"1002000", 2_500_000, None);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Contract Type").unwrap()),
+            "TransferAssetContract (TRC-10 Transfer)"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Asset").unwrap()),
+            "BitTorrent Old (ID 1002000)"
+        );
+        let (amount, abbrev) = amount_value(find_field(&payload, "Amount").unwrap());
+        assert_eq!(amount, "2.5");
+        assert_eq!(abbrev, "BTTOLD");
+        assert_eq!(
+            address_value(find_field(&payload, "To").unwrap()),
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"
+        );
+    }
+
+    #[test]
+    fn trc10_transfer_of_unknown_asset_shows_raw_units() {
+        let payload = transfer_asset_payload(b"1000999", 42, None);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Asset").unwrap()),
+            "ID 1000999 (unknown asset)"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Amount").unwrap()),
+            "42 (raw units)"
+        );
+    }
+
+    #[test]
+    fn trc10_metadata_fills_gaps_but_cannot_override_builtin_assets() {
+        let metadata = TronMetadata {
+            assets: [
+                (
+                    "1000999".to_string(),
+                    generated::parser::TronAsset {
+                        name: "Example".to_string(),
+                        symbol: "EXM".to_string(),
+                        precision: 2,
+                    },
+                ),
+                (
+                    "1002000".to_string(),
+                    generated::parser::TronAsset {
+                        name: "Bad".to_string(),
+                        symbol: "BAD".to_string(),
+                        precision: 2,
+                    },
+                ),
+                (
+                    "1000998".to_string(),
+                    generated::parser::TronAsset {
+                        name: "Wide".to_string(),
+                        symbol: "WIDE".to_string(),
+                        precision: 18,
+                    },
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+
+        let payload = transfer_asset_payload(b"1000999", 1234, Some(metadata.clone()));
+        assert_eq!(
+            text_value(find_field(&payload, "Asset").unwrap()),
+            "Example (ID 1000999; symbol and precision from unverified wallet metadata)"
+        );
+        let (amount, abbrev) = amount_value(find_field(&payload, "Amount").unwrap());
+        assert_eq!(amount, "12.34");
+        assert_eq!(abbrev, "EXM");
+
+        // The built-in entry wins over the request's, and is not annotated.
+        let payload = transfer_asset_payload(b"1002000", 1234, Some(metadata.clone()));
+        assert_eq!(
+            text_value(find_field(&payload, "Asset").unwrap()),
+            "BitTorrent Old (ID 1002000)"
+        );
+        let (amount, abbrev) = amount_value(find_field(&payload, "Amount").unwrap());
+        assert_eq!(amount, "0.001234");
+        assert_eq!(abbrev, "BTTOLD");
+
+        // A precision TRC-10 cannot have is not trusted.
+        let payload = transfer_asset_payload(b"1000998", 1234, Some(metadata));
+        assert_eq!(
+            text_value(find_field(&payload, "Amount").unwrap()),
+            "1234 (raw units)"
+        );
+    }
+
+    #[test]
+    fn trc10_metadata_assets_ignored_in_strict_mode() {
+        let metadata = TronMetadata {
+            assets: [(
+                "1000999".to_string(),
+                generated::parser::TronAsset {
+                    name: "Example".to_string(),
+                    symbol: "EXM".to_string(),
+                    precision: 2,
+                },
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        let options = VisualSignOptions {
+            metadata: Some(generated::parser::ChainMetadata {
+                metadata: Some(chain_metadata::Metadata::Tron(metadata)),
+            }),
+            strict_registry: true,
+            ..Default::default()
+        };
+
+        let payload = transfer_asset_payload_with_options(b"1000999", 1234, options);
+        assert_eq!(
+            text_value(find_field(&payload, "Asset").unwrap()),
+            "ID 1000999 (unknown asset)"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Amount").unwrap()),
+            "1234 (raw units)"
+        );
+    }
+
+    #[test]
+    fn trc10_legacy_asset_name_is_shown_verbatim() {
+        let payload = transfer_asset_payload(b"SEED", 7, None);
+        assert_eq!(
+            text_value(find_field(&payload, "Asset").unwrap()),
+            "SEED (legacy asset name)"
+        );
+    }
+
+    #[test]
+    fn trc20_usdt_transfer_scales_amount() {
+        // transfer(owner, 12.5 USDT) on the USDT contract (RECEIVER_HEX is TR7NH...).
+        let data = trc20_calldata("a9059cbb", &[&OWNER_HEX[2..], "bebc20"]);
+        let payload = trigger_payload(receiver_bytes(), data, 0);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Method").unwrap()),
+            "TRC-20 Transfer"
+        );
+        assert_eq!(
+            address_value(find_field(&payload, "To").unwrap()),
+            address_to_base58(&owner_bytes())
+        );
+        let (amount, abbrev) = amount_value(find_field(&payload, "Amount").unwrap());
+        assert_eq!(amount, "12.5");
+        assert_eq!(abbrev, "USDT");
+        assert!(find_field(&payload, "Call Value").is_none());
+    }
+
+    #[test]
+    fn trc20_unlimited_approve_is_flagged() {
+        let data = trc20_calldata("095ea7b3", &[&OWNER_HEX[2..], &"f".repeat(64)]);
+        let payload = trigger_payload(receiver_bytes(), data, 0);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Method").unwrap()),
+            "TRC-20 Approve"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Allowance").unwrap()),
+            "Unlimited"
+        );
+        assert!(find_field(&payload, "Warning").is_some());
+    }
+
+    #[test]
+    fn trc20_unknown_token_shows_raw_units() {
+        let data = trc20_calldata("a9059cbb", &[&RECEIVER_HEX[2..], "3e8"]);
+        let payload = trigger_payload(owner_bytes(), data, 1_000_000);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Amount").unwrap()),
+            "1000 (raw units, unknown token)"
+        );
+        let (call_value, _) = amount_value(find_field(&payload, "Call Value").unwrap());
+        assert_eq!(call_value, "1");
+    }
+
+    #[test]
+    fn trigger_smart_contract_with_unknown_calldata_shows_raw_data() {
+        let payload = trigger_payload(receiver_bytes(), vec![0xde, 0xad, 0xbe, 0xef], 0);
+
+        assert!(find_field(&payload, "Method").is_none());
+        assert_eq!(
+            text_value(find_field(&payload, "Data").unwrap()),
+            "deadbeef"
+        );
+    }
+
+    #[test]
+    fn trigger_smart_contract_shows_max_energy_burn() {
+        let payload = trigger_payload(receiver_bytes(), vec![0xde, 0xad, 0xbe, 0xef], 0);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Max Energy Burn").unwrap()),
+            "Up to 10 TRX once the owner's staked energy is used up"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Valid For").unwrap()),
+            "1 second"
+        );
+    }
+
+    #[test]
+    fn max_energy_burn_without_fee_limit_relies_on_staked_energy() {
+        assert_eq!(
+            max_energy_burn_label(0),
+            "None: the call can only use staked energy and fails if that runs out"
+        );
+    }
+
+    #[test]
+    fn validity_window_skips_unset_or_inverted_times() {
+        assert_eq!(
+            validity_window_label(1_700_000_000_000, 1_700_000_060_000).as_deref(),
+            Some("1 minute")
+        );
+        assert_eq!(validity_window_label(0, 1_700_000_060_000), None);
+        assert_eq!(
+            validity_window_label(1_700_000_060_000, 1_700_000_000_000),
+            None
+        );
+    }
+
     #[test]
     fn transfer_contract_still_uses_address_and_amount_fields() {
         // Regression: the legacy TransferContract path migrated to field_builders,
@@ -1317,4 +2024,300 @@ mod tests {
         assert_eq!(amount, "7");
         assert_eq!(abbrev, "TRX");
     }
+
+    const SUNSWAP_V2_ROUTER_HEX: &str = "416e0617948fe030a7e4970f8389d4ad295f249b7e";
+    const SUNSWAP_V3_ROUTER_HEX: &str = "419bc8fbecba5d240b977b6fd4e9ac25b0012ef843";
+    const WTRX_HEX: &str = "891cdb91d149f23b1a45d9c5ca78a88d0cb44c18";
+
+    #[test]
+    fn sunswap_v2_exact_tokens_for_trx() {
+        // swapExactTokensForETH(1 USDT, min 3.5 TRX, [USDT, WTRX], owner, deadline)
+        let data = trc20_calldata(
+            "18cbafe5",
+            &[
+                "f4240",
+                "3567e0",
+                "a0",
+                &OWNER_HEX[2..],
+                "6553f100",
+                "2",
+                &RECEIVER_HEX[2..],
+                WTRX_HEX,
+            ],
+        );
+        let payload = trigger_payload(hex::decode(SUNSWAP_V2_ROUTER_HEX).unwrap(), data, 0);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Method").unwrap()),
+            "SunSwap V2 Swap (exact input)"
+        );
+        assert_eq!(
+            address_value(find_field(&payload, "Token In").unwrap()),
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Token Out").unwrap()),
+            "TRX"
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Amount In").unwrap()),
+            ("1", "USDT")
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Min Amount Out").unwrap()),
+            ("3.5", "TRX")
+        );
+        assert!(find_field(&payload, "Route").is_none());
+        assert!(find_field(&payload, "Warning").is_none());
+        assert!(
+            text_value(find_field(&payload, "Deadline").unwrap())
+                .starts_with("2023-11-14 22:13:20 UTC")
+        );
+    }
+
+    #[test]
+    fn sunswap_v2_multi_hop_lists_the_route() {
+        // swapExactTokensForETH(1 USDT, min 3.5 TRX, [USDT, unknown token, WTRX], owner, deadline)
+        let data = trc20_calldata(
+            "18cbafe5",
+            &[
+                "f4240",
+                "3567e0",
+                "a0",
+                &OWNER_HEX[2..],
+                "6553f100",
+                "3",
+                &RECEIVER_HEX[2..],
+                &OWNER_HEX[2..],
+                WTRX_HEX,
+            ],
+        );
+        let payload = trigger_payload(hex::decode(SUNSWAP_V2_ROUTER_HEX).unwrap(), data, 0);
+
+        let route = text_value(find_field(&payload, "Route").unwrap());
+        assert!(route.starts_with("USDT -> T"), "got: {route}");
+        assert!(route.ends_with(" -> WTRX"), "got: {route}");
+        assert!(route.is_ascii(), "got: {route}");
+    }
+
+    #[test]
+    fn sunswap_v3_exact_input_single_from_trx_to_other_recipient() {
+        // exactInputSingle(WTRX -> USDT, fee 500, recipient != owner, 10 TRX in, min 2.5 USDT)
+        let data = trc20_calldata(
+            "414bf389",
+            &[
+                WTRX_HEX,
+                &RECEIVER_HEX[2..],
+                "1f4",
+                &RECEIVER_HEX[2..],
+                "6553f100",
+                "989680",
+                "2625a0",
+                "0",
+            ],
+        );
+        let payload = trigger_payload(
+            hex::decode(SUNSWAP_V3_ROUTER_HEX).unwrap(),
+            data,
+            10_000_000,
+        );
+
+        assert_eq!(
+            text_value(find_field(&payload, "Method").unwrap()),
+            "SunSwap V3 Swap (exact input)"
+        );
+        assert_eq!(text_value(find_field(&payload, "Token In").unwrap()), "TRX");
+        assert_eq!(
+            amount_value(find_field(&payload, "Amount In").unwrap()),
+            ("10", "TRX")
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Min Amount Out").unwrap()),
+            ("2.5", "USDT")
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Fee Tier").unwrap()),
+            "0.05%"
+        );
+        assert_eq!(
+            text_value(find_field(&payload, "Warning").unwrap()),
+            "The swap output is sent to an address other than the owner"
+        );
+    }
+
+    #[test]
+    fn sunswap_selector_on_other_contract_is_not_decoded() {
+        let data = trc20_calldata("414bf389", &["0"; 8]);
+        let payload = trigger_payload(owner_bytes(), data, 0);
+        assert!(find_field(&payload, "Method").is_none());
+        assert!(find_field(&payload, "Data").is_some());
+    }
+
+    const JUSTLEND_JTRX_HEX: &str = "412c7c9963111905d29eb8da37d28b0f53a7bb5c28";
+    const JUSTLEND_JUSDT_HEX: &str = "41ea09611b57e89d67fbb33a516eb90508ca95a3e5";
+
+    #[test]
+    fn justlend_supply_usdt() {
+        // mint(1 USDT) on the jUSDT market
+        let data = trc20_calldata("a0712d68", &["f4240"]);
+        let payload = trigger_payload(hex::decode(JUSTLEND_JUSDT_HEX).unwrap(), data, 0);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Method").unwrap()),
+            "JustLend Supply"
+        );
+        assert_eq!(
+            address_value(find_field(&payload, "Market").unwrap()),
+            "TXJgMdjVX5dKiQaUi9QobwNxtSQaFqccvd"
+        );
+        assert_eq!(
+            address_value(find_field(&payload, "Asset").unwrap()),
+            "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"
+        );
+        assert_eq!(
+            amount_value(find_field(&payload, "Amount").unwrap()),
+            ("1", "USDT")
+        );
+        assert!(find_field(&payload, "Warning").is_none());
+    }
+
+    #[test]
+    fn justlend_supply_trx_uses_call_value() {
+        // mint() on the jTRX market, paying 25 TRX
+        let data = trc20_calldata("1249c58b", &[]);
+        let payload = trigger_payload(hex::decode(JUSTLEND_JTRX_HEX).unwrap(), data, 25_000_000);
+
+        assert_eq!(
+            text_value(find_field(&payload, "Method").unwrap()),
+            "JustLend Supply"
+        );
+        assert_eq!(text_value(find_field(&payload, "Asset").unwrap()), "TRX");
+        assert_eq!(
+            amount_value(find_field(&payload, "Amount").unwrap()),
+            ("25", "TRX")
+        );
+    }
+
+    #[test]
+    fn justlend_borrow_warns_and_full_repay_is_named() {
+        let borrow = trigger_payload(
+            hex::decode(JUSTLEND_JUSDT_HEX).unwrap(),
+            trc20_calldata("c5ebeaec", &["2faf080"]),
+            0,
+        );
+        assert_eq!(
+            amount_value(find_field(&borrow, "Amount").unwrap()),
+            ("50", "USDT")
+        );
+        assert!(text_value(find_field(&borrow, "Warning").unwrap()).contains("liquidated"));
+
+        let max = "f".repeat(64);
+        let repay = trigger_payload(
+            hex::decode(JUSTLEND_JUSDT_HEX).unwrap(),
+            trc20_calldata("2608f818", &[&OWNER_HEX[2..], &max]),
+            0,
+        );
+        assert_eq!(
+            text_value(find_field(&repay, "Method").unwrap()),
+            "JustLend Repay on Behalf"
+        );
+        assert_eq!(
+            address_value(find_field(&repay, "Borrower").unwrap()),
+            "TKfvnGvr7YMTBYz8xfx1XfgkWk2JZZgj4s"
+        );
+        assert_eq!(
+            text_value(find_field(&repay, "Amount").unwrap()),
+            "Full outstanding debt"
+        );
+    }
+
+    fn tron_metadata_options(metadata: TronMetadata) -> VisualSignOptions {
+        VisualSignOptions {
+            metadata: Some(generated::parser::ChainMetadata {
+                metadata: Some(chain_metadata::Metadata::Tron(metadata)),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn trc20_metadata_tokens_scale_unknown_token_amounts() {
+        // OWNER_HEX stands in for a token contract the parser does not know.
+        let metadata = TronMetadata {
+            tokens: [(
+                "TKfvnGvr7YMTBYz8xfx1XfgkWk2JZZgj4s".to_string(),
+                generated::parser::TronToken {
+                    symbol: "DEMO".to_string(),
+                    decimals: 3,
+                },
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        let inner = TriggerSmartContract {
+            owner_address: owner_bytes(),
+            contract_address: owner_bytes(),
+            data: trc20_calldata("a9059cbb", &[&RECEIVER_HEX[2..], "3e8"]),
+            ..Default::default()
+        };
+        let raw = build_raw_with_contract(
+            "type.googleapis.com/protocol.TriggerSmartContract",
+            inner.write_to_bytes().unwrap(),
+        );
+        let payload = to_payload(
+            TronTransactionWrapper::from_string(&encode_hex(&raw)).unwrap(),
+            tron_metadata_options(metadata),
+        )
+        .unwrap();
+
+        let (amount, abbrev) = amount_value(find_field(&payload, "Amount").unwrap());
+        assert_eq!(amount, "1");
+        assert_eq!(abbrev, "DEMO");
+    }
+
+    #[test]
+    fn address_book_names_transfer_recipient() {
+        let metadata = TronMetadata {
+            address_book: [(
+                "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t".to_string(),
+                generated::parser::AddressLabel {
+                    name: "Exchange Deposit".to_string(),
+                    badge: Some("External".to_string()),
+                },
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        let inner = TransferContract {
+            owner_address: owner_bytes(),
+            to_address: receiver_bytes(),
+            amount: 7_000_000,
+            ..Default::default()
+        };
+        let raw = build_raw_with_contract(
+            "type.googleapis.com/protocol.TransferContract",
+            inner.write_to_bytes().unwrap(),
+        );
+        let payload = to_payload(
+            TronTransactionWrapper::from_string(&encode_hex(&raw)).unwrap(),
+            tron_metadata_options(metadata),
+        )
+        .unwrap();
+
+        match find_field(&payload, "To").unwrap() {
+            SignablePayloadField::AddressV2 { address_v2, .. } => {
+                assert_eq!(address_v2.name, "Exchange Deposit");
+                assert_eq!(address_v2.badge_text.as_deref(), Some("External"));
+            }
+            _ => panic!("expected AddressV2"),
+        }
+        match find_field(&payload, "From").unwrap() {
+            SignablePayloadField::AddressV2 { address_v2, .. } => {
+                assert!(address_v2.name.is_empty());
+            }
+            _ => panic!("expected AddressV2"),
+        }
+    }
 }
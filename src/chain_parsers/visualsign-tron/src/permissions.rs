@@ -0,0 +1,208 @@
+// AccountPermissionUpdateContract replaces the account's multi-signature setup: the owner
+// permission (full control, including further permission updates), the optional witness
+// permission (block production for SRs) and up to eight active permissions, each limited to
+// the contract types set in its `operations` bitmask. A signer who approves the wrong keys
+// or thresholds hands the account to someone else, which makes this the most common Tron
+// account-takeover vector.
+
+use anychain_tron::protocol::Tron::Permission;
+use anychain_tron::protocol::account_contract::AccountPermissionUpdateContract;
+use visualsign::AnnotatedPayloadField;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{create_preview_layout, create_text_field};
+use visualsign::registry::LayeredRegistry;
+
+use crate::registry::TronRegistry;
+use crate::{address_field, address_to_base58, labeled_address_field};
+
+// Contract type IDs from `Transaction.Contract.ContractType`, which index the bits of an
+// active permission's `operations` bitmask.
+const CONTRACT_TYPES: &[(usize, &str)] = &[
+    (0, "AccountCreate"),
+    (1, "Transfer"),
+    (2, "TransferAsset"),
+    (3, "VoteAsset"),
+    (4, "VoteWitness"),
+    (5, "WitnessCreate"),
+    (6, "AssetIssue"),
+    (8, "WitnessUpdate"),
+    (9, "ParticipateAssetIssue"),
+    (10, "AccountUpdate"),
+    (11, "FreezeBalance"),
+    (12, "UnfreezeBalance"),
+    (13, "WithdrawBalance"),
+    (14, "UnfreezeAsset"),
+    (15, "UpdateAsset"),
+    (16, "ProposalCreate"),
+    (17, "ProposalApprove"),
+    (18, "ProposalDelete"),
+    (19, "SetAccountId"),
+    (20, "CustomContract"),
+    (30, "CreateSmartContract"),
+    (31, "TriggerSmartContract"),
+    (32, "GetContract"),
+    (33, "UpdateSetting"),
+    (41, "ExchangeCreate"),
+    (42, "ExchangeInject"),
+    (43, "ExchangeWithdraw"),
+    (44, "ExchangeTransaction"),
+    (45, "UpdateEnergyLimit"),
+    (46, "AccountPermissionUpdate"),
+    (48, "ClearABI"),
+    (49, "UpdateBrokerage"),
+    (51, "ShieldedTransfer"),
+    (52, "MarketSellAsset"),
+    (53, "MarketCancelOrder"),
+    (54, "FreezeBalanceV2"),
+    (55, "UnfreezeBalanceV2"),
+    (56, "WithdrawExpireUnfreeze"),
+    (57, "DelegateResource"),
+    (58, "UnDelegateResource"),
+    (59, "CancelAllUnfreezeV2"),
+];
+
+/// Fields for an AccountPermissionUpdateContract, starting with the takeover warning.
+pub fn permission_update_fields(
+    update: &AccountPermissionUpdateContract,
+    registry: &LayeredRegistry<TronRegistry>,
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let owner_address = address_to_base58(&update.owner_address);
+
+    let mut fields = vec![
+        create_text_field(
+            "Warning",
+            "Changes who can sign for this account. Approving keys you do not control hands \
+             over the account and everything in it",
+        )?,
+        address_field("Owner", &update.owner_address, registry)?,
+    ];
+
+    match update.owner.as_ref() {
+        Some(owner) => {
+            let keeps_own_key = owner
+                .keys
+                .iter()
+                .any(|key| address_to_base58(&key.address) == owner_address);
+            if !keeps_own_key {
+                fields.push(create_text_field(
+                    "Owner Key",
+                    "Removed: the account's own key is not in the new owner permission",
+                )?);
+            }
+            fields.push(permission_field(
+                "Owner Permission",
+                owner,
+                false,
+                registry,
+            )?);
+        }
+        None => fields.push(create_text_field("Owner Permission", "<missing>")?),
+    }
+    if let Some(witness) = update.witness.as_ref() {
+        fields.push(permission_field(
+            "Witness Permission",
+            witness,
+            false,
+            registry,
+        )?);
+    }
+    for (index, active) in update.actives.iter().enumerate() {
+        fields.push(permission_field(
+            &format!("Active Permission {}", index + 1),
+            active,
+            true,
+            registry,
+        )?);
+    }
+    Ok(fields)
+}
+
+fn permission_field(
+    label: &str,
+    permission: &Permission,
+    show_operations: bool,
+    registry: &LayeredRegistry<TronRegistry>,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let total_weight = permission
+        .keys
+        .iter()
+        .map(|key| key.weight)
+        .fold(0i64, i64::saturating_add);
+
+    let mut fields = Vec::new();
+    if !permission.permission_name.is_empty() {
+        fields.push(create_text_field("Name", &permission.permission_name)?);
+    }
+    fields.push(create_text_field(
+        "Threshold",
+        &permission.threshold.to_string(),
+    )?);
+    for (index, key) in permission.keys.iter().enumerate() {
+        fields.push(labeled_address_field(
+            &format!("Key {}", index + 1),
+            &address_to_base58(&key.address),
+            None,
+            Some(&format!("Weight {}", key.weight)),
+            registry,
+        )?);
+    }
+    if show_operations {
+        fields.push(create_text_field(
+            "Operations",
+            &operations_label(&permission.operations),
+        )?);
+    }
+
+    let subtitle = format!(
+        "Threshold {} of total weight {total_weight} across {} key(s)",
+        permission.threshold,
+        permission.keys.len()
+    );
+    Ok(create_preview_layout(label, subtitle, fields))
+}
+
+// Lists the contract types an active permission may sign. Bits that do not name a known
+// contract type are listed by number so nothing the permission allows goes unshown.
+fn operations_label(operations: &[u8]) -> String {
+    let allowed: Vec<String> = operations
+        .iter()
+        .enumerate()
+        .flat_map(|(byte_index, byte)| {
+            (0..8)
+                .filter(move |bit| byte & (1u8 << bit) != 0)
+                .map(move |bit| byte_index * 8 + bit)
+        })
+        .map(|id| {
+            CONTRACT_TYPES
+                .iter()
+                .find(|(known, _)| *known == id)
+                .map_or_else(|| format!("Unknown({id})"), |(_, name)| (*name).to_string())
+        })
+        .collect();
+
+    if allowed.is_empty() {
+        "None".to_string()
+    } else {
+        allowed.join(", ")
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations_label_names_each_allowed_contract() {
+        // Bits 1 (Transfer), 31 (TriggerSmartContract) and 63 (not assigned).
+        let mut operations = vec![0u8; 32];
+        operations[0] = 0b0000_0010;
+        operations[3] = 0b1000_0000;
+        operations[7] = 0b1000_0000;
+        assert_eq!(
+            operations_label(&operations),
+            "Transfer, TriggerSmartContract, Unknown(63)"
+        );
+        assert_eq!(operations_label(&[0u8; 32]), "None");
+    }
+}
@@ -0,0 +1,221 @@
+// JustLend, Tron's Compound fork. Each market is a jToken contract that takes the supply,
+// withdraw, borrow and repay calls directly, with amounts in the underlying asset's base
+// units; only `redeem` counts jTokens instead. The TRX market takes TRX as the call value
+// through the payable, argument-less variants.
+
+use visualsign::AnnotatedPayloadField;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{create_address_field, create_amount_field, create_text_field};
+
+use super::ContractCall;
+use crate::abi::{self, WORD_LEN, Word, word_to_decimal};
+use crate::{address_field, scale_decimal, sun_to_trx_string, trc20};
+
+// jTokens, like Compound's cTokens, have 8 decimals whatever the underlying asset's.
+const JTOKEN_DECIMALS: u8 = 8;
+const TRX_DECIMALS: u8 = 6;
+
+struct Market {
+    jtoken: &'static str,
+    symbol: &'static str,
+    // Base58check address of the underlying TRC-20, `None` for the TRX market.
+    underlying: Option<&'static str>,
+}
+
+const MARKETS: &[Market] = &[
+    Market {
+        jtoken: "TE2RzoSV3wFK99w6J9UnnZ4vLfXYoxvRwP",
+        symbol: "jTRX",
+        underlying: None,
+    },
+    Market {
+        jtoken: "TXJgMdjVX5dKiQaUi9QobwNxtSQaFqccvd",
+        symbol: "jUSDT",
+        underlying: Some("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"),
+    },
+];
+
+// mint()
+const MINT_TRX: [u8; 4] = [0x12, 0x49, 0xc5, 0x8b];
+// mint(uint256)
+const MINT: [u8; 4] = [0xa0, 0x71, 0x2d, 0x68];
+// redeem(uint256)
+const REDEEM: [u8; 4] = [0xdb, 0x00, 0x6a, 0x75];
+// redeemUnderlying(uint256)
+const REDEEM_UNDERLYING: [u8; 4] = [0x85, 0x2a, 0x12, 0xe3];
+// borrow(uint256)
+const BORROW: [u8; 4] = [0xc5, 0xeb, 0xea, 0xec];
+// repayBorrow()
+const REPAY_TRX: [u8; 4] = [0x4e, 0x4d, 0x9f, 0xea];
+// repayBorrow(uint256)
+const REPAY: [u8; 4] = [0x0e, 0x75, 0x27, 0x02];
+// repayBorrowBehalf(address)
+const REPAY_BEHALF_TRX: [u8; 4] = [0xe5, 0x97, 0x46, 0x19];
+// repayBorrowBehalf(address,uint256)
+const REPAY_BEHALF: [u8; 4] = [0x26, 0x08, 0xf8, 0x18];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Supply,
+    Withdraw,
+    Borrow,
+    Repay,
+}
+
+enum Amount {
+    CallValue(i64),
+    Underlying(Word),
+    JTokens(Word),
+}
+
+struct MarketCall {
+    action: Action,
+    amount: Amount,
+    borrower: Option<Vec<u8>>,
+}
+
+fn parse_call(data: &[u8], call_value: i64) -> Option<MarketCall> {
+    let (selector, args) = data.split_first_chunk::<4>()?;
+    let words = args.len() / WORD_LEN;
+    if args.len() % WORD_LEN != 0 {
+        return None;
+    }
+    let call = |action, amount, borrower| {
+        Some(MarketCall {
+            action,
+            amount,
+            borrower,
+        })
+    };
+    match (*selector, words) {
+        (MINT_TRX, 0) => call(Action::Supply, Amount::CallValue(call_value), None),
+        (MINT, 1) => call(
+            Action::Supply,
+            Amount::Underlying(abi::word(args, 0)?),
+            None,
+        ),
+        (REDEEM, 1) => call(Action::Withdraw, Amount::JTokens(abi::word(args, 0)?), None),
+        (REDEEM_UNDERLYING, 1) => call(
+            Action::Withdraw,
+            Amount::Underlying(abi::word(args, 0)?),
+            None,
+        ),
+        (BORROW, 1) => call(
+            Action::Borrow,
+            Amount::Underlying(abi::word(args, 0)?),
+            None,
+        ),
+        (REPAY_TRX, 0) => call(Action::Repay, Amount::CallValue(call_value), None),
+        (REPAY, 1) => call(Action::Repay, Amount::Underlying(abi::word(args, 0)?), None),
+        (REPAY_BEHALF_TRX, 1) => call(
+            Action::Repay,
+            Amount::CallValue(call_value),
+            Some(abi::address(args, 0)?),
+        ),
+        (REPAY_BEHALF, 2) => call(
+            Action::Repay,
+            Amount::Underlying(abi::word(args, 1)?),
+            Some(abi::address(args, 0)?),
+        ),
+        _ => None,
+    }
+}
+
+/// Decodes a supply, withdraw, borrow or repay call on a JustLend market.
+pub fn decode(
+    call: &ContractCall<'_>,
+) -> Result<Option<Vec<AnnotatedPayloadField>>, VisualSignError> {
+    let Some(market) = MARKETS.iter().find(|m| m.jtoken == call.contract) else {
+        return Ok(None);
+    };
+    let Some(market_call) = parse_call(call.data, call.call_value) else {
+        return Ok(None);
+    };
+
+    let underlying = market.underlying.and_then(|address| {
+        call.registry
+            .lookup_amount_data(|r| r.get_token(address).cloned())
+    });
+    let method = match (market_call.action, &market_call.borrower) {
+        (Action::Supply, _) => "JustLend Supply",
+        (Action::Withdraw, _) => "JustLend Withdraw",
+        (Action::Borrow, _) => "JustLend Borrow",
+        (Action::Repay, None) => "JustLend Repay",
+        (Action::Repay, Some(_)) => "JustLend Repay on Behalf",
+    };
+    let mut fields = vec![
+        create_text_field("Method", method)?,
+        create_address_field(
+            "Market",
+            market.jtoken,
+            Some(market.symbol),
+            None,
+            None,
+            None,
+        )?,
+    ];
+    fields.push(match market.underlying {
+        Some(address) => create_address_field(
+            "Asset",
+            address,
+            underlying.as_ref().map(|t| t.symbol.as_str()),
+            None,
+            None,
+            None,
+        )?,
+        None => create_text_field("Asset", "TRX")?,
+    });
+    if let Some(borrower) = &market_call.borrower {
+        fields.push(address_field("Borrower", borrower, call.registry)?);
+    }
+
+    let is_repay = market_call.action == Action::Repay;
+    fields.push(match &market_call.amount {
+        Amount::CallValue(sun) => create_amount_field("Amount", &sun_to_trx_string(*sun), "TRX")?,
+        // Compound treats an all-ones repay amount as "repay everything owed".
+        Amount::Underlying(word) if is_repay && *word == [0xff; WORD_LEN] => {
+            create_text_field("Amount", "Full outstanding debt")?
+        }
+        Amount::Underlying(word) if market.underlying.is_none() => create_amount_field(
+            "Amount",
+            &scale_decimal(&word_to_decimal(word), TRX_DECIMALS),
+            "TRX",
+        )?,
+        Amount::Underlying(word) => trc20::amount_field("Amount", word, underlying.as_ref())?,
+        Amount::JTokens(word) => create_amount_field(
+            "Amount",
+            &scale_decimal(&word_to_decimal(word), JTOKEN_DECIMALS),
+            market.symbol,
+        )?,
+    });
+    if matches!(market_call.amount, Amount::JTokens(_)) {
+        fields.push(create_text_field(
+            "Note",
+            "The underlying amount received depends on the market's exchange rate",
+        )?);
+    }
+    if market_call.action == Action::Borrow {
+        fields.push(create_text_field(
+            "Warning",
+            "Borrowing against supplied collateral: the account can be liquidated if the \
+             collateral's value falls",
+        )?);
+    }
+    Ok(Some(fields))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_call_checks_argument_count() {
+        let mut data = BORROW.to_vec();
+        assert!(parse_call(&data, 0).is_none());
+        data.extend_from_slice(&[0u8; WORD_LEN]);
+        assert!(parse_call(&data, 0).is_some());
+        data.extend_from_slice(&[0u8; WORD_LEN]);
+        assert!(parse_call(&data, 0).is_none());
+    }
+}
@@ -0,0 +1,35 @@
+// Decoders for DeFi protocols reached through TriggerSmartContract. Each one only claims
+// calls to its own deployed contracts and returns `None` for anything else, so the caller
+// can fall back to showing the raw calldata.
+
+pub mod justlend;
+pub mod sunswap;
+
+use visualsign::AnnotatedPayloadField;
+use visualsign::errors::VisualSignError;
+use visualsign::registry::LayeredRegistry;
+
+use crate::registry::TronRegistry;
+
+/// A TriggerSmartContract call as the protocol decoders see it.
+pub struct ContractCall<'a> {
+    /// Base58check address of the account signing the call
+    pub owner: &'a str,
+    /// Base58check address of the contract being called
+    pub contract: &'a str,
+    /// TRX sent with the call, in SUN
+    pub call_value: i64,
+    /// ABI calldata, selector first
+    pub data: &'a [u8],
+    pub registry: &'a LayeredRegistry<TronRegistry>,
+}
+
+/// Decodes `call` with the protocol that owns its contract, if any.
+pub fn decode_protocol_call(
+    call: &ContractCall<'_>,
+) -> Result<Option<Vec<AnnotatedPayloadField>>, VisualSignError> {
+    if let Some(fields) = sunswap::decode(call)? {
+        return Ok(Some(fields));
+    }
+    justlend::decode(call)
+}
@@ -0,0 +1,433 @@
+// SunSwap, Tron's main AMM. The V2 router is a fork of Uniswap's Router02 and the V3 router
+// of Uniswap's SwapRouter, so the calldata uses the same selectors and layouts, with TRX in
+// place of ETH and WTRX in place of WETH. Only calls to the mainnet routers are decoded.
+
+use visualsign::AnnotatedPayloadField;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{create_address_field, create_amount_field, create_text_field};
+
+use super::ContractCall;
+use crate::abi::{self, WORD_LEN, Word, word_to_decimal};
+use crate::{
+    address_field, address_to_base58, render_time_field, scale_decimal, sun_to_trx_string, trc20,
+};
+
+const V2_ROUTER: &str = "TKzxdSv2FZKQrEqkKVgp5DcwEXBEKMg2Ax";
+const V3_ROUTER: &str = "TQAvWQpT9H916GckwWDJNhYZvQMkuRL7PN";
+const WTRX: &str = "TNUC9Qb1rRpS5CbWLmNMxXBjyFoydXjWFR";
+
+// WTRX wraps TRX one to one, so its amounts are SUN.
+const TRX_DECIMALS: u8 = 6;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Exact {
+    Input,
+    Output,
+}
+
+// How a V2 router function moves value: whether one side is native TRX, and which amount
+// is fixed.
+struct V2Function {
+    selector: [u8; 4],
+    exact: Exact,
+    trx_in: bool,
+    trx_out: bool,
+}
+
+const V2_FUNCTIONS: &[V2Function] = &[
+    // swapExactTokensForTokens(uint256,uint256,address[],address,uint256)
+    V2Function {
+        selector: [0x38, 0xed, 0x17, 0x39],
+        exact: Exact::Input,
+        trx_in: false,
+        trx_out: false,
+    },
+    // swapExactTokensForTokensSupportingFeeOnTransferTokens(...)
+    V2Function {
+        selector: [0x5c, 0x11, 0xd7, 0x95],
+        exact: Exact::Input,
+        trx_in: false,
+        trx_out: false,
+    },
+    // swapTokensForExactTokens(uint256,uint256,address[],address,uint256)
+    V2Function {
+        selector: [0x88, 0x03, 0xdb, 0xee],
+        exact: Exact::Output,
+        trx_in: false,
+        trx_out: false,
+    },
+    // swapExactETHForTokens(uint256,address[],address,uint256)
+    V2Function {
+        selector: [0x7f, 0xf3, 0x6a, 0xb5],
+        exact: Exact::Input,
+        trx_in: true,
+        trx_out: false,
+    },
+    // swapExactETHForTokensSupportingFeeOnTransferTokens(...)
+    V2Function {
+        selector: [0xb6, 0xf9, 0xde, 0x95],
+        exact: Exact::Input,
+        trx_in: true,
+        trx_out: false,
+    },
+    // swapETHForExactTokens(uint256,address[],address,uint256)
+    V2Function {
+        selector: [0xfb, 0x3b, 0xdb, 0x41],
+        exact: Exact::Output,
+        trx_in: true,
+        trx_out: false,
+    },
+    // swapExactTokensForETH(uint256,uint256,address[],address,uint256)
+    V2Function {
+        selector: [0x18, 0xcb, 0xaf, 0xe5],
+        exact: Exact::Input,
+        trx_in: false,
+        trx_out: true,
+    },
+    // swapExactTokensForETHSupportingFeeOnTransferTokens(...)
+    V2Function {
+        selector: [0x79, 0x1a, 0xc9, 0x47],
+        exact: Exact::Input,
+        trx_in: false,
+        trx_out: true,
+    },
+    // swapTokensForExactETH(uint256,uint256,address[],address,uint256)
+    V2Function {
+        selector: [0x4a, 0x25, 0xd9, 0x4a],
+        exact: Exact::Output,
+        trx_in: false,
+        trx_out: true,
+    },
+];
+
+// exactInputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))
+const EXACT_INPUT_SINGLE: [u8; 4] = [0x41, 0x4b, 0xf3, 0x89];
+// exactOutputSingle((address,address,uint24,address,uint256,uint256,uint256,uint160))
+const EXACT_OUTPUT_SINGLE: [u8; 4] = [0xdb, 0x3e, 0x21, 0x98];
+// exactInput((bytes,address,uint256,uint256,uint256))
+const EXACT_INPUT: [u8; 4] = [0xc0, 0x4b, 0x8d, 0x59];
+// exactOutput((bytes,address,uint256,uint256,uint256))
+const EXACT_OUTPUT: [u8; 4] = [0xf2, 0x8c, 0x04, 0x98];
+
+// An amount the swap fixes or bounds: either TRX sent as the call value, or a 256-bit ABI
+// word in the token's base units.
+enum SwapAmount {
+    CallValue(i64),
+    Word(Word),
+}
+
+struct Swap {
+    version: &'static str,
+    exact: Exact,
+    // Token addresses from input to output, WTRX standing in for TRX legs.
+    path: Vec<Vec<u8>>,
+    // V3 pool fees in hundredths of a basis point, one per hop.
+    fees: Vec<u32>,
+    trx_in: bool,
+    trx_out: bool,
+    // The fixed side: the input for exact-input swaps, the output for exact-output ones.
+    exact_amount: SwapAmount,
+    // The bound on the other side: minimum output or maximum input.
+    limit_amount: SwapAmount,
+    recipient: Vec<u8>,
+    deadline: Word,
+}
+
+/// Decodes a swap on one of the SunSwap routers.
+pub fn decode(
+    call: &ContractCall<'_>,
+) -> Result<Option<Vec<AnnotatedPayloadField>>, VisualSignError> {
+    let swap = match call.contract {
+        V2_ROUTER => parse_v2(call.data, call.call_value),
+        V3_ROUTER => parse_v3(call.data, call.call_value),
+        _ => None,
+    };
+    match swap {
+        Some(swap) => swap_fields(&swap, call).map(Some),
+        None => Ok(None),
+    }
+}
+
+fn parse_v2(data: &[u8], call_value: i64) -> Option<Swap> {
+    let (selector, args) = data.split_first_chunk::<4>()?;
+    let function = V2_FUNCTIONS.iter().find(|f| f.selector == *selector)?;
+    // TRX-in functions take the fixed or maximum input from the call value instead of an
+    // argument, which shifts the remaining arguments down by one word.
+    let (exact_amount, limit_amount, first_dynamic) = if function.trx_in {
+        let amount = SwapAmount::Word(abi::word(args, 0)?);
+        let value = SwapAmount::CallValue(call_value);
+        match function.exact {
+            Exact::Input => (value, amount, 1),
+            Exact::Output => (amount, value, 1),
+        }
+    } else {
+        (
+            SwapAmount::Word(abi::word(args, 0)?),
+            SwapAmount::Word(abi::word(args, 1)?),
+            2,
+        )
+    };
+    let path = abi::address_array(args, first_dynamic)?;
+    if path.len() < 2 {
+        return None;
+    }
+    Some(Swap {
+        version: "SunSwap V2",
+        exact: function.exact,
+        path,
+        fees: Vec::new(),
+        trx_in: function.trx_in,
+        trx_out: function.trx_out,
+        exact_amount,
+        limit_amount,
+        recipient: abi::address(args, first_dynamic + 1)?,
+        deadline: abi::word(args, first_dynamic + 2)?,
+    })
+}
+
+fn parse_v3(data: &[u8], call_value: i64) -> Option<Swap> {
+    let (selector, args) = data.split_first_chunk::<4>()?;
+    let (exact, path, fees, recipient, deadline, exact_amount, limit_amount) = match *selector {
+        EXACT_INPUT_SINGLE | EXACT_OUTPUT_SINGLE if args.len() == 8 * WORD_LEN => {
+            let exact = if *selector == EXACT_INPUT_SINGLE {
+                Exact::Input
+            } else {
+                Exact::Output
+            };
+            let fee = u32::try_from(abi::u64_word(args, 2)?).ok()?;
+            (
+                exact,
+                vec![abi::address(args, 0)?, abi::address(args, 1)?],
+                vec![fee],
+                abi::address(args, 3)?,
+                abi::word(args, 4)?,
+                abi::word(args, 5)?,
+                abi::word(args, 6)?,
+            )
+        }
+        EXACT_INPUT | EXACT_OUTPUT => {
+            let params = abi::tail(args, 0)?;
+            let (mut path, mut fees) = parse_v3_path(abi::bytes(params, 0)?)?;
+            // exactOutput encodes its path from the output token back to the input.
+            let exact = if *selector == EXACT_INPUT {
+                Exact::Input
+            } else {
+                path.reverse();
+                fees.reverse();
+                Exact::Output
+            };
+            (
+                exact,
+                path,
+                fees,
+                abi::address(params, 1)?,
+                abi::word(params, 2)?,
+                abi::word(params, 3)?,
+                abi::word(params, 4)?,
+            )
+        }
+        _ => return None,
+    };
+
+    // The router wraps TRX sent with the call when the input token is WTRX. The output
+    // stays WTRX unless a separate unwrap follows, which these calls do not do.
+    let trx_in = call_value > 0 && path.first().is_some_and(|t| address_to_base58(t) == WTRX);
+    let (exact_amount, limit_amount) = match (trx_in, exact) {
+        (true, Exact::Input) => (
+            SwapAmount::CallValue(call_value),
+            SwapAmount::Word(limit_amount),
+        ),
+        _ => (
+            SwapAmount::Word(exact_amount),
+            SwapAmount::Word(limit_amount),
+        ),
+    };
+    Some(Swap {
+        version: "SunSwap V3",
+        exact,
+        path,
+        fees,
+        trx_in,
+        trx_out: false,
+        exact_amount,
+        limit_amount,
+        recipient,
+        deadline,
+    })
+}
+
+// A V3 path is token (20 bytes), then fee (3 bytes) and token repeated for each hop.
+fn parse_v3_path(path: &[u8]) -> Option<(Vec<Vec<u8>>, Vec<u32>)> {
+    const ADDRESS_LEN: usize = 20;
+    const HOP_LEN: usize = 3 + ADDRESS_LEN;
+    if path.len() < ADDRESS_LEN + HOP_LEN || (path.len() - ADDRESS_LEN) % HOP_LEN != 0 {
+        return None;
+    }
+    let tron_address = |bytes: &[u8]| {
+        let mut address = vec![crate::TRON_MAINNET_PREFIX];
+        address.extend_from_slice(bytes);
+        address
+    };
+    let mut tokens = vec![tron_address(&path[..ADDRESS_LEN])];
+    let mut fees = Vec::new();
+    for hop in path[ADDRESS_LEN..].chunks_exact(HOP_LEN) {
+        let (fee, token) = hop.split_at(3);
+        fees.push(u32::from_be_bytes([0, fee[0], fee[1], fee[2]]));
+        tokens.push(tron_address(token));
+    }
+    Some((tokens, fees))
+}
+
+fn swap_fields(
+    swap: &Swap,
+    call: &ContractCall<'_>,
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let (Some(token_in), Some(token_out)) = (swap.path.first(), swap.path.last()) else {
+        return Err(VisualSignError::InvariantViolation(
+            "SunSwap path has no tokens".to_string(),
+        ));
+    };
+    let exact_label = match swap.exact {
+        Exact::Input => "exact input",
+        Exact::Output => "exact output",
+    };
+    let (exact_in, exact_out) = match swap.exact {
+        Exact::Input => (&swap.exact_amount, &swap.limit_amount),
+        Exact::Output => (&swap.limit_amount, &swap.exact_amount),
+    };
+    let (in_label, out_label) = match swap.exact {
+        Exact::Input => ("Amount In", "Min Amount Out"),
+        Exact::Output => ("Max Amount In", "Amount Out"),
+    };
+
+    let mut fields = vec![
+        create_text_field("Method", &format!("{} Swap ({exact_label})", swap.version))?,
+        token_field("Token In", token_in, swap.trx_in, call)?,
+        token_field("Token Out", token_out, swap.trx_out, call)?,
+        amount_field(in_label, exact_in, token_in, swap.trx_in, call)?,
+        amount_field(out_label, exact_out, token_out, swap.trx_out, call)?,
+    ];
+    if swap.path.len() > 2 {
+        let route: Vec<String> = swap
+            .path
+            .iter()
+            .map(|token| token_symbol(token, call).unwrap_or_else(|| address_to_base58(token)))
+            .collect();
+        fields.push(create_text_field("Route", &route.join(" -> "))?);
+    }
+    if let [fee] = swap.fees.as_slice() {
+        fields.push(create_text_field(
+            "Fee Tier",
+            &format!("{}%", scale_decimal(&fee.to_string(), 4)),
+        )?);
+    }
+
+    fields.push(address_field("Recipient", &swap.recipient, call.registry)?);
+    if address_to_base58(&swap.recipient) != call.owner {
+        fields.push(create_text_field(
+            "Warning",
+            "The swap output is sent to an address other than the owner",
+        )?);
+    }
+    fields.push(create_text_field(
+        "Deadline",
+        &deadline_label(&swap.deadline),
+    )?);
+    Ok(fields)
+}
+
+fn token_symbol(token: &[u8], call: &ContractCall<'_>) -> Option<String> {
+    let address = address_to_base58(token);
+    call.registry
+        .lookup_amount_data(|r| r.get_token(&address).map(|t| t.symbol.clone()))
+}
+
+fn token_field(
+    label: &str,
+    token: &[u8],
+    is_trx: bool,
+    call: &ContractCall<'_>,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    if is_trx {
+        return create_text_field(label, "TRX");
+    }
+    let symbol = token_symbol(token, call);
+    create_address_field(
+        label,
+        &address_to_base58(token),
+        symbol.as_deref(),
+        None,
+        None,
+        None,
+    )
+}
+
+fn amount_field(
+    label: &str,
+    amount: &SwapAmount,
+    token: &[u8],
+    is_trx: bool,
+    call: &ContractCall<'_>,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    match amount {
+        SwapAmount::CallValue(sun) => create_amount_field(label, &sun_to_trx_string(*sun), "TRX"),
+        SwapAmount::Word(word) if is_trx => create_amount_field(
+            label,
+            &scale_decimal(&word_to_decimal(word), TRX_DECIMALS),
+            "TRX",
+        ),
+        SwapAmount::Word(word) => {
+            let address = address_to_base58(token);
+            let token = call
+                .registry
+                .lookup_amount_data(|r| r.get_token(&address).cloned());
+            trc20::amount_field(label, word, token.as_ref())
+        }
+    }
+}
+
+// Deadlines are Unix seconds. Routers are often called with a far-future deadline, which
+// is shown as the raw value rather than a nonsensical date.
+fn deadline_label(deadline: &Word) -> String {
+    let millis = abi::u64_word(deadline, 0)
+        .and_then(|secs| secs.checked_mul(1000))
+        .and_then(|ms| i64::try_from(ms).ok());
+    match millis {
+        Some(ms) => render_time_field(ms, chrono::Utc::now().timestamp_millis()),
+        None => format!("{} (no practical deadline)", word_to_decimal(deadline)),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_v3_path_splits_tokens_and_fees() {
+        let mut path = vec![0x11; 20];
+        path.extend_from_slice(&[0x00, 0x0b, 0xb8]); // 3000
+        path.extend_from_slice(&[0x22; 20]);
+        path.extend_from_slice(&[0x00, 0x01, 0xf4]); // 500
+        path.extend_from_slice(&[0x33; 20]);
+
+        let (tokens, fees) = parse_v3_path(&path).unwrap();
+        assert_eq!(fees, vec![3000, 500]);
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[2][0], crate::TRON_MAINNET_PREFIX);
+        assert_eq!(&tokens[2][1..], &[0x33; 20]);
+
+        assert!(parse_v3_path(&path[..40]).is_none());
+    }
+
+    #[test]
+    fn deadline_label_keeps_far_future_values_raw() {
+        assert_eq!(
+            deadline_label(&[0xff; WORD_LEN]),
+            format!(
+                "{} (no practical deadline)",
+                word_to_decimal(&[0xff; WORD_LEN])
+            )
+        );
+    }
+}
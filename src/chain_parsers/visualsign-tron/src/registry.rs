@@ -0,0 +1,282 @@
+// Token and address data the Tron converter looks up while rendering. The built-in layer
+// (the well-known TRC-20 tokens) is built once and shared by every request; a request's
+// TronMetadata is loaded into its own layer, which can name addresses and add tokens the
+// built-in layer lacks but never change a built-in token.
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use generated::parser::TronMetadata;
+use visualsign::registry::LayeredRegistry;
+
+use crate::{TRON_ADDRESS_LEN, TRON_MAINNET_PREFIX, address_to_base58};
+
+// A 256-bit amount has at most 78 digits, so more decimals than this would only ever
+// render as a fraction below one base unit.
+const MAX_TOKEN_DECIMALS: u32 = 77;
+
+/// A TRC-20 token's display symbol and decimals.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trc20Token {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+/// Wallet-provided name for an address, from the request's address book.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressLabel {
+    /// Display name, e.g. "Treasury Cold Wallet"
+    pub name: String,
+    /// Short tag shown next to the name
+    pub badge: Option<String>,
+}
+
+/// TRC-20 tokens and address labels keyed by base58check address.
+#[derive(Debug, Clone, Default)]
+pub struct TronRegistry {
+    tokens: HashMap<String, Trc20Token>,
+    address_labels: HashMap<String, AddressLabel>,
+}
+
+impl TronRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The registry with the parser's built-in TRC-20 tokens.
+    pub fn built_in() -> Self {
+        let mut registry = Self::new();
+        for (address, symbol, decimals) in crate::trc20::KNOWN_TOKENS {
+            registry.register_token(
+                address,
+                Trc20Token {
+                    symbol: (*symbol).to_string(),
+                    decimals: *decimals,
+                },
+            );
+        }
+        registry
+    }
+
+    pub fn register_token(&mut self, contract_address: &str, token: Trc20Token) {
+        self.tokens.insert(contract_address.to_string(), token);
+    }
+
+    pub fn get_token(&self, contract_address: &str) -> Option<&Trc20Token> {
+        self.tokens.get(contract_address)
+    }
+
+    pub fn register_address_label(&mut self, address: &str, label: AddressLabel) {
+        self.address_labels.insert(address.to_string(), label);
+    }
+
+    pub fn get_address_label(&self, address: &str) -> Option<&AddressLabel> {
+        self.address_labels.get(address)
+    }
+
+    /// Loads the tokens and address book of a request's TronMetadata.
+    ///
+    /// Keys must be valid base58check mainnet addresses, token symbols and address names
+    /// non-empty, and decimals at most 77; a blank badge is dropped. Tokens the parser
+    /// already knows are rejected, so a request cannot change how their amounts are
+    /// scaled. Valid entries are registered even when others are rejected.
+    ///
+    /// # Returns
+    /// `Ok(())` if every entry loaded, `Err(String)` describing the rejected ones otherwise
+    pub fn load_metadata(&mut self, metadata: &TronMetadata) -> Result<(), String> {
+        let mut errors = Vec::new();
+        for (address, token) in &metadata.tokens {
+            let Some(address) = normalize_address(address) else {
+                errors.push(format!("invalid token address: {address}"));
+                continue;
+            };
+            if is_known_token(&address) {
+                errors.push(format!("cannot override built-in token {address}"));
+                continue;
+            }
+            let symbol = token.symbol.trim();
+            if symbol.is_empty() {
+                errors.push(format!("empty token symbol for {address}"));
+                continue;
+            }
+            let decimals = match u8::try_from(token.decimals) {
+                Ok(decimals) if token.decimals <= MAX_TOKEN_DECIMALS => decimals,
+                _ => {
+                    errors.push(format!(
+                        "out-of-range token decimals {} for {address}",
+                        token.decimals
+                    ));
+                    continue;
+                }
+            };
+            self.register_token(
+                &address,
+                Trc20Token {
+                    symbol: symbol.to_string(),
+                    decimals,
+                },
+            );
+        }
+        for (address, entry) in &metadata.address_book {
+            let Some(address) = normalize_address(address) else {
+                errors.push(format!("invalid address-book address: {address}"));
+                continue;
+            };
+            let name = entry.name.trim();
+            if name.is_empty() {
+                errors.push(format!("empty address-book name for {address}"));
+                continue;
+            }
+            let badge = entry
+                .badge
+                .as_deref()
+                .map(str::trim)
+                .filter(|badge| !badge.is_empty())
+                .map(str::to_string);
+            self.register_address_label(
+                &address,
+                AddressLabel {
+                    name: name.to_string(),
+                    badge,
+                },
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+}
+
+// Round-trips a base58check address so a key with a bad checksum or the wrong prefix never
+// matches, and a valid one matches exactly what `address_to_base58` renders.
+fn normalize_address(address: &str) -> Option<String> {
+    let bytes = bs58::decode(address.trim())
+        .with_check(None)
+        .into_vec()
+        .ok()?;
+    (bytes.len() == TRON_ADDRESS_LEN && bytes[0] == TRON_MAINNET_PREFIX)
+        .then(|| address_to_base58(&bytes))
+}
+
+fn is_known_token(address: &str) -> bool {
+    crate::trc20::KNOWN_TOKENS
+        .iter()
+        .any(|(known, _, _)| *known == address)
+}
+
+fn global_registry() -> Arc<TronRegistry> {
+    static GLOBAL: OnceLock<Arc<TronRegistry>> = OnceLock::new();
+    Arc::clone(GLOBAL.get_or_init(|| Arc::new(TronRegistry::built_in())))
+}
+
+/// The registry for one request: the built-in layer, with the request's metadata (if it
+/// has tokens or an address book) layered on top. In strict mode the request's tokens
+/// are never used to render amounts.
+pub fn layered_registry(
+    metadata: Option<&TronMetadata>,
+    strict: bool,
+) -> LayeredRegistry<TronRegistry> {
+    let metadata = match metadata {
+        Some(metadata) if !metadata.tokens.is_empty() || !metadata.address_book.is_empty() => {
+            metadata
+        }
+        _ => return LayeredRegistry::new(global_registry()),
+    };
+    let mut request_registry = TronRegistry::new();
+    if let Err(errors) = request_registry.load_metadata(metadata) {
+        // Malformed entries are dropped; the rest of the metadata still applies
+        log::warn!("ignoring TronMetadata entries: {errors}");
+    }
+    LayeredRegistry::with_request(global_registry(), request_registry).with_strict(strict)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+    use generated::parser::TronToken;
+
+    const USDT: &str = "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t";
+
+    fn token(symbol: &str, decimals: u32) -> TronToken {
+        TronToken {
+            symbol: symbol.to_string(),
+            decimals,
+        }
+    }
+
+    #[test]
+    fn request_tokens_cannot_override_built_in_and_bad_entries_are_skipped() {
+        const WALLET_TOKEN: &str = "TLa2f6VPqDgRE67v1736s7bJ8Ray5wYjU7";
+        let metadata = TronMetadata {
+            tokens: [
+                (USDT.to_string(), token("USDT0", 8)),
+                // Same address with its checksum broken
+                (
+                    "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6u".to_string(),
+                    token("BAD", 6),
+                ),
+                (WALLET_TOKEN.to_string(), token("WIN", 6)),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        let mut request = TronRegistry::new();
+        let errors = request.load_metadata(&metadata).unwrap_err();
+        assert!(errors.contains("invalid token address"), "{errors}");
+        assert!(
+            errors.contains(&format!("cannot override built-in token {USDT}")),
+            "{errors}"
+        );
+        assert!(request.get_token(USDT).is_none());
+        assert_eq!(
+            request.get_token(WALLET_TOKEN),
+            Some(&Trc20Token {
+                symbol: "WIN".to_string(),
+                decimals: 6,
+            })
+        );
+
+        let layered = LayeredRegistry::with_request(global_registry(), request);
+        assert_eq!(
+            layered.lookup_amount_data(|r| r.get_token(USDT).cloned()),
+            Some(Trc20Token {
+                symbol: "USDT".to_string(),
+                decimals: 6,
+            })
+        );
+    }
+
+    #[test]
+    fn out_of_range_decimals_are_rejected() {
+        let metadata = TronMetadata {
+            tokens: [(
+                "TLa2f6VPqDgRE67v1736s7bJ8Ray5wYjU7".to_string(),
+                token("WIN", 78),
+            )]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        };
+        let errors = TronRegistry::new().load_metadata(&metadata).unwrap_err();
+        assert!(
+            errors.contains("out-of-range token decimals 78"),
+            "{errors}"
+        );
+    }
+
+    #[test]
+    fn layered_registry_without_request_data_uses_built_in_only() {
+        let layered = layered_registry(Some(&TronMetadata::default()), false);
+        assert!(layered.request().is_none());
+        assert_eq!(
+            layered
+                .lookup(|r| r.get_token(USDT).cloned())
+                .map(|token| token.symbol),
+            Some("USDT".to_string())
+        );
+    }
+}
@@ -0,0 +1,118 @@
+// TRC-10 assets are native to the chain and are transferred with TransferAssetContract. Since
+// the allowSameTokenName proposal, `asset_name` carries the asset's numeric ID as ASCII digits
+// (older transactions carried the issuer-chosen name), and `amount` is in base units of the
+// asset's precision. Neither the name nor the precision is in the transaction, so both come
+// from a built-in table that TronMetadata can extend with assets the table doesn't know.
+
+use generated::parser::TronMetadata;
+use visualsign::AnnotatedPayloadField;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{create_amount_field, create_text_field};
+use visualsign::registry::{EntryOrigin, Provenance};
+
+use crate::scale_decimal;
+
+// TRC-10 precision is fixed at issuance and capped at 6 by the protocol.
+const MAX_PRECISION: u32 = 6;
+
+struct Trc10Asset<'a> {
+    name: &'a str,
+    symbol: &'a str,
+    precision: u8,
+}
+
+struct ResolvedAsset<'a> {
+    asset: Trc10Asset<'a>,
+    origin: EntryOrigin,
+}
+
+const KNOWN_ASSETS: &[(&str, Trc10Asset<'static>)] = &[(
+    "1002000",
+    Trc10Asset {
+        name: "BitTorrent Old",
+        symbol: "BTTOLD",
+        precision: 6,
+    },
+)];
+
+/// Built-in assets always win, so a request can't rename BTTOLD or change its precision;
+/// metadata only describes assets the table doesn't know, and not at all in strict mode.
+fn resolve_asset<'a>(
+    asset_id: &str,
+    metadata: Option<&'a TronMetadata>,
+    strict: bool,
+) -> Option<ResolvedAsset<'a>> {
+    if let Some((_, asset)) = KNOWN_ASSETS.iter().find(|(id, _)| *id == asset_id) {
+        return Some(ResolvedAsset {
+            asset: Trc10Asset {
+                name: asset.name,
+                symbol: asset.symbol,
+                precision: asset.precision,
+            },
+            origin: EntryOrigin::built_in(),
+        });
+    }
+    if strict {
+        return None;
+    }
+    let asset = metadata?.assets.get(asset_id)?;
+    u8::try_from(asset.precision)
+        .ok()
+        .filter(|_| asset.precision <= MAX_PRECISION)
+        .map(|precision| ResolvedAsset {
+            asset: Trc10Asset {
+                name: &asset.name,
+                symbol: &asset.symbol,
+                precision,
+            },
+            origin: EntryOrigin::new(Provenance::WalletMetadata),
+        })
+}
+
+/// The "Asset" and "Amount" fields of a TRC-10 transfer. `strict` ignores the assets in
+/// `metadata`, as strict registry mode does for TRC-20 tokens.
+pub fn asset_transfer_fields(
+    asset_name: &[u8],
+    amount: i64,
+    metadata: Option<&TronMetadata>,
+    strict: bool,
+) -> Result<Vec<AnnotatedPayloadField>, VisualSignError> {
+    let is_asset_id = !asset_name.is_empty() && asset_name.iter().all(u8::is_ascii_digit);
+    if !is_asset_id {
+        // A legacy name is whatever the issuer registered, so it is only shown verbatim when
+        // it is plain printable ASCII; either way the amount's precision is unknown.
+        let name = match std::str::from_utf8(asset_name) {
+            Ok(name) if !name.is_empty() && name.bytes().all(|b| b.is_ascii_graphic()) => {
+                name.to_string()
+            }
+            _ => format!("0x{}", hex::encode(asset_name)),
+        };
+        return Ok(vec![
+            create_text_field("Asset", &format!("{name} (legacy asset name)"))?,
+            create_text_field("Amount", &format!("{amount} (raw units)"))?,
+        ]);
+    }
+
+    // Digits only, so this is valid UTF-8.
+    let asset_id = String::from_utf8_lossy(asset_name);
+    match resolve_asset(&asset_id, metadata, strict) {
+        Some(ResolvedAsset { asset, origin }) => {
+            let sign = if amount < 0 { "-" } else { "" };
+            let scaled = scale_decimal(&amount.unsigned_abs().to_string(), asset.precision);
+            // Top-level fields lose their static annotations, so the origin note goes in
+            // the asset text where the signer sees it.
+            let asset_text = match origin.annotation("symbol and precision") {
+                Some(note) => format!("{} (ID {asset_id}; {note})", asset.name),
+                None => format!("{} (ID {asset_id})", asset.name),
+            };
+            Ok(vec![
+                create_text_field("Asset", &asset_text)?,
+                create_amount_field("Amount", &format!("{sign}{scaled}"), asset.symbol)?,
+            ])
+        }
+        None => Ok(vec![
+            create_text_field("Asset", &format!("ID {asset_id} (unknown asset)"))?,
+            create_text_field("Amount", &format!("{amount} (raw units)"))?,
+        ]),
+    }
+}
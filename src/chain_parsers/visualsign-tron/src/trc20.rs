@@ -0,0 +1,167 @@
+// TRC-20 calls carried in a TriggerSmartContract. The calldata is plain Solidity ABI, so
+// `transfer`, `approve` and `transferFrom` decode exactly as their ERC-20 counterparts; the
+// only Tron-specific step is turning each 20-byte ABI address back into a 0x41-prefixed
+// base58check address.
+
+use visualsign::AnnotatedPayloadField;
+use visualsign::errors::VisualSignError;
+use visualsign::field_builders::{create_address_field, create_amount_field, create_text_field};
+use visualsign::registry::LayeredRegistry;
+
+use crate::abi::{self, WORD_LEN, Word, word_to_decimal};
+use crate::registry::{Trc20Token, TronRegistry};
+use crate::{address_field, scale_decimal};
+
+const TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+const APPROVE_SELECTOR: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+const TRANSFER_FROM_SELECTOR: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+
+// Mainnet contracts for the stablecoins that make up most TRC-20 traffic, and wrapped TRX
+// that DEX routes pass through, as (base58check address, symbol, decimals). They seed the
+// registry's built-in layer.
+pub(crate) const KNOWN_TOKENS: &[(&str, &str, u8)] = &[
+    ("TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t", "USDT", 6),
+    ("TEkxiTehnzSmSe2XqrBj4w32RUN966rdz8", "USDC", 6),
+    ("TNUC9Qb1rRpS5CbWLmNMxXBjyFoydXjWFR", "WTRX", 6),
+];
+
+enum Trc20Call {
+    Transfer {
+        to: Vec<u8>,
+        amount: Word,
+    },
+    Approve {
+        spender: Vec<u8>,
+        amount: Word,
+    },
+    TransferFrom {
+        from: Vec<u8>,
+        to: Vec<u8>,
+        amount: Word,
+    },
+}
+
+fn parse_call(data: &[u8]) -> Option<Trc20Call> {
+    let (selector, args) = data.split_first_chunk::<4>()?;
+    let word = |index: usize| abi::word(args, index);
+    // Dirty address padding means this is not the call it claims to be.
+    let address = |index: usize| abi::address(args, index);
+
+    match *selector {
+        TRANSFER_SELECTOR if args.len() == 2 * WORD_LEN => Some(Trc20Call::Transfer {
+            to: address(0)?,
+            amount: word(1)?,
+        }),
+        APPROVE_SELECTOR if args.len() == 2 * WORD_LEN => Some(Trc20Call::Approve {
+            spender: address(0)?,
+            amount: word(1)?,
+        }),
+        TRANSFER_FROM_SELECTOR if args.len() == 3 * WORD_LEN => Some(Trc20Call::TransferFrom {
+            from: address(0)?,
+            to: address(1)?,
+            amount: word(2)?,
+        }),
+        _ => None,
+    }
+}
+
+/// Decodes `data` as a TRC-20 `transfer`, `approve` or `transferFrom` on `contract_address`.
+/// Returns `None` when the calldata is not one of those calls, so the caller can fall back
+/// to showing it raw.
+pub fn decode_trc20_call(
+    contract_address: &str,
+    data: &[u8],
+    registry: &LayeredRegistry<TronRegistry>,
+) -> Result<Option<Vec<AnnotatedPayloadField>>, VisualSignError> {
+    let Some(call) = parse_call(data) else {
+        return Ok(None);
+    };
+    // The decimals scale every amount below, so built-in tokens always win; wallet-supplied
+    // tokens only fill in unknown contracts, and not at all in strict mode.
+    let token = registry.lookup_amount_data(|r| r.get_token(contract_address).cloned());
+    let token_field = create_address_field(
+        "Token",
+        contract_address,
+        token.as_ref().map(|t| t.symbol.as_str()),
+        None,
+        None,
+        None,
+    )?;
+
+    let mut fields = Vec::new();
+    match call {
+        Trc20Call::Transfer { to, amount } => {
+            fields.push(create_text_field("Method", "TRC-20 Transfer")?);
+            fields.push(token_field);
+            fields.push(address_field("To", &to, registry)?);
+            fields.push(amount_field("Amount", &amount, token.as_ref())?);
+        }
+        Trc20Call::TransferFrom { from, to, amount } => {
+            fields.push(create_text_field("Method", "TRC-20 Transfer From")?);
+            fields.push(token_field);
+            fields.push(address_field("From", &from, registry)?);
+            fields.push(address_field("To", &to, registry)?);
+            fields.push(amount_field("Amount", &amount, token.as_ref())?);
+        }
+        Trc20Call::Approve { spender, amount } => {
+            fields.push(create_text_field("Method", "TRC-20 Approve")?);
+            fields.push(token_field);
+            fields.push(address_field("Spender", &spender, registry)?);
+            if amount == [0xff; WORD_LEN] {
+                fields.push(create_text_field("Allowance", "Unlimited")?);
+                fields.push(create_text_field(
+                    "Warning",
+                    "Unlimited approval: the spender can move the full token balance",
+                )?);
+            } else {
+                fields.push(amount_field("Allowance", &amount, token.as_ref())?);
+            }
+        }
+    }
+    Ok(Some(fields))
+}
+
+// Known tokens are scaled by their decimals; for anything else the signer sees the exact
+// on-chain integer, labelled as such, rather than a guess at the precision.
+pub(crate) fn amount_field(
+    label: &str,
+    amount: &Word,
+    token: Option<&Trc20Token>,
+) -> Result<AnnotatedPayloadField, VisualSignError> {
+    let raw = word_to_decimal(amount);
+    match token {
+        Some(token) => {
+            create_amount_field(label, &scale_decimal(&raw, token.decimals), &token.symbol)
+        }
+        None => create_text_field(label, &format!("{raw} (raw units, unknown token)")),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    fn word_from_u128(value: u128) -> Word {
+        let mut word = [0u8; WORD_LEN];
+        word[16..].copy_from_slice(&value.to_be_bytes());
+        word
+    }
+
+    #[test]
+    fn parse_call_rejects_dirty_address_padding() {
+        let mut data = TRANSFER_SELECTOR.to_vec();
+        let mut to = [0u8; WORD_LEN];
+        to[0] = 1;
+        data.extend_from_slice(&to);
+        data.extend_from_slice(&word_from_u128(1));
+        assert!(parse_call(&data).is_none());
+    }
+
+    #[test]
+    fn parse_call_rejects_wrong_length() {
+        let mut data = APPROVE_SELECTOR.to_vec();
+        data.extend_from_slice(&[0u8; WORD_LEN]);
+        assert!(parse_call(&data).is_none());
+    }
+}
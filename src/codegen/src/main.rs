@@ -48,7 +48,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .type_attribute(".parser.Signature", SERDE_DERIVE)
         .type_attribute(".parser.ChainMetadata", SERDE_DERIVE)
         .type_attribute(".parser.EthereumMetadata", SERDE_DERIVE)
+        .type_attribute(".parser.EthereumNetwork", SERDE_DERIVE)
+        .type_attribute(".parser.AddressLabel", SERDE_DERIVE)
+        .type_attribute(".parser.TokenPrice", SERDE_DERIVE)
+        .type_attribute(".parser.EthereumToken", SERDE_DERIVE)
         .type_attribute(".parser.SolanaMetadata", SERDE_DERIVE)
+        .type_attribute(".parser.AddressLookupTable", SERDE_DERIVE)
+        .type_attribute(".parser.SolanaMarket", SERDE_DERIVE)
+        .type_attribute(".parser.SolanaToken", SERDE_DERIVE)
+        .type_attribute(".parser.SuiMetadata", SERDE_DERIVE)
+        .type_attribute(".parser.SuiAccount", SERDE_DERIVE)
+        .type_attribute(".parser.SuiMultisig", SERDE_DERIVE)
+        .type_attribute(".parser.SuiMultisigMember", SERDE_DERIVE)
+        .type_attribute(".parser.SuiZkLogin", SERDE_DERIVE)
+        .type_attribute(".parser.SuiStakedSui", SERDE_DERIVE)
+        .type_attribute(".parser.SuiCoinInfo", SERDE_DERIVE)
+        .type_attribute(".parser.TronMetadata", SERDE_DERIVE)
+        .type_attribute(".parser.TronAsset", SERDE_DERIVE)
+        .type_attribute(".parser.TronToken", SERDE_DERIVE)
+        .type_attribute(".parser.TonMetadata", SERDE_DERIVE)
+        .type_attribute(".parser.TonJetton", SERDE_DERIVE)
+        .type_attribute(".parser.HederaMetadata", SERDE_DERIVE)
         .type_attribute(".parser.Abi", SERDE_DERIVE)
         .type_attribute(".parser.Idl", SERDE_DERIVE)
         .type_attribute(".parser.SignatureMetadata", SERDE_DERIVE)
@@ -58,7 +78,32 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .enum_attribute(".parser.ChainMetadata.metadata", SERDE_ENUM_DERIVE)
         // serde(default) on map fields so callers can omit them when empty
         .field_attribute(".parser.EthereumMetadata.abi_mappings", SERDE_DEFAULT)
+        .field_attribute(
+            ".parser.EthereumMetadata.proxy_implementations",
+            SERDE_DEFAULT,
+        )
+        .field_attribute(".parser.EthereumMetadata.custom_networks", SERDE_DEFAULT)
+        .field_attribute(".parser.EthereumMetadata.address_book", SERDE_DEFAULT)
+        .field_attribute(".parser.EthereumMetadata.token_prices", SERDE_DEFAULT)
+        .field_attribute(".parser.EthereumMetadata.tokens", SERDE_DEFAULT)
+        .field_attribute(".parser.EthereumMetadata.erc4626_vaults", SERDE_DEFAULT)
         .field_attribute(".parser.SolanaMetadata.idl_mappings", SERDE_DEFAULT)
+        .field_attribute(
+            ".parser.SolanaMetadata.address_lookup_tables",
+            SERDE_DEFAULT,
+        )
+        .field_attribute(".parser.SolanaMetadata.markets", SERDE_DEFAULT)
+        .field_attribute(".parser.SolanaMetadata.tokens", SERDE_DEFAULT)
+        .field_attribute(".parser.SuiMetadata.accounts", SERDE_DEFAULT)
+        .field_attribute(".parser.SuiMetadata.validators", SERDE_DEFAULT)
+        .field_attribute(".parser.SuiMetadata.staked_sui", SERDE_DEFAULT)
+        .field_attribute(".parser.SuiMetadata.coins", SERDE_DEFAULT)
+        .field_attribute(".parser.TronMetadata.assets", SERDE_DEFAULT)
+        .field_attribute(".parser.TronMetadata.witnesses", SERDE_DEFAULT)
+        .field_attribute(".parser.TronMetadata.tokens", SERDE_DEFAULT)
+        .field_attribute(".parser.TronMetadata.address_book", SERDE_DEFAULT)
+        .field_attribute(".parser.TonMetadata.jettons", SERDE_DEFAULT)
+        .field_attribute(".parser.HederaMetadata.abi_mappings", SERDE_DEFAULT)
         // Represent the abi_type enum as its string name over JSON
         .field_attribute(".parser.Abi.abi_type", SERDE_ABI_TYPE)
         // BORSH - Used for QOS sha256 checks
@@ -72,8 +117,48 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .enum_attribute(".parser.Metadata", BORSH_ENUM_DISC_ATTR)
         .type_attribute(".parser.SolanaMetadata", BORSH_DERIVE)
         .enum_attribute(".parser.SolanaMetadata", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.AddressLookupTable", BORSH_DERIVE)
+        .enum_attribute(".parser.AddressLookupTable", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.SolanaMarket", BORSH_DERIVE)
+        .enum_attribute(".parser.SolanaMarket", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.SolanaToken", BORSH_DERIVE)
+        .enum_attribute(".parser.SolanaToken", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.SuiMetadata", BORSH_DERIVE)
+        .enum_attribute(".parser.SuiMetadata", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.SuiAccount", BORSH_DERIVE)
+        .enum_attribute(".parser.SuiAccount", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.SuiMultisig", BORSH_DERIVE)
+        .enum_attribute(".parser.SuiMultisig", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.SuiMultisigMember", BORSH_DERIVE)
+        .enum_attribute(".parser.SuiMultisigMember", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.SuiZkLogin", BORSH_DERIVE)
+        .enum_attribute(".parser.SuiZkLogin", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.SuiStakedSui", BORSH_DERIVE)
+        .enum_attribute(".parser.SuiStakedSui", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.SuiCoinInfo", BORSH_DERIVE)
+        .enum_attribute(".parser.SuiCoinInfo", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.TronMetadata", BORSH_DERIVE)
+        .enum_attribute(".parser.TronMetadata", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.TronAsset", BORSH_DERIVE)
+        .enum_attribute(".parser.TronAsset", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.TronToken", BORSH_DERIVE)
+        .enum_attribute(".parser.TronToken", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.TonMetadata", BORSH_DERIVE)
+        .enum_attribute(".parser.TonMetadata", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.TonJetton", BORSH_DERIVE)
+        .enum_attribute(".parser.TonJetton", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.HederaMetadata", BORSH_DERIVE)
+        .enum_attribute(".parser.HederaMetadata", BORSH_ENUM_DISC_ATTR)
         .type_attribute(".parser.EthereumMetadata", BORSH_DERIVE)
         .enum_attribute(".parser.EthereumMetadata", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.EthereumNetwork", BORSH_DERIVE)
+        .enum_attribute(".parser.EthereumNetwork", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.AddressLabel", BORSH_DERIVE)
+        .enum_attribute(".parser.AddressLabel", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.TokenPrice", BORSH_DERIVE)
+        .enum_attribute(".parser.TokenPrice", BORSH_ENUM_DISC_ATTR)
+        .type_attribute(".parser.EthereumToken", BORSH_DERIVE)
+        .enum_attribute(".parser.EthereumToken", BORSH_ENUM_DISC_ATTR)
         .type_attribute(".parser.Abi", BORSH_DERIVE)
         .enum_attribute(".parser.Abi", BORSH_ENUM_DISC_ATTR)
         .type_attribute(".parser.Idl", BORSH_DERIVE)
@@ -6,7 +6,7 @@
 // Test file to verify library documentation examples compile
 // Run with: cargo run -p library_integration_test
 
-use generated::parser::{chain_metadata::Metadata, ChainMetadata, EthereumMetadata};
+use generated::parser::{ChainMetadata, EthereumMetadata, chain_metadata::Metadata};
 use parser_app::registry::create_registry;
 use visualsign::registry::Chain;
 use visualsign::vsptrait::{DeveloperConfig, VisualSignOptions};
@@ -58,10 +58,20 @@ fn main() {
             metadata: Some(Metadata::Ethereum(EthereumMetadata {
                 network_id: Some("ETHEREUM_MAINNET".to_string()),
                 abi_mappings: Default::default(),
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         }),
         developer_config: Some(DeveloperConfig {
             allow_signed_transactions: true,
+            allow_typed_data: false,
+            allow_offchain_messages: false,
+            allow_personal_messages: false,
+            allow_user_operations: false,
         }),
         ..Default::default()
     };
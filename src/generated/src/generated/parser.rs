@@ -58,6 +58,11 @@ pub struct ParseRequest {
     /// emits an empty `intermediate_output` and the signed digest is unchanged.
     #[prost(bool, tag = "4")]
     pub include_intermediate_output: bool,
+    /// Opt-in: when true, amounts are only rendered with symbols and decimals the
+    /// parser knows itself. Token and asset entries supplied in `chain_metadata`
+    /// are ignored and such amounts are shown as raw base units instead.
+    #[prost(bool, tag = "5")]
+    pub strict_registry: bool,
 }
 #[cfg_attr(
     feature = "serde_derive",
@@ -68,7 +73,7 @@ pub struct ParseRequest {
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ChainMetadata {
-    #[prost(oneof = "chain_metadata::Metadata", tags = "1, 2")]
+    #[prost(oneof = "chain_metadata::Metadata", tags = "1, 2, 3, 4, 5, 6")]
     pub metadata: ::core::option::Option<chain_metadata::Metadata>,
 }
 /// Nested message and enum types in `ChainMetadata`.
@@ -88,6 +93,14 @@ pub mod chain_metadata {
         Ethereum(super::EthereumMetadata),
         #[prost(message, tag = "2")]
         Solana(super::SolanaMetadata),
+        #[prost(message, tag = "3")]
+        Sui(super::SuiMetadata),
+        #[prost(message, tag = "4")]
+        Tron(super::TronMetadata),
+        #[prost(message, tag = "5")]
+        Ton(super::TonMetadata),
+        #[prost(message, tag = "6")]
+        Hedera(super::HederaMetadata),
     }
 }
 #[cfg_attr(
@@ -219,6 +232,131 @@ pub struct EthereumMetadata {
         ::prost::alloc::string::String,
         Abi,
     >,
+    /// Map of proxy address to implementation address, both 0x-prefixed. A call to a
+    /// listed proxy is decoded against the implementation's ABI from abi_mappings
+    /// (falling back to the proxy's own), and against the implementation's known
+    /// contract type when the proxy itself is not a known contract.
+    #[prost(btree_map = "string, string", tag = "4")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub proxy_implementations: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Chains the parser has no built-in definition for, such as private networks
+    /// and appchains. Entries for built-in chain ids are ignored.
+    #[prost(message, repeated, tag = "5")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub custom_networks: ::prost::alloc::vec::Vec<EthereumNetwork>,
+    /// Wallet address book: 0x-prefixed address to the name and badge the parser
+    /// shows for it, e.g. naming the destination "Anchorage Cold Wallet 3".
+    #[prost(btree_map = "string, message", tag = "6")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub address_book: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        AddressLabel,
+    >,
+    /// Fiat prices keyed by 0x-prefixed token address; the zero address prices the
+    /// chain's native currency. Only used for informational "≈ $1,530.22"
+    /// annotations on amounts, never for the amounts themselves.
+    #[prost(btree_map = "string, message", tag = "7")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub token_prices: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        TokenPrice,
+    >,
+    /// Map of 0x-prefixed token contract address to its symbol, name and
+    /// decimals. Only fills in tokens the parser doesn't already know; built-in
+    /// entries always win.
+    #[prost(btree_map = "string, message", tag = "8")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub tokens: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        EthereumToken,
+    >,
+    /// Map of 0x-prefixed ERC-4626 vault address to the 0x-prefixed address of its
+    /// underlying asset (what the vault's `asset()` returns), so deposits and
+    /// withdrawals show asset amounts. Only adds vaults the parser doesn't already
+    /// know; addresses of built-in contracts and tokens are rejected.
+    #[prost(btree_map = "string, string", tag = "9")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub erc4626_vaults: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumToken {
+    #[prost(string, tag = "1")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub decimals: u32,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenPrice {
+    /// Decimal price of one whole token (e.g., "1530.22")
+    #[prost(string, tag = "1")]
+    pub price: ::prost::alloc::string::String,
+    /// ISO 4217 currency code (e.g., "USD")
+    #[prost(string, tag = "2")]
+    pub currency: ::prost::alloc::string::String,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddressLabel {
+    /// Display name for the address
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// Short tag shown next to the name (e.g., "Internal")
+    #[prost(string, optional, tag = "2")]
+    pub badge: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// A wallet-supplied EVM network definition. The name is caller-provided, so the
+/// parser always shows it alongside the chain id.
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EthereumNetwork {
+    #[prost(uint64, tag = "1")]
+    pub chain_id: u64,
+    /// Display name (e.g., "Acme Appchain")
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// Native currency ticker (e.g., "ACME")
+    #[prost(string, tag = "3")]
+    pub native_symbol: ::prost::alloc::string::String,
+    /// Native currency decimals, 18 when unset
+    #[prost(uint32, optional, tag = "4")]
+    pub native_decimals: ::core::option::Option<u32>,
+    /// Block explorer base URL
+    #[prost(string, optional, tag = "5")]
+    pub explorer_url: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[cfg_attr(
     feature = "serde_derive",
@@ -242,6 +380,337 @@ pub struct SolanaMetadata {
         ::prost::alloc::string::String,
         Idl,
     >,
+    /// Map of address lookup table address (base58 string) to its contents,
+    /// used to resolve the accounts a v0 transaction loads through that table
+    #[prost(btree_map = "string, message", tag = "4")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub address_lookup_tables: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        AddressLookupTable,
+    >,
+    /// Map of order book market address (base58 string) to the symbols of the
+    /// tokens it trades, used to label order placement instructions
+    #[prost(btree_map = "string, message", tag = "5")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub markets: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        SolanaMarket,
+    >,
+    /// Map of token mint address (base58 string) to its symbol, name and
+    /// decimals, layered over the parser's built-in token list
+    #[prost(btree_map = "string, message", tag = "6")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub tokens: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        SolanaToken,
+    >,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolanaMarket {
+    #[prost(string, tag = "1")]
+    pub base_symbol: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub quote_symbol: ::prost::alloc::string::String,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SolanaToken {
+    #[prost(string, tag = "1")]
+    pub symbol: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub decimals: u32,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddressLookupTable {
+    /// Table entries in on-chain order (base58 strings); transaction lookups
+    /// index into this list
+    #[prost(string, repeated, tag = "1")]
+    pub addresses: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuiMetadata {
+    /// Map of 0x-prefixed Sui address to the kind of account behind it; used to
+    /// tell approvers when the sender is a multisig or zkLogin account
+    #[prost(btree_map = "string, message", tag = "1")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub accounts: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        SuiAccount,
+    >,
+    /// Map of 0x-prefixed validator address to its display name (e.g. "Figment")
+    #[prost(btree_map = "string, string", tag = "2")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub validators: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Map of 0x-prefixed StakedSui object ID to the stake it holds; a withdrawal
+    /// only references the object, so its amount is not in the transaction
+    #[prost(btree_map = "string, message", tag = "3")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub staked_sui: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        SuiStakedSui,
+    >,
+    /// Map of coin type (e.g. "0x2::sui::SUI") to how its amounts are displayed,
+    /// for coins missing from the parser's built-in coin list; entries for
+    /// built-in coins are ignored
+    #[prost(btree_map = "string, message", tag = "4")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub coins: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        SuiCoinInfo,
+    >,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuiAccount {
+    /// Set when the address is a multisig account
+    #[prost(message, optional, tag = "1")]
+    pub multisig: ::core::option::Option<SuiMultisig>,
+    /// Set when the address is a zkLogin account
+    #[prost(message, optional, tag = "2")]
+    pub zk_login: ::core::option::Option<SuiZkLogin>,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuiMultisig {
+    /// Total member weight required to sign
+    #[prost(uint32, tag = "1")]
+    pub threshold: u32,
+    #[prost(message, repeated, tag = "2")]
+    pub members: ::prost::alloc::vec::Vec<SuiMultisigMember>,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuiMultisigMember {
+    /// 0x-prefixed Sui address of the member key
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub weight: u32,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuiZkLogin {
+    /// OpenID provider issuer, as in the JWT `iss` claim
+    #[prost(string, tag = "1")]
+    pub issuer: ::prost::alloc::string::String,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuiStakedSui {
+    /// Staked amount in MIST
+    #[prost(uint64, tag = "1")]
+    pub principal: u64,
+    #[prost(uint64, tag = "2")]
+    pub activation_epoch: u64,
+    /// 0x-prefixed address of the validator the stake is delegated to
+    #[prost(string, tag = "3")]
+    pub validator: ::prost::alloc::string::String,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SuiCoinInfo {
+    #[prost(string, tag = "1")]
+    pub symbol: ::prost::alloc::string::String,
+    /// Number of decimal places between the base unit and one whole coin
+    #[prost(uint32, tag = "2")]
+    pub decimals: u32,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TronMetadata {
+    /// Map of TRC-10 asset ID (e.g. "1002000") to how its transfers are
+    /// displayed, for assets missing from the parser's built-in asset list;
+    /// entries for built-in assets are ignored
+    #[prost(btree_map = "string, message", tag = "1")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub assets: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        TronAsset,
+    >,
+    /// Map of base58 super representative address to its display name
+    /// (e.g. "Binance Staking")
+    #[prost(btree_map = "string, string", tag = "2")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub witnesses: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    /// Map of base58 TRC-20 contract address to its symbol and decimals, for
+    /// contracts missing from the parser's built-in token list; entries for
+    /// built-in tokens are rejected
+    #[prost(btree_map = "string, message", tag = "3")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub tokens: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        TronToken,
+    >,
+    /// Wallet address book: base58 address to the name and badge the parser
+    /// shows for it
+    #[prost(btree_map = "string, message", tag = "4")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub address_book: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        AddressLabel,
+    >,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TronAsset {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub symbol: ::prost::alloc::string::String,
+    /// Number of decimal places between the base unit and one whole token
+    #[prost(uint32, tag = "3")]
+    pub precision: u32,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TronToken {
+    #[prost(string, tag = "1")]
+    pub symbol: ::prost::alloc::string::String,
+    /// Number of decimal places between the base unit and one whole token
+    #[prost(uint32, tag = "2")]
+    pub decimals: u32,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TonMetadata {
+    /// Map of jetton wallet address (raw "0:<hex>" or user-friendly form) to
+    /// the jetton it holds. A jetton transfer is addressed to the sender's own
+    /// jetton wallet, so that address, not the jetton master, is the key
+    #[prost(btree_map = "string, message", tag = "1")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub jettons: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        TonJetton,
+    >,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TonJetton {
+    #[prost(string, tag = "1")]
+    pub symbol: ::prost::alloc::string::String,
+    /// Number of decimal places between the base unit and one whole token
+    #[prost(uint32, tag = "2")]
+    pub decimals: u32,
+}
+#[cfg_attr(
+    feature = "serde_derive",
+    derive(::serde::Serialize, ::serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[derive(borsh::BorshSerialize, borsh::BorshDeserialize)]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HederaMetadata {
+    /// Map of contract to the ABI of its functions, used to decode the function
+    /// parameters of a ContractCall. Keys are contract IDs ("0.0.1234") or
+    /// 0x-prefixed EVM addresses
+    #[prost(btree_map = "string, message", tag = "1")]
+    #[cfg_attr(feature = "serde_derive", serde(default))]
+    pub abi_mappings: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        Abi,
+    >,
 }
 #[cfg_attr(
     feature = "serde_derive",
@@ -303,6 +772,13 @@ pub enum Chain {
     Solana = 3,
     Sui = 4,
     Tron = 5,
+    Stellar = 6,
+    Ton = 7,
+    Avalanche = 8,
+    Hedera = 9,
+    Tezos = 10,
+    Litecoin = 11,
+    Dogecoin = 12,
     /// Custom for extensibility
     Custom = 999,
 }
@@ -319,6 +795,13 @@ impl Chain {
             Chain::Solana => "CHAIN_SOLANA",
             Chain::Sui => "CHAIN_SUI",
             Chain::Tron => "CHAIN_TRON",
+            Chain::Stellar => "CHAIN_STELLAR",
+            Chain::Ton => "CHAIN_TON",
+            Chain::Avalanche => "CHAIN_AVALANCHE",
+            Chain::Hedera => "CHAIN_HEDERA",
+            Chain::Tezos => "CHAIN_TEZOS",
+            Chain::Litecoin => "CHAIN_LITECOIN",
+            Chain::Dogecoin => "CHAIN_DOGECOIN",
             Chain::Custom => "CHAIN_CUSTOM",
         }
     }
@@ -331,6 +814,13 @@ impl Chain {
             "CHAIN_SOLANA" => Some(Self::Solana),
             "CHAIN_SUI" => Some(Self::Sui),
             "CHAIN_TRON" => Some(Self::Tron),
+            "CHAIN_STELLAR" => Some(Self::Stellar),
+            "CHAIN_TON" => Some(Self::Ton),
+            "CHAIN_AVALANCHE" => Some(Self::Avalanche),
+            "CHAIN_HEDERA" => Some(Self::Hedera),
+            "CHAIN_TEZOS" => Some(Self::Tezos),
+            "CHAIN_LITECOIN" => Some(Self::Litecoin),
+            "CHAIN_DOGECOIN" => Some(Self::Dogecoin),
             "CHAIN_CUSTOM" => Some(Self::Custom),
             _ => None,
         }
@@ -93,6 +93,7 @@ async fn parser_e2e() {
     async fn test(test_args: TestArgs) {
         let parse_request = ParseRequest {
             include_intermediate_output: false,
+            strict_registry: false,
             unsigned_payload: "unsignedpayload".to_string(),
             chain: Chain::Unspecified as i32,
             chain_metadata: None,
@@ -134,6 +135,7 @@ async fn propagates_grpc_errors() {
     async fn test(test_args: TestArgs) {
         let parse_request = ParseRequest {
             include_intermediate_output: false,
+            strict_registry: false,
             unsigned_payload: "no-no-that-is-not-valid-base64".to_string(),
             chain: Chain::Ethereum as i32,
             chain_metadata: None,
@@ -210,6 +212,7 @@ async fn parser_solana_native_transfer_e2e() {
         tracing::debug!("Solana transaction: {}", solana_tx);
         let parse_request = ParseRequest {
             include_intermediate_output: false,
+            strict_registry: false,
             unsigned_payload: solana_tx,
             chain: Chain::Solana as i32,
             chain_metadata: None,
@@ -236,6 +239,30 @@ async fn parser_solana_native_transfer_e2e() {
                     },
                     "Type": "text_v2"
                 },
+                {
+                    "FallbackText": "8jSCrV9xWkmMRSyf6xH3phL7SretagdqP3LRqkUYUp73",
+                    "Label": "Fee Payer",
+                    "TextV2": {
+                        "Text": "8jSCrV9xWkmMRSyf6xH3phL7SretagdqP3LRqkUYUp73"
+                    },
+                    "Type": "text_v2"
+                },
+                {
+                    "FallbackText": "2",
+                    "Label": "Required Signatures",
+                    "TextV2": {
+                        "Text": "2"
+                    },
+                    "Type": "text_v2"
+                },
+                {
+                    "FallbackText": "8jSCrV9xWkmMRSyf6xH3phL7SretagdqP3LRqkUYUp73, HdD2N8HDzNEM6vwAq5mBLiUbgy1P9wyJfbASt93ndDsD",
+                    "Label": "Writable Signers",
+                    "TextV2": {
+                        "Text": "8jSCrV9xWkmMRSyf6xH3phL7SretagdqP3LRqkUYUp73, HdD2N8HDzNEM6vwAq5mBLiUbgy1P9wyJfbASt93ndDsD"
+                    },
+                    "Type": "text_v2"
+                },
                 {
                     "FallbackText": "Transfer 1: From HdD2N8HDzNEM6vwAq5mBLiUbgy1P9wyJfbASt93ndDsD To 8jSCrV9xWkmMRSyf6xH3phL7SretagdqP3LRqkUYUp73 For 1000000000",
                     "Label": "Transfer 1",
@@ -251,10 +278,10 @@ async fn parser_solana_native_transfer_e2e() {
                         "Condensed": {
                             "Fields": [
                                 {
-                                    "FallbackText": "Transfer: 1000000000 lamports",
+                                    "FallbackText": "Transfer: 1 SOL",
                                     "Label": "Instruction",
                                     "TextV2": {
-                                        "Text": "Transfer: 1000000000 lamports"
+                                        "Text": "Transfer: 1 SOL"
                                     },
                                     "Type": "text_v2"
                                 }
@@ -270,10 +297,26 @@ async fn parser_solana_native_transfer_e2e() {
                                     },
                                     "Type": "text_v2"
                                 },
+                                {
+                                    "FallbackText": "HdD2N8HDzNEM6vwAq5mBLiUbgy1P9wyJfbASt93ndDsD",
+                                    "Label": "From",
+                                    "TextV2": {
+                                        "Text": "HdD2N8HDzNEM6vwAq5mBLiUbgy1P9wyJfbASt93ndDsD"
+                                    },
+                                    "Type": "text_v2"
+                                },
+                                {
+                                    "FallbackText": "8jSCrV9xWkmMRSyf6xH3phL7SretagdqP3LRqkUYUp73",
+                                    "Label": "To",
+                                    "TextV2": {
+                                        "Text": "8jSCrV9xWkmMRSyf6xH3phL7SretagdqP3LRqkUYUp73"
+                                    },
+                                    "Type": "text_v2"
+                                },
                                 {
                                     "AmountV2": {
-                                        "Abbreviation": "lamports",
-                                        "Amount": "1000000000"
+                                        "Abbreviation": "SOL",
+                                        "Amount": "1"
                                     },
                                     "FallbackText": "1 SOL",
                                     "Label": "Transfer Amount",
@@ -293,7 +336,7 @@ async fn parser_solana_native_transfer_e2e() {
                             "Text": ""
                         },
                         "Title": {
-                            "Text": "Transfer: 1000000000 lamports"
+                            "Text": "Transfer: 1 SOL"
                         }
                     },
                     "Type": "preview_layout"
@@ -403,6 +446,7 @@ async fn parser_ethereum_native_transfer_e2e() {
 
         let parse_request = ParseRequest {
             include_intermediate_output: false,
+            strict_registry: false,
             unsigned_payload: ethereum_tx_hex.to_string(),
             chain: Chain::Ethereum as i32,
             chain_metadata: None,
@@ -462,6 +506,14 @@ async fn parser_ethereum_native_transfer_e2e() {
             },
             "Type": "text_v2"
           },
+          {
+            "FallbackText": "0.00042",
+            "Label": "Max Network Fee",
+            "AmountV2": {
+              "Amount": "0.00042"
+            },
+            "Type": "amount_v2"
+          },
           {
             "FallbackText": "0",
             "Label": "Nonce",
@@ -513,6 +565,7 @@ async fn parser_charset_validation_all_chains() {
         for (chain, transaction, description) in test_cases {
             let parse_request = ParseRequest {
                 include_intermediate_output: false,
+                strict_registry: false,
                 unsigned_payload: transaction.to_string(),
                 chain: chain as i32,
                 chain_metadata: None,
@@ -571,6 +624,7 @@ async fn parser_sui_native_transfer_e2e() {
 
         let parse_request = ParseRequest {
             include_intermediate_output: false,
+            strict_registry: false,
             unsigned_payload: sui_tx_b64.to_string(),
             chain: Chain::Sui as i32,
             chain_metadata: None,
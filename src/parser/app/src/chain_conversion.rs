@@ -5,10 +5,18 @@ use generated::parser::Chain as ProtoChain;
 
 pub(crate) fn proto_to_registry(proto: ProtoChain) -> RegistryChain {
     match proto {
+        ProtoChain::Bitcoin => RegistryChain::Bitcoin,
         ProtoChain::Solana => RegistryChain::Solana,
         ProtoChain::Ethereum => RegistryChain::Ethereum,
         ProtoChain::Sui => RegistryChain::Sui,
         ProtoChain::Tron => RegistryChain::Tron,
+        ProtoChain::Stellar => RegistryChain::Stellar,
+        ProtoChain::Ton => RegistryChain::Ton,
+        ProtoChain::Avalanche => RegistryChain::Avalanche,
+        ProtoChain::Hedera => RegistryChain::Hedera,
+        ProtoChain::Tezos => RegistryChain::Tezos,
+        ProtoChain::Litecoin => RegistryChain::Litecoin,
+        ProtoChain::Dogecoin => RegistryChain::Dogecoin,
         ProtoChain::Unspecified => RegistryChain::Unspecified,
         _ => RegistryChain::Custom("custom_unknown".into()),
     }
@@ -21,6 +29,7 @@ mod tests {
     pub(crate) fn registry_to_proto(registry: &RegistryChain) -> ProtoChain {
         match registry {
             RegistryChain::Unspecified => ProtoChain::Unspecified,
+            RegistryChain::Bitcoin => ProtoChain::Bitcoin,
             RegistryChain::Solana => ProtoChain::Solana,
             RegistryChain::Ethereum => ProtoChain::Ethereum,
             RegistryChain::Sui => ProtoChain::Sui,
@@ -32,6 +41,7 @@ mod tests {
     fn test_conversions() {
         // Test supported chains round-trip
         for (proto, registry) in [
+            (ProtoChain::Bitcoin, RegistryChain::Bitcoin),
             (ProtoChain::Solana, RegistryChain::Solana),
             (ProtoChain::Ethereum, RegistryChain::Ethereum),
             (ProtoChain::Sui, RegistryChain::Sui),
@@ -41,10 +51,7 @@ mod tests {
         }
 
         // Test unsupported map to unspecified
-        assert_eq!(
-            registry_to_proto(&RegistryChain::Bitcoin),
-            ProtoChain::Custom
-        );
+        assert_eq!(registry_to_proto(&RegistryChain::Aptos), ProtoChain::Custom);
         assert_eq!(
             proto_to_registry(ProtoChain::Unspecified),
             RegistryChain::Unspecified,
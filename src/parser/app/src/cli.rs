@@ -10,9 +10,11 @@ use qos_core::{
     handles::EphemeralKeyHandle,
     parser::{GetParserForOptions, OptionsParser, Parser, Token},
 };
+use visualsign::vsptrait::DeveloperConfig;
 
 const HOST_IP: &str = "host-ip";
 const HOST_PORT: &str = "host-port";
+const ALLOW_TYPED_DATA: &str = "allow-typed-data";
 
 /// CLI options for starting up the app server.
 #[derive(Default, Clone, Debug, PartialEq)]
@@ -52,6 +54,10 @@ impl ParserOpts {
             .clone()
     }
 
+    fn allow_typed_data(&self) -> bool {
+        self.parsed.flag(ALLOW_TYPED_DATA).unwrap_or(false)
+    }
+
     fn ephemeral_file(&self) -> String {
         self.parsed
             .single(EPHEMERAL_FILE_OPT)
@@ -74,7 +80,13 @@ impl GetParserForOptions for ParserParser {
                     .takes_value(true)
                     .required(true),
             )
-
+            .token(
+                Token::new(
+                    ALLOW_TYPED_DATA,
+                    "developer only: accept EIP-712 typed data in place of a transaction. Never set for production.",
+                )
+                .takes_value(false),
+            )
             .token(
                 Token::new(
                     EPHEMERAL_FILE_OPT,
@@ -104,8 +116,18 @@ impl Cli {
         } else if opts.parsed.help() {
             println!("{}", opts.parsed.info());
         } else {
-            let processor =
-                crate::service::Processor::new(EphemeralKeyHandle::new(opts.ephemeral_file()));
+            let handle = EphemeralKeyHandle::new(opts.ephemeral_file());
+            let processor = if opts.allow_typed_data() {
+                crate::service::Processor::with_developer_config(
+                    handle,
+                    DeveloperConfig {
+                        allow_typed_data: true,
+                        ..Default::default()
+                    },
+                )
+            } else {
+                crate::service::Processor::new(handle)
+            };
 
             println!(
                 "---- Starting Parser server (version: {}) -----",
@@ -14,21 +14,61 @@ pub fn create_registry() -> visualsign::registry::TransactionConverterRegistry {
     let mut registry = visualsign::registry::TransactionConverterRegistry::new();
     // TODO: Create a ChainRegistry trait that all chains can implement for token metadata,
     // contract types, etc. Currently only Ethereum has a ContractRegistry.
+    #[cfg(feature = "avalanche")]
+    registry.register::<visualsign_avalanche::AvalancheTransactionWrapper, _>(
+        visualsign::registry::Chain::Avalanche,
+        visualsign_avalanche::AvalancheVisualSignConverter,
+    );
+    #[cfg(feature = "bitcoin")]
+    registry.register::<visualsign_bitcoin::BitcoinTransactionWrapper, _>(
+        visualsign::registry::Chain::Bitcoin,
+        visualsign_bitcoin::BitcoinVisualSignConverter,
+    );
+    #[cfg(feature = "dogecoin")]
+    registry.register::<visualsign_bitcoin::DogecoinTransactionWrapper, _>(
+        visualsign::registry::Chain::Dogecoin,
+        visualsign_bitcoin::DogecoinVisualSignConverter,
+    );
     #[cfg(feature = "ethereum")]
     registry.register::<visualsign_ethereum::EthereumTransactionWrapper, _>(
         visualsign::registry::Chain::Ethereum,
         visualsign_ethereum::EthereumVisualSignConverter::new(),
     );
+    #[cfg(feature = "hedera")]
+    registry.register::<visualsign_hedera::HederaTransactionWrapper, _>(
+        visualsign::registry::Chain::Hedera,
+        visualsign_hedera::HederaVisualSignConverter,
+    );
+    #[cfg(feature = "litecoin")]
+    registry.register::<visualsign_bitcoin::LitecoinTransactionWrapper, _>(
+        visualsign::registry::Chain::Litecoin,
+        visualsign_bitcoin::LitecoinVisualSignConverter,
+    );
     #[cfg(feature = "solana")]
     registry.register::<visualsign_solana::SolanaTransactionWrapper, _>(
         visualsign::registry::Chain::Solana,
         visualsign_solana::SolanaVisualSignConverter,
     );
+    #[cfg(feature = "stellar")]
+    registry.register::<visualsign_stellar::StellarTransactionWrapper, _>(
+        visualsign::registry::Chain::Stellar,
+        visualsign_stellar::StellarVisualSignConverter,
+    );
     #[cfg(feature = "sui")]
     registry.register::<visualsign_sui::SuiTransactionWrapper, _>(
         visualsign::registry::Chain::Sui,
         visualsign_sui::SuiVisualSignConverter,
     );
+    #[cfg(feature = "tezos")]
+    registry.register::<visualsign_tezos::TezosTransactionWrapper, _>(
+        visualsign::registry::Chain::Tezos,
+        visualsign_tezos::TezosVisualSignConverter,
+    );
+    #[cfg(feature = "ton")]
+    registry.register::<visualsign_ton::TonTransactionWrapper, _>(
+        visualsign::registry::Chain::Ton,
+        visualsign_ton::TonVisualSignConverter,
+    );
     #[cfg(feature = "tron")]
     registry.register::<visualsign_tron::TronTransactionWrapper, _>(
         visualsign::registry::Chain::Tron,
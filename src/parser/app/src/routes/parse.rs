@@ -14,7 +14,7 @@ use qos_p256::P256Pair;
 
 use visualsign::errors::VisualSignError;
 use visualsign::registry::{Chain as VisualSignRegistryChain, TransactionConverterRegistry};
-use visualsign::vsptrait::VisualSignOptions;
+use visualsign::vsptrait::{DeveloperConfig, VisualSignOptions};
 
 /// Parses an unsigned transaction payload and returns a signed parsed response.
 ///
@@ -25,9 +25,25 @@ use visualsign::vsptrait::VisualSignOptions;
 pub fn parse(
     parse_request: &ParseRequest,
     ephemeral_key: &P256Pair,
+) -> Result<ParseResponse, GrpcError> {
+    parse_with_developer_config(parse_request, ephemeral_key, None)
+}
+
+/// Same as [`parse`] but forwards a deployment-level [`DeveloperConfig`] to the
+/// converters, e.g. to accept EIP-712 typed data in place of an Ethereum
+/// transaction. Only developer deployments should pass `Some`; production keeps
+/// the unsigned-transaction-only behavior of [`parse`].
+///
+/// # Panics
+///
+/// Same as [`parse`].
+pub fn parse_with_developer_config(
+    parse_request: &ParseRequest,
+    ephemeral_key: &P256Pair,
+    developer_config: Option<&DeveloperConfig>,
 ) -> Result<ParseResponse, GrpcError> {
     let registry = create_registry();
-    parse_with_registry(parse_request, ephemeral_key, &registry)
+    parse_with_registry(parse_request, ephemeral_key, &registry, developer_config)
 }
 
 /// Same as [`parse`] but accepts a caller-provided registry. Exists primarily as
@@ -37,6 +53,7 @@ pub(crate) fn parse_with_registry(
     parse_request: &ParseRequest,
     ephemeral_key: &P256Pair,
     registry: &TransactionConverterRegistry,
+    developer_config: Option<&DeveloperConfig>,
 ) -> Result<ParseResponse, GrpcError> {
     let request_payload = parse_request.unsigned_payload.as_str();
     if request_payload.is_empty() {
@@ -50,8 +67,12 @@ pub(crate) fn parse_with_registry(
         decode_transfers: true,
         transaction_name: None,
         metadata: parse_request.chain_metadata.clone(),
-        developer_config: None, // Production API: only accept unsigned transactions
+        // Production API: only accept unsigned transactions. Signed envelopes and
+        // EIP-712 typed data stay behind DeveloperConfig, which only developer
+        // deployments set (see `parse_with_developer_config`).
+        developer_config: developer_config.cloned(),
         include_intermediate_output: parse_request.include_intermediate_output,
+        strict_registry: parse_request.strict_registry,
     };
     let proto_chain = ProtoChain::try_from(parse_request.chain)
         .map_err(|_| GrpcError::new(Code::InvalidArgument, "invalid chain"))?;
@@ -201,12 +222,24 @@ mod tests {
             metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                 network_id: Some("ETHEREUM_MAINNET".to_string()),
                 abi_mappings: abi_mappings_forward,
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
         let metadata_b = ChainMetadata {
             metadata: Some(chain_metadata::Metadata::Ethereum(EthereumMetadata {
                 network_id: Some("ETHEREUM_MAINNET".to_string()),
                 abi_mappings: abi_mappings_reverse,
+                proxy_implementations: Default::default(),
+                custom_networks: Default::default(),
+                address_book: Default::default(),
+                token_prices: Default::default(),
+                tokens: Default::default(),
+                erc4626_vaults: Default::default(),
             })),
         };
 
@@ -359,6 +392,7 @@ mod tests {
     fn stub_request() -> ParseRequest {
         ParseRequest {
             include_intermediate_output: false,
+            strict_registry: false,
             unsigned_payload: "stub".to_string(),
             chain: ProtoChain::Tron as i32,
             chain_metadata: None,
@@ -384,7 +418,7 @@ mod tests {
         );
 
         let key = P256Pair::generate().expect("generate ephemeral key");
-        let err = parse_with_registry(&stub_request(), &key, &registry).expect_err(
+        let err = parse_with_registry(&stub_request(), &key, &registry, None).expect_err(
             "parse_with_registry must reject payloads whose strings contain \
              non-ASCII characters, even when the converter skipped its own \
              charset validation",
@@ -410,7 +444,7 @@ mod tests {
         );
 
         let key = P256Pair::generate().expect("generate ephemeral key");
-        let response = parse_with_registry(&stub_request(), &key, &registry).expect(
+        let response = parse_with_registry(&stub_request(), &key, &registry, None).expect(
             "benign ASCII payload must still parse successfully through a \
              converter that skipped its own charset validation",
         );
@@ -438,13 +472,52 @@ mod tests {
         );
 
         let key = P256Pair::generate().expect("generate ephemeral key");
-        let err = parse_with_registry(&stub_request(), &key, &registry).expect_err(
+        let err = parse_with_registry(&stub_request(), &key, &registry, None).expect_err(
             "parse_with_registry must reject non-ASCII payloads even when the \
              converter skips its inner validate_charset call",
         );
         assert_eq!(err.code, Code::InvalidArgument);
     }
 
+    /// EIP-712 typed data is refused by the production `parse` entry point and
+    /// only parsed when the deployment opts in through `DeveloperConfig`.
+    #[cfg(feature = "ethereum")]
+    #[test]
+    fn typed_data_is_parsed_only_with_developer_config() {
+        let request = ParseRequest {
+            include_intermediate_output: false,
+            strict_registry: false,
+            unsigned_payload: r#"{
+                "types": {
+                    "EIP712Domain": [{"name": "name", "type": "string"}],
+                    "Note": [{"name": "text", "type": "string"}]
+                },
+                "primaryType": "Note",
+                "domain": {"name": "Notes"},
+                "message": {"text": "hello"}
+            }"#
+            .to_string(),
+            chain: ProtoChain::Ethereum as i32,
+            chain_metadata: None,
+        };
+        let key = P256Pair::generate().expect("generate ephemeral key");
+
+        let err = parse(&request, &key).expect_err("typed data must be refused by default");
+        assert_eq!(err.code, Code::InvalidArgument);
+
+        let developer_config = DeveloperConfig {
+            allow_typed_data: true,
+            ..Default::default()
+        };
+        let response = parse_with_developer_config(&request, &key, Some(&developer_config))
+            .expect("typed data must parse when the deployment allows it");
+        let payload = response
+            .parsed_transaction
+            .and_then(|t| t.payload)
+            .expect("parsed payload");
+        assert!(payload.parsed_payload.contains("EIP-712 Typed Data"));
+    }
+
     fn sample_payload(intermediate_output: Vec<u8>) -> ParsedTransactionPayload {
         ParsedTransactionPayload {
             parsed_payload: "parsed".to_string(),
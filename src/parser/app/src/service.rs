@@ -8,11 +8,13 @@ use generated::{
 };
 use qos_core::handles::EphemeralKeyHandle;
 use tokio::sync::RwLock;
+use visualsign::vsptrait::DeveloperConfig;
 
 /// Struct holding a request processor for QOS
 #[derive(Debug)]
 pub struct Processor {
     handle: EphemeralKeyHandle,
+    developer_config: Option<DeveloperConfig>,
 }
 
 /// `Processor` shared between tasks
@@ -22,7 +24,23 @@ impl Processor {
     /// Creates a new request processor. The only argument needed is an ephemeral key handle.
     #[must_use]
     pub fn new(handle: EphemeralKeyHandle) -> SharedProcessor {
-        Arc::new(RwLock::new(Self { handle }))
+        Arc::new(RwLock::new(Self {
+            handle,
+            developer_config: None,
+        }))
+    }
+
+    /// Creates a request processor that forwards `developer_config` to every
+    /// parse request. Only for developer deployments; production uses [`Processor::new`].
+    #[must_use]
+    pub fn with_developer_config(
+        handle: EphemeralKeyHandle,
+        developer_config: DeveloperConfig,
+    ) -> SharedProcessor {
+        Arc::new(RwLock::new(Self {
+            handle,
+            developer_config: Some(developer_config),
+        }))
     }
 }
 
@@ -67,15 +85,19 @@ impl Processor {
 
             let output = match input {
                 qos_parser_request::Input::ParseRequest(parse_request) => {
-                    match crate::routes::parse::parse(parse_request, &ephemeral_key)
-                        .map(qos_parser_response::Output::ParseResponse)
-                        .map_err(|e| {
-                            qos_parser_response::Output::Status(Status {
-                                code: e.code as i32,
-                                message: e.message,
-                                details: vec![],
-                            })
-                        }) {
+                    match crate::routes::parse::parse_with_developer_config(
+                        parse_request,
+                        &ephemeral_key,
+                        self.developer_config.as_ref(),
+                    )
+                    .map(qos_parser_response::Output::ParseResponse)
+                    .map_err(|e| {
+                        qos_parser_response::Output::Status(Status {
+                            code: e.code as i32,
+                            message: e.message,
+                            details: vec![],
+                        })
+                    }) {
                         Ok(o) | Err(o) => o,
                     }
                 }
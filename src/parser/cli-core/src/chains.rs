@@ -11,6 +11,13 @@ fn chain_string_mapping() -> BTreeMap<&'static str, Chain> {
     mapping.insert("aptos", Chain::Aptos);
     mapping.insert("polkadot", Chain::Polkadot);
     mapping.insert("tron", Chain::Tron);
+    mapping.insert("stellar", Chain::Stellar);
+    mapping.insert("ton", Chain::Ton);
+    mapping.insert("avalanche", Chain::Avalanche);
+    mapping.insert("hedera", Chain::Hedera);
+    mapping.insert("tezos", Chain::Tezos);
+    mapping.insert("litecoin", Chain::Litecoin);
+    mapping.insert("dogecoin", Chain::Dogecoin);
     mapping
 }
 
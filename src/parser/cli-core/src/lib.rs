@@ -71,6 +71,11 @@ pub struct SharedArgs {
     #[arg(long)]
     pub with_intermediate: bool,
 
+    /// Only use the parser's built-in token list for amounts; ignore token
+    /// metadata supplied by the chain plugin.
+    #[arg(long)]
+    pub strict_registry: bool,
+
     /// Network identifier (chain ID or canonical name).
     #[arg(
         long,
@@ -132,11 +137,16 @@ pub fn prepare_runtime(
     let chain_metadata = plugin.create_metadata(network)?;
     let options = VisualSignOptions {
         include_intermediate_output,
+        strict_registry: false,
         decode_transfers: true,
         transaction_name: None,
         metadata: chain_metadata,
         developer_config: Some(DeveloperConfig {
             allow_signed_transactions: true,
+            allow_typed_data: true,
+            allow_offchain_messages: true,
+            allow_personal_messages: true,
+            allow_user_operations: true,
         }),
     };
 
@@ -146,12 +156,16 @@ pub fn prepare_runtime(
 /// CLI entry point. Pass the shared args plus an ordered list of chain plugins.
 /// The first plugin whose `chain()` matches `shared.chain` handles the transaction.
 pub fn run(shared: &SharedArgs, plugins: &[Box<dyn ChainPlugin>]) -> Result<(), String> {
-    let Runtime { registry, options } = prepare_runtime(
+    let Runtime {
+        registry,
+        mut options,
+    } = prepare_runtime(
         &shared.chain,
         shared.network.clone(),
         plugins,
         shared.with_intermediate,
     )?;
+    options.strict_registry = shared.strict_registry;
 
     let raw_tx =
         tx_input::resolve_transaction_input(&shared.transaction).map_err(|e| e.to_string())?;
@@ -45,6 +45,31 @@ pub(crate) struct ChainArgs {
     #[cfg(feature = "tron")]
     #[command(flatten)]
     pub(crate) tron: visualsign_tron::TronArgs,
+
+    // Shared by every chain the Bitcoin parser handles.
+    #[cfg(any(feature = "bitcoin", feature = "litecoin", feature = "dogecoin"))]
+    #[command(flatten)]
+    pub(crate) bitcoin: visualsign_bitcoin::BitcoinArgs,
+
+    #[cfg(feature = "stellar")]
+    #[command(flatten)]
+    pub(crate) stellar: visualsign_stellar::StellarArgs,
+
+    #[cfg(feature = "ton")]
+    #[command(flatten)]
+    pub(crate) ton: visualsign_ton::TonArgs,
+
+    #[cfg(feature = "avalanche")]
+    #[command(flatten)]
+    pub(crate) avalanche: visualsign_avalanche::AvalancheArgs,
+
+    #[cfg(feature = "hedera")]
+    #[command(flatten)]
+    pub(crate) hedera: visualsign_hedera::HederaArgs,
+
+    #[cfg(feature = "tezos")]
+    #[command(flatten)]
+    pub(crate) tezos: visualsign_tezos::TezosArgs,
 }
 
 impl ChainArgs {
@@ -64,6 +89,36 @@ impl ChainArgs {
         plugins.push(Box::new(visualsign_tron::TronPlugin::new(
             self.tron.clone(),
         )));
+        #[cfg(feature = "bitcoin")]
+        plugins.push(Box::new(visualsign_bitcoin::BitcoinPlugin::new(
+            self.bitcoin.clone(),
+        )));
+        #[cfg(feature = "litecoin")]
+        plugins.push(Box::new(visualsign_bitcoin::LitecoinPlugin::new(
+            self.bitcoin.clone(),
+        )));
+        #[cfg(feature = "dogecoin")]
+        plugins.push(Box::new(visualsign_bitcoin::DogecoinPlugin::new(
+            self.bitcoin.clone(),
+        )));
+        #[cfg(feature = "stellar")]
+        plugins.push(Box::new(visualsign_stellar::StellarPlugin::new(
+            self.stellar.clone(),
+        )));
+        #[cfg(feature = "ton")]
+        plugins.push(Box::new(visualsign_ton::TonPlugin::new(self.ton.clone())));
+        #[cfg(feature = "avalanche")]
+        plugins.push(Box::new(visualsign_avalanche::AvalanchePlugin::new(
+            self.avalanche.clone(),
+        )));
+        #[cfg(feature = "hedera")]
+        plugins.push(Box::new(visualsign_hedera::HederaPlugin::new(
+            self.hedera.clone(),
+        )));
+        #[cfg(feature = "tezos")]
+        plugins.push(Box::new(visualsign_tezos::TezosPlugin::new(
+            self.tezos.clone(),
+        )));
         plugins
     }
 }
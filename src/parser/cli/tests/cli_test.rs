@@ -56,6 +56,22 @@ fn test_cli_with_fixtures() {
         "solana",
         #[cfg(not(feature = "tron"))]
         "tron",
+        #[cfg(not(feature = "bitcoin"))]
+        "bitcoin",
+        #[cfg(not(feature = "litecoin"))]
+        "litecoin",
+        #[cfg(not(feature = "dogecoin"))]
+        "dogecoin",
+        #[cfg(not(feature = "stellar"))]
+        "stellar",
+        #[cfg(not(feature = "ton"))]
+        "ton",
+        #[cfg(not(feature = "avalanche"))]
+        "avalanche",
+        #[cfg(not(feature = "hedera"))]
+        "hedera",
+        #[cfg(not(feature = "tezos"))]
+        "tezos",
     ];
 
     for input_file in test_cases {
@@ -15,8 +15,9 @@ use generated::grpc::health::v1::{
     HealthCheckRequest, health_check_response::ServingStatus, health_client::HealthClient,
 };
 use generated::parser::{
-    Chain, ChainMetadata, EthereumMetadata, ParseRequest, SignatureScheme, SolanaMetadata,
-    chain_metadata, parser_service_client::ParserServiceClient,
+    Chain, ChainMetadata, EthereumMetadata, HederaMetadata, ParseRequest, SignatureScheme,
+    SolanaMetadata, SuiMetadata, TonMetadata, TronMetadata, chain_metadata,
+    parser_service_client::ParserServiceClient,
 };
 use generated::tonic;
 use host_primitives::GRPC_MAX_RECV_MSG_SIZE;
@@ -41,6 +42,14 @@ enum ChainMetadataInput {
     Ethereum(EthereumMetadata),
     #[serde(rename = "CHAIN_SOLANA")]
     Solana(SolanaMetadata),
+    #[serde(rename = "CHAIN_SUI")]
+    Sui(SuiMetadata),
+    #[serde(rename = "CHAIN_TRON")]
+    Tron(TronMetadata),
+    #[serde(rename = "CHAIN_TON")]
+    Ton(TonMetadata),
+    #[serde(rename = "CHAIN_HEDERA")]
+    Hedera(HederaMetadata),
 }
 
 impl From<ChainMetadataInput> for ChainMetadata {
@@ -48,6 +57,10 @@ impl From<ChainMetadataInput> for ChainMetadata {
         let metadata = match input {
             ChainMetadataInput::Ethereum(eth) => chain_metadata::Metadata::Ethereum(eth),
             ChainMetadataInput::Solana(sol) => chain_metadata::Metadata::Solana(sol),
+            ChainMetadataInput::Sui(sui) => chain_metadata::Metadata::Sui(sui),
+            ChainMetadataInput::Tron(tron) => chain_metadata::Metadata::Tron(tron),
+            ChainMetadataInput::Ton(ton) => chain_metadata::Metadata::Ton(ton),
+            ChainMetadataInput::Hedera(hedera) => chain_metadata::Metadata::Hedera(hedera),
         };
         ChainMetadata {
             metadata: Some(metadata),
@@ -64,6 +77,9 @@ struct TurnkeyRequest {
     /// false so existing REST callers that omit it behave exactly as before.
     #[serde(default)]
     include_intermediate_output: bool,
+    /// Opt-in to ignoring caller-supplied token metadata. Defaults to false.
+    #[serde(default)]
+    strict_registry: bool,
 }
 
 #[derive(Serialize)]
@@ -234,6 +250,7 @@ async fn parse_handler(
         chain,
         chain_metadata: wrapper.request.chain_metadata.map(ChainMetadata::from),
         include_intermediate_output: wrapper.request.include_intermediate_output,
+        strict_registry: wrapper.request.strict_registry,
     });
 
     let response = match tokio::time::timeout(PARSE_TIMEOUT, grpc_client.parse(request)).await {
@@ -514,6 +531,50 @@ mod tests {
         assert!(matches!(parsed, ChainMetadataInput::Solana(_)));
     }
 
+    #[test]
+    fn chain_metadata_input_sui_deserializes() {
+        let json = r#"{"chain":"CHAIN_SUI","accounts":{"0xabc":{"multisig":{"threshold":2,"members":[{"address":"0x1","weight":1}]}}}}"#;
+        let parsed: ChainMetadataInput = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            parsed,
+            ChainMetadataInput::Sui(ref sui)
+                if sui.accounts["0xabc"]
+                    .multisig
+                    .as_ref()
+                    .is_some_and(|m| m.threshold == 2 && m.members[0].weight == 1)
+        ));
+    }
+
+    #[test]
+    fn chain_metadata_input_tron_deserializes() {
+        let json = r#"{"chain":"CHAIN_TRON","assets":{"1002000":{"name":"BitTorrent Old","symbol":"BTTOLD","precision":6}}}"#;
+        let parsed: ChainMetadataInput = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            parsed,
+            ChainMetadataInput::Tron(ref tron) if tron.assets["1002000"].precision == 6
+        ));
+    }
+
+    #[test]
+    fn chain_metadata_input_ton_deserializes() {
+        let json = r#"{"chain":"CHAIN_TON","jettons":{"0:aa":{"symbol":"USDT","decimals":6}}}"#;
+        let parsed: ChainMetadataInput = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            parsed,
+            ChainMetadataInput::Ton(ref ton) if ton.jettons["0:aa"].decimals == 6
+        ));
+    }
+
+    #[test]
+    fn chain_metadata_input_hedera_deserializes() {
+        let json = r#"{"chain":"CHAIN_HEDERA","abiMappings":{"0.0.1234":{"value":"[]"}}}"#;
+        let parsed: ChainMetadataInput = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            parsed,
+            ChainMetadataInput::Hedera(ref hedera) if hedera.abi_mappings["0.0.1234"].value == "[]"
+        ));
+    }
+
     #[test]
     fn chain_metadata_input_ethereum_deserializes() {
         let json = r#"{"chain":"CHAIN_ETHEREUM","networkId":"ETHEREUM_MAINNET"}"#;
@@ -526,6 +587,51 @@ mod tests {
         let json = r#"{"networkId":"ETHEREUM_MAINNET"}"#;
         let parsed: EthereumMetadata = serde_json::from_str(json).unwrap();
         assert!(parsed.abi_mappings.is_empty());
+        assert!(parsed.proxy_implementations.is_empty());
+        assert!(parsed.custom_networks.is_empty());
+        assert!(parsed.address_book.is_empty());
+        assert!(parsed.token_prices.is_empty());
+    }
+
+    #[test]
+    fn ethereum_metadata_token_prices_deserializes() {
+        let json = r#"{"tokenPrices":{"0x0000000000000000000000000000000000000000":{"price":"2500.5","currency":"USD"}}}"#;
+        let parsed: EthereumMetadata = serde_json::from_str(json).unwrap();
+        let price = &parsed.token_prices["0x0000000000000000000000000000000000000000"];
+        assert_eq!(price.price, "2500.5");
+        assert_eq!(price.currency, "USD");
+    }
+
+    #[test]
+    fn ethereum_metadata_address_book_deserializes() {
+        let json = r#"{"addressBook":{"0x1111111111111111111111111111111111111111":{"name":"Cold Wallet 3","badge":"Internal"}}}"#;
+        let parsed: EthereumMetadata = serde_json::from_str(json).unwrap();
+        let label = &parsed.address_book["0x1111111111111111111111111111111111111111"];
+        assert_eq!(label.name, "Cold Wallet 3");
+        assert_eq!(label.badge.as_deref(), Some("Internal"));
+    }
+
+    #[test]
+    fn ethereum_metadata_custom_networks_deserializes() {
+        let json = r#"{"customNetworks":[{"chainId":99999,"name":"Acme Appchain","nativeSymbol":"ACME","explorerUrl":"https://explorer.acme.example"}]}"#;
+        let parsed: EthereumMetadata = serde_json::from_str(json).unwrap();
+        let network = &parsed.custom_networks[0];
+        assert_eq!(network.chain_id, 99999);
+        assert_eq!(network.native_symbol, "ACME");
+        assert_eq!(network.native_decimals, None);
+    }
+
+    #[test]
+    fn ethereum_metadata_proxy_implementations_deserializes() {
+        let json = r#"{"networkId":"ETHEREUM_MAINNET","proxyImplementations":{"0x1111111111111111111111111111111111111111":"0x2222222222222222222222222222222222222222"}}"#;
+        let parsed: EthereumMetadata = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            parsed
+                .proxy_implementations
+                .get("0x1111111111111111111111111111111111111111")
+                .map(String::as_str),
+            Some("0x2222222222222222222222222222222222222222")
+        );
     }
 
     #[test]
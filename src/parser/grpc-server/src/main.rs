@@ -15,26 +15,31 @@ use generated::parser::{
     parser_service_server::{ParserService, ParserServiceServer},
 };
 use generated::tonic::{self, Request, Response, Status};
-use parser_app::routes::parse::parse;
+use parser_app::routes::parse::parse_with_developer_config;
 use qos_core::handles::EphemeralKeyHandle;
 use qos_p256::P256Pair;
 use std::net::SocketAddr;
+use visualsign::vsptrait::DeveloperConfig;
 
 /// Standalone gRPC service that calls the parser directly
 struct GrpcService {
     ephemeral_key: P256Pair,
+    developer_config: Option<DeveloperConfig>,
 }
 
 /// Health check service - always returns SERVING
 struct HealthService;
 
 impl GrpcService {
-    fn new(ephemeral_file: &str) -> Self {
+    fn new(ephemeral_file: &str, developer_config: Option<DeveloperConfig>) -> Self {
         let handle = EphemeralKeyHandle::new(ephemeral_file.to_string());
         let ephemeral_key = handle
             .get_ephemeral_key()
             .expect("Failed to load ephemeral key");
-        Self { ephemeral_key }
+        Self {
+            ephemeral_key,
+            developer_config,
+        }
     }
 }
 
@@ -45,9 +50,13 @@ impl ParserService for GrpcService {
         request: Request<ParseRequest>,
     ) -> Result<Response<ParseResponse>, Status> {
         // Direct function call - no sockets needed
-        parse(&request.into_inner(), &self.ephemeral_key)
-            .map(Response::new)
-            .map_err(|e| Status::new(tonic::Code::from(e.code as i32), e.message))
+        parse_with_developer_config(
+            &request.into_inner(),
+            &self.ephemeral_key,
+            self.developer_config.as_ref(),
+        )
+        .map(Response::new)
+        .map_err(|e| Status::new(tonic::Code::from(e.code as i32), e.message))
     }
 }
 
@@ -80,7 +89,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let ephemeral_file = std::env::var("EPHEMERAL_FILE")
         .unwrap_or_else(|_| "integration/fixtures/ephemeral.secret".to_string());
 
-    let svc = GrpcService::new(&ephemeral_file);
+    // Developer-only: accept EIP-712 typed data in place of a transaction
+    let developer_config = std::env::var("ALLOW_TYPED_DATA")
+        .is_ok_and(|v| v == "1" || v == "true")
+        .then(|| DeveloperConfig {
+            allow_typed_data: true,
+            ..Default::default()
+        });
+
+    let svc = GrpcService::new(&ephemeral_file, developer_config);
 
     let reflection_service = generated::tonic_reflection::server::Builder::configure()
         .register_encoded_file_descriptor_set(generated::FILE_DESCRIPTOR_SET)
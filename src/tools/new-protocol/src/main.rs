@@ -0,0 +1,367 @@
+//! Scaffolding generator for new Ethereum protocol modules.
+//!
+//! Writes `protocols/<name>/{mod.rs, config.rs, contracts/}` into the
+//! visualsign-ethereum crate from the templates in `templates/`, wires the module
+//! into `protocols::register_all`, and (when deployment addresses are given)
+//! writes an example fixture input for `tests/lib_test.rs`.
+//!
+//! Usage:
+//!   new-protocol <name> --contract <ContractName> [--display <Display Name>]
+//!                [--address <0x...>]... [--crate-dir <path>]
+//!
+//! Example:
+//!   cargo run -p new-protocol -- morpho --contract Blue --display "Morpho" \
+//!       --address 0xBBBBBbbBBb9cC5e90e3b3Af64bdAF62C37EEFFCb
+
+use alloy_primitives::{Address, U256, keccak256};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+const MOD_TEMPLATE: &str = include_str!("../templates/mod.rs.tmpl");
+const CONFIG_TEMPLATE: &str = include_str!("../templates/config.rs.tmpl");
+const CONTRACTS_MOD_TEMPLATE: &str = include_str!("../templates/contracts_mod.rs.tmpl");
+const CONTRACT_TEMPLATE: &str = include_str!("../templates/contract.rs.tmpl");
+
+/// Signature of the placeholder function emitted by `contract.rs.tmpl`
+const EXAMPLE_SIGNATURE: &str = "exampleAction(address,uint256)";
+
+const USAGE: &str = "usage: new-protocol <name> --contract <ContractName> \
+     [--display <Display Name>] [--address <0x...>]... [--crate-dir <path>]";
+
+struct Args {
+    /// snake_case module name, e.g. `morpho`
+    name: String,
+    /// PascalCase contract name, e.g. `Blue`
+    contract: String,
+    display: String,
+    addresses: Vec<Address>,
+    crate_dir: PathBuf,
+}
+
+fn main() -> Result<()> {
+    let args = parse_args(std::env::args().skip(1).collect())?;
+    let protocol_dir = args
+        .crate_dir
+        .join("src")
+        .join("protocols")
+        .join(&args.name);
+    if protocol_dir.exists() {
+        anyhow::bail!("{} already exists", protocol_dir.display());
+    }
+
+    let vars = template_vars(&args);
+    let contract_snake = to_snake_case(&args.contract);
+    let files = [
+        (protocol_dir.join("mod.rs"), MOD_TEMPLATE),
+        (protocol_dir.join("config.rs"), CONFIG_TEMPLATE),
+        (
+            protocol_dir.join("contracts").join("mod.rs"),
+            CONTRACTS_MOD_TEMPLATE,
+        ),
+        (
+            protocol_dir
+                .join("contracts")
+                .join(format!("{contract_snake}.rs")),
+            CONTRACT_TEMPLATE,
+        ),
+    ];
+
+    fs::create_dir_all(protocol_dir.join("contracts"))
+        .with_context(|| format!("failed to create {}", protocol_dir.display()))?;
+    for (path, template) in &files {
+        fs::write(path, render(template, &vars))
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        println!("created {}", path.display());
+    }
+
+    let protocols_mod = args.crate_dir.join("src").join("protocols").join("mod.rs");
+    let source = fs::read_to_string(&protocols_mod)
+        .with_context(|| format!("failed to read {}", protocols_mod.display()))?;
+    fs::write(
+        &protocols_mod,
+        register_protocol(&source, &args.name, &args.display)?,
+    )
+    .with_context(|| format!("failed to write {}", protocols_mod.display()))?;
+    println!("updated {}", protocols_mod.display());
+
+    let fixture_name = format!(
+        "{}-{}",
+        args.name.replace('_', "-"),
+        contract_snake.replace('_', "-")
+    );
+    if let Some(address) = args.addresses.first() {
+        let fixture = args
+            .crate_dir
+            .join("tests")
+            .join("fixtures")
+            .join(format!("{fixture_name}.input"));
+        fs::write(&fixture, example_fixture(*address))
+            .with_context(|| format!("failed to write {}", fixture.display()))?;
+        println!("created {}", fixture.display());
+    }
+
+    // Best effort: the templates are close to rustfmt output but not exact.
+    let _ = Command::new("rustfmt")
+        .args(["--edition", "2024"])
+        .args(files.iter().map(|(path, _)| path))
+        .status();
+
+    println!(
+        "\nNext steps:\n  \
+         1. Replace `exampleAction` in protocols/{name}/contracts/{contract_snake}.rs with the real interface.\n  \
+         2. Add a `{ty}::short_type_id()` dispatch branch in `convert_to_visual_sign_payload` (src/lib.rs).\n  \
+         3. Add \"{fixture_name}\" to FIXTURES in tests/lib_test.rs and bootstrap its .expected with\n     \
+         REGEN_FIXTURES=1 cargo test -p visualsign-ethereum --test lib_test test_with_fixtures",
+        name = args.name,
+        ty = vars_type(&args),
+    );
+    Ok(())
+}
+
+fn parse_args(raw: Vec<String>) -> Result<Args> {
+    let mut iter = raw.into_iter();
+    let name = iter.next().context(USAGE)?;
+    if name.is_empty()
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        || name.starts_with(|c: char| c.is_ascii_digit())
+    {
+        anyhow::bail!("protocol name must be a snake_case identifier, got '{name}'");
+    }
+
+    let mut contract = None;
+    let mut display = None;
+    let mut addresses = Vec::new();
+    let mut crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("..")
+        .join("chain_parsers")
+        .join("visualsign-ethereum");
+
+    while let Some(flag) = iter.next() {
+        let value = iter
+            .next()
+            .with_context(|| format!("missing value for {flag}\n{USAGE}"))?;
+        match flag.as_str() {
+            "--contract" => contract = Some(value),
+            "--display" => display = Some(value),
+            "--address" => addresses.push(
+                value
+                    .parse()
+                    .with_context(|| format!("invalid address '{value}'"))?,
+            ),
+            "--crate-dir" => crate_dir = PathBuf::from(value),
+            _ => anyhow::bail!("unknown flag {flag}\n{USAGE}"),
+        }
+    }
+
+    let contract = contract.context(USAGE)?;
+    if !contract.starts_with(|c: char| c.is_ascii_uppercase())
+        || !contract.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        anyhow::bail!("contract name must be PascalCase, got '{contract}'");
+    }
+
+    Ok(Args {
+        display: display.unwrap_or_else(|| to_pascal_case(&name)),
+        name,
+        contract,
+        addresses,
+        crate_dir,
+    })
+}
+
+fn vars_type(args: &Args) -> String {
+    format!("{}{}", to_pascal_case(&args.name), args.contract)
+}
+
+/// Placeholder -> replacement pairs applied to every template
+fn template_vars(args: &Args) -> Vec<(&'static str, String)> {
+    let mut address_arms = "            networks::ethereum::MAINNET => &[\n".to_string();
+    if args.addresses.is_empty() {
+        address_arms.push_str("                // TODO: add deployment addresses\n");
+    }
+    for address in &args.addresses {
+        address_arms.push_str(&format!("                \"{address}\",\n"));
+    }
+    address_arms.push_str("            ],\n");
+
+    vec![
+        ("__address_arms__", address_arms),
+        ("__Display__", args.display.clone()),
+        ("__Type__", vars_type(args)),
+        ("__Proto__", to_pascal_case(&args.name)),
+        ("__proto__", args.name.clone()),
+        ("__Contract__", args.contract.clone()),
+        ("__contract__", to_snake_case(&args.contract)),
+    ]
+}
+
+fn render(template: &str, vars: &[(&str, String)]) -> String {
+    vars.iter().fold(template.to_string(), |acc, (key, value)| {
+        acc.replace(key, value)
+    })
+}
+
+/// Adds `pub mod <name>;` and a `<name>::register(..)` call to `protocols/mod.rs`
+fn register_protocol(source: &str, name: &str, display: &str) -> Result<String> {
+    let mod_line = format!("pub mod {name};");
+    if source.lines().any(|l| l.trim() == mod_line) {
+        anyhow::bail!("protocols/mod.rs already declares `{mod_line}`");
+    }
+
+    // Keep the `pub mod` block sorted.
+    let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+    let insert_at = lines
+        .iter()
+        .position(|l| l.starts_with("pub mod ") && l.as_str() > mod_line.as_str())
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|l| l.starts_with("pub mod "))
+                .map(|i| i + 1)
+        })
+        .unwrap_or(0);
+    lines.insert(insert_at, mod_line);
+
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("pub fn register_all("))
+        .context("protocols/mod.rs has no `register_all` function")?;
+    let end = lines[start..]
+        .iter()
+        .position(|l| l == "}")
+        .map(|i| start + i)
+        .context("unterminated `register_all` function")?;
+    lines.splice(
+        end..end,
+        [
+            String::new(),
+            format!("    // Register {display} protocol"),
+            format!("    {name}::register(contract_reg, visualizer_reg);"),
+        ],
+    );
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Ok(out)
+}
+
+/// Legacy JSON transaction calling the template's `exampleAction` on `to`
+fn example_fixture(to: Address) -> String {
+    let mut data = keccak256(EXAMPLE_SIGNATURE.as_bytes())[..4].to_vec();
+    data.extend_from_slice(&[0u8; 12]);
+    data.extend_from_slice(Address::repeat_byte(0x11).as_slice());
+    data.extend_from_slice(&U256::from(42u64).to_be_bytes::<32>());
+
+    format!(
+        "{{\n  \"type\": \"transaction\",\n  \"to\": \"{to}\",\n  \"value\": \"0x0\",\n  \
+         \"nonce\": \"0x0\",\n  \"gas\": \"0x30d40\",\n  \"gasPrice\": \"0x3b9aca00\",\n  \
+         \"chainId\": \"0x1\",\n  \"data\": \"0x{}\"\n}}\n",
+        hex::encode(&data)
+    )
+}
+
+fn to_pascal_case(snake: &str) -> String {
+    snake
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect()
+}
+
+fn to_snake_case(pascal: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in pascal.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+mod tests {
+    use super::*;
+
+    const PROTOCOLS_MOD: &str = "pub mod aave;
+pub mod uniswap;
+
+pub fn register_all(
+    contract_reg: &mut ContractRegistry,
+    visualizer_reg: &mut EthereumVisualizerRegistryBuilder,
+) {
+    // Register Uniswap protocol
+    uniswap::register(contract_reg, visualizer_reg);
+}
+";
+
+    #[test]
+    fn test_case_conversion() {
+        assert_eq!(to_pascal_case("morpho_blue"), "MorphoBlue");
+        assert_eq!(to_pascal_case("aave"), "Aave");
+        assert_eq!(to_snake_case("VariableDebtToken"), "variable_debt_token");
+        assert_eq!(to_snake_case("Pool"), "pool");
+    }
+
+    #[test]
+    fn test_register_protocol_keeps_mods_sorted() {
+        let out = register_protocol(PROTOCOLS_MOD, "morpho", "Morpho").unwrap();
+        assert!(out.starts_with("pub mod aave;\npub mod morpho;\npub mod uniswap;\n"));
+        assert!(out.ends_with(
+            "    uniswap::register(contract_reg, visualizer_reg);\n\n    \
+             // Register Morpho protocol\n    \
+             morpho::register(contract_reg, visualizer_reg);\n}\n"
+        ));
+    }
+
+    #[test]
+    fn test_register_protocol_rejects_duplicate() {
+        assert!(register_protocol(PROTOCOLS_MOD, "aave", "Aave").is_err());
+    }
+
+    #[test]
+    fn test_render_replaces_all_placeholders() {
+        let args = parse_args(
+            ["morpho_blue", "--contract", "Vault"]
+                .map(String::from)
+                .to_vec(),
+        )
+        .unwrap();
+        let vars = template_vars(&args);
+        for template in [
+            MOD_TEMPLATE,
+            CONFIG_TEMPLATE,
+            CONTRACTS_MOD_TEMPLATE,
+            CONTRACT_TEMPLATE,
+        ] {
+            let rendered = render(template, &vars);
+            assert!(
+                !rendered.contains("__"),
+                "unrendered placeholder:\n{rendered}"
+            );
+        }
+        assert!(render(CONFIG_TEMPLATE, &vars).contains("pub struct MorphoBlueVault;"));
+    }
+
+    #[test]
+    fn test_parse_args_rejects_bad_names() {
+        assert!(parse_args(["Morpho", "--contract", "Blue"].map(String::from).to_vec()).is_err());
+        assert!(parse_args(["morpho", "--contract", "blue"].map(String::from).to_vec()).is_err());
+        assert!(parse_args(vec!["morpho".to_string()]).is_err());
+    }
+}
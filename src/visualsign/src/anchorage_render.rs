@@ -19,9 +19,12 @@
 //! | `preview_layout` | yes (container)    | every descendant must render            |
 //! | `list_layout`    | only as a container| INVALID as a standalone field entry     |
 //! | `number`         | yes (as `amount_v2`)| the in-memory `Number` variant serializes to `amount_v2` on the wire (VSP has no `number` type), so it renders fine |
+//! | `percentage`     | yes (as `amount_v2`)| the in-memory `Percentage` variant serializes to `amount_v2` with a `%` abbreviation, like `Number` |
 //! | `text` (v1)      | no                 | superseded by `text_v2`                 |
 //! | `address` (v1)   | no                 | superseded by `address_v2`              |
 //! | `amount` (v1)    | no                 | superseded by `amount_v2`               |
+//! | `date_time_v2`   | no                 | not in the wallet decoder yet; use `text_v2` with a formatted date for wallet-bound payloads |
+//! | `uri_v2`         | no                 | not in the wallet decoder yet; leave links out of wallet-bound payloads |
 //! | `divider`        | no                 | not in the wallet decoder               |
 //! | `unknown`        | no                 | explicit fallback/unsupported           |
 //!
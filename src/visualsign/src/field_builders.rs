@@ -1,10 +1,12 @@
 use crate::errors;
+use crate::time_fmt::format_timestamp_ms;
 #[cfg(feature = "diagnostics")]
 use crate::SignablePayloadFieldDiagnostic;
 use crate::{
-    AnnotatedPayloadField, SignablePayloadField, SignablePayloadFieldAddressV2,
-    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldListLayout,
-    SignablePayloadFieldNumber, SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2,
+    AnnotatedPayloadField, DateTimeRenderHint, SignablePayloadField, SignablePayloadFieldAddressV2,
+    SignablePayloadFieldAmountV2, SignablePayloadFieldCommon, SignablePayloadFieldDateTimeV2,
+    SignablePayloadFieldListLayout, SignablePayloadFieldNumber, SignablePayloadFieldPercentage,
+    SignablePayloadFieldPreviewLayout, SignablePayloadFieldTextV2, SignablePayloadFieldUriV2,
 };
 
 use regex::Regex;
@@ -120,6 +122,164 @@ pub fn create_amount_field(
     })
 }
 
+/// Create a percentage field from a rate in basis points, e.g. `"50"` for a 0.5% slippage
+/// tolerance. Fractional basis points are allowed for finer rates such as Uniswap's
+/// 0.01% fee tier (`"1"`) or a 0.005% tier (`"0.5"`). The fallback text is the percent,
+/// like `0.5%`.
+pub fn create_percentage_field(
+    label: &str,
+    basis_points: &str,
+) -> Result<AnnotatedPayloadField, errors::VisualSignError> {
+    validate_number_string(basis_points)?;
+    let percentage = SignablePayloadFieldPercentage {
+        basis_points: basis_points.to_string(),
+    };
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::Percentage {
+            common: SignablePayloadFieldCommon {
+                fallback_text: format!("{}%", percentage.percent()),
+                label: label.to_string(),
+            },
+            percentage,
+        },
+    })
+}
+
+/// Decides which domains a URI field may link to. Implemented for closures and for
+/// slices of domain names, where a listed domain also allows its subdomains: with
+/// `["etherscan.io"]`, `sepolia.etherscan.io` is allowed too.
+pub trait UriAllowlist {
+    fn allows(&self, domain: &str) -> bool;
+}
+
+impl<F: Fn(&str) -> bool> UriAllowlist for F {
+    fn allows(&self, domain: &str) -> bool {
+        self(domain)
+    }
+}
+
+impl<S: AsRef<str>> UriAllowlist for [S] {
+    fn allows(&self, domain: &str) -> bool {
+        self.iter().any(|allowed| {
+            let allowed = allowed.as_ref();
+            domain
+                .len()
+                .checked_sub(allowed.len())
+                .is_some_and(|split| {
+                    let (subdomains, rest) = domain.split_at(split);
+                    rest.eq_ignore_ascii_case(allowed)
+                        && (subdomains.is_empty() || subdomains.ends_with('.'))
+                })
+        })
+    }
+}
+
+const MAX_URI_LEN: usize = 2048;
+
+// Returns the lowercased host of an `http` or `https` URI. Hosts must be domain names
+// in ASCII: internationalized names are given in punycode so a homograph shows up as
+// `xn--…`, and IP literals are refused. So is user info, which makes
+// `https://app.uniswap.org@evil.example` point at `evil.example`.
+fn uri_domain(uri: &str) -> Result<String, String> {
+    if uri.len() > MAX_URI_LEN {
+        return Err(format!("is longer than {MAX_URI_LEN} characters"));
+    }
+    if !uri.bytes().all(|b| b.is_ascii_graphic()) {
+        return Err("contains whitespace or non-ASCII characters".to_string());
+    }
+    let (scheme, rest) = uri
+        .split_once("://")
+        .ok_or_else(|| "has no scheme".to_string())?;
+    if !["https", "http"]
+        .iter()
+        .any(|allowed| allowed.eq_ignore_ascii_case(scheme))
+    {
+        return Err(format!(
+            "uses scheme '{scheme}'; only http and https are allowed"
+        ));
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if authority.contains('@') {
+        return Err("has user info before the host".to_string());
+    }
+    if authority.starts_with('[') {
+        return Err("has an IP address for a host".to_string());
+    }
+    let host = match authority.rsplit_once(':') {
+        Some((host, port)) if !port.is_empty() && port.bytes().all(|b| b.is_ascii_digit()) => host,
+        Some(_) => return Err("has an invalid port".to_string()),
+        None => authority,
+    };
+
+    let labels: Vec<&str> = host.split('.').collect();
+    let is_domain = host.len() <= 253
+        && labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        })
+        // An all-numeric top label means an IPv4 address
+        && labels
+            .last()
+            .is_some_and(|tld| !tld.bytes().all(|b| b.is_ascii_digit()));
+    if !is_domain {
+        return Err(format!("has host '{host}', which is not a domain name"));
+    }
+    Ok(host.to_ascii_lowercase())
+}
+
+/// Create a link field, for an explorer page, a token logo or a dApp origin. `uri` must
+/// be an `http` or `https` URI on a domain name; the domain is kept separately so the
+/// client can highlight it.
+pub fn create_uri_field(
+    label: &str,
+    uri: &str,
+) -> Result<AnnotatedPayloadField, errors::VisualSignError> {
+    let domain = uri_domain(uri)
+        .map_err(|reason| errors::VisualSignError::ValidationError(format!("URI {reason}")))?;
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::UriV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: uri.to_string(),
+                label: label.to_string(),
+            },
+            uri_v2: SignablePayloadFieldUriV2 {
+                domain,
+                uri: uri.to_string(),
+            },
+        },
+    })
+}
+
+/// Like [`create_uri_field`], but also rejects a URI whose domain `allowlist` doesn't
+/// allow, for parsers that should only ever link to known explorers or asset hosts.
+pub fn create_uri_field_with_allowlist<A: UriAllowlist + ?Sized>(
+    label: &str,
+    uri: &str,
+    allowlist: &A,
+) -> Result<AnnotatedPayloadField, errors::VisualSignError> {
+    let field = create_uri_field(label, uri)?;
+    if let SignablePayloadField::UriV2 { uri_v2, .. } = &field.signable_payload_field {
+        if !allowlist.allows(&uri_v2.domain) {
+            return Err(errors::VisualSignError::ValidationError(format!(
+                "URI domain '{}' is not allowed",
+                uri_v2.domain
+            )));
+        }
+    }
+    Ok(field)
+}
+
 /// Helper function to create an address field
 pub fn create_address_field(
     label: &str,
@@ -149,6 +309,62 @@ pub fn create_address_field(
     })
 }
 
+// `UTC` or an IANA zone name (`Europe/London`, `America/Argentina/Buenos_Aires`,
+// `Etc/GMT+5`). The tz database itself isn't bundled, so only the shape is checked here.
+fn is_time_zone_name(name: &str) -> bool {
+    name.split('/').all(|part| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '+'))
+    })
+}
+
+/// Create a date/time field from a Unix timestamp in seconds.
+///
+/// `time_zone` is `UTC` or an IANA zone name; `None` lets the client use the signer's own
+/// zone. The fallback text is always the UTC date and time, so it reads the same to every
+/// signer whatever the hint and zone.
+pub fn create_datetime_field(
+    label: &str,
+    timestamp: i64,
+    render_hint: DateTimeRenderHint,
+    time_zone: Option<&str>,
+) -> Result<AnnotatedPayloadField, errors::VisualSignError> {
+    let fallback_text = timestamp
+        .checked_mul(1000)
+        .filter(|ms| chrono::DateTime::from_timestamp_millis(*ms).is_some())
+        .map(format_timestamp_ms)
+        .ok_or_else(|| {
+            errors::VisualSignError::ValidationError(format!(
+                "timestamp {timestamp} is outside the representable date range"
+            ))
+        })?;
+    if let Some(zone) = time_zone {
+        if !is_time_zone_name(zone) {
+            return Err(errors::VisualSignError::ValidationError(format!(
+                "'{zone}' is not a time zone name"
+            )));
+        }
+    }
+
+    Ok(AnnotatedPayloadField {
+        static_annotation: None,
+        dynamic_annotation: None,
+        signable_payload_field: SignablePayloadField::DateTimeV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text,
+                label: label.to_string(),
+            },
+            date_time_v2: SignablePayloadFieldDateTimeV2 {
+                render_hint,
+                time_zone: time_zone.map(str::to_string),
+                timestamp,
+            },
+        },
+    })
+}
+
 fn default_hex_representation(data: &[u8]) -> String {
     data.iter()
         .map(|byte| format!("{byte:02x}"))
@@ -395,6 +611,186 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_create_percentage_field() {
+        let test_cases = [
+            ("50", "0.5%"),
+            ("5", "0.05%"),
+            ("1", "0.01%"),
+            ("0.5", "0.005%"),
+            ("100", "1%"),
+            ("10000", "100%"),
+            ("12345", "123.45%"),
+            ("5.00", "0.05%"),
+            ("0", "0%"),
+            ("-25", "-0.25%"),
+        ];
+
+        for (basis_points, expected_fallback) in test_cases {
+            let field = create_percentage_field("Slippage", basis_points).expect("should succeed");
+            match field.signable_payload_field {
+                SignablePayloadField::Percentage { common, percentage } => {
+                    assert_eq!(common.label, "Slippage");
+                    assert_eq!(common.fallback_text, expected_fallback, "{basis_points}");
+                    assert_eq!(percentage.basis_points, basis_points);
+                }
+                _ => panic!("Expected Percentage field"),
+            }
+        }
+
+        for invalid in ["", "abc", "0.5%", "1e4"] {
+            assert!(
+                create_percentage_field("Slippage", invalid).is_err(),
+                "{invalid:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_uri_field() {
+        let test_cases = [
+            ("https://etherscan.io/tx/0xabc?a=1#logs", "etherscan.io"),
+            ("HTTPS://App.Uniswap.org", "app.uniswap.org"),
+            ("http://localhost.test:8080/", "localhost.test"),
+            ("https://xn--uniswp-5va.org/swap", "xn--uniswp-5va.org"),
+        ];
+
+        for (uri, expected_domain) in test_cases {
+            let field = create_uri_field("Explorer", uri).expect("should succeed");
+            match field.signable_payload_field {
+                SignablePayloadField::UriV2 { common, uri_v2 } => {
+                    assert_eq!(common.label, "Explorer");
+                    assert_eq!(common.fallback_text, uri);
+                    assert_eq!(uri_v2.uri, uri);
+                    assert_eq!(uri_v2.domain, expected_domain);
+                }
+                _ => panic!("Expected UriV2 field"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_create_uri_field_rejects_invalid_uris() {
+        let invalid = [
+            "",
+            "etherscan.io/tx/0xabc",
+            "javascript:alert(1)",
+            "data://text/html,hi",
+            "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            "https://app.uniswap.org@evil.example/",
+            "https://192.168.0.1/",
+            "https://[::1]/",
+            "https://localhost/",
+            "https://exa_mple.com/",
+            "https://-example.com/",
+            "https://example.com:port/",
+            "https://example..com/",
+            "https://ùniswap.org/",
+            "https://example.com/a b",
+        ];
+        for uri in invalid {
+            assert!(
+                matches!(
+                    create_uri_field("Link", uri),
+                    Err(VisualSignError::ValidationError(_))
+                ),
+                "{uri:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_uri_field_with_allowlist() {
+        let explorers = ["etherscan.io", "solscan.io"];
+        for uri in [
+            "https://etherscan.io/tx/0x1",
+            "https://sepolia.etherscan.io/tx/0x1",
+            "https://SOLSCAN.io/tx/1",
+        ] {
+            assert!(
+                create_uri_field_with_allowlist("Explorer", uri, explorers.as_slice()).is_ok(),
+                "{uri}"
+            );
+        }
+        for uri in [
+            "https://evil.example/tx/0x1",
+            "https://fakeetherscan.io/tx/0x1",
+            "https://etherscan.io.evil.example/tx/0x1",
+        ] {
+            assert!(
+                matches!(
+                    create_uri_field_with_allowlist("Explorer", uri, explorers.as_slice()),
+                    Err(VisualSignError::ValidationError(_))
+                ),
+                "{uri}"
+            );
+        }
+
+        let only_uniswap = |domain: &str| domain == "app.uniswap.org";
+        assert!(create_uri_field_with_allowlist(
+            "Origin",
+            "https://app.uniswap.org",
+            &only_uniswap
+        )
+        .is_ok());
+        assert!(
+            create_uri_field_with_allowlist("Origin", "https://uniswap.org", &only_uniswap)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_create_datetime_field() {
+        let field = create_datetime_field(
+            "Deadline",
+            1_700_000_000,
+            DateTimeRenderHint::Relative,
+            Some("America/New_York"),
+        )
+        .expect("should succeed");
+
+        match field.signable_payload_field {
+            SignablePayloadField::DateTimeV2 {
+                common,
+                date_time_v2,
+            } => {
+                assert_eq!(common.label, "Deadline");
+                // The fallback stays in UTC whichever zone the client is asked to use.
+                assert_eq!(common.fallback_text, "2023-11-14 22:13:20 UTC");
+                assert_eq!(date_time_v2.timestamp, 1_700_000_000);
+                assert_eq!(date_time_v2.render_hint, DateTimeRenderHint::Relative);
+                assert_eq!(date_time_v2.time_zone.as_deref(), Some("America/New_York"));
+            }
+            _ => panic!("Expected DateTimeV2 field"),
+        }
+
+        for zone in ["UTC", "Etc/GMT+5", "America/Argentina/Buenos_Aires"] {
+            assert!(
+                create_datetime_field("T", 0, DateTimeRenderHint::Date, Some(zone)).is_ok(),
+                "{zone}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_datetime_field_rejects_invalid_input() {
+        for timestamp in [i64::MAX, i64::MIN, 100_000_000_000_000] {
+            assert!(matches!(
+                create_datetime_field("T", timestamp, DateTimeRenderHint::DateTime, None),
+                Err(VisualSignError::ValidationError(_))
+            ));
+        }
+        for zone in ["", "Europe/", "/UTC", "UTC+01:00", "Europe/London Time"] {
+            assert!(
+                matches!(
+                    create_datetime_field("T", 0, DateTimeRenderHint::DateTime, Some(zone)),
+                    Err(VisualSignError::ValidationError(_))
+                ),
+                "{zone:?}"
+            );
+        }
+    }
+
     #[test]
     fn test_default_hex_representation() {
         let test_cases = [
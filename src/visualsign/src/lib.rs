@@ -179,6 +179,30 @@ pub enum SignablePayloadField {
         amount_v2: SignablePayloadFieldAmountV2,
     },
 
+    #[serde(rename = "date_time_v2")]
+    DateTimeV2 {
+        #[serde(flatten)]
+        common: SignablePayloadFieldCommon,
+        #[serde(rename = "DateTimeV2")]
+        date_time_v2: SignablePayloadFieldDateTimeV2,
+    },
+
+    #[serde(rename = "percentage")]
+    Percentage {
+        #[serde(flatten)]
+        common: SignablePayloadFieldCommon,
+        #[serde(rename = "Percentage")]
+        percentage: SignablePayloadFieldPercentage,
+    },
+
+    #[serde(rename = "uri_v2")]
+    UriV2 {
+        #[serde(flatten)]
+        common: SignablePayloadFieldCommon,
+        #[serde(rename = "UriV2")]
+        uri_v2: SignablePayloadFieldUriV2,
+    },
+
     #[serde(rename = "divider")]
     Divider {
         #[serde(flatten)]
@@ -287,6 +311,30 @@ impl FieldSerializer for SignablePayloadField {
             SignablePayloadField::AmountV2 { common, amount_v2 } => {
                 serialize_field_variant!(fields, "amount_v2", common, ("AmountV2", amount_v2));
             }
+            SignablePayloadField::DateTimeV2 {
+                common,
+                date_time_v2,
+            } => {
+                serialize_field_variant!(
+                    fields,
+                    "date_time_v2",
+                    common,
+                    ("DateTimeV2", date_time_v2)
+                );
+            }
+            SignablePayloadField::Percentage { common, percentage } => {
+                // Like `number`, `percentage` goes out as `amount_v2` so wallets
+                // that only know VSP types still render it: the percent value
+                // with a `%` abbreviation.
+                let amount_v2 = SignablePayloadFieldAmountV2 {
+                    amount: percentage.percent(),
+                    abbreviation: Some("%".to_string()),
+                };
+                serialize_field_variant!(fields, "amount_v2", common, ("AmountV2", &amount_v2));
+            }
+            SignablePayloadField::UriV2 { common, uri_v2 } => {
+                serialize_field_variant!(fields, "uri_v2", common, ("UriV2", uri_v2));
+            }
             SignablePayloadField::Divider { common, divider } => {
                 serialize_field_variant!(fields, "divider", common, ("Divider", divider));
             }
@@ -337,6 +385,9 @@ impl FieldSerializer for SignablePayloadField {
             SignablePayloadField::Number { .. } => base_fields.push("AmountV2"),
             SignablePayloadField::Amount { .. } => base_fields.push("Amount"),
             SignablePayloadField::AmountV2 { .. } => base_fields.push("AmountV2"),
+            SignablePayloadField::DateTimeV2 { .. } => base_fields.push("DateTimeV2"),
+            SignablePayloadField::Percentage { .. } => base_fields.push("AmountV2"),
+            SignablePayloadField::UriV2 { .. } => base_fields.push("UriV2"),
             SignablePayloadField::Divider { .. } => base_fields.push("Divider"),
             SignablePayloadField::PreviewLayout { .. } => base_fields.push("PreviewLayout"),
             SignablePayloadField::ListLayout { .. } => base_fields.push("ListLayout"),
@@ -411,6 +462,9 @@ impl SignablePayloadField {
             SignablePayloadField::Number { common, .. } => &common.fallback_text,
             SignablePayloadField::Amount { common, .. } => &common.fallback_text,
             SignablePayloadField::AmountV2 { common, .. } => &common.fallback_text,
+            SignablePayloadField::DateTimeV2 { common, .. } => &common.fallback_text,
+            SignablePayloadField::Percentage { common, .. } => &common.fallback_text,
+            SignablePayloadField::UriV2 { common, .. } => &common.fallback_text,
             SignablePayloadField::Divider { common, .. } => &common.fallback_text,
             SignablePayloadField::PreviewLayout { common, .. } => &common.fallback_text,
             SignablePayloadField::ListLayout { common, .. } => &common.fallback_text,
@@ -429,6 +483,9 @@ impl SignablePayloadField {
             SignablePayloadField::Number { common, .. } => &common.label,
             SignablePayloadField::Amount { common, .. } => &common.label,
             SignablePayloadField::AmountV2 { common, .. } => &common.label,
+            SignablePayloadField::DateTimeV2 { common, .. } => &common.label,
+            SignablePayloadField::Percentage { common, .. } => &common.label,
+            SignablePayloadField::UriV2 { common, .. } => &common.label,
             SignablePayloadField::Divider { common, .. } => &common.label,
             SignablePayloadField::PreviewLayout { common, .. } => &common.label,
             SignablePayloadField::ListLayout { common, .. } => &common.label,
@@ -447,6 +504,9 @@ impl SignablePayloadField {
             SignablePayloadField::Number { .. } => "number",
             SignablePayloadField::Amount { .. } => "amount",
             SignablePayloadField::AmountV2 { .. } => "amount_v2",
+            SignablePayloadField::DateTimeV2 { .. } => "date_time_v2",
+            SignablePayloadField::Percentage { .. } => "percentage",
+            SignablePayloadField::UriV2 { .. } => "uri_v2",
             SignablePayloadField::Divider { .. } => "divider",
             SignablePayloadField::PreviewLayout { .. } => "preview_layout",
             SignablePayloadField::ListLayout { .. } => "list_layout",
@@ -629,6 +689,111 @@ impl Serialize for SignablePayloadFieldAmountV2 {
 // Implement DeterministicOrdering for SignablePayloadFieldAmountV2
 impl DeterministicOrdering for SignablePayloadFieldAmountV2 {}
 
+/// A point in time, kept as a Unix timestamp so clients can format it for the signer
+/// instead of showing a chain's raw integer.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SignablePayloadFieldDateTimeV2 {
+    #[serde(rename = "RenderHint")]
+    pub render_hint: DateTimeRenderHint,
+    /// Zone to show the time in: `UTC` or an IANA name like `America/New_York`. When
+    /// absent the client uses the signer's own zone.
+    #[serde(rename = "TimeZone", skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    /// Seconds since the Unix epoch, UTC
+    #[serde(rename = "Timestamp")]
+    pub timestamp: i64,
+}
+
+// Implement DeterministicOrdering for SignablePayloadFieldDateTimeV2
+impl DeterministicOrdering for SignablePayloadFieldDateTimeV2 {}
+
+/// How much of a [`SignablePayloadFieldDateTimeV2`] the client should show. The
+/// timestamp itself is always exact; the hint only picks the presentation.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeRenderHint {
+    /// Calendar date only, for day-granular values such as vesting or unlock dates
+    #[serde(rename = "date")]
+    Date,
+    /// Date and time of day
+    #[serde(rename = "date_time")]
+    DateTime,
+    /// Distance from now ("in 20 minutes"), for deadlines and expiries
+    #[serde(rename = "relative")]
+    Relative,
+}
+
+/// A rate such as slippage tolerance or a pool fee, kept in basis points (1 bp =
+/// 0.01%) so every chain hands clients the same unit.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SignablePayloadFieldPercentage {
+    /// Decimal string, fractional for sub-basis-point rates like Uniswap's 0.01% tier
+    #[serde(rename = "BasisPoints")]
+    pub basis_points: String,
+}
+
+impl SignablePayloadFieldPercentage {
+    /// The rate in percent, e.g. `"0.5"` for 50 basis points
+    pub fn percent(&self) -> String {
+        basis_points_to_percent(&self.basis_points)
+    }
+}
+
+// Implement DeterministicOrdering for SignablePayloadFieldPercentage
+impl DeterministicOrdering for SignablePayloadFieldPercentage {}
+
+/// Converts a decimal basis-point string to percent by moving the decimal point two
+/// places left, without going through floating point: `"5"` is `"0.05"`, `"10000"` is
+/// `"100"`. Trailing fractional zeros are dropped. Input is expected to be a plain
+/// signed decimal; anything else is returned unchanged.
+pub fn basis_points_to_percent(basis_points: &str) -> String {
+    let (sign, unsigned) = match basis_points.strip_prefix(['-', '+']) {
+        Some(rest) => (&basis_points[..1], rest),
+        None => ("", basis_points),
+    };
+    let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if whole.is_empty()
+        || !whole.bytes().all(|b| b.is_ascii_digit())
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return basis_points.to_string();
+    }
+
+    let whole = format!("{whole:0>3}");
+    let (percent_whole, moved) = whole.split_at(whole.len() - 2);
+    let percent_whole = match percent_whole.trim_start_matches('0') {
+        "" => "0",
+        trimmed => trimmed,
+    };
+    let percent_fraction = format!("{moved}{fraction}");
+    let percent_fraction = percent_fraction.trim_end_matches('0');
+
+    let magnitude = if percent_fraction.is_empty() {
+        percent_whole.to_string()
+    } else {
+        format!("{percent_whole}.{percent_fraction}")
+    };
+    if magnitude == "0" || sign == "+" {
+        magnitude
+    } else {
+        format!("{sign}{magnitude}")
+    }
+}
+
+/// A link to something off-chain, such as a block explorer page, a token logo or the
+/// origin of the dApp that built the transaction.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SignablePayloadFieldUriV2 {
+    /// Host the URI points at, lowercased. Clients highlight it so that a lookalike
+    /// link stands out from the path around it.
+    #[serde(rename = "Domain")]
+    pub domain: String,
+    #[serde(rename = "Uri")]
+    pub uri: String,
+}
+
+// Implement DeterministicOrdering for SignablePayloadFieldUriV2
+impl DeterministicOrdering for SignablePayloadFieldUriV2 {}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 pub struct SignablePayloadFieldDivider {
     #[serde(rename = "Style")]
@@ -2626,6 +2791,107 @@ mod tests {
         assert!(pos_title < pos_version, "Title should come before Version");
     }
 
+    #[test]
+    fn test_date_time_field_serialization_and_roundtrip() {
+        let field = SignablePayloadField::DateTimeV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: "2023-11-14 22:13:20 UTC".to_string(),
+                label: "Deadline".to_string(),
+            },
+            date_time_v2: SignablePayloadFieldDateTimeV2 {
+                render_hint: DateTimeRenderHint::Relative,
+                time_zone: Some("UTC".to_string()),
+                timestamp: 1_700_000_000,
+            },
+        };
+
+        field
+            .verify_deterministic_ordering()
+            .expect("DateTimeV2 field should have deterministic ordering");
+
+        let value = serde_json::to_value(&field).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "DateTimeV2": {
+                    "RenderHint": "relative",
+                    "TimeZone": "UTC",
+                    "Timestamp": 1_700_000_000
+                },
+                "FallbackText": "2023-11-14 22:13:20 UTC",
+                "Label": "Deadline",
+                "Type": "date_time_v2"
+            })
+        );
+
+        let deserialized: SignablePayloadField = serde_json::from_value(value).unwrap();
+        assert_eq!(field, deserialized);
+    }
+
+    #[test]
+    fn test_percentage_field_serializes_as_amount_v2() {
+        let field = SignablePayloadField::Percentage {
+            common: SignablePayloadFieldCommon {
+                fallback_text: "0.5%".to_string(),
+                label: "Slippage".to_string(),
+            },
+            percentage: SignablePayloadFieldPercentage {
+                basis_points: "50".to_string(),
+            },
+        };
+
+        field
+            .verify_deterministic_ordering()
+            .expect("Percentage field should have deterministic ordering");
+        assert_eq!(
+            serde_json::to_value(&field).unwrap(),
+            json!({
+                "AmountV2": {
+                    "Abbreviation": "%",
+                    "Amount": "0.5"
+                },
+                "FallbackText": "0.5%",
+                "Label": "Slippage",
+                "Type": "amount_v2"
+            })
+        );
+    }
+
+    #[test]
+    fn test_uri_field_serialization_and_roundtrip() {
+        let field = SignablePayloadField::UriV2 {
+            common: SignablePayloadFieldCommon {
+                fallback_text: "https://etherscan.io/address/0x1f98".to_string(),
+                label: "Explorer".to_string(),
+            },
+            uri_v2: SignablePayloadFieldUriV2 {
+                domain: "etherscan.io".to_string(),
+                uri: "https://etherscan.io/address/0x1f98".to_string(),
+            },
+        };
+
+        field
+            .verify_deterministic_ordering()
+            .expect("UriV2 field should have deterministic ordering");
+
+        let value = serde_json::to_value(&field).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "FallbackText": "https://etherscan.io/address/0x1f98",
+                "Label": "Explorer",
+                "Type": "uri_v2",
+                "UriV2": {
+                    "Domain": "etherscan.io",
+                    "Uri": "https://etherscan.io/address/0x1f98"
+                }
+            })
+        );
+
+        let deserialized: SignablePayloadField = serde_json::from_value(value).unwrap();
+        assert_eq!(field, deserialized);
+    }
+
     #[cfg(feature = "diagnostics")]
     #[test]
     fn test_diagnostic_field_serialization_alphabetical() {
@@ -19,6 +19,13 @@ pub enum Chain {
     Aptos,
     Polkadot,
     Tron,
+    Stellar,
+    Ton,
+    Avalanche,
+    Hedera,
+    Tezos,
+    Litecoin,
+    Dogecoin,
     // Add other chains as needed
     Custom(String), // For extensibility without modifying the enum
 }
@@ -34,6 +41,13 @@ impl Chain {
             Chain::Aptos => "Aptos",
             Chain::Polkadot => "Polkadot",
             Chain::Tron => "Tron",
+            Chain::Stellar => "Stellar",
+            Chain::Ton => "Ton",
+            Chain::Avalanche => "Avalanche",
+            Chain::Hedera => "Hedera",
+            Chain::Tezos => "Tezos",
+            Chain::Litecoin => "Litecoin",
+            Chain::Dogecoin => "Dogecoin",
             Chain::Custom(name) => name.as_str(),
         }
     }
@@ -52,6 +66,13 @@ impl FromStr for Chain {
             "aptos" => Chain::Aptos,
             "polkadot" => Chain::Polkadot,
             "tron" => Chain::Tron,
+            "stellar" => Chain::Stellar,
+            "ton" => Chain::Ton,
+            "avalanche" => Chain::Avalanche,
+            "hedera" => Chain::Hedera,
+            "tezos" => Chain::Tezos,
+            "litecoin" => Chain::Litecoin,
+            "dogecoin" => Chain::Dogecoin,
             _ => Chain::Custom(s.to_string()),
         })
     }
@@ -187,6 +208,101 @@ impl TransactionConverterRegistry {
     }
 }
 
+/// Where a registry entry came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Provenance {
+    /// Compiled into the parser.
+    BuiltIn,
+    /// Loaded at startup from an operator-provided deployment bundle.
+    DeploymentBundle,
+    /// Supplied with the request in wallet `ChainMetadata`.
+    WalletMetadata,
+}
+
+impl Provenance {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Provenance::BuiltIn => "built-in",
+            Provenance::DeploymentBundle => "deployment bundle",
+            Provenance::WalletMetadata => "wallet metadata",
+        }
+    }
+
+    /// Trust tier an entry gets when nothing vouches for it beyond its source.
+    ///
+    /// Operator-controlled sources are verified; request-supplied entries are not.
+    pub fn default_tier(&self) -> TrustTier {
+        match self {
+            Provenance::BuiltIn | Provenance::DeploymentBundle => TrustTier::Verified,
+            Provenance::WalletMetadata => TrustTier::Unverified,
+        }
+    }
+}
+
+/// How far a registry entry can be trusted when rendering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TrustTier {
+    /// Nothing vouches for the entry (e.g. unsigned wallet metadata).
+    Unverified,
+    /// Operator-controlled or signature-verified.
+    Verified,
+}
+
+impl TrustTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrustTier::Unverified => "unverified",
+            TrustTier::Verified => "verified",
+        }
+    }
+}
+
+/// Provenance and trust tier attached to a registry entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EntryOrigin {
+    pub provenance: Provenance,
+    pub tier: TrustTier,
+}
+
+impl EntryOrigin {
+    /// Origin with the provenance's default tier.
+    pub fn new(provenance: Provenance) -> Self {
+        Self {
+            provenance,
+            tier: provenance.default_tier(),
+        }
+    }
+
+    pub fn built_in() -> Self {
+        Self::new(Provenance::BuiltIn)
+    }
+
+    pub fn is_verified(&self) -> bool {
+        self.tier == TrustTier::Verified
+    }
+
+    /// Note to attach to a field derived from this entry, e.g.
+    /// `"symbol from unverified wallet metadata"`.
+    ///
+    /// Returns `None` for verified entries so trusted data renders unannotated.
+    pub fn annotation(&self, what: &str) -> Option<String> {
+        if self.is_verified() {
+            return None;
+        }
+        Some(format!(
+            "{what} from {} {}",
+            self.tier.as_str(),
+            self.provenance.as_str()
+        ))
+    }
+}
+
+impl Default for EntryOrigin {
+    fn default() -> Self {
+        Self::built_in()
+    }
+}
+
 /// Generic layered registry for combining global and request-scoped data.
 ///
 /// This struct enables efficient per-request registry overlays without cloning.
@@ -215,6 +331,9 @@ impl TransactionConverterRegistry {
 /// assert_eq!(layered.lookup(|r| r.get("USDC").copied()), Some(8)); // From wallet
 /// assert_eq!(layered.lookup(|r| r.get("WETH").copied()), Some(18)); // From global
 /// assert_eq!(layered.lookup(|r| r.get("DAI").copied()), None); // Not found
+///
+/// // Amount-affecting lookups never let the wallet override a global entry
+/// assert_eq!(layered.lookup_amount_data(|r| r.get("USDC").copied()), Some(6));
 /// ```
 ///
 /// # Type Parameter
@@ -226,6 +345,8 @@ pub struct LayeredRegistry<R> {
     request: Option<R>,
     /// Global registry shared across requests via Arc
     global: Arc<R>,
+    /// When set, amount-affecting lookups ignore the request layer
+    strict: bool,
 }
 
 impl<R> LayeredRegistry<R> {
@@ -236,6 +357,7 @@ impl<R> LayeredRegistry<R> {
         Self {
             request: None,
             global,
+            strict: false,
         }
     }
 
@@ -246,9 +368,26 @@ impl<R> LayeredRegistry<R> {
         Self {
             request: Some(request),
             global,
+            strict: false,
         }
     }
 
+    /// Enables or disables strict mode.
+    ///
+    /// In strict mode [`Self::lookup_amount_data`] never consults the
+    /// request layer, so wallet-supplied entries cannot supply decimals or
+    /// symbols even for tokens the global registry doesn't know. Display-only
+    /// lookups are unaffected.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns whether strict mode is enabled.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
     /// Returns a reference to the global registry.
     pub fn global(&self) -> &R {
         &self.global
@@ -292,6 +431,61 @@ impl<R> LayeredRegistry<R> {
         f(&self.global)
     }
 
+    /// Layered lookup for data that changes the value shown to the signer,
+    /// such as the decimals and symbol used to render an amount.
+    ///
+    /// Unlike [`Self::lookup`] the global layer answers first, so the request
+    /// layer can only fill in entries the global registry doesn't have and
+    /// never overrides a built-in one. In strict mode only the global layer is
+    /// consulted.
+    pub fn lookup_amount_data<T, F>(&self, f: F) -> Option<T>
+    where
+        F: Fn(&R) -> Option<T>,
+    {
+        self.lookup_amount_data_with_origin(f, |_| EntryOrigin::built_in())
+            .map(|(result, _)| result)
+    }
+
+    /// Same as [`Self::lookup_amount_data`], but also reports which layer
+    /// answered, tagged like [`Self::lookup_with_origin`].
+    pub fn lookup_amount_data_with_origin<T, F, O>(
+        &self,
+        f: F,
+        global_origin: O,
+    ) -> Option<(T, EntryOrigin)>
+    where
+        F: Fn(&R) -> Option<T>,
+        O: Fn(&R) -> EntryOrigin,
+    {
+        if let Some(result) = f(&self.global) {
+            return Some((result, global_origin(&self.global)));
+        }
+        if self.strict {
+            return None;
+        }
+        let result = f(self.request.as_ref()?)?;
+        Some((result, EntryOrigin::new(Provenance::WalletMetadata)))
+    }
+
+    /// Layered lookup that also reports which layer answered.
+    ///
+    /// Request-layer hits are tagged [`Provenance::WalletMetadata`]; global hits
+    /// carry `global_origin`, since the global layer can mix built-in and
+    /// deployment-bundle entries and only the caller knows which applies.
+    pub fn lookup_with_origin<T, F, O>(&self, f: F, global_origin: O) -> Option<(T, EntryOrigin)>
+    where
+        F: Fn(&R) -> Option<T>,
+        O: Fn(&R) -> EntryOrigin,
+    {
+        if let Some(ref request) = self.request {
+            if let Some(result) = f(request) {
+                return Some((result, EntryOrigin::new(Provenance::WalletMetadata)));
+            }
+        }
+        let result = f(&self.global)?;
+        Some((result, global_origin(&self.global)))
+    }
+
     /// Performs a layered lookup that returns a Result.
     ///
     /// Similar to `lookup`, but for fallible operations. Checks request first,
@@ -570,6 +764,13 @@ mod tests {
         assert_eq!(Chain::from_str("aptos"), Ok(Chain::Aptos));
         assert_eq!(Chain::from_str("polkadot"), Ok(Chain::Polkadot));
         assert_eq!(Chain::from_str("tron"), Ok(Chain::Tron));
+        assert_eq!(Chain::from_str("stellar"), Ok(Chain::Stellar));
+        assert_eq!(Chain::from_str("ton"), Ok(Chain::Ton));
+        assert_eq!(Chain::from_str("avalanche"), Ok(Chain::Avalanche));
+        assert_eq!(Chain::from_str("hedera"), Ok(Chain::Hedera));
+        assert_eq!(Chain::from_str("tezos"), Ok(Chain::Tezos));
+        assert_eq!(Chain::from_str("litecoin"), Ok(Chain::Litecoin));
+        assert_eq!(Chain::from_str("dogecoin"), Ok(Chain::Dogecoin));
         assert_eq!(
             Chain::from_str("unknown"),
             Ok(Chain::Custom("unknown".to_string()))
@@ -585,6 +786,13 @@ mod tests {
         assert_eq!(Chain::Aptos.as_str(), "Aptos");
         assert_eq!(Chain::Polkadot.as_str(), "Polkadot");
         assert_eq!(Chain::Tron.as_str(), "Tron");
+        assert_eq!(Chain::Stellar.as_str(), "Stellar");
+        assert_eq!(Chain::Ton.as_str(), "Ton");
+        assert_eq!(Chain::Avalanche.as_str(), "Avalanche");
+        assert_eq!(Chain::Hedera.as_str(), "Hedera");
+        assert_eq!(Chain::Tezos.as_str(), "Tezos");
+        assert_eq!(Chain::Litecoin.as_str(), "Litecoin");
+        assert_eq!(Chain::Dogecoin.as_str(), "Dogecoin");
         assert_eq!(Chain::Custom("MyChain".to_string()).as_str(), "MyChain");
     }
 
@@ -707,4 +915,65 @@ mod tests {
             layered.lookup_result(|r| r.get("missing").ok_or("not found"));
         assert_eq!(result, Err("not found"));
     }
+
+    #[test]
+    fn test_layered_registry_amount_data_never_overrides_global() {
+        let global = Arc::new(MockRegistry::with_value("decimals", "6"));
+        let request = MockRegistry::with_value("decimals", "18");
+        let layered = LayeredRegistry::with_request(global, request);
+
+        assert_eq!(
+            layered.lookup_amount_data(|r| r.get("decimals")),
+            Some("6".to_string())
+        );
+        // Display lookups still see the wallet-provided value.
+        assert_eq!(
+            layered.lookup(|r| r.get("decimals")),
+            Some("18".to_string())
+        );
+    }
+
+    #[test]
+    fn test_layered_registry_amount_data_from_request_unless_strict() {
+        let global = Arc::new(MockRegistry::with_value("known", "6"));
+        let request = MockRegistry::with_value("unknown", "18");
+        let layered = LayeredRegistry::with_request(global, request);
+
+        let (value, origin) = layered
+            .lookup_amount_data_with_origin(|r| r.get("unknown"), |_| EntryOrigin::built_in())
+            .unwrap();
+        assert_eq!(value, "18");
+        assert_eq!(origin.provenance, Provenance::WalletMetadata);
+
+        let strict = layered.with_strict(true);
+        assert!(strict.is_strict());
+        assert_eq!(strict.lookup_amount_data(|r| r.get("unknown")), None);
+        assert_eq!(
+            strict.lookup_amount_data(|r| r.get("known")),
+            Some("6".to_string())
+        );
+    }
+
+    #[test]
+    fn test_layered_registry_lookup_with_origin() {
+        let global = Arc::new(MockRegistry::with_value("global_key", "g"));
+        let request = MockRegistry::with_value("request_key", "r");
+        let layered = LayeredRegistry::with_request(global, request);
+
+        let (value, origin) = layered
+            .lookup_with_origin(|r| r.get("request_key"), |_| EntryOrigin::built_in())
+            .unwrap();
+        assert_eq!(value, "r");
+        assert_eq!(origin.provenance, Provenance::WalletMetadata);
+        assert_eq!(
+            origin.annotation("symbol").as_deref(),
+            Some("symbol from unverified wallet metadata")
+        );
+
+        let (_, origin) = layered
+            .lookup_with_origin(|r| r.get("global_key"), |_| EntryOrigin::built_in())
+            .unwrap();
+        assert!(origin.is_verified());
+        assert_eq!(origin.annotation("symbol"), None);
+    }
 }
@@ -105,6 +105,21 @@ pub fn check_signable_payload_field(
             .then(|| amount_v2.amount.to_string())
             .into_iter()
             .collect(),
+        SignablePayloadField::DateTimeV2 {
+            common,
+            date_time_v2,
+        } => (common.label == label)
+            .then(|| date_time_v2.timestamp.to_string())
+            .into_iter()
+            .collect(),
+        SignablePayloadField::Percentage { common, percentage } => (common.label == label)
+            .then(|| percentage.basis_points.to_string())
+            .into_iter()
+            .collect(),
+        SignablePayloadField::UriV2 { common, uri_v2 } => (common.label == label)
+            .then(|| uri_v2.uri.to_string())
+            .into_iter()
+            .collect(),
         SignablePayloadField::PreviewLayout {
             preview_layout,
             common,
@@ -1,11 +1,13 @@
 //! Shared timestamp formatting helpers for chain parsers.
 //!
-//! Absolute formatting via chrono; relative ("N minutes ago") is hand-rolled
-//! integer math so the wording stays consistent across chains and the helper
-//! has zero extra dependencies.
+//! Absolute formatting via chrono; relative ("N minutes ago") and durations
+//! ("2 days 4 hours") are hand-rolled integer math so the wording stays
+//! consistent across chains and the helper has zero extra dependencies.
 
 use chrono::DateTime;
 
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S UTC";
+
 /// Format epoch-milliseconds as `YYYY-MM-DD HH:MM:SS UTC`.
 ///
 /// Returns `"invalid timestamp"` for values outside chrono's representable
@@ -13,10 +15,18 @@ use chrono::DateTime;
 /// see the original bytes even when the date is unrepresentable.
 pub fn format_timestamp_ms(ms: i64) -> String {
     DateTime::from_timestamp_millis(ms)
-        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .map(|dt| dt.format(TIMESTAMP_FORMAT).to_string())
         .unwrap_or_else(|| "invalid timestamp".to_string())
 }
 
+/// Format epoch-seconds as `YYYY-MM-DD HH:MM:SS UTC`.
+///
+/// Returns `None` for values outside chrono's representable range, so callers
+/// that already carry their own fallback (e.g. `"unix:<value>"`) can use it.
+pub fn format_timestamp_secs(secs: i64) -> Option<String> {
+    DateTime::from_timestamp(secs, 0).map(|dt| dt.format(TIMESTAMP_FORMAT).to_string())
+}
+
 /// Format epoch-ms relative to `now_ms`, e.g. `"about 2 hours ago"` or
 /// `"in about 23 hours"`. Coarse one-unit precision, integer math, no f64.
 ///
@@ -63,6 +73,53 @@ pub fn format_relative_ms(ms: i64, now_ms: i64) -> Option<String> {
     Some(rendered)
 }
 
+/// Format a length of time in seconds, e.g. `"2 days 4 hours"`.
+///
+/// Renders the largest non-zero unit plus the next smaller unit when that is
+/// non-zero; anything finer is truncated. Durations are lock-ups, delays and
+/// validity windows, not timestamps, so there is no month or year unit: a
+/// one-year lock-up reads as `"365 days"`. Callers should keep the raw value
+/// next to this (see [`format_duration_with_raw`]).
+pub fn format_duration_secs(secs: u64) -> String {
+    const UNITS: [(u64, &str); 4] = [
+        (86_400, "day"),
+        (3_600, "hour"),
+        (60, "minute"),
+        (1, "second"),
+    ];
+
+    if secs == 0 {
+        return "0 seconds".to_string();
+    }
+
+    let Some(idx) = UNITS.iter().position(|(size, _)| secs >= *size) else {
+        return "0 seconds".to_string();
+    };
+    let plural = |n: u64| if n == 1 { "" } else { "s" };
+
+    let (size, unit) = UNITS[idx];
+    let major = secs / size;
+    let mut rendered = format!("{major} {unit}{}", plural(major));
+    if let Some((next_size, next_unit)) = UNITS.get(idx + 1) {
+        let minor = (secs % size) / next_size;
+        if minor > 0 {
+            rendered.push_str(&format!(" {minor} {next_unit}{}", plural(minor)));
+        }
+    }
+    rendered
+}
+
+/// Format a duration counted in chain-native units (blocks, slots, epochs)
+/// as `"<human> (<count> <unit_label>)"`, e.g. `"3 days (86400 blocks)"`.
+///
+/// `unit_secs` is the nominal length of one unit; the product saturates at
+/// `u64::MAX` rather than overflowing. The raw count is always included since
+/// block and slot times are targets, not guarantees.
+pub fn format_duration_with_raw(count: u64, unit_secs: u64, unit_label: &str) -> String {
+    let human = format_duration_secs(count.saturating_mul(unit_secs));
+    format!("{human} ({count} {unit_label})")
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -80,6 +137,15 @@ mod tests {
         assert_eq!(format_timestamp_ms(i64::MAX), "invalid timestamp");
     }
 
+    #[test]
+    fn format_timestamp_secs_matches_ms_and_rejects_unrepresentable() {
+        assert_eq!(
+            format_timestamp_secs(NOW / 1_000).as_deref(),
+            Some("2023-11-14 22:13:20 UTC")
+        );
+        assert!(format_timestamp_secs(i64::MAX).is_none());
+    }
+
     #[test]
     fn relative_ms_just_now_both_directions() {
         assert_eq!(format_relative_ms(NOW, NOW).as_deref(), Some("just now"));
@@ -160,4 +226,31 @@ mod tests {
         assert!(format_relative_ms(i64::MAX, NOW).is_none());
         assert!(format_relative_ms(i64::MIN, NOW).is_none());
     }
+
+    #[test]
+    fn duration_secs_two_largest_units() {
+        assert_eq!(format_duration_secs(0), "0 seconds");
+        assert_eq!(format_duration_secs(1), "1 second");
+        assert_eq!(format_duration_secs(90), "1 minute 30 seconds");
+        assert_eq!(format_duration_secs(3_600), "1 hour");
+        assert_eq!(
+            format_duration_secs(2 * 86_400 + 4 * 3_600),
+            "2 days 4 hours"
+        );
+        // Minutes under a day-scale duration are truncated.
+        assert_eq!(format_duration_secs(86_400 + 59 * 60), "1 day");
+        assert_eq!(format_duration_secs(365 * 86_400), "365 days");
+    }
+
+    #[test]
+    fn duration_with_raw_keeps_count_and_saturates() {
+        assert_eq!(
+            format_duration_with_raw(86_400, 3, "blocks"),
+            "3 days (86400 blocks)",
+        );
+        assert_eq!(
+            format_duration_with_raw(u64::MAX, 3, "blocks"),
+            format!("{} ({} blocks)", format_duration_secs(u64::MAX), u64::MAX),
+        );
+    }
 }
@@ -11,6 +11,21 @@ pub struct DeveloperConfig {
     /// Allow decoding signed transactions by extracting the unsigned portion.
     /// Only enable for CLI/developer tools.
     pub allow_signed_transactions: bool,
+    /// Accept EIP-712 typed data (`eth_signTypedData_v4` JSON) on chains that
+    /// support it, in place of a transaction. Only enable for CLI/developer tools.
+    pub allow_typed_data: bool,
+    /// Accept Solana off-chain messages (including Sign-In-With-Solana) on the
+    /// Solana string entry point, in place of a transaction. Only enable for
+    /// CLI/developer tools.
+    pub allow_offchain_messages: bool,
+    /// Accept Sui personal messages (`signPersonalMessage` intent messages) on
+    /// the Sui string entry point, in place of a transaction. Only enable for
+    /// CLI/developer tools.
+    pub allow_personal_messages: bool,
+    /// Accept ERC-4337 user operation JSON (`eth_sendUserOperation`) on the
+    /// Ethereum string entry point, in place of a transaction. Only enable for
+    /// CLI/developer tools.
+    pub allow_user_operations: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -24,6 +39,10 @@ pub struct VisualSignOptions {
     /// `intermediate_output` blob on the `ConversionResult`. Default false
     /// keeps the pre-feature behavior (no intermediate output, unchanged digest).
     pub include_intermediate_output: bool,
+    /// When true, request-supplied metadata can never change how amounts are
+    /// scaled or labelled: tokens missing from the built-in registry are shown
+    /// in raw units instead of using wallet-provided decimals and symbols.
+    pub strict_registry: bool,
 }
 
 /// Converter output: the human-readable `SignablePayload` plus an optional
@@ -304,6 +323,7 @@ mod tests {
             metadata: None,
             developer_config: None,
             include_intermediate_output: false,
+            strict_registry: false,
         };
 
         let result = converter.to_visual_sign_payload(transaction, options);